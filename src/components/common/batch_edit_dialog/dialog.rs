@@ -0,0 +1,433 @@
+// 服务器批量编辑弹窗渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use gpui_component::scroll::ScrollableElement;
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::error;
+
+use super::state::BatchEditState;
+use crate::components::common::icon::render_icon;
+use crate::components::common::server_dialog::helpers::{render_form_label, render_switch};
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::ProxyType;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染服务器批量编辑弹窗覆盖层
+pub fn render_batch_edit_dialog_overlay(
+    state: Entity<BatchEditState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let title = i18n::t(&lang, "batch_edit.title")
+        .replace("{}", &state_read.selected_ids.len().to_string());
+
+    div()
+        .id("batch-edit-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(520.))
+                .max_h(px(640.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(cx.theme().foreground)
+                                .child(title),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(&lang, "batch_edit.hint")),
+                        ),
+                )
+                // 各项修改
+                .child(
+                    div()
+                        .id("batch-edit-fields")
+                        .flex_1()
+                        .min_h(px(0.))
+                        .overflow_y_scrollbar()
+                        .flex()
+                        .flex_col()
+                        .gap_4()
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.username"),
+                            icons::USER,
+                            state_read.edit_username,
+                            |s| &mut s.edit_username,
+                            render_input(&state_read.username_input),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.port"),
+                            icons::LINK,
+                            state_read.edit_port,
+                            |s| &mut s.edit_port,
+                            render_input(&state_read.port_input),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.private_key"),
+                            icons::CODE,
+                            state_read.edit_private_key,
+                            |s| &mut s.edit_private_key,
+                            render_private_key_picker(state.clone(), state_read, cx),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.nav.jump_host"),
+                            icons::SERVER,
+                            state_read.edit_jump_host,
+                            |s| &mut s.edit_jump_host,
+                            render_jump_host_options(state.clone(), state_read, &lang, cx),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.nav.proxy"),
+                            icons::GLOBE,
+                            state_read.edit_proxy,
+                            |s| &mut s.edit_proxy,
+                            render_proxy_fields(state.clone(), state_read, &lang, cx),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.group"),
+                            icons::FOLDER,
+                            state_read.edit_group,
+                            |s| &mut s.edit_group,
+                            render_input(&state_read.group_input),
+                            cx,
+                        ))
+                        .child(render_field(
+                            state.clone(),
+                            i18n::t(&lang, "server_dialog.tags"),
+                            icons::TAG,
+                            state_read.edit_tags,
+                            |s| &mut s.edit_tags,
+                            div()
+                                .flex()
+                                .flex_col()
+                                .gap_2()
+                                .child(render_input(&state_read.add_tags_input))
+                                .child(render_input(&state_read.remove_tags_input))
+                                .into_any_element(),
+                            cx,
+                        )),
+                )
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_2()
+                        .pt_2()
+                        .child(
+                            Button::new("batch-edit-cancel")
+                                .outline()
+                                .child(i18n::t(&lang, "common.cancel"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| {
+                                        state.update(cx, |s, cx| {
+                                            s.close();
+                                            cx.notify();
+                                        });
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("batch-edit-confirm")
+                                .primary()
+                                .child(i18n::t(&lang, "batch_edit.apply"))
+                                .on_click(move |_, _, cx| {
+                                    state.update(cx, |s, cx| {
+                                        s.confirm(cx);
+                                        cx.notify();
+                                    });
+                                }),
+                        ),
+                ),
+        )
+}
+
+/// 渲染一项修改：标题 + 开关，打开开关后显示编辑控件
+fn render_field(
+    state: Entity<BatchEditState>,
+    label: &'static str,
+    icon: &'static str,
+    enabled: bool,
+    flag: fn(&mut BatchEditState) -> &mut bool,
+    content: AnyElement,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(render_form_label(label, icon, cx))
+                .child(render_switch(enabled, move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        let flag = flag(s);
+                        *flag = !*flag;
+                        cx.notify();
+                    });
+                })),
+        )
+        .when(enabled, |this| this.child(content))
+}
+
+/// 渲染输入框（尚未创建时为空）
+fn render_input(input: &Option<Entity<InputState>>) -> AnyElement {
+    match input {
+        Some(input) => Input::new(input).into_any_element(),
+        None => div().into_any_element(),
+    }
+}
+
+/// 私钥：输入框 + 浏览按钮（选择的私钥复制到 keys 目录）
+fn render_private_key_picker(
+    state: Entity<BatchEditState>,
+    state_read: &BatchEditState,
+    cx: &App,
+) -> AnyElement {
+    let secondary_hover = cx.theme().secondary_hover;
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(
+            div()
+                .flex_1()
+                .child(render_input(&state_read.private_key_input)),
+        )
+        .child(
+            div()
+                .id("batch-edit-browse-key")
+                .px_3()
+                .py_1p5()
+                .bg(cx.theme().secondary)
+                .border_1()
+                .border_color(cx.theme().border)
+                .rounded_md()
+                .cursor_pointer()
+                .hover(move |s| s.bg(secondary_hover))
+                .on_click(move |_, _, cx| {
+                    let state = state.clone();
+                    let receiver = cx.prompt_for_paths(PathPromptOptions {
+                        files: true,
+                        directories: false,
+                        multiple: false,
+                        prompt: Some("Select Private Key File".into()),
+                    });
+                    cx.spawn(async move |cx| {
+                        let Ok(Ok(Some(paths))) = receiver.await else {
+                            return;
+                        };
+                        let Some(path) = paths.first() else {
+                            return;
+                        };
+                        match storage::store_private_key(path) {
+                            Ok(filename) => {
+                                let _ = cx.update(|cx| {
+                                    state.update(cx, |s, cx| {
+                                        s.pending_private_key = Some(filename);
+                                        cx.notify();
+                                    });
+                                });
+                            }
+                            Err(e) => error!("[BatchEdit] Failed to store private key: {}", e),
+                        }
+                    })
+                    .detach();
+                })
+                .child(render_icon(
+                    icons::FOLDER_OPEN,
+                    cx.theme().foreground.into(),
+                )),
+        )
+        .into_any_element()
+}
+
+/// 跳板机：取消跳板机或从已保存的服务器中选择
+fn render_jump_host_options(
+    state: Entity<BatchEditState>,
+    state_read: &BatchEditState,
+    lang: &Language,
+    cx: &App,
+) -> AnyElement {
+    let options = std::iter::once((None, i18n::t(lang, "batch_edit.no_jump_host").to_string()))
+        .chain(
+            state_read
+                .jump_host_options
+                .iter()
+                .map(|(id, label)| (Some(id.clone()), label.clone())),
+        );
+    let hover_bg = cx.theme().list_hover;
+
+    div()
+        .id("batch-edit-jump-hosts")
+        .max_h(px(160.))
+        .overflow_y_scrollbar()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .p_1()
+        .children(options.enumerate().map(|(index, (id, label))| {
+            let selected = id == state_read.jump_host_id;
+            let state = state.clone();
+            div()
+                .id(("batch-edit-jump-host", index))
+                .px_2()
+                .py_1p5()
+                .rounded_md()
+                .flex()
+                .items_center()
+                .gap_2()
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .when(selected, |this| this.bg(cx.theme().list_active))
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        s.jump_host_id = id.clone();
+                        cx.notify();
+                    });
+                })
+                .child(
+                    div()
+                        .flex_1()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(label),
+                )
+                .when(selected, |this| {
+                    this.child(
+                        svg()
+                            .path(icons::CHECK)
+                            .size(px(14.))
+                            .text_color(cx.theme().primary),
+                    )
+                })
+        }))
+        .into_any_element()
+}
+
+/// 代理：类型 + 地址 + 端口，地址留空表示取消代理
+fn render_proxy_fields(
+    state: Entity<BatchEditState>,
+    state_read: &BatchEditState,
+    lang: &Language,
+    cx: &App,
+) -> AnyElement {
+    let proxy_type = state_read.proxy_type.clone();
+    let type_button = |id: &'static str, label: &'static str, value: ProxyType| {
+        let selected = proxy_type == value;
+        let state = state.clone();
+        Button::new(id)
+            .small()
+            .when(selected, |b| b.primary())
+            .when(!selected, |b| b.outline())
+            .child(label)
+            .on_click(move |_, _, cx| {
+                state.update(cx, |s, cx| {
+                    s.proxy_type = value.clone();
+                    cx.notify();
+                });
+            })
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .gap_2()
+                .child(type_button(
+                    "batch-edit-proxy-http",
+                    "HTTP",
+                    ProxyType::Http,
+                ))
+                .child(type_button(
+                    "batch-edit-proxy-socks5",
+                    "SOCKS5",
+                    ProxyType::Socks5,
+                )),
+        )
+        .child(
+            div()
+                .flex()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .child(render_input(&state_read.proxy_host_input)),
+                )
+                .child(
+                    div()
+                        .w(px(100.))
+                        .child(render_input(&state_read.proxy_port_input)),
+                ),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(lang, "batch_edit.proxy_hint")),
+        )
+        .into_any_element()
+}
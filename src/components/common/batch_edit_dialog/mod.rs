@@ -0,0 +1,7 @@
+// 服务器批量编辑弹窗：主页多选后统一修改用户名、端口、私钥、跳板机、代理、分组与标签
+
+mod dialog;
+mod state;
+
+pub use dialog::render_batch_edit_dialog_overlay;
+pub use state::BatchEditState;
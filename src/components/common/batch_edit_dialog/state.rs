@@ -0,0 +1,220 @@
+// 服务器批量编辑状态管理：主页的多选与批量修改弹窗
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tracing::error;
+
+use crate::i18n;
+use crate::models::server::{parse_tags, ProxyConfig, ProxyType, ServerPatch};
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 服务器批量编辑状态
+#[derive(Default)]
+pub struct BatchEditState {
+    /// 主页中选中的服务器 ID（Ctrl/Cmd+点击切换）
+    pub selected_ids: Vec<String>,
+    /// 是否显示批量编辑弹窗
+    pub visible: bool,
+
+    // 每一项只有打开开关后才会修改
+    pub edit_username: bool,
+    pub username_input: Option<Entity<InputState>>,
+    pub edit_port: bool,
+    pub port_input: Option<Entity<InputState>>,
+    pub edit_private_key: bool,
+    pub private_key_input: Option<Entity<InputState>>,
+    /// 通过文件选择器导入的私钥文件名（待写入输入框）
+    pub pending_private_key: Option<String>,
+    pub edit_jump_host: bool,
+    /// 选中的跳板机（None 表示取消跳板机）
+    pub jump_host_id: Option<String>,
+    /// 可选跳板机：(服务器 ID, 名称)
+    pub jump_host_options: Vec<(String, String)>,
+    pub edit_proxy: bool,
+    pub proxy_type: ProxyType,
+    pub proxy_host_input: Option<Entity<InputState>>,
+    pub proxy_port_input: Option<Entity<InputState>>,
+    pub edit_group: bool,
+    pub group_input: Option<Entity<InputState>>,
+    pub edit_tags: bool,
+    pub add_tags_input: Option<Entity<InputState>>,
+    pub remove_tags_input: Option<Entity<InputState>>,
+
+    /// 校验或保存失败的错误信息
+    pub error: Option<String>,
+    /// 需要刷新服务器列表
+    pub needs_page_refresh: bool,
+}
+
+impl BatchEditState {
+    /// 服务器是否被选中
+    pub fn is_selected(&self, server_id: &str) -> bool {
+        self.selected_ids.iter().any(|id| id == server_id)
+    }
+
+    /// 切换服务器的选中状态
+    pub fn toggle_selection(&mut self, server_id: String) {
+        if let Some(pos) = self.selected_ids.iter().position(|id| *id == server_id) {
+            self.selected_ids.remove(pos);
+        } else {
+            self.selected_ids.push(server_id);
+        }
+    }
+
+    /// 清空选择
+    pub fn clear_selection(&mut self) {
+        self.selected_ids.clear();
+    }
+
+    /// 打开批量编辑弹窗，各项默认不修改
+    pub fn open(&mut self) {
+        if self.selected_ids.is_empty() {
+            return;
+        }
+        *self = Self {
+            selected_ids: std::mem::take(&mut self.selected_ids),
+            needs_page_refresh: self.needs_page_refresh,
+            visible: true,
+            ..Default::default()
+        };
+        let config = storage::load_servers().unwrap_or_default();
+        self.jump_host_options = config
+            .servers
+            .iter()
+            .map(|s| (s.id.clone(), s.label.clone()))
+            .collect();
+    }
+
+    /// 关闭弹窗（保留选择）
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.error = None;
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let mut create = |slot: &mut Option<Entity<InputState>>, key: &'static str| {
+            if slot.is_none() {
+                let placeholder = i18n::t(&lang, key);
+                *slot = Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+            }
+        };
+        create(&mut self.username_input, "server_dialog.username");
+        create(&mut self.port_input, "server_dialog.port");
+        create(
+            &mut self.private_key_input,
+            "server_dialog.private_key_placeholder",
+        );
+        create(&mut self.proxy_host_input, "server_dialog.proxy_host");
+        create(&mut self.proxy_port_input, "server_dialog.proxy_port");
+        create(&mut self.group_input, "server_dialog.group_placeholder");
+        create(&mut self.add_tags_input, "batch_edit.add_tags_placeholder");
+        create(
+            &mut self.remove_tags_input,
+            "batch_edit.remove_tags_placeholder",
+        );
+
+        if let Some(filename) = self.pending_private_key.take() {
+            if let Some(input) = &self.private_key_input {
+                input.update(cx, |s, cx| s.set_value(filename, window, cx));
+            }
+        }
+    }
+
+    /// 根据打开的开关与输入构建修改内容
+    fn build_patch(&self, lang: &Language, cx: &App) -> Result<ServerPatch, String> {
+        let text = |input: &Option<Entity<InputState>>| {
+            input
+                .as_ref()
+                .map(|i| i.read(cx).text().to_string().trim().to_string())
+                .unwrap_or_default()
+        };
+        let invalid = |key: &'static str| i18n::t(lang, key).to_string();
+
+        let mut patch = ServerPatch::default();
+        if self.edit_username {
+            let username = text(&self.username_input);
+            if username.is_empty() {
+                return Err(invalid("batch_edit.username_empty"));
+            }
+            patch.username = Some(username);
+        }
+        if self.edit_port {
+            match text(&self.port_input).parse::<u16>() {
+                Ok(port) if port > 0 => patch.port = Some(port),
+                _ => return Err(invalid("batch_edit.port_invalid")),
+            }
+        }
+        if self.edit_private_key {
+            let key = text(&self.private_key_input);
+            if key.is_empty() {
+                return Err(invalid("batch_edit.private_key_empty"));
+            }
+            patch.private_key_filename = Some(key);
+        }
+        if self.edit_jump_host {
+            patch.jump_host_id = Some(self.jump_host_id.clone());
+        }
+        if self.edit_proxy {
+            let host = text(&self.proxy_host_input);
+            patch.proxy = Some(if host.is_empty() {
+                None
+            } else {
+                let port = match text(&self.proxy_port_input).parse::<u16>() {
+                    Ok(port) if port > 0 => port,
+                    _ => return Err(invalid("batch_edit.proxy_port_invalid")),
+                };
+                Some(ProxyConfig {
+                    enabled: true,
+                    proxy_type: self.proxy_type.clone(),
+                    host,
+                    port,
+                    username: None,
+                    password_encrypted: None,
+                })
+            });
+        }
+        if self.edit_group {
+            let path = text(&self.group_input);
+            patch.group_path = Some((!path.is_empty()).then_some(path));
+        }
+        if self.edit_tags {
+            patch.add_tags = parse_tags(&text(&self.add_tags_input));
+            patch.remove_tags = parse_tags(&text(&self.remove_tags_input));
+        }
+        if patch.is_empty() {
+            return Err(invalid("batch_edit.nothing_to_apply"));
+        }
+        Ok(patch)
+    }
+
+    /// 应用修改，成功后关闭弹窗并清空选择
+    pub fn confirm(&mut self, cx: &App) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let patch = match self.build_patch(&lang, cx) {
+            Ok(patch) => patch,
+            Err(message) => {
+                self.error = Some(message);
+                return;
+            }
+        };
+        match storage::update_servers(&self.selected_ids, &patch) {
+            Ok(count) => {
+                tracing::info!("[BatchEdit] Updated {} servers", count);
+                self.needs_page_refresh = true;
+                self.selected_ids.clear();
+                self.close();
+            }
+            Err(e) => {
+                error!("[BatchEdit] {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+// 关闭标签确认对话框渲染组件
+
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+use crate::state::{SessionState, TabActiveWork};
+
+/// 一个标签上进行中工作的摘要，如"2 个终端 · 1 个传输"
+fn work_summary(work: &TabActiveWork, lang: &Language) -> String {
+    [
+        (work.terminals, "close_tabs.terminals"),
+        (work.transfers, "close_tabs.transfers"),
+        (work.port_forwards, "close_tabs.forwards"),
+    ]
+    .into_iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, key)| i18n::t(lang, key).replace("{}", &count.to_string()))
+    .collect::<Vec<_>>()
+    .join(" · ")
+}
+
+/// 渲染关闭标签确认对话框覆盖层
+pub fn render_close_tabs_dialog_overlay(
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let (tab_count, work) = session_state
+        .read(cx)
+        .pending_tab_close
+        .as_ref()
+        .map(|pending| (pending.tab_ids.len(), pending.work.clone()))
+        .unwrap_or_default();
+
+    let state_cancel = session_state.clone();
+    let state_confirm = session_state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id("close-tabs-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(420.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(
+                            i18n::t(&lang, "close_tabs.title")
+                                .replace("{}", &tab_count.to_string()),
+                        ),
+                )
+                // 提示信息与进行中的工作
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "close_tabs.message")),
+                        )
+                        .child(
+                            div()
+                                .id("close-tabs-work-list")
+                                .max_h(px(240.))
+                                .overflow_y_scroll()
+                                .flex()
+                                .flex_col()
+                                .gap_1()
+                                .children(work.iter().map(|work| {
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .justify_between()
+                                        .gap_3()
+                                        .text_sm()
+                                        .child(
+                                            div()
+                                                .flex_1()
+                                                .min_w_0()
+                                                .overflow_hidden()
+                                                .text_ellipsis()
+                                                .whitespace_nowrap()
+                                                .text_color(foreground)
+                                                .child(work.label.clone()),
+                                        )
+                                        .child(
+                                            div()
+                                                .flex_shrink_0()
+                                                .text_xs()
+                                                .text_color(muted_foreground)
+                                                .child(work_summary(work, &lang)),
+                                        )
+                                })),
+                        ),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        // 取消按钮
+                        .child(
+                            div()
+                                .id("close-tabs-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |state, cx| {
+                                        state.cancel_pending_close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        // 关闭按钮
+                        .child(
+                            div()
+                                .id("close-tabs-confirm-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().danger)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().danger_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_confirm.update(cx, |state, cx| {
+                                        state.confirm_pending_close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().danger_foreground)
+                                        .child(i18n::t(&lang, "close_tabs.confirm")),
+                                ),
+                        ),
+                ),
+        )
+}
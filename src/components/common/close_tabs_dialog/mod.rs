@@ -0,0 +1,5 @@
+// 关闭标签确认对话框：关闭有进行中工作的标签前列出终端、传输与端口转发
+
+mod dialog;
+
+pub use dialog::render_close_tabs_dialog_overlay;
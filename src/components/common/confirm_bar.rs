@@ -0,0 +1,123 @@
+// 确认栏组件：面板底部显示等待确认的操作说明与取消/确认按钮
+// 进程、连接、服务、Docker、计划任务与软件包视图共用
+
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use crate::i18n::t;
+use crate::models::settings::Language;
+
+/// 确认栏的配色
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfirmTone {
+    /// 不可撤销的操作（结束进程、删除等）：红色背景与确认按钮
+    Danger,
+    /// 耗时或影响较大的操作：黄色背景，主色确认按钮
+    Warning,
+    /// 普通操作：次要背景，主色确认按钮
+    Normal,
+}
+
+/// 使用确认栏的视图：保存等待确认的操作，确认后执行
+pub trait ConfirmBarHost: Sized + 'static {
+    /// 等待确认的操作
+    type Pending;
+
+    /// 保存等待确认操作的字段
+    fn pending_mut(&mut self) -> &mut Option<Self::Pending>;
+
+    /// 执行已确认的操作
+    fn run_confirmed(&mut self, pending: Self::Pending, cx: &mut Context<Self>);
+
+    /// 请求执行操作（先显示确认栏）
+    fn request_confirm(&mut self, pending: Self::Pending, cx: &mut Context<Self>) {
+        *self.pending_mut() = Some(pending);
+        cx.notify();
+    }
+
+    /// 确认执行等待中的操作
+    fn confirm_pending(&mut self, cx: &mut Context<Self>) {
+        if let Some(pending) = self.pending_mut().take() {
+            self.run_confirmed(pending, cx);
+        }
+        cx.notify();
+    }
+
+    /// 取消等待中的操作
+    fn cancel_pending(&mut self, cx: &mut Context<Self>) {
+        *self.pending_mut() = None;
+        cx.notify();
+    }
+}
+
+/// 渲染确认栏，`id` 为取消/确认按钮元素 ID 的前缀
+pub fn render_confirm_bar<V: ConfirmBarHost>(
+    id: &str,
+    message: impl IntoElement,
+    tone: ConfirmTone,
+    lang: &Language,
+    cx: &mut Context<V>,
+) -> impl IntoElement {
+    let (bg, confirm_bg) = match tone {
+        ConfirmTone::Danger => (cx.theme().danger.opacity(0.12), cx.theme().danger),
+        ConfirmTone::Warning => (cx.theme().warning.opacity(0.12), cx.theme().primary),
+        ConfirmTone::Normal => (cx.theme().secondary, cx.theme().primary),
+    };
+
+    div()
+        .w_full()
+        .flex_shrink_0()
+        .px_2()
+        .py_1()
+        .flex()
+        .items_center()
+        .gap_2()
+        .border_t_1()
+        .border_color(cx.theme().border)
+        .bg(bg)
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .text_xs()
+                .text_color(cx.theme().foreground)
+                .child(message),
+        )
+        .child(render_bar_button(
+            SharedString::from(format!("{}-cancel", id)),
+            t(lang, "common.cancel"),
+            cx.theme().foreground,
+            cx.theme().secondary,
+            cx.listener(|this, _, _, cx| this.cancel_pending(cx)),
+        ))
+        .child(render_bar_button(
+            SharedString::from(format!("{}-ok", id)),
+            t(lang, "common.confirm"),
+            cx.theme().primary_foreground,
+            confirm_bg,
+            cx.listener(|this, _, _, cx| this.confirm_pending(cx)),
+        ))
+}
+
+/// 渲染确认栏中的文字按钮（面板中其他同样式的小按钮也可使用）
+pub fn render_bar_button(
+    id: impl Into<ElementId>,
+    label: &'static str,
+    color: Hsla,
+    bg: Hsla,
+    on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+) -> impl IntoElement {
+    div()
+        .id(id)
+        .flex_shrink_0()
+        .px_2()
+        .py_0p5()
+        .rounded(px(4.))
+        .text_xs()
+        .text_color(color)
+        .bg(bg)
+        .cursor_pointer()
+        .hover(|s| s.opacity(0.8))
+        .child(label)
+        .on_click(on_click)
+}
@@ -0,0 +1,246 @@
+// 服务器分组弹窗渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::scroll::ScrollableElement;
+use gpui_component::ActiveTheme;
+
+use super::state::{GroupDialogMode, GroupDialogState};
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染服务器分组弹窗覆盖层
+pub fn render_group_dialog_overlay(state: Entity<GroupDialogState>, cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let is_delete = matches!(state_read.mode, GroupDialogMode::Delete { .. });
+    let title = match &state_read.mode {
+        GroupDialogMode::Create { parent_id: Some(_) } => {
+            i18n::t(&lang, "server_group.dialog.create_sub_title")
+                .replace("{}", &state_read.group_path)
+        }
+        GroupDialogMode::Create { parent_id: None } => {
+            i18n::t(&lang, "server_group.dialog.create_title").to_string()
+        }
+        GroupDialogMode::Rename { .. } => {
+            i18n::t(&lang, "server_group.dialog.rename_title").to_string()
+        }
+        GroupDialogMode::Delete { .. } => {
+            i18n::t(&lang, "server_group.dialog.delete_title").replace("{}", &state_read.group_path)
+        }
+        GroupDialogMode::Closed => String::new(),
+    };
+
+    div()
+        .id("group-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(420.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(title),
+                )
+                // 名称输入（新建、重命名）
+                .when(!is_delete, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(i18n::t(&lang, "server_group.dialog.name")),
+                            )
+                            .child(if let Some(input) = &state_read.name_input {
+                                Input::new(input).into_any_element()
+                            } else {
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(i18n::t(&lang, "common.loading"))
+                                    .into_any_element()
+                            }),
+                    )
+                })
+                // 删除：选择服务器去向
+                .when(is_delete, |this| {
+                    this.when(state_read.server_count > 0, |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(
+                                    i18n::t(&lang, "server_group.dialog.reassign")
+                                        .replace("{}", &state_read.server_count.to_string()),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .id("group-dialog-reassign")
+                                .max_h(px(240.))
+                                .overflow_y_scrollbar()
+                                .rounded_md()
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .p_1()
+                                .children(state_read.reassign_targets.iter().enumerate().map(
+                                    |(index, (id, path))| {
+                                        let label = if id.is_none() {
+                                            i18n::t(&lang, "server_list.ungrouped").to_string()
+                                        } else {
+                                            path.clone()
+                                        };
+                                        render_reassign_option(
+                                            index,
+                                            id.clone(),
+                                            label,
+                                            *id == state_read.reassign_to,
+                                            state.clone(),
+                                            cx,
+                                        )
+                                    },
+                                )),
+                        )
+                    })
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(&lang, "server_group.dialog.subgroups_note")),
+                    )
+                })
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_2()
+                        .pt_2()
+                        .child(
+                            Button::new("group-dialog-cancel")
+                                .outline()
+                                .child(i18n::t(&lang, "common.cancel"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| {
+                                        state.update(cx, |s, cx| {
+                                            s.close();
+                                            cx.notify();
+                                        });
+                                    }
+                                }),
+                        )
+                        .child({
+                            let button = Button::new("group-dialog-confirm");
+                            let button = if is_delete {
+                                button
+                                    .danger()
+                                    .child(i18n::t(&lang, "server_group.dialog.delete_confirm"))
+                            } else {
+                                button.primary().child(i18n::t(&lang, "common.save"))
+                            };
+                            button.on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.confirm(cx);
+                                    cx.notify();
+                                });
+                            })
+                        }),
+                ),
+        )
+}
+
+/// 渲染一个服务器去向选项
+fn render_reassign_option(
+    index: usize,
+    group_id: Option<String>,
+    label: String,
+    selected: bool,
+    state: Entity<GroupDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let hover_bg = cx.theme().list_hover;
+
+    div()
+        .id(("group-reassign-option", index))
+        .px_2()
+        .py_1p5()
+        .rounded_md()
+        .flex()
+        .items_center()
+        .gap_2()
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .when(selected, |this| this.bg(cx.theme().list_active))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                s.reassign_to = group_id.clone();
+                cx.notify();
+            });
+        })
+        .child(
+            svg()
+                .path(if group_id.is_some() {
+                    icons::FOLDER
+                } else {
+                    icons::SERVER
+                })
+                .size(px(14.))
+                .text_color(cx.theme().muted_foreground),
+        )
+        .child(
+            div()
+                .flex_1()
+                .text_sm()
+                .text_color(cx.theme().foreground)
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(label),
+        )
+        .when(selected, |this| {
+            this.child(
+                svg()
+                    .path(icons::CHECK)
+                    .size(px(14.))
+                    .text_color(cx.theme().primary),
+            )
+        })
+}
@@ -0,0 +1,7 @@
+// 服务器分组管理弹窗：新建、重命名与删除（删除时选择服务器的去向）
+
+mod dialog;
+mod state;
+
+pub use dialog::render_group_dialog_overlay;
+pub use state::{GroupDialogMode, GroupDialogState};
@@ -0,0 +1,181 @@
+// 服务器分组弹窗状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tracing::error;
+
+use crate::i18n;
+use crate::models::server::GROUP_PATH_SEPARATOR;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 弹窗模式
+#[derive(Clone, PartialEq, Eq, Default)]
+pub enum GroupDialogMode {
+    #[default]
+    Closed,
+    /// 新建分组（`parent_id` 为 None 时建在顶层）
+    Create {
+        parent_id: Option<String>,
+    },
+    Rename {
+        group_id: String,
+    },
+    Delete {
+        group_id: String,
+    },
+}
+
+/// 服务器分组弹窗状态
+#[derive(Default)]
+pub struct GroupDialogState {
+    pub mode: GroupDialogMode,
+    /// 输入框：分组名称（新建、重命名）
+    pub name_input: Option<Entity<InputState>>,
+    /// 标题中显示的分组路径（新建子分组时为父分组，删除时为被删分组）
+    pub group_path: String,
+    /// 删除时分组中的服务器数量
+    pub server_count: usize,
+    /// 删除时可选的服务器去向：(分组 ID, 路径)，ID 为 None 表示未分组
+    pub reassign_targets: Vec<(Option<String>, String)>,
+    /// 删除时选中的去向
+    pub reassign_to: Option<String>,
+    /// 校验或保存失败的错误信息
+    pub error: Option<String>,
+    /// 需要刷新服务器列表
+    pub needs_page_refresh: bool,
+    /// 待设置的初始名称（重命名模式）
+    pending_name_value: Option<String>,
+}
+
+impl GroupDialogState {
+    /// 是否显示弹窗
+    pub fn is_open(&self) -> bool {
+        self.mode != GroupDialogMode::Closed
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if matches!(self.mode, GroupDialogMode::Delete { .. }) {
+            return;
+        }
+        if self.name_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "server_group.dialog.name_placeholder");
+            self.name_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        if let Some(name) = self.pending_name_value.take() {
+            if let Some(input) = &self.name_input {
+                input.update(cx, |s, cx| s.set_value(name, window, cx));
+            }
+        }
+    }
+
+    /// 打开新建分组弹窗
+    pub fn open_create(&mut self, parent_id: Option<String>) {
+        self.reset();
+        if let (Some(parent), Ok(config)) = (&parent_id, storage::load_servers()) {
+            self.group_path = config.group_path(parent);
+        }
+        self.mode = GroupDialogMode::Create { parent_id };
+    }
+
+    /// 打开重命名弹窗
+    pub fn open_rename(&mut self, group_id: String) {
+        self.reset();
+        if let Ok(config) = storage::load_servers() {
+            self.pending_name_value = config
+                .groups
+                .iter()
+                .find(|g| g.id == group_id)
+                .map(|g| g.name.clone());
+        }
+        self.mode = GroupDialogMode::Rename { group_id };
+    }
+
+    /// 打开删除弹窗，服务器默认移到上一级分组
+    pub fn open_delete(&mut self, group_id: String) {
+        self.reset();
+        let config = storage::load_servers().unwrap_or_default();
+        self.group_path = config.group_path(&group_id);
+        self.server_count = config
+            .servers
+            .iter()
+            .filter(|s| s.group_id.as_deref() == Some(group_id.as_str()))
+            .count();
+        self.reassign_targets = std::iter::once((None, String::new()))
+            .chain(
+                config
+                    .ordered_groups()
+                    .into_iter()
+                    .filter(|(_, g)| g.id != group_id)
+                    .map(|(_, g)| (Some(g.id.clone()), config.group_path(&g.id))),
+            )
+            .collect();
+        self.reassign_to = config
+            .groups
+            .iter()
+            .find(|g| g.id == group_id)
+            .and_then(|g| g.parent_id.clone());
+        self.mode = GroupDialogMode::Delete { group_id };
+    }
+
+    /// 关闭弹窗
+    pub fn close(&mut self) {
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        *self = Self {
+            needs_page_refresh: self.needs_page_refresh,
+            ..Default::default()
+        };
+    }
+
+    /// 确认当前操作，成功后关闭弹窗
+    pub fn confirm(&mut self, cx: &App) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let name = self
+            .name_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default();
+
+        let result = match self.mode.clone() {
+            GroupDialogMode::Closed => return,
+            GroupDialogMode::Create { .. } | GroupDialogMode::Rename { .. } if name.is_empty() => {
+                self.error = Some(i18n::t(&lang, "server_group.dialog.name_empty").to_string());
+                return;
+            }
+            GroupDialogMode::Create { .. } | GroupDialogMode::Rename { .. }
+                if name.contains(GROUP_PATH_SEPARATOR) =>
+            {
+                self.error = Some(i18n::t(&lang, "server_group.dialog.name_invalid").to_string());
+                return;
+            }
+            GroupDialogMode::Create { parent_id } => {
+                storage::add_group(&name, parent_id).map(|_| ())
+            }
+            GroupDialogMode::Rename { group_id } => storage::rename_group(&group_id, &name),
+            GroupDialogMode::Delete { group_id } => {
+                storage::delete_group(&group_id, self.reassign_to.clone())
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                self.needs_page_refresh = true;
+                self.close();
+            }
+            Err(e) => {
+                error!("[Group] {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+// 主机发现对话框渲染
+
+use std::collections::HashSet;
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::scroll::ScrollableElement;
+use gpui_component::{ActiveTheme, Sizable};
+
+use super::state::HostDiscoveryState;
+use crate::components::common::server_dialog::ServerDialogState;
+use crate::i18n;
+use crate::models::server::ServerData;
+use crate::models::settings::Language;
+use crate::services::host_discovery::DiscoveredHost;
+use crate::services::storage;
+
+/// 渲染主机发现对话框覆盖层：输入网段 → 扫描 → 将响应的主机添加为服务器
+pub fn render_host_discovery_dialog_overlay(
+    state: Entity<HostDiscoveryState>,
+    server_dialog_state: Entity<ServerDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let scanning = state_read.is_scanning();
+    let total = state_read.total;
+    let scanned = state_read.scanned;
+    let found = state_read.results.len();
+    // 已保存的服务器地址，用于标记已添加的主机
+    let saved: HashSet<(String, u16)> = storage::load_servers()
+        .unwrap_or_default()
+        .servers
+        .into_iter()
+        .map(|s| (s.host, s.port))
+        .collect();
+
+    div()
+        .id("host-discovery-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(640.))
+                .max_h(px(600.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题与说明
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(&lang, "discovery.title")),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(i18n::t(&lang, "discovery.hint")),
+                )
+                // 扫描目标、端口与开始/停止按钮
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .children(
+                            state_read
+                                .range_input
+                                .clone()
+                                .map(|input| div().flex_1().child(Input::new(&input))),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(&lang, "discovery.port")),
+                        )
+                        .children(
+                            state_read
+                                .port_input
+                                .clone()
+                                .map(|input| div().w(px(72.)).child(Input::new(&input))),
+                        )
+                        .child(if scanning {
+                            Button::new("host-discovery-stop")
+                                .outline()
+                                .child(i18n::t(&lang, "discovery.stop"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| state.update(cx, |s, cx| s.cancel(cx))
+                                })
+                        } else {
+                            Button::new("host-discovery-start")
+                                .primary()
+                                .child(i18n::t(&lang, "discovery.start"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| state.update(cx, |s, cx| s.start(cx))
+                                })
+                        }),
+                )
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 进度
+                .when(total > 0, |this| {
+                    this.child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                i18n::t(&lang, "discovery.progress")
+                                    .replacen("{}", &scanned.to_string(), 1)
+                                    .replacen("{}", &total.to_string(), 1)
+                                    .replacen("{}", &found.to_string(), 1),
+                            ),
+                    )
+                })
+                // 结果列表
+                .when(found > 0, |this| {
+                    this.child(
+                        div()
+                            .id("host-discovery-results")
+                            .flex_1()
+                            .min_h(px(0.))
+                            .max_h(px(340.))
+                            .overflow_y_scrollbar()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .p_1()
+                            .children(state_read.results.iter().enumerate().map(
+                                |(index, host)| {
+                                    let is_saved =
+                                        saved.contains(&(host.ip.to_string(), host.port));
+                                    render_result_row(
+                                        index,
+                                        host,
+                                        is_saved,
+                                        &lang,
+                                        state.clone(),
+                                        server_dialog_state.clone(),
+                                        cx,
+                                    )
+                                },
+                            )),
+                    )
+                })
+                .when(total > 0 && !scanning && found == 0, |this| {
+                    this.child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(&lang, "discovery.empty")),
+                    )
+                })
+                // 底部按钮
+                .child(
+                    div().flex().justify_end().pt_2().child(
+                        Button::new("host-discovery-close")
+                            .outline()
+                            .child(i18n::t(&lang, "common.close"))
+                            .on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.close();
+                                    cx.notify();
+                                });
+                            }),
+                    ),
+                ),
+        )
+}
+
+/// 渲染一台响应的主机：地址、服务端标识、延迟与“添加为服务器”按钮
+fn render_result_row(
+    index: usize,
+    host: &DiscoveredHost,
+    is_saved: bool,
+    lang: &Language,
+    state: Entity<HostDiscoveryState>,
+    server_dialog_state: Entity<ServerDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let address = host.ip.to_string();
+    let port = host.port;
+    let banner = host
+        .banner
+        .clone()
+        .unwrap_or_else(|| i18n::t(lang, "discovery.no_banner").to_string());
+
+    div()
+        .id(("host-discovery-row", index))
+        .px_2()
+        .py_1p5()
+        .rounded_md()
+        .flex()
+        .items_center()
+        .gap_3()
+        .hover(|s| s.bg(cx.theme().list_hover))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(format!("{}:{}", address, port)),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(banner),
+                ),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(format!("{} ms", host.latency_ms)),
+        )
+        .child(if is_saved {
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(lang, "discovery.added"))
+                .into_any_element()
+        } else {
+            Button::new(("host-discovery-add", index))
+                .outline()
+                .xsmall()
+                .child(i18n::t(lang, "discovery.add"))
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        s.hide();
+                        cx.notify();
+                    });
+                    let server = ServerData {
+                        label: address.clone(),
+                        host: address.clone(),
+                        port,
+                        ..Default::default()
+                    };
+                    server_dialog_state.update(cx, |s, cx| {
+                        s.open_add_prefilled(server);
+                        cx.notify();
+                    });
+                })
+                .into_any_element()
+        })
+}
@@ -0,0 +1,7 @@
+// 主机发现对话框组件：扫描网段中开放 SSH 端口的主机并添加为服务器
+
+mod dialog;
+mod state;
+
+pub use dialog::render_host_discovery_dialog_overlay;
+pub use state::HostDiscoveryState;
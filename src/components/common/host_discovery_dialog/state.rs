@@ -0,0 +1,209 @@
+// 主机发现对话框状态：输入扫描目标与端口，后台扫描并实时列出响应的主机
+
+use gpui::{AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::host_discovery::{self, DiscoveredHost, TargetError, MAX_CONCURRENT};
+use crate::services::storage;
+
+/// 默认扫描端口
+const DEFAULT_PORT: u16 = 22;
+
+/// 主机发现对话框状态
+#[derive(Default)]
+pub struct HostDiscoveryState {
+    pub visible: bool,
+    /// 输入框：扫描目标（网段、范围或地址）
+    pub range_input: Option<Entity<InputState>>,
+    /// 输入框：端口
+    pub port_input: Option<Entity<InputState>>,
+    /// 响应的主机（按地址排序）
+    pub results: Vec<DiscoveredHost>,
+    /// 本次扫描的地址总数
+    pub total: usize,
+    /// 已扫描的地址数
+    pub scanned: usize,
+    /// 输入无效的提示
+    pub error: Option<String>,
+    cancel_token: Option<CancellationToken>,
+    scan_id: u64,
+}
+
+impl HostDiscoveryState {
+    /// 打开对话框（隐藏期间的扫描结果保留，便于继续添加）
+    pub fn open(&mut self) {
+        self.visible = true;
+    }
+
+    /// 暂时隐藏对话框（添加服务器时），扫描继续进行
+    pub fn hide(&mut self) {
+        self.visible = false;
+    }
+
+    /// 关闭对话框：取消扫描并清空结果
+    pub fn close(&mut self) {
+        if let Some(token) = self.cancel_token.take() {
+            token.cancel();
+        }
+        // 递增扫描编号，丢弃已取消扫描的剩余结果
+        *self = Self {
+            scan_id: self.scan_id + 1,
+            ..Default::default()
+        };
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.cancel_token.is_some()
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用），扫描目标默认为本机所在网段
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        if self.range_input.is_none() {
+            let placeholder = i18n::t(&lang, "discovery.range_placeholder");
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            if let Some(subnet) = host_discovery::local_subnet() {
+                input.update(cx, |s, cx| s.set_value(subnet, window, cx));
+            }
+            self.range_input = Some(input);
+        }
+        if self.port_input.is_none() {
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder("22"));
+            input.update(cx, |s, cx| {
+                s.set_value(DEFAULT_PORT.to_string(), window, cx)
+            });
+            self.port_input = Some(input);
+        }
+    }
+
+    /// 开始扫描：解析目标后在 SSH 运行时中并发探测，结果逐个返回界面
+    pub fn start(&mut self, cx: &mut Context<Self>) {
+        if self.is_scanning() {
+            return;
+        }
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let range = self
+            .range_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string())
+            .unwrap_or_default();
+        let port = self
+            .port_input
+            .as_ref()
+            .map(|i| i.read(cx).text().trim().to_string())
+            .unwrap_or_default();
+
+        let targets = match host_discovery::parse_targets(&range) {
+            Ok(targets) if targets.is_empty() => {
+                self.error = Some(i18n::t(&lang, "discovery.error.empty").to_string());
+                cx.notify();
+                return;
+            }
+            Ok(targets) => targets,
+            Err(e) => {
+                let detail = match &e {
+                    TargetError::Invalid(token) => token.clone(),
+                    TargetError::TooMany => host_discovery::MAX_HOSTS.to_string(),
+                };
+                self.error = Some(i18n::t(&lang, e.message_key()).replace("{}", &detail));
+                cx.notify();
+                return;
+            }
+        };
+        let Some(port) = port.parse::<u16>().ok().filter(|p| *p > 0) else {
+            self.error = Some(i18n::t(&lang, "discovery.error.port").to_string());
+            cx.notify();
+            return;
+        };
+
+        info!(
+            "[Discovery] Scanning {} addresses on port {}",
+            targets.len(),
+            port
+        );
+        self.error = None;
+        self.results.clear();
+        self.total = targets.len();
+        self.scanned = 0;
+        let token = CancellationToken::new();
+        self.cancel_token = Some(token.clone());
+        self.scan_id += 1;
+        let scan_id = self.scan_id;
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Option<DiscoveredHost>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT));
+                for ip in targets {
+                    let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                        break;
+                    };
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    let tx = tx.clone();
+                    let token = token.clone();
+                    tokio::spawn(async move {
+                        let _permit = permit;
+                        tokio::select! {
+                            _ = token.cancelled() => {}
+                            host = host_discovery::probe(ip, port) => {
+                                let _ = tx.send(host);
+                            }
+                        }
+                    });
+                }
+            });
+
+        cx.spawn(async move |this, cx| {
+            while let Some(host) = rx.recv().await {
+                let updated = this.update(cx, |this, cx| {
+                    if this.scan_id != scan_id {
+                        return;
+                    }
+                    this.scanned += 1;
+                    if let Some(host) = host {
+                        let index = this.results.partition_point(|h| h.ip < host.ip);
+                        this.results.insert(index, host);
+                    }
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
+                }
+            }
+            // 全部地址扫描完成（或已取消）
+            let _ = this.update(cx, |this, cx| {
+                if this.scan_id == scan_id {
+                    info!(
+                        "[Discovery] Scan finished, {} of {} addresses responded",
+                        this.results.len(),
+                        this.scanned
+                    );
+                    this.cancel_token = None;
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+        cx.notify();
+    }
+
+    /// 停止扫描（已发现的主机保留）
+    pub fn cancel(&mut self, cx: &mut Context<Self>) {
+        if let Some(token) = self.cancel_token.take() {
+            token.cancel();
+        }
+        cx.notify();
+    }
+}
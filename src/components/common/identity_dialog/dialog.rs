@@ -0,0 +1,267 @@
+// 身份编辑弹窗渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::error;
+
+use super::state::IdentityDialogState;
+use crate::components::common::icon::render_icon;
+use crate::components::common::server_dialog::helpers::render_form_label;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::IdentityKind;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染身份编辑弹窗覆盖层
+pub fn render_identity_dialog_overlay(
+    state: Entity<IdentityDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let title = if state_read.editing.is_some() {
+        i18n::t(&lang, "identity.edit_title")
+    } else {
+        i18n::t(&lang, "identity.new_title")
+    };
+    let kind = state_read.kind.clone();
+
+    let kind_button = |id: &'static str, label: &'static str, value: IdentityKind| {
+        let selected = kind == value;
+        let state = state.clone();
+        Button::new(id)
+            .small()
+            .when(selected, |b| b.primary())
+            .when(!selected, |b| b.outline())
+            .child(label)
+            .on_click(move |_, _, cx| {
+                state.update(cx, |s, cx| {
+                    s.kind = value.clone();
+                    cx.notify();
+                });
+            })
+    };
+
+    div()
+        .id("identity-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(480.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(title),
+                )
+                // 密钥来源
+                .child(
+                    div()
+                        .flex()
+                        .gap_2()
+                        .child(kind_button(
+                            "identity-kind-key-file",
+                            i18n::t(&lang, "identity.kind.key_file"),
+                            IdentityKind::KeyFile,
+                        ))
+                        .child(kind_button(
+                            "identity-kind-agent",
+                            i18n::t(&lang, "identity.kind.agent"),
+                            IdentityKind::Agent,
+                        )),
+                )
+                .child(render_field(
+                    i18n::t(&lang, "identity.name"),
+                    icons::KEY,
+                    &state_read.name_input,
+                    cx,
+                ))
+                .child(render_field(
+                    i18n::t(&lang, "identity.username"),
+                    icons::USER,
+                    &state_read.username_input,
+                    cx,
+                ))
+                .when(kind == IdentityKind::KeyFile, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .child(render_form_label(
+                                i18n::t(&lang, "server_dialog.private_key"),
+                                icons::CODE,
+                                cx,
+                            ))
+                            .child(
+                                div()
+                                    .flex()
+                                    .items_center()
+                                    .gap_2()
+                                    .child(
+                                        div()
+                                            .flex_1()
+                                            .child(render_input(&state_read.private_key_input)),
+                                    )
+                                    .child(render_browse_button(state.clone(), cx)),
+                            ),
+                    )
+                    .child(render_field(
+                        i18n::t(&lang, "server_dialog.passphrase"),
+                        icons::LOCK,
+                        &state_read.passphrase_input,
+                        cx,
+                    ))
+                })
+                .when(kind == IdentityKind::Agent, |this| {
+                    this.child(render_field(
+                        i18n::t(&lang, "identity.agent_key"),
+                        icons::FINGERPRINT,
+                        &state_read.agent_key_input,
+                        cx,
+                    ))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(&lang, "identity.agent_hint")),
+                    )
+                })
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_2()
+                        .pt_2()
+                        .child(
+                            Button::new("identity-cancel")
+                                .outline()
+                                .child(i18n::t(&lang, "common.cancel"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| {
+                                        state.update(cx, |s, cx| {
+                                            s.close();
+                                            cx.notify();
+                                        });
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("identity-save")
+                                .primary()
+                                .child(i18n::t(&lang, "common.save"))
+                                .on_click(move |_, _, cx| {
+                                    state.update(cx, |s, cx| {
+                                        s.confirm(cx);
+                                        cx.notify();
+                                    });
+                                }),
+                        ),
+                ),
+        )
+}
+
+/// 渲染带标题的输入框
+fn render_field(
+    label: &'static str,
+    icon: &'static str,
+    input: &Option<Entity<InputState>>,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(render_form_label(label, icon, cx))
+        .child(render_input(input))
+}
+
+/// 渲染输入框（尚未创建时为空）
+fn render_input(input: &Option<Entity<InputState>>) -> AnyElement {
+    match input {
+        Some(input) => Input::new(input).into_any_element(),
+        None => div().into_any_element(),
+    }
+}
+
+/// 浏览按钮：选择的私钥复制到 keys 目录
+fn render_browse_button(state: Entity<IdentityDialogState>, cx: &App) -> impl IntoElement {
+    let secondary_hover = cx.theme().secondary_hover;
+    div()
+        .id("identity-browse-key")
+        .px_3()
+        .py_1p5()
+        .bg(cx.theme().secondary)
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(secondary_hover))
+        .on_click(move |_, _, cx| {
+            let state = state.clone();
+            let receiver = cx.prompt_for_paths(PathPromptOptions {
+                files: true,
+                directories: false,
+                multiple: false,
+                prompt: Some("Select Private Key File".into()),
+            });
+            cx.spawn(async move |cx| {
+                let Ok(Ok(Some(paths))) = receiver.await else {
+                    return;
+                };
+                let Some(path) = paths.first() else {
+                    return;
+                };
+                match storage::store_private_key(path) {
+                    Ok(filename) => {
+                        let _ = cx.update(|cx| {
+                            state.update(cx, |s, cx| {
+                                s.pending_private_key = Some(filename);
+                                cx.notify();
+                            });
+                        });
+                    }
+                    Err(e) => error!("[Identity] Failed to store private key: {}", e),
+                }
+            })
+            .detach();
+        })
+        .child(render_icon(
+            icons::FOLDER_OPEN,
+            cx.theme().foreground.into(),
+        ))
+}
@@ -0,0 +1,7 @@
+// 身份编辑弹窗：新建或编辑身份库中的私钥 / Agent 身份
+
+mod dialog;
+mod state;
+
+pub use dialog::render_identity_dialog_overlay;
+pub use state::IdentityDialogState;
@@ -0,0 +1,177 @@
+// 身份编辑状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tracing::{error, info};
+
+use crate::i18n;
+use crate::models::server::{Identity, IdentityKind};
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 身份编辑状态
+#[derive(Default)]
+pub struct IdentityDialogState {
+    pub visible: bool,
+    /// 编辑中的身份（新建时为 None）
+    pub editing: Option<Identity>,
+    pub kind: IdentityKind,
+    pub name_input: Option<Entity<InputState>>,
+    pub username_input: Option<Entity<InputState>>,
+    pub private_key_input: Option<Entity<InputState>>,
+    pub passphrase_input: Option<Entity<InputState>>,
+    pub agent_key_input: Option<Entity<InputState>>,
+    /// 通过文件选择器导入的私钥文件名（待写入输入框）
+    pub pending_private_key: Option<String>,
+    /// 打开后待填入表单
+    pending_load: bool,
+    /// 校验或保存失败的错误信息
+    pub error: Option<String>,
+}
+
+impl IdentityDialogState {
+    /// 新建身份
+    pub fn open_new(&mut self) {
+        *self = Self {
+            visible: true,
+            ..Default::default()
+        };
+    }
+
+    /// 编辑身份
+    pub fn open_edit(&mut self, identity: Identity) {
+        *self = Self {
+            visible: true,
+            kind: identity.kind.clone(),
+            editing: Some(identity),
+            pending_load: true,
+            ..Default::default()
+        };
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.error = None;
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let mut create = |slot: &mut Option<Entity<InputState>>, key: &'static str| {
+            if slot.is_none() {
+                let placeholder = i18n::t(&lang, key);
+                *slot = Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+            }
+        };
+        create(&mut self.name_input, "identity.name_placeholder");
+        create(&mut self.username_input, "identity.username_placeholder");
+        create(
+            &mut self.private_key_input,
+            "server_dialog.private_key_placeholder",
+        );
+        create(&mut self.passphrase_input, "server_dialog.passphrase");
+        create(&mut self.agent_key_input, "identity.agent_key_placeholder");
+
+        if std::mem::take(&mut self.pending_load) {
+            if let Some(identity) = self.editing.clone() {
+                let mut set = |input: &Option<Entity<InputState>>, value: String| {
+                    if let Some(input) = input {
+                        input.update(cx, |s, cx| s.set_value(value, window, cx));
+                    }
+                };
+                set(&self.name_input, identity.name);
+                set(&self.username_input, identity.username);
+                set(
+                    &self.private_key_input,
+                    identity.private_key_filename.unwrap_or_default(),
+                );
+                set(
+                    &self.passphrase_input,
+                    identity.key_passphrase_encrypted.unwrap_or_default(),
+                );
+                set(
+                    &self.agent_key_input,
+                    identity.agent_key.unwrap_or_default(),
+                );
+            }
+        }
+        if let Some(filename) = self.pending_private_key.take() {
+            if let Some(input) = &self.private_key_input {
+                input.update(cx, |s, cx| s.set_value(filename, window, cx));
+            }
+        }
+    }
+
+    /// 校验并保存；编辑时更换了私钥则按更换私钥处理（清理不再使用的旧私钥）
+    pub fn confirm(&mut self, cx: &App) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let text = |input: &Option<Entity<InputState>>| {
+            input
+                .as_ref()
+                .map(|i| i.read(cx).text().to_string().trim().to_string())
+                .unwrap_or_default()
+        };
+        let non_empty = |value: String| (!value.is_empty()).then_some(value);
+
+        let name = text(&self.name_input);
+        if name.is_empty() {
+            self.error = Some(i18n::t(&lang, "identity.name_empty").to_string());
+            return;
+        }
+        let private_key = non_empty(text(&self.private_key_input));
+        if self.kind == IdentityKind::KeyFile && private_key.is_none() {
+            self.error = Some(i18n::t(&lang, "identity.private_key_empty").to_string());
+            return;
+        }
+        let is_key_file = self.kind == IdentityKind::KeyFile;
+        let identity = Identity {
+            id: self
+                .editing
+                .as_ref()
+                .map(|i| i.id.clone())
+                .unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+            name,
+            kind: self.kind.clone(),
+            username: text(&self.username_input),
+            private_key_filename: private_key.filter(|_| is_key_file),
+            key_passphrase_encrypted: non_empty(text(&self.passphrase_input))
+                .filter(|_| is_key_file),
+            agent_key: non_empty(text(&self.agent_key_input)).filter(|_| !is_key_file),
+            created_at: self
+                .editing
+                .as_ref()
+                .map(|i| i.created_at.clone())
+                .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        };
+
+        let key_changed = self.editing.as_ref().is_some_and(|old| {
+            old.private_key_filename.is_some()
+                && old.private_key_filename != identity.private_key_filename
+        });
+        let result = (|| {
+            if let (true, Some(filename)) = (key_changed, &identity.private_key_filename) {
+                let count = storage::rotate_identity_key(
+                    &identity.id,
+                    filename.clone(),
+                    identity.key_passphrase_encrypted.clone(),
+                )?;
+                info!(
+                    "[Identity] Rotated key of {}, used by {} servers",
+                    identity.name, count
+                );
+            }
+            storage::save_identity(identity)
+        })();
+        match result {
+            Ok(()) => self.close(),
+            Err(e) => {
+                error!("[Identity] Failed to save identity: {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
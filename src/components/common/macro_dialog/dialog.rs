@@ -0,0 +1,170 @@
+// 键盘宏编辑弹窗渲染组件
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::MacroDialogState;
+
+/// 渲染键盘宏编辑弹窗覆盖层
+pub fn render_macro_dialog_overlay(state: Entity<MacroDialogState>, cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let title = if state_read.is_new {
+        i18n::t(&lang, "macro.dialog.add_title")
+    } else {
+        i18n::t(&lang, "macro.dialog.edit_title")
+    };
+    let name_input = state_read.name_input.clone();
+    let keys_input = state_read.keys_input.clone();
+    let keybinding_input = state_read.keybinding_input.clone();
+    let error = state_read.error.clone();
+
+    let state_cancel = state.clone();
+    let state_save = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let label = |key: &'static str| {
+        div()
+            .text_sm()
+            .text_color(muted_foreground)
+            .child(i18n::t(&lang, key))
+    };
+    let hint = |key: &'static str| {
+        div()
+            .text_xs()
+            .text_color(muted_foreground)
+            .child(i18n::t(&lang, key))
+    };
+
+    div()
+        .id("macro-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(480.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(title),
+                )
+                // 名称
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("macro.dialog.name"))
+                        .when_some(name_input, |this, input| this.child(Input::new(&input))),
+                )
+                // 按键序列
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("macro.dialog.keys"))
+                        .when_some(keys_input, |this, input| {
+                            this.child(Input::new(&input).font_family("monospace"))
+                        })
+                        .child(hint("macro.dialog.keys_hint")),
+                )
+                // 快捷键
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("macro.dialog.keybinding"))
+                        .when_some(keybinding_input, |this, input| {
+                            this.child(Input::new(&input))
+                        })
+                        .child(hint("macro.dialog.keybinding_hint")),
+                )
+                .when_some(error, |this, error| {
+                    this.child(div().text_sm().text_color(cx.theme().danger).child(error))
+                })
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .child(
+                            div()
+                                .id("macro-dialog-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .id("macro-dialog-save-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().primary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_save.update(cx, |s, cx| s.save(cx));
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(i18n::t(&lang, "common.save")),
+                                ),
+                        ),
+                ),
+        )
+}
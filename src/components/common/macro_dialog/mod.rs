@@ -0,0 +1,7 @@
+// 键盘宏编辑弹窗：命名录制的按键序列、编辑序列并绑定快捷键
+
+mod dialog;
+mod state;
+
+pub use dialog::render_macro_dialog_overlay;
+pub use state::{MacroDialogEvent, MacroDialogState};
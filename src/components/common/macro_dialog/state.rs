@@ -0,0 +1,218 @@
+// 键盘宏编辑弹窗状态管理
+
+use gpui::{App, AppContext, Context, Entity, EventEmitter, Keystroke, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::terminal_macro::{escape_keys, unescape_keys};
+use crate::models::TerminalMacro;
+use crate::services::storage;
+
+/// 键盘宏弹窗事件
+pub enum MacroDialogEvent {
+    /// 已保存到存储
+    Saved,
+}
+
+/// 键盘宏编辑弹窗状态
+#[derive(Default)]
+pub struct MacroDialogState {
+    pub is_open: bool,
+    /// 打开弹窗的标签（弹窗只在该标签中显示）
+    pub tab_id: String,
+    /// 编辑中的键盘宏（新建时为新生成的 ID）
+    editing: Option<TerminalMacro>,
+    pub is_new: bool,
+    /// 输入框：名称
+    pub name_input: Option<Entity<InputState>>,
+    /// 输入框：按键序列（控制字符以转义形式显示）
+    pub keys_input: Option<Entity<InputState>>,
+    /// 输入框：快捷键
+    pub keybinding_input: Option<Entity<InputState>>,
+    /// 保存失败的原因
+    pub error: Option<String>,
+    pending_name_value: Option<String>,
+    pending_keys_value: Option<String>,
+    pending_keybinding_value: Option<String>,
+}
+
+impl EventEmitter<MacroDialogEvent> for MacroDialogState {}
+
+impl MacroDialogState {
+    /// 录制结束后打开新建弹窗
+    pub fn open_new(&mut self, tab_id: String, keys: String) {
+        self.reset();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.is_new = true;
+        self.pending_keys_value = Some(escape_keys(&keys));
+        self.editing = Some(TerminalMacro {
+            id: uuid::Uuid::new_v4().to_string(),
+            created_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            ..Default::default()
+        });
+    }
+
+    /// 打开编辑弹窗
+    pub fn open_edit(&mut self, tab_id: String, terminal_macro: &TerminalMacro) {
+        self.reset();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.pending_name_value = Some(terminal_macro.name.clone());
+        self.pending_keys_value = Some(escape_keys(&terminal_macro.keys));
+        self.pending_keybinding_value = terminal_macro.keybinding.clone();
+        self.editing = Some(terminal_macro.clone());
+    }
+
+    /// 关闭弹窗
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.editing = None;
+        self.is_new = false;
+        self.name_input = None;
+        self.keys_input = None;
+        self.keybinding_input = None;
+        self.error = None;
+        self.pending_name_value = None;
+        self.pending_keys_value = None;
+        self.pending_keybinding_value = None;
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        if self.name_input.is_none() {
+            let placeholder = i18n::t(&lang, "macro.dialog.enter_name").to_string();
+            let value = self.pending_name_value.take();
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            if let Some(value) = value {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            self.name_input = Some(input);
+        }
+        if self.keys_input.is_none() {
+            let value = self.pending_keys_value.take();
+            let input = cx.new(|cx| InputState::new(window, cx).auto_grow(2, 8)); // 2-8 行自动增长
+            if let Some(value) = value {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            self.keys_input = Some(input);
+        }
+        if self.keybinding_input.is_none() {
+            let placeholder = i18n::t(&lang, "macro.dialog.enter_keybinding").to_string();
+            let value = self.pending_keybinding_value.take();
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            if let Some(value) = value {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            self.keybinding_input = Some(input);
+        }
+    }
+
+    /// 获取输入框的文本值（去除首尾空白）
+    fn get_input_text(input: &Option<Entity<InputState>>, cx: &App) -> String {
+        input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 解析快捷键输入（空表示不绑定），统一为 GPUI 按键格式；无效或已被其他宏使用时返回错误提示
+    fn parse_keybinding(&self, text: &str, lang: &Language) -> Result<Option<String>, String> {
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let keystroke = Keystroke::parse(text)
+            .map_err(|_| i18n::t(lang, "macro.dialog.invalid_keybinding").to_string())?;
+        // 不带 Ctrl/Alt/Cmd 的按键会与正常输入冲突
+        let modifiers = keystroke.modifiers;
+        if !(modifiers.control || modifiers.alt || modifiers.platform) {
+            return Err(i18n::t(lang, "macro.dialog.keybinding_modifier").to_string());
+        }
+        let keybinding = keystroke.unparse();
+        let editing_id = self.editing.as_ref().map(|m| m.id.as_str());
+        let taken = storage::load_snippets()
+            .map(|config| {
+                config.macros.iter().any(|m| {
+                    Some(m.id.as_str()) != editing_id
+                        && m.keybinding.as_deref() == Some(keybinding.as_str())
+                })
+            })
+            .unwrap_or(false);
+        if taken {
+            return Err(i18n::t(lang, "macro.dialog.keybinding_taken").replace("{}", &keybinding));
+        }
+        Ok(Some(keybinding))
+    }
+
+    /// 校验并保存，成功后关闭弹窗并发出 Saved 事件
+    pub fn save(&mut self, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let Some(mut terminal_macro) = self.editing.clone() else {
+            return;
+        };
+
+        terminal_macro.name = Self::get_input_text(&self.name_input, cx);
+        if terminal_macro.name.is_empty() {
+            self.error = Some(i18n::t(&lang, "macro.dialog.name_required").to_string());
+            cx.notify();
+            return;
+        }
+        // 序列中的空格可能是录制的按键，不去除首尾空白；输入框中的换行只用于排版（回车写作 \r）
+        let keys_text = self
+            .keys_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().replace('\n', ""))
+            .unwrap_or_default();
+        terminal_macro.keys = match unescape_keys(&keys_text) {
+            Ok(keys) if !keys.is_empty() => keys,
+            Ok(_) => {
+                self.error = Some(i18n::t(&lang, "macro.dialog.keys_required").to_string());
+                cx.notify();
+                return;
+            }
+            Err(position) => {
+                self.error = Some(
+                    i18n::t(&lang, "macro.dialog.invalid_keys")
+                        .replace("{}", &position.to_string()),
+                );
+                cx.notify();
+                return;
+            }
+        };
+        let keybinding_text = Self::get_input_text(&self.keybinding_input, cx);
+        terminal_macro.keybinding = match self.parse_keybinding(&keybinding_text, &lang) {
+            Ok(keybinding) => keybinding,
+            Err(e) => {
+                self.error = Some(e);
+                cx.notify();
+                return;
+            }
+        };
+
+        match storage::save_macro(terminal_macro) {
+            Ok(()) => {
+                self.close();
+                cx.emit(MacroDialogEvent::Saved);
+            }
+            Err(e) => {
+                self.error = Some(format!(
+                    "{}: {}",
+                    i18n::t(&lang, "macro.dialog.save_failed"),
+                    e
+                ));
+            }
+        }
+        cx.notify();
+    }
+}
@@ -1,10 +1,26 @@
 // 通用 UI 组件模块
 
+pub mod batch_edit_dialog;
 pub mod button;
+pub mod close_tabs_dialog;
+pub mod confirm_bar;
 pub mod dialog;
+pub mod group_dialog;
+pub mod host_discovery_dialog;
 pub mod icon;
+pub mod identity_dialog;
 pub mod input;
+pub mod macro_dialog;
+pub mod plugin_dialog;
+pub mod recovery_dialog;
+pub mod runbook_dialog;
+pub mod schedule_dialog;
 pub mod server_dialog;
+pub mod server_import_dialog;
 pub mod settings_dialog;
+pub mod snippet_variables_dialog;
 pub mod snippets_dialog;
+pub mod ssh_snippet_dialog;
+pub mod sync_conflict_dialog;
 pub mod window_controls;
+pub mod workspace_dialog;
@@ -0,0 +1,116 @@
+// 插件对话框渲染组件
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+use crate::state::SessionState;
+
+/// 渲染插件对话框覆盖层（一次显示一个，关闭后显示下一个）
+pub fn render_plugin_dialog_overlay(
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let Some(dialog) = session_state.read(cx).plugins.dialogs.front() else {
+        return div().into_any_element();
+    };
+    let confirm = dialog.confirm;
+    let state_cancel = session_state.clone();
+
+    div()
+        .id("plugin-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(420.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题与来源插件
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(cx.theme().foreground)
+                                .child(dialog.title.clone()),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(
+                                    i18n::t(&lang, "plugins.dialog.source")
+                                        .replace("{}", &dialog.plugin_name),
+                                ),
+                        ),
+                )
+                // 内容
+                .child(
+                    div()
+                        .id("plugin-dialog-message")
+                        .max_h(px(320.))
+                        .overflow_y_scroll()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(dialog.message.clone()),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .when(confirm, |this| {
+                            this.child(
+                                Button::new("plugin-dialog-cancel")
+                                    .outline()
+                                    .child(i18n::t(&lang, "common.cancel"))
+                                    .on_click(move |_, _, cx| {
+                                        state_cancel.update(cx, |state, cx| {
+                                            state.respond_plugin_dialog(false, cx);
+                                        });
+                                    }),
+                            )
+                        })
+                        .child(
+                            Button::new("plugin-dialog-ok")
+                                .primary()
+                                .child(i18n::t(&lang, "common.confirm"))
+                                .on_click(move |_, _, cx| {
+                                    session_state.update(cx, |state, cx| {
+                                        state.respond_plugin_dialog(true, cx);
+                                    });
+                                }),
+                        ),
+                ),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,5 @@
+// 插件对话框：显示插件脚本通过 alert/confirm 请求的提示框与确认框
+
+mod dialog;
+
+pub use dialog::render_plugin_dialog_overlay;
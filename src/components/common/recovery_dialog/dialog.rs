@@ -0,0 +1,200 @@
+// 配置恢复对话框渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable};
+
+use super::state::{RecoveryItem, RecoveryState};
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::database::StorageKey;
+use crate::services::storage;
+
+/// 文档的显示名称
+fn document_label_key(key: StorageKey) -> &'static str {
+    match key {
+        StorageKey::Servers => "recovery.document.servers",
+        StorageKey::Settings => "recovery.document.settings",
+        StorageKey::Snippets => "recovery.document.snippets",
+        StorageKey::KnownHosts => "recovery.document.known_hosts",
+        StorageKey::TransferHistory => "recovery.document.transfer_history",
+        StorageKey::ConnectionHistory => "recovery.document.connection_history",
+        StorageKey::SftpBookmarks => "recovery.document.sftp_bookmarks",
+        StorageKey::Workspaces => "recovery.document.workspaces",
+        StorageKey::WindowState => "recovery.document.window_state",
+        StorageKey::Schedules => "recovery.document.schedules",
+        StorageKey::JobHistory => "recovery.document.job_history",
+    }
+}
+
+/// 渲染配置恢复对话框覆盖层
+pub fn render_recovery_dialog_overlay(state: Entity<RecoveryState>, cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    // 数据库无法打开时提示只读运行，否则提示文档损坏
+    let (title_key, message_key) = if state_read.database_error.is_some() {
+        ("recovery.database_title", "recovery.database_message")
+    } else {
+        ("recovery.title", "recovery.message")
+    };
+
+    div()
+        .id("recovery-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(520.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(&lang, title_key)),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(i18n::t(&lang, message_key)),
+                )
+                .children(state_read.database_error.clone().map(|error| {
+                    div()
+                        .p_3()
+                        .rounded_md()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(error)
+                }))
+                // 损坏的文档
+                .child(
+                    div().flex().flex_col().gap_2().children(
+                        state_read.items.iter().enumerate().map(|(index, item)| {
+                            render_item(index, item, state.clone(), &lang, cx)
+                        }),
+                    ),
+                )
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 底部按钮
+                .child(
+                    div().flex().justify_end().pt_2().child(
+                        Button::new("recovery-later")
+                            .outline()
+                            .child(i18n::t(&lang, "recovery.later"))
+                            .on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.dismiss();
+                                    cx.notify();
+                                });
+                            }),
+                    ),
+                ),
+        )
+}
+
+/// 渲染一个损坏的文档：名称、错误原因与恢复/重置按钮
+fn render_item(
+    index: usize,
+    item: &RecoveryItem,
+    state: Entity<RecoveryState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let backup_hint = match &item.backup {
+        Some((_, time)) => i18n::t(lang, "recovery.backup_found").replace("{}", time),
+        None => i18n::t(lang, "recovery.no_backup").to_string(),
+    };
+
+    div()
+        .p_3()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .text_sm()
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(cx.theme().foreground)
+                .child(i18n::t(lang, document_label_key(item.key))),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(item.error.clone()),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(backup_hint),
+        )
+        .child(
+            div()
+                .flex()
+                .justify_end()
+                .gap_2()
+                .child(
+                    Button::new(("recovery-reset", index))
+                        .small()
+                        .outline()
+                        .child(i18n::t(lang, "recovery.reset"))
+                        .on_click({
+                            let state = state.clone();
+                            move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.reset(index);
+                                    cx.notify();
+                                });
+                            }
+                        }),
+                )
+                .when(item.backup.is_some(), |this| {
+                    this.child(
+                        Button::new(("recovery-restore", index))
+                            .small()
+                            .primary()
+                            .child(i18n::t(lang, "recovery.restore"))
+                            .on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.restore(index);
+                                    cx.notify();
+                                });
+                            }),
+                    )
+                }),
+        )
+}
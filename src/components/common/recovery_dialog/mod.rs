@@ -0,0 +1,7 @@
+// 配置恢复对话框：启动时发现无法解析的配置，提示从最近的备份恢复或重置
+
+mod dialog;
+mod state;
+
+pub use dialog::render_recovery_dialog_overlay;
+pub use state::RecoveryState;
@@ -0,0 +1,128 @@
+// 配置恢复状态：损坏的文档列表与恢复操作
+
+use std::path::PathBuf;
+
+use tracing::{error, info};
+
+use crate::services::database::{Database, StorageKey};
+use crate::services::{schema, storage};
+
+/// 一个无法解析的文档
+pub struct RecoveryItem {
+    pub key: StorageKey,
+    /// 解析失败的原因
+    pub error: String,
+    /// 最近一份可以解析的备份及其时间
+    pub backup: Option<(PathBuf, String)>,
+}
+
+/// 配置恢复状态（有待处理的文档时显示对话框）
+#[derive(Default)]
+pub struct RecoveryState {
+    pub items: Vec<RecoveryItem>,
+    /// 数据库文件无法打开的错误（本次运行只读，修改不会保存）
+    pub database_error: Option<String>,
+    /// 最近一次恢复失败的错误信息
+    pub error: Option<String>,
+}
+
+impl RecoveryState {
+    /// 读取数据库启动检查发现的损坏文档与打开错误
+    pub fn new() -> Self {
+        let db = Database::global();
+        let items = db
+            .corrupted()
+            .into_iter()
+            .map(|(key, error)| RecoveryItem {
+                key,
+                error,
+                backup: latest_valid_backup(key),
+            })
+            .collect();
+        Self {
+            items,
+            database_error: db.open_error(),
+            error: None,
+        }
+    }
+
+    pub fn visible(&self) -> bool {
+        !self.items.is_empty() || self.database_error.is_some()
+    }
+
+    /// 用最近的可用备份恢复
+    pub fn restore(&mut self, index: usize) {
+        let Some(item) = self.items.get(index) else {
+            return;
+        };
+        let Some((path, _)) = &item.backup else {
+            return;
+        };
+        let result = std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Database::global().restore(item.key, &content));
+        match result {
+            Ok(()) => {
+                info!("[Recovery] Restored {} from {:?}", item.key.as_str(), path);
+                self.items.remove(index);
+                self.error = None;
+            }
+            Err(e) => {
+                error!(
+                    "[Recovery] Failed to restore {}: {:#}",
+                    item.key.as_str(),
+                    e
+                );
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+
+    /// 重置为默认值（损坏的内容已另存到备份目录）
+    pub fn reset(&mut self, index: usize) {
+        let Some(item) = self.items.get(index) else {
+            return;
+        };
+        match Database::global().reset(item.key) {
+            Ok(()) => {
+                info!("[Recovery] Reset {}", item.key.as_str());
+                self.items.remove(index);
+                self.error = None;
+            }
+            Err(e) => {
+                error!("[Recovery] Failed to reset {}: {:#}", item.key.as_str(), e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+
+    /// 暂不处理：本次运行使用默认值，文档未被重新写入时下次启动会再次提示
+    pub fn dismiss(&mut self) {
+        self.items.clear();
+        self.database_error = None;
+        self.error = None;
+    }
+}
+
+/// 从新到旧查找第一份能够解析的备份
+fn latest_valid_backup(key: StorageKey) -> Option<(PathBuf, String)> {
+    storage::list_backups(key.as_str())
+        .ok()?
+        .into_iter()
+        .find(|path| {
+            std::fs::read_to_string(path)
+                .ok()
+                .is_some_and(|content| schema::parse_document(key, &content).is_ok())
+        })
+        .map(|path| {
+            let time = std::fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .map(|t| {
+                    chrono::DateTime::<chrono::Local>::from(t)
+                        .format("%Y-%m-%d %H:%M:%S")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            (path, time)
+        })
+}
@@ -0,0 +1,340 @@
+// Runbook 编辑弹窗渲染组件
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputState};
+use gpui_component::ActiveTheme;
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::{RunbookDialogState, StepKind};
+
+/// 渲染 Runbook 编辑弹窗覆盖层
+pub fn render_runbook_dialog_overlay(
+    state: Entity<RunbookDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let title = if state_read.is_new {
+        i18n::t(&lang, "runbook.dialog.add_title")
+    } else {
+        i18n::t(&lang, "runbook.dialog.edit_title")
+    };
+    let name_input = state_read.name_input.clone();
+    let description_input = state_read.description_input.clone();
+    let error = state_read.error.clone();
+    let step_count = state_read.steps.len();
+    let steps: Vec<_> = state_read
+        .steps
+        .iter()
+        .map(|s| (s.kind, s.value_input.clone(), s.timeout_input.clone()))
+        .collect();
+
+    let state_add = state.clone();
+    let state_cancel = state.clone();
+    let state_save = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let label = |key: &'static str| {
+        div()
+            .text_sm()
+            .text_color(muted_foreground)
+            .child(i18n::t(&lang, key))
+    };
+
+    div()
+        .id("runbook-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(560.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(title),
+                )
+                // 名称与描述
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("runbook.dialog.name"))
+                        .when_some(name_input, |this, input| this.child(Input::new(&input))),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("runbook.dialog.description"))
+                        .when_some(description_input, |this, input| {
+                            this.child(Input::new(&input))
+                        }),
+                )
+                // 步骤列表
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("runbook.dialog.steps"))
+                        .child(
+                            div()
+                                .id("runbook-dialog-steps")
+                                .max_h(px(320.))
+                                .overflow_y_scroll()
+                                .flex()
+                                .flex_col()
+                                .gap_2()
+                                .children(steps.into_iter().enumerate().map(
+                                    |(index, (kind, value_input, timeout_input))| {
+                                        render_step_row(
+                                            state.clone(),
+                                            index,
+                                            step_count,
+                                            kind,
+                                            value_input,
+                                            timeout_input,
+                                            &lang,
+                                            cx,
+                                        )
+                                    },
+                                )),
+                        )
+                        .child(
+                            div()
+                                .id("runbook-dialog-add-step")
+                                .flex()
+                                .items_center()
+                                .gap_1()
+                                .px_2()
+                                .py_1()
+                                .rounded_md()
+                                .cursor_pointer()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .hover(|s| s.bg(cx.theme().secondary).text_color(foreground))
+                                .on_click(move |_, _, cx| {
+                                    state_add.update(cx, |s, cx| {
+                                        s.add_step(StepKind::Send);
+                                        cx.notify();
+                                    });
+                                })
+                                .child(render_icon(icons::PLUS, muted_foreground))
+                                .child(i18n::t(&lang, "runbook.dialog.add_step")),
+                        ),
+                )
+                .when_some(error, |this, error| {
+                    this.child(div().text_sm().text_color(cx.theme().danger).child(error))
+                })
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .child(
+                            div()
+                                .id("runbook-dialog-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .id("runbook-dialog-save-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().primary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_save.update(cx, |s, cx| s.save(cx));
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(i18n::t(&lang, "common.save")),
+                                ),
+                        ),
+                ),
+        )
+}
+
+/// 渲染单个步骤：类型选择、内容输入与排序/删除按钮
+#[allow(clippy::too_many_arguments)]
+fn render_step_row(
+    state: Entity<RunbookDialogState>,
+    index: usize,
+    step_count: usize,
+    kind: StepKind,
+    value_input: Option<Entity<InputState>>,
+    timeout_input: Option<Entity<InputState>>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let kind_chips = StepKind::ALL.into_iter().map(|chip_kind| {
+        let selected = chip_kind == kind;
+        let state = state.clone();
+        div()
+            .id(SharedString::from(format!(
+                "runbook-step-{}-kind-{:?}",
+                index, chip_kind
+            )))
+            .px_2()
+            .py(px(2.))
+            .rounded(px(4.))
+            .cursor_pointer()
+            .text_xs()
+            .when(selected, |s| {
+                s.bg(cx.theme().primary)
+                    .text_color(cx.theme().primary_foreground)
+            })
+            .when(!selected, |s| {
+                s.text_color(muted_foreground)
+                    .hover(|s| s.bg(cx.theme().secondary))
+            })
+            .on_click(move |_, _, cx| {
+                state.update(cx, |s, cx| {
+                    s.set_step_kind(index, chip_kind);
+                    cx.notify();
+                });
+            })
+            .child(i18n::t(lang, chip_kind.label_key()))
+    });
+
+    let icon_button = |id: &'static str, icon: &'static str, enabled: bool| {
+        let state = state.clone();
+        div()
+            .id(SharedString::from(format!("runbook-step-{}-{}", index, id)))
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .when(!enabled, |s| s.opacity(0.3))
+            .when(enabled, |s| {
+                s.cursor_pointer()
+                    .hover(|s| s.bg(cx.theme().secondary))
+                    .on_click(move |_, _, cx| {
+                        state.update(cx, |s, cx| {
+                            match id {
+                                "up" => s.move_step(index, true),
+                                "down" => s.move_step(index, false),
+                                _ => s.remove_step(index),
+                            }
+                            cx.notify();
+                        });
+                    })
+            })
+            .child(svg().path(icon).size(px(14.)).text_color(muted_foreground))
+    };
+
+    div()
+        .p_2()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(
+                    div()
+                        .w(px(24.))
+                        .text_xs()
+                        .text_color(foreground)
+                        .child(format!("{}.", index + 1)),
+                )
+                .children(kind_chips)
+                .child(div().flex_1())
+                .child(icon_button("up", icons::ARROW_UP, index > 0))
+                .child(icon_button(
+                    "down",
+                    icons::ARROW_DOWN,
+                    index + 1 < step_count,
+                ))
+                .child(icon_button("delete", icons::TRASH, true)),
+        )
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .when_some(value_input, |this, input| {
+                    this.child(div().flex_1().child(Input::new(&input)))
+                })
+                .when_some(timeout_input, |this, input| {
+                    this.child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .text_xs()
+                            .text_color(muted_foreground)
+                            .child(i18n::t(lang, "runbook.dialog.timeout"))
+                            .child(div().w(px(64.)).child(Input::new(&input)))
+                            .child("s"),
+                    )
+                }),
+        )
+}
@@ -0,0 +1,7 @@
+// Runbook 编辑弹窗：编辑名称、描述与按顺序执行的步骤
+
+mod dialog;
+mod state;
+
+pub use dialog::render_runbook_dialog_overlay;
+pub use state::{RunbookDialogEvent, RunbookDialogState};
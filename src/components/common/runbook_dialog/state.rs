@@ -0,0 +1,339 @@
+// Runbook 编辑弹窗状态管理
+
+use gpui::{App, AppContext, Context, Entity, EventEmitter, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::runbook::DEFAULT_WAIT_TIMEOUT_SECS;
+use crate::models::settings::Language;
+use crate::models::{Runbook, RunbookStep};
+use crate::services::storage;
+
+/// 步骤类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepKind {
+    Send,
+    WaitFor,
+    Confirm,
+    Sleep,
+}
+
+impl StepKind {
+    pub const ALL: [StepKind; 4] = [
+        StepKind::Send,
+        StepKind::WaitFor,
+        StepKind::Confirm,
+        StepKind::Sleep,
+    ];
+
+    /// 类型名称的 i18n 键
+    pub fn label_key(self) -> &'static str {
+        match self {
+            StepKind::Send => "runbook.step.send",
+            StepKind::WaitFor => "runbook.step.wait_for",
+            StepKind::Confirm => "runbook.step.confirm",
+            StepKind::Sleep => "runbook.step.sleep",
+        }
+    }
+
+    /// 内容输入框占位符的 i18n 键
+    fn placeholder_key(self) -> &'static str {
+        match self {
+            StepKind::Send => "runbook.dialog.enter_command",
+            StepKind::WaitFor => "runbook.dialog.enter_pattern",
+            StepKind::Confirm => "runbook.dialog.enter_message",
+            StepKind::Sleep => "runbook.dialog.enter_secs",
+        }
+    }
+}
+
+/// 编辑中的步骤
+pub struct StepDraft {
+    pub kind: StepKind,
+    /// 输入框：命令、正则表达式、确认提示或秒数
+    pub value_input: Option<Entity<InputState>>,
+    /// 输入框：超时秒数（仅等待输出步骤）
+    pub timeout_input: Option<Entity<InputState>>,
+    pending_value: Option<String>,
+    pending_timeout: Option<String>,
+}
+
+impl StepDraft {
+    fn new(kind: StepKind) -> Self {
+        Self {
+            kind,
+            value_input: None,
+            timeout_input: None,
+            pending_value: None,
+            pending_timeout: (kind == StepKind::WaitFor)
+                .then(|| DEFAULT_WAIT_TIMEOUT_SECS.to_string()),
+        }
+    }
+
+    fn from_step(step: &RunbookStep) -> Self {
+        let (kind, value, timeout) = match step {
+            RunbookStep::Send { command } => (StepKind::Send, command.clone(), None),
+            RunbookStep::WaitFor {
+                pattern,
+                timeout_secs,
+            } => (
+                StepKind::WaitFor,
+                pattern.clone(),
+                Some(timeout_secs.to_string()),
+            ),
+            RunbookStep::Confirm { message } => (StepKind::Confirm, message.clone(), None),
+            RunbookStep::Sleep { secs } => (StepKind::Sleep, secs.to_string(), None),
+        };
+        Self {
+            kind,
+            value_input: None,
+            timeout_input: None,
+            pending_value: Some(value),
+            pending_timeout: timeout,
+        }
+    }
+}
+
+/// Runbook 弹窗事件
+pub enum RunbookDialogEvent {
+    /// 已保存到存储
+    Saved,
+}
+
+/// Runbook 编辑弹窗状态
+#[derive(Default)]
+pub struct RunbookDialogState {
+    pub is_open: bool,
+    /// 打开弹窗的标签（弹窗只在该标签中显示）
+    pub tab_id: String,
+    /// 编辑中的 Runbook（新建时为新生成的 ID）
+    editing: Option<Runbook>,
+    pub is_new: bool,
+    /// 输入框：名称
+    pub name_input: Option<Entity<InputState>>,
+    /// 输入框：描述
+    pub description_input: Option<Entity<InputState>>,
+    pub steps: Vec<StepDraft>,
+    /// 保存失败的原因
+    pub error: Option<String>,
+    pending_name_value: Option<String>,
+    pending_description_value: Option<String>,
+}
+
+impl EventEmitter<RunbookDialogEvent> for RunbookDialogState {}
+
+impl RunbookDialogState {
+    /// 打开新建弹窗（默认带一个发送命令步骤）
+    pub fn open_new(&mut self, tab_id: String) {
+        self.reset();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.is_new = true;
+        self.editing = Some(Runbook {
+            id: uuid::Uuid::new_v4().to_string(),
+            created_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+            ..Default::default()
+        });
+        self.steps.push(StepDraft::new(StepKind::Send));
+    }
+
+    /// 打开编辑弹窗
+    pub fn open_edit(&mut self, tab_id: String, runbook: &Runbook) {
+        self.reset();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.pending_name_value = Some(runbook.name.clone());
+        self.pending_description_value = runbook.description.clone();
+        self.steps = runbook.steps.iter().map(StepDraft::from_step).collect();
+        self.editing = Some(runbook.clone());
+    }
+
+    /// 关闭弹窗
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.editing = None;
+        self.is_new = false;
+        self.name_input = None;
+        self.description_input = None;
+        self.steps.clear();
+        self.error = None;
+        self.pending_name_value = None;
+        self.pending_description_value = None;
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        if self.name_input.is_none() {
+            let placeholder = i18n::t(&lang, "runbook.dialog.enter_name");
+            let value = self.pending_name_value.take();
+            self.name_input = Some(new_input(placeholder, value, window, cx));
+        }
+        if self.description_input.is_none() {
+            let placeholder = i18n::t(&lang, "runbook.dialog.enter_description");
+            let value = self.pending_description_value.take();
+            self.description_input = Some(new_input(placeholder, value, window, cx));
+        }
+
+        for step in &mut self.steps {
+            if step.value_input.is_none() {
+                let placeholder = i18n::t(&lang, step.kind.placeholder_key());
+                let value = step.pending_value.take();
+                step.value_input = Some(new_input(placeholder, value, window, cx));
+            }
+            if step.kind == StepKind::WaitFor && step.timeout_input.is_none() {
+                let value = step.pending_timeout.take();
+                step.timeout_input = Some(new_input("", value, window, cx));
+            }
+        }
+    }
+
+    /// 在末尾添加步骤
+    pub fn add_step(&mut self, kind: StepKind) {
+        self.steps.push(StepDraft::new(kind));
+    }
+
+    /// 删除步骤
+    pub fn remove_step(&mut self, index: usize) {
+        if index < self.steps.len() {
+            self.steps.remove(index);
+        }
+    }
+
+    /// 上移（`up` 为 true）或下移步骤
+    pub fn move_step(&mut self, index: usize, up: bool) {
+        let target = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1)
+        };
+        if let Some(target) = target.filter(|&t| t < self.steps.len()) {
+            self.steps.swap(index, target);
+        }
+    }
+
+    /// 修改步骤类型（内容不同类型间不通用，清空后重新输入）
+    pub fn set_step_kind(&mut self, index: usize, kind: StepKind) {
+        if let Some(step) = self.steps.get_mut(index) {
+            if step.kind != kind {
+                *step = StepDraft::new(kind);
+            }
+        }
+    }
+
+    /// 获取输入框的文本值（去除首尾空白）
+    fn get_input_text(input: &Option<Entity<InputState>>, cx: &App) -> String {
+        input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 根据输入构建步骤列表，输入无效时返回错误提示
+    fn build_steps(&self, lang: &Language, cx: &App) -> Result<Vec<RunbookStep>, String> {
+        if self.steps.is_empty() {
+            return Err(i18n::t(lang, "runbook.dialog.steps_required").to_string());
+        }
+        let error = |key: &'static str, index: usize| {
+            i18n::t(lang, key).replace("{}", &(index + 1).to_string())
+        };
+        let parse_secs = |text: &str, index: usize| {
+            text.parse::<u64>()
+                .ok()
+                .filter(|secs| *secs > 0)
+                .ok_or_else(|| error("runbook.dialog.invalid_secs", index))
+        };
+
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let value = Self::get_input_text(&step.value_input, cx);
+                if value.is_empty() {
+                    return Err(error("runbook.dialog.step_empty", index));
+                }
+                Ok(match step.kind {
+                    StepKind::Send => RunbookStep::Send { command: value },
+                    StepKind::WaitFor => {
+                        if regex::Regex::new(&value).is_err() {
+                            return Err(error("runbook.dialog.invalid_pattern", index));
+                        }
+                        let timeout = Self::get_input_text(&step.timeout_input, cx);
+                        RunbookStep::WaitFor {
+                            pattern: value,
+                            timeout_secs: parse_secs(&timeout, index)?,
+                        }
+                    }
+                    StepKind::Confirm => RunbookStep::Confirm { message: value },
+                    StepKind::Sleep => RunbookStep::Sleep {
+                        secs: parse_secs(&value, index)?,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// 校验并保存，成功后关闭弹窗并发出 Saved 事件
+    pub fn save(&mut self, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let Some(mut runbook) = self.editing.clone() else {
+            return;
+        };
+
+        runbook.name = Self::get_input_text(&self.name_input, cx);
+        if runbook.name.is_empty() {
+            self.error = Some(i18n::t(&lang, "runbook.dialog.name_required").to_string());
+            cx.notify();
+            return;
+        }
+        let description = Self::get_input_text(&self.description_input, cx);
+        runbook.description = (!description.is_empty()).then_some(description);
+        runbook.steps = match self.build_steps(&lang, cx) {
+            Ok(steps) => steps,
+            Err(e) => {
+                self.error = Some(e);
+                cx.notify();
+                return;
+            }
+        };
+
+        match storage::save_runbook(runbook) {
+            Ok(()) => {
+                self.close();
+                cx.emit(RunbookDialogEvent::Saved);
+            }
+            Err(e) => {
+                self.error = Some(format!(
+                    "{}: {}",
+                    i18n::t(&lang, "runbook.dialog.save_failed"),
+                    e
+                ));
+            }
+        }
+        cx.notify();
+    }
+}
+
+/// 创建输入框并填入初始值
+fn new_input(
+    placeholder: &str,
+    value: Option<String>,
+    window: &mut Window,
+    cx: &mut Context<RunbookDialogState>,
+) -> Entity<InputState> {
+    let placeholder = placeholder.to_string();
+    let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+    if let Some(value) = value {
+        input.update(cx, |s, cx| s.set_value(value, window, cx));
+    }
+    input
+}
@@ -0,0 +1,321 @@
+// 定时任务编辑弹窗渲染组件
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::ActiveTheme;
+
+use crate::components::common::icon::render_icon;
+use crate::components::cron::describe_schedule;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::schedule::ScheduledTask;
+use crate::models::settings::Language;
+use crate::models::SnippetsConfig;
+use crate::services::{scheduler, storage};
+
+use super::state::ScheduleDialogState;
+
+/// 计划预览中显示的运行次数
+const PREVIEW_RUNS: usize = 3;
+
+/// 任务运行内容的显示名称（快捷命令或 Runbook 已删除时为 None）
+pub fn task_label(
+    task: &ScheduledTask,
+    snippets: &SnippetsConfig,
+    lang: &Language,
+) -> Option<String> {
+    match task {
+        ScheduledTask::Snippet { snippet_id } => snippets
+            .commands
+            .iter()
+            .find(|c| &c.id == snippet_id)
+            .map(|c| c.name.clone()),
+        ScheduledTask::Runbook { runbook_id } => snippets
+            .runbooks
+            .iter()
+            .find(|r| &r.id == runbook_id)
+            .map(|r| format!("{} · {}", i18n::t(lang, "schedules.runbook"), r.name)),
+    }
+}
+
+/// 渲染定时任务编辑弹窗覆盖层
+pub fn render_schedule_dialog_overlay(
+    state: Entity<ScheduleDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let title = if state_read.is_new {
+        i18n::t(&lang, "schedules.dialog.add_title")
+    } else {
+        i18n::t(&lang, "schedules.dialog.edit_title")
+    };
+    let name_input = state_read.name_input.clone();
+    let schedule_input = state_read.schedule_input.clone();
+    let error = state_read.error.clone();
+
+    let servers = storage::load_servers().unwrap_or_default().servers;
+    let snippets = storage::load_snippets().unwrap_or_default();
+    let server_label = state_read
+        .server_id
+        .as_ref()
+        .and_then(|id| servers.iter().find(|s| &s.id == id))
+        .map(|s| s.label.clone())
+        .unwrap_or_else(|| i18n::t(&lang, "schedules.dialog.select_server").to_string());
+    let task_text = state_read
+        .task
+        .as_ref()
+        .and_then(|task| task_label(task, &snippets, &lang))
+        .unwrap_or_else(|| i18n::t(&lang, "schedules.dialog.select_task").to_string());
+
+    // 计划说明与接下来几次运行时间
+    let schedule_text = ScheduleDialogState::get_input_text(&schedule_input, cx);
+    let (schedule_hint, next_runs) = match scheduler::parse_schedule(&schedule_text) {
+        Ok(parsed) => (
+            describe_schedule(&parsed, &lang),
+            parsed
+                .next_runs(chrono::Local::now(), PREVIEW_RUNS)
+                .into_iter()
+                .map(|run| run.format("%Y-%m-%d %H:%M").to_string())
+                .collect::<Vec<_>>(),
+        ),
+        Err(_) => (i18n::t(&lang, "cron.hint.invalid").to_string(), Vec::new()),
+    };
+
+    let state_cancel = state.clone();
+    let state_save = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let label = |key: &'static str| {
+        div()
+            .text_sm()
+            .text_color(muted_foreground)
+            .child(i18n::t(&lang, key))
+    };
+    // 下拉选择按钮：当前选择 + 下拉图标
+    let select_button = |id: &'static str, text: String| {
+        Button::new(id).w_full().outline().justify_start().child(
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(div().text_sm().text_color(foreground).child(text))
+                .child(render_icon(icons::CHEVRON_DOWN, muted_foreground.into())),
+        )
+    };
+
+    let no_servers = i18n::t(&lang, "schedules.dialog.no_servers");
+    let state_for_servers = state.clone();
+    let no_tasks = i18n::t(&lang, "schedules.dialog.no_tasks");
+    let runbook_prefix = i18n::t(&lang, "schedules.runbook");
+    let state_for_tasks = state.clone();
+    let commands = snippets.commands.clone();
+    let runbooks = snippets.runbooks.clone();
+
+    div()
+        .id("schedule-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(480.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(title),
+                )
+                // 名称
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("schedules.dialog.name"))
+                        .when_some(name_input, |this, input| this.child(Input::new(&input))),
+                )
+                // 服务器
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("schedules.dialog.server"))
+                        .child(
+                            select_button("schedule-dialog-server", server_label)
+                                .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+                                    if servers.is_empty() {
+                                        return menu
+                                            .item(PopupMenuItem::new(no_servers).disabled(true));
+                                    }
+                                    let mut menu = menu.min_w(px(240.));
+                                    for server in &servers {
+                                        let server_id = server.id.clone();
+                                        let state = state_for_servers.clone();
+                                        menu = menu.item(
+                                            PopupMenuItem::new(server.label.clone()).on_click(
+                                                move |_, _, cx| {
+                                                    state.update(cx, |s, cx| {
+                                                        s.server_id = Some(server_id.clone());
+                                                        cx.notify();
+                                                    });
+                                                },
+                                            ),
+                                        );
+                                    }
+                                    menu
+                                }),
+                        ),
+                )
+                // 运行内容：快捷命令或 Runbook
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("schedules.dialog.task"))
+                        .child(
+                            select_button("schedule-dialog-task", task_text)
+                                .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+                                    if commands.is_empty() && runbooks.is_empty() {
+                                        return menu
+                                            .item(PopupMenuItem::new(no_tasks).disabled(true));
+                                    }
+                                    let mut menu = menu.min_w(px(240.));
+                                    let tasks = commands
+                                        .iter()
+                                        .map(|c| {
+                                            let task = ScheduledTask::Snippet {
+                                                snippet_id: c.id.clone(),
+                                            };
+                                            (c.name.clone(), task)
+                                        })
+                                        .chain(runbooks.iter().map(|r| {
+                                            let task = ScheduledTask::Runbook {
+                                                runbook_id: r.id.clone(),
+                                            };
+                                            (format!("{} · {}", runbook_prefix, r.name), task)
+                                        }));
+                                    for (name, task) in tasks {
+                                        let state = state_for_tasks.clone();
+                                        menu = menu.item(PopupMenuItem::new(name).on_click(
+                                            move |_, _, cx| {
+                                                state.update(cx, |s, cx| {
+                                                    s.task = Some(task.clone());
+                                                    cx.notify();
+                                                });
+                                            },
+                                        ));
+                                    }
+                                    menu
+                                }),
+                        ),
+                )
+                // 计划
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(label("schedules.dialog.schedule"))
+                        .when_some(schedule_input, |this, input| {
+                            this.child(Input::new(&input).font_family("monospace"))
+                        })
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(muted_foreground)
+                                .child(schedule_hint),
+                        )
+                        .when(!next_runs.is_empty(), |this| {
+                            this.child(div().text_xs().text_color(muted_foreground).child(format!(
+                                "{}: {}",
+                                i18n::t(&lang, "cron.next_runs"),
+                                next_runs.join(", ")
+                            )))
+                        }),
+                )
+                .when_some(error, |this, error| {
+                    this.child(div().text_sm().text_color(cx.theme().danger).child(error))
+                })
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .child(
+                            div()
+                                .id("schedule-dialog-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .id("schedule-dialog-save-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().primary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_save.update(cx, |s, cx| s.save(cx));
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(i18n::t(&lang, "common.save")),
+                                ),
+                        ),
+                ),
+        )
+}
@@ -0,0 +1,7 @@
+// 定时任务编辑弹窗：选择服务器与要运行的快捷命令或 Runbook，并设置 cron 计划
+
+mod dialog;
+mod state;
+
+pub use dialog::{render_schedule_dialog_overlay, task_label};
+pub use state::ScheduleDialogState;
@@ -0,0 +1,170 @@
+// 定时任务编辑弹窗状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::schedule::{ScheduledJob, ScheduledTask};
+use crate::models::settings::Language;
+use crate::services::scheduler::{self, ScheduleError};
+use crate::services::storage;
+
+/// 定时任务编辑弹窗状态
+#[derive(Default)]
+pub struct ScheduleDialogState {
+    pub visible: bool,
+    /// 编辑中的任务（新建时为新生成的 ID）
+    editing: Option<ScheduledJob>,
+    pub is_new: bool,
+    /// 选择的服务器
+    pub server_id: Option<String>,
+    /// 选择的快捷命令或 Runbook
+    pub task: Option<ScheduledTask>,
+    /// 输入框：名称
+    pub name_input: Option<Entity<InputState>>,
+    /// 输入框：cron 计划
+    pub schedule_input: Option<Entity<InputState>>,
+    /// 保存失败的原因
+    pub error: Option<String>,
+    pending_name_value: Option<String>,
+    pending_schedule_value: Option<String>,
+}
+
+impl ScheduleDialogState {
+    /// 打开新建弹窗
+    pub fn open_new(&mut self) {
+        self.reset();
+        self.visible = true;
+        self.is_new = true;
+        self.pending_schedule_value = Some("0 3 * * *".to_string());
+        self.editing = Some(ScheduledJob {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: String::new(),
+            server_id: String::new(),
+            task: ScheduledTask::Snippet {
+                snippet_id: String::new(),
+            },
+            schedule: String::new(),
+            enabled: true,
+            created_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        });
+    }
+
+    /// 打开编辑弹窗
+    pub fn open_edit(&mut self, job: &ScheduledJob) {
+        self.reset();
+        self.visible = true;
+        self.server_id = Some(job.server_id.clone());
+        self.task = Some(job.task.clone());
+        self.pending_name_value = Some(job.name.clone());
+        self.pending_schedule_value = Some(job.schedule.clone());
+        self.editing = Some(job.clone());
+    }
+
+    /// 关闭弹窗
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.editing = None;
+        self.is_new = false;
+        self.server_id = None;
+        self.task = None;
+        self.name_input = None;
+        self.schedule_input = None;
+        self.error = None;
+        self.pending_name_value = None;
+        self.pending_schedule_value = None;
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        if self.name_input.is_none() {
+            let placeholder = i18n::t(&lang, "schedules.dialog.enter_name").to_string();
+            let value = self.pending_name_value.take();
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            if let Some(value) = value {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            self.name_input = Some(input);
+        }
+        if self.schedule_input.is_none() {
+            let value = self.pending_schedule_value.take();
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder("0 3 * * *"));
+            if let Some(value) = value {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            self.schedule_input = Some(input);
+        }
+    }
+
+    /// 获取输入框的文本值（去除首尾空白）
+    pub fn get_input_text(input: &Option<Entity<InputState>>, cx: &App) -> String {
+        input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 校验并保存，成功后关闭弹窗
+    pub fn save(&mut self, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let Some(mut job) = self.editing.clone() else {
+            return;
+        };
+
+        job.name = Self::get_input_text(&self.name_input, cx);
+        let error_key = if job.name.is_empty() {
+            Some("schedules.dialog.name_required")
+        } else if self.server_id.is_none() {
+            Some("schedules.dialog.server_required")
+        } else if self.task.is_none() {
+            Some("schedules.dialog.task_required")
+        } else {
+            None
+        };
+        if let Some(key) = error_key {
+            self.error = Some(i18n::t(&lang, key).to_string());
+            cx.notify();
+            return;
+        }
+        job.schedule = Self::get_input_text(&self.schedule_input, cx);
+        if let Err(e) = scheduler::parse_schedule(&job.schedule) {
+            let detail = match e {
+                ScheduleError::Reboot => i18n::t(&lang, "schedules.error.reboot").to_string(),
+                ScheduleError::Invalid(detail) => detail,
+            };
+            self.error = Some(format!(
+                "{}: {}",
+                i18n::t(&lang, "schedules.dialog.invalid_schedule"),
+                detail
+            ));
+            cx.notify();
+            return;
+        }
+        job.server_id = self.server_id.clone().unwrap_or_default();
+        if let Some(task) = self.task.clone() {
+            job.task = task;
+        }
+
+        match storage::save_scheduled_job(job) {
+            Ok(()) => self.close(),
+            Err(e) => {
+                self.error = Some(format!(
+                    "{}: {}",
+                    i18n::t(&lang, "schedules.dialog.save_failed"),
+                    e
+                ));
+            }
+        }
+        cx.notify();
+    }
+}
@@ -7,17 +7,21 @@ use gpui::*;
 use gpui_component::input::InputState;
 use gpui_component::scroll::ScrollableElement;
 use gpui_component::ActiveTheme;
+use tracing::error;
 
 use crate::components::common::icon::render_icon;
 use crate::constants::icons;
 use crate::i18n;
-use crate::models::server::{AuthType, ProxyConfig, ProxyType, ServerData};
-use crate::models::settings::Language;
+use crate::models::server::{
+    parse_tags, AuthType, ProxyConfig, ProxyType, ServerConfig, ServerData, ServerTemplate,
+};
+use crate::models::settings::{AppSettings, Language};
 use crate::services::storage;
+use std::collections::HashSet;
 
 use panels::{
     render_basic_info_form, render_jump_host_form, render_other_settings_form,
-    render_proxy_settings_form,
+    render_overrides_form, render_proxy_settings_form,
 };
 
 /// 左侧导航菜单类型
@@ -27,9 +31,67 @@ pub enum DialogSection {
     BasicInfo,
     JumpHost,
     ProxySettings,
+    Overrides,
     OtherSettings,
 }
 
+/// 可按服务器覆盖的全局设置项
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum OverrideField {
+    TerminalFontSize,
+    TerminalColorScheme,
+    Scrollback,
+    TerminalOpacity,
+    MonitorInterval,
+    Keepalive,
+}
+
+impl OverrideField {
+    /// 设置覆盖面板中的全部设置项（按显示顺序）
+    pub const ALL: [OverrideField; 6] = [
+        OverrideField::TerminalFontSize,
+        OverrideField::TerminalColorScheme,
+        OverrideField::Scrollback,
+        OverrideField::TerminalOpacity,
+        OverrideField::MonitorInterval,
+        OverrideField::Keepalive,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            OverrideField::TerminalFontSize => "server_dialog.terminal_font_size",
+            OverrideField::TerminalColorScheme => "server_dialog.terminal_color_scheme",
+            OverrideField::Scrollback => "server_dialog.scrollback",
+            OverrideField::TerminalOpacity => "server_dialog.terminal_opacity",
+            OverrideField::MonitorInterval => "server_dialog.monitor_interval",
+            OverrideField::Keepalive => "server_dialog.keepalive",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            OverrideField::TerminalFontSize => icons::TERMINAL,
+            OverrideField::TerminalColorScheme => icons::GRID,
+            OverrideField::Scrollback => icons::HISTORY,
+            OverrideField::TerminalOpacity => icons::EYE,
+            OverrideField::MonitorInterval => icons::GAUGE,
+            OverrideField::Keepalive => icons::ACTIVITY,
+        }
+    }
+
+    /// 对应的全局设置值（未覆盖时显示，开启覆盖时作为初始值）
+    pub fn global_value(&self, settings: &AppSettings) -> String {
+        match self {
+            OverrideField::TerminalFontSize => settings.terminal.font_size.to_string(),
+            OverrideField::TerminalColorScheme => settings.terminal.color_scheme.clone(),
+            OverrideField::Scrollback => settings.terminal.scrollback_lines.to_string(),
+            OverrideField::TerminalOpacity => settings.terminal.background_opacity.to_string(),
+            OverrideField::MonitorInterval => settings.monitor.refresh_interval.secs().to_string(),
+            OverrideField::Keepalive => settings.connection.keepalive_interval_secs.to_string(),
+        }
+    }
+}
+
 /// 服务器弹窗状态
 pub struct ServerDialogState {
     pub visible: bool,
@@ -38,6 +100,12 @@ pub struct ServerDialogState {
     pub edit_server_id: Option<String>,
     /// 待加载编辑数据标记（在下一次 ensure_inputs_created 时加载）
     pub pending_load_edit_data: bool,
+    /// 模板模式：表单用于新建或编辑服务器模板
+    pub template_mode: bool,
+    /// 编辑中的模板 ID
+    pub edit_template_id: Option<String>,
+    /// 待填入表单的预设数据（从模板新建服务器或编辑模板时设置）
+    pub pending_prefill: Option<ServerData>,
     pub current_section: DialogSection,
     /// 标记是否需要刷新服务器列表（保存成功后设置为 true）
     pub needs_refresh: bool,
@@ -57,10 +125,14 @@ pub struct ServerDialogState {
     pub password_input: Option<Entity<InputState>>,
     // 描述
     pub description_input: Option<Entity<InputState>>,
+    // 标签（逗号或空格分隔）
+    pub tags_input: Option<Entity<InputState>>,
     // 认证数据
     pub auth_type: AuthType,
     pub private_key_input: Option<Entity<InputState>>,
     pub passphrase_input: Option<Entity<InputState>>,
+    /// 认证方式为身份时选中的身份 ID
+    pub identity_id: Option<String>,
     // 跳板机数据
     pub enable_jump_host: bool,
     pub jump_host_input: Option<Entity<InputState>>,
@@ -71,6 +143,19 @@ pub struct ServerDialogState {
     pub proxy_port_input: Option<Entity<InputState>>,
     pub proxy_username_input: Option<Entity<InputState>>,
     pub proxy_password_input: Option<Entity<InputState>>,
+    // 设置覆盖
+    /// 已开启「覆盖全局」的设置项
+    pub overrides: HashSet<OverrideField>,
+    pub terminal_font_size_input: Option<Entity<InputState>>,
+    /// 覆盖的终端配色方案名称
+    pub terminal_color_scheme: String,
+    pub scrollback_input: Option<Entity<InputState>>,
+    pub terminal_opacity_input: Option<Entity<InputState>>,
+    pub monitor_interval_input: Option<Entity<InputState>>,
+    pub keepalive_input: Option<Entity<InputState>>,
+    // 其他设置
+    /// 连接后执行的命令（每行一条）
+    pub post_connect_input: Option<Entity<InputState>>,
 }
 
 impl Default for ServerDialogState {
@@ -80,6 +165,9 @@ impl Default for ServerDialogState {
             is_edit: false,
             edit_server_id: None,
             pending_load_edit_data: false,
+            template_mode: false,
+            edit_template_id: None,
+            pending_prefill: None,
             current_section: DialogSection::BasicInfo,
             needs_refresh: false,
             group_input: None,
@@ -93,9 +181,11 @@ impl Default for ServerDialogState {
             username_input: None,
             password_input: None,
             description_input: None,
+            tags_input: None,
             auth_type: AuthType::Password,
             private_key_input: None,
             passphrase_input: None,
+            identity_id: None,
             enable_jump_host: false,
             jump_host_input: None,
             enable_proxy: false,
@@ -104,6 +194,14 @@ impl Default for ServerDialogState {
             proxy_port_input: None,
             proxy_username_input: None,
             proxy_password_input: None,
+            overrides: HashSet::new(),
+            terminal_font_size_input: None,
+            terminal_color_scheme: String::new(),
+            scrollback_input: None,
+            terminal_opacity_input: None,
+            monitor_interval_input: None,
+            keepalive_input: None,
+            post_connect_input: None,
         }
     }
 }
@@ -122,8 +220,12 @@ impl ServerDialogState {
             self.group_input =
                 Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
             // 加载可用分组
-            if let Ok(groups) = storage::get_groups() {
-                self.available_groups = groups.into_iter().map(|g| g.name).collect();
+            if let Ok(config) = storage::load_servers() {
+                self.available_groups = config
+                    .ordered_groups()
+                    .into_iter()
+                    .map(|(_, g)| config.group_path(&g.id))
+                    .collect();
             }
         }
 
@@ -166,6 +268,11 @@ impl ServerDialogState {
             self.description_input =
                 Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
         }
+        if self.tags_input.is_none() {
+            let placeholder = i18n::t(&lang, "server_dialog.tags_placeholder");
+            self.tags_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
         if self.private_key_input.is_none() {
             let placeholder = i18n::t(&lang, "server_dialog.private_key_placeholder");
             self.private_key_input =
@@ -220,115 +327,223 @@ impl ServerDialogState {
             }));
         }
 
+        // 设置覆盖输入
+        for input in [
+            &mut self.terminal_font_size_input,
+            &mut self.scrollback_input,
+            &mut self.terminal_opacity_input,
+            &mut self.monitor_interval_input,
+            &mut self.keepalive_input,
+        ] {
+            if input.is_none() {
+                *input = Some(cx.new(|cx| InputState::new(window, cx)));
+            }
+        }
+
+        // 其他设置输入
+        if self.post_connect_input.is_none() {
+            let placeholder = i18n::t(&lang, "server_dialog.post_connect_placeholder");
+            self.post_connect_input = Some(cx.new(|cx| {
+                InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .auto_grow(3, 8)
+            }));
+        }
+
         // 如果是编辑模式且有待加载标记，加载服务器数据
         if self.pending_load_edit_data {
             self.pending_load_edit_data = false;
-            if let Some(server_id) = &self.edit_server_id {
+            if let Some(server_id) = self.edit_server_id.clone() {
                 if let Ok(config) = storage::load_servers() {
-                    if let Some(server_data) = config.servers.iter().find(|s| &s.id == server_id) {
-                        // 加载分组名称
-                        if let Some(group_id) = &server_data.group_id {
-                            if let Some(group) = config.groups.iter().find(|g| &g.id == group_id) {
-                                if let Some(input) = &self.group_input {
-                                    input.update(cx, |s, cx| {
-                                        s.set_value(group.name.clone(), window, cx)
-                                    });
-                                }
-                            }
-                        }
-                        // 加载基本信息
-                        if let Some(input) = &self.label_input {
-                            input.update(cx, |s, cx| {
-                                s.set_value(server_data.label.clone(), window, cx)
-                            });
-                        }
-                        if let Some(input) = &self.host_input {
-                            input.update(cx, |s, cx| {
-                                s.set_value(server_data.host.clone(), window, cx)
-                            });
-                        }
-                        if let Some(input) = &self.port_input {
-                            input.update(cx, |s, cx| {
-                                s.set_value(server_data.port.to_string(), window, cx)
-                            });
-                        }
-                        if let Some(input) = &self.username_input {
-                            input.update(cx, |s, cx| {
-                                s.set_value(server_data.username.clone(), window, cx)
-                            });
-                        }
-                        // 设置认证类型
-                        self.auth_type = server_data.auth_type.clone();
-                        // 加载密码或私钥
-                        if let Some(pwd) = &server_data.password_encrypted {
-                            if let Some(input) = &self.password_input {
-                                input.update(cx, |s, cx| s.set_value(pwd.clone(), window, cx));
-                            }
-                        }
-                        // 优先使用新字段 private_key_filename，如果不存在则回退到旧字段 private_key_path（向后兼容）
-                        let key_to_load = server_data
-                            .private_key_filename
-                            .as_ref()
-                            .or(server_data.private_key_path.as_ref());
-                        if let Some(key) = key_to_load {
-                            if let Some(input) = &self.private_key_input {
-                                input.update(cx, |s, cx| s.set_value(key.clone(), window, cx));
-                            }
-                        }
-                        if let Some(passphrase) = &server_data.key_passphrase_encrypted {
-                            if let Some(input) = &self.passphrase_input {
-                                input.update(cx, |s, cx| {
-                                    s.set_value(passphrase.clone(), window, cx)
-                                });
-                            }
-                        }
-                        // 加载描述
-                        if let Some(desc) = &server_data.description {
-                            if let Some(input) = &self.description_input {
-                                input.update(cx, |s, cx| s.set_value(desc.clone(), window, cx));
-                            }
-                        }
-                        // 加载跳板机设置
-                        if let Some(jump_host) = &server_data.jump_host_id {
-                            self.enable_jump_host = true;
-                            if let Some(input) = &self.jump_host_input {
-                                input
-                                    .update(cx, |s, cx| s.set_value(jump_host.clone(), window, cx));
-                            }
-                        }
-                        // 加载代理设置
-                        if let Some(proxy) = &server_data.proxy {
-                            self.enable_proxy = proxy.enabled;
-                            self.proxy_type = proxy.proxy_type.clone();
-                            if let Some(input) = &self.proxy_host_input {
-                                input.update(cx, |s, cx| {
-                                    s.set_value(proxy.host.clone(), window, cx)
-                                });
-                            }
-                            if let Some(input) = &self.proxy_port_input {
-                                input.update(cx, |s, cx| {
-                                    s.set_value(proxy.port.to_string(), window, cx)
-                                });
-                            }
-                            if let Some(username) = &proxy.username {
-                                if let Some(input) = &self.proxy_username_input {
-                                    input.update(cx, |s, cx| {
-                                        s.set_value(username.clone(), window, cx)
-                                    });
-                                }
-                            }
-                            if let Some(password) = &proxy.password_encrypted {
-                                if let Some(input) = &self.proxy_password_input {
-                                    input.update(cx, |s, cx| {
-                                        s.set_value(password.clone(), window, cx)
-                                    });
-                                }
-                            }
-                        }
+                    if let Some(server_data) = config.servers.iter().find(|s| s.id == server_id) {
+                        self.load_form(server_data, &config, window, cx);
                     }
                 }
             }
         }
+        // 从模板新建或编辑模板时填入预设数据
+        if let Some(server_data) = self.pending_prefill.take() {
+            let config = storage::load_servers().unwrap_or_default();
+            self.load_form(&server_data, &config, window, cx);
+        }
+    }
+
+    /// 把服务器配置填入表单
+    fn load_form(
+        &mut self,
+        server_data: &ServerData,
+        config: &ServerConfig,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // 加载分组路径
+        if let Some(group_id) = &server_data.group_id {
+            if config.groups.iter().any(|g| &g.id == group_id) {
+                let path = config.group_path(group_id);
+                if let Some(input) = &self.group_input {
+                    input.update(cx, |s, cx| s.set_value(path, window, cx));
+                }
+            }
+        }
+        // 加载基本信息
+        if let Some(input) = &self.label_input {
+            input.update(cx, |s, cx| {
+                s.set_value(server_data.label.clone(), window, cx)
+            });
+        }
+        if let Some(input) = &self.host_input {
+            input.update(cx, |s, cx| {
+                s.set_value(server_data.host.clone(), window, cx)
+            });
+        }
+        if let Some(input) = &self.port_input {
+            input.update(cx, |s, cx| {
+                s.set_value(server_data.port.to_string(), window, cx)
+            });
+        }
+        if let Some(input) = &self.username_input {
+            input.update(cx, |s, cx| {
+                s.set_value(server_data.username.clone(), window, cx)
+            });
+        }
+        // 设置认证类型
+        self.auth_type = server_data.auth_type.clone();
+        self.identity_id = server_data.identity_id.clone();
+        // 加载密码或私钥
+        if let Some(pwd) = &server_data.password_encrypted {
+            if let Some(input) = &self.password_input {
+                input.update(cx, |s, cx| s.set_value(pwd.clone(), window, cx));
+            }
+        }
+        // 优先使用新字段 private_key_filename，如果不存在则回退到旧字段 private_key_path（向后兼容）
+        let key_to_load = server_data
+            .private_key_filename
+            .as_ref()
+            .or(server_data.private_key_path.as_ref());
+        if let Some(key) = key_to_load {
+            if let Some(input) = &self.private_key_input {
+                input.update(cx, |s, cx| s.set_value(key.clone(), window, cx));
+            }
+        }
+        if let Some(passphrase) = &server_data.key_passphrase_encrypted {
+            if let Some(input) = &self.passphrase_input {
+                input.update(cx, |s, cx| s.set_value(passphrase.clone(), window, cx));
+            }
+        }
+        // 加载描述
+        if let Some(desc) = &server_data.description {
+            if let Some(input) = &self.description_input {
+                input.update(cx, |s, cx| s.set_value(desc.clone(), window, cx));
+            }
+        }
+        // 加载标签
+        if !server_data.tags.is_empty() {
+            if let Some(input) = &self.tags_input {
+                let tags = server_data.tags.join(", ");
+                input.update(cx, |s, cx| s.set_value(tags, window, cx));
+            }
+        }
+        // 加载跳板机设置
+        if let Some(jump_host) = &server_data.jump_host_id {
+            self.enable_jump_host = true;
+            if let Some(input) = &self.jump_host_input {
+                input.update(cx, |s, cx| s.set_value(jump_host.clone(), window, cx));
+            }
+        }
+        // 加载代理设置
+        if let Some(proxy) = &server_data.proxy {
+            self.enable_proxy = proxy.enabled;
+            self.proxy_type = proxy.proxy_type.clone();
+            if let Some(input) = &self.proxy_host_input {
+                input.update(cx, |s, cx| s.set_value(proxy.host.clone(), window, cx));
+            }
+            if let Some(input) = &self.proxy_port_input {
+                input.update(cx, |s, cx| s.set_value(proxy.port.to_string(), window, cx));
+            }
+            if let Some(username) = &proxy.username {
+                if let Some(input) = &self.proxy_username_input {
+                    input.update(cx, |s, cx| s.set_value(username.clone(), window, cx));
+                }
+            }
+            if let Some(password) = &proxy.password_encrypted {
+                if let Some(input) = &self.proxy_password_input {
+                    input.update(cx, |s, cx| s.set_value(password.clone(), window, cx));
+                }
+            }
+        }
+        // 加载设置覆盖
+        if let Some(color_scheme) = &server_data.terminal_color_scheme {
+            self.overrides.insert(OverrideField::TerminalColorScheme);
+            self.terminal_color_scheme = color_scheme.clone();
+        }
+        for (field, value) in [
+            (
+                OverrideField::TerminalFontSize,
+                server_data.terminal_font_size,
+            ),
+            (OverrideField::Scrollback, server_data.scrollback_lines),
+            (OverrideField::TerminalOpacity, server_data.terminal_opacity),
+            (
+                OverrideField::MonitorInterval,
+                server_data.monitor_interval_secs,
+            ),
+            (
+                OverrideField::Keepalive,
+                server_data.keepalive_interval_secs,
+            ),
+        ] {
+            let Some(value) = value else {
+                continue;
+            };
+            self.overrides.insert(field);
+            if let Some(input) = self.override_input(field) {
+                input.update(cx, |s, cx| s.set_value(value.to_string(), window, cx));
+            }
+        }
+        // 加载连接后命令
+        if !server_data.post_connect_commands.is_empty() {
+            if let Some(input) = &self.post_connect_input {
+                let commands = server_data.post_connect_commands.join("\n");
+                input.update(cx, |s, cx| s.set_value(commands, window, cx));
+            }
+        }
+    }
+
+    /// 设置项对应的输入框（配色方案使用下拉选择，没有输入框）
+    pub fn override_input(&self, field: OverrideField) -> Option<&Entity<InputState>> {
+        match field {
+            OverrideField::TerminalFontSize => self.terminal_font_size_input.as_ref(),
+            OverrideField::TerminalColorScheme => None,
+            OverrideField::Scrollback => self.scrollback_input.as_ref(),
+            OverrideField::TerminalOpacity => self.terminal_opacity_input.as_ref(),
+            OverrideField::MonitorInterval => self.monitor_interval_input.as_ref(),
+            OverrideField::Keepalive => self.keepalive_input.as_ref(),
+        }
+    }
+
+    /// 开启或关闭设置项的「覆盖全局」，开启时以全局设置值作为初始值
+    pub fn set_override(
+        &mut self,
+        field: OverrideField,
+        enabled: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !enabled {
+            self.overrides.remove(&field);
+            return;
+        }
+        self.overrides.insert(field);
+        let global = field.global_value(&storage::load_settings().unwrap_or_default());
+        if field == OverrideField::TerminalColorScheme {
+            if self.terminal_color_scheme.is_empty() {
+                self.terminal_color_scheme = global;
+            }
+        } else if let Some(input) = self.override_input(field) {
+            if input.read(cx).text().to_string().trim().is_empty() {
+                input.update(cx, |s, cx| s.set_value(global, window, cx));
+            }
+        }
     }
 
     pub fn open_add(&mut self) {
@@ -340,6 +555,44 @@ impl ServerDialogState {
         self.current_section = DialogSection::BasicInfo;
     }
 
+    /// 以模板的设置预填表单，打开添加服务器弹窗
+    pub fn open_add_from_template(&mut self, template_id: &str) {
+        let config = storage::load_servers().unwrap_or_default();
+        let Some(template) = config.templates.iter().find(|t| t.id == template_id) else {
+            return;
+        };
+        self.open_add();
+        self.pending_prefill = Some(template.to_server_data());
+    }
+
+    /// 以给定的服务器配置预填表单，打开添加服务器弹窗（如主机发现的结果）
+    pub fn open_add_prefilled(&mut self, server: ServerData) {
+        self.open_add();
+        self.pending_prefill = Some(server);
+    }
+
+    /// 打开新建模板弹窗
+    pub fn open_new_template(&mut self) {
+        self.open_add();
+        self.template_mode = true;
+    }
+
+    /// 打开编辑模板弹窗（名称显示在名称输入框中）
+    pub fn open_edit_template(&mut self, template_id: &str) {
+        let config = storage::load_servers().unwrap_or_default();
+        let Some(template) = config.templates.iter().find(|t| t.id == template_id) else {
+            return;
+        };
+        self.open_add();
+        self.template_mode = true;
+        self.is_edit = true;
+        self.edit_template_id = Some(template.id.clone());
+        self.pending_prefill = Some(ServerData {
+            label: template.name.clone(),
+            ..template.to_server_data()
+        });
+    }
+
     /// 打开编辑服务器弹窗
     pub fn open_edit(&mut self, server_id: String) {
         // 重置所有输入框状态，让它们用当前语言重新创建
@@ -359,6 +612,7 @@ impl ServerDialogState {
         self.port_input = None;
         self.username_input = None;
         self.password_input = None;
+        self.tags_input = None;
         self.private_key_input = None;
         self.passphrase_input = None;
         self.jump_host_input = None;
@@ -366,14 +620,26 @@ impl ServerDialogState {
         self.proxy_port_input = None;
         self.proxy_username_input = None;
         self.proxy_password_input = None;
+        self.terminal_font_size_input = None;
+        self.scrollback_input = None;
+        self.terminal_opacity_input = None;
+        self.monitor_interval_input = None;
+        self.keepalive_input = None;
+        self.post_connect_input = None;
         // 重置表单状态
         self.auth_type = AuthType::Password;
+        self.identity_id = None;
         self.enable_jump_host = false;
         self.enable_proxy = false;
         self.proxy_type = ProxyType::Http;
+        self.overrides.clear();
+        self.terminal_color_scheme.clear();
         self.show_group_dropdown = false;
         self.pending_group_value = None;
         self.pending_private_key_path = None;
+        self.template_mode = false;
+        self.edit_template_id = None;
+        self.pending_prefill = None;
     }
 
     pub fn close(&mut self) {
@@ -381,6 +647,17 @@ impl ServerDialogState {
         self.edit_server_id = None;
     }
 
+    /// 从表单状态提取模板（名称取自名称输入框）
+    pub fn to_template(&self, cx: &App) -> ServerTemplate {
+        let server_data = self.to_server_data(cx);
+        let id = self
+            .edit_template_id
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let name = server_data.label.trim().to_string();
+        ServerTemplate::from_server(id, name, &server_data)
+    }
+
     /// 从表单状态提取 ServerData
     pub fn to_server_data(&self, cx: &App) -> ServerData {
         let get_text = |input: &Option<Entity<gpui_component::input::InputState>>| -> String {
@@ -398,6 +675,7 @@ impl ServerDialogState {
         let username = get_text(&self.username_input);
         let password = get_text(&self.password_input);
         let description = get_text(&self.description_input);
+        let tags = parse_tags(&get_text(&self.tags_input));
         let private_key = get_text(&self.private_key_input);
         let passphrase = get_text(&self.passphrase_input);
         let jump_host = get_text(&self.jump_host_input);
@@ -406,16 +684,42 @@ impl ServerDialogState {
         let proxy_port = proxy_port_str.parse::<u16>().unwrap_or(0);
         let proxy_username = get_text(&self.proxy_username_input);
         let proxy_password = get_text(&self.proxy_password_input);
+        // 未开启覆盖、留空或非法值表示跟随全局设置
+        let override_value = |field: OverrideField| -> Option<u32> {
+            if !self.overrides.contains(&field) {
+                return None;
+            }
+            self.override_input(field)
+                .and_then(|input| input.read(cx).text().to_string().trim().parse::<u32>().ok())
+        };
+        let terminal_font_size =
+            override_value(OverrideField::TerminalFontSize).map(|v| v.clamp(8, 144));
+        let terminal_color_scheme = (self.overrides.contains(&OverrideField::TerminalColorScheme)
+            && !self.terminal_color_scheme.is_empty())
+        .then(|| self.terminal_color_scheme.clone());
+        let scrollback_lines =
+            override_value(OverrideField::Scrollback).map(|v| v.clamp(100, 100000));
+        let terminal_opacity =
+            override_value(OverrideField::TerminalOpacity).map(|v| v.clamp(20, 100));
+        let monitor_interval_secs = override_value(OverrideField::MonitorInterval)
+            .filter(|v| *v > 0)
+            .map(|v| v.min(3600));
+        let keepalive_interval_secs = override_value(OverrideField::Keepalive).map(|v| v.min(3600));
+        let post_connect_commands: Vec<String> = get_text(&self.post_connect_input)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect();
 
-        // 根据分组名称查找 group_id，如果不存在则使用分组名称作为新 ID
+        // 根据分组路径查找 group_id，如果不存在则保留路径，保存时由存储层逐级创建分组
+        let group_name = group_name.trim().to_string();
         let group_id = if group_name.is_empty() {
             None
         } else {
             // 尝试从已有分组中查找
-            if let Ok(groups) = storage::get_groups() {
-                groups
-                    .iter()
-                    .find(|g| g.name == group_name)
+            if let Ok(config) = storage::load_servers() {
+                config
+                    .find_group_by_path(&group_name)
                     .map(|g| g.id.clone())
                     .or_else(|| Some(group_name.clone()))
             } else {
@@ -460,6 +764,11 @@ impl ServerDialogState {
             } else {
                 None
             },
+            identity_id: if self.auth_type == AuthType::Identity {
+                self.identity_id.clone()
+            } else {
+                None
+            },
             description: if !description.is_empty() {
                 Some(description)
             } else {
@@ -491,6 +800,15 @@ impl ServerDialogState {
                 None
             },
             enable_monitor: true,
+            terminal_opacity,
+            monitor_interval_secs,
+            terminal_font_size,
+            terminal_color_scheme,
+            scrollback_lines,
+            keepalive_interval_secs,
+            tags,
+            post_connect_commands,
+            favorite: false,
             created_at: chrono::Utc::now().to_rfc3339(),
             last_connected_at: None,
         }
@@ -646,6 +964,11 @@ fn render_left_menu(state: Entity<ServerDialogState>, cx: &App) -> impl IntoElem
             i18n::t(&lang, "server_dialog.nav.proxy"),
             icons::GLOBE,
         ),
+        (
+            DialogSection::Overrides,
+            i18n::t(&lang, "server_dialog.nav.overrides"),
+            icons::LIST_CHECKS,
+        ),
         (
             DialogSection::OtherSettings,
             i18n::t(&lang, "server_dialog.nav.other"),
@@ -720,10 +1043,11 @@ fn render_right_content(
         .map(|s| s.theme.language)
         .unwrap_or(Language::Chinese);
     let is_edit = state.read(cx).is_edit;
-    let title = if is_edit {
-        i18n::t(&lang, "server_dialog.edit_title")
-    } else {
-        i18n::t(&lang, "server_dialog.add_title")
+    let title = match (state.read(cx).template_mode, is_edit) {
+        (true, true) => i18n::t(&lang, "server_dialog.edit_template_title"),
+        (true, false) => i18n::t(&lang, "server_dialog.new_template_title"),
+        (false, true) => i18n::t(&lang, "server_dialog.edit_title"),
+        (false, false) => i18n::t(&lang, "server_dialog.add_title"),
     };
 
     div()
@@ -767,6 +1091,9 @@ fn render_right_content(
                     DialogSection::ProxySettings => {
                         render_proxy_settings_form(state.clone(), cx).into_any_element()
                     }
+                    DialogSection::Overrides => {
+                        render_overrides_form(state.clone(), cx).into_any_element()
+                    }
                     DialogSection::OtherSettings => {
                         render_other_settings_form(state.clone(), cx).into_any_element()
                     }
@@ -799,6 +1126,11 @@ fn render_footer_buttons(state: Entity<ServerDialogState>, cx: &App) -> impl Int
     // 提前读取表单数据和编辑模式状态
     let server_data = state.read(cx).to_server_data(cx);
     let is_edit = state.read(cx).is_edit;
+    let template = state
+        .read(cx)
+        .template_mode
+        .then(|| state.read(cx).to_template(cx));
+    let editing_template_id = state.read(cx).edit_template_id.clone();
 
     div()
         .h(px(64.))
@@ -810,6 +1142,33 @@ fn render_footer_buttons(state: Entity<ServerDialogState>, cx: &App) -> impl Int
         .justify_end()
         .gap_3()
         .px_6()
+        // 删除模板按钮（仅编辑模板时显示）
+        .children(editing_template_id.map(|template_id| {
+            let state_for_delete = state.clone();
+            div()
+                .id("delete-template-btn")
+                .mr_auto()
+                .px_4()
+                .py_2()
+                .rounded_md()
+                .cursor_pointer()
+                .hover(move |s| s.bg(secondary_hover))
+                .on_click(move |_, _, cx| {
+                    if let Err(e) = storage::delete_template(&template_id) {
+                        error!("Failed to delete template: {}", e);
+                    }
+                    state_for_delete.update(cx, |s, _| {
+                        s.needs_refresh = true;
+                        s.close();
+                    });
+                })
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().danger)
+                        .child(i18n::t(&lang, "common.delete")),
+                )
+        }))
         // 取消按钮
         .child(
             div()
@@ -838,8 +1197,10 @@ fn render_footer_buttons(state: Entity<ServerDialogState>, cx: &App) -> impl Int
                 .cursor_pointer()
                 .hover(move |s| s.bg(primary_hover))
                 .on_click(move |_, _, cx| {
-                    // 根据是新增还是编辑模式调用不同的存储函数
-                    let result = if is_edit {
+                    // 根据模板、新增或编辑模式调用不同的存储函数
+                    let result = if let Some(template) = &template {
+                        storage::save_template(template.clone())
+                    } else if is_edit {
                         storage::update_server(server_data.clone())
                     } else {
                         storage::add_server(server_data.clone())
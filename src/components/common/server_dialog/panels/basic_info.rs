@@ -25,6 +25,13 @@ pub fn render_basic_info_form(state: Entity<ServerDialogState>, cx: &App) -> imp
 
     let state_read = state.read(cx);
     let auth_type = state_read.auth_type.clone();
+    // 模板不保存主机地址与描述，名称即模板名称
+    let template_mode = state_read.template_mode;
+    let label_key = if template_mode {
+        "server_dialog.template_name"
+    } else {
+        "server_dialog.label"
+    };
 
     // 预先准备输入框元素
     let loading_text = i18n::t(&lang, "common.loading");
@@ -78,6 +85,12 @@ pub fn render_basic_info_form(state: Entity<ServerDialogState>, cx: &App) -> imp
         div().child(loading_text).into_any_element()
     };
 
+    let tags_input = if let Some(input) = &state_read.tags_input {
+        Input::new(input).into_any_element()
+    } else {
+        div().child(loading_text).into_any_element()
+    };
+
     let state_for_group_dropdown = state.clone();
 
     div()
@@ -94,32 +107,47 @@ pub fn render_basic_info_form(state: Entity<ServerDialogState>, cx: &App) -> imp
             loading_text,
             cx,
         ))
-        // 服务器标签
+        // 标签（逗号或空格分隔）
         .child(
             div()
                 .flex()
                 .flex_col()
                 .gap_2()
                 .child(render_form_label(
-                    i18n::t(&lang, "server_dialog.label"),
-                    icons::SERVER,
+                    i18n::t(&lang, "server_dialog.tags"),
+                    icons::TAG,
                     cx,
                 ))
-                .child(label_input),
+                .child(tags_input),
         )
-        // 主机地址
+        // 服务器标签
         .child(
             div()
                 .flex()
                 .flex_col()
                 .gap_2()
                 .child(render_form_label(
-                    i18n::t(&lang, "server_dialog.host"),
-                    icons::GLOBE,
+                    i18n::t(&lang, label_key),
+                    icons::SERVER,
                     cx,
                 ))
-                .child(host_input),
+                .child(label_input),
         )
+        // 主机地址
+        .when(!template_mode, |this| {
+            this.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .child(render_form_label(
+                        i18n::t(&lang, "server_dialog.host"),
+                        icons::GLOBE,
+                        cx,
+                    ))
+                    .child(host_input),
+            )
+        })
         // 端口
         .child(
             div()
@@ -190,6 +218,16 @@ pub fn render_basic_info_form(state: Entity<ServerDialogState>, cx: &App) -> imp
                             unselected_bg,
                             selected_text,
                             unselected_text,
+                        ))
+                        .child(render_auth_type_button(
+                            state.clone(),
+                            AuthType::Identity,
+                            i18n::t(&lang, "server_dialog.auth_identity"),
+                            auth_type == AuthType::Identity,
+                            selected_bg,
+                            unselected_bg,
+                            selected_text,
+                            unselected_text,
                         )),
                 )
         })
@@ -304,20 +342,125 @@ pub fn render_basic_info_form(state: Entity<ServerDialogState>, cx: &App) -> imp
                     )
                     .into_any_element(),
             ),
+            AuthType::Identity => Some(render_identity_picker(
+                state.clone(),
+                state_read.identity_id.clone(),
+                &lang,
+                cx,
+            )),
         })
         // 描述（可选）
-        .child(
+        .when(!template_mode, |this| {
+            this.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .child(render_form_label(
+                        i18n::t(&lang, "server_dialog.description"),
+                        icons::EDIT,
+                        cx,
+                    ))
+                    .child(description_input),
+            )
+        })
+}
+
+/// 渲染身份选择列表（身份在“身份”页面中管理）
+fn render_identity_picker(
+    state: Entity<ServerDialogState>,
+    selected_id: Option<String>,
+    lang: &Language,
+    cx: &App,
+) -> AnyElement {
+    let identities = storage::load_servers()
+        .map(|c| c.identities)
+        .unwrap_or_default();
+    let hover_bg = cx.theme().list_hover;
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(render_form_label(
+            i18n::t(lang, "server_dialog.identity"),
+            icons::USER,
+            cx,
+        ))
+        .child(if identities.is_empty() {
             div()
-                .flex()
-                .flex_col()
-                .gap_2()
-                .child(render_form_label(
-                    i18n::t(&lang, "server_dialog.description"),
-                    icons::EDIT,
-                    cx,
-                ))
-                .child(description_input),
-        )
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(lang, "server_dialog.no_identities"))
+                .into_any_element()
+        } else {
+            div()
+                .id("server-dialog-identities")
+                .max_h(px(180.))
+                .overflow_y_scroll()
+                .rounded_md()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_1()
+                .children(identities.into_iter().enumerate().map(|(index, identity)| {
+                    let selected = selected_id.as_deref() == Some(identity.id.as_str());
+                    let state = state.clone();
+                    let detail = match identity.username.as_str() {
+                        "" => i18n::t(lang, identity.kind.label_key()).to_string(),
+                        username => format!(
+                            "{} · {}",
+                            username,
+                            i18n::t(lang, identity.kind.label_key())
+                        ),
+                    };
+                    div()
+                        .id(("server-dialog-identity", index))
+                        .px_2()
+                        .py_1p5()
+                        .rounded_md()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(hover_bg))
+                        .when(selected, |this| this.bg(cx.theme().list_active))
+                        .on_click(move |_, _, cx| {
+                            state.update(cx, |s, cx| {
+                                s.identity_id = Some(identity.id.clone());
+                                cx.notify();
+                            });
+                        })
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .child(identity.name.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(detail),
+                                ),
+                        )
+                        .when(selected, |this| {
+                            this.child(
+                                svg()
+                                    .path(icons::CHECK)
+                                    .size(px(14.))
+                                    .text_color(cx.theme().primary),
+                            )
+                        })
+                }))
+                .into_any_element()
+        })
+        .into_any_element()
 }
 
 /// 渲染认证方式切换按钮
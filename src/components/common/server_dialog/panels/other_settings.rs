@@ -2,25 +2,42 @@
 
 use gpui::prelude::*;
 use gpui::*;
-use gpui_component::ActiveTheme;
+use gpui_component::input::Input;
 
+use crate::constants::icons;
 use crate::i18n;
 use crate::models::settings::Language;
 use crate::services::storage;
 
+use super::super::helpers::render_form_label;
 use super::super::ServerDialogState;
 
 /// 渲染其他设置表单
-pub fn render_other_settings_form(_state: Entity<ServerDialogState>, cx: &App) -> impl IntoElement {
+pub fn render_other_settings_form(state: Entity<ServerDialogState>, cx: &App) -> impl IntoElement {
     // 加载当前语言
     let lang = storage::load_settings()
         .map(|s| s.theme.language)
         .unwrap_or(Language::Chinese);
 
+    let state_read = state.read(cx);
+    let loading_text = i18n::t(&lang, "common.loading");
+
+    let post_connect_input = if let Some(input) = &state_read.post_connect_input {
+        Input::new(input).into_any_element()
+    } else {
+        div().child(loading_text).into_any_element()
+    };
+
     div().flex().flex_col().gap_3().child(
         div()
-            .text_sm()
-            .text_color(cx.theme().muted_foreground)
-            .child(i18n::t(&lang, "server_dialog.no_other_settings")),
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(render_form_label(
+                i18n::t(&lang, "server_dialog.post_connect"),
+                icons::CODE,
+                cx,
+            ))
+            .child(post_connect_input),
     )
 }
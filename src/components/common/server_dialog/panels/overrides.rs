@@ -0,0 +1,150 @@
+// 设置覆盖面板：按服务器覆盖部分全局设置
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::input::Input;
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::switch::Switch;
+use gpui_component::ActiveTheme;
+
+use crate::components::common::icon::render_icon;
+use crate::components::common::settings_dialog::helpers::TERMINAL_THEMES;
+use crate::constants::icons;
+use crate::i18n;
+use crate::services::storage;
+
+use super::super::helpers::render_form_label;
+use super::super::{OverrideField, ServerDialogState};
+
+/// 渲染设置覆盖表单
+pub fn render_overrides_form(state: Entity<ServerDialogState>, cx: &App) -> impl IntoElement {
+    let settings = storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language.clone();
+    let muted = cx.theme().muted_foreground;
+    let loading_text = i18n::t(&lang, "common.loading");
+
+    let state_read = state.read(cx);
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(
+            div()
+                .text_xs()
+                .text_color(muted)
+                .child(i18n::t(&lang, "server_dialog.override.hint")),
+        )
+        .children(
+            OverrideField::ALL
+                .into_iter()
+                .enumerate()
+                .map(|(ix, field)| {
+                    let enabled = state_read.overrides.contains(&field);
+
+                    // 未覆盖时显示全局设置值
+                    let control = if !enabled {
+                        div()
+                            .text_sm()
+                            .text_color(muted)
+                            .child(
+                                i18n::t(&lang, "server_dialog.override.global")
+                                    .replace("{}", &field.global_value(&settings)),
+                            )
+                            .into_any_element()
+                    } else if field == OverrideField::TerminalColorScheme {
+                        render_color_scheme_select(
+                            state.clone(),
+                            &state_read.terminal_color_scheme,
+                            cx,
+                        )
+                        .into_any_element()
+                    } else if let Some(input) = state_read.override_input(field) {
+                        Input::new(input).into_any_element()
+                    } else {
+                        div().child(loading_text).into_any_element()
+                    };
+
+                    let state = state.clone();
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .flex()
+                                .items_center()
+                                .justify_between()
+                                .child(render_form_label(
+                                    i18n::t(&lang, field.label_key()),
+                                    field.icon(),
+                                    cx,
+                                ))
+                                .child(
+                                    div()
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .child(
+                                            div().text_xs().text_color(muted).child(i18n::t(
+                                                &lang,
+                                                "server_dialog.override.toggle",
+                                            )),
+                                        )
+                                        .child(
+                                            Switch::new(("server-override-switch", ix))
+                                                .checked(enabled)
+                                                .on_click(move |new_val, window, cx| {
+                                                    state.update(cx, |s, cx| {
+                                                        s.set_override(field, *new_val, window, cx);
+                                                    });
+                                                }),
+                                        ),
+                                ),
+                        )
+                        .child(control)
+                }),
+        )
+}
+
+/// 渲染终端配色方案下拉选择
+fn render_color_scheme_select(
+    state: Entity<ServerDialogState>,
+    current: &str,
+    cx: &App,
+) -> impl IntoElement {
+    Button::new("server-color-scheme-dropdown")
+        .w_full()
+        .outline()
+        .justify_start()
+        .child(
+            div()
+                .flex()
+                .flex_1()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(current.to_string()),
+                )
+                .child(render_icon(
+                    icons::CHEVRON_DOWN,
+                    cx.theme().muted_foreground.into(),
+                )),
+        )
+        .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+            let mut menu = menu.min_w(px(240.));
+            for theme in TERMINAL_THEMES {
+                let state = state.clone();
+                menu = menu.item(PopupMenuItem::new(*theme).on_click(move |_, _, cx| {
+                    state.update(cx, |s, _| {
+                        s.terminal_color_scheme = theme.to_string();
+                    });
+                }));
+            }
+            menu
+        })
+}
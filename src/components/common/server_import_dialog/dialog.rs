@@ -0,0 +1,406 @@
+// 服务器导入对话框渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::scroll::ScrollableElement;
+use gpui_component::ActiveTheme;
+
+use super::state::ServerImportState;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::server_import::{ImportSource, ImportedServer};
+use crate::services::storage;
+
+/// 渲染服务器导入对话框覆盖层：选择来源 → 选择文件 → 预览勾选 → 导入
+pub fn render_server_import_dialog_overlay(
+    state: Entity<ServerImportState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let source = state_read.source;
+    let selected_count = state_read.selected_count();
+    let total = state_read.servers.len();
+    let duplicates = state_read.servers.iter().filter(|s| s.duplicate).count();
+
+    div()
+        .id("server-import-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(640.))
+                .max_h(px(600.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(&lang, "server_import.title")),
+                )
+                // 导入来源
+                .child(div().flex().flex_wrap().gap_2().children(
+                    ImportSource::available().into_iter().map(|option| {
+                        render_source_option(option, option == source, &lang, state.clone(), cx)
+                    }),
+                ))
+                // 选择文件 / 读取注册表
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .child(render_pick_button(source, &lang, state.clone()))
+                        .when(state_read.loaded && state_read.error.is_none(), |this| {
+                            this.child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(
+                                        i18n::t(&lang, "server_import.summary")
+                                            .replacen("{}", &total.to_string(), 1)
+                                            .replacen("{}", &duplicates.to_string(), 1),
+                                    ),
+                            )
+                        }),
+                )
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 预览列表
+                .when(state_read.loaded && total > 0, |this| {
+                    this.child(
+                        div()
+                            .id("server-import-preview")
+                            .flex_1()
+                            .min_h(px(0.))
+                            .max_h(px(340.))
+                            .overflow_y_scrollbar()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .p_1()
+                            .children(
+                                state_read
+                                    .servers
+                                    .iter()
+                                    .zip(state_read.selected.iter())
+                                    .enumerate()
+                                    .map(|(index, (server, checked))| {
+                                        render_preview_row(
+                                            index,
+                                            server,
+                                            *checked,
+                                            &lang,
+                                            state.clone(),
+                                            cx,
+                                        )
+                                    }),
+                            ),
+                    )
+                })
+                .when(
+                    state_read.loaded && total == 0 && state_read.error.is_none(),
+                    |this| {
+                        this.child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(&lang, "server_import.empty")),
+                        )
+                    },
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .pt_2()
+                        .when(total > 0, |this| {
+                            let all_selected = selected_count == total;
+                            this.child(
+                                Button::new("server-import-select-all")
+                                    .ghost()
+                                    .child(i18n::t(
+                                        &lang,
+                                        if all_selected {
+                                            "server_import.select_none"
+                                        } else {
+                                            "server_import.select_all"
+                                        },
+                                    ))
+                                    .on_click({
+                                        let state = state.clone();
+                                        move |_, _, cx| {
+                                            state.update(cx, |s, cx| {
+                                                s.set_all(!all_selected);
+                                                cx.notify();
+                                            });
+                                        }
+                                    }),
+                            )
+                        })
+                        .child(div().flex_1())
+                        .child(
+                            Button::new("server-import-cancel")
+                                .outline()
+                                .child(i18n::t(&lang, "common.cancel"))
+                                .on_click({
+                                    let state = state.clone();
+                                    move |_, _, cx| {
+                                        state.update(cx, |s, cx| {
+                                            s.close();
+                                            cx.notify();
+                                        });
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("server-import-confirm")
+                                .primary()
+                                .child(
+                                    i18n::t(&lang, "server_import.confirm")
+                                        .replace("{}", &selected_count.to_string()),
+                                )
+                                .disabled(selected_count == 0)
+                                .on_click(move |_, _, cx| {
+                                    state.update(cx, |s, cx| {
+                                        s.confirm();
+                                        cx.notify();
+                                    });
+                                }),
+                        ),
+                ),
+        )
+}
+
+/// 渲染一个导入来源选项
+fn render_source_option(
+    option: ImportSource,
+    active: bool,
+    lang: &Language,
+    state: Entity<ServerImportState>,
+    cx: &App,
+) -> impl IntoElement {
+    let (bg, hover_bg, text_color) = if active {
+        (
+            cx.theme().primary,
+            cx.theme().primary_hover,
+            cx.theme().primary_foreground,
+        )
+    } else {
+        (
+            cx.theme().secondary,
+            cx.theme().secondary_hover,
+            cx.theme().foreground,
+        )
+    };
+
+    div()
+        .id(SharedString::from(format!(
+            "server-import-source-{:?}",
+            option
+        )))
+        .px_3()
+        .py_1p5()
+        .rounded_md()
+        .bg(bg)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                s.set_source(option);
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .text_sm()
+                .text_color(text_color)
+                .child(i18n::t(lang, option.label_key())),
+        )
+}
+
+/// 渲染选择文件按钮；读取注册表的来源直接解析
+fn render_pick_button(
+    source: ImportSource,
+    lang: &Language,
+    state: Entity<ServerImportState>,
+) -> impl IntoElement {
+    let label = if source.file_extensions().is_some() {
+        "server_import.choose_file"
+    } else {
+        "server_import.read_registry"
+    };
+    let title = i18n::t(lang, "server_import.title");
+    let filter_name = i18n::t(lang, source.label_key());
+
+    Button::new("server-import-pick")
+        .outline()
+        .child(i18n::t(lang, label))
+        .on_click(move |_, _, cx| {
+            let Some(extensions) = source.file_extensions() else {
+                state.update(cx, |s, cx| {
+                    s.load(None);
+                    cx.notify();
+                });
+                return;
+            };
+            let state = state.clone();
+            cx.spawn(async move |cx| {
+                let picker = rfd::AsyncFileDialog::new()
+                    .set_title(title)
+                    .add_filter(filter_name, extensions);
+                if let Some(file) = picker.pick_file().await {
+                    let _ = state.update(cx, |s, cx| {
+                        s.load(Some(file.path()));
+                        cx.notify();
+                    });
+                }
+            })
+            .detach();
+        })
+}
+
+/// 渲染预览列表中的一行
+fn render_preview_row(
+    index: usize,
+    server: &ImportedServer,
+    checked: bool,
+    lang: &Language,
+    state: Entity<ServerImportState>,
+    cx: &App,
+) -> impl IntoElement {
+    let hover_bg = cx.theme().list_hover;
+    let address = if server.username.is_empty() {
+        format!("{}:{}", server.host, server.port)
+    } else {
+        format!("{}@{}:{}", server.username, server.host, server.port)
+    };
+
+    div()
+        .id(("server-import-row", index))
+        .px_2()
+        .py_1p5()
+        .rounded_md()
+        .flex()
+        .items_center()
+        .gap_3()
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                s.toggle(index);
+                cx.notify();
+            });
+        })
+        .child(render_checkbox(checked, cx))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(server.label.clone()),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(address),
+                ),
+        )
+        .children(server.group.clone().map(|group| {
+            div()
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(group)
+        }))
+        .when(server.proxy.is_some(), |this| {
+            this.child(render_badge(
+                i18n::t(lang, "server_import.proxy"),
+                cx.theme().muted_foreground,
+                cx,
+            ))
+        })
+        .when(server.duplicate, |this| {
+            this.child(render_badge(
+                i18n::t(lang, "server_import.duplicate"),
+                cx.theme().warning,
+                cx,
+            ))
+        })
+}
+
+fn render_badge(label: &'static str, color: Hsla, cx: &App) -> impl IntoElement {
+    div()
+        .flex_shrink_0()
+        .px_1p5()
+        .rounded(px(3.))
+        .border_1()
+        .border_color(color)
+        .text_xs()
+        .text_color(color)
+        .bg(cx.theme().popover)
+        .child(label)
+}
+
+/// 渲染复选框
+fn render_checkbox(checked: bool, cx: &App) -> impl IntoElement {
+    div()
+        .size(px(16.))
+        .flex_shrink_0()
+        .flex()
+        .items_center()
+        .justify_center()
+        .rounded(px(3.))
+        .border_1()
+        .when(checked, |this| {
+            this.bg(cx.theme().primary)
+                .border_color(cx.theme().primary)
+                .child(
+                    svg()
+                        .path(icons::CHECK)
+                        .size(px(12.))
+                        .text_color(cx.theme().primary_foreground),
+                )
+        })
+        .when(!checked, |this| this.border_color(cx.theme().border))
+}
@@ -0,0 +1,7 @@
+// 从其他 SSH 客户端导入服务器的对话框组件
+
+mod dialog;
+mod state;
+
+pub use dialog::render_server_import_dialog_overlay;
+pub use state::ServerImportState;
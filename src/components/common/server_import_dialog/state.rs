@@ -0,0 +1,122 @@
+// 服务器导入对话框状态：选择来源、解析文件、预览勾选与确认导入
+
+use std::path::Path;
+
+use tracing::error;
+
+use crate::services::server_import::{self, ImportSource, ImportedServer};
+
+/// 服务器导入对话框状态
+pub struct ServerImportState {
+    pub visible: bool,
+    /// 当前选择的导入来源
+    pub source: ImportSource,
+    /// 解析出的服务器（预览列表）
+    pub servers: Vec<ImportedServer>,
+    /// 各服务器是否勾选导入，与 `servers` 一一对应
+    pub selected: Vec<bool>,
+    /// 是否已解析过文件（区分“尚未选择”与“没有可导入的服务器”）
+    pub loaded: bool,
+    /// 解析或导入失败的错误信息
+    pub error: Option<String>,
+    /// 导入完成后需要刷新主页服务器列表
+    pub needs_page_refresh: bool,
+}
+
+impl Default for ServerImportState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            source: ImportSource::PuttyReg,
+            servers: Vec::new(),
+            selected: Vec::new(),
+            loaded: false,
+            error: None,
+            needs_page_refresh: false,
+        }
+    }
+}
+
+impl ServerImportState {
+    pub fn open(&mut self) {
+        *self = Self {
+            visible: true,
+            needs_page_refresh: self.needs_page_refresh,
+            ..Default::default()
+        };
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.servers.clear();
+        self.selected.clear();
+    }
+
+    /// 切换导入来源并清空之前的预览
+    pub fn set_source(&mut self, source: ImportSource) {
+        if self.source != source {
+            self.source = source;
+            self.servers.clear();
+            self.selected.clear();
+            self.loaded = false;
+            self.error = None;
+        }
+    }
+
+    /// 解析文件（或注册表）并生成预览，重复项默认不勾选
+    pub fn load(&mut self, path: Option<&Path>) {
+        match server_import::parse(self.source, path) {
+            Ok(servers) => {
+                self.selected = servers.iter().map(|s| !s.duplicate).collect();
+                self.servers = servers;
+                self.error = None;
+            }
+            Err(e) => {
+                error!("[Import] {:#}", e);
+                self.servers.clear();
+                self.selected.clear();
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+        self.loaded = true;
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(selected) = self.selected.get_mut(index) {
+            *selected = !*selected;
+        }
+    }
+
+    /// 全选或全不选
+    pub fn set_all(&mut self, selected: bool) {
+        self.selected.iter_mut().for_each(|s| *s = selected);
+    }
+
+    pub fn selected_count(&self) -> usize {
+        self.selected.iter().filter(|s| **s).count()
+    }
+
+    /// 导入勾选的服务器，成功后关闭对话框
+    pub fn confirm(&mut self) {
+        let servers: Vec<ImportedServer> = self
+            .servers
+            .iter()
+            .zip(&self.selected)
+            .filter(|(_, selected)| **selected)
+            .map(|(server, _)| server.clone())
+            .collect();
+        if servers.is_empty() {
+            return;
+        }
+        match server_import::import(&servers) {
+            Ok(_) => {
+                self.needs_page_refresh = true;
+                self.close();
+            }
+            Err(e) => {
+                error!("[Import] {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
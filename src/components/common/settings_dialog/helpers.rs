@@ -11,6 +11,7 @@ use gpui_component::ActiveTheme;
 use crate::components::common::icon::render_icon;
 use crate::constants::icons;
 
+use super::search::is_highlighted;
 use super::SettingsDialogState;
 
 // ======================== 常量 ========================
@@ -61,6 +62,15 @@ pub const TERMINAL_THEMES: &[&str] = &[
 
 // ======================== 辅助渲染函数 ========================
 
+/// 设置行背景色（从搜索结果跳转过来的设置项高亮显示）
+pub fn row_bg(label: &str, cx: &App) -> Hsla {
+    if is_highlighted(label, cx) {
+        cx.theme().primary.opacity(0.2)
+    } else {
+        cx.theme().muted
+    }
+}
+
 pub fn render_section_title(title: &'static str, cx: &App) -> impl IntoElement {
     div()
         .text_base()
@@ -83,7 +93,7 @@ pub fn render_input_row(
         .justify_between()
         .py_3()
         .px_4()
-        .bg(cx.theme().muted)
+        .bg(row_bg(label, cx))
         .rounded_lg()
         .mb_2()
         .child(
@@ -116,7 +126,7 @@ pub fn render_font_input_row(
         .justify_between()
         .py_3()
         .px_4()
-        .bg(cx.theme().muted)
+        .bg(row_bg(label, cx))
         .rounded_lg()
         .mb_2()
         .child(
@@ -187,7 +197,7 @@ pub fn render_theme_select_row(
         .justify_between()
         .py_3()
         .px_4()
-        .bg(cx.theme().muted)
+        .bg(row_bg(label, cx))
         .rounded_lg()
         .mb_2()
         .child(
@@ -240,6 +250,75 @@ pub fn render_theme_select_row(
         )
 }
 
+/// 渲染通用下拉选择行，选中后以选项下标调用 `select_fn`
+pub fn render_select_row(
+    id: impl Into<ElementId>,
+    label: &'static str,
+    current: SharedString,
+    options: Vec<SharedString>,
+    state: Entity<SettingsDialogState>,
+    select_fn: fn(&mut SettingsDialogState, usize),
+    cx: &App,
+) -> impl IntoElement {
+    use gpui::Corner;
+
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .py_3()
+        .px_4()
+        .bg(row_bg(label, cx))
+        .rounded_lg()
+        .mb_2()
+        .child(
+            div()
+                .w(px(120.))
+                .text_sm()
+                .text_color(cx.theme().foreground)
+                .child(label),
+        )
+        .child(
+            Button::new(id)
+                .w(px(200.))
+                .h(px(32.))
+                .outline()
+                .justify_start()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .w(px(180.))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(current),
+                        )
+                        .child(render_icon(
+                            icons::CHEVRON_DOWN,
+                            cx.theme().muted_foreground.into(),
+                        )),
+                )
+                .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+                    let mut menu = menu.min_w(px(200.));
+                    for (index, option) in options.iter().enumerate() {
+                        let state_clone = state.clone();
+                        menu = menu.item(PopupMenuItem::new(option.clone()).on_click(
+                            move |_, _, cx| {
+                                state_clone.update(cx, |s, _| {
+                                    select_fn(s, index);
+                                    s.mark_changed();
+                                });
+                            },
+                        ));
+                    }
+                    menu
+                }),
+        )
+}
+
 /// 渲染带数字输入框的设置行（带 +/- 按钮）
 pub fn render_number_row(
     label: &'static str,
@@ -252,7 +331,7 @@ pub fn render_number_row(
         .justify_between()
         .py_3()
         .px_4()
-        .bg(cx.theme().muted)
+        .bg(row_bg(label, cx))
         .rounded_lg()
         .mb_2()
         .child(
@@ -288,7 +367,7 @@ pub fn render_switch_row(
         .justify_between()
         .py_3()
         .px_4()
-        .bg(cx.theme().muted)
+        .bg(row_bg(label, cx))
         .rounded_lg()
         .mb_2()
         .child(div().text_sm().text_color(text_color).child(label))
@@ -1,23 +1,29 @@
 // 子模块声明
 pub mod helpers;
 pub mod panels;
+pub mod search;
 
 use gpui::prelude::*;
 use gpui::*;
-use gpui_component::input::InputState;
+use gpui_component::input::{InputEvent, InputState};
 use gpui_component::scroll::ScrollableElement;
+use gpui_component::theme::{Theme as GpuiTheme, ThemeMode as GpuiThemeMode};
 use gpui_component::ActiveTheme;
 
 use crate::components::common::icon::render_icon;
+use crate::components::common::sync_conflict_dialog::SyncState;
 use crate::constants::icons;
 use crate::i18n;
-use crate::models::settings::AppSettings;
-use crate::services::storage;
+use crate::models::settings::{AppSettings, ThemeMode, UiColorSlot, UiTheme};
+use crate::services::{storage, transfer};
 
 // 导入辅助函数
 use helpers::create_float_number_input;
 use helpers::create_int_number_input;
 
+// 导入搜索函数
+use search::{render_search_field, render_search_results, SearchMatch};
+
 // 导入面板函数
 use panels::{
     render_about_panel, render_connection_panel, render_keybindings_panel, render_monitor_panel,
@@ -41,6 +47,34 @@ pub enum SettingsSection {
 }
 
 impl SettingsSection {
+    /// 导航菜单中的全部区域（按显示顺序）
+    pub const ALL: [SettingsSection; 9] = [
+        SettingsSection::Theme,
+        SettingsSection::Terminal,
+        SettingsSection::KeyBindings,
+        SettingsSection::Sftp,
+        SettingsSection::Monitor,
+        SettingsSection::Connection,
+        SettingsSection::Sync,
+        SettingsSection::System,
+        SettingsSection::About,
+    ];
+
+    /// 路由路径中使用的 ID
+    pub fn id(&self) -> &'static str {
+        match self {
+            SettingsSection::Theme => "theme",
+            SettingsSection::Terminal => "terminal",
+            SettingsSection::KeyBindings => "keybindings",
+            SettingsSection::Sftp => "sftp",
+            SettingsSection::Monitor => "monitor",
+            SettingsSection::Connection => "connection",
+            SettingsSection::Sync => "sync",
+            SettingsSection::System => "system",
+            SettingsSection::About => "about",
+        }
+    }
+
     pub fn label_key(&self) -> &'static str {
         match self {
             SettingsSection::Theme => "settings.nav.theme",
@@ -75,18 +109,31 @@ pub struct SettingsDialogState {
     pub visible: bool,
     pub current_section: SettingsSection,
     pub settings: AppSettings,
-    /// 标记设置是否有变更
+    /// 标记设置是否有变更（与已保存的设置不同）
     pub has_changes: bool,
+    /// 最近一次保存（或打开时读取）的设置，用于判断是否有未保存的变更
+    saved_settings: AppSettings,
+    /// 关闭时有未保存的变更，显示放弃确认
+    pub confirm_discard: bool,
+    /// 设置搜索框
+    pub search_input: Option<Entity<InputState>>,
 
     // ============ 主题设置输入 ============
     pub ui_font_family_input: Option<Entity<InputState>>,
     pub ui_font_size_input: Option<Entity<InputState>>,
+    /// 界面配色编辑的是深色还是浅色模式的配色
+    pub ui_theme_dark: bool,
+    /// 界面配色各颜色的输入框（为空时重新创建）
+    pub ui_theme_inputs: Vec<(UiColorSlot, Entity<InputState>)>,
+    /// 最近一次主题导入/导出的结果
+    pub ui_theme_result: Option<Result<String, String>>,
 
     // ============ 终端设置输入 ============
     pub terminal_font_family_input: Option<Entity<InputState>>,
     pub terminal_font_size_input: Option<Entity<InputState>>,
     pub terminal_line_height_input: Option<Entity<InputState>>,
     pub scrollback_lines_input: Option<Entity<InputState>>,
+    pub background_opacity_input: Option<Entity<InputState>>,
 
     // ============ 连接设置输入 ============
     pub default_port_input: Option<Entity<InputState>>,
@@ -94,16 +141,29 @@ pub struct SettingsDialogState {
     pub keepalive_interval_input: Option<Entity<InputState>>,
     pub reconnect_attempts_input: Option<Entity<InputState>>,
     pub reconnect_interval_input: Option<Entity<InputState>>,
+    pub hibernate_after_input: Option<Entity<InputState>>,
+    pub reachability_interval_input: Option<Entity<InputState>>,
+    pub db_client_mysql_input: Option<Entity<InputState>>,
+    pub db_client_postgresql_input: Option<Entity<InputState>>,
+    pub db_client_redis_input: Option<Entity<InputState>>,
+    pub db_client_mongodb_input: Option<Entity<InputState>>,
 
     // ============ 监控设置输入 ============
     pub history_retention_input: Option<Entity<InputState>>,
     pub cpu_threshold_input: Option<Entity<InputState>>,
     pub memory_threshold_input: Option<Entity<InputState>>,
     pub disk_threshold_input: Option<Entity<InputState>>,
+    pub temperature_threshold_input: Option<Entity<InputState>>,
+    pub alert_consecutive_input: Option<Entity<InputState>>,
 
     // ============ SFTP 设置输入 ============
     pub concurrent_transfers_input: Option<Entity<InputState>>,
+    pub max_active_transfers_input: Option<Entity<InputState>>,
+    pub max_transfers_per_server_input: Option<Entity<InputState>>,
+    pub download_limit_input: Option<Entity<InputState>>,
+    pub upload_limit_input: Option<Entity<InputState>>,
     pub local_default_path_input: Option<Entity<InputState>>,
+    pub remote_trash_dir_input: Option<Entity<InputState>>,
     // 编辑器设置输入
     pub external_editor_path_input: Option<Entity<InputState>>,
     pub max_edit_file_size_input: Option<Entity<InputState>>,
@@ -118,9 +178,33 @@ pub struct SettingsDialogState {
     pub webdav_username_input: Option<Entity<InputState>>,
     pub webdav_password_input: Option<Entity<InputState>>,
     pub webdav_path_input: Option<Entity<InputState>>,
+    pub sync_passphrase_input: Option<Entity<InputState>>,
+    pub git_remote_input: Option<Entity<InputState>>,
+    pub git_branch_input: Option<Entity<InputState>>,
+    pub git_private_key_input: Option<Entity<InputState>>,
+    pub inventory_source_input: Option<Entity<InputState>>,
+    pub inventory_refresh_input: Option<Entity<InputState>>,
 
     // ============ 系统设置输入 ============
     pub log_retention_input: Option<Entity<InputState>>,
+
+    // ============ 配置导入/导出 ============
+    /// 归档口令输入
+    pub archive_password_input: Option<Entity<InputState>>,
+    /// 导出时是否包含密码与私钥
+    pub archive_include_secrets: bool,
+    /// 最近一次导入/导出的结果
+    pub archive_result: Option<Result<String, String>>,
+    /// 最近一次服务器清单导出（CSV / SSH config）的结果
+    pub inventory_result: Option<Result<String, String>>,
+    /// 最近一次注册 ssh:// 默认处理程序的结果
+    pub url_handler_result: Option<Result<String, String>>,
+    /// 导入配置后需要刷新主页数据（服务器列表、快捷命令）
+    pub needs_page_refresh: bool,
+    /// 保存设置后需要应用到已打开的会话（终端字体、回滚行数等）
+    pub needs_session_refresh: bool,
+    /// 数据同步状态（由主页创建后注入）
+    pub sync_state: Option<Entity<SyncState>>,
 }
 
 impl Default for SettingsDialogState {
@@ -129,30 +213,50 @@ impl Default for SettingsDialogState {
         Self {
             visible: false,
             current_section: SettingsSection::Theme,
+            ui_theme_dark: settings.theme.mode != ThemeMode::Light,
+            saved_settings: settings.clone(),
             settings,
             has_changes: false,
+            confirm_discard: false,
+            search_input: None,
             // 主题
             ui_font_family_input: None,
             ui_font_size_input: None,
+            ui_theme_inputs: Vec::new(),
+            ui_theme_result: None,
             // 终端
             terminal_font_family_input: None,
             terminal_font_size_input: None,
             terminal_line_height_input: None,
             scrollback_lines_input: None,
+            background_opacity_input: None,
             // 连接
             default_port_input: None,
             connection_timeout_input: None,
             keepalive_interval_input: None,
             reconnect_attempts_input: None,
             reconnect_interval_input: None,
+            hibernate_after_input: None,
+            reachability_interval_input: None,
+            db_client_mysql_input: None,
+            db_client_postgresql_input: None,
+            db_client_redis_input: None,
+            db_client_mongodb_input: None,
             // 监控
             history_retention_input: None,
             cpu_threshold_input: None,
             memory_threshold_input: None,
             disk_threshold_input: None,
+            temperature_threshold_input: None,
+            alert_consecutive_input: None,
             // SFTP
             concurrent_transfers_input: None,
+            max_active_transfers_input: None,
+            max_transfers_per_server_input: None,
+            download_limit_input: None,
+            upload_limit_input: None,
             local_default_path_input: None,
+            remote_trash_dir_input: None,
             external_editor_path_input: None,
             max_edit_file_size_input: None,
             editor_font_family_input: None,
@@ -165,8 +269,23 @@ impl Default for SettingsDialogState {
             webdav_username_input: None,
             webdav_password_input: None,
             webdav_path_input: None,
+            sync_passphrase_input: None,
+            git_remote_input: None,
+            git_branch_input: None,
+            git_private_key_input: None,
+            inventory_source_input: None,
+            inventory_refresh_input: None,
             // 系统
             log_retention_input: None,
+            // 配置导入/导出
+            archive_password_input: None,
+            archive_include_secrets: false,
+            archive_result: None,
+            inventory_result: None,
+            url_handler_result: None,
+            needs_page_refresh: false,
+            needs_session_refresh: false,
+            sync_state: None,
         }
     }
 }
@@ -175,32 +294,64 @@ impl SettingsDialogState {
     pub fn open(&mut self) {
         // 打开时重新加载设置
         self.settings = storage::load_settings().unwrap_or_default();
+        self.saved_settings = self.settings.clone();
         self.visible = true;
         self.current_section = SettingsSection::Theme;
         self.has_changes = false;
+        self.confirm_discard = false;
+        self.ui_theme_dark = self.settings.theme.mode != ThemeMode::Light;
+        self.ui_theme_result = None;
+        self.archive_result = None;
+        self.inventory_result = None;
+        self.url_handler_result = None;
         // 清除输入状态以便重新加载
         self.reset_inputs();
     }
 
+    /// 导入配置或同步拉取后重新加载设置并刷新输入框
+    pub fn reload_after_import(&mut self) {
+        self.settings = storage::load_settings().unwrap_or_default();
+        self.saved_settings = self.settings.clone();
+        self.has_changes = false;
+        self.needs_page_refresh = true;
+        self.reset_inputs();
+    }
+
     /// 重置所有输入框状态
     fn reset_inputs(&mut self) {
+        self.search_input = None;
         self.ui_font_family_input = None;
         self.ui_font_size_input = None;
+        self.ui_theme_inputs.clear();
         self.terminal_font_family_input = None;
         self.terminal_font_size_input = None;
         self.terminal_line_height_input = None;
         self.scrollback_lines_input = None;
+        self.background_opacity_input = None;
         self.default_port_input = None;
         self.connection_timeout_input = None;
         self.keepalive_interval_input = None;
         self.reconnect_attempts_input = None;
         self.reconnect_interval_input = None;
+        self.hibernate_after_input = None;
+        self.reachability_interval_input = None;
+        self.db_client_mysql_input = None;
+        self.db_client_postgresql_input = None;
+        self.db_client_redis_input = None;
+        self.db_client_mongodb_input = None;
         self.history_retention_input = None;
         self.cpu_threshold_input = None;
         self.memory_threshold_input = None;
         self.disk_threshold_input = None;
+        self.temperature_threshold_input = None;
+        self.alert_consecutive_input = None;
         self.concurrent_transfers_input = None;
+        self.max_active_transfers_input = None;
+        self.max_transfers_per_server_input = None;
+        self.download_limit_input = None;
+        self.upload_limit_input = None;
         self.local_default_path_input = None;
+        self.remote_trash_dir_input = None;
         self.external_editor_path_input = None;
         self.max_edit_file_size_input = None;
         self.editor_font_family_input = None;
@@ -212,18 +363,79 @@ impl SettingsDialogState {
         self.webdav_username_input = None;
         self.webdav_password_input = None;
         self.webdav_path_input = None;
+        self.sync_passphrase_input = None;
+        self.git_remote_input = None;
+        self.git_branch_input = None;
+        self.git_private_key_input = None;
+        self.inventory_source_input = None;
+        self.inventory_refresh_input = None;
         self.log_retention_input = None;
+        self.archive_password_input = None;
+    }
+
+    /// 打开设置并定位到指定区域（命令面板）
+    pub fn open_section(&mut self, section: SettingsSection) {
+        self.open();
+        self.current_section = section;
     }
 
     pub fn close(&mut self) {
         self.visible = false;
     }
 
+    /// 请求关闭：有未保存的变更时先显示放弃确认
+    pub fn request_close(&mut self, cx: &mut App) {
+        self.refresh_changes(cx);
+        if self.has_changes {
+            self.confirm_discard = true;
+        } else {
+            self.cancel(cx);
+        }
+    }
+
+    /// 不保存关闭：撤销界面模式与界面配色的实时预览
+    pub fn cancel(&mut self, cx: &mut App) {
+        let saved = &self.saved_settings.theme;
+        if saved.mode != self.settings.theme.mode {
+            match saved.mode {
+                ThemeMode::Light => GpuiTheme::change(GpuiThemeMode::Light, None, cx),
+                ThemeMode::Dark => GpuiTheme::change(GpuiThemeMode::Dark, None, cx),
+                ThemeMode::System => GpuiTheme::sync_system_appearance(None, cx),
+            }
+        }
+        if saved.ui_theme != self.settings.theme.ui_theme {
+            crate::theme::apply_ui_theme(&saved.ui_theme, cx);
+        }
+        search::set_highlight(None, cx);
+        self.confirm_discard = false;
+        self.close();
+    }
+
+    /// 替换界面配色并实时预览（输入框随后按新配色重新创建）
+    pub fn set_ui_theme(&mut self, ui_theme: UiTheme, cx: &mut App) {
+        crate::theme::apply_ui_theme(&ui_theme, cx);
+        self.settings.theme.ui_theme = ui_theme;
+        self.ui_theme_inputs.clear();
+        self.mark_changed();
+    }
+
     pub fn save(&mut self) {
-        if let Err(e) = storage::save_settings(&self.settings) {
-            eprintln!("保存设置失败: {}", e);
+        match storage::save_settings(&self.settings) {
+            Ok(()) => self.saved_settings = self.settings.clone(),
+            Err(e) => eprintln!("保存设置失败: {}", e),
         }
+        // 限速立即对进行中的传输生效
+        transfer::set_global_limit_kb(false, self.settings.sftp.download_limit_kb);
+        transfer::set_global_limit_kb(true, self.settings.sftp.upload_limit_kb);
         self.has_changes = false;
+        self.needs_session_refresh = true;
+    }
+
+    /// 保存并立即应用到已打开的会话（不关闭弹窗）
+    /// 终端透明/模糊对应的窗口背景由会话视图按当前标签在渲染时更新
+    pub fn apply(&mut self, cx: &mut App) {
+        self.sync_from_inputs(cx);
+        self.save();
     }
 
     /// 标记设置已变更
@@ -231,8 +443,29 @@ impl SettingsDialogState {
         self.has_changes = true;
     }
 
+    /// 从输入框同步后重新判断是否有未保存的变更
+    pub fn refresh_changes(&mut self, cx: &App) {
+        self.sync_from_inputs(cx);
+        // AppSettings 未实现 PartialEq，按序列化结果比较
+        self.has_changes = serde_json::to_value(&self.settings).ok()
+            != serde_json::to_value(&self.saved_settings).ok();
+    }
+
     /// 确保输入框已创建（在有 window 上下文时调用）
     pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        // 设置搜索
+        if self.search_input.is_none() {
+            let placeholder = i18n::t(&self.settings.theme.language, "settings.search.placeholder");
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            cx.subscribe(&input, |_, _, event: &InputEvent, cx| {
+                if let InputEvent::Change = event {
+                    cx.notify();
+                }
+            })
+            .detach();
+            self.search_input = Some(input);
+        }
+
         // 主题设置
         if self.ui_font_family_input.is_none() {
             let value = self.settings.theme.ui_font_family.clone();
@@ -246,6 +479,42 @@ impl SettingsDialogState {
             let value = self.settings.theme.ui_font_size.to_string();
             self.ui_font_size_input = Some(create_int_number_input(value, 8, 144, 1, window, cx));
         }
+        if self.ui_theme_inputs.is_empty() {
+            let palette = self.settings.theme.ui_theme.palette(self.ui_theme_dark).clone();
+            for slot in UiColorSlot::ALL {
+                let value = palette.get(slot).unwrap_or_default().to_string();
+                let input = cx.new(|cx| {
+                    let mut state = InputState::new(window, cx).placeholder("#RRGGBB");
+                    state.set_value(value, window, cx);
+                    state
+                });
+                // 输入有效颜色（或清空）时实时预览
+                cx.subscribe(&input, move |this, input, event: &InputEvent, cx| {
+                    if let InputEvent::Change = event {
+                        let value = input.read(cx).value().trim().to_string();
+                        let color = (!value.is_empty()).then_some(value);
+                        if color
+                            .as_deref()
+                            .is_some_and(|c| crate::theme::parse_hex_color(c).is_none())
+                        {
+                            return;
+                        }
+                        let dark = this.ui_theme_dark;
+                        let ui_theme = &mut this.settings.theme.ui_theme;
+                        if ui_theme.palette(dark).get(slot) == color.as_deref() {
+                            return;
+                        }
+                        // 手动修改后不再是原来的预设或导入的主题
+                        ui_theme.name.clear();
+                        ui_theme.palette_mut(dark).set(slot, color);
+                        crate::theme::apply_ui_theme(ui_theme, cx);
+                        this.mark_changed();
+                    }
+                })
+                .detach();
+                self.ui_theme_inputs.push((slot, input));
+            }
+        }
 
         // 终端设置
         if self.terminal_font_family_input.is_none() {
@@ -271,6 +540,11 @@ impl SettingsDialogState {
             self.scrollback_lines_input =
                 Some(create_int_number_input(value, 100, 100000, 100, window, cx));
         }
+        if self.background_opacity_input.is_none() {
+            let value = self.settings.terminal.background_opacity.to_string();
+            self.background_opacity_input =
+                Some(create_int_number_input(value, 20, 100, 5, window, cx));
+        }
 
         // 连接设置
         if self.default_port_input.is_none() {
@@ -297,6 +571,34 @@ impl SettingsDialogState {
             self.reconnect_interval_input =
                 Some(create_int_number_input(value, 1, 300, 1, window, cx));
         }
+        if self.hibernate_after_input.is_none() {
+            let value = self.settings.connection.hibernate_after_minutes.to_string();
+            self.hibernate_after_input =
+                Some(create_int_number_input(value, 0, 1440, 5, window, cx));
+        }
+        if self.reachability_interval_input.is_none() {
+            let value = self.settings.connection.reachability_interval_minutes.to_string();
+            self.reachability_interval_input =
+                Some(create_int_number_input(value, 1, 1440, 1, window, cx));
+        }
+        if self.db_client_mysql_input.is_none() {
+            let commands = self.settings.connection.db_client_commands.clone();
+            let placeholder = i18n::t(
+                &self.settings.theme.language,
+                "settings.connection.db_client_placeholder",
+            );
+            let mut create = |value: String| {
+                cx.new(|cx| {
+                    let mut state = InputState::new(window, cx).placeholder(placeholder);
+                    state.set_value(value, window, cx);
+                    state
+                })
+            };
+            self.db_client_mysql_input = Some(create(commands.mysql));
+            self.db_client_postgresql_input = Some(create(commands.postgresql));
+            self.db_client_redis_input = Some(create(commands.redis));
+            self.db_client_mongodb_input = Some(create(commands.mongodb));
+        }
 
         // 监控设置
         if self.history_retention_input.is_none() {
@@ -317,6 +619,20 @@ impl SettingsDialogState {
             let value = self.settings.monitor.disk_alert_threshold.to_string();
             self.disk_threshold_input = Some(create_int_number_input(value, 0, 100, 1, window, cx));
         }
+        if self.temperature_threshold_input.is_none() {
+            let value = self
+                .settings
+                .monitor
+                .temperature_alert_threshold
+                .to_string();
+            self.temperature_threshold_input =
+                Some(create_int_number_input(value, 0, 150, 1, window, cx));
+        }
+        if self.alert_consecutive_input.is_none() {
+            let value = self.settings.monitor.alert_consecutive_samples.to_string();
+            self.alert_consecutive_input =
+                Some(create_int_number_input(value, 1, 60, 1, window, cx));
+        }
 
         // SFTP 设置
         let lang = &self.settings.theme.language;
@@ -325,6 +641,28 @@ impl SettingsDialogState {
             self.concurrent_transfers_input =
                 Some(create_int_number_input(value, 1, 10, 1, window, cx));
         }
+        if self.max_active_transfers_input.is_none() {
+            let value = self.settings.sftp.max_active_transfers.to_string();
+            self.max_active_transfers_input =
+                Some(create_int_number_input(value, 1, 20, 1, window, cx));
+        }
+        if self.max_transfers_per_server_input.is_none() {
+            let value = self.settings.sftp.max_transfers_per_server.to_string();
+            self.max_transfers_per_server_input =
+                Some(create_int_number_input(value, 1, 20, 1, window, cx));
+        }
+        if self.download_limit_input.is_none() {
+            let value = self.settings.sftp.download_limit_kb.to_string();
+            self.download_limit_input = Some(create_int_number_input(
+                value, 0, 1_048_576, 128, window, cx,
+            ));
+        }
+        if self.upload_limit_input.is_none() {
+            let value = self.settings.sftp.upload_limit_kb.to_string();
+            self.upload_limit_input = Some(create_int_number_input(
+                value, 0, 1_048_576, 128, window, cx,
+            ));
+        }
         if self.local_default_path_input.is_none() {
             let value = self.settings.sftp.local_default_path.clone();
             let placeholder = i18n::t(lang, "settings.sftp.default_download_path_placeholder");
@@ -334,6 +672,14 @@ impl SettingsDialogState {
                 state
             }));
         }
+        if self.remote_trash_dir_input.is_none() {
+            let value = self.settings.sftp.remote_trash_dir.clone();
+            self.remote_trash_dir_input = Some(cx.new(|cx| {
+                let mut state = InputState::new(window, cx);
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
         // 编辑器设置
         if self.external_editor_path_input.is_none() {
             let value = self.settings.sftp.external_editor_path.clone();
@@ -415,12 +761,71 @@ impl SettingsDialogState {
                 state
             }));
         }
+        if self.sync_passphrase_input.is_none() {
+            let value = self.settings.sync.sync_passphrase.clone();
+            let placeholder = i18n::t(lang, "settings.sync.passphrase");
+            self.sync_passphrase_input = Some(cx.new(|cx| {
+                let mut state = InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .masked(true);
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
+        if self.git_remote_input.is_none() {
+            let value = self.settings.sync.git_remote.clone();
+            self.git_remote_input = Some(cx.new(|cx| {
+                let mut state =
+                    InputState::new(window, cx).placeholder("git@github.com:user/config.git");
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
+        if self.git_branch_input.is_none() {
+            let value = self.settings.sync.git_branch.clone();
+            self.git_branch_input = Some(cx.new(|cx| {
+                let mut state = InputState::new(window, cx).placeholder("main");
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
+        if self.git_private_key_input.is_none() {
+            let value = self.settings.sync.git_private_key.clone();
+            let placeholder = i18n::t(lang, "settings.sync.git_private_key_placeholder");
+            self.git_private_key_input = Some(cx.new(|cx| {
+                let mut state = InputState::new(window, cx).placeholder(placeholder);
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
+        if self.inventory_source_input.is_none() {
+            let value = self.settings.sync.inventory_source.clone();
+            let placeholder = i18n::t(lang, "settings.sync.inventory_source_placeholder");
+            self.inventory_source_input = Some(cx.new(|cx| {
+                let mut state = InputState::new(window, cx).placeholder(placeholder);
+                state.set_value(value, window, cx);
+                state
+            }));
+        }
+        if self.inventory_refresh_input.is_none() {
+            let value = self.settings.sync.inventory_refresh_minutes.to_string();
+            self.inventory_refresh_input =
+                Some(create_int_number_input(value, 1, 1440, 5, window, cx));
+        }
 
         // 系统设置
         if self.log_retention_input.is_none() {
             let value = self.settings.system.log_retention_days.to_string();
             self.log_retention_input = Some(create_int_number_input(value, 1, 365, 1, window, cx));
         }
+        if self.archive_password_input.is_none() {
+            let placeholder = i18n::t(lang, "settings.system.backup.password_placeholder");
+            self.archive_password_input = Some(cx.new(|cx| {
+                InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .masked(true)
+            }));
+        }
     }
 
     /// 从 InputState 同步值到 settings
@@ -454,6 +859,11 @@ impl SettingsDialogState {
                 self.settings.terminal.scrollback_lines = v;
             }
         }
+        if let Some(input) = &self.background_opacity_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.terminal.background_opacity = v.clamp(20, 100);
+            }
+        }
 
         // 连接
         if let Some(input) = &self.default_port_input {
@@ -481,6 +891,27 @@ impl SettingsDialogState {
                 self.settings.connection.reconnect_interval_secs = v;
             }
         }
+        if let Some(input) = &self.hibernate_after_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.connection.hibernate_after_minutes = v;
+            }
+        }
+        if let Some(input) = &self.reachability_interval_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.connection.reachability_interval_minutes = v;
+            }
+        }
+        let commands = &mut self.settings.connection.db_client_commands;
+        for (input, command) in [
+            (&self.db_client_mysql_input, &mut commands.mysql),
+            (&self.db_client_postgresql_input, &mut commands.postgresql),
+            (&self.db_client_redis_input, &mut commands.redis),
+            (&self.db_client_mongodb_input, &mut commands.mongodb),
+        ] {
+            if let Some(input) = input {
+                *command = input.read(cx).value().trim().to_string();
+            }
+        }
 
         // 监控
         if let Some(input) = &self.history_retention_input {
@@ -503,11 +934,53 @@ impl SettingsDialogState {
                 self.settings.monitor.disk_alert_threshold = v;
             }
         }
+        if let Some(input) = &self.temperature_threshold_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.monitor.temperature_alert_threshold = v;
+            }
+        }
+        if let Some(input) = &self.alert_consecutive_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.monitor.alert_consecutive_samples = v;
+            }
+        }
 
         // SFTP
+        if let Some(input) = &self.concurrent_transfers_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sftp.concurrent_transfers = v;
+            }
+        }
+        if let Some(input) = &self.max_active_transfers_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sftp.max_active_transfers = v;
+            }
+        }
+        if let Some(input) = &self.max_transfers_per_server_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sftp.max_transfers_per_server = v;
+            }
+        }
+        if let Some(input) = &self.download_limit_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sftp.download_limit_kb = v;
+            }
+        }
+        if let Some(input) = &self.upload_limit_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sftp.upload_limit_kb = v;
+            }
+        }
         if let Some(input) = &self.local_default_path_input {
             self.settings.sftp.local_default_path = input.read(cx).value().to_string();
         }
+        if let Some(input) = &self.remote_trash_dir_input {
+            let value = input.read(cx).value().trim().to_string();
+            // 根目录、主目录及其上级目录不能作为回收站（清空时会误删）
+            if crate::services::sftp::is_safe_trash_root(&value, None) {
+                self.settings.sftp.remote_trash_dir = value;
+            }
+        }
         if let Some(input) = &self.external_editor_path_input {
             self.settings.sftp.external_editor_path = input.read(cx).value().to_string();
         }
@@ -553,6 +1026,29 @@ impl SettingsDialogState {
         if let Some(input) = &self.webdav_path_input {
             self.settings.sync.webdav_path = input.read(cx).value().to_string();
         }
+        if let Some(input) = &self.sync_passphrase_input {
+            self.settings.sync.sync_passphrase = input.read(cx).value().to_string();
+        }
+        if let Some(input) = &self.git_remote_input {
+            self.settings.sync.git_remote = input.read(cx).value().to_string();
+        }
+        if let Some(input) = &self.git_branch_input {
+            let branch = input.read(cx).value().trim().to_string();
+            if !branch.is_empty() {
+                self.settings.sync.git_branch = branch;
+            }
+        }
+        if let Some(input) = &self.git_private_key_input {
+            self.settings.sync.git_private_key = input.read(cx).value().to_string();
+        }
+        if let Some(input) = &self.inventory_source_input {
+            self.settings.sync.inventory_source = input.read(cx).value().trim().to_string();
+        }
+        if let Some(input) = &self.inventory_refresh_input {
+            if let Ok(v) = input.read(cx).value().parse::<u32>() {
+                self.settings.sync.inventory_refresh_minutes = v;
+            }
+        }
 
         // 系统
         if let Some(input) = &self.log_retention_input {
@@ -586,7 +1082,10 @@ pub fn render_settings_dialog_overlay(
                 .inset_0()
                 .bg(rgba(0x00000080))
                 .on_click(move |_, _, cx| {
-                    state_for_close.update(cx, |s, _| s.close());
+                    state_for_close.update(cx, |s, cx| {
+                        s.request_close(cx);
+                        cx.notify();
+                    });
                 }),
         )
         // 弹窗内容
@@ -599,6 +1098,14 @@ fn render_dialog_content(state: Entity<SettingsDialogState>, cx: &App) -> impl I
     let state_for_cancel = state.clone();
     let state_for_save = state.clone();
 
+    // 搜索框有内容时，导航显示各分区的匹配数，内容区显示搜索结果
+    let state_read = state.read(cx);
+    let search = state_read.search_input.as_ref().and_then(|input| {
+        let query = input.read(cx).value().to_string();
+        (!query.trim().is_empty())
+            .then(|| search::search(&query, &state_read.settings.theme.language))
+    });
+
     // 使用全局主题帮助函数
     let bg_color = crate::theme::popover_color(cx);
     let border_color = cx.theme().border;
@@ -621,28 +1128,23 @@ fn render_dialog_content(state: Entity<SettingsDialogState>, cx: &App) -> impl I
         .on_scroll_wheel(|_, _, cx| {
             cx.stop_propagation();
         })
-        .child(render_left_nav(state_for_nav, cx))
+        .child(render_left_nav(state_for_nav, search.as_deref(), cx))
         .child(render_right_content(
             state,
             state_for_cancel,
             state_for_save,
+            search.as_deref(),
             cx,
         ))
 }
 
 /// 渲染左侧导航菜单
-fn render_left_nav(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
-    let sections = [
-        SettingsSection::Theme,
-        SettingsSection::Terminal,
-        SettingsSection::KeyBindings,
-        SettingsSection::Sftp,
-        SettingsSection::Monitor,
-        SettingsSection::Connection,
-        SettingsSection::Sync,
-        SettingsSection::System,
-        SettingsSection::About,
-    ];
+fn render_left_nav(
+    state: Entity<SettingsDialogState>,
+    search: Option<&[SearchMatch]>,
+    cx: &App,
+) -> impl IntoElement {
+    let sections = SettingsSection::ALL;
 
     let bg_color = crate::theme::sidebar_color(cx);
     let border_color = cx.theme().border;
@@ -658,22 +1160,29 @@ fn render_left_nav(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoEle
         .flex_col()
         .p_4()
         .gap_1()
+        .child(render_search_field(&state, cx))
         .children(sections.into_iter().map(|section| {
             let state = state.clone();
-            render_nav_item(state, section, cx)
+            let match_count =
+                search.map(|matches| matches.iter().filter(|m| m.section == section).count());
+            render_nav_item(state, section, match_count, cx)
         }))
 }
 
 /// 渲染导航项
+/// `match_count` 为搜索时该分区的匹配数（无匹配的分区淡化显示）
 fn render_nav_item(
     state: Entity<SettingsDialogState>,
     section: SettingsSection,
+    match_count: Option<usize>,
     cx: &App,
 ) -> impl IntoElement {
     let state_for_click = state.clone();
     let hover_bg = cx.theme().muted;
     let icon_color = cx.theme().muted_foreground;
     let text_color = cx.theme().foreground;
+    let badge_bg = cx.theme().primary;
+    let badge_fg = cx.theme().primary_foreground;
     let lang = &state.read(cx).settings.theme.language;
 
     div()
@@ -686,18 +1195,30 @@ fn render_nav_item(
         .items_center()
         .gap_2()
         .hover(move |s| s.bg(hover_bg))
+        .when(match_count == Some(0), |this| this.opacity(0.5))
         .on_click(move |_, _, cx| {
             state_for_click.update(cx, |s, _| {
                 s.current_section = section;
             });
+            search::set_highlight(None, cx);
         })
         .child(render_icon(section.icon(), icon_color.into()))
         .child(
             div()
+                .flex_1()
                 .text_sm()
                 .text_color(text_color)
                 .child(i18n::t(lang, section.label_key())),
         )
+        .children(match_count.filter(|count| *count > 0).map(|count| {
+            div()
+                .px_1p5()
+                .rounded_md()
+                .bg(badge_bg)
+                .text_xs()
+                .text_color(badge_fg)
+                .child(count.to_string())
+        }))
 }
 
 /// 渲染右侧内容区域
@@ -705,9 +1226,11 @@ fn render_right_content(
     state: Entity<SettingsDialogState>,
     state_for_cancel: Entity<SettingsDialogState>,
     state_for_save: Entity<SettingsDialogState>,
+    search: Option<&[SearchMatch]>,
     cx: &App,
 ) -> impl IntoElement {
     let state_for_panel = state.clone();
+    let lang = state.read(cx).settings.theme.language.clone();
     let border_color = cx.theme().border;
     let title_color = cx.theme().foreground;
 
@@ -733,10 +1256,7 @@ fn render_right_content(
                         .text_color(title_color)
                         .font_weight(FontWeight::SEMIBOLD)
                         .text_color(title_color)
-                        .child(i18n::t(
-                            &state.read(cx).settings.theme.language,
-                            "settings.title",
-                        )),
+                        .child(i18n::t(&lang, "settings.title")),
                 ),
         )
         // 内容区域
@@ -747,7 +1267,11 @@ fn render_right_content(
                 .min_h(px(0.))
                 .overflow_y_scrollbar()
                 .p_6()
-                .child(render_section_content(state_for_panel, cx)),
+                .child(match search {
+                    Some(matches) => render_search_results(state_for_panel, matches, &lang, cx)
+                        .into_any_element(),
+                    None => render_section_content(state_for_panel, cx).into_any_element(),
+                }),
         )
         // 底部按钮
         .child(render_footer_buttons(state_for_cancel, state_for_save, cx))
@@ -783,62 +1307,144 @@ fn render_footer_buttons(
     let primary_bg = cx.theme().primary;
     let primary_hover = cx.theme().primary_hover;
     let primary_fg = cx.theme().primary_foreground;
-    let lang = &state_for_cancel.read(cx).settings.theme.language;
+    let state_read = state_for_cancel.read(cx);
+    let lang = &state_read.settings.theme.language;
+    let has_changes = state_read.has_changes;
+    let confirm_discard = state_read.confirm_discard;
+    let state_for_apply = state_for_save.clone();
 
-    div()
+    // 保存按钮（放弃确认时同样可以保存后关闭）
+    let save_button = render_footer_button(
+        "settings-save-btn",
+        i18n::t(lang, "common.save"),
+        (primary_bg, primary_hover, primary_fg, primary_bg),
+        move |_, _, cx| {
+            state_for_save.update(cx, |s, cx| {
+                s.apply(cx);
+                s.close();
+            });
+            search::set_highlight(None, cx);
+        },
+    );
+
+    let footer = div()
         .h(px(64.))
         .flex_shrink_0()
         .border_t_1()
         .border_color(border_color)
         .flex()
         .items_center()
-        .justify_end()
         .gap_3()
-        .px_6()
-        // 取消按钮
+        .px_6();
+
+    // 关闭时有未保存的变更：放弃、继续编辑或保存
+    if confirm_discard {
+        let state_for_keep = state_for_cancel.clone();
+        return footer
+            .bg(cx.theme().danger.opacity(0.12))
+            .child(
+                div()
+                    .flex_1()
+                    .text_sm()
+                    .text_color(text_color)
+                    .child(i18n::t(lang, "settings.confirm_discard")),
+            )
+            .child(render_footer_button(
+                "settings-keep-editing-btn",
+                i18n::t(lang, "settings.keep_editing"),
+                (secondary_bg, secondary_hover, text_color, border_color),
+                move |_, _, cx| {
+                    state_for_keep.update(cx, |s, cx| {
+                        s.confirm_discard = false;
+                        cx.notify();
+                    });
+                },
+            ))
+            .child(render_footer_button(
+                "settings-discard-btn",
+                i18n::t(lang, "settings.discard"),
+                (
+                    cx.theme().danger,
+                    cx.theme().danger_hover,
+                    cx.theme().danger_foreground,
+                    cx.theme().danger,
+                ),
+                move |_, _, cx| {
+                    state_for_cancel.update(cx, |s, cx| s.cancel(cx));
+                },
+            ))
+            .child(save_button);
+    }
+
+    footer
+        // 未保存提示
         .child(
             div()
-                .id("settings-cancel-btn")
-                .px_4()
-                .py_2()
-                .rounded_md()
-                .border_1()
-                .border_color(border_color)
-                .bg(secondary_bg)
-                .cursor_pointer()
-                .hover(move |s| s.bg(secondary_hover))
-                .on_click(move |_, _, cx| {
-                    state_for_cancel.update(cx, |s, _| s.close());
-                })
-                .child(
-                    div()
-                        .text_sm()
-                        .text_color(text_color)
-                        .child(i18n::t(lang, "common.cancel")),
-                ),
+                .flex_1()
+                .flex()
+                .items_center()
+                .gap_2()
+                .when(has_changes, |this| {
+                    this.child(div().size(px(6.)).rounded_full().bg(cx.theme().warning))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(lang, "settings.unsaved")),
+                        )
+                }),
         )
-        // 保存按钮
+        // 取消按钮
+        .child(render_footer_button(
+            "settings-cancel-btn",
+            i18n::t(lang, "common.cancel"),
+            (secondary_bg, secondary_hover, text_color, border_color),
+            move |_, _, cx| {
+                state_for_cancel.update(cx, |s, cx| {
+                    s.request_close(cx);
+                    cx.notify();
+                });
+            },
+        ))
+        // 应用按钮：保存并立即生效，不关闭弹窗
         .child(
             div()
-                .id("settings-save-btn")
-                .px_4()
-                .py_2()
-                .rounded_md()
-                .bg(primary_bg)
-                .cursor_pointer()
-                .hover(move |s| s.bg(primary_hover))
-                .on_click(move |_, _, cx| {
-                    state_for_save.update(cx, |s, cx| {
-                        s.sync_from_inputs(cx);
-                        s.save();
-                        s.close();
-                    });
-                })
-                .child(
-                    div()
-                        .text_sm()
-                        .text_color(primary_fg)
-                        .child(i18n::t(lang, "common.save")),
-                ),
+                .when(!has_changes, |this| this.opacity(0.5))
+                .child(render_footer_button(
+                    "settings-apply-btn",
+                    i18n::t(lang, "settings.apply"),
+                    (secondary_bg, secondary_hover, text_color, border_color),
+                    move |_, _, cx| {
+                        state_for_apply.update(cx, |s, cx| {
+                            s.apply(cx);
+                            cx.notify();
+                        });
+                    },
+                )),
         )
+        // 保存按钮
+        .child(save_button)
+}
+
+/// 渲染底部按钮，`colors` 为 (背景, 悬停背景, 文字, 边框)
+fn render_footer_button(
+    id: &'static str,
+    label: &'static str,
+    colors: (Hsla, Hsla, Hsla, Hsla),
+    on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+) -> impl IntoElement {
+    let (bg, hover_bg, fg, border) = colors;
+
+    div()
+        .id(id)
+        .px_4()
+        .py_2()
+        .rounded_md()
+        .border_1()
+        .border_color(border)
+        .bg(bg)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(on_click)
+        .child(div().text_sm().text_color(fg).child(label))
 }
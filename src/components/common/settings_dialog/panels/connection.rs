@@ -2,10 +2,13 @@
 
 use gpui::prelude::*;
 use gpui::*;
+use gpui_component::ActiveTheme;
 
 use crate::i18n;
 
-use super::super::helpers::{render_number_row, render_section_title, render_switch_row};
+use super::super::helpers::{
+    render_input_row, render_number_row, render_section_title, render_switch_row,
+};
 use super::super::SettingsDialogState;
 
 /// 渲染连接设置面板
@@ -20,6 +23,14 @@ pub fn render_connection_panel(state: Entity<SettingsDialogState>, cx: &App) ->
     let keepalive_interval_input = state_read.keepalive_interval_input.clone();
     let reconnect_attempts_input = state_read.reconnect_attempts_input.clone();
     let reconnect_interval_input = state_read.reconnect_interval_input.clone();
+    let hibernate_after_input = state_read.hibernate_after_input.clone();
+    let reachability_interval_input = state_read.reachability_interval_input.clone();
+    let db_client_inputs = [
+        ("MySQL", state_read.db_client_mysql_input.clone()),
+        ("PostgreSQL", state_read.db_client_postgresql_input.clone()),
+        ("Redis", state_read.db_client_redis_input.clone()),
+        ("MongoDB", state_read.db_client_mongodb_input.clone()),
+    ];
 
     div()
         .flex()
@@ -110,4 +121,96 @@ pub fn render_connection_panel(state: Entity<SettingsDialogState>, cx: &App) ->
                         })),
                 ),
         )
+        // 标签休眠
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(render_section_title(
+                    i18n::t(lang, "settings.connection.hibernation"),
+                    cx,
+                ))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_3()
+                        .children(hibernate_after_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.connection.hibernate_after"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        .child(render_switch_row(
+                            "conn-hibernate-connected",
+                            i18n::t(lang, "settings.connection.hibernate_connected"),
+                            conn.hibernate_connected_tabs,
+                            state.clone(),
+                            |s, v| s.settings.connection.hibernate_connected_tabs = v,
+                            cx,
+                        )),
+                ),
+        )
+        // 服务器可达性
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(render_section_title(
+                    i18n::t(lang, "settings.connection.reachability"),
+                    cx,
+                ))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(i18n::t(lang, "settings.connection.reachability_hint")),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_3()
+                        .child(render_switch_row(
+                            "conn-reachability-probe",
+                            i18n::t(lang, "settings.connection.reachability_probe"),
+                            conn.reachability_probe,
+                            state.clone(),
+                            |s, v| s.settings.connection.reachability_probe = v,
+                            cx,
+                        ))
+                        .children(reachability_interval_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.connection.reachability_interval"),
+                                input,
+                                cx,
+                            )
+                        })),
+                ),
+        )
+        // 数据库隧道客户端
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(render_section_title(
+                    i18n::t(lang, "settings.connection.db_clients"),
+                    cx,
+                ))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(i18n::t(lang, "settings.connection.db_clients_hint")),
+                )
+                .child(div().flex().flex_col().gap_3().children(
+                    db_client_inputs.into_iter().filter_map(|(label, input)| {
+                        input.map(|input| render_input_row(label, &input, cx))
+                    }),
+                )),
+        )
 }
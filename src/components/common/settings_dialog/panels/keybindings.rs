@@ -17,11 +17,89 @@ struct KeyBindingItem {
 
 /// 获取全局快捷键列表
 fn get_global_keybindings() -> Vec<KeyBindingItem> {
-    vec![KeyBindingItem {
-        action: "settings.keybindings.quit",
-        shortcut_mac: "⌘Q",
-        shortcut_other: "Ctrl+Q",
-    }]
+    vec![
+        KeyBindingItem {
+            action: "settings.keybindings.quit",
+            shortcut_mac: "⌘Q",
+            shortcut_other: "Ctrl+Q",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.tab_overview",
+            shortcut_mac: "⌘⇧A",
+            shortcut_other: "Ctrl+Shift+A",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.command_palette",
+            shortcut_mac: "⌘K",
+            shortcut_other: "Ctrl+Shift+K",
+        },
+    ]
+}
+
+/// 获取导航快捷键列表
+fn get_navigation_keybindings() -> Vec<KeyBindingItem> {
+    vec![
+        KeyBindingItem {
+            action: "settings.keybindings.next_tab",
+            shortcut_mac: "⌃Tab",
+            shortcut_other: "Ctrl+Tab",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.prev_tab",
+            shortcut_mac: "⌃⇧Tab",
+            shortcut_other: "Ctrl+Shift+Tab",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.activate_tab",
+            shortcut_mac: "⌘1 … ⌘8",
+            shortcut_other: "Ctrl+1 … Ctrl+8",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.last_tab",
+            shortcut_mac: "⌘9",
+            shortcut_other: "Ctrl+9",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.next_terminal",
+            shortcut_mac: "⌘]",
+            shortcut_other: "Ctrl+Shift+]",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.prev_terminal",
+            shortcut_mac: "⌘[",
+            shortcut_other: "Ctrl+Shift+[",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.toggle_sidebar",
+            shortcut_mac: "⌘B",
+            shortcut_other: "Ctrl+Shift+B",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.focus_terminal",
+            shortcut_mac: "⌘⌥1",
+            shortcut_other: "Ctrl+Alt+1",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.focus_sftp",
+            shortcut_mac: "⌘⌥2",
+            shortcut_other: "Ctrl+Alt+2",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.focus_monitor",
+            shortcut_mac: "⌘⌥3",
+            shortcut_other: "Ctrl+Alt+3",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.navigate_back",
+            shortcut_mac: "⌘⌥←",
+            shortcut_other: "Ctrl+Alt+←",
+        },
+        KeyBindingItem {
+            action: "settings.keybindings.navigate_forward",
+            shortcut_mac: "⌘⌥→",
+            shortcut_other: "Ctrl+Alt+→",
+        },
+    ]
 }
 
 /// 获取终端快捷键列表
@@ -40,6 +118,15 @@ fn get_terminal_keybindings() -> Vec<KeyBindingItem> {
     ]
 }
 
+/// 获取 SFTP 快捷键列表
+fn get_sftp_keybindings() -> Vec<KeyBindingItem> {
+    vec![KeyBindingItem {
+        action: "settings.keybindings.rename",
+        shortcut_mac: "F2",
+        shortcut_other: "F2",
+    }]
+}
+
 /// 渲染按键绑定面板
 pub fn render_keybindings_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
     let lang = &state.read(cx).settings.theme.language;
@@ -55,7 +142,9 @@ pub fn render_keybindings_panel(state: Entity<SettingsDialogState>, cx: &App) ->
     let is_mac = false;
 
     let global_bindings = get_global_keybindings();
+    let navigation_bindings = get_navigation_keybindings();
     let terminal_bindings = get_terminal_keybindings();
+    let sftp_bindings = get_sftp_keybindings();
 
     // 渲染快捷键分组的辅助闭包
     let render_keybinding_section =
@@ -125,11 +214,21 @@ pub fn render_keybindings_panel(state: Entity<SettingsDialogState>, cx: &App) ->
             "settings.keybindings.global_title",
             global_bindings,
         ))
+        // 导航快捷键分组
+        .child(render_keybinding_section(
+            "settings.keybindings.navigation_title",
+            navigation_bindings,
+        ))
         // 终端快捷键分组
         .child(render_keybinding_section(
             "settings.keybindings.terminal_title",
             terminal_bindings,
         ))
+        // SFTP 快捷键分组
+        .child(render_keybinding_section(
+            "settings.keybindings.sftp_title",
+            sftp_bindings,
+        ))
         // 底部提示
         .child(
             div()
@@ -9,6 +9,7 @@ pub mod sync;
 pub mod system;
 pub mod terminal;
 pub mod theme;
+pub mod ui_theme;
 
 pub use about::render_about_panel;
 pub use connection::render_connection_panel;
@@ -4,8 +4,11 @@ use gpui::prelude::*;
 use gpui::*;
 
 use crate::i18n;
+use crate::models::settings::MonitorInterval;
 
-use super::super::helpers::{render_number_row, render_section_title, render_switch_row};
+use super::super::helpers::{
+    render_number_row, render_section_title, render_select_row, render_switch_row,
+};
 use super::super::SettingsDialogState;
 
 /// 渲染监控设置面板
@@ -19,6 +22,17 @@ pub fn render_monitor_panel(state: Entity<SettingsDialogState>, cx: &App) -> imp
     let cpu_threshold_input = state_read.cpu_threshold_input.clone();
     let memory_threshold_input = state_read.memory_threshold_input.clone();
     let disk_threshold_input = state_read.disk_threshold_input.clone();
+    let temperature_threshold_input = state_read.temperature_threshold_input.clone();
+    let alert_consecutive_input = state_read.alert_consecutive_input.clone();
+
+    // 刷新间隔选项
+    let interval_label = |interval: &MonitorInterval| -> SharedString {
+        i18n::t(lang, "settings.monitor.interval_secs")
+            .replace("{}", &interval.secs().to_string())
+            .into()
+    };
+    let current_interval = interval_label(&monitor.refresh_interval);
+    let interval_options = MonitorInterval::ALL.iter().map(interval_label).collect();
 
     div()
         .flex()
@@ -39,6 +53,18 @@ pub fn render_monitor_panel(state: Entity<SettingsDialogState>, cx: &App) -> imp
                         .flex()
                         .flex_col()
                         .gap_3()
+                        .child(render_select_row(
+                            "monitor-refresh-interval",
+                            i18n::t(lang, "settings.monitor.refresh_interval"),
+                            current_interval,
+                            interval_options,
+                            state.clone(),
+                            |s, index| {
+                                s.settings.monitor.refresh_interval =
+                                    MonitorInterval::ALL[index].clone()
+                            },
+                            cx,
+                        ))
                         .children(history_retention_input.as_ref().map(|input| {
                             render_number_row(
                                 i18n::t(lang, "settings.monitor.history_retention"),
@@ -102,6 +128,30 @@ pub fn render_monitor_panel(state: Entity<SettingsDialogState>, cx: &App) -> imp
                             state.clone(),
                             |s, v| s.settings.monitor.show_network = v,
                             cx,
+                        ))
+                        .child(render_switch_row(
+                            "monitor-show-gpu",
+                            i18n::t(lang, "settings.monitor.gpu"),
+                            monitor.show_gpu,
+                            state.clone(),
+                            |s, v| s.settings.monitor.show_gpu = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "monitor-show-health",
+                            i18n::t(lang, "settings.monitor.health"),
+                            monitor.show_health,
+                            state.clone(),
+                            |s, v| s.settings.monitor.show_health = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "monitor-show-logins",
+                            i18n::t(lang, "settings.monitor.logins"),
+                            monitor.show_logins,
+                            state.clone(),
+                            |s, v| s.settings.monitor.show_logins = v,
+                            cx,
                         )),
                 ),
         )
@@ -140,7 +190,29 @@ pub fn render_monitor_panel(state: Entity<SettingsDialogState>, cx: &App) -> imp
                                 input,
                                 cx,
                             )
-                        })),
+                        }))
+                        .children(temperature_threshold_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.monitor.temperature_threshold"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        .children(alert_consecutive_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.monitor.alert_consecutive"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        .child(render_switch_row(
+                            "monitor-alert-notification",
+                            i18n::t(lang, "settings.monitor.alert_notification"),
+                            monitor.alert_notification,
+                            state.clone(),
+                            |s, v| s.settings.monitor.alert_notification = v,
+                            cx,
+                        )),
                 ),
         )
 }
@@ -4,8 +4,13 @@ use gpui::prelude::*;
 use gpui::*;
 use gpui_component::button::Button;
 use gpui_component::input::Input;
+use gpui_component::menu::DropdownMenu;
+use gpui_component::ActiveTheme;
 
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
 use crate::i18n;
+use crate::models::settings::{ConflictAction, Language};
 
 use super::super::helpers::{
     render_input_row, render_number_row, render_section_title, render_switch_row,
@@ -52,6 +57,14 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             state.clone(),
                             |s, v| s.settings.sftp.folders_first = v,
                             cx,
+                        ))
+                        .child(render_switch_row(
+                            "sftp-dual-pane",
+                            i18n::t(lang, "settings.sftp.dual_pane"),
+                            sftp.dual_pane,
+                            state.clone(),
+                            |s, v| s.settings.sftp.dual_pane = v,
+                            cx,
                         )),
                 ),
         )
@@ -83,6 +96,44 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                                     )
                                 }),
                         )
+                        // 传输队列的并发限制
+                        .children(
+                            state
+                                .read(cx)
+                                .max_active_transfers_input
+                                .as_ref()
+                                .map(|input| {
+                                    render_number_row(
+                                        i18n::t(lang, "settings.sftp.max_active_transfers"),
+                                        input,
+                                        cx,
+                                    )
+                                }),
+                        )
+                        .children(state.read(cx).max_transfers_per_server_input.as_ref().map(
+                            |input| {
+                                render_number_row(
+                                    i18n::t(lang, "settings.sftp.max_transfers_per_server"),
+                                    input,
+                                    cx,
+                                )
+                            },
+                        ))
+                        // 上传/下载总限速
+                        .children(state.read(cx).download_limit_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.sftp.download_limit"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        .children(state.read(cx).upload_limit_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.sftp.upload_limit"),
+                                input,
+                                cx,
+                            )
+                        }))
                         // 默认下载路径
                         .children(
                             state
@@ -93,6 +144,7 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                                     render_path_row(
                                         i18n::t(lang, "settings.sftp.default_download_path"),
                                         i18n::t(lang, "settings.sftp.browse"),
+                                        i18n::t(lang, "settings.sftp.pick_download_path"),
                                         input,
                                         state.clone(),
                                         cx,
@@ -114,9 +166,90 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             state.clone(),
                             |s, v| s.settings.sftp.resume_transfers = v,
                             cx,
+                        ))
+                        .child(render_switch_row(
+                            "sftp-verify-checksum",
+                            i18n::t(lang, "settings.sftp.verify_checksum"),
+                            sftp.verify_checksum,
+                            state.clone(),
+                            |s, v| s.settings.sftp.verify_checksum = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "sftp-transfer-compression",
+                            i18n::t(lang, "settings.sftp.transfer_compression"),
+                            sftp.transfer_compression,
+                            state.clone(),
+                            |s, v| s.settings.sftp.transfer_compression = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "sftp-auto-extract",
+                            i18n::t(lang, "settings.sftp.auto_extract"),
+                            sftp.auto_extract_downloads,
+                            state.clone(),
+                            |s, v| s.settings.sftp.auto_extract_downloads = v,
+                            cx,
+                        ))
+                        // 文件已存在时的默认处理方式
+                        .child(render_conflict_action_row(
+                            i18n::t(lang, "settings.sftp.conflict_action"),
+                            lang.clone(),
+                            &sftp.conflict_action,
+                            state.clone(),
+                            cx,
                         )),
                 ),
         )
+        // 删除
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(render_section_title(
+                    i18n::t(lang, "settings.sftp.delete"),
+                    cx,
+                ))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(render_switch_row(
+                            "sftp-use-remote-trash",
+                            i18n::t(lang, "settings.sftp.use_remote_trash"),
+                            sftp.use_remote_trash,
+                            state.clone(),
+                            |s, v| s.settings.sftp.use_remote_trash = v,
+                            cx,
+                        ))
+                        .children(state.read(cx).remote_trash_dir_input.as_ref().map(|input| {
+                            render_input_row(
+                                i18n::t(lang, "settings.sftp.remote_trash_dir"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        // 回收站目录不安全时提示（保存时会被忽略）
+                        .children(
+                            state
+                                .read(cx)
+                                .remote_trash_dir_input
+                                .as_ref()
+                                .filter(|input| {
+                                    let value = input.read(cx).value();
+                                    !crate::services::sftp::is_safe_trash_root(value.trim(), None)
+                                })
+                                .map(|_| {
+                                    div().text_xs().text_color(cx.theme().danger).child(i18n::t(
+                                        lang,
+                                        "settings.sftp.remote_trash_dir_invalid",
+                                    ))
+                                }),
+                        ),
+                ),
+        )
         // 编辑器 - 基本设置
         .child(
             div()
@@ -150,6 +283,7 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                                     render_file_path_row(
                                         i18n::t(lang, "settings.sftp.external_editor_path"),
                                         i18n::t(lang, "settings.sftp.browse"),
+                                        i18n::t(lang, "settings.sftp.pick_editor"),
                                         input,
                                         state.clone(),
                                         cx,
@@ -251,10 +385,103 @@ pub fn render_sftp_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
         )
 }
 
+/// 冲突处理方式的可选项
+const CONFLICT_ACTIONS: [ConflictAction; 5] = [
+    ConflictAction::Ask,
+    ConflictAction::Overwrite,
+    ConflictAction::OverwriteIfNewer,
+    ConflictAction::Rename,
+    ConflictAction::Skip,
+];
+
+/// 冲突处理方式的显示文本
+fn conflict_action_label(lang: &Language, action: &ConflictAction) -> &'static str {
+    let key = match action {
+        ConflictAction::Ask => "settings.sftp.conflict.ask",
+        ConflictAction::Overwrite => "settings.sftp.conflict.overwrite",
+        ConflictAction::OverwriteIfNewer => "settings.sftp.conflict.overwrite_if_newer",
+        ConflictAction::Rename => "settings.sftp.conflict.rename",
+        ConflictAction::Skip => "settings.sftp.conflict.skip",
+    };
+    i18n::t(lang, key)
+}
+
+/// 渲染冲突处理方式选择行
+fn render_conflict_action_row(
+    label: &'static str,
+    lang: Language,
+    current: &ConflictAction,
+    state: Entity<SettingsDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    use gpui::Corner;
+    use gpui_component::menu::PopupMenuItem;
+
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .py_3()
+        .px_4()
+        .bg(cx.theme().muted)
+        .rounded_lg()
+        .mb_2()
+        .child(
+            div()
+                .w(px(150.))
+                .text_sm()
+                .text_color(cx.theme().foreground)
+                .child(label),
+        )
+        .child(
+            Button::new("sftp-conflict-action-dropdown")
+                .w(px(200.))
+                .h(px(32.))
+                .outline()
+                .justify_start()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .w(px(180.))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(conflict_action_label(&lang, current)),
+                        )
+                        .child(render_icon(
+                            icons::CHEVRON_DOWN,
+                            cx.theme().muted_foreground.into(),
+                        )),
+                )
+                .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+                    CONFLICT_ACTIONS
+                        .iter()
+                        .fold(menu.min_w(px(200.)), |menu, action| {
+                            let state = state.clone();
+                            let action = action.clone();
+                            menu.item(
+                                PopupMenuItem::new(conflict_action_label(&lang, &action)).on_click(
+                                    move |_, _, cx| {
+                                        state.update(cx, |s, _| {
+                                            s.settings.sftp.conflict_action = action.clone();
+                                            s.mark_changed();
+                                        });
+                                    },
+                                ),
+                            )
+                        })
+                }),
+        )
+}
+
 /// 渲染带浏览按钮的路径输入行
 fn render_path_row(
     label: &'static str,
     browse_label: &'static str,
+    picker_title: &'static str,
     input: &Entity<gpui_component::input::InputState>,
     state: Entity<SettingsDialogState>,
     cx: &App,
@@ -297,7 +524,7 @@ fn render_path_row(
                             // 使用异步文件对话框选择路径
                             cx.spawn(async move |cx| {
                                 let folder_picker =
-                                    rfd::AsyncFileDialog::new().set_title("选择默认下载路径");
+                                    rfd::AsyncFileDialog::new().set_title(picker_title);
 
                                 if let Some(folder) = folder_picker.pick_folder().await {
                                     let path = folder.path().to_string_lossy().to_string();
@@ -325,6 +552,7 @@ fn render_path_row(
 fn render_file_path_row(
     label: &'static str,
     browse_label: &'static str,
+    picker_title: &'static str,
     input: &Entity<gpui_component::input::InputState>,
     state: Entity<SettingsDialogState>,
     cx: &App,
@@ -367,7 +595,7 @@ fn render_file_path_row(
                             // 使用异步文件对话框选择文件（而非文件夹）
                             cx.spawn(async move |cx| {
                                 let file_picker =
-                                    rfd::AsyncFileDialog::new().set_title("选择编辑器程序");
+                                    rfd::AsyncFileDialog::new().set_title(picker_title);
 
                                 if let Some(file) = file_picker.pick_file().await {
                                     let path = file.path().to_string_lossy().to_string();
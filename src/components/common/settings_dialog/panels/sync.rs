@@ -2,11 +2,19 @@
 
 use gpui::prelude::*;
 use gpui::*;
-use gpui_component::button::Button;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable};
 
+use crate::components::common::sync_conflict_dialog::SyncState;
 use crate::i18n;
+use crate::models::settings::{ConflictStrategy, SyncInterval, SyncMethod, SyncSettings};
+use crate::services::inventory::{InventoryService, SharedInventory};
+use crate::services::storage;
+use crate::services::sync::GitCommit;
 
-use super::super::helpers::{render_input_row, render_section_title, render_switch_row};
+use super::super::helpers::{
+    render_input_row, render_number_row, render_section_title, render_select_row, render_switch_row,
+};
 use super::super::SettingsDialogState;
 
 /// 渲染数据同步面板
@@ -20,6 +28,18 @@ pub fn render_sync_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
     let webdav_username_input = state_read.webdav_username_input.clone();
     let webdav_password_input = state_read.webdav_password_input.clone();
     let webdav_path_input = state_read.webdav_path_input.clone();
+    let sync_passphrase_input = state_read.sync_passphrase_input.clone();
+    let git_remote_input = state_read.git_remote_input.clone();
+    let git_branch_input = state_read.git_branch_input.clone();
+    let git_private_key_input = state_read.git_private_key_input.clone();
+    let is_git = sync.method == SyncMethod::Git;
+
+    // 同步运行状态
+    let sync_state = state_read.sync_state.as_ref().map(|s| s.read(cx));
+    let running = sync_state.is_some_and(|s| s.running);
+    let status = sync_state.and_then(|s| s.status.clone());
+    let git_history = sync_state.and_then(|s| s.git_history.clone());
+    let last_synced = crate::services::sync::last_synced_at();
 
     div()
         .flex()
@@ -48,6 +68,18 @@ pub fn render_sync_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             |s, v| s.settings.sync.enabled = v,
                             cx,
                         ))
+                        .child(render_select_row(
+                            "sync-method",
+                            i18n::t(lang, "settings.sync.method"),
+                            i18n::t(lang, sync.method.label_key()).into(),
+                            SyncMethod::ALL
+                                .iter()
+                                .map(|m| i18n::t(lang, m.label_key()).into())
+                                .collect(),
+                            state.clone(),
+                            |s, index| s.settings.sync.method = SyncMethod::ALL[index].clone(),
+                            cx,
+                        ))
                         .child(render_switch_row(
                             "sync-auto",
                             i18n::t(lang, "settings.sync.auto"),
@@ -55,6 +87,43 @@ pub fn render_sync_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             state.clone(),
                             |s, v| s.settings.sync.auto_sync = v,
                             cx,
+                        ))
+                        .child(render_select_row(
+                            "sync-interval",
+                            i18n::t(lang, "settings.sync.interval"),
+                            i18n::t(lang, sync.sync_interval.label_key()).into(),
+                            SyncInterval::ALL
+                                .iter()
+                                .map(|i| i18n::t(lang, i.label_key()).into())
+                                .collect(),
+                            state.clone(),
+                            |s, index| {
+                                s.settings.sync.sync_interval = SyncInterval::ALL[index].clone()
+                            },
+                            cx,
+                        ))
+                        .child(render_select_row(
+                            "sync-conflict-strategy",
+                            i18n::t(lang, "settings.sync.conflict_strategy"),
+                            i18n::t(lang, sync.conflict_strategy.label_key()).into(),
+                            ConflictStrategy::ALL
+                                .iter()
+                                .map(|c| i18n::t(lang, c.label_key()).into())
+                                .collect(),
+                            state.clone(),
+                            |s, index| {
+                                s.settings.sync.conflict_strategy =
+                                    ConflictStrategy::ALL[index].clone()
+                            },
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "sync-backup",
+                            i18n::t(lang, "settings.sync.backup_before_sync"),
+                            sync.backup_before_sync,
+                            state.clone(),
+                            |s, v| s.settings.sync.backup_before_sync = v,
+                            cx,
                         )),
                 ),
         )
@@ -104,45 +173,113 @@ pub fn render_sync_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             state.clone(),
                             |s, v| s.settings.sync.sync_keybindings = v,
                             cx,
+                        ))
+                        .child(render_switch_row(
+                            "sync-keys",
+                            i18n::t(lang, "settings.sync.keys"),
+                            sync.sync_keys,
+                            state.clone(),
+                            |s, v| s.settings.sync.sync_keys = v,
+                            cx,
                         )),
                 ),
         )
-        // WebDAV
+        // WebDAV / Git 仓库
         .child(
             div()
                 .flex()
                 .flex_col()
                 .gap_3()
                 .child(render_section_title(
-                    i18n::t(lang, "settings.sync.webdav"),
+                    i18n::t(
+                        lang,
+                        if is_git {
+                            "settings.sync.git"
+                        } else {
+                            "settings.sync.webdav"
+                        },
+                    ),
                     cx,
                 ))
-                .child(
-                    div()
-                        .flex()
-                        .flex_col()
-                        .gap_2()
-                        .children(webdav_url_input.as_ref().map(|input| {
-                            render_input_row(i18n::t(lang, "settings.sync.webdav_url"), input, cx)
-                        }))
-                        .children(webdav_username_input.as_ref().map(|input| {
-                            render_input_row(
-                                i18n::t(lang, "settings.sync.webdav_username"),
-                                input,
-                                cx,
-                            )
-                        }))
-                        .children(webdav_password_input.as_ref().map(|input| {
-                            render_input_row(
-                                i18n::t(lang, "settings.sync.webdav_password"),
-                                input,
-                                cx,
-                            )
-                        }))
-                        .children(webdav_path_input.as_ref().map(|input| {
-                            render_input_row(i18n::t(lang, "settings.sync.webdav_path"), input, cx)
-                        })),
-                )
+                .when(is_git, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .children(git_remote_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.git_remote"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .children(git_branch_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.git_branch"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .children(git_private_key_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.git_private_key"),
+                                    input,
+                                    cx,
+                                )
+                            })),
+                    )
+                })
+                .when(!is_git, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_2()
+                            .children(webdav_url_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.webdav_url"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .children(webdav_username_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.webdav_username"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .children(webdav_password_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.webdav_password"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .children(webdav_path_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.webdav_path"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            // 同步口令：只用于本地加密，不会发送到 WebDAV 服务器
+                            .children(sync_passphrase_input.as_ref().map(|input| {
+                                render_input_row(
+                                    i18n::t(lang, "settings.sync.passphrase"),
+                                    input,
+                                    cx,
+                                )
+                            }))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(i18n::t(lang, "settings.sync.passphrase_hint")),
+                            ),
+                    )
+                })
                 // 测试和同步按钮
                 .child(
                     div()
@@ -153,23 +290,290 @@ pub fn render_sync_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl I
                             Button::new("test-webdav")
                                 .outline()
                                 .child(i18n::t(lang, "settings.sync.test_connection"))
+                                .disabled(running)
                                 .on_click({
-                                    let _state = state.clone();
-                                    move |_event, _window, _cx| {
-                                        // TODO: 实现测试连接功能
+                                    let state = state.clone();
+                                    move |_event, _window, cx| {
+                                        let Some((sync_state, settings)) =
+                                            current_sync_settings(&state, cx)
+                                        else {
+                                            return;
+                                        };
+                                        sync_state
+                                            .update(cx, |s, cx| s.test_connection(settings, cx));
                                     }
                                 }),
                         )
                         .child(
                             Button::new("sync-now")
                                 .child(i18n::t(lang, "settings.sync.sync_now"))
+                                .disabled(running)
                                 .on_click({
-                                    let _state = state.clone();
-                                    move |_event, _window, _cx| {
-                                        // TODO: 实现同步功能
+                                    let state = state.clone();
+                                    move |_event, _window, cx| {
+                                        let Some((sync_state, settings)) =
+                                            current_sync_settings(&state, cx)
+                                        else {
+                                            return;
+                                        };
+                                        sync_state.update(cx, |s, cx| s.sync_now(settings, cx));
                                     }
                                 }),
                         ),
+                )
+                // 同步状态与结果
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .text_sm()
+                        .child(
+                            div()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(if running {
+                                    i18n::t(lang, "settings.sync.running").to_string()
+                                } else {
+                                    format!(
+                                        "{} {}",
+                                        i18n::t(lang, "settings.sync.last_synced"),
+                                        last_synced.unwrap_or_else(|| {
+                                            i18n::t(lang, "settings.sync.never").to_string()
+                                        })
+                                    )
+                                }),
+                        )
+                        .children(status.map(|status| match status {
+                            Ok(text) => div().text_color(cx.theme().success).child(text),
+                            Err(text) => div().text_color(cx.theme().danger).child(text),
+                        })),
                 ),
         )
+        // Git 历史版本
+        .when(is_git, |this| {
+            this.child(render_git_history(state.clone(), git_history, running, cx))
+        })
+        // 团队共享服务器清单
+        .child(render_inventory_section(state.clone(), cx))
+}
+
+/// 渲染团队共享服务器清单设置：地址、刷新间隔、默认身份与刷新状态
+fn render_inventory_section(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let state_read = state.read(cx);
+    let sync = &state_read.settings.sync;
+    let lang = &state_read.settings.theme.language;
+    let source_input = state_read.inventory_source_input.clone();
+    let refresh_input = state_read.inventory_refresh_input.clone();
+
+    // 默认身份：第一项为“不指定”
+    let identities = storage::load_servers().unwrap_or_default().identities;
+    let current_identity = sync
+        .inventory_identity_id
+        .as_deref()
+        .and_then(|id| identities.iter().find(|i| i.id == id))
+        .map(|i| SharedString::from(i.name.clone()))
+        .unwrap_or_else(|| i18n::t(lang, "settings.sync.inventory_identity_none").into());
+    let identity_options: Vec<SharedString> =
+        std::iter::once(i18n::t(lang, "settings.sync.inventory_identity_none").into())
+            .chain(identities.iter().map(|i| i.name.clone().into()))
+            .collect();
+
+    let running = cx.global::<InventoryService>().running();
+    let status = SharedInventory::global().status();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(lang, "settings.sync.inventory"),
+            cx,
+        ))
+        .child(
+            div()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(lang, "settings.sync.inventory_hint")),
+        )
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .children(source_input.as_ref().map(|input| {
+                    render_input_row(i18n::t(lang, "settings.sync.inventory_source"), input, cx)
+                }))
+                .children(refresh_input.as_ref().map(|input| {
+                    render_number_row(i18n::t(lang, "settings.sync.inventory_refresh"), input, cx)
+                }))
+                .child(render_select_row(
+                    "sync-inventory-identity",
+                    i18n::t(lang, "settings.sync.inventory_identity"),
+                    current_identity,
+                    identity_options,
+                    state.clone(),
+                    |s, index| {
+                        s.settings.sync.inventory_identity_id =
+                            index.checked_sub(1).and_then(|i| {
+                                storage::load_servers()
+                                    .unwrap_or_default()
+                                    .identities
+                                    .get(i)
+                                    .map(|identity| identity.id.clone())
+                            });
+                    },
+                    cx,
+                )),
+        )
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .child(
+                    Button::new("sync-inventory-refresh")
+                        .outline()
+                        .child(i18n::t(lang, "settings.sync.inventory_refresh_now"))
+                        .disabled(running)
+                        .on_click(move |_event, _window, cx| {
+                            let settings = state.update(cx, |s, cx| {
+                                s.sync_from_inputs(cx);
+                                s.settings.sync.clone()
+                            });
+                            InventoryService::refresh(&settings, cx);
+                        }),
+                )
+                .child(div().text_sm().children(if running {
+                    Some(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(lang, "settings.sync.inventory_loading")),
+                    )
+                } else {
+                    status.map(|status| match status {
+                        Ok(count) => div().text_color(cx.theme().success).child(
+                            i18n::t(lang, "settings.sync.inventory_loaded")
+                                .replace("{}", &count.to_string()),
+                        ),
+                        Err(e) => div().text_color(cx.theme().danger).child(e),
+                    })
+                })),
+        )
+}
+
+/// 渲染 Git 仓库中服务器列表的历史版本，每个版本可一键恢复
+fn render_git_history(
+    state: Entity<SettingsDialogState>,
+    history: Option<Vec<GitCommit>>,
+    running: bool,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = &state.read(cx).settings.theme.language;
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(lang, "settings.sync.history"),
+            cx,
+        ))
+        .child(
+            div().flex().child(
+                Button::new("sync-git-history")
+                    .outline()
+                    .child(i18n::t(lang, "settings.sync.history.load"))
+                    .disabled(running)
+                    .on_click({
+                        let state = state.clone();
+                        move |_event, _window, cx| {
+                            let Some((sync_state, settings)) = current_sync_settings(&state, cx)
+                            else {
+                                return;
+                            };
+                            sync_state.update(cx, |s, cx| s.load_history(settings, cx));
+                        }
+                    }),
+            ),
+        )
+        .children(history.map(|commits| {
+            if commits.is_empty() {
+                return div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(i18n::t(lang, "settings.sync.history.empty"));
+            }
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(commits.into_iter().enumerate().map(|(index, commit)| {
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .py_2()
+                        .px_4()
+                        .bg(cx.theme().muted)
+                        .rounded_lg()
+                        .child(
+                            div()
+                                .text_xs()
+                                .font_family("monospace")
+                                .text_color(cx.theme().muted_foreground)
+                                .child(commit.id.chars().take(8).collect::<String>()),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(commit.time.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .whitespace_nowrap()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(commit.message.clone()),
+                        )
+                        .child(
+                            Button::new(("sync-git-restore", index))
+                                .xsmall()
+                                .ghost()
+                                .child(i18n::t(lang, "settings.sync.history.restore"))
+                                .disabled(running)
+                                .on_click({
+                                    let state = state.clone();
+                                    let commit_id = commit.id.clone();
+                                    move |_event, _window, cx| {
+                                        let Some((sync_state, settings)) =
+                                            current_sync_settings(&state, cx)
+                                        else {
+                                            return;
+                                        };
+                                        let commit_id = commit_id.clone();
+                                        sync_state.update(cx, |s, cx| {
+                                            s.restore_version(settings, commit_id, cx)
+                                        });
+                                    }
+                                }),
+                        )
+                }))
+        }))
+}
+
+/// 读取弹窗中当前（可能未保存）的同步设置
+fn current_sync_settings(
+    state: &Entity<SettingsDialogState>,
+    cx: &mut App,
+) -> Option<(Entity<SyncState>, SyncSettings)> {
+    state.update(cx, |s, cx| {
+        s.sync_from_inputs(cx);
+        s.sync_state
+            .clone()
+            .map(|sync_state| (sync_state, s.settings.sync.clone()))
+    })
 }
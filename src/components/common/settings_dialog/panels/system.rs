@@ -2,10 +2,18 @@
 
 use gpui::prelude::*;
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::switch::Switch;
+use gpui_component::ActiveTheme;
 
 use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::server_export::{self, ExportFormat};
+use crate::services::{config_archive, storage, url_handler};
 
-use super::super::helpers::{render_number_row, render_section_title, render_switch_row};
+use super::super::helpers::{
+    render_input_row, render_number_row, render_section_title, render_switch_row,
+};
 use super::super::SettingsDialogState;
 
 /// 渲染系统配置面板
@@ -52,6 +60,14 @@ pub fn render_system_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl
                             |s, v| s.settings.system.start_minimized = v,
                             cx,
                         ))
+                        .child(render_switch_row(
+                            "sys-restore-sessions",
+                            i18n::t(lang, "settings.system.restore_sessions"),
+                            system.restore_sessions,
+                            state.clone(),
+                            |s, v| s.settings.system.restore_sessions = v,
+                            cx,
+                        ))
                         .child(render_switch_row(
                             "sys-check-updates",
                             i18n::t(lang, "settings.system.check_updates"),
@@ -133,6 +149,22 @@ pub fn render_system_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl
                             state.clone(),
                             |s, v| s.settings.system.notify_on_transfer = v,
                             cx,
+                        ))
+                        .child(render_switch_row(
+                            "sys-system-notifications",
+                            i18n::t(lang, "settings.system.system_notifications"),
+                            system.system_notifications,
+                            state.clone(),
+                            |s, v| s.settings.system.system_notifications = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "sys-do-not-disturb",
+                            i18n::t(lang, "settings.system.do_not_disturb"),
+                            system.do_not_disturb,
+                            state.clone(),
+                            |s, v| s.settings.system.do_not_disturb = v,
+                            cx,
                         )),
                 ),
         )
@@ -168,4 +200,368 @@ pub fn render_system_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl
                         })),
                 ),
         )
+        // 配置备份
+        .child(render_backup_section(state.clone(), cx))
+        // 服务器清单导出
+        .child(render_inventory_section(state.clone(), cx))
+        // ssh:// 链接处理程序
+        .child(render_url_handler_section(state.clone(), cx))
+}
+
+/// 渲染 ssh:// 链接处理程序区域：注册后点击 ssh:// 链接会在 ShellMaster 中打开连接
+fn render_url_handler_section(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let state_read = state.read(cx);
+    let lang = state_read.settings.theme.language.clone();
+    let result = state_read.url_handler_result.clone();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(&lang, "settings.system.url_handler"),
+            cx,
+        ))
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(&lang, "settings.system.url_handler.hint")),
+        )
+        .child(
+            div().flex().child(
+                Button::new("sys-register-ssh-handler")
+                    .outline()
+                    .child(i18n::t(&lang, "settings.system.url_handler.register"))
+                    .on_click(move |_, _, cx| {
+                        let result = url_handler::register_ssh_handler()
+                            .map(|_| {
+                                i18n::t(&lang, "settings.system.url_handler.registered").to_string()
+                            })
+                            .map_err(|e| format!("{:#}", e));
+                        state.update(cx, |s, cx| {
+                            s.url_handler_result = Some(result);
+                            cx.notify();
+                        });
+                    }),
+            ),
+        )
+        .children(result.map(|result| {
+            let (text, color) = match result {
+                Ok(text) => (text, cx.theme().success),
+                Err(text) => (text, cx.theme().danger),
+            };
+            div().text_sm().text_color(color).child(text)
+        }))
+}
+
+/// 渲染服务器清单导出区域（CSV / OpenSSH config，不含密码）
+fn render_inventory_section(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let state_read = state.read(cx);
+    let lang = state_read.settings.theme.language.clone();
+    let result = state_read.inventory_result.clone();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(&lang, "settings.system.inventory"),
+            cx,
+        ))
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(&lang, "settings.system.inventory.hint")),
+        )
+        .child(
+            div()
+                .flex()
+                .gap_3()
+                .child(
+                    Button::new("sys-export-csv")
+                        .outline()
+                        .child(i18n::t(&lang, "settings.system.inventory.export_csv"))
+                        .on_click({
+                            let state = state.clone();
+                            let lang = lang.clone();
+                            move |_, _, cx| {
+                                export_inventory(state.clone(), ExportFormat::Csv, &lang, cx)
+                            }
+                        }),
+                )
+                .child(
+                    Button::new("sys-export-ssh-config")
+                        .outline()
+                        .child(i18n::t(
+                            &lang,
+                            "settings.system.inventory.export_ssh_config",
+                        ))
+                        .on_click({
+                            let state = state.clone();
+                            let lang = lang.clone();
+                            move |_, _, cx| {
+                                export_inventory(state.clone(), ExportFormat::SshConfig, &lang, cx)
+                            }
+                        }),
+                ),
+        )
+        .children(result.map(|result| {
+            let (text, color) = match result {
+                Ok(text) => (text, cx.theme().success),
+                Err(text) => (text, cx.theme().danger),
+            };
+            div().text_sm().text_color(color).child(text)
+        }))
+}
+
+/// 渲染配置导入/导出区域
+fn render_backup_section(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let state_read = state.read(cx);
+    let lang = state_read.settings.theme.language.clone();
+    let include_secrets = state_read.archive_include_secrets;
+    let password_input = state_read.archive_password_input.clone();
+    let result = state_read.archive_result.clone();
+    let backups_dir = storage::get_backups_dir()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(&lang, "settings.system.backup"),
+            cx,
+        ))
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .py_3()
+                        .px_4()
+                        .bg(cx.theme().muted)
+                        .rounded_lg()
+                        .mb_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(i18n::t(&lang, "settings.system.backup.include_secrets")),
+                        )
+                        // 仅影响导出内容，不属于设置项，不标记变更
+                        .child(
+                            Switch::new("sys-backup-secrets")
+                                .checked(include_secrets)
+                                .on_click({
+                                    let state = state.clone();
+                                    move |new_val, _, cx| {
+                                        state.update(cx, |s, cx| {
+                                            s.archive_include_secrets = *new_val;
+                                            cx.notify();
+                                        });
+                                    }
+                                }),
+                        ),
+                )
+                .children(password_input.as_ref().map(|input| {
+                    render_input_row(i18n::t(&lang, "settings.system.backup.password"), input, cx)
+                }))
+                .child(
+                    div()
+                        .flex()
+                        .gap_3()
+                        .child(
+                            Button::new("sys-export-config")
+                                .outline()
+                                .child(i18n::t(&lang, "settings.system.backup.export"))
+                                .on_click({
+                                    let state = state.clone();
+                                    let lang = lang.clone();
+                                    move |_, _, cx| export_config(state.clone(), &lang, cx)
+                                }),
+                        )
+                        .child(
+                            Button::new("sys-import-config")
+                                .outline()
+                                .child(i18n::t(&lang, "settings.system.backup.import"))
+                                .on_click({
+                                    let state = state.clone();
+                                    let lang = lang.clone();
+                                    move |_, _, cx| import_config(state.clone(), &lang, cx)
+                                }),
+                        ),
+                )
+                .children(result.map(|result| {
+                    let (text, color) = match result {
+                        Ok(text) => (text, cx.theme().success),
+                        Err(text) => (text, cx.theme().danger),
+                    };
+                    div().text_sm().text_color(color).child(text)
+                }))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!(
+                            "{} {}",
+                            i18n::t(&lang, "settings.system.backup.auto_backup_hint"),
+                            backups_dir
+                        )),
+                ),
+        )
+}
+
+/// 读取归档口令，为空时直接提示
+fn archive_password(
+    state: &Entity<SettingsDialogState>,
+    lang: &Language,
+    cx: &mut App,
+) -> Option<String> {
+    let password = state
+        .read(cx)
+        .archive_password_input
+        .as_ref()
+        .map(|input| input.read(cx).value().to_string())
+        .unwrap_or_default();
+    if password.is_empty() {
+        state.update(cx, |s, cx| {
+            s.archive_result = Some(Err(i18n::t(
+                lang,
+                "settings.system.backup.password_required",
+            )
+            .to_string()));
+            cx.notify();
+        });
+        return None;
+    }
+    Some(password)
+}
+
+/// 选择保存位置并导出加密配置
+fn export_config(state: Entity<SettingsDialogState>, lang: &Language, cx: &mut App) {
+    let Some(password) = archive_password(&state, lang, cx) else {
+        return;
+    };
+    let include_secrets = state.read(cx).archive_include_secrets;
+    let title = i18n::t(lang, "settings.system.backup.export");
+    let done = i18n::t(lang, "settings.system.backup.exported");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .set_file_name(config_archive::default_file_name())
+            .add_filter("ShellMaster", &["smconf"])
+            .save_file()
+            .await
+        else {
+            return;
+        };
+        let result = config_archive::export_config(file.path(), &password, include_secrets)
+            .map(|_| format!("{} {}", done, file.path().display()))
+            .map_err(|e| e.to_string());
+        let _ = state.update(cx, |s, cx| {
+            s.archive_result = Some(result);
+            cx.notify();
+        });
+    })
+    .detach();
+}
+
+/// 选择归档文件并导入配置（写入前自动备份本地配置）
+fn import_config(state: Entity<SettingsDialogState>, lang: &Language, cx: &mut App) {
+    let Some(password) = archive_password(&state, lang, cx) else {
+        return;
+    };
+    let title = i18n::t(lang, "settings.system.backup.import");
+    let done = i18n::t(lang, "settings.system.backup.imported");
+    let servers_label = i18n::t(lang, "settings.system.backup.servers");
+    let groups_label = i18n::t(lang, "settings.system.backup.groups");
+    let snippets_label = i18n::t(lang, "settings.system.backup.snippets");
+    let no_secrets_note = i18n::t(lang, "settings.system.backup.no_secrets_note");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .add_filter("ShellMaster", &["smconf"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+        let result = config_archive::import_config(file.path(), &password);
+        let _ = state.update(cx, |s, cx| {
+            match result {
+                Ok(summary) => {
+                    s.reload_after_import();
+                    crate::theme::apply_ui_theme(&s.settings.theme.ui_theme, cx);
+                    let mut text = format!(
+                        "{} · {} {} · {} {} · {} {}",
+                        done,
+                        servers_label,
+                        summary.servers,
+                        groups_label,
+                        summary.groups,
+                        snippets_label,
+                        summary.snippets
+                    );
+                    if !summary.includes_secrets {
+                        text.push_str(" · ");
+                        text.push_str(no_secrets_note);
+                    }
+                    s.archive_result = Some(Ok(text));
+                }
+                Err(e) => s.archive_result = Some(Err(e.to_string())),
+            }
+            cx.notify();
+        });
+    })
+    .detach();
+}
+
+/// 选择保存位置并导出服务器清单
+fn export_inventory(
+    state: Entity<SettingsDialogState>,
+    format: ExportFormat,
+    lang: &Language,
+    cx: &mut App,
+) {
+    let title = match format {
+        ExportFormat::Csv => i18n::t(lang, "settings.system.inventory.export_csv"),
+        ExportFormat::SshConfig => i18n::t(lang, "settings.system.inventory.export_ssh_config"),
+    };
+    let done = i18n::t(lang, "settings.system.inventory.exported");
+
+    cx.spawn(async move |cx| {
+        let mut dialog = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .set_file_name(format.default_file_name());
+        if format == ExportFormat::Csv {
+            dialog = dialog.add_filter("CSV", &["csv"]);
+        }
+        let Some(file) = dialog.save_file().await else {
+            return;
+        };
+        let result = server_export::export_servers(file.path(), format)
+            .map(|count| {
+                format!(
+                    "{} {}",
+                    done.replace("{}", &count.to_string()),
+                    file.path().display()
+                )
+            })
+            .map_err(|e| e.to_string());
+        let _ = state.update(cx, |s, cx| {
+            s.inventory_result = Some(result);
+            cx.notify();
+        });
+    })
+    .detach();
 }
@@ -28,6 +28,7 @@ pub fn render_terminal_panel(state: Entity<SettingsDialogState>, cx: &App) -> im
     let font_size_input = state_read.terminal_font_size_input.clone();
     let line_height_input = state_read.terminal_line_height_input.clone();
     let scrollback_input = state_read.scrollback_lines_input.clone();
+    let opacity_input = state_read.background_opacity_input.clone();
 
     // 光标样式选项
     let cursor_style = terminal.cursor_style.clone();
@@ -151,6 +152,46 @@ pub fn render_terminal_panel(state: Entity<SettingsDialogState>, cx: &App) -> im
                         })),
                 ),
         )
+        // 透明度与模糊
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .child(render_section_title(
+                    i18n::t(lang, "settings.terminal.transparency"),
+                    cx,
+                ))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_3()
+                        .children(opacity_input.as_ref().map(|input| {
+                            render_number_row(
+                                i18n::t(lang, "settings.terminal.background_opacity"),
+                                input,
+                                cx,
+                            )
+                        }))
+                        .child(render_switch_row(
+                            "terminal-background-blur",
+                            i18n::t(lang, "settings.terminal.background_blur"),
+                            terminal.background_blur,
+                            state.clone(),
+                            |s, v| s.settings.terminal.background_blur = v,
+                            cx,
+                        ))
+                        .child(render_switch_row(
+                            "terminal-dim-inactive",
+                            i18n::t(lang, "settings.terminal.dim_inactive"),
+                            terminal.dim_inactive_panes,
+                            state.clone(),
+                            |s, v| s.settings.terminal.dim_inactive_panes = v,
+                            cx,
+                        )),
+                ),
+        )
 }
 
 /// 渲染光标样式选择行
@@ -10,6 +10,7 @@ use crate::models::settings::{Language, ThemeMode};
 
 use super::super::helpers::{render_font_input_row, render_number_row, render_section_title, UI_FONTS};
 use super::super::SettingsDialogState;
+use super::ui_theme::render_ui_theme_section;
 
 /// 渲染主题设置面板
 pub fn render_theme_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
@@ -41,19 +42,12 @@ pub fn render_theme_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl
                 .child(
                     div()
                         .flex()
+                        .flex_wrap()
                         .gap_3()
-                        .child(render_language_button(
-                            state.clone(),
-                            Language::Chinese,
-                            current_language == Language::Chinese,
-                            cx,
-                        ))
-                        .child(render_language_button(
-                            state.clone(),
-                            Language::English,
-                            current_language == Language::English,
-                            cx,
-                        )),
+                        .children(Language::ALL.into_iter().map(|lang| {
+                            let selected = lang == current_language;
+                            render_language_button(state.clone(), lang, selected, cx)
+                        })),
                 ),
         )
         // 外观模式
@@ -105,6 +99,8 @@ pub fn render_theme_panel(state: Entity<SettingsDialogState>, cx: &App) -> impl
                         )),
                 ),
         )
+        // 界面配色
+        .child(render_ui_theme_section(state.clone(), cx))
         // 字体设置
         .child(
             div()
@@ -233,6 +229,11 @@ fn render_theme_mode_button(
             // Save to settings
             state.update(cx, |s, _| {
                 s.settings.theme.mode = mode.clone();
+                // 界面配色编辑跟随所选模式
+                if mode != ThemeMode::System {
+                    s.ui_theme_dark = mode == ThemeMode::Dark;
+                    s.ui_theme_inputs.clear();
+                }
                 s.mark_changed();
             });
 
@@ -0,0 +1,354 @@
+// 界面配色：预设画廊、颜色编辑与主题导入/导出
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::i18n;
+use crate::models::settings::{Language, UiColorSlot, UiTheme};
+use crate::services::ui_theme;
+use crate::theme::palette_color;
+
+use super::super::helpers::{render_section_title, row_bg};
+use super::super::SettingsDialogState;
+
+/// 渲染界面配色区域
+pub fn render_ui_theme_section(state: Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let state_read = state.read(cx);
+    let lang = state_read.settings.theme.language.clone();
+    let current = state_read.settings.theme.ui_theme.clone();
+    let editing_dark = state_read.ui_theme_dark;
+    let inputs = state_read.ui_theme_inputs.clone();
+    let result = state_read.ui_theme_result.clone();
+    // 预设卡片按当前显示的外观模式预览
+    let preview_dark = cx.theme().mode.is_dark();
+
+    let current_name = if !current.name.is_empty() {
+        current.name.clone()
+    } else if current.same_colors(&UiTheme::default()) {
+        i18n::t(&lang, "settings.ui_theme.default").to_string()
+    } else {
+        i18n::t(&lang, "settings.ui_theme.custom").to_string()
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_section_title(
+            i18n::t(&lang, "settings.ui_theme.title"),
+            cx,
+        ))
+        // 预设画廊
+        .child(
+            div().flex().flex_wrap().gap_3().children(
+                ui_theme::presets()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(ix, preset)| {
+                        let selected = preset.same_colors(&current);
+                        render_preset_card(
+                            state.clone(),
+                            ix,
+                            preset,
+                            selected,
+                            preview_dark,
+                            &lang,
+                            cx,
+                        )
+                    }),
+            ),
+        )
+        // 当前主题名称 + 导入/导出
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!(
+                            "{}: {}",
+                            i18n::t(&lang, "settings.ui_theme.current"),
+                            current_name
+                        )),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .gap_2()
+                        .child(
+                            Button::new("ui-theme-import")
+                                .small()
+                                .outline()
+                                .child(i18n::t(&lang, "settings.ui_theme.import"))
+                                .on_click({
+                                    let state = state.clone();
+                                    let lang = lang.clone();
+                                    move |_, _, cx| import_theme(state.clone(), &lang, cx)
+                                }),
+                        )
+                        .child(
+                            Button::new("ui-theme-export")
+                                .small()
+                                .outline()
+                                .child(i18n::t(&lang, "settings.ui_theme.export"))
+                                .on_click({
+                                    let state = state.clone();
+                                    let lang = lang.clone();
+                                    move |_, _, cx| export_theme(state.clone(), &lang, cx)
+                                }),
+                        ),
+                ),
+        )
+        .children(result.map(|result| {
+            let (text, color) = match result {
+                Ok(text) => (text, cx.theme().success),
+                Err(text) => (text, cx.theme().danger),
+            };
+            div().text_sm().text_color(color).child(text)
+        }))
+        // 编辑深色/浅色配色
+        .child(
+            div()
+                .flex()
+                .gap_2()
+                .child(render_mode_tab(
+                    state.clone(),
+                    true,
+                    editing_dark,
+                    &lang,
+                    cx,
+                ))
+                .child(render_mode_tab(
+                    state.clone(),
+                    false,
+                    editing_dark,
+                    &lang,
+                    cx,
+                )),
+        )
+        .children(inputs.into_iter().map(|(slot, input)| {
+            let swatch = palette_color(current.palette(editing_dark), slot, editing_dark, cx);
+            let label = i18n::t(&lang, slot.label_key());
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .py_3()
+                .px_4()
+                .bg(row_bg(label, cx))
+                .rounded_lg()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(label),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .size(px(20.))
+                                .rounded_sm()
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .bg(swatch),
+                        )
+                        .child(div().w(px(140.)).child(Input::new(&input).small())),
+                )
+        }))
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(&lang, "settings.ui_theme.hint")),
+        )
+}
+
+/// 渲染预设卡片：配色色块 + 名称
+fn render_preset_card(
+    state: Entity<SettingsDialogState>,
+    ix: usize,
+    preset: UiTheme,
+    selected: bool,
+    dark: bool,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let palette = preset.palette(dark);
+    let swatches: Vec<Hsla> = [
+        UiColorSlot::Sidebar,
+        UiColorSlot::Background,
+        UiColorSlot::Surface,
+        UiColorSlot::Accent,
+    ]
+    .into_iter()
+    .map(|slot| palette_color(palette, slot, dark, cx))
+    .collect();
+    let name = if preset.name.is_empty() {
+        i18n::t(lang, "settings.ui_theme.default").to_string()
+    } else {
+        preset.name.clone()
+    };
+    let border_color = if selected {
+        cx.theme().primary
+    } else {
+        cx.theme().border
+    };
+    let hover_bg = cx.theme().list_hover;
+
+    div()
+        .id(("ui-theme-preset", ix))
+        .w(px(112.))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .p_1()
+        .rounded_md()
+        .border_2()
+        .border_color(border_color)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                s.set_ui_theme(preset.clone(), cx);
+                s.ui_theme_result = None;
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .h(px(36.))
+                .flex()
+                .rounded_sm()
+                .overflow_hidden()
+                .children(
+                    swatches
+                        .into_iter()
+                        .map(|color| div().flex_1().h_full().bg(color)),
+                ),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_center()
+                .text_color(cx.theme().foreground)
+                .child(name),
+        )
+}
+
+/// 渲染深色/浅色配色切换按钮
+fn render_mode_tab(
+    state: Entity<SettingsDialogState>,
+    dark: bool,
+    editing_dark: bool,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let selected = dark == editing_dark;
+    let (id, label) = if dark {
+        ("ui-theme-edit-dark", "settings.ui_theme.edit_dark")
+    } else {
+        ("ui-theme-edit-light", "settings.ui_theme.edit_light")
+    };
+    let (bg, fg, hover) = if selected {
+        (
+            cx.theme().primary,
+            cx.theme().primary_foreground,
+            cx.theme().primary_hover,
+        )
+    } else {
+        (
+            cx.theme().secondary,
+            cx.theme().secondary_foreground,
+            cx.theme().secondary_hover,
+        )
+    };
+
+    div()
+        .id(id)
+        .px_3()
+        .py_1()
+        .rounded_md()
+        .bg(bg)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                if s.ui_theme_dark != dark {
+                    s.ui_theme_dark = dark;
+                    // 输入框按所选模式的配色重新创建
+                    s.ui_theme_inputs.clear();
+                    cx.notify();
+                }
+            });
+        })
+        .child(div().text_sm().text_color(fg).child(i18n::t(lang, label)))
+}
+
+/// 选择 JSON 文件导入主题并实时预览
+fn import_theme(state: Entity<SettingsDialogState>, lang: &Language, cx: &mut App) {
+    let title = i18n::t(lang, "settings.ui_theme.import");
+    let done = i18n::t(lang, "settings.ui_theme.imported");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .add_filter("JSON", &["json"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+        let result = ui_theme::import_theme(file.path());
+        let _ = state.update(cx, |s, cx| {
+            match result {
+                Ok(theme) => {
+                    s.ui_theme_result = Some(Ok(done.replace("{}", &theme.name)));
+                    s.set_ui_theme(theme, cx);
+                }
+                Err(e) => s.ui_theme_result = Some(Err(e.to_string())),
+            }
+            cx.notify();
+        });
+    })
+    .detach();
+}
+
+/// 选择保存位置并导出当前主题
+fn export_theme(state: Entity<SettingsDialogState>, lang: &Language, cx: &mut App) {
+    let mut theme = state.read(cx).settings.theme.ui_theme.clone();
+    if theme.name.is_empty() {
+        theme.name = i18n::t(lang, "settings.ui_theme.custom").to_string();
+    }
+    let title = i18n::t(lang, "settings.ui_theme.export");
+    let done = i18n::t(lang, "settings.ui_theme.exported");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .set_file_name(ui_theme::default_file_name(&theme))
+            .add_filter("JSON", &["json"])
+            .save_file()
+            .await
+        else {
+            return;
+        };
+        let result = ui_theme::export_theme(file.path(), &theme)
+            .map(|_| format!("{} {}", done, file.path().display()))
+            .map_err(|e| e.to_string());
+        let _ = state.update(cx, |s, cx| {
+            s.ui_theme_result = Some(result);
+            cx.notify();
+        });
+    })
+    .detach();
+}
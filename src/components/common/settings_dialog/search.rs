@@ -0,0 +1,384 @@
+// 设置搜索：按名称在所有分区的设置项中查找，高亮匹配文字，点击结果跳转到所在分区
+
+use std::ops::Range;
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+
+use super::{SettingsDialogState, SettingsSection};
+
+/// 可搜索的设置项：各分区中设置项名称的 i18n key（按面板中的显示顺序）
+const ENTRIES: &[(SettingsSection, &[&str])] = &[
+    (
+        SettingsSection::Theme,
+        &[
+            "settings.theme.mode",
+            "settings.theme.language",
+            "settings.ui_theme.title",
+            "settings.ui_theme.edit_dark",
+            "settings.ui_theme.edit_light",
+            "settings.ui_theme.accent",
+            "settings.ui_theme.background",
+            "settings.ui_theme.surface",
+            "settings.ui_theme.sidebar",
+            "settings.ui_theme.border",
+            "settings.ui_theme.import",
+            "settings.ui_theme.export",
+            "settings.theme.font",
+            "settings.theme.font_family",
+            "settings.theme.font_size",
+        ],
+    ),
+    (
+        SettingsSection::Terminal,
+        &[
+            "settings.terminal.font",
+            "settings.terminal.font_family",
+            "settings.terminal.font_size",
+            "settings.terminal.line_height",
+            "settings.terminal.ligatures",
+            "settings.terminal.theme",
+            "settings.terminal.color_scheme",
+            "settings.terminal.display",
+            "settings.terminal.cursor_style",
+            "settings.terminal.cursor_blink",
+            "settings.terminal.scrollback",
+            "settings.terminal.dim_inactive",
+            "settings.terminal.transparency",
+            "settings.terminal.background_opacity",
+            "settings.terminal.background_blur",
+        ],
+    ),
+    (
+        SettingsSection::KeyBindings,
+        &[
+            "settings.keybindings.global_title",
+            "settings.keybindings.command_palette",
+            "settings.keybindings.toggle_sidebar",
+            "settings.keybindings.quit",
+            "settings.keybindings.navigation_title",
+            "settings.keybindings.next_tab",
+            "settings.keybindings.prev_tab",
+            "settings.keybindings.last_tab",
+            "settings.keybindings.activate_tab",
+            "settings.keybindings.tab_overview",
+            "settings.keybindings.focus_terminal",
+            "settings.keybindings.focus_sftp",
+            "settings.keybindings.focus_monitor",
+            "settings.keybindings.terminal_title",
+            "settings.keybindings.copy",
+            "settings.keybindings.paste",
+            "settings.keybindings.next_terminal",
+            "settings.keybindings.prev_terminal",
+            "settings.keybindings.sftp_title",
+            "settings.keybindings.navigate_back",
+            "settings.keybindings.navigate_forward",
+            "settings.keybindings.rename",
+        ],
+    ),
+    (
+        SettingsSection::Sftp,
+        &[
+            "settings.sftp.file_display",
+            "settings.sftp.show_hidden",
+            "settings.sftp.folders_first",
+            "settings.sftp.dual_pane",
+            "settings.sftp.transfer",
+            "settings.sftp.concurrent",
+            "settings.sftp.max_active_transfers",
+            "settings.sftp.max_transfers_per_server",
+            "settings.sftp.download_limit",
+            "settings.sftp.upload_limit",
+            "settings.sftp.preserve_time",
+            "settings.sftp.resume",
+            "settings.sftp.verify_checksum",
+            "settings.sftp.transfer_compression",
+            "settings.sftp.auto_extract",
+            "settings.sftp.conflict_action",
+            "settings.sftp.default_download_path",
+            "settings.sftp.delete",
+            "settings.sftp.use_remote_trash",
+            "settings.sftp.remote_trash_dir",
+            "settings.sftp.editor",
+            "settings.sftp.builtin_editor",
+            "settings.sftp.external_editor_path",
+            "settings.sftp.max_edit_file_size",
+            "settings.sftp.editor_appearance",
+            "settings.sftp.editor_font_family",
+            "settings.sftp.editor_font_size",
+            "settings.sftp.editor_line_height",
+            "settings.sftp.editor_gutter_width",
+            "settings.sftp.editor_gutter_padding",
+        ],
+    ),
+    (
+        SettingsSection::Monitor,
+        &[
+            "settings.monitor.data_collection",
+            "settings.monitor.refresh_interval",
+            "settings.monitor.interval_secs",
+            "settings.monitor.history_retention",
+            "settings.monitor.auto_deploy",
+            "settings.monitor.display_items",
+            "settings.monitor.cpu",
+            "settings.monitor.memory",
+            "settings.monitor.disk",
+            "settings.monitor.network",
+            "settings.monitor.gpu",
+            "settings.monitor.health",
+            "settings.monitor.logins",
+            "settings.monitor.alerts",
+            "settings.monitor.cpu_threshold",
+            "settings.monitor.memory_threshold",
+            "settings.monitor.disk_threshold",
+            "settings.monitor.temperature_threshold",
+            "settings.monitor.alert_consecutive",
+            "settings.monitor.alert_notification",
+        ],
+    ),
+    (
+        SettingsSection::Connection,
+        &[
+            "settings.connection.ssh",
+            "settings.connection.default_port",
+            "settings.connection.timeout",
+            "settings.connection.keepalive",
+            "settings.connection.compression",
+            "settings.connection.reconnect",
+            "settings.connection.reconnect_enabled",
+            "settings.connection.reconnect_attempts",
+            "settings.connection.reconnect_interval",
+            "settings.connection.hibernation",
+            "settings.connection.hibernate_after",
+            "settings.connection.hibernate_connected",
+            "settings.connection.reachability",
+            "settings.connection.reachability_probe",
+            "settings.connection.reachability_interval",
+            "settings.connection.db_clients",
+        ],
+    ),
+    (
+        SettingsSection::Sync,
+        &[
+            "settings.sync.enabled",
+            "settings.sync.method",
+            "settings.sync.webdav",
+            "settings.sync.webdav_url",
+            "settings.sync.webdav_username",
+            "settings.sync.webdav_password",
+            "settings.sync.webdav_path",
+            "settings.sync.passphrase",
+            "settings.sync.git",
+            "settings.sync.git_remote",
+            "settings.sync.git_branch",
+            "settings.sync.git_private_key",
+            "settings.sync.test_connection",
+            "settings.sync.content",
+            "settings.sync.servers",
+            "settings.sync.groups",
+            "settings.sync.settings",
+            "settings.sync.keybindings",
+            "settings.sync.keys",
+            "settings.sync.auto",
+            "settings.sync.interval",
+            "settings.sync.conflict_strategy",
+            "settings.sync.backup_before_sync",
+            "settings.sync.status",
+            "settings.sync.sync_now",
+            "settings.sync.history",
+            "settings.sync.inventory",
+            "settings.sync.inventory_source",
+            "settings.sync.inventory_refresh",
+            "settings.sync.inventory_identity",
+            "settings.sync.inventory_refresh_now",
+        ],
+    ),
+    (
+        SettingsSection::System,
+        &[
+            "settings.system.startup",
+            "settings.system.auto_start",
+            "settings.system.start_minimized",
+            "settings.system.restore_sessions",
+            "settings.system.check_updates",
+            "settings.system.single_instance",
+            "settings.system.window",
+            "settings.system.close_to_tray",
+            "settings.system.show_tray_icon",
+            "settings.system.notification",
+            "settings.system.system_notifications",
+            "settings.system.notify_transfer",
+            "settings.system.notify_disconnect",
+            "settings.system.do_not_disturb",
+            "settings.system.url_handler",
+            "settings.system.url_handler.register",
+            "settings.system.logging",
+            "settings.system.logging_enabled",
+            "settings.system.log_retention",
+            "settings.system.backup",
+            "settings.system.backup.export",
+            "settings.system.backup.import",
+            "settings.system.backup.include_secrets",
+            "settings.system.inventory",
+            "settings.system.inventory.export_csv",
+            "settings.system.inventory.export_ssh_config",
+        ],
+    ),
+    (
+        SettingsSection::About,
+        &["settings.about.platform", "settings.about.arch"],
+    ),
+];
+
+/// 搜索结果跳转后在面板中高亮的设置项名称
+struct SearchHighlight(Option<&'static str>);
+
+impl Global for SearchHighlight {}
+
+/// 一条搜索结果
+pub struct SearchMatch {
+    pub section: SettingsSection,
+    /// 当前语言下的设置项名称
+    pub label: &'static str,
+    /// 名称中匹配部分的字节区间
+    pub range: Range<usize>,
+}
+
+/// 在当前语言的设置项名称中查找（ASCII 忽略大小写），结果按分区顺序排列
+pub fn search(query: &str, lang: &Language) -> Vec<SearchMatch> {
+    let query = query.trim().to_ascii_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<SearchMatch> = Vec::new();
+    for (section, key) in ENTRIES
+        .iter()
+        .flat_map(|(section, keys)| keys.iter().map(move |key| (*section, *key)))
+    {
+        let label = i18n::t(lang, key);
+        // 同一分区中名称相同的设置项只保留一条
+        if matches
+            .iter()
+            .any(|m| m.section == section && m.label == label)
+        {
+            continue;
+        }
+        // 只转换 ASCII 字母，保证字节位置与原文一致
+        if let Some(start) = label.to_ascii_lowercase().find(&query) {
+            matches.push(SearchMatch {
+                section,
+                label,
+                range: start..start + query.len(),
+            });
+        }
+    }
+    matches
+}
+
+/// 设置项是否为搜索结果跳转的目标
+pub fn is_highlighted(label: &str, cx: &App) -> bool {
+    cx.try_global::<SearchHighlight>()
+        .and_then(|h| h.0)
+        .is_some_and(|h| h == label)
+}
+
+/// 设置（或清除）面板中高亮的设置项
+pub fn set_highlight(label: Option<&'static str>, cx: &mut App) {
+    cx.set_global(SearchHighlight(label));
+}
+
+/// 渲染左侧导航顶部的搜索框
+pub fn render_search_field(state: &Entity<SettingsDialogState>, cx: &App) -> impl IntoElement {
+    let input = state.read(cx).search_input.clone();
+    let muted = cx.theme().muted_foreground;
+
+    div().mb_2().children(input.map(|input| {
+        Input::new(&input)
+            .small()
+            .cleanable(true)
+            .prefix(svg().path(icons::SEARCH).size(px(12.)).text_color(muted))
+    }))
+}
+
+/// 渲染搜索结果列表（替代当前分区内容）
+pub fn render_search_results(
+    state: Entity<SettingsDialogState>,
+    matches: &[SearchMatch],
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+
+    if matches.is_empty() {
+        return div()
+            .py_8()
+            .flex()
+            .justify_center()
+            .text_sm()
+            .text_color(muted)
+            .child(i18n::t(lang, "settings.search.no_results"))
+            .into_any_element();
+    }
+
+    let hover_bg = cx.theme().list_hover;
+    let match_style = HighlightStyle {
+        background_color: Some(cx.theme().primary.opacity(0.35)),
+        font_weight: Some(FontWeight::SEMIBOLD),
+        ..Default::default()
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(div().mb_2().text_sm().text_color(muted).child(
+            i18n::t(lang, "settings.search.results").replace("{}", &matches.len().to_string()),
+        ))
+        .children(matches.iter().enumerate().map(|(ix, m)| {
+            let state = state.clone();
+            let section = m.section;
+            let label = m.label;
+
+            div()
+                .id(("settings-search-result", ix))
+                .flex()
+                .items_center()
+                .gap_2()
+                .py_2()
+                .px_3()
+                .rounded_md()
+                .bg(cx.theme().muted)
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .on_click(move |_, window, cx| {
+                    // 跳转到所在分区并清空搜索，面板中高亮该设置项
+                    state.update(cx, |s, cx| {
+                        s.current_section = section;
+                        if let Some(input) = s.search_input.clone() {
+                            input.update(cx, |input, cx| input.set_value("", window, cx));
+                        }
+                        cx.notify();
+                    });
+                    set_highlight(Some(label), cx);
+                })
+                .child(render_icon(section.icon(), muted.into()))
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(format!("{} ›", i18n::t(lang, section.label_key()))),
+                )
+                .child(div().text_sm().text_color(cx.theme().foreground).child(
+                    StyledText::new(label).with_highlights(vec![(m.range.clone(), match_style)]),
+                ))
+        }))
+        .into_any_element()
+}
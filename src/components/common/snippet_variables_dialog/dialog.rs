@@ -0,0 +1,177 @@
+// 快捷命令变量表单渲染组件
+
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::{SnippetVariablesDialogState, SnippetVariablesEvent};
+
+/// 渲染快捷命令变量表单覆盖层
+pub fn render_snippet_variables_dialog_overlay(
+    state: Entity<SnippetVariablesDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let fields: Vec<_> = state_read
+        .variables
+        .iter()
+        .zip(state_read.inputs.iter().cloned())
+        .map(|(variable, input)| (variable.name.clone(), input))
+        .collect();
+    let preview = state_read.preview(cx);
+
+    let state_cancel = state.clone();
+    let state_run = state;
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id("snippet-variables-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(460.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "snippets.variables.title")),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted_foreground)
+                        .child(i18n::t(&lang, "snippets.variables.hint")),
+                )
+                // 变量输入
+                .child(
+                    div()
+                        .id("snippet-variables-fields")
+                        .max_h(px(320.))
+                        .overflow_y_scroll()
+                        .flex()
+                        .flex_col()
+                        .gap_3()
+                        .children(fields.into_iter().map(|(name, input)| {
+                            div()
+                                .flex()
+                                .flex_col()
+                                .gap_1()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .font_family("monospace")
+                                        .text_color(muted_foreground)
+                                        .child(name),
+                                )
+                                .child(Input::new(&input))
+                        })),
+                )
+                // 最终命令预览
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "snippets.variables.preview")),
+                        )
+                        .child(
+                            div()
+                                .px_3()
+                                .py_2()
+                                .rounded_md()
+                                .bg(cx.theme().secondary)
+                                .text_sm()
+                                .font_family("monospace")
+                                .text_color(foreground)
+                                .child(preview),
+                        ),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .child(
+                            div()
+                                .id("snippet-variables-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        .child(
+                            div()
+                                .id("snippet-variables-run-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().primary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_run.update(cx, |_, cx| {
+                                        cx.emit(SnippetVariablesEvent::Submit);
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(i18n::t(&lang, "snippets.variables.run")),
+                                ),
+                        ),
+                ),
+        )
+}
@@ -0,0 +1,7 @@
+// 快捷命令变量表单：运行带 {{变量}} 占位符的命令前填写变量值
+
+mod dialog;
+mod state;
+
+pub use dialog::render_snippet_variables_dialog_overlay;
+pub use state::{SnippetVariablesDialogState, SnippetVariablesEvent};
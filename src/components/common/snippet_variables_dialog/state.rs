@@ -0,0 +1,112 @@
+// 快捷命令变量表单状态管理
+
+use std::collections::HashMap;
+
+use gpui::{App, AppContext, Context, Entity, EventEmitter, Window};
+use gpui_component::input::{InputEvent, InputState};
+
+use crate::models::snippets::fill_variables;
+use crate::models::SnippetVariable;
+
+/// 表单事件
+pub enum SnippetVariablesEvent {
+    /// 确认运行（点击运行按钮或在输入框中按回车）
+    Submit,
+}
+
+/// 快捷命令变量表单状态
+#[derive(Default)]
+pub struct SnippetVariablesDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 命令模板
+    pub template: String,
+    /// 运行命令的标签
+    pub tab_id: String,
+    /// 标签对应的服务器 ID（用于记住填写的值）
+    pub server_id: String,
+    /// 命令中的变量
+    pub variables: Vec<SnippetVariable>,
+    /// 变量输入框（与 variables 一一对应，渲染时创建）
+    pub inputs: Vec<Entity<InputState>>,
+    /// 输入框的初始值（上次在该服务器上填写的值）
+    initial_values: Vec<Option<String>>,
+}
+
+impl EventEmitter<SnippetVariablesEvent> for SnippetVariablesDialogState {}
+
+impl SnippetVariablesDialogState {
+    /// 打开表单；`remembered` 为该服务器上次填写的变量值
+    pub fn open(
+        &mut self,
+        template: String,
+        variables: Vec<SnippetVariable>,
+        tab_id: String,
+        server_id: String,
+        remembered: Option<&HashMap<String, String>>,
+    ) {
+        self.initial_values = variables
+            .iter()
+            .map(|v| remembered.and_then(|values| values.get(&v.name)).cloned())
+            .collect();
+        self.is_open = true;
+        self.template = template;
+        self.variables = variables;
+        self.tab_id = tab_id;
+        self.server_id = server_id;
+        // 重置输入框（将在渲染时创建）
+        self.inputs.clear();
+    }
+
+    /// 关闭表单
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.template.clear();
+        self.variables.clear();
+        self.inputs.clear();
+        self.initial_values.clear();
+        self.tab_id.clear();
+        self.server_id.clear();
+    }
+
+    /// 确保输入框已创建，创建后聚焦第一个输入框（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.inputs.is_empty() || self.variables.is_empty() {
+            return;
+        }
+        for (variable, initial) in self.variables.iter().zip(&self.initial_values) {
+            let placeholder = variable.default.clone().unwrap_or_default();
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            if let Some(value) = initial.clone() {
+                input.update(cx, |s, cx| s.set_value(value, window, cx));
+            }
+            cx.subscribe_in(&input, window, |_, _, event: &InputEvent, _, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    cx.emit(SnippetVariablesEvent::Submit);
+                }
+            })
+            .detach();
+            self.inputs.push(input);
+        }
+        if let Some(first) = self.inputs.first() {
+            first.update(cx, |input, cx| input.focus(window, cx));
+        }
+    }
+
+    /// 填写的变量值（留空的变量不包含在内，运行时使用默认值）
+    pub fn values(&self, cx: &App) -> HashMap<String, String> {
+        self.variables
+            .iter()
+            .zip(&self.inputs)
+            .filter_map(|(variable, input)| {
+                let value = input.read(cx).text().to_string();
+                (!value.is_empty()).then(|| (variable.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// 替换变量后的最终命令
+    pub fn preview(&self, cx: &App) -> String {
+        fill_variables(&self.template, &self.values(cx))
+    }
+}
@@ -1,11 +1,15 @@
 // Snippets 弹窗渲染组件
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
-use gpui_component::input::Input;
+use gpui_component::input::{Input, InputState};
+use gpui_component::switch::Switch;
 use gpui_component::ActiveTheme;
 
 use crate::i18n;
 use crate::models::settings::Language;
+use crate::models::SnippetScope;
+use crate::pages::home::titlebar::TAB_COLORS;
 use crate::services::storage;
 
 use super::state::{SnippetsDialogMode, SnippetsDialogState};
@@ -32,7 +36,14 @@ pub fn render_snippets_dialog_overlay(
     // 获取输入框 Entity
     let name_input = state_read.name_input.clone();
     let command_input = state_read.command_input.clone();
+    let description_input = state_read.description_input.clone();
+    let tags_input = state_read.tags_input.clone();
+    let selected_color = state_read.color;
+    let scope = state_read.scope.clone();
+    let auto_run = state_read.auto_run;
 
+    let state_color = state.clone();
+    let state_scope = state.clone();
     let state_cancel = state.clone();
     let state_save = state;
 
@@ -74,53 +85,61 @@ pub fn render_snippets_dialog_overlay(
                         .child(dialog_title),
                 )
                 // 名称输入
-                .child(
-                    div()
-                        .flex()
-                        .flex_col()
-                        .gap_2()
-                        .child(div().text_sm().text_color(muted_foreground).child(
-                            if is_group_dialog {
-                                i18n::t(&lang, "snippets.dialog.group_name")
-                            } else {
-                                i18n::t(&lang, "snippets.dialog.command_name")
-                            },
-                        ))
-                        .child(if let Some(input) = &name_input {
-                            Input::new(input).into_any_element()
-                        } else {
-                            div()
-                                .text_sm()
-                                .text_color(muted_foreground)
-                                .child(i18n::t(&lang, "common.loading"))
-                                .into_any_element()
-                        }),
-                )
-                // 命令内容输入 (仅命令弹窗)
-                .children(if !is_group_dialog {
-                    Some(
+                .child(render_input_field(
+                    if is_group_dialog {
+                        i18n::t(&lang, "snippets.dialog.group_name")
+                    } else {
+                        i18n::t(&lang, "snippets.dialog.command_name")
+                    },
+                    name_input.as_ref(),
+                    &lang,
+                    muted_foreground,
+                ))
+                // 命令内容输入与变量语法说明 (仅命令弹窗)
+                .when(!is_group_dialog, |this| {
+                    this.child(render_input_field(
+                        i18n::t(&lang, "snippets.dialog.command_content"),
+                        command_input.as_ref(),
+                        &lang,
+                        muted_foreground,
+                    ))
+                    .child(
                         div()
-                            .flex()
-                            .flex_col()
-                            .gap_2()
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .text_color(muted_foreground)
-                                    .child(i18n::t(&lang, "snippets.dialog.command_content")),
-                            )
-                            .child(if let Some(input) = &command_input {
-                                Input::new(input).into_any_element()
-                            } else {
-                                div()
-                                    .text_sm()
-                                    .text_color(muted_foreground)
-                                    .child(i18n::t(&lang, "common.loading"))
-                                    .into_any_element()
-                            }),
+                            .text_xs()
+                            .text_color(muted_foreground)
+                            .child(i18n::t(&lang, "snippets.dialog.variables_hint")),
                     )
-                } else {
-                    None
+                })
+                // 描述输入
+                .child(render_input_field(
+                    i18n::t(&lang, "snippets.dialog.description"),
+                    description_input.as_ref(),
+                    &lang,
+                    muted_foreground,
+                ))
+                // 标签与颜色 (仅命令弹窗)
+                .when(!is_group_dialog, |this| {
+                    this.child(render_input_field(
+                        i18n::t(&lang, "snippets.dialog.tags"),
+                        tags_input.as_ref(),
+                        &lang,
+                        muted_foreground,
+                    ))
+                    .child(render_color_picker(
+                        state_color,
+                        selected_color,
+                        &lang,
+                        muted_foreground,
+                        cx,
+                    ))
+                    .child(render_scope_picker(
+                        state_scope,
+                        &scope,
+                        auto_run,
+                        &lang,
+                        muted_foreground,
+                        cx,
+                    ))
                 })
                 // 底部按钮
                 .child(
@@ -176,3 +195,250 @@ pub fn render_snippets_dialog_overlay(
                 ),
         )
 }
+
+/// 渲染带标题的输入框
+fn render_input_field(
+    label: &'static str,
+    input: Option<&Entity<InputState>>,
+    lang: &Language,
+    muted_foreground: Hsla,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(div().text_sm().text_color(muted_foreground).child(label))
+        .child(if let Some(input) = input {
+            Input::new(input).into_any_element()
+        } else {
+            div()
+                .text_sm()
+                .text_color(muted_foreground)
+                .child(i18n::t(lang, "common.loading"))
+                .into_any_element()
+        })
+}
+
+/// 渲染颜色标记选择（第一个为不标记）
+fn render_color_picker(
+    state: Entity<SnippetsDialogState>,
+    selected: Option<u32>,
+    lang: &Language,
+    muted_foreground: Hsla,
+    cx: &App,
+) -> impl IntoElement {
+    let border = cx.theme().border;
+    let ring = cx.theme().foreground;
+    let options = std::iter::once(None).chain(TAB_COLORS.iter().map(|(rgb, _)| Some(*rgb)));
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .text_sm()
+                .text_color(muted_foreground)
+                .child(i18n::t(lang, "snippets.dialog.color")),
+        )
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .children(options.map(|color| {
+                    let state = state.clone();
+                    let is_selected = color == selected;
+                    div()
+                        .id(SharedString::from(format!(
+                            "snippet-color-{}",
+                            color.map(|c| format!("{:06x}", c)).unwrap_or_default()
+                        )))
+                        .size(px(20.))
+                        .rounded_full()
+                        .cursor_pointer()
+                        .border_2()
+                        .border_color(if is_selected { ring } else { border })
+                        .map(|this| match color {
+                            Some(color) => this.bg(rgb(color)),
+                            // 不标记：空心圆
+                            None => this,
+                        })
+                        .on_click(move |_, _, cx| {
+                            state.update(cx, |s, cx| {
+                                s.set_color(color);
+                                cx.notify();
+                            });
+                        })
+                })),
+        )
+}
+
+/// 切换可见范围中某一项的方法
+type ScopeToggle = fn(&mut SnippetsDialogState, &str);
+
+/// 渲染可见范围选择（服务器分组、服务器标签与服务器）与连接后自动执行开关
+fn render_scope_picker(
+    state: Entity<SnippetsDialogState>,
+    scope: &SnippetScope,
+    auto_run: bool,
+    lang: &Language,
+    muted_foreground: Hsla,
+    cx: &App,
+) -> impl IntoElement {
+    let servers = storage::load_servers().unwrap_or_default();
+    let groups: Vec<(String, String)> = servers
+        .ordered_groups()
+        .into_iter()
+        .map(|(_, group)| (group.id.clone(), servers.group_path(&group.id)))
+        .collect();
+    let mut tags: Vec<String> = Vec::new();
+    for tag in servers.servers.iter().flat_map(|s| s.tags.iter()) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.clone());
+        }
+    }
+    tags.sort_by_key(|t| t.to_lowercase());
+    let server_items: Vec<(String, String)> = servers
+        .servers
+        .iter()
+        .map(|s| {
+            let label = if s.label.is_empty() {
+                s.host.clone()
+            } else {
+                s.label.clone()
+            };
+            (s.id.clone(), label)
+        })
+        .collect();
+
+    let chip = |id: String, label: String, selected: bool, on_click: ScopeToggle| {
+        let state = state.clone();
+        div()
+            .id(SharedString::from(format!("snippet-scope-{}", id)))
+            .px_2()
+            .py(px(2.))
+            .rounded(px(4.))
+            .border_1()
+            .cursor_pointer()
+            .text_xs()
+            .when(selected, |s| {
+                s.bg(cx.theme().primary)
+                    .border_color(cx.theme().primary)
+                    .text_color(cx.theme().primary_foreground)
+            })
+            .when(!selected, |s| {
+                s.border_color(cx.theme().border)
+                    .text_color(muted_foreground)
+                    .hover(|s| s.bg(cx.theme().secondary))
+            })
+            .on_click(move |_, _, cx| {
+                state.update(cx, |s, cx| {
+                    on_click(s, &id);
+                    cx.notify();
+                });
+            })
+            .child(label)
+    };
+    let section = |key: &'static str, chips: Vec<Stateful<Div>>| {
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_foreground)
+                    .child(i18n::t(lang, key)),
+            )
+            .child(div().flex().flex_wrap().gap_1().children(chips))
+    };
+
+    let group_chips: Vec<_> = groups
+        .into_iter()
+        .map(|(id, path)| {
+            let selected = scope.group_ids.contains(&id);
+            chip(id, path, selected, SnippetsDialogState::toggle_scope_group)
+        })
+        .collect();
+    let tag_chips: Vec<_> = tags
+        .into_iter()
+        .map(|tag| {
+            let selected = scope.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag));
+            chip(
+                tag.clone(),
+                tag,
+                selected,
+                SnippetsDialogState::toggle_scope_tag,
+            )
+        })
+        .collect();
+    let server_chips: Vec<_> = server_items
+        .into_iter()
+        .map(|(id, label)| {
+            let selected = scope.server_ids.contains(&id);
+            chip(
+                id,
+                label,
+                selected,
+                SnippetsDialogState::toggle_scope_server,
+            )
+        })
+        .collect();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .text_sm()
+                .text_color(muted_foreground)
+                .child(i18n::t(lang, "snippets.dialog.scope")),
+        )
+        .child(
+            div()
+                .text_xs()
+                .text_color(muted_foreground)
+                .child(i18n::t(lang, "snippets.dialog.scope_hint")),
+        )
+        .child(
+            div()
+                .id("snippet-scope-options")
+                .max_h(px(140.))
+                .overflow_y_scroll()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .when(!group_chips.is_empty(), |this| {
+                    this.child(section("snippets.dialog.scope_groups", group_chips))
+                })
+                .when(!tag_chips.is_empty(), |this| {
+                    this.child(section("snippets.dialog.scope_tags", tag_chips))
+                })
+                .when(!server_chips.is_empty(), |this| {
+                    this.child(section("snippets.dialog.scope_servers", server_chips))
+                }),
+        )
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted_foreground)
+                        .child(i18n::t(lang, "snippets.dialog.auto_run")),
+                )
+                .child(Switch::new("snippet-auto-run").checked(auto_run).on_click(
+                    move |checked, _, cx| {
+                        state.update(cx, |s, cx| {
+                            s.auto_run = *checked;
+                            cx.notify();
+                        });
+                    },
+                )),
+        )
+}
@@ -5,7 +5,7 @@ use gpui_component::input::InputState;
 
 use crate::i18n;
 use crate::models::settings::Language;
-use crate::models::{SnippetCommand, SnippetGroup};
+use crate::models::{SnippetCommand, SnippetGroup, SnippetScope};
 use crate::services::storage;
 
 /// 弹窗模式
@@ -31,6 +31,16 @@ pub struct SnippetsDialogState {
     pub name_input: Option<Entity<InputState>>,
     /// 输入框：命令内容
     pub command_input: Option<Entity<InputState>>,
+    /// 输入框：描述
+    pub description_input: Option<Entity<InputState>>,
+    /// 输入框：标签（逗号分隔，仅命令弹窗）
+    pub tags_input: Option<Entity<InputState>>,
+    /// 选中的颜色标记（仅命令弹窗）
+    pub color: Option<u32>,
+    /// 可见范围（仅命令弹窗）
+    pub scope: SnippetScope,
+    /// 连接范围内的服务器后自动执行（仅命令弹窗）
+    pub auto_run: bool,
     /// 当前父级 ID
     pub current_parent_id: Option<String>,
     /// 需要刷新页面
@@ -38,6 +48,8 @@ pub struct SnippetsDialogState {
     /// 待设置的初始值（用于编辑模式）
     pending_name_value: Option<String>,
     pending_command_value: Option<String>,
+    pending_description_value: Option<String>,
+    pending_tags_value: Option<String>,
 }
 
 impl Default for SnippetsDialogState {
@@ -48,10 +60,17 @@ impl Default for SnippetsDialogState {
             editing_command: None,
             name_input: None,
             command_input: None,
+            description_input: None,
+            tags_input: None,
+            color: None,
+            scope: SnippetScope::default(),
+            auto_run: false,
             current_parent_id: None,
             needs_page_refresh: false,
             pending_name_value: None,
             pending_command_value: None,
+            pending_description_value: None,
+            pending_tags_value: None,
         }
     }
 }
@@ -104,6 +123,20 @@ impl SnippetsDialogState {
             }));
         }
 
+        // 创建描述输入框
+        if self.description_input.is_none() {
+            let placeholder = i18n::t(&lang, "snippets.dialog.enter_description");
+            self.description_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+
+        // 创建标签输入框（仅命令弹窗）
+        if !is_group && self.tags_input.is_none() {
+            let placeholder = i18n::t(&lang, "snippets.dialog.enter_tags");
+            self.tags_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+
         // 应用待设置的值（编辑模式）
         if let Some(name) = self.pending_name_value.take() {
             if let Some(input) = &self.name_input {
@@ -115,6 +148,16 @@ impl SnippetsDialogState {
                 input.update(cx, |s, cx| s.set_value(cmd, window, cx));
             }
         }
+        if let Some(description) = self.pending_description_value.take() {
+            if let Some(input) = &self.description_input {
+                input.update(cx, |s, cx| s.set_value(description, window, cx));
+            }
+        }
+        if let Some(tags) = self.pending_tags_value.take() {
+            if let Some(input) = &self.tags_input {
+                input.update(cx, |s, cx| s.set_value(tags, window, cx));
+            }
+        }
     }
 
     /// 打开添加组弹窗
@@ -135,6 +178,7 @@ impl SnippetsDialogState {
         self.dialog_mode = SnippetsDialogMode::EditGroup;
         self.editing_group = Some(group.clone());
         self.pending_name_value = Some(group.name.clone());
+        self.pending_description_value = group.description.clone();
     }
 
     /// 打开添加命令弹窗
@@ -156,14 +200,46 @@ impl SnippetsDialogState {
         self.editing_command = Some(command.clone());
         self.pending_name_value = Some(command.name.clone());
         self.pending_command_value = Some(command.command.clone());
+        self.pending_description_value = command.description.clone();
+        self.pending_tags_value = Some(command.tags.join(", "));
+        self.color = command.color;
+        self.scope = command.scope.clone();
+        self.auto_run = command.auto_run;
     }
 
     /// 重置输入框状态
     fn reset_inputs(&mut self) {
         self.name_input = None;
         self.command_input = None;
+        self.description_input = None;
+        self.tags_input = None;
+        self.color = None;
+        self.scope = SnippetScope::default();
+        self.auto_run = false;
         self.pending_name_value = None;
         self.pending_command_value = None;
+        self.pending_description_value = None;
+        self.pending_tags_value = None;
+    }
+
+    /// 设置颜色标记（None 表示不标记）
+    pub fn set_color(&mut self, color: Option<u32>) {
+        self.color = color;
+    }
+
+    /// 切换可见范围中的服务器
+    pub fn toggle_scope_server(&mut self, server_id: &str) {
+        toggle(&mut self.scope.server_ids, server_id);
+    }
+
+    /// 切换可见范围中的服务器分组
+    pub fn toggle_scope_group(&mut self, group_id: &str) {
+        toggle(&mut self.scope.group_ids, group_id);
+    }
+
+    /// 切换可见范围中的服务器标签
+    pub fn toggle_scope_tag(&mut self, tag: &str) {
+        toggle(&mut self.scope.tags, tag);
     }
 
     /// 关闭弹窗
@@ -182,10 +258,30 @@ impl SnippetsDialogState {
             .unwrap_or_default()
     }
 
+    /// 获取描述，空白时为 None
+    fn get_description(&self, cx: &App) -> Option<String> {
+        let description = Self::get_input_text(&self.description_input, cx);
+        let description = description.trim();
+        (!description.is_empty()).then(|| description.to_string())
+    }
+
+    /// 解析标签输入（中英文逗号分隔，去除空白与重复）
+    fn get_tags(&self, cx: &App) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in Self::get_input_text(&self.tags_input, cx).split([',', '，']) {
+            let tag = tag.trim();
+            if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        tags
+    }
+
     /// 保存组
     pub fn save_group(&mut self, cx: &App) -> bool {
         if let Some(mut group) = self.editing_group.take() {
             group.name = Self::get_input_text(&self.name_input, cx);
+            group.description = self.get_description(cx);
             let result = if self.dialog_mode == SnippetsDialogMode::AddGroup {
                 storage::add_snippet_group(group)
             } else {
@@ -203,6 +299,11 @@ impl SnippetsDialogState {
         if let Some(mut cmd) = self.editing_command.take() {
             cmd.name = Self::get_input_text(&self.name_input, cx);
             cmd.command = Self::get_input_text(&self.command_input, cx);
+            cmd.description = self.get_description(cx);
+            cmd.tags = self.get_tags(cx);
+            cmd.color = self.color;
+            cmd.scope = self.scope.clone();
+            cmd.auto_run = self.auto_run;
             let result = if self.dialog_mode == SnippetsDialogMode::AddCommand {
                 storage::add_snippet_command(cmd)
             } else {
@@ -215,3 +316,13 @@ impl SnippetsDialogState {
         false
     }
 }
+
+/// 列表中存在时移除，否则追加
+fn toggle(values: &mut Vec<String>, value: &str) {
+    match values.iter().position(|v| v == value) {
+        Some(pos) => {
+            values.remove(pos);
+        }
+        None => values.push(value.to_string()),
+    }
+}
@@ -0,0 +1,189 @@
+// SSH 配置片段弹窗渲染组件
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::{SnippetPart, SshSnippetDialogState};
+
+/// 渲染 SSH 配置片段弹窗覆盖层
+pub fn render_ssh_snippet_dialog_overlay(
+    state: Entity<SshSnippetDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let title = i18n::t(&lang, "ssh_snippet.title").replace("{}", &state_read.server_label);
+    let (config, command) = state_read
+        .snippet
+        .as_ref()
+        .map(|s| (s.config.clone(), s.command.clone()))
+        .unwrap_or_default();
+    let forward_count = state_read.forward_count;
+    let copied = state_read.copied;
+
+    let state_close = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    // 一段可复制的内容：标题 + 复制按钮 + 等宽文本
+    let section = |part: SnippetPart, label_key: &'static str, text: String| {
+        let state = state.clone();
+        let text_for_copy = text.clone();
+        let (id, copy_key) = match part {
+            SnippetPart::Config => ("ssh-snippet-copy-config", "ssh_snippet.copy_config"),
+            SnippetPart::Command => ("ssh-snippet-copy-command", "ssh_snippet.copy_command"),
+        };
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(muted_foreground)
+                            .child(i18n::t(&lang, label_key)),
+                    )
+                    .child(
+                        Button::new(id)
+                            .xsmall()
+                            .ghost()
+                            .child(i18n::t(
+                                &lang,
+                                if copied == Some(part) {
+                                    "ssh_snippet.copied"
+                                } else {
+                                    copy_key
+                                },
+                            ))
+                            .on_click(move |_, _, cx| {
+                                cx.write_to_clipboard(ClipboardItem::new_string(
+                                    text_for_copy.clone(),
+                                ));
+                                state.update(cx, |s, cx| {
+                                    s.copied = Some(part);
+                                    cx.notify();
+                                });
+                            }),
+                    ),
+            )
+            .child(
+                div()
+                    .p_3()
+                    .rounded_md()
+                    .bg(cx.theme().muted)
+                    .text_xs()
+                    .font_family("monospace")
+                    .text_color(foreground)
+                    .child(text),
+            )
+    };
+
+    div()
+        .id("ssh-snippet-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(560.))
+                .max_h(relative(0.85))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(title),
+                )
+                .child(
+                    div()
+                        .id("ssh-snippet-scroll")
+                        .flex_1()
+                        .min_h(px(0.))
+                        .overflow_y_scroll()
+                        .flex()
+                        .flex_col()
+                        .gap_4()
+                        .child(section(SnippetPart::Config, "ssh_snippet.config", config))
+                        .child(section(
+                            SnippetPart::Command,
+                            "ssh_snippet.command",
+                            command,
+                        )),
+                )
+                // 说明：密码不会导出；包含当前会话的端口转发时注明数量
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .text_xs()
+                        .text_color(muted_foreground)
+                        .child(i18n::t(&lang, "ssh_snippet.hint"))
+                        .when(forward_count > 0, |this| {
+                            this.child(
+                                i18n::t(&lang, "ssh_snippet.forwards")
+                                    .replace("{}", &forward_count.to_string()),
+                            )
+                        }),
+                )
+                // 底部按钮
+                .child(
+                    div().flex().justify_end().pt_2().child(
+                        div()
+                            .id("ssh-snippet-close-btn")
+                            .px_4()
+                            .py_2()
+                            .bg(cx.theme().secondary)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(move |s| s.bg(cx.theme().secondary_hover))
+                            .on_click(move |_, _, cx| {
+                                state_close.update(cx, |s, cx| {
+                                    s.close();
+                                    cx.notify();
+                                });
+                            })
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(foreground)
+                                    .child(i18n::t(&lang, "common.close")),
+                            ),
+                    ),
+                ),
+        )
+}
@@ -0,0 +1,7 @@
+// SSH 配置片段弹窗：显示服务器等效的 OpenSSH Host 块与 ssh 命令，可一键复制
+
+mod dialog;
+mod state;
+
+pub use dialog::render_ssh_snippet_dialog_overlay;
+pub use state::SshSnippetDialogState;
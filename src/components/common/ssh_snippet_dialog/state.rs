@@ -0,0 +1,50 @@
+// SSH 配置片段弹窗状态管理
+
+use crate::services::server_export::SshSnippet;
+
+/// 复制的内容
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SnippetPart {
+    Config,
+    Command,
+}
+
+/// SSH 配置片段弹窗状态
+#[derive(Default)]
+pub struct SshSnippetDialogState {
+    pub is_open: bool,
+    /// 打开弹窗的标签（从主页打开时为 None，弹窗只在对应视图中显示）
+    pub tab_id: Option<String>,
+    pub server_label: String,
+    pub snippet: Option<SshSnippet>,
+    /// 包含的端口转发数量
+    pub forward_count: usize,
+    /// 最近复制的内容（按钮显示“已复制”）
+    pub copied: Option<SnippetPart>,
+}
+
+impl SshSnippetDialogState {
+    /// 打开弹窗
+    pub fn open(
+        &mut self,
+        tab_id: Option<String>,
+        server_label: String,
+        snippet: SshSnippet,
+        forward_count: usize,
+    ) {
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.server_label = server_label;
+        self.snippet = Some(snippet);
+        self.forward_count = forward_count;
+        self.copied = None;
+    }
+
+    /// 关闭弹窗
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.tab_id = None;
+        self.snippet = None;
+        self.copied = None;
+    }
+}
@@ -0,0 +1,213 @@
+// 同步冲突对话框渲染
+
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use super::state::SyncState;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+use crate::services::sync::ConflictResolution;
+
+/// 渲染同步冲突对话框覆盖层：保留本地 / 使用远程 / 合并
+pub fn render_sync_conflict_dialog_overlay(state: Entity<SyncState>, cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let (local, remote, remote_time) = state
+        .read(cx)
+        .conflict
+        .as_ref()
+        .map(|c| {
+            (
+                (c.local_servers, c.local_snippets),
+                (c.remote_servers(), c.remote_snippets()),
+                c.remote.created_at.clone(),
+            )
+        })
+        .unwrap_or_default();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let summary = |(servers, snippets): (usize, usize)| {
+        format!(
+            "{} {} · {} {}",
+            servers,
+            i18n::t(&lang, "sync.conflict.servers"),
+            snippets,
+            i18n::t(&lang, "sync.conflict.snippets")
+        )
+    };
+    let remote_summary = if remote_time.is_empty() {
+        summary(remote)
+    } else {
+        // 只显示到秒
+        let time = remote_time
+            .get(..19)
+            .unwrap_or(&remote_time)
+            .replace('T', " ");
+        format!("{} · {}", summary(remote), time)
+    };
+
+    div()
+        .id("sync-conflict-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(460.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sync.conflict.title")),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted_foreground)
+                        .child(i18n::t(&lang, "sync.conflict.message")),
+                )
+                // 两侧内容概况
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(render_side_row(
+                            i18n::t(&lang, "sync.conflict.local"),
+                            summary(local),
+                            muted_foreground,
+                            foreground,
+                        ))
+                        .child(render_side_row(
+                            i18n::t(&lang, "sync.conflict.remote"),
+                            remote_summary,
+                            muted_foreground,
+                            foreground,
+                        )),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_2()
+                        .pt_2()
+                        .child(render_action_button(
+                            "sync-conflict-later-btn",
+                            i18n::t(&lang, "sync.conflict.later"),
+                            None,
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sync-conflict-remote-btn",
+                            i18n::t(&lang, "sync.conflict.keep_remote"),
+                            Some(ConflictResolution::KeepRemote),
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sync-conflict-local-btn",
+                            i18n::t(&lang, "sync.conflict.keep_local"),
+                            Some(ConflictResolution::KeepLocal),
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sync-conflict-merge-btn",
+                            i18n::t(&lang, "sync.conflict.merge"),
+                            Some(ConflictResolution::Merge),
+                            true,
+                            state,
+                            cx,
+                        )),
+                ),
+        )
+}
+
+/// 渲染一侧的内容概况
+fn render_side_row(
+    label: &'static str,
+    value: String,
+    label_color: Hsla,
+    value_color: Hsla,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .items_center()
+        .gap_3()
+        .text_sm()
+        .child(div().w(px(60.)).text_color(label_color).child(label))
+        .child(div().text_color(value_color).child(value))
+}
+
+/// 渲染处理按钮；`resolution` 为 None 表示稍后处理
+fn render_action_button(
+    id: &'static str,
+    label: &'static str,
+    resolution: Option<ConflictResolution>,
+    primary: bool,
+    state: Entity<SyncState>,
+    cx: &App,
+) -> impl IntoElement {
+    let (bg, hover_bg, text_color) = if primary {
+        (
+            cx.theme().primary,
+            cx.theme().primary_hover,
+            cx.theme().primary_foreground,
+        )
+    } else {
+        (
+            cx.theme().secondary,
+            cx.theme().secondary_hover,
+            cx.theme().foreground,
+        )
+    };
+
+    div()
+        .id(id)
+        .px_3()
+        .py_2()
+        .bg(bg)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                match resolution {
+                    Some(resolution) => s.resolve(resolution, cx),
+                    None => s.dismiss_conflict(),
+                }
+                cx.notify();
+            });
+        })
+        .child(div().text_sm().text_color(text_color).child(label))
+}
@@ -0,0 +1,7 @@
+// 数据同步状态与冲突对话框组件
+
+mod dialog;
+mod state;
+
+pub use dialog::render_sync_conflict_dialog_overlay;
+pub use state::SyncState;
@@ -0,0 +1,242 @@
+// 数据同步状态：手动/自动同步、测试连接与冲突处理
+
+use std::time::{Duration, Instant};
+
+use gpui::prelude::*;
+use gpui::Context;
+use tracing::{error, info};
+
+use crate::i18n;
+use crate::models::settings::{SyncInterval, SyncSettings};
+use crate::services::storage;
+use crate::services::sync::{self, ConflictResolution, GitCommit, SyncConflict, SyncOutcome};
+
+/// 自动同步检查间隔
+const AUTO_SYNC_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 后台同步任务
+enum SyncTask {
+    Sync,
+    Resolve(SyncConflict, ConflictResolution),
+    Test,
+    /// 读取 Git 仓库中服务器列表的历史
+    History,
+    /// 恢复到某个 Git 提交
+    Restore(String),
+}
+
+/// 后台任务回传的结果
+enum SyncTaskResult {
+    Synced(Result<SyncOutcome, String>),
+    Tested(Result<(), String>),
+    History(Result<Vec<GitCommit>, String>),
+    Restored(Result<usize, String>),
+}
+
+/// 数据同步状态
+#[derive(Default)]
+pub struct SyncState {
+    /// 是否有同步任务在执行
+    pub running: bool,
+    /// 最近一次同步/测试的结果
+    pub status: Option<Result<String, String>>,
+    /// 等待用户处理的冲突（有值时显示冲突对话框）
+    pub conflict: Option<SyncConflict>,
+    /// 拉取远程配置后需要刷新主页数据
+    pub needs_page_refresh: bool,
+    /// Git 仓库中服务器列表的历史版本（加载后显示在同步面板）
+    pub git_history: Option<Vec<GitCommit>>,
+    /// 产生冲突时使用的同步设置
+    conflict_settings: Option<SyncSettings>,
+    /// 上次自动同步的时间
+    last_auto_sync: Option<Instant>,
+}
+
+impl SyncState {
+    /// 立即同步
+    pub fn sync_now(&mut self, settings: SyncSettings, cx: &mut Context<Self>) {
+        self.run(SyncTask::Sync, settings, cx);
+    }
+
+    /// 测试 WebDAV 连接
+    pub fn test_connection(&mut self, settings: SyncSettings, cx: &mut Context<Self>) {
+        self.run(SyncTask::Test, settings, cx);
+    }
+
+    /// 加载 Git 历史版本
+    pub fn load_history(&mut self, settings: SyncSettings, cx: &mut Context<Self>) {
+        self.run(SyncTask::History, settings, cx);
+    }
+
+    /// 把服务器列表恢复到某个 Git 提交
+    pub fn restore_version(
+        &mut self,
+        settings: SyncSettings,
+        commit: String,
+        cx: &mut Context<Self>,
+    ) {
+        self.run(SyncTask::Restore(commit), settings, cx);
+    }
+
+    /// 按用户选择处理当前冲突
+    pub fn resolve(&mut self, resolution: ConflictResolution, cx: &mut Context<Self>) {
+        let (Some(conflict), Some(settings)) =
+            (self.conflict.take(), self.conflict_settings.take())
+        else {
+            return;
+        };
+        self.run(SyncTask::Resolve(conflict, resolution), settings, cx);
+    }
+
+    /// 暂不处理冲突（下次同步时会再次检测）
+    pub fn dismiss_conflict(&mut self) {
+        self.conflict = None;
+        self.conflict_settings = None;
+    }
+
+    /// 启动自动同步定时器：开启自动同步后按设置的频率同步，启动时先同步一次
+    pub fn start_auto_sync_timer(&self, cx: &mut Context<Self>) {
+        let sync_state = cx.entity().downgrade();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                loop {
+                    let result = async_cx
+                        .update(|cx| sync_state.update(cx, |state, cx| state.auto_sync_tick(cx)));
+                    // SyncState 已释放，退出循环
+                    if !matches!(result, Ok(Ok(()))) {
+                        break;
+                    }
+                    async_cx
+                        .background_executor()
+                        .timer(AUTO_SYNC_CHECK_INTERVAL)
+                        .await;
+                }
+            })
+            .detach();
+    }
+
+    fn auto_sync_tick(&mut self, cx: &mut Context<Self>) {
+        let settings = storage::load_settings().unwrap_or_default().sync;
+        if !settings.auto_sync || !sync::is_configured(&settings) {
+            return;
+        }
+        if self.running || self.conflict.is_some() {
+            return;
+        }
+        let due = match (&settings.sync_interval, self.last_auto_sync) {
+            (SyncInterval::Manual, _) => false,
+            (_, None) => true,
+            (SyncInterval::OnStartup, Some(_)) => false,
+            (SyncInterval::Hourly, Some(last)) => last.elapsed() >= Duration::from_secs(3600),
+            (SyncInterval::Daily, Some(last)) => last.elapsed() >= Duration::from_secs(86400),
+        };
+        if due {
+            info!("[Sync] Auto sync ({:?})", settings.sync_interval);
+            self.last_auto_sync = Some(Instant::now());
+            self.run(SyncTask::Sync, settings, cx);
+        }
+    }
+
+    fn run(&mut self, task: SyncTask, settings: SyncSettings, cx: &mut Context<Self>) {
+        if self.running {
+            return;
+        }
+        self.running = true;
+        self.status = None;
+        cx.notify();
+
+        let settings_for_task = settings.clone();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<SyncTaskResult>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let settings = settings_for_task;
+                let result = match task {
+                    SyncTask::Sync => SyncTaskResult::Synced(
+                        sync::sync(&settings).await.map_err(|e| format!("{:#}", e)),
+                    ),
+                    SyncTask::Resolve(conflict, resolution) => SyncTaskResult::Synced(
+                        sync::resolve(&settings, conflict, resolution)
+                            .await
+                            .map_err(|e| format!("{:#}", e)),
+                    ),
+                    SyncTask::Test => SyncTaskResult::Tested(
+                        sync::test_connection(&settings)
+                            .await
+                            .map_err(|e| format!("{:#}", e)),
+                    ),
+                    SyncTask::History => SyncTaskResult::History(
+                        sync::git_history(&settings)
+                            .await
+                            .map_err(|e| format!("{:#}", e)),
+                    ),
+                    SyncTask::Restore(commit) => SyncTaskResult::Restored(
+                        sync::restore_git_version(&settings, commit)
+                            .await
+                            .map_err(|e| format!("{:#}", e)),
+                    ),
+                };
+                let _ = tx.send(result);
+            });
+
+        let sync_state = cx.entity().downgrade();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    sync_state.update(cx, |state, cx| {
+                        state.running = false;
+                        state.finish(result, settings);
+                        cx.notify();
+                    })
+                });
+            })
+            .detach();
+    }
+
+    fn finish(&mut self, result: SyncTaskResult, settings: SyncSettings) {
+        let lang = storage::load_settings().unwrap_or_default().theme.language;
+        let key = match result {
+            SyncTaskResult::Tested(Ok(())) => "sync.result.test_ok",
+            SyncTaskResult::Synced(Ok(SyncOutcome::UpToDate)) => "sync.result.up_to_date",
+            SyncTaskResult::Synced(Ok(SyncOutcome::Pushed)) => "sync.result.pushed",
+            SyncTaskResult::Synced(Ok(SyncOutcome::Pulled)) => {
+                self.needs_page_refresh = true;
+                "sync.result.pulled"
+            }
+            SyncTaskResult::Synced(Ok(SyncOutcome::Merged)) => {
+                self.needs_page_refresh = true;
+                "sync.result.merged"
+            }
+            SyncTaskResult::Synced(Ok(SyncOutcome::Conflict(conflict))) => {
+                self.conflict = Some(conflict);
+                self.conflict_settings = Some(settings);
+                "sync.result.conflict"
+            }
+            SyncTaskResult::History(Ok(commits)) => {
+                self.git_history = Some(commits);
+                return;
+            }
+            SyncTaskResult::Restored(Ok(count)) => {
+                self.needs_page_refresh = true;
+                self.status = Some(Ok(format!(
+                    "{} ({})",
+                    i18n::t(&lang, "sync.result.restored"),
+                    count
+                )));
+                return;
+            }
+            SyncTaskResult::Synced(Err(e))
+            | SyncTaskResult::Tested(Err(e))
+            | SyncTaskResult::History(Err(e))
+            | SyncTaskResult::Restored(Err(e)) => {
+                error!("[Sync] {}", e);
+                self.status = Some(Err(e));
+                return;
+            }
+        };
+        self.status = Some(Ok(i18n::t(&lang, key).to_string()));
+    }
+}
@@ -0,0 +1,272 @@
+// 会话工作区弹窗渲染
+
+use gpui::prelude::*;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::scroll::ScrollableElement;
+use gpui_component::{ActiveTheme, Sizable};
+
+use super::state::WorkspaceDialogState;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::workspace::Workspace;
+use crate::services::storage;
+
+/// 渲染会话工作区弹窗覆盖层
+pub fn render_workspace_dialog_overlay(
+    state: Entity<WorkspaceDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let has_sessions = state_read.session_state.read(cx).has_sessions();
+    let last_session = state_read.config.last_session.clone();
+    // 最近保存的在前
+    let workspaces: Vec<Workspace> = state_read.config.workspaces.iter().rev().cloned().collect();
+    let is_empty = last_session.is_none() && workspaces.is_empty();
+
+    div()
+        .id("workspace-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(480.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(&lang, "workspace.title")),
+                )
+                // 保存当前会话
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(&lang, "workspace.save_current")),
+                        )
+                        .child(
+                            div()
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .child(div().flex_1().child(
+                                    if let Some(input) = &state_read.name_input {
+                                        Input::new(input).into_any_element()
+                                    } else {
+                                        div()
+                                            .text_sm()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(i18n::t(&lang, "common.loading"))
+                                            .into_any_element()
+                                    },
+                                ))
+                                .child(
+                                    Button::new("workspace-save")
+                                        .primary()
+                                        .disabled(!has_sessions)
+                                        .child(i18n::t(&lang, "common.save"))
+                                        .on_click({
+                                            let state = state.clone();
+                                            move |_, window, cx| {
+                                                state.update(cx, |s, cx| {
+                                                    s.save_current(window, cx);
+                                                    cx.notify();
+                                                });
+                                            }
+                                        }),
+                                ),
+                        ),
+                )
+                .children(
+                    state_read
+                        .error
+                        .clone()
+                        .map(|error| div().text_sm().text_color(cx.theme().danger).child(error)),
+                )
+                // 工作区列表
+                .child(if is_empty {
+                    div()
+                        .py_6()
+                        .flex()
+                        .justify_center()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(i18n::t(&lang, "workspace.empty"))
+                        .into_any_element()
+                } else {
+                    div()
+                        .id("workspace-list")
+                        .max_h(px(320.))
+                        .overflow_y_scrollbar()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .children(last_session.map(|workspace| {
+                            render_workspace_row(0, workspace, true, &lang, state.clone(), cx)
+                        }))
+                        .children(
+                            workspaces
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, workspace)| {
+                                    render_workspace_row(
+                                        index + 1,
+                                        workspace,
+                                        false,
+                                        &lang,
+                                        state.clone(),
+                                        cx,
+                                    )
+                                }),
+                        )
+                        .into_any_element()
+                })
+                // 底部按钮
+                .child(
+                    div().flex().justify_end().pt_2().child(
+                        Button::new("workspace-dialog-close")
+                            .outline()
+                            .child(i18n::t(&lang, "common.close"))
+                            .on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.close();
+                                    cx.notify();
+                                });
+                            }),
+                    ),
+                ),
+        )
+}
+
+/// 渲染一个工作区（上次会话不可删除）
+fn render_workspace_row(
+    index: usize,
+    workspace: Workspace,
+    is_last_session: bool,
+    lang: &Language,
+    state: Entity<WorkspaceDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let name = if is_last_session {
+        i18n::t(lang, "workspace.last_session").to_string()
+    } else {
+        workspace.name.clone()
+    };
+    let saved_at = chrono::DateTime::parse_from_rfc3339(&workspace.saved_at)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    let servers = workspace
+        .tabs
+        .iter()
+        .map(|t| t.server_label.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let workspace_id = workspace.id.clone();
+    let state_for_delete = state.clone();
+
+    div()
+        .id(("workspace-row", index))
+        .px_3()
+        .py_2()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .gap_3()
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(cx.theme().foreground)
+                        .child(name),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(format!(
+                            "{} · {} · {}",
+                            saved_at,
+                            i18n::t(lang, "workspace.tabs")
+                                .replace("{}", &workspace.tabs.len().to_string()),
+                            servers
+                        )),
+                ),
+        )
+        .child(
+            div()
+                .flex()
+                .gap_1()
+                .child(
+                    Button::new(("workspace-open", index))
+                        .xsmall()
+                        .ghost()
+                        .child(i18n::t(lang, "workspace.open"))
+                        .on_click(move |_, _, cx| {
+                            let workspace = workspace.clone();
+                            state.update(cx, |s, cx| {
+                                s.restore(workspace, cx);
+                                cx.notify();
+                            });
+                        }),
+                )
+                .when(!is_last_session, |this| {
+                    this.child(
+                        Button::new(("workspace-delete", index))
+                            .xsmall()
+                            .ghost()
+                            .child(i18n::t(lang, "common.delete"))
+                            .on_click(move |_, _, cx| {
+                                state_for_delete.update(cx, |s, cx| {
+                                    s.delete(&workspace_id);
+                                    cx.notify();
+                                });
+                            }),
+                    )
+                }),
+        )
+}
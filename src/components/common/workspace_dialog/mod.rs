@@ -0,0 +1,7 @@
+// 会话工作区弹窗：保存当前打开的会话，打开或删除已保存的工作区
+
+mod dialog;
+mod state;
+
+pub use dialog::render_workspace_dialog_overlay;
+pub use state::WorkspaceDialogState;
@@ -0,0 +1,118 @@
+// 会话工作区弹窗状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tracing::{error, info};
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::workspace::{Workspace, WorkspacesConfig};
+use crate::services::storage;
+use crate::state::SessionState;
+
+/// 会话工作区弹窗状态
+pub struct WorkspaceDialogState {
+    pub visible: bool,
+    pub session_state: Entity<SessionState>,
+    /// 输入框：保存当前会话时的工作区名称
+    pub name_input: Option<Entity<InputState>>,
+    /// 打开弹窗时加载的工作区
+    pub config: WorkspacesConfig,
+    /// 校验或保存失败的错误信息
+    pub error: Option<String>,
+}
+
+impl WorkspaceDialogState {
+    pub fn new(session_state: Entity<SessionState>) -> Self {
+        Self {
+            visible: false,
+            session_state,
+            name_input: None,
+            config: WorkspacesConfig::default(),
+            error: None,
+        }
+    }
+
+    /// 打开弹窗并重新加载工作区列表
+    pub fn open(&mut self) {
+        self.visible = true;
+        self.error = None;
+        self.reload();
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.error = None;
+    }
+
+    fn reload(&mut self) {
+        self.config = storage::load_workspaces().unwrap_or_default();
+    }
+
+    /// 确保输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.name_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "workspace.name_placeholder");
+            self.name_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+    }
+
+    /// 把当前打开的会话保存为工作区（同名的工作区被覆盖）
+    pub fn save_current(&mut self, window: &mut Window, cx: &mut App) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let name = self
+            .name_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default();
+        if name.is_empty() {
+            self.error = Some(i18n::t(&lang, "workspace.name_empty").to_string());
+            return;
+        }
+        let Some(workspace) = self.session_state.read(cx).workspace_snapshot(name) else {
+            self.error = Some(i18n::t(&lang, "workspace.no_sessions").to_string());
+            return;
+        };
+
+        match storage::save_workspace(workspace) {
+            Ok(()) => {
+                info!("[Workspace] Saved workspace");
+                self.error = None;
+                if let Some(input) = &self.name_input {
+                    input.update(cx, |s, cx| s.set_value("", window, cx));
+                }
+                self.reload();
+            }
+            Err(e) => {
+                error!("[Workspace] Failed to save workspace: {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+
+    /// 打开工作区中的会话并关闭弹窗
+    pub fn restore(&mut self, workspace: Workspace, cx: &mut App) {
+        self.session_state.update(cx, |state, cx| {
+            state.restore_workspace(&workspace, cx);
+            cx.notify();
+        });
+        self.close();
+    }
+
+    /// 删除已保存的工作区
+    pub fn delete(&mut self, workspace_id: &str) {
+        match storage::delete_workspace(workspace_id) {
+            Ok(()) => self.reload(),
+            Err(e) => {
+                error!("[Workspace] Failed to delete workspace: {:#}", e);
+                self.error = Some(format!("{:#}", e));
+            }
+        }
+    }
+}
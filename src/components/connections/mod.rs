@@ -0,0 +1,5 @@
+// 网络连接组件模块
+
+mod view;
+
+pub use view::{ConnectionEvent, ConnectionListView};
@@ -0,0 +1,601 @@
+// 网络连接视图
+// 显示在 Monitor 区域的"网络"页：过滤栏 + 按进程分组的监听端口和已建立连接，右键在终端查看或结束所属进程（需确认）
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{ContextMenuExt, PopupMenu, PopupMenuItem};
+use gpui_component::{ActiveTheme, Sizable, StyledExt};
+
+use crate::components::common::confirm_bar::{self, ConfirmBarHost, ConfirmTone};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::connections::ConnectionEntry;
+use crate::services::processes::ProcessSignal;
+
+/// 网络连接事件
+#[derive(Clone, Debug)]
+pub enum ConnectionEvent {
+    /// 向占用端口的进程发送信号
+    Kill(u32, ProcessSignal),
+    /// 在新终端中查看本地端口相关的套接字
+    Terminal(String),
+}
+
+/// 显示范围
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ConnectionScope {
+    All,
+    Listening,
+    Established,
+}
+
+/// 等待确认的结束进程操作
+#[derive(Clone, Debug)]
+pub struct PendingKill {
+    pid: u32,
+    name: String,
+    signal: ProcessSignal,
+}
+
+/// 同一进程的套接字
+struct ProcessGroup {
+    pid: Option<u32>,
+    name: String,
+    connections: Vec<ConnectionEntry>,
+}
+
+/// 网络连接视图
+pub struct ConnectionListView {
+    connections: Vec<ConnectionEntry>,
+    filter_input: Entity<InputState>,
+    /// 过滤条件（小写，匹配进程名、PID、地址和状态）
+    filter: String,
+    scope: ConnectionScope,
+    /// 是否已收到过连接列表
+    loaded: bool,
+    /// 最近一次获取列表的错误
+    error: Option<String>,
+    /// 最近一次操作失败的提示
+    action_error: Option<String>,
+    /// 等待确认的操作
+    pending: Option<PendingKill>,
+    on_event: Rc<dyn Fn(ConnectionEvent, &mut App)>,
+}
+
+impl ConnectionListView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(ConnectionEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        // 输入过滤条件时即时过滤连接列表
+        let filter_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "connections.filter_placeholder"))
+        });
+        cx.subscribe(&filter_input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                this.filter = input.read(cx).value().trim().to_lowercase();
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            connections: Vec::new(),
+            filter_input,
+            filter: String::new(),
+            scope: ConnectionScope::All,
+            loaded: false,
+            error: None,
+            action_error: None,
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入最新的连接列表
+    pub fn set_connections(
+        &mut self,
+        result: Result<Vec<ConnectionEntry>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(connections) => {
+                self.loaded = true;
+                self.error = None;
+                self.connections = connections;
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 显示操作失败的提示
+    pub fn set_action_error(&mut self, error: Option<String>, cx: &mut Context<Self>) {
+        self.action_error = error;
+        cx.notify();
+    }
+
+    /// 发送事件（在终端查看无需确认）
+    fn emit(&mut self, event: ConnectionEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    /// 请求结束进程：先显示确认栏
+    fn request_kill(
+        &mut self,
+        pid: u32,
+        name: String,
+        signal: ProcessSignal,
+        cx: &mut Context<Self>,
+    ) {
+        self.action_error = None;
+        self.request_confirm(PendingKill { pid, name, signal }, cx);
+    }
+
+    fn set_scope(&mut self, scope: ConnectionScope, cx: &mut Context<Self>) {
+        self.scope = scope;
+        cx.notify();
+    }
+
+    /// 按范围和过滤条件筛选后，按所属进程分组（进程名排序，无权限查看的进程排在最后）
+    fn visible_groups(&self) -> Vec<ProcessGroup> {
+        let filter = &self.filter;
+        let mut groups: Vec<ProcessGroup> = Vec::new();
+        for connection in &self.connections {
+            let in_scope = match self.scope {
+                ConnectionScope::All => true,
+                ConnectionScope::Listening => connection.is_listening(),
+                ConnectionScope::Established => !connection.is_listening(),
+            };
+            let matches = filter.is_empty()
+                || connection.process.to_lowercase().contains(filter)
+                || connection.pid.is_some_and(|pid| pid.to_string() == *filter)
+                || connection.local.to_lowercase().contains(filter)
+                || connection.peer.to_lowercase().contains(filter)
+                || connection.state.to_lowercase().contains(filter);
+            if !in_scope || !matches {
+                continue;
+            }
+            match groups
+                .iter_mut()
+                .find(|g| g.pid == connection.pid && g.name == connection.process)
+            {
+                Some(group) => group.connections.push(connection.clone()),
+                None => groups.push(ProcessGroup {
+                    pid: connection.pid,
+                    name: connection.process.clone(),
+                    connections: vec![connection.clone()],
+                }),
+            }
+        }
+        groups.sort_by(|a, b| {
+            a.pid
+                .is_none()
+                .cmp(&b.pid.is_none())
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+                .then_with(|| a.pid.cmp(&b.pid))
+        });
+        groups
+    }
+
+    /// 渲染分组标题：进程名、PID 和监听/连接数量
+    fn render_group_header(
+        &self,
+        ix: usize,
+        group: &ProcessGroup,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let listening = group
+            .connections
+            .iter()
+            .filter(|c| c.is_listening())
+            .count();
+        let summary = t(lang, "connections.group_summary")
+            .replace("{listening}", &listening.to_string())
+            .replace(
+                "{established}",
+                &(group.connections.len() - listening).to_string(),
+            );
+        let name = if group.name.is_empty() {
+            t(lang, "connections.unknown_process").to_string()
+        } else {
+            group.name.clone()
+        };
+        let entity = cx.entity().clone();
+        let pid = group.pid;
+        let name_for_menu = name.clone();
+
+        let header = div()
+            .id(("connection-group", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .bg(cx.theme().secondary)
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .font_medium()
+                    .text_color(cx.theme().foreground)
+                    .child(match pid {
+                        Some(pid) => format!("{} · {}", name, pid),
+                        None => name,
+                    }),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(summary),
+            );
+
+        // 知道 PID 时才能结束进程
+        match pid {
+            Some(pid) => header
+                .context_menu(move |menu, _window, _cx| {
+                    let lang = crate::services::storage::load_settings()
+                        .map(|s| s.theme.language)
+                        .unwrap_or_default();
+                    build_kill_menu(menu, pid, name_for_menu.clone(), &lang, entity.clone())
+                })
+                .into_any_element(),
+            None => header.into_any_element(),
+        }
+    }
+
+    /// 渲染套接字行：协议、本地地址、对端地址和状态
+    fn render_connection(
+        &self,
+        id: SharedString,
+        connection: &ConnectionEntry,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let listening = connection.is_listening();
+        let badge_color = if listening {
+            cx.theme().success
+        } else {
+            cx.theme().primary
+        };
+        let state_text = if listening {
+            t(lang, "connections.listening")
+        } else {
+            t(lang, "connections.established")
+        };
+        let entity = cx.entity().clone();
+        let connection_for_menu = connection.clone();
+
+        div()
+            .id(id)
+            .w_full()
+            .pl_4()
+            .pr_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .child(
+                div()
+                    .w(px(28.))
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(connection.protocol.clone()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(if listening {
+                        connection.local.clone()
+                    } else {
+                        format!("{} → {}", connection.local, connection.peer)
+                    }),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .text_color(badge_color)
+                    .bg(badge_color.opacity(0.12))
+                    .child(state_text),
+            )
+            .context_menu(move |menu, _window, _cx| {
+                let lang = crate::services::storage::load_settings()
+                    .map(|s| s.theme.language)
+                    .unwrap_or_default();
+                build_connection_context_menu(
+                    menu,
+                    connection_for_menu.clone(),
+                    &lang,
+                    entity.clone(),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// 渲染范围切换按钮
+    fn render_scope_tab(
+        &self,
+        id: &'static str,
+        label: &'static str,
+        scope: ConnectionScope,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let selected = self.scope == scope;
+        let muted_foreground = cx.theme().muted_foreground;
+
+        div()
+            .id(id)
+            .flex_shrink_0()
+            .px_1()
+            .py_0p5()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .text_xs()
+            .when(selected, |this| {
+                this.bg(cx.theme().muted).text_color(cx.theme().foreground)
+            })
+            .when(!selected, |this| {
+                this.text_color(muted_foreground)
+                    .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+            })
+            .child(label)
+            .on_click(cx.listener(move |this, _, _, cx| this.set_scope(scope, cx)))
+    }
+
+    /// 渲染确认栏
+    fn render_confirm_bar(
+        &self,
+        pending: &PendingKill,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = match pending.signal {
+            ProcessSignal::Term => t(lang, "processes.confirm_kill"),
+            ProcessSignal::Kill => t(lang, "processes.confirm_force_kill"),
+        }
+        .replace("{pid}", &pending.pid.to_string())
+        .replace("{name}", &pending.name);
+        confirm_bar::render_confirm_bar(
+            "connections-confirm",
+            message,
+            ConfirmTone::Danger,
+            lang,
+            cx,
+        )
+    }
+}
+
+impl ConfirmBarHost for ConnectionListView {
+    type Pending = PendingKill;
+
+    fn pending_mut(&mut self) -> &mut Option<PendingKill> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, pending: PendingKill, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(ConnectionEvent::Kill(pending.pid, pending.signal), cx);
+    }
+}
+
+/// 在菜单中追加结束进程、强制结束两项
+fn build_kill_menu(
+    menu: PopupMenu,
+    pid: u32,
+    name: String,
+    lang: &Language,
+    entity: Entity<ConnectionListView>,
+) -> PopupMenu {
+    let kill_item = |label: &str, signal: ProcessSignal| {
+        let entity = entity.clone();
+        let name = name.clone();
+        PopupMenuItem::new(label.to_string()).on_click(move |_, _, cx| {
+            entity.update(cx, |view, cx| {
+                view.request_kill(pid, name.clone(), signal, cx)
+            });
+        })
+    };
+    menu.min_w(px(180.))
+        .label(format!("{} · {}", pid, name))
+        .item(kill_item(t(lang, "processes.kill"), ProcessSignal::Term))
+        .item(kill_item(
+            t(lang, "processes.force_kill"),
+            ProcessSignal::Kill,
+        ))
+}
+
+/// 构建套接字右键菜单：在终端查看、结束所属进程
+fn build_connection_context_menu(
+    menu: PopupMenu,
+    connection: ConnectionEntry,
+    lang: &Language,
+    entity: Entity<ConnectionListView>,
+) -> PopupMenu {
+    let port = connection.local_port().to_string();
+    let menu = menu.min_w(px(180.)).label(connection.local.clone()).item(
+        PopupMenuItem::new(t(lang, "connections.open_in_terminal")).on_click({
+            let entity = entity.clone();
+            move |_, _, cx| {
+                entity.update(cx, |view, cx| {
+                    view.emit(ConnectionEvent::Terminal(port.clone()), cx)
+                });
+            }
+        }),
+    );
+    match connection.pid {
+        Some(pid) => build_kill_menu(menu.separator(), pid, connection.process, lang, entity),
+        None => menu,
+    }
+}
+
+impl Render for ConnectionListView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+
+        let groups = self.visible_groups();
+        let visible: usize = groups.iter().map(|g| g.connections.len()).sum();
+        let total = self.connections.len();
+        let count_text = if visible == total {
+            t(&lang, "connections.count").replace("{}", &total.to_string())
+        } else {
+            format!("{} / {}", visible, total)
+        };
+
+        let filter_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    Input::new(&self.filter_input)
+                        .xsmall()
+                        .cleanable(true)
+                        .prefix(svg().path(icons::FILTER).size(px(12.)).text_color(muted)),
+                ),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(count_text),
+            );
+
+        let scope_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(self.render_scope_tab(
+                "connections-scope-all",
+                t(&lang, "connections.scope_all"),
+                ConnectionScope::All,
+                cx,
+            ))
+            .child(self.render_scope_tab(
+                "connections-scope-listening",
+                t(&lang, "connections.listening"),
+                ConnectionScope::Listening,
+                cx,
+            ))
+            .child(self.render_scope_tab(
+                "connections-scope-established",
+                t(&lang, "connections.established"),
+                ConnectionScope::Established,
+                cx,
+            ));
+
+        let body = if self.loaded {
+            let mut rows: Vec<AnyElement> = Vec::new();
+            for (group_ix, group) in groups.iter().enumerate() {
+                rows.push(self.render_group_header(group_ix, group, &lang, cx));
+                for (ix, connection) in group.connections.iter().enumerate() {
+                    let id = SharedString::from(format!("connection-{}-{}", group_ix, ix));
+                    rows.push(self.render_connection(id, connection, &lang, cx));
+                }
+            }
+            div()
+                .id("connections-list")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .children(rows)
+                .into_any_element()
+        } else {
+            let hint = match &self.error {
+                Some(e) => format!("{}: {}", t(&lang, "connections.load_failed"), e),
+                None => t(&lang, "common.loading").to_string(),
+            };
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .px_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()
+        };
+
+        let action_error = self.action_error.clone();
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(filter_bar)
+            .child(scope_bar)
+            .child(body)
+            .when_some(action_error, |this, error| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(format!(
+                            "{}: {}",
+                            t(&lang, "processes.action_failed"),
+                            error
+                        )),
+                )
+            })
+            .when_some(pending, |this, pending| {
+                this.child(self.render_confirm_bar(&pending, &lang, cx))
+            })
+    }
+}
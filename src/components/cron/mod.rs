@@ -0,0 +1,5 @@
+// cron 计划任务组件模块
+
+mod view;
+
+pub use view::{describe_schedule, CronEvent, CronView};
@@ -0,0 +1,974 @@
+// cron 计划任务视图
+// 显示在 Monitor 区域的"计划任务"页：当前用户的 crontab（可新增、编辑、启停、删除，保存时统一写回）
+// 以及只读的 /etc/cron.d 任务；每条任务显示可读的计划说明和下次运行时间
+
+use std::rc::Rc;
+
+use chrono::Local;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{ContextMenuExt, PopupMenu, PopupMenuItem};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::components::common::confirm_bar::{
+    self, render_bar_button, ConfirmBarHost, ConfirmTone,
+};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::cron::{serialize_crontab, CronEntry, CronSchedule, CrontabLine, UserCrontab};
+
+/// 编辑器中预览的运行次数
+const PREVIEW_RUNS: usize = 3;
+
+/// 新建任务的默认计划（每天 03:00）
+const DEFAULT_SCHEDULE: &str = "0 3 * * *";
+
+/// 计划任务事件
+#[derive(Clone, Debug)]
+pub enum CronEvent {
+    /// 重新读取当前用户的 crontab
+    Reload,
+    /// 通过 sudo 读取 /etc/cron.d
+    LoadSystem,
+    /// 写回 crontab（`original` 为读取时的原文，用于确认远端未被修改）
+    Save { original: String, content: String },
+}
+
+/// 正在编辑的任务
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditTarget {
+    /// 编辑已有任务（crontab 行下标）
+    Existing(usize),
+    New,
+}
+
+/// 等待确认的操作
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PendingAction {
+    Save,
+    /// 放弃未保存的修改并重新读取
+    Reload,
+}
+
+/// cron 计划任务视图
+pub struct CronView {
+    /// 最近一次读取的 crontab（None 表示尚未读取到）
+    crontab: Option<UserCrontab>,
+    /// 编辑中的 crontab 行
+    lines: Vec<CrontabLine>,
+    /// 是否有未保存的修改
+    dirty: bool,
+    /// 最近一次读取 crontab 的错误
+    error: Option<String>,
+    /// /etc/cron.d 中的任务（None 表示尚未读取）
+    system_entries: Option<Vec<CronEntry>>,
+    system_loading: bool,
+    system_error: Option<String>,
+    editing: Option<EditTarget>,
+    schedule_input: Entity<InputState>,
+    command_input: Entity<InputState>,
+    /// 编辑器中的校验错误
+    edit_error: Option<String>,
+    saving: bool,
+    /// 保存成功后的提示（包含备份路径）
+    status: Option<String>,
+    /// 最近一次保存失败的提示
+    action_error: Option<String>,
+    pending: Option<PendingAction>,
+    on_event: Rc<dyn Fn(CronEvent, &mut App)>,
+}
+
+impl CronView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(CronEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        // 输入计划表达式时即时刷新说明和运行时间预览
+        let schedule_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "cron.schedule_placeholder"))
+        });
+        cx.subscribe(&schedule_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                this.edit_error = None;
+                cx.notify();
+            }
+        })
+        .detach();
+        let command_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "cron.command_placeholder"))
+        });
+
+        Self {
+            crontab: None,
+            lines: Vec::new(),
+            dirty: false,
+            error: None,
+            system_entries: None,
+            system_loading: false,
+            system_error: None,
+            editing: None,
+            schedule_input,
+            command_input,
+            edit_error: None,
+            saving: false,
+            status: None,
+            action_error: None,
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入最新读取的 crontab（会丢弃未保存的修改）
+    pub fn set_crontab(&mut self, result: Result<UserCrontab, String>, cx: &mut Context<Self>) {
+        match result {
+            Ok(crontab) => {
+                self.error = None;
+                self.lines = crontab.lines.clone();
+                self.crontab = Some(crontab);
+                self.dirty = false;
+                self.editing = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 写入 /etc/cron.d 的任务
+    pub fn set_system_entries(
+        &mut self,
+        result: Result<Vec<CronEntry>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        self.system_loading = false;
+        match result {
+            Ok(entries) => {
+                self.system_error = None;
+                self.system_entries = Some(entries);
+            }
+            Err(e) => self.system_error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 取消读取 /etc/cron.d（如关闭了 sudo 密码对话框）
+    pub fn cancel_system_loading(&mut self, cx: &mut Context<Self>) {
+        self.system_loading = false;
+        cx.notify();
+    }
+
+    /// 保存完成：显示备份路径（原来没有 crontab 时不备份）
+    pub fn set_saved(&mut self, backup: Option<String>, cx: &mut Context<Self>) {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        self.saving = false;
+        self.action_error = None;
+        self.status = Some(match backup {
+            Some(path) => t(&lang, "cron.saved_with_backup").replace("{}", &path),
+            None => t(&lang, "cron.saved").to_string(),
+        });
+        cx.notify();
+    }
+
+    /// 显示保存失败的提示
+    pub fn set_action_error(&mut self, error: Option<String>, cx: &mut Context<Self>) {
+        self.saving = false;
+        self.action_error = error;
+        cx.notify();
+    }
+
+    /// 发送事件
+    fn emit(&mut self, event: CronEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        self.status = None;
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    /// 重新读取：有未保存的修改时先确认
+    fn request_reload(&mut self, cx: &mut Context<Self>) {
+        if self.dirty {
+            self.request_confirm(PendingAction::Reload, cx);
+        } else {
+            self.emit(CronEvent::Reload, cx);
+        }
+    }
+
+    /// 读取 /etc/cron.d
+    fn load_system(&mut self, cx: &mut Context<Self>) {
+        self.system_loading = true;
+        self.system_error = None;
+        self.emit(CronEvent::LoadSystem, cx);
+    }
+
+    /// 请求保存：先显示确认栏
+    fn request_save(&mut self, cx: &mut Context<Self>) {
+        if self.dirty && !self.saving {
+            self.request_confirm(PendingAction::Save, cx);
+        }
+    }
+
+    /// 打开编辑器：编辑已有任务或新建任务
+    fn start_edit(&mut self, target: EditTarget, window: &mut Window, cx: &mut Context<Self>) {
+        let (schedule, command) = match target {
+            EditTarget::Existing(index) => match self.lines.get(index) {
+                Some(CrontabLine::Entry(entry)) => (entry.schedule.clone(), entry.command.clone()),
+                _ => return,
+            },
+            EditTarget::New => (DEFAULT_SCHEDULE.to_string(), String::new()),
+        };
+        self.schedule_input
+            .update(cx, |s, cx| s.set_value(schedule, window, cx));
+        self.command_input
+            .update(cx, |s, cx| s.set_value(command, window, cx));
+        self.editing = Some(target);
+        self.edit_error = None;
+        cx.notify();
+    }
+
+    /// 关闭编辑器
+    fn cancel_edit(&mut self, cx: &mut Context<Self>) {
+        self.editing = None;
+        self.edit_error = None;
+        cx.notify();
+    }
+
+    /// 校验编辑器内容并写入编辑中的 crontab（需要保存后才会写回服务器）
+    fn apply_edit(&mut self, cx: &mut Context<Self>) {
+        let Some(target) = self.editing else {
+            return;
+        };
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let schedule = self.schedule_input.read(cx).value().trim().to_string();
+        let command = self.command_input.read(cx).value().trim().to_string();
+        if let Err(e) = CronSchedule::parse(&schedule) {
+            self.edit_error = Some(format!("{}: {}", t(&lang, "cron.hint.invalid"), e));
+            cx.notify();
+            return;
+        }
+        if command.is_empty() || command.contains('\n') {
+            self.edit_error = Some(t(&lang, "cron.command_required").to_string());
+            cx.notify();
+            return;
+        }
+        let schedule = schedule.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        match target {
+            EditTarget::Existing(index) => {
+                if let Some(CrontabLine::Entry(entry)) = self.lines.get_mut(index) {
+                    entry.schedule = schedule;
+                    entry.command = command;
+                }
+            }
+            EditTarget::New => self.lines.push(CrontabLine::Entry(CronEntry {
+                schedule,
+                command,
+                enabled: true,
+                user: None,
+                source: None,
+            })),
+        }
+        self.dirty = true;
+        self.status = None;
+        self.editing = None;
+        cx.notify();
+    }
+
+    /// 启用或停用任务（停用的任务以注释保存）
+    fn toggle_enabled(&mut self, index: usize, cx: &mut Context<Self>) {
+        if let Some(CrontabLine::Entry(entry)) = self.lines.get_mut(index) {
+            entry.enabled = !entry.enabled;
+            self.dirty = true;
+            self.status = None;
+            cx.notify();
+        }
+    }
+
+    /// 删除任务
+    fn delete(&mut self, index: usize, cx: &mut Context<Self>) {
+        if matches!(self.lines.get(index), Some(CrontabLine::Entry(_))) {
+            self.lines.remove(index);
+            // 删除后行下标会变化，关闭编辑器
+            self.editing = None;
+            self.dirty = true;
+            self.status = None;
+            cx.notify();
+        }
+    }
+
+    /// 渲染工具栏图标按钮
+    fn render_icon_button(
+        &self,
+        id: &'static str,
+        icon: &'static str,
+        tooltip: &'static str,
+        highlighted: bool,
+        on_click: impl Fn(&mut Self, &mut Window, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let hover_bg = cx.theme().list_active;
+        div()
+            .id(id)
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(svg().path(icon).size(px(14.)).text_color(if highlighted {
+                cx.theme().primary
+            } else {
+                cx.theme().muted_foreground
+            }))
+            .tooltip(move |window, cx| Tooltip::new(tooltip).build(window, cx))
+            .on_click(cx.listener(move |this, _, window, cx| on_click(this, window, cx)))
+    }
+
+    /// 渲染用户 crontab 中的一条任务
+    fn render_entry(
+        &self,
+        index: usize,
+        entry: &CronEntry,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let selected = self.editing == Some(EditTarget::Existing(index));
+        let (hint, next_run) = schedule_summary(&entry.schedule, lang);
+        let enabled_color = if entry.enabled {
+            cx.theme().success
+        } else {
+            cx.theme().muted_foreground
+        };
+        let entity = cx.entity().clone();
+        let enabled = entry.enabled;
+
+        div()
+            .id(("cron-row", index))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_start()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .cursor_pointer()
+            .when(selected, |this| this.bg(cx.theme().muted))
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.start_edit(EditTarget::Existing(index), window, cx)
+            }))
+            // 启用状态（点击切换）
+            .child(
+                div()
+                    .id(("cron-toggle", index))
+                    .flex_shrink_0()
+                    .mt(px(4.))
+                    .size(px(8.))
+                    .rounded_full()
+                    .bg(enabled_color)
+                    .tooltip(move |window, cx| {
+                        let lang = crate::services::storage::load_settings()
+                            .map(|s| s.theme.language)
+                            .unwrap_or_default();
+                        Tooltip::new(if enabled {
+                            t(&lang, "cron.disable")
+                        } else {
+                            t(&lang, "cron.enable")
+                        })
+                        .build(window, cx)
+                    })
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        cx.stop_propagation();
+                        this.toggle_enabled(index, cx)
+                    })),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .when(!entry.enabled, |this| this.opacity(0.6))
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .gap_2()
+                            .text_xs()
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .min_w_0()
+                                    .overflow_hidden()
+                                    .whitespace_nowrap()
+                                    .text_ellipsis()
+                                    .text_color(cx.theme().foreground)
+                                    .child(hint),
+                            )
+                            .when_some(next_run, |this, next| {
+                                this.child(
+                                    div()
+                                        .flex_shrink_0()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(next),
+                                )
+                            }),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(format!("{}  {}", entry.schedule, entry.command)),
+                    ),
+            )
+            .context_menu(move |menu, _window, _cx| {
+                let lang = crate::services::storage::load_settings()
+                    .map(|s| s.theme.language)
+                    .unwrap_or_default();
+                build_entry_context_menu(menu, index, enabled, &lang, entity.clone())
+            })
+            .into_any_element()
+    }
+
+    /// 渲染编辑器：计划表达式（附说明和接下来几次运行时间）与命令
+    fn render_editor(&self, lang: &Language, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let schedule = self.schedule_input.read(cx).value().to_string();
+        let (hint, runs) = match CronSchedule::parse(&schedule) {
+            Ok(parsed) => (
+                describe_schedule(&parsed, lang),
+                parsed
+                    .next_runs(Local::now(), PREVIEW_RUNS)
+                    .into_iter()
+                    .map(|run| run.format("%Y-%m-%d %H:%M").to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            Err(_) => (t(lang, "cron.hint.invalid").to_string(), Vec::new()),
+        };
+        let title = match self.editing {
+            Some(EditTarget::New) => t(lang, "cron.new"),
+            _ => t(lang, "cron.edit"),
+        };
+
+        div()
+            .w_full()
+            .flex_shrink_0()
+            .px_2()
+            .py_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().secondary)
+            .text_xs()
+            .child(div().text_color(cx.theme().foreground).child(title))
+            .child(Input::new(&self.schedule_input).xsmall())
+            .child(div().text_color(muted).child(hint))
+            .when(!runs.is_empty(), |this| {
+                this.child(div().text_color(muted).child(format!(
+                    "{}: {}",
+                    t(lang, "cron.next_runs"),
+                    runs.join(", ")
+                )))
+            })
+            .child(Input::new(&self.command_input).xsmall())
+            .when_some(self.edit_error.clone(), |this, error| {
+                this.child(div().text_color(cx.theme().danger).child(error))
+            })
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_end()
+                    .gap_2()
+                    .child(render_bar_button(
+                        "cron-edit-cancel",
+                        t(lang, "common.cancel"),
+                        cx.theme().secondary_foreground,
+                        cx.theme().background,
+                        cx.listener(|this, _, _, cx| this.cancel_edit(cx)),
+                    ))
+                    .child(render_bar_button(
+                        "cron-edit-apply",
+                        t(lang, "cron.apply"),
+                        cx.theme().primary_foreground,
+                        cx.theme().primary,
+                        cx.listener(|this, _, _, cx| this.apply_edit(cx)),
+                    )),
+            )
+    }
+
+    /// 渲染 /etc/cron.d 区域（只读）
+    fn render_system_section(&self, lang: &Language, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted = cx.theme().muted_foreground;
+        let header = div()
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .bg(cx.theme().muted.opacity(0.5))
+            .text_xs()
+            .text_color(muted)
+            .child(t(lang, "cron.system_title"))
+            .when(!self.system_loading, |this| {
+                this.child(
+                    div()
+                        .id("cron-load-system")
+                        .flex_shrink_0()
+                        .cursor_pointer()
+                        .text_color(cx.theme().primary)
+                        .hover(|s| s.opacity(0.8))
+                        .child(if self.system_entries.is_some() {
+                            t(lang, "cron.system_reload")
+                        } else {
+                            t(lang, "cron.system_load")
+                        })
+                        .on_click(cx.listener(|this, _, _, cx| this.load_system(cx))),
+                )
+            });
+
+        let body = if self.system_loading {
+            vec![div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(muted)
+                .child(t(lang, "common.loading"))
+                .into_any_element()]
+        } else if let Some(error) = &self.system_error {
+            vec![div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .child(format!("{}: {}", t(lang, "cron.load_failed"), error))
+                .into_any_element()]
+        } else {
+            match &self.system_entries {
+                Some(entries) if entries.is_empty() => vec![div()
+                    .px_2()
+                    .py_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(t(lang, "cron.empty"))
+                    .into_any_element()],
+                Some(entries) => entries
+                    .iter()
+                    .map(|entry| render_system_entry(entry, lang, cx))
+                    .collect(),
+                None => Vec::new(),
+            }
+        };
+
+        div()
+            .w_full()
+            .flex()
+            .flex_col()
+            .child(header)
+            .children(body)
+    }
+
+    /// 渲染确认栏
+    fn render_confirm_bar(
+        &self,
+        pending: PendingAction,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = match pending {
+            PendingAction::Save => t(lang, "cron.confirm_save"),
+            PendingAction::Reload => t(lang, "cron.confirm_discard"),
+        };
+        confirm_bar::render_confirm_bar("cron-confirm", message, ConfirmTone::Danger, lang, cx)
+    }
+}
+
+impl ConfirmBarHost for CronView {
+    type Pending = PendingAction;
+
+    fn pending_mut(&mut self) -> &mut Option<PendingAction> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, pending: PendingAction, cx: &mut Context<Self>) {
+        match pending {
+            PendingAction::Save => {
+                let original = self
+                    .crontab
+                    .as_ref()
+                    .map(|c| c.original.clone())
+                    .unwrap_or_default();
+                self.saving = true;
+                self.editing = None;
+                self.emit(
+                    CronEvent::Save {
+                        original,
+                        content: serialize_crontab(&self.lines),
+                    },
+                    cx,
+                );
+            }
+            PendingAction::Reload => self.emit(CronEvent::Reload, cx),
+        }
+    }
+}
+
+/// 渲染 /etc/cron.d 中的一条任务：计划说明、执行用户、命令和来源文件
+fn render_system_entry(entry: &CronEntry, lang: &Language, cx: &App) -> AnyElement {
+    let (hint, next_run) = schedule_summary(&entry.schedule, lang);
+    let source = entry
+        .source
+        .as_deref()
+        .and_then(|path| path.rsplit('/').next())
+        .unwrap_or_default()
+        .to_string();
+
+    div()
+        .w_full()
+        .px_2()
+        .py_1()
+        .flex()
+        .flex_col()
+        .border_b_1()
+        .border_color(cx.theme().border.opacity(0.5))
+        .text_xs()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_color(cx.theme().foreground)
+                        .child(hint),
+                )
+                .when_some(next_run, |this, next| {
+                    this.child(
+                        div()
+                            .flex_shrink_0()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(next),
+                    )
+                }),
+        )
+        .child(
+            div()
+                .text_color(cx.theme().muted_foreground)
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(format!(
+                    "{} · {} · {}",
+                    source,
+                    entry.user.clone().unwrap_or_default(),
+                    entry.command
+                )),
+        )
+        .into_any_element()
+}
+
+/// 构建任务右键菜单：编辑、启停、删除
+fn build_entry_context_menu(
+    menu: PopupMenu,
+    index: usize,
+    enabled: bool,
+    lang: &Language,
+    entity: Entity<CronView>,
+) -> PopupMenu {
+    let entity_for_edit = entity.clone();
+    let entity_for_toggle = entity.clone();
+    menu.min_w(px(160.))
+        .item(
+            PopupMenuItem::new(t(lang, "cron.edit")).on_click(move |_, window, cx| {
+                entity_for_edit.update(cx, |view, cx| {
+                    view.start_edit(EditTarget::Existing(index), window, cx)
+                });
+            }),
+        )
+        .item(
+            PopupMenuItem::new(if enabled {
+                t(lang, "cron.disable")
+            } else {
+                t(lang, "cron.enable")
+            })
+            .on_click(move |_, _, cx| {
+                entity_for_toggle.update(cx, |view, cx| view.toggle_enabled(index, cx));
+            }),
+        )
+        .separator()
+        .item(
+            PopupMenuItem::new(t(lang, "cron.delete")).on_click(move |_, _, cx| {
+                entity.update(cx, |view, cx| view.delete(index, cx));
+            }),
+        )
+}
+
+/// 任务的计划说明和下次运行时间（无法解析时只返回提示）
+fn schedule_summary(schedule: &str, lang: &Language) -> (String, Option<String>) {
+    match CronSchedule::parse(schedule) {
+        Ok(parsed) => (
+            describe_schedule(&parsed, lang),
+            parsed
+                .next_runs(Local::now(), 1)
+                .first()
+                .map(|run| run.format("%m-%d %H:%M").to_string()),
+        ),
+        Err(_) => (t(lang, "cron.hint.invalid").to_string(), None),
+    }
+}
+
+/// 把计划表达式转为可读说明，常见的按分钟/小时/天/周/月的计划给出具体描述，其余显示为自定义计划
+pub fn describe_schedule(schedule: &CronSchedule, lang: &Language) -> String {
+    if schedule.reboot {
+        return t(lang, "cron.hint.reboot").to_string();
+    }
+    let [minute, hour, day, month, weekday] = [0, 1, 2, 3, 4].map(|i| schedule.fields[i].as_str());
+    let every = |field: &str| field.strip_prefix("*/").and_then(parse_number);
+
+    if day == "*" && month == "*" && weekday == "*" {
+        if minute == "*" && hour == "*" {
+            return t(lang, "cron.hint.every_minute").to_string();
+        }
+        if let (Some(n), "*") = (every(minute), hour) {
+            return t(lang, "cron.hint.every_n_minutes").replace("{}", &n.to_string());
+        }
+        if let (Some(m), "*") = (parse_number(minute), hour) {
+            return t(lang, "cron.hint.hourly").replace("{}", &m.to_string());
+        }
+        if let (Some(m), Some(n)) = (parse_number(minute), every(hour)) {
+            return t(lang, "cron.hint.every_n_hours")
+                .replace("{n}", &n.to_string())
+                .replace("{m}", &m.to_string());
+        }
+    }
+
+    let Some(times) = format_times(minute, hour) else {
+        return t(lang, "cron.hint.custom").to_string();
+    };
+    if month != "*" {
+        return t(lang, "cron.hint.custom").to_string();
+    }
+    match (day, weekday) {
+        ("*", "*") => t(lang, "cron.hint.daily").replace("{}", &times),
+        ("*", _) => {
+            let names: Vec<&str> = t(lang, "cron.weekdays").split(',').collect();
+            let days: Vec<&str> = schedule
+                .weekdays()
+                .into_iter()
+                .filter_map(|d| names.get(d as usize).copied())
+                .collect();
+            t(lang, "cron.hint.weekly")
+                .replace("{days}", &days.join(", "))
+                .replace("{time}", &times)
+        }
+        (day, "*") if parse_number(day).is_some() => t(lang, "cron.hint.monthly")
+            .replace("{day}", day)
+            .replace("{time}", &times),
+        _ => t(lang, "cron.hint.custom").to_string(),
+    }
+}
+
+fn parse_number(value: &str) -> Option<u32> {
+    value.parse().ok()
+}
+
+/// 固定分钟 + 一个或多个固定小时的运行时间（如 02:30, 14:30），其他形式返回 None
+fn format_times(minute: &str, hour: &str) -> Option<String> {
+    let minute = parse_number(minute)?;
+    let hours = hour
+        .split(',')
+        .map(parse_number)
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        hours
+            .iter()
+            .map(|h| format!("{:02}:{:02}", h, minute))
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
+
+impl Render for CronView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+        let entry_count = self
+            .lines
+            .iter()
+            .filter(|line| matches!(line, CrontabLine::Entry(_)))
+            .count();
+
+        let toolbar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(format!(
+                        "{}{}",
+                        t(&lang, "cron.count").replace("{}", &entry_count.to_string()),
+                        if self.dirty {
+                            t(&lang, "cron.unsaved")
+                        } else {
+                            ""
+                        }
+                    )),
+            )
+            .child(self.render_icon_button(
+                "cron-new",
+                icons::PLUS,
+                t(&lang, "cron.new"),
+                false,
+                |this, window, cx| this.start_edit(EditTarget::New, window, cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "cron-reload",
+                icons::REFRESH,
+                t(&lang, "cron.reload"),
+                false,
+                |this, _, cx| this.request_reload(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "cron-save",
+                icons::CHECK,
+                t(&lang, "cron.save"),
+                self.dirty,
+                |this, _, cx| this.request_save(cx),
+                cx,
+            ));
+
+        let user_rows: Vec<AnyElement> = if self.crontab.is_some() {
+            let entries: Vec<(usize, CronEntry)> = self
+                .lines
+                .iter()
+                .enumerate()
+                .filter_map(|(index, line)| match line {
+                    CrontabLine::Entry(entry) => Some((index, entry.clone())),
+                    CrontabLine::Raw(_) => None,
+                })
+                .collect();
+            if entries.is_empty() {
+                vec![div()
+                    .px_2()
+                    .py_2()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(t(&lang, "cron.empty"))
+                    .into_any_element()]
+            } else {
+                entries
+                    .iter()
+                    .map(|(index, entry)| self.render_entry(*index, entry, &lang, cx))
+                    .collect()
+            }
+        } else {
+            let hint = match &self.error {
+                Some(e) => format!("{}: {}", t(&lang, "cron.load_failed"), e),
+                None => t(&lang, "common.loading").to_string(),
+            };
+            vec![div()
+                .px_2()
+                .py_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()]
+        };
+
+        let body = div()
+            .id("cron-list")
+            .flex_1()
+            .min_h(px(0.))
+            .overflow_y_scroll()
+            .children(user_rows)
+            .child(self.render_system_section(&lang, cx));
+
+        let editor = self
+            .editing
+            .is_some()
+            .then(|| self.render_editor(&lang, cx));
+        let status = self.status.clone();
+        let action_error = self.action_error.clone();
+        let pending = self.pending;
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(toolbar)
+            .child(body)
+            .children(editor)
+            .when_some(status, |this, status| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().success)
+                        .child(status),
+                )
+            })
+            .when_some(action_error, |this, error| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(format!("{}: {}", t(&lang, "cron.save_failed"), error)),
+                )
+            })
+            .when_some(pending, |this, pending| {
+                this.child(self.render_confirm_bar(pending, &lang, cx))
+            })
+    }
+}
@@ -0,0 +1,5 @@
+// Docker 容器管理组件模块
+
+mod view;
+
+pub use view::{DockerEvent, DockerView};
@@ -0,0 +1,523 @@
+// Docker 容器管理视图
+// 显示在 Monitor 区域的"Docker"页：容器/镜像列表，右键启停、删除、查看日志或进入容器（删除前需确认）
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::menu::{ContextMenuExt, PopupMenu, PopupMenuItem};
+use gpui_component::ActiveTheme;
+
+use crate::components::common::confirm_bar::{self, ConfirmBarHost, ConfirmTone};
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::docker::{ContainerAction, DockerContainer, DockerImage, DockerSnapshot};
+
+/// Docker 管理事件
+#[derive(Clone, Debug)]
+pub enum DockerEvent {
+    /// 对容器执行启动/停止/重启/删除
+    Container(String, ContainerAction),
+    /// 删除镜像
+    RemoveImage(String),
+    /// 在新终端中跟踪容器日志
+    Logs(String),
+    /// 在新终端中进入容器
+    Exec(String),
+}
+
+/// 当前显示的列表
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DockerListKind {
+    Containers,
+    Images,
+}
+
+/// docker 检测状态
+#[derive(Clone, Debug)]
+enum DockerStatus {
+    /// 正在检测
+    Detecting,
+    /// 不可用（未安装或无权访问 daemon）
+    Unavailable(String),
+    /// 可用
+    Available,
+}
+
+/// 等待确认的删除操作
+#[derive(Clone, Debug)]
+pub struct PendingAction {
+    /// 容器名或镜像引用（用于确认提示）
+    name: String,
+    event: DockerEvent,
+}
+
+/// Docker 管理视图
+pub struct DockerView {
+    status: DockerStatus,
+    list: DockerListKind,
+    snapshot: Option<DockerSnapshot>,
+    /// 最近一次获取列表的错误
+    error: Option<String>,
+    /// 最近一次操作失败的提示
+    action_error: Option<String>,
+    /// 等待确认的操作
+    pending: Option<PendingAction>,
+    on_event: Rc<dyn Fn(DockerEvent, &mut App)>,
+}
+
+impl DockerView {
+    pub fn new<F>(on_event: F) -> Self
+    where
+        F: Fn(DockerEvent, &mut App) + 'static,
+    {
+        Self {
+            status: DockerStatus::Detecting,
+            list: DockerListKind::Containers,
+            snapshot: None,
+            error: None,
+            action_error: None,
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入 docker 检测结果
+    pub fn set_detected(&mut self, result: Result<String, String>, cx: &mut Context<Self>) {
+        self.status = match result {
+            Ok(_) => DockerStatus::Available,
+            Err(e) => DockerStatus::Unavailable(e),
+        };
+        cx.notify();
+    }
+
+    /// 写入最新的容器与镜像列表
+    pub fn set_snapshot(&mut self, result: Result<DockerSnapshot, String>, cx: &mut Context<Self>) {
+        match result {
+            Ok(snapshot) => {
+                self.snapshot = Some(snapshot);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 显示操作失败的提示
+    pub fn set_action_error(&mut self, error: Option<String>, cx: &mut Context<Self>) {
+        self.action_error = error;
+        cx.notify();
+    }
+
+    /// 发送事件（启停、日志、进入容器无需确认）
+    fn emit(&mut self, event: DockerEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    /// 请求删除（先显示确认栏）
+    fn request(&mut self, name: String, event: DockerEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        self.request_confirm(PendingAction { name, event }, cx);
+    }
+
+    /// 切换容器/镜像列表
+    fn set_list(&mut self, list: DockerListKind, cx: &mut Context<Self>) {
+        self.list = list;
+        cx.notify();
+    }
+
+    /// 渲染容器/镜像切换按钮
+    fn render_list_tab(
+        &self,
+        id: &'static str,
+        label: String,
+        target: DockerListKind,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let selected = self.list == target;
+        let muted_foreground = cx.theme().muted_foreground;
+
+        div()
+            .id(id)
+            .px_2()
+            .py(px(2.))
+            .rounded(px(4.))
+            .cursor_pointer()
+            .text_xs()
+            .when(selected, |this| {
+                this.bg(cx.theme().muted)
+                    .text_color(cx.theme().foreground)
+                    .font_medium()
+            })
+            .when(!selected, |this| {
+                this.text_color(muted_foreground)
+                    .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+            })
+            .child(label)
+            .on_click(cx.listener(move |this, _, _, cx| this.set_list(target, cx)))
+    }
+
+    /// 渲染容器行
+    fn render_container(
+        &self,
+        ix: usize,
+        container: &DockerContainer,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let running = container.is_running();
+        let dot_color = if running {
+            cx.theme().success
+        } else {
+            cx.theme().muted_foreground
+        };
+        let mut detail = format!("{} · {}", container.image, container.status);
+        if !container.ports.is_empty() {
+            detail.push_str(" · ");
+            detail.push_str(&container.ports);
+        }
+        let entity = cx.entity().clone();
+        let container_for_menu = container.clone();
+        let lang_for_menu = lang.clone();
+
+        div()
+            .id(("docker-container", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .size(px(8.))
+                    .rounded_full()
+                    .bg(dot_color),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(container.name().to_string()),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(detail),
+                    ),
+            )
+            .context_menu(move |menu, _window, _cx| {
+                build_container_context_menu(
+                    menu,
+                    container_for_menu.clone(),
+                    &lang_for_menu,
+                    entity.clone(),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// 渲染镜像行
+    fn render_image(
+        &self,
+        ix: usize,
+        image: &DockerImage,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let entity = cx.entity().clone();
+        let reference = image.reference();
+        let image_id = image.id.clone();
+        let remove_label = t(lang, "docker.remove_image");
+
+        div()
+            .id(("docker-image", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(reference.clone()),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format!("{} · {}", image.size, image.created_since)),
+            )
+            .context_menu(move |menu, _window, _cx| {
+                let entity = entity.clone();
+                let reference = reference.clone();
+                let image_id = image_id.clone();
+                menu.min_w(px(160.)).label(reference.clone()).item(
+                    PopupMenuItem::new(remove_label).on_click(move |_, _, cx| {
+                        entity.update(cx, |view, cx| {
+                            view.request(
+                                reference.clone(),
+                                DockerEvent::RemoveImage(image_id.clone()),
+                                cx,
+                            )
+                        });
+                    }),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// 渲染确认栏
+    fn render_confirm_bar(
+        &self,
+        pending: &PendingAction,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = match pending.event {
+            DockerEvent::RemoveImage(_) => t(lang, "docker.confirm_remove_image"),
+            _ => t(lang, "docker.confirm_remove_container"),
+        }
+        .replace("{}", &pending.name);
+        confirm_bar::render_confirm_bar("docker-confirm", message, ConfirmTone::Danger, lang, cx)
+    }
+}
+
+impl ConfirmBarHost for DockerView {
+    type Pending = PendingAction;
+
+    fn pending_mut(&mut self) -> &mut Option<PendingAction> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, pending: PendingAction, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(pending.event, cx);
+    }
+}
+
+/// 构建容器右键菜单：启停、日志、进入容器、删除
+fn build_container_context_menu(
+    menu: PopupMenu,
+    container: DockerContainer,
+    lang: &Language,
+    entity: Entity<DockerView>,
+) -> PopupMenu {
+    let running = container.is_running();
+    let event_item = |label: &str, event: DockerEvent| {
+        let entity = entity.clone();
+        PopupMenuItem::new(label.to_string()).on_click(move |_, _, cx| {
+            let event = event.clone();
+            entity.update(cx, |view, cx| view.emit(event, cx));
+        })
+    };
+    let id = container.id.clone();
+
+    let menu =
+        menu.min_w(px(180.))
+            .label(format!("{} · {}", container.short_id(), container.name()));
+    let menu = if running {
+        menu.item(event_item(
+            t(lang, "docker.stop"),
+            DockerEvent::Container(id.clone(), ContainerAction::Stop),
+        ))
+        .item(event_item(
+            t(lang, "docker.restart"),
+            DockerEvent::Container(id.clone(), ContainerAction::Restart),
+        ))
+    } else {
+        menu.item(event_item(
+            t(lang, "docker.start"),
+            DockerEvent::Container(id.clone(), ContainerAction::Start),
+        ))
+    };
+    let menu = menu.separator().item(event_item(
+        t(lang, "docker.logs"),
+        DockerEvent::Logs(id.clone()),
+    ));
+    let menu = if running {
+        menu.item(event_item(
+            t(lang, "docker.exec"),
+            DockerEvent::Exec(id.clone()),
+        ))
+    } else {
+        menu
+    };
+
+    let name = container.name().to_string();
+    menu.separator().item(
+        PopupMenuItem::new(t(lang, "docker.remove_container")).on_click(move |_, _, cx| {
+            entity.update(cx, |view, cx| {
+                view.request(
+                    name.clone(),
+                    DockerEvent::Container(id.clone(), ContainerAction::Remove),
+                    cx,
+                )
+            });
+        }),
+    )
+}
+
+impl Render for DockerView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+
+        // 检测中、不可用或尚未获取到列表时显示提示
+        let hint = match (&self.status, &self.snapshot) {
+            (DockerStatus::Detecting, _) => Some(t(&lang, "docker.detecting").to_string()),
+            (DockerStatus::Unavailable(e), _) => {
+                Some(format!("{}: {}", t(&lang, "docker.unavailable"), e))
+            }
+            (DockerStatus::Available, None) => Some(match &self.error {
+                Some(e) => format!("{}: {}", t(&lang, "docker.load_failed"), e),
+                None => t(&lang, "common.loading").to_string(),
+            }),
+            (DockerStatus::Available, Some(_)) => None,
+        };
+        if let Some(hint) = hint {
+            return div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .px_2()
+                .bg(crate::theme::sidebar_color(cx))
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element();
+        }
+
+        let (containers, images) = self
+            .snapshot
+            .as_ref()
+            .map(|s| (s.containers.clone(), s.images.clone()))
+            .unwrap_or_default();
+        let running = containers.iter().filter(|c| c.is_running()).count();
+
+        let header = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(self.render_list_tab(
+                "docker-list-containers",
+                t(&lang, "docker.containers").replace("{}", &containers.len().to_string()),
+                DockerListKind::Containers,
+                cx,
+            ))
+            .child(self.render_list_tab(
+                "docker-list-images",
+                t(&lang, "docker.images").replace("{}", &images.len().to_string()),
+                DockerListKind::Images,
+                cx,
+            ))
+            .child(div().flex_1())
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(t(&lang, "docker.running_count").replace("{}", &running.to_string())),
+            );
+
+        let rows: Vec<AnyElement> = match self.list {
+            DockerListKind::Containers => containers
+                .iter()
+                .enumerate()
+                .map(|(ix, c)| self.render_container(ix, c, &lang, cx))
+                .collect(),
+            DockerListKind::Images => images
+                .iter()
+                .enumerate()
+                .map(|(ix, image)| self.render_image(ix, image, &lang, cx))
+                .collect(),
+        };
+        let body = if rows.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_xs()
+                .text_color(muted)
+                .child(t(&lang, "docker.empty"))
+                .into_any_element()
+        } else {
+            div()
+                .id("docker-list")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .children(rows)
+                .into_any_element()
+        };
+
+        let action_error = self.action_error.clone();
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(header)
+            .child(body)
+            .when_some(action_error, |this, error| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(format!("{}: {}", t(&lang, "docker.action_failed"), error)),
+                )
+            })
+            .when_some(pending, |this, pending| {
+                this.child(self.render_confirm_bar(&pending, &lang, cx))
+            })
+            .into_any_element()
+    }
+}
@@ -0,0 +1,5 @@
+// 防火墙规则组件模块
+
+mod view;
+
+pub use view::{FirewallEvent, FirewallView};
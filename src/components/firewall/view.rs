@@ -0,0 +1,632 @@
+// 防火墙规则视图
+// 显示在 Monitor 区域的"防火墙"页：检测到的防火墙及其状态、可搜索的规则表，
+// 以及放行/拒绝端口的快捷操作（执行前需要确认）
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::firewall::{
+    FirewallBackend, FirewallRule, FirewallStatus, PortProtocol, PortRule,
+};
+
+/// 防火墙事件
+#[derive(Clone, Debug)]
+pub enum FirewallEvent {
+    /// 重新读取规则（需要 sudo）
+    Reload,
+    /// 放行或拒绝端口
+    Apply {
+        backend: FirewallBackend,
+        rule: PortRule,
+    },
+}
+
+/// 防火墙规则视图
+pub struct FirewallView {
+    /// 最近一次读取的状态（None 表示尚未读取到）
+    status: Option<FirewallStatus>,
+    loading: bool,
+    /// 最近一次读取失败的原因
+    error: Option<String>,
+    search_input: Entity<InputState>,
+    port_input: Entity<InputState>,
+    protocol: PortProtocol,
+    /// 端口输入校验失败的提示
+    form_error: Option<&'static str>,
+    /// 等待确认的端口规则
+    pending: Option<PortRule>,
+    applying: bool,
+    /// 最近一次快捷操作的结果（成功提示或错误）
+    action_result: Option<Result<String, String>>,
+    on_event: Rc<dyn Fn(FirewallEvent, &mut App)>,
+}
+
+impl FirewallView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(FirewallEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        // 输入时即时筛选规则
+        let search_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "firewall.search_placeholder"))
+        });
+        cx.subscribe(&search_input, |_, _, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                cx.notify();
+            }
+        })
+        .detach();
+        let port_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "firewall.port_placeholder"))
+        });
+
+        Self {
+            status: None,
+            loading: true,
+            error: None,
+            search_input,
+            port_input,
+            protocol: PortProtocol::Tcp,
+            form_error: None,
+            pending: None,
+            applying: false,
+            action_result: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入读取到的规则
+    pub fn set_status(&mut self, result: Result<FirewallStatus, String>, cx: &mut Context<Self>) {
+        self.loading = false;
+        match result {
+            Ok(status) => {
+                self.error = None;
+                self.status = Some(status);
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 取消读取（如关闭了 sudo 密码对话框）
+    pub fn cancel_loading(&mut self, cx: &mut Context<Self>) {
+        self.loading = false;
+        self.applying = false;
+        cx.notify();
+    }
+
+    /// 重新执行等待 sudo 密码的操作前恢复进行中状态
+    pub fn start(&mut self, event: &FirewallEvent, cx: &mut Context<Self>) {
+        match event {
+            FirewallEvent::Reload => {
+                self.loading = true;
+                self.error = None;
+            }
+            FirewallEvent::Apply { .. } => self.applying = true,
+        }
+        cx.notify();
+    }
+
+    /// 快捷操作完成
+    pub fn set_applied(
+        &mut self,
+        rule: &PortRule,
+        result: Result<(), String>,
+        cx: &mut Context<Self>,
+    ) {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        self.applying = false;
+        self.action_result = Some(result.map(|_| {
+            let key = if rule.allow {
+                "firewall.allowed"
+            } else {
+                "firewall.denied"
+            };
+            t(&lang, key).replace("{}", &rule.describe())
+        }));
+        cx.notify();
+    }
+
+    /// 发送事件
+    fn emit(&mut self, event: FirewallEvent, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    fn reload(&mut self, cx: &mut Context<Self>) {
+        if self.loading {
+            return;
+        }
+        self.loading = true;
+        self.error = None;
+        self.emit(FirewallEvent::Reload, cx);
+    }
+
+    fn set_protocol(&mut self, protocol: PortProtocol, cx: &mut Context<Self>) {
+        self.protocol = protocol;
+        cx.notify();
+    }
+
+    /// 校验端口并显示确认栏
+    fn request(&mut self, allow: bool, cx: &mut Context<Self>) {
+        let port = self.port_input.read(cx).value().trim().parse::<u16>();
+        match port {
+            Ok(port) if port != 0 => {
+                self.form_error = None;
+                self.action_result = None;
+                self.pending = Some(PortRule {
+                    allow,
+                    port,
+                    protocol: self.protocol,
+                });
+            }
+            _ => self.form_error = Some("firewall.invalid_port"),
+        }
+        cx.notify();
+    }
+
+    /// 确认执行等待中的端口规则
+    fn confirm(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(backend) = self.status.as_ref().and_then(|s| s.backend) else {
+            return;
+        };
+        let Some(rule) = self.pending.take() else {
+            return;
+        };
+        self.applying = true;
+        self.port_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        self.emit(FirewallEvent::Apply { backend, rule }, cx);
+    }
+
+    /// 取消等待中的操作
+    fn cancel(&mut self, cx: &mut Context<Self>) {
+        self.pending = None;
+        cx.notify();
+    }
+
+    /// 渲染一条规则：序号、动作、目标，以及来源和方向
+    fn render_rule(
+        &self,
+        rule: &FirewallRule,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let action_color = if rule.is_allow() {
+            cx.theme().success
+        } else {
+            cx.theme().danger
+        };
+        let muted = cx.theme().muted_foreground;
+        let source = if rule.source.is_empty() {
+            t(lang, "firewall.anywhere").to_string()
+        } else {
+            rule.source.clone()
+        };
+        let detail = if rule.direction.is_empty() {
+            source
+        } else {
+            format!("{} · {}", source, rule.direction)
+        };
+        let raw: SharedString = rule.raw.clone().into();
+
+        div()
+            .id(SharedString::from(format!(
+                "firewall-rule-{}-{}",
+                rule.direction, rule.raw
+            )))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .child(
+                div()
+                    .w(px(18.))
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(muted)
+                    .children(rule.index.map(|i| i.to_string())),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .child(
+                                div()
+                                    .flex_shrink_0()
+                                    .px_1()
+                                    .rounded(px(3.))
+                                    .text_xs()
+                                    .bg(action_color.opacity(0.15))
+                                    .text_color(action_color)
+                                    .child(rule.action.clone()),
+                            )
+                            .child(
+                                div()
+                                    .min_w_0()
+                                    .text_xs()
+                                    .font_family("monospace")
+                                    .text_color(cx.theme().foreground)
+                                    .overflow_hidden()
+                                    .whitespace_nowrap()
+                                    .text_ellipsis()
+                                    .child(rule.target.clone()),
+                            ),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(muted)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(detail),
+                    ),
+            )
+            .tooltip(move |window, cx| Tooltip::new(raw.clone()).build(window, cx))
+            .into_any_element()
+    }
+
+    /// 渲染放行/拒绝端口的表单
+    fn render_form(&self, lang: &Language, cx: &mut Context<Self>) -> impl IntoElement {
+        let disabled = self.applying || self.status.as_ref().and_then(|s| s.backend).is_none();
+        let protocols: Vec<AnyElement> = [PortProtocol::Tcp, PortProtocol::Udp, PortProtocol::Any]
+            .into_iter()
+            .map(|protocol| {
+                let active = self.protocol == protocol;
+                div()
+                    .id(SharedString::from(format!(
+                        "firewall-protocol-{}",
+                        protocol.label()
+                    )))
+                    .px_1p5()
+                    .py_0p5()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .cursor_pointer()
+                    .when(active, |s| {
+                        s.bg(cx.theme().primary)
+                            .text_color(cx.theme().primary_foreground)
+                    })
+                    .when(!active, |s| {
+                        s.text_color(cx.theme().muted_foreground)
+                            .hover(|s| s.bg(cx.theme().list_active))
+                    })
+                    .child(protocol.label())
+                    .on_click(cx.listener(move |this, _, _, cx| this.set_protocol(protocol, cx)))
+                    .into_any_element()
+            })
+            .collect();
+
+        div()
+            .w_full()
+            .flex_shrink_0()
+            .px_2()
+            .py_1()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child(
+                        div()
+                            .w(px(72.))
+                            .child(Input::new(&self.port_input).xsmall()),
+                    )
+                    .children(protocols)
+                    .child(div().flex_1())
+                    .child(render_bar_button(
+                        "firewall-allow",
+                        t(lang, "firewall.allow"),
+                        cx.theme().primary_foreground,
+                        cx.theme().success,
+                        disabled,
+                        cx.listener(|this, _, _, cx| this.request(true, cx)),
+                    ))
+                    .child(render_bar_button(
+                        "firewall-deny",
+                        t(lang, "firewall.deny"),
+                        cx.theme().primary_foreground,
+                        cx.theme().danger,
+                        disabled,
+                        cx.listener(|this, _, _, cx| this.request(false, cx)),
+                    )),
+            )
+            .when_some(self.form_error, |this, key| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(t(lang, key)),
+                )
+            })
+    }
+
+    /// 渲染确认栏：显示将要执行的命令
+    fn render_confirm_bar(
+        &self,
+        rule: &PortRule,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let key = if rule.allow {
+            "firewall.confirm_allow"
+        } else {
+            "firewall.confirm_deny"
+        };
+        let command = self
+            .status
+            .as_ref()
+            .and_then(|s| s.backend)
+            .map(|backend| rule.command(backend))
+            .unwrap_or_default();
+
+        div()
+            .w_full()
+            .flex_shrink_0()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_t_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().danger.opacity(0.12))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .text_xs()
+                    .child(
+                        div()
+                            .text_color(cx.theme().foreground)
+                            .child(t(lang, key).replace("{}", &rule.describe())),
+                    )
+                    .child(
+                        div()
+                            .font_family("monospace")
+                            .text_color(cx.theme().muted_foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(command),
+                    ),
+            )
+            .child(render_bar_button(
+                "firewall-confirm-cancel",
+                t(lang, "common.cancel"),
+                cx.theme().foreground,
+                cx.theme().secondary,
+                false,
+                cx.listener(|this, _, _, cx| this.cancel(cx)),
+            ))
+            .child(render_bar_button(
+                "firewall-confirm-ok",
+                t(lang, "common.confirm"),
+                cx.theme().primary_foreground,
+                cx.theme().danger,
+                false,
+                cx.listener(|this, _, window, cx| this.confirm(window, cx)),
+            ))
+    }
+}
+
+/// 渲染表单/确认栏中的文字按钮
+fn render_bar_button(
+    id: &'static str,
+    label: &'static str,
+    color: Hsla,
+    bg: Hsla,
+    disabled: bool,
+    on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+) -> impl IntoElement {
+    div()
+        .id(id)
+        .flex_shrink_0()
+        .px_2()
+        .py_0p5()
+        .rounded(px(4.))
+        .text_xs()
+        .text_color(color)
+        .bg(bg)
+        .child(label)
+        .when(disabled, |s| s.opacity(0.4))
+        .when(!disabled, |s| {
+            s.cursor_pointer()
+                .hover(|s| s.opacity(0.8))
+                .on_click(on_click)
+        })
+}
+
+impl Render for FirewallView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_active;
+        let query = self.search_input.read(cx).value().trim().to_string();
+
+        let summary = match &self.status {
+            Some(status) => match status.backend {
+                Some(backend) if status.summary.is_empty() => backend.label().to_string(),
+                Some(backend) => format!("{} · {}", backend.label(), status.summary),
+                None => t(&lang, "firewall.not_found").to_string(),
+            },
+            None if self.loading => t(&lang, "common.loading").to_string(),
+            None => String::new(),
+        };
+        let reload_tooltip = t(&lang, "firewall.reload");
+
+        let toolbar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(summary),
+            )
+            .child(
+                div()
+                    .id("firewall-reload")
+                    .size(px(22.))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .rounded(px(4.))
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(hover_bg))
+                    .child(svg().path(icons::REFRESH).size(px(14.)).text_color(muted))
+                    .tooltip(move |window, cx| Tooltip::new(reload_tooltip).build(window, cx))
+                    .on_click(cx.listener(|this, _, _, cx| this.reload(cx))),
+            );
+
+        let rules: Vec<FirewallRule> = self
+            .status
+            .as_ref()
+            .map(|s| {
+                s.rules
+                    .iter()
+                    .filter(|r| r.matches(&query))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let rows: Vec<AnyElement> = if let Some(error) = &self.error {
+            vec![div()
+                .px_2()
+                .py_2()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .child(format!("{}: {}", t(&lang, "firewall.load_failed"), error))
+                .into_any_element()]
+        } else if self.status.is_none() {
+            let hint = if self.loading {
+                t(&lang, "common.loading")
+            } else {
+                t(&lang, "firewall.needs_sudo")
+            };
+            vec![div()
+                .px_2()
+                .py_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()]
+        } else if rules.is_empty() {
+            let hint = if query.is_empty() {
+                t(&lang, "firewall.empty")
+            } else {
+                t(&lang, "firewall.no_match")
+            };
+            vec![div()
+                .px_2()
+                .py_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()]
+        } else {
+            rules
+                .iter()
+                .map(|rule| self.render_rule(rule, &lang, cx))
+                .collect()
+        };
+
+        let action_result = self.action_result.clone();
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(toolbar)
+            .child(
+                div()
+                    .w_full()
+                    .flex_shrink_0()
+                    .px_2()
+                    .py_1()
+                    .child(Input::new(&self.search_input).xsmall()),
+            )
+            .child(
+                div()
+                    .id("firewall-rules")
+                    .flex_1()
+                    .min_h(px(0.))
+                    .overflow_y_scroll()
+                    .children(rows),
+            )
+            .child(self.render_form(&lang, cx))
+            .when_some(action_result, |this, result| {
+                let (text, color) = match result {
+                    Ok(message) => (message, cx.theme().success),
+                    Err(e) => (
+                        format!("{}: {}", t(&lang, "firewall.apply_failed"), e),
+                        cx.theme().danger,
+                    ),
+                };
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(color)
+                        .child(text),
+                )
+            })
+            .when_some(pending, |this, rule| {
+                this.child(self.render_confirm_bar(&rule, &lang, cx))
+            })
+    }
+}
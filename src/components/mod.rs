@@ -1,6 +1,14 @@
 // 可复用组件模块
 
 pub mod common;
+pub mod connections;
+pub mod cron;
+pub mod docker;
+pub mod firewall;
 pub mod monitor;
+pub mod packages;
+pub mod port_forward;
+pub mod processes;
 pub mod sftp;
+pub mod systemd;
 pub mod terminal;
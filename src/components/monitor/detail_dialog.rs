@@ -1,11 +1,16 @@
 // Monitor detail dialog components
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
 use gpui_component::scroll::ScrollableElement;
-use gpui_component::{ActiveTheme, StyledExt};
+use gpui_component::{ActiveTheme, Sizable, StyledExt};
 
 use crate::constants::icons;
-use crate::models::monitor::MonitorState;
+use crate::models::monitor::{HistoryRange, LoginRecord, MetricSample, MonitorState};
+use crate::models::settings::{ChartStyle, Language};
+use crate::services::monitor_export::{default_file_name, export_samples, ExportFormat};
 
 /// Detail dialog types
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -14,18 +19,28 @@ pub enum DetailDialogType {
     SystemInfo,
     LoadInfo,
     NetworkInfo,
+    /// 历史图表（CPU / 内存 / 网络 / 磁盘 IO）
+    History,
+    /// 登录信息（当前会话 / 最近登录 / 失败登录）
+    LoginInfo,
 }
 
 /// Detail dialog state
 #[derive(Clone)]
 pub struct DetailDialogState {
     pub dialog_type: DetailDialogType,
+    /// 历史图表当前选择的时间范围
+    pub history_range: HistoryRange,
+    /// 最近一次导出的结果（成功为文件路径，失败为原因）
+    pub export_result: Option<Result<String, String>>,
 }
 
 impl Default for DetailDialogState {
     fn default() -> Self {
         Self {
             dialog_type: DetailDialogType::None,
+            history_range: HistoryRange::default(),
+            export_result: None,
         }
     }
 }
@@ -37,10 +52,12 @@ impl DetailDialogState {
 
     pub fn open(&mut self, dialog_type: DetailDialogType) {
         self.dialog_type = dialog_type;
+        self.export_result = None;
     }
 
     pub fn close(&mut self) {
         self.dialog_type = DetailDialogType::None;
+        self.export_result = None;
     }
 }
 
@@ -51,6 +68,10 @@ pub fn render_detail_button(
     cx: &App,
 ) -> impl IntoElement {
     let muted_color = cx.theme().muted_foreground;
+    let icon = match dialog_type {
+        DetailDialogType::History => icons::ACTIVITY,
+        _ => icons::EXPAND,
+    };
 
     div()
         .id(SharedString::from(format!("detail-btn-{:?}", dialog_type)))
@@ -67,12 +88,7 @@ pub fn render_detail_button(
                 s.open(dialog_type);
             });
         })
-        .child(
-            svg()
-                .path(icons::EXPAND)
-                .size(px(14.))
-                .text_color(muted_color),
-        )
+        .child(svg().path(icon).size(px(14.)).text_color(muted_color))
 }
 
 /// Render detail dialog overlay
@@ -88,6 +104,8 @@ pub fn render_detail_dialog(
     }
 
     let dialog_type = state.dialog_type;
+    let history_range = state.history_range;
+    let export_result = state.export_result.clone();
     let bg_overlay = hsla(0.0, 0.0, 0.0, 0.5);
     let dialog_bg = cx.theme().popover;
     let border_color = cx.theme().border;
@@ -103,6 +121,8 @@ pub fn render_detail_dialog(
         DetailDialogType::SystemInfo => crate::i18n::t(&lang, "monitor.system_info").to_string(),
         DetailDialogType::LoadInfo => crate::i18n::t(&lang, "monitor.load").to_string(),
         DetailDialogType::NetworkInfo => crate::i18n::t(&lang, "monitor.network").to_string(),
+        DetailDialogType::History => crate::i18n::t(&lang, "monitor.history.title").to_string(),
+        DetailDialogType::LoginInfo => crate::i18n::t(&lang, "monitor.logins").to_string(),
         DetailDialogType::None => String::new(),
     };
 
@@ -153,11 +173,27 @@ pub fn render_detail_dialog(
                         .justify_between()
                         .child(
                             div()
+                                .flex_1()
                                 .text_base()
                                 .font_medium()
                                 .text_color(title_color)
                                 .child(title),
                         )
+                        // 历史图表的时间范围选择与导出
+                        .when(dialog_type == DetailDialogType::History, |this| {
+                            this.child(render_range_selector(
+                                dialog_state.clone(),
+                                history_range,
+                                cx,
+                            ))
+                            .child(render_export_button(
+                                dialog_state.clone(),
+                                monitor_state,
+                                history_range,
+                                &lang,
+                                cx,
+                            ))
+                        })
                         .child({
                             let dialog_state_x = dialog_state.clone();
                             div()
@@ -176,6 +212,11 @@ pub fn render_detail_dialog(
                                 .child(svg().path(icons::X).size(px(16.)).text_color(muted_color))
                         }),
                 )
+                // 导出结果
+                .when_some(
+                    export_result.filter(|_| dialog_type == DetailDialogType::History),
+                    |this, result| this.child(render_export_result(result, &lang, cx)),
+                )
                 // Content
                 .child(
                     div()
@@ -194,6 +235,13 @@ pub fn render_detail_dialog(
                             DetailDialogType::NetworkInfo => {
                                 render_network_detail(monitor_state, cx).into_any_element()
                             }
+                            DetailDialogType::History => {
+                                render_history_detail(monitor_state, history_range, cx)
+                                    .into_any_element()
+                            }
+                            DetailDialogType::LoginInfo => {
+                                render_login_detail(monitor_state, cx).into_any_element()
+                            }
                             DetailDialogType::None => div().into_any_element(),
                         }),
                 ),
@@ -415,6 +463,589 @@ fn render_network_detail(state: &MonitorState, cx: &App) -> impl IntoElement {
     }
 }
 
+/// Render login info detail
+/// 当前会话 (who)、最近登录 (last)、失败登录 (lastb，需要 root 或免密 sudo)
+fn render_login_detail(state: &MonitorState, cx: &App) -> impl IntoElement {
+    let value_color = cx.theme().muted_foreground;
+
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+
+    if let Some(info) = &state.login_info {
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .child(render_login_table(
+                &crate::i18n::t(&lang, "monitor.logins.sessions")
+                    .replace("{}", &info.sessions.len().to_string()),
+                &info.sessions,
+                None,
+                &lang,
+                cx,
+            ))
+            .child(render_login_table(
+                crate::i18n::t(&lang, "monitor.logins.recent"),
+                &info.recent,
+                None,
+                &lang,
+                cx,
+            ))
+            .child(render_login_table(
+                crate::i18n::t(&lang, "monitor.logins.failed"),
+                &info.failed,
+                (!info.failed_available)
+                    .then(|| crate::i18n::t(&lang, "monitor.logins.failed_unavailable")),
+                &lang,
+                cx,
+            ))
+    } else {
+        div()
+            .text_sm()
+            .text_color(value_color)
+            .child(crate::i18n::t(&lang, "monitor.no_data"))
+    }
+}
+
+/// Render login record table
+/// 用户 (user), 终端 (tty), 来源 (from), 时间 (time)；`hint` 不为空时替代表格内容显示
+fn render_login_table(
+    title: &str,
+    records: &[LoginRecord],
+    hint: Option<&str>,
+    lang: &crate::models::settings::Language,
+    cx: &App,
+) -> impl IntoElement {
+    let section_bg = cx.theme().secondary;
+    let title_color = cx.theme().foreground;
+    let label_color = cx.theme().muted_foreground;
+    let value_color = cx.theme().foreground;
+    let hint = hint.or_else(|| {
+        records
+            .is_empty()
+            .then(|| crate::i18n::t(lang, "monitor.logins.empty"))
+    });
+
+    div()
+        .w_full()
+        .bg(section_bg)
+        .rounded(px(6.))
+        .overflow_hidden()
+        // Title
+        .child(
+            div()
+                .px_3()
+                .py_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    div()
+                        .text_sm()
+                        .font_medium()
+                        .text_color(title_color)
+                        .child(title.to_string()),
+                ),
+        )
+        .child(match hint {
+            Some(hint) => div()
+                .px_3()
+                .py_2()
+                .text_xs()
+                .text_color(label_color)
+                .child(hint)
+                .into_any_element(),
+            None => div()
+                .flex()
+                .flex_col()
+                // Header
+                .child(
+                    div()
+                        .px_3()
+                        .py_1()
+                        .flex()
+                        .border_b_1()
+                        .border_color(cx.theme().border)
+                        .child(
+                            div()
+                                .w(px(80.))
+                                .text_xs()
+                                .text_color(label_color)
+                                .child(crate::i18n::t(lang, "monitor.logins.user")),
+                        )
+                        .child(
+                            div()
+                                .w(px(80.))
+                                .text_xs()
+                                .text_color(label_color)
+                                .child(crate::i18n::t(lang, "monitor.logins.tty")),
+                        )
+                        .child(
+                            div()
+                                .w(px(110.))
+                                .text_xs()
+                                .text_color(label_color)
+                                .child(crate::i18n::t(lang, "monitor.logins.from")),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_xs()
+                                .text_color(label_color)
+                                .child(crate::i18n::t(lang, "monitor.logins.time")),
+                        ),
+                )
+                // Rows
+                .child(
+                    div()
+                        .px_3()
+                        .py_1()
+                        .flex()
+                        .flex_col()
+                        .children(records.iter().map(|r| {
+                            div()
+                                .flex()
+                                .py_1()
+                                .text_xs()
+                                .text_color(value_color)
+                                .child(
+                                    div()
+                                        .w(px(80.))
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(r.user.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .w(px(80.))
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(r.tty.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .w(px(110.))
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(if r.from.is_empty() {
+                                            "--".to_string()
+                                        } else {
+                                            r.from.clone()
+                                        }),
+                                )
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .min_w_0()
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(r.time.clone()),
+                                )
+                        })),
+                )
+                .into_any_element(),
+        })
+}
+
+/// 渲染时间范围选择按钮（5m / 30m / 1h）
+fn render_range_selector(
+    dialog_state: Entity<DetailDialogState>,
+    current: HistoryRange,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .items_center()
+        .gap_1()
+        .mr_2()
+        .children(HistoryRange::ALL.into_iter().map(|range| {
+            let selected = range == current;
+            let dialog_state = dialog_state.clone();
+            div()
+                .id(SharedString::from(format!(
+                    "history-range-{}",
+                    range.label()
+                )))
+                .px_2()
+                .py_0p5()
+                .rounded(px(4.))
+                .text_xs()
+                .cursor_pointer()
+                .when(selected, |this| {
+                    this.bg(cx.theme().primary)
+                        .text_color(cx.theme().primary_foreground)
+                })
+                .when(!selected, |this| {
+                    this.bg(cx.theme().secondary)
+                        .text_color(cx.theme().muted_foreground)
+                        .hover(|s| s.bg(cx.theme().secondary_hover))
+                })
+                .on_click(move |_, _, cx| {
+                    dialog_state.update(cx, |s, _| s.history_range = range);
+                })
+                .child(range.label())
+        }))
+}
+
+/// 渲染导出按钮：选择格式后弹出保存对话框，把当前时间范围内的历史采样写入文件
+fn render_export_button(
+    dialog_state: Entity<DetailDialogState>,
+    monitor_state: &MonitorState,
+    range: HistoryRange,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let samples: Vec<MetricSample> = monitor_state
+        .history_in_range(range)
+        .into_iter()
+        .cloned()
+        .collect();
+    let host = monitor_state
+        .system_info
+        .as_ref()
+        .map(|info| {
+            if info.host.hostname.is_empty() {
+                info.host.address.clone()
+            } else {
+                info.host.hostname.clone()
+            }
+        })
+        .unwrap_or_default();
+    let dialog_title = crate::i18n::t(lang, "monitor.export.title");
+
+    Button::new("history-export")
+        .ghost()
+        .xsmall()
+        .mr_2()
+        .disabled(samples.is_empty())
+        .tooltip(crate::i18n::t(lang, "monitor.export.tooltip"))
+        .child(
+            svg()
+                .path(icons::DOWNLOAD)
+                .size(px(14.))
+                .text_color(cx.theme().muted_foreground),
+        )
+        .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+            let mut menu = menu;
+            for format in ExportFormat::ALL {
+                let samples = samples.clone();
+                let host = host.clone();
+                let dialog_state = dialog_state.clone();
+                menu = menu.item(
+                    PopupMenuItem::new(format.label()).on_click(move |_, _, cx| {
+                        let samples = samples.clone();
+                        let host = host.clone();
+                        let dialog_state = dialog_state.clone();
+                        cx.spawn(async move |cx| {
+                            let extension = format.extension();
+                            let picker = rfd::AsyncFileDialog::new()
+                                .set_title(dialog_title)
+                                .set_file_name(default_file_name(&host, format))
+                                .add_filter(format.label(), &[extension]);
+                            let Some(handle) = picker.save_file().await else {
+                                return;
+                            };
+                            let path = handle.path().to_path_buf();
+                            let result = export_samples(&samples, &host, format)
+                                .and_then(|content| {
+                                    std::fs::write(&path, content).map_err(|e| e.to_string())
+                                })
+                                .map(|_| path.display().to_string());
+                            let _ = dialog_state.update(cx, |s, cx| {
+                                s.export_result = Some(result);
+                                cx.notify();
+                            });
+                        })
+                        .detach();
+                    }),
+                );
+            }
+            menu
+        })
+}
+
+/// 渲染导出结果提示（成功显示文件路径，失败显示原因）
+fn render_export_result(
+    result: Result<String, String>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let (text, color) = match result {
+        Ok(path) => (
+            crate::i18n::t(lang, "monitor.export.done").replace("{}", &path),
+            cx.theme().muted_foreground,
+        ),
+        Err(e) => (
+            crate::i18n::t(lang, "monitor.export.failed").replace("{}", &e),
+            cx.theme().danger,
+        ),
+    };
+
+    div()
+        .px_4()
+        .py_1()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .text_xs()
+        .text_color(color)
+        .overflow_hidden()
+        .text_ellipsis()
+        .child(text)
+}
+
+/// 图表中的一条数据线
+struct ChartSeries {
+    label: &'static str,
+    color: Hsla,
+    /// (时间戳, 数值)
+    points: Vec<(u64, f64)>,
+    /// 当前值的显示文本
+    current: String,
+}
+
+/// Render history detail(History)
+/// CPU 使用率、内存使用率、网络速率（接收/发送）、磁盘 IO（读/写）
+fn render_history_detail(state: &MonitorState, range: HistoryRange, cx: &App) -> impl IntoElement {
+    let value_color = cx.theme().muted_foreground;
+
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language.clone();
+    let chart_style = settings.monitor.chart_style.clone();
+    let show_grid = settings.monitor.show_grid;
+
+    let samples = state.history_in_range(range);
+    if samples.len() < 2 {
+        return div()
+            .text_sm()
+            .text_color(value_color)
+            .child(crate::i18n::t(&lang, "monitor.history.collecting"))
+            .into_any_element();
+    }
+
+    let end = samples.last().map(|s| s.timestamp).unwrap_or(0);
+    let start = end.saturating_sub(range.seconds());
+    let series = |label: &'static str,
+                  color: Hsla,
+                  value: &dyn Fn(&MetricSample) -> f64,
+                  format: &dyn Fn(f64) -> String| {
+        let points: Vec<(u64, f64)> = samples.iter().map(|s| (s.timestamp, value(s))).collect();
+        let current = format(points.last().map(|p| p.1).unwrap_or(0.0));
+        ChartSeries {
+            label,
+            color,
+            points,
+            current,
+        }
+    };
+    let percent = |v: f64| format!("{:.1}%", v);
+    let speed = |v: f64| format!("{}/s", format_bytes(v as u64));
+
+    let blue = hsla(210.0 / 360.0, 1.0, 0.5, 1.0);
+    let green = hsla(145.0 / 360.0, 0.63, 0.42, 1.0);
+    let orange = hsla(30.0 / 360.0, 0.9, 0.5, 1.0);
+    let purple = hsla(270.0 / 360.0, 0.6, 0.55, 1.0);
+
+    let charts = vec![
+        (
+            crate::i18n::t(&lang, "monitor.history.cpu"),
+            Some(100.0),
+            vec![series("", blue, &|s| s.cpu_percent as f64, &percent)],
+        ),
+        (
+            crate::i18n::t(&lang, "monitor.history.memory"),
+            Some(100.0),
+            vec![series("", purple, &|s| s.memory_percent as f64, &percent)],
+        ),
+        (
+            crate::i18n::t(&lang, "monitor.history.network"),
+            None,
+            vec![
+                series("↓", green, &|s| s.rx_speed, &speed),
+                series("↑", orange, &|s| s.tx_speed, &speed),
+            ],
+        ),
+        (
+            crate::i18n::t(&lang, "monitor.history.disk_io"),
+            None,
+            vec![
+                series(
+                    crate::i18n::t(&lang, "monitor.history.read"),
+                    blue,
+                    &|s| s.disk_read_speed,
+                    &speed,
+                ),
+                series(
+                    crate::i18n::t(&lang, "monitor.history.write"),
+                    orange,
+                    &|s| s.disk_write_speed,
+                    &speed,
+                ),
+            ],
+        ),
+    ];
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .children(charts.into_iter().map(|(title, max, series)| {
+            render_history_chart(
+                title,
+                max,
+                series,
+                (start, end),
+                &chart_style,
+                show_grid,
+                cx,
+            )
+        }))
+        .into_any_element()
+}
+
+/// 渲染单个历史图表：标题与当前值 + 折线/面积图
+/// `max` 为 None 时按数据最大值自动缩放（速率类图表）
+fn render_history_chart(
+    title: &str,
+    max: Option<f64>,
+    series: Vec<ChartSeries>,
+    (start, end): (u64, u64),
+    chart_style: &ChartStyle,
+    show_grid: bool,
+    cx: &App,
+) -> impl IntoElement {
+    let section_bg = cx.theme().secondary;
+    let title_color = cx.theme().foreground;
+    let label_color = cx.theme().muted_foreground;
+    let grid_color = cx.theme().border;
+
+    let max = max.unwrap_or_else(|| {
+        let peak = series
+            .iter()
+            .flat_map(|s| s.points.iter().map(|p| p.1))
+            .fold(0.0_f64, f64::max);
+        // 留出顶部空间，且至少 1 KB/s，避免空闲时噪声被放大
+        (peak * 1.2).max(1024.0)
+    });
+    let fill_area = *chart_style == ChartStyle::Area;
+    let span = end.saturating_sub(start).max(1) as f64;
+
+    let legend = series
+        .iter()
+        .map(|s| {
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(div().w(px(8.)).h(px(2.)).bg(s.color))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(label_color)
+                        .child(format!("{} {}", s.label, s.current).trim().to_string()),
+                )
+        })
+        .collect::<Vec<_>>();
+
+    let lines: Vec<(Hsla, Vec<(f32, f32)>)> = series
+        .into_iter()
+        .map(|s| {
+            let points = s
+                .points
+                .iter()
+                .map(|(ts, v)| {
+                    let x = ts.saturating_sub(start) as f64 / span;
+                    let y = (v / max).clamp(0.0, 1.0);
+                    (x as f32, y as f32)
+                })
+                .collect();
+            (s.color, points)
+        })
+        .collect();
+
+    div()
+        .w_full()
+        .bg(section_bg)
+        .rounded(px(6.))
+        .overflow_hidden()
+        .px_3()
+        .py_2()
+        .flex()
+        .flex_col()
+        .gap_1()
+        // 标题 + 当前值
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_sm()
+                        .font_medium()
+                        .text_color(title_color)
+                        .child(title.to_string()),
+                )
+                .child(div().flex().items_center().gap_3().children(legend)),
+        )
+        // 图表
+        .child(
+            div().w_full().h(px(80.)).child(
+                canvas(
+                    |_bounds, _window, _cx| {},
+                    move |bounds, _, window, _cx| {
+                        let origin = bounds.origin;
+                        let width = bounds.size.width;
+                        let height = bounds.size.height;
+                        let point = |(x, y): (f32, f32)| {
+                            Point::new(origin.x + width * x, origin.y + height * (1.0 - y))
+                        };
+
+                        if show_grid {
+                            for i in 0..=4 {
+                                let y = origin.y + height * (i as f32 / 4.0);
+                                window.paint_quad(fill(
+                                    Bounds::new(Point::new(origin.x, y), size(width, px(1.))),
+                                    grid_color.opacity(0.5),
+                                ));
+                            }
+                        }
+
+                        for (color, points) in &lines {
+                            if points.len() < 2 {
+                                continue;
+                            }
+                            if fill_area {
+                                let mut area = PathBuilder::fill();
+                                area.move_to(point((points[0].0, 0.0)));
+                                for p in points {
+                                    area.line_to(point(*p));
+                                }
+                                area.line_to(point((points[points.len() - 1].0, 0.0)));
+                                area.close();
+                                if let Ok(path) = area.build() {
+                                    window.paint_path(path, color.opacity(0.2));
+                                }
+                            }
+                            let mut line = PathBuilder::stroke(px(1.5));
+                            line.move_to(point(points[0]));
+                            for p in &points[1..] {
+                                line.line_to(point(*p));
+                            }
+                            if let Ok(path) = line.build() {
+                                window.paint_path(path, *color);
+                            }
+                        }
+                    },
+                )
+                .size_full(),
+            ),
+        )
+}
+
 /// Render a detail section with title and key-value pairs
 fn render_detail_section(title: &str, items: Vec<(&str, String)>, cx: &App) -> impl IntoElement {
     let section_bg = cx.theme().secondary;
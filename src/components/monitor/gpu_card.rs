@@ -0,0 +1,267 @@
+// GPU 状态区块组件（nvidia-smi）
+
+use gpui::*;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::constants::icons;
+use crate::models::monitor::{GpuDeviceInfo, GpuInfo, GpuProcessInfo};
+use crate::models::settings::TemperatureUnit;
+
+/// 渲染 GPU 状态区块（无卡片边框）；没有 GPU 时返回 None，界面不显示该区块
+pub fn render_gpu_card(gpu_info: Option<&GpuInfo>, cx: &App) -> Option<impl IntoElement> {
+    let info = gpu_info.filter(|info| !info.gpus.is_empty())?;
+    let title_color = hsla(210.0 / 360.0, 1.0, 0.5, 1.0); // 蓝色标题
+    let border_color = cx.theme().border;
+
+    // 获取语言与温度单位设置
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language;
+    let temperature_unit = settings.monitor.temperature_unit;
+
+    Some(
+        div()
+            .w_full()
+            .py_2()
+            .border_b_1()
+            .border_color(border_color)
+            .flex()
+            .flex_col()
+            .gap_2()
+            // 标题行
+            .child(
+                div()
+                    .text_xs()
+                    .font_medium()
+                    .text_color(title_color)
+                    .child(crate::i18n::t(&lang, "monitor.gpu")),
+            )
+            // 每块 GPU 一个区域
+            .children(info.gpus.iter().map(|gpu| {
+                let processes: Vec<&GpuProcessInfo> = info
+                    .processes
+                    .iter()
+                    .filter(|p| p.gpu_uuid == gpu.uuid)
+                    .collect();
+                render_gpu_device(gpu, &processes, &temperature_unit, &lang, cx)
+            })),
+    )
+}
+
+/// 渲染单块 GPU：型号与温度、使用率、显存、进程列表
+fn render_gpu_device(
+    gpu: &GpuDeviceInfo,
+    processes: &[&GpuProcessInfo],
+    temperature_unit: &TemperatureUnit,
+    lang: &crate::models::settings::Language,
+    cx: &App,
+) -> impl IntoElement {
+    let green_color = hsla(145.0 / 360.0, 0.63, 0.42, 1.0); // 绿色进度条
+    let foreground = cx.theme().foreground;
+    let muted_color = cx.theme().muted_foreground;
+
+    let temperature = gpu
+        .temperature_celsius
+        .map(|celsius| match temperature_unit {
+            TemperatureUnit::Celsius => format!("{:.0}°C", celsius),
+            TemperatureUnit::Fahrenheit => format!("{:.0}°F", celsius * 9.0 / 5.0 + 32.0),
+        });
+    // 高温时以橙色/红色提示
+    let temperature_color = match gpu.temperature_celsius {
+        Some(t) if t >= 85.0 => hsla(0.0, 0.8, 0.5, 1.0),
+        Some(t) if t >= 75.0 => hsla(40.0 / 360.0, 0.8, 0.5, 1.0),
+        _ => muted_color,
+    };
+
+    div()
+        .w_full()
+        .px_2()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded(px(6.))
+        .flex()
+        .flex_col()
+        .gap_2()
+        // 第一行：序号、型号和温度
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_xs()
+                        .font_bold()
+                        .text_color(foreground)
+                        .child(format!("#{} {}", gpu.index, gpu.name)),
+                )
+                .children(temperature.map(|text| {
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .font_medium()
+                        .text_color(temperature_color)
+                        .child(text)
+                })),
+        )
+        // GPU 使用率
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    svg()
+                        .path(icons::GAUGE)
+                        .size(px(18.))
+                        .text_color(green_color),
+                )
+                .child(render_progress_bar(
+                    gpu.utilization_percent,
+                    None,
+                    green_color,
+                    cx,
+                )),
+        )
+        // 显存
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    svg()
+                        .path(icons::MEMORY)
+                        .size(px(18.))
+                        .text_color(green_color),
+                )
+                .child(render_progress_bar(
+                    gpu.memory_percent(),
+                    Some(format!(
+                        "{}/{}",
+                        format_bytes(gpu.memory_used_bytes),
+                        format_bytes(gpu.memory_total_bytes)
+                    )),
+                    green_color,
+                    cx,
+                )),
+        )
+        // 进程列表（按显存占用排序）
+        .child(if processes.is_empty() {
+            div()
+                .text_xs()
+                .text_color(muted_color)
+                .child(crate::i18n::t(lang, "monitor.gpu.no_processes"))
+        } else {
+            let mut processes = processes.to_vec();
+            processes.sort_by(|a, b| b.memory_used_bytes.cmp(&a.memory_used_bytes));
+            div()
+                .flex()
+                .flex_col()
+                .gap_0p5()
+                .children(processes.into_iter().map(|p| {
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .text_xs()
+                        .child(
+                            div()
+                                .w(px(50.))
+                                .flex_shrink_0()
+                                .text_color(muted_color)
+                                .child(p.pid.to_string()),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_color(foreground)
+                                .child(p.name.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_color(muted_color)
+                                .child(format_bytes(p.memory_used_bytes)),
+                        )
+                }))
+        })
+}
+
+/// 渲染进度条（左侧百分比，右侧可选的附加文字）
+fn render_progress_bar(
+    percent: f32,
+    detail: Option<String>,
+    color: Hsla,
+    cx: &App,
+) -> impl IntoElement {
+    let bg_color = cx.theme().border;
+    let width_percent = percent.clamp(0.0, 100.0);
+    let text_color = cx.theme().foreground;
+    let muted_color = cx.theme().muted_foreground;
+
+    div()
+        .h(px(20.))
+        .flex_1()
+        .min_w(px(80.))
+        .bg(bg_color)
+        .rounded(px(4.))
+        .overflow_hidden()
+        .relative()
+        // 进度填充
+        .child(
+            div()
+                .absolute()
+                .left_0()
+                .top_0()
+                .h_full()
+                .w(relative(width_percent / 100.0))
+                .bg(color)
+                .rounded(px(4.)),
+        )
+        // 文字层
+        .child(
+            div()
+                .absolute()
+                .inset_0()
+                .flex()
+                .items_center()
+                .justify_between()
+                .px_2()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_medium()
+                        .text_color(text_color)
+                        .child(format!("{:.1}%", percent)),
+                )
+                .children(detail.map(|text| div().text_xs().text_color(muted_color).child(text))),
+        )
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+    const TB: u64 = GB * 1024;
+
+    if bytes >= TB {
+        format!("{:.1} TB", bytes as f64 / TB as f64)
+    } else if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
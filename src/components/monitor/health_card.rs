@@ -0,0 +1,210 @@
+// 硬件健康区块组件（sensors 温度 + smartctl 硬盘自检）
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::constants::icons;
+use crate::models::monitor::{DriveHealth, MonitorState, TemperatureSensor};
+use crate::models::settings::{Language, TemperatureUnit};
+use crate::services::monitor::sensor_name;
+
+/// 渲染硬件健康区块（无卡片边框）；既没有温度也没有 SMART 数据时返回 None
+pub fn render_health_card(state: &MonitorState, cx: &App) -> Option<impl IntoElement> {
+    if state.temperatures.is_empty() && state.drive_health.is_empty() {
+        return None;
+    }
+    let title_color = hsla(210.0 / 360.0, 1.0, 0.5, 1.0); // 蓝色标题
+    let border_color = cx.theme().border;
+
+    // 获取语言、温度单位和告警阈值
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language;
+    let temperature_unit = settings.monitor.temperature_unit;
+    let threshold = settings.monitor.temperature_alert_threshold as f32;
+
+    // 自检失败的硬盘排在最前面
+    let mut drives: Vec<&DriveHealth> = state.drive_health.iter().collect();
+    drives.sort_by_key(|d| d.passed != Some(false));
+
+    Some(
+        div()
+            .w_full()
+            .py_2()
+            .border_b_1()
+            .border_color(border_color)
+            .flex()
+            .flex_col()
+            .gap_2()
+            // 标题行
+            .child(
+                div()
+                    .text_xs()
+                    .font_medium()
+                    .text_color(title_color)
+                    .child(crate::i18n::t(&lang, "monitor.health")),
+            )
+            // 温度传感器
+            .when(!state.temperatures.is_empty(), |this| {
+                this.child(render_section(
+                    icons::GAUGE,
+                    crate::i18n::t(&lang, "monitor.health.temperatures"),
+                    state
+                        .temperatures
+                        .iter()
+                        .map(|sensor| {
+                            render_temperature_row(sensor, threshold, &temperature_unit, cx)
+                                .into_any_element()
+                        })
+                        .collect(),
+                    cx,
+                ))
+            })
+            // 硬盘 SMART 状态
+            .when(!drives.is_empty(), |this| {
+                this.child(render_section(
+                    icons::HARD_DRIVE,
+                    crate::i18n::t(&lang, "monitor.health.drives"),
+                    drives
+                        .into_iter()
+                        .map(|drive| render_drive_row(drive, &lang, cx).into_any_element())
+                        .collect(),
+                    cx,
+                ))
+            }),
+    )
+}
+
+/// 渲染一组数据（图标 + 小标题 + 行列表）
+fn render_section(
+    icon: &'static str,
+    title: &'static str,
+    rows: Vec<AnyElement>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_color = cx.theme().muted_foreground;
+
+    div()
+        .w_full()
+        .px_2()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded(px(6.))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(svg().path(icon).size(px(14.)).text_color(muted_color))
+                .child(div().text_xs().text_color(muted_color).child(title)),
+        )
+        .children(rows)
+}
+
+/// 渲染单个温度传感器：名称和温度，达到高温/临界值或告警阈值时变色
+fn render_temperature_row(
+    sensor: &TemperatureSensor,
+    threshold: f32,
+    temperature_unit: &TemperatureUnit,
+    cx: &App,
+) -> impl IntoElement {
+    let critical = sensor.critical.map_or(false, |c| sensor.celsius >= c);
+    let high = sensor.high.map_or(false, |h| sensor.celsius >= h);
+    let color = if critical || (threshold > 0.0 && sensor.celsius >= threshold) {
+        cx.theme().danger
+    } else if high {
+        cx.theme().warning
+    } else {
+        cx.theme().foreground
+    };
+    let text = match temperature_unit {
+        TemperatureUnit::Celsius => format!("{:.0}°C", sensor.celsius),
+        TemperatureUnit::Fahrenheit => format!("{:.0}°F", sensor.celsius * 9.0 / 5.0 + 32.0),
+    };
+
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .text_xs()
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .text_color(cx.theme().muted_foreground)
+                .child(sensor_name(sensor)),
+        )
+        .child(
+            div()
+                .flex_shrink_0()
+                .font_medium()
+                .text_color(color)
+                .child(text),
+        )
+}
+
+/// 渲染单块硬盘：设备、型号和自检结果徽标，失败时整行以红色背景突出显示
+fn render_drive_row(drive: &DriveHealth, lang: &Language, cx: &App) -> impl IntoElement {
+    let failed = drive.passed == Some(false);
+    let (label, color) = match drive.passed {
+        Some(true) => (
+            crate::i18n::t(lang, "monitor.health.passed").to_string(),
+            cx.theme().success,
+        ),
+        Some(false) => (
+            crate::i18n::t(lang, "monitor.health.failed").replace("{}", &drive.status),
+            cx.theme().danger,
+        ),
+        None => (
+            crate::i18n::t(lang, "monitor.health.unknown").to_string(),
+            cx.theme().muted_foreground,
+        ),
+    };
+
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .text_xs()
+        .rounded(px(4.))
+        .when(failed, |this| {
+            this.px_1().bg(cx.theme().danger.opacity(0.15))
+        })
+        .child(
+            div()
+                .flex_shrink_0()
+                .font_medium()
+                .text_color(if failed {
+                    cx.theme().danger
+                } else {
+                    cx.theme().foreground
+                })
+                .child(drive.device.clone()),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .text_color(cx.theme().muted_foreground)
+                .child(drive.model.clone()),
+        )
+        .child(
+            div()
+                .flex_shrink_0()
+                .px_1()
+                .rounded(px(3.))
+                .bg(color.opacity(0.15))
+                .text_color(color)
+                .font_medium()
+                .child(label),
+        )
+}
@@ -1,5 +1,6 @@
 // 系统负载区块组件
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::{ActiveTheme, StyledExt};
 
@@ -18,10 +19,11 @@ pub fn render_load_card(
     let green_color = hsla(145.0 / 360.0, 0.63, 0.42, 1.0); // 绿色进度条
     let border_color = cx.theme().border;
 
-    // 获取语言设置
-    let lang = crate::services::storage::load_settings()
-        .map(|s| s.theme.language)
-        .unwrap_or_default();
+    // 获取语言设置与显示项目
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language;
+    let show_cpu = settings.monitor.show_cpu;
+    let show_memory = settings.monitor.show_memory;
 
     // 获取负载信息
     let (cpu_percent, memory_percent, memory_used, memory_total) =
@@ -70,9 +72,15 @@ pub fn render_load_card(
                 )
                 // 详情按钮
                 .child(render_detail_button(
-                    dialog_state,
+                    dialog_state.clone(),
                     DetailDialogType::LoadInfo,
                     cx,
+                ))
+                // 历史图表按钮
+                .child(render_detail_button(
+                    dialog_state,
+                    DetailDialogType::History,
+                    cx,
                 )),
         )
         // 内容区域
@@ -87,38 +95,42 @@ pub fn render_load_card(
                 .flex_col()
                 .gap_3()
                 // CPU 使用率行
-                .child(
-                    div()
-                        .flex()
-                        .items_center()
-                        .gap_2()
-                        // CPU 图标
-                        .child(svg().path(icons::CPU).size(px(18.)).text_color(green_color))
-                        // 进度条（带内嵌文字）
-                        .child(render_cpu_progress_bar(cpu_percent, green_color, cx)),
-                )
+                .when(show_cpu, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            // CPU 图标
+                            .child(svg().path(icons::CPU).size(px(18.)).text_color(green_color))
+                            // 进度条（带内嵌文字）
+                            .child(render_cpu_progress_bar(cpu_percent, green_color, cx)),
+                    )
+                })
                 // 内存使用率行
-                .child(
-                    div()
-                        .flex()
-                        .items_center()
-                        .gap_2()
-                        // 内存图标
-                        .child(
-                            svg()
-                                .path(icons::MEMORY)
-                                .size(px(18.))
-                                .text_color(green_color),
-                        )
-                        // 进度条（带内嵌文字：左侧百分比，右侧内存数值）
-                        .child(render_memory_progress_bar(
-                            memory_percent,
-                            memory_used,
-                            memory_total,
-                            green_color,
-                            cx,
-                        )),
-                ),
+                .when(show_memory, |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            // 内存图标
+                            .child(
+                                svg()
+                                    .path(icons::MEMORY)
+                                    .size(px(18.))
+                                    .text_color(green_color),
+                            )
+                            // 进度条（带内嵌文字：左侧百分比，右侧内存数值）
+                            .child(render_memory_progress_bar(
+                                memory_percent,
+                                memory_used,
+                                memory_total,
+                                green_color,
+                                cx,
+                            )),
+                    )
+                }),
         )
 }
 
@@ -0,0 +1,176 @@
+// 登录信息区块组件（who / last / lastb）
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::models::monitor::{LoginInfo, LoginRecord};
+
+use super::detail_dialog::{render_detail_button, DetailDialogState, DetailDialogType};
+
+/// 卡片中最多显示的当前会话数（完整列表在详情弹窗中）
+const MAX_CARD_SESSIONS: usize = 3;
+
+/// 渲染登录信息区块（无卡片边框）；尚未获取到登录信息时返回 None
+pub fn render_login_card(
+    login_info: Option<&LoginInfo>,
+    dialog_state: Entity<DetailDialogState>,
+    cx: &App,
+) -> Option<impl IntoElement> {
+    let info = login_info?;
+    let title_color = hsla(210.0 / 360.0, 1.0, 0.5, 1.0); // 蓝色标题
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_color = cx.theme().muted_foreground;
+
+    // 获取语言设置
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+
+    // 失败登录：有记录时以红色提示，无权限读取时说明原因
+    let (failed_text, failed_color) = if !info.failed_available {
+        (
+            crate::i18n::t(&lang, "monitor.logins.failed_unavailable").to_string(),
+            muted_color,
+        )
+    } else if info.failed.is_empty() {
+        (
+            crate::i18n::t(&lang, "monitor.logins.failed_none").to_string(),
+            muted_color,
+        )
+    } else {
+        (
+            crate::i18n::t(&lang, "monitor.logins.failed_count")
+                .replace("{}", &info.failed.len().to_string()),
+            cx.theme().danger,
+        )
+    };
+    let last_failed = info.failed.first().map(format_record);
+    let last_login = info.recent.first().map(format_record);
+
+    Some(
+        div()
+            .w_full()
+            .py_2()
+            .border_b_1()
+            .border_color(border_color)
+            .flex()
+            .flex_col()
+            .gap_2()
+            // 标题行
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_medium()
+                            .text_color(title_color)
+                            .child(crate::i18n::t(&lang, "monitor.logins")),
+                    )
+                    // 详情按钮
+                    .child(render_detail_button(
+                        dialog_state,
+                        DetailDialogType::LoginInfo,
+                        cx,
+                    )),
+            )
+            .child(
+                div()
+                    .w_full()
+                    .px_2()
+                    .py_2()
+                    .bg(cx.theme().secondary)
+                    .rounded(px(6.))
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .text_xs()
+                    // 当前会话
+                    .child(
+                        div().text_color(muted_color).child(
+                            crate::i18n::t(&lang, "monitor.logins.sessions")
+                                .replace("{}", &info.sessions.len().to_string()),
+                        ),
+                    )
+                    .children(info.sessions.iter().take(MAX_CARD_SESSIONS).map(|session| {
+                        div()
+                            .flex()
+                            .items_center()
+                            .gap_2()
+                            .child(
+                                div()
+                                    .flex_shrink_0()
+                                    .font_medium()
+                                    .text_color(foreground)
+                                    .child(session.user.clone()),
+                            )
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .min_w_0()
+                                    .overflow_hidden()
+                                    .whitespace_nowrap()
+                                    .text_ellipsis()
+                                    .text_color(muted_color)
+                                    .child(if session.from.is_empty() {
+                                        session.tty.clone()
+                                    } else {
+                                        format!("{} · {}", session.tty, session.from)
+                                    }),
+                            )
+                    }))
+                    .when(info.sessions.len() > MAX_CARD_SESSIONS, |this| {
+                        this.child(
+                            div()
+                                .text_color(muted_color)
+                                .child(format!("+{}", info.sessions.len() - MAX_CARD_SESSIONS)),
+                        )
+                    })
+                    // 最近一次登录
+                    .when_some(last_login, |this, text| {
+                        this.child(
+                            div()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_color(muted_color)
+                                .child(format!(
+                                    "{}: {}",
+                                    crate::i18n::t(&lang, "monitor.logins.last_login"),
+                                    text
+                                )),
+                        )
+                    })
+                    // 失败登录
+                    .child(
+                        div()
+                            .font_medium()
+                            .text_color(failed_color)
+                            .child(failed_text),
+                    )
+                    .when_some(last_failed, |this, text| {
+                        this.child(
+                            div()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_color(muted_color)
+                                .child(text),
+                        )
+                    }),
+            ),
+    )
+}
+
+/// 单行显示一条登录记录：用户@来源 时间
+fn format_record(record: &LoginRecord) -> String {
+    if record.from.is_empty() {
+        format!("{} {}", record.user, record.time)
+    } else {
+        format!("{}@{} {}", record.user, record.from, record.time)
+    }
+}
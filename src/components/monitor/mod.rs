@@ -2,7 +2,10 @@
 
 pub mod detail_dialog;
 pub mod disk_card;
+pub mod gpu_card;
+pub mod health_card;
 pub mod load_card;
+pub mod login_card;
 pub mod network_card;
 pub mod system_card;
 pub mod view;
@@ -29,11 +29,8 @@ pub fn render_system_card(
         let uptime = format_uptime(info.host.uptime_seconds);
         (info.host.address.clone(), info.host.os.clone(), uptime)
     } else {
-        (
-            "获取中...".to_string(),
-            "获取中...".to_string(),
-            "0s".to_string(),
-        )
+        let loading = crate::i18n::t(&lang, "common.loading");
+        (loading.to_string(), loading.to_string(), "0s".to_string())
     };
 
     let host_address_for_copy = host_address.clone();
@@ -1,14 +1,20 @@
 // 系统监控主视图
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::scroll::ScrollableElement;
+use gpui_component::ActiveTheme;
 
 use crate::models::monitor::MonitorState;
+use crate::models::settings::Language;
 use crate::state::SessionState;
 
 use super::detail_dialog::DetailDialogState;
 use super::disk_card::render_disk_card;
+use super::gpu_card::render_gpu_card;
+use super::health_card::render_health_card;
 use super::load_card::render_load_card;
+use super::login_card::render_login_card;
 use super::network_card::render_network_card;
 use super::system_card::render_system_card;
 
@@ -21,6 +27,10 @@ pub fn render_monitor_view(
     cx: &App,
 ) -> impl IntoElement {
     let bg_color = crate::theme::sidebar_color(cx);
+    // 设置中关闭的卡片显示为暂停状态（同时暂停对应的采集）
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let monitor = &settings.monitor;
+    let lang = &settings.theme.language;
 
     div()
         .id("monitor-view-scroll")
@@ -33,18 +43,114 @@ pub fn render_monitor_view(
         .flex()
         .flex_col()
         .gap_0()
+        // 采集失败提示（如远端系统不受支持、脚本执行失败）
+        .when_some(state.collector_error.clone(), |this, error| {
+            this.child(render_collector_error(error, cx))
+        })
         // 系统信息卡片
         .child(render_system_card(state, dialog_state.clone(), cx))
         // 系统负载卡片
-        .child(render_load_card(state, dialog_state.clone(), cx))
+        .child(if monitor.show_load() {
+            render_load_card(state, dialog_state.clone(), cx).into_any_element()
+        } else {
+            render_paused_card("monitor.load", lang, cx).into_any_element()
+        })
+        // GPU 状态卡片（没有 GPU 时不显示）
+        .when(monitor.show_gpu, |this| {
+            this.children(render_gpu_card(state.gpu_info.as_ref(), cx))
+        })
+        .when(!monitor.show_gpu, |this| {
+            this.child(render_paused_card("monitor.gpu", lang, cx))
+        })
+        // 硬件健康卡片（温度与 SMART，没有数据时不显示）
+        .when(monitor.show_health, |this| {
+            this.children(render_health_card(state, cx))
+        })
+        .when(!monitor.show_health, |this| {
+            this.child(render_paused_card("monitor.health", lang, cx))
+        })
         // 网络状态卡片
-        .child(render_network_card(
-            state,
-            dialog_state.clone(),
-            session_state,
-            tab_id,
-            cx,
-        ))
+        .child(if monitor.show_network {
+            render_network_card(state, dialog_state.clone(), session_state, tab_id, cx)
+                .into_any_element()
+        } else {
+            render_paused_card("monitor.network", lang, cx).into_any_element()
+        })
         // 磁盘状态卡片
-        .child(render_disk_card(state, cx))
+        .child(if monitor.show_disk {
+            render_disk_card(state, cx).into_any_element()
+        } else {
+            render_paused_card("monitor.disk", lang, cx).into_any_element()
+        })
+        // 登录信息卡片（尚未获取到时不显示）
+        .when(monitor.show_logins, |this| {
+            this.children(render_login_card(
+                state.login_info.as_ref(),
+                dialog_state,
+                cx,
+            ))
+        })
+        .when(!monitor.show_logins, |this| {
+            this.child(render_paused_card("monitor.logins", lang, cx))
+        })
+}
+
+/// 渲染已暂停的卡片：只保留灰色标题和“已暂停”标记
+fn render_paused_card(title_key: &'static str, lang: &Language, cx: &App) -> impl IntoElement {
+    let muted_color = cx.theme().muted_foreground;
+
+    div()
+        .w_full()
+        .py_2()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .justify_between()
+        .text_xs()
+        .opacity(0.6)
+        .child(
+            div()
+                .text_color(muted_color)
+                .child(crate::i18n::t(lang, title_key)),
+        )
+        .child(
+            div()
+                .px_1p5()
+                .rounded(px(4.))
+                .bg(cx.theme().muted)
+                .text_color(muted_color)
+                .child(crate::i18n::t(lang, "monitor.paused")),
+        )
+}
+
+/// 渲染采集失败提示条
+fn render_collector_error(error: String, cx: &App) -> impl IntoElement {
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+
+    div()
+        .w_full()
+        .mb_2()
+        .px_2()
+        .py_1()
+        .rounded(px(6.))
+        .bg(cx.theme().danger.opacity(0.1))
+        .flex()
+        .flex_col()
+        .gap_0p5()
+        .text_xs()
+        .child(
+            div()
+                .text_color(cx.theme().danger)
+                .child(crate::i18n::t(&lang, "monitor.collector_error")),
+        )
+        .child(
+            div()
+                .text_color(cx.theme().muted_foreground)
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(error),
+        )
 }
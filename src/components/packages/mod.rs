@@ -0,0 +1,5 @@
+// 软件包管理组件模块
+
+mod view;
+
+pub use view::{PackageEvent, PackageView};
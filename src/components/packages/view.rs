@@ -0,0 +1,784 @@
+// 软件包管理视图
+// 显示在 Monitor 区域的"软件包"页：检测到的包管理器、可升级的软件包（可单独或全部升级）、按名称搜索并安装，
+// 以及正在执行的操作的实时输出
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::components::common::confirm_bar::{self, ConfirmBarHost, ConfirmTone};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::packages::{
+    PackageAction, PackageManager, PackageSearchResult, UpgradablePackage,
+};
+
+/// 输出区最多保留的行数
+const MAX_OUTPUT_LINES: usize = 5000;
+
+/// 输出区的行高
+const OUTPUT_ROW_HEIGHT: f32 = 16.;
+
+/// 输出区的高度
+const OUTPUT_HEIGHT: f32 = 180.;
+
+/// 软件包管理事件
+#[derive(Clone, Debug)]
+pub enum PackageEvent {
+    /// 重新检测包管理器并读取可升级列表
+    Reload,
+    Search {
+        manager: PackageManager,
+        query: String,
+    },
+    /// 执行修改软件包的操作
+    Run {
+        manager: PackageManager,
+        action: PackageAction,
+    },
+}
+
+/// 正在执行或最近执行的操作
+struct ActionOutput {
+    title: String,
+    lines: VecDeque<String>,
+    /// 执行结果（None 表示仍在执行）
+    result: Option<Result<(), String>>,
+}
+
+/// 软件包管理视图
+pub struct PackageView {
+    /// 检测到的包管理器（外层 None 表示尚未检测完成，内层 None 表示未找到）
+    manager: Option<Option<PackageManager>>,
+    /// 检测或读取可升级列表的错误
+    error: Option<String>,
+    /// 可升级的软件包（None 表示正在读取）
+    upgradable: Option<Vec<UpgradablePackage>>,
+    search_input: Entity<InputState>,
+    searching: bool,
+    /// 搜索结果（None 表示未搜索）
+    search_results: Option<Vec<PackageSearchResult>>,
+    search_error: Option<String>,
+    output: Option<ActionOutput>,
+    output_scroll: UniformListScrollHandle,
+    /// 等待确认的操作
+    pending: Option<PackageAction>,
+    on_event: Rc<dyn Fn(PackageEvent, &mut App)>,
+}
+
+impl PackageView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(PackageEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        let search_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "packages.search_placeholder"))
+        });
+        cx.subscribe(&search_input, |this, _, event: &InputEvent, cx| {
+            if let InputEvent::PressEnter { .. } = event {
+                this.search(cx);
+            }
+        })
+        .detach();
+
+        Self {
+            manager: None,
+            error: None,
+            upgradable: None,
+            search_input,
+            searching: false,
+            search_results: None,
+            search_error: None,
+            output: None,
+            output_scroll: UniformListScrollHandle::new(),
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 检测到的包管理器
+    pub fn manager(&self) -> Option<PackageManager> {
+        self.manager.clone().flatten()
+    }
+
+    /// 写入包管理器检测结果
+    pub fn set_manager(
+        &mut self,
+        result: Result<Option<PackageManager>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(manager) => {
+                self.error = None;
+                if manager.is_none() {
+                    self.upgradable = Some(Vec::new());
+                }
+                self.manager = Some(manager);
+            }
+            Err(e) => {
+                self.manager = Some(None);
+                self.upgradable = Some(Vec::new());
+                self.error = Some(e);
+            }
+        }
+        cx.notify();
+    }
+
+    /// 写入可升级的软件包
+    pub fn set_upgradable(
+        &mut self,
+        result: Result<Vec<UpgradablePackage>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(packages) => {
+                self.error = None;
+                self.upgradable = Some(packages);
+            }
+            Err(e) => {
+                self.upgradable = Some(Vec::new());
+                self.error = Some(e);
+            }
+        }
+        cx.notify();
+    }
+
+    /// 写入搜索结果
+    pub fn set_search_results(
+        &mut self,
+        result: Result<Vec<PackageSearchResult>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        self.searching = false;
+        match result {
+            Ok(results) => {
+                self.search_error = None;
+                self.search_results = Some(results);
+            }
+            Err(e) => {
+                self.search_results = None;
+                self.search_error = Some(e);
+            }
+        }
+        cx.notify();
+    }
+
+    /// 开始显示操作输出
+    pub fn start_output(&mut self, action: &PackageAction, cx: &mut Context<Self>) {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        self.output = Some(ActionOutput {
+            title: action_title(action, &lang),
+            lines: VecDeque::new(),
+            result: None,
+        });
+        cx.notify();
+    }
+
+    /// 追加输出行并滚动到末尾
+    pub fn append_output(&mut self, lines: Vec<String>, cx: &mut Context<Self>) {
+        let Some(output) = self.output.as_mut() else {
+            return;
+        };
+        output.lines.extend(lines);
+        while output.lines.len() > MAX_OUTPUT_LINES {
+            output.lines.pop_front();
+        }
+        if !output.lines.is_empty() {
+            self.output_scroll
+                .scroll_to_item(output.lines.len() - 1, ScrollStrategy::Top);
+        }
+        cx.notify();
+    }
+
+    /// 操作结束
+    pub fn finish_output(&mut self, result: Result<(), String>, cx: &mut Context<Self>) {
+        if let Some(output) = self.output.as_mut() {
+            output.result = Some(result);
+        }
+        cx.notify();
+    }
+
+    /// 取消尚未开始输出的操作（如需要先输入 sudo 密码）
+    pub fn cancel_output(&mut self, cx: &mut Context<Self>) {
+        if self
+            .output
+            .as_ref()
+            .is_some_and(|o| o.result.is_none() && o.lines.is_empty())
+        {
+            self.output = None;
+        }
+        cx.notify();
+    }
+
+    /// 是否有操作正在执行
+    fn is_running(&self) -> bool {
+        self.output.as_ref().is_some_and(|o| o.result.is_none())
+    }
+
+    /// 发送事件
+    fn emit(&mut self, event: PackageEvent, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    /// 重新检测并读取可升级列表
+    fn reload(&mut self, cx: &mut Context<Self>) {
+        self.manager = None;
+        self.upgradable = None;
+        self.error = None;
+        self.emit(PackageEvent::Reload, cx);
+    }
+
+    /// 按输入框内容搜索
+    fn search(&mut self, cx: &mut Context<Self>) {
+        let query = self.search_input.read(cx).value().trim().to_string();
+        let Some(manager) = self.manager().filter(|_| !query.is_empty()) else {
+            return;
+        };
+        self.searching = true;
+        self.search_error = None;
+        self.emit(PackageEvent::Search { manager, query }, cx);
+    }
+
+    /// 清除搜索结果
+    fn clear_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.search_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        self.search_results = None;
+        self.search_error = None;
+        cx.notify();
+    }
+
+    /// 请求执行操作：更新索引直接执行，其他操作先显示确认栏
+    fn request(&mut self, action: PackageAction, cx: &mut Context<Self>) {
+        if self.is_running() || self.manager().is_none() {
+            return;
+        }
+        if action == PackageAction::Refresh {
+            self.run(action, cx);
+        } else {
+            self.request_confirm(action, cx);
+        }
+    }
+
+    /// 执行操作
+    fn run(&mut self, action: PackageAction, cx: &mut Context<Self>) {
+        let Some(manager) = self.manager() else {
+            return;
+        };
+        self.start_output(&action, cx);
+        self.emit(PackageEvent::Run { manager, action }, cx);
+    }
+
+    /// 关闭已结束的操作输出
+    fn close_output(&mut self, cx: &mut Context<Self>) {
+        if !self.is_running() {
+            self.output = None;
+            cx.notify();
+        }
+    }
+
+    /// 渲染工具栏图标按钮
+    fn render_icon_button(
+        &self,
+        id: &'static str,
+        icon: &'static str,
+        tooltip: &'static str,
+        disabled: bool,
+        on_click: impl Fn(&mut Self, &mut Window, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let hover_bg = cx.theme().list_active;
+        div()
+            .id(id)
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .when(disabled, |s| s.opacity(0.4))
+            .when(!disabled, |s| {
+                s.cursor_pointer().hover(move |s| s.bg(hover_bg))
+            })
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(14.))
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .tooltip(move |window, cx| Tooltip::new(tooltip).build(window, cx))
+            .when(!disabled, |s| {
+                s.on_click(cx.listener(move |this, _, window, cx| on_click(this, window, cx)))
+            })
+    }
+
+    /// 渲染一行软件包：名称、版本说明和操作按钮
+    fn render_package_row(
+        &self,
+        id: SharedString,
+        name: &str,
+        detail: String,
+        button_label: &'static str,
+        action: PackageAction,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let disabled = self.is_running();
+        div()
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_medium()
+                            .text_color(cx.theme().foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(name.to_string()),
+                    )
+                    .when(!detail.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(detail),
+                        )
+                    }),
+            )
+            .child(
+                div()
+                    .id(id)
+                    .flex_shrink_0()
+                    .px_2()
+                    .py_0p5()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .bg(cx.theme().secondary)
+                    .text_color(cx.theme().foreground)
+                    .when(disabled, |s| s.opacity(0.4))
+                    .when(!disabled, |s| {
+                        s.cursor_pointer().hover(|s| s.opacity(0.8)).on_click(
+                            cx.listener(move |this, _, _, cx| this.request(action.clone(), cx)),
+                        )
+                    })
+                    .child(button_label),
+            )
+            .into_any_element()
+    }
+
+    /// 渲染分节标题
+    fn render_section_header(&self, label: String, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .w_full()
+            .px_2()
+            .pt_2()
+            .pb_1()
+            .text_xs()
+            .font_medium()
+            .text_color(cx.theme().muted_foreground)
+            .child(label)
+    }
+
+    /// 渲染搜索结果
+    fn render_search_section(&self, lang: &Language, cx: &mut Context<Self>) -> Vec<AnyElement> {
+        let muted = cx.theme().muted_foreground;
+        let hint = |text: String| {
+            div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(muted)
+                .child(text)
+                .into_any_element()
+        };
+        if self.searching {
+            return vec![hint(t(lang, "packages.searching").to_string())];
+        }
+        if let Some(e) = &self.search_error {
+            return vec![div()
+                .px_2()
+                .py_1()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .child(format!("{}: {}", t(lang, "packages.search_failed"), e))
+                .into_any_element()];
+        }
+        let Some(results) = self.search_results.clone() else {
+            return Vec::new();
+        };
+
+        let mut rows = vec![self
+            .render_section_header(
+                t(lang, "packages.search_results").replace("{}", &results.len().to_string()),
+                cx,
+            )
+            .into_any_element()];
+        if results.is_empty() {
+            rows.push(hint(t(lang, "packages.no_results").to_string()));
+        }
+        for (index, result) in results.iter().enumerate() {
+            rows.push(self.render_package_row(
+                SharedString::from(format!("package-install-{}", index)),
+                &result.name,
+                result.description.clone(),
+                t(lang, "packages.install"),
+                PackageAction::Install(result.name.clone()),
+                cx,
+            ));
+        }
+        rows
+    }
+
+    /// 渲染可升级的软件包
+    fn render_upgradable_section(
+        &self,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> Vec<AnyElement> {
+        let muted = cx.theme().muted_foreground;
+        let Some(packages) = self.upgradable.clone() else {
+            return vec![div()
+                .px_2()
+                .py_2()
+                .text_xs()
+                .text_color(muted)
+                .child(t(lang, "common.loading"))
+                .into_any_element()];
+        };
+        if self.manager().is_none() {
+            return Vec::new();
+        }
+
+        let mut rows = vec![self
+            .render_section_header(
+                t(lang, "packages.upgradable").replace("{}", &packages.len().to_string()),
+                cx,
+            )
+            .into_any_element()];
+        if packages.is_empty() {
+            rows.push(
+                div()
+                    .px_2()
+                    .py_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(t(lang, "packages.up_to_date"))
+                    .into_any_element(),
+            );
+        }
+        for (index, package) in packages.iter().enumerate() {
+            let detail = if package.current.is_empty() {
+                package.available.clone()
+            } else {
+                format!("{} → {}", package.current, package.available)
+            };
+            rows.push(self.render_package_row(
+                SharedString::from(format!("package-upgrade-{}", index)),
+                &package.name,
+                detail,
+                t(lang, "packages.upgrade"),
+                PackageAction::Upgrade(package.name.clone()),
+                cx,
+            ));
+        }
+        rows
+    }
+
+    /// 渲染操作输出
+    fn render_output(&self, lang: &Language, cx: &mut Context<Self>) -> Option<AnyElement> {
+        let output = self.output.as_ref()?;
+        let (status, status_color) = match &output.result {
+            None => (t(lang, "packages.running").to_string(), cx.theme().primary),
+            Some(Ok(())) => (t(lang, "packages.done").to_string(), cx.theme().success),
+            Some(Err(e)) => (
+                format!("{}: {}", t(lang, "packages.failed"), e),
+                cx.theme().danger,
+            ),
+        };
+        let lines: Vec<String> = output.lines.iter().cloned().collect();
+        let foreground = cx.theme().foreground;
+        let running = output.result.is_none();
+
+        Some(
+            div()
+                .w_full()
+                .h(px(OUTPUT_HEIGHT))
+                .flex_shrink_0()
+                .flex()
+                .flex_col()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_0p5()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .text_xs()
+                                .font_medium()
+                                .text_color(foreground)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(output.title.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .max_w(px(160.))
+                                .text_xs()
+                                .text_color(status_color)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(status),
+                        )
+                        .child(self.render_icon_button(
+                            "packages-output-close",
+                            icons::X,
+                            t(lang, "packages.close_output"),
+                            running,
+                            |this, _, cx| this.close_output(cx),
+                            cx,
+                        )),
+                )
+                .child(
+                    uniform_list(
+                        "packages-output-lines",
+                        lines.len(),
+                        move |range: Range<usize>, _window, _cx| {
+                            range
+                                .map(|ix| {
+                                    div()
+                                        .h(px(OUTPUT_ROW_HEIGHT))
+                                        .px_2()
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_xs()
+                                        .font_family("monospace")
+                                        .text_color(foreground)
+                                        .child(lines[ix].clone())
+                                        .into_any_element()
+                                })
+                                .collect::<Vec<_>>()
+                        },
+                    )
+                    .track_scroll(self.output_scroll.clone())
+                    .flex_1()
+                    .min_h(px(0.))
+                    .bg(cx.theme().background),
+                )
+                .into_any_element(),
+        )
+    }
+
+    /// 渲染确认栏（使用 sudo 时附带密码提示）
+    fn render_confirm_bar(
+        &self,
+        action: &PackageAction,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let sudo = self.manager().is_some_and(|m| m.needs_sudo());
+        let message = div()
+            .child(t(lang, "packages.confirm").replace("{}", &action_title(action, lang)))
+            .when(sudo, |this| {
+                this.child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t(lang, "packages.sudo_hint")),
+                )
+            });
+        confirm_bar::render_confirm_bar("packages-confirm", message, ConfirmTone::Warning, lang, cx)
+    }
+}
+
+impl ConfirmBarHost for PackageView {
+    type Pending = PackageAction;
+
+    fn pending_mut(&mut self) -> &mut Option<PackageAction> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, action: PackageAction, cx: &mut Context<Self>) {
+        self.run(action, cx);
+    }
+}
+
+/// 操作的显示名称
+fn action_title(action: &PackageAction, lang: &Language) -> String {
+    match action {
+        PackageAction::Refresh => t(lang, "packages.action.refresh").to_string(),
+        PackageAction::UpgradeAll => t(lang, "packages.action.upgrade_all").to_string(),
+        PackageAction::Upgrade(name) => t(lang, "packages.action.upgrade").replace("{}", name),
+        PackageAction::Install(name) => t(lang, "packages.action.install").replace("{}", name),
+    }
+}
+
+impl Render for PackageView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+        let running = self.is_running();
+        let manager = self.manager();
+        let detecting = self.manager.is_none();
+
+        let manager_label = match &manager {
+            Some(manager) => format!(
+                "{}{}",
+                manager.kind.label(),
+                if manager.needs_sudo() { " · sudo" } else { "" }
+            ),
+            None if detecting => t(&lang, "packages.detecting").to_string(),
+            None => t(&lang, "packages.not_found").to_string(),
+        };
+        let no_manager = manager.is_none();
+
+        let toolbar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(manager_label),
+            )
+            .child(self.render_icon_button(
+                "packages-reload",
+                icons::REFRESH,
+                t(&lang, "packages.reload"),
+                running,
+                |this, _, cx| this.reload(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "packages-refresh-index",
+                icons::DOWNLOAD,
+                t(&lang, "packages.action.refresh"),
+                running || no_manager,
+                |this, _, cx| this.request(PackageAction::Refresh, cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "packages-upgrade-all",
+                icons::ARROW_UP,
+                t(&lang, "packages.action.upgrade_all"),
+                running || no_manager,
+                |this, _, cx| this.request(PackageAction::UpgradeAll, cx),
+                cx,
+            ));
+
+        let has_search = self.search_results.is_some() || self.search_error.is_some();
+        let search_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(
+                div()
+                    .flex_1()
+                    .child(Input::new(&self.search_input).xsmall()),
+            )
+            .when(has_search, |this| {
+                this.child(self.render_icon_button(
+                    "packages-clear-search",
+                    icons::X,
+                    t(&lang, "packages.clear_search"),
+                    false,
+                    |this, window, cx| this.clear_search(window, cx),
+                    cx,
+                ))
+            });
+
+        let error = self.error.clone();
+        let search_rows = self.render_search_section(&lang, cx);
+        let upgradable_rows = self.render_upgradable_section(&lang, cx);
+        let body = div()
+            .id("packages-list")
+            .flex_1()
+            .min_h(px(0.))
+            .overflow_y_scroll()
+            .when_some(error, |this, error| {
+                this.child(
+                    div()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(error),
+                )
+            })
+            .children(search_rows)
+            .children(upgradable_rows);
+
+        let output = self.render_output(&lang, cx);
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(toolbar)
+            .when(!no_manager, |this| this.child(search_bar))
+            .child(body)
+            .children(output)
+            .when_some(pending, |this, action| {
+                this.child(self.render_confirm_bar(&action, &lang, cx))
+            })
+    }
+}
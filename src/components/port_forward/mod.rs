@@ -0,0 +1,5 @@
+// 端口转发组件模块
+
+mod view;
+
+pub use view::{ForwardEntry, PortForwardEvent, PortForwardView};
@@ -0,0 +1,645 @@
+// 端口转发视图
+// 显示在会话右侧边栏的"端口转发"页：上方为新建表单（L/R/D）和数据库隧道快捷入口，下方为每条转发的实时状态（连接数、流量、最近的错误）及启停按钮
+
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::{DbClientCommands, Language};
+use crate::services::db_tunnel::{launch_db_client, DatabaseKind};
+use crate::ssh::{parse_host_port, ForwardKind, ForwardSpec, ForwardStats};
+
+/// 运行中的转发刷新统计的间隔
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 端口转发事件
+#[derive(Clone)]
+pub enum PortForwardEvent {
+    /// 启动转发（新建或重新启动已停止的转发）
+    Start(ForwardEntry),
+    /// 停止转发（按转发 ID）
+    Stop(String),
+}
+
+/// 一条转发（统计由转发任务实时更新）
+#[derive(Clone)]
+pub struct ForwardEntry {
+    pub id: String,
+    pub spec: ForwardSpec,
+    pub stats: Arc<ForwardStats>,
+    /// 数据库隧道对应的数据库（普通转发为 None）
+    pub database: Option<DatabaseKind>,
+}
+
+/// 端口转发视图
+pub struct PortForwardView {
+    entries: Vec<ForwardEntry>,
+    /// 表单中选择的转发类型
+    kind: ForwardKind,
+    bind_input: Entity<InputState>,
+    target_input: Entity<InputState>,
+    /// 表单校验失败的提示
+    form_error: Option<&'static str>,
+    /// 启动数据库客户端失败的原因
+    client_error: Option<String>,
+    on_event: Rc<dyn Fn(PortForwardEvent, &mut App)>,
+}
+
+impl PortForwardView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(PortForwardEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        let bind_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "forward.bind_placeholder"))
+        });
+        let target_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "forward.target_placeholder"))
+        });
+        // 任一输入框回车即提交
+        for input in [&bind_input, &target_input] {
+            cx.subscribe_in(input, window, |this, _, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.submit(window, cx);
+                }
+            })
+            .detach();
+        }
+
+        // 有转发在运行时定时刷新统计
+        cx.spawn(async move |this, cx| loop {
+            cx.background_executor().timer(REFRESH_INTERVAL).await;
+            let updated = this.update(cx, |this, cx| {
+                if this.entries.iter().any(|e| e.stats.is_running()) {
+                    cx.notify();
+                }
+            });
+            if updated.is_err() {
+                break;
+            }
+        })
+        .detach();
+
+        Self {
+            entries: Vec::new(),
+            kind: ForwardKind::Local,
+            bind_input,
+            target_input,
+            form_error: None,
+            client_error: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 新建一条转发并返回（由调用方启动）
+    pub fn add(
+        &mut self,
+        spec: ForwardSpec,
+        database: Option<DatabaseKind>,
+        cx: &mut Context<Self>,
+    ) -> ForwardEntry {
+        let entry = ForwardEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            spec,
+            stats: Arc::new(ForwardStats::default()),
+            database,
+        };
+        self.entries.push(entry.clone());
+        cx.notify();
+        entry
+    }
+
+    /// 已添加的全部转发配置
+    pub fn specs(&self) -> Vec<ForwardSpec> {
+        self.entries.iter().map(|e| e.spec.clone()).collect()
+    }
+
+    /// 停止并删除转发
+    fn remove(&mut self, id: String, cx: &mut Context<Self>) {
+        self.entries.retain(|e| e.id != id);
+        self.emit(PortForwardEvent::Stop(id), cx);
+    }
+
+    fn emit(&mut self, event: PortForwardEvent, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    fn set_kind(&mut self, kind: ForwardKind, cx: &mut Context<Self>) {
+        self.kind = kind;
+        self.form_error = None;
+        cx.notify();
+    }
+
+    /// 校验表单并新建转发
+    fn submit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let bind = self.bind_input.read(cx).value().to_string();
+        let target = self.target_input.read(cx).value().to_string();
+
+        // 本地/动态转发默认只监听本机；远程转发默认监听服务器的 localhost
+        let default_bind = match self.kind {
+            ForwardKind::Remote => "localhost",
+            _ => "127.0.0.1",
+        };
+        let Some((bind_host, bind_port)) = parse_host_port(&bind, default_bind) else {
+            self.form_error = Some("forward.invalid_bind");
+            cx.notify();
+            return;
+        };
+        let (target_host, target_port) = if self.kind == ForwardKind::Dynamic {
+            (String::new(), 0)
+        } else {
+            let default_target = match self.kind {
+                ForwardKind::Remote => "127.0.0.1",
+                _ => "localhost",
+            };
+            match parse_host_port(&target, default_target) {
+                Some(target) if target.1 != 0 => target,
+                _ => {
+                    self.form_error = Some("forward.invalid_target");
+                    cx.notify();
+                    return;
+                }
+            }
+        };
+
+        self.form_error = None;
+        self.bind_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        self.target_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        let entry = self.add(
+            ForwardSpec {
+                kind: self.kind,
+                bind_host,
+                bind_port,
+                target_host,
+                target_port,
+            },
+            None,
+            cx,
+        );
+        self.emit(PortForwardEvent::Start(entry), cx);
+    }
+
+    /// 新建数据库隧道：本机随机端口 → 目标输入框中的地址（为空时为服务器本机的默认端口）
+    fn add_db_tunnel(&mut self, kind: DatabaseKind, window: &mut Window, cx: &mut Context<Self>) {
+        let target = self.target_input.read(cx).value().to_string();
+        let (target_host, target_port) = if target.trim().is_empty() {
+            ("127.0.0.1".to_string(), kind.default_port())
+        } else {
+            match parse_host_port(&target, "127.0.0.1") {
+                Some(target) if target.1 != 0 => target,
+                _ => {
+                    self.form_error = Some("forward.invalid_target");
+                    cx.notify();
+                    return;
+                }
+            }
+        };
+
+        self.form_error = None;
+        self.target_input
+            .update(cx, |input, cx| input.set_value("", window, cx));
+        let entry = self.add(
+            ForwardSpec {
+                kind: ForwardKind::Local,
+                bind_host: "127.0.0.1".to_string(),
+                bind_port: 0,
+                target_host,
+                target_port,
+            },
+            Some(kind),
+            cx,
+        );
+        self.emit(PortForwardEvent::Start(entry), cx);
+    }
+
+    /// 用设置中配置的命令启动本地客户端
+    fn launch_client(
+        &mut self,
+        command: &str,
+        kind: DatabaseKind,
+        local_port: u16,
+        cx: &mut Context<Self>,
+    ) {
+        self.client_error = launch_db_client(command, kind, local_port)
+            .err()
+            .map(|e| e.to_string());
+        cx.notify();
+    }
+
+    /// 渲染新建表单
+    fn render_form(&self, lang: &Language, cx: &mut Context<Self>) -> impl IntoElement {
+        let kinds = [
+            (ForwardKind::Local, "forward.kind.local"),
+            (ForwardKind::Remote, "forward.kind.remote"),
+            (ForwardKind::Dynamic, "forward.kind.dynamic"),
+        ];
+        let kind_tabs: Vec<AnyElement> = kinds
+            .into_iter()
+            .map(|(kind, tooltip_key)| {
+                let active = self.kind == kind;
+                let tooltip = t(lang, tooltip_key);
+                div()
+                    .id(SharedString::from(format!(
+                        "forward-kind-{}",
+                        kind.short_label()
+                    )))
+                    .flex_1()
+                    .py_0p5()
+                    .flex()
+                    .justify_center()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .cursor_pointer()
+                    .when(active, |s| {
+                        s.bg(cx.theme().primary)
+                            .text_color(cx.theme().primary_foreground)
+                    })
+                    .when(!active, |s| {
+                        s.text_color(cx.theme().muted_foreground)
+                            .hover(|s| s.bg(cx.theme().list_active))
+                    })
+                    .child(kind.short_label())
+                    .tooltip(move |window, cx| Tooltip::new(tooltip).build(window, cx))
+                    .on_click(cx.listener(move |this, _, _, cx| this.set_kind(kind, cx)))
+                    .into_any_element()
+            })
+            .collect();
+        let db_tooltip = t(lang, "forward.db_tunnel_tooltip");
+        let db_buttons: Vec<AnyElement> =
+            DatabaseKind::ALL
+                .into_iter()
+                .map(|kind| {
+                    div()
+                        .id(SharedString::from(format!("forward-db-{}", kind.label())))
+                        .flex_1()
+                        .py_0p5()
+                        .flex()
+                        .justify_center()
+                        .rounded(px(4.))
+                        .text_xs()
+                        .bg(cx.theme().secondary)
+                        .text_color(cx.theme().foreground)
+                        .cursor_pointer()
+                        .hover(|s| s.opacity(0.8))
+                        .child(kind.label())
+                        .tooltip(move |window, cx| Tooltip::new(db_tooltip).build(window, cx))
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            this.add_db_tunnel(kind, window, cx)
+                        }))
+                        .into_any_element()
+                })
+                .collect();
+
+        div()
+            .w_full()
+            .flex_shrink_0()
+            .p_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(div().flex().gap_1().children(kind_tabs))
+            .child(Input::new(&self.bind_input).xsmall())
+            .when(self.kind != ForwardKind::Dynamic, |this| {
+                this.child(Input::new(&self.target_input).xsmall())
+            })
+            .when_some(self.form_error, |this, key| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(t(lang, key)),
+                )
+            })
+            .child(
+                div()
+                    .id("forward-add")
+                    .w_full()
+                    .py_0p5()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .gap_1()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .bg(cx.theme().secondary)
+                    .text_color(cx.theme().foreground)
+                    .cursor_pointer()
+                    .hover(|s| s.opacity(0.8))
+                    .child(
+                        svg()
+                            .path(icons::PLUS)
+                            .size(px(12.))
+                            .text_color(cx.theme().foreground),
+                    )
+                    .child(t(lang, "forward.add"))
+                    .on_click(cx.listener(|this, _, window, cx| this.submit(window, cx))),
+            )
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex_shrink_0()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t(lang, "forward.db_tunnel")),
+                    )
+                    .children(db_buttons),
+            )
+            .when_some(self.client_error.clone(), |this, error| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(t(lang, "forward.launch_failed").replace("{}", &error)),
+                )
+            })
+    }
+
+    /// 渲染一条转发
+    fn render_entry(
+        &self,
+        entry: &ForwardEntry,
+        lang: &Language,
+        db_clients: &DbClientCommands,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let stats = &entry.stats;
+        let running = stats.is_running();
+        let last_error = stats.last_error();
+        let dot_color = if running {
+            cx.theme().success
+        } else if last_error.is_some() {
+            cx.theme().danger
+        } else {
+            cx.theme().muted_foreground
+        };
+        // 实际监听端口与配置不同时（如远程转发端口为 0）显示实际端口
+        let bound = stats
+            .bound_port()
+            .filter(|port| *port != entry.spec.bind_port)
+            .map(|port| format!(" (:{})", port))
+            .unwrap_or_default();
+        let connections = t(lang, "forward.connections")
+            .replace("{active}", &stats.active_connections().to_string())
+            .replace("{total}", &stats.total_connections().to_string());
+        let traffic = format!(
+            "↓ {}  ↑ {}",
+            format_bytes(stats.bytes_in()),
+            format_bytes(stats.bytes_out())
+        );
+
+        let entry_for_toggle = entry.clone();
+        let id_for_remove = entry.id.clone();
+        let (toggle_icon, toggle_tooltip) = if running {
+            (icons::PAUSE, t(lang, "forward.stop"))
+        } else {
+            (icons::PLAY, t(lang, "forward.start"))
+        };
+        let remove_tooltip = t(lang, "forward.remove");
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_active;
+
+        // 数据库隧道监听后显示本地连接串
+        let db_row = entry
+            .database
+            .zip(stats.bound_port().filter(|_| running))
+            .map(|(kind, port)| {
+                let url = kind.connection_string(port);
+                let command = kind.client_command(db_clients).to_string();
+                let copy_tooltip = t(lang, "forward.copy_url");
+                let launch_tooltip = t(lang, "forward.launch_client");
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .text_xs()
+                            .font_family("monospace")
+                            .text_color(cx.theme().link)
+                            .child(url.clone()),
+                    )
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("forward-copy-{}", entry.id)))
+                            .size(px(18.))
+                            .flex_shrink_0()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(px(4.))
+                            .cursor_pointer()
+                            .hover(move |s| s.bg(hover_bg))
+                            .child(svg().path(icons::COPY).size(px(12.)).text_color(muted))
+                            .tooltip(move |window, cx| Tooltip::new(copy_tooltip).build(window, cx))
+                            .on_click(move |_, _, cx| {
+                                cx.write_to_clipboard(ClipboardItem::new_string(url.clone()));
+                            }),
+                    )
+                    .when(!command.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .id(SharedString::from(format!("forward-launch-{}", entry.id)))
+                                .size(px(18.))
+                                .flex_shrink_0()
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .rounded(px(4.))
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(hover_bg))
+                                .child(svg().path(icons::TERMINAL).size(px(12.)).text_color(muted))
+                                .tooltip(move |window, cx| {
+                                    Tooltip::new(launch_tooltip).build(window, cx)
+                                })
+                                .on_click(cx.listener(move |this, _, _, cx| {
+                                    this.launch_client(&command, kind, port, cx);
+                                })),
+                        )
+                    })
+            });
+
+        div()
+            .id(SharedString::from(format!("forward-{}", entry.id)))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1()
+                    .child(
+                        div()
+                            .size(px(6.))
+                            .flex_shrink_0()
+                            .rounded_full()
+                            .bg(dot_color),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .text_xs()
+                            .font_family("monospace")
+                            .text_color(cx.theme().foreground)
+                            .child(format!("{}{}", entry.spec.describe(), bound)),
+                    )
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("forward-toggle-{}", entry.id)))
+                            .size(px(18.))
+                            .flex_shrink_0()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(px(4.))
+                            .cursor_pointer()
+                            .hover(move |s| s.bg(hover_bg))
+                            .child(svg().path(toggle_icon).size(px(12.)).text_color(muted))
+                            .tooltip(move |window, cx| {
+                                Tooltip::new(toggle_tooltip).build(window, cx)
+                            })
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                let event = if running {
+                                    PortForwardEvent::Stop(entry_for_toggle.id.clone())
+                                } else {
+                                    PortForwardEvent::Start(entry_for_toggle.clone())
+                                };
+                                this.emit(event, cx);
+                            })),
+                    )
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("forward-remove-{}", entry.id)))
+                            .size(px(18.))
+                            .flex_shrink_0()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(px(4.))
+                            .cursor_pointer()
+                            .hover(move |s| s.bg(hover_bg))
+                            .child(svg().path(icons::X).size(px(12.)).text_color(muted))
+                            .tooltip(move |window, cx| {
+                                Tooltip::new(remove_tooltip).build(window, cx)
+                            })
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.remove(id_for_remove.clone(), cx);
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .flex()
+                    .justify_between()
+                    .gap_2()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(connections)
+                    .child(traffic),
+            )
+            .children(db_row)
+            .when_some(last_error, |this, error| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(error),
+                )
+            })
+            .into_any_element()
+    }
+}
+
+/// 格式化字节数
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else if bytes < 1024 * 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.2} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+impl Render for PortForwardView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let settings = crate::services::storage::load_settings().unwrap_or_default();
+        let lang = settings.theme.language;
+        let db_clients = settings.connection.db_client_commands;
+
+        let body = if self.entries.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .px_2()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(t(&lang, "forward.empty"))
+                .into_any_element()
+        } else {
+            let rows: Vec<AnyElement> = self
+                .entries
+                .clone()
+                .iter()
+                .map(|entry| self.render_entry(entry, &lang, &db_clients, cx))
+                .collect();
+            div()
+                .id("forward-list")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .children(rows)
+                .into_any_element()
+        };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .child(self.render_form(&lang, cx))
+            .child(body)
+    }
+}
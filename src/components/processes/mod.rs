@@ -0,0 +1,6 @@
+// 远程进程管理组件模块
+
+mod table;
+mod view;
+
+pub use view::{ProcessEvent, ProcessListView};
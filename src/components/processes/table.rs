@@ -0,0 +1,235 @@
+// 进程表格：列定义、过滤与排序
+
+use gpui::*;
+use gpui_component::table::{Column, ColumnSort, TableDelegate, TableState};
+use gpui_component::ActiveTheme;
+
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::processes::ProcessEntry;
+
+/// 进程表格的列
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProcessColumn {
+    Pid,
+    User,
+    Cpu,
+    Memory,
+    Command,
+}
+
+impl ProcessColumn {
+    const ALL: [ProcessColumn; 5] = [
+        ProcessColumn::Pid,
+        ProcessColumn::User,
+        ProcessColumn::Cpu,
+        ProcessColumn::Memory,
+        ProcessColumn::Command,
+    ];
+
+    /// (列 key, 标题 i18n key, 初始宽度)
+    fn spec(self) -> (&'static str, &'static str, f32) {
+        match self {
+            ProcessColumn::Pid => ("pid", "processes.column.pid", 56.),
+            ProcessColumn::User => ("user", "processes.column.user", 72.),
+            ProcessColumn::Cpu => ("cpu", "processes.column.cpu", 52.),
+            ProcessColumn::Memory => ("mem", "processes.column.mem", 52.),
+            ProcessColumn::Command => ("command", "processes.column.command", 320.),
+        }
+    }
+}
+
+/// 进程表格数据源
+pub struct ProcessTableDelegate {
+    columns: Vec<Column>,
+    processes: Vec<ProcessEntry>,
+    /// 过滤、排序后的显示顺序（processes 的下标）
+    row_order: Vec<usize>,
+    /// 过滤条件（小写，匹配 PID、用户和命令行）
+    filter: String,
+    sort_column: ProcessColumn,
+    sort: ColumnSort,
+}
+
+impl ProcessTableDelegate {
+    pub fn new(lang: &Language) -> Self {
+        let mut delegate = Self {
+            columns: ProcessColumn::ALL
+                .iter()
+                .map(|kind| {
+                    let (key, label, width) = kind.spec();
+                    Column::new(key, t(lang, label))
+                        .width(px(width))
+                        .resizable(true)
+                        .sortable()
+                })
+                .collect(),
+            processes: Vec::new(),
+            row_order: Vec::new(),
+            filter: String::new(),
+            // 默认按 CPU 占用从高到低
+            sort_column: ProcessColumn::Cpu,
+            sort: ColumnSort::Descending,
+        };
+        delegate.sync_column_sort_state();
+        delegate
+    }
+
+    /// 同步列标题上的排序指示
+    fn sync_column_sort_state(&mut self) {
+        for (column, kind) in self.columns.iter_mut().zip(ProcessColumn::ALL) {
+            column.sort = Some(if kind == self.sort_column {
+                self.sort
+            } else {
+                ColumnSort::Default
+            });
+        }
+    }
+
+    /// 更新进程列表，保留过滤条件和排序状态
+    pub fn set_processes(&mut self, processes: Vec<ProcessEntry>) {
+        self.processes = processes;
+        self.refresh_rows();
+    }
+
+    /// 设置过滤条件
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.trim().to_lowercase();
+        self.refresh_rows();
+    }
+
+    /// 进程总数与过滤后显示的数量
+    pub fn counts(&self) -> (usize, usize) {
+        (self.processes.len(), self.row_order.len())
+    }
+
+    /// 指定行对应的进程
+    pub fn entry(&self, row_ix: usize) -> Option<&ProcessEntry> {
+        self.row_order
+            .get(row_ix)
+            .and_then(|&ix| self.processes.get(ix))
+    }
+
+    /// PID 所在的行
+    pub fn row_of(&self, pid: u32) -> Option<usize> {
+        self.row_order
+            .iter()
+            .position(|&ix| self.processes[ix].pid == pid)
+    }
+
+    /// 按过滤条件和排序状态重建行顺序
+    fn refresh_rows(&mut self) {
+        let processes = &self.processes;
+        let filter = &self.filter;
+        self.row_order.clear();
+        self.row_order.extend((0..processes.len()).filter(|&ix| {
+            let p = &processes[ix];
+            filter.is_empty()
+                || p.pid.to_string().contains(filter.as_str())
+                || p.user.to_lowercase().contains(filter.as_str())
+                || p.command.to_lowercase().contains(filter.as_str())
+        }));
+
+        if self.sort == ColumnSort::Default {
+            return;
+        }
+        let descending = self.sort == ColumnSort::Descending;
+        let sort_column = self.sort_column;
+        self.row_order.sort_by(|&a, &b| {
+            let (a, b) = (&processes[a], &processes[b]);
+            let ordering = match sort_column {
+                ProcessColumn::Pid => a.pid.cmp(&b.pid),
+                ProcessColumn::User => a.user.cmp(&b.user),
+                ProcessColumn::Cpu => a.cpu_percent.total_cmp(&b.cpu_percent),
+                ProcessColumn::Memory => a.memory_percent.total_cmp(&b.memory_percent),
+                ProcessColumn::Command => a.command.cmp(&b.command),
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+}
+
+impl TableDelegate for ProcessTableDelegate {
+    fn columns_count(&self, _cx: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _cx: &App) -> usize {
+        self.row_order.len()
+    }
+
+    fn column(&self, col_ix: usize, _cx: &App) -> &Column {
+        &self.columns[col_ix]
+    }
+
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) {
+        let Some(kind) = ProcessColumn::ALL.get(col_ix).copied() else {
+            return;
+        };
+        self.sort_column = kind;
+        self.sort = sort;
+        self.sync_column_sort_state();
+        self.refresh_rows();
+        cx.notify();
+    }
+
+    fn render_th(
+        &mut self,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let name = self.columns[col_ix].name.clone();
+        div()
+            .size_full()
+            .flex()
+            .items_center()
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .child(name)
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let Some(entry) = self.entry(row_ix) else {
+            return div();
+        };
+        let text = match ProcessColumn::ALL[col_ix] {
+            ProcessColumn::Pid => entry.pid.to_string(),
+            ProcessColumn::User => entry.user.clone(),
+            ProcessColumn::Cpu => format!("{:.1}", entry.cpu_percent),
+            ProcessColumn::Memory => format!("{:.1}", entry.memory_percent),
+            ProcessColumn::Command => entry.command.clone(),
+        };
+        let color = if ProcessColumn::ALL[col_ix] == ProcessColumn::Command {
+            cx.theme().foreground
+        } else {
+            cx.theme().muted_foreground
+        };
+        div()
+            .h_full()
+            .flex()
+            .items_center()
+            .text_xs()
+            .text_color(color)
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .text_ellipsis()
+            .child(text)
+    }
+}
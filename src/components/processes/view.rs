@@ -0,0 +1,338 @@
+// 进程管理视图
+// 显示在 Monitor 区域的"进程"页：过滤栏 + 可排序的进程表格，右键结束进程或调整优先级（执行前需确认）
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{ContextMenuExt, PopupMenu, PopupMenuItem};
+use gpui_component::table::{Table, TableState};
+use gpui_component::{ActiveTheme, Sizable};
+
+use super::table::ProcessTableDelegate;
+use crate::components::common::confirm_bar::{self, ConfirmBarHost, ConfirmTone};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::processes::{ProcessEntry, ProcessSignal};
+
+/// 右键菜单中提供的 nice 值
+const NICE_LEVELS: [i32; 5] = [-5, 0, 5, 10, 19];
+
+/// 进程管理事件
+#[derive(Clone, Debug)]
+pub enum ProcessEvent {
+    /// 向进程发送信号
+    Signal(u32, ProcessSignal),
+    /// 调整进程的 nice 值
+    Renice(u32, i32),
+}
+
+/// 等待确认的操作
+#[derive(Clone, Debug)]
+pub struct PendingAction {
+    pid: u32,
+    /// 程序名（用于确认提示）
+    name: String,
+    event: ProcessEvent,
+}
+
+/// 进程管理视图
+pub struct ProcessListView {
+    table_state: Entity<TableState<ProcessTableDelegate>>,
+    filter_input: Entity<InputState>,
+    /// 是否已收到过进程列表
+    loaded: bool,
+    /// 最近一次获取列表的错误
+    error: Option<String>,
+    /// 最近一次操作失败的提示
+    action_error: Option<String>,
+    /// 等待确认的操作
+    pending: Option<PendingAction>,
+    on_event: Rc<dyn Fn(ProcessEvent, &mut App)>,
+}
+
+impl ProcessListView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(ProcessEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        let delegate = ProcessTableDelegate::new(&lang);
+        let table_state = cx.new(|cx| {
+            TableState::new(delegate, window, cx)
+                .sortable(true)
+                .col_resizable(true)
+                .row_selectable(true)
+        });
+
+        // 输入过滤条件时即时过滤进程列表
+        let filter_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "processes.filter_placeholder"))
+        });
+        cx.subscribe(&filter_input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                let filter = input.read(cx).value().to_string();
+                this.table_state.update(cx, |table_state, cx| {
+                    table_state.delegate_mut().set_filter(&filter);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+
+        Self {
+            table_state,
+            filter_input,
+            loaded: false,
+            error: None,
+            action_error: None,
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入最新的进程列表，刷新后保持原来选中的进程
+    pub fn set_processes(
+        &mut self,
+        result: Result<Vec<ProcessEntry>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(processes) => {
+                self.loaded = true;
+                self.error = None;
+                self.table_state.update(cx, |table_state, cx| {
+                    let selected_pid = table_state
+                        .selected_row()
+                        .and_then(|row| table_state.delegate().entry(row))
+                        .map(|p| p.pid);
+                    table_state.delegate_mut().set_processes(processes);
+                    if let Some(row) =
+                        selected_pid.and_then(|pid| table_state.delegate().row_of(pid))
+                    {
+                        table_state.set_selected_row(row, cx);
+                    }
+                    cx.notify();
+                });
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 显示操作失败的提示
+    pub fn set_action_error(&mut self, error: Option<String>, cx: &mut Context<Self>) {
+        self.action_error = error;
+        cx.notify();
+    }
+
+    /// 当前选中的进程
+    fn selected_process(&self, cx: &App) -> Option<ProcessEntry> {
+        let table_state = self.table_state.read(cx);
+        let row = table_state.selected_row()?;
+        table_state.delegate().entry(row).cloned()
+    }
+
+    /// 请求执行操作（先显示确认栏）
+    fn request(&mut self, process: &ProcessEntry, event: ProcessEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        self.request_confirm(
+            PendingAction {
+                pid: process.pid,
+                name: process.name().to_string(),
+                event,
+            },
+            cx,
+        );
+    }
+
+    /// 渲染确认栏
+    fn render_confirm_bar(
+        &self,
+        pending: &PendingAction,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = match pending.event {
+            ProcessEvent::Signal(_, ProcessSignal::Term) => t(lang, "processes.confirm_kill"),
+            ProcessEvent::Signal(_, ProcessSignal::Kill) => t(lang, "processes.confirm_force_kill"),
+            ProcessEvent::Renice(_, _) => t(lang, "processes.confirm_renice"),
+        };
+        let message = message
+            .replace("{pid}", &pending.pid.to_string())
+            .replace("{name}", &pending.name);
+        let message = match pending.event {
+            ProcessEvent::Renice(_, nice) => message.replace("{nice}", &nice.to_string()),
+            ProcessEvent::Signal(_, _) => message,
+        };
+        let tone = if matches!(pending.event, ProcessEvent::Signal(..)) {
+            ConfirmTone::Danger
+        } else {
+            ConfirmTone::Normal
+        };
+        confirm_bar::render_confirm_bar("processes-confirm", message, tone, lang, cx)
+    }
+}
+
+impl ConfirmBarHost for ProcessListView {
+    type Pending = PendingAction;
+
+    fn pending_mut(&mut self) -> &mut Option<PendingAction> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, pending: PendingAction, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(pending.event, cx);
+    }
+}
+
+/// 构建进程右键菜单：结束、强制结束、调整优先级
+fn build_process_context_menu(
+    menu: PopupMenu,
+    process: ProcessEntry,
+    lang: &Language,
+    entity: Entity<ProcessListView>,
+) -> PopupMenu {
+    let signal_item = |label: &str, signal: ProcessSignal| {
+        let entity = entity.clone();
+        let process = process.clone();
+        PopupMenuItem::new(label.to_string()).on_click(move |_, _, cx| {
+            entity.update(cx, |view, cx| {
+                view.request(&process, ProcessEvent::Signal(process.pid, signal), cx)
+            });
+        })
+    };
+    let menu = menu
+        .min_w(px(180.))
+        .label(format!("{} · {}", process.pid, process.name()))
+        .item(signal_item(t(lang, "processes.kill"), ProcessSignal::Term))
+        .item(signal_item(
+            t(lang, "processes.force_kill"),
+            ProcessSignal::Kill,
+        ))
+        .separator()
+        .label(t(lang, "processes.renice"));
+    NICE_LEVELS.iter().fold(menu, |menu, &nice| {
+        let entity = entity.clone();
+        let process = process.clone();
+        menu.item(
+            PopupMenuItem::new(format!("nice {}", nice)).on_click(move |_, _, cx| {
+                entity.update(cx, |view, cx| {
+                    view.request(&process, ProcessEvent::Renice(process.pid, nice), cx)
+                });
+            }),
+        )
+    })
+}
+
+impl Render for ProcessListView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+        let (total, shown) = self.table_state.read(cx).delegate().counts();
+        let count_text = if shown == total {
+            t(&lang, "processes.count").replace("{}", &total.to_string())
+        } else {
+            format!("{} / {}", shown, total)
+        };
+
+        let filter_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    Input::new(&self.filter_input)
+                        .xsmall()
+                        .cleanable(true)
+                        .prefix(svg().path(icons::FILTER).size(px(12.)).text_color(muted)),
+                ),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(count_text),
+            );
+
+        let body = if self.loaded {
+            let this = cx.entity().clone();
+            let lang_for_menu = lang.clone();
+            div()
+                .id("processes-table")
+                .flex_1()
+                .min_h_0()
+                .child(Table::new(&self.table_state).stripe(true).bordered(false))
+                .context_menu(
+                    move |menu, _window, cx| match this.read(cx).selected_process(cx) {
+                        Some(process) => {
+                            build_process_context_menu(menu, process, &lang_for_menu, this.clone())
+                        }
+                        None => menu,
+                    },
+                )
+                .into_any_element()
+        } else {
+            let hint = match &self.error {
+                Some(e) => format!("{}: {}", t(&lang, "processes.load_failed"), e),
+                None => t(&lang, "common.loading").to_string(),
+            };
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .px_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()
+        };
+
+        let action_error = self.action_error.clone();
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(filter_bar)
+            .child(body)
+            .when_some(action_error, |this, error| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(format!(
+                            "{}: {}",
+                            t(&lang, "processes.action_failed"),
+                            error
+                        )),
+                )
+            })
+            .when_some(pending, |this, pending| {
+                this.child(self.render_confirm_bar(&pending, &lang, cx))
+            })
+    }
+}
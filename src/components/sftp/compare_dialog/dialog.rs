@@ -0,0 +1,508 @@
+// 文件/目录对比对话框渲染
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use super::highlight::{highlight_line, syntax_for, TokenKind};
+use super::state::{CompareContent, CompareDialogState};
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::sftp::{
+    CompareLocation, CompareTarget, DiffLine, DiffLineKind, DirDiffEntry, DirDiffStatus,
+};
+use crate::services::storage;
+
+/// 差异行高
+const ROW_HEIGHT: f32 = 20.0;
+/// 行号栏宽度
+const LINE_NUMBER_WIDTH: f32 = 48.0;
+/// 目录树每级缩进
+const INDENT_WIDTH: f32 = 16.0;
+
+/// 渲染文件/目录对比对话框覆盖层
+/// `on_open_child` 在目录对比中点击两侧都存在的文件时调用，参数为相对路径
+pub fn render_compare_dialog_overlay<F>(
+    state: Entity<CompareDialogState>,
+    on_open_child: F,
+    cx: &App,
+) -> impl IntoElement
+where
+    F: Fn(Entity<CompareDialogState>, String, &mut App) + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let has_parent = state_read.has_parent();
+    let left_label = state_read
+        .left
+        .as_ref()
+        .map(|t| target_label(t, &lang))
+        .unwrap_or_default();
+    let right_label = state_read
+        .right
+        .as_ref()
+        .map(|t| target_label(t, &lang))
+        .unwrap_or_default();
+    let file_name = state_read
+        .right
+        .as_ref()
+        .map(|t| {
+            t.path
+                .rsplit(['/', '\\'])
+                .next()
+                .unwrap_or(&t.path)
+                .to_string()
+        })
+        .unwrap_or_default();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let hover_bg = cx.theme().list_hover;
+
+    let (summary, body): (Option<String>, AnyElement) = match &state_read.content {
+        CompareContent::Loading => (
+            None,
+            render_message(i18n::t(&lang, "sftp.compare.loading"), cx),
+        ),
+        CompareContent::Error(message) => (None, render_message(message.clone(), cx)),
+        CompareContent::TooLarge => (
+            None,
+            render_message(i18n::t(&lang, "sftp.compare.too_large"), cx),
+        ),
+        CompareContent::Binary { identical } => (
+            None,
+            render_message(
+                if *identical {
+                    i18n::t(&lang, "sftp.compare.binary_identical")
+                } else {
+                    i18n::t(&lang, "sftp.compare.binary_different")
+                },
+                cx,
+            ),
+        ),
+        CompareContent::Text {
+            lines,
+            added,
+            removed,
+        } => {
+            if lines.is_empty() {
+                (
+                    None,
+                    render_message(i18n::t(&lang, "sftp.compare.identical"), cx),
+                )
+            } else {
+                (
+                    Some(format!("+{}  −{}", added, removed)),
+                    render_text_diff(lines.clone(), &file_name, cx),
+                )
+            }
+        }
+        CompareContent::Dir { entries } => {
+            let count = |status| entries.iter().filter(|e| e.status == status).count();
+            let summary = format!(
+                "{} {}  {} {}  {} {}",
+                i18n::t(&lang, "sftp.compare.added"),
+                count(DirDiffStatus::Added),
+                i18n::t(&lang, "sftp.compare.removed"),
+                count(DirDiffStatus::Removed),
+                i18n::t(&lang, "sftp.compare.modified"),
+                count(DirDiffStatus::Modified),
+            );
+            let visible = state_read.visible_dir_entries();
+            let body = if visible.is_empty() {
+                render_message(i18n::t(&lang, "sftp.compare.identical"), cx)
+            } else {
+                render_dir_diff(
+                    state.clone(),
+                    entries.clone(),
+                    visible,
+                    Rc::new(on_open_child),
+                    &lang,
+                    cx,
+                )
+            };
+            (Some(summary), body)
+        }
+    };
+    let is_dir = matches!(state_read.content, CompareContent::Dir { .. });
+    let only_diffs = state_read.only_diffs;
+
+    let state_back = state.clone();
+    let state_toggle = state.clone();
+    let state_close = state.clone();
+
+    let header_button = |id: &'static str, icon: &'static str| {
+        div()
+            .id(id)
+            .size(px(24.))
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(svg().path(icon).size(px(14.)).text_color(foreground))
+    };
+
+    div()
+        .id("compare-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(relative(0.85))
+                .h(relative(0.85))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .flex()
+                .flex_col()
+                .overflow_hidden()
+                // 标题栏
+                .child(
+                    div()
+                        .px_4()
+                        .py_3()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .border_b_1()
+                        .border_color(border_color)
+                        .when(has_parent, |this| {
+                            this.child(header_button("compare-back", icons::ARROW_LEFT).on_click(
+                                move |_, _, cx| {
+                                    state_back.update(cx, |s, cx| {
+                                        s.back();
+                                        cx.notify();
+                                    });
+                                },
+                            ))
+                        })
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(foreground)
+                                .child(i18n::t(&lang, "sftp.compare.title")),
+                        )
+                        .child(div().flex_1())
+                        .children(summary.map(|summary| {
+                            div().text_sm().text_color(muted_foreground).child(summary)
+                        }))
+                        .when(is_dir, |this| {
+                            this.child(
+                                div()
+                                    .id("compare-only-diffs")
+                                    .px_2()
+                                    .py_1()
+                                    .rounded_md()
+                                    .text_sm()
+                                    .cursor_pointer()
+                                    .text_color(if only_diffs {
+                                        foreground
+                                    } else {
+                                        muted_foreground
+                                    })
+                                    .when(only_diffs, |this| this.bg(cx.theme().list_active))
+                                    .hover(move |s| s.bg(hover_bg))
+                                    .on_click(move |_, _, cx| {
+                                        state_toggle.update(cx, |s, cx| {
+                                            s.toggle_only_diffs();
+                                            cx.notify();
+                                        });
+                                    })
+                                    .child(i18n::t(&lang, "sftp.compare.only_diffs")),
+                            )
+                        })
+                        .child(header_button("compare-close", icons::X).on_click(
+                            move |_, _, cx| {
+                                state_close.update(cx, |s, cx| {
+                                    s.close();
+                                    cx.notify();
+                                });
+                            },
+                        )),
+                )
+                // 两侧路径
+                .child(
+                    div()
+                        .px_4()
+                        .py_2()
+                        .flex()
+                        .gap_4()
+                        .text_xs()
+                        .border_b_1()
+                        .border_color(border_color)
+                        .child(side_label("−", left_label, cx.theme().danger, cx))
+                        .child(side_label("+", right_label, cx.theme().success, cx)),
+                )
+                .child(div().flex_1().min_h(px(0.)).flex().flex_col().child(body)),
+        )
+}
+
+/// 对比对象的显示名称（位置: 路径）
+fn target_label(target: &CompareTarget, lang: &Language) -> String {
+    let location = match &target.location {
+        CompareLocation::Local => i18n::t(lang, "sftp.compare.local").to_string(),
+        CompareLocation::Remote { server_label, .. } => server_label.clone(),
+    };
+    format!("{}: {}", location, target.path)
+}
+
+/// 渲染一侧的路径标签
+fn side_label(marker: &'static str, label: String, color: Hsla, cx: &App) -> impl IntoElement {
+    div()
+        .flex_1()
+        .min_w_0()
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(div().text_color(color).child(marker))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .text_color(cx.theme().muted_foreground)
+                .child(label),
+        )
+}
+
+/// 渲染居中的提示信息
+fn render_message(message: impl Into<SharedString>, cx: &App) -> AnyElement {
+    div()
+        .flex_1()
+        .flex()
+        .items_center()
+        .justify_center()
+        .text_sm()
+        .text_color(cx.theme().muted_foreground)
+        .child(message.into())
+        .into_any_element()
+}
+
+/// 渲染文本差异（统一视图，按扩展名做语法高亮）
+fn render_text_diff(lines: Rc<Vec<DiffLine>>, file_name: &str, cx: &App) -> AnyElement {
+    let syntax = syntax_for(file_name);
+    let foreground = cx.theme().foreground;
+    let muted = cx.theme().muted_foreground;
+    let added_bg = cx.theme().success.opacity(0.15);
+    let removed_bg = cx.theme().danger.opacity(0.15);
+    let gap_bg = cx.theme().muted;
+    let token_color = {
+        let (comment, string, number, keyword) = (
+            cx.theme().muted_foreground,
+            cx.theme().success,
+            cx.theme().warning,
+            cx.theme().link,
+        );
+        move |kind: TokenKind| match kind {
+            TokenKind::Comment => comment,
+            TokenKind::String => string,
+            TokenKind::Number => number,
+            TokenKind::Keyword => keyword,
+        }
+    };
+
+    uniform_list(
+        "compare-text-diff",
+        lines.len(),
+        move |range: std::ops::Range<usize>, _window, _cx| {
+            range
+                .map(|ix| {
+                    let line = &lines[ix];
+                    let row = div()
+                        .w_full()
+                        .h(px(ROW_HEIGHT))
+                        .flex()
+                        .items_center()
+                        .font_family("monospace")
+                        .text_xs();
+                    if line.kind == DiffLineKind::Gap {
+                        return row
+                            .bg(gap_bg)
+                            .justify_center()
+                            .text_color(muted)
+                            .child("⋯")
+                            .into_any_element();
+                    }
+
+                    let (marker, bg) = match line.kind {
+                        DiffLineKind::Added => ("+", Some(added_bg)),
+                        DiffLineKind::Removed => ("−", Some(removed_bg)),
+                        _ => (" ", None),
+                    };
+                    let number = |n: Option<usize>| {
+                        div()
+                            .w(px(LINE_NUMBER_WIDTH))
+                            .flex_shrink_0()
+                            .pr_2()
+                            .flex()
+                            .justify_end()
+                            .text_color(muted)
+                            .child(n.map(|n| n.to_string()).unwrap_or_default())
+                    };
+                    let highlights: Vec<(std::ops::Range<usize>, HighlightStyle)> = syntax
+                        .map(|syntax| {
+                            highlight_line(&line.text, syntax)
+                                .into_iter()
+                                .map(|(range, kind)| {
+                                    (
+                                        range,
+                                        HighlightStyle {
+                                            color: Some(token_color(kind)),
+                                            ..Default::default()
+                                        },
+                                    )
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    row.when_some(bg, |row, bg| row.bg(bg))
+                        .child(number(line.old_line))
+                        .child(number(line.new_line))
+                        .child(
+                            div()
+                                .w(px(16.))
+                                .flex_shrink_0()
+                                .text_color(muted)
+                                .child(marker),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_color(foreground)
+                                .child(
+                                    StyledText::new(line.text.clone()).with_highlights(highlights),
+                                ),
+                        )
+                        .into_any_element()
+                })
+                .collect::<Vec<_>>()
+        },
+    )
+    .flex_1()
+    .min_h(px(0.))
+    .into_any_element()
+}
+
+/// 渲染目录差异（树形列表，点击两侧都存在的文件查看内容差异）
+fn render_dir_diff(
+    state: Entity<CompareDialogState>,
+    entries: Rc<Vec<DirDiffEntry>>,
+    visible: Vec<usize>,
+    on_open_child: Rc<dyn Fn(Entity<CompareDialogState>, String, &mut App)>,
+    lang: &Language,
+    cx: &App,
+) -> AnyElement {
+    let foreground = cx.theme().foreground;
+    let muted = cx.theme().muted_foreground;
+    let link = cx.theme().link;
+    let hover_bg = cx.theme().list_hover;
+    let status_style = {
+        let labels = [
+            i18n::t(lang, "sftp.compare.added"),
+            i18n::t(lang, "sftp.compare.removed"),
+            i18n::t(lang, "sftp.compare.modified"),
+        ];
+        let (success, danger, warning) =
+            (cx.theme().success, cx.theme().danger, cx.theme().warning);
+        move |status: DirDiffStatus| match status {
+            DirDiffStatus::Added => Some((labels[0], success)),
+            DirDiffStatus::Removed => Some((labels[1], danger)),
+            DirDiffStatus::Modified => Some((labels[2], warning)),
+            DirDiffStatus::Same => None,
+        }
+    };
+
+    uniform_list(
+        "compare-dir-diff",
+        visible.len(),
+        move |range: std::ops::Range<usize>, _window, _cx| {
+            range
+                .map(|ix| {
+                    let entry = &entries[visible[ix]];
+                    let style = status_style(entry.status);
+                    let openable = entry.comparable && entry.status == DirDiffStatus::Modified;
+                    let row = div()
+                        .id(("compare-dir-row", ix))
+                        .w_full()
+                        .h(px(ROW_HEIGHT + 4.))
+                        .px_4()
+                        .pl(px(16. + entry.depth as f32 * INDENT_WIDTH))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .text_xs()
+                        .child(
+                            svg()
+                                .path(if entry.is_dir {
+                                    icons::FOLDER
+                                } else {
+                                    icons::FILE
+                                })
+                                .size(px(14.))
+                                .flex_shrink_0()
+                                .text_color(if entry.is_dir { link } else { muted }),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_color(match style {
+                                    Some((_, color)) => color,
+                                    None => foreground,
+                                })
+                                .child(entry.name.clone()),
+                        )
+                        .children(style.map(|(label, color)| div().text_color(color).child(label)));
+
+                    if openable {
+                        let state = state.clone();
+                        let on_open_child = on_open_child.clone();
+                        let relative_path = entry.relative_path.clone();
+                        row.cursor_pointer()
+                            .hover(move |s| s.bg(hover_bg))
+                            .on_click(move |_, _, cx| {
+                                on_open_child(state.clone(), relative_path.clone(), cx);
+                            })
+                            .into_any_element()
+                    } else {
+                        row.into_any_element()
+                    }
+                })
+                .collect::<Vec<_>>()
+        },
+    )
+    .flex_1()
+    .min_h(px(0.))
+    .into_any_element()
+}
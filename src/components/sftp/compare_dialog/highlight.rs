@@ -0,0 +1,168 @@
+// 差异视图的轻量语法高亮
+// 按文件扩展名选择词法规则，逐行识别注释、字符串、数字与关键字（不处理跨行的块注释）
+
+use std::ops::Range;
+
+/// 高亮的词法类别
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Comment,
+    String,
+    Number,
+    Keyword,
+}
+
+/// 一种语言的词法规则
+pub struct Syntax {
+    /// 行注释前缀
+    line_comments: &'static [&'static str],
+    /// 字符串引号
+    quotes: &'static [char],
+    keywords: &'static [&'static str],
+    /// 关键字不区分大小写
+    ignore_case: bool,
+}
+
+const RUST: Syntax = Syntax {
+    line_comments: &["//"],
+    quotes: &['"'],
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "else", "enum", "false",
+        "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+        "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+        "unsafe", "use", "where", "while",
+    ],
+    ignore_case: false,
+};
+
+const C_LIKE: Syntax = Syntax {
+    line_comments: &["//"],
+    quotes: &['"', '\'', '`'],
+    keywords: &[
+        "break", "case", "catch", "class", "const", "continue", "default", "do", "else", "enum",
+        "export", "extends", "false", "final", "finally", "for", "func", "function", "go", "if",
+        "import", "let", "new", "null", "package", "private", "public", "return", "static",
+        "struct", "switch", "this", "throw", "true", "try", "type", "var", "void", "while",
+    ],
+    ignore_case: false,
+};
+
+const SCRIPT: Syntax = Syntax {
+    line_comments: &["#"],
+    quotes: &['"', '\''],
+    keywords: &[
+        "and", "case", "class", "def", "do", "done", "elif", "else", "esac", "export", "fi", "for",
+        "from", "function", "if", "import", "in", "local", "not", "or", "return", "then", "while",
+        "with", "yield", "True", "False", "None", "true", "false",
+    ],
+    ignore_case: false,
+};
+
+const SQL: Syntax = Syntax {
+    line_comments: &["--"],
+    quotes: &['\'', '"'],
+    keywords: &[
+        "select", "from", "where", "insert", "into", "values", "update", "set", "delete", "create",
+        "table", "drop", "alter", "join", "left", "inner", "on", "and", "or", "not", "null",
+        "order", "group", "by", "as",
+    ],
+    ignore_case: true,
+};
+
+const DATA: Syntax = Syntax {
+    line_comments: &[],
+    quotes: &['"'],
+    keywords: &["true", "false", "null"],
+    ignore_case: false,
+};
+
+/// 按文件名选择词法规则，未识别的类型不做高亮
+pub fn syntax_for(name: &str) -> Option<&'static Syntax> {
+    let lower = name.to_lowercase();
+    if matches!(
+        lower.as_str(),
+        "dockerfile" | "makefile" | ".bashrc" | ".zshrc" | ".profile"
+    ) {
+        return Some(&SCRIPT);
+    }
+    let ext = lower.rsplit_once('.').map(|(_, ext)| ext)?;
+    match ext {
+        "rs" => Some(&RUST),
+        "c" | "h" | "cc" | "cpp" | "hpp" | "java" | "kt" | "js" | "jsx" | "ts" | "tsx" | "go"
+        | "cs" | "swift" | "scala" | "php" => Some(&C_LIKE),
+        "sh" | "bash" | "zsh" | "py" | "rb" | "pl" | "yaml" | "yml" | "toml" | "conf" | "ini"
+        | "cfg" | "env" | "properties" => Some(&SCRIPT),
+        "sql" => Some(&SQL),
+        "json" => Some(&DATA),
+        _ => None,
+    }
+}
+
+/// 计算一行文本中需要高亮的范围（字节偏移）
+pub fn highlight_line(line: &str, syntax: &Syntax) -> Vec<(Range<usize>, TokenKind)> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if syntax
+            .line_comments
+            .iter()
+            .any(|prefix| line[start..].starts_with(prefix))
+        {
+            tokens.push((start..line.len(), TokenKind::Comment));
+            break;
+        }
+
+        if syntax.quotes.contains(&c) {
+            let mut end = line.len();
+            let mut escaped = false;
+            for (ix, ch) in chars.by_ref() {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == c {
+                    end = ix + ch.len_utf8();
+                    break;
+                }
+            }
+            tokens.push((start..end, TokenKind::String));
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let mut end = start + 1;
+            while let Some(&(ix, ch)) = chars.peek() {
+                if !(ch.is_ascii_alphanumeric() || ch == '.' || ch == '_') {
+                    break;
+                }
+                end = ix + 1;
+                chars.next();
+            }
+            tokens.push((start..end, TokenKind::Number));
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some(&(ix, ch)) = chars.peek() {
+                if !(ch.is_alphanumeric() || ch == '_') {
+                    break;
+                }
+                end = ix + ch.len_utf8();
+                chars.next();
+            }
+            let word = &line[start..end];
+            let is_keyword = syntax.keywords.iter().any(|k| {
+                if syntax.ignore_case {
+                    k.eq_ignore_ascii_case(word)
+                } else {
+                    *k == word
+                }
+            });
+            if is_keyword {
+                tokens.push((start..end, TokenKind::Keyword));
+            }
+        }
+    }
+    tokens
+}
@@ -0,0 +1,8 @@
+// SFTP 文件/目录对比对话框组件
+
+mod dialog;
+mod highlight;
+mod state;
+
+pub use dialog::render_compare_dialog_overlay;
+pub use state::{CompareContent, CompareDialogState};
@@ -0,0 +1,183 @@
+// 文件/目录对比对话框状态管理
+
+use std::rc::Rc;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::services::sftp::{CompareTarget, DiffLine, DirDiffEntry, DirDiffStatus, FileDiff};
+
+/// 对比结果
+pub enum CompareContent {
+    /// 正在读取两侧内容
+    Loading,
+    /// 文本文件差异；`lines` 为空表示内容相同
+    Text {
+        lines: Rc<Vec<DiffLine>>,
+        added: usize,
+        removed: usize,
+    },
+    /// 二进制文件
+    Binary { identical: bool },
+    /// 文件过大，未对比
+    TooLarge,
+    /// 目录差异（树形顺序）
+    Dir { entries: Rc<Vec<DirDiffEntry>> },
+    /// 读取失败
+    Error(String),
+}
+
+impl CompareContent {
+    /// 由文件对比结果生成
+    pub fn from_file_diff(diff: FileDiff) -> Self {
+        match diff {
+            FileDiff::Text {
+                lines,
+                added,
+                removed,
+            } => CompareContent::Text {
+                lines: Rc::new(lines),
+                added,
+                removed,
+            },
+            FileDiff::Binary { identical } => CompareContent::Binary { identical },
+            FileDiff::TooLarge => CompareContent::TooLarge,
+        }
+    }
+}
+
+/// 从目录对比进入文件对比前保存的目录对比
+struct ParentCompare {
+    left: CompareTarget,
+    right: CompareTarget,
+    entries: Rc<Vec<DirDiffEntry>>,
+}
+
+/// 文件/目录对比对话框状态
+pub struct CompareDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 左侧（先选中的）对象
+    pub left: Option<CompareTarget>,
+    /// 右侧对象
+    pub right: Option<CompareTarget>,
+    /// 对比结果
+    pub content: CompareContent,
+    /// 目录对比只显示有差异的条目
+    pub only_diffs: bool,
+    /// 从目录对比进入文件对比时保存的目录对比
+    parent: Option<ParentCompare>,
+    /// 读取任务的取消令牌
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl Default for CompareDialogState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            left: None,
+            right: None,
+            content: CompareContent::Loading,
+            only_diffs: true,
+            parent: None,
+            cancellation_token: None,
+        }
+    }
+}
+
+impl CompareDialogState {
+    /// 打开对话框并开始对比，返回本次对比的取消令牌
+    pub fn open(&mut self, left: CompareTarget, right: CompareTarget) -> CancellationToken {
+        self.is_open = true;
+        self.parent = None;
+        self.start(left, right)
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.cancel();
+        self.is_open = false;
+        self.left = None;
+        self.right = None;
+        self.content = CompareContent::Loading;
+        self.parent = None;
+    }
+
+    /// 开始对比两个对象
+    fn start(&mut self, left: CompareTarget, right: CompareTarget) -> CancellationToken {
+        self.cancel();
+        let token = CancellationToken::new();
+        self.cancellation_token = Some(token.clone());
+        self.left = Some(left);
+        self.right = Some(right);
+        self.content = CompareContent::Loading;
+        token
+    }
+
+    /// 从目录对比中打开两侧都存在的文件，返回两侧对象与取消令牌
+    pub fn open_child(
+        &mut self,
+        relative_path: &str,
+    ) -> Option<(CompareTarget, CompareTarget, CancellationToken)> {
+        let CompareContent::Dir { entries } = &self.content else {
+            return None;
+        };
+        let (left, right) = (self.left.clone()?, self.right.clone()?);
+        self.parent = Some(ParentCompare {
+            left: left.clone(),
+            right: right.clone(),
+            entries: entries.clone(),
+        });
+        let left = left.child(relative_path, false);
+        let right = right.child(relative_path, false);
+        let token = self.start(left.clone(), right.clone());
+        Some((left, right, token))
+    }
+
+    /// 是否可以返回目录对比
+    pub fn has_parent(&self) -> bool {
+        self.parent.is_some()
+    }
+
+    /// 返回目录对比
+    pub fn back(&mut self) {
+        if let Some(parent) = self.parent.take() {
+            self.cancel();
+            self.left = Some(parent.left);
+            self.right = Some(parent.right);
+            self.content = CompareContent::Dir {
+                entries: parent.entries,
+            };
+        }
+    }
+
+    /// 写入对比结果
+    pub fn set_content(&mut self, content: CompareContent) {
+        self.cancellation_token = None;
+        self.content = content;
+    }
+
+    /// 切换目录对比是否只显示差异
+    pub fn toggle_only_diffs(&mut self) {
+        self.only_diffs = !self.only_diffs;
+    }
+
+    /// 目录对比中要显示的条目下标
+    pub fn visible_dir_entries(&self) -> Vec<usize> {
+        let CompareContent::Dir { entries } = &self.content else {
+            return Vec::new();
+        };
+        entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| !self.only_diffs || e.status != DirDiffStatus::Same)
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    /// 取消正在进行的读取
+    fn cancel(&mut self) {
+        if let Some(token) = self.cancellation_token.take() {
+            token.cancel();
+        }
+    }
+}
@@ -0,0 +1,350 @@
+// 传输冲突对话框渲染
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use super::state::{ConflictDialogState, ConflictFileInfo};
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::{ConflictAction, Language};
+use crate::services::sftp::ArchiveFormat;
+use crate::services::storage;
+
+/// 渲染传输冲突对话框覆盖层
+pub fn render_conflict_dialog_overlay(
+    state: Entity<ConflictDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let apply_to_all = state_read.apply_to_all;
+    let queued = state_read.queued_count();
+    let (target_path, is_upload, source, target, options) = state_read
+        .current
+        .as_ref()
+        .map(|r| {
+            (
+                r.target_path.clone(),
+                r.is_upload,
+                r.source.clone(),
+                r.target.clone(),
+                r.options,
+            )
+        })
+        .unwrap_or_default();
+    // 只有下载归档文件时提供自动解压
+    let can_extract = !is_upload && ArchiveFormat::from_name(&target_path).is_some();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let (source_label, target_label) = if is_upload {
+        ("sftp.conflict.local_file", "sftp.conflict.remote_file")
+    } else {
+        ("sftp.conflict.remote_file", "sftp.conflict.local_file")
+    };
+
+    let state_toggle = state.clone();
+    let state_compress = state.clone();
+    let state_extract = state.clone();
+
+    div()
+        .id("sftp-conflict-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(460.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sftp.conflict.title")),
+                )
+                // 目标路径
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(foreground)
+                        .font_family("monospace")
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(target_path),
+                )
+                // 源/目标文件对比
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(render_file_row(
+                            i18n::t(&lang, source_label),
+                            &source,
+                            muted_foreground,
+                            foreground,
+                        ))
+                        .child(render_file_row(
+                            i18n::t(&lang, target_label),
+                            &target,
+                            muted_foreground,
+                            foreground,
+                        )),
+                )
+                // 本次传输的选项
+                .children(options.map(|options| {
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(render_checkbox_row(
+                            "sftp-conflict-compress",
+                            i18n::t(&lang, "sftp.conflict.compress").to_string(),
+                            options.compress,
+                            move |cx| {
+                                state_compress.update(cx, |s, cx| {
+                                    s.update_options(|o| o.compress = !o.compress);
+                                    cx.notify();
+                                });
+                            },
+                            cx,
+                        ))
+                        .when(can_extract, |this| {
+                            this.child(render_checkbox_row(
+                                "sftp-conflict-extract",
+                                i18n::t(&lang, "sftp.conflict.extract").to_string(),
+                                options.extract,
+                                move |cx| {
+                                    state_extract.update(cx, |s, cx| {
+                                        s.update_options(|o| o.extract = !o.extract);
+                                        cx.notify();
+                                    });
+                                },
+                                cx,
+                            ))
+                        })
+                }))
+                // 应用到全部
+                .child(render_checkbox_row(
+                    "sftp-conflict-apply-all",
+                    if queued > 0 {
+                        format!(
+                            "{} ({})",
+                            i18n::t(&lang, "sftp.conflict.apply_to_all"),
+                            queued + 1
+                        )
+                    } else {
+                        i18n::t(&lang, "sftp.conflict.apply_to_all").to_string()
+                    },
+                    apply_to_all,
+                    move |cx| {
+                        state_toggle.update(cx, |s, cx| {
+                            s.apply_to_all = !s.apply_to_all;
+                            cx.notify();
+                        });
+                    },
+                    cx,
+                ))
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_2()
+                        .pt_2()
+                        .child(render_action_button(
+                            "sftp-conflict-skip-btn",
+                            i18n::t(&lang, "sftp.conflict.skip"),
+                            ConflictAction::Skip,
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sftp-conflict-rename-btn",
+                            i18n::t(&lang, "sftp.conflict.rename"),
+                            ConflictAction::Rename,
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sftp-conflict-newer-btn",
+                            i18n::t(&lang, "sftp.conflict.overwrite_if_newer"),
+                            ConflictAction::OverwriteIfNewer,
+                            false,
+                            state.clone(),
+                            cx,
+                        ))
+                        .child(render_action_button(
+                            "sftp-conflict-overwrite-btn",
+                            i18n::t(&lang, "sftp.conflict.overwrite"),
+                            ConflictAction::Overwrite,
+                            true,
+                            state,
+                            cx,
+                        )),
+                ),
+        )
+}
+
+/// 渲染一行文件信息（标签、大小、修改时间）
+fn render_file_row(
+    label: &'static str,
+    info: &ConflictFileInfo,
+    label_color: Hsla,
+    value_color: Hsla,
+) -> impl IntoElement {
+    div()
+        .flex()
+        .items_center()
+        .gap_3()
+        .text_sm()
+        .child(div().w(px(80.)).text_color(label_color).child(label))
+        .child(
+            div()
+                .w(px(90.))
+                .text_color(value_color)
+                .child(format_size(info.size)),
+        )
+        .child(
+            div()
+                .text_color(value_color)
+                .child(format_time(info.modified)),
+        )
+}
+
+/// 渲染带勾选框的一行选项，点击整行切换
+fn render_checkbox_row(
+    id: &'static str,
+    label: String,
+    checked: bool,
+    on_toggle: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .id(id)
+        .flex()
+        .items_center()
+        .gap_2()
+        .cursor_pointer()
+        .on_click(move |_, _, cx| on_toggle(cx))
+        .child(
+            div()
+                .size(px(16.))
+                .flex()
+                .items_center()
+                .justify_center()
+                .rounded(px(3.))
+                .border_1()
+                .border_color(if checked {
+                    cx.theme().primary
+                } else {
+                    cx.theme().border
+                })
+                .when(checked, |this| {
+                    this.bg(cx.theme().primary).child(
+                        svg()
+                            .path(icons::CHECK)
+                            .size(px(12.))
+                            .text_color(cx.theme().primary_foreground),
+                    )
+                }),
+        )
+        .child(
+            div()
+                .text_sm()
+                .text_color(cx.theme().foreground)
+                .child(label),
+        )
+}
+
+/// 渲染冲突处理按钮，点击后回应当前冲突
+fn render_action_button(
+    id: &'static str,
+    label: &'static str,
+    action: ConflictAction,
+    danger: bool,
+    state: Entity<ConflictDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let (bg, hover_bg, text_color) = if danger {
+        (
+            cx.theme().danger,
+            cx.theme().danger_hover,
+            cx.theme().danger_foreground,
+        )
+    } else {
+        (
+            cx.theme().secondary,
+            cx.theme().secondary_hover,
+            cx.theme().foreground,
+        )
+    };
+
+    div()
+        .id(id)
+        .px_3()
+        .py_2()
+        .bg(bg)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .on_click(move |_, _, cx| {
+            state.update(cx, |s, cx| {
+                s.respond(action.clone());
+                cx.notify();
+            });
+        })
+        .child(div().text_sm().text_color(text_color).child(label))
+}
+
+/// 格式化文件大小
+fn format_size(size: u64) -> String {
+    let size_f = size as f64;
+    if size_f >= 1_073_741_824.0 {
+        format!("{:.1} GB", size_f / 1_073_741_824.0)
+    } else if size_f >= 1_048_576.0 {
+        format!("{:.1} MB", size_f / 1_048_576.0)
+    } else if size_f >= 1_024.0 {
+        format!("{:.1} KB", size_f / 1_024.0)
+    } else {
+        format!("{} B", size)
+    }
+}
+
+/// 格式化修改时间
+fn format_time(time: Option<std::time::SystemTime>) -> String {
+    use chrono::{DateTime, Local};
+
+    time.map(|t| {
+        DateTime::<Local>::from(t)
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    })
+    .unwrap_or_else(|| "-".to_string())
+}
@@ -0,0 +1,7 @@
+// SFTP 传输冲突对话框组件
+
+mod dialog;
+mod state;
+
+pub use dialog::render_conflict_dialog_overlay;
+pub use state::{ConflictDialogState, ConflictFileInfo, ConflictRequest};
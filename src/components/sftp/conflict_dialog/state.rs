@@ -0,0 +1,96 @@
+// 传输冲突对话框状态管理
+
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+use tokio::sync::oneshot;
+
+use crate::models::settings::ConflictAction;
+use crate::models::sftp::TransferOptions;
+
+/// 冲突双方的文件信息
+#[derive(Clone, Debug, Default)]
+pub struct ConflictFileInfo {
+    /// 文件大小（字节）
+    pub size: u64,
+    /// 修改时间
+    pub modified: Option<SystemTime>,
+}
+
+/// 一次待确认的传输冲突
+pub struct ConflictRequest {
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 已存在的目标路径
+    pub target_path: String,
+    /// 是否是上传（目标在远程）
+    pub is_upload: bool,
+    /// 源文件信息
+    pub source: ConflictFileInfo,
+    /// 目标文件信息
+    pub target: ConflictFileInfo,
+    /// 本次传输的可选处理（压缩传输/自动解压），`None` 表示该传输不支持，不显示相应选项
+    pub options: Option<TransferOptions>,
+    /// 用户选择后回传给等待中的传输任务（选择的操作, 是否应用到全部, 修改后的传输选项）
+    pub responder: oneshot::Sender<(ConflictAction, bool, Option<TransferOptions>)>,
+}
+
+/// 传输冲突对话框状态
+/// 多个传输同时遇到冲突时依次排队确认
+#[derive(Default)]
+pub struct ConflictDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 当前显示的冲突
+    pub current: Option<ConflictRequest>,
+    /// 是否将本次选择应用到所有排队中的冲突及同一批传输的后续冲突
+    pub apply_to_all: bool,
+    /// 排队中的冲突
+    queue: VecDeque<ConflictRequest>,
+}
+
+impl ConflictDialogState {
+    /// 添加一个冲突，对话框空闲时立即显示
+    pub fn push(&mut self, request: ConflictRequest) {
+        if self.current.is_none() {
+            self.current = Some(request);
+            self.is_open = true;
+        } else {
+            self.queue.push_back(request);
+        }
+    }
+
+    /// 排队中（不含当前）的冲突数量
+    pub fn queued_count(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// 回应当前冲突；勾选"应用到全部"时一并回应排队中的冲突
+    pub fn respond(&mut self, action: ConflictAction) {
+        if let Some(request) = self.current.take() {
+            let _ = request
+                .responder
+                .send((action.clone(), self.apply_to_all, request.options));
+        }
+        if self.apply_to_all {
+            for request in self.queue.drain(..) {
+                let _ = request
+                    .responder
+                    .send((action.clone(), true, request.options));
+            }
+        }
+
+        self.current = self.queue.pop_front();
+        if self.current.is_none() {
+            self.is_open = false;
+            self.apply_to_all = false;
+        }
+    }
+
+    /// 修改当前冲突对应传输的选项
+    pub fn update_options(&mut self, update: impl FnOnce(&mut TransferOptions)) {
+        if let Some(options) = self.current.as_mut().and_then(|r| r.options.as_mut()) {
+            update(options);
+        }
+    }
+}
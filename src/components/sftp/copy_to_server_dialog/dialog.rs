@@ -0,0 +1,237 @@
+// 复制到其他服务器对话框渲染
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use super::state::CopyToServerDialogState;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染复制到其他服务器对话框覆盖层
+/// `on_copy` 在点击"复制"且校验通过时调用
+pub fn render_copy_to_server_dialog_overlay<F>(
+    state: Entity<CopyToServerDialogState>,
+    on_copy: F,
+    cx: &App,
+) -> impl IntoElement
+where
+    F: Fn(Entity<CopyToServerDialogState>, &mut App) + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let source_path = state_read.source_path.clone();
+    let dir_input = state_read.dir_input.clone();
+    let error_message = state_read.error_message.clone();
+    let selected = state_read.selected;
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let danger = cx.theme().danger;
+    let hover_bg = cx.theme().list_hover;
+    let active_bg = cx.theme().list_active;
+
+    // 目标服务器列表
+    let target_rows: Vec<AnyElement> = state_read
+        .targets
+        .iter()
+        .enumerate()
+        .map(|(ix, target)| {
+            let state = state.clone();
+            let is_selected = selected == Some(ix);
+            div()
+                .id(("copy-target", ix))
+                .px_3()
+                .py_2()
+                .flex()
+                .items_center()
+                .gap_2()
+                .rounded_md()
+                .cursor_pointer()
+                .when(is_selected, |this| this.bg(active_bg))
+                .hover(move |s| s.bg(hover_bg))
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        s.select(ix);
+                        cx.notify();
+                    });
+                })
+                .child(
+                    svg()
+                        .path(icons::SERVER)
+                        .size(px(14.))
+                        .text_color(if is_selected {
+                            foreground
+                        } else {
+                            muted_foreground
+                        }),
+                )
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(foreground)
+                        .child(target.label.clone()),
+                )
+                .into_any_element()
+        })
+        .collect();
+
+    let state_cancel = state.clone();
+    let state_copy = state.clone();
+
+    div()
+        .id("copy-to-server-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(420.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sftp.remote_copy.title")),
+                )
+                // 源路径
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted_foreground)
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(source_path),
+                )
+                // 目标服务器
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "sftp.remote_copy.target_server")),
+                        )
+                        .child(
+                            div()
+                                .id("copy-target-list")
+                                .max_h(px(180.))
+                                .overflow_y_scroll()
+                                .flex()
+                                .flex_col()
+                                .gap_1()
+                                .children(target_rows),
+                        ),
+                )
+                // 目标目录
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "sftp.remote_copy.target_dir")),
+                        )
+                        .child(if let Some(input) = &dir_input {
+                            Input::new(input).into_any_element()
+                        } else {
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "common.loading"))
+                                .into_any_element()
+                        }),
+                )
+                // 错误信息
+                .children(error_message.map(|msg| div().text_sm().text_color(danger).child(msg)))
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        // 取消按钮
+                        .child(
+                            div()
+                                .id("copy-to-server-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        // 复制按钮
+                        .child(
+                            div()
+                                .id("copy-to-server-confirm-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().primary_hover))
+                                .on_click(move |_, _, cx| {
+                                    let valid = state_copy.update(cx, |s, cx| {
+                                        let valid = s.validate(cx);
+                                        cx.notify();
+                                        valid
+                                    });
+                                    if valid {
+                                        on_copy(state_copy.clone(), cx);
+                                    }
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(i18n::t(&lang, "sftp.remote_copy.copy")),
+                                ),
+                        ),
+                ),
+        )
+}
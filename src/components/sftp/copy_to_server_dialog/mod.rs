@@ -0,0 +1,7 @@
+// 复制到其他服务器对话框模块
+
+mod dialog;
+mod state;
+
+pub use dialog::render_copy_to_server_dialog_overlay;
+pub use state::{CopyTarget, CopyToServerDialogState};
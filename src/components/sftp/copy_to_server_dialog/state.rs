@@ -0,0 +1,120 @@
+// 复制到其他服务器对话框状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 可选的目标服务器（其他已连接的标签页）
+#[derive(Clone, Debug)]
+pub struct CopyTarget {
+    /// 目标标签页 ID
+    pub tab_id: String,
+    /// 服务器显示名称
+    pub label: String,
+    /// 目标标签页 SFTP 当前目录（作为默认目标目录）
+    pub current_path: String,
+}
+
+/// 复制到其他服务器对话框状态
+#[derive(Default)]
+pub struct CopyToServerDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 源标签页 ID
+    pub tab_id: String,
+    /// 源文件/文件夹路径
+    pub source_path: String,
+    /// 可选的目标服务器
+    pub targets: Vec<CopyTarget>,
+    /// 选中的目标服务器索引
+    pub selected: Option<usize>,
+    /// 目标目录输入框
+    pub dir_input: Option<Entity<InputState>>,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 待写入目标目录输入框的路径
+    pending_dir: Option<String>,
+}
+
+impl CopyToServerDialogState {
+    /// 打开对话框，默认选中第一个目标服务器
+    pub fn open(&mut self, tab_id: String, source_path: String, targets: Vec<CopyTarget>) {
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.source_path = source_path;
+        self.pending_dir = targets.first().map(|t| t.current_path.clone());
+        self.selected = if targets.is_empty() { None } else { Some(0) };
+        self.targets = targets;
+        self.error_message = None;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.tab_id.clear();
+        self.source_path.clear();
+        self.targets.clear();
+        self.selected = None;
+        self.pending_dir = None;
+        self.error_message = None;
+    }
+
+    /// 选择目标服务器，目标目录切换为该服务器的当前目录
+    pub fn select(&mut self, ix: usize) {
+        if let Some(target) = self.targets.get(ix) {
+            self.selected = Some(ix);
+            self.pending_dir = Some(target.current_path.clone());
+        }
+    }
+
+    /// 当前选中的目标服务器
+    pub fn selected_target(&self) -> Option<&CopyTarget> {
+        self.selected.and_then(|ix| self.targets.get(ix))
+    }
+
+    /// 确保输入框已创建，并填入待设置的目标目录
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.dir_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "sftp.remote_copy.dir_placeholder");
+            self.dir_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        if let (Some(dir), Some(input)) = (self.pending_dir.take(), &self.dir_input) {
+            input.update(cx, |input, cx| input.set_value(dir, window, cx));
+        }
+    }
+
+    /// 输入的目标目录
+    pub fn target_dir(&self, cx: &App) -> String {
+        self.dir_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 验证目标服务器与目录
+    pub fn validate(&mut self, cx: &App) -> bool {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        if self.selected_target().is_none() {
+            self.error_message =
+                Some(i18n::t(&lang, "sftp.remote_copy.error_no_target").to_string());
+            return false;
+        }
+        if !self.target_dir(cx).starts_with('/') {
+            self.error_message = Some(i18n::t(&lang, "sftp.remote_copy.error_dir").to_string());
+            return false;
+        }
+
+        self.error_message = None;
+        true
+    }
+}
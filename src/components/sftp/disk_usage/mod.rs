@@ -0,0 +1,6 @@
+// 磁盘占用分析模块
+
+mod treemap;
+mod view;
+
+pub use view::{DiskUsageEvent, DiskUsageView};
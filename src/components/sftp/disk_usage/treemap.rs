@@ -0,0 +1,112 @@
+// 矩形树图布局（squarified 算法）
+// 按大小把矩形区域划分给各项，使每个矩形尽量接近正方形
+
+/// 布局结果中的一个矩形（坐标与传入区域的单位相同）
+#[derive(Clone, Copy, Debug)]
+pub struct TreemapRect {
+    /// 对应输入中的下标
+    pub index: usize,
+    pub x: f32,
+    pub y: f32,
+    pub w: f32,
+    pub h: f32,
+}
+
+/// 计算矩形树图布局
+///
+/// `sizes` 应按从大到小排列；大小为 0 的项不参与布局
+pub fn squarify(sizes: &[u64], width: f32, height: f32) -> Vec<TreemapRect> {
+    let total: u64 = sizes.iter().sum();
+    let mut rects = Vec::with_capacity(sizes.len());
+    if total == 0 || width <= 0. || height <= 0. {
+        return rects;
+    }
+
+    let scale = width * height / total as f32;
+    let areas: Vec<(usize, f32)> = sizes
+        .iter()
+        .enumerate()
+        .filter(|(_, &size)| size > 0)
+        .map(|(ix, &size)| (ix, size as f32 * scale))
+        .collect();
+
+    let mut bounds = (0., 0., width, height);
+    let mut row: Vec<(usize, f32)> = Vec::new();
+    let mut ix = 0;
+    while ix < areas.len() {
+        let side = bounds.2.min(bounds.3);
+        let item = areas[ix];
+        let keep_growing = row.is_empty() || {
+            let current = worst_ratio(&row, side);
+            row.push(item);
+            let grown = worst_ratio(&row, side);
+            row.pop();
+            grown <= current
+        };
+        if keep_growing {
+            row.push(item);
+            ix += 1;
+        } else {
+            layout_row(&row, &mut bounds, &mut rects);
+            row.clear();
+        }
+    }
+    if !row.is_empty() {
+        layout_row(&row, &mut bounds, &mut rects);
+    }
+    rects
+}
+
+/// 一行中最差的长宽比
+fn worst_ratio(row: &[(usize, f32)], side: f32) -> f32 {
+    let sum: f32 = row.iter().map(|(_, area)| area).sum();
+    let (min, max) = row.iter().fold((f32::MAX, 0f32), |(min, max), &(_, area)| {
+        (min.min(area), max.max(area))
+    });
+    let sum2 = sum * sum;
+    let side2 = side * side;
+    (side2 * max / sum2).max(sum2 / (side2 * min))
+}
+
+/// 沿较短边放置一行，并从剩余区域中扣除
+fn layout_row(
+    row: &[(usize, f32)],
+    bounds: &mut (f32, f32, f32, f32),
+    rects: &mut Vec<TreemapRect>,
+) {
+    let (x, y, w, h) = *bounds;
+    let sum: f32 = row.iter().map(|(_, area)| area).sum();
+    if w >= h {
+        // 在左侧放置一列
+        let column_w = sum / h;
+        let mut offset = y;
+        for &(index, area) in row {
+            let item_h = area / column_w;
+            rects.push(TreemapRect {
+                index,
+                x,
+                y: offset,
+                w: column_w,
+                h: item_h,
+            });
+            offset += item_h;
+        }
+        *bounds = (x + column_w, y, (w - column_w).max(0.), h);
+    } else {
+        // 在顶部放置一行
+        let row_h = sum / w;
+        let mut offset = x;
+        for &(index, area) in row {
+            let item_w = area / row_h;
+            rects.push(TreemapRect {
+                index,
+                x: offset,
+                y,
+                w: item_w,
+                h: row_h,
+            });
+            offset += item_w;
+        }
+        *bounds = (x, y + row_h, w, (h - row_h).max(0.));
+    }
+}
@@ -0,0 +1,539 @@
+// 磁盘占用分析视图
+// 打开时替换文件列表区域：左侧为矩形树图，右侧为按大小排列的列表，点击目录进入下一级
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::tooltip::Tooltip;
+use gpui_component::ActiveTheme;
+use tokio_util::sync::CancellationToken;
+
+use super::treemap::squarify;
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::sftp::state::get_parent_path;
+use crate::services::sftp::{DiskUsageItem, DiskUsageLevel, DiskUsageSource};
+
+/// 树图中最多显示的项数（其余的项太小，无法辨认）
+const MAX_TREEMAP_ITEMS: usize = 150;
+/// 树图布局使用的宽高比
+const TREEMAP_ASPECT: f32 = 2.;
+
+/// 磁盘占用分析事件
+#[derive(Clone, Debug)]
+pub enum DiskUsageEvent {
+    /// 统计目录（携带本次统计的取消令牌）
+    Scan(String, CancellationToken),
+    /// 在文件列表中显示
+    Reveal(String),
+}
+
+/// 磁盘占用分析视图
+pub struct DiskUsageView {
+    /// 当前显示的目录
+    current: String,
+    /// 已统计的目录（返回上一级时无需重新统计）
+    levels: HashMap<String, DiskUsageLevel>,
+    /// 是否正在统计
+    scanning: bool,
+    /// 错误信息
+    error: Option<String>,
+    /// 选中的项
+    selected: Option<String>,
+    /// 是否显示
+    is_open: bool,
+    /// 当前统计的取消令牌
+    cancel_token: Option<CancellationToken>,
+    /// 事件回调
+    on_event: Rc<dyn Fn(DiskUsageEvent, &mut App)>,
+}
+
+impl DiskUsageView {
+    pub fn new<F>(on_event: F) -> Self
+    where
+        F: Fn(DiskUsageEvent, &mut App) + 'static,
+    {
+        Self {
+            current: String::new(),
+            levels: HashMap::new(),
+            scanning: false,
+            error: None,
+            selected: None,
+            is_open: false,
+            cancel_token: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 是否显示
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// 打开并分析目录（清空之前的统计），返回需要开始统计时的取消令牌
+    pub fn open(&mut self, path: String, cx: &mut Context<Self>) -> Option<CancellationToken> {
+        self.is_open = true;
+        self.levels.clear();
+        let token = self.show(path);
+        cx.notify();
+        token
+    }
+
+    /// 显示目录，尚未统计时返回本次统计的取消令牌
+    fn show(&mut self, path: String) -> Option<CancellationToken> {
+        self.cancel();
+        self.current = path;
+        self.selected = None;
+        self.error = None;
+        if self.levels.contains_key(&self.current) {
+            return None;
+        }
+        let token = CancellationToken::new();
+        self.cancel_token = Some(token.clone());
+        self.scanning = true;
+        Some(token)
+    }
+
+    /// 进入目录
+    fn navigate(&mut self, path: String, cx: &mut Context<Self>) {
+        if let Some(token) = self.show(path) {
+            let on_event = self.on_event.clone();
+            on_event(DiskUsageEvent::Scan(self.current.clone(), token), cx);
+        }
+        cx.notify();
+    }
+
+    /// 返回上一级目录
+    fn go_up(&mut self, cx: &mut Context<Self>) {
+        let parent = get_parent_path(&self.current);
+        if parent != self.current {
+            self.navigate(parent, cx);
+        }
+    }
+
+    /// 重新统计当前目录
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.levels.remove(&self.current);
+        self.navigate(self.current.clone(), cx);
+    }
+
+    /// 统计完成，已切换到其他目录时忽略
+    pub fn finish_scan(
+        &mut self,
+        path: &str,
+        result: Result<DiskUsageLevel, String>,
+        cx: &mut Context<Self>,
+    ) {
+        if path != self.current {
+            return;
+        }
+        self.scanning = false;
+        self.cancel_token = None;
+        match result {
+            Ok(level) => {
+                self.levels.insert(path.to_string(), level);
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 选中一项
+    fn select(&mut self, path: String, cx: &mut Context<Self>) {
+        self.selected = Some(path);
+        cx.notify();
+    }
+
+    /// 在文件列表中显示并关闭视图
+    fn reveal(&mut self, path: String, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(DiskUsageEvent::Reveal(path), cx);
+        self.close(cx);
+    }
+
+    /// 点击某一项：目录进入下一级，文件选中
+    fn activate(&mut self, item: &DiskUsageItem, cx: &mut Context<Self>) {
+        if item.is_dir {
+            self.navigate(item.path.clone(), cx);
+        } else {
+            self.select(item.path.clone(), cx);
+        }
+    }
+
+    /// 取消正在进行的统计
+    fn cancel(&mut self) {
+        if let Some(token) = self.cancel_token.take() {
+            token.cancel();
+        }
+        self.scanning = false;
+    }
+
+    /// 关闭视图
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.cancel();
+        self.is_open = false;
+        self.levels.clear();
+        self.selected = None;
+        self.error = None;
+        cx.notify();
+    }
+
+    /// 渲染头部图标按钮
+    fn render_icon_button(
+        &self,
+        id: &'static str,
+        icon: &'static str,
+        tooltip: &'static str,
+        on_click: impl Fn(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let hover_bg = cx.theme().list_active;
+        div()
+            .id(id)
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(14.))
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .tooltip(move |window, cx| Tooltip::new(tooltip).build(window, cx))
+            .on_click(cx.listener(move |this, _, _, cx| on_click(this, cx)))
+    }
+
+    /// 渲染矩形树图
+    fn render_treemap(&self, level: &DiskUsageLevel, cx: &mut Context<Self>) -> AnyElement {
+        let items: Vec<&DiskUsageItem> = level.items.iter().take(MAX_TREEMAP_ITEMS).collect();
+        let sizes: Vec<u64> = items.iter().map(|item| item.size).collect();
+        let rects = squarify(&sizes, TREEMAP_ASPECT, 1.);
+
+        let dir_bg = cx.theme().primary.opacity(0.3);
+        let file_bg = cx.theme().secondary;
+        let hover_bg = cx.theme().primary.opacity(0.45);
+        let border_color = crate::theme::sidebar_color(cx);
+        let selected_border = cx.theme().primary;
+        let foreground = cx.theme().foreground;
+        let muted = cx.theme().muted_foreground;
+
+        let cells = rects.into_iter().map(|rect| {
+            let item = (*items[rect.index]).clone();
+            let selected = self.selected.as_ref() == Some(&item.path);
+            let tooltip = SharedString::from(format!("{} · {}", item.name, format_size(item.size)));
+            // 足够大的矩形才显示文字
+            let show_label = rect.w > 0.12 && rect.h > 0.08;
+
+            div()
+                .id(SharedString::from(format!("disk-usage-cell-{}", item.path)))
+                .absolute()
+                .left(relative(rect.x / TREEMAP_ASPECT))
+                .top(relative(rect.y))
+                .w(relative(rect.w / TREEMAP_ASPECT))
+                .h(relative(rect.h))
+                .border_1()
+                .border_color(border_color)
+                .bg(if item.is_dir { dir_bg } else { file_bg })
+                .when(selected, |el| el.border_2().border_color(selected_border))
+                .overflow_hidden()
+                .px_1()
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .when(show_label, |el| {
+                    el.child(
+                        div()
+                            .text_xs()
+                            .text_color(foreground)
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(item.name.clone()),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(muted)
+                            .child(format_size(item.size)),
+                    )
+                })
+                .tooltip(move |window, cx| Tooltip::new(tooltip.clone()).build(window, cx))
+                .on_click(cx.listener(move |this, _, _, cx| this.activate(&item, cx)))
+        });
+
+        div()
+            .relative()
+            .size_full()
+            .children(cells)
+            .into_any_element()
+    }
+
+    /// 渲染列表中的一项
+    fn render_item(
+        &self,
+        ix: usize,
+        item: &DiskUsageItem,
+        total: u64,
+        lang: &crate::models::settings::Language,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_hover;
+        let active_bg = cx.theme().list_active;
+        let selected = self.selected.as_ref() == Some(&item.path);
+        let percent = if total > 0 {
+            item.size as f32 / total as f32
+        } else {
+            0.
+        };
+        let item_for_click = item.clone();
+        let path_for_reveal = item.path.clone();
+
+        div()
+            .id(("disk-usage-item", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .flex_col()
+            .gap(px(2.))
+            .cursor_pointer()
+            .when(selected, |el| el.bg(active_bg))
+            .hover(move |s| s.bg(hover_bg))
+            .on_click(cx.listener(move |this, _, _, cx| this.activate(&item_for_click, cx)))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        svg()
+                            .path(if item.is_dir {
+                                icons::FOLDER
+                            } else {
+                                icons::FILE
+                            })
+                            .size(px(14.))
+                            .flex_shrink_0()
+                            .text_color(if item.is_dir { cx.theme().link } else { muted }),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .text_xs()
+                            .text_color(cx.theme().foreground)
+                            .child(item.name.clone()),
+                    )
+                    .child(
+                        div()
+                            .flex_shrink_0()
+                            .text_xs()
+                            .text_color(muted)
+                            .child(format!(
+                                "{} · {:.1}%",
+                                format_size(item.size),
+                                percent * 100.
+                            )),
+                    )
+                    .child(
+                        div()
+                            .id(("disk-usage-reveal", ix))
+                            .flex_shrink_0()
+                            .size(px(18.))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .rounded(px(4.))
+                            .hover(move |s| s.bg(active_bg))
+                            .child(svg().path(icons::EYE).size(px(12.)).text_color(muted))
+                            .tooltip({
+                                let label = t(lang, "sftp.disk_usage.reveal");
+                                move |window, cx| Tooltip::new(label).build(window, cx)
+                            })
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                cx.stop_propagation();
+                                this.reveal(path_for_reveal.clone(), cx);
+                            })),
+                    ),
+            )
+            // 占比条
+            .child(
+                div()
+                    .w_full()
+                    .h(px(3.))
+                    .rounded(px(2.))
+                    .bg(cx.theme().secondary)
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(percent))
+                            .rounded(px(2.))
+                            .bg(cx.theme().primary),
+                    ),
+            )
+            .into_any_element()
+    }
+}
+
+impl Render for DiskUsageView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let bg_color = crate::theme::sidebar_color(cx);
+        let border_color = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let level = self.levels.get(&self.current).cloned();
+
+        // 状态文本
+        let status = if self.scanning {
+            t(&lang, "sftp.disk_usage.scanning").to_string()
+        } else if let Some(error) = &self.error {
+            format!("{}: {}", t(&lang, "sftp.disk_usage.failed"), error)
+        } else if let Some(level) = &level {
+            let source = match level.source {
+                DiskUsageSource::Du => "du",
+                DiskUsageSource::SftpWalk => "SFTP",
+            };
+            format!(
+                "{} {} · {}",
+                t(&lang, "sftp.disk_usage.total"),
+                format_size(level.total),
+                source
+            )
+        } else {
+            String::new()
+        };
+
+        let header = div()
+            .w_full()
+            .h(px(32.))
+            .flex_shrink_0()
+            .px_2()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(border_color)
+            .child(self.render_icon_button(
+                "sftp-disk-usage-up",
+                icons::ARROW_UP,
+                t(&lang, "sftp.disk_usage.up"),
+                |this, cx| this.go_up(cx),
+                cx,
+            ))
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(t(&lang, "sftp.disk_usage.title")),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(format!("{} · {}", self.current, status)),
+            )
+            .child(self.render_icon_button(
+                "sftp-disk-usage-refresh",
+                icons::REFRESH,
+                t(&lang, "sftp.disk_usage.refresh"),
+                |this, cx| this.refresh(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "sftp-disk-usage-close",
+                icons::X,
+                t(&lang, "sftp.disk_usage.close"),
+                |this, cx| this.close(cx),
+                cx,
+            ));
+
+        let body: AnyElement = match level {
+            Some(level) if !level.items.is_empty() => {
+                let rows: Vec<AnyElement> = level
+                    .items
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, item)| self.render_item(ix, item, level.total, &lang, cx))
+                    .collect();
+                div()
+                    .flex_1()
+                    .min_h(px(0.))
+                    .flex()
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .p_1()
+                            .child(self.render_treemap(&level, cx)),
+                    )
+                    .child(
+                        div()
+                            .id("sftp-disk-usage-list")
+                            .w(px(280.))
+                            .flex_shrink_0()
+                            .border_l_1()
+                            .border_color(border_color)
+                            .overflow_y_scroll()
+                            .children(rows),
+                    )
+                    .into_any_element()
+            }
+            _ => {
+                let hint = if self.scanning || self.error.is_some() {
+                    status
+                } else {
+                    t(&lang, "sftp.disk_usage.empty").to_string()
+                };
+                div()
+                    .flex_1()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_sm()
+                    .text_color(muted)
+                    .child(hint)
+                    .into_any_element()
+            }
+        };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(bg_color)
+            .child(header)
+            .child(body)
+    }
+}
+
+/// 格式化大小
+fn format_size(size: u64) -> String {
+    let size_f = size as f64;
+    if size_f >= 1_099_511_627_776.0 {
+        format!("{:.1} TB", size_f / 1_099_511_627_776.0)
+    } else if size_f >= 1_073_741_824.0 {
+        format!("{:.1} GB", size_f / 1_073_741_824.0)
+    } else if size_f >= 1_048_576.0 {
+        format!("{:.1} MB", size_f / 1_048_576.0)
+    } else if size_f >= 1_024.0 {
+        format!("{:.1} KB", size_f / 1_024.0)
+    } else {
+        format!("{} B", size)
+    }
+}
@@ -0,0 +1,145 @@
+// 清空回收站确认对话框渲染组件
+
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::EmptyTrashDialogState;
+
+/// 渲染清空回收站确认对话框覆盖层
+/// `on_confirm` 参数为 tab_id
+pub fn render_empty_trash_dialog_overlay<F>(
+    state: Entity<EmptyTrashDialogState>,
+    on_confirm: F,
+    cx: &App,
+) -> impl IntoElement
+where
+    F: Fn(String, &mut App) + Clone + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let trash_root = state_read.trash_root.clone();
+
+    let state_cancel = state.clone();
+    let state_confirm = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id("sftp-empty-trash-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(400.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sftp.trash.empty_title")),
+                )
+                // 提示信息
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "sftp.trash.empty_message")),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(foreground)
+                                .font_family("monospace")
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .child(trash_root),
+                        ),
+                )
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        // 取消按钮
+                        .child(
+                            div()
+                                .id("sftp-empty-trash-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, _| s.close());
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        // 清空按钮
+                        .child(
+                            div()
+                                .id("sftp-empty-trash-confirm-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().danger)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().danger_hover))
+                                .on_click(move |_, _, cx| {
+                                    let tab_id = state_confirm.update(cx, |s, _| {
+                                        let tab_id = s.tab_id.clone();
+                                        s.close();
+                                        tab_id
+                                    });
+                                    on_confirm(tab_id, cx);
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().danger_foreground)
+                                        .child(i18n::t(&lang, "sftp.trash.empty_confirm")),
+                                ),
+                        ),
+                ),
+        )
+}
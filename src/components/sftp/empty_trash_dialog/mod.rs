@@ -0,0 +1,7 @@
+// SFTP 清空回收站确认对话框组件
+
+mod dialog;
+mod state;
+
+pub use dialog::render_empty_trash_dialog_overlay;
+pub use state::EmptyTrashDialogState;
@@ -0,0 +1,29 @@
+// 清空回收站确认对话框状态管理
+
+/// 清空回收站确认对话框状态
+/// 清空会永久删除回收站中的全部内容，执行前等待用户确认
+#[derive(Default)]
+pub struct EmptyTrashDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 回收站目录
+    pub trash_root: String,
+}
+
+impl EmptyTrashDialogState {
+    /// 打开对话框
+    pub fn open(&mut self, tab_id: String, trash_root: String) {
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.trash_root = trash_root;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.tab_id.clear();
+        self.trash_root.clear();
+    }
+}
@@ -1,37 +1,52 @@
 // SFTP 文件列表组件 - 使用 gpui_component::Table 实现
-// 支持列排序和手动调整列宽
+// 支持列排序、手动调整列宽、拖动调整列顺序、显示/隐藏列和文件名过滤
 
-use std::cmp::Reverse;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
-use gpui_component::menu::{ContextMenuExt, PopupMenuItem};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{ContextMenuExt, DropdownMenu, PopupMenuItem};
 use gpui_component::table::{Column, ColumnSort, Table, TableDelegate, TableEvent, TableState};
-use gpui_component::ActiveTheme;
+use gpui_component::{ActiveTheme, Sizable};
 
+use super::local_panel::DraggedLocalEntry;
 use crate::constants::icons;
 use crate::i18n::t;
-use crate::models::settings::Language;
-use crate::models::sftp::{FileEntry, FileType, SftpState};
+use crate::models::settings::{Language, SftpColumn, SftpSettings, SftpSortBy};
+use crate::models::sftp::{DirLoadError, FileEntry, FileType, SftpState};
+use crate::services::sftp::{compare_base, ArchiveFormat};
 
 /// SFTP 文件列表右键菜单事件
 #[derive(Clone, Debug)]
 pub enum FileListContextMenuEvent {
     // 文件操作
-    Download(String),   // 文件路径
-    EditFile(String),   // 文件路径
-    CopyName(String),   // 文件名
-    CopyPath(String),   // 完整路径
-    Rename(String),     // 文件路径 - 开始重命名
-    Delete(String),     // 文件路径
-    Properties(String), // 文件路径
+    Download(String),        // 文件路径
+    EditFile(String),        // 文件路径
+    EditAsRoot(String),      // 文件路径 - 以管理员身份编辑
+    HexView(String),         // 文件路径 - 十六进制查看
+    TailLog(String),         // 文件路径 - 在日志查看器中跟踪
+    CopyName(String),        // 文件名
+    CopyPath(String),        // 完整路径
+    Rename(String),          // 文件路径 - 开始重命名
+    Delete(String),          // 文件路径
+    Properties(Vec<String>), // 文件路径（多选时为全部标记的路径）
 
     // 文件夹操作
-    OpenFolder(String),     // 文件夹路径
-    DownloadFolder(String), // 文件夹路径
-    OpenInTerminal(String), // 目录路径
+    OpenFolder(String),              // 文件夹路径
+    DownloadFolder(String),          // 文件夹路径
+    OpenInTerminal(String),          // 目录路径
+    SyncDirectory(String),           // 远程目录路径 - 与本地目录同步
+    CopyToServer(String),            // 文件/文件夹路径 - 复制到其他服务器
+    Compress(String, ArchiveFormat), // 文件/文件夹路径 - 压缩为指定格式
+    Extract(String),                 // 归档文件路径 - 解压到所在目录
+    NewLink(Option<String>),         // 预填的链接目标路径 - 新建符号链接/硬链接
+    FollowLink(String),              // 符号链接路径 - 跳转到链接目标
+    CompareSelect(String, bool),     // 文件/文件夹路径, 是否目录 - 选择用于比较
+    CompareWith(String, bool),       // 文件/文件夹路径, 是否目录 - 与已选对象比较
 
     // 空白区域操作
     Refresh,
@@ -39,7 +54,7 @@ pub enum FileListContextMenuEvent {
     NewFile,
     UploadFile,
     UploadFolder,
-    SelectAll,
+    EmptyTrash,
 
     // 重命名确认/取消
     RenameConfirmed {
@@ -53,88 +68,191 @@ pub enum FileListContextMenuEvent {
         paths: Vec<std::path::PathBuf>, // 本地文件/文件夹路径
         target_dir: String,             // 目标远程目录
     },
+
+    // 拖出窗口后松开：下载到系统下载目录
+    DragOut(String), // 被拖动的远程路径
+
+    // 拖动移动（远程内部）
+    MoveEntry {
+        source: String,     // 被拖动的远程路径
+        target_dir: String, // 目标远程目录
+    },
+
+    // 双面板：传输到本地面板（F5 复制 / F6 移动）
+    TransferToLocal {
+        path: String,        // 远程路径
+        remove_source: bool, // 传输完成后删除远程源
+    },
+
+    // 前往路径（Cmd+Shift+G 打开地址栏输入）
+    GoToPath,
+
+    // 目录读取失败：重试 / 以 sudo 列出
+    RetryLoad,
+    SudoList,
+    // 停止当前目录的分批加载
+    CancelLoad,
+}
+
+actions!(
+    sftp,
+    [RenameSelected, CopyToOtherPane, MoveToOtherPane, GoToPath]
+);
+
+/// SFTP 文件列表上下文名称
+pub const SFTP_FILE_LIST_CONTEXT: &str = "SftpFileList";
+
+/// 拖动中的远程条目（文件列表与文件夹树共用）
+#[derive(Clone, Debug)]
+pub struct DraggedSftpEntry {
+    pub path: String,
+    pub name: String,
+    pub is_dir: bool,
+}
+
+impl Render for DraggedSftpEntry {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let icon = if self.is_dir {
+            icons::FOLDER
+        } else {
+            icons::FILE
+        };
+        div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_2()
+            .py_1()
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(4.))
+            .shadow_md()
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(ICON_SIZE))
+                    .text_color(cx.theme().link),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(self.name.clone()),
+            )
+    }
 }
 
 /// 图标尺寸
 const ICON_SIZE: f32 = 16.0;
 
-/// 列索引常量
-const COL_NAME: usize = 0;
-const COL_PERMISSIONS: usize = 1;
-const COL_OWNER: usize = 2;
-const COL_SIZE: usize = 3;
-const COL_MODIFIED: usize = 4;
-
 /// 行拖放回调类型
 pub type RowDropCallback =
     std::sync::Arc<dyn Fn(Vec<std::path::PathBuf>, String) + Send + Sync + 'static>;
 
+/// 行内拖动移动回调类型（源路径, 目标目录）
+pub type RowMoveCallback = std::sync::Arc<dyn Fn(String, String) + Send + Sync + 'static>;
+
+/// 开始拖动行的回调类型
+pub type DragStartCallback = std::sync::Arc<dyn Fn(DraggedSftpEntry) + Send + Sync + 'static>;
+
 /// 文件列表 Delegate - 实现 TableDelegate trait
 pub struct FileListDelegate {
     /// 文件列表数据
     pub file_list: Vec<FileEntry>,
-    /// 当前显示行顺序（索引）
+    /// 当前显示行顺序（过滤并排序后的索引）
     row_order: Vec<usize>,
     /// 列定义
     columns: Vec<Column>,
+    /// 各列对应的内容（与 columns 一一对应）
+    column_kinds: Vec<SftpColumn>,
     /// uid -> username 缓存
     pub user_cache: HashMap<u32, String>,
     /// gid -> groupname 缓存
     pub group_cache: HashMap<u32, String>,
     /// 语言设置
     lang: Language,
-    /// 当前排序列
-    current_sort_col: usize,
+    /// 当前排序方式
+    sort_by: SftpSortBy,
     /// 当前排序方向
     current_sort: ColumnSort,
+    /// 文件夹是否始终排在文件之前
+    folders_first: bool,
+    /// 文件名过滤条件（子串或 * ? 通配符）
+    filter: String,
     /// 正在编辑的文件路径（用于内联重命名）
     pub editing_path: Option<String>,
     /// 重命名输入框状态（用于内联编辑）
     pub rename_input: Option<Entity<gpui_component::input::InputState>>,
     /// 行拖放回调（当文件拖放到文件夹行上时调用）
     pub on_row_drop: Option<RowDropCallback>,
+    /// 行内拖动移动回调（当远程条目拖放到文件夹行上时调用）
+    pub on_row_move: Option<RowMoveCallback>,
+    /// 开始拖动行时调用（记录拖动的条目，用于拖出窗口下载）
+    pub on_drag_start: Option<DragStartCallback>,
+    /// 多选标记的路径（Ctrl/Cmd+点击或全选，用于批量查看属性）
+    marked_paths: HashSet<String>,
 }
 
 impl FileListDelegate {
-    /// 创建新的 FileListDelegate
+    /// 创建新的 FileListDelegate（列与排序方式读取自设置）
     pub fn new(lang: Language) -> Self {
-        let columns = Self::create_columns(&lang);
+        let sftp = crate::services::storage::load_settings()
+            .map(|s| s.sftp)
+            .unwrap_or_default();
+        let column_kinds = sftp.file_list_columns;
+        let columns = Self::create_columns(&lang, &column_kinds);
         let mut delegate = Self {
             file_list: Vec::new(),
             row_order: Vec::new(),
             columns,
+            column_kinds,
             user_cache: HashMap::new(),
             group_cache: HashMap::new(),
             lang,
-            current_sort_col: COL_NAME,
-            current_sort: ColumnSort::Ascending,
+            sort_by: sftp.sort_by,
+            current_sort: if sftp.sort_ascending {
+                ColumnSort::Ascending
+            } else {
+                ColumnSort::Descending
+            },
+            folders_first: sftp.folders_first,
+            filter: String::new(),
             editing_path: None,
             rename_input: None,
             on_row_drop: None,
+            on_row_move: None,
+            on_drag_start: None,
+            marked_paths: HashSet::new(),
         };
         delegate.sync_column_sort_state();
         delegate
     }
 
     /// 创建列定义
-    fn create_columns(lang: &Language) -> Vec<Column> {
-        vec![
-            Column::new("name", t(lang, "sftp.header.name"))
-                .width(px(300.))
-                .sortable(),
-            Column::new("permissions", t(lang, "sftp.header.permissions"))
-                .width(px(90.))
-                .resizable(true),
-            Column::new("owner", t(lang, "sftp.header.owner"))
-                .width(px(100.))
-                .resizable(true),
-            Column::new("size", t(lang, "sftp.header.size"))
-                .width(px(70.))
-                .sortable(),
-            Column::new("modified", t(lang, "sftp.header.modified"))
-                .width(px(135.))
-                .sortable(),
-        ]
+    fn create_columns(lang: &Language, kinds: &[SftpColumn]) -> Vec<Column> {
+        kinds
+            .iter()
+            .map(|kind| {
+                let (key, width) = match kind {
+                    SftpColumn::Name => ("name", 300.),
+                    SftpColumn::Permissions => ("permissions", 90.),
+                    SftpColumn::Owner => ("owner", 80.),
+                    SftpColumn::Group => ("group", 80.),
+                    SftpColumn::LinkTarget => ("link_target", 160.),
+                    SftpColumn::Size => ("size", 70.),
+                    SftpColumn::Modified => ("modified", 135.),
+                };
+                let column = Column::new(key, t(lang, kind.label_key()))
+                    .width(px(width))
+                    .resizable(true);
+                if kind.sort_by().is_some() {
+                    column.sortable()
+                } else {
+                    column
+                }
+            })
+            .collect()
     }
 
     /// 更新语言与列标题（保留列宽与排序状态）
@@ -153,46 +271,92 @@ impl FileListDelegate {
 
     /// 更新列标题文本
     fn update_column_titles(&mut self, lang: &Language) {
-        if let Some(column) = self.columns.get_mut(COL_NAME) {
-            column.name = t(lang, "sftp.header.name").into();
-        }
-        if let Some(column) = self.columns.get_mut(COL_PERMISSIONS) {
-            column.name = t(lang, "sftp.header.permissions").into();
-        }
-        if let Some(column) = self.columns.get_mut(COL_OWNER) {
-            column.name = t(lang, "sftp.header.owner").into();
-        }
-        if let Some(column) = self.columns.get_mut(COL_SIZE) {
-            column.name = t(lang, "sftp.header.size").into();
-        }
-        if let Some(column) = self.columns.get_mut(COL_MODIFIED) {
-            column.name = t(lang, "sftp.header.modified").into();
+        for (column, kind) in self.columns.iter_mut().zip(self.column_kinds.iter()) {
+            column.name = t(lang, kind.label_key()).into();
         }
     }
 
+    /// 设置显示的列（按显示顺序）
+    pub fn set_columns(&mut self, kinds: Vec<SftpColumn>) {
+        self.columns = Self::create_columns(&self.lang, &kinds);
+        self.column_kinds = kinds;
+        self.sync_column_sort_state();
+    }
+
+    /// 当前显示的列
+    pub fn column_kinds(&self) -> &[SftpColumn] {
+        &self.column_kinds
+    }
+
     /// 同步列排序状态（用于保留 UI 选中状态）
     fn sync_column_sort_state(&mut self) {
-        for (ix, column) in self.columns.iter_mut().enumerate() {
+        for (column, kind) in self.columns.iter_mut().zip(self.column_kinds.iter()) {
             if column.sort.is_some() {
-                column.sort = Some(ColumnSort::Default);
-            }
-            if ix == self.current_sort_col && column.sort.is_some() {
-                column.sort = Some(self.current_sort);
+                column.sort = Some(if kind.sort_by() == Some(self.sort_by.clone()) {
+                    self.current_sort
+                } else {
+                    ColumnSort::Default
+                });
             }
         }
     }
 
-    /// 重置行顺序为原始顺序
-    fn reset_row_order(&mut self) {
-        self.row_order.clear();
-        self.row_order.extend(0..self.file_list.len());
-    }
-
     /// 更新文件列表
     pub fn update_file_list(&mut self, entries: Vec<FileEntry>) {
         self.file_list = entries;
-        // 使用当前排序状态进行排序
-        self.apply_current_sort();
+        // 只保留仍在列表中的多选标记
+        let file_list = &self.file_list;
+        self.marked_paths
+            .retain(|path| file_list.iter().any(|e| &e.path == path));
+        // 使用当前过滤条件和排序状态重建行顺序
+        self.refresh_rows();
+    }
+
+    /// 行点击：`toggle` 为 true（Ctrl/Cmd+点击）时切换该行的多选标记，否则清除所有标记
+    ///
+    /// 返回标记是否发生变化
+    fn click_row(&mut self, path: &str, toggle: bool) -> bool {
+        if toggle {
+            if !self.marked_paths.remove(path) {
+                self.marked_paths.insert(path.to_string());
+            }
+            true
+        } else if !self.marked_paths.is_empty() {
+            self.marked_paths.clear();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 标记当前显示的全部行
+    fn mark_all_rows(&mut self) {
+        self.marked_paths = self
+            .row_order
+            .iter()
+            .filter_map(|&ix| self.file_list.get(ix))
+            .map(|e| e.path.clone())
+            .collect();
+    }
+
+    /// 属性对话框的目标路径：右键的行已被标记且标记多于一项时为全部标记（按显示顺序），否则只有该行
+    fn properties_targets(&self, path: &str) -> Vec<String> {
+        if self.marked_paths.len() > 1 && self.marked_paths.contains(path) {
+            self.row_order
+                .iter()
+                .filter_map(|&ix| self.file_list.get(ix))
+                .filter(|e| self.marked_paths.contains(&e.path))
+                .map(|e| e.path.clone())
+                .collect()
+        } else {
+            vec![path.to_string()]
+        }
+    }
+
+    /// 设置文件名过滤条件
+    pub fn set_filter(&mut self, filter: &str) {
+        self.filter = filter.trim().to_lowercase();
+        self.refresh_rows();
     }
 
     /// 更新用户缓存
@@ -205,90 +369,80 @@ impl FileListDelegate {
         self.group_cache = cache;
     }
 
-    /// 设置并应用排序
+    /// 设置并应用排序，升序/降序会保存到设置中
     fn sort_file_list(&mut self, col_ix: usize, sort: ColumnSort) {
+        let Some(sort_by) = self.column_kinds.get(col_ix).and_then(|k| k.sort_by()) else {
+            return;
+        };
         // 保存当前排序状态
-        self.current_sort_col = col_ix;
+        self.sort_by = sort_by.clone();
         self.current_sort = sort;
         self.sync_column_sort_state();
         // 应用排序
-        self.apply_current_sort();
+        self.refresh_rows();
+
+        if sort != ColumnSort::Default {
+            save_sftp_settings(|sftp| {
+                sftp.sort_by = sort_by;
+                sftp.sort_ascending = sort == ColumnSort::Ascending;
+            });
+        }
     }
 
-    /// 应用当前排序状态
-    fn apply_current_sort(&mut self) {
+    /// 按过滤条件和排序状态重建行顺序
+    fn refresh_rows(&mut self) {
+        let entries = &self.file_list;
+        self.row_order.clear();
+        self.row_order.extend(
+            (0..entries.len()).filter(|&ix| name_matches_filter(&entries[ix].name, &self.filter)),
+        );
+
         if self.current_sort == ColumnSort::Default {
-            self.reset_row_order();
             return;
         }
 
-        if self.row_order.len() != self.file_list.len() {
-            self.reset_row_order();
-        }
-
-        let entries = &self.file_list;
-        match (self.current_sort_col, self.current_sort) {
-            (COL_NAME, ColumnSort::Ascending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, entry.name.to_lowercase(), ix)
-                });
-            }
-            (COL_NAME, ColumnSort::Descending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, Reverse(entry.name.to_lowercase()), ix)
-                });
-            }
-            (COL_SIZE, ColumnSort::Ascending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, entry.size, ix)
-                });
-            }
-            (COL_SIZE, ColumnSort::Descending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, Reverse(entry.size), ix)
-                });
-            }
-            (COL_MODIFIED, ColumnSort::Ascending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, entry.modified.clone(), ix)
-                });
-            }
-            (COL_MODIFIED, ColumnSort::Descending) => {
-                self.row_order.sort_by_cached_key(|&ix| {
-                    let entry = &entries[ix];
-                    let dir_rank = if entry.is_dir() { 0u8 } else { 1u8 };
-                    (dir_rank, Reverse(entry.modified.clone()), ix)
-                });
-            }
-            _ => {}
-        }
+        let folders_first = self.folders_first;
+        let descending = self.current_sort == ColumnSort::Descending;
+        let sort_by = self.sort_by.clone();
+        // 稳定排序，相同键保持原始顺序
+        self.row_order.sort_by(|&a, &b| {
+            let (a, b) = (&entries[a], &entries[b]);
+            let dir_rank = |e: &FileEntry| !(folders_first && e.is_dir());
+            let ordering = match sort_by {
+                SftpSortBy::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                SftpSortBy::Size => a.size.cmp(&b.size),
+                SftpSortBy::Modified => a.modified.cmp(&b.modified),
+                SftpSortBy::Permissions => (a.permissions & 0o7777).cmp(&(b.permissions & 0o7777)),
+                SftpSortBy::Type => a
+                    .extension()
+                    .cmp(&b.extension())
+                    .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+            };
+            let ordering = if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            dir_rank(a).cmp(&dir_rank(b)).then(ordering)
+        });
     }
 
-    /// 格式化 uid:gid 为 username:groupname
-    fn format_owner(&self, uid: Option<u32>, gid: Option<u32>) -> String {
-        let user = uid
-            .and_then(|u| self.user_cache.get(&u).cloned())
+    /// 格式化 uid 为用户名
+    fn format_user(&self, uid: Option<u32>) -> String {
+        uid.and_then(|u| self.user_cache.get(&u).cloned())
             .unwrap_or_else(|| {
                 uid.map(|u| u.to_string())
                     .unwrap_or_else(|| "-".to_string())
-            });
-        let group = gid
-            .and_then(|g| self.group_cache.get(&g).cloned())
+            })
+    }
+
+    /// 格式化 gid 为组名
+    fn format_group(&self, gid: Option<u32>) -> String {
+        gid.and_then(|g| self.group_cache.get(&g).cloned())
             .unwrap_or_else(|| {
                 gid.map(|g| g.to_string())
                     .unwrap_or_else(|| "-".to_string())
-            });
-        format!("{}:{}", user, group)
+            })
     }
 
     /// 获取指定行的文件路径
@@ -305,6 +459,55 @@ impl FileListDelegate {
     }
 }
 
+/// 文件名是否符合过滤条件（已转为小写）
+///
+/// 包含 `*` 或 `?` 时按通配符匹配整个文件名，否则按子串匹配，均不区分大小写
+fn name_matches_filter(name: &str, filter: &str) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+    let name = name.to_lowercase();
+    if !filter.contains(['*', '?']) {
+        return name.contains(filter);
+    }
+
+    let name: Vec<char> = name.chars().collect();
+    let pattern: Vec<char> = filter.chars().collect();
+    let (mut n, mut p) = (0, 0);
+    // 最近一个 * 的位置及其匹配到的文件名位置，用于回溯
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                n += 1;
+                p += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// 修改并保存 SFTP 设置（文件列表的列、排序等偏好）
+fn save_sftp_settings(f: impl FnOnce(&mut SftpSettings)) {
+    let mut settings = crate::services::storage::load_settings().unwrap_or_default();
+    f(&mut settings.sftp);
+    if let Err(e) = crate::services::storage::save_settings(&settings) {
+        tracing::error!("[SFTP] Failed to save file list preferences: {}", e);
+    }
+}
+
 fn hash_row_id(value: &str) -> u64 {
     let mut hasher = DefaultHasher::new();
     value.hash(&mut hasher);
@@ -312,7 +515,7 @@ fn hash_row_id(value: &str) -> u64 {
 }
 
 /// 获取文件类型图标
-fn get_file_icon(entry: &FileEntry) -> &'static str {
+pub(super) fn get_file_icon(entry: &FileEntry) -> &'static str {
     match entry.file_type {
         FileType::Directory => icons::FOLDER,
         FileType::Symlink => icons::LINK,
@@ -329,7 +532,7 @@ fn get_file_icon(entry: &FileEntry) -> &'static str {
 }
 
 /// 格式化修改时间
-fn format_modified_time(entry: &FileEntry) -> String {
+pub(super) fn format_modified_time(entry: &FileEntry) -> String {
     use chrono::{Local, TimeZone};
 
     match entry.modified {
@@ -405,8 +608,12 @@ impl TableDelegate for FileListDelegate {
         let foreground = cx.theme().foreground;
         let muted = cx.theme().muted_foreground;
 
-        match col_ix {
-            COL_NAME => {
+        let Some(kind) = self.column_kinds.get(col_ix).copied() else {
+            return div().into_any_element();
+        };
+
+        match kind {
+            SftpColumn::Name => {
                 let icon = get_file_icon(entry);
                 let icon_color = if entry.is_dir() {
                     cx.theme().link
@@ -454,7 +661,13 @@ impl TableDelegate for FileListDelegate {
                     }
                 }
 
-                // 正常显示模式
+                // 正常显示模式（符号链接在名称后显示指向的目标）
+                let name = div()
+                    .text_xs()
+                    .text_color(foreground)
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .child(entry.name.clone());
                 div()
                     .h_full()
                     .flex()
@@ -462,19 +675,24 @@ impl TableDelegate for FileListDelegate {
                     .gap_2()
                     .overflow_hidden()
                     .child(svg().path(icon).size(px(ICON_SIZE)).text_color(icon_color))
-                    .child(
+                    .child(if entry.link_target.is_some() {
+                        name.flex_shrink_0().max_w(relative(0.6))
+                    } else {
+                        name.flex_1()
+                    })
+                    .children(entry.link_target.clone().map(|target| {
                         div()
                             .flex_1()
                             .text_xs()
-                            .text_color(foreground)
+                            .text_color(muted)
                             .overflow_hidden()
                             .text_ellipsis()
-                            .child(entry.name.clone()),
-                    )
+                            .child(format!("→ {}", target))
+                    }))
                     .into_any_element()
             }
 
-            COL_PERMISSIONS => div()
+            SftpColumn::Permissions => div()
                 .h_full()
                 .flex()
                 .items_center()
@@ -483,8 +701,12 @@ impl TableDelegate for FileListDelegate {
                 .font_family("monospace")
                 .child(entry.format_permissions())
                 .into_any_element(),
-            COL_OWNER => {
-                let owner = self.format_owner(entry.uid, entry.gid);
+            SftpColumn::Owner | SftpColumn::Group | SftpColumn::LinkTarget => {
+                let text = match kind {
+                    SftpColumn::Owner => self.format_user(entry.uid),
+                    SftpColumn::Group => self.format_group(entry.gid),
+                    _ => entry.link_target.clone().unwrap_or_default(),
+                };
                 div()
                     .h_full()
                     .flex()
@@ -493,10 +715,10 @@ impl TableDelegate for FileListDelegate {
                     .text_color(muted)
                     .overflow_hidden()
                     .text_ellipsis()
-                    .child(owner)
+                    .child(text)
                     .into_any_element()
             }
-            COL_SIZE => div()
+            SftpColumn::Size => div()
                 .h_full()
                 .flex()
                 .items_center()
@@ -504,7 +726,7 @@ impl TableDelegate for FileListDelegate {
                 .text_color(muted)
                 .child(entry.format_size())
                 .into_any_element(),
-            COL_MODIFIED => div()
+            SftpColumn::Modified => div()
                 .h_full()
                 .flex()
                 .items_center()
@@ -512,15 +734,33 @@ impl TableDelegate for FileListDelegate {
                 .text_color(muted)
                 .child(format_modified_time(entry))
                 .into_any_element(),
-            _ => div().into_any_element(),
         }
     }
 
+    fn move_column(
+        &mut self,
+        col_ix: usize,
+        to_ix: usize,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) {
+        if col_ix >= self.columns.len() || to_ix >= self.columns.len() {
+            return;
+        }
+        let column = self.columns.remove(col_ix);
+        self.columns.insert(to_ix, column);
+        let kind = self.column_kinds.remove(col_ix);
+        self.column_kinds.insert(to_ix, kind);
+
+        let kinds = self.column_kinds.clone();
+        save_sftp_settings(|sftp| sftp.file_list_columns = kinds);
+    }
+
     fn render_tr(
         &mut self,
         row_ix: usize,
         _window: &mut Window,
-        _cx: &mut Context<TableState<Self>>,
+        cx: &mut Context<TableState<Self>>,
     ) -> Stateful<Div> {
         // 获取当前行的文件信息
         let entry = self
@@ -533,11 +773,45 @@ impl TableDelegate for FileListDelegate {
         let is_dir = entry.map(|e| e.is_dir()).unwrap_or(false);
         let folder_path = entry.map(|e| e.path.clone());
         let on_row_drop = self.on_row_drop.clone();
+        let on_row_move = self.on_row_move.clone();
+
+        let mut base_div = div().id(("file-row", row_id));
+
+        // Ctrl/Cmd+点击切换多选标记，普通点击清除标记
+        if let Some(entry) = entry {
+            let path = entry.path.clone();
+            let is_marked = self.marked_paths.contains(&entry.path);
+            base_div = base_div
+                .when(is_marked, |this| this.bg(cx.theme().accent))
+                .on_click(cx.listener(move |table, event: &ClickEvent, _, cx| {
+                    let toggle = event.modifiers().secondary();
+                    if table.delegate_mut().click_row(&path, toggle) {
+                        cx.notify();
+                    }
+                }));
+        }
 
-        let base_div = div().id(("file-row", row_id));
+        // 正在重命名的行不允许拖动
+        if let Some(entry) = entry.filter(|e| self.editing_path.as_ref() != Some(&e.path)) {
+            let dragged = DraggedSftpEntry {
+                path: entry.path.clone(),
+                name: entry.name.clone(),
+                is_dir,
+            };
+            let on_drag_start = self.on_drag_start.clone();
+            base_div = base_div.on_drag(dragged, move |dragged, _, _, cx| {
+                if let Some(callback) = &on_drag_start {
+                    callback(dragged.clone());
+                }
+                cx.new(|_| dragged.clone())
+            });
+        }
 
         // 如果是文件夹，添加拖放处理器
         if is_dir {
+            let folder_path_for_move = folder_path.clone();
+            let folder_path_for_local = folder_path.clone();
+            let on_row_drop_local = on_row_drop.clone();
             base_div
                 .drag_over::<ExternalPaths>(|this, _, _, cx| {
                     // 文件夹行高亮 - 使用明显的背景色
@@ -554,6 +828,30 @@ impl TableDelegate for FileListDelegate {
                         }
                     }
                 })
+                .drag_over::<DraggedSftpEntry>(|this, _, _, cx| {
+                    this.bg(cx.theme().info.opacity(0.3))
+                })
+                .on_drop(move |dragged: &DraggedSftpEntry, _window, _cx| {
+                    if let (Some(callback), Some(target_folder)) =
+                        (&on_row_move, &folder_path_for_move)
+                    {
+                        // 拖放到自身时忽略
+                        if dragged.path != *target_folder {
+                            callback(dragged.path.clone(), target_folder.clone());
+                        }
+                    }
+                })
+                // 双面板：本地条目拖放到文件夹行上传到该目录
+                .drag_over::<DraggedLocalEntry>(|this, _, _, cx| {
+                    this.bg(cx.theme().info.opacity(0.3))
+                })
+                .on_drop(move |dragged: &DraggedLocalEntry, _window, _cx| {
+                    if let (Some(callback), Some(target_folder)) =
+                        (&on_row_drop_local, &folder_path_for_local)
+                    {
+                        callback(vec![dragged.path.clone()], target_folder.clone());
+                    }
+                })
         } else {
             base_div
         }
@@ -581,6 +879,14 @@ pub struct FileListView {
     loading: bool,
     /// 是否已连接
     connected: bool,
+    /// 当前目录读取失败的信息
+    load_error: Option<DirLoadError>,
+    /// 当前列表是否通过 sudo 列出
+    sudo_listing: bool,
+    /// 是否处于 SCP 回退模式
+    scp_fallback: bool,
+    /// 分批加载中已加载的条目数
+    loaded_count: Option<usize>,
     /// 语言设置
     lang: Language,
     /// 记录列宽（用于语言切换时保留）
@@ -591,17 +897,30 @@ pub struct FileListView {
     last_user_cache_revision: u64,
     /// 组缓存版本
     last_group_cache_revision: u64,
+    /// 文件定位请求版本
+    last_reveal_revision: u64,
     /// 内联重命名输入框
     rename_input: Option<Entity<gpui_component::input::InputState>>,
     /// 正在编辑的文件路径
     editing_path: Option<String>,
     /// 当前远程路径（用于拖放上传）
     current_path: String,
+    /// 文件名过滤输入框
+    filter_input: Entity<InputState>,
     /// 待处理的行拖放事件队列
     pending_row_drops: std::sync::Arc<std::sync::Mutex<Vec<(Vec<std::path::PathBuf>, String)>>>,
+    /// 待处理的行内拖动移动事件队列（源路径, 目标目录）
+    pending_row_moves: std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>,
+    /// 正在拖动的远程条目（松开鼠标时清除）
+    dragging_entry: std::sync::Arc<std::sync::Mutex<Option<DraggedSftpEntry>>>,
 }
 
 impl FileListView {
+    /// 聚焦文件列表（键盘导航）
+    pub fn focus(&self, window: &mut Window, cx: &App) {
+        window.focus(&self.table_state.read(cx).focus_handle(cx));
+    }
+
     /// 创建新的文件列表视图
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let lang = crate::services::storage::load_settings()
@@ -613,6 +932,12 @@ impl FileListView {
             std::sync::Mutex<Vec<(Vec<std::path::PathBuf>, String)>>,
         > = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
         let pending_row_drops_for_callback = pending_row_drops.clone();
+        let pending_row_moves: std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let pending_row_moves_for_callback = pending_row_moves.clone();
+        let dragging_entry: std::sync::Arc<std::sync::Mutex<Option<DraggedSftpEntry>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(None));
+        let dragging_entry_for_callback = dragging_entry.clone();
 
         // 创建 delegate 并设置回调
         let mut delegate = FileListDelegate::new(lang.clone());
@@ -621,10 +946,20 @@ impl FileListView {
                 queue.push((paths, target_dir));
             }
         }));
+        delegate.on_row_move = Some(std::sync::Arc::new(move |source, target_dir| {
+            if let Ok(mut queue) = pending_row_moves_for_callback.lock() {
+                queue.push((source, target_dir));
+            }
+        }));
+        delegate.on_drag_start = Some(std::sync::Arc::new(move |entry| {
+            if let Ok(mut dragging) = dragging_entry_for_callback.lock() {
+                *dragging = Some(entry);
+            }
+        }));
 
         let table_state = cx.new(|cx| {
             TableState::new(delegate, window, cx)
-                .col_movable(false)
+                .col_movable(true)
                 .sortable(true)
                 .col_resizable(true)
                 .row_selectable(true)
@@ -635,27 +970,81 @@ impl FileListView {
             &table_state,
             window,
             |this, _state, event: &TableEvent, _window, cx| {
-                if let TableEvent::ColumnWidthsChanged(widths) = event {
-                    this.column_widths = widths.clone();
+                match event {
+                    TableEvent::ColumnWidthsChanged(widths) => {
+                        this.column_widths = widths.clone();
+                    }
+                    // 拖动列标题调整顺序后，列宽跟随移动
+                    TableEvent::MoveColumn(col_ix, to_ix) => {
+                        if *col_ix < this.column_widths.len() && *to_ix < this.column_widths.len() {
+                            let width = this.column_widths.remove(*col_ix);
+                            this.column_widths.insert(*to_ix, width);
+                        }
+                    }
+                    _ => {}
                 }
                 cx.emit(event.clone());
             },
         )
         .detach();
 
+        // 输入过滤条件时即时过滤当前目录
+        let filter_input = cx
+            .new(|cx| InputState::new(window, cx).placeholder(t(&lang, "sftp.filter.placeholder")));
+        cx.subscribe(&filter_input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                let filter = input.read(cx).value().to_string();
+                this.table_state.update(cx, |table_state, cx| {
+                    table_state.delegate_mut().set_filter(&filter);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+
         Self {
             table_state,
             loading: false,
             connected: false,
+            load_error: None,
+            sudo_listing: false,
+            scp_fallback: false,
+            loaded_count: None,
             lang,
             column_widths: Vec::new(),
             last_file_list_revision: 0,
             last_user_cache_revision: 0,
             last_group_cache_revision: 0,
+            last_reveal_revision: 0,
             rename_input: None,
             editing_path: None,
             current_path: String::new(),
+            filter_input,
             pending_row_drops,
+            pending_row_moves,
+            dragging_entry,
+        }
+    }
+
+    /// 鼠标松开时结束行拖动：在窗口外松开时请求下载被拖动的条目
+    ///
+    /// GPUI 不提供拖出到系统文件管理器的放置位置，因此下载到系统下载目录
+    fn finish_row_drag(
+        &mut self,
+        position: Point<Pixels>,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) {
+        let entry = self
+            .dragging_entry
+            .lock()
+            .ok()
+            .and_then(|mut dragging| dragging.take());
+        let Some(entry) = entry else {
+            return;
+        };
+        if cx.has_active_drag() && released_outside_window(position, window.viewport_size()) {
+            cx.emit(FileListContextMenuEvent::DragOut(entry.path));
         }
     }
 
@@ -695,8 +1084,22 @@ impl FileListView {
                     self.loading = state.loading;
                     needs_notify = true;
                 }
+                if self.load_error != state.load_error || self.sudo_listing != state.sudo_listing {
+                    self.load_error = state.load_error.clone();
+                    self.sudo_listing = state.sudo_listing;
+                    needs_notify = true;
+                }
+                if self.scp_fallback != state.scp_fallback {
+                    self.scp_fallback = state.scp_fallback;
+                    needs_notify = true;
+                }
+                if self.loaded_count != state.loaded_count {
+                    self.loaded_count = state.loaded_count;
+                    needs_notify = true;
+                }
 
-                if !state.loading {
+                // 分批加载时每批到达即同步显示
+                if !state.loading || state.loaded_count.is_some() {
                     let file_list_changed =
                         state.file_list_revision != self.last_file_list_revision;
                     let user_cache_changed =
@@ -725,6 +1128,14 @@ impl FileListView {
                         });
                         needs_notify = true;
                     }
+
+                    // 文件列表就绪后再处理定位请求
+                    if state.reveal_revision != self.last_reveal_revision {
+                        self.last_reveal_revision = state.reveal_revision;
+                        if let Some(path) = state.reveal_path.as_deref() {
+                            self.select_path(path, cx);
+                        }
+                    }
                 }
             }
             None => {
@@ -733,9 +1144,14 @@ impl FileListView {
                     self.loading = false;
                     needs_notify = true;
                 }
+                self.load_error = None;
+                self.sudo_listing = false;
+                self.scp_fallback = false;
+                self.loaded_count = None;
                 self.last_file_list_revision = 0;
                 self.last_user_cache_revision = 0;
                 self.last_group_cache_revision = 0;
+                self.last_reveal_revision = 0;
             }
         }
         if needs_notify {
@@ -743,11 +1159,65 @@ impl FileListView {
         }
     }
 
+    /// 显示或隐藏列（名称列始终显示），并保存到设置
+    fn toggle_column(&mut self, kind: SftpColumn, cx: &mut Context<Self>) {
+        if kind == SftpColumn::Name {
+            return;
+        }
+        let mut kinds = self.table_state.read(cx).delegate().column_kinds().to_vec();
+        match kinds.iter().position(|k| *k == kind) {
+            Some(ix) => {
+                kinds.remove(ix);
+            }
+            None => kinds.push(kind),
+        }
+        let saved = kinds.clone();
+        save_sftp_settings(|sftp| sftp.file_list_columns = saved);
+
+        // 列数变化后原有列宽不再适用
+        self.column_widths.clear();
+        self.table_state.update(cx, |table_state, cx| {
+            table_state.delegate_mut().set_columns(kinds);
+            table_state.refresh(cx);
+        });
+        cx.notify();
+    }
+
     /// 获取指定行的文件路径
     pub fn get_file_path(&self, row_ix: usize, cx: &App) -> Option<String> {
         self.table_state.read(cx).delegate().get_file_path(row_ix)
     }
 
+    /// 选中指定路径对应的行（按当前排序后的显示顺序）
+    fn select_path(&mut self, path: &str, cx: &mut Context<Self>) {
+        self.table_state.update(cx, |table_state, cx| {
+            let delegate = table_state.delegate();
+            let row_ix = delegate
+                .row_order
+                .iter()
+                .position(|&ix| delegate.file_list.get(ix).is_some_and(|e| e.path == path));
+            if let Some(row_ix) = row_ix {
+                table_state.set_selected_row(row_ix, cx);
+            }
+        });
+    }
+
+    /// 标记当前显示的全部条目（多选）
+    fn select_all(&mut self, cx: &mut Context<Self>) {
+        self.table_state.update(cx, |table_state, cx| {
+            table_state.delegate_mut().mark_all_rows();
+            cx.notify();
+        });
+    }
+
+    /// 属性对话框的目标路径（右键的条目属于多选标记时为全部标记的条目）
+    fn properties_targets(&self, path: &str, cx: &App) -> Vec<String> {
+        self.table_state
+            .read(cx)
+            .delegate()
+            .properties_targets(path)
+    }
+
     /// 获取当前选中的文件条目
     pub fn get_selected_file(&self, cx: &App) -> Option<FileEntry> {
         let table_state = self.table_state.read(cx);
@@ -757,6 +1227,19 @@ impl FileListView {
         delegate.file_list.get(entry_ix).cloned()
     }
 
+    /// 将选中项传输到本地面板
+    fn transfer_selected_to_local(&mut self, remove_source: bool, cx: &mut Context<Self>) {
+        if self.editing_path.is_some() {
+            return;
+        }
+        if let Some(entry) = self.get_selected_file(cx) {
+            cx.emit(FileListContextMenuEvent::TransferToLocal {
+                path: entry.path,
+                remove_source,
+            });
+        }
+    }
+
     /// 开始内联重命名
     pub fn start_rename(&mut self, path: String, window: &mut Window, cx: &mut Context<Self>) {
         // 获取文件名
@@ -844,6 +1327,11 @@ impl Render for FileListView {
                 cx.emit(FileListContextMenuEvent::DropFiles { paths, target_dir });
             }
         }
+        if let Ok(mut queue) = self.pending_row_moves.lock() {
+            for (source, target_dir) in queue.drain(..) {
+                cx.emit(FileListContextMenuEvent::MoveEntry { source, target_dir });
+            }
+        }
 
         let bg_color = crate::theme::sidebar_color(cx);
         let muted_foreground = cx.theme().muted_foreground;
@@ -866,7 +1354,7 @@ impl Render for FileListView {
                 .into_any_element();
         }
 
-        if self.loading {
+        if self.loading && self.loaded_count.is_none() {
             // 加载中状态
             return div()
                 .size_full()
@@ -883,19 +1371,119 @@ impl Render for FileListView {
                 .into_any_element();
         }
 
+        if let Some(error) = &self.load_error {
+            return self.render_load_error(error, cx);
+        }
+
         let has_items = !self.table_state.read(cx).delegate().is_empty();
+        let trash_enabled = crate::services::storage::load_settings()
+            .map(|s| s.sftp.use_remote_trash)
+            .unwrap_or(false);
 
         // 获取自身 Entity 用于在上下文菜单中发出事件
         let this = cx.entity().clone();
 
+        // 过滤栏：文件名过滤 + 显示列菜单
+        let visible_columns = self.table_state.read(cx).delegate().column_kinds().to_vec();
+        let this_for_columns = this.clone();
+        let lang_for_columns = lang.clone();
+        let filter_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    Input::new(&self.filter_input)
+                        .xsmall()
+                        .cleanable(true)
+                        .prefix(
+                            svg()
+                                .path(icons::FILTER)
+                                .size(px(12.))
+                                .text_color(muted_foreground),
+                        ),
+                ),
+            )
+            .child(
+                Button::new("sftp-file-list-columns")
+                    .ghost()
+                    .xsmall()
+                    .tooltip(t(&lang, "sftp.columns.title"))
+                    .child(
+                        svg()
+                            .path(icons::COLUMNS)
+                            .size(px(14.))
+                            .text_color(cx.theme().foreground),
+                    )
+                    .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                        SftpColumn::ALL
+                            .iter()
+                            .fold(menu.min_w(px(140.)), |menu, &kind| {
+                                let this = this_for_columns.clone();
+                                menu.item(
+                                    PopupMenuItem::new(t(&lang_for_columns, kind.label_key()))
+                                        .checked(visible_columns.contains(&kind))
+                                        .disabled(kind == SftpColumn::Name)
+                                        .on_click(move |_, _, cx| {
+                                            this.update(cx, |view, cx| {
+                                                view.toggle_column(kind, cx)
+                                            });
+                                        }),
+                                )
+                            })
+                    }),
+            );
+
         // 正常状态 - 显示 Table，包装在带上下文菜单的容器中
         let table = Table::new(&self.table_state).stripe(true).bordered(false);
 
-        div()
+        let list = div()
             .id("sftp-file-list-container")
-            .size_full()
+            .key_context(SFTP_FILE_LIST_CONTEXT)
+            .w_full()
+            .flex_1()
+            .min_h_0()
             .relative()
+            // F2 重命名选中项
+            .on_action(cx.listener(|view, _: &RenameSelected, window, cx| {
+                if view.editing_path.is_some() {
+                    return;
+                }
+                if let Some(entry) = view.get_selected_file(cx) {
+                    view.start_rename(entry.path, window, cx);
+                }
+            }))
+            // F5/F6 复制/移动选中项到本地面板
+            .on_action(cx.listener(|view, _: &CopyToOtherPane, _, cx| {
+                view.transfer_selected_to_local(false, cx);
+            }))
+            .on_action(cx.listener(|view, _: &MoveToOtherPane, _, cx| {
+                view.transfer_selected_to_local(true, cx);
+            }))
+            // Cmd+Shift+G 前往路径
+            .on_action(cx.listener(|_, _: &GoToPath, _, cx| {
+                cx.emit(FileListContextMenuEvent::GoToPath);
+            }))
             .child(table)
+            // 行拖出窗口后松开：下载到系统下载目录
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|view, event: &MouseUpEvent, window, cx| {
+                    view.finish_row_drag(event.position, window, cx);
+                }),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|view, event: &MouseUpEvent, window, cx| {
+                    view.finish_row_drag(event.position, window, cx);
+                }),
+            )
             // 拖放上传支持
             .drag_over::<ExternalPaths>(|this, _, _, cx| {
                 // 拖动悬停时显示视觉反馈 - 使用明显的背景色和边框
@@ -916,6 +1504,19 @@ impl Render for FileListView {
                     }
                 }),
             )
+            // 双面板：本地条目拖放到列表空白处，上传到当前目录
+            .drag_over::<DraggedLocalEntry>(|this, _, _, cx| {
+                this.bg(cx.theme().drop_target)
+                    .border_2()
+                    .border_color(cx.theme().primary)
+            })
+            .on_drop(
+                cx.listener(move |view, dragged: &DraggedLocalEntry, _window, cx| {
+                    let paths = vec![dragged.path.clone()];
+                    let target_dir = view.current_path.clone();
+                    cx.emit(FileListContextMenuEvent::DropFiles { paths, target_dir });
+                }),
+            )
             .context_menu(move |menu, _window, cx| {
                 // 读取当前选中的文件条目
                 let selected_entry = this.read(cx).get_selected_file(cx);
@@ -933,18 +1534,186 @@ impl Render for FileListView {
                     }
                     None if has_items => {
                         // 有文件但没选中 - 空白区域菜单
-                        build_empty_area_context_menu(menu, &lang, this.clone())
+                        build_empty_area_context_menu(menu, &lang, trash_enabled, this.clone())
                     }
                     None => {
                         // 完全空的文件夹
-                        build_empty_area_context_menu(menu, &lang, this.clone())
+                        build_empty_area_context_menu(menu, &lang, trash_enabled, this.clone())
                     }
                 }
+            });
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .child(filter_bar)
+            .when(self.sudo_listing, |this| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .bg(cx.theme().warning.opacity(0.15))
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(
+                            svg()
+                                .path(icons::LOCK)
+                                .size(px(12.))
+                                .text_color(cx.theme().warning),
+                        )
+                        .child(t(&lang, "sftp.load_error.sudo_listing")),
+                )
+            })
+            .when(self.scp_fallback, |this| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .bg(cx.theme().warning.opacity(0.15))
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(
+                            svg()
+                                .path(icons::INFO)
+                                .size(px(12.))
+                                .text_color(cx.theme().warning),
+                        )
+                        .child(t(&lang, "sftp.scp_fallback")),
+                )
             })
+            .when_some(self.loaded_count, |this, count| {
+                this.child(self.render_loading_progress(count, cx))
+            })
+            .child(list)
             .into_any_element()
     }
 }
 
+impl FileListView {
+    /// 渲染分批加载进度条：已加载条目数 + 停止加载
+    fn render_loading_progress(&self, count: usize, cx: &mut Context<Self>) -> AnyElement {
+        let label = t(&self.lang, "sftp.loading_progress").replace("{}", &format_count(count));
+        div()
+            .w_full()
+            .flex_shrink_0()
+            .px_2()
+            .py_0p5()
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .bg(cx.theme().muted)
+            .text_xs()
+            .text_color(cx.theme().muted_foreground)
+            .child(label)
+            .child(
+                Button::new("sftp-load-cancel")
+                    .ghost()
+                    .xsmall()
+                    .child(t(&self.lang, "sftp.loading_cancel"))
+                    .on_click(cx.listener(|_, _, _, cx| {
+                        cx.emit(FileListContextMenuEvent::CancelLoad);
+                    })),
+            )
+            .into_any_element()
+    }
+
+    /// 渲染目录读取失败状态：错误信息 + 重试 / 以 sudo 列出
+    fn render_load_error(&self, error: &DirLoadError, cx: &mut Context<Self>) -> AnyElement {
+        let lang = &self.lang;
+        let title = if error.permission_denied {
+            t(lang, "sftp.load_error.permission_denied")
+        } else {
+            t(lang, "sftp.load_error.title")
+        };
+
+        div()
+            .size_full()
+            .bg(crate::theme::sidebar_color(cx))
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .px_4()
+            .child(
+                svg()
+                    .path(if error.permission_denied {
+                        icons::LOCK
+                    } else {
+                        icons::INFO
+                    })
+                    .size(px(24.))
+                    .text_color(cx.theme().danger),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().foreground)
+                    .child(title),
+            )
+            .child(
+                div()
+                    .max_w(px(420.))
+                    .text_xs()
+                    .text_center()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(error.message.clone()),
+            )
+            .child(
+                div()
+                    .mt_2()
+                    .flex()
+                    .gap_2()
+                    .child(
+                        Button::new("sftp-load-retry")
+                            .outline()
+                            .xsmall()
+                            .child(t(lang, "sftp.load_error.retry"))
+                            .on_click(cx.listener(|_, _, _, cx| {
+                                cx.emit(FileListContextMenuEvent::RetryLoad);
+                            })),
+                    )
+                    .when(error.permission_denied, |this| {
+                        this.child(
+                            Button::new("sftp-load-sudo")
+                                .outline()
+                                .xsmall()
+                                .child(t(lang, "sftp.load_error.sudo_list"))
+                                .on_click(cx.listener(|_, _, _, cx| {
+                                    cx.emit(FileListContextMenuEvent::SudoList);
+                                })),
+                        )
+                    }),
+            )
+            .into_any_element()
+    }
+}
+
+/// 以千位分隔符格式化条目数（如 12,000）
+fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (ix, c) in digits.chars().enumerate() {
+        if ix > 0 && (digits.len() - ix) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(c);
+    }
+    formatted
+}
+
 /// 构建文件右键菜单
 fn build_file_context_menu(
     menu: gpui_component::menu::PopupMenu,
@@ -964,15 +1733,28 @@ fn build_file_context_menu(
         .parent()
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|| "/".to_string());
+    let path_for_copy_to_server = path.clone();
+    let path_for_compress = path.clone();
+    let is_archive = ArchiveFormat::from_name(&name).is_some();
+    let is_symlink = entry.file_type == FileType::Symlink;
     let path_for_properties = path.clone();
 
     let download_label = t(lang, "sftp.context_menu.download").to_string();
     let edit_label = t(lang, "sftp.context_menu.edit_file").to_string();
+    let edit_as_root_label = t(lang, "sftp.context_menu.edit_as_root").to_string();
+    let hex_view_label = t(lang, "sftp.context_menu.hex_view").to_string();
+    let tail_log_label = t(lang, "sftp.context_menu.tail_log").to_string();
     let copy_name_label = t(lang, "sftp.context_menu.copy_name").to_string();
     let copy_path_label = t(lang, "sftp.context_menu.copy_path").to_string();
     let rename_label = t(lang, "sftp.context_menu.rename").to_string();
     let delete_label = t(lang, "sftp.context_menu.delete").to_string();
     let terminal_label = t(lang, "sftp.context_menu.open_in_terminal").to_string();
+    let copy_to_server_label = t(lang, "sftp.context_menu.copy_to_server").to_string();
+    let compress_tar_label = t(lang, "sftp.context_menu.compress_tar_gz").to_string();
+    let compress_zip_label = t(lang, "sftp.context_menu.compress_zip").to_string();
+    let extract_label = t(lang, "sftp.context_menu.extract").to_string();
+    let follow_link_label = t(lang, "sftp.context_menu.follow_link").to_string();
+    let new_link_label = t(lang, "sftp.context_menu.new_link").to_string();
     let properties_label = t(lang, "sftp.context_menu.properties").to_string();
 
     let e1 = entity.clone();
@@ -981,8 +1763,46 @@ fn build_file_context_menu(
     let e4 = entity.clone();
     let e5 = entity.clone();
     let e6 = entity.clone();
+    let e_hex = entity.clone();
+    let e_tail = entity.clone();
+    let e_edit_root = entity.clone();
     let e_copy_name = entity.clone();
     let e_copy_path = entity.clone();
+    let e_copy_to_server = entity.clone();
+    let e_archive = entity.clone();
+    let e_follow_link = entity.clone();
+    let e_new_link = entity.clone();
+
+    // 符号链接优先显示跳转到目标
+    let menu = if is_symlink {
+        let path = path.clone();
+        menu.item(
+            menu_item_element(icons::LINK, &follow_link_label).on_click(move |_, _, cx| {
+                e_follow_link.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::FollowLink(path.clone()));
+                });
+            }),
+        )
+        .separator()
+    } else {
+        menu
+    };
+
+    // 归档文件优先显示解压
+    let menu = if is_archive {
+        let path = path_for_compress.clone();
+        let entity = e_archive.clone();
+        menu.item(
+            menu_item_element(icons::ARCHIVE, &extract_label).on_click(move |_, _, cx| {
+                entity.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::Extract(path.clone()));
+                });
+            }),
+        )
+        .separator()
+    } else {
+        menu
+    };
 
     menu.item(
         menu_item_element(icons::DOWNLOAD, &download_label).on_click(move |_, _, cx| {
@@ -1001,6 +1821,30 @@ fn build_file_context_menu(
             });
         })
     })
+    .item({
+        let path = path_for_edit.clone();
+        menu_item_element(icons::LOCK, &edit_as_root_label).on_click(move |_, _, cx| {
+            e_edit_root.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::EditAsRoot(path.clone()));
+            });
+        })
+    })
+    .item({
+        let path = path_for_edit.clone();
+        menu_item_element(icons::FILE_CODE, &hex_view_label).on_click(move |_, _, cx| {
+            e_hex.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::HexView(path.clone()));
+            });
+        })
+    })
+    .item({
+        let path = path_for_edit.clone();
+        menu_item_element(icons::FILE_TEXT, &tail_log_label).on_click(move |_, _, cx| {
+            e_tail.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::TailLog(path.clone()));
+            });
+        })
+    })
     .separator()
     .item({
         let name = name_for_copy.clone();
@@ -1018,6 +1862,16 @@ fn build_file_context_menu(
             });
         })
     })
+    .item({
+        let path = path_for_copy.clone();
+        menu_item_element(icons::LINK, &new_link_label).on_click(move |_, _, cx| {
+            e_new_link.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::NewLink(Some(path.clone())));
+            });
+        })
+    })
+    .separator()
+    .compare_items(&path, false, lang, entity.clone())
     .separator()
     .item({
         let path = path_for_rename.clone();
@@ -1036,6 +1890,31 @@ fn build_file_context_menu(
         })
     })
     .separator()
+    .item({
+        let path = path_for_compress.clone();
+        let entity = e_archive.clone();
+        menu_item_element(icons::ARCHIVE, &compress_tar_label).on_click(move |_, _, cx| {
+            entity.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::Compress(
+                    path.clone(),
+                    ArchiveFormat::TarGz,
+                ));
+            });
+        })
+    })
+    .item({
+        let path = path_for_compress.clone();
+        let entity = e_archive.clone();
+        menu_item_element(icons::ARCHIVE, &compress_zip_label).on_click(move |_, _, cx| {
+            entity.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::Compress(
+                    path.clone(),
+                    ArchiveFormat::Zip,
+                ));
+            });
+        })
+    })
+    .separator()
     .item({
         let path = path_for_terminal.clone();
         menu_item_element(icons::TERMINAL, &terminal_label).on_click(move |_, _, cx| {
@@ -1044,11 +1923,20 @@ fn build_file_context_menu(
             });
         })
     })
+    .item({
+        let path = path_for_copy_to_server.clone();
+        menu_item_element(icons::SERVER, &copy_to_server_label).on_click(move |_, _, cx| {
+            e_copy_to_server.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::CopyToServer(path.clone()));
+            });
+        })
+    })
     .item({
         let path = path_for_properties.clone();
         menu_item_element(icons::INFO, &properties_label).on_click(move |_, _, cx| {
-            e6.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::Properties(path.clone()));
+            e6.update(cx, |view, cx| {
+                let paths = view.properties_targets(&path, cx);
+                cx.emit(FileListContextMenuEvent::Properties(paths));
             });
         })
     })
@@ -1070,6 +1958,10 @@ fn build_folder_context_menu(
     let path_for_rename = path.clone();
     let path_for_delete = path.clone();
     let path_for_terminal = path.clone();
+    let path_for_sync = path.clone();
+    let path_for_link = path.clone();
+    let path_for_copy_to_server = path.clone();
+    let path_for_compress = path.clone();
     let path_for_properties = path.clone();
 
     let open_label = t(lang, "sftp.context_menu.open_folder").to_string();
@@ -1079,6 +1971,11 @@ fn build_folder_context_menu(
     let rename_label = t(lang, "sftp.context_menu.rename").to_string();
     let delete_label = t(lang, "sftp.context_menu.delete").to_string();
     let terminal_label = t(lang, "sftp.context_menu.open_in_terminal").to_string();
+    let sync_label = t(lang, "sftp.context_menu.sync_directory").to_string();
+    let new_link_label = t(lang, "sftp.context_menu.new_link").to_string();
+    let copy_to_server_label = t(lang, "sftp.context_menu.copy_to_server").to_string();
+    let compress_tar_label = t(lang, "sftp.context_menu.compress_tar_gz").to_string();
+    let compress_zip_label = t(lang, "sftp.context_menu.compress_zip").to_string();
     let properties_label = t(lang, "sftp.context_menu.properties").to_string();
 
     let e1 = entity.clone();
@@ -1087,6 +1984,10 @@ fn build_folder_context_menu(
     let e4 = entity.clone();
     let e5 = entity.clone();
     let e6 = entity.clone();
+    let e7 = entity.clone();
+    let e_new_link = entity.clone();
+    let e_copy_to_server = entity.clone();
+    let e_archive = entity.clone();
 
     menu.item({
         let path = path_for_open.clone();
@@ -1115,6 +2016,16 @@ fn build_folder_context_menu(
             cx.write_to_clipboard(ClipboardItem::new_string(path_for_copy.clone()));
         }),
     )
+    .item({
+        let path = path_for_link.clone();
+        menu_item_element(icons::LINK, &new_link_label).on_click(move |_, _, cx| {
+            e_new_link.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::NewLink(Some(path.clone())));
+            });
+        })
+    })
+    .separator()
+    .compare_items(&path, true, lang, entity.clone())
     .separator()
     .item({
         let path = path_for_rename.clone();
@@ -1133,6 +2044,31 @@ fn build_folder_context_menu(
         })
     })
     .separator()
+    .item({
+        let path = path_for_compress.clone();
+        let entity = e_archive.clone();
+        menu_item_element(icons::ARCHIVE, &compress_tar_label).on_click(move |_, _, cx| {
+            entity.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::Compress(
+                    path.clone(),
+                    ArchiveFormat::TarGz,
+                ));
+            });
+        })
+    })
+    .item({
+        let path = path_for_compress.clone();
+        let entity = e_archive.clone();
+        menu_item_element(icons::ARCHIVE, &compress_zip_label).on_click(move |_, _, cx| {
+            entity.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::Compress(
+                    path.clone(),
+                    ArchiveFormat::Zip,
+                ));
+            });
+        })
+    })
+    .separator()
     .item({
         let path = path_for_terminal.clone();
         menu_item_element(icons::TERMINAL, &terminal_label).on_click(move |_, _, cx| {
@@ -1141,28 +2077,102 @@ fn build_folder_context_menu(
             });
         })
     })
+    .item({
+        let path = path_for_sync.clone();
+        menu_item_element(icons::TRANSFER, &sync_label).on_click(move |_, _, cx| {
+            e7.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::SyncDirectory(path.clone()));
+            });
+        })
+    })
+    .item({
+        let path = path_for_copy_to_server.clone();
+        menu_item_element(icons::SERVER, &copy_to_server_label).on_click(move |_, _, cx| {
+            e_copy_to_server.update(cx, |_, cx| {
+                cx.emit(FileListContextMenuEvent::CopyToServer(path.clone()));
+            });
+        })
+    })
     .item({
         let path = path_for_properties.clone();
         menu_item_element(icons::INFO, &properties_label).on_click(move |_, _, cx| {
-            e6.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::Properties(path.clone()));
+            e6.update(cx, |view, cx| {
+                let paths = view.properties_targets(&path, cx);
+                cx.emit(FileListContextMenuEvent::Properties(paths));
             });
         })
     })
 }
 
+/// 右键菜单中的文件对比菜单项
+trait CompareMenuExt {
+    /// 添加"选择用于比较"/"与已选比较"
+    ///
+    /// 仅当已选中的对象与当前条目同为文件或同为目录时显示"与已选比较"
+    fn compare_items(
+        self,
+        path: &str,
+        is_dir: bool,
+        lang: &Language,
+        entity: Entity<FileListView>,
+    ) -> Self;
+}
+
+impl CompareMenuExt for gpui_component::menu::PopupMenu {
+    fn compare_items(
+        self,
+        path: &str,
+        is_dir: bool,
+        lang: &Language,
+        entity: Entity<FileListView>,
+    ) -> Self {
+        let select_label = t(lang, "sftp.context_menu.compare_select").to_string();
+        let compare_label = t(lang, "sftp.context_menu.compare_with").to_string();
+        let can_compare = compare_base().is_some_and(|base| base.is_dir == is_dir);
+
+        let e_select = entity.clone();
+        let path_for_select = path.to_string();
+        let menu = self.item(menu_item_element(icons::COLUMNS, &select_label).on_click(
+            move |_, _, cx| {
+                e_select.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::CompareSelect(
+                        path_for_select.clone(),
+                        is_dir,
+                    ));
+                });
+            },
+        ));
+        if !can_compare {
+            return menu;
+        }
+
+        let path = path.to_string();
+        menu.item(
+            menu_item_element(icons::COLUMNS, &compare_label).on_click(move |_, _, cx| {
+                entity.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::CompareWith(path.clone(), is_dir));
+                });
+            }),
+        )
+    }
+}
+
 /// 构建空白区域右键菜单
 fn build_empty_area_context_menu(
     menu: gpui_component::menu::PopupMenu,
     lang: &Language,
+    trash_enabled: bool,
     entity: Entity<FileListView>,
 ) -> gpui_component::menu::PopupMenu {
     let refresh_label = t(lang, "sftp.context_menu.refresh").to_string();
     let new_folder_label = t(lang, "sftp.context_menu.new_folder").to_string();
     let new_file_label = t(lang, "sftp.context_menu.new_file").to_string();
+    let new_link_label = t(lang, "sftp.context_menu.new_link").to_string();
     let upload_file_label = t(lang, "sftp.context_menu.upload_file").to_string();
     let upload_folder_label = t(lang, "sftp.context_menu.upload_folder").to_string();
     let select_all_label = t(lang, "sftp.context_menu.select_all").to_string();
+    let sync_label = t(lang, "sftp.context_menu.sync_directory").to_string();
+    let empty_trash_label = t(lang, "sftp.context_menu.empty_trash").to_string();
 
     let e1 = entity.clone();
     let e2 = entity.clone();
@@ -1170,56 +2180,86 @@ fn build_empty_area_context_menu(
     let e4 = entity.clone();
     let e5 = entity.clone();
     let e6 = entity.clone();
-
-    menu.item(
-        menu_item_element(icons::REFRESH, &refresh_label).on_click(move |_, _, cx| {
-            e1.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::Refresh);
-            });
-        }),
-    )
-    .separator()
-    .item(
-        menu_item_element(icons::FOLDER_PLUS, &new_folder_label).on_click(move |_, _, cx| {
-            e2.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::NewFolder);
-            });
-        }),
-    )
-    .item(
-        menu_item_element(icons::FILE, &new_file_label).on_click(move |_, _, cx| {
-            e3.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::NewFile);
-            });
-        }),
-    )
-    .separator()
-    .item(
-        menu_item_element(icons::UPLOAD, &upload_file_label).on_click(move |_, _, cx| {
-            e4.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::UploadFile);
-            });
-        }),
-    )
-    .item(
-        menu_item_element(icons::UPLOAD, &upload_folder_label).on_click(move |_, _, cx| {
-            e5.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::UploadFolder);
-            });
-        }),
-    )
-    .separator()
-    .item(
-        menu_item_element(icons::CHECK, &select_all_label).on_click(move |_, _, cx| {
-            e6.update(cx, |_, cx| {
-                cx.emit(FileListContextMenuEvent::SelectAll);
-            });
-        }),
-    )
+    let e7 = entity.clone();
+    let e_new_link = entity.clone();
+
+    let menu = menu
+        .item(
+            menu_item_element(icons::REFRESH, &refresh_label).on_click(move |_, _, cx| {
+                e1.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::Refresh);
+                });
+            }),
+        )
+        .separator()
+        .item(
+            menu_item_element(icons::FOLDER_PLUS, &new_folder_label).on_click(move |_, _, cx| {
+                e2.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::NewFolder);
+                });
+            }),
+        )
+        .item(
+            menu_item_element(icons::FILE, &new_file_label).on_click(move |_, _, cx| {
+                e3.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::NewFile);
+                });
+            }),
+        )
+        .item(
+            menu_item_element(icons::LINK, &new_link_label).on_click(move |_, _, cx| {
+                e_new_link.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::NewLink(None));
+                });
+            }),
+        )
+        .separator()
+        .item(
+            menu_item_element(icons::UPLOAD, &upload_file_label).on_click(move |_, _, cx| {
+                e4.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::UploadFile);
+                });
+            }),
+        )
+        .item(
+            menu_item_element(icons::UPLOAD, &upload_folder_label).on_click(move |_, _, cx| {
+                e5.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::UploadFolder);
+                });
+            }),
+        )
+        .item(
+            menu_item_element(icons::TRANSFER, &sync_label).on_click(move |_, _, cx| {
+                e7.update(cx, |view, cx| {
+                    cx.emit(FileListContextMenuEvent::SyncDirectory(
+                        view.current_path.clone(),
+                    ));
+                });
+            }),
+        )
+        .separator()
+        .item(
+            menu_item_element(icons::CHECK, &select_all_label).on_click(move |_, _, cx| {
+                e6.update(cx, |view, cx| view.select_all(cx));
+            }),
+        );
+
+    // 启用远程回收站时提供清空入口
+    if trash_enabled {
+        menu.separator().item(
+            menu_item_element(icons::TRASH, &empty_trash_label).on_click(move |_, _, cx| {
+                entity.update(cx, |_, cx| {
+                    cx.emit(FileListContextMenuEvent::EmptyTrash);
+                });
+            }),
+        )
+    } else {
+        menu
+    }
 }
 
 /// 创建带图标的菜单项元素
-fn menu_item_element(icon: &str, label: &str) -> PopupMenuItem {
+pub(super) fn menu_item_element(icon: &str, label: &str) -> PopupMenuItem {
     let icon = icon.to_string();
     let label = label.to_string();
     PopupMenuItem::element(move |_window, cx| {
@@ -1237,3 +2277,34 @@ fn menu_item_element(icon: &str, label: &str) -> PopupMenuItem {
             )
     })
 }
+
+/// 鼠标松开的位置（窗口坐标）是否在窗口之外
+fn released_outside_window(position: Point<Pixels>, viewport: Size<Pixels>) -> bool {
+    position.x < px(0.)
+        || position.y < px(0.)
+        || position.x >= viewport.width
+        || position.y >= viewport.height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_released_outside_window() {
+        let viewport = size(px(800.), px(600.));
+        assert!(!released_outside_window(point(px(0.), px(0.)), viewport));
+        assert!(!released_outside_window(
+            point(px(400.), px(300.)),
+            viewport
+        ));
+        assert!(!released_outside_window(
+            point(px(799.), px(599.)),
+            viewport
+        ));
+        assert!(released_outside_window(point(px(-1.), px(300.)), viewport));
+        assert!(released_outside_window(point(px(400.), px(-20.)), viewport));
+        assert!(released_outside_window(point(px(800.), px(300.)), viewport));
+        assert!(released_outside_window(point(px(400.), px(650.)), viewport));
+    }
+}
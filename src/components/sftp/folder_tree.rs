@@ -10,6 +10,8 @@ use gpui_component::ActiveTheme;
 use crate::constants::icons;
 use crate::models::sftp::SftpState;
 
+use super::file_list::DraggedSftpEntry;
+
 /// 树节点缩进宽度
 const INDENT_WIDTH: f32 = 16.0;
 /// 树项高度
@@ -22,6 +24,8 @@ pub enum FolderTreeEvent {
     ToggleExpand(String),
     /// 选择目录（导航到）
     SelectDir(String),
+    /// 将远程条目拖放移动到目录
+    MoveInto { source: String, target_dir: String },
 }
 
 #[derive(Clone, Debug)]
@@ -79,16 +83,18 @@ impl FolderTreeViewState {
 }
 
 /// 渲染单个树节点
-fn render_tree_row<F1, F2>(
+fn render_tree_row<F1, F2, F3>(
     row: &FolderTreeRow,
     is_selected: bool,
     on_toggle: F1,
     on_select: F2,
+    on_move: F3,
     cx: &App,
 ) -> AnyElement
 where
     F1: Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
     F2: Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    F3: Fn(String, &mut App) + 'static,
 {
     let foreground = cx.theme().foreground;
     let muted = cx.theme().muted_foreground;
@@ -141,6 +147,16 @@ where
         .cursor_pointer()
         .hover(|s| s.bg(hover_bg))
         .on_mouse_down(MouseButton::Left, on_select)
+        // 接收拖动的远程条目，移动到此目录
+        .drag_over::<DraggedSftpEntry>(|this, _, _, cx| this.bg(cx.theme().info.opacity(0.3)))
+        .on_drop({
+            let target = row.path.clone();
+            move |dragged: &DraggedSftpEntry, _window, cx| {
+                if dragged.path != target {
+                    on_move(dragged.path.clone(), cx);
+                }
+            }
+        })
         .child(expand_icon)
         .child(folder_icon)
         .child(
@@ -157,6 +173,13 @@ where
         el = el.px_2();
     } else {
         el = el.pl(indent).pr_2();
+        // 根目录以外的节点可拖动
+        let dragged = DraggedSftpEntry {
+            path: row.path.clone(),
+            name: row.name.clone(),
+            is_dir: true,
+        };
+        el = el.on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()));
     }
 
     if is_selected {
@@ -235,8 +258,10 @@ where
                         let is_selected = row.path == current_path;
                         let on_toggle = on_event.clone();
                         let on_select = on_event.clone();
+                        let on_move = on_event.clone();
                         let toggle_path = row.path.clone();
                         let select_path = row.path.clone();
+                        let move_target = row.path.clone();
 
                         items.push(render_tree_row(
                             row,
@@ -247,6 +272,15 @@ where
                             move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
                                 on_select(FolderTreeEvent::SelectDir(select_path.clone()), cx);
                             },
+                            move |source: String, cx: &mut App| {
+                                on_move(
+                                    FolderTreeEvent::MoveInto {
+                                        source,
+                                        target_dir: move_target.clone(),
+                                    },
+                                    cx,
+                                );
+                            },
                             cx,
                         ));
                     }
@@ -271,6 +305,9 @@ where
         }
         None => {
             // 加载中或未连接
+            let lang = crate::services::storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or_default();
             div()
                 .size_full()
                 .bg(bg_color)
@@ -281,7 +318,7 @@ where
                     div()
                         .text_sm()
                         .text_color(muted_foreground)
-                        .child("正在加载..."),
+                        .child(crate::i18n::t(&lang, "common.loading")),
                 )
                 .into_any_element()
         }
@@ -0,0 +1,411 @@
+// 十六进制查看器对话框渲染
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use super::state::HexViewerDialogState;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::sftp::{ascii_char, offset_width, HexSearchMode, HEX_BYTES_PER_ROW};
+use crate::services::storage;
+
+/// 十六进制列中每个字节的宽度
+const HEX_CELL_WIDTH: f32 = 22.;
+/// ASCII 列中每个字符的宽度
+const ASCII_CELL_WIDTH: f32 = 8.;
+/// 每行的高度
+const ROW_HEIGHT: f32 = 20.;
+
+/// 渲染十六进制查看器对话框覆盖层
+/// `on_load` 在需要读取某一页时调用（参数为页起始偏移量），`on_search` 在点击"查找下一个"时调用
+pub fn render_hex_viewer_dialog_overlay<L, S>(
+    state: Entity<HexViewerDialogState>,
+    on_load: L,
+    on_search: S,
+    cx: &App,
+) -> impl IntoElement
+where
+    L: Fn(Entity<HexViewerDialogState>, u64, &mut App) + 'static,
+    S: Fn(Entity<HexViewerDialogState>, &mut App) + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let on_load = Rc::new(on_load);
+
+    let state_read = state.read(cx);
+    let error_message = state_read.error_message.clone();
+    let page_end = state_read.page_offset + state_read.data.len() as u64;
+    let width = offset_width(state_read.file_size);
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted = cx.theme().muted_foreground;
+    let danger = cx.theme().danger;
+
+    // 翻页按钮
+    let nav_targets = [
+        (
+            "hex-first-page",
+            "«",
+            (state_read.page_offset > 0).then_some(0),
+        ),
+        ("hex-prev-page", "‹", state_read.prev_page_offset()),
+        ("hex-next-page", "›", state_read.next_page_offset()),
+        (
+            "hex-last-page",
+            "»",
+            state_read
+                .next_page_offset()
+                .map(|_| state_read.last_page_offset()),
+        ),
+    ];
+    let nav_buttons = nav_targets.into_iter().map(|(id, label, target)| {
+        let state = state.clone();
+        let on_load = on_load.clone();
+        render_button(
+            id,
+            label.to_string(),
+            target.is_some() && !state_read.loading,
+            move |cx| {
+                if let Some(offset) = target {
+                    on_load(state.clone(), offset, cx);
+                }
+            },
+            cx,
+        )
+    });
+
+    // 跳转到偏移量
+    let go_button = {
+        let state = state.clone();
+        let on_load = on_load.clone();
+        render_button(
+            "hex-go-offset",
+            i18n::t(&lang, "sftp.hex.go").to_string(),
+            !state_read.loading,
+            move |cx| {
+                let target = state.update(cx, |s, cx| {
+                    let target = s.target_offset(cx);
+                    cx.notify();
+                    target
+                });
+                if let Some(offset) = target {
+                    on_load(state.clone(), crate::services::sftp::page_start(offset), cx);
+                }
+            },
+            cx,
+        )
+    };
+
+    let nav_row = div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .children(nav_buttons)
+        .child(
+            div()
+                .flex_1()
+                .text_xs()
+                .text_color(muted)
+                .font_family("monospace")
+                .child(format!(
+                    "{:0w$X} - {:0w$X}",
+                    state_read.page_offset,
+                    page_end.saturating_sub(1),
+                    w = width
+                )),
+        )
+        .child(
+            div()
+                .w(px(160.))
+                .when_some(state_read.offset_input.clone(), |el, input| {
+                    el.child(Input::new(&input))
+                }),
+        )
+        .child(go_button);
+
+    // 搜索
+    let search_mode = state_read.search_mode;
+    let mode_label = match search_mode {
+        HexSearchMode::Hex => i18n::t(&lang, "sftp.hex.mode_hex"),
+        HexSearchMode::Text => i18n::t(&lang, "sftp.hex.mode_text"),
+    };
+    let mode_button = {
+        let state = state.clone();
+        render_button(
+            "hex-search-mode",
+            mode_label.to_string(),
+            true,
+            move |cx| {
+                state.update(cx, |s, cx| {
+                    s.toggle_search_mode();
+                    cx.notify();
+                });
+            },
+            cx,
+        )
+    };
+    let search_button = {
+        let state = state.clone();
+        let label = if state_read.searching {
+            i18n::t(&lang, "sftp.hex.searching")
+        } else {
+            i18n::t(&lang, "sftp.hex.find_next")
+        };
+        render_button(
+            "hex-find-next",
+            label.to_string(),
+            !state_read.searching,
+            move |cx| on_search(state.clone(), cx),
+            cx,
+        )
+    };
+    let search_row = div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(mode_button)
+        .child(
+            div()
+                .flex_1()
+                .when_some(state_read.search_input.clone(), |el, input| {
+                    el.child(Input::new(&input))
+                }),
+        )
+        .child(search_button);
+
+    // 关闭按钮
+    let state_close = state.clone();
+    let close_button = div()
+        .id("hex-close-btn")
+        .px_4()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(cx.theme().secondary_hover))
+        .on_click(move |_, _, cx| {
+            state_close.update(cx, |s, cx| {
+                s.close();
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .text_sm()
+                .text_color(foreground)
+                .child(i18n::t(&lang, "common.close")),
+        );
+
+    div()
+        .id("hex-viewer-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(760.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_3()
+                // 标题
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(foreground)
+                                .child(i18n::t(&lang, "sftp.hex.title")),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .text_sm()
+                                .text_color(muted)
+                                .child(format!(
+                                    "{} · {} B (0x{:X})",
+                                    state_read.name, state_read.file_size, state_read.file_size
+                                )),
+                        ),
+                )
+                .child(nav_row)
+                .child(search_row)
+                .child(render_data(state_read, &lang, cx))
+                // 错误信息
+                .children(error_message.map(|msg| div().text_sm().text_color(danger).child(msg)))
+                // 底部按钮
+                .child(div().flex().justify_end().child(close_button)),
+        )
+}
+
+/// 渲染当前页的偏移量、十六进制与 ASCII 三栏
+fn render_data(state: &HexViewerDialogState, lang: &Language, cx: &App) -> AnyElement {
+    let muted = cx.theme().muted_foreground;
+    let border_color = cx.theme().border;
+
+    if state.data.is_empty() {
+        let hint = if state.loading {
+            i18n::t(lang, "sftp.loading")
+        } else {
+            i18n::t(lang, "sftp.hex.empty")
+        };
+        return div()
+            .h(px(360.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .text_sm()
+            .text_color(muted)
+            .child(hint)
+            .into_any_element();
+    }
+
+    let width = offset_width(state.file_size);
+    let header_cells = (0..HEX_BYTES_PER_ROW).map(|i| {
+        div()
+            .w(px(HEX_CELL_WIDTH))
+            .when(i == HEX_BYTES_PER_ROW / 2, |el| el.ml(px(8.)))
+            .child(format!("{:02X}", i))
+    });
+    let header = div()
+        .flex()
+        .h(px(ROW_HEIGHT))
+        .items_center()
+        .text_xs()
+        .font_family("monospace")
+        .text_color(muted)
+        .border_b_1()
+        .border_color(border_color)
+        .child(
+            div()
+                .w(px(ASCII_CELL_WIDTH * (width + 2) as f32))
+                .child("Offset"),
+        )
+        .children(header_cells)
+        .child(div().ml(px(12.)).child("ASCII"));
+
+    let rows: Rc<Vec<u8>> = Rc::new(state.data.clone());
+    let page_offset = state.page_offset;
+    let highlight = state.highlight;
+    let row_count = rows.len().div_ceil(HEX_BYTES_PER_ROW);
+    let foreground = cx.theme().foreground;
+    let highlight_bg = cx.theme().primary.opacity(0.35);
+
+    let list = uniform_list(
+        "hex-viewer-rows",
+        row_count,
+        move |range: std::ops::Range<usize>, _window, _cx| {
+            range
+                .map(|row_ix| {
+                    let start = row_ix * HEX_BYTES_PER_ROW;
+                    let end = (start + HEX_BYTES_PER_ROW).min(rows.len());
+                    let row_offset = page_offset + start as u64;
+                    let is_highlighted = |ix: usize| {
+                        let offset = page_offset + ix as u64;
+                        highlight.is_some_and(|(h, len)| offset >= h && offset < h + len as u64)
+                    };
+
+                    let hex_cells = (start..end).map(|ix| {
+                        div()
+                            .w(px(HEX_CELL_WIDTH))
+                            .when(ix - start == HEX_BYTES_PER_ROW / 2, |el| el.ml(px(8.)))
+                            .when(is_highlighted(ix), |el| el.bg(highlight_bg))
+                            .child(format!("{:02X}", rows[ix]))
+                    });
+                    // 不足一行时补齐宽度，保证 ASCII 栏对齐
+                    let padding = (HEX_BYTES_PER_ROW - (end - start)) as f32 * HEX_CELL_WIDTH
+                        + if end - start <= HEX_BYTES_PER_ROW / 2 {
+                            8.
+                        } else {
+                            0.
+                        };
+                    let ascii_cells = (start..end).map(|ix| {
+                        div()
+                            .w(px(ASCII_CELL_WIDTH))
+                            .when(is_highlighted(ix), |el| el.bg(highlight_bg))
+                            .child(ascii_char(rows[ix]).to_string())
+                    });
+
+                    div()
+                        .flex()
+                        .h(px(ROW_HEIGHT))
+                        .items_center()
+                        .text_xs()
+                        .font_family("monospace")
+                        .text_color(foreground)
+                        .child(
+                            div()
+                                .w(px(ASCII_CELL_WIDTH * (width + 2) as f32))
+                                .text_color(muted)
+                                .child(format!("{:0w$X}", row_offset, w = width)),
+                        )
+                        .children(hex_cells)
+                        .child(div().w(px(padding)))
+                        .child(div().ml(px(12.)).flex().children(ascii_cells))
+                })
+                .collect::<Vec<_>>()
+        },
+    )
+    .h(px(340.));
+
+    div()
+        .flex()
+        .flex_col()
+        .child(header)
+        .child(list)
+        .into_any_element()
+}
+
+/// 渲染工具栏按钮
+fn render_button(
+    id: &'static str,
+    label: String,
+    enabled: bool,
+    on_click: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    let button = div()
+        .id(id)
+        .px_3()
+        .py_1()
+        .rounded_md()
+        .bg(cx.theme().secondary)
+        .text_sm()
+        .text_color(cx.theme().foreground)
+        .child(label);
+    if enabled {
+        let hover_bg = cx.theme().secondary_hover;
+        button
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .on_click(move |_, _, cx| on_click(cx))
+    } else {
+        button.opacity(0.5)
+    }
+}
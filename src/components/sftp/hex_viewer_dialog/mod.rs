@@ -0,0 +1,7 @@
+// 十六进制查看器对话框模块
+
+mod dialog;
+mod state;
+
+pub use dialog::render_hex_viewer_dialog_overlay;
+pub use state::HexViewerDialogState;
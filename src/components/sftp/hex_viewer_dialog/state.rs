@@ -0,0 +1,231 @@
+// 十六进制查看器对话框状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tokio_util::sync::CancellationToken;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::sftp::{
+    page_start, parse_offset, parse_search_pattern, HexSearchMode, HEX_PAGE_BYTES,
+};
+use crate::services::storage;
+
+/// 十六进制查看器对话框状态
+#[derive(Default)]
+pub struct HexViewerDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 远程文件路径
+    pub path: String,
+    /// 文件名
+    pub name: String,
+    /// 文件大小
+    pub file_size: u64,
+    /// 当前页的起始偏移量
+    pub page_offset: u64,
+    /// 当前页的数据
+    pub data: Vec<u8>,
+    /// 是否正在读取
+    pub loading: bool,
+    /// 是否正在搜索
+    pub searching: bool,
+    /// 搜索内容的格式
+    pub search_mode: HexSearchMode,
+    /// 高亮的字节范围（搜索匹配或跳转位置）：起始偏移量和长度
+    pub highlight: Option<(u64, usize)>,
+    /// 偏移量输入框
+    pub offset_input: Option<Entity<InputState>>,
+    /// 搜索输入框
+    pub search_input: Option<Entity<InputState>>,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 读取请求序号，只接受最新一次请求的结果
+    load_seq: u64,
+    /// 搜索任务的取消令牌
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl HexViewerDialogState {
+    /// 打开对话框
+    pub fn open(&mut self, tab_id: String, path: String, name: String, file_size: u64) {
+        self.cancel_search();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.path = path;
+        self.name = name;
+        self.file_size = file_size;
+        self.page_offset = 0;
+        self.data.clear();
+        self.highlight = None;
+        self.error_message = None;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.cancel_search();
+        self.is_open = false;
+        self.tab_id.clear();
+        self.path.clear();
+        self.data.clear();
+        self.loading = false;
+        self.highlight = None;
+        self.error_message = None;
+    }
+
+    /// 确保输入框已创建
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        if self.offset_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.hex.offset_placeholder");
+            self.offset_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        if self.search_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.hex.search_placeholder");
+            self.search_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+    }
+
+    /// 最后一页的起始偏移量
+    pub fn last_page_offset(&self) -> u64 {
+        page_start(self.file_size.saturating_sub(1))
+    }
+
+    /// 上一页的起始偏移量
+    pub fn prev_page_offset(&self) -> Option<u64> {
+        (self.page_offset > 0).then(|| self.page_offset.saturating_sub(HEX_PAGE_BYTES as u64))
+    }
+
+    /// 下一页的起始偏移量
+    pub fn next_page_offset(&self) -> Option<u64> {
+        let next = self.page_offset + HEX_PAGE_BYTES as u64;
+        (next < self.file_size).then_some(next)
+    }
+
+    /// 开始读取一页，返回本次请求的序号
+    pub fn start_loading(&mut self) -> u64 {
+        self.load_seq += 1;
+        self.loading = true;
+        self.error_message = None;
+        self.load_seq
+    }
+
+    /// 读取完成，请求已过期时忽略
+    pub fn set_page(&mut self, seq: u64, offset: u64, result: Result<Vec<u8>, String>) {
+        if seq != self.load_seq {
+            return;
+        }
+        self.loading = false;
+        match result {
+            Ok(data) => {
+                self.page_offset = offset;
+                self.data = data;
+            }
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    /// 解析偏移量输入框，无效或超出文件大小时设置错误信息
+    pub fn target_offset(&mut self, cx: &App) -> Option<u64> {
+        let input = self
+            .offset_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string())
+            .unwrap_or_default();
+        match parse_offset(&input).filter(|&offset| offset < self.file_size) {
+            Some(offset) => {
+                self.highlight = Some((offset, 1));
+                self.error_message = None;
+                Some(offset)
+            }
+            None => {
+                self.error_message = Some(tr("sftp.hex.invalid_offset").to_string());
+                None
+            }
+        }
+    }
+
+    /// 切换搜索内容的格式
+    pub fn toggle_search_mode(&mut self) {
+        self.search_mode = match self.search_mode {
+            HexSearchMode::Hex => HexSearchMode::Text,
+            HexSearchMode::Text => HexSearchMode::Hex,
+        };
+    }
+
+    /// 解析搜索输入框，无效时设置错误信息
+    pub fn search_pattern(&mut self, cx: &App) -> Option<Vec<u8>> {
+        let input = self
+            .search_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string())
+            .unwrap_or_default();
+        let pattern = parse_search_pattern(self.search_mode, &input);
+        if pattern.is_none() {
+            self.error_message = Some(tr("sftp.hex.invalid_pattern").to_string());
+        }
+        pattern
+    }
+
+    /// 开始搜索，返回搜索起点和取消令牌
+    ///
+    /// 从上一次匹配之后继续查找（查找下一个），否则从当前页开头查找
+    pub fn start_search(&mut self) -> (u64, CancellationToken) {
+        self.cancel_search();
+        let token = CancellationToken::new();
+        self.cancellation_token = Some(token.clone());
+        self.searching = true;
+        self.error_message = None;
+        let start = self
+            .highlight
+            .map(|(offset, _)| offset + 1)
+            .unwrap_or(self.page_offset);
+        (start, token)
+    }
+
+    /// 搜索结束，找到时高亮匹配并返回所在页的起始偏移量
+    pub fn finish_search(
+        &mut self,
+        result: Result<Option<u64>, String>,
+        pattern_len: usize,
+    ) -> Option<u64> {
+        self.cancellation_token = None;
+        self.searching = false;
+        match result {
+            Ok(Some(offset)) => {
+                self.highlight = Some((offset, pattern_len));
+                Some(page_start(offset))
+            }
+            Ok(None) => {
+                self.error_message = Some(tr("sftp.hex.not_found").to_string());
+                None
+            }
+            Err(e) => {
+                self.error_message = Some(e);
+                None
+            }
+        }
+    }
+
+    /// 取消正在进行的搜索
+    pub fn cancel_search(&mut self) {
+        if let Some(token) = self.cancellation_token.take() {
+            token.cancel();
+        }
+        self.searching = false;
+    }
+}
+
+/// 按当前语言翻译
+fn tr(key: &'static str) -> &'static str {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    i18n::t(&lang, key)
+}
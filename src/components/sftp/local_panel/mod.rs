@@ -0,0 +1,7 @@
+// 本地文件面板（双面板模式下显示在远程文件列表旁）
+
+mod state;
+mod view;
+
+pub use state::{LocalPanelEvent, LocalPanelState};
+pub use view::{DraggedLocalEntry, SFTP_LOCAL_PANEL_CONTEXT};
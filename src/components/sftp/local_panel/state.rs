@@ -0,0 +1,226 @@
+// 本地文件面板状态
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use gpui::*;
+
+use crate::models::sftp::{FileEntry, FileType};
+
+/// 本地面板事件
+#[derive(Clone, Debug)]
+pub enum LocalPanelEvent {
+    /// 上传本地条目到远程当前目录（F5 复制 / F6 移动）
+    Upload {
+        paths: Vec<PathBuf>,
+        remove_source: bool,
+    },
+    /// 将远程条目下载到本地目录（从远程面板拖入）
+    Download {
+        remote_path: String,
+        target_dir: PathBuf,
+    },
+    /// 选择本地条目用于比较
+    CompareSelect { path: PathBuf, is_dir: bool },
+    /// 将本地条目与已选对象比较
+    CompareWith { path: PathBuf, is_dir: bool },
+}
+
+/// 本地文件面板状态
+pub struct LocalPanelState {
+    /// 当前本地目录
+    pub(super) current_path: PathBuf,
+    /// 当前目录的条目（目录在前）
+    pub(super) entries: Vec<FileEntry>,
+    /// 选中的条目路径
+    pub(super) selected: Option<String>,
+    /// 是否显示隐藏文件
+    pub(super) show_hidden: bool,
+    /// 读取目录的错误信息
+    pub(super) error: Option<String>,
+    /// 焦点句柄（用于 F5/F6 快捷键）
+    pub(super) focus_handle: FocusHandle,
+    /// 事件回调
+    pub(super) on_event: Rc<dyn Fn(LocalPanelEvent, &mut App)>,
+}
+
+impl LocalPanelState {
+    pub fn new<F>(cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(LocalPanelEvent, &mut App) + 'static,
+    {
+        let settings = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .sftp;
+        // 优先使用默认下载路径，否则使用用户主目录
+        let start_path = Some(settings.local_default_path)
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from)
+            .filter(|p| p.is_dir())
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("/"));
+
+        let mut state = Self {
+            current_path: PathBuf::new(),
+            entries: Vec::new(),
+            selected: None,
+            show_hidden: settings.show_hidden_files,
+            error: None,
+            focus_handle: cx.focus_handle(),
+            on_event: Rc::new(on_event),
+        };
+        state.load(start_path);
+        state
+    }
+
+    /// 当前本地目录
+    pub fn current_path(&self) -> &Path {
+        &self.current_path
+    }
+
+    /// 当前选中的条目
+    pub(super) fn selected_entry(&self) -> Option<&FileEntry> {
+        let selected = self.selected.as_deref()?;
+        self.entries.iter().find(|e| e.path == selected)
+    }
+
+    /// 读取目录内容
+    fn load(&mut self, path: PathBuf) {
+        match read_local_dir(&path, self.show_hidden) {
+            Ok(entries) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Err(e) => {
+                self.entries.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+        if self.current_path != path {
+            self.selected = None;
+        }
+        self.current_path = path;
+    }
+
+    /// 进入指定目录
+    pub fn navigate_to(&mut self, path: PathBuf, cx: &mut Context<Self>) {
+        self.load(path);
+        cx.notify();
+    }
+
+    /// 返回上级目录
+    pub fn go_up(&mut self, cx: &mut Context<Self>) {
+        if let Some(parent) = self.current_path.parent() {
+            let parent = parent.to_path_buf();
+            // 返回后选中原来所在的目录
+            let previous = self.current_path.to_string_lossy().to_string();
+            self.navigate_to(parent, cx);
+            self.selected = Some(previous);
+        }
+    }
+
+    /// 回到用户主目录
+    pub fn go_home(&mut self, cx: &mut Context<Self>) {
+        if let Some(home) = dirs::home_dir() {
+            self.navigate_to(home, cx);
+        }
+    }
+
+    /// 重新读取当前目录
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let path = self.current_path.clone();
+        self.load(path);
+        cx.notify();
+    }
+
+    /// 切换显示隐藏文件
+    pub(super) fn toggle_hidden(&mut self, cx: &mut Context<Self>) {
+        self.show_hidden = !self.show_hidden;
+        self.refresh(cx);
+    }
+
+    /// 选中条目
+    pub(super) fn select(&mut self, path: String, cx: &mut Context<Self>) {
+        self.selected = Some(path);
+        cx.notify();
+    }
+
+    /// 打开条目（目录则进入）
+    pub(super) fn open(&mut self, entry: &FileEntry, cx: &mut Context<Self>) {
+        if entry.is_dir() {
+            self.navigate_to(PathBuf::from(&entry.path), cx);
+        }
+    }
+
+    /// 将选中条目传输到远程面板
+    pub(super) fn transfer_selected(&mut self, remove_source: bool, cx: &mut Context<Self>) {
+        let Some(selected) = self.selected.clone() else {
+            return;
+        };
+        let on_event = self.on_event.clone();
+        on_event(
+            LocalPanelEvent::Upload {
+                paths: vec![PathBuf::from(selected)],
+                remove_source,
+            },
+            cx,
+        );
+    }
+
+    /// 远程条目拖放到本地目录
+    pub(super) fn drop_remote(
+        &mut self,
+        remote_path: String,
+        target_dir: PathBuf,
+        cx: &mut Context<Self>,
+    ) {
+        let on_event = self.on_event.clone();
+        on_event(
+            LocalPanelEvent::Download {
+                remote_path,
+                target_dir,
+            },
+            cx,
+        );
+    }
+}
+
+/// 读取本地目录，目录在前并按名称排序
+fn read_local_dir(path: &Path, show_hidden: bool) -> std::io::Result<Vec<FileEntry>> {
+    let mut entries = Vec::new();
+    for item in std::fs::read_dir(path)? {
+        let item = item?;
+        let name = item.file_name().to_string_lossy().to_string();
+        if !show_hidden && name.starts_with('.') {
+            continue;
+        }
+
+        // 符号链接按目标类型显示，便于直接进入链接的目录
+        let metadata = match std::fs::metadata(item.path()) {
+            Ok(m) => m,
+            Err(_) => match item.metadata() {
+                Ok(m) => m,
+                Err(_) => continue,
+            },
+        };
+        let file_type = if metadata.is_dir() {
+            FileType::Directory
+        } else if metadata.is_file() {
+            FileType::File
+        } else {
+            FileType::Other
+        };
+
+        let mut entry = FileEntry::new(name, item.path().to_string_lossy().to_string(), file_type);
+        entry.size = metadata.len();
+        entry.modified = metadata.modified().ok();
+        entries.push(entry);
+    }
+
+    entries.sort_by(|a, b| {
+        b.is_dir()
+            .cmp(&a.is_dir())
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    Ok(entries)
+}
@@ -0,0 +1,379 @@
+// 本地文件面板渲染
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use gpui::*;
+use gpui_component::menu::{ContextMenuExt, PopupMenu};
+use gpui_component::ActiveTheme;
+
+use super::state::{LocalPanelEvent, LocalPanelState};
+use crate::components::sftp::file_list::{
+    format_modified_time, get_file_icon, menu_item_element, CopyToOtherPane, DraggedSftpEntry,
+    MoveToOtherPane,
+};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::models::sftp::FileEntry;
+use crate::services::sftp::compare_base;
+
+/// 本地面板上下文名称
+pub const SFTP_LOCAL_PANEL_CONTEXT: &str = "SftpLocalPanel";
+
+/// 头部高度（与 SFTP 工具栏一致）
+const HEADER_HEIGHT: f32 = 32.0;
+/// 行高
+const ROW_HEIGHT: f32 = 24.0;
+/// 图标尺寸
+const ICON_SIZE: f32 = 14.0;
+
+/// 拖动中的本地条目（拖放到远程文件列表上传）
+#[derive(Clone, Debug)]
+pub struct DraggedLocalEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub is_dir: bool,
+}
+
+impl Render for DraggedLocalEntry {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let icon = if self.is_dir {
+            icons::FOLDER
+        } else {
+            icons::FILE
+        };
+        div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .px_2()
+            .py_1()
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(4.))
+            .shadow_md()
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(ICON_SIZE))
+                    .text_color(cx.theme().link),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(self.name.clone()),
+            )
+    }
+}
+
+impl LocalPanelState {
+    /// 渲染头部按钮
+    fn render_header_button(
+        &self,
+        id: &'static str,
+        icon: &'static str,
+        on_click: impl Fn(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let hover_bg = cx.theme().list_active;
+        div()
+            .id(id)
+            .size(px(24.))
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(ICON_SIZE))
+                    .text_color(cx.theme().foreground),
+            )
+            .on_click(cx.listener(move |this, _, _, cx| on_click(this, cx)))
+    }
+
+    /// 渲染单行
+    fn render_row(&self, ix: usize, entry: &FileEntry, cx: &mut Context<Self>) -> AnyElement {
+        let is_selected = self.selected.as_deref() == Some(entry.path.as_str());
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().table_hover;
+        let icon_color = if entry.is_dir() {
+            cx.theme().link
+        } else {
+            muted
+        };
+
+        let entry_for_click = entry.clone();
+        let dragged = DraggedLocalEntry {
+            path: PathBuf::from(&entry.path),
+            name: entry.name.clone(),
+            is_dir: entry.is_dir(),
+        };
+
+        let mut row = div()
+            .id(("sftp-local-row", ix))
+            .w_full()
+            .h(px(ROW_HEIGHT))
+            .px_2()
+            .flex()
+            .items_center()
+            .gap_2()
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+                window.focus(&this.focus_handle);
+                if event.click_count() >= 2 {
+                    this.open(&entry_for_click, cx);
+                } else {
+                    this.select(entry_for_click.path.clone(), cx);
+                }
+            }))
+            .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+            .child(
+                svg()
+                    .path(get_file_icon(entry))
+                    .size(px(ICON_SIZE))
+                    .flex_shrink_0()
+                    .text_color(icon_color),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(entry.name.clone()),
+            )
+            .child(
+                div()
+                    .w(px(64.))
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(entry.format_size()),
+            )
+            .child(
+                div()
+                    .w(px(110.))
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(format_modified_time(entry)),
+            );
+
+        // 目录行接收远程条目，下载到该目录
+        if entry.is_dir() {
+            let target_dir = PathBuf::from(&entry.path);
+            row = row
+                .drag_over::<DraggedSftpEntry>(|this, _, _, cx| {
+                    this.bg(cx.theme().info.opacity(0.3))
+                })
+                .on_drop(cx.listener(move |this, dragged: &DraggedSftpEntry, _, cx| {
+                    this.drop_remote(dragged.path.clone(), target_dir.clone(), cx);
+                }));
+        }
+
+        if is_selected {
+            row = row
+                .bg(cx.theme().table_active)
+                .border_1()
+                .border_color(cx.theme().table_active_border);
+        }
+
+        row.into_any_element()
+    }
+}
+
+impl Render for LocalPanelState {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let bg_color = crate::theme::sidebar_color(cx);
+        let border_color = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let hidden_icon = if self.show_hidden {
+            icons::EYE_OFF
+        } else {
+            icons::EYE
+        };
+
+        let header = div()
+            .w_full()
+            .h(px(HEADER_HEIGHT))
+            .flex_shrink_0()
+            .px_1()
+            .flex()
+            .items_center()
+            .gap_0p5()
+            .border_b_1()
+            .border_color(border_color)
+            .child(self.render_header_button(
+                "sftp-local-up",
+                icons::ARROW_UP,
+                |this, cx| this.go_up(cx),
+                cx,
+            ))
+            .child(self.render_header_button(
+                "sftp-local-home",
+                icons::HOME,
+                |this, cx| this.go_home(cx),
+                cx,
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .mx_2()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(self.current_path.to_string_lossy().to_string()),
+            )
+            .child(self.render_header_button(
+                "sftp-local-refresh",
+                icons::REFRESH,
+                |this, cx| this.refresh(cx),
+                cx,
+            ))
+            .child(self.render_header_button(
+                "sftp-local-hidden",
+                hidden_icon,
+                |this, cx| this.toggle_hidden(cx),
+                cx,
+            ));
+
+        let content: AnyElement = if let Some(error) = &self.error {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_xs()
+                .text_color(muted)
+                .child(error.clone())
+                .into_any_element()
+        } else {
+            uniform_list(
+                "sftp-local-list",
+                self.entries.len(),
+                cx.processor(|this, range: std::ops::Range<usize>, _window, cx| {
+                    range
+                        .map(|ix| this.render_row(ix, &this.entries[ix], cx))
+                        .collect::<Vec<_>>()
+                }),
+            )
+            .flex_1()
+            .min_h(px(0.))
+            .into_any_element()
+        };
+
+        let current_dir = self.current_path.clone();
+        let this = cx.entity().clone();
+
+        div()
+            .id("sftp-local-panel")
+            .key_context(SFTP_LOCAL_PANEL_CONTEXT)
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(bg_color)
+            .on_action(cx.listener(|this, _: &CopyToOtherPane, _, cx| {
+                this.transfer_selected(false, cx);
+            }))
+            .on_action(cx.listener(|this, _: &MoveToOtherPane, _, cx| {
+                this.transfer_selected(true, cx);
+            }))
+            // 远程条目拖放到面板空白处，下载到当前目录
+            .drag_over::<DraggedSftpEntry>(|this, _, _, cx| {
+                this.bg(cx.theme().drop_target)
+                    .border_2()
+                    .border_color(cx.theme().primary)
+            })
+            .on_drop(cx.listener(move |this, dragged: &DraggedSftpEntry, _, cx| {
+                this.drop_remote(dragged.path.clone(), current_dir.clone(), cx);
+            }))
+            .child(header)
+            .child(content)
+            .context_menu(move |menu, _window, cx| {
+                let panel = this.read(cx);
+                match panel.selected_entry() {
+                    Some(entry) => build_entry_context_menu(menu, entry, panel.on_event.clone()),
+                    None => {
+                        let this = this.clone();
+                        let lang = current_language();
+                        menu.item(
+                            menu_item_element(
+                                icons::REFRESH,
+                                t(&lang, "sftp.context_menu.refresh"),
+                            )
+                            .on_click(move |_, _, cx| {
+                                this.update(cx, |panel, cx| panel.refresh(cx));
+                            }),
+                        )
+                    }
+                }
+            })
+    }
+}
+
+/// 当前界面语言
+fn current_language() -> Language {
+    crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese)
+}
+
+/// 构建本地条目右键菜单（选择用于比较 / 与已选比较）
+fn build_entry_context_menu(
+    menu: PopupMenu,
+    entry: &FileEntry,
+    on_event: Rc<dyn Fn(LocalPanelEvent, &mut App)>,
+) -> PopupMenu {
+    let lang = current_language();
+    let path = PathBuf::from(&entry.path);
+    let is_dir = entry.is_dir();
+
+    let on_select = on_event.clone();
+    let path_for_select = path.clone();
+    let menu = menu.item(
+        menu_item_element(icons::COLUMNS, t(&lang, "sftp.context_menu.compare_select")).on_click(
+            move |_, _, cx| {
+                on_select(
+                    LocalPanelEvent::CompareSelect {
+                        path: path_for_select.clone(),
+                        is_dir,
+                    },
+                    cx,
+                );
+            },
+        ),
+    );
+
+    // 已选对象与当前条目同为文件或同为目录时才能比较
+    if !compare_base().is_some_and(|base| base.is_dir == is_dir) {
+        return menu;
+    }
+    menu.item(
+        menu_item_element(icons::COLUMNS, t(&lang, "sftp.context_menu.compare_with")).on_click(
+            move |_, _, cx| {
+                on_event(
+                    LocalPanelEvent::CompareWith {
+                        path: path.clone(),
+                        is_dir,
+                    },
+                    cx,
+                );
+            },
+        ),
+    )
+}
@@ -0,0 +1,5 @@
+// 远程日志查看器模块
+
+mod view;
+
+pub use view::{LogViewerEvent, LogViewerView};
@@ -0,0 +1,687 @@
+// 远程日志查看器视图
+// 打开时替换文件列表区域：通过 tail -F 持续读取远程日志，支持暂停、跟随到底部、过滤、关键词高亮，并按级别着色
+
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::services::sftp::{detect_severity, find_matches, LogSeverity, COMMON_LOG_PATHS};
+
+/// 最多保留的日志行数（超出后丢弃最早的行）
+const MAX_LINES: usize = 5000;
+/// 日志行高度
+const ROW_HEIGHT: f32 = 18.;
+
+/// 日志查看器事件
+#[derive(Clone, Debug)]
+pub enum LogViewerEvent {
+    /// 开始跟踪日志文件（会停止之前的跟踪）
+    Tail(String),
+    /// 停止跟踪
+    Stop,
+}
+
+/// 一行日志
+#[derive(Clone)]
+struct LogLine {
+    text: SharedString,
+    severity: LogSeverity,
+}
+
+/// 远程日志查看器视图
+pub struct LogViewerView {
+    /// 是否显示
+    is_open: bool,
+    /// 正在跟踪的文件
+    path: String,
+    /// 是否正在编辑路径
+    editing_path: bool,
+    path_input: Entity<InputState>,
+    filter_input: Entity<InputState>,
+    /// 过滤条件（ASCII 小写）
+    filter: String,
+    keyword_input: Entity<InputState>,
+    /// 高亮关键词（保存在 SFTP 设置中）
+    keywords: Vec<String>,
+    lines: VecDeque<LogLine>,
+    /// 暂停期间收到的行（恢复时一并显示）
+    paused_lines: Vec<LogLine>,
+    paused: bool,
+    /// 有新内容时自动滚动到底部
+    follow: bool,
+    /// 是否正在跟踪
+    streaming: bool,
+    /// 跟踪结束的原因（连接断开、命令失败等）
+    error: Option<String>,
+    scroll_handle: UniformListScrollHandle,
+    on_event: Rc<dyn Fn(LogViewerEvent, &mut App)>,
+}
+
+impl LogViewerView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(LogViewerEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        let path_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "sftp.log_viewer.path_placeholder"))
+        });
+        cx.subscribe(
+            &path_input,
+            |this, input, event: &InputEvent, cx| match event {
+                InputEvent::PressEnter { .. } => {
+                    let path = input.read(cx).value().trim().to_string();
+                    this.editing_path = false;
+                    if !path.is_empty() {
+                        this.tail(path, cx);
+                    }
+                    cx.notify();
+                }
+                InputEvent::Blur => {
+                    this.editing_path = false;
+                    cx.notify();
+                }
+                _ => {}
+            },
+        )
+        .detach();
+
+        // 输入过滤条件时即时过滤
+        let filter_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "sftp.log_viewer.filter_placeholder"))
+        });
+        cx.subscribe(&filter_input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                this.filter = input.read(cx).value().trim().to_ascii_lowercase();
+                cx.notify();
+            }
+        })
+        .detach();
+
+        // 回车添加高亮关键词
+        let keyword_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "sftp.log_viewer.keyword_placeholder"))
+        });
+        cx.subscribe_in(
+            &keyword_input,
+            window,
+            |this, input, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    let keyword = input.read(cx).value().trim().to_string();
+                    if !keyword.is_empty() && !this.keywords.contains(&keyword) {
+                        this.keywords.push(keyword);
+                        this.save_keywords();
+                    }
+                    input.update(cx, |s, cx| s.set_value("", window, cx));
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
+        let keywords = crate::services::storage::load_settings()
+            .map(|s| s.sftp.log_highlight_keywords)
+            .unwrap_or_default();
+
+        Self {
+            is_open: false,
+            path: String::new(),
+            editing_path: false,
+            path_input,
+            filter_input,
+            filter: String::new(),
+            keyword_input,
+            keywords,
+            lines: VecDeque::new(),
+            paused_lines: Vec::new(),
+            paused: false,
+            follow: true,
+            streaming: false,
+            error: None,
+            scroll_handle: UniformListScrollHandle::new(),
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 是否显示
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// 打开查看器；指定路径时清空旧内容并返回需要跟踪的路径（由调用方开始跟踪）
+    pub fn open(&mut self, path: Option<String>, cx: &mut Context<Self>) -> Option<String> {
+        self.is_open = true;
+        if let Some(path) = &path {
+            self.reset(path.clone());
+        }
+        cx.notify();
+        path
+    }
+
+    /// 切换到新的日志文件并清空之前的内容
+    fn reset(&mut self, path: String) {
+        self.path = path;
+        self.lines.clear();
+        self.paused_lines.clear();
+        self.paused = false;
+        self.error = None;
+        self.streaming = true;
+    }
+
+    /// 开始跟踪日志文件
+    fn tail(&mut self, path: String, cx: &mut Context<Self>) {
+        self.reset(path.clone());
+        let on_event = self.on_event.clone();
+        on_event(LogViewerEvent::Tail(path), cx);
+        cx.notify();
+    }
+
+    /// 追加新读取的日志行（暂停时先缓存）
+    pub fn append_lines(&mut self, lines: Vec<String>, cx: &mut Context<Self>) {
+        let lines = lines.into_iter().map(|text| LogLine {
+            severity: detect_severity(&text),
+            text: text.into(),
+        });
+        if self.paused {
+            self.paused_lines.extend(lines);
+            // 暂停太久时只保留最近的内容
+            if self.paused_lines.len() > MAX_LINES {
+                let excess = self.paused_lines.len() - MAX_LINES;
+                self.paused_lines.drain(..excess);
+            }
+            cx.notify();
+            return;
+        }
+        self.lines.extend(lines);
+        self.trim_and_follow();
+        cx.notify();
+    }
+
+    /// 跟踪结束
+    pub fn finish(&mut self, result: Result<(), String>, cx: &mut Context<Self>) {
+        self.streaming = false;
+        self.error = result.err();
+        cx.notify();
+    }
+
+    /// 丢弃超出上限的旧行，跟随模式下滚动到最后一行
+    fn trim_and_follow(&mut self) {
+        while self.lines.len() > MAX_LINES {
+            self.lines.pop_front();
+        }
+        if self.follow && self.filter.is_empty() && !self.lines.is_empty() {
+            self.scroll_handle
+                .scroll_to_item(self.lines.len() - 1, ScrollStrategy::Top);
+        }
+    }
+
+    /// 暂停或继续显示新内容（暂停期间仍在读取）
+    fn toggle_pause(&mut self, cx: &mut Context<Self>) {
+        self.paused = !self.paused;
+        if !self.paused {
+            let pending = std::mem::take(&mut self.paused_lines);
+            self.lines.extend(pending);
+            self.trim_and_follow();
+        }
+        cx.notify();
+    }
+
+    /// 开启或关闭跟随到底部
+    fn toggle_follow(&mut self, cx: &mut Context<Self>) {
+        self.follow = !self.follow;
+        self.trim_and_follow();
+        cx.notify();
+    }
+
+    /// 清空已显示的内容
+    fn clear(&mut self, cx: &mut Context<Self>) {
+        self.lines.clear();
+        self.paused_lines.clear();
+        cx.notify();
+    }
+
+    /// 进入路径编辑模式
+    fn start_edit_path(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.editing_path = true;
+        let path = self.path.clone();
+        self.path_input.update(cx, |input, cx| {
+            input.set_value(&path, window, cx);
+            input.focus(window, cx);
+        });
+        cx.notify();
+    }
+
+    /// 删除高亮关键词
+    fn remove_keyword(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index < self.keywords.len() {
+            self.keywords.remove(index);
+            self.save_keywords();
+            cx.notify();
+        }
+    }
+
+    /// 保存高亮关键词到 SFTP 设置
+    fn save_keywords(&self) {
+        let mut settings = crate::services::storage::load_settings().unwrap_or_default();
+        settings.sftp.log_highlight_keywords = self.keywords.clone();
+        if let Err(e) = crate::services::storage::save_settings(&settings) {
+            tracing::error!("[LogViewer] Failed to save highlight keywords: {}", e);
+        }
+    }
+
+    /// 关闭查看器并停止跟踪
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.is_open = false;
+        self.streaming = false;
+        self.lines.clear();
+        self.paused_lines.clear();
+        self.paused = false;
+        self.error = None;
+        let on_event = self.on_event.clone();
+        on_event(LogViewerEvent::Stop, cx);
+        cx.notify();
+    }
+
+    /// 渲染头部图标按钮
+    fn render_icon_button(
+        &self,
+        id: &'static str,
+        icon: &'static str,
+        tooltip: &'static str,
+        active: bool,
+        on_click: impl Fn(&mut Self, &mut Context<Self>) + 'static,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let hover_bg = cx.theme().list_active;
+        div()
+            .id(id)
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .when(active, |this| this.bg(hover_bg))
+            .hover(move |s| s.bg(hover_bg))
+            .child(
+                svg()
+                    .path(icon)
+                    .size(px(14.))
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .tooltip(move |window, cx| Tooltip::new(tooltip).build(window, cx))
+            .on_click(cx.listener(move |this, _, _, cx| on_click(this, cx)))
+    }
+
+    /// 渲染常用日志路径下拉按钮
+    fn render_common_paths_button(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let entity = cx.entity().clone();
+
+        Button::new("log-viewer-common-paths")
+            .ghost()
+            .xsmall()
+            .tooltip(t(&lang, "sftp.log_viewer.common_paths"))
+            .child(
+                svg()
+                    .path(icons::FILE_TEXT)
+                    .size(px(14.))
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                let mut menu = menu.min_w(px(220.));
+                for path in COMMON_LOG_PATHS {
+                    let entity = entity.clone();
+                    menu = menu.item(PopupMenuItem::new(path).on_click(move |_, _, cx| {
+                        entity.update(cx, |view, cx| view.tail(path.to_string(), cx));
+                    }));
+                }
+                menu
+            })
+    }
+
+    /// 渲染高亮关键词标签（点击删除）
+    fn render_keyword_chip(
+        &self,
+        index: usize,
+        keyword: &str,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let color = cx.theme().warning;
+        div()
+            .id(("log-viewer-keyword", index))
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_0p5()
+            .px_1()
+            .rounded(px(4.))
+            .bg(color.opacity(0.2))
+            .text_xs()
+            .text_color(cx.theme().foreground)
+            .cursor_pointer()
+            .hover(|s| s.opacity(0.8))
+            .child(keyword.to_string())
+            .child(svg().path(icons::X).size(px(10.)).text_color(color))
+            .on_click(cx.listener(move |this, _, _, cx| this.remove_keyword(index, cx)))
+    }
+}
+
+/// 计算一行中需要高亮的区间（过滤词优先，重叠的区间只保留先出现的）
+fn line_highlights(
+    text: &str,
+    filter: &str,
+    keywords: &[String],
+    filter_style: HighlightStyle,
+    keyword_style: HighlightStyle,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    let mut ranges: Vec<(Range<usize>, HighlightStyle)> = find_matches(text, filter)
+        .into_iter()
+        .map(|range| (range, filter_style))
+        .collect();
+    for keyword in keywords {
+        ranges.extend(
+            find_matches(text, &keyword.to_ascii_lowercase())
+                .into_iter()
+                .map(|range| (range, keyword_style)),
+        );
+    }
+    ranges.sort_by_key(|(range, _)| range.start);
+    let mut merged: Vec<(Range<usize>, HighlightStyle)> = Vec::with_capacity(ranges.len());
+    for (range, style) in ranges {
+        if merged
+            .last()
+            .is_some_and(|(last, _)| range.start < last.end)
+        {
+            continue;
+        }
+        merged.push((range, style));
+    }
+    merged
+}
+
+impl Render for LogViewerView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+        let border_color = cx.theme().border;
+
+        // 路径：编辑时显示输入框，否则显示当前路径（点击编辑）
+        let path_area = if self.editing_path {
+            div()
+                .flex_1()
+                .min_w_0()
+                .child(Input::new(&self.path_input).xsmall())
+                .into_any_element()
+        } else {
+            div()
+                .id("log-viewer-path")
+                .flex_1()
+                .min_w_0()
+                .px_1()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .text_xs()
+                .cursor_text()
+                .text_color(if self.path.is_empty() {
+                    muted
+                } else {
+                    cx.theme().foreground
+                })
+                .child(if self.path.is_empty() {
+                    t(&lang, "sftp.log_viewer.choose_path").to_string()
+                } else {
+                    self.path.clone()
+                })
+                .on_click(cx.listener(|this, _, window, cx| this.start_edit_path(window, cx)))
+                .into_any_element()
+        };
+
+        let header = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(border_color)
+            .child(
+                svg()
+                    .path(icons::FILE_TEXT)
+                    .size(px(14.))
+                    .flex_shrink_0()
+                    .text_color(muted),
+            )
+            .child(path_area)
+            .child(self.render_common_paths_button(cx))
+            .child(self.render_icon_button(
+                "log-viewer-pause",
+                if self.paused {
+                    icons::PLAY
+                } else {
+                    icons::PAUSE
+                },
+                if self.paused {
+                    t(&lang, "sftp.log_viewer.resume")
+                } else {
+                    t(&lang, "sftp.log_viewer.pause")
+                },
+                false,
+                |this, cx| this.toggle_pause(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "log-viewer-follow",
+                icons::CHEVRON_DOWN,
+                t(&lang, "sftp.log_viewer.follow"),
+                self.follow,
+                |this, cx| this.toggle_follow(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "log-viewer-clear",
+                icons::TRASH,
+                t(&lang, "sftp.log_viewer.clear"),
+                false,
+                |this, cx| this.clear(cx),
+                cx,
+            ))
+            .child(self.render_icon_button(
+                "log-viewer-close",
+                icons::X,
+                t(&lang, "sftp.log_viewer.close"),
+                false,
+                |this, cx| this.close(cx),
+                cx,
+            ));
+
+        let keyword_chips: Vec<AnyElement> = self
+            .keywords
+            .clone()
+            .iter()
+            .enumerate()
+            .map(|(ix, keyword)| self.render_keyword_chip(ix, keyword, cx).into_any_element())
+            .collect();
+        let filter_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(border_color)
+            .child(
+                div().flex_1().min_w_0().child(
+                    Input::new(&self.filter_input)
+                        .xsmall()
+                        .cleanable(true)
+                        .prefix(svg().path(icons::FILTER).size(px(12.)).text_color(muted)),
+                ),
+            )
+            .child(
+                div()
+                    .w(px(140.))
+                    .flex_shrink_0()
+                    .child(Input::new(&self.keyword_input).xsmall()),
+            )
+            .children(keyword_chips);
+
+        // 过滤后的行
+        let filter = self.filter.clone();
+        let visible: Rc<Vec<LogLine>> = Rc::new(
+            self.lines
+                .iter()
+                .filter(|line| filter.is_empty() || !find_matches(&line.text, &filter).is_empty())
+                .cloned()
+                .collect(),
+        );
+        let keywords = Rc::new(self.keywords.clone());
+        let foreground = cx.theme().foreground;
+        let danger = cx.theme().danger;
+        let warning = cx.theme().warning;
+        let filter_style = HighlightStyle {
+            background_color: Some(cx.theme().primary.opacity(0.35)),
+            ..Default::default()
+        };
+        let keyword_style = HighlightStyle {
+            background_color: Some(warning.opacity(0.35)),
+            ..Default::default()
+        };
+
+        let body = if visible.is_empty() {
+            let hint = if self.path.is_empty() {
+                t(&lang, "sftp.log_viewer.choose_path")
+            } else if self.lines.is_empty() {
+                t(&lang, "sftp.log_viewer.waiting")
+            } else {
+                t(&lang, "sftp.log_viewer.no_match")
+            };
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()
+        } else {
+            let rows = visible.clone();
+            uniform_list(
+                "log-viewer-lines",
+                visible.len(),
+                move |range: Range<usize>, _window, _cx| {
+                    range
+                        .map(|ix| {
+                            let line = &rows[ix];
+                            let color = match line.severity {
+                                LogSeverity::Error => danger,
+                                LogSeverity::Warning => warning,
+                                LogSeverity::Debug => muted,
+                                LogSeverity::Info | LogSeverity::Plain => foreground,
+                            };
+                            let highlights = line_highlights(
+                                &line.text,
+                                &filter,
+                                &keywords,
+                                filter_style,
+                                keyword_style,
+                            );
+                            div()
+                                .h(px(ROW_HEIGHT))
+                                .px_2()
+                                .flex()
+                                .items_center()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_xs()
+                                .font_family("monospace")
+                                .text_color(color)
+                                .child(
+                                    StyledText::new(line.text.clone()).with_highlights(highlights),
+                                )
+                                .into_any_element()
+                        })
+                        .collect::<Vec<_>>()
+                },
+            )
+            .track_scroll(self.scroll_handle.clone())
+            .flex_1()
+            .min_h(px(0.))
+            .into_any_element()
+        };
+
+        // 状态栏：行数、暂停/跟踪状态或结束原因
+        let status = if self.paused {
+            t(&lang, "sftp.log_viewer.paused").replace("{}", &self.paused_lines.len().to_string())
+        } else if let Some(error) = &self.error {
+            format!("{}: {}", t(&lang, "sftp.log_viewer.stopped"), error)
+        } else if self.streaming {
+            t(&lang, "sftp.log_viewer.streaming").to_string()
+        } else {
+            t(&lang, "sftp.log_viewer.stopped").to_string()
+        };
+        let count_text = if visible.len() == self.lines.len() {
+            t(&lang, "sftp.log_viewer.count").replace("{}", &self.lines.len().to_string())
+        } else {
+            format!("{} / {}", visible.len(), self.lines.len())
+        };
+        let status_color = if self.error.is_some() { danger } else { muted };
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(header)
+            .child(filter_bar)
+            .child(body)
+            .child(
+                div()
+                    .w_full()
+                    .flex_shrink_0()
+                    .px_2()
+                    .py_0p5()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .border_t_1()
+                    .border_color(border_color)
+                    .text_xs()
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .text_color(status_color)
+                            .child(status),
+                    )
+                    .child(div().flex_shrink_0().text_color(muted).child(count_text)),
+            )
+    }
+}
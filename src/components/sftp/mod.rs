@@ -1,19 +1,82 @@
 // SFTP 文件管理组件模块
 
+pub mod compare_dialog;
+pub mod conflict_dialog;
+pub mod copy_to_server_dialog;
+pub mod disk_usage;
 pub mod editor;
+pub mod empty_trash_dialog;
 pub mod file_list;
 pub mod folder_tree;
+pub mod hex_viewer_dialog;
+pub mod local_panel;
+pub mod log_viewer;
 pub mod new_file_dialog;
 pub mod new_folder_dialog;
+pub mod new_link_dialog;
+pub mod overwrite_dialog;
 pub mod path_bar;
+pub mod preview_pane;
 pub mod properties_dialog;
+pub mod search_view;
+pub mod sudo_password_dialog;
+pub mod sync_dialog;
 pub mod toolbar;
 pub mod view;
 
-pub use file_list::{FileListContextMenuEvent, FileListView};
+pub use compare_dialog::{render_compare_dialog_overlay, CompareContent, CompareDialogState};
+pub use conflict_dialog::{
+    render_conflict_dialog_overlay, ConflictDialogState, ConflictFileInfo, ConflictRequest,
+};
+pub use copy_to_server_dialog::{
+    render_copy_to_server_dialog_overlay, CopyTarget, CopyToServerDialogState,
+};
+pub use disk_usage::{DiskUsageEvent, DiskUsageView};
+pub use empty_trash_dialog::{render_empty_trash_dialog_overlay, EmptyTrashDialogState};
+pub use file_list::{
+    CopyToOtherPane, DraggedSftpEntry, FileListContextMenuEvent, FileListView, GoToPath,
+    MoveToOtherPane, RenameSelected, SFTP_FILE_LIST_CONTEXT,
+};
 pub use folder_tree::{render_folder_tree, FolderTreeEvent};
+pub use hex_viewer_dialog::{render_hex_viewer_dialog_overlay, HexViewerDialogState};
+pub use local_panel::{LocalPanelEvent, LocalPanelState, SFTP_LOCAL_PANEL_CONTEXT};
+pub use log_viewer::{LogViewerEvent, LogViewerView};
 pub use new_file_dialog::{render_new_file_dialog_overlay, NewFileDialogState};
 pub use new_folder_dialog::{render_new_folder_dialog_overlay, NewFolderDialogState};
+pub use new_link_dialog::{render_new_link_dialog_overlay, NewLinkDialogState};
+pub use overwrite_dialog::{render_overwrite_dialog_overlay, OverwriteDialogState};
 pub use path_bar::{PathBarEvent, PathBarState};
+pub use preview_pane::{render_preview_pane, SftpPreview};
 pub use properties_dialog::{render_properties_dialog_overlay, PropertiesDialogState};
+pub use search_view::{SftpSearchEvent, SftpSearchState};
+pub use sudo_password_dialog::{render_sudo_password_dialog_overlay, SudoPasswordDialogState};
+pub use sync_dialog::{render_sync_dialog_overlay, SyncDialogState};
 pub use toolbar::{render_sftp_toolbar, SftpToolbarEvent};
+
+use gpui::{App, KeyBinding};
+
+/// 初始化 SFTP 组件（注册文件列表与本地面板上下文的按键绑定）
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("f2", RenameSelected, Some(SFTP_FILE_LIST_CONTEXT)),
+        KeyBinding::new("f5", CopyToOtherPane, Some(SFTP_FILE_LIST_CONTEXT)),
+        KeyBinding::new("f6", MoveToOtherPane, Some(SFTP_FILE_LIST_CONTEXT)),
+        KeyBinding::new("f5", CopyToOtherPane, Some(SFTP_LOCAL_PANEL_CONTEXT)),
+        KeyBinding::new("f6", MoveToOtherPane, Some(SFTP_LOCAL_PANEL_CONTEXT)),
+    ]);
+
+    // 前往路径
+    #[cfg(target_os = "macos")]
+    cx.bind_keys([KeyBinding::new(
+        "cmd-shift-g",
+        GoToPath,
+        Some(SFTP_FILE_LIST_CONTEXT),
+    )]);
+
+    #[cfg(not(target_os = "macos"))]
+    cx.bind_keys([KeyBinding::new(
+        "ctrl-shift-g",
+        GoToPath,
+        Some(SFTP_FILE_LIST_CONTEXT),
+    )]);
+}
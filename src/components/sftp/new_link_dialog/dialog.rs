@@ -0,0 +1,397 @@
+// 新建链接对话框渲染
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use super::state::NewLinkDialogState;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::sftp::state::get_parent_path;
+use crate::services::sftp::LinkKind;
+use crate::services::storage;
+
+/// 链接类型选项
+const KIND_OPTIONS: [(LinkKind, &str); 2] = [
+    (LinkKind::Symbolic, "sftp.new_link.kind_symbolic"),
+    (LinkKind::Hard, "sftp.new_link.kind_hard"),
+];
+
+/// 渲染新建链接对话框覆盖层
+/// `on_browse` 在目标选择器需要读取某个目录时调用，`on_create` 在点击"确定"且校验通过时调用
+pub fn render_new_link_dialog_overlay<B, C>(
+    state: Entity<NewLinkDialogState>,
+    on_browse: B,
+    on_create: C,
+    cx: &App,
+) -> impl IntoElement
+where
+    B: Fn(Entity<NewLinkDialogState>, String, &mut App) + 'static,
+    C: Fn(Entity<NewLinkDialogState>, &mut App) + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let on_browse = Rc::new(on_browse);
+
+    let state_read = state.read(cx);
+    let kind = state_read.kind;
+    let name_input = state_read.name_input.clone();
+    let target_input = state_read.target_input.clone();
+    let error_message = state_read.error_message.clone();
+    let is_creating = state_read.is_creating;
+    let browse_dir = state_read.browse_dir.clone();
+    let browse_loading = state_read.browse_loading;
+    let current_target = state_read.target(cx);
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let danger = cx.theme().danger;
+    let hover_bg = cx.theme().list_hover;
+    let active_bg = cx.theme().list_active;
+    let link_color = cx.theme().link;
+
+    // 链接类型切换
+    let kind_buttons: Vec<AnyElement> = KIND_OPTIONS
+        .iter()
+        .map(|&(option, key)| {
+            let state = state.clone();
+            let is_active = kind == option;
+            div()
+                .id(key)
+                .px_3()
+                .py_1()
+                .rounded_md()
+                .text_sm()
+                .cursor_pointer()
+                .when(is_active, |this| this.bg(active_bg))
+                .hover(move |s| s.bg(hover_bg))
+                .text_color(if is_active {
+                    foreground
+                } else {
+                    muted_foreground
+                })
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        s.set_kind(option);
+                        cx.notify();
+                    });
+                })
+                .child(i18n::t(&lang, key))
+                .into_any_element()
+        })
+        .collect();
+
+    // 目标选择器：单击选中为目标，点击目录右侧箭头进入该目录
+    let browse_rows: Vec<AnyElement> = state_read
+        .browse_entries
+        .iter()
+        .enumerate()
+        .map(|(ix, entry)| {
+            let is_dir = entry.is_dir();
+            let is_selected = current_target == entry.path;
+            let path = entry.path.clone();
+            let state_pick = state.clone();
+            div()
+                .id(("link-target-entry", ix))
+                .px_2()
+                .py_1()
+                .flex()
+                .items_center()
+                .gap_2()
+                .rounded_md()
+                .cursor_pointer()
+                .when(is_selected, |this| this.bg(active_bg))
+                .hover(move |s| s.bg(hover_bg))
+                .on_click({
+                    let path = path.clone();
+                    move |_, _, cx| {
+                        state_pick.update(cx, |s, cx| {
+                            s.pick_target(path.clone());
+                            cx.notify();
+                        });
+                    }
+                })
+                .child(
+                    svg()
+                        .path(if is_dir { icons::FOLDER } else { icons::FILE })
+                        .size(px(14.))
+                        .text_color(if is_dir { link_color } else { muted_foreground }),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .text_sm()
+                        .text_color(foreground)
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(entry.name.clone()),
+                )
+                .when(is_dir, |this| {
+                    let state = state.clone();
+                    let on_browse = on_browse.clone();
+                    this.child(
+                        div()
+                            .id(("link-target-enter", ix))
+                            .p_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(move |s| s.bg(hover_bg))
+                            .on_click(move |_, _, cx| {
+                                cx.stop_propagation();
+                                on_browse(state.clone(), path.clone(), cx);
+                            })
+                            .child(
+                                svg()
+                                    .path(icons::CHEVRON_RIGHT)
+                                    .size(px(14.))
+                                    .text_color(muted_foreground),
+                            ),
+                    )
+                })
+                .into_any_element()
+        })
+        .collect();
+
+    let browse_status = if browse_loading {
+        Some(i18n::t(&lang, "common.loading"))
+    } else if browse_rows.is_empty() {
+        Some(i18n::t(&lang, "sftp.new_link.browse_empty"))
+    } else {
+        None
+    };
+
+    let state_up = state.clone();
+    let on_browse_up = on_browse.clone();
+    let parent_dir = get_parent_path(&browse_dir);
+    let can_go_up = browse_dir != "/";
+
+    let state_cancel = state.clone();
+    let state_create = state.clone();
+
+    let input_or_loading = |input: Option<Entity<gpui_component::input::InputState>>| {
+        if let Some(input) = &input {
+            Input::new(input).into_any_element()
+        } else {
+            div()
+                .text_sm()
+                .text_color(muted_foreground)
+                .child(i18n::t(&lang, "common.loading"))
+                .into_any_element()
+        }
+    };
+
+    div()
+        .id("new-link-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(460.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sftp.new_link.title")),
+                )
+                // 链接类型
+                .child(div().flex().gap_2().children(kind_buttons))
+                // 链接名称
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "sftp.new_link.name")),
+                        )
+                        .child(input_or_loading(name_input)),
+                )
+                // 链接目标
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, "sftp.new_link.target")),
+                        )
+                        .child(input_or_loading(target_input))
+                        // 远程目标选择器
+                        .child(
+                            div()
+                                .border_1()
+                                .border_color(border_color)
+                                .rounded_md()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .px_2()
+                                        .py_1()
+                                        .flex()
+                                        .items_center()
+                                        .gap_2()
+                                        .border_b_1()
+                                        .border_color(border_color)
+                                        .child(
+                                            div()
+                                                .id("link-target-up")
+                                                .p_1()
+                                                .rounded_md()
+                                                .when(can_go_up, |this| {
+                                                    this.cursor_pointer()
+                                                        .hover(move |s| s.bg(hover_bg))
+                                                        .on_click(move |_, _, cx| {
+                                                            on_browse_up(
+                                                                state_up.clone(),
+                                                                parent_dir.clone(),
+                                                                cx,
+                                                            );
+                                                        })
+                                                })
+                                                .when(!can_go_up, |this| this.opacity(0.4))
+                                                .child(
+                                                    svg()
+                                                        .path(icons::ARROW_UP)
+                                                        .size(px(14.))
+                                                        .text_color(muted_foreground),
+                                                ),
+                                        )
+                                        .child(
+                                            div()
+                                                .flex_1()
+                                                .text_xs()
+                                                .text_color(muted_foreground)
+                                                .overflow_hidden()
+                                                .text_ellipsis()
+                                                .child(browse_dir),
+                                        ),
+                                )
+                                .child(
+                                    div()
+                                        .id("link-target-list")
+                                        .h(px(180.))
+                                        .overflow_y_scroll()
+                                        .p_1()
+                                        .flex()
+                                        .flex_col()
+                                        .children(browse_status.map(|status| {
+                                            div()
+                                                .py_4()
+                                                .flex()
+                                                .justify_center()
+                                                .text_sm()
+                                                .text_color(muted_foreground)
+                                                .child(status)
+                                        }))
+                                        .children(browse_rows),
+                                ),
+                        ),
+                )
+                // 错误信息
+                .children(error_message.map(|msg| div().text_sm().text_color(danger).child(msg)))
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        // 取消按钮
+                        .child(
+                            div()
+                                .id("new-link-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, cx| {
+                                        s.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        // 创建按钮
+                        .child({
+                            let create_btn = div()
+                                .id("new-link-create-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(if is_creating {
+                                            i18n::t(&lang, "common.loading")
+                                        } else {
+                                            i18n::t(&lang, "common.confirm")
+                                        }),
+                                );
+
+                            if is_creating {
+                                create_btn.opacity(0.6)
+                            } else {
+                                create_btn
+                                    .cursor_pointer()
+                                    .hover(move |s| s.bg(cx.theme().primary_hover))
+                                    .on_click(move |_, _, cx| {
+                                        let valid = state_create.update(cx, |s, cx| {
+                                            let valid = s.validate(cx);
+                                            if valid {
+                                                s.start_creating();
+                                            }
+                                            cx.notify();
+                                            valid
+                                        });
+                                        if valid {
+                                            on_create(state_create.clone(), cx);
+                                        }
+                                    })
+                            }
+                        }),
+                ),
+        )
+}
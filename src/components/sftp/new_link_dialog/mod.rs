@@ -0,0 +1,7 @@
+// SFTP 新建链接对话框组件（符号链接 / 硬链接）
+
+mod dialog;
+mod state;
+
+pub use dialog::render_new_link_dialog_overlay;
+pub use state::NewLinkDialogState;
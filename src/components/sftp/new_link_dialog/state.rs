@@ -0,0 +1,205 @@
+// 新建链接对话框状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::sftp::state::join_path;
+use crate::models::sftp::FileEntry;
+use crate::services::sftp::LinkKind;
+use crate::services::storage;
+
+/// 新建链接对话框状态
+#[derive(Default)]
+pub struct NewLinkDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 链接所在目录
+    pub current_path: String,
+    /// 链接类型
+    pub kind: LinkKind,
+    /// 链接名称输入框
+    pub name_input: Option<Entity<InputState>>,
+    /// 链接目标输入框
+    pub target_input: Option<Entity<InputState>>,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 是否正在创建中
+    pub is_creating: bool,
+    /// 目标选择器当前浏览的目录
+    pub browse_dir: String,
+    /// 目标选择器当前目录的条目（目录在前）
+    pub browse_entries: Vec<FileEntry>,
+    /// 目标选择器是否正在读取
+    pub browse_loading: bool,
+    /// 打开时的初始名称（输入框创建时填入）
+    pending_name: Option<String>,
+    /// 待写入目标输入框的路径（打开时或在选择器中选中后）
+    pending_target: Option<String>,
+    /// 目录读取请求序号，只接受最新一次请求的结果
+    browse_seq: u64,
+}
+
+impl NewLinkDialogState {
+    /// 打开对话框
+    ///
+    /// `target` 为从文件右键菜单打开时预填的目标路径
+    pub fn open(&mut self, tab_id: String, current_path: String, target: Option<String>) {
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.kind = LinkKind::Symbolic;
+        self.error_message = None;
+        self.is_creating = false;
+        self.browse_dir = current_path.clone();
+        self.browse_entries.clear();
+        self.pending_name = target
+            .as_deref()
+            .and_then(|t| t.rsplit('/').next())
+            .map(|name| format!("{} link", name));
+        self.pending_target = target;
+        self.current_path = current_path;
+        // 重置输入框（将在渲染时创建）
+        self.name_input = None;
+        self.target_input = None;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.tab_id.clear();
+        self.current_path.clear();
+        self.name_input = None;
+        self.target_input = None;
+        self.error_message = None;
+        self.is_creating = false;
+        self.browse_entries.clear();
+        self.browse_loading = false;
+        self.pending_name = None;
+        self.pending_target = None;
+    }
+
+    /// 确保输入框已创建，并填入待设置的名称与目标
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        if self.name_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.new_link.name_placeholder");
+            self.name_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        if self.target_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.new_link.target_placeholder");
+            self.target_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        for (pending, input) in [
+            (self.pending_name.take(), &self.name_input),
+            (self.pending_target.take(), &self.target_input),
+        ] {
+            if let (Some(value), Some(input)) = (pending, input) {
+                input.update(cx, |input, cx| input.set_value(value, window, cx));
+            }
+        }
+    }
+
+    /// 输入的链接名称
+    pub fn link_name(&self, cx: &App) -> String {
+        self.name_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 输入的链接目标
+    pub fn target(&self, cx: &App) -> String {
+        self.target_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 链接的完整路径
+    pub fn link_path(&self, cx: &App) -> String {
+        join_path(&self.current_path, &self.link_name(cx))
+    }
+
+    /// 验证名称与目标
+    pub fn validate(&mut self, cx: &App) -> bool {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        let name = self.link_name(cx);
+        if name.is_empty() {
+            self.error_message = Some(i18n::t(&lang, "sftp.new_link.error_empty").to_string());
+            return false;
+        }
+        if name.contains('/') || name.contains('\0') {
+            self.error_message = Some(i18n::t(&lang, "sftp.new_link.error_invalid").to_string());
+            return false;
+        }
+        if self.target(cx).is_empty() {
+            self.error_message = Some(i18n::t(&lang, "sftp.new_link.error_target").to_string());
+            return false;
+        }
+
+        self.error_message = None;
+        true
+    }
+
+    /// 切换链接类型
+    pub fn set_kind(&mut self, kind: LinkKind) {
+        self.kind = kind;
+    }
+
+    /// 在选择器中选中路径，下次渲染时写入目标输入框
+    pub fn pick_target(&mut self, path: String) {
+        self.pending_target = Some(path);
+    }
+
+    /// 开始读取选择器目录，返回本次请求的序号
+    pub fn start_browsing(&mut self, dir: String) -> u64 {
+        self.browse_seq += 1;
+        self.browse_dir = dir;
+        self.browse_loading = true;
+        self.browse_seq
+    }
+
+    /// 写入选择器目录的读取结果（过期请求的结果会被忽略）
+    pub fn set_browse_entries(&mut self, seq: u64, result: Result<Vec<FileEntry>, String>) {
+        if seq != self.browse_seq {
+            return;
+        }
+        self.browse_loading = false;
+        match result {
+            Ok(mut entries) => {
+                entries.sort_by(|a, b| {
+                    b.is_dir()
+                        .cmp(&a.is_dir())
+                        .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+                });
+                self.browse_entries = entries;
+            }
+            Err(e) => {
+                self.browse_entries.clear();
+                self.error_message = Some(e);
+            }
+        }
+    }
+
+    /// 设置错误信息
+    pub fn set_error(&mut self, message: String) {
+        self.error_message = Some(message);
+        self.is_creating = false;
+    }
+
+    /// 开始创建
+    pub fn start_creating(&mut self) {
+        self.is_creating = true;
+        self.error_message = None;
+    }
+}
@@ -0,0 +1,7 @@
+// SFTP 覆盖确认对话框组件
+
+mod dialog;
+mod state;
+
+pub use dialog::render_overwrite_dialog_overlay;
+pub use state::OverwriteDialogState;
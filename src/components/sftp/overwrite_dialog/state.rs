@@ -0,0 +1,33 @@
+// 覆盖确认对话框状态管理
+
+/// 覆盖确认对话框状态
+/// 移动/重命名时目标已存在，等待用户确认是否覆盖
+#[derive(Default)]
+pub struct OverwriteDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 源路径
+    pub source_path: String,
+    /// 目标路径（已存在）
+    pub target_path: String,
+}
+
+impl OverwriteDialogState {
+    /// 打开对话框
+    pub fn open(&mut self, tab_id: String, source_path: String, target_path: String) {
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.source_path = source_path;
+        self.target_path = target_path;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.tab_id.clear();
+        self.source_path.clear();
+        self.target_path.clear();
+    }
+}
@@ -32,7 +32,12 @@ impl PathBarState {
     where
         F: Fn(PathBarEvent, &mut App) + 'static,
     {
-        let input_state = cx.new(|cx| InputState::new(window, cx));
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let input_state = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(crate::i18n::t(&lang, "sftp.go_to_path"))
+        });
 
         // 监听输入框的失焦事件
         cx.subscribe(&input_state, |this, _input, event: &InputEvent, cx| {
@@ -69,9 +74,13 @@ impl PathBarState {
         self.is_editing = true;
 
         let path = self.current_path.clone();
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
 
-        // 设置输入框的值为当前路径，并聚焦
+        // 设置输入框的值为当前路径，并聚焦（占位符跟随当前语言）
         self.input_state.update(cx, |input, cx| {
+            input.set_placeholder(crate::i18n::t(&lang, "sftp.go_to_path"), window, cx);
             input.set_value(&path, window, cx);
             // 聚焦输入框
             input.focus(window, cx);
@@ -0,0 +1,281 @@
+// SFTP 文件预览面板
+// 显示选中文件的基本信息，以及图片缩略图、文本开头部分或 PDF/二进制头部
+
+use std::sync::Arc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use super::file_list::{format_modified_time, get_file_icon};
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::models::sftp::FileEntry;
+use crate::services::sftp::{PreviewData, PreviewImageFormat};
+
+/// 图标尺寸
+const ICON_SIZE: f32 = 16.0;
+
+/// 预览内容的加载状态
+pub enum PreviewContent {
+    /// 正在读取文件开头
+    Loading,
+    /// 目录等无需读取内容，只显示基本信息
+    Metadata,
+    /// 读取完成
+    Ready(PreviewData),
+    /// 读取失败
+    Failed(String),
+}
+
+/// 文件预览（每个标签页一个）
+pub struct SftpPreview {
+    /// 预览的文件
+    pub entry: FileEntry,
+    /// 预览内容
+    pub content: PreviewContent,
+    /// 图片缩略图（读取完成后创建一次，避免每帧重新解码）
+    image: Option<Arc<Image>>,
+}
+
+impl SftpPreview {
+    /// 创建预览，文件需要读取内容时处于加载状态
+    pub fn new(entry: FileEntry, loading: bool) -> Self {
+        Self {
+            entry,
+            content: if loading {
+                PreviewContent::Loading
+            } else {
+                PreviewContent::Metadata
+            },
+            image: None,
+        }
+    }
+
+    /// 设置读取结果
+    pub fn set_result(&mut self, result: Result<PreviewData, String>) {
+        self.image = match &result {
+            Ok(PreviewData::Image { format, bytes }) => Some(Arc::new(Image::from_bytes(
+                image_format(*format),
+                bytes.clone(),
+            ))),
+            _ => None,
+        };
+        self.content = match result {
+            Ok(data) => PreviewContent::Ready(data),
+            Err(e) => PreviewContent::Failed(e),
+        };
+    }
+}
+
+fn image_format(format: PreviewImageFormat) -> ImageFormat {
+    match format {
+        PreviewImageFormat::Png => ImageFormat::Png,
+        PreviewImageFormat::Jpeg => ImageFormat::Jpeg,
+        PreviewImageFormat::Gif => ImageFormat::Gif,
+        PreviewImageFormat::Webp => ImageFormat::Webp,
+        PreviewImageFormat::Bmp => ImageFormat::Bmp,
+        PreviewImageFormat::Svg => ImageFormat::Svg,
+    }
+}
+
+/// 渲染一行基本信息
+fn render_meta_row(label: String, value: String, cx: &App) -> impl IntoElement {
+    div()
+        .flex()
+        .gap_2()
+        .text_xs()
+        .child(
+            div()
+                .w(px(64.))
+                .flex_shrink_0()
+                .text_color(cx.theme().muted_foreground)
+                .child(label),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .text_ellipsis()
+                .text_color(cx.theme().foreground)
+                .child(value),
+        )
+}
+
+/// 渲染提示文本
+fn render_hint(text: String, cx: &App) -> Div {
+    div()
+        .text_xs()
+        .text_color(cx.theme().muted_foreground)
+        .child(text)
+}
+
+/// 渲染预览内容
+fn render_content(preview: &SftpPreview, lang: &Language, cx: &App) -> AnyElement {
+    let mono = |text: String| {
+        div()
+            .text_xs()
+            .font_family("monospace")
+            .text_color(cx.theme().foreground)
+            .child(text)
+    };
+
+    match &preview.content {
+        PreviewContent::Loading => {
+            render_hint(t(lang, "sftp.loading").to_string(), cx).into_any_element()
+        }
+        PreviewContent::Metadata => div().into_any_element(),
+        PreviewContent::Failed(e) => div()
+            .text_xs()
+            .text_color(cx.theme().danger)
+            .child(e.clone())
+            .into_any_element(),
+        PreviewContent::Ready(PreviewData::Image { .. }) => match &preview.image {
+            Some(image) => img(image.clone())
+                .max_w_full()
+                .max_h(px(240.))
+                .object_fit(ObjectFit::Contain)
+                .into_any_element(),
+            None => div().into_any_element(),
+        },
+        PreviewContent::Ready(PreviewData::Text {
+            text,
+            encoding,
+            truncated,
+        }) => div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(render_hint(
+                format!(
+                    "{}: {}{}",
+                    t(lang, "sftp.preview.encoding"),
+                    encoding,
+                    if *truncated {
+                        format!(" · {}", t(lang, "sftp.preview.truncated"))
+                    } else {
+                        String::new()
+                    }
+                ),
+                cx,
+            ))
+            .child(mono(text.clone()).whitespace_normal())
+            .into_any_element(),
+        PreviewContent::Ready(PreviewData::Pdf { version }) => render_hint(
+            format!(
+                "PDF {}",
+                version
+                    .clone()
+                    .unwrap_or_else(|| t(lang, "sftp.preview.unknown_version").to_string())
+            ),
+            cx,
+        )
+        .into_any_element(),
+        PreviewContent::Ready(PreviewData::Binary { header }) => div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .child(render_hint(t(lang, "sftp.preview.binary").to_string(), cx))
+            .child(mono(header.clone()))
+            .into_any_element(),
+        PreviewContent::Ready(PreviewData::TooLarge) => {
+            render_hint(t(lang, "sftp.preview.too_large").to_string(), cx).into_any_element()
+        }
+    }
+}
+
+/// 渲染预览面板
+pub fn render_preview_pane(preview: Option<&SftpPreview>, cx: &App) -> impl IntoElement {
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+    let bg_color = crate::theme::sidebar_color(cx);
+
+    let Some(preview) = preview else {
+        return div()
+            .size_full()
+            .bg(bg_color)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(render_hint(t(&lang, "sftp.preview.empty").to_string(), cx))
+            .into_any_element();
+    };
+
+    let entry = &preview.entry;
+    let icon_color = if entry.is_dir() {
+        cx.theme().link
+    } else {
+        cx.theme().muted_foreground
+    };
+
+    div()
+        .id("sftp-preview-pane")
+        .size_full()
+        .bg(bg_color)
+        .overflow_y_scroll()
+        .p_2()
+        .flex()
+        .flex_col()
+        .gap_2()
+        // 文件名
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    svg()
+                        .path(get_file_icon(entry))
+                        .size(px(ICON_SIZE))
+                        .text_color(icon_color),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(entry.name.clone()),
+                ),
+        )
+        // 基本信息
+        .child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .pb_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .when(!entry.is_dir(), |el| {
+                    el.child(render_meta_row(
+                        t(&lang, "sftp.header.size").to_string(),
+                        entry.format_size(),
+                        cx,
+                    ))
+                })
+                .child(render_meta_row(
+                    t(&lang, "sftp.header.modified").to_string(),
+                    format_modified_time(entry),
+                    cx,
+                ))
+                .child(render_meta_row(
+                    t(&lang, "sftp.header.permissions").to_string(),
+                    entry.format_permissions(),
+                    cx,
+                ))
+                .when_some(entry.link_target.clone(), |el, target| {
+                    el.child(render_meta_row(
+                        t(&lang, "sftp.header.link_target").to_string(),
+                        target,
+                        cx,
+                    ))
+                }),
+        )
+        .child(render_content(preview, &lang, cx))
+        .into_any_element()
+}
@@ -2,20 +2,47 @@
 
 use gpui::prelude::FluentBuilder;
 use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::input::Input;
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
 use gpui_component::ActiveTheme;
 
-use super::state::PropertiesDialogState;
+use super::state::{ChecksumState, PropertiesDialogState};
 use crate::constants::icons;
 use crate::i18n;
 use crate::models::settings::Language;
+use crate::models::sftp::state::get_parent_path;
 use crate::models::sftp::FileType;
+use crate::services::sftp::ChecksumAlgorithm;
 use crate::services::storage;
 
+/// 权限矩阵的行（所有者/组/其他）对应的位移
+const PERMISSION_ROWS: [(&str, u32); 3] = [
+    ("sftp.properties.perm_owner", 6),
+    ("sftp.properties.perm_group", 3),
+    ("sftp.properties.perm_other", 0),
+];
+
+/// 权限矩阵的列（读/写/执行）对应的位
+const PERMISSION_COLUMNS: [(&str, u32); 3] = [
+    ("sftp.properties.perm_read", 0o4),
+    ("sftp.properties.perm_write", 0o2),
+    ("sftp.properties.perm_execute", 0o1),
+];
+
 /// 渲染属性对话框覆盖层
-pub fn render_properties_dialog_overlay(
+/// `on_apply` 在点击"应用"时调用，用于提交权限/所有者修改；
+/// `on_checksum` 在点击"计算"时调用，用于按需计算文件校验和
+pub fn render_properties_dialog_overlay<F, C>(
     state: Entity<PropertiesDialogState>,
+    on_apply: F,
+    on_checksum: C,
     cx: &App,
-) -> impl IntoElement {
+) -> impl IntoElement
+where
+    F: Fn(Entity<PropertiesDialogState>, &mut App) + 'static,
+    C: Fn(Entity<PropertiesDialogState>, ChecksumAlgorithm, &mut App) + Clone + 'static,
+{
     let state_data = state.read(cx);
 
     // 如果没有 entry，返回空
@@ -40,9 +67,15 @@ pub fn render_properties_dialog_overlay(
 
     // 获取动态数据
     let symlink_target = state_data.symlink_target.clone();
-    let folder_size_display = state_data.format_folder_size();
+    let folder_size_display = state_data.format_folder_size(&lang);
+    let is_calculating_size = state_data.is_calculating_size;
+    let is_multi = state_data.is_multi();
+    let has_folder = state_data.has_folder();
     let is_folder = entry.is_dir();
     let is_symlink = entry.file_type == FileType::Symlink;
+    let is_regular_file = !is_multi && entry.file_type == FileType::File;
+    let extended_attributes = state_data.extended_attributes.clone();
+    let acl_entries = state_data.acl_entries.clone();
 
     // 格式化修改时间
     let modified_str = entry
@@ -79,13 +112,29 @@ pub fn render_properties_dialog_overlay(
         FileType::Other => i18n::t(&lang, "sftp.properties.type_other"),
     };
 
-    // 大小显示
-    let size_display = if is_folder {
+    // 大小显示（多选时为总大小）
+    let size_display = if is_folder || is_multi {
         folder_size_display
     } else {
         entry.format_size()
     };
 
+    // 多选时的标题与汇总
+    let header_name = if is_multi {
+        i18n::t(&lang, "sftp.properties.selected_items")
+            .replace("{}", &state_data.selection.len().to_string())
+    } else {
+        entry.name.clone()
+    };
+    let selection_summary = state_data.format_selection_summary(&lang);
+    let location = get_parent_path(&entry.path);
+
+    // 符号链接的权限由目标决定，不提供编辑（多选时只要有非符号链接的条目即可编辑）
+    let is_editable = state_data
+        .targets()
+        .iter()
+        .any(|e| e.file_type != FileType::Symlink);
+
     let state_for_close = state.clone();
     let state_for_backdrop = state.clone();
 
@@ -105,7 +154,7 @@ pub fn render_properties_dialog_overlay(
         .child(
             div()
                 .id("properties-dialog")
-                .w(px(320.))
+                .w(px(400.))
                 .bg(dialog_bg)
                 .border_1()
                 .border_color(border_color)
@@ -166,6 +215,7 @@ pub fn render_properties_dialog_overlay(
                             // 文件图标
                             svg()
                                 .path(match entry.file_type {
+                                    _ if is_multi => icons::COPY,
                                     FileType::Directory => icons::FOLDER,
                                     FileType::Symlink => icons::LINK,
                                     _ => icons::FILE,
@@ -180,7 +230,7 @@ pub fn render_properties_dialog_overlay(
                                 .text_color(value_color)
                                 .overflow_hidden()
                                 .text_ellipsis()
-                                .child(entry.name.clone()),
+                                .child(header_name),
                         ),
                 )
                 // 内容区域 - 使用 section 样式
@@ -198,43 +248,68 @@ pub fn render_properties_dialog_overlay(
                                     .flex()
                                     .flex_col()
                                     .gap_1()
-                                    // 类型
-                                    .child(render_property_row(
-                                        &type_label,
-                                        &type_display,
-                                        label_color,
-                                        value_color,
-                                    ))
-                                    // 完整路径
-                                    .child(render_property_row(
-                                        &path_label,
-                                        &entry.path,
-                                        label_color,
-                                        value_color,
-                                    ))
-                                    // 大小
-                                    .child(render_property_row(
+                                    // 多选：数量汇总与所在位置
+                                    .when(is_multi, |this| {
+                                        this.child(render_property_row(
+                                            i18n::t(&lang, "sftp.properties.items"),
+                                            &selection_summary,
+                                            label_color,
+                                            value_color,
+                                        ))
+                                        .child(
+                                            render_property_row(
+                                                i18n::t(&lang, "sftp.properties.location"),
+                                                &location,
+                                                label_color,
+                                                value_color,
+                                            ),
+                                        )
+                                    })
+                                    // 单选：类型与完整路径
+                                    .when(!is_multi, |this| {
+                                        this.child(render_property_row(
+                                            &type_label,
+                                            &type_display,
+                                            label_color,
+                                            value_color,
+                                        ))
+                                        .child(
+                                            render_property_row(
+                                                &path_label,
+                                                &entry.path,
+                                                label_color,
+                                                value_color,
+                                            ),
+                                        )
+                                    })
+                                    // 大小（计算中可停止）
+                                    .child(render_size_row(
+                                        state.clone(),
                                         &size_label,
                                         &size_display,
-                                        label_color,
-                                        value_color,
-                                    ))
-                                    // 修改时间
-                                    .child(render_property_row(
-                                        &modified_label,
-                                        &modified_str,
-                                        label_color,
-                                        value_color,
-                                    ))
-                                    // 权限
-                                    .child(render_property_row(
-                                        &permissions_label,
-                                        &perms_display,
-                                        label_color,
-                                        value_color,
+                                        is_calculating_size,
+                                        &lang,
+                                        cx,
                                     ))
+                                    // 单选：修改时间与权限
+                                    .when(!is_multi, |this| {
+                                        this.child(render_property_row(
+                                            &modified_label,
+                                            &modified_str,
+                                            label_color,
+                                            value_color,
+                                        ))
+                                        .child(
+                                            render_property_row(
+                                                &permissions_label,
+                                                &perms_display,
+                                                label_color,
+                                                value_color,
+                                            ),
+                                        )
+                                    })
                                     // 符号链接目标（仅对符号链接显示）
-                                    .when(is_symlink, |this| {
+                                    .when(!is_multi && is_symlink, |this| {
                                         let target = symlink_target.as_deref().unwrap_or("...");
                                         this.child(render_property_row(
                                             &link_target_label,
@@ -245,11 +320,547 @@ pub fn render_properties_dialog_overlay(
                                     }),
                             ),
                     ),
-                ),
+                )
+                // 校验和（仅普通文件，按需计算）
+                .when(is_regular_file, |this| {
+                    this.child(render_checksums(state.clone(), on_checksum, &lang, cx))
+                })
+                // 扩展属性与 ACL（存在时显示）
+                .when(!extended_attributes.is_empty(), |this| {
+                    this.child(render_attribute_list(
+                        i18n::t(&lang, "sftp.properties.xattrs"),
+                        extended_attributes,
+                        cx,
+                    ))
+                })
+                .when(!acl_entries.is_empty(), |this| {
+                    this.child(render_attribute_list(
+                        i18n::t(&lang, "sftp.properties.acl"),
+                        acl_entries,
+                        cx,
+                    ))
+                })
+                // 权限/所有者编辑
+                .when(is_editable, |this| {
+                    this.child(render_permissions_editor(
+                        state.clone(),
+                        has_folder,
+                        &lang,
+                        cx,
+                    ))
+                    .child(render_footer(state.clone(), on_apply, &lang, cx))
+                }),
         )
         .into_any_element()
 }
 
+/// 渲染权限编辑区域（rwx 矩阵、八进制输入、所有者/组下拉、递归选项）
+fn render_permissions_editor(
+    state: Entity<PropertiesDialogState>,
+    is_folder: bool,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let state_data = state.read(cx);
+    let mode = state_data.mode;
+    let recursive = state_data.recursive;
+    let octal_input = state_data.octal_input.clone();
+    let error_message = state_data.error_message.clone();
+
+    let owner_label = name_for_id(&state_data.users, state_data.uid);
+    let group_label = name_for_id(&state_data.groups, state_data.gid);
+    let users = state_data.users.clone();
+    let groups = state_data.groups.clone();
+
+    let label_color = cx.theme().muted_foreground;
+    let value_color = cx.theme().foreground;
+
+    div()
+        .px_3()
+        .pb_3()
+        .flex()
+        .flex_col()
+        .gap_2()
+        // 权限矩阵表头
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .child(div().w(px(80.)))
+                .children(PERMISSION_COLUMNS.iter().map(|(key, _)| {
+                    div()
+                        .flex_1()
+                        .text_xs()
+                        .text_color(label_color)
+                        .text_center()
+                        .child(i18n::t(lang, key))
+                })),
+        )
+        // 权限矩阵
+        .children(PERMISSION_ROWS.iter().map(|(row_key, shift)| {
+            div()
+                .flex()
+                .items_center()
+                .child(
+                    div()
+                        .w(px(80.))
+                        .text_xs()
+                        .text_color(label_color)
+                        .child(i18n::t(lang, row_key)),
+                )
+                .children(PERMISSION_COLUMNS.iter().map(|(_, bit)| {
+                    let bit = bit << shift;
+                    let state_for_toggle = state.clone();
+                    div()
+                        .flex_1()
+                        .flex()
+                        .justify_center()
+                        .child(render_checkbox(
+                            SharedString::from(format!("perm-bit-{:o}", bit)),
+                            mode & bit != 0,
+                            move |cx| {
+                                state_for_toggle.update(cx, |s, cx| {
+                                    s.toggle_permission_bit(bit);
+                                    cx.notify();
+                                });
+                            },
+                            cx,
+                        ))
+                }))
+        }))
+        // 八进制
+        .child(render_edit_row(
+            i18n::t(lang, "sftp.properties.octal"),
+            label_color,
+            match octal_input {
+                Some(input) => Input::new(&input).into_any_element(),
+                None => div().into_any_element(),
+            },
+        ))
+        // 所有者
+        .child(render_edit_row(
+            i18n::t(lang, "sftp.properties.owner"),
+            label_color,
+            render_id_dropdown(
+                "properties-owner-dropdown",
+                owner_label,
+                users,
+                state.clone(),
+                |s, id| s.uid = Some(id),
+                cx,
+            )
+            .into_any_element(),
+        ))
+        // 所属组
+        .child(render_edit_row(
+            i18n::t(lang, "sftp.properties.group"),
+            label_color,
+            render_id_dropdown(
+                "properties-group-dropdown",
+                group_label,
+                groups,
+                state.clone(),
+                |s, id| s.gid = Some(id),
+                cx,
+            )
+            .into_any_element(),
+        ))
+        // 递归应用（仅文件夹）
+        .when(is_folder, |this| {
+            let state_for_toggle = state.clone();
+            this.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(render_checkbox(
+                        "properties-recursive",
+                        recursive,
+                        move |cx| {
+                            state_for_toggle.update(cx, |s, cx| {
+                                s.recursive = !s.recursive;
+                                cx.notify();
+                            });
+                        },
+                        cx,
+                    ))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(value_color)
+                            .child(i18n::t(lang, "sftp.properties.recursive")),
+                    ),
+            )
+        })
+        // 错误信息
+        .children(error_message.map(|msg| {
+            div()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(msg)
+        }))
+}
+
+/// 渲染底部按钮（取消 / 应用）
+fn render_footer<F>(
+    state: Entity<PropertiesDialogState>,
+    on_apply: F,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement
+where
+    F: Fn(Entity<PropertiesDialogState>, &mut App) + 'static,
+{
+    let state_data = state.read(cx);
+    let can_apply = !state_data.is_applying && (state_data.has_changes() || state_data.recursive);
+
+    let state_for_cancel = state.clone();
+    let state_for_apply = state.clone();
+
+    div()
+        .px_3()
+        .py_2()
+        .border_t_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .justify_end()
+        .gap_2()
+        .child(
+            div()
+                .id("properties-cancel-btn")
+                .px_3()
+                .py_1()
+                .rounded(px(4.))
+                .bg(cx.theme().secondary)
+                .cursor_pointer()
+                .hover(|s| s.bg(cx.theme().secondary_hover))
+                .on_click(move |_, _, cx| {
+                    state_for_cancel.update(cx, |s, _| s.close());
+                })
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(lang, "common.cancel")),
+                ),
+        )
+        .child(
+            div()
+                .id("properties-apply-btn")
+                .px_3()
+                .py_1()
+                .rounded(px(4.))
+                .bg(cx.theme().primary)
+                .when(can_apply, |this| {
+                    this.cursor_pointer()
+                        .hover(|s| s.bg(cx.theme().primary_hover))
+                        .on_click(move |_, _, cx| {
+                            on_apply(state_for_apply.clone(), cx);
+                        })
+                })
+                .when(!can_apply, |this| this.opacity(0.5))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().primary_foreground)
+                        .child(i18n::t(lang, "sftp.properties.apply")),
+                ),
+        )
+}
+
+/// 渲染大小行，计算进行中时在值后提供"停止"
+fn render_size_row(
+    state: Entity<PropertiesDialogState>,
+    label: &str,
+    value: &str,
+    is_calculating: bool,
+    lang: &Language,
+    cx: &App,
+) -> Div {
+    let label_color = cx.theme().muted_foreground;
+    render_property_row(label, value, label_color, cx.theme().foreground).when(
+        is_calculating,
+        |this| {
+            this.child(
+                div()
+                    .id("properties-stop-size")
+                    .ml_2()
+                    .text_xs()
+                    .text_color(cx.theme().primary)
+                    .cursor_pointer()
+                    .on_click(move |_, _, cx| {
+                        state.update(cx, |s, cx| {
+                            s.stop_calculating_size();
+                            cx.notify();
+                        });
+                    })
+                    .child(i18n::t(lang, "sftp.properties.stop")),
+            )
+        },
+    )
+}
+
+/// 渲染校验和区域：每种算法一行，未计算时显示"计算"，完成后显示摘要与"复制"
+fn render_checksums<C>(
+    state: Entity<PropertiesDialogState>,
+    on_checksum: C,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement
+where
+    C: Fn(Entity<PropertiesDialogState>, ChecksumAlgorithm, &mut App) + Clone + 'static,
+{
+    let label_color = cx.theme().muted_foreground;
+    let value_color = cx.theme().foreground;
+    let link_color = cx.theme().primary;
+
+    div()
+        .px_3()
+        .pb_3()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(
+            div()
+                .text_xs()
+                .text_color(label_color)
+                .child(i18n::t(lang, "sftp.properties.checksum")),
+        )
+        .children(
+            [ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha256].map(|algorithm| {
+                let checksum = state.read(cx).checksum(algorithm).clone();
+                let (value, action): (String, Option<AnyElement>) = match checksum {
+                    ChecksumState::Idle => {
+                        let state = state.clone();
+                        let on_checksum = on_checksum.clone();
+                        (
+                            "-".to_string(),
+                            Some(
+                                div()
+                                    .id(SharedString::from(format!(
+                                        "properties-checksum-{}",
+                                        algorithm.label()
+                                    )))
+                                    .text_xs()
+                                    .text_color(link_color)
+                                    .cursor_pointer()
+                                    .on_click(move |_, _, cx| {
+                                        on_checksum(state.clone(), algorithm, cx);
+                                    })
+                                    .child(i18n::t(lang, "sftp.properties.compute"))
+                                    .into_any_element(),
+                            ),
+                        )
+                    }
+                    ChecksumState::Running => (
+                        i18n::t(lang, "sftp.properties.calculating").to_string(),
+                        None,
+                    ),
+                    ChecksumState::Done(hash) => {
+                        let hash_for_copy = hash.clone();
+                        (
+                            hash,
+                            Some(
+                                div()
+                                    .id(SharedString::from(format!(
+                                        "properties-copy-{}",
+                                        algorithm.label()
+                                    )))
+                                    .text_xs()
+                                    .text_color(link_color)
+                                    .cursor_pointer()
+                                    .on_click(move |_, _, cx| {
+                                        cx.write_to_clipboard(ClipboardItem::new_string(
+                                            hash_for_copy.clone(),
+                                        ));
+                                    })
+                                    .child(i18n::t(lang, "sftp.properties.copy"))
+                                    .into_any_element(),
+                            ),
+                        )
+                    }
+                    ChecksumState::Failed(_) => (
+                        i18n::t(lang, "sftp.properties.checksum_failed").to_string(),
+                        None,
+                    ),
+                };
+
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w(px(56.))
+                            .flex_shrink_0()
+                            .text_xs()
+                            .text_color(label_color)
+                            .child(algorithm.label()),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .min_w_0()
+                            .text_xs()
+                            .font_family("monospace")
+                            .text_color(value_color)
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .child(value),
+                    )
+                    .children(action)
+            }),
+        )
+}
+
+/// 渲染扩展属性/ACL 列表
+fn render_attribute_list(title: &str, lines: Vec<String>, cx: &App) -> impl IntoElement {
+    div()
+        .px_3()
+        .pb_3()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(title.to_string()),
+        )
+        .child(
+            div()
+                .id(SharedString::from(format!("properties-attrs-{}", title)))
+                .max_h(px(96.))
+                .overflow_y_scroll()
+                .px_2()
+                .py_1()
+                .bg(cx.theme().secondary)
+                .rounded(px(4.))
+                .children(lines.into_iter().map(|line| {
+                    div()
+                        .text_xs()
+                        .font_family("monospace")
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(line)
+                })),
+        )
+}
+
+/// 渲染编辑行（左侧标签 + 右侧控件）
+fn render_edit_row(label: &str, label_color: Hsla, control: AnyElement) -> Div {
+    div()
+        .flex()
+        .items_center()
+        .justify_between()
+        .child(
+            div()
+                .w(px(80.))
+                .text_xs()
+                .text_color(label_color)
+                .child(label.to_string()),
+        )
+        .child(div().flex_1().child(control))
+}
+
+/// 渲染勾选框
+fn render_checkbox(
+    id: impl Into<ElementId>,
+    checked: bool,
+    on_toggle: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .id(id)
+        .size(px(16.))
+        .flex()
+        .items_center()
+        .justify_center()
+        .rounded(px(3.))
+        .border_1()
+        .border_color(if checked {
+            cx.theme().primary
+        } else {
+            cx.theme().border
+        })
+        .when(checked, |this| this.bg(cx.theme().primary))
+        .cursor_pointer()
+        .on_click(move |_, _, cx| on_toggle(cx))
+        .when(checked, |this| {
+            this.child(
+                svg()
+                    .path(icons::CHECK)
+                    .size(px(12.))
+                    .text_color(cx.theme().primary_foreground),
+            )
+        })
+}
+
+/// 渲染用户/组选择下拉框
+fn render_id_dropdown(
+    id: &'static str,
+    current_label: String,
+    options: Vec<(u32, String)>,
+    state: Entity<PropertiesDialogState>,
+    on_select: fn(&mut PropertiesDialogState, u32),
+    cx: &App,
+) -> impl IntoElement {
+    Button::new(id)
+        .w_full()
+        .h(px(26.))
+        .outline()
+        .justify_start()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .w_full()
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().foreground)
+                        .child(current_label),
+                )
+                .child(
+                    svg()
+                        .path(icons::CHEVRON_DOWN)
+                        .size(px(12.))
+                        .text_color(cx.theme().muted_foreground),
+                ),
+        )
+        .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+            let mut menu = menu.min_w(px(200.));
+            for (option_id, name) in &options {
+                let option_id = *option_id;
+                let label: SharedString = format!("{} ({})", name, option_id).into();
+                let state_for_click = state.clone();
+                menu = menu.item(PopupMenuItem::new(label).on_click(move |_, _, cx| {
+                    state_for_click.update(cx, |s, cx| {
+                        on_select(s, option_id);
+                        cx.notify();
+                    });
+                }));
+            }
+            menu
+        })
+}
+
+/// 根据 id 查找显示名称（未找到时显示数字 id）
+fn name_for_id(options: &[(u32, String)], id: Option<u32>) -> String {
+    match id {
+        Some(id) => options
+            .iter()
+            .find(|(option_id, _)| *option_id == id)
+            .map(|(_, name)| name.clone())
+            .unwrap_or_else(|| id.to_string()),
+        None => "-".to_string(),
+    }
+}
+
 /// 渲染属性行
 fn render_property_row(label: &str, value: &str, label_color: Hsla, value_color: Hsla) -> Div {
     div()
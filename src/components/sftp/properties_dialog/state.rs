@@ -1,25 +1,78 @@
 // 属性对话框状态管理
 
+use gpui::{AppContext, Context, Entity, Window};
+use gpui_component::input::{InputEvent, InputState};
 use tokio_util::sync::CancellationToken;
 
-use crate::models::sftp::FileEntry;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::sftp::{FileEntry, SftpState};
+use crate::services::sftp::ChecksumAlgorithm;
+
+/// 校验和计算状态
+#[derive(Clone, Debug, Default)]
+pub enum ChecksumState {
+    /// 尚未计算
+    #[default]
+    Idle,
+    /// 计算中
+    Running,
+    /// 计算完成（小写十六进制摘要）
+    Done(String),
+    /// 计算失败
+    Failed(String),
+}
 
 /// 属性对话框状态
 pub struct PropertiesDialogState {
     /// 是否打开
     pub is_open: bool,
-    /// 文件/文件夹条目信息
+    /// 文件/文件夹条目信息（多选时为第一个条目，用作权限/所有者的初始值）
     pub entry: Option<FileEntry>,
+    /// 多选时的全部条目（单选时为空）
+    pub selection: Vec<FileEntry>,
     /// 符号链接目标（仅对符号链接有效）
     pub symlink_target: Option<String>,
-    /// 文件夹大小（仅对文件夹有效，需要异步计算）
+    /// 文件夹大小（仅对文件夹有效，需要异步计算）；多选时为所有条目的总大小
     pub folder_size: Option<u64>,
     /// 是否正在计算文件夹大小
     pub is_calculating_size: bool,
+    /// 大小计算是否失败或被停止
+    size_unavailable: bool,
+    /// MD5 校验和
+    pub md5: ChecksumState,
+    /// SHA-256 校验和
+    pub sha256: ChecksumState,
+    /// 扩展属性（`name="value"` 形式）
+    pub extended_attributes: Vec<String>,
+    /// 超出基本权限位的 ACL 条目
+    pub acl_entries: Vec<String>,
     /// 关联的 tab_id
     pub tab_id: String,
-    /// 取消计算的 token
+    /// 编辑中的权限位
+    pub mode: u32,
+    /// 八进制权限输入框
+    pub octal_input: Option<Entity<InputState>>,
+    /// 编辑中的所有者 uid
+    pub uid: Option<u32>,
+    /// 编辑中的所属组 gid
+    pub gid: Option<u32>,
+    /// 可选用户列表（来自 /etc/passwd）
+    pub users: Vec<(u32, String)>,
+    /// 可选组列表（来自 /etc/group）
+    pub groups: Vec<(u32, String)>,
+    /// 是否递归应用到子目录（仅对文件夹有效）
+    pub recursive: bool,
+    /// 是否正在应用修改
+    pub is_applying: bool,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 八进制输入框是否需要按 `mode` 重新同步
+    octal_dirty: bool,
+    /// 对话框内所有异步计算的父 token，关闭对话框时统一取消
     cancellation_token: Option<CancellationToken>,
+    /// 大小计算的 token（父 token 的子 token，可单独停止）
+    size_token: Option<CancellationToken>,
 }
 
 impl Default for PropertiesDialogState {
@@ -27,27 +80,75 @@ impl Default for PropertiesDialogState {
         Self {
             is_open: false,
             entry: None,
+            selection: Vec::new(),
             symlink_target: None,
             folder_size: None,
             is_calculating_size: false,
+            size_unavailable: false,
+            md5: ChecksumState::Idle,
+            sha256: ChecksumState::Idle,
+            extended_attributes: Vec::new(),
+            acl_entries: Vec::new(),
             tab_id: String::new(),
+            mode: 0,
+            octal_input: None,
+            uid: None,
+            gid: None,
+            users: Vec::new(),
+            groups: Vec::new(),
+            recursive: false,
+            is_applying: false,
+            error_message: None,
+            octal_dirty: false,
             cancellation_token: None,
+            size_token: None,
         }
     }
 }
 
 impl PropertiesDialogState {
     /// 打开对话框
-    pub fn open(&mut self, entry: FileEntry, tab_id: String) {
+    /// `entries` 为选中的条目（至少一个，多个时显示汇总信息），`sftp_state` 用于填充用户/组下拉列表
+    pub fn open(
+        &mut self,
+        mut entries: Vec<FileEntry>,
+        tab_id: String,
+        sftp_state: Option<&SftpState>,
+    ) {
         // 取消之前的计算（如果有）
         self.cancel_calculation();
+        if entries.is_empty() {
+            return;
+        }
+        let entry = entries[0].clone();
+        if entries.len() == 1 {
+            entries.clear();
+        }
 
         self.is_open = true;
+        self.cancellation_token = Some(CancellationToken::new());
+        self.selection = entries;
+        self.mode = entry.permissions & 0o7777;
+        self.uid = entry.uid;
+        self.gid = entry.gid;
+        self.users = sorted_names(sftp_state.map(|s| &s.user_cache));
+        self.groups = sorted_names(sftp_state.map(|s| &s.group_cache));
         self.entry = Some(entry);
         self.symlink_target = None;
         self.folder_size = None;
         self.is_calculating_size = false;
+        self.size_unavailable = false;
+        self.md5 = ChecksumState::Idle;
+        self.sha256 = ChecksumState::Idle;
+        self.extended_attributes.clear();
+        self.acl_entries.clear();
         self.tab_id = tab_id;
+        self.recursive = false;
+        self.is_applying = false;
+        self.error_message = None;
+        // 重置输入框（将在渲染时创建）
+        self.octal_input = None;
+        self.octal_dirty = false;
     }
 
     /// 关闭对话框
@@ -57,17 +158,140 @@ impl PropertiesDialogState {
 
         self.is_open = false;
         self.entry = None;
+        self.selection.clear();
         self.symlink_target = None;
         self.folder_size = None;
         self.is_calculating_size = false;
+        self.md5 = ChecksumState::Idle;
+        self.sha256 = ChecksumState::Idle;
+        self.extended_attributes.clear();
+        self.acl_entries.clear();
         self.tab_id.clear();
+        self.octal_input = None;
+        self.users.clear();
+        self.groups.clear();
+        self.is_applying = false;
+        self.error_message = None;
     }
 
-    /// 取消计算
+    /// 确保八进制输入框已创建，并在权限矩阵变化后同步其内容
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.octal_input.is_none() {
+            let value = format_octal(self.mode);
+            let input = cx.new(|cx| {
+                let mut state = InputState::new(window, cx);
+                state.set_value(value, window, cx);
+                state
+            });
+
+            // 输入合法的八进制值时同步到权限矩阵
+            cx.subscribe(&input, |this, input, event: &InputEvent, cx| {
+                if let InputEvent::Change = event {
+                    let text = input.read(cx).value().trim().to_string();
+                    if let Ok(mode) = u32::from_str_radix(&text, 8) {
+                        if mode <= 0o7777 && mode != this.mode {
+                            this.mode = mode;
+                            cx.notify();
+                        }
+                    }
+                }
+            })
+            .detach();
+
+            self.octal_input = Some(input);
+            self.octal_dirty = false;
+        } else if self.octal_dirty {
+            if let Some(input) = &self.octal_input {
+                let value = format_octal(self.mode);
+                input.update(cx, |state, cx| {
+                    state.set_value(value, window, cx);
+                });
+            }
+            self.octal_dirty = false;
+        }
+    }
+
+    /// 切换权限矩阵中的某一位
+    pub fn toggle_permission_bit(&mut self, bit: u32) {
+        self.mode ^= bit;
+        self.octal_dirty = true;
+    }
+
+    /// 是否为多选
+    pub fn is_multi(&self) -> bool {
+        self.selection.len() > 1
+    }
+
+    /// 本次修改作用的全部条目
+    pub fn targets(&self) -> Vec<&FileEntry> {
+        if self.is_multi() {
+            self.selection.iter().collect()
+        } else {
+            self.entry.iter().collect()
+        }
+    }
+
+    /// 选中项中是否包含文件夹（决定是否提供递归选项）
+    pub fn has_folder(&self) -> bool {
+        self.targets().iter().any(|e| e.is_dir())
+    }
+
+    /// 权限与所有者是否被编辑过：(权限, 所有者)
+    ///
+    /// 以第一个条目为初始值，多选时未编辑的项不会覆盖其余条目原有的设置
+    fn edited(&self) -> (bool, bool) {
+        let Some(entry) = &self.entry else {
+            return (false, false);
+        };
+        (
+            self.mode != entry.permissions & 0o7777,
+            self.uid != entry.uid || self.gid != entry.gid,
+        )
+    }
+
+    /// 权限或所有者是否有改动
+    pub fn has_changes(&self) -> bool {
+        let (mode_edited, owner_edited) = self.edited();
+        mode_edited || owner_edited
+    }
+
+    /// 获取某个条目需要应用的修改：(新权限, 新所有者)，无需修改的项为 `None`
+    /// 递归应用时总是下发权限，保证子项与当前设置一致（多选时仅在权限被编辑过时）
+    pub fn pending_changes(&self, entry: &FileEntry) -> (Option<u32>, Option<(u32, u32)>) {
+        let (mode_edited, owner_edited) = self.edited();
+        let recursive = self.recursive && entry.is_dir() && (mode_edited || !self.is_multi());
+        let mode_changed = mode_edited && self.mode != entry.permissions & 0o7777;
+        let owner_changed = owner_edited && (self.uid != entry.uid || self.gid != entry.gid);
+        let mode = (recursive || mode_changed).then_some(self.mode);
+        let owner = self.uid.zip(self.gid).filter(|_| owner_changed);
+        (mode, owner)
+    }
+
+    /// 开始应用修改
+    pub fn start_applying(&mut self) {
+        self.is_applying = true;
+        self.error_message = None;
+    }
+
+    /// 应用失败
+    pub fn set_apply_error(&mut self, error: String) {
+        self.is_applying = false;
+        self.error_message = Some(error);
+    }
+
+    /// 取消对话框内所有正在进行的计算
     fn cancel_calculation(&mut self) {
         if let Some(token) = self.cancellation_token.take() {
             token.cancel();
         }
+        self.size_token = None;
+    }
+
+    /// 对话框父 token 的子 token，关闭对话框时随之取消
+    fn child_token(&mut self) -> CancellationToken {
+        self.cancellation_token
+            .get_or_insert_with(CancellationToken::new)
+            .child_token()
     }
 
     /// 设置符号链接目标
@@ -77,12 +301,15 @@ impl PropertiesDialogState {
 
     /// 开始计算文件夹大小，返回 CancellationToken 供异步任务使用
     pub fn start_calculating_size(&mut self) -> CancellationToken {
-        // 取消之前的计算（如果有）
-        self.cancel_calculation();
+        // 停止之前的大小计算（如果有）
+        if let Some(token) = self.size_token.take() {
+            token.cancel();
+        }
 
-        let token = CancellationToken::new();
-        self.cancellation_token = Some(token.clone());
+        let token = self.child_token();
+        self.size_token = Some(token.clone());
         self.is_calculating_size = true;
+        self.size_unavailable = false;
         token
     }
 
@@ -90,11 +317,80 @@ impl PropertiesDialogState {
     pub fn set_folder_size(&mut self, size: u64) {
         self.folder_size = Some(size);
         self.is_calculating_size = false;
-        self.cancellation_token = None;
+        self.size_token = None;
+    }
+
+    /// 大小计算失败
+    pub fn set_folder_size_failed(&mut self) {
+        self.folder_size = None;
+        self.is_calculating_size = false;
+        self.size_unavailable = true;
+        self.size_token = None;
+    }
+
+    /// 停止正在进行的大小计算（远程 du 随 exec 通道关闭而终止）
+    pub fn stop_calculating_size(&mut self) {
+        if let Some(token) = self.size_token.take() {
+            token.cancel();
+        }
+        if self.is_calculating_size {
+            self.is_calculating_size = false;
+            self.size_unavailable = true;
+        }
+    }
+
+    /// 获取指定算法的校验和状态
+    pub fn checksum(&self, algorithm: ChecksumAlgorithm) -> &ChecksumState {
+        match algorithm {
+            ChecksumAlgorithm::Md5 => &self.md5,
+            ChecksumAlgorithm::Sha256 => &self.sha256,
+        }
+    }
+
+    /// 开始计算校验和，返回 CancellationToken 供异步任务使用
+    pub fn start_checksum(&mut self, algorithm: ChecksumAlgorithm) -> CancellationToken {
+        self.set_checksum_state(algorithm, ChecksumState::Running);
+        self.child_token()
+    }
+
+    /// 设置校验和计算结果
+    pub fn set_checksum(&mut self, algorithm: ChecksumAlgorithm, result: Result<String, String>) {
+        let state = match result {
+            Ok(hash) => ChecksumState::Done(hash),
+            Err(e) => ChecksumState::Failed(e),
+        };
+        self.set_checksum_state(algorithm, state);
+    }
+
+    fn set_checksum_state(&mut self, algorithm: ChecksumAlgorithm, state: ChecksumState) {
+        match algorithm {
+            ChecksumAlgorithm::Md5 => self.md5 = state,
+            ChecksumAlgorithm::Sha256 => self.sha256 = state,
+        }
+    }
+
+    /// 扩展属性/ACL 查询的 CancellationToken
+    pub fn extended_attributes_token(&mut self) -> CancellationToken {
+        self.child_token()
+    }
+
+    /// 设置扩展属性与 ACL
+    pub fn set_extended_attributes(&mut self, xattrs: Vec<String>, acl: Vec<String>) {
+        self.extended_attributes = xattrs;
+        self.acl_entries = acl;
+    }
+
+    /// 多选时的数量汇总，如"3 个文件，2 个文件夹"
+    pub fn format_selection_summary(&self, lang: &Language) -> String {
+        let folders = self.selection.iter().filter(|e| e.is_dir()).count();
+        let files = self.selection.len() - folders;
+        i18n::t(lang, "sftp.properties.selection_summary")
+            .replace("{files}", &files.to_string())
+            .replace("{folders}", &folders.to_string())
     }
 
     /// 格式化文件夹大小
-    pub fn format_folder_size(&self) -> String {
+    pub fn format_folder_size(&self, lang: &Language) -> String {
         if let Some(size) = self.folder_size {
             let size_f = size as f64;
             if size_f >= 1_073_741_824.0 {
@@ -107,9 +403,29 @@ impl PropertiesDialogState {
                 format!("{} B", size)
             }
         } else if self.is_calculating_size {
-            "计算中...".to_string()
+            i18n::t(lang, "sftp.properties.calculating").to_string()
+        } else if self.size_unavailable {
+            i18n::t(lang, "sftp.properties.size_unavailable").to_string()
         } else {
             "-".to_string()
         }
     }
 }
+
+/// 格式化权限位为八进制字符串（无特殊位时显示 3 位）
+fn format_octal(mode: u32) -> String {
+    if mode & 0o7000 != 0 {
+        format!("{:04o}", mode)
+    } else {
+        format!("{:03o}", mode)
+    }
+}
+
+/// 将 id -> 名称映射转换为按名称排序的列表
+fn sorted_names(cache: Option<&std::collections::HashMap<u32, String>>) -> Vec<(u32, String)> {
+    let mut names: Vec<(u32, String)> = cache
+        .map(|c| c.iter().map(|(id, name)| (*id, name.clone())).collect())
+        .unwrap_or_default();
+    names.sort_by(|a, b| a.1.cmp(&b.1));
+    names
+}
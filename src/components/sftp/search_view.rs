@@ -0,0 +1,453 @@
+// SFTP 远程搜索组件
+// 工具栏中的搜索框 + 文件列表区域的搜索结果视图
+
+use std::rc::Rc;
+
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::{ActiveTheme, Sizable};
+use tokio_util::sync::CancellationToken;
+
+use crate::constants::icons;
+use crate::models::settings::Language;
+use crate::models::sftp::state::get_parent_path;
+use crate::services::sftp::{SearchHit, SearchMatchMode, SearchQuery, MAX_SEARCH_RESULTS};
+
+/// 搜索事件
+#[derive(Clone, Debug)]
+pub enum SftpSearchEvent {
+    /// 开始搜索（携带本次搜索的取消令牌）
+    Start(SearchQuery, CancellationToken),
+    /// 跳转到搜索结果所在位置
+    Reveal(SearchHit),
+}
+
+/// 搜索状态
+pub struct SftpSearchState {
+    /// 文件名输入框（显示在工具栏）
+    name_input: Entity<InputState>,
+    /// 文件内容输入框（显示在结果视图头部）
+    content_input: Entity<InputState>,
+    /// 匹配模式
+    mode: SearchMatchMode,
+    /// 搜索根目录（当前目录）
+    root: String,
+    /// 本次搜索的根目录
+    searched_root: String,
+    /// 搜索结果
+    results: Vec<SearchHit>,
+    /// 是否正在搜索
+    is_searching: bool,
+    /// 结果是否被截断
+    truncated: bool,
+    /// 错误信息
+    error: Option<String>,
+    /// 是否显示结果视图
+    is_open: bool,
+    /// 当前搜索的取消令牌
+    cancel_token: Option<CancellationToken>,
+    /// 输入框占位符对应的语言
+    lang: Language,
+    /// 事件回调
+    on_event: Rc<dyn Fn(SftpSearchEvent, &mut App)>,
+}
+
+impl SftpSearchState {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(SftpSearchEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        let name_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(crate::i18n::t(&lang, "sftp.search.name_placeholder"))
+        });
+        let content_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder(crate::i18n::t(&lang, "sftp.search.content_placeholder"))
+        });
+
+        // 任一输入框按下回车即开始搜索
+        for input in [&name_input, &content_input] {
+            cx.subscribe(input, |this, _input, event: &InputEvent, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.start(cx);
+                }
+            })
+            .detach();
+        }
+
+        Self {
+            name_input,
+            content_input,
+            mode: SearchMatchMode::default(),
+            root: String::new(),
+            searched_root: String::new(),
+            results: Vec::new(),
+            is_searching: false,
+            truncated: false,
+            error: None,
+            is_open: false,
+            cancel_token: None,
+            lang,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 语言变化后更新输入框占位符
+    pub fn sync_language(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        if lang == self.lang {
+            return;
+        }
+        self.name_input.update(cx, |input, cx| {
+            input.set_placeholder(
+                crate::i18n::t(&lang, "sftp.search.name_placeholder"),
+                window,
+                cx,
+            );
+        });
+        self.content_input.update(cx, |input, cx| {
+            input.set_placeholder(
+                crate::i18n::t(&lang, "sftp.search.content_placeholder"),
+                window,
+                cx,
+            );
+        });
+        self.lang = lang;
+    }
+
+    /// 文件名输入框
+    pub fn name_input(&self) -> &Entity<InputState> {
+        &self.name_input
+    }
+
+    /// 是否显示结果视图
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// 同步搜索根目录
+    pub fn set_root(&mut self, path: &str) {
+        if self.root != path {
+            self.root = path.to_string();
+        }
+    }
+
+    /// 切换匹配模式
+    fn set_mode(&mut self, mode: SearchMatchMode, cx: &mut Context<Self>) {
+        self.mode = mode;
+        cx.notify();
+    }
+
+    /// 开始搜索（会取消正在进行的搜索）
+    pub fn start(&mut self, cx: &mut Context<Self>) {
+        let name_pattern = self.name_input.read(cx).value().trim().to_string();
+        let content_pattern = self.content_input.read(cx).value().to_string();
+        if (name_pattern.is_empty() && content_pattern.is_empty()) || self.root.is_empty() {
+            return;
+        }
+
+        self.cancel();
+        let token = CancellationToken::new();
+        self.cancel_token = Some(token.clone());
+        self.searched_root = self.root.clone();
+        self.results.clear();
+        self.truncated = false;
+        self.error = None;
+        self.is_searching = true;
+        self.is_open = true;
+        cx.notify();
+
+        let query = SearchQuery {
+            root: self.root.clone(),
+            name_pattern,
+            content_pattern,
+            mode: self.mode,
+        };
+        let on_event = self.on_event.clone();
+        on_event(SftpSearchEvent::Start(query, token), cx);
+    }
+
+    /// 追加一批搜索结果
+    pub fn append_results(&mut self, hits: Vec<SearchHit>, cx: &mut Context<Self>) {
+        self.results.extend(hits);
+        cx.notify();
+    }
+
+    /// 搜索结束
+    pub fn finish(&mut self, result: Result<bool, String>, cx: &mut Context<Self>) {
+        self.is_searching = false;
+        self.cancel_token = None;
+        match result {
+            Ok(truncated) => self.truncated = truncated,
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 取消正在进行的搜索
+    fn cancel(&mut self) {
+        if let Some(token) = self.cancel_token.take() {
+            token.cancel();
+        }
+        self.is_searching = false;
+    }
+
+    /// 停止搜索，保留已有结果
+    fn stop(&mut self, cx: &mut Context<Self>) {
+        self.cancel();
+        cx.notify();
+    }
+
+    /// 关闭结果视图
+    pub fn close(&mut self, cx: &mut Context<Self>) {
+        self.cancel();
+        self.is_open = false;
+        self.results.clear();
+        self.error = None;
+        cx.notify();
+    }
+
+    /// 跳转到结果位置并关闭结果视图
+    fn reveal(&mut self, hit: SearchHit, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(SftpSearchEvent::Reveal(hit), cx);
+        self.close(cx);
+    }
+
+    /// 渲染模式切换按钮
+    fn render_mode_button(
+        &self,
+        mode: SearchMatchMode,
+        label: &'static str,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let selected = self.mode == mode;
+        let (bg, fg) = if selected {
+            (cx.theme().list_active, cx.theme().foreground)
+        } else {
+            (transparent_black(), cx.theme().muted_foreground)
+        };
+        let hover_bg = cx.theme().list_hover;
+
+        div()
+            .id(SharedString::from(format!("sftp-search-mode-{}", label)))
+            .px_2()
+            .h(px(22.))
+            .flex()
+            .items_center()
+            .rounded(px(4.))
+            .text_xs()
+            .bg(bg)
+            .text_color(fg)
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .child(label)
+            .on_click(cx.listener(move |this, _, _, cx| this.set_mode(mode, cx)))
+    }
+
+    /// 渲染单条结果
+    fn render_hit(&self, ix: usize, hit: &SearchHit, cx: &mut Context<Self>) -> AnyElement {
+        let name = hit.path.rsplit('/').next().unwrap_or(&hit.path).to_string();
+        // 显示相对于搜索根目录的父路径
+        let parent = get_parent_path(&hit.path);
+        let relative_dir = match parent.strip_prefix(self.searched_root.trim_end_matches('/')) {
+            Some(rest) if !rest.trim_start_matches('/').is_empty() => {
+                rest.trim_start_matches('/').to_string()
+            }
+            Some(_) => ".".to_string(),
+            None => parent.clone(),
+        };
+
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_hover;
+        let hit_for_click = hit.clone();
+
+        let mut row = div()
+            .id(("sftp-search-hit", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .on_click(cx.listener(move |this, _, _, cx| {
+                this.reveal(hit_for_click.clone(), cx);
+            }))
+            .child(
+                svg()
+                    .path(icons::FILE)
+                    .size(px(14.))
+                    .flex_shrink_0()
+                    .text_color(muted),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(cx.theme().foreground)
+                    .child(name),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(relative_dir),
+            );
+
+        if let (Some(line), Some(text)) = (hit.line, hit.text.as_ref()) {
+            row = row.child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(format!("{}: {}", line, text)),
+            );
+        }
+
+        row.into_any_element()
+    }
+}
+
+impl Render for SftpSearchState {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let bg_color = crate::theme::sidebar_color(cx);
+        let border_color = cx.theme().border;
+        let muted = cx.theme().muted_foreground;
+        let hover_bg = cx.theme().list_active;
+
+        // 状态文本
+        let status = if self.is_searching {
+            format!(
+                "{} {} {}",
+                crate::i18n::t(&lang, "sftp.search.searching"),
+                self.results.len(),
+                crate::i18n::t(&lang, "sftp.search.results")
+            )
+        } else if let Some(error) = &self.error {
+            format!("{}: {}", crate::i18n::t(&lang, "sftp.search.failed"), error)
+        } else if self.results.is_empty() {
+            crate::i18n::t(&lang, "sftp.search.no_results").to_string()
+        } else if self.truncated {
+            crate::i18n::t(&lang, "sftp.search.truncated").to_string()
+        } else {
+            format!(
+                "{} {}",
+                self.results.len(),
+                crate::i18n::t(&lang, "sftp.search.results")
+            )
+        };
+
+        let mut header = div()
+            .w_full()
+            .h(px(32.))
+            .flex_shrink_0()
+            .px_2()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(border_color)
+            .child(
+                div()
+                    .w(px(200.))
+                    .child(Input::new(&self.content_input).xsmall()),
+            )
+            .child(self.render_mode_button(
+                SearchMatchMode::Glob,
+                crate::i18n::t(&lang, "sftp.search.glob"),
+                cx,
+            ))
+            .child(self.render_mode_button(
+                SearchMatchMode::Regex,
+                crate::i18n::t(&lang, "sftp.search.regex"),
+                cx,
+            ))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(format!(
+                        "{} {} · {}",
+                        crate::i18n::t(&lang, "sftp.search.in"),
+                        self.searched_root,
+                        status
+                    )),
+            );
+
+        if self.is_searching {
+            header = header.child(
+                div()
+                    .id("sftp-search-stop")
+                    .px_2()
+                    .h(px(22.))
+                    .flex()
+                    .items_center()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(hover_bg))
+                    .child(crate::i18n::t(&lang, "sftp.search.stop"))
+                    .on_click(cx.listener(|this, _, _, cx| this.stop(cx))),
+            );
+        }
+
+        header = header.child(
+            div()
+                .id("sftp-search-close")
+                .size(px(22.))
+                .flex()
+                .items_center()
+                .justify_center()
+                .rounded(px(4.))
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .child(svg().path(icons::X).size(px(14.)).text_color(muted))
+                .on_click(cx.listener(|this, _, _, cx| this.close(cx))),
+        );
+
+        let rows: Vec<AnyElement> = self
+            .results
+            .iter()
+            .take(MAX_SEARCH_RESULTS)
+            .enumerate()
+            .map(|(ix, hit)| self.render_hit(ix, hit, cx))
+            .collect();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(bg_color)
+            .child(header)
+            .child(
+                div()
+                    .id("sftp-search-results")
+                    .flex_1()
+                    .min_h(px(0.))
+                    .overflow_y_scroll()
+                    .children(rows),
+            )
+    }
+}
@@ -0,0 +1,187 @@
+// sudo 密码对话框渲染组件
+
+use gpui::*;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+use super::state::SudoPasswordDialogState;
+
+/// 渲染 sudo 密码对话框覆盖层
+///
+/// `on_confirm` 参数为 (tab_id, 远程文件路径或服务名, 密码)
+pub fn render_sudo_password_dialog_overlay<F>(
+    state: Entity<SudoPasswordDialogState>,
+    on_confirm: F,
+    cx: &App,
+) -> impl IntoElement
+where
+    F: Fn(String, String, String, &mut App) + Clone + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let password_input = state_read.password_input.clone();
+    let error_message = state_read.error_message.clone();
+    let is_verifying = state_read.is_verifying;
+    let remote_path = state_read.remote_path.clone();
+    let title_key = state_read.title_key;
+    let prompt_key = state_read.prompt_key;
+
+    let state_cancel = state.clone();
+    let state_confirm = state.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let danger = cx.theme().danger;
+    let warning = cx.theme().warning;
+
+    div()
+        .id("sudo-password-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(400.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(svg().path(icons::LOCK).size(px(16.)).text_color(warning))
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(foreground)
+                                .child(i18n::t(&lang, title_key)),
+                        ),
+                )
+                // 说明与目标文件
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted_foreground)
+                                .child(i18n::t(&lang, prompt_key)),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(foreground)
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .child(remote_path),
+                        ),
+                )
+                // 密码输入
+                .child(if let Some(input) = &password_input {
+                    Input::new(input).into_any_element()
+                } else {
+                    div()
+                        .text_sm()
+                        .text_color(muted_foreground)
+                        .child(i18n::t(&lang, "common.loading"))
+                        .into_any_element()
+                })
+                // 错误信息
+                .children(error_message.map(|msg| div().text_sm().text_color(danger).child(msg)))
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        // 取消按钮
+                        .child(
+                            div()
+                                .id("sudo-password-cancel-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().secondary)
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(cx.theme().secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    state_cancel.update(cx, |s, _| s.close());
+                                })
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(foreground)
+                                        .child(i18n::t(&lang, "common.cancel")),
+                                ),
+                        )
+                        // 确认按钮
+                        .child({
+                            let confirm_btn = div()
+                                .id("sudo-password-confirm-btn")
+                                .px_4()
+                                .py_2()
+                                .bg(cx.theme().primary)
+                                .rounded_md()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().primary_foreground)
+                                        .child(if is_verifying {
+                                            i18n::t(&lang, "common.loading")
+                                        } else {
+                                            i18n::t(&lang, "common.confirm")
+                                        }),
+                                );
+
+                            if is_verifying {
+                                confirm_btn.opacity(0.6)
+                            } else {
+                                confirm_btn
+                                    .cursor_pointer()
+                                    .hover(move |s| s.bg(cx.theme().primary_hover))
+                                    .on_click(move |_, _, cx| {
+                                        state_confirm.update(cx, |s, cx| {
+                                            let password = s.get_password(cx);
+                                            if password.is_empty() {
+                                                return;
+                                            }
+                                            let tab_id = s.tab_id.clone();
+                                            let remote_path = s.remote_path.clone();
+                                            s.start_verifying();
+                                            on_confirm(tab_id, remote_path, password, cx);
+                                        });
+                                    })
+                            }
+                        }),
+                ),
+        )
+}
@@ -0,0 +1,7 @@
+// SFTP sudo 密码对话框组件（以管理员身份编辑文件时输入密码）
+
+mod dialog;
+mod state;
+
+pub use dialog::render_sudo_password_dialog_overlay;
+pub use state::SudoPasswordDialogState;
@@ -0,0 +1,100 @@
+// sudo 密码对话框状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// sudo 密码对话框状态
+#[derive(Default)]
+pub struct SudoPasswordDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 需要 sudo 权限的对象（远程文件路径或服务名）
+    pub remote_path: String,
+    /// 标题的 i18n key
+    pub title_key: &'static str,
+    /// 说明文字的 i18n key
+    pub prompt_key: &'static str,
+    /// 密码输入框
+    pub password_input: Option<Entity<InputState>>,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 是否正在验证密码
+    pub is_verifying: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+}
+
+impl SudoPasswordDialogState {
+    /// 打开对话框（以管理员身份编辑文件）
+    pub fn open(&mut self, remote_path: String, tab_id: String) {
+        self.open_with(remote_path, tab_id, "sftp.sudo.title", "sftp.sudo.prompt");
+    }
+
+    /// 打开对话框，使用指定的标题与说明文字
+    pub fn open_with(
+        &mut self,
+        remote_path: String,
+        tab_id: String,
+        title_key: &'static str,
+        prompt_key: &'static str,
+    ) {
+        self.is_open = true;
+        self.title_key = title_key;
+        self.prompt_key = prompt_key;
+        self.remote_path = remote_path;
+        self.tab_id = tab_id;
+        self.error_message = None;
+        self.is_verifying = false;
+        // 重置输入框（将在渲染时创建）
+        self.password_input = None;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.remote_path.clear();
+        self.tab_id.clear();
+        self.password_input = None;
+        self.error_message = None;
+        self.is_verifying = false;
+    }
+
+    /// 确保输入框已创建
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.password_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "sftp.sudo.password_placeholder");
+            self.password_input = Some(cx.new(|cx| {
+                InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .masked(true)
+            }));
+        }
+    }
+
+    /// 获取输入的密码
+    pub fn get_password(&self, cx: &App) -> String {
+        self.password_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 开始验证
+    pub fn start_verifying(&mut self) {
+        self.is_verifying = true;
+        self.error_message = None;
+    }
+
+    /// 验证失败
+    pub fn set_error(&mut self, error: String) {
+        self.is_verifying = false;
+        self.error_message = Some(error);
+    }
+}
@@ -0,0 +1,496 @@
+// 目录同步对话框渲染
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::Button;
+use gpui_component::input::Input;
+use gpui_component::ActiveTheme;
+
+use super::state::{SyncDialogPhase, SyncDialogState};
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::sftp::{SyncAction, SyncCompareMode, SyncFileInfo, SyncItem, SyncMode};
+use crate::services::storage;
+
+/// 预览列表最多渲染的条目数
+const MAX_PREVIEW_ROWS: usize = 2000;
+
+/// 同步模式选项
+const MODE_OPTIONS: [(SyncMode, &str); 3] = [
+    (SyncMode::MirrorToRemote, "sftp.sync.mode_to_remote"),
+    (SyncMode::MirrorToLocal, "sftp.sync.mode_to_local"),
+    (SyncMode::Bidirectional, "sftp.sync.mode_both"),
+];
+
+/// 比较方式选项
+const COMPARE_OPTIONS: [(SyncCompareMode, &str); 2] = [
+    (SyncCompareMode::SizeMtime, "sftp.sync.compare_size_mtime"),
+    (SyncCompareMode::Checksum, "sftp.sync.compare_checksum"),
+];
+
+/// 渲染目录同步对话框覆盖层
+/// `on_compare` 在点击"比较"时调用，`on_execute` 在预览中点击"执行"时调用
+pub fn render_sync_dialog_overlay<C, E>(
+    state: Entity<SyncDialogState>,
+    on_compare: C,
+    on_execute: E,
+    cx: &App,
+) -> impl IntoElement
+where
+    C: Fn(Entity<SyncDialogState>, &mut App) + 'static,
+    E: Fn(Entity<SyncDialogState>, &mut App) + 'static,
+{
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let state_read = state.read(cx);
+    let phase = state_read.phase;
+    let error_message = state_read.error_message.clone();
+
+    let bg_color = cx.theme().popover;
+    let border_color = cx.theme().border;
+    let foreground = cx.theme().foreground;
+    let danger = cx.theme().danger;
+
+    let body = match phase {
+        SyncDialogPhase::Configure => render_configure(&state, &lang, cx).into_any_element(),
+        SyncDialogPhase::Comparing => div()
+            .py_6()
+            .flex()
+            .justify_center()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(i18n::t(&lang, "sftp.sync.comparing"))
+            .into_any_element(),
+        SyncDialogPhase::Preview => render_preview(&state, &lang, cx).into_any_element(),
+    };
+
+    // 底部按钮：左侧为取消/返回，右侧为比较/执行
+    let state_secondary = state.clone();
+    let is_configure = phase == SyncDialogPhase::Configure;
+    let secondary_label = if is_configure {
+        i18n::t(&lang, "common.cancel")
+    } else {
+        i18n::t(&lang, "sftp.sync.back")
+    };
+    let secondary = div()
+        .id("sync-secondary-btn")
+        .px_4()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(cx.theme().secondary_hover))
+        .on_click(move |_, _, cx| {
+            state_secondary.update(cx, |s, cx| {
+                if is_configure {
+                    s.close();
+                } else {
+                    s.back();
+                }
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .text_sm()
+                .text_color(foreground)
+                .child(secondary_label),
+        );
+
+    let (primary_label, primary_enabled) = match phase {
+        SyncDialogPhase::Configure => (i18n::t(&lang, "sftp.sync.compare"), true),
+        SyncDialogPhase::Comparing => (i18n::t(&lang, "sftp.sync.execute"), false),
+        SyncDialogPhase::Preview => {
+            let summary = state_read.summary();
+            (
+                i18n::t(&lang, "sftp.sync.execute"),
+                summary.upload + summary.download + summary.delete > 0,
+            )
+        }
+    };
+    let primary = div()
+        .id("sync-primary-btn")
+        .px_4()
+        .py_2()
+        .bg(cx.theme().primary)
+        .rounded_md()
+        .child(
+            div()
+                .text_sm()
+                .text_color(cx.theme().primary_foreground)
+                .child(primary_label),
+        );
+    let state_primary = state.clone();
+    let primary = if !primary_enabled {
+        primary.opacity(0.6)
+    } else {
+        primary
+            .cursor_pointer()
+            .hover(move |s| s.bg(cx.theme().primary_hover))
+            .on_click(move |_, _, cx| {
+                if phase == SyncDialogPhase::Preview {
+                    on_execute(state_primary.clone(), cx);
+                } else if state_primary.update(cx, |s, cx| {
+                    let valid = s.validate(cx);
+                    cx.notify();
+                    valid
+                }) {
+                    on_compare(state_primary.clone(), cx);
+                }
+            })
+    };
+
+    div()
+        .id("sync-dialog-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.5))
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+        })
+        .child(
+            div()
+                .w(px(640.))
+                .max_h(px(560.))
+                .bg(bg_color)
+                .rounded_lg()
+                .border_1()
+                .border_color(border_color)
+                .p_6()
+                .flex()
+                .flex_col()
+                .gap_4()
+                // 标题
+                .child(
+                    div()
+                        .text_lg()
+                        .font_weight(FontWeight::BOLD)
+                        .text_color(foreground)
+                        .child(i18n::t(&lang, "sftp.sync.title")),
+                )
+                .child(body)
+                // 错误信息
+                .children(error_message.map(|msg| div().text_sm().text_color(danger).child(msg)))
+                // 底部按钮
+                .child(
+                    div()
+                        .flex()
+                        .justify_end()
+                        .gap_3()
+                        .pt_2()
+                        .child(secondary)
+                        .child(primary),
+                ),
+        )
+}
+
+/// 渲染配置步骤：目录、同步模式与比较方式
+fn render_configure(state: &Entity<SyncDialogState>, lang: &Language, cx: &App) -> Div {
+    let state_read = state.read(cx);
+    let muted = cx.theme().muted_foreground;
+
+    let local_row = div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(match &state_read.local_input {
+            Some(input) => div().flex_1().child(Input::new(input)).into_any_element(),
+            None => div().flex_1().into_any_element(),
+        })
+        .child({
+            let state = state.clone();
+            let title = i18n::t(lang, "sftp.sync.local_dir");
+            Button::new("sync-browse-local")
+                .outline()
+                .child(i18n::t(lang, "sftp.sync.browse"))
+                .on_click(move |_, _, cx| {
+                    let state = state.clone();
+                    cx.spawn(async move |cx| {
+                        let picker = rfd::AsyncFileDialog::new().set_title(title);
+                        if let Some(folder) = picker.pick_folder().await {
+                            let path = folder.path().to_string_lossy().to_string();
+                            let _ = state.update(cx, |s, cx| {
+                                s.set_local_path(path);
+                                cx.notify();
+                            });
+                        }
+                    })
+                    .detach();
+                })
+        });
+
+    let remote_row = match &state_read.remote_input {
+        Some(input) => Input::new(input).into_any_element(),
+        None => div().into_any_element(),
+    };
+
+    let mode = state_read.mode;
+    let compare = state_read.compare;
+    let mode_hint = match mode {
+        SyncMode::MirrorToRemote => "sftp.sync.mode_to_remote_hint",
+        SyncMode::MirrorToLocal => "sftp.sync.mode_to_local_hint",
+        SyncMode::Bidirectional => "sftp.sync.mode_both_hint",
+    };
+
+    let mode_options = MODE_OPTIONS.iter().map(|&(option, key)| {
+        let state = state.clone();
+        render_option(
+            SharedString::from(format!("sync-mode-{:?}", option)),
+            i18n::t(lang, key),
+            option == mode,
+            move |cx| {
+                state.update(cx, |s, cx| {
+                    s.mode = option;
+                    cx.notify();
+                })
+            },
+            cx,
+        )
+    });
+    let compare_options = COMPARE_OPTIONS.iter().map(|&(option, key)| {
+        let state = state.clone();
+        render_option(
+            SharedString::from(format!("sync-compare-{:?}", option)),
+            i18n::t(lang, key),
+            option == compare,
+            move |cx| {
+                state.update(cx, |s, cx| {
+                    s.compare = option;
+                    cx.notify();
+                })
+            },
+            cx,
+        )
+    });
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(render_label(i18n::t(lang, "sftp.sync.local_dir"), muted))
+        .child(local_row)
+        .child(render_label(i18n::t(lang, "sftp.sync.remote_dir"), muted))
+        .child(remote_row)
+        .child(render_label(i18n::t(lang, "sftp.sync.mode"), muted))
+        .child(div().flex().gap_2().children(mode_options))
+        .child(
+            div()
+                .text_xs()
+                .text_color(muted)
+                .child(i18n::t(lang, mode_hint)),
+        )
+        .child(render_label(i18n::t(lang, "sftp.sync.compare_by"), muted))
+        .child(div().flex().gap_2().children(compare_options))
+}
+
+/// 渲染预览步骤：统计信息与差异列表
+fn render_preview(state: &Entity<SyncDialogState>, lang: &Language, cx: &App) -> Div {
+    let state_read = state.read(cx);
+    let muted = cx.theme().muted_foreground;
+    let summary = state_read.summary();
+
+    let summary_text = format!(
+        "{} {} · {} {} · {} {} · {} {} · {} {}",
+        i18n::t(lang, "sftp.sync.action_upload"),
+        summary.upload,
+        i18n::t(lang, "sftp.sync.action_download"),
+        summary.download,
+        i18n::t(lang, "sftp.sync.action_delete"),
+        summary.delete,
+        i18n::t(lang, "sftp.sync.action_skip"),
+        summary.skip,
+        i18n::t(lang, "sftp.sync.identical"),
+        state_read.identical_count,
+    );
+
+    let list: AnyElement = if state_read.items.is_empty() {
+        div()
+            .py_6()
+            .flex()
+            .justify_center()
+            .text_sm()
+            .text_color(muted)
+            .child(i18n::t(lang, "sftp.sync.in_sync"))
+            .into_any_element()
+    } else {
+        let rows: Vec<AnyElement> = state_read
+            .items
+            .iter()
+            .take(MAX_PREVIEW_ROWS)
+            .enumerate()
+            .map(|(ix, item)| render_item(state, ix, item, lang, cx))
+            .collect();
+        div()
+            .id("sync-preview-list")
+            .h(px(300.))
+            .overflow_y_scroll()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .children(rows)
+            .into_any_element()
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_3()
+        .child(div().text_sm().text_color(muted).child(summary_text))
+        .when(!state_read.items.is_empty(), |this| {
+            this.child(
+                div()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(i18n::t(lang, "sftp.sync.click_to_change")),
+            )
+        })
+        .child(list)
+}
+
+/// 渲染差异列表中的一行，点击操作标签切换操作
+fn render_item(
+    state: &Entity<SyncDialogState>,
+    ix: usize,
+    item: &SyncItem,
+    lang: &Language,
+    cx: &App,
+) -> AnyElement {
+    let muted = cx.theme().muted_foreground;
+    let (action_key, action_color) = match item.action {
+        SyncAction::Upload => ("sftp.sync.action_upload", cx.theme().primary),
+        SyncAction::Download => ("sftp.sync.action_download", cx.theme().success),
+        SyncAction::DeleteLocal => ("sftp.sync.action_delete_local", cx.theme().danger),
+        SyncAction::DeleteRemote => ("sftp.sync.action_delete_remote", cx.theme().danger),
+        SyncAction::Skip => ("sftp.sync.action_skip", muted),
+    };
+    let state = state.clone();
+
+    div()
+        .id(("sync-item", ix))
+        .w_full()
+        .px_2()
+        .py_1()
+        .flex()
+        .items_center()
+        .gap_2()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .child(
+            div()
+                .id(("sync-item-action", ix))
+                .w(px(72.))
+                .flex_shrink_0()
+                .px_1()
+                .rounded(px(4.))
+                .border_1()
+                .border_color(action_color)
+                .text_xs()
+                .text_center()
+                .text_color(action_color)
+                .cursor_pointer()
+                .child(i18n::t(lang, action_key))
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |s, cx| {
+                        s.cycle_action(ix);
+                        cx.notify();
+                    });
+                }),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .text_xs()
+                .text_color(cx.theme().foreground)
+                .child(item.relative_path.clone()),
+        )
+        .child(
+            div()
+                .w(px(150.))
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(muted)
+                .child(format_file_info(item.local.as_ref())),
+        )
+        .child(
+            div()
+                .w(px(150.))
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(muted)
+                .child(format_file_info(item.remote.as_ref())),
+        )
+        .into_any_element()
+}
+
+/// 渲染选项按钮
+fn render_option(
+    id: SharedString,
+    label: &'static str,
+    selected: bool,
+    on_select: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    let (bg, fg) = if selected {
+        (cx.theme().primary, cx.theme().primary_foreground)
+    } else {
+        (cx.theme().secondary, cx.theme().foreground)
+    };
+    let hover_bg = if selected {
+        cx.theme().primary_hover
+    } else {
+        cx.theme().secondary_hover
+    };
+
+    div()
+        .id(id)
+        .px_3()
+        .py_1()
+        .rounded_md()
+        .bg(bg)
+        .text_sm()
+        .text_color(fg)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover_bg))
+        .child(label)
+        .on_click(move |_, _, cx| on_select(cx))
+}
+
+/// 渲染字段标签
+fn render_label(text: &'static str, color: Hsla) -> impl IntoElement {
+    div().text_sm().text_color(color).child(text)
+}
+
+/// 格式化一侧的文件信息（大小与修改时间）
+fn format_file_info(info: Option<&SyncFileInfo>) -> String {
+    let Some(info) = info else {
+        return "-".to_string();
+    };
+    let size = info.size as f64;
+    let size = if size >= 1_073_741_824.0 {
+        format!("{:.1} GB", size / 1_073_741_824.0)
+    } else if size >= 1_048_576.0 {
+        format!("{:.1} MB", size / 1_048_576.0)
+    } else if size >= 1_024.0 {
+        format!("{:.1} KB", size / 1_024.0)
+    } else {
+        format!("{} B", info.size)
+    };
+    match info.modified {
+        Some(t) => {
+            let datetime: chrono::DateTime<chrono::Local> = t.into();
+            format!("{} {}", size, datetime.format("%m-%d %H:%M"))
+        }
+        None => size,
+    }
+}
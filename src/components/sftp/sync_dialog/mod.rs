@@ -0,0 +1,7 @@
+// 目录同步对话框模块
+
+mod dialog;
+mod state;
+
+pub use dialog::render_sync_dialog_overlay;
+pub use state::SyncDialogState;
@@ -0,0 +1,235 @@
+// 目录同步对话框状态管理
+
+use gpui::{App, AppContext, Context, Entity, Window};
+use gpui_component::input::InputState;
+use tokio_util::sync::CancellationToken;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::sftp::{SyncAction, SyncCompareMode, SyncItem, SyncMode};
+use crate::services::storage;
+
+/// 对话框所处的步骤
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncDialogPhase {
+    /// 选择目录与同步方式
+    Configure,
+    /// 正在比较两侧文件
+    Comparing,
+    /// 预览差异并调整每个文件的操作
+    Preview,
+}
+
+/// 同步计划的操作统计
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SyncPlanSummary {
+    pub upload: usize,
+    pub download: usize,
+    pub delete: usize,
+    pub skip: usize,
+}
+
+/// 目录同步对话框状态
+pub struct SyncDialogState {
+    /// 是否打开
+    pub is_open: bool,
+    /// 关联的 tab_id
+    pub tab_id: String,
+    /// 当前步骤
+    pub phase: SyncDialogPhase,
+    /// 本地目录输入框
+    pub local_input: Option<Entity<InputState>>,
+    /// 远程目录输入框
+    pub remote_input: Option<Entity<InputState>>,
+    /// 同步模式
+    pub mode: SyncMode,
+    /// 比较方式
+    pub compare: SyncCompareMode,
+    /// 差异列表（同步计划）
+    pub items: Vec<SyncItem>,
+    /// 内容相同而未列出的文件数
+    pub identical_count: usize,
+    /// 错误信息
+    pub error_message: Option<String>,
+    /// 打开时的初始本地目录（输入框创建时填入）
+    pending_local: Option<String>,
+    /// 打开时的初始远程目录（输入框创建时填入）
+    pending_remote: Option<String>,
+    /// 比较任务的取消令牌
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl Default for SyncDialogState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            tab_id: String::new(),
+            phase: SyncDialogPhase::Configure,
+            local_input: None,
+            remote_input: None,
+            mode: SyncMode::default(),
+            compare: SyncCompareMode::default(),
+            items: Vec::new(),
+            identical_count: 0,
+            error_message: None,
+            pending_local: None,
+            pending_remote: None,
+            cancellation_token: None,
+        }
+    }
+}
+
+impl SyncDialogState {
+    /// 打开对话框
+    pub fn open(&mut self, tab_id: String, local_path: String, remote_path: String) {
+        self.cancel_compare();
+        self.is_open = true;
+        self.tab_id = tab_id;
+        self.phase = SyncDialogPhase::Configure;
+        self.items.clear();
+        self.identical_count = 0;
+        self.error_message = None;
+        self.pending_local = Some(local_path);
+        self.pending_remote = Some(remote_path);
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.cancel_compare();
+        self.is_open = false;
+        self.tab_id.clear();
+        self.phase = SyncDialogPhase::Configure;
+        self.items.clear();
+        self.identical_count = 0;
+        self.error_message = None;
+    }
+
+    /// 确保输入框已创建，并填入待设置的路径
+    pub fn ensure_input_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        if self.local_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.sync.local_placeholder");
+            self.local_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        if self.remote_input.is_none() {
+            let placeholder = i18n::t(&lang, "sftp.sync.remote_placeholder");
+            self.remote_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+        for (pending, input) in [
+            (self.pending_local.take(), &self.local_input),
+            (self.pending_remote.take(), &self.remote_input),
+        ] {
+            if let (Some(value), Some(input)) = (pending, input) {
+                input.update(cx, |input, cx| input.set_value(value, window, cx));
+            }
+        }
+    }
+
+    /// 设置本地目录（文件夹选择器返回后调用，下次渲染时写入输入框）
+    pub fn set_local_path(&mut self, path: String) {
+        self.pending_local = Some(path);
+    }
+
+    /// 输入的本地目录
+    pub fn local_path(&self, cx: &App) -> String {
+        self.local_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 输入的远程目录
+    pub fn remote_path(&self, cx: &App) -> String {
+        self.remote_input
+            .as_ref()
+            .map(|i| i.read(cx).text().to_string().trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 验证输入的目录
+    pub fn validate(&mut self, cx: &App) -> bool {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+
+        let local = self.local_path(cx);
+        if local.is_empty() || !std::path::Path::new(&local).is_dir() {
+            self.error_message = Some(i18n::t(&lang, "sftp.sync.error_local").to_string());
+            return false;
+        }
+        if !self.remote_path(cx).starts_with('/') {
+            self.error_message = Some(i18n::t(&lang, "sftp.sync.error_remote").to_string());
+            return false;
+        }
+
+        self.error_message = None;
+        true
+    }
+
+    /// 开始比较，返回本次比较的取消令牌
+    pub fn start_comparing(&mut self) -> CancellationToken {
+        self.cancel_compare();
+        let token = CancellationToken::new();
+        self.cancellation_token = Some(token.clone());
+        self.phase = SyncDialogPhase::Comparing;
+        self.items.clear();
+        self.identical_count = 0;
+        self.error_message = None;
+        token
+    }
+
+    /// 比较完成，进入预览
+    pub fn set_plan(&mut self, items: Vec<SyncItem>, identical_count: usize) {
+        self.cancellation_token = None;
+        self.phase = SyncDialogPhase::Preview;
+        self.items = items;
+        self.identical_count = identical_count;
+    }
+
+    /// 比较失败，回到配置步骤
+    pub fn set_error(&mut self, message: String) {
+        self.cancellation_token = None;
+        self.phase = SyncDialogPhase::Configure;
+        self.error_message = Some(message);
+    }
+
+    /// 返回配置步骤（取消正在进行的比较）
+    pub fn back(&mut self) {
+        self.cancel_compare();
+        self.phase = SyncDialogPhase::Configure;
+        self.items.clear();
+        self.error_message = None;
+    }
+
+    /// 切换某个文件的操作
+    pub fn cycle_action(&mut self, ix: usize) {
+        if let Some(item) = self.items.get_mut(ix) {
+            item.cycle_action();
+        }
+    }
+
+    /// 统计各类操作数量
+    pub fn summary(&self) -> SyncPlanSummary {
+        let mut summary = SyncPlanSummary::default();
+        for item in &self.items {
+            match item.action {
+                SyncAction::Upload => summary.upload += 1,
+                SyncAction::Download => summary.download += 1,
+                SyncAction::DeleteLocal | SyncAction::DeleteRemote => summary.delete += 1,
+                SyncAction::Skip => summary.skip += 1,
+            }
+        }
+        summary
+    }
+
+    /// 取消正在进行的比较
+    fn cancel_compare(&mut self) {
+        if let Some(token) = self.cancellation_token.take() {
+            token.cancel();
+        }
+    }
+}
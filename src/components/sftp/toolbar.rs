@@ -1,12 +1,16 @@
 // SFTP 工具栏组件
-// 包含导航按钮（返回、前进、上级、主目录）+ 地址栏 + 操作按钮
+// 包含导航按钮（返回、前进、上级、主目录）+ 地址栏 + 收藏夹 + 搜索框 + 操作按钮
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
-use gpui_component::ActiveTheme;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::Input;
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::{ActiveTheme, Sizable};
 
-use super::PathBarState;
+use super::{PathBarState, SftpSearchState};
 use crate::constants::icons;
-use crate::models::sftp::SftpState;
+use crate::models::sftp::{ServerBookmarks, SftpState};
 
 /// 工具栏高度
 const TOOLBAR_HEIGHT: f32 = 32.0;
@@ -25,8 +29,22 @@ pub enum SftpToolbarEvent {
     Refresh,
     NewFolder,
     ToggleHidden,
+    ToggleDualPane,
+    TogglePreview,
+    /// 开启或关闭当前目录的监视（内容变化时自动刷新）
+    ToggleWatch,
+    /// 分析当前目录的磁盘占用
+    DiskUsage,
+    /// 打开远程日志查看器
+    LogViewer,
     Upload,
     Download,
+    /// 收藏或取消收藏当前目录
+    ToggleBookmark,
+    /// 跳转到收藏夹或最近目录中的路径
+    Navigate(String),
+    /// 重新打开最近编辑的文件
+    EditFile(String),
 }
 
 /// 渲染工具栏按钮
@@ -71,10 +89,73 @@ where
     el
 }
 
+/// 渲染收藏夹菜单按钮（收藏目录 + 最近目录 + 最近文件）
+fn render_bookmarks_menu<F>(bookmarks: ServerBookmarks, on_event: F, cx: &App) -> impl IntoElement
+where
+    F: Fn(SftpToolbarEvent, &mut App) + Clone + 'static,
+{
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+
+    Button::new("sftp-btn-bookmarks")
+        .ghost()
+        .xsmall()
+        .tooltip(crate::i18n::t(&lang, "sftp.bookmarks.title"))
+        .child(
+            svg()
+                .path(icons::BOOKMARK)
+                .size(px(ICON_SIZE))
+                .text_color(cx.theme().foreground),
+        )
+        .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+            let sections: [(&str, &Vec<String>, fn(String) -> SftpToolbarEvent); 3] = [
+                (
+                    "sftp.bookmarks.title",
+                    &bookmarks.bookmarks,
+                    SftpToolbarEvent::Navigate,
+                ),
+                (
+                    "sftp.bookmarks.recent",
+                    &bookmarks.recent,
+                    SftpToolbarEvent::Navigate,
+                ),
+                (
+                    "sftp.bookmarks.recent_files",
+                    &bookmarks.recent_files,
+                    SftpToolbarEvent::EditFile,
+                ),
+            ];
+            sections.into_iter().enumerate().fold(
+                menu.min_w(px(200.)),
+                |menu, (ix, (title, paths, event))| {
+                    let menu = if ix > 0 { menu.separator() } else { menu };
+                    let menu = menu.label(crate::i18n::t(&lang, title));
+                    if paths.is_empty() {
+                        return menu.item(
+                            PopupMenuItem::new(crate::i18n::t(&lang, "sftp.bookmarks.empty"))
+                                .disabled(true),
+                        );
+                    }
+                    paths.iter().fold(menu, |menu, path| {
+                        let on_event = on_event.clone();
+                        let path = path.clone();
+                        menu.item(
+                            PopupMenuItem::new(path.clone())
+                                .on_click(move |_, _, cx| on_event(event(path.clone()), cx)),
+                        )
+                    })
+                },
+            )
+        })
+}
+
 /// 渲染 SFTP 工具栏
 pub fn render_sftp_toolbar<F>(
     state: Option<&SftpState>,
     path_bar_state: Entity<PathBarState>,
+    search_state: Option<Entity<SftpSearchState>>,
+    bookmarks: ServerBookmarks,
     on_event: F,
     cx: &App,
 ) -> impl IntoElement
@@ -85,15 +166,19 @@ where
     let border_color = cx.theme().border;
 
     // 获取状态信息
-    let (can_back, can_forward, can_up, show_hidden) = match state {
-        Some(s) => (
-            s.can_go_back(),
-            s.can_go_forward(),
-            s.can_go_up(),
-            s.show_hidden,
-        ),
-        None => (false, false, false, false),
-    };
+    let (can_back, can_forward, can_up, show_hidden, dual_pane, preview_open, watching) =
+        match state {
+            Some(s) => (
+                s.can_go_back(),
+                s.can_go_forward(),
+                s.can_go_up(),
+                s.show_hidden,
+                s.dual_pane,
+                s.preview_open,
+                s.is_watching(),
+            ),
+            None => (false, false, false, false, false, false, false),
+        };
 
     // === 导航按钮组 ===
     let on_back = on_event.clone();
@@ -146,6 +231,53 @@ where
     // === 地址栏（使用 PathBarState 组件，支持编辑模式） ===
     let path_bar = div().flex_1().mx_2().child(path_bar_state);
 
+    // === 收藏夹：收藏当前目录 + 收藏目录/最近目录菜单 ===
+    let bookmarked = state.is_some_and(|s| bookmarks.is_bookmarked(&s.current_path));
+    let on_toggle_bookmark = on_event.clone();
+    let bookmark_buttons = div()
+        .flex()
+        .items_center()
+        .gap_0p5()
+        .flex_shrink_0()
+        .child(
+            div()
+                .id("sftp-btn-star")
+                .size(px(BUTTON_SIZE))
+                .flex()
+                .items_center()
+                .justify_center()
+                .rounded(px(4.))
+                .cursor_pointer()
+                .hover(|s| s.bg(cx.theme().list_active))
+                .child(
+                    svg()
+                        .path(icons::STAR)
+                        .size(px(ICON_SIZE))
+                        .text_color(if bookmarked {
+                            cx.theme().warning
+                        } else {
+                            cx.theme().foreground
+                        }),
+                )
+                .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                    on_toggle_bookmark(SftpToolbarEvent::ToggleBookmark, cx);
+                }),
+        )
+        .child(render_bookmarks_menu(bookmarks, on_event.clone(), cx));
+
+    // === 搜索框（回车在当前目录下搜索） ===
+    let search_box = search_state.map(|search_state| {
+        let input = search_state.read(cx).name_input().clone();
+        div().w(px(160.)).flex_shrink_0().child(
+            Input::new(&input).xsmall().prefix(
+                svg()
+                    .path(icons::SEARCH)
+                    .size(px(12.))
+                    .text_color(cx.theme().muted_foreground),
+            ),
+        )
+    });
+
     // === 操作按钮组 ===
     let hidden_icon = if show_hidden {
         icons::EYE_OFF
@@ -154,8 +286,13 @@ where
     };
 
     let on_refresh = on_event.clone();
+    let on_toggle_watch = on_event.clone();
     let on_new_folder = on_event.clone();
     let on_toggle_hidden = on_event.clone();
+    let on_toggle_dual_pane = on_event.clone();
+    let on_toggle_preview = on_event.clone();
+    let on_disk_usage = on_event.clone();
+    let on_log_viewer = on_event.clone();
     let on_upload = on_event.clone();
     let on_download = on_event.clone();
 
@@ -173,6 +310,20 @@ where
             }),
             cx,
         ))
+        .child(
+            div()
+                .rounded(px(4.))
+                .when(watching, |el| el.bg(cx.theme().list_active))
+                .child(toolbar_button(
+                    "sftp-btn-watch",
+                    icons::ACTIVITY,
+                    true,
+                    Some(move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
+                        on_toggle_watch(SftpToolbarEvent::ToggleWatch, cx);
+                    }),
+                    cx,
+                )),
+        )
         .child(toolbar_button(
             "sftp-btn-new-folder",
             icons::FOLDER_PLUS,
@@ -191,6 +342,52 @@ where
             }),
             cx,
         ))
+        .child(
+            div()
+                .rounded(px(4.))
+                .when(dual_pane, |el| el.bg(cx.theme().list_active))
+                .child(toolbar_button(
+                    "sftp-btn-dual-pane",
+                    icons::COLUMNS,
+                    true,
+                    Some(move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
+                        on_toggle_dual_pane(SftpToolbarEvent::ToggleDualPane, cx);
+                    }),
+                    cx,
+                )),
+        )
+        .child(
+            div()
+                .rounded(px(4.))
+                .when(preview_open, |el| el.bg(cx.theme().list_active))
+                .child(toolbar_button(
+                    "sftp-btn-preview",
+                    icons::PANEL_RIGHT_OPEN,
+                    true,
+                    Some(move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
+                        on_toggle_preview(SftpToolbarEvent::TogglePreview, cx);
+                    }),
+                    cx,
+                )),
+        )
+        .child(toolbar_button(
+            "sftp-btn-disk-usage",
+            icons::HARD_DRIVE,
+            true,
+            Some(move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
+                on_disk_usage(SftpToolbarEvent::DiskUsage, cx);
+            }),
+            cx,
+        ))
+        .child(toolbar_button(
+            "sftp-btn-log-viewer",
+            icons::FILE_TEXT,
+            true,
+            Some(move |_: &MouseDownEvent, _: &mut Window, cx: &mut App| {
+                on_log_viewer(SftpToolbarEvent::LogViewer, cx);
+            }),
+            cx,
+        ))
         .child(div().w(px(1.)).h(px(16.)).mx_1().bg(border_color))
         .child(toolbar_button(
             "sftp-btn-upload",
@@ -225,5 +422,7 @@ where
         .gap_1()
         .child(nav_buttons)
         .child(path_bar)
+        .child(bookmark_buttons)
+        .children(search_box)
         .child(action_buttons)
 }
@@ -0,0 +1,5 @@
+// systemd 服务管理组件模块
+
+mod view;
+
+pub use view::{ServiceEvent, ServiceListView};
@@ -0,0 +1,399 @@
+// systemd 服务管理视图
+// 显示在 Monitor 区域的"服务"页：过滤栏 + 带状态标记的服务列表，右键启停、设置开机启动或查看日志（停止/重启/禁用前需确认）
+
+use std::rc::Rc;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::menu::{ContextMenuExt, PopupMenu, PopupMenuItem};
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::components::common::confirm_bar::{self, ConfirmBarHost, ConfirmTone};
+use crate::constants::icons;
+use crate::i18n::t;
+use crate::models::settings::Language;
+use crate::services::systemd::{ServiceAction, ServiceUnit};
+
+/// 服务管理事件
+#[derive(Clone, Debug)]
+pub enum ServiceEvent {
+    /// 对服务执行 systemctl 操作
+    Action(String, ServiceAction),
+    /// 在新终端中查看服务日志
+    Journal(String),
+}
+
+/// 等待确认的操作
+#[derive(Clone, Debug)]
+pub struct PendingAction {
+    unit: String,
+    action: ServiceAction,
+}
+
+/// systemd 服务管理视图
+pub struct ServiceListView {
+    services: Vec<ServiceUnit>,
+    filter_input: Entity<InputState>,
+    /// 过滤条件（小写，匹配单元名和描述）
+    filter: String,
+    /// 是否已收到过服务列表
+    loaded: bool,
+    /// 最近一次获取列表的错误
+    error: Option<String>,
+    /// 最近一次操作失败的提示
+    action_error: Option<String>,
+    /// 等待确认的操作
+    pending: Option<PendingAction>,
+    on_event: Rc<dyn Fn(ServiceEvent, &mut App)>,
+}
+
+impl ServiceListView {
+    pub fn new<F>(window: &mut Window, cx: &mut Context<Self>, on_event: F) -> Self
+    where
+        F: Fn(ServiceEvent, &mut App) + 'static,
+    {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+
+        // 输入过滤条件时即时过滤服务列表
+        let filter_input = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(&lang, "services.filter_placeholder"))
+        });
+        cx.subscribe(&filter_input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change = event {
+                this.filter = input.read(cx).value().trim().to_lowercase();
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            services: Vec::new(),
+            filter_input,
+            filter: String::new(),
+            loaded: false,
+            error: None,
+            action_error: None,
+            pending: None,
+            on_event: Rc::new(on_event),
+        }
+    }
+
+    /// 写入最新的服务列表
+    pub fn set_services(
+        &mut self,
+        result: Result<Vec<ServiceUnit>, String>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(services) => {
+                self.loaded = true;
+                self.error = None;
+                self.services = services;
+            }
+            Err(e) => self.error = Some(e),
+        }
+        cx.notify();
+    }
+
+    /// 显示操作失败的提示
+    pub fn set_action_error(&mut self, error: Option<String>, cx: &mut Context<Self>) {
+        self.action_error = error;
+        cx.notify();
+    }
+
+    /// 发送事件（启动、启用、查看日志无需确认）
+    fn emit(&mut self, event: ServiceEvent, cx: &mut Context<Self>) {
+        self.action_error = None;
+        let on_event = self.on_event.clone();
+        on_event(event, cx);
+        cx.notify();
+    }
+
+    /// 请求执行操作：停止、重启、禁用先显示确认栏
+    fn request(&mut self, unit: String, action: ServiceAction, cx: &mut Context<Self>) {
+        match action {
+            ServiceAction::Stop | ServiceAction::Restart | ServiceAction::Disable => {
+                self.action_error = None;
+                self.request_confirm(PendingAction { unit, action }, cx);
+            }
+            ServiceAction::Start | ServiceAction::Enable => {
+                self.emit(ServiceEvent::Action(unit, action), cx)
+            }
+        }
+    }
+
+    /// 渲染服务行
+    fn render_service(
+        &self,
+        ix: usize,
+        service: &ServiceUnit,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let badge_color = match service.active.as_str() {
+            "active" => cx.theme().success,
+            "failed" => cx.theme().danger,
+            "activating" | "deactivating" | "reloading" => cx.theme().warning,
+            _ => cx.theme().muted_foreground,
+        };
+        let state_text = if service.load == "loaded" {
+            format!("{} ({})", service.active, service.sub)
+        } else {
+            service.load.clone()
+        };
+        let entity = cx.entity().clone();
+        let service_for_menu = service.clone();
+
+        div()
+            .id(("service-row", ix))
+            .w_full()
+            .px_2()
+            .py_1()
+            .flex()
+            .items_center()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border.opacity(0.5))
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(service.name().to_string()),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .text_ellipsis()
+                            .child(service.description.clone()),
+                    ),
+            )
+            .when(!service.enabled.is_empty(), |this| {
+                this.child(
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(service.enabled.clone()),
+                )
+            })
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .rounded(px(4.))
+                    .text_xs()
+                    .text_color(badge_color)
+                    .bg(badge_color.opacity(0.12))
+                    .child(state_text),
+            )
+            .context_menu(move |menu, _window, _cx| {
+                let lang = crate::services::storage::load_settings()
+                    .map(|s| s.theme.language)
+                    .unwrap_or_default();
+                build_service_context_menu(menu, service_for_menu.clone(), &lang, entity.clone())
+            })
+            .into_any_element()
+    }
+
+    /// 渲染确认栏
+    fn render_confirm_bar(
+        &self,
+        pending: &PendingAction,
+        lang: &Language,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let message = match pending.action {
+            ServiceAction::Restart => t(lang, "services.confirm_restart"),
+            ServiceAction::Disable => t(lang, "services.confirm_disable"),
+            _ => t(lang, "services.confirm_stop"),
+        }
+        .replace("{}", &pending.unit);
+        confirm_bar::render_confirm_bar("services-confirm", message, ConfirmTone::Danger, lang, cx)
+    }
+}
+
+impl ConfirmBarHost for ServiceListView {
+    type Pending = PendingAction;
+
+    fn pending_mut(&mut self) -> &mut Option<PendingAction> {
+        &mut self.pending
+    }
+
+    fn run_confirmed(&mut self, pending: PendingAction, cx: &mut Context<Self>) {
+        let on_event = self.on_event.clone();
+        on_event(ServiceEvent::Action(pending.unit, pending.action), cx);
+    }
+}
+
+/// 构建服务右键菜单：启停、开机启动、查看日志
+fn build_service_context_menu(
+    menu: PopupMenu,
+    service: ServiceUnit,
+    lang: &Language,
+    entity: Entity<ServiceListView>,
+) -> PopupMenu {
+    let action_item = |label: &str, action: ServiceAction| {
+        let entity = entity.clone();
+        let unit = service.unit.clone();
+        PopupMenuItem::new(label.to_string()).on_click(move |_, _, cx| {
+            entity.update(cx, |view, cx| view.request(unit.clone(), action, cx));
+        })
+    };
+
+    let menu = menu.min_w(px(180.)).label(service.unit.clone());
+    let menu = if service.is_active() {
+        menu.item(action_item(t(lang, "services.stop"), ServiceAction::Stop))
+            .item(action_item(
+                t(lang, "services.restart"),
+                ServiceAction::Restart,
+            ))
+    } else {
+        menu.item(action_item(t(lang, "services.start"), ServiceAction::Start))
+    };
+    let menu = match service.enabled.as_str() {
+        _ if !service.can_toggle_enabled() => menu,
+        "enabled" => menu.separator().item(action_item(
+            t(lang, "services.disable"),
+            ServiceAction::Disable,
+        )),
+        _ => menu.separator().item(action_item(
+            t(lang, "services.enable"),
+            ServiceAction::Enable,
+        )),
+    };
+
+    let unit = service.unit.clone();
+    menu.separator()
+        .item(
+            PopupMenuItem::new(t(lang, "services.journal")).on_click(move |_, _, cx| {
+                entity.update(cx, |view, cx| {
+                    view.emit(ServiceEvent::Journal(unit.clone()), cx)
+                });
+            }),
+        )
+}
+
+impl Render for ServiceListView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
+        let muted = cx.theme().muted_foreground;
+
+        let filter = self.filter.clone();
+        let visible: Vec<ServiceUnit> = self
+            .services
+            .iter()
+            .filter(|s| {
+                filter.is_empty()
+                    || s.unit.to_lowercase().contains(&filter)
+                    || s.description.to_lowercase().contains(&filter)
+            })
+            .cloned()
+            .collect();
+        let total = self.services.len();
+        let count_text = if visible.len() == total {
+            t(&lang, "services.count").replace("{}", &total.to_string())
+        } else {
+            format!("{} / {}", visible.len(), total)
+        };
+
+        let filter_bar = div()
+            .w_full()
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .py_0p5()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                div().flex_1().child(
+                    Input::new(&self.filter_input)
+                        .xsmall()
+                        .cleanable(true)
+                        .prefix(svg().path(icons::FILTER).size(px(12.)).text_color(muted)),
+                ),
+            )
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted)
+                    .child(count_text),
+            );
+
+        let body = if self.loaded {
+            let rows: Vec<AnyElement> = visible
+                .iter()
+                .enumerate()
+                .map(|(ix, service)| self.render_service(ix, service, cx))
+                .collect();
+            div()
+                .id("services-list")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .children(rows)
+                .into_any_element()
+        } else {
+            let hint = match &self.error {
+                Some(e) => format!("{}: {}", t(&lang, "services.load_failed"), e),
+                None => t(&lang, "common.loading").to_string(),
+            };
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .px_2()
+                .text_xs()
+                .text_color(muted)
+                .child(hint)
+                .into_any_element()
+        };
+
+        let action_error = self.action_error.clone();
+        let pending = self.pending.clone();
+
+        div()
+            .size_full()
+            .flex()
+            .flex_col()
+            .bg(crate::theme::sidebar_color(cx))
+            .child(filter_bar)
+            .child(body)
+            .when_some(action_error, |this, error| {
+                this.child(
+                    div()
+                        .w_full()
+                        .flex_shrink_0()
+                        .px_2()
+                        .py_1()
+                        .text_xs()
+                        .text_color(cx.theme().danger)
+                        .child(format!("{}: {}", t(&lang, "services.action_failed"), error)),
+                )
+            })
+            .when_some(pending, |this, pending| {
+                this.child(self.render_confirm_bar(&pending, &lang, cx))
+            })
+    }
+}
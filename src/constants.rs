@@ -17,6 +17,7 @@ pub mod icons {
     pub const LINK: &str = "icons/link.svg";
     pub const GLOBE: &str = "icons/globe.svg";
     pub const LOCK: &str = "icons/lock.svg";
+    pub const KEY: &str = "icons/key.svg";
     pub const FOLDER_OPEN: &str = "icons/folder-open.svg";
     pub const FOLDER: &str = "icons/folder.svg";
     pub const CHEVRON_DOWN: &str = "icons/chevron-down.svg";
@@ -34,6 +35,7 @@ pub mod icons {
     pub const REFRESH: &str = "icons/refresh.svg";
     pub const UPLOAD: &str = "icons/upload.svg";
     pub const DOWNLOAD: &str = "icons/download.svg";
+    pub const GAUGE: &str = "icons/gauge.svg";
     pub const PANEL_RIGHT_CLOSE: &str = "icons/panel-right-close.svg";
     pub const PANEL_RIGHT_OPEN: &str = "icons/panel-right-open.svg";
     // 小侧栏图标
@@ -41,6 +43,7 @@ pub mod icons {
     pub const TRANSFER: &str = "icons/transfer.svg";
     pub const FINGERPRINT: &str = "icons/fingerprint.svg";
     pub const SEND: &str = "icons/send.svg";
+    pub const BELL: &str = "icons/bell.svg";
     // Monitor 图标
     pub const CPU: &str = "icons/cpu.svg";
     pub const MEMORY: &str = "icons/memory.svg";
@@ -52,10 +55,16 @@ pub mod icons {
     pub const ARROW_LEFT: &str = "icons/arrow-left.svg";
     pub const ARROW_RIGHT: &str = "icons/arrow-right.svg";
     pub const ARROW_UP: &str = "icons/arrow-up.svg";
+    pub const ARROW_DOWN: &str = "icons/arrow-down.svg";
     pub const FOLDER_PLUS: &str = "icons/folder-plus.svg";
     pub const EYE: &str = "icons/eye.svg";
     pub const EYE_OFF: &str = "icons/eye-off.svg";
     pub const SEARCH: &str = "icons/search.svg";
+    pub const STAR: &str = "icons/star.svg";
+    pub const BOOKMARK: &str = "icons/bookmark.svg";
+    pub const FILTER: &str = "icons/filter.svg";
+    pub const TAG: &str = "icons/tag.svg";
+    pub const ACTIVITY: &str = "icons/activity.svg";
     // 文件类型图标
     pub const FILE: &str = "icons/file.svg";
     pub const FILE_TEXT: &str = "icons/file-text.svg";
@@ -63,4 +72,9 @@ pub mod icons {
     pub const FILE_JSON: &str = "icons/file-json.svg";
     pub const IMAGE: &str = "icons/image.svg";
     pub const ARCHIVE: &str = "icons/archive.svg";
+    pub const COLUMNS: &str = "icons/columns.svg";
+    pub const LIST_CHECKS: &str = "icons/list-checks.svg";
+    pub const PUZZLE: &str = "icons/puzzle.svg";
+    pub const KEYBOARD: &str = "icons/keyboard.svg";
+    pub const CLOCK: &str = "icons/clock.svg";
 }
@@ -1,9 +1,23 @@
+// 界面文本翻译
+//
+// 添加新语言：
+// 1. 在 `models::settings::Language` 中新增变体，并加入 `Language::ALL` 和 `label()`
+// 2. 参照 `en_us` 新增翻译函数（未翻译的键返回键本身即可）
+// 3. 在 `t` 中添加对应分支
+//
+// 未翻译的键会回退到英文，英文也缺失时显示键本身
+
 use crate::models::settings::Language;
 
 pub fn t(lang: &Language, key: &'static str) -> &'static str {
-    match lang {
+    let text = match lang {
         Language::Chinese => zh_cn(key),
         Language::English => en_us(key),
+    };
+    if text == key {
+        en_us(key)
+    } else {
+        text
     }
 }
 
@@ -16,6 +30,7 @@ fn zh_cn(key: &'static str) -> &'static str {
         "common.loading" => "加载中...",
         "common.edit" => "编辑",
         "common.delete" => "删除",
+        "common.close" => "关闭",
 
         // 设置菜单
         "settings.title" => "设置",
@@ -28,6 +43,14 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.nav.sync" => "数据同步",
         "settings.nav.system" => "系统配置",
         "settings.nav.about" => "关于",
+        "settings.search.placeholder" => "搜索设置",
+        "settings.search.results" => "找到 {} 个设置项，点击跳转",
+        "settings.search.no_results" => "没有匹配的设置项",
+        "settings.apply" => "应用",
+        "settings.unsaved" => "有未保存的更改",
+        "settings.confirm_discard" => "有未保存的更改，关闭后将丢失",
+        "settings.discard" => "放弃更改",
+        "settings.keep_editing" => "继续编辑",
 
         // 主题设置
         "settings.theme.language" => "语言 / Language",
@@ -38,6 +61,22 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.theme.font" => "字体设置",
         "settings.theme.font_family" => "界面字体",
         "settings.theme.font_size" => "界面字号",
+        "settings.ui_theme.title" => "界面配色",
+        "settings.ui_theme.default" => "默认",
+        "settings.ui_theme.custom" => "自定义",
+        "settings.ui_theme.current" => "当前配色",
+        "settings.ui_theme.import" => "导入主题",
+        "settings.ui_theme.export" => "导出主题",
+        "settings.ui_theme.imported" => "已导入主题「{}」",
+        "settings.ui_theme.exported" => "主题已导出到",
+        "settings.ui_theme.edit_dark" => "深色配色",
+        "settings.ui_theme.edit_light" => "浅色配色",
+        "settings.ui_theme.accent" => "强调色",
+        "settings.ui_theme.background" => "背景",
+        "settings.ui_theme.surface" => "卡片与弹窗",
+        "settings.ui_theme.sidebar" => "侧边栏与标题栏",
+        "settings.ui_theme.border" => "边框",
+        "settings.ui_theme.hint" => "输入 #RRGGBB 格式的颜色即时预览，留空使用内置颜色；保存后生效，取消则恢复",
 
         // 终端设置
         "settings.terminal.font" => "字体",
@@ -54,26 +93,67 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.terminal.cursor_style.bar" => "竖线",
         "settings.terminal.cursor_style.underline" => "下划线",
         "settings.terminal.scrollback" => "滚动缓冲区",
+        "settings.terminal.transparency" => "透明与模糊",
+        "settings.terminal.background_opacity" => "背景不透明度(%)",
+        "settings.terminal.background_blur" => "背景模糊",
+        "settings.terminal.dim_inactive" => "非活动面板变暗",
 
         // 按键绑定
         "settings.keybindings.global_title" => "全局快捷键",
         "settings.keybindings.quit" => "退出应用",
+        "settings.keybindings.tab_overview" => "标签页总览",
+        "settings.keybindings.command_palette" => "命令面板",
+        "settings.keybindings.navigation_title" => "导航快捷键",
+        "settings.keybindings.next_tab" => "下一个标签",
+        "settings.keybindings.prev_tab" => "上一个标签",
+        "settings.keybindings.activate_tab" => "切换到第 N 个标签",
+        "settings.keybindings.last_tab" => "切换到最后一个标签",
+        "settings.keybindings.next_terminal" => "下一个终端",
+        "settings.keybindings.prev_terminal" => "上一个终端",
+        "settings.keybindings.toggle_sidebar" => "折叠/展开侧边栏",
+        "settings.keybindings.focus_terminal" => "聚焦终端",
+        "settings.keybindings.focus_sftp" => "聚焦文件列表",
+        "settings.keybindings.focus_monitor" => "聚焦监控面板",
+        "settings.keybindings.navigate_back" => "后退",
+        "settings.keybindings.navigate_forward" => "前进",
         "settings.keybindings.terminal_title" => "终端快捷键",
         "settings.keybindings.copy" => "复制",
         "settings.keybindings.paste" => "粘贴",
+        "settings.keybindings.sftp_title" => "文件管理快捷键",
+        "settings.keybindings.rename" => "重命名",
         "settings.keybindings.more_coming_soon" => "更多快捷键设置将在后续版本提供",
 
         // SFTP 设置
         "settings.sftp.file_display" => "文件显示",
         "settings.sftp.show_hidden" => "显示隐藏文件",
         "settings.sftp.folders_first" => "文件夹优先",
+        "settings.sftp.dual_pane" => "默认使用双面板（本地 + 远程）",
         "settings.sftp.transfer" => "传输设置",
         "settings.sftp.concurrent" => "并发传输数",
+        "settings.sftp.max_active_transfers" => "同时传输文件数",
+        "settings.sftp.max_transfers_per_server" => "单服务器同时传输数",
+        "settings.sftp.download_limit" => "下载限速 (KB/s，0 不限)",
+        "settings.sftp.upload_limit" => "上传限速 (KB/s，0 不限)",
         "settings.sftp.preserve_time" => "保留时间戳",
         "settings.sftp.resume" => "断点续传",
+        "settings.sftp.verify_checksum" => "传输后校验完整性 (SHA-256)",
+        "settings.sftp.transfer_compression" => "单文件传输时使用 gzip 压缩",
+        "settings.sftp.auto_extract" => "下载归档后自动解压",
+        "settings.sftp.conflict_action" => "文件已存在时",
+        "settings.sftp.conflict.ask" => "每次询问",
+        "settings.sftp.conflict.overwrite" => "覆盖",
+        "settings.sftp.conflict.overwrite_if_newer" => "较新时覆盖",
+        "settings.sftp.conflict.rename" => "自动重命名",
+        "settings.sftp.conflict.skip" => "跳过",
         "settings.sftp.default_download_path" => "默认下载路径",
         "settings.sftp.default_download_path_placeholder" => "留空则每次下载弹窗选择",
         "settings.sftp.browse" => "浏览",
+        "settings.sftp.pick_download_path" => "选择默认下载路径",
+        "settings.sftp.pick_editor" => "选择编辑器程序",
+        "settings.sftp.delete" => "删除",
+        "settings.sftp.use_remote_trash" => "删除到远程回收站",
+        "settings.sftp.remote_trash_dir" => "回收站目录",
+        "settings.sftp.remote_trash_dir_invalid" => "回收站目录不能为根目录、主目录或其上级目录，保存时将忽略",
         "settings.sftp.editor" => "编辑器",
         "settings.sftp.builtin_editor" => "使用内置编辑器",
         "settings.sftp.external_editor_path" => "外置编辑器路径",
@@ -88,6 +168,8 @@ fn zh_cn(key: &'static str) -> &'static str {
 
         // 监控设置
         "settings.monitor.data_collection" => "数据采集",
+        "settings.monitor.refresh_interval" => "刷新间隔",
+        "settings.monitor.interval_secs" => "{} 秒",
         "settings.monitor.history_retention" => "历史保留(分钟)",
         "settings.monitor.auto_deploy" => "自动部署Agent",
         "settings.monitor.display_items" => "显示项目",
@@ -95,10 +177,16 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.monitor.memory" => "内存",
         "settings.monitor.disk" => "磁盘",
         "settings.monitor.network" => "网络",
+        "settings.monitor.gpu" => "GPU",
+        "settings.monitor.health" => "硬件健康",
+        "settings.monitor.logins" => "登录信息",
         "settings.monitor.alerts" => "告警阈值",
         "settings.monitor.cpu_threshold" => "CPU (%)",
         "settings.monitor.memory_threshold" => "内存 (%)",
         "settings.monitor.disk_threshold" => "磁盘 (%)",
+        "settings.monitor.temperature_threshold" => "温度 (°C)",
+        "settings.monitor.alert_consecutive" => "连续超限次数",
+        "settings.monitor.alert_notification" => "弹出告警通知",
 
         // 连接设置
         "settings.connection.ssh" => "SSH 设置",
@@ -110,6 +198,16 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.connection.reconnect_enabled" => "自动重连",
         "settings.connection.reconnect_attempts" => "重连次数",
         "settings.connection.reconnect_interval" => "重连间隔(秒)",
+        "settings.connection.hibernation" => "标签休眠",
+        "settings.connection.hibernate_after" => "闲置休眠(分钟，0 为关闭)",
+        "settings.connection.hibernate_connected" => "休眠已连接标签（将关闭远端 Shell）",
+        "settings.connection.reachability" => "服务器可达性",
+        "settings.connection.reachability_hint" => "通过连接 SSH 端口检测服务器是否在线，经跳板机或代理连接的服务器显示为未知",
+        "settings.connection.reachability_probe" => "后台定期检测",
+        "settings.connection.reachability_interval" => "检测间隔 (分钟)",
+        "settings.connection.db_clients" => "数据库客户端",
+        "settings.connection.db_clients_hint" => "建立数据库隧道后用于启动本地客户端的命令，可用 {host}、{port}、{url} 占位符，留空则不显示启动按钮",
+        "settings.connection.db_client_placeholder" => "如 mysql -h {host} -P {port} -u root -p",
 
         // 数据同步
         "settings.sync.status" => "同步状态",
@@ -124,25 +222,251 @@ fn zh_cn(key: &'static str) -> &'static str {
         "settings.sync.webdav_url" => "服务器地址",
         "settings.sync.webdav_username" => "用户名",
         "settings.sync.webdav_password" => "密码",
+        "settings.sync.passphrase" => "同步口令",
+        "settings.sync.passphrase_hint" => "同步数据在本机用此口令加密后上传，口令不会发送到服务器，各设备需设置相同的口令",
         "settings.sync.webdav_path" => "存储路径",
         "settings.sync.test_connection" => "测试连接",
         "settings.sync.sync_now" => "立即同步",
+        "settings.sync.interval" => "自动同步频率",
+        "settings.sync.interval.manual" => "仅手动",
+        "settings.sync.interval.on_startup" => "启动时",
+        "settings.sync.interval.hourly" => "每小时",
+        "settings.sync.interval.daily" => "每天",
+        "settings.sync.conflict_strategy" => "冲突处理",
+        "settings.sync.conflict.ask" => "每次询问",
+        "settings.sync.conflict.local_first" => "本地优先",
+        "settings.sync.conflict.remote_first" => "远程优先",
+        "settings.sync.conflict.merge" => "自动合并",
+        "settings.sync.backup_before_sync" => "覆盖远程前备份到本地",
+        "settings.sync.keys" => "密码与私钥",
+        "settings.sync.running" => "正在同步...",
+        "settings.sync.last_synced" => "上次同步：",
+        "settings.sync.never" => "从未同步",
+        "sync.result.test_ok" => "连接成功",
+        "sync.result.up_to_date" => "已是最新，无需同步",
+        "sync.result.pushed" => "已上传本地配置",
+        "sync.result.pulled" => "已拉取远程配置",
+        "sync.result.merged" => "已合并本地与远程配置",
+        "sync.result.conflict" => "本地与远程都有改动，请选择处理方式",
+        "settings.sync.method" => "同步方式",
+        "settings.sync.method.webdav" => "WebDAV",
+        "settings.sync.method.icloud" => "iCloud",
+        "settings.sync.method.git" => "Git 仓库",
+        "settings.sync.git" => "Git 仓库配置",
+        "settings.sync.git_remote" => "仓库地址",
+        "settings.sync.git_branch" => "分支",
+        "settings.sync.git_private_key" => "私钥路径",
+        "settings.sync.git_private_key_placeholder" => "留空使用 ~/.ssh 下的默认私钥",
+        "settings.sync.inventory" => "团队共享服务器清单",
+        "settings.sync.inventory_hint" => "从 HTTP(S) 地址或共享目录加载只读的服务器列表（JSON / YAML），连接时使用本机的身份认证。",
+        "settings.sync.inventory_source" => "清单地址",
+        "settings.sync.inventory_source_placeholder" => "https://... 或 /mnt/team/servers.yaml",
+        "settings.sync.inventory_refresh" => "刷新间隔（分钟）",
+        "settings.sync.inventory_identity" => "默认身份",
+        "settings.sync.inventory_identity_none" => "不指定",
+        "settings.sync.inventory_refresh_now" => "立即刷新",
+        "settings.sync.inventory_loading" => "正在加载...",
+        "settings.sync.inventory_loaded" => "已加载 {} 台共享服务器",
+        "settings.sync.history" => "历史版本",
+        "settings.sync.history.load" => "查看历史",
+        "settings.sync.history.empty" => "仓库中还没有服务器列表的提交",
+        "settings.sync.history.restore" => "恢复",
+        "sync.result.restored" => "已恢复服务器列表",
+        "identity.items" => "个身份",
+        "identity.new" => "新建身份",
+        "identity.new_title" => "新建身份",
+        "identity.edit_title" => "编辑身份",
+        "identity.empty" => "还没有身份。把常用的私钥或 SSH Agent 密钥登记为身份，服务器即可直接引用，更换私钥时无需逐台修改。",
+        "identity.name" => "名称",
+        "identity.name_placeholder" => "如：工作密钥",
+        "identity.username" => "默认用户名",
+        "identity.username_placeholder" => "服务器未填写用户名时使用",
+        "identity.agent_key" => "Agent 密钥",
+        "identity.agent_key_placeholder" => "密钥注释或 SHA256 指纹（留空则尝试全部密钥）",
+        "identity.agent_hint" => "通过 SSH_AUTH_SOCK 连接本机的 SSH Agent，私钥不会离开 Agent。",
+        "identity.agent_any" => "Agent 中的任意密钥",
+        "identity.kind.key_file" => "私钥文件",
+        "identity.kind.agent" => "SSH Agent",
+        "identity.used_by" => "{} 台服务器使用",
+        "identity.unused" => "未被服务器使用",
+        "identity.edit" => "编辑",
+        "identity.rotate" => "更换私钥",
+        "identity.delete" => "删除",
+        "identity.name_empty" => "请输入身份名称",
+        "identity.private_key_empty" => "请选择私钥文件",
+        "trash.items" => "台已删除的服务器",
+        "trash.retention" => "保留 {} 天后自动永久删除",
+        "trash.empty" => "回收站是空的",
+        "trash.empty_trash" => "清空回收站",
+        "trash.days_left" => "剩余 {} 天",
+        "trash.restore" => "恢复",
+        "trash.purge" => "永久删除",
+        "trash.deleted" => "已删除“{}”",
+        "trash.undo_hint" => "点击撤销，或在回收站中恢复",
+
+        // 会话工作区
+        "workspace.title" => "会话工作区",
+        "workspace.save_current" => "保存当前打开的会话",
+        "workspace.name_placeholder" => "工作区名称",
+        "workspace.name_empty" => "请输入工作区名称",
+        "workspace.no_sessions" => "当前没有打开的会话",
+        "workspace.empty" => "暂无保存的工作区",
+        "workspace.last_session" => "上次会话",
+        "workspace.tabs" => "{} 个标签",
+        "workspace.open" => "打开",
+        "workspace.restore_prompt" => "恢复上次会话？",
+        "workspace.restore_hint" => "上次退出时打开了 {} 个会话，点击重新连接",
+
+        // 命令面板
+        "palette.placeholder" => "搜索命令、服务器、快捷命令、收藏目录或标签…",
+        "palette.empty" => "没有匹配的命令",
+        "palette.hint" => "↑↓ 选择 · Enter 执行 · Esc 关闭",
+        "palette.no_terminal" => "没有可执行命令的终端",
+        "palette.kind.action" => "操作",
+        "palette.kind.server" => "连接",
+        "palette.kind.menu" => "页面",
+        "palette.kind.settings" => "设置",
+        "palette.kind.snippet" => "快捷命令",
+        "palette.kind.bookmark" => "SFTP 收藏",
+        "palette.kind.recent_file" => "最近文件",
+        "palette.kind.tab" => "标签",
+        "palette.action.tab_overview" => "标签页总览",
+        "palette.action.quick_connect" => "快速连接",
+        "palette.action.quit" => "退出",
+        "palette.action.next_tab" => "下一个标签",
+        "palette.action.prev_tab" => "上一个标签",
+        "palette.action.next_terminal" => "下一个终端",
+        "palette.action.prev_terminal" => "上一个终端",
+        "palette.action.toggle_sidebar" => "折叠/展开侧边栏",
+        "palette.action.focus_terminal" => "聚焦终端",
+        "palette.action.focus_sftp" => "聚焦文件列表",
+        "palette.action.focus_monitor" => "聚焦监控面板",
+        "palette.action.navigate_back" => "后退到上一个视图",
+        "palette.action.navigate_forward" => "前进到下一个视图",
+        "palette.action.notifications" => "通知中心",
+
+        // 会话标签
+        "tab.close" => "关闭标签页",
+        "tab.close_others" => "关闭其他标签页",
+        "tab.close_all" => "关闭全部",
+        "tab.pin" => "固定标签",
+        "tab.unpin" => "取消固定",
+        "tab.open_in_new_window" => "在新窗口打开",
+        "tab.ssh_snippet" => "SSH 配置与命令",
+        "tab.move_to_main" => "移到主窗口",
+        "tab.move_to_window" => "移到窗口：{}",
+        "tab.color" => "颜色标记",
+        "tab.color.auto" => "按分组自动",
+        "tab.color.none" => "无",
+        "tab.color.red" => "红色",
+        "tab.color.orange" => "橙色",
+        "tab.color.yellow" => "黄色",
+        "tab.color.green" => "绿色",
+        "tab.color.teal" => "青色",
+        "tab.color.blue" => "蓝色",
+        "tab.color.purple" => "紫色",
+        "tab.color.pink" => "粉色",
+
+        // 关闭标签确认
+        "close_tabs.title" => "关闭 {} 个标签页？",
+        "close_tabs.message" => "以下标签页仍有进行中的工作，关闭后将断开连接并中止：",
+        "close_tabs.terminals" => "{} 个终端",
+        "close_tabs.transfers" => "{} 个传输",
+        "close_tabs.forwards" => "{} 个端口转发",
+        "close_tabs.confirm" => "关闭",
+
+        // 通知中心
+        "notifications.title" => "通知中心",
+        "notifications.empty" => "暂无通知",
+        "notifications.mark_all_read" => "全部已读",
+        "notifications.clear" => "清空",
+        "notifications.filter_all" => "全部",
+        "notifications.category.transfer" => "文件传输",
+        "notifications.category.session" => "会话",
+        "notifications.category.monitor" => "监控告警",
+        "notifications.category.operation" => "操作",
+
+        // 托盘菜单
+        "tray.favorites" => "收藏的服务器",
+        "tray.no_favorites" => "暂无收藏（在主机列表右键收藏）",
+        "tray.transfers" => "进行中的传输 ({})",
+        "tray.toggle_window" => "显示/隐藏主窗口",
+        "tray.quit" => "退出 ShellMaster",
+        "server_dialog.auth_identity" => "身份",
+        "server_dialog.identity" => "身份",
+        "server_dialog.no_identities" => "还没有身份，可在侧边栏的“身份”页面中添加。",
+        "recovery.title" => "配置文件已损坏",
+        "recovery.message" => "以下配置无法读取。损坏的内容已另存到备份目录，可以从最近的备份恢复，或重置为默认值。",
+        "recovery.backup_found" => "最近可用的备份：{}",
+        "recovery.no_backup" => "没有可用的备份",
+        "recovery.restore" => "从备份恢复",
+        "recovery.reset" => "重置为默认",
+        "recovery.later" => "暂不处理",
+        "recovery.database_title" => "无法打开配置数据库",
+        "recovery.database_message" => "本次运行为只读模式，所有修改都不会保存。请检查配置目录的权限与磁盘空间后重新启动应用。",
+        "recovery.document.servers" => "服务器列表",
+        "recovery.document.settings" => "应用设置",
+        "recovery.document.snippets" => "命令片段",
+        "recovery.document.known_hosts" => "已知主机",
+        "recovery.document.transfer_history" => "传输历史",
+        "recovery.document.connection_history" => "连接历史",
+        "recovery.document.sftp_bookmarks" => "SFTP 收藏夹",
+        "recovery.document.workspaces" => "会话工作区",
+        "recovery.document.window_state" => "窗口状态",
+        "recovery.document.schedules" => "定时任务",
+        "recovery.document.job_history" => "定时任务运行记录",
+        "sync.conflict.title" => "同步冲突",
+        "sync.conflict.message" => "上次同步后本地与远程配置都发生了变化，请选择保留哪一份，或合并两侧（同一条目以本地为准）。",
+        "sync.conflict.local" => "本地",
+        "sync.conflict.remote" => "远程",
+        "sync.conflict.servers" => "台服务器",
+        "sync.conflict.snippets" => "条命令",
+        "sync.conflict.later" => "稍后处理",
+        "sync.conflict.keep_remote" => "使用远程",
+        "sync.conflict.keep_local" => "保留本地",
+        "sync.conflict.merge" => "合并",
 
         // 系统配置
         "settings.system.startup" => "启动",
         "settings.system.auto_start" => "开机启动",
         "settings.system.start_minimized" => "启动时最小化",
+        "settings.system.restore_sessions" => "启动时自动恢复上次会话",
         "settings.system.check_updates" => "检查更新",
         "settings.system.window" => "窗口",
-        "settings.system.close_to_tray" => "关闭到托盘",
+        "settings.system.close_to_tray" => "关闭窗口时最小化到托盘",
         "settings.system.show_tray_icon" => "显示托盘图标",
         "settings.system.single_instance" => "单实例运行",
         "settings.system.notification" => "通知",
         "settings.system.notify_disconnect" => "断开连接通知",
         "settings.system.notify_transfer" => "传输完成通知",
+        "settings.system.system_notifications" => "应用在后台时发送系统通知",
+        "settings.system.do_not_disturb" => "免打扰（只记录到通知中心）",
         "settings.system.logging" => "日志",
         "settings.system.logging_enabled" => "启用日志",
         "settings.system.log_retention" => "日志保留(天)",
+        "settings.system.backup" => "配置备份",
+        "settings.system.inventory" => "服务器清单",
+        "settings.system.inventory.hint" => "导出为 CSV 表格或 OpenSSH config（Host 块），供其他工具或同事使用，不包含密码",
+        "settings.system.inventory.export_csv" => "导出 CSV",
+        "settings.system.inventory.export_ssh_config" => "导出 SSH config",
+        "settings.system.inventory.exported" => "已导出 {} 台服务器到",
+        "settings.system.url_handler" => "ssh:// 链接",
+        "settings.system.url_handler.hint" => "设为系统打开 ssh:// 链接的默认程序后，点击网页或其他应用中的 ssh:// 链接会在 ShellMaster 中连接；也可以在命令行运行 shellmaster user@host -p 端口 --key 私钥 直接连接",
+        "settings.system.url_handler.register" => "设为默认程序",
+        "settings.system.url_handler.registered" => "已设为 ssh:// 链接的默认程序",
+        "settings.system.backup.include_secrets" => "导出时包含密码与私钥",
+        "settings.system.backup.password" => "归档口令",
+        "settings.system.backup.password_placeholder" => "用于加密/解密配置归档",
+        "settings.system.backup.password_required" => "请先输入归档口令",
+        "settings.system.backup.export" => "导出配置",
+        "settings.system.backup.import" => "导入配置",
+        "settings.system.backup.exported" => "已导出到",
+        "settings.system.backup.imported" => "导入完成",
+        "settings.system.backup.servers" => "服务器",
+        "settings.system.backup.groups" => "分组",
+        "settings.system.backup.snippets" => "命令",
+        "settings.system.backup.no_secrets_note" => "归档不含密码，已保留本地密码",
+        "settings.system.backup.auto_backup_hint" => "每次写入配置前会自动备份到：",
 
         // 关于
         "settings.about.platform" => "平台",
@@ -153,26 +477,65 @@ fn zh_cn(key: &'static str) -> &'static str {
         "sidebar.hosts" => "服务器",
         "sidebar.monitor" => "主机监控",
         "sidebar.snippets" => "快捷命令",
+        "sidebar.batch_exec" => "批量执行",
 
+        "sidebar.identities" => "身份",
         "sidebar.known_hosts" => "已知主机",
-        "sidebar.history" => "历史记录",
+        "sidebar.connection_history" => "连接历史",
+        "sidebar.plugins" => "插件",
+        "sidebar.schedules" => "定时任务",
+        "sidebar.trash" => "回收站",
+        "sidebar.history" => "最近连接",
         "sidebar.settings" => "设置",
 
+        // 会话布局
+        "session_layout.sidebar_left" => "侧边栏移到左侧",
+        "session_layout.sidebar_right" => "侧边栏移到右侧",
+        "session_layout.collapse_monitor" => "折叠监控面板",
+        "session_layout.expand_monitor" => "展开监控面板",
+        "session_layout.reset" => "恢复默认布局",
+
+        // 深链接
+        "deep_link.connect_prompt" => "连接到 {}？",
+        "deep_link.connect_hint" => "外部链接请求建立临时连接（不保存服务器），点击此通知连接",
+
+        // 会话延迟
+        "latency.title" => "延迟（最近 5 分钟）",
+        "latency.current" => "当前 {} ms",
+        "latency.average" => "平均 {} ms",
+        "latency.stale" => "无响应",
+        "latency.no_reply_for" => "已 {} 秒未收到应答",
+        "latency.never_replied" => "尚未收到应答",
+
         // 历史记录时间
         "history.just_now" => "刚刚",
         "history.minutes_ago" => "分钟前",
         "history.hours_ago" => "小时前",
         "history.days_ago" => "天前",
 
+        // 连接历史页
+        "connection_history.search_placeholder" => "搜索服务器名称或地址",
+        "connection_history.items" => "条记录",
+        "connection_history.clear" => "清空历史",
+        "connection_history.empty" => "暂无连接历史",
+        "connection_history.active" => "连接中",
+        "connection_history.reconnect" => "重新连接",
+        "connection_history.filter.all" => "全部",
+        "connection_history.filter.succeeded" => "成功",
+        "connection_history.filter.failed" => "失败",
+
         // 服务器对话框
         "server_dialog.add_title" => "添加服务器",
         "server_dialog.edit_title" => "编辑服务器",
         "server_dialog.nav.basic_info" => "基本信息",
         "server_dialog.nav.jump_host" => "跳板机",
         "server_dialog.nav.proxy" => "代理设置",
+        "server_dialog.nav.overrides" => "设置覆盖",
         "server_dialog.nav.other" => "其他设置",
         "server_dialog.group" => "服务器分组",
-        "server_dialog.group_placeholder" => "选择或输入分组",
+        "server_dialog.group_placeholder" => "选择或输入分组，用 / 表示子分组",
+        "server_dialog.tags" => "标签",
+        "server_dialog.tags_placeholder" => "用逗号分隔，如 env:prod, role:db",
         "server_dialog.label" => "服务器标签",
         "server_dialog.label_placeholder" => "请输入服务器名称",
         "server_dialog.host" => "主机地址",
@@ -198,9 +561,54 @@ fn zh_cn(key: &'static str) -> &'static str {
         "server_dialog.description" => "描述",
         "server_dialog.description_placeholder" => "输入服务器描述（可选）",
         "server_dialog.no_other_settings" => "暂无其他设置选项",
+        "server_dialog.terminal_font_size" => "终端字体大小",
+        "server_dialog.terminal_color_scheme" => "终端配色方案",
+        "server_dialog.scrollback" => "回滚行数",
+        "server_dialog.keepalive" => "心跳间隔(秒，0 为关闭)",
+        "server_dialog.override.hint" => "开启「覆盖全局」后，此服务器使用下方的值，其余设置跟随全局设置",
+        "server_dialog.override.toggle" => "覆盖全局",
+        "server_dialog.override.global" => "全局设置：{}",
+        "server_dialog.terminal_opacity" => "终端背景不透明度(%)",
+        "server_dialog.monitor_interval" => "监控刷新间隔(秒)",
+        "server_dialog.new_template_title" => "新建服务器模板",
+        "server_dialog.edit_template_title" => "编辑服务器模板",
+        "server_dialog.template_name" => "模板名称",
+        "server_dialog.post_connect" => "连接后执行的命令",
+        "server_dialog.post_connect_placeholder" => "每行一条命令，连接成功后在首个终端中依次执行",
 
         // 服务器列表
         "server_list.add_server" => "添加服务器",
+        "server_list.import" => "导入",
+        "server_list.discover" => "发现主机",
+        "server_import.title" => "导入服务器",
+        "server_import.source.putty_reg" => "PuTTY (.reg)",
+        "server_import.source.putty_registry" => "PuTTY（注册表）",
+        "server_import.source.termius" => "Termius",
+        "server_import.source.securecrt" => "SecureCRT (XML)",
+        "server_import.choose_file" => "选择文件...",
+        "server_import.read_registry" => "读取注册表",
+        "server_import.summary" => "共 {} 台服务器，其中 {} 台已存在",
+        "server_import.empty" => "未找到可导入的 SSH 会话",
+        "server_import.duplicate" => "已存在",
+        "server_import.proxy" => "代理",
+        "server_import.select_all" => "全选",
+        "server_import.select_none" => "全不选",
+        "server_import.confirm" => "导入 {} 台",
+        "discovery.title" => "发现主机",
+        "discovery.hint" => "扫描网段或 IP 范围内开放 SSH 端口的主机。支持 192.168.1.0/24、192.168.1.10-50 与单个地址，多个目标用逗号分隔",
+        "discovery.range_placeholder" => "如 192.168.1.0/24",
+        "discovery.port" => "端口",
+        "discovery.start" => "开始扫描",
+        "discovery.stop" => "停止",
+        "discovery.progress" => "已扫描 {} / {}，发现 {} 台主机",
+        "discovery.empty" => "未发现开放 SSH 端口的主机",
+        "discovery.no_banner" => "未返回 SSH 标识",
+        "discovery.add" => "添加为服务器",
+        "discovery.added" => "已添加",
+        "discovery.error.empty" => "请输入扫描目标",
+        "discovery.error.invalid" => "无法识别的扫描目标：{}",
+        "discovery.error.too_many" => "单次最多扫描 {} 个地址",
+        "discovery.error.port" => "端口无效",
         "server_list.empty_title" => "暂无服务器",
         "server_list.empty_description" => "点击下方按钮添加您的第一台服务器",
         "server_list.header.server" => "服务器",
@@ -211,7 +619,71 @@ fn zh_cn(key: &'static str) -> &'static str {
         "server_list.header.last_connected" => "最近连接",
         "server_list.header.actions" => "操作",
         "server_list.ungrouped" => "未分组",
+        "server_list.shared" => "共享",
+        "server_list.shared_badge" => "共享",
+        "server_list.copy_to_local" => "复制为本地服务器",
         "server_list.never_connected" => "从未",
+        "server_list.new_group" => "新建分组",
+        "server_list.probe_all" => "检测全部",
+        "server_list.probing" => "检测中…",
+        "server_list.reachability.online" => "在线 · {} ms",
+        "server_list.reachability.offline" => "离线（SSH 端口无法连接）",
+        "server_list.reachability.unknown" => "未知（尚未检测，或经跳板机、代理连接）",
+        "server_list.search_placeholder" => "搜索服务器，回车连接；或输入 user@host:port 临时连接",
+        "server_list.filter_tags" => "标签",
+        "server_list.clear_filter" => "清除筛选",
+        "server_list.no_match" => "没有匹配的服务器",
+        "server_list.match_count" => "{} / {} 台服务器",
+        "server_list.enter_to_connect" => "按回车连接 {}",
+        "server_list.adhoc_connect" => "临时连接 {}（不保存）",
+        "server_list.adhoc_password" => "密码（留空则使用 ~/.ssh 默认私钥）",
+        "server_list.connect" => "连接",
+        "server_list.edit" => "编辑",
+        "server_list.clone" => "克隆服务器",
+        "server_list.favorite" => "收藏到托盘菜单",
+        "server_list.unfavorite" => "取消收藏",
+        "server_list.clone_label" => "{} (副本)",
+        "server_list.save_as_template" => "另存为模板",
+        "server_list.ssh_snippet" => "SSH 配置与命令",
+        "server_list.template_name" => "{} 模板",
+        "server_list.templates" => "模板",
+        "server_list.new_from_template" => "从模板新建：{}",
+        "server_list.edit_template" => "编辑模板：{}",
+        "server_list.new_template" => "新建模板",
+        "server_group.rename" => "重命名",
+        "server_group.new_subgroup" => "新建子分组",
+        "server_group.move_up" => "上移",
+        "server_group.move_down" => "下移",
+        "server_group.move_to_top" => "移到顶层",
+        "server_group.delete" => "删除分组",
+        "server_group.dialog.create_title" => "新建分组",
+        "server_group.dialog.create_sub_title" => "在 “{}” 下新建子分组",
+        "server_group.dialog.rename_title" => "重命名分组",
+        "server_group.dialog.delete_title" => "删除分组 “{}”",
+        "server_group.dialog.name" => "分组名称",
+        "server_group.dialog.name_placeholder" => "输入分组名称",
+        "server_group.dialog.name_empty" => "分组名称不能为空",
+        "server_group.dialog.name_invalid" => "分组名称不能包含 /",
+        "server_group.dialog.reassign" => "该分组中的 {} 台服务器将移动到：",
+        "server_group.dialog.subgroups_note" => "子分组会移到上一级分组",
+        "server_group.dialog.delete_confirm" => "删除",
+        // 批量编辑
+        "batch_edit.title" => "批量编辑 {} 台服务器",
+        "batch_edit.hint" => "仅修改已打开开关的项目，其余设置保持不变",
+        "batch_edit.selected_count" => "已选择 {} 台服务器（Ctrl/Cmd+点击多选）",
+        "batch_edit.edit" => "批量编辑",
+        "batch_edit.select_all" => "全选",
+        "batch_edit.clear_selection" => "取消选择",
+        "batch_edit.add_tags_placeholder" => "添加标签，用逗号分隔",
+        "batch_edit.remove_tags_placeholder" => "移除标签，用逗号分隔",
+        "batch_edit.no_jump_host" => "不使用跳板机",
+        "batch_edit.proxy_hint" => "地址留空表示取消代理",
+        "batch_edit.apply" => "应用",
+        "batch_edit.username_empty" => "用户名不能为空",
+        "batch_edit.port_invalid" => "端口无效",
+        "batch_edit.private_key_empty" => "请选择私钥文件",
+        "batch_edit.proxy_port_invalid" => "代理端口无效",
+        "batch_edit.nothing_to_apply" => "请至少打开一项修改",
         "server_list.placeholder.snippets" => "代码片段功能",
         "server_list.placeholder.known_hosts" => "已知主机管理",
         "server_list.placeholder.history" => "连接历史记录",
@@ -240,6 +712,15 @@ fn zh_cn(key: &'static str) -> &'static str {
         "connecting.host_key.btn_accept_once" => "仅本次信任",
         "connecting.host_key.btn_reject" => "拒绝连接",
         "connecting.connected" => "连接成功",
+        "connecting.stage.initializing" => "初始化连接",
+        "connecting.stage.proxy" => "连接代理服务器",
+        "connecting.stage.jump_host" => "连接跳板机",
+        "connecting.stage.host" => "连接目标主机",
+        "connecting.stage.handshaking" => "SSH 握手",
+        "connecting.stage.authenticating" => "验证身份",
+        "connecting.stage.channel" => "建立安全通道",
+        "connecting.stage.session" => "启动会话",
+        "connecting.stage.connected" => "连接成功",
 
         // 会话页面
         "session.connected" => "已连接到",
@@ -247,6 +728,44 @@ fn zh_cn(key: &'static str) -> &'static str {
         // 会话侧边栏
         "session.sidebar.quick_actions" => "快捷操作",
         "session.sidebar.new_terminal" => "新建终端",
+        // 标签页总览
+        "tab_overview.title" => "标签页总览",
+        // 主机监控总览
+        "dashboard.title" => "主机监控总览",
+        "dashboard.summary" => "{online} / {total} 台在线",
+        "dashboard.show_disconnected" => "显示未连接的服务器",
+        "dashboard.empty" => "暂无已连接的服务器，打开显示未连接的服务器后可点击卡片连接",
+        "dashboard.status.online" => "在线",
+        "dashboard.status.connecting" => "连接中",
+        "dashboard.status.offline" => "离线",
+        "dashboard.alerts" => "{} 条告警",
+        "dashboard.cpu" => "CPU",
+        "dashboard.memory" => "内存",
+        "dashboard.disk" => "磁盘",
+        "dashboard.click_to_connect" => "点击连接",
+        // 批量执行
+        "batch.title" => "批量执行",
+        "batch.summary" => "{done} / {total} 台完成，{failed} 台失败",
+        "batch.servers" => "服务器（已选 {}）",
+        "batch.select_all" => "全选",
+        "batch.select_none" => "清空",
+        "batch.empty_servers" => "暂无已保存的服务器",
+        "batch.command_placeholder" => "输入要在选中服务器上执行的命令",
+        "batch.snippet" => "插入快捷命令",
+        "batch.no_snippets" => "暂无快捷命令",
+        "batch.run" => "执行",
+        "batch.export_csv" => "导出 CSV",
+        "batch.export_done" => "已导出到 {}",
+        "batch.export_failed" => "导出失败：{}",
+        "batch.col.server" => "服务器",
+        "batch.col.status" => "状态",
+        "batch.col.duration" => "耗时",
+        "batch.running" => "执行中...",
+        "batch.cancelled" => "已取消",
+        "batch.failed" => "连接或执行失败",
+        "batch.exit_code" => "退出码 {}",
+        "batch.no_output" => "无输出",
+        "batch.no_results" => "选择服务器并输入命令后点击执行，未连接的服务器会临时连接",
         "session.sidebar.file_browser" => "文件浏览",
         "session.sidebar.system_info" => "系统信息",
         // Monitor 面板
@@ -266,7 +785,9 @@ fn zh_cn(key: &'static str) -> &'static str {
         // SFTP 文件列表表头
         "sftp.header.name" => "名称",
         "sftp.header.permissions" => "权限",
-        "sftp.header.owner" => "用户/组",
+        "sftp.header.owner" => "所有者",
+        "sftp.header.group" => "组",
+        "sftp.header.link_target" => "链接目标",
         "sftp.header.size" => "大小",
         "sftp.header.modified" => "修改时间",
         // SFTP 新建文件夹对话框
@@ -285,14 +806,71 @@ fn zh_cn(key: &'static str) -> &'static str {
         "sftp.new_file.error_invalid" => "文件名称包含非法字符",
         "sftp.new_file.success" => "文件创建成功",
         "sftp.new_file.failed" => "创建文件失败",
+        // SFTP 新建链接对话框
+        "sftp.new_link.title" => "新建链接",
+        "sftp.new_link.kind_symbolic" => "符号链接",
+        "sftp.new_link.kind_hard" => "硬链接",
+        "sftp.new_link.name" => "链接名称",
+        "sftp.new_link.name_placeholder" => "请输入链接名称",
+        "sftp.new_link.target" => "链接目标",
+        "sftp.new_link.target_placeholder" => "输入目标路径，或在下方选择",
+        "sftp.new_link.browse_empty" => "目录为空",
+        "sftp.new_link.error_empty" => "链接名称不能为空",
+        "sftp.new_link.error_invalid" => "链接名称包含非法字符",
+        "sftp.new_link.error_target" => "链接目标不能为空",
+        "sftp.link.target_missing" => "链接目标不存在或无法访问",
+        // SFTP 文件/目录对比
+        "sftp.compare.title" => "对比",
+        "sftp.compare.local" => "本地",
+        "sftp.compare.loading" => "正在读取…",
+        "sftp.compare.identical" => "内容相同",
+        "sftp.compare.too_large" => "文件过大（超过 2 MB），无法对比",
+        "sftp.compare.binary_identical" => "二进制文件，内容相同",
+        "sftp.compare.binary_different" => "二进制文件，内容不同",
+        "sftp.compare.added" => "新增",
+        "sftp.compare.removed" => "删除",
+        "sftp.compare.modified" => "修改",
+        "sftp.compare.only_diffs" => "仅显示差异",
+        "sftp.compare.selected" => "已选择用于比较",
+        "sftp.compare.no_selection" => "请先选择用于比较的文件或目录",
+        "sftp.compare.kind_mismatch" => "文件只能与文件比较，目录只能与目录比较",
+        "sftp.compare.same_target" => "不能与自身比较",
         "sftp.loading" => "加载中...",
+        "sftp.loading_progress" => "已加载 {} 项…",
+        "sftp.loading_cancel" => "停止加载",
+        "sftp.loading_cancelled" => "已停止加载，列表仅包含部分条目",
         "sftp.not_connected" => "未连接",
+        "sftp.load_error.title" => "无法读取目录",
+        "sftp.load_error.permission_denied" => "没有权限读取此目录",
+        "sftp.load_error.retry" => "重试",
+        "sftp.load_error.sudo_list" => "以 sudo 列出",
+        "sftp.load_error.sudo_listing" => "当前列表通过 sudo 列出，仅供查看",
+        "sftp.scp_fallback" => {
+            "服务器未启用 SFTP，已切换到 SCP 兼容模式（不支持断点续传与多通道传输）"
+        }
+        // SFTP 以管理员身份编辑
+        "sftp.sudo.title" => "以管理员身份编辑",
+        "sftp.sudo.prompt" => "保存该文件需要 sudo 权限，请输入当前用户的密码：",
+        "sftp.sudo.password_placeholder" => "sudo 密码",
+        "sftp.sudo.auth_failed" => "sudo 验证失败",
+        "sftp.sudo.list_title" => "需要管理员权限",
+        "sftp.sudo.list_prompt" => "列出该目录需要 sudo 权限，请输入当前用户的密码：",
         // SFTP 删除通知
         "sftp.delete.success" => "删除成功",
+        "sftp.init_failed" => "SFTP 初始化失败",
+        "sftp.pick.save_file" => "保存文件",
+        "sftp.pick.upload_files" => "选择要上传的文件",
+        "sftp.pick.download_dir" => "选择下载保存位置",
+        "sftp.pick.upload_folder" => "选择要上传的文件夹",
         "sftp.delete.failed" => "删除失败",
         // SFTP 重命名通知
         "sftp.rename.success" => "重命名成功",
         "sftp.rename.failed" => "重命名失败",
+        // SFTP 移动
+        "sftp.move.failed" => "移动失败",
+        "sftp.overwrite.title" => "目标已存在",
+        "sftp.overwrite.message" => "目标位置已存在同名项目，是否覆盖？",
+        "sftp.overwrite.confirm" => "覆盖",
         // SFTP 下载通知
         "sftp.download.success" => "下载完成",
         "sftp.download.failed" => "下载失败",
@@ -308,6 +886,7 @@ fn zh_cn(key: &'static str) -> &'static str {
         "sftp.context_menu.download" => "下载",
         "sftp.context_menu.download_folder" => "下载文件夹",
         "sftp.context_menu.edit_file" => "编辑文件",
+        "sftp.context_menu.edit_as_root" => "以管理员身份编辑",
         "sftp.context_menu.copy_name" => "复制名称",
         "sftp.context_menu.copy_path" => "复制路径",
         "sftp.context_menu.rename" => "重命名",
@@ -315,12 +894,31 @@ fn zh_cn(key: &'static str) -> &'static str {
         "sftp.context_menu.open_folder" => "打开",
         "sftp.context_menu.open_in_terminal" => "在终端打开",
         "sftp.context_menu.properties" => "属性",
+        "sftp.context_menu.hex_view" => "十六进制查看",
+        "sftp.context_menu.tail_log" => "跟踪日志",
+        "sftp.context_menu.new_link" => "新建符号链接…",
+        "sftp.context_menu.follow_link" => "跳转到目标",
+        "sftp.context_menu.compare_select" => "选择用于比较",
+        "sftp.context_menu.compare_with" => "与已选比较",
+        "sftp.context_menu.sync_directory" => "同步目录…",
+        "sftp.context_menu.copy_to_server" => "复制到其他服务器…",
+        "sftp.context_menu.compress_tar_gz" => "压缩为 tar.gz",
+        "sftp.context_menu.compress_zip" => "压缩为 zip",
+        "sftp.context_menu.extract" => "解压到当前目录",
         "sftp.context_menu.refresh" => "刷新",
         "sftp.context_menu.new_folder" => "新建文件夹",
         "sftp.context_menu.new_file" => "新建文件",
         "sftp.context_menu.upload_file" => "上传文件",
         "sftp.context_menu.upload_folder" => "上传文件夹",
         "sftp.context_menu.select_all" => "全选",
+        "sftp.context_menu.empty_trash" => "清空回收站",
+        // SFTP 回收站
+        "sftp.trash.emptied" => "回收站已清空",
+        "sftp.trash.empty_title" => "清空回收站",
+        "sftp.trash.empty_message" => "将永久删除回收站中的所有项目，此操作无法撤销：",
+        "sftp.trash.empty_confirm" => "清空",
+        "sftp.trash.unsafe_root" => "回收站目录不安全，已拒绝操作",
+        "sftp.trash.empty_failed" => "清空回收站失败",
         // SFTP 属性对话框
         "sftp.properties.title" => "属性",
         "sftp.properties.close" => "关闭",
@@ -334,6 +932,160 @@ fn zh_cn(key: &'static str) -> &'static str {
         "sftp.properties.type_folder" => "文件夹",
         "sftp.properties.type_symlink" => "符号链接",
         "sftp.properties.type_other" => "其他",
+        "sftp.properties.perm_owner" => "所有者",
+        "sftp.properties.perm_group" => "组",
+        "sftp.properties.perm_other" => "其他",
+        "sftp.properties.perm_read" => "读",
+        "sftp.properties.perm_write" => "写",
+        "sftp.properties.perm_execute" => "执行",
+        "sftp.properties.octal" => "八进制",
+        "sftp.properties.owner" => "所有者",
+        "sftp.properties.group" => "所属组",
+        "sftp.properties.recursive" => "应用到所有子项",
+        "sftp.properties.apply" => "应用",
+        "sftp.properties.applied" => "属性已更新",
+        "sftp.properties.apply_failed" => "修改属性失败",
+        "sftp.properties.selected_items" => "已选择 {} 项",
+        "sftp.properties.items" => "项目",
+        "sftp.properties.selection_summary" => "{files} 个文件，{folders} 个文件夹",
+        "sftp.properties.location" => "位置",
+        "sftp.properties.calculating" => "计算中...",
+        "sftp.properties.size_unavailable" => "无法计算",
+        "sftp.properties.stop" => "停止",
+        "sftp.properties.checksum" => "校验和",
+        "sftp.properties.compute" => "计算",
+        "sftp.properties.copy" => "复制",
+        "sftp.properties.checksum_failed" => "计算失败",
+        "sftp.properties.xattrs" => "扩展属性",
+        "sftp.properties.acl" => "访问控制列表 (ACL)",
+        // SFTP 搜索
+        "sftp.search.name_placeholder" => "搜索文件名...",
+        "sftp.search.content_placeholder" => "包含内容（可选）",
+        "sftp.search.glob" => "通配符",
+        "sftp.search.regex" => "正则",
+        "sftp.search.searching" => "正在搜索...",
+        "sftp.search.results" => "个结果",
+        "sftp.search.truncated" => "结果过多，仅显示前 1000 个",
+        "sftp.search.no_results" => "未找到匹配的文件",
+        "sftp.search.failed" => "搜索失败",
+        "sftp.bookmarks.title" => "收藏夹",
+        "sftp.bookmarks.recent" => "最近目录",
+        "sftp.bookmarks.recent_files" => "最近文件",
+        "sftp.bookmarks.empty" => "暂无",
+        "sftp.go_to_path" => "前往路径…",
+        "sftp.filter.placeholder" => "过滤当前目录（支持 * ?）",
+        "sftp.columns.title" => "显示列",
+        "sftp.preview.empty" => "选择文件以预览",
+        "sftp.preview.encoding" => "编码",
+        "sftp.preview.truncated" => "仅显示开头部分",
+        "sftp.preview.binary" => "二进制文件，文件头：",
+        "sftp.preview.too_large" => "图片过大，不生成预览",
+        "sftp.preview.unknown_version" => "（未知版本）",
+        "sftp.disk_usage.title" => "磁盘占用分析",
+        "sftp.disk_usage.scanning" => "正在统计...",
+        "sftp.disk_usage.failed" => "统计失败",
+        "sftp.disk_usage.total" => "总计",
+        "sftp.disk_usage.empty" => "目录为空",
+        "sftp.disk_usage.up" => "上一级",
+        "sftp.disk_usage.refresh" => "重新统计",
+        "sftp.disk_usage.close" => "关闭",
+        "sftp.disk_usage.reveal" => "在文件列表中显示",
+        "sftp.log_viewer.path_placeholder" => "输入日志文件路径，回车开始跟踪",
+        "sftp.log_viewer.filter_placeholder" => "过滤",
+        "sftp.log_viewer.keyword_placeholder" => "添加高亮关键词",
+        "sftp.log_viewer.common_paths" => "常用日志",
+        "sftp.log_viewer.choose_path" => "点击输入路径，或从常用日志中选择",
+        "sftp.log_viewer.pause" => "暂停",
+        "sftp.log_viewer.resume" => "继续",
+        "sftp.log_viewer.follow" => "跟随到底部",
+        "sftp.log_viewer.clear" => "清空",
+        "sftp.log_viewer.close" => "关闭",
+        "sftp.log_viewer.waiting" => "等待日志输出...",
+        "sftp.log_viewer.no_match" => "没有匹配的行",
+        "sftp.log_viewer.paused" => "已暂停，{} 行待显示",
+        "sftp.log_viewer.streaming" => "正在跟踪",
+        "sftp.log_viewer.stopped" => "已停止",
+        "sftp.log_viewer.count" => "{} 行",
+        "sftp.hex.title" => "十六进制查看",
+        "sftp.hex.go" => "跳转",
+        "sftp.hex.offset_placeholder" => "偏移量（如 0x1F00）",
+        "sftp.hex.search_placeholder" => "搜索内容",
+        "sftp.hex.mode_hex" => "十六进制",
+        "sftp.hex.mode_text" => "文本",
+        "sftp.hex.find_next" => "查找下一个",
+        "sftp.hex.searching" => "查找中...",
+        "sftp.hex.not_found" => "未找到匹配内容",
+        "sftp.hex.invalid_offset" => "偏移量无效或超出文件大小",
+        "sftp.hex.invalid_pattern" => "搜索内容无效，十六进制需为完整字节（如 7F 45 4C 46）",
+        "sftp.hex.empty" => "文件为空",
+        "sftp.search.stop" => "停止",
+        "sftp.search.in" => "搜索范围",
+        // SFTP 双面板
+        "sftp.pane.moved" => "移动完成，已删除源文件",
+        "sftp.pane.move_incomplete" => "部分传输未成功，已保留源文件",
+        // SFTP 目录同步
+        "sftp.sync.title" => "同步目录",
+        "sftp.sync.local_dir" => "本地目录",
+        "sftp.sync.remote_dir" => "远程目录",
+        "sftp.sync.local_placeholder" => "选择本地目录",
+        "sftp.sync.remote_placeholder" => "远程目录的绝对路径",
+        "sftp.sync.browse" => "浏览...",
+        "sftp.sync.mode" => "同步方式",
+        "sftp.sync.mode_to_remote" => "镜像到远程",
+        "sftp.sync.mode_to_local" => "镜像到本地",
+        "sftp.sync.mode_both" => "双向同步",
+        "sftp.sync.mode_to_remote_hint" => "以本地为准：上传新增和变更的文件，删除远程多余的文件",
+        "sftp.sync.mode_to_local_hint" => "以远程为准：下载新增和变更的文件，删除本地多余的文件",
+        "sftp.sync.mode_both_hint" => "互相补齐缺失的文件，两侧都有变更时保留较新的版本",
+        "sftp.sync.compare_by" => "比较方式",
+        "sftp.sync.compare_size_mtime" => "大小和修改时间",
+        "sftp.sync.compare_checksum" => "校验和 (SHA-256)",
+        "sftp.sync.compare" => "比较",
+        "sftp.sync.comparing" => "正在比较两侧文件...",
+        "sftp.sync.back" => "返回",
+        "sftp.sync.execute" => "执行同步",
+        "sftp.sync.in_sync" => "两侧目录内容一致，无需同步",
+        "sftp.sync.click_to_change" => "点击操作标签可切换该文件的操作",
+        "sftp.sync.identical" => "相同",
+        "sftp.sync.action_upload" => "上传",
+        "sftp.sync.action_download" => "下载",
+        "sftp.sync.action_delete" => "删除",
+        "sftp.sync.action_delete_local" => "删除本地",
+        "sftp.sync.action_delete_remote" => "删除远程",
+        "sftp.sync.action_skip" => "跳过",
+        "sftp.sync.error_local" => "本地目录不存在",
+        "sftp.sync.error_remote" => "请输入远程目录的绝对路径",
+        "sftp.sync.done" => "目录同步完成",
+        "sftp.sync.partial" => "目录同步完成，部分文件失败",
+        // SFTP 跨服务器复制
+        "sftp.remote_copy.title" => "复制到其他服务器",
+        "sftp.remote_copy.target_server" => "目标服务器",
+        "sftp.remote_copy.target_dir" => "目标目录",
+        "sftp.remote_copy.dir_placeholder" => "目标服务器上的目录",
+        "sftp.remote_copy.copy" => "复制",
+        "sftp.remote_copy.error_no_target" => "请选择目标服务器",
+        "sftp.remote_copy.error_dir" => "请输入目标目录的绝对路径",
+        "sftp.remote_copy.no_targets" => "没有其他已连接的服务器",
+        "sftp.remote_copy.done" => "复制到其他服务器完成",
+        "sftp.remote_copy.failed" => "复制到其他服务器失败",
+        // SFTP 传输冲突
+        "sftp.conflict.title" => "文件已存在",
+        "sftp.conflict.local_file" => "本地文件",
+        "sftp.conflict.remote_file" => "远程文件",
+        "sftp.conflict.apply_to_all" => "对之后的冲突执行相同操作",
+        "sftp.conflict.compress" => "传输时使用 gzip 压缩",
+        "sftp.conflict.extract" => "下载后自动解压",
+        "sftp.conflict.skip" => "跳过",
+        "sftp.conflict.rename" => "重命名",
+        "sftp.conflict.overwrite_if_newer" => "较新时覆盖",
+        "sftp.conflict.overwrite" => "覆盖",
+        // SFTP 压缩/解压
+        "sftp.archive.compressing" => "压缩中",
+        "sftp.archive.extracting" => "解压中",
+        "sftp.archive.compress_done" => "压缩完成",
+        "sftp.archive.compress_failed" => "压缩失败",
+        "sftp.archive.extract_done" => "解压完成",
+        "sftp.archive.extract_failed" => "解压失败",
         // 终端输入
         "session.terminal.simulated" => "模拟终端区域",
         "session.terminal.command_placeholder" => "输入命令...",
@@ -356,6 +1108,29 @@ fn zh_cn(key: &'static str) -> &'static str {
         "monitor.network_interface" => "接口",
         "monitor.no_interfaces" => "暂无可用网络接口",
         "monitor.disk" => "磁盘状态",
+        "monitor.gpu" => "GPU 状态",
+        "monitor.gpu.no_processes" => "无计算进程",
+        "monitor.health" => "硬件健康",
+        "monitor.health.temperatures" => "温度",
+        "monitor.health.drives" => "硬盘 SMART",
+        "monitor.health.passed" => "正常",
+        "monitor.health.failed" => "异常 {}",
+        "monitor.health.unknown" => "未知",
+        "monitor.logins" => "登录",
+        "monitor.collector_error" => "监控数据采集失败",
+        "monitor.paused" => "已暂停",
+        "monitor.logins.sessions" => "当前会话 ({})",
+        "monitor.logins.recent" => "最近登录",
+        "monitor.logins.failed" => "失败登录",
+        "monitor.logins.last_login" => "最近登录",
+        "monitor.logins.failed_count" => "最近 {} 次失败登录",
+        "monitor.logins.failed_none" => "没有失败登录记录",
+        "monitor.logins.failed_unavailable" => "查看失败登录需要 root 或免密 sudo",
+        "monitor.logins.empty" => "无记录",
+        "monitor.logins.user" => "用户",
+        "monitor.logins.tty" => "终端",
+        "monitor.logins.from" => "来源",
+        "monitor.logins.time" => "时间",
         "monitor.no_data" => "暂无数据",
 
         // Monitor 详情弹窗
@@ -387,11 +1162,199 @@ fn zh_cn(key: &'static str) -> &'static str {
         "monitor.detail.uptime_days" => "天",
         "monitor.detail.uptime_hours" => "小时",
         "monitor.detail.uptime_minutes" => "分钟",
+        "monitor.history.title" => "历史趋势",
+        "monitor.export.tooltip" => "导出当前时间范围的数据",
+        "monitor.export.title" => "导出监控数据",
+        "monitor.export.done" => "已导出到 {}",
+        "monitor.export.failed" => "导出失败：{}",
+        "monitor.history.collecting" => "正在收集历史数据，请稍候…",
+        "monitor.history.cpu" => "CPU 使用率",
+        "monitor.history.memory" => "内存使用率",
+        "monitor.history.network" => "网络速率",
+        "monitor.history.disk_io" => "磁盘 IO",
+        "monitor.history.read" => "读",
+        "monitor.history.write" => "写",
+        "monitor.tab.monitor" => "监控",
+        "monitor.tab.processes" => "进程",
+        "monitor.tab.docker" => "Docker",
+        "monitor.tab.services" => "服务",
+        "monitor.tab.network" => "网络",
+        "monitor.tab.cron" => "计划任务",
+        "monitor.tab.packages" => "软件包",
+        "monitor.tab.firewall" => "防火墙",
+
+        // 进程管理
+        "processes.filter_placeholder" => "过滤 PID / 用户 / 命令",
+        "processes.count" => "{} 个进程",
+        "processes.column.pid" => "PID",
+        "processes.column.user" => "用户",
+        "processes.column.cpu" => "CPU%",
+        "processes.column.mem" => "内存%",
+        "processes.column.command" => "命令",
+        "processes.load_failed" => "获取进程列表失败",
+        "processes.kill" => "结束进程",
+        "processes.force_kill" => "强制结束 (SIGKILL)",
+        "processes.renice" => "调整优先级",
+        "processes.confirm_kill" => "结束进程 {pid}（{name}）？",
+        "processes.confirm_force_kill" => "强制结束进程 {pid}（{name}）？未保存的数据将丢失",
+        "processes.confirm_renice" => "将进程 {pid}（{name}）的 nice 值调整为 {nice}？",
+        "processes.action_done" => "操作已完成",
+        "processes.action_failed" => "操作失败",
+
+        // Docker
+        "docker.detecting" => "正在检测 Docker...",
+        "docker.unavailable" => "未检测到可用的 Docker",
+        "docker.load_failed" => "获取容器列表失败",
+        "docker.containers" => "容器 ({})",
+        "docker.images" => "镜像 ({})",
+        "docker.running_count" => "{} 个运行中",
+        "docker.empty" => "暂无数据",
+        "docker.start" => "启动",
+        "docker.stop" => "停止",
+        "docker.restart" => "重启",
+        "docker.logs" => "查看日志",
+        "docker.exec" => "进入容器",
+        "docker.remove_container" => "删除容器",
+        "docker.remove_image" => "删除镜像",
+        "docker.confirm_remove_container" => "删除容器 {}？",
+        "docker.confirm_remove_image" => "删除镜像 {}？",
+        "docker.action_done" => "操作已完成",
+        "docker.action_failed" => "操作失败",
+
+        // systemd 服务
+        "services.filter_placeholder" => "过滤服务名 / 描述",
+        "services.count" => "{} 个服务",
+        "services.load_failed" => "获取服务列表失败",
+        "services.start" => "启动",
+        "services.stop" => "停止",
+        "services.restart" => "重启",
+        "services.enable" => "开机启动",
+        "services.disable" => "取消开机启动",
+        "services.journal" => "查看日志",
+        "services.confirm_stop" => "停止服务 {}？",
+        "services.confirm_restart" => "重启服务 {}？",
+        "services.confirm_disable" => "取消服务 {} 的开机启动？",
+        "services.action_done" => "操作已完成",
+        "services.action_failed" => "操作失败",
+        "services.sudo.title" => "需要管理员权限",
+        "services.sudo.prompt" => "管理该服务需要 sudo 权限，请输入当前用户的密码：",
+        "cron.count" => "{} 个计划任务",
+        "cron.unsaved" => "（未保存）",
+        "cron.new" => "新建任务",
+        "cron.edit" => "编辑任务",
+        "cron.reload" => "重新读取",
+        "cron.save" => "保存到服务器",
+        "cron.apply" => "应用",
+        "cron.delete" => "删除",
+        "cron.enable" => "启用",
+        "cron.disable" => "停用",
+        "cron.empty" => "没有计划任务",
+        "cron.load_failed" => "读取计划任务失败",
+        "cron.save_failed" => "保存失败",
+        "cron.saved" => "crontab 已保存",
+        "cron.saved_with_backup" => "crontab 已保存，原内容已备份到 {}",
+        "cron.schedule_placeholder" => "分 时 日 月 周，如 0 3 * * *",
+        "cron.command_placeholder" => "要执行的命令",
+        "cron.command_required" => "命令不能为空，且只能有一行",
+        "cron.next_runs" => "接下来运行",
+        "cron.confirm_save" => "写回 crontab？原内容会先备份到 ~/.crontab.bak.<时间>",
+        "cron.confirm_discard" => "放弃未保存的修改并重新读取？",
+        "cron.system_title" => "/etc/cron.d（只读）",
+        "cron.system_load" => "读取（sudo）",
+        "cron.system_reload" => "刷新",
+        "cron.sudo.title" => "需要管理员权限",
+        "cron.error.modified" => "服务器上的 crontab 已被修改，请重新加载后再保存",
+        "cron.sudo.prompt" => "读取 /etc/cron.d 需要 sudo 权限，请输入当前用户的密码：",
+        "cron.weekdays" => "周日,周一,周二,周三,周四,周五,周六",
+        "cron.hint.invalid" => "无法解析的计划",
+        "cron.hint.reboot" => "开机时运行",
+        "cron.hint.every_minute" => "每分钟",
+        "cron.hint.every_n_minutes" => "每 {} 分钟",
+        "cron.hint.hourly" => "每小时第 {} 分",
+        "cron.hint.every_n_hours" => "每 {n} 小时的第 {m} 分",
+        "cron.hint.daily" => "每天 {}",
+        "cron.hint.weekly" => "每{days} {time}",
+        "cron.hint.monthly" => "每月 {day} 日 {time}",
+        "cron.hint.custom" => "自定义计划",
+        "packages.detecting" => "正在检测包管理器...",
+        "packages.not_found" => "未检测到支持的包管理器（apt / dnf / yum / brew）",
+        "packages.reload" => "重新检测",
+        "packages.search_placeholder" => "搜索软件包，回车确认",
+        "packages.clear_search" => "清除搜索",
+        "packages.searching" => "搜索中...",
+        "packages.search_failed" => "搜索失败",
+        "packages.search_results" => "搜索结果（{}）",
+        "packages.no_results" => "没有匹配的软件包",
+        "packages.upgradable" => "可升级（{}）",
+        "packages.up_to_date" => "所有软件包均为最新（基于本地索引，可先更新索引）",
+        "packages.install" => "安装",
+        "packages.upgrade" => "升级",
+        "packages.action.refresh" => "更新软件包索引",
+        "packages.action.upgrade_all" => "升级全部软件包",
+        "packages.action.upgrade" => "升级 {}",
+        "packages.action.install" => "安装 {}",
+        "packages.confirm" => "确认{}？",
+        "packages.sudo_hint" => "将通过 sudo 执行",
+        "packages.running" => "执行中...",
+        "packages.done" => "已完成",
+        "packages.failed" => "失败",
+        "packages.close_output" => "关闭输出",
+        "packages.action_done" => "软件包操作已完成",
+        "packages.sudo.title" => "需要管理员权限",
+        "packages.sudo.prompt" => "修改软件包需要 sudo 权限，请输入当前用户的密码：",
+        "firewall.reload" => "重新读取规则",
+        "firewall.search_placeholder" => "搜索端口、来源或动作",
+        "firewall.not_found" => "未检测到 ufw / firewalld / iptables",
+        "firewall.needs_sudo" => "读取防火墙规则需要 sudo 权限",
+        "firewall.load_failed" => "读取防火墙规则失败",
+        "firewall.empty" => "没有规则",
+        "firewall.no_match" => "没有匹配的规则",
+        "firewall.anywhere" => "任意来源",
+        "firewall.port_placeholder" => "端口",
+        "firewall.allow" => "放行",
+        "firewall.deny" => "拒绝",
+        "firewall.invalid_port" => "请输入 1-65535 之间的端口",
+        "firewall.confirm_allow" => "确定放行 {} 吗？",
+        "firewall.confirm_deny" => "确定拒绝 {} 吗？",
+        "firewall.allowed" => "已放行 {}",
+        "firewall.denied" => "已拒绝 {}",
+        "firewall.apply_failed" => "修改防火墙规则失败",
+        "firewall.applied" => "防火墙规则已更新",
+        "firewall.sudo.title" => "需要管理员权限",
+        "firewall.sudo.prompt" => "读取和修改防火墙规则需要 sudo 权限，请输入当前用户的密码：",
+        "connections.filter_placeholder" => "搜索进程 / PID / 地址 / 端口",
+        "connections.count" => "{} 个套接字",
+        "connections.load_failed" => "获取网络连接失败",
+        "connections.scope_all" => "全部",
+        "connections.listening" => "监听",
+        "connections.established" => "已建立",
+        "connections.group_summary" => "监听 {listening} · 连接 {established}",
+        "connections.unknown_process" => "未知进程（需要 root 权限查看）",
+        "connections.open_in_terminal" => "在终端查看",
+        "alerts.metric.cpu" => "CPU",
+        "alerts.metric.memory" => "内存",
+        "alerts.metric.disk" => "磁盘 {}",
+        "alerts.message_temperature" => "{sensor} 温度 {value} 已超过阈值 {threshold}",
+        "alerts.message_drive" => "硬盘 {} SMART 自检未通过",
+        "alerts.message" => "{metric} 使用率 {value}% 已超过阈值 {threshold}%",
+        "alerts.empty" => "暂无告警",
+        "alerts.mute_all" => "全部静音",
+        "alerts.unmute_all" => "取消全部静音",
+        "alerts.mute_server" => "静音 {}",
+        "alerts.unmute_server" => "取消静音 {}",
+        "alerts.clear" => "清空",
+        "alerts.muted" => "已静音",
 
         // Snippets 快捷命令
         "snippets.add_group" => "新建组",
         "snippets.add_command" => "新建命令",
+        "snippets.library.import" => "导入",
+        "snippets.library.export" => "导出",
+        "snippets.library.imported" => "已导入 {} 条命令",
+        "snippets.library.duplicates" => "跳过 {} 条重复命令",
+        "snippets.library.exported" => "已导出 {} 条命令到",
         "snippets.breadcrumb.all" => "全部",
+        "snippets.group.items" => "{} 项",
         "snippets.empty.title" => "暂无快捷命令",
         "snippets.empty.description" => "创建命令组或命令开始使用",
         "snippets.dialog.group_name" => "组名称",
@@ -403,6 +1366,21 @@ fn zh_cn(key: &'static str) -> &'static str {
         "snippets.dialog.edit_command" => "编辑命令",
         "snippets.dialog.enter_name" => "请输入名称...",
         "snippets.dialog.enter_command" => "请输入命令...",
+        "snippets.dialog.enter_description" => "可选，简要说明用途...",
+        "snippets.dialog.tags" => "标签",
+        "snippets.dialog.enter_tags" => "多个标签用逗号分隔...",
+        "snippets.dialog.color" => "颜色",
+        "snippets.dialog.variables_hint" => "使用 {{变量}} 或 {{变量:默认值}} 插入运行时填写的变量",
+        "snippets.dialog.scope" => "可见范围",
+        "snippets.dialog.scope_hint" => "只在匹配所选分组、标签或服务器的会话中显示，未选择时对所有服务器可见",
+        "snippets.dialog.scope_groups" => "服务器分组",
+        "snippets.dialog.scope_tags" => "服务器标签",
+        "snippets.dialog.scope_servers" => "服务器",
+        "snippets.dialog.auto_run" => "连接范围内的服务器后自动执行",
+        "snippets.variables.title" => "运行快捷命令",
+        "snippets.variables.hint" => "填写命令中的变量，留空时使用默认值",
+        "snippets.variables.preview" => "将要执行的命令",
+        "snippets.variables.run" => "运行",
         // 快捷命令右键菜单
         "snippets.context_menu.execute" => "在终端执行",
         "snippets.context_menu.edit_in_box" => "在命令框编辑",
@@ -410,14 +1388,222 @@ fn zh_cn(key: &'static str) -> &'static str {
         // 小侧栏
         "mini_sidebar.snippets" => "快捷命令",
         "mini_sidebar.transfer" => "传输管理",
+        "mini_sidebar.notifications" => "通知中心",
+        "mini_sidebar.forwards" => "端口转发",
+        "mini_sidebar.runbooks" => "Runbook",
+        "mini_sidebar.macros" => "键盘宏",
+        "forward.kind.local" => "本地转发：本机端口 → 远程目标",
+        "forward.kind.remote" => "远程转发：服务器端口 → 本机目标",
+        "forward.kind.dynamic" => "动态转发：本机 SOCKS5 代理",
+        "forward.bind_placeholder" => "监听 [地址:]端口",
+        "forward.target_placeholder" => "目标 主机:端口",
+        "forward.invalid_bind" => "监听端口无效",
+        "forward.invalid_target" => "目标地址无效，格式为 主机:端口",
+        "forward.add" => "添加转发",
+        "forward.empty" => "暂无端口转发",
+        "forward.connections" => "连接 {active} / 共 {total}",
+        "forward.start" => "启动",
+        "forward.stop" => "停止",
+        "forward.remove" => "删除",
+        "forward.db_tunnel" => "数据库隧道",
+        "forward.db_tunnel_tooltip" => "转发到服务器上该数据库的端口（目标为空时使用默认端口）",
+        "forward.copy_url" => "复制连接串",
+        "forward.launch_client" => "启动客户端",
+        "forward.launch_failed" => "启动客户端失败：{}",
 
         // 传输管理
         "transfer.empty" => "暂无传输任务",
+        "transfer.resumed_from" => "续传自",
+        "transfer.verified" => "已校验",
+        "transfer.pause_all" => "全部暂停",
+        "transfer.resume_all" => "全部继续",
+        "transfer.drag_hint" => "拖动等待中的任务可调整顺序",
+        "transfer.limit.title" => "限速",
+        "transfer.limit.unlimited" => "不限速",
+        "transfer.folder.files" => "个文件",
+        "transfer.folder.failed_files" => "{} 个文件传输失败",
+        "transfer.cancelled_by_user" => "用户取消",
+        "transfer.download_cancelled" => "下载已取消",
+        "transfer.upload_cancelled" => "上传已取消",
+        "transfer.status.pending" => "等待中",
+        "transfer.status.downloading" => "下载中",
+        "transfer.status.uploading" => "上传中",
+        "transfer.status.paused" => "已暂停",
+        "transfer.status.completed" => "完成",
+        "transfer.status.failed" => "失败",
+        "transfer.status.cancelled" => "已取消",
+        "transfer.tab.active" => "传输中",
+        "transfer.tab.history" => "历史",
+        "transfer.history.empty" => "暂无传输历史",
+        "transfer.history.clear" => "清空历史",
+        "transfer.history.retry" => "重新传输",
+        "transfer.history.open_folder" => "打开所在文件夹",
+        "transfer.history.filter.all" => "全部",
+        "transfer.history.filter.uploads" => "上传",
+        "transfer.history.filter.downloads" => "下载",
+        "transfer.history.filter.failed" => "失败",
 
         // 已知主机
         "known_hosts.empty.title" => "暂无已知主机",
         "known_hosts.empty.description" => "连接服务器并信任主机密钥后，会在这里显示",
         "known_hosts.items" => "项",
+        "known_hosts.first_seen" => "首次: {}",
+        "known_hosts.last_used" => "最近: {}",
+
+        // Runbook
+        "runbook.add" => "新建 Runbook",
+        "runbook.empty" => "暂无 Runbook，新建后可按步骤执行部署、维护等操作流程",
+        "runbook.step_count" => "{} 个步骤",
+        "runbook.no_terminal" => "当前标签没有可用的终端",
+        "runbook.pause" => "暂停",
+        "runbook.resume" => "继续",
+        "runbook.abort" => "中止",
+        "runbook.run_again" => "重新运行",
+        "runbook.continue" => "确认并继续",
+        "runbook.log" => "运行日志",
+        "runbook.status.running" => "运行中",
+        "runbook.status.awaiting_confirm" => "等待确认",
+        "runbook.status.paused" => "已暂停",
+        "runbook.status.finished" => "已完成",
+        "runbook.status.failed" => "失败",
+        "runbook.status.aborted" => "已中止",
+        "runbook.step.send" => "发送命令",
+        "runbook.step.wait_for" => "等待输出",
+        "runbook.step.confirm" => "手动确认",
+        "runbook.step.sleep" => "等待",
+        "runbook.log.started" => "开始运行 {}",
+        "runbook.log.sent" => "发送命令：{}",
+        "runbook.log.waiting" => "等待输出匹配：{}",
+        "runbook.log.matched" => "输出已匹配",
+        "runbook.log.timeout" => "等待输出超时（{} 秒）",
+        "runbook.log.invalid_pattern" => "正则表达式无效：{}",
+        "runbook.log.confirm" => "等待确认：{}",
+        "runbook.log.confirmed" => "已确认",
+        "runbook.log.sleep" => "等待 {} 秒",
+        "runbook.log.paused" => "已暂停",
+        "runbook.log.resumed" => "继续运行",
+        "runbook.log.aborted" => "已中止",
+        "runbook.log.finished" => "全部步骤已完成",
+        "runbook.log.no_terminal" => "终端不可用，无法发送命令",
+        "runbook.notify.finished" => "Runbook「{}」已完成",
+        "runbook.notify.failed" => "Runbook「{}」运行失败",
+        "runbook.dialog.add_title" => "新建 Runbook",
+        "runbook.dialog.edit_title" => "编辑 Runbook",
+        "runbook.dialog.name" => "名称",
+        "runbook.dialog.description" => "描述",
+        "runbook.dialog.steps" => "步骤",
+        "runbook.dialog.add_step" => "添加步骤",
+        "runbook.dialog.timeout" => "超时",
+        "runbook.dialog.enter_name" => "请输入名称...",
+        "runbook.dialog.enter_description" => "可选",
+        "runbook.dialog.enter_command" => "要发送的命令",
+        "runbook.dialog.enter_pattern" => "正则表达式，如 \\$ $",
+        "runbook.dialog.enter_message" => "确认提示",
+        "runbook.dialog.enter_secs" => "秒数",
+        "runbook.dialog.name_required" => "请输入名称",
+        "runbook.dialog.steps_required" => "至少需要一个步骤",
+        "runbook.dialog.step_empty" => "步骤 {} 的内容不能为空",
+        "runbook.dialog.invalid_pattern" => "步骤 {} 的正则表达式无效",
+        "runbook.dialog.invalid_secs" => "步骤 {} 的秒数必须为正整数",
+        "runbook.dialog.save_failed" => "保存失败",
+
+        // 键盘宏
+        "macro.record" => "录制",
+        "macro.stop" => "停止录制",
+        "macro.recording" => "正在录制…（{} 个字符）",
+        "macro.empty" => "暂无键盘宏，点击录制后在终端中输入按键",
+        "macro.play_to" => "回放到其他会话",
+        "macro.no_terminal" => "目标会话没有可用的终端",
+        "macro.notify.empty" => "没有录制到按键",
+        "macro.dialog.add_title" => "保存键盘宏",
+        "macro.dialog.edit_title" => "编辑键盘宏",
+        "macro.dialog.name" => "名称",
+        "macro.dialog.enter_name" => "请输入键盘宏名称",
+        "macro.dialog.keys" => "按键序列",
+        "macro.dialog.keys_hint" => "控制字符以转义形式显示：\\r 回车、\\t Tab、\\e Esc、\\xNN 其他控制字符、\\\\ 反斜杠",
+        "macro.dialog.keybinding" => "快捷键",
+        "macro.dialog.enter_keybinding" => "例如 ctrl-alt-m（留空表示不绑定）",
+        "macro.dialog.keybinding_hint" => "在终端中按下快捷键即回放该键盘宏",
+        "macro.dialog.name_required" => "请输入名称",
+        "macro.dialog.keys_required" => "按键序列不能为空",
+        "macro.dialog.invalid_keys" => "按键序列第 {} 个字符处的转义无效",
+        "macro.dialog.invalid_keybinding" => "快捷键格式无效",
+        "macro.dialog.keybinding_modifier" => "快捷键需要包含 Ctrl、Alt 或 Cmd",
+        "macro.dialog.keybinding_taken" => "快捷键 {} 已绑定到其他键盘宏",
+        "macro.dialog.save_failed" => "保存失败",
+
+        // 插件
+        "plugins.hint" => "将 Rhai 脚本（.rhai）放入插件目录，启用后即在后台运行；脚本可定义 run() 供手动运行，定义 on_connect(tab_id, server) 在会话连接后执行",
+        "plugins.open_dir" => "打开插件目录",
+        "plugins.reload" => "重新加载",
+        "plugins.empty" => "插件目录中还没有脚本",
+        "plugins.run" => "运行",
+        "plugins.log" => "日志",
+        "plugins.clear_log" => "清空",
+        "plugins.log_empty" => "暂无日志",
+        "plugins.status.stopped" => "未启用",
+        "plugins.status.running" => "运行中",
+        "plugins.status.idle" => "已加载",
+        "plugins.status.failed" => "加载失败",
+        "plugins.log.started" => "插件已启动",
+        "plugins.log.stopped" => "插件已停止",
+        "plugins.error.server_not_found" => "未找到服务器：{}",
+        "plugins.error.no_terminal" => "会话 {} 没有可用的终端",
+        "plugins.error.no_sftp" => "会话 {} 的 SFTP 尚未就绪",
+        "plugins.dialog.source" => "来自插件「{}」",
+
+        // 定时任务
+        "schedules.hint" => "按计划在服务器上运行快捷命令或 Runbook（未连接时临时连接），仅在应用运行期间生效",
+        "schedules.add" => "新建任务",
+        "schedules.empty" => "还没有定时任务",
+        "schedules.missing" => "（已删除）",
+        "schedules.next_run" => "下次运行",
+        "schedules.running" => "运行中…",
+        "schedules.run_now" => "立即运行",
+        "schedules.edit" => "编辑",
+        "schedules.delete" => "删除",
+        "schedules.runbook" => "Runbook",
+        "schedules.history" => "运行记录",
+        "schedules.clear_history" => "清空",
+        "schedules.history_empty" => "暂无运行记录",
+        "schedules.status.success" => "成功",
+        "schedules.status.exit" => "退出码",
+        "schedules.status.error" => "失败",
+        "schedules.manual" => "手动运行",
+        "schedules.no_output" => "（无输出）",
+        "schedules.notify.failed" => "定时任务「{}」在 {} 上运行失败",
+        "schedules.error.server_missing" => "服务器已不存在",
+        "schedules.error.snippet_missing" => "快捷命令已不存在",
+        "schedules.error.runbook_missing" => "Runbook 已不存在",
+        "schedules.error.runbook_confirm" => "包含手动确认步骤的 Runbook 无法定时运行",
+        "schedules.error.reboot" => "定时任务不支持 @reboot",
+        "schedules.dialog.add_title" => "新建定时任务",
+        "schedules.dialog.edit_title" => "编辑定时任务",
+        "schedules.dialog.name" => "名称",
+        "schedules.dialog.enter_name" => "输入任务名称",
+        "schedules.dialog.server" => "服务器",
+        "schedules.dialog.select_server" => "选择服务器",
+        "schedules.dialog.no_servers" => "没有已保存的服务器",
+        "schedules.dialog.task" => "运行内容",
+        "schedules.dialog.select_task" => "选择快捷命令或 Runbook",
+        "schedules.dialog.no_tasks" => "没有快捷命令或 Runbook",
+        "schedules.dialog.schedule" => "计划（cron 表达式）",
+        "schedules.dialog.name_required" => "请输入任务名称",
+        "schedules.dialog.server_required" => "请选择服务器",
+        "schedules.dialog.task_required" => "请选择要运行的快捷命令或 Runbook",
+        "schedules.dialog.invalid_schedule" => "计划表达式无效",
+        "schedules.dialog.save_failed" => "保存失败",
+
+        // SSH 配置片段
+        "ssh_snippet.title" => "{} 的 SSH 配置",
+        "ssh_snippet.config" => "OpenSSH 配置（~/.ssh/config）",
+        "ssh_snippet.command" => "ssh 命令",
+        "ssh_snippet.copy_config" => "复制配置",
+        "ssh_snippet.copy_command" => "复制命令",
+        "ssh_snippet.copied" => "已复制",
+        "ssh_snippet.hint" => "密码不会导出；跳板机自身的私钥与代理只在配置中保留",
+        "ssh_snippet.forwards" => "已包含当前会话的 {} 条端口转发",
+        "ssh_snippet.failed" => "生成 SSH 配置失败",
 
         _ => key,
     }
@@ -432,6 +1618,7 @@ fn en_us(key: &'static str) -> &'static str {
         "common.loading" => "Loading...",
         "common.edit" => "Edit",
         "common.delete" => "Delete",
+        "common.close" => "Close",
 
         // Settings Menu
         "settings.title" => "Settings",
@@ -444,6 +1631,14 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.nav.sync" => "Sync",
         "settings.nav.system" => "System",
         "settings.nav.about" => "About",
+        "settings.search.placeholder" => "Search settings",
+        "settings.search.results" => "{} matching settings, click to jump",
+        "settings.search.no_results" => "No matching settings",
+        "settings.apply" => "Apply",
+        "settings.unsaved" => "Unsaved changes",
+        "settings.confirm_discard" => "You have unsaved changes that will be lost",
+        "settings.discard" => "Discard",
+        "settings.keep_editing" => "Keep Editing",
 
         // Theme Settings
         "settings.theme.language" => "Language",
@@ -454,6 +1649,22 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.theme.font" => "Font",
         "settings.theme.font_family" => "UI Font",
         "settings.theme.font_size" => "UI Font Size",
+        "settings.ui_theme.title" => "Interface Colors",
+        "settings.ui_theme.default" => "Default",
+        "settings.ui_theme.custom" => "Custom",
+        "settings.ui_theme.current" => "Current",
+        "settings.ui_theme.import" => "Import Theme",
+        "settings.ui_theme.export" => "Export Theme",
+        "settings.ui_theme.imported" => "Imported theme \"{}\"",
+        "settings.ui_theme.exported" => "Theme exported to",
+        "settings.ui_theme.edit_dark" => "Dark Palette",
+        "settings.ui_theme.edit_light" => "Light Palette",
+        "settings.ui_theme.accent" => "Accent",
+        "settings.ui_theme.background" => "Background",
+        "settings.ui_theme.surface" => "Cards & Dialogs",
+        "settings.ui_theme.sidebar" => "Sidebar & Title Bar",
+        "settings.ui_theme.border" => "Border",
+        "settings.ui_theme.hint" => "Colors in #RRGGBB format preview instantly; leave empty for the built-in color. Save to keep, Cancel to revert",
 
         // Terminal Settings
         "settings.terminal.font" => "Font",
@@ -470,13 +1681,34 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.terminal.cursor_style.bar" => "Bar",
         "settings.terminal.cursor_style.underline" => "Underline",
         "settings.terminal.scrollback" => "Scrollback Lines",
+        "settings.terminal.transparency" => "Transparency & Blur",
+        "settings.terminal.background_opacity" => "Background Opacity (%)",
+        "settings.terminal.background_blur" => "Background Blur",
+        "settings.terminal.dim_inactive" => "Dim Inactive Panes",
 
         // Key Bindings
         "settings.keybindings.global_title" => "Global Shortcuts",
         "settings.keybindings.quit" => "Quit Application",
+        "settings.keybindings.tab_overview" => "Tab Overview",
+        "settings.keybindings.command_palette" => "Command Palette",
+        "settings.keybindings.navigation_title" => "Navigation Shortcuts",
+        "settings.keybindings.next_tab" => "Next Tab",
+        "settings.keybindings.prev_tab" => "Previous Tab",
+        "settings.keybindings.activate_tab" => "Go to Tab N",
+        "settings.keybindings.last_tab" => "Go to Last Tab",
+        "settings.keybindings.next_terminal" => "Next Terminal",
+        "settings.keybindings.prev_terminal" => "Previous Terminal",
+        "settings.keybindings.toggle_sidebar" => "Toggle Sidebar",
+        "settings.keybindings.focus_terminal" => "Focus Terminal",
+        "settings.keybindings.focus_sftp" => "Focus File List",
+        "settings.keybindings.focus_monitor" => "Focus Monitor Panel",
+        "settings.keybindings.navigate_back" => "Go Back",
+        "settings.keybindings.navigate_forward" => "Go Forward",
         "settings.keybindings.terminal_title" => "Terminal Shortcuts",
         "settings.keybindings.copy" => "Copy",
         "settings.keybindings.paste" => "Paste",
+        "settings.keybindings.sftp_title" => "File Manager Shortcuts",
+        "settings.keybindings.rename" => "Rename",
         "settings.keybindings.more_coming_soon" => {
             "More shortcut settings coming in a future release"
         }
@@ -485,13 +1717,33 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.sftp.file_display" => "File Display",
         "settings.sftp.show_hidden" => "Show Hidden Files",
         "settings.sftp.folders_first" => "Folders First",
+        "settings.sftp.dual_pane" => "Dual-pane layout by default (local + remote)",
         "settings.sftp.transfer" => "Transfer Settings",
         "settings.sftp.concurrent" => "Concurrent Transfers",
+        "settings.sftp.max_active_transfers" => "Max Simultaneous Files",
+        "settings.sftp.max_transfers_per_server" => "Max Files per Server",
+        "settings.sftp.download_limit" => "Download Limit (KB/s, 0 = off)",
+        "settings.sftp.upload_limit" => "Upload Limit (KB/s, 0 = off)",
         "settings.sftp.preserve_time" => "Preserve Timestamps",
         "settings.sftp.resume" => "Resume Transfers",
+        "settings.sftp.verify_checksum" => "Verify Integrity After Transfer (SHA-256)",
+        "settings.sftp.transfer_compression" => "Compress Single-File Transfers with gzip",
+        "settings.sftp.auto_extract" => "Extract Archives After Download",
+        "settings.sftp.conflict_action" => "When File Exists",
+        "settings.sftp.conflict.ask" => "Ask Every Time",
+        "settings.sftp.conflict.overwrite" => "Overwrite",
+        "settings.sftp.conflict.overwrite_if_newer" => "Overwrite If Newer",
+        "settings.sftp.conflict.rename" => "Rename Automatically",
+        "settings.sftp.conflict.skip" => "Skip",
         "settings.sftp.default_download_path" => "Default Download Path",
         "settings.sftp.default_download_path_placeholder" => "Leave empty to prompt each time",
         "settings.sftp.browse" => "Browse",
+        "settings.sftp.pick_download_path" => "Choose Default Download Path",
+        "settings.sftp.pick_editor" => "Choose Editor Program",
+        "settings.sftp.delete" => "Delete",
+        "settings.sftp.use_remote_trash" => "Move Deleted Items to Remote Trash",
+        "settings.sftp.remote_trash_dir" => "Trash Directory",
+        "settings.sftp.remote_trash_dir_invalid" => "The trash directory cannot be /, your home directory or one of its parents; it will not be saved",
         "settings.sftp.editor" => "Editor",
         "settings.sftp.builtin_editor" => "Use Built-in Editor",
         "settings.sftp.external_editor_path" => "External Editor Path",
@@ -508,6 +1760,8 @@ fn en_us(key: &'static str) -> &'static str {
 
         // Monitor Settings
         "settings.monitor.data_collection" => "Data Collection",
+        "settings.monitor.refresh_interval" => "Refresh Interval",
+        "settings.monitor.interval_secs" => "{}s",
         "settings.monitor.history_retention" => "History Retention (min)",
         "settings.monitor.auto_deploy" => "Auto Deploy Agent",
         "settings.monitor.display_items" => "Display Items",
@@ -515,10 +1769,16 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.monitor.memory" => "Memory",
         "settings.monitor.disk" => "Disk",
         "settings.monitor.network" => "Network",
+        "settings.monitor.gpu" => "GPU",
+        "settings.monitor.health" => "Hardware Health",
+        "settings.monitor.logins" => "Logins",
         "settings.monitor.alerts" => "Alert Thresholds",
         "settings.monitor.cpu_threshold" => "CPU (%)",
         "settings.monitor.memory_threshold" => "Memory (%)",
         "settings.monitor.disk_threshold" => "Disk (%)",
+        "settings.monitor.temperature_threshold" => "Temperature (°C)",
+        "settings.monitor.alert_consecutive" => "Consecutive Samples",
+        "settings.monitor.alert_notification" => "Show Alert Notifications",
 
         // Connection Settings
         "settings.connection.ssh" => "SSH Settings",
@@ -530,6 +1790,16 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.connection.reconnect_enabled" => "Auto Reconnect",
         "settings.connection.reconnect_attempts" => "Reconnect Attempts",
         "settings.connection.reconnect_interval" => "Reconnect Interval (s)",
+        "settings.connection.hibernation" => "Tab Hibernation",
+        "settings.connection.hibernate_after" => "Hibernate After (min, 0 = off)",
+        "settings.connection.hibernate_connected" => "Hibernate Connected Tabs (Closes Shell)",
+        "settings.connection.reachability" => "Server Reachability",
+        "settings.connection.reachability_hint" => "Checks whether servers are online by connecting to the SSH port; servers behind a jump host or proxy show as unknown",
+        "settings.connection.reachability_probe" => "Check in Background",
+        "settings.connection.reachability_interval" => "Check Interval (minutes)",
+        "settings.connection.db_clients" => "Database Clients",
+        "settings.connection.db_clients_hint" => "Commands used to launch a local client for database tunnels. Supports {host}, {port} and {url} placeholders; leave empty to hide the launch button",
+        "settings.connection.db_client_placeholder" => "e.g. mysql -h {host} -P {port} -u root -p",
 
         // Data Sync
         "settings.sync.status" => "Sync Status",
@@ -544,25 +1814,251 @@ fn en_us(key: &'static str) -> &'static str {
         "settings.sync.webdav_url" => "Server URL",
         "settings.sync.webdav_username" => "Username",
         "settings.sync.webdav_password" => "Password",
+        "settings.sync.passphrase" => "Sync Passphrase",
+        "settings.sync.passphrase_hint" => "Synced data is encrypted locally with this passphrase before upload. It is never sent to the server; use the same passphrase on every device",
         "settings.sync.webdav_path" => "Storage Path",
         "settings.sync.test_connection" => "Test Connection",
         "settings.sync.sync_now" => "Sync Now",
+        "settings.sync.interval" => "Auto Sync Frequency",
+        "settings.sync.interval.manual" => "Manual only",
+        "settings.sync.interval.on_startup" => "On startup",
+        "settings.sync.interval.hourly" => "Hourly",
+        "settings.sync.interval.daily" => "Daily",
+        "settings.sync.conflict_strategy" => "Conflicts",
+        "settings.sync.conflict.ask" => "Ask every time",
+        "settings.sync.conflict.local_first" => "Prefer local",
+        "settings.sync.conflict.remote_first" => "Prefer remote",
+        "settings.sync.conflict.merge" => "Merge automatically",
+        "settings.sync.backup_before_sync" => "Back up remote before overwriting",
+        "settings.sync.keys" => "Passwords and Keys",
+        "settings.sync.running" => "Syncing...",
+        "settings.sync.last_synced" => "Last synced:",
+        "settings.sync.never" => "Never",
+        "sync.result.test_ok" => "Connection successful",
+        "sync.result.up_to_date" => "Already up to date",
+        "sync.result.pushed" => "Local config uploaded",
+        "sync.result.pulled" => "Remote config applied",
+        "sync.result.merged" => "Local and remote config merged",
+        "sync.result.conflict" => "Both sides changed, choose how to resolve",
+        "settings.sync.method" => "Sync Method",
+        "settings.sync.method.webdav" => "WebDAV",
+        "settings.sync.method.icloud" => "iCloud",
+        "settings.sync.method.git" => "Git Repository",
+        "settings.sync.git" => "Git Repository",
+        "settings.sync.git_remote" => "Remote URL",
+        "settings.sync.git_branch" => "Branch",
+        "settings.sync.git_private_key" => "Private Key",
+        "settings.sync.git_private_key_placeholder" => "Leave empty to use the default key in ~/.ssh",
+        "settings.sync.inventory" => "Team Shared Inventory",
+        "settings.sync.inventory_hint" => "Load a read-only server list (JSON / YAML) from an HTTP(S) URL or a shared folder. Connections use identities stored on this machine.",
+        "settings.sync.inventory_source" => "Inventory Location",
+        "settings.sync.inventory_source_placeholder" => "https://... or /mnt/team/servers.yaml",
+        "settings.sync.inventory_refresh" => "Refresh (minutes)",
+        "settings.sync.inventory_identity" => "Default Identity",
+        "settings.sync.inventory_identity_none" => "None",
+        "settings.sync.inventory_refresh_now" => "Refresh Now",
+        "settings.sync.inventory_loading" => "Loading...",
+        "settings.sync.inventory_loaded" => "Loaded {} shared servers",
+        "settings.sync.history" => "History",
+        "settings.sync.history.load" => "Show History",
+        "settings.sync.history.empty" => "No server list commits in the repository yet",
+        "settings.sync.history.restore" => "Restore",
+        "sync.result.restored" => "Server list restored",
+        "identity.items" => "identities",
+        "identity.new" => "New Identity",
+        "identity.new_title" => "New Identity",
+        "identity.edit_title" => "Edit Identity",
+        "identity.empty" => "No identities yet. Register a private key or SSH agent key as an identity so servers can reference it, and rotate the key in one place.",
+        "identity.name" => "Name",
+        "identity.name_placeholder" => "e.g. Work key",
+        "identity.username" => "Default Username",
+        "identity.username_placeholder" => "Used when the server has no username",
+        "identity.agent_key" => "Agent Key",
+        "identity.agent_key_placeholder" => "Key comment or SHA256 fingerprint (empty tries all keys)",
+        "identity.agent_hint" => "Connects to the local SSH agent via SSH_AUTH_SOCK. The private key never leaves the agent.",
+        "identity.agent_any" => "Any key in the agent",
+        "identity.kind.key_file" => "Key File",
+        "identity.kind.agent" => "SSH Agent",
+        "identity.used_by" => "Used by {} servers",
+        "identity.unused" => "Not used by any server",
+        "identity.edit" => "Edit",
+        "identity.rotate" => "Rotate Key",
+        "identity.delete" => "Delete",
+        "identity.name_empty" => "Please enter an identity name",
+        "identity.private_key_empty" => "Please choose a private key file",
+        "trash.items" => "deleted servers",
+        "trash.retention" => "permanently deleted after {} days",
+        "trash.empty" => "Trash is empty",
+        "trash.empty_trash" => "Empty Trash",
+        "trash.days_left" => "{} days left",
+        "trash.restore" => "Restore",
+        "trash.purge" => "Delete Forever",
+        "trash.deleted" => "Deleted \"{}\"",
+        "trash.undo_hint" => "Click to undo, or restore it from Trash",
+
+        // Session workspaces
+        "workspace.title" => "Session Workspaces",
+        "workspace.save_current" => "Save the open sessions",
+        "workspace.name_placeholder" => "Workspace name",
+        "workspace.name_empty" => "Please enter a workspace name",
+        "workspace.no_sessions" => "No sessions are open",
+        "workspace.empty" => "No saved workspaces",
+        "workspace.last_session" => "Last Session",
+        "workspace.tabs" => "{} tabs",
+        "workspace.open" => "Open",
+        "workspace.restore_prompt" => "Restore last session?",
+        "workspace.restore_hint" => "{} sessions were open when you quit, click to reconnect",
+
+        // Command palette
+        "palette.placeholder" => "Search commands, servers, snippets, bookmarks or tabs…",
+        "palette.empty" => "No matching commands",
+        "palette.hint" => "↑↓ Select · Enter Run · Esc Close",
+        "palette.no_terminal" => "No terminal available to run the command",
+        "palette.kind.action" => "Action",
+        "palette.kind.server" => "Connect",
+        "palette.kind.menu" => "Go to",
+        "palette.kind.settings" => "Settings",
+        "palette.kind.snippet" => "Snippet",
+        "palette.kind.bookmark" => "SFTP Bookmark",
+        "palette.kind.recent_file" => "Recent File",
+        "palette.kind.tab" => "Tab",
+        "palette.action.tab_overview" => "Tab Overview",
+        "palette.action.quick_connect" => "Quick Connect",
+        "palette.action.quit" => "Quit",
+        "palette.action.next_tab" => "Next Tab",
+        "palette.action.prev_tab" => "Previous Tab",
+        "palette.action.next_terminal" => "Next Terminal",
+        "palette.action.prev_terminal" => "Previous Terminal",
+        "palette.action.toggle_sidebar" => "Toggle Sidebar",
+        "palette.action.focus_terminal" => "Focus Terminal",
+        "palette.action.focus_sftp" => "Focus File List",
+        "palette.action.focus_monitor" => "Focus Monitor Panel",
+        "palette.action.navigate_back" => "Go Back to Previous View",
+        "palette.action.navigate_forward" => "Go Forward to Next View",
+        "palette.action.notifications" => "Notification Center",
+
+        // Session tabs
+        "tab.close" => "Close Tab",
+        "tab.close_others" => "Close Other Tabs",
+        "tab.close_all" => "Close All",
+        "tab.pin" => "Pin Tab",
+        "tab.unpin" => "Unpin Tab",
+        "tab.open_in_new_window" => "Open in New Window",
+        "tab.ssh_snippet" => "SSH Config & Command",
+        "tab.move_to_main" => "Move to Main Window",
+        "tab.move_to_window" => "Move to Window: {}",
+        "tab.color" => "Color",
+        "tab.color.auto" => "Auto (by group)",
+        "tab.color.none" => "None",
+        "tab.color.red" => "Red",
+        "tab.color.orange" => "Orange",
+        "tab.color.yellow" => "Yellow",
+        "tab.color.green" => "Green",
+        "tab.color.teal" => "Teal",
+        "tab.color.blue" => "Blue",
+        "tab.color.purple" => "Purple",
+        "tab.color.pink" => "Pink",
+
+        // Close tabs confirmation
+        "close_tabs.title" => "Close {} tab(s)?",
+        "close_tabs.message" => "These tabs still have work in progress. Closing them will disconnect and stop it:",
+        "close_tabs.terminals" => "{} terminal(s)",
+        "close_tabs.transfers" => "{} transfer(s)",
+        "close_tabs.forwards" => "{} port forward(s)",
+        "close_tabs.confirm" => "Close",
+
+        // Notification center
+        "notifications.title" => "Notifications",
+        "notifications.empty" => "No notifications",
+        "notifications.mark_all_read" => "Mark All Read",
+        "notifications.clear" => "Clear",
+        "notifications.filter_all" => "All",
+        "notifications.category.transfer" => "Transfers",
+        "notifications.category.session" => "Sessions",
+        "notifications.category.monitor" => "Monitor Alerts",
+        "notifications.category.operation" => "Operations",
+
+        // Tray menu
+        "tray.favorites" => "Favorite Servers",
+        "tray.no_favorites" => "No favorites (right-click a host to add)",
+        "tray.transfers" => "Active Transfers ({})",
+        "tray.toggle_window" => "Show/Hide Main Window",
+        "tray.quit" => "Quit ShellMaster",
+        "server_dialog.auth_identity" => "Identity",
+        "server_dialog.identity" => "Identity",
+        "server_dialog.no_identities" => "No identities yet. Add one on the Identities page in the sidebar.",
+        "recovery.title" => "Configuration Corrupted",
+        "recovery.message" => "The following configuration could not be read. The corrupted content has been saved to the backups folder. Restore from the most recent backup or reset to defaults.",
+        "recovery.backup_found" => "Most recent usable backup: {}",
+        "recovery.no_backup" => "No usable backup",
+        "recovery.restore" => "Restore from Backup",
+        "recovery.reset" => "Reset to Defaults",
+        "recovery.later" => "Not Now",
+        "recovery.database_title" => "Cannot Open Configuration Database",
+        "recovery.database_message" => "The app is running read-only and no changes will be saved. Check the permissions and free space of the configuration folder, then restart the app.",
+        "recovery.document.servers" => "Server list",
+        "recovery.document.settings" => "App settings",
+        "recovery.document.snippets" => "Snippets",
+        "recovery.document.known_hosts" => "Known hosts",
+        "recovery.document.transfer_history" => "Transfer history",
+        "recovery.document.connection_history" => "Connection history",
+        "recovery.document.sftp_bookmarks" => "SFTP bookmarks",
+        "recovery.document.workspaces" => "Session workspaces",
+        "recovery.document.window_state" => "Window state",
+        "recovery.document.schedules" => "Scheduled jobs",
+        "recovery.document.job_history" => "Scheduled job history",
+        "sync.conflict.title" => "Sync Conflict",
+        "sync.conflict.message" => "Both the local and remote config changed since the last sync. Keep one side, or merge both (local wins for the same entry).",
+        "sync.conflict.local" => "Local",
+        "sync.conflict.remote" => "Remote",
+        "sync.conflict.servers" => "servers",
+        "sync.conflict.snippets" => "commands",
+        "sync.conflict.later" => "Later",
+        "sync.conflict.keep_remote" => "Use Remote",
+        "sync.conflict.keep_local" => "Keep Local",
+        "sync.conflict.merge" => "Merge",
 
         // System Settings
         "settings.system.startup" => "Startup",
         "settings.system.auto_start" => "Launch at Login",
         "settings.system.start_minimized" => "Start Minimized",
+        "settings.system.restore_sessions" => "Restore Last Session on Launch",
         "settings.system.check_updates" => "Check Updates",
         "settings.system.window" => "Window",
-        "settings.system.close_to_tray" => "Close to Tray",
+        "settings.system.close_to_tray" => "Minimize to Tray on Close",
         "settings.system.show_tray_icon" => "Show Tray Icon",
         "settings.system.single_instance" => "Single Instance",
         "settings.system.notification" => "Notification",
         "settings.system.notify_disconnect" => "Disconnect Notification",
         "settings.system.notify_transfer" => "Transfer Complete Notification",
+        "settings.system.system_notifications" => "System Notifications When in Background",
+        "settings.system.do_not_disturb" => "Do Not Disturb (Record to Notification Center Only)",
         "settings.system.logging" => "Logging",
         "settings.system.logging_enabled" => "Enable Logging",
         "settings.system.log_retention" => "Log Retention (days)",
+        "settings.system.backup" => "Config Backup",
+        "settings.system.inventory" => "Server Inventory",
+        "settings.system.inventory.hint" => "Export as CSV or an OpenSSH config file (Host blocks) for other tools and teammates. Passwords are not included.",
+        "settings.system.inventory.export_csv" => "Export CSV",
+        "settings.system.inventory.export_ssh_config" => "Export SSH config",
+        "settings.system.inventory.exported" => "Exported {} servers to",
+        "settings.system.url_handler" => "ssh:// Links",
+        "settings.system.url_handler.hint" => "Make ShellMaster the default app for ssh:// links so clicking one in a browser or another app connects here. You can also run shellmaster user@host -p port --key identity_file to connect from the command line",
+        "settings.system.url_handler.register" => "Set as Default",
+        "settings.system.url_handler.registered" => "ShellMaster is now the default app for ssh:// links",
+        "settings.system.backup.include_secrets" => "Include passwords and private keys",
+        "settings.system.backup.password" => "Archive Password",
+        "settings.system.backup.password_placeholder" => "Encrypts / decrypts the archive",
+        "settings.system.backup.password_required" => "Enter the archive password first",
+        "settings.system.backup.export" => "Export Config",
+        "settings.system.backup.import" => "Import Config",
+        "settings.system.backup.exported" => "Exported to",
+        "settings.system.backup.imported" => "Import complete",
+        "settings.system.backup.servers" => "Servers",
+        "settings.system.backup.groups" => "Groups",
+        "settings.system.backup.snippets" => "Commands",
+        "settings.system.backup.no_secrets_note" => "archive has no passwords, local ones kept",
+        "settings.system.backup.auto_backup_hint" => "Config files are backed up before every write to:",
 
         // About
         "settings.about.platform" => "Platform",
@@ -573,26 +2069,65 @@ fn en_us(key: &'static str) -> &'static str {
         "sidebar.hosts" => "Hosts",
         "sidebar.monitor" => "Monitor",
         "sidebar.snippets" => "Snippets",
+        "sidebar.batch_exec" => "Batch Exec",
 
+        "sidebar.identities" => "Identities",
         "sidebar.known_hosts" => "Known Hosts",
-        "sidebar.history" => "History",
+        "sidebar.connection_history" => "Connection History",
+        "sidebar.plugins" => "Plugins",
+        "sidebar.schedules" => "Schedules",
+        "sidebar.trash" => "Trash",
+        "sidebar.history" => "Recent",
         "sidebar.settings" => "Settings",
 
+        // Session layout
+        "session_layout.sidebar_left" => "Move Sidebar to Left",
+        "session_layout.sidebar_right" => "Move Sidebar to Right",
+        "session_layout.collapse_monitor" => "Collapse Monitor Panel",
+        "session_layout.expand_monitor" => "Expand Monitor Panel",
+        "session_layout.reset" => "Reset Layout",
+
+        // Deep links
+        "deep_link.connect_prompt" => "Connect to {}?",
+        "deep_link.connect_hint" => "An external link requested a temporary connection (server not saved). Click this notification to connect.",
+
+        // Session latency
+        "latency.title" => "Latency (last 5 minutes)",
+        "latency.current" => "Current {} ms",
+        "latency.average" => "Average {} ms",
+        "latency.stale" => "Stale",
+        "latency.no_reply_for" => "No reply for {}s",
+        "latency.never_replied" => "No reply yet",
+
         // History Time
         "history.just_now" => "Just now",
         "history.minutes_ago" => "m ago",
         "history.hours_ago" => "h ago",
         "history.days_ago" => "d ago",
 
+        // Connection history page
+        "connection_history.search_placeholder" => "Search server name or address",
+        "connection_history.items" => "entries",
+        "connection_history.clear" => "Clear History",
+        "connection_history.empty" => "No connection history",
+        "connection_history.active" => "Active",
+        "connection_history.reconnect" => "Reconnect",
+        "connection_history.filter.all" => "All",
+        "connection_history.filter.succeeded" => "Succeeded",
+        "connection_history.filter.failed" => "Failed",
+
         // Server Dialog
         "server_dialog.add_title" => "Add Server",
         "server_dialog.edit_title" => "Edit Server",
         "server_dialog.nav.basic_info" => "Basic Info",
         "server_dialog.nav.jump_host" => "Jump Host",
         "server_dialog.nav.proxy" => "Proxy Settings",
+        "server_dialog.nav.overrides" => "Overrides",
         "server_dialog.nav.other" => "Other Settings",
         "server_dialog.group" => "Server Group",
-        "server_dialog.group_placeholder" => "Select or enter group",
+        "server_dialog.group_placeholder" => "Select or enter group, use / for subgroups",
+        "server_dialog.tags" => "Tags",
+        "server_dialog.tags_placeholder" => "Comma separated, e.g. env:prod, role:db",
         "server_dialog.label" => "Server Label",
         "server_dialog.label_placeholder" => "Enter server name",
         "server_dialog.host" => "Host Address",
@@ -618,9 +2153,54 @@ fn en_us(key: &'static str) -> &'static str {
         "server_dialog.description" => "Description",
         "server_dialog.description_placeholder" => "Enter server description (optional)",
         "server_dialog.no_other_settings" => "No other settings available",
+        "server_dialog.terminal_font_size" => "Terminal Font Size",
+        "server_dialog.terminal_color_scheme" => "Terminal Color Scheme",
+        "server_dialog.scrollback" => "Scrollback Lines",
+        "server_dialog.keepalive" => "Keepalive Interval (s, 0 to disable)",
+        "server_dialog.override.hint" => "When \"Override global\" is on, this server uses the value below; other settings follow the global settings",
+        "server_dialog.override.toggle" => "Override global",
+        "server_dialog.override.global" => "Global: {}",
+        "server_dialog.terminal_opacity" => "Terminal Background Opacity (%)",
+        "server_dialog.monitor_interval" => "Monitor Refresh Interval (s)",
+        "server_dialog.new_template_title" => "New Server Template",
+        "server_dialog.edit_template_title" => "Edit Server Template",
+        "server_dialog.template_name" => "Template Name",
+        "server_dialog.post_connect" => "Post-connect Commands",
+        "server_dialog.post_connect_placeholder" => "One command per line, run in the first terminal after connecting",
 
         // Server List
         "server_list.add_server" => "Add Server",
+        "server_list.import" => "Import",
+        "server_list.discover" => "Discover",
+        "server_import.title" => "Import Servers",
+        "server_import.source.putty_reg" => "PuTTY (.reg)",
+        "server_import.source.putty_registry" => "PuTTY (Registry)",
+        "server_import.source.termius" => "Termius",
+        "server_import.source.securecrt" => "SecureCRT (XML)",
+        "server_import.choose_file" => "Choose File...",
+        "server_import.read_registry" => "Read Registry",
+        "server_import.summary" => "{} servers found, {} already exist",
+        "server_import.empty" => "No SSH sessions found to import",
+        "server_import.duplicate" => "Exists",
+        "server_import.proxy" => "Proxy",
+        "server_import.select_all" => "Select All",
+        "server_import.select_none" => "Select None",
+        "server_import.confirm" => "Import {}",
+        "discovery.title" => "Discover Hosts",
+        "discovery.hint" => "Scan a subnet or IP range for hosts with an open SSH port. Accepts 192.168.1.0/24, 192.168.1.10-50 and single addresses, separated by commas",
+        "discovery.range_placeholder" => "e.g. 192.168.1.0/24",
+        "discovery.port" => "Port",
+        "discovery.start" => "Scan",
+        "discovery.stop" => "Stop",
+        "discovery.progress" => "Scanned {} / {}, found {} hosts",
+        "discovery.empty" => "No hosts with an open SSH port found",
+        "discovery.no_banner" => "No SSH banner received",
+        "discovery.add" => "Add as Server",
+        "discovery.added" => "Added",
+        "discovery.error.empty" => "Enter a target to scan",
+        "discovery.error.invalid" => "Unrecognized target: {}",
+        "discovery.error.too_many" => "At most {} addresses can be scanned at once",
+        "discovery.error.port" => "Invalid port",
         "server_list.empty_title" => "No Servers",
         "server_list.empty_description" => "Click the button below to add your first server",
         "server_list.header.server" => "Server",
@@ -631,7 +2211,71 @@ fn en_us(key: &'static str) -> &'static str {
         "server_list.header.last_connected" => "Last Connected",
         "server_list.header.actions" => "Actions",
         "server_list.ungrouped" => "Ungrouped",
+        "server_list.shared" => "Shared",
+        "server_list.shared_badge" => "Shared",
+        "server_list.copy_to_local" => "Copy to My Servers",
         "server_list.never_connected" => "Never",
+        "server_list.new_group" => "New Group",
+        "server_list.probe_all" => "Check All",
+        "server_list.probing" => "Checking…",
+        "server_list.reachability.online" => "Online · {} ms",
+        "server_list.reachability.offline" => "Offline (SSH port unreachable)",
+        "server_list.reachability.unknown" => "Unknown (not checked yet, or via jump host or proxy)",
+        "server_list.search_placeholder" => "Search servers and press Enter to connect, or type user@host:port",
+        "server_list.filter_tags" => "Tags",
+        "server_list.clear_filter" => "Clear Filters",
+        "server_list.no_match" => "No matching servers",
+        "server_list.match_count" => "{} / {} servers",
+        "server_list.enter_to_connect" => "Press Enter to connect to {}",
+        "server_list.adhoc_connect" => "Connect to {} without saving",
+        "server_list.adhoc_password" => "Password (empty uses default key in ~/.ssh)",
+        "server_list.connect" => "Connect",
+        "server_list.edit" => "Edit",
+        "server_list.clone" => "Clone Server",
+        "server_list.favorite" => "Add to Tray Favorites",
+        "server_list.unfavorite" => "Remove from Favorites",
+        "server_list.clone_label" => "{} (copy)",
+        "server_list.save_as_template" => "Save as Template",
+        "server_list.ssh_snippet" => "SSH Config & Command",
+        "server_list.template_name" => "{} template",
+        "server_list.templates" => "Templates",
+        "server_list.new_from_template" => "New from template: {}",
+        "server_list.edit_template" => "Edit template: {}",
+        "server_list.new_template" => "New Template",
+        "server_group.rename" => "Rename",
+        "server_group.new_subgroup" => "New Subgroup",
+        "server_group.move_up" => "Move Up",
+        "server_group.move_down" => "Move Down",
+        "server_group.move_to_top" => "Move to Top Level",
+        "server_group.delete" => "Delete Group",
+        "server_group.dialog.create_title" => "New Group",
+        "server_group.dialog.create_sub_title" => "New Subgroup in \"{}\"",
+        "server_group.dialog.rename_title" => "Rename Group",
+        "server_group.dialog.delete_title" => "Delete Group \"{}\"",
+        "server_group.dialog.name" => "Group Name",
+        "server_group.dialog.name_placeholder" => "Enter group name",
+        "server_group.dialog.name_empty" => "Group name cannot be empty",
+        "server_group.dialog.name_invalid" => "Group name cannot contain /",
+        "server_group.dialog.reassign" => "Move the {} server(s) in this group to:",
+        "server_group.dialog.subgroups_note" => "Subgroups will move up one level",
+        "server_group.dialog.delete_confirm" => "Delete",
+        // Batch edit
+        "batch_edit.title" => "Edit {} Servers",
+        "batch_edit.hint" => "Only the enabled fields are changed; everything else is kept",
+        "batch_edit.selected_count" => "{} server(s) selected (Ctrl/Cmd+click to select)",
+        "batch_edit.edit" => "Batch Edit",
+        "batch_edit.select_all" => "Select All",
+        "batch_edit.clear_selection" => "Clear Selection",
+        "batch_edit.add_tags_placeholder" => "Tags to add, comma separated",
+        "batch_edit.remove_tags_placeholder" => "Tags to remove, comma separated",
+        "batch_edit.no_jump_host" => "No jump host",
+        "batch_edit.proxy_hint" => "Leave the host empty to remove the proxy",
+        "batch_edit.apply" => "Apply",
+        "batch_edit.username_empty" => "Username cannot be empty",
+        "batch_edit.port_invalid" => "Invalid port",
+        "batch_edit.private_key_empty" => "Please choose a private key file",
+        "batch_edit.proxy_port_invalid" => "Invalid proxy port",
+        "batch_edit.nothing_to_apply" => "Enable at least one field to change",
         "server_list.placeholder.snippets" => "Snippets Feature",
         "server_list.placeholder.known_hosts" => "Known Hosts Management",
         "server_list.placeholder.history" => "Connection History",
@@ -660,6 +2304,15 @@ fn en_us(key: &'static str) -> &'static str {
         "connecting.host_key.btn_accept_once" => "Trust Once",
         "connecting.host_key.btn_reject" => "Reject",
         "connecting.connected" => "Connected",
+        "connecting.stage.initializing" => "Initializing",
+        "connecting.stage.proxy" => "Connecting to proxy",
+        "connecting.stage.jump_host" => "Connecting to jump host",
+        "connecting.stage.host" => "Connecting to host",
+        "connecting.stage.handshaking" => "SSH handshake",
+        "connecting.stage.authenticating" => "Authenticating",
+        "connecting.stage.channel" => "Establishing channel",
+        "connecting.stage.session" => "Starting session",
+        "connecting.stage.connected" => "Connected",
 
         // Session Page
         "session.connected" => "Connected to",
@@ -667,6 +2320,44 @@ fn en_us(key: &'static str) -> &'static str {
         // Session Sidebar
         "session.sidebar.quick_actions" => "Quick Actions",
         "session.sidebar.new_terminal" => "New Terminal",
+        // Tab overview
+        "tab_overview.title" => "Tab Overview",
+        // Monitor dashboard
+        "dashboard.title" => "Monitor Dashboard",
+        "dashboard.summary" => "{online} / {total} online",
+        "dashboard.show_disconnected" => "Show disconnected servers",
+        "dashboard.empty" => "No connected servers. Turn on disconnected servers to connect from a tile.",
+        "dashboard.status.online" => "Online",
+        "dashboard.status.connecting" => "Connecting",
+        "dashboard.status.offline" => "Offline",
+        "dashboard.alerts" => "{} alerts",
+        "dashboard.cpu" => "CPU",
+        "dashboard.memory" => "Mem",
+        "dashboard.disk" => "Disk",
+        "dashboard.click_to_connect" => "Click to connect",
+        // Batch exec
+        "batch.title" => "Batch Exec",
+        "batch.summary" => "{done} / {total} done, {failed} failed",
+        "batch.servers" => "Servers ({} selected)",
+        "batch.select_all" => "All",
+        "batch.select_none" => "None",
+        "batch.empty_servers" => "No saved servers",
+        "batch.command_placeholder" => "Command to run on the selected servers",
+        "batch.snippet" => "Insert Snippet",
+        "batch.no_snippets" => "No snippets",
+        "batch.run" => "Run",
+        "batch.export_csv" => "Export CSV",
+        "batch.export_done" => "Exported to {}",
+        "batch.export_failed" => "Export failed: {}",
+        "batch.col.server" => "Server",
+        "batch.col.status" => "Status",
+        "batch.col.duration" => "Duration",
+        "batch.running" => "Running...",
+        "batch.cancelled" => "Cancelled",
+        "batch.failed" => "Connection or execution failed",
+        "batch.exit_code" => "Exit code {}",
+        "batch.no_output" => "No output",
+        "batch.no_results" => "Select servers, enter a command and click Run. Servers that are not connected are connected temporarily.",
         "session.sidebar.file_browser" => "File Browser",
         "session.sidebar.system_info" => "System Info",
         // Monitor Panel
@@ -686,7 +2377,9 @@ fn en_us(key: &'static str) -> &'static str {
         // SFTP File List Header
         "sftp.header.name" => "Name",
         "sftp.header.permissions" => "Permissions",
-        "sftp.header.owner" => "User/Group",
+        "sftp.header.owner" => "Owner",
+        "sftp.header.group" => "Group",
+        "sftp.header.link_target" => "Link Target",
         "sftp.header.size" => "Size",
         "sftp.header.modified" => "Modified",
         // SFTP New Folder Dialog
@@ -705,14 +2398,69 @@ fn en_us(key: &'static str) -> &'static str {
         "sftp.new_file.error_invalid" => "File name contains invalid characters",
         "sftp.new_file.success" => "File created successfully",
         "sftp.new_file.failed" => "Failed to create file",
+        // SFTP New Link Dialog
+        "sftp.new_link.title" => "New Link",
+        "sftp.new_link.kind_symbolic" => "Symbolic Link",
+        "sftp.new_link.kind_hard" => "Hard Link",
+        "sftp.new_link.name" => "Link Name",
+        "sftp.new_link.name_placeholder" => "Enter link name",
+        "sftp.new_link.target" => "Link Target",
+        "sftp.new_link.target_placeholder" => "Enter a target path, or pick one below",
+        "sftp.new_link.browse_empty" => "Empty directory",
+        "sftp.new_link.error_empty" => "Link name cannot be empty",
+        "sftp.new_link.error_invalid" => "Link name contains invalid characters",
+        "sftp.new_link.error_target" => "Link target cannot be empty",
+        "sftp.link.target_missing" => "Link target does not exist or is inaccessible",
+        // SFTP Compare
+        "sftp.compare.title" => "Compare",
+        "sftp.compare.local" => "Local",
+        "sftp.compare.loading" => "Reading…",
+        "sftp.compare.identical" => "Contents are identical",
+        "sftp.compare.too_large" => "File is too large to compare (over 2 MB)",
+        "sftp.compare.binary_identical" => "Binary files are identical",
+        "sftp.compare.binary_different" => "Binary files differ",
+        "sftp.compare.added" => "Added",
+        "sftp.compare.removed" => "Removed",
+        "sftp.compare.modified" => "Modified",
+        "sftp.compare.only_diffs" => "Only Differences",
+        "sftp.compare.selected" => "Selected for compare",
+        "sftp.compare.no_selection" => "Select a file or folder for compare first",
+        "sftp.compare.kind_mismatch" => "Only files with files, or folders with folders",
+        "sftp.compare.same_target" => "Cannot compare an item with itself",
         "sftp.loading" => "Loading...",
+        "sftp.loading_progress" => "Loaded {} items…",
+        "sftp.loading_cancel" => "Stop loading",
+        "sftp.loading_cancelled" => "Loading stopped, the list is incomplete",
         "sftp.not_connected" => "Not connected",
+        "sftp.load_error.title" => "Unable to read directory",
+        "sftp.load_error.permission_denied" => "Permission denied for this directory",
+        "sftp.load_error.retry" => "Retry",
+        "sftp.load_error.sudo_list" => "List with sudo",
+        "sftp.load_error.sudo_listing" => "Listed with sudo, view only",
+        "sftp.scp_fallback" => "SFTP is disabled on this server, using SCP compatibility mode (no resume or multi-channel transfers)",
+        // SFTP edit as administrator
+        "sftp.sudo.title" => "Edit as Administrator",
+        "sftp.sudo.prompt" => "Saving this file requires sudo. Enter your password:",
+        "sftp.sudo.password_placeholder" => "sudo password",
+        "sftp.sudo.auth_failed" => "sudo authentication failed",
+        "sftp.sudo.list_title" => "Administrator Required",
+        "sftp.sudo.list_prompt" => "Listing this directory requires sudo. Enter your password:",
         // SFTP Delete Notification
         "sftp.delete.success" => "Delete successful",
+        "sftp.init_failed" => "SFTP initialization failed",
+        "sftp.pick.save_file" => "Save File",
+        "sftp.pick.upload_files" => "Choose Files to Upload",
+        "sftp.pick.download_dir" => "Choose Download Location",
+        "sftp.pick.upload_folder" => "Choose Folder to Upload",
         "sftp.delete.failed" => "Delete failed",
         // SFTP Rename Notification
         "sftp.rename.success" => "Rename successful",
         "sftp.rename.failed" => "Rename failed",
+        // SFTP move
+        "sftp.move.failed" => "Move failed",
+        "sftp.overwrite.title" => "Target Already Exists",
+        "sftp.overwrite.message" => "An item with the same name already exists. Overwrite it?",
+        "sftp.overwrite.confirm" => "Overwrite",
         // SFTP Download Notification
         "sftp.download.success" => "Download complete",
         "sftp.download.failed" => "Download failed",
@@ -728,6 +2476,7 @@ fn en_us(key: &'static str) -> &'static str {
         "sftp.context_menu.download" => "Download",
         "sftp.context_menu.download_folder" => "Download Folder",
         "sftp.context_menu.edit_file" => "Edit File",
+        "sftp.context_menu.edit_as_root" => "Edit as Administrator",
         "sftp.context_menu.copy_name" => "Copy Name",
         "sftp.context_menu.copy_path" => "Copy Path",
         "sftp.context_menu.rename" => "Rename",
@@ -735,12 +2484,31 @@ fn en_us(key: &'static str) -> &'static str {
         "sftp.context_menu.open_folder" => "Open",
         "sftp.context_menu.open_in_terminal" => "Open in Terminal",
         "sftp.context_menu.properties" => "Properties",
+        "sftp.context_menu.hex_view" => "View as Hex",
+        "sftp.context_menu.tail_log" => "Tail Log",
+        "sftp.context_menu.new_link" => "New Link…",
+        "sftp.context_menu.follow_link" => "Go to Target",
+        "sftp.context_menu.compare_select" => "Select for Compare",
+        "sftp.context_menu.compare_with" => "Compare with Selected",
+        "sftp.context_menu.sync_directory" => "Sync Directory…",
+        "sftp.context_menu.copy_to_server" => "Copy to Another Server…",
+        "sftp.context_menu.compress_tar_gz" => "Compress to tar.gz",
+        "sftp.context_menu.compress_zip" => "Compress to zip",
+        "sftp.context_menu.extract" => "Extract Here",
         "sftp.context_menu.refresh" => "Refresh",
         "sftp.context_menu.new_folder" => "New Folder",
         "sftp.context_menu.new_file" => "New File",
         "sftp.context_menu.upload_file" => "Upload File",
         "sftp.context_menu.upload_folder" => "Upload Folder",
         "sftp.context_menu.select_all" => "Select All",
+        "sftp.context_menu.empty_trash" => "Empty Trash",
+        // SFTP trash
+        "sftp.trash.emptied" => "Trash emptied",
+        "sftp.trash.empty_title" => "Empty Trash",
+        "sftp.trash.empty_message" => "All items in the trash will be permanently deleted. This cannot be undone:",
+        "sftp.trash.empty_confirm" => "Empty",
+        "sftp.trash.unsafe_root" => "Unsafe trash directory, operation refused",
+        "sftp.trash.empty_failed" => "Failed to empty trash",
         // SFTP Properties Dialog
         "sftp.properties.title" => "Properties",
         "sftp.properties.close" => "Close",
@@ -754,6 +2522,160 @@ fn en_us(key: &'static str) -> &'static str {
         "sftp.properties.type_folder" => "Folder",
         "sftp.properties.type_symlink" => "Symbolic Link",
         "sftp.properties.type_other" => "Other",
+        "sftp.properties.perm_owner" => "Owner",
+        "sftp.properties.perm_group" => "Group",
+        "sftp.properties.perm_other" => "Others",
+        "sftp.properties.perm_read" => "Read",
+        "sftp.properties.perm_write" => "Write",
+        "sftp.properties.perm_execute" => "Execute",
+        "sftp.properties.octal" => "Octal",
+        "sftp.properties.owner" => "Owner",
+        "sftp.properties.group" => "Group",
+        "sftp.properties.recursive" => "Apply to all children",
+        "sftp.properties.apply" => "Apply",
+        "sftp.properties.applied" => "Properties updated",
+        "sftp.properties.apply_failed" => "Failed to update properties",
+        "sftp.properties.selected_items" => "{} items selected",
+        "sftp.properties.items" => "Items",
+        "sftp.properties.selection_summary" => "{files} files, {folders} folders",
+        "sftp.properties.location" => "Location",
+        "sftp.properties.calculating" => "Calculating...",
+        "sftp.properties.size_unavailable" => "Unavailable",
+        "sftp.properties.stop" => "Stop",
+        "sftp.properties.checksum" => "Checksums",
+        "sftp.properties.compute" => "Compute",
+        "sftp.properties.copy" => "Copy",
+        "sftp.properties.checksum_failed" => "Failed",
+        "sftp.properties.xattrs" => "Extended Attributes",
+        "sftp.properties.acl" => "Access Control List",
+        // SFTP Search
+        "sftp.search.name_placeholder" => "Search file name...",
+        "sftp.search.content_placeholder" => "Containing text (optional)",
+        "sftp.search.glob" => "Glob",
+        "sftp.search.regex" => "Regex",
+        "sftp.search.searching" => "Searching...",
+        "sftp.search.results" => "results",
+        "sftp.search.truncated" => "Too many results, showing the first 1000",
+        "sftp.search.no_results" => "No matching files",
+        "sftp.search.failed" => "Search failed",
+        "sftp.bookmarks.title" => "Bookmarks",
+        "sftp.bookmarks.recent" => "Recent Directories",
+        "sftp.bookmarks.recent_files" => "Recent Files",
+        "sftp.bookmarks.empty" => "None",
+        "sftp.go_to_path" => "Go to Path…",
+        "sftp.filter.placeholder" => "Filter current folder (* ? supported)",
+        "sftp.columns.title" => "Columns",
+        "sftp.preview.empty" => "Select a file to preview",
+        "sftp.preview.encoding" => "Encoding",
+        "sftp.preview.truncated" => "showing the beginning only",
+        "sftp.preview.binary" => "Binary file, header:",
+        "sftp.preview.too_large" => "Image too large to preview",
+        "sftp.preview.unknown_version" => "(unknown version)",
+        "sftp.disk_usage.title" => "Disk Usage",
+        "sftp.disk_usage.scanning" => "Scanning...",
+        "sftp.disk_usage.failed" => "Scan failed",
+        "sftp.disk_usage.total" => "Total",
+        "sftp.disk_usage.empty" => "Directory is empty",
+        "sftp.disk_usage.up" => "Up",
+        "sftp.disk_usage.refresh" => "Rescan",
+        "sftp.disk_usage.close" => "Close",
+        "sftp.disk_usage.reveal" => "Show in File List",
+        "sftp.log_viewer.path_placeholder" => "Log file path, press Enter to tail",
+        "sftp.log_viewer.filter_placeholder" => "Filter",
+        "sftp.log_viewer.keyword_placeholder" => "Add highlight keyword",
+        "sftp.log_viewer.common_paths" => "Common Logs",
+        "sftp.log_viewer.choose_path" => "Click to enter a path, or pick a common log",
+        "sftp.log_viewer.pause" => "Pause",
+        "sftp.log_viewer.resume" => "Resume",
+        "sftp.log_viewer.follow" => "Follow Output",
+        "sftp.log_viewer.clear" => "Clear",
+        "sftp.log_viewer.close" => "Close",
+        "sftp.log_viewer.waiting" => "Waiting for log output...",
+        "sftp.log_viewer.no_match" => "No matching lines",
+        "sftp.log_viewer.paused" => "Paused, {} lines pending",
+        "sftp.log_viewer.streaming" => "Tailing",
+        "sftp.log_viewer.stopped" => "Stopped",
+        "sftp.log_viewer.count" => "{} lines",
+        "sftp.hex.title" => "Hex Viewer",
+        "sftp.hex.go" => "Go",
+        "sftp.hex.offset_placeholder" => "Offset (e.g. 0x1F00)",
+        "sftp.hex.search_placeholder" => "Search",
+        "sftp.hex.mode_hex" => "Hex",
+        "sftp.hex.mode_text" => "Text",
+        "sftp.hex.find_next" => "Find Next",
+        "sftp.hex.searching" => "Searching...",
+        "sftp.hex.not_found" => "No match found",
+        "sftp.hex.invalid_offset" => "Invalid offset or beyond end of file",
+        "sftp.hex.invalid_pattern" => "Invalid search, hex must be whole bytes (e.g. 7F 45 4C 46)",
+        "sftp.hex.empty" => "File is empty",
+        "sftp.search.stop" => "Stop",
+        "sftp.search.in" => "Search in",
+        // SFTP Dual Pane
+        "sftp.pane.moved" => "Move completed, source removed",
+        "sftp.pane.move_incomplete" => "Some transfers did not finish, source kept",
+        // SFTP Directory Sync
+        "sftp.sync.title" => "Sync Directory",
+        "sftp.sync.local_dir" => "Local Directory",
+        "sftp.sync.remote_dir" => "Remote Directory",
+        "sftp.sync.local_placeholder" => "Choose a local directory",
+        "sftp.sync.remote_placeholder" => "Absolute path of the remote directory",
+        "sftp.sync.browse" => "Browse...",
+        "sftp.sync.mode" => "Mode",
+        "sftp.sync.mode_to_remote" => "Mirror to Remote",
+        "sftp.sync.mode_to_local" => "Mirror to Local",
+        "sftp.sync.mode_both" => "Both Ways",
+        "sftp.sync.mode_to_remote_hint" => "Local wins: upload changes, delete extra remote files",
+        "sftp.sync.mode_to_local_hint" => "Remote wins: download changes, delete extra local files",
+        "sftp.sync.mode_both_hint" => "Copy missing files both ways, newer version wins",
+        "sftp.sync.compare_by" => "Compare By",
+        "sftp.sync.compare_size_mtime" => "Size and modified time",
+        "sftp.sync.compare_checksum" => "Checksum (SHA-256)",
+        "sftp.sync.compare" => "Compare",
+        "sftp.sync.comparing" => "Comparing both sides...",
+        "sftp.sync.back" => "Back",
+        "sftp.sync.execute" => "Sync",
+        "sftp.sync.in_sync" => "Both directories are already in sync",
+        "sftp.sync.click_to_change" => "Click an action label to change the action for that file",
+        "sftp.sync.identical" => "Identical",
+        "sftp.sync.action_upload" => "Upload",
+        "sftp.sync.action_download" => "Download",
+        "sftp.sync.action_delete" => "Delete",
+        "sftp.sync.action_delete_local" => "Del Local",
+        "sftp.sync.action_delete_remote" => "Del Remote",
+        "sftp.sync.action_skip" => "Skip",
+        "sftp.sync.error_local" => "Local directory does not exist",
+        "sftp.sync.error_remote" => "Enter an absolute remote path",
+        "sftp.sync.done" => "Directory sync completed",
+        "sftp.sync.partial" => "Directory sync finished with some failures",
+        // SFTP Remote Copy
+        "sftp.remote_copy.title" => "Copy to Another Server",
+        "sftp.remote_copy.target_server" => "Target Server",
+        "sftp.remote_copy.target_dir" => "Target Directory",
+        "sftp.remote_copy.dir_placeholder" => "Directory on target server",
+        "sftp.remote_copy.copy" => "Copy",
+        "sftp.remote_copy.error_no_target" => "Select a target server",
+        "sftp.remote_copy.error_dir" => "Enter an absolute target path",
+        "sftp.remote_copy.no_targets" => "No other connected servers",
+        "sftp.remote_copy.done" => "Copy to server completed",
+        "sftp.remote_copy.failed" => "Copy to server failed",
+        // SFTP Transfer Conflict
+        "sftp.conflict.title" => "File Already Exists",
+        "sftp.conflict.local_file" => "Local file",
+        "sftp.conflict.remote_file" => "Remote file",
+        "sftp.conflict.apply_to_all" => "Do this for all conflicts",
+        "sftp.conflict.compress" => "Compress with gzip during transfer",
+        "sftp.conflict.extract" => "Extract after download",
+        "sftp.conflict.skip" => "Skip",
+        "sftp.conflict.rename" => "Rename",
+        "sftp.conflict.overwrite_if_newer" => "Overwrite If Newer",
+        "sftp.conflict.overwrite" => "Overwrite",
+        // SFTP Archive
+        "sftp.archive.compressing" => "Compressing",
+        "sftp.archive.extracting" => "Extracting",
+        "sftp.archive.compress_done" => "Compression completed",
+        "sftp.archive.compress_failed" => "Compression failed",
+        "sftp.archive.extract_done" => "Extraction completed",
+        "sftp.archive.extract_failed" => "Extraction failed",
         // Terminal Input
         "session.terminal.simulated" => "Simulated Terminal",
         "session.terminal.command_placeholder" => "Enter command...",
@@ -776,6 +2698,29 @@ fn en_us(key: &'static str) -> &'static str {
         "monitor.network_interface" => "Interface",
         "monitor.no_interfaces" => "No available network interfaces",
         "monitor.disk" => "Disk Status",
+        "monitor.gpu" => "GPU Status",
+        "monitor.gpu.no_processes" => "No compute processes",
+        "monitor.health" => "Hardware Health",
+        "monitor.health.temperatures" => "Temperatures",
+        "monitor.health.drives" => "Drive SMART",
+        "monitor.health.passed" => "PASSED",
+        "monitor.health.failed" => "FAILED {}",
+        "monitor.health.unknown" => "Unknown",
+        "monitor.logins" => "Logins",
+        "monitor.collector_error" => "Failed to collect monitor data",
+        "monitor.paused" => "Paused",
+        "monitor.logins.sessions" => "Current Sessions ({})",
+        "monitor.logins.recent" => "Recent Logins",
+        "monitor.logins.failed" => "Failed Logins",
+        "monitor.logins.last_login" => "Last login",
+        "monitor.logins.failed_count" => "{} recent failed logins",
+        "monitor.logins.failed_none" => "No failed logins",
+        "monitor.logins.failed_unavailable" => "Failed logins require root or passwordless sudo",
+        "monitor.logins.empty" => "No records",
+        "monitor.logins.user" => "User",
+        "monitor.logins.tty" => "TTY",
+        "monitor.logins.from" => "From",
+        "monitor.logins.time" => "Time",
         "monitor.no_data" => "No data",
 
         // Monitor Detail Dialog
@@ -807,11 +2752,199 @@ fn en_us(key: &'static str) -> &'static str {
         "monitor.detail.uptime_days" => "d",
         "monitor.detail.uptime_hours" => "h",
         "monitor.detail.uptime_minutes" => "m",
+        "monitor.history.title" => "History",
+        "monitor.export.tooltip" => "Export data in the selected range",
+        "monitor.export.title" => "Export Monitor Data",
+        "monitor.export.done" => "Exported to {}",
+        "monitor.export.failed" => "Export failed: {}",
+        "monitor.history.collecting" => "Collecting history data, please wait…",
+        "monitor.history.cpu" => "CPU Usage",
+        "monitor.history.memory" => "Memory Usage",
+        "monitor.history.network" => "Network Speed",
+        "monitor.history.disk_io" => "Disk IO",
+        "monitor.history.read" => "Read",
+        "monitor.history.write" => "Write",
+        "monitor.tab.monitor" => "Monitor",
+        "monitor.tab.processes" => "Processes",
+        "monitor.tab.docker" => "Docker",
+        "monitor.tab.services" => "Services",
+        "monitor.tab.network" => "Network",
+        "monitor.tab.cron" => "Cron",
+        "monitor.tab.packages" => "Packages",
+        "monitor.tab.firewall" => "Firewall",
+
+        // Processes
+        "processes.filter_placeholder" => "Filter by PID / user / command",
+        "processes.count" => "{} processes",
+        "processes.column.pid" => "PID",
+        "processes.column.user" => "User",
+        "processes.column.cpu" => "CPU%",
+        "processes.column.mem" => "MEM%",
+        "processes.column.command" => "Command",
+        "processes.load_failed" => "Failed to list processes",
+        "processes.kill" => "End Process",
+        "processes.force_kill" => "Force Kill (SIGKILL)",
+        "processes.renice" => "Change Priority",
+        "processes.confirm_kill" => "End process {pid} ({name})?",
+        "processes.confirm_force_kill" => "Force kill process {pid} ({name})? Unsaved data will be lost",
+        "processes.confirm_renice" => "Set the nice value of process {pid} ({name}) to {nice}?",
+        "processes.action_done" => "Done",
+        "processes.action_failed" => "Action failed",
+
+        // Docker
+        "docker.detecting" => "Detecting Docker...",
+        "docker.unavailable" => "Docker is not available",
+        "docker.load_failed" => "Failed to list containers",
+        "docker.containers" => "Containers ({})",
+        "docker.images" => "Images ({})",
+        "docker.running_count" => "{} running",
+        "docker.empty" => "Nothing here",
+        "docker.start" => "Start",
+        "docker.stop" => "Stop",
+        "docker.restart" => "Restart",
+        "docker.logs" => "View Logs",
+        "docker.exec" => "Open Shell",
+        "docker.remove_container" => "Remove Container",
+        "docker.remove_image" => "Remove Image",
+        "docker.confirm_remove_container" => "Remove container {}?",
+        "docker.confirm_remove_image" => "Remove image {}?",
+        "docker.action_done" => "Done",
+        "docker.action_failed" => "Action failed",
+
+        // systemd services
+        "services.filter_placeholder" => "Filter by name / description",
+        "services.count" => "{} services",
+        "services.load_failed" => "Failed to list services",
+        "services.start" => "Start",
+        "services.stop" => "Stop",
+        "services.restart" => "Restart",
+        "services.enable" => "Enable at Boot",
+        "services.disable" => "Disable at Boot",
+        "services.journal" => "View Logs",
+        "services.confirm_stop" => "Stop service {}?",
+        "services.confirm_restart" => "Restart service {}?",
+        "services.confirm_disable" => "Disable service {} at boot?",
+        "services.action_done" => "Done",
+        "services.action_failed" => "Action failed",
+        "services.sudo.title" => "Administrator Required",
+        "services.sudo.prompt" => "Managing this service requires sudo. Enter your password:",
+        "cron.count" => "{} cron jobs",
+        "cron.unsaved" => " (unsaved)",
+        "cron.new" => "New Job",
+        "cron.edit" => "Edit Job",
+        "cron.reload" => "Reload",
+        "cron.save" => "Save to Server",
+        "cron.apply" => "Apply",
+        "cron.delete" => "Delete",
+        "cron.enable" => "Enable",
+        "cron.disable" => "Disable",
+        "cron.empty" => "No cron jobs",
+        "cron.load_failed" => "Failed to read cron jobs",
+        "cron.save_failed" => "Save failed",
+        "cron.saved" => "Crontab saved",
+        "cron.saved_with_backup" => "Crontab saved, previous content backed up to {}",
+        "cron.schedule_placeholder" => "min hour day month weekday, e.g. 0 3 * * *",
+        "cron.command_placeholder" => "Command to run",
+        "cron.command_required" => "Command must be a single non-empty line",
+        "cron.next_runs" => "Next runs",
+        "cron.confirm_save" => "Write back the crontab? The current content is backed up to ~/.crontab.bak.<time> first",
+        "cron.confirm_discard" => "Discard unsaved changes and reload?",
+        "cron.system_title" => "/etc/cron.d (read-only)",
+        "cron.system_load" => "Load (sudo)",
+        "cron.system_reload" => "Refresh",
+        "cron.sudo.title" => "Administrator Required",
+        "cron.error.modified" => "The crontab was modified on the server. Reload it before saving.",
+        "cron.sudo.prompt" => "Reading /etc/cron.d requires sudo. Enter your password:",
+        "cron.weekdays" => "Sun,Mon,Tue,Wed,Thu,Fri,Sat",
+        "cron.hint.invalid" => "Invalid schedule",
+        "cron.hint.reboot" => "At boot",
+        "cron.hint.every_minute" => "Every minute",
+        "cron.hint.every_n_minutes" => "Every {} minutes",
+        "cron.hint.hourly" => "Hourly at minute {}",
+        "cron.hint.every_n_hours" => "Every {n} hours at minute {m}",
+        "cron.hint.daily" => "Daily at {}",
+        "cron.hint.weekly" => "Every {days} at {time}",
+        "cron.hint.monthly" => "Monthly on day {day} at {time}",
+        "cron.hint.custom" => "Custom schedule",
+        "packages.detecting" => "Detecting package manager...",
+        "packages.not_found" => "No supported package manager found (apt / dnf / yum / brew)",
+        "packages.reload" => "Detect Again",
+        "packages.search_placeholder" => "Search packages, press Enter",
+        "packages.clear_search" => "Clear Search",
+        "packages.searching" => "Searching...",
+        "packages.search_failed" => "Search failed",
+        "packages.search_results" => "Search Results ({})",
+        "packages.no_results" => "No matching packages",
+        "packages.upgradable" => "Upgradable ({})",
+        "packages.up_to_date" => "All packages are up to date (based on the local index; refresh it first)",
+        "packages.install" => "Install",
+        "packages.upgrade" => "Upgrade",
+        "packages.action.refresh" => "Refresh Package Index",
+        "packages.action.upgrade_all" => "Upgrade All Packages",
+        "packages.action.upgrade" => "Upgrade {}",
+        "packages.action.install" => "Install {}",
+        "packages.confirm" => "{}?",
+        "packages.sudo_hint" => "Will run with sudo",
+        "packages.running" => "Running...",
+        "packages.done" => "Done",
+        "packages.failed" => "Failed",
+        "packages.close_output" => "Close Output",
+        "packages.action_done" => "Package operation completed",
+        "packages.sudo.title" => "Administrator Privileges Required",
+        "packages.sudo.prompt" => "Changing packages requires sudo. Enter the password of the current user:",
+        "firewall.reload" => "Reload rules",
+        "firewall.search_placeholder" => "Search port, source or action",
+        "firewall.not_found" => "No ufw / firewalld / iptables found",
+        "firewall.needs_sudo" => "Reading firewall rules requires sudo",
+        "firewall.load_failed" => "Failed to read firewall rules",
+        "firewall.empty" => "No rules",
+        "firewall.no_match" => "No matching rules",
+        "firewall.anywhere" => "Anywhere",
+        "firewall.port_placeholder" => "Port",
+        "firewall.allow" => "Allow",
+        "firewall.deny" => "Deny",
+        "firewall.invalid_port" => "Enter a port between 1 and 65535",
+        "firewall.confirm_allow" => "Allow {}?",
+        "firewall.confirm_deny" => "Deny {}?",
+        "firewall.allowed" => "Allowed {}",
+        "firewall.denied" => "Denied {}",
+        "firewall.apply_failed" => "Failed to change firewall rules",
+        "firewall.applied" => "Firewall rules updated",
+        "firewall.sudo.title" => "Administrator Privileges Required",
+        "firewall.sudo.prompt" => "Reading and changing firewall rules requires sudo. Enter the password of the current user:",
+        "connections.filter_placeholder" => "Search process / PID / address / port",
+        "connections.count" => "{} sockets",
+        "connections.load_failed" => "Failed to list connections",
+        "connections.scope_all" => "All",
+        "connections.listening" => "Listening",
+        "connections.established" => "Established",
+        "connections.group_summary" => "{listening} listening · {established} connected",
+        "connections.unknown_process" => "Unknown process (root required)",
+        "connections.open_in_terminal" => "View in Terminal",
+        "alerts.metric.cpu" => "CPU",
+        "alerts.metric.memory" => "Memory",
+        "alerts.metric.disk" => "Disk {}",
+        "alerts.message_temperature" => "{sensor} temperature {value} exceeds threshold {threshold}",
+        "alerts.message_drive" => "Drive {} failed SMART self-assessment",
+        "alerts.message" => "{metric} usage {value}% exceeded the {threshold}% threshold",
+        "alerts.empty" => "No alerts",
+        "alerts.mute_all" => "Mute All",
+        "alerts.unmute_all" => "Unmute All",
+        "alerts.mute_server" => "Mute {}",
+        "alerts.unmute_server" => "Unmute {}",
+        "alerts.clear" => "Clear",
+        "alerts.muted" => "Muted",
 
         // Snippets
         "snippets.add_group" => "New Group",
         "snippets.add_command" => "New Command",
+        "snippets.library.import" => "Import",
+        "snippets.library.export" => "Export",
+        "snippets.library.imported" => "Imported {} commands",
+        "snippets.library.duplicates" => "skipped {} duplicates",
+        "snippets.library.exported" => "Exported {} commands to",
         "snippets.breadcrumb.all" => "All",
+        "snippets.group.items" => "{} items",
         "snippets.empty.title" => "No Snippets",
         "snippets.empty.description" => "Create a group or command to get started",
         "snippets.dialog.group_name" => "Group Name",
@@ -823,6 +2956,21 @@ fn en_us(key: &'static str) -> &'static str {
         "snippets.dialog.edit_command" => "Edit Command",
         "snippets.dialog.enter_name" => "Enter name...",
         "snippets.dialog.enter_command" => "Enter command...",
+        "snippets.dialog.enter_description" => "Optional, what this is for...",
+        "snippets.dialog.tags" => "Tags",
+        "snippets.dialog.enter_tags" => "Separate tags with commas...",
+        "snippets.dialog.color" => "Color",
+        "snippets.dialog.variables_hint" => "Use {{name}} or {{name:default}} for variables filled in when the snippet runs",
+        "snippets.dialog.scope" => "Visibility",
+        "snippets.dialog.scope_hint" => "Only shown in sessions matching the selected groups, tags or servers; visible on all servers when nothing is selected",
+        "snippets.dialog.scope_groups" => "Server groups",
+        "snippets.dialog.scope_tags" => "Server tags",
+        "snippets.dialog.scope_servers" => "Servers",
+        "snippets.dialog.auto_run" => "Run automatically after connecting to matching servers",
+        "snippets.variables.title" => "Run Snippet",
+        "snippets.variables.hint" => "Fill in the variables; empty fields use their default value",
+        "snippets.variables.preview" => "Command to run",
+        "snippets.variables.run" => "Run",
         // Snippet Context Menu
         "snippets.context_menu.execute" => "Execute in Terminal",
         "snippets.context_menu.edit_in_box" => "Edit in Command Box",
@@ -830,14 +2978,222 @@ fn en_us(key: &'static str) -> &'static str {
         // Mini Sidebar
         "mini_sidebar.snippets" => "Snippets",
         "mini_sidebar.transfer" => "Transfer",
+        "mini_sidebar.notifications" => "Notifications",
+        "mini_sidebar.forwards" => "Port Forwarding",
+        "mini_sidebar.runbooks" => "Runbooks",
+        "mini_sidebar.macros" => "Macros",
+        "forward.kind.local" => "Local: local port → remote target",
+        "forward.kind.remote" => "Remote: server port → local target",
+        "forward.kind.dynamic" => "Dynamic: local SOCKS5 proxy",
+        "forward.bind_placeholder" => "Listen [address:]port",
+        "forward.target_placeholder" => "Target host:port",
+        "forward.invalid_bind" => "Invalid listen port",
+        "forward.invalid_target" => "Invalid target, use host:port",
+        "forward.add" => "Add Forward",
+        "forward.empty" => "No port forwards",
+        "forward.connections" => "{active} active / {total} total",
+        "forward.start" => "Start",
+        "forward.stop" => "Stop",
+        "forward.remove" => "Remove",
+        "forward.db_tunnel" => "DB Tunnel",
+        "forward.db_tunnel_tooltip" => "Forward to this database on the server (default port when target is empty)",
+        "forward.copy_url" => "Copy Connection String",
+        "forward.launch_client" => "Launch Client",
+        "forward.launch_failed" => "Failed to launch client: {}",
 
         // Transfer Panel
         "transfer.empty" => "No active transfers",
+        "transfer.resumed_from" => "Resumed at",
+        "transfer.verified" => "Verified",
+        "transfer.pause_all" => "Pause All",
+        "transfer.resume_all" => "Resume All",
+        "transfer.drag_hint" => "Drag waiting tasks to reorder",
+        "transfer.limit.title" => "Speed limit",
+        "transfer.limit.unlimited" => "Unlimited",
+        "transfer.folder.files" => "files",
+        "transfer.folder.failed_files" => "{} file(s) failed to transfer",
+        "transfer.cancelled_by_user" => "Cancelled by user",
+        "transfer.download_cancelled" => "Download cancelled",
+        "transfer.upload_cancelled" => "Upload cancelled",
+        "transfer.status.pending" => "Pending",
+        "transfer.status.downloading" => "Downloading",
+        "transfer.status.uploading" => "Uploading",
+        "transfer.status.paused" => "Paused",
+        "transfer.status.completed" => "Completed",
+        "transfer.status.failed" => "Failed",
+        "transfer.status.cancelled" => "Cancelled",
+        "transfer.tab.active" => "Active",
+        "transfer.tab.history" => "History",
+        "transfer.history.empty" => "No transfer history",
+        "transfer.history.clear" => "Clear History",
+        "transfer.history.retry" => "Transfer Again",
+        "transfer.history.open_folder" => "Open Containing Folder",
+        "transfer.history.filter.all" => "All",
+        "transfer.history.filter.uploads" => "Uploads",
+        "transfer.history.filter.downloads" => "Downloads",
+        "transfer.history.filter.failed" => "Failed",
 
         // Known Hosts
         "known_hosts.empty.title" => "No Known Hosts",
         "known_hosts.empty.description" => "Connect to a server and trust its key to see it here",
         "known_hosts.items" => "hosts",
+        "known_hosts.first_seen" => "First seen: {}",
+        "known_hosts.last_used" => "Last used: {}",
+
+        // Runbooks
+        "runbook.add" => "New Runbook",
+        "runbook.empty" => "No runbooks yet. Create one to run deploy and maintenance procedures step by step",
+        "runbook.step_count" => "{} steps",
+        "runbook.no_terminal" => "No terminal is available in this tab",
+        "runbook.pause" => "Pause",
+        "runbook.resume" => "Resume",
+        "runbook.abort" => "Abort",
+        "runbook.run_again" => "Run Again",
+        "runbook.continue" => "Confirm and Continue",
+        "runbook.log" => "Run Log",
+        "runbook.status.running" => "Running",
+        "runbook.status.awaiting_confirm" => "Awaiting confirmation",
+        "runbook.status.paused" => "Paused",
+        "runbook.status.finished" => "Finished",
+        "runbook.status.failed" => "Failed",
+        "runbook.status.aborted" => "Aborted",
+        "runbook.step.send" => "Send",
+        "runbook.step.wait_for" => "Wait for",
+        "runbook.step.confirm" => "Confirm",
+        "runbook.step.sleep" => "Sleep",
+        "runbook.log.started" => "Started {}",
+        "runbook.log.sent" => "Sent: {}",
+        "runbook.log.waiting" => "Waiting for output matching: {}",
+        "runbook.log.matched" => "Output matched",
+        "runbook.log.timeout" => "Timed out waiting for output ({}s)",
+        "runbook.log.invalid_pattern" => "Invalid regular expression: {}",
+        "runbook.log.confirm" => "Waiting for confirmation: {}",
+        "runbook.log.confirmed" => "Confirmed",
+        "runbook.log.sleep" => "Sleeping {}s",
+        "runbook.log.paused" => "Paused",
+        "runbook.log.resumed" => "Resumed",
+        "runbook.log.aborted" => "Aborted",
+        "runbook.log.finished" => "All steps completed",
+        "runbook.log.no_terminal" => "Terminal unavailable, could not send the command",
+        "runbook.notify.finished" => "Runbook \"{}\" finished",
+        "runbook.notify.failed" => "Runbook \"{}\" failed",
+        "runbook.dialog.add_title" => "New Runbook",
+        "runbook.dialog.edit_title" => "Edit Runbook",
+        "runbook.dialog.name" => "Name",
+        "runbook.dialog.description" => "Description",
+        "runbook.dialog.steps" => "Steps",
+        "runbook.dialog.add_step" => "Add Step",
+        "runbook.dialog.timeout" => "Timeout",
+        "runbook.dialog.enter_name" => "Enter name...",
+        "runbook.dialog.enter_description" => "Optional",
+        "runbook.dialog.enter_command" => "Command to send",
+        "runbook.dialog.enter_pattern" => "Regular expression, e.g. \\$ $",
+        "runbook.dialog.enter_message" => "Confirmation prompt",
+        "runbook.dialog.enter_secs" => "Seconds",
+        "runbook.dialog.name_required" => "Please enter a name",
+        "runbook.dialog.steps_required" => "At least one step is required",
+        "runbook.dialog.step_empty" => "Step {} cannot be empty",
+        "runbook.dialog.invalid_pattern" => "Step {} has an invalid regular expression",
+        "runbook.dialog.invalid_secs" => "Step {} must be a positive number of seconds",
+        "runbook.dialog.save_failed" => "Failed to save",
+
+        // Macros
+        "macro.record" => "Record",
+        "macro.stop" => "Stop",
+        "macro.recording" => "Recording… ({} chars)",
+        "macro.empty" => "No macros yet. Click Record and type in the terminal",
+        "macro.play_to" => "Play in another session",
+        "macro.no_terminal" => "No terminal is available in the target session",
+        "macro.notify.empty" => "No keys were recorded",
+        "macro.dialog.add_title" => "Save Macro",
+        "macro.dialog.edit_title" => "Edit Macro",
+        "macro.dialog.name" => "Name",
+        "macro.dialog.enter_name" => "Enter macro name",
+        "macro.dialog.keys" => "Key Sequence",
+        "macro.dialog.keys_hint" => "Control characters are escaped: \\r Enter, \\t Tab, \\e Esc, \\xNN other control characters, \\\\ backslash",
+        "macro.dialog.keybinding" => "Shortcut",
+        "macro.dialog.enter_keybinding" => "e.g. ctrl-alt-m (leave empty for none)",
+        "macro.dialog.keybinding_hint" => "Press the shortcut in the terminal to play this macro",
+        "macro.dialog.name_required" => "Please enter a name",
+        "macro.dialog.keys_required" => "The key sequence cannot be empty",
+        "macro.dialog.invalid_keys" => "Invalid escape at character {} of the key sequence",
+        "macro.dialog.invalid_keybinding" => "Invalid shortcut",
+        "macro.dialog.keybinding_modifier" => "The shortcut must include Ctrl, Alt or Cmd",
+        "macro.dialog.keybinding_taken" => "Shortcut {} is already bound to another macro",
+        "macro.dialog.save_failed" => "Failed to save",
+
+        // Plugins
+        "plugins.hint" => "Put Rhai scripts (.rhai) in the plugins folder; enabled plugins run in the background. Define run() to run a plugin manually and on_connect(tab_id, server) to act on new sessions",
+        "plugins.open_dir" => "Open Plugins Folder",
+        "plugins.reload" => "Reload",
+        "plugins.empty" => "No scripts in the plugins folder yet",
+        "plugins.run" => "Run",
+        "plugins.log" => "Log",
+        "plugins.clear_log" => "Clear",
+        "plugins.log_empty" => "No log entries",
+        "plugins.status.stopped" => "Disabled",
+        "plugins.status.running" => "Running",
+        "plugins.status.idle" => "Loaded",
+        "plugins.status.failed" => "Failed to load",
+        "plugins.log.started" => "Plugin started",
+        "plugins.log.stopped" => "Plugin stopped",
+        "plugins.error.server_not_found" => "Server not found: {}",
+        "plugins.error.no_terminal" => "Session {} has no available terminal",
+        "plugins.error.no_sftp" => "SFTP is not ready for session {}",
+        "plugins.dialog.source" => "From plugin \"{}\"",
+
+        // Scheduled jobs
+        "schedules.hint" => "Run snippets or runbooks on servers on a schedule (connecting if needed). Jobs only run while the app is open",
+        "schedules.add" => "New Job",
+        "schedules.empty" => "No scheduled jobs yet",
+        "schedules.missing" => "(deleted)",
+        "schedules.next_run" => "next",
+        "schedules.running" => "Running…",
+        "schedules.run_now" => "Run Now",
+        "schedules.edit" => "Edit",
+        "schedules.delete" => "Delete",
+        "schedules.runbook" => "Runbook",
+        "schedules.history" => "Run History",
+        "schedules.clear_history" => "Clear",
+        "schedules.history_empty" => "No runs yet",
+        "schedules.status.success" => "Success",
+        "schedules.status.exit" => "Exit",
+        "schedules.status.error" => "Failed",
+        "schedules.manual" => "manual",
+        "schedules.no_output" => "(no output)",
+        "schedules.notify.failed" => "Scheduled job \"{}\" failed on {}",
+        "schedules.error.server_missing" => "The server no longer exists",
+        "schedules.error.snippet_missing" => "The snippet no longer exists",
+        "schedules.error.runbook_missing" => "The runbook no longer exists",
+        "schedules.error.runbook_confirm" => "Runbooks with manual confirmation steps cannot be scheduled",
+        "schedules.error.reboot" => "@reboot is not supported for scheduled jobs",
+        "schedules.dialog.add_title" => "New Scheduled Job",
+        "schedules.dialog.edit_title" => "Edit Scheduled Job",
+        "schedules.dialog.name" => "Name",
+        "schedules.dialog.enter_name" => "Enter a job name",
+        "schedules.dialog.server" => "Server",
+        "schedules.dialog.select_server" => "Select a server",
+        "schedules.dialog.no_servers" => "No saved servers",
+        "schedules.dialog.task" => "Run",
+        "schedules.dialog.select_task" => "Select a snippet or runbook",
+        "schedules.dialog.no_tasks" => "No snippets or runbooks",
+        "schedules.dialog.schedule" => "Schedule (cron expression)",
+        "schedules.dialog.name_required" => "Please enter a job name",
+        "schedules.dialog.server_required" => "Please select a server",
+        "schedules.dialog.task_required" => "Please select a snippet or runbook to run",
+        "schedules.dialog.invalid_schedule" => "Invalid schedule",
+        "schedules.dialog.save_failed" => "Failed to save",
+
+        // SSH config snippet
+        "ssh_snippet.title" => "SSH Config for {}",
+        "ssh_snippet.config" => "OpenSSH config (~/.ssh/config)",
+        "ssh_snippet.command" => "ssh command",
+        "ssh_snippet.copy_config" => "Copy Config",
+        "ssh_snippet.copy_command" => "Copy Command",
+        "ssh_snippet.copied" => "Copied",
+        "ssh_snippet.hint" => "Passwords are not exported. Jump hosts' own keys and proxies are only kept in the config",
+        "ssh_snippet.forwards" => "Includes {} port forward(s) from the open session",
+        "ssh_snippet.failed" => "Failed to generate the SSH config",
 
         _ => key,
     }
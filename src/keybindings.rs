@@ -1,10 +1,77 @@
 // 全局快捷键管理
 // 集中管理应用级别的快捷键绑定
 
-use gpui::{actions, App, KeyBinding};
+use gpui::{actions, Action, App, KeyBinding};
 
 // 定义全局 actions
-actions!(app, [Quit]);
+actions!(
+    app,
+    [
+        Quit,
+        ToggleTabOverview,
+        QuickConnect,
+        ToggleCommandPalette,
+        ToggleNotificationCenter
+    ]
+);
+
+// 标签、终端实例、会话面板之间的键盘导航与视图的后退/前进
+actions!(
+    navigation,
+    [
+        NextTab,
+        PrevTab,
+        ActivateTab1,
+        ActivateTab2,
+        ActivateTab3,
+        ActivateTab4,
+        ActivateTab5,
+        ActivateTab6,
+        ActivateTab7,
+        ActivateTab8,
+        ActivateLastTab,
+        NextTerminal,
+        PrevTerminal,
+        ToggleSidebar,
+        FocusTerminal,
+        FocusSftp,
+        FocusMonitor,
+        NavigateBack,
+        NavigateForward
+    ]
+);
+
+// 命令面板内的键盘操作
+actions!(
+    command_palette,
+    [SelectPrevCommand, SelectNextCommand, DismissCommandPalette]
+);
+
+/// 命令面板上下文名称
+pub const COMMAND_PALETTE_CONTEXT: &str = "CommandPalette";
+
+/// 可在命令面板中搜索执行的全局 action（i18n 键, action）
+pub fn palette_actions() -> Vec<(&'static str, Box<dyn Action>)> {
+    vec![
+        ("palette.action.tab_overview", Box::new(ToggleTabOverview)),
+        ("palette.action.quick_connect", Box::new(QuickConnect)),
+        (
+            "palette.action.notifications",
+            Box::new(ToggleNotificationCenter),
+        ),
+        ("palette.action.next_tab", Box::new(NextTab)),
+        ("palette.action.prev_tab", Box::new(PrevTab)),
+        ("palette.action.next_terminal", Box::new(NextTerminal)),
+        ("palette.action.prev_terminal", Box::new(PrevTerminal)),
+        ("palette.action.toggle_sidebar", Box::new(ToggleSidebar)),
+        ("palette.action.focus_terminal", Box::new(FocusTerminal)),
+        ("palette.action.focus_sftp", Box::new(FocusSftp)),
+        ("palette.action.focus_monitor", Box::new(FocusMonitor)),
+        ("palette.action.navigate_back", Box::new(NavigateBack)),
+        ("palette.action.navigate_forward", Box::new(NavigateForward)),
+        ("palette.action.quit", Box::new(Quit)),
+    ]
+}
 
 /// 初始化全局快捷键
 /// 注册应用级别的快捷键绑定
@@ -14,12 +81,89 @@ pub fn init(cx: &mut App) {
     cx.bind_keys([
         // Cmd+Q 退出应用
         KeyBinding::new("cmd-q", Quit, None),
+        // Cmd+Shift+A 标签页总览
+        KeyBinding::new("cmd-shift-a", ToggleTabOverview, None),
+        // Cmd+Shift+O 快速连接（聚焦主页搜索框）
+        KeyBinding::new("cmd-shift-o", QuickConnect, None),
+        // Cmd+K 命令面板
+        KeyBinding::new("cmd-k", ToggleCommandPalette, None),
+        // Ctrl+Tab / Ctrl+Shift+Tab 下一个/上一个标签
+        KeyBinding::new("ctrl-tab", NextTab, None),
+        KeyBinding::new("ctrl-shift-tab", PrevTab, None),
+        KeyBinding::new("cmd-shift-]", NextTab, None),
+        KeyBinding::new("cmd-shift-[", PrevTab, None),
+        // Cmd+1..8 切换到第 N 个标签，Cmd+9 切换到最后一个标签
+        KeyBinding::new("cmd-1", ActivateTab1, None),
+        KeyBinding::new("cmd-2", ActivateTab2, None),
+        KeyBinding::new("cmd-3", ActivateTab3, None),
+        KeyBinding::new("cmd-4", ActivateTab4, None),
+        KeyBinding::new("cmd-5", ActivateTab5, None),
+        KeyBinding::new("cmd-6", ActivateTab6, None),
+        KeyBinding::new("cmd-7", ActivateTab7, None),
+        KeyBinding::new("cmd-8", ActivateTab8, None),
+        KeyBinding::new("cmd-9", ActivateLastTab, None),
+        // Cmd+] / Cmd+[ 下一个/上一个终端实例
+        KeyBinding::new("cmd-]", NextTerminal, None),
+        KeyBinding::new("cmd-[", PrevTerminal, None),
+        // Cmd+B 折叠/展开会话侧边栏
+        KeyBinding::new("cmd-b", ToggleSidebar, None),
+        // Cmd+Alt+1/2/3 聚焦终端 / SFTP / Monitor 面板
+        KeyBinding::new("cmd-alt-1", FocusTerminal, None),
+        KeyBinding::new("cmd-alt-2", FocusSftp, None),
+        KeyBinding::new("cmd-alt-3", FocusMonitor, None),
+        // Cmd+Alt+← / Cmd+Alt+→ 后退/前进到上一个/下一个视图（Ctrl+- 留给终端）
+        KeyBinding::new("cmd-alt-left", NavigateBack, None),
+        KeyBinding::new("cmd-alt-right", NavigateForward, None),
     ]);
 
     #[cfg(not(target_os = "macos"))]
     cx.bind_keys([
         // Ctrl+Q 退出应用 (Windows/Linux)
         KeyBinding::new("ctrl-q", Quit, None),
+        // Ctrl+Shift+A 标签页总览
+        KeyBinding::new("ctrl-shift-a", ToggleTabOverview, None),
+        // Ctrl+Shift+O 快速连接（聚焦主页搜索框）
+        KeyBinding::new("ctrl-shift-o", QuickConnect, None),
+        // Ctrl+Shift+K 命令面板（Ctrl+K 留给终端）
+        KeyBinding::new("ctrl-shift-k", ToggleCommandPalette, None),
+        // Ctrl+Tab / Ctrl+Shift+Tab 下一个/上一个标签
+        KeyBinding::new("ctrl-tab", NextTab, None),
+        KeyBinding::new("ctrl-shift-tab", PrevTab, None),
+        KeyBinding::new("ctrl-pagedown", NextTab, None),
+        KeyBinding::new("ctrl-pageup", PrevTab, None),
+        // Ctrl+1..8 切换到第 N 个标签，Ctrl+9 切换到最后一个标签（Alt+数字留给终端的 Meta 键）
+        KeyBinding::new("ctrl-1", ActivateTab1, None),
+        KeyBinding::new("ctrl-2", ActivateTab2, None),
+        KeyBinding::new("ctrl-3", ActivateTab3, None),
+        KeyBinding::new("ctrl-4", ActivateTab4, None),
+        KeyBinding::new("ctrl-5", ActivateTab5, None),
+        KeyBinding::new("ctrl-6", ActivateTab6, None),
+        KeyBinding::new("ctrl-7", ActivateTab7, None),
+        KeyBinding::new("ctrl-8", ActivateTab8, None),
+        KeyBinding::new("ctrl-9", ActivateLastTab, None),
+        // Ctrl+Shift+] / Ctrl+Shift+[ 下一个/上一个终端实例
+        KeyBinding::new("ctrl-shift-]", NextTerminal, None),
+        KeyBinding::new("ctrl-shift-[", PrevTerminal, None),
+        // Ctrl+Shift+B 折叠/展开会话侧边栏
+        KeyBinding::new("ctrl-shift-b", ToggleSidebar, None),
+        // Ctrl+Alt+1/2/3 聚焦终端 / SFTP / Monitor 面板
+        KeyBinding::new("ctrl-alt-1", FocusTerminal, None),
+        KeyBinding::new("ctrl-alt-2", FocusSftp, None),
+        KeyBinding::new("ctrl-alt-3", FocusMonitor, None),
+        // Ctrl+Alt+← / Ctrl+Alt+→ 后退/前进到上一个/下一个视图（Ctrl+_ 等留给终端）
+        KeyBinding::new("ctrl-alt-left", NavigateBack, None),
+        KeyBinding::new("ctrl-alt-right", NavigateForward, None),
+    ]);
+
+    // 命令面板：上下键选择，Esc 关闭
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrevCommand, Some(COMMAND_PALETTE_CONTEXT)),
+        KeyBinding::new("down", SelectNextCommand, Some(COMMAND_PALETTE_CONTEXT)),
+        KeyBinding::new(
+            "escape",
+            DismissCommandPalette,
+            Some(COMMAND_PALETTE_CONTEXT),
+        ),
     ]);
 
     // 注册 action 处理器
@@ -15,7 +15,6 @@ mod keybindings;
 mod models;
 mod pages;
 
-// 暂未使用，保留结构
 mod i18n;
 mod router;
 mod services;
@@ -28,8 +27,118 @@ use assets::Assets;
 use gpui_component::theme::{Theme, ThemeMode as GpuiThemeMode};
 use models::settings::ThemeMode;
 use pages::HomePage;
+use services::server_search::{self, AdHocTarget};
 use services::storage;
 
+/// 命令行用法
+const USAGE: &str = "\
+Usage: shellmaster [user@]host[:port] [-p port] [-l user] [--key identity_file]
+       shellmaster ssh://[user@]host[:port]
+       shellmaster --register-ssh-handler
+
+Options:
+  -p, --port <port>         SSH port (default 22)
+  -l, --user <user>         Login user (default: the local user name)
+  -i, --key <file>          Private key file
+      --register-ssh-handler
+                            Make ShellMaster the default handler for ssh:// links
+  -h, --help                Print this help";
+
+/// 命令行启动参数
+#[derive(Default)]
+struct LaunchArgs {
+    /// 启动后直接连接的目标与私钥（不保存为服务器）
+    target: Option<(AdHocTarget, Option<PathBuf>)>,
+    /// shellmaster:// 深链接与 ssh:// 链接
+    links: Vec<String>,
+    /// 注册为 ssh:// 链接的默认处理程序后退出
+    register_ssh_handler: bool,
+    help: bool,
+}
+
+/// 解析命令行参数：`[user@]host[:port] -p 2222 --key ~/.ssh/id`，或 shellmaster:// 与 ssh:// 链接
+fn parse_launch_args(args: &[String]) -> Result<LaunchArgs, String> {
+    let mut launch = LaunchArgs::default();
+    let mut destination = None;
+    let mut port = None;
+    let mut user = None;
+    let mut key = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        // 支持 `--port=2222` 与 `-p2222` 写法
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ if matches!(arg.get(..2), Some("-p" | "-l" | "-i"))
+                && arg.len() > 2
+                && !arg.starts_with("-psn_") =>
+            {
+                (&arg[..2], Some(arg[2..].to_string()))
+            }
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| {
+            inline
+                .clone()
+                .or_else(|| iter.next().cloned())
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+        match flag {
+            "-h" | "--help" => launch.help = true,
+            "--register-ssh-handler" => launch.register_ssh_handler = true,
+            "-p" | "--port" => {
+                let raw = value(flag)?;
+                port = Some(
+                    raw.parse::<u16>()
+                        .ok()
+                        .filter(|p| *p > 0)
+                        .ok_or_else(|| format!("Invalid port: {}", raw))?,
+                );
+            }
+            "-l" | "--user" => user = Some(value(flag)?),
+            "-i" | "--key" | "--identity" => key = Some(value(flag)?),
+            // macOS 从 Finder 启动旧版本系统时附带的进程序列号
+            _ if flag.starts_with("-psn_") => {}
+            _ if flag.contains("://") => launch.links.push(arg.clone()),
+            _ if flag.starts_with('-') => return Err(format!("Unknown option: {}", arg)),
+            _ if destination.is_none() => destination = Some(arg.clone()),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    if let Some(destination) = destination {
+        let destination = if destination.contains('@') {
+            destination
+        } else {
+            let user = user
+                .clone()
+                .or_else(server_search::local_username)
+                .ok_or("Cannot determine the login user, use user@host or --user")?;
+            format!("{}@{}", user, destination)
+        };
+        let mut target = AdHocTarget::parse(&destination)
+            .ok_or_else(|| format!("Invalid destination: {}", destination))?;
+        if let Some(user) = user {
+            target.username = user;
+        }
+        if let Some(port) = port {
+            target.port = port;
+        }
+        launch.target = Some((target, key.map(|k| expand_home(&k))));
+    } else if port.is_some() || user.is_some() || key.is_some() {
+        return Err("Missing destination host".to_string());
+    }
+    Ok(launch)
+}
+
+/// 展开路径开头的 `~`（`--key=~/.ssh/id` 不会被 shell 展开）
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
 /// 获取资源目录路径
 /// 在开发环境中使用项目的 assets 目录，在 .app 包中使用 Resources 目录
 fn get_assets_path() -> PathBuf {
@@ -54,6 +163,35 @@ fn get_assets_path() -> PathBuf {
 }
 
 fn main() {
+    // 被 git 作为 GIT_SSH_COMMAND 调用时只做 SSH 转发，不启动界面
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some(services::sync::GIT_SSH_ARG) {
+        std::process::exit(services::sync::run_git_ssh(&args[1..]));
+    }
+
+    // 命令行参数：连接目标、链接与一次性操作
+    let launch = match parse_launch_args(&args) {
+        Ok(launch) => launch,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+    if launch.help {
+        println!("{}", USAGE);
+        return;
+    }
+    if launch.register_ssh_handler {
+        match services::url_handler::register_ssh_handler() {
+            Ok(()) => println!("ShellMaster is now the default handler for ssh:// links"),
+            Err(e) => {
+                eprintln!("Failed to register the ssh:// handler: {:#}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     // 初始化日志系统
     // 可以通过 RUST_LOG 环境变量控制日志级别，例如：RUST_LOG=debug cargo run
     tracing_subscriber::fmt()
@@ -75,25 +213,53 @@ fn main() {
         cx.activate(true);
     });
 
+    // shellmaster:// 深链接与 ssh:// 链接：macOS 通过系统事件送达，Windows/Linux 作为启动参数传入
+    let (deep_link_tx, deep_link_rx) = futures::channel::mpsc::unbounded::<String>();
+    for link in launch.links {
+        let _ = deep_link_tx.unbounded_send(link);
+    }
+    let launch_target = launch.target;
+    app.on_open_urls(move |urls| {
+        for url in urls {
+            let _ = deep_link_tx.unbounded_send(url);
+        }
+    });
+
     app.run(|cx: &mut App| {
         // 初始化 gpui-component 组件库（必须在使用任何组件之前调用）
         gpui_component::init(cx);
 
+        // 初始化存储服务（打开数据库、导入旧的 JSON 配置并执行模式迁移，写入后通知界面刷新）
+        crate::services::database::StorageService::init(cx);
+
+        // 定时清理回收站中超过保留期的服务器（启动时执行一次，之后每小时检查）
+        cx.spawn(async move |cx| loop {
+            match storage::purge_expired_trash() {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Purged {} expired servers from trash", count),
+                Err(e) => tracing::warn!("Failed to purge trash: {:#}", e),
+            }
+            cx.background_executor()
+                .timer(std::time::Duration::from_secs(60 * 60))
+                .await;
+        })
+        .detach();
+
+        // 加载团队共享服务器清单（按设置的间隔刷新）
+        crate::services::inventory::InventoryService::init(cx);
+
+        // 服务器可达性探测（在连接设置中开启后台探测）
+        crate::services::reachability::ReachabilityService::init(cx);
+
         // 初始化全局快捷键（Cmd+Q / Ctrl+Q 退出等）
         crate::keybindings::init(cx);
 
-        // 迁移旧的私钥路径到新的密钥目录
-        if let Err(e) = storage::migrate_legacy_private_keys() {
-            tracing::warn!("私钥迁移失败: {}", e);
-        }
-
         // 根据保存的设置初始化主题模式
-        if let Ok(settings) = storage::load_settings() {
-            match settings.theme.mode {
-                ThemeMode::Light => Theme::change(GpuiThemeMode::Light, None, cx),
-                ThemeMode::Dark => Theme::change(GpuiThemeMode::Dark, None, cx),
-                ThemeMode::System => {} // 默认已跟随系统
-            }
+        let settings = storage::load_settings().unwrap_or_default();
+        match settings.theme.mode {
+            ThemeMode::Light => Theme::change(GpuiThemeMode::Light, None, cx),
+            ThemeMode::Dark => Theme::change(GpuiThemeMode::Dark, None, cx),
+            ThemeMode::System => {} // 默认已跟随系统
         }
 
         // 应用自定义全局主题配置（覆盖默认深色模式颜色）
@@ -102,20 +268,43 @@ fn main() {
         // 初始化终端模块（注册 Terminal 上下文的按键绑定）
         crate::terminal::init(cx);
 
-        let bounds = Bounds::centered(None, size(px(1200.), px(800.)), cx);
+        // 初始化 SFTP 组件（注册文件列表上下文的按键绑定）
+        crate::components::sftp::init(cx);
+
+        // 恢复上次退出时的窗口位置、大小与所在显示器
+        let (window_bounds, display_id) =
+            crate::services::window_state::initial_window_bounds(cx);
         let window_handle = cx
             .open_window(
                 WindowOptions {
-                    window_bounds: Some(WindowBounds::Windowed(bounds)),
+                    window_bounds: Some(window_bounds),
+                    display_id,
                     titlebar: Some(TitlebarOptions {
                         appears_transparent: true,
                         traffic_light_position: Some(point(px(16.), px(16.))),
                         ..Default::default()
                     }),
+                    // 终端背景透明/模糊需要窗口背景配合
+                    window_background: crate::theme::window_background_appearance(
+                        &settings.terminal,
+                        None,
+                    ),
                     ..Default::default()
                 },
                 |window, cx| {
-                    let view = cx.new(|cx| HomePage::new(cx));
+                    let view = cx.new(|cx| {
+                        let mut page = HomePage::new(cx);
+                        page.observe_storage(cx);
+                        page.save_session_on_quit(cx);
+                        page.remember_window_state(window, cx);
+                        page.install_tray(window, cx);
+                        page.restore_last_session(window, cx);
+                        if let Some((target, identity_file)) = launch_target {
+                            page.open_launch_target(target, identity_file, cx);
+                        }
+                        page.handle_deep_links(deep_link_rx, window, cx);
+                        page
+                    });
                     // 使用 Root 包装视图，这是 gpui-component 的要求
                     cx.new(|cx| Root::new(view, window, cx))
                 },
@@ -126,9 +315,12 @@ fn main() {
         // 根据设置决定是隐藏到 Dock/托盘还是真正退出应用
         let _ = window_handle.update(cx, |_, window, cx| {
             window.on_window_should_close(cx, |_window, cx| {
-                // 读取 close_to_tray 设置
+                // 读取 close_to_tray 设置（Windows/Linux 隐藏后只能从托盘恢复，需显示托盘图标）
                 let close_to_tray = storage::load_settings()
-                    .map(|s| s.system.close_to_tray)
+                    .map(|s| {
+                        s.system.close_to_tray
+                            && (cfg!(target_os = "macos") || s.system.show_tray_icon)
+                    })
                     .unwrap_or(false);
 
                 if close_to_tray {
@@ -146,3 +338,88 @@ fn main() {
         cx.activate(true);
     });
 }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<LaunchArgs, String> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        parse_launch_args(&args)
+    }
+
+    fn error(args: &[&str]) -> String {
+        parse(args).err().unwrap()
+    }
+
+    fn target(args: &[&str]) -> (AdHocTarget, Option<PathBuf>) {
+        parse(args).unwrap().target.unwrap()
+    }
+
+    #[test]
+    fn test_parse_launch_args_destination() {
+        let (target_host, key) = target(&["root@example.com"]);
+        assert_eq!(target_host.username, "root");
+        assert_eq!(target_host.host, "example.com");
+        assert_eq!(target_host.port, 22);
+        assert_eq!(key, None);
+
+        let (target_host, key) = target(&[
+            "example.com",
+            "-p",
+            "2222",
+            "-l",
+            "admin",
+            "--key",
+            "keys/id_test",
+        ]);
+        assert_eq!(target_host.username, "admin");
+        assert_eq!(target_host.port, 2222);
+        assert_eq!(key, Some(PathBuf::from("keys/id_test")));
+
+        // 命令行选项优先于目标中的用户名和端口
+        let (target_host, _) = target(&["--port=2200", "-ladmin", "deploy@[::1]:2022"]);
+        assert_eq!(target_host.username, "admin");
+        assert_eq!(target_host.host, "::1");
+        assert_eq!(target_host.port, 2200);
+
+        let (target_host, _) = target(&["-p2201", "root@h"]);
+        assert_eq!(target_host.port, 2201);
+    }
+
+    #[test]
+    fn test_parse_launch_args_flags_and_links() {
+        let launch = parse(&["ssh://root@h:2222", "shellmaster://connect?id=1"]).unwrap();
+        assert!(launch.target.is_none());
+        assert_eq!(
+            launch.links,
+            ["ssh://root@h:2222", "shellmaster://connect?id=1"]
+        );
+
+        assert!(
+            parse(&["--register-ssh-handler"])
+                .unwrap()
+                .register_ssh_handler
+        );
+        assert!(parse(&["-h"]).unwrap().help);
+        assert!(parse(&["--help"]).unwrap().help);
+
+        // macOS 附带的进程序列号被忽略
+        let launch = parse(&["-psn_0_12345"]).unwrap();
+        assert!(launch.target.is_none() && launch.links.is_empty());
+        assert!(parse(&[]).unwrap().target.is_none());
+    }
+
+    #[test]
+    fn test_parse_launch_args_errors() {
+        assert_eq!(error(&["-p"]), "Missing value for -p");
+        assert_eq!(error(&["root@h", "--user"]), "Missing value for --user");
+        assert_eq!(error(&["-p", "0", "root@h"]), "Invalid port: 0");
+        assert_eq!(error(&["--port=70000", "root@h"]), "Invalid port: 70000");
+        assert_eq!(error(&["-p", "ssh", "root@h"]), "Invalid port: ssh");
+        assert_eq!(error(&["--bogus"]), "Unknown option: --bogus");
+        assert_eq!(error(&["a@h", "b@h"]), "Unexpected argument: b@h");
+        assert_eq!(error(&["-l", "root"]), "Missing destination host");
+        assert_eq!(error(&["root@"]), "Invalid destination: root@");
+        assert_eq!(error(&["root@h:port"]), "Invalid destination: root@h:port");
+    }
+}
@@ -0,0 +1,133 @@
+// 连接历史记录
+// 每次连接（成功或失败）都记录一条，供主页"最近连接"与历史页查看和重新连接
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use super::ServerData;
+
+/// 历史记录时间格式
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 连接历史条目
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ConnectionHistoryEntry {
+    /// 唯一标识符
+    pub id: String,
+    /// 服务器 ID（临时连接为 adhoc- 开头，不在服务器列表中）
+    pub server_id: String,
+    /// 服务器名称
+    pub server_label: String,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    /// 开始连接的时间
+    pub started_at: String,
+    /// 会话结束时间（失败时为失败时间，仍在连接中为 None）
+    #[serde(default)]
+    pub ended_at: Option<String>,
+    /// 是否连接成功
+    pub succeeded: bool,
+    /// 失败原因
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl ConnectionHistoryEntry {
+    /// 根据连接结果创建历史条目；失败的连接立即结束
+    pub fn new(server: &ServerData, started_at: DateTime<Local>, error: Option<String>) -> Self {
+        let now = Local::now().format(TIME_FORMAT).to_string();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            server_id: server.id.clone(),
+            server_label: server.label.clone(),
+            host: server.host.clone(),
+            port: server.port,
+            username: server.username.clone(),
+            started_at: started_at.format(TIME_FORMAT).to_string(),
+            ended_at: error.is_some().then_some(now),
+            succeeded: error.is_none(),
+            error,
+        }
+    }
+
+    /// 标记会话结束（已结束的不再更新）
+    pub fn finish(&mut self) {
+        if self.ended_at.is_none() {
+            self.ended_at = Some(Local::now().format(TIME_FORMAT).to_string());
+        }
+    }
+
+    /// 开始时间
+    pub fn started(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(&self.started_at, TIME_FORMAT).ok()
+    }
+
+    /// 持续时长（仍在连接中的按当前时间计算）
+    pub fn duration(&self) -> Option<Duration> {
+        let started = self.started()?;
+        let ended = match &self.ended_at {
+            Some(ended) => NaiveDateTime::parse_from_str(ended, TIME_FORMAT).ok()?,
+            None => Local::now().naive_local(),
+        };
+        Some(ended - started)
+    }
+
+    /// 连接地址，如 `root@example.com:22`
+    pub fn address(&self) -> String {
+        format!("{}@{}:{}", self.username, self.host, self.port)
+    }
+}
+
+/// 连接历史记录（最新的在前）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConnectionHistory {
+    pub entries: Vec<ConnectionHistoryEntry>,
+}
+
+impl ConnectionHistory {
+    /// 删除早于保留天数的记录，返回是否有记录被删除
+    pub fn prune(&mut self, retention_days: u32) -> bool {
+        let cutoff = Local::now().naive_local() - Duration::days(retention_days as i64);
+        let before = self.entries.len();
+        self.entries
+            .retain(|e| e.started().map(|t| t >= cutoff).unwrap_or(true));
+        self.entries.len() != before
+    }
+}
+
+/// 连接历史的筛选条件
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConnectionHistoryFilter {
+    #[default]
+    All,
+    Succeeded,
+    Failed,
+}
+
+impl ConnectionHistoryFilter {
+    /// 所有筛选条件（按显示顺序）
+    pub const ALL: [ConnectionHistoryFilter; 3] = [
+        ConnectionHistoryFilter::All,
+        ConnectionHistoryFilter::Succeeded,
+        ConnectionHistoryFilter::Failed,
+    ];
+
+    /// 条目是否符合筛选条件
+    pub fn matches(&self, entry: &ConnectionHistoryEntry) -> bool {
+        match self {
+            ConnectionHistoryFilter::All => true,
+            ConnectionHistoryFilter::Succeeded => entry.succeeded,
+            ConnectionHistoryFilter::Failed => !entry.succeeded,
+        }
+    }
+
+    /// 显示文本的 i18n 键
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ConnectionHistoryFilter::All => "connection_history.filter.all",
+            ConnectionHistoryFilter::Succeeded => "connection_history.filter.succeeded",
+            ConnectionHistoryFilter::Failed => "connection_history.filter.failed",
+        }
+    }
+}
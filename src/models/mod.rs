@@ -1,16 +1,24 @@
 // 数据模型模块
 
 pub mod connection;
+pub mod history;
 pub mod known_hosts;
 pub mod monitor;
+pub mod runbook;
+pub mod schedule;
 pub mod server;
 pub mod session;
 pub mod settings;
 pub mod sftp;
 pub mod snippets;
+pub mod terminal_macro;
+pub mod window_state;
+pub mod workspace;
 
 pub use known_hosts::{KnownHost, KnownHostsConfig};
+pub use runbook::{Runbook, RunbookStep};
 pub use server::{HistoryItem, Server, ServerConfig, ServerData, ServerGroup, ServerGroupData};
 pub use settings::AppSettings;
 pub use sftp::SftpState;
-pub use snippets::{SnippetCommand, SnippetGroup, SnippetsConfig};
+pub use snippets::{SnippetCommand, SnippetGroup, SnippetScope, SnippetVariable, SnippetsConfig};
+pub use terminal_macro::TerminalMacro;
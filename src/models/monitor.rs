@@ -62,6 +62,8 @@ pub struct LoadInfo {
     pub timestamp: u64,
     pub cpu: CpuLoadInfo,
     pub memory: MemoryLoadInfo,
+    #[serde(default)]
+    pub disk_io: DiskIoInfo,
     pub top_cpu_processes: Vec<ProcessInfo>,
     pub top_memory_processes: Vec<ProcessInfo>,
 }
@@ -90,6 +92,15 @@ pub struct MemoryLoadInfo {
     pub swap_used_bytes: u64,
 }
 
+/// 磁盘 IO 累计字节数（来自 /proc/diskstats，只统计整块磁盘）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiskIoInfo {
+    /// 累计读取（字节）
+    pub read_bytes: u64,
+    /// 累计写入（字节）
+    pub write_bytes: u64,
+}
+
 /// 进程信息
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessInfo {
@@ -191,6 +202,120 @@ pub struct DiskDeviceInfo {
     pub inodes_available: u64,
 }
 
+// ============================================================================
+// 健康状态 (温度传感器与 SMART)
+// ============================================================================
+
+/// 温度传感器读数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TemperatureSensor {
+    /// 传感器芯片（如 coretemp-isa-0000，来自 thermal_zone 时为空）
+    pub chip: String,
+    /// 传感器名称（如 Package id 0）
+    pub label: String,
+    /// 当前温度（摄氏度）
+    pub celsius: f32,
+    /// 高温阈值（摄氏度）
+    pub high: Option<f32>,
+    /// 临界温度（摄氏度）
+    pub critical: Option<f32>,
+}
+
+/// 硬盘 SMART 健康状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DriveHealth {
+    /// 设备名（如 sda、nvme0n1）
+    pub device: String,
+    /// 硬盘型号
+    pub model: String,
+    /// 自检是否通过（无权限或设备不支持 SMART 时为 None）
+    pub passed: Option<bool>,
+    /// smartctl 输出的原始状态（如 PASSED、OK、FAILED!）
+    pub status: String,
+}
+
+// ============================================================================
+// GPU 状态 (nvidia-smi，与负载信息同频刷新)
+// ============================================================================
+
+/// GPU 状态信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GpuInfo {
+    pub gpus: Vec<GpuDeviceInfo>,
+    /// 使用 GPU 的计算进程
+    pub processes: Vec<GpuProcessInfo>,
+}
+
+/// 单块 GPU 的信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GpuDeviceInfo {
+    /// GPU 序号
+    pub index: u32,
+    /// 型号名称
+    pub name: String,
+    /// GPU UUID（用于关联进程）
+    pub uuid: String,
+    /// GPU 使用率百分比
+    pub utilization_percent: f32,
+    /// 已使用显存（字节）
+    pub memory_used_bytes: u64,
+    /// 显存总量（字节）
+    pub memory_total_bytes: u64,
+    /// 温度（摄氏度，驱动不支持时为 None）
+    pub temperature_celsius: Option<f32>,
+}
+
+impl GpuDeviceInfo {
+    /// 显存使用率百分比
+    pub fn memory_percent(&self) -> f32 {
+        if self.memory_total_bytes > 0 {
+            (self.memory_used_bytes as f32 / self.memory_total_bytes as f32) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// 使用 GPU 的进程
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GpuProcessInfo {
+    /// 所在 GPU 的 UUID
+    pub gpu_uuid: String,
+    pub pid: u32,
+    pub name: String,
+    /// 占用显存（字节）
+    pub memory_used_bytes: u64,
+}
+
+// ============================================================================
+// 登录信息 (who / last / lastb，每分钟刷新)
+// ============================================================================
+
+/// 登录信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoginInfo {
+    /// 当前登录的会话（who）
+    pub sessions: Vec<LoginRecord>,
+    /// 最近的登录记录（last）
+    pub recent: Vec<LoginRecord>,
+    /// 最近的失败登录（lastb）
+    pub failed: Vec<LoginRecord>,
+    /// 是否能读取失败登录（lastb 需要 root 或免密 sudo）
+    pub failed_available: bool,
+}
+
+/// 单条登录记录
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoginRecord {
+    pub user: String,
+    /// 终端（如 pts/0、ssh:notty）
+    pub tty: String,
+    /// 来源地址（本地登录时为空）
+    pub from: String,
+    /// 登录时间（远端原样输出，last 还包含退出时间和时长）
+    pub time: String,
+}
+
 // ============================================================================
 // UI 状态
 // ============================================================================
@@ -204,6 +329,60 @@ pub struct NetworkSpeedSnapshot {
     pub tx_speed: f64,
 }
 
+/// 历史采样点（每次负载刷新记录一条，用于历史图表）
+#[derive(Debug, Clone, Default)]
+pub struct MetricSample {
+    /// 采样时间（远端 Unix 时间戳，秒）
+    pub timestamp: u64,
+    /// CPU 使用率百分比
+    pub cpu_percent: f32,
+    /// 内存使用率百分比
+    pub memory_percent: f32,
+    /// 当前网卡接收速率 (bytes/s)
+    pub rx_speed: f64,
+    /// 当前网卡发送速率 (bytes/s)
+    pub tx_speed: f64,
+    /// 磁盘读取速率 (bytes/s)
+    pub disk_read_speed: f64,
+    /// 磁盘写入速率 (bytes/s)
+    pub disk_write_speed: f64,
+}
+
+/// 历史图表的时间范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoryRange {
+    #[default]
+    FiveMinutes,
+    ThirtyMinutes,
+    OneHour,
+}
+
+impl HistoryRange {
+    pub const ALL: [HistoryRange; 3] = [
+        HistoryRange::FiveMinutes,
+        HistoryRange::ThirtyMinutes,
+        HistoryRange::OneHour,
+    ];
+
+    /// 时间跨度（秒）
+    pub fn seconds(self) -> u64 {
+        match self {
+            HistoryRange::FiveMinutes => 5 * 60,
+            HistoryRange::ThirtyMinutes => 30 * 60,
+            HistoryRange::OneHour => 60 * 60,
+        }
+    }
+
+    /// 选择器上显示的标签
+    pub fn label(self) -> &'static str {
+        match self {
+            HistoryRange::FiveMinutes => "5m",
+            HistoryRange::ThirtyMinutes => "30m",
+            HistoryRange::OneHour => "1h",
+        }
+    }
+}
+
 /// Monitor UI 状态
 #[derive(Debug, Clone, Default)]
 pub struct MonitorState {
@@ -217,10 +396,24 @@ pub struct MonitorState {
     pub network_history: VecDeque<NetworkInfo>,
     /// 最新磁盘信息
     pub disk_info: Option<DiskInfo>,
+    /// GPU 信息（远端没有 nvidia-smi 或没有 GPU 时为 None）
+    pub gpu_info: Option<GpuInfo>,
+    /// 温度传感器读数（没有可用传感器时为空）
+    pub temperatures: Vec<TemperatureSensor>,
+    /// 硬盘 SMART 健康状态（远端没有 smartctl 时为空）
+    pub drive_health: Vec<DriveHealth>,
+    /// 登录信息（远端没有 who 时为 None）
+    pub login_info: Option<LoginInfo>,
     /// 当前选中的网络接口索引
     pub selected_interface_index: usize,
     /// 网速历史（用于图表，最近30秒）
     pub speed_history: VecDeque<NetworkSpeedSnapshot>,
+    /// 历史采样（按 history_retention_secs 保留，用于详情弹窗中的历史图表）
+    pub metric_history: VecDeque<MetricSample>,
+    /// 历史采样保留时长（秒）
+    pub history_retention_secs: u64,
+    /// 最近一次采集失败的原因（下一次负载采集成功后清除）
+    pub collector_error: Option<String>,
 }
 
 impl MonitorState {
@@ -241,10 +434,14 @@ impl MonitorState {
     /// 历史记录最大条目数（1分钟，每2秒一条 = 30条）
     const MAX_HISTORY_SIZE: usize = 30;
 
+    /// 默认的历史采样保留时长（秒）
+    const DEFAULT_HISTORY_RETENTION_SECS: u64 = 60 * 60;
+
     /// 创建初始化的空状态（用于真实数据，显示占位符）
     pub fn empty() -> Self {
         let mut state = Self::default();
         state.enabled = true;
+        state.history_retention_secs = Self::DEFAULT_HISTORY_RETENTION_SECS;
 
         // 初始化空的负载信息，供 UI 显示占位符
         state.load_history.push_back(LoadInfo::default());
@@ -268,8 +465,26 @@ impl MonitorState {
         self.system_info = Some(info);
     }
 
-    /// 更新负载信息（并维护历史记录）
+    /// 设置历史采样保留时长（分钟，至少 1 分钟）
+    pub fn set_history_retention_minutes(&mut self, minutes: u32) {
+        self.history_retention_secs = u64::from(minutes.max(1)) * 60;
+        self.prune_metric_history();
+    }
+
+    /// 记录采集失败的原因
+    pub fn set_collector_error(&mut self, error: String) {
+        self.collector_error = Some(error);
+    }
+
+    /// 更新负载信息（并维护历史记录与历史采样）
     pub fn update_load_info(&mut self, info: LoadInfo) {
+        self.collector_error = None;
+        if info.timestamp > 0 {
+            let sample = self.metric_sample(&info);
+            self.metric_history.push_back(sample);
+            self.prune_metric_history();
+        }
+
         self.load_history.push_back(info);
         // 限制历史记录数量
         while self.load_history.len() > Self::MAX_HISTORY_SIZE {
@@ -343,11 +558,101 @@ impl MonitorState {
         }
     }
 
+    /// 根据新的负载信息生成历史采样（磁盘 IO 速率与上一条负载信息比较得出）
+    fn metric_sample(&self, info: &LoadInfo) -> MetricSample {
+        let total_memory = self
+            .system_info
+            .as_ref()
+            .map(|s| s.memory.total_bytes)
+            .unwrap_or(0);
+        let memory_percent = if total_memory > 0 {
+            (info.memory.used_bytes as f32 / total_memory as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        let (disk_read_speed, disk_write_speed) = self
+            .load_history
+            .back()
+            .filter(|prev| prev.timestamp > 0 && info.timestamp > prev.timestamp)
+            .map(|prev| {
+                let time_diff = (info.timestamp - prev.timestamp) as f64;
+                let read = info
+                    .disk_io
+                    .read_bytes
+                    .saturating_sub(prev.disk_io.read_bytes);
+                let write = info
+                    .disk_io
+                    .write_bytes
+                    .saturating_sub(prev.disk_io.write_bytes);
+                (read as f64 / time_diff, write as f64 / time_diff)
+            })
+            .unwrap_or((0.0, 0.0));
+
+        let (rx_speed, tx_speed) = self.current_speed();
+        MetricSample {
+            timestamp: info.timestamp,
+            cpu_percent: info.cpu.usage_percent,
+            memory_percent,
+            rx_speed,
+            tx_speed,
+            disk_read_speed,
+            disk_write_speed,
+        }
+    }
+
+    /// 丢弃超过保留时长的历史采样
+    fn prune_metric_history(&mut self) {
+        let Some(latest) = self.metric_history.back().map(|s| s.timestamp) else {
+            return;
+        };
+        let cutoff = latest.saturating_sub(self.history_retention_secs);
+        while self
+            .metric_history
+            .front()
+            .is_some_and(|s| s.timestamp < cutoff)
+        {
+            self.metric_history.pop_front();
+        }
+    }
+
+    /// 指定时间范围内的历史采样（以最新一条采样的时间为终点）
+    pub fn history_in_range(&self, range: HistoryRange) -> Vec<&MetricSample> {
+        let Some(latest) = self.metric_history.back().map(|s| s.timestamp) else {
+            return Vec::new();
+        };
+        let cutoff = latest.saturating_sub(range.seconds());
+        self.metric_history
+            .iter()
+            .filter(|s| s.timestamp >= cutoff)
+            .collect()
+    }
+
     /// 更新磁盘信息
     pub fn update_disk_info(&mut self, info: DiskInfo) {
         self.disk_info = Some(info);
     }
 
+    /// 更新 GPU 信息
+    pub fn update_gpu_info(&mut self, info: GpuInfo) {
+        self.gpu_info = Some(info);
+    }
+
+    /// 更新温度传感器读数
+    pub fn update_temperatures(&mut self, sensors: Vec<TemperatureSensor>) {
+        self.temperatures = sensors;
+    }
+
+    /// 更新硬盘 SMART 健康状态
+    pub fn update_drive_health(&mut self, drives: Vec<DriveHealth>) {
+        self.drive_health = drives;
+    }
+
+    /// 更新登录信息
+    pub fn update_login_info(&mut self, info: LoginInfo) {
+        self.login_info = Some(info);
+    }
+
     /// 获取当前网速 (RX, TX) bytes/s
     pub fn current_speed(&self) -> (f64, f64) {
         self.speed_history
@@ -0,0 +1,56 @@
+// Runbook 数据模型：按顺序执行的步骤清单（发送命令、等待输出、手动确认、等待时长）
+
+use serde::{Deserialize, Serialize};
+
+/// 等待输出步骤的默认超时时间（秒）
+pub const DEFAULT_WAIT_TIMEOUT_SECS: u64 = 30;
+
+/// Runbook 中的单个步骤
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RunbookStep {
+    /// 向终端发送命令（自动追加回车）
+    Send { command: String },
+    /// 等待终端输出匹配正则表达式（如 shell 提示符），超时后运行失败
+    WaitFor { pattern: String, timeout_secs: u64 },
+    /// 暂停运行，等待手动确认后继续
+    Confirm { message: String },
+    /// 等待指定秒数
+    Sleep { secs: u64 },
+}
+
+impl RunbookStep {
+    /// 步骤类型名称的 i18n 键
+    pub fn kind_key(&self) -> &'static str {
+        match self {
+            RunbookStep::Send { .. } => "runbook.step.send",
+            RunbookStep::WaitFor { .. } => "runbook.step.wait_for",
+            RunbookStep::Confirm { .. } => "runbook.step.confirm",
+            RunbookStep::Sleep { .. } => "runbook.step.sleep",
+        }
+    }
+
+    /// 步骤内容摘要（命令、正则、确认提示或秒数）
+    pub fn summary(&self) -> String {
+        match self {
+            RunbookStep::Send { command } => command.clone(),
+            RunbookStep::WaitFor {
+                pattern,
+                timeout_secs,
+            } => format!("/{}/ ({}s)", pattern, timeout_secs),
+            RunbookStep::Confirm { message } => message.clone(),
+            RunbookStep::Sleep { secs } => format!("{}s", secs),
+        }
+    }
+}
+
+/// Runbook：适用于部署、维护等需要按顺序执行的操作流程
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Runbook {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub steps: Vec<RunbookStep>,
+    pub created_at: String,
+}
@@ -0,0 +1,75 @@
+// 定时任务数据模型：按 cron 表达式在服务器上运行快捷命令或 Runbook，并记录每次运行的结果
+
+use serde::{Deserialize, Serialize};
+
+/// 最多保留的运行记录数
+pub const MAX_JOB_HISTORY: usize = 500;
+
+/// 定时任务要运行的内容
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledTask {
+    /// 快捷命令（变量使用该服务器上次填写的值或默认值）
+    Snippet { snippet_id: String },
+    /// Runbook（发送命令与等待时长步骤依次执行，不支持手动确认步骤）
+    Runbook { runbook_id: String },
+}
+
+/// 定时任务
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub id: String,
+    pub name: String,
+    /// 运行的服务器（未连接时临时连接，运行后断开）
+    pub server_id: String,
+    pub task: ScheduledTask,
+    /// cron 计划表达式（5 个字段或 @daily 等宏）
+    pub schedule: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub created_at: String,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 定时任务配置根结构
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SchedulesConfig {
+    pub jobs: Vec<ScheduledJob>,
+}
+
+/// 一次运行的结果
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JobRun {
+    pub id: String,
+    pub job_id: String,
+    /// 运行时的任务名称与服务器名称（任务删除或改名后仍能显示）
+    pub job_name: String,
+    pub server_label: String,
+    /// 开始时间（%Y-%m-%d %H:%M:%S）
+    pub started_at: String,
+    pub duration_ms: u64,
+    /// 退出码（连接或执行失败时为 None）
+    pub exit_code: Option<u32>,
+    /// 标准输出与标准错误（超长时截断）
+    pub output: String,
+    /// 连接或执行失败的原因
+    pub error: Option<String>,
+    /// 手动运行（而非按计划触发）
+    #[serde(default)]
+    pub manual: bool,
+}
+
+impl JobRun {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && self.exit_code == Some(0)
+    }
+}
+
+/// 运行记录（最新的在前）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JobHistory {
+    pub runs: Vec<JobRun>,
+}
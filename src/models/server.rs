@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::settings::{ConnectionSettings, MonitorSettings, TerminalSettings};
+
 // ============== 视图展示用的简化结构（兼容现有代码）==============
 
 /// 服务器数据结构（用于视图展示）
@@ -14,19 +16,31 @@ pub struct Server {
     pub description: String,
     pub account: String,
     pub last_connected: String,
+    /// 自由标签（如 env:prod、role:db）
+    pub tags: Vec<String>,
+    /// 来自团队共享清单（只读）
+    pub shared: bool,
 }
 
 /// 服务器组（用于视图展示）
 #[derive(Clone)]
 pub struct ServerGroup {
+    /// 分组 ID（“未分组”为 None）
+    pub id: Option<String>,
+    /// 嵌套层级（顶层为 0）
+    pub depth: usize,
     pub name: String,
     pub icon_path: &'static str,
     pub servers: Vec<Server>,
+    /// 团队共享清单中的分组（只读，不能拖放或管理）
+    pub shared: bool,
 }
 
-/// 侧边栏历史记录项
+/// 侧边栏"最近连接"项
 #[derive(Clone, Debug)]
 pub struct HistoryItem {
+    /// 对应的连接历史条目 ID
+    pub entry_id: String,
     pub name: String,
     pub time: String,
 }
@@ -39,6 +53,47 @@ pub enum AuthType {
     #[default]
     Password,
     PublicKey,
+    /// 引用身份库中的身份（见 `ServerData::identity_id`）
+    Identity,
+}
+
+/// 身份的密钥来源
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+pub enum IdentityKind {
+    /// keys 目录中的私钥文件
+    #[default]
+    KeyFile,
+    /// SSH Agent 中的密钥
+    Agent,
+}
+
+impl IdentityKind {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            IdentityKind::KeyFile => "identity.kind.key_file",
+            IdentityKind::Agent => "identity.kind.agent",
+        }
+    }
+}
+
+/// 身份：命名的私钥或 Agent 密钥及默认用户名，服务器通过 ID 引用而不复制私钥路径
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct Identity {
+    pub id: String,
+    pub name: String,
+    pub kind: IdentityKind,
+    /// 默认用户名（服务器未填写用户名时使用）
+    #[serde(default)]
+    pub username: String,
+    /// keys 目录下的私钥文件名
+    #[serde(default)]
+    pub private_key_filename: Option<String>,
+    #[serde(default)]
+    pub key_passphrase_encrypted: Option<String>,
+    /// Agent 密钥的注释或 SHA256 指纹，为空时依次尝试 Agent 中的全部密钥
+    #[serde(default)]
+    pub agent_key: Option<String>,
+    pub created_at: String,
 }
 
 /// 代理类型
@@ -81,10 +136,40 @@ pub struct ServerData {
     pub private_key_path: Option<String>,
 
     pub key_passphrase_encrypted: Option<String>,
+    /// 认证方式为 `AuthType::Identity` 时引用的身份 ID
+    #[serde(default)]
+    pub identity_id: Option<String>,
     pub description: Option<String>,
     pub jump_host_id: Option<String>,
     pub proxy: Option<ProxyConfig>,
     pub enable_monitor: bool,
+    /// 终端背景不透明度覆盖（0-100，None 表示跟随全局设置）
+    #[serde(default)]
+    pub terminal_opacity: Option<u32>,
+    /// 监控刷新间隔覆盖（秒，None 表示跟随全局设置）
+    #[serde(default)]
+    pub monitor_interval_secs: Option<u32>,
+    /// 终端字体大小覆盖（None 表示跟随全局设置）
+    #[serde(default)]
+    pub terminal_font_size: Option<u32>,
+    /// 终端配色方案覆盖（None 表示跟随全局设置）
+    #[serde(default)]
+    pub terminal_color_scheme: Option<String>,
+    /// 终端回滚行数覆盖（None 表示跟随全局设置）
+    #[serde(default)]
+    pub scrollback_lines: Option<u32>,
+    /// 心跳间隔覆盖（秒，0 表示关闭，None 表示跟随全局设置）
+    #[serde(default)]
+    pub keepalive_interval_secs: Option<u32>,
+    /// 自由标签（如 env:prod、role:db、region:eu）
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 连接成功后在首个终端中依次执行的命令
+    #[serde(default)]
+    pub post_connect_commands: Vec<String>,
+    /// 收藏（显示在托盘菜单中，可快速连接）
+    #[serde(default)]
+    pub favorite: bool,
     pub created_at: String,
     pub last_connected_at: Option<String>,
 }
@@ -103,22 +188,225 @@ impl Default for ServerData {
             private_key_filename: None,
             private_key_path: None,
             key_passphrase_encrypted: None,
+            identity_id: None,
             description: None,
             jump_host_id: None,
             proxy: None,
             enable_monitor: true,
+            terminal_opacity: None,
+            monitor_interval_secs: None,
+            terminal_font_size: None,
+            terminal_color_scheme: None,
+            scrollback_lines: None,
+            keepalive_interval_secs: None,
+            tags: Vec::new(),
+            post_connect_commands: Vec::new(),
+            favorite: false,
             created_at: String::new(),
             last_connected_at: None,
         }
     }
 }
 
+impl ServerData {
+    /// 连接后要写入终端的命令（每条以换行结尾），没有时返回 None
+    pub fn post_connect_script(&self) -> Option<String> {
+        let commands: Vec<&str> = self
+            .post_connect_commands
+            .iter()
+            .map(|c| c.trim())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if commands.is_empty() {
+            None
+        } else {
+            Some(format!("{}\n", commands.join("\n")))
+        }
+    }
+
+    /// 应用本服务器覆盖的终端设置（字体大小、配色方案、回滚行数）
+    pub fn resolve_terminal_settings(&self, mut settings: TerminalSettings) -> TerminalSettings {
+        if let Some(font_size) = self.terminal_font_size {
+            settings.font_size = font_size;
+        }
+        if let Some(color_scheme) = &self.terminal_color_scheme {
+            settings.color_scheme = color_scheme.clone();
+        }
+        if let Some(scrollback_lines) = self.scrollback_lines {
+            settings.scrollback_lines = scrollback_lines;
+        }
+        settings
+    }
+
+    /// 心跳间隔（秒）：服务器覆盖优先，否则使用全局设置
+    pub fn resolve_keepalive_secs(&self, settings: &ConnectionSettings) -> u32 {
+        self.keepalive_interval_secs
+            .unwrap_or(settings.keepalive_interval_secs)
+    }
+
+    /// 监控刷新间隔（秒）：服务器覆盖优先，否则使用全局设置
+    pub fn resolve_monitor_interval_secs(&self, settings: &MonitorSettings) -> u32 {
+        self.monitor_interval_secs
+            .unwrap_or_else(|| settings.refresh_interval.secs())
+    }
+}
+
+/// 服务器模板：保存认证方式、跳板机、代理与连接后命令，用于快速新建同类主机
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct ServerTemplate {
+    pub id: String,
+    pub name: String,
+    pub port: u16,
+    pub username: String,
+    pub auth_type: AuthType,
+    pub password_encrypted: Option<String>,
+    pub private_key_filename: Option<String>,
+    pub key_passphrase_encrypted: Option<String>,
+    #[serde(default)]
+    pub identity_id: Option<String>,
+    pub jump_host_id: Option<String>,
+    pub proxy: Option<ProxyConfig>,
+    pub group_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub post_connect_commands: Vec<String>,
+    pub created_at: String,
+}
+
+impl ServerTemplate {
+    /// 从服务器配置提取模板（主机地址、名称、描述等主机相关字段不会保存）
+    pub fn from_server(id: String, name: String, server: &ServerData) -> Self {
+        Self {
+            id,
+            name,
+            port: server.port,
+            username: server.username.clone(),
+            auth_type: server.auth_type.clone(),
+            password_encrypted: server.password_encrypted.clone(),
+            private_key_filename: server
+                .private_key_filename
+                .clone()
+                .or_else(|| server.private_key_path.clone()),
+            key_passphrase_encrypted: server.key_passphrase_encrypted.clone(),
+            identity_id: server.identity_id.clone(),
+            jump_host_id: server.jump_host_id.clone(),
+            proxy: server.proxy.clone(),
+            group_id: server.group_id.clone(),
+            tags: server.tags.clone(),
+            post_connect_commands: server.post_connect_commands.clone(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// 生成预填了模板设置的服务器配置（名称与主机地址为空）
+    pub fn to_server_data(&self) -> ServerData {
+        ServerData {
+            group_id: self.group_id.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            auth_type: self.auth_type.clone(),
+            password_encrypted: self.password_encrypted.clone(),
+            private_key_filename: self.private_key_filename.clone(),
+            key_passphrase_encrypted: self.key_passphrase_encrypted.clone(),
+            identity_id: self.identity_id.clone(),
+            jump_host_id: self.jump_host_id.clone(),
+            proxy: self.proxy.clone(),
+            tags: self.tags.clone(),
+            post_connect_commands: self.post_connect_commands.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+/// 解析以逗号或空白分隔的标签，去除空项与重复项（保持输入顺序）
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input
+        .split(|c: char| c == ',' || c == '，' || c.is_whitespace())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+    {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// 批量编辑时对服务器的修改（None 表示保持不变）
+#[derive(Clone, Debug, Default)]
+pub struct ServerPatch {
+    pub username: Option<String>,
+    pub port: Option<u16>,
+    /// keys 目录下的私钥文件名，设置后认证方式改为公钥
+    pub private_key_filename: Option<String>,
+    /// 跳板机 ID，`Some(None)` 表示取消跳板机
+    pub jump_host_id: Option<Option<String>>,
+    /// 代理，`Some(None)` 表示取消代理
+    pub proxy: Option<Option<ProxyConfig>>,
+    /// 分组路径，`Some(None)` 表示移到未分组（由存储层解析为分组 ID）
+    pub group_path: Option<Option<String>>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+}
+
+impl ServerPatch {
+    /// 是否没有任何修改
+    pub fn is_empty(&self) -> bool {
+        self.username.is_none()
+            && self.port.is_none()
+            && self.private_key_filename.is_none()
+            && self.jump_host_id.is_none()
+            && self.proxy.is_none()
+            && self.group_path.is_none()
+            && self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+    }
+
+    /// 应用到单台服务器（分组除外）；服务器不会被设为自己的跳板机
+    pub fn apply(&self, server: &mut ServerData) {
+        if let Some(username) = &self.username {
+            server.username = username.clone();
+        }
+        if let Some(port) = self.port {
+            server.port = port;
+        }
+        if let Some(filename) = &self.private_key_filename {
+            server.auth_type = AuthType::PublicKey;
+            server.private_key_filename = Some(filename.clone());
+            server.private_key_path = None;
+            server.password_encrypted = None;
+            server.identity_id = None;
+        }
+        if let Some(jump_host_id) = &self.jump_host_id {
+            if jump_host_id.as_deref() != Some(server.id.as_str()) {
+                server.jump_host_id = jump_host_id.clone();
+            }
+        }
+        if let Some(proxy) = &self.proxy {
+            server.proxy = proxy.clone();
+        }
+        server
+            .tags
+            .retain(|t| !self.remove_tags.iter().any(|r| r.eq_ignore_ascii_case(t)));
+        for tag in &self.add_tags {
+            if !server.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                server.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
 /// 服务器组（持久化用）
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServerGroupData {
     pub id: String,
     pub name: String,
     pub icon_path: String,
+    /// 父分组 ID（顶层分组为 None）
+    #[serde(default)]
+    pub parent_id: Option<String>,
 }
 
 impl Default for ServerGroupData {
@@ -127,13 +415,193 @@ impl Default for ServerGroupData {
             id: String::new(),
             name: "默认分组".to_string(),
             icon_path: "icons/server.svg".to_string(),
+            parent_id: None,
         }
     }
 }
 
+/// 嵌套分组路径的分隔符（如 “生产/数据库”）
+pub const GROUP_PATH_SEPARATOR: char = '/';
+
 /// 配置数据（用于存储到文件）
+///
+/// `groups` 与 `servers` 的顺序即显示顺序；同一父分组下的子分组按在 `groups` 中的先后排列
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct ServerConfig {
     pub groups: Vec<ServerGroupData>,
     pub servers: Vec<ServerData>,
+    /// 服务器模板
+    #[serde(default)]
+    pub templates: Vec<ServerTemplate>,
+    /// 身份库
+    #[serde(default)]
+    pub identities: Vec<Identity>,
+    /// 回收站：已删除但仍可恢复的服务器
+    #[serde(default)]
+    pub trash: Vec<TrashedServer>,
+}
+
+/// 回收站中的服务器保留天数，过期后永久删除
+pub const TRASH_RETENTION_DAYS: i64 = 30;
+
+/// 回收站中的服务器
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrashedServer {
+    pub server: ServerData,
+    /// 删除时间（RFC 3339）
+    pub deleted_at: String,
+}
+
+impl TrashedServer {
+    pub fn new(server: ServerData) -> Self {
+        Self {
+            server,
+            deleted_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// 距离永久删除的剩余天数（不足一天记为 0）；删除时间无法解析时视为刚删除
+    pub fn days_left(&self) -> i64 {
+        let elapsed = chrono::DateTime::parse_from_rfc3339(&self.deleted_at)
+            .map(|t| (chrono::Utc::now() - t.with_timezone(&chrono::Utc)).num_days())
+            .unwrap_or(0);
+        (TRASH_RETENTION_DAYS - elapsed).max(0)
+    }
+
+    /// 是否已超过保留期
+    pub fn is_expired(&self) -> bool {
+        chrono::DateTime::parse_from_rfc3339(&self.deleted_at).is_ok_and(|t| {
+            chrono::Utc::now() - t.with_timezone(&chrono::Utc)
+                >= chrono::Duration::days(TRASH_RETENTION_DAYS)
+        })
+    }
+}
+
+impl ServerConfig {
+    /// 按 ID 查找身份
+    pub fn identity(&self, identity_id: &str) -> Option<&Identity> {
+        self.identities.iter().find(|i| i.id == identity_id)
+    }
+
+    /// 引用某个身份的服务器
+    pub fn identity_users<'a>(
+        &'a self,
+        identity_id: &'a str,
+    ) -> impl Iterator<Item = &'a ServerData> + 'a {
+        self.servers.iter().filter(move |s| {
+            s.auth_type == AuthType::Identity && s.identity_id.as_deref() == Some(identity_id)
+        })
+    }
+
+    /// 某个父分组下的直接子分组（保持存储顺序）；父分组丢失的分组视为顶层
+    pub fn child_groups<'a>(
+        &'a self,
+        parent_id: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a ServerGroupData> + 'a {
+        self.groups
+            .iter()
+            .filter(move |g| self.effective_parent(g) == parent_id)
+    }
+
+    fn effective_parent<'a>(&self, group: &'a ServerGroupData) -> Option<&'a str> {
+        group
+            .parent_id
+            .as_deref()
+            .filter(|parent| self.groups.iter().any(|g| g.id == *parent))
+    }
+
+    /// 按树的先序遍历返回所有分组及其层级
+    pub fn ordered_groups(&self) -> Vec<(usize, &ServerGroupData)> {
+        fn visit<'a>(
+            config: &'a ServerConfig,
+            parent_id: Option<&'a str>,
+            depth: usize,
+            out: &mut Vec<(usize, &'a ServerGroupData)>,
+        ) {
+            for group in config.child_groups(parent_id) {
+                // 防止异常数据中的环导致无限递归
+                if out.iter().any(|(_, g)| g.id == group.id) {
+                    continue;
+                }
+                out.push((depth, group));
+                visit(config, Some(&group.id), depth + 1, out);
+            }
+        }
+
+        let mut out = Vec::new();
+        visit(self, None, 0, &mut out);
+        out
+    }
+
+    /// 分组的完整路径（如 “生产/数据库”）
+    pub fn group_path(&self, group_id: &str) -> String {
+        let mut names = Vec::new();
+        let mut current = self.groups.iter().find(|g| g.id == group_id);
+        while let Some(group) = current {
+            // 防止异常数据中的环导致死循环
+            if names.len() > self.groups.len() {
+                break;
+            }
+            names.push(group.name.as_str());
+            current = group
+                .parent_id
+                .as_deref()
+                .and_then(|parent| self.groups.iter().find(|g| g.id == parent));
+        }
+        names.reverse();
+        names.join(&GROUP_PATH_SEPARATOR.to_string())
+    }
+
+    /// 按完整路径查找分组
+    pub fn find_group_by_path(&self, path: &str) -> Option<&ServerGroupData> {
+        self.groups.iter().find(|g| self.group_path(&g.id) == path)
+    }
+
+    /// 按路径查找分组，不存在的层级依次创建（仅修改内存中的配置），返回末级分组 ID
+    pub fn ensure_group_path(&mut self, path: &str) -> Option<String> {
+        let mut parent_id: Option<String> = None;
+        for name in path
+            .split(GROUP_PATH_SEPARATOR)
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+        {
+            let existing = self
+                .child_groups(parent_id.as_deref())
+                .find(|g| g.name == name)
+                .map(|g| g.id.clone());
+            let id = existing.unwrap_or_else(|| {
+                let id = uuid::Uuid::new_v4().to_string();
+                self.groups.push(ServerGroupData {
+                    id: id.clone(),
+                    name: name.to_string(),
+                    parent_id: parent_id.clone(),
+                    ..Default::default()
+                });
+                id
+            });
+            parent_id = Some(id);
+        }
+        parent_id
+    }
+
+    /// `group_id` 是否为 `ancestor_id` 本身或其子孙分组
+    pub fn is_within(&self, group_id: &str, ancestor_id: &str) -> bool {
+        let mut current = Some(group_id.to_string());
+        let mut steps = 0;
+        while let Some(id) = current {
+            if id == ancestor_id {
+                return true;
+            }
+            steps += 1;
+            if steps > self.groups.len() {
+                break;
+            }
+            current = self
+                .groups
+                .iter()
+                .find(|g| g.id == id)
+                .and_then(|g| g.parent_id.clone());
+        }
+        false
+    }
 }
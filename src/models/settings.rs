@@ -48,6 +48,10 @@ pub enum Language {
 }
 
 impl Language {
+    /// 所有可选语言（设置页按此顺序显示）
+    pub const ALL: [Language; 2] = [Language::Chinese, Language::English];
+
+    /// 语言的本地名称（不随界面语言变化）
     pub fn label(&self) -> &'static str {
         match self {
             Language::Chinese => "简体中文",
@@ -60,9 +64,11 @@ impl Language {
 pub struct ThemeSettings {
     pub mode: ThemeMode,
     pub language: Language,
-    pub accent_color: String,
     pub ui_font_family: String,
     pub ui_font_size: u32,
+    /// 界面配色（覆盖内置主题的强调色、侧边栏、表面等颜色）
+    #[serde(default)]
+    pub ui_theme: UiTheme,
 }
 
 impl Default for ThemeSettings {
@@ -70,13 +76,115 @@ impl Default for ThemeSettings {
         Self {
             mode: ThemeMode::Dark,
             language: Language::Chinese,
-            accent_color: "#3b82f6".to_string(), // Blue
             ui_font_family: "system-ui".to_string(),
             ui_font_size: 14,
+            ui_theme: UiTheme::default(),
+        }
+    }
+}
+
+/// 界面配色中可自定义的颜色
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiColorSlot {
+    /// 强调色（主按钮、选中状态）
+    Accent,
+    /// 主背景
+    Background,
+    /// 卡片与弹窗等表面
+    Surface,
+    /// 侧边栏与标题栏
+    Sidebar,
+    /// 边框
+    Border,
+}
+
+impl UiColorSlot {
+    pub const ALL: [UiColorSlot; 5] = [
+        UiColorSlot::Accent,
+        UiColorSlot::Background,
+        UiColorSlot::Surface,
+        UiColorSlot::Sidebar,
+        UiColorSlot::Border,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            UiColorSlot::Accent => "settings.ui_theme.accent",
+            UiColorSlot::Background => "settings.ui_theme.background",
+            UiColorSlot::Surface => "settings.ui_theme.surface",
+            UiColorSlot::Sidebar => "settings.ui_theme.sidebar",
+            UiColorSlot::Border => "settings.ui_theme.border",
+        }
+    }
+}
+
+/// 一种外观模式下的界面配色（十六进制颜色，None 表示使用内置颜色）
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct UiPalette {
+    pub accent: Option<String>,
+    pub background: Option<String>,
+    pub surface: Option<String>,
+    pub sidebar: Option<String>,
+    pub border: Option<String>,
+}
+
+impl UiPalette {
+    pub fn get(&self, slot: UiColorSlot) -> Option<&str> {
+        match slot {
+            UiColorSlot::Accent => self.accent.as_deref(),
+            UiColorSlot::Background => self.background.as_deref(),
+            UiColorSlot::Surface => self.surface.as_deref(),
+            UiColorSlot::Sidebar => self.sidebar.as_deref(),
+            UiColorSlot::Border => self.border.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, slot: UiColorSlot, color: Option<String>) {
+        match slot {
+            UiColorSlot::Accent => self.accent = color,
+            UiColorSlot::Background => self.background = color,
+            UiColorSlot::Surface => self.surface = color,
+            UiColorSlot::Sidebar => self.sidebar = color,
+            UiColorSlot::Border => self.border = color,
         }
     }
 }
 
+/// 界面主题：深色与浅色模式各一套配色，可导出为 JSON 分享
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct UiTheme {
+    /// 主题名称（为空表示内置默认配色）
+    pub name: String,
+    pub dark: UiPalette,
+    pub light: UiPalette,
+}
+
+impl UiTheme {
+    /// 指定模式下的配色
+    pub fn palette(&self, dark: bool) -> &UiPalette {
+        if dark {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+
+    pub fn palette_mut(&mut self, dark: bool) -> &mut UiPalette {
+        if dark {
+            &mut self.dark
+        } else {
+            &mut self.light
+        }
+    }
+
+    /// 配色是否相同（忽略名称）
+    pub fn same_colors(&self, other: &UiTheme) -> bool {
+        self.dark == other.dark && self.light == other.light
+    }
+}
+
 // ======================== 终端设置 ========================
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -120,6 +228,12 @@ pub struct TerminalSettings {
     pub cursor_style: CursorStyle,
     pub cursor_blink: bool,
     pub background_opacity: u32,
+    /// 背景透明时启用窗口模糊（由系统合成器完成）
+    #[serde(default)]
+    pub background_blur: bool,
+    /// 终端失去焦点时变暗，突出当前聚焦的面板
+    #[serde(default)]
+    pub dim_inactive_panes: bool,
     pub scrollback_lines: u32,
     // 行为
     pub copy_on_select: bool,
@@ -149,6 +263,8 @@ impl Default for TerminalSettings {
             cursor_style: CursorStyle::Block,
             cursor_blink: true,
             background_opacity: 100,
+            background_blur: false,
+            dim_inactive_panes: false,
             scrollback_lines: 10000,
             copy_on_select: false,
             right_click_paste: true,
@@ -178,6 +294,56 @@ pub enum SftpSortBy {
     Size,
     Modified,
     Type,
+    Permissions,
+}
+
+/// SFTP 文件列表的列
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SftpColumn {
+    Name,
+    Permissions,
+    Owner,
+    Group,
+    LinkTarget,
+    Size,
+    Modified,
+}
+
+impl SftpColumn {
+    /// 所有列（按列菜单中的显示顺序）
+    pub const ALL: [SftpColumn; 7] = [
+        SftpColumn::Name,
+        SftpColumn::Permissions,
+        SftpColumn::Owner,
+        SftpColumn::Group,
+        SftpColumn::LinkTarget,
+        SftpColumn::Size,
+        SftpColumn::Modified,
+    ];
+
+    /// 列标题的 i18n 键
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SftpColumn::Name => "sftp.header.name",
+            SftpColumn::Permissions => "sftp.header.permissions",
+            SftpColumn::Owner => "sftp.header.owner",
+            SftpColumn::Group => "sftp.header.group",
+            SftpColumn::LinkTarget => "sftp.header.link_target",
+            SftpColumn::Size => "sftp.header.size",
+            SftpColumn::Modified => "sftp.header.modified",
+        }
+    }
+
+    /// 点击列标题时的排序方式，不可排序的列返回 None
+    pub fn sort_by(&self) -> Option<SftpSortBy> {
+        match self {
+            SftpColumn::Name => Some(SftpSortBy::Name),
+            SftpColumn::Permissions => Some(SftpSortBy::Permissions),
+            SftpColumn::Size => Some(SftpSortBy::Size),
+            SftpColumn::Modified => Some(SftpSortBy::Modified),
+            SftpColumn::Owner | SftpColumn::Group | SftpColumn::LinkTarget => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -185,6 +351,8 @@ pub enum ConflictAction {
     #[default]
     Ask,
     Overwrite,
+    /// 源文件比目标文件新时才覆盖
+    OverwriteIfNewer,
     Skip,
     Rename,
 }
@@ -198,17 +366,50 @@ pub struct SftpSettings {
     pub sort_by: SftpSortBy,
     pub sort_ascending: bool,
     pub folders_first: bool,
+    /// 文件列表显示的列（按显示顺序）
+    #[serde(default = "default_file_list_columns")]
+    pub file_list_columns: Vec<SftpColumn>,
+    /// 默认使用双面板模式（本地 + 远程）
+    #[serde(default)]
+    pub dual_pane: bool,
     // 传输
     pub concurrent_transfers: u32,
+    /// 传输队列中同时进行的文件数
+    #[serde(default = "default_max_active_transfers")]
+    pub max_active_transfers: u32,
+    /// 同一服务器同时进行的文件数
+    #[serde(default = "default_max_transfers_per_server")]
+    pub max_transfers_per_server: u32,
     pub conflict_action: ConflictAction,
     pub preserve_timestamps: bool,
-    pub speed_limit_kb: u32,
+    /// 下载总限速（KB/s，0 表示不限速）
+    #[serde(default)]
+    pub download_limit_kb: u32,
+    /// 上传总限速（KB/s，0 表示不限速）
+    #[serde(default)]
+    pub upload_limit_kb: u32,
     pub resume_transfers: bool,
+    /// 传输完成后比较远程与本地的 SHA-256 校验完整性
+    #[serde(default)]
+    pub verify_checksum: bool,
+    /// 传输时使用 gzip 压缩数据流（远程需要 gzip，适合文本等可压缩文件）
+    #[serde(default)]
+    pub transfer_compression: bool,
+    /// 下载归档文件后自动解压到同名文件夹
+    #[serde(default)]
+    pub auto_extract_downloads: bool,
     pub open_folder_after_download: bool,
     // 路径
     pub local_default_path: String,
     pub remote_default_path: String,
     pub remember_last_path: bool,
+    // 删除
+    /// 删除时移动到远程回收站而不是直接删除
+    #[serde(default)]
+    pub use_remote_trash: bool,
+    /// 远程回收站目录（支持 ~ 表示远程主目录）
+    #[serde(default = "default_remote_trash_dir")]
+    pub remote_trash_dir: String,
     // 编辑器 - 基本设置
     pub use_builtin_editor: bool,
     pub external_editor_path: String,
@@ -219,6 +420,33 @@ pub struct SftpSettings {
     pub editor_line_height: f32,
     pub editor_gutter_width: u32,
     pub editor_gutter_padding: u32,
+    // 日志查看器
+    /// 日志查看器中高亮显示的关键词
+    #[serde(default)]
+    pub log_highlight_keywords: Vec<String>,
+}
+
+fn default_file_list_columns() -> Vec<SftpColumn> {
+    vec![
+        SftpColumn::Name,
+        SftpColumn::Permissions,
+        SftpColumn::Owner,
+        SftpColumn::Group,
+        SftpColumn::Size,
+        SftpColumn::Modified,
+    ]
+}
+
+fn default_max_active_transfers() -> u32 {
+    3
+}
+
+fn default_max_transfers_per_server() -> u32 {
+    2
+}
+
+fn default_remote_trash_dir() -> String {
+    "~/.shellmaster_trash".to_string()
 }
 
 impl Default for SftpSettings {
@@ -230,15 +458,25 @@ impl Default for SftpSettings {
             sort_by: SftpSortBy::Name,
             sort_ascending: true,
             folders_first: true,
+            file_list_columns: default_file_list_columns(),
+            dual_pane: false,
             concurrent_transfers: 3,
+            max_active_transfers: default_max_active_transfers(),
+            max_transfers_per_server: default_max_transfers_per_server(),
             conflict_action: ConflictAction::Ask,
             preserve_timestamps: true,
-            speed_limit_kb: 0,
+            download_limit_kb: 0,
+            upload_limit_kb: 0,
             resume_transfers: true,
+            verify_checksum: false,
+            transfer_compression: false,
+            auto_extract_downloads: false,
             open_folder_after_download: false,
             local_default_path: String::new(),
             remote_default_path: String::new(),
             remember_last_path: true,
+            use_remote_trash: false,
+            remote_trash_dir: default_remote_trash_dir(),
             use_builtin_editor: true,
             external_editor_path: String::new(),
             max_edit_file_size_kb: 5120, // 5MB
@@ -247,6 +485,7 @@ impl Default for SftpSettings {
             editor_line_height: 1.4,
             editor_gutter_width: 48,
             editor_gutter_padding: 8,
+            log_highlight_keywords: Vec::new(),
         }
     }
 }
@@ -260,6 +499,32 @@ pub enum MonitorInterval {
     Sec2,
     Sec5,
     Sec10,
+    Sec30,
+    Sec60,
+}
+
+impl MonitorInterval {
+    /// 所有可选的刷新间隔（按从短到长排列）
+    pub const ALL: [MonitorInterval; 6] = [
+        MonitorInterval::Sec1,
+        MonitorInterval::Sec2,
+        MonitorInterval::Sec5,
+        MonitorInterval::Sec10,
+        MonitorInterval::Sec30,
+        MonitorInterval::Sec60,
+    ];
+
+    /// 刷新间隔（秒）
+    pub fn secs(&self) -> u32 {
+        match self {
+            MonitorInterval::Sec1 => 1,
+            MonitorInterval::Sec2 => 2,
+            MonitorInterval::Sec5 => 5,
+            MonitorInterval::Sec10 => 10,
+            MonitorInterval::Sec30 => 30,
+            MonitorInterval::Sec60 => 60,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -288,6 +553,15 @@ pub struct MonitorSettings {
     pub show_disk: bool,
     pub show_network: bool,
     pub show_processes: bool,
+    /// 显示并采集 GPU 状态
+    #[serde(default = "default_true")]
+    pub show_gpu: bool,
+    /// 显示并采集硬件健康（温度与 SMART）
+    #[serde(default = "default_true")]
+    pub show_health: bool,
+    /// 显示并采集登录信息
+    #[serde(default = "default_true")]
+    pub show_logins: bool,
     pub chart_style: ChartStyle,
     pub show_grid: bool,
     pub temperature_unit: TemperatureUnit,
@@ -295,27 +569,57 @@ pub struct MonitorSettings {
     pub cpu_alert_threshold: u32,
     pub memory_alert_threshold: u32,
     pub disk_alert_threshold: u32,
+    /// 温度告警阈值（摄氏度，0 表示不检查）
+    #[serde(default = "default_temperature_alert_threshold")]
+    pub temperature_alert_threshold: u32,
+    /// 连续多少次采样超过阈值才触发告警
+    #[serde(default = "default_alert_consecutive_samples")]
+    pub alert_consecutive_samples: u32,
     pub alert_notification: bool,
     pub alert_sound: bool,
 }
 
+impl MonitorSettings {
+    /// 是否显示并采集系统负载（CPU 与内存都关闭时暂停）
+    pub fn show_load(&self) -> bool {
+        self.show_cpu || self.show_memory
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_temperature_alert_threshold() -> u32 {
+    85
+}
+
+fn default_alert_consecutive_samples() -> u32 {
+    3
+}
+
 impl Default for MonitorSettings {
     fn default() -> Self {
         Self {
             refresh_interval: MonitorInterval::Sec2,
-            history_retention_minutes: 5,
+            history_retention_minutes: 60,
             auto_deploy_agent: true,
             show_cpu: true,
             show_memory: true,
             show_disk: true,
             show_network: true,
             show_processes: true,
+            show_gpu: true,
+            show_health: true,
+            show_logins: true,
             chart_style: ChartStyle::Line,
             show_grid: true,
             temperature_unit: TemperatureUnit::Celsius,
             cpu_alert_threshold: 90,
             memory_alert_threshold: 85,
             disk_alert_threshold: 90,
+            temperature_alert_threshold: default_temperature_alert_threshold(),
+            alert_consecutive_samples: default_alert_consecutive_samples(),
             alert_notification: true,
             alert_sound: false,
         }
@@ -346,12 +650,52 @@ pub struct ConnectionSettings {
     pub reconnect_attempts: u32,
     pub reconnect_interval_secs: u32,
     pub restore_session: bool,
+    // 标签休眠
+    /// 非活动标签闲置多少分钟后休眠，0 表示不休眠
+    #[serde(default = "default_hibernate_after_minutes")]
+    pub hibernate_after_minutes: u32,
+    /// 是否休眠仍处于连接状态的标签（会关闭远端 Shell）
+    #[serde(default)]
+    pub hibernate_connected_tabs: bool,
+    // 服务器可达性
+    /// 是否在后台定期探测服务器是否在线
+    #[serde(default)]
+    pub reachability_probe: bool,
+    /// 后台探测的间隔（分钟）
+    #[serde(default = "default_reachability_interval_minutes")]
+    pub reachability_interval_minutes: u32,
     // 全局代理
     pub global_proxy_type: GlobalProxyType,
     pub global_proxy_host: String,
     pub global_proxy_port: u16,
     pub global_proxy_username: String,
     pub global_proxy_password: String,
+    // 数据库隧道
+    /// 各数据库的本地客户端启动命令
+    #[serde(default)]
+    pub db_client_commands: DbClientCommands,
+}
+
+fn default_hibernate_after_minutes() -> u32 {
+    30
+}
+
+fn default_reachability_interval_minutes() -> u32 {
+    5
+}
+
+/// 数据库隧道的本地客户端命令（为空表示不启动客户端）
+/// 命令中可使用 `{host}`、`{port}`、`{url}` 占位符
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct DbClientCommands {
+    #[serde(default)]
+    pub mysql: String,
+    #[serde(default)]
+    pub postgresql: String,
+    #[serde(default)]
+    pub redis: String,
+    #[serde(default)]
+    pub mongodb: String,
 }
 
 impl Default for ConnectionSettings {
@@ -366,11 +710,16 @@ impl Default for ConnectionSettings {
             reconnect_attempts: 3,
             reconnect_interval_secs: 5,
             restore_session: false,
+            hibernate_after_minutes: default_hibernate_after_minutes(),
+            hibernate_connected_tabs: false,
+            reachability_probe: false,
+            reachability_interval_minutes: default_reachability_interval_minutes(),
             global_proxy_type: GlobalProxyType::None,
             global_proxy_host: String::new(),
             global_proxy_port: 0,
             global_proxy_username: String::new(),
             global_proxy_password: String::new(),
+            db_client_commands: DbClientCommands::default(),
         }
     }
 }
@@ -383,6 +732,21 @@ pub enum SyncMethod {
     None,
     WebDAV,
     ICloud,
+    /// 私有 Git 仓库（通过应用自身的 SSH 连接推送/拉取）
+    Git,
+}
+
+impl SyncMethod {
+    /// 设置中可选的同步后端（None 与 WebDAV 等价）
+    pub const ALL: [SyncMethod; 2] = [SyncMethod::WebDAV, SyncMethod::Git];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SyncMethod::None | SyncMethod::WebDAV => "settings.sync.method.webdav",
+            SyncMethod::ICloud => "settings.sync.method.icloud",
+            SyncMethod::Git => "settings.sync.method.git",
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -394,6 +758,25 @@ pub enum SyncInterval {
     Daily,
 }
 
+impl SyncInterval {
+    /// 所有可选的自动同步频率
+    pub const ALL: [SyncInterval; 4] = [
+        SyncInterval::Manual,
+        SyncInterval::OnStartup,
+        SyncInterval::Hourly,
+        SyncInterval::Daily,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            SyncInterval::Manual => "settings.sync.interval.manual",
+            SyncInterval::OnStartup => "settings.sync.interval.on_startup",
+            SyncInterval::Hourly => "settings.sync.interval.hourly",
+            SyncInterval::Daily => "settings.sync.interval.daily",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
 pub enum ConflictStrategy {
     #[default]
@@ -403,6 +786,25 @@ pub enum ConflictStrategy {
     Merge,
 }
 
+impl ConflictStrategy {
+    /// 所有可选的冲突处理策略
+    pub const ALL: [ConflictStrategy; 4] = [
+        ConflictStrategy::Ask,
+        ConflictStrategy::LocalFirst,
+        ConflictStrategy::RemoteFirst,
+        ConflictStrategy::Merge,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ConflictStrategy::Ask => "settings.sync.conflict.ask",
+            ConflictStrategy::LocalFirst => "settings.sync.conflict.local_first",
+            ConflictStrategy::RemoteFirst => "settings.sync.conflict.remote_first",
+            ConflictStrategy::Merge => "settings.sync.conflict.merge",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SyncSettings {
     pub enabled: bool,
@@ -414,6 +816,18 @@ pub struct SyncSettings {
     pub webdav_username: String,
     pub webdav_password: String,
     pub webdav_path: String,
+    /// 加密同步归档的口令，只保存在本机、不会发送到服务器（各设备需一致）
+    #[serde(default)]
+    pub sync_passphrase: String,
+    // Git
+    /// 远程仓库地址（如 git@github.com:user/shellmaster-config.git）
+    #[serde(default)]
+    pub git_remote: String,
+    #[serde(default = "default_git_branch")]
+    pub git_branch: String,
+    /// 连接仓库使用的私钥路径（为空时使用 ~/.ssh 下的默认私钥）
+    #[serde(default)]
+    pub git_private_key: String,
     // 同步内容
     pub sync_servers: bool,
     pub sync_groups: bool,
@@ -423,6 +837,16 @@ pub struct SyncSettings {
     // 冲突处理
     pub conflict_strategy: ConflictStrategy,
     pub backup_before_sync: bool,
+    // 团队共享服务器清单
+    /// 共享清单地址：HTTP(S) URL 或本地/挂载路径（JSON 或 YAML），为空表示不加载
+    #[serde(default)]
+    pub inventory_source: String,
+    /// 共享清单的刷新间隔（分钟）
+    #[serde(default = "default_inventory_refresh_minutes")]
+    pub inventory_refresh_minutes: u32,
+    /// 连接共享服务器时默认使用的本地身份（清单条目未指定身份时使用）
+    #[serde(default)]
+    pub inventory_identity_id: Option<String>,
 }
 
 impl Default for SyncSettings {
@@ -436,6 +860,10 @@ impl Default for SyncSettings {
             webdav_username: String::new(),
             webdav_password: String::new(),
             webdav_path: "/shellmaster".to_string(),
+            sync_passphrase: String::new(),
+            git_remote: String::new(),
+            git_branch: default_git_branch(),
+            git_private_key: String::new(),
             sync_servers: true,
             sync_groups: true,
             sync_settings: true,
@@ -443,10 +871,21 @@ impl Default for SyncSettings {
             sync_keys: false,
             conflict_strategy: ConflictStrategy::Ask,
             backup_before_sync: true,
+            inventory_source: String::new(),
+            inventory_refresh_minutes: default_inventory_refresh_minutes(),
+            inventory_identity_id: None,
         }
     }
 }
 
+fn default_git_branch() -> String {
+    "main".to_string()
+}
+
+fn default_inventory_refresh_minutes() -> u32 {
+    15
+}
+
 // ======================== 系统设置 ========================
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
@@ -493,6 +932,9 @@ pub struct SystemSettings {
     pub notify_on_disconnect: bool,
     pub notify_on_transfer: bool,
     pub do_not_disturb: bool,
+    /// 应用处于后台时发送系统通知（传输完成、会话断开、监控告警）
+    #[serde(default = "default_true")]
+    pub system_notifications: bool,
     // 隐私
     pub master_password_enabled: bool,
     pub auto_lock: AutoLockTime,
@@ -502,6 +944,10 @@ pub struct SystemSettings {
     pub logging_enabled: bool,
     pub log_level: LogLevel,
     pub log_retention_days: u32,
+    // 插件
+    /// 已启用的插件（脚本文件名，不含扩展名）
+    #[serde(default)]
+    pub enabled_plugins: Vec<String>,
 }
 
 impl Default for SystemSettings {
@@ -519,6 +965,7 @@ impl Default for SystemSettings {
             notify_on_disconnect: true,
             notify_on_transfer: true,
             do_not_disturb: false,
+            system_notifications: true,
             master_password_enabled: false,
             auto_lock: AutoLockTime::Never,
             history_retention: HistoryRetention::Forever,
@@ -526,6 +973,7 @@ impl Default for SystemSettings {
             logging_enabled: true,
             log_level: LogLevel::Info,
             log_retention_days: 7,
+            enabled_plugins: Vec::new(),
         }
     }
 }
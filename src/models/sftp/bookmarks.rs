@@ -0,0 +1,69 @@
+// SFTP 目录收藏夹、最近访问的目录与最近编辑的文件
+// 按服务器保存，供 SFTP 工具栏的收藏夹菜单一键跳转或重新打开
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 每个服务器最多保留的最近目录/最近文件数量
+const MAX_RECENT: usize = 10;
+
+/// 单个服务器的收藏目录、最近目录和最近编辑的文件
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ServerBookmarks {
+    /// 收藏的目录（按收藏顺序）
+    #[serde(default)]
+    pub bookmarks: Vec<String>,
+    /// 最近访问的目录（最新的在前）
+    #[serde(default)]
+    pub recent: Vec<String>,
+    /// 最近在编辑器中打开的文件（最新的在前）
+    #[serde(default)]
+    pub recent_files: Vec<String>,
+}
+
+impl ServerBookmarks {
+    /// 目录是否已收藏
+    pub fn is_bookmarked(&self, path: &str) -> bool {
+        self.bookmarks.iter().any(|p| p == path)
+    }
+
+    /// 收藏或取消收藏目录，返回操作后是否处于收藏状态
+    pub fn toggle_bookmark(&mut self, path: &str) -> bool {
+        if self.is_bookmarked(path) {
+            self.bookmarks.retain(|p| p != path);
+            false
+        } else {
+            self.bookmarks.push(path.to_string());
+            true
+        }
+    }
+
+    /// 记录最近访问的目录，返回列表是否有变化
+    pub fn record_recent(&mut self, path: &str) -> bool {
+        push_recent(&mut self.recent, path)
+    }
+
+    /// 记录最近编辑的文件，返回列表是否有变化
+    pub fn record_recent_file(&mut self, path: &str) -> bool {
+        push_recent(&mut self.recent_files, path)
+    }
+}
+
+/// 把路径移到列表最前面并截断到上限，返回列表是否有变化
+fn push_recent(list: &mut Vec<String>, path: &str) -> bool {
+    if list.first().is_some_and(|p| p == path) {
+        return false;
+    }
+    list.retain(|p| p != path);
+    list.insert(0, path.to_string());
+    list.truncate(MAX_RECENT);
+    true
+}
+
+/// 所有服务器的收藏夹（server_id -> 收藏夹）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SftpBookmarks {
+    #[serde(default)]
+    pub servers: HashMap<String, ServerBookmarks>,
+}
@@ -0,0 +1,125 @@
+// SFTP 传输历史记录
+// 已完成或失败的传输持久化到磁盘，供传输面板的"历史"页查看和重新传输
+
+use std::path::PathBuf;
+
+use chrono::{Duration, Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use super::TransferItem;
+
+/// 历史记录时间格式
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 传输历史条目
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TransferHistoryEntry {
+    /// 唯一标识符（沿用传输项 ID）
+    pub id: String,
+    /// 服务器 ID
+    pub server_id: String,
+    /// 服务器名称
+    pub server_label: String,
+    /// 是否是上传（false 表示下载）
+    pub is_upload: bool,
+    /// 远程文件路径
+    pub remote_path: String,
+    /// 本地文件路径
+    pub local_path: PathBuf,
+    /// 文件大小
+    pub size: u64,
+    /// 是否成功
+    pub succeeded: bool,
+    /// 失败原因
+    #[serde(default)]
+    pub error: Option<String>,
+    /// 结束时间
+    pub finished_at: String,
+}
+
+impl TransferHistoryEntry {
+    /// 根据结束的传输项创建历史条目
+    pub fn from_transfer(item: &TransferItem, server_id: &str, server_label: &str) -> Self {
+        Self {
+            id: item.id.clone(),
+            server_id: server_id.to_string(),
+            server_label: server_label.to_string(),
+            is_upload: item.is_upload,
+            remote_path: item.remote_path.clone(),
+            local_path: item.local_path.clone(),
+            size: item.progress.total_bytes,
+            succeeded: item.status.is_complete(),
+            error: item.error.clone(),
+            finished_at: Local::now().format(TIME_FORMAT).to_string(),
+        }
+    }
+
+    /// 文件名
+    pub fn file_name(&self) -> String {
+        self.remote_path
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.remote_path)
+            .to_string()
+    }
+}
+
+/// 传输历史记录（最新的在前）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TransferHistory {
+    pub entries: Vec<TransferHistoryEntry>,
+}
+
+impl TransferHistory {
+    /// 删除早于保留天数的记录，返回是否有记录被删除
+    pub fn prune(&mut self, retention_days: u32) -> bool {
+        let cutoff = Local::now().naive_local() - Duration::days(retention_days as i64);
+        let before = self.entries.len();
+        self.entries.retain(|e| {
+            NaiveDateTime::parse_from_str(&e.finished_at, TIME_FORMAT)
+                .map(|t| t >= cutoff)
+                .unwrap_or(true)
+        });
+        self.entries.len() != before
+    }
+}
+
+/// 传输历史的筛选条件
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransferHistoryFilter {
+    #[default]
+    All,
+    Uploads,
+    Downloads,
+    Failed,
+}
+
+impl TransferHistoryFilter {
+    /// 所有筛选条件（按显示顺序）
+    pub const ALL: [TransferHistoryFilter; 4] = [
+        TransferHistoryFilter::All,
+        TransferHistoryFilter::Uploads,
+        TransferHistoryFilter::Downloads,
+        TransferHistoryFilter::Failed,
+    ];
+
+    /// 条目是否符合筛选条件
+    pub fn matches(&self, entry: &TransferHistoryEntry) -> bool {
+        match self {
+            TransferHistoryFilter::All => true,
+            TransferHistoryFilter::Uploads => entry.is_upload,
+            TransferHistoryFilter::Downloads => !entry.is_upload,
+            TransferHistoryFilter::Failed => !entry.succeeded,
+        }
+    }
+
+    /// 显示文本的 i18n 键
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            TransferHistoryFilter::All => "transfer.history.filter.all",
+            TransferHistoryFilter::Uploads => "transfer.history.filter.uploads",
+            TransferHistoryFilter::Downloads => "transfer.history.filter.downloads",
+            TransferHistoryFilter::Failed => "transfer.history.filter.failed",
+        }
+    }
+}
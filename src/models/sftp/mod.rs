@@ -1,9 +1,13 @@
 // SFTP 数据模型
 
+pub mod bookmarks;
+pub mod history;
 pub mod state;
 pub mod transfer;
 pub mod types;
 
+pub use bookmarks::{ServerBookmarks, SftpBookmarks};
+pub use history::{TransferHistory, TransferHistoryEntry, TransferHistoryFilter};
 pub use state::SftpState;
-pub use transfer::{TransferItem, TransferStatus};
-pub use types::{FileEntry, FileType};
+pub use transfer::{ArchiveOp, TransferItem, TransferOptions, TransferStatus};
+pub use types::{DirLoadError, FileEntry, FileType};
@@ -2,7 +2,9 @@
 
 use std::collections::{HashMap, HashSet};
 
-use super::types::{CachedDir, DirCache, FileEntry, NavigationHistory};
+use tokio_util::sync::CancellationToken;
+
+use super::types::{CachedDir, DirCache, DirLoadError, FileEntry, NavigationHistory};
 
 /// SFTP 状态（每个 SessionTab 独立）
 #[derive(Debug, Clone, Default)]
@@ -31,12 +33,39 @@ pub struct SftpState {
     /// 是否正在加载
     pub loading: bool,
 
+    /// 当前目录分批加载中已显示的条目数（None 表示未在分批加载）
+    pub loaded_count: Option<usize>,
+
+    /// 当前目录分批加载的取消令牌
+    pub listing_cancel: Option<CancellationToken>,
+
+    /// 目录监视的取消令牌（Some 表示已开启监视）
+    pub watch_cancel: Option<CancellationToken>,
+
+    /// 当前一轮目录监视的取消令牌（切换目录时取消，立即改为监视新目录）
+    pub watch_cycle: Option<CancellationToken>,
+
     /// 错误信息
     pub error: Option<String>,
 
+    /// 当前目录读取失败的信息
+    pub load_error: Option<DirLoadError>,
+
+    /// 当前列表是否通过 sudo 列出（SFTP 无权读取，仅供查看）
+    pub sudo_listing: bool,
+
+    /// 服务器禁用了 SFTP 子系统，文件操作通过 SCP 与 shell 命令完成
+    pub scp_fallback: bool,
+
     /// 是否显示隐藏文件
     pub show_hidden: bool,
 
+    /// 是否显示本地面板（双面板模式）
+    pub dual_pane: bool,
+
+    /// 是否显示文件预览面板
+    pub preview_open: bool,
+
     /// 用户主目录路径
     pub home_dir: String,
 
@@ -49,6 +78,11 @@ pub struct SftpState {
     pub user_cache_revision: u64,
     /// 组缓存版本号（用于 UI 增量同步）
     pub group_cache_revision: u64,
+
+    /// 待定位并选中的文件路径（如搜索结果跳转）
+    pub reveal_path: Option<String>,
+    /// 定位请求版本号（用于 UI 增量同步）
+    pub reveal_revision: u64,
 }
 
 impl SftpState {
@@ -110,6 +144,12 @@ impl SftpState {
     fn set_path_internal(&mut self, path: String) {
         self.current_path = path;
         self.error = None;
+        self.load_error = None;
+        self.sudo_listing = false;
+        self.cancel_listing();
+        if let Some(cycle) = self.watch_cycle.take() {
+            cycle.cancel();
+        }
     }
 
     /// 导航到指定路径（记录历史）
@@ -194,6 +234,12 @@ impl SftpState {
         self.file_list_revision = self.file_list_revision.wrapping_add(1);
     }
 
+    /// 请求在文件列表中定位并选中指定文件
+    pub fn request_reveal(&mut self, path: String) {
+        self.reveal_path = Some(path);
+        self.reveal_revision = self.reveal_revision.wrapping_add(1);
+    }
+
     /// 从文件列表中移除指定路径的文件（乐观更新）
     /// 返回被移除的文件条目及其索引（用于失败时恢复）
     pub fn remove_file_from_list(&mut self, path: &str) -> Option<(usize, FileEntry)> {
@@ -286,6 +332,67 @@ impl SftpState {
         self.loading = false;
     }
 
+    /// 记录当前目录读取失败
+    pub fn set_load_error(&mut self, error: DirLoadError) {
+        self.load_error = Some(error);
+        self.loading = false;
+        self.finish_listing();
+    }
+
+    /// 显示目录内容并清除读取错误；`via_sudo` 表示内容来自 sudo 列出
+    pub fn set_listing(&mut self, entries: Vec<FileEntry>, via_sudo: bool) {
+        self.load_error = None;
+        self.sudo_listing = via_sudo;
+        self.finish_listing();
+        self.update_file_list(entries);
+    }
+
+    /// 开始分批加载当前目录（取消上一次未完成的分批加载）
+    pub fn begin_listing(&mut self, cancel_token: CancellationToken) {
+        self.cancel_listing();
+        self.listing_cancel = Some(cancel_token);
+    }
+
+    /// 追加分批加载到的条目；第一批替换原有列表
+    pub fn append_listing(&mut self, entries: Vec<FileEntry>) {
+        let loaded = match self.loaded_count {
+            Some(count) => count + entries.len(),
+            None => {
+                self.file_list.clear();
+                self.load_error = None;
+                self.sudo_listing = false;
+                entries.len()
+            }
+        };
+        self.loaded_count = Some(loaded);
+        let show_hidden = self.show_hidden;
+        self.file_list.extend(
+            entries
+                .into_iter()
+                .filter(|e| show_hidden || !e.is_hidden()),
+        );
+        self.file_list_revision = self.file_list_revision.wrapping_add(1);
+    }
+
+    /// 取消未完成的分批加载（已加载的条目保留）
+    pub fn cancel_listing(&mut self) {
+        if let Some(token) = self.listing_cancel.take() {
+            token.cancel();
+        }
+        self.loaded_count = None;
+    }
+
+    /// 结束分批加载状态
+    pub fn finish_listing(&mut self) {
+        self.listing_cancel = None;
+        self.loaded_count = None;
+    }
+
+    /// 是否开启了目录监视
+    pub fn is_watching(&self) -> bool {
+        self.watch_cancel.is_some()
+    }
+
     /// 设置主目录
     pub fn set_home_dir(&mut self, home: String) {
         self.home_dir = home;
@@ -2,7 +2,7 @@
 // 定义传输状态、进度和传输项
 
 use std::path::PathBuf;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::sync::Arc;
 
 use tokio_util::sync::CancellationToken;
@@ -33,16 +33,16 @@ impl Default for TransferStatus {
 }
 
 impl TransferStatus {
-    /// 获取状态的显示文本
-    pub fn display_text(&self) -> &'static str {
+    /// 状态显示文本的 i18n 键
+    pub fn label_key(&self) -> &'static str {
         match self {
-            TransferStatus::Pending => "等待中",
-            TransferStatus::Downloading => "下载中",
-            TransferStatus::Uploading => "上传中",
-            TransferStatus::Paused => "已暂停",
-            TransferStatus::Completed => "完成",
-            TransferStatus::Failed => "失败",
-            TransferStatus::Cancelled => "已取消",
+            TransferStatus::Pending => "transfer.status.pending",
+            TransferStatus::Downloading => "transfer.status.downloading",
+            TransferStatus::Uploading => "transfer.status.uploading",
+            TransferStatus::Paused => "transfer.status.paused",
+            TransferStatus::Completed => "transfer.status.completed",
+            TransferStatus::Failed => "transfer.status.failed",
+            TransferStatus::Cancelled => "transfer.status.cancelled",
         }
     }
 
@@ -110,6 +110,24 @@ impl TransferStatus {
     }
 }
 
+/// 远程归档操作（以传输项的形式展示进度，进度单位为条目数）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveOp {
+    /// 压缩
+    Compress,
+    /// 解压
+    Extract,
+}
+
+/// 单次传输的可选处理（默认值来自 SFTP 设置，可在冲突对话框中按文件修改）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferOptions {
+    /// 使用 gzip 压缩数据流传输
+    pub compress: bool,
+    /// 下载归档后自动解压（仅对下载有效）
+    pub extract: bool,
+}
+
 /// 传输进度
 #[derive(Debug, Clone, Default)]
 pub struct TransferProgress {
@@ -166,6 +184,24 @@ pub struct TransferItem {
     pub cancel_token: CancellationToken,
     /// 暂停标志
     pub pause_flag: Arc<AtomicBool>,
+    /// 单个传输的限速（字节/秒，0 表示不限速），传输过程中可随时调整
+    pub speed_limit: Arc<AtomicU64>,
+    /// 跨服务器复制的目标（服务器名称, 目标路径），本地与远程之间的传输为 None
+    pub remote_target: Option<(String, String)>,
+    /// 远程归档操作，普通传输为 None
+    pub archive_op: Option<ArchiveOp>,
+    /// 断点续传的起点（开始传输时 `.part` 部分文件中已有的字节数）
+    pub resume_offset: u64,
+    /// 失败或取消后是否保留了 `.part` 部分文件，可以断点续传
+    pub resumable: bool,
+    /// 传输完成后是否已通过完整性校验
+    pub verified: bool,
+    /// 是否是文件夹传输（进度为文件夹内所有文件的汇总）
+    pub is_folder: bool,
+    /// 文件夹传输中各文件的传输项，普通传输为空
+    pub children: Vec<TransferItem>,
+    /// 文件夹传输是否在列表中展开各文件明细
+    pub expanded: bool,
 }
 
 impl TransferItem {
@@ -181,6 +217,15 @@ impl TransferItem {
             is_upload: false,
             cancel_token: CancellationToken::new(),
             pause_flag: Arc::new(AtomicBool::new(false)),
+            speed_limit: Arc::new(AtomicU64::new(0)),
+            remote_target: None,
+            archive_op: None,
+            resume_offset: 0,
+            resumable: false,
+            verified: false,
+            is_folder: false,
+            children: Vec::new(),
+            expanded: false,
         }
     }
 
@@ -196,6 +241,142 @@ impl TransferItem {
             is_upload: true,
             cancel_token: CancellationToken::new(),
             pause_flag: Arc::new(AtomicBool::new(false)),
+            speed_limit: Arc::new(AtomicU64::new(0)),
+            remote_target: None,
+            archive_op: None,
+            resume_offset: 0,
+            resumable: false,
+            verified: false,
+            is_folder: false,
+            children: Vec::new(),
+            expanded: false,
+        }
+    }
+
+    /// 创建新的跨服务器复制项（`remote_path` 为源服务器上的路径）
+    pub fn new_remote_copy(
+        source_path: String,
+        target_server: String,
+        target_path: String,
+        total_bytes: u64,
+    ) -> Self {
+        Self {
+            remote_target: Some((target_server, target_path)),
+            ..Self::new_download(source_path, PathBuf::new(), total_bytes)
+        }
+    }
+
+    /// 创建新的远程归档项（`remote_path` 为归档文件路径）
+    pub fn new_archive(op: ArchiveOp, archive_path: String) -> Self {
+        Self {
+            archive_op: Some(op),
+            ..Self::new_download(archive_path, PathBuf::new(), 0)
+        }
+    }
+
+    /// 创建文件夹传输项，进度由 `children` 中各文件的传输项汇总
+    ///
+    /// 各文件共享文件夹的暂停标志和限速，取消文件夹时所有文件一并取消
+    pub fn new_folder(
+        is_upload: bool,
+        remote_path: String,
+        local_path: PathBuf,
+        children: Vec<TransferItem>,
+    ) -> Self {
+        let total_bytes = children.iter().map(|c| c.progress.total_bytes).sum();
+        let mut folder = if is_upload {
+            Self::new_upload(local_path, remote_path, total_bytes)
+        } else {
+            Self::new_download(remote_path, local_path, total_bytes)
+        };
+        folder.children = children
+            .into_iter()
+            .map(|mut child| {
+                child.cancel_token = folder.cancel_token.child_token();
+                child.pause_flag = folder.pause_flag.clone();
+                child.speed_limit = folder.speed_limit.clone();
+                child
+            })
+            .collect();
+        folder.is_folder = true;
+        folder
+    }
+
+    /// 实际传输的文件：文件夹传输返回各文件的传输项，普通传输返回自身
+    pub fn files(&self) -> &[TransferItem] {
+        if self.is_folder {
+            &self.children
+        } else {
+            std::slice::from_ref(self)
+        }
+    }
+
+    /// 查找文件夹传输中的文件传输项
+    pub fn child_mut(&mut self, id: &str) -> Option<&mut TransferItem> {
+        self.children.iter_mut().find(|c| c.id == id)
+    }
+
+    /// 文件夹传输中已完成的文件数和文件总数
+    pub fn file_counts(&self) -> (usize, usize) {
+        let completed = self
+            .children
+            .iter()
+            .filter(|c| c.status.is_complete())
+            .count();
+        (completed, self.children.len())
+    }
+
+    /// 根据各文件的进度和状态汇总文件夹传输的进度和状态
+    ///
+    /// 所有文件结束后，全部成功则文件夹完成，否则标记为失败
+    pub fn refresh_folder(&mut self) {
+        if !self.is_folder {
+            return;
+        }
+        self.progress.bytes_transferred = self
+            .children
+            .iter()
+            .map(|c| c.progress.bytes_transferred)
+            .sum();
+        self.progress.total_bytes = self.children.iter().map(|c| c.progress.total_bytes).sum();
+        if self.status.is_terminal() {
+            return;
+        }
+        self.progress.speed_bytes_per_sec = if self.status == TransferStatus::Paused {
+            0
+        } else {
+            self.children
+                .iter()
+                .filter(|c| c.status.is_active())
+                .map(|c| c.progress.speed_bytes_per_sec)
+                .sum()
+        };
+
+        if self.children.iter().all(|c| c.status.is_terminal()) {
+            let failed = self.children.iter().filter(|c| c.status.is_error()).count();
+            if failed == 0 {
+                self.set_completed();
+                self.verified = self.children.iter().all(|c| c.verified);
+            } else {
+                let lang = crate::services::storage::load_settings()
+                    .map(|s| s.theme.language)
+                    .unwrap_or_default();
+                self.set_failed(
+                    crate::i18n::t(&lang, "transfer.folder.failed_files")
+                        .replace("{}", &failed.to_string()),
+                );
+            }
+        } else if self.status == TransferStatus::Pending
+            && self
+                .children
+                .iter()
+                .any(|c| c.status.is_active() && c.status != TransferStatus::Pending)
+        {
+            self.status = if self.is_upload {
+                TransferStatus::Uploading
+            } else {
+                TransferStatus::Downloading
+            };
         }
     }
 
@@ -205,12 +386,47 @@ impl TransferItem {
         self.error = Some(error);
     }
 
+    /// 失败或取消后标记部分文件已保留，可以断点续传
+    ///
+    /// 只有本地与远程之间的单个文件传输支持续传
+    pub fn mark_resumable(&mut self, resume: bool) {
+        self.resumable =
+            resume && !self.is_folder && self.remote_target.is_none() && self.archive_op.is_none();
+    }
+
+    /// 是否可以从部分文件继续传输
+    pub fn can_resume(&self) -> bool {
+        self.resumable && self.status.is_error()
+    }
+
+    /// 本次传输是否从部分文件续传，返回续传起点
+    pub fn resumed_from(&self) -> Option<u64> {
+        let is_plain = self.remote_target.is_none() && self.archive_op.is_none();
+        (is_plain && self.resume_offset > 0).then_some(self.resume_offset)
+    }
+
     /// 设置完成状态
     pub fn set_completed(&mut self) {
         self.status = TransferStatus::Completed;
         self.progress.complete();
     }
 
+    /// 标记传输完成后已通过完整性校验
+    pub fn mark_verified(&mut self, verified: bool) {
+        self.verified = verified && self.status.is_complete();
+    }
+
+    /// 单个传输的限速（字节/秒，0 表示不限速）
+    pub fn speed_limit(&self) -> u64 {
+        self.speed_limit.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// 设置单个传输的限速，立即对进行中的传输生效
+    pub fn set_speed_limit(&mut self, bytes_per_sec: u64) {
+        self.speed_limit
+            .store(bytes_per_sec, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// 更新进度（不改变状态）
     /// 这是安全的进度更新方法，只更新进度数据，不修改状态
     pub fn update_progress(&mut self, transferred: u64, total: u64, speed: u64) {
@@ -227,8 +443,10 @@ impl TransferItem {
             self.progress.speed_bytes_per_sec = speed;
 
             // 如果是 Pending 状态，自动切换到 Downloading
+            // 传输开始时会先回报一次续传起点，作为首次进度记录下来
             if self.status == TransferStatus::Pending {
                 self.status = TransferStatus::Downloading;
+                self.resume_offset = transferred;
             }
         }
     }
@@ -41,6 +41,8 @@ pub struct FileEntry {
     pub uid: Option<u32>,
     /// 所有者组 ID
     pub gid: Option<u32>,
+    /// 符号链接指向的路径
+    pub link_target: Option<String>,
 }
 
 impl FileEntry {
@@ -55,6 +57,7 @@ impl FileEntry {
             permissions: 0,
             uid: None,
             gid: None,
+            link_target: None,
         }
     }
 
@@ -201,5 +204,16 @@ impl NavigationHistory {
     }
 }
 
+/// 目录读取失败信息（文件列表据此显示错误与恢复操作）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirLoadError {
+    /// 读取失败的目录
+    pub path: String,
+    /// 错误信息
+    pub message: String,
+    /// 是否因权限不足失败（可尝试以 sudo 列出）
+    pub permission_denied: bool,
+}
+
 /// 目录缓存管理器
 pub type DirCache = HashMap<String, CachedDir>;
@@ -1,7 +1,13 @@
 // Snippets (快捷命令) 数据模型
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use super::runbook::Runbook;
+use super::server::{ServerConfig, ServerData};
+use super::terminal_macro::TerminalMacro;
+
 /// 单个快捷命令
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SnippetCommand {
@@ -11,6 +17,18 @@ pub struct SnippetCommand {
     pub description: Option<String>,
     pub group_id: Option<String>, // 所属命令组 ID，None 表示未分组
     pub created_at: String,
+    /// 标签（用于筛选和搜索）
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// 颜色标记（0xRRGGBB），None 表示不标记
+    #[serde(default)]
+    pub color: Option<u32>,
+    /// 可见范围（限定的服务器、分组或标签）
+    #[serde(default)]
+    pub scope: SnippetScope,
+    /// 连接范围内的服务器后自动执行
+    #[serde(default)]
+    pub auto_run: bool,
 }
 
 impl Default for SnippetCommand {
@@ -22,10 +40,47 @@ impl Default for SnippetCommand {
             description: None,
             group_id: None,
             created_at: String::new(),
+            tags: Vec::new(),
+            color: None,
+            scope: SnippetScope::default(),
+            auto_run: false,
         }
     }
 }
 
+/// 快捷命令的可见范围：匹配任一服务器、服务器分组（含子分组）或服务器标签即可见，全部为空时对所有服务器可见
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetScope {
+    #[serde(default)]
+    pub server_ids: Vec<String>,
+    #[serde(default)]
+    pub group_ids: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl SnippetScope {
+    /// 是否未限定范围
+    pub fn is_empty(&self) -> bool {
+        self.server_ids.is_empty() && self.group_ids.is_empty() && self.tags.is_empty()
+    }
+
+    /// 服务器是否在范围内（`servers` 用于判断子分组）
+    pub fn matches(&self, server: &ServerData, servers: &ServerConfig) -> bool {
+        self.is_empty()
+            || self.server_ids.contains(&server.id)
+            || server.group_id.as_deref().is_some_and(|group_id| {
+                self.group_ids
+                    .iter()
+                    .any(|ancestor| servers.is_within(group_id, ancestor))
+            })
+            || server
+                .tags
+                .iter()
+                .any(|tag| self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    }
+}
+
 /// 命令组 (支持嵌套)
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SnippetGroup {
@@ -48,11 +103,96 @@ impl Default for SnippetGroup {
     }
 }
 
+/// 命令中的变量占位符：`{{name}}` 或带默认值的 `{{name:default}}`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnippetVariable {
+    pub name: String,
+    pub default: Option<String>,
+}
+
+/// 解析命令中的变量占位符（按首次出现的顺序去重，默认值取第一个写了默认值的占位符）
+pub fn parse_variables(command: &str) -> Vec<SnippetVariable> {
+    let mut variables: Vec<SnippetVariable> = Vec::new();
+    for_each_placeholder(command, |name, default| {
+        match variables.iter_mut().find(|v| v.name == name) {
+            Some(existing) => {
+                if existing.default.is_none() {
+                    existing.default = default.map(str::to_string);
+                }
+            }
+            None => variables.push(SnippetVariable {
+                name: name.to_string(),
+                default: default.map(str::to_string),
+            }),
+        }
+        None
+    });
+    variables
+}
+
+/// 用填写的值替换命令中的变量占位符（未填写的变量使用默认值，没有默认值时替换为空）
+pub fn fill_variables(command: &str, values: &HashMap<String, String>) -> String {
+    for_each_placeholder(command, |name, default| {
+        Some(
+            values
+                .get(name)
+                .map(String::as_str)
+                .or(default)
+                .unwrap_or_default()
+                .to_string(),
+        )
+    })
+}
+
+/// 遍历 `{{name}}` / `{{name:default}}` 占位符，`f` 返回 Some 时替换该占位符，返回替换后的命令
+///
+/// 变量名只允许字母、数字、`_`、`-` 和 `.`，其他 `{{...}}`（如 Go 模板）原样保留
+fn for_each_placeholder(
+    command: &str,
+    mut f: impl FnMut(&str, Option<&str>) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(command.len());
+    let mut rest = command;
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = &after[..end];
+        let (name, default) = match inner.split_once(':') {
+            Some((name, default)) => (name.trim(), Some(default)),
+            None => (inner.trim(), None),
+        };
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        match valid.then(|| f(name, default)).flatten() {
+            Some(replacement) => result.push_str(&replacement),
+            None => result.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
 /// Snippets 配置根结构
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct SnippetsConfig {
     pub groups: Vec<SnippetGroup>,
     pub commands: Vec<SnippetCommand>,
+    /// 各服务器上次填写的变量值（服务器 ID -> 变量名 -> 值）
+    #[serde(default)]
+    pub variable_values: HashMap<String, HashMap<String, String>>,
+    /// Runbook 列表
+    #[serde(default)]
+    pub runbooks: Vec<Runbook>,
+    /// 键盘宏列表
+    #[serde(default)]
+    pub macros: Vec<TerminalMacro>,
 }
 
 impl SnippetsConfig {
@@ -95,6 +235,121 @@ impl SnippetsConfig {
             .map(|g| g.name.as_str())
     }
 
+    /// 只保留对该服务器可见的命令；原本有命令但全部被隐藏的组也一并隐藏
+    pub fn visible_for(&self, server: &ServerData, servers: &ServerConfig) -> SnippetsConfig {
+        let mut config = self.clone();
+        config
+            .commands
+            .retain(|command| command.scope.matches(server, servers));
+        let has_commands = |config: &SnippetsConfig, group_id: &str| {
+            config.commands.iter().any(|c| {
+                c.group_id
+                    .as_deref()
+                    .is_some_and(|id| config.is_within(id, group_id))
+            })
+        };
+        let groups = self
+            .groups
+            .iter()
+            .filter(|g| !has_commands(self, &g.id) || has_commands(&config, &g.id))
+            .cloned()
+            .collect();
+        config.groups = groups;
+        config
+    }
+
+    /// 连接服务器后自动执行的命令（每条以换行结尾），没有时返回 None
+    ///
+    /// 变量使用上次在该服务器上填写的值，没有时使用默认值
+    pub fn auto_run_script(&self, server: &ServerData, servers: &ServerConfig) -> Option<String> {
+        let values = self
+            .variable_values
+            .get(&server.id)
+            .cloned()
+            .unwrap_or_default();
+        let commands: Vec<String> = self
+            .commands
+            .iter()
+            .filter(|c| c.auto_run && c.scope.matches(server, servers))
+            .map(|c| fill_variables(c.command.trim(), &values))
+            .filter(|c| !c.is_empty())
+            .collect();
+        if commands.is_empty() {
+            None
+        } else {
+            Some(format!("{}\n", commands.join("\n")))
+        }
+    }
+
+    /// 判断 `group_id` 是否为 `ancestor_id` 本身或其子孙组
+    pub fn is_within(&self, group_id: &str, ancestor_id: &str) -> bool {
+        let mut current = Some(group_id);
+        while let Some(id) = current {
+            if id == ancestor_id {
+                return true;
+            }
+            current = self
+                .groups
+                .iter()
+                .find(|g| g.id == id)
+                .and_then(|g| g.parent_id.as_deref());
+        }
+        false
+    }
+
+    /// 把命令移到 `group_id`（None 为未分组）；`before_id` 指定时排在该命令之前，否则排在末尾
+    ///
+    /// 返回是否已移动
+    pub fn move_command(
+        &mut self,
+        command_id: &str,
+        group_id: Option<String>,
+        before_id: Option<&str>,
+    ) -> bool {
+        if before_id == Some(command_id) {
+            return false;
+        }
+        let Some(pos) = self.commands.iter().position(|c| c.id == command_id) else {
+            return false;
+        };
+        let mut command = self.commands.remove(pos);
+        command.group_id = group_id;
+        let index = before_id
+            .and_then(|before| self.commands.iter().position(|c| c.id == before))
+            .unwrap_or(self.commands.len());
+        self.commands.insert(index, command);
+        true
+    }
+
+    /// 把命令组移到 `parent_id` 下；`before_id` 指定时排在该组之前，否则排在同级末尾
+    ///
+    /// 不允许移动到自身或子孙组下，返回是否已移动
+    pub fn move_group(
+        &mut self,
+        group_id: &str,
+        parent_id: Option<String>,
+        before_id: Option<&str>,
+    ) -> bool {
+        if before_id == Some(group_id) {
+            return false;
+        }
+        if let Some(parent) = &parent_id {
+            if self.is_within(parent, group_id) {
+                return false;
+            }
+        }
+        let Some(pos) = self.groups.iter().position(|g| g.id == group_id) else {
+            return false;
+        };
+        let mut group = self.groups.remove(pos);
+        group.parent_id = parent_id;
+        let index = before_id
+            .and_then(|before| self.groups.iter().position(|g| g.id == before))
+            .unwrap_or(self.groups.len());
+        self.groups.insert(index, group);
+        true
+    }
+
     /// 构建面包屑路径（从根到当前组的名称列表）
     pub fn build_breadcrumb(&self, current_path: &[String]) -> Vec<(String, String)> {
         current_path
@@ -106,3 +361,95 @@ impl SnippetsConfig {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_containing_colon() {
+        let command = "curl {{url:http://localhost:8080/api}}";
+        assert_eq!(
+            parse_variables(command),
+            vec![SnippetVariable {
+                name: "url".to_string(),
+                default: Some("http://localhost:8080/api".to_string()),
+            }]
+        );
+        assert_eq!(
+            fill_variables(command, &HashMap::new()),
+            "curl http://localhost:8080/api"
+        );
+        assert_eq!(
+            fill_variables(command, &values(&[("url", "https://example.com")])),
+            "curl https://example.com"
+        );
+    }
+
+    #[test]
+    fn test_unterminated_placeholder() {
+        assert!(parse_variables("echo {{name").is_empty());
+        assert_eq!(
+            fill_variables("echo {{name", &HashMap::new()),
+            "echo {{name"
+        );
+        assert_eq!(
+            fill_variables("echo {{a}} {{b", &values(&[("a", "1"), ("b", "2")])),
+            "echo 1 {{b"
+        );
+        assert_eq!(fill_variables("echo }} {{", &HashMap::new()), "echo }} {{");
+    }
+
+    #[test]
+    fn test_repeated_variables() {
+        let command = "scp {{file}} {{host}}:{{file:backup.tar}}";
+        assert_eq!(
+            parse_variables(command),
+            vec![
+                SnippetVariable {
+                    name: "file".to_string(),
+                    default: Some("backup.tar".to_string()),
+                },
+                SnippetVariable {
+                    name: "host".to_string(),
+                    default: None,
+                },
+            ]
+        );
+        assert_eq!(
+            fill_variables(command, &values(&[("file", "a.txt"), ("host", "web")])),
+            "scp a.txt web:a.txt"
+        );
+    }
+
+    #[test]
+    fn test_empty_names_are_kept() {
+        for command in ["echo {{}}", "echo {{ }}", "echo {{:default}}"] {
+            assert!(parse_variables(command).is_empty());
+            assert_eq!(fill_variables(command, &values(&[("", "x")])), command);
+        }
+    }
+
+    #[test]
+    fn test_invalid_names_are_kept() {
+        let command = "docker ps --format '{{json .}}' {{name}}";
+        assert_eq!(
+            parse_variables(command),
+            vec![SnippetVariable {
+                name: "name".to_string(),
+                default: None,
+            }]
+        );
+        assert_eq!(
+            fill_variables(command, &values(&[("name", "web")])),
+            "docker ps --format '{{json .}}' web"
+        );
+    }
+}
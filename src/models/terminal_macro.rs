@@ -0,0 +1,65 @@
+// 键盘宏数据模型：在终端中录制的按键序列，可命名、编辑并绑定快捷键回放
+
+use serde::{Deserialize, Serialize};
+
+/// 键盘宏
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TerminalMacro {
+    pub id: String,
+    pub name: String,
+    /// 录制的按键序列（发送到 PTY 的原始字符，含控制字符与转义序列）
+    pub keys: String,
+    /// 绑定的快捷键（GPUI 按键格式，如 `ctrl-alt-m`），None 表示不绑定
+    #[serde(default)]
+    pub keybinding: Option<String>,
+    pub created_at: String,
+}
+
+/// 把按键序列转换为可编辑的文本：控制字符写成 `\r`、`\t`、`\e`、`\xNN`，反斜杠写成 `\\`
+pub fn escape_keys(keys: &str) -> String {
+    let mut text = String::with_capacity(keys.len());
+    for c in keys.chars() {
+        match c {
+            '\\' => text.push_str("\\\\"),
+            '\r' => text.push_str("\\r"),
+            '\n' => text.push_str("\\n"),
+            '\t' => text.push_str("\\t"),
+            '\x1b' => text.push_str("\\e"),
+            c if c.is_control() && (c as u32) < 0x80 => {
+                text.push_str(&format!("\\x{:02x}", c as u32))
+            }
+            c => text.push(c),
+        }
+    }
+    text
+}
+
+/// 解析 `escape_keys` 生成的文本，格式错误时返回出错的位置（从 1 开始的字符序号）
+pub fn unescape_keys(text: &str) -> Result<String, usize> {
+    let mut keys = String::with_capacity(text.len());
+    let mut chars = text.chars().enumerate();
+    while let Some((index, c)) = chars.next() {
+        if c != '\\' {
+            keys.push(c);
+            continue;
+        }
+        match chars.next().map(|(_, c)| c) {
+            Some('\\') => keys.push('\\'),
+            Some('r') => keys.push('\r'),
+            Some('n') => keys.push('\n'),
+            Some('t') => keys.push('\t'),
+            Some('e') => keys.push('\x1b'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).map(|(_, c)| c).collect();
+                let byte = (hex.len() == 2)
+                    .then(|| u8::from_str_radix(&hex, 16).ok())
+                    .flatten()
+                    .filter(u8::is_ascii)
+                    .ok_or(index + 1)?;
+                keys.push(byte as char);
+            }
+            _ => return Err(index + 1),
+        }
+    }
+    Ok(keys)
+}
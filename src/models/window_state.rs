@@ -0,0 +1,86 @@
+// 窗口状态：主窗口的位置、大小、最大化/全屏状态、所在显示器与会话面板布局，启动时恢复
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 会话侧边栏的默认宽度
+pub const DEFAULT_SESSION_SIDEBAR_WIDTH: f32 = 230.;
+/// Monitor 面板的默认宽度
+pub const DEFAULT_MONITOR_PANEL_WIDTH: f32 = 229.;
+/// SFTP 面板的默认高度
+pub const DEFAULT_SFTP_PANEL_HEIGHT: f32 = 300.;
+
+/// 窗口位置与大小（屏幕坐标）
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct SavedWindowBounds {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// 会话视图中可拖动调整的面板尺寸、侧边栏位置与折叠状态
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PanelLayout {
+    #[serde(default = "default_session_sidebar_width")]
+    pub session_sidebar_width: f32,
+    #[serde(default = "default_monitor_panel_width")]
+    pub monitor_panel_width: f32,
+    #[serde(default = "default_sftp_panel_height")]
+    pub sftp_panel_height: f32,
+    #[serde(default)]
+    pub sidebar_collapsed: bool,
+    /// 会话侧边栏位于左侧（默认位于右侧）
+    #[serde(default)]
+    pub sidebar_left: bool,
+    /// 折叠 Monitor 面板，终端占满上方区域
+    #[serde(default)]
+    pub monitor_collapsed: bool,
+}
+
+fn default_session_sidebar_width() -> f32 {
+    DEFAULT_SESSION_SIDEBAR_WIDTH
+}
+
+fn default_monitor_panel_width() -> f32 {
+    DEFAULT_MONITOR_PANEL_WIDTH
+}
+
+fn default_sftp_panel_height() -> f32 {
+    DEFAULT_SFTP_PANEL_HEIGHT
+}
+
+impl Default for PanelLayout {
+    fn default() -> Self {
+        Self {
+            session_sidebar_width: DEFAULT_SESSION_SIDEBAR_WIDTH,
+            monitor_panel_width: DEFAULT_MONITOR_PANEL_WIDTH,
+            sftp_panel_height: DEFAULT_SFTP_PANEL_HEIGHT,
+            sidebar_collapsed: false,
+            sidebar_left: false,
+            monitor_collapsed: false,
+        }
+    }
+}
+
+/// 主窗口状态
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct WindowState {
+    /// 窗口化时的位置与大小（最大化、全屏时为还原后的位置与大小）
+    #[serde(default)]
+    pub bounds: Option<SavedWindowBounds>,
+    #[serde(default)]
+    pub maximized: bool,
+    #[serde(default)]
+    pub fullscreen: bool,
+    /// 窗口所在显示器的 UUID，显示器已断开时回到主显示器
+    #[serde(default)]
+    pub display_uuid: Option<String>,
+    /// 最近一次调整的面板布局，没有单独记录布局的服务器使用该布局
+    #[serde(default)]
+    pub layout: PanelLayout,
+    /// 按服务器 ID 记录的面板布局
+    #[serde(default)]
+    pub server_layouts: HashMap<String, PanelLayout>,
+}
@@ -0,0 +1,54 @@
+// 会话工作区：保存打开的标签（服务器、终端数量、SFTP 目录）与面板布局，用于之后恢复
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::server::ServerData;
+use crate::state::{MonitorPanelView, SidebarPanel, TabColor};
+
+/// 工作区中的一个会话标签
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WorkspaceTab {
+    pub server_id: String,
+    pub server_label: String,
+    /// 未保存在服务器列表中的连接（临时连接、共享清单）的连接数据
+    #[serde(default)]
+    pub server_data: Option<ServerData>,
+    /// 终端数量
+    pub terminal_count: u32,
+    /// SFTP 当前目录
+    #[serde(default)]
+    pub sftp_path: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub color: TabColor,
+}
+
+/// 会话工作区
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: String,
+    pub name: String,
+    /// 标签按显示顺序排列
+    pub tabs: Vec<WorkspaceTab>,
+    /// 激活标签的下标
+    #[serde(default)]
+    pub active_index: Option<usize>,
+    #[serde(default)]
+    pub sidebar_panel: SidebarPanel,
+    #[serde(default)]
+    pub sidebar_collapsed: bool,
+    #[serde(default)]
+    pub monitor_panel_view: MonitorPanelView,
+    pub saved_at: String,
+}
+
+/// 工作区存储
+#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+pub struct WorkspacesConfig {
+    /// 用户保存的工作区
+    pub workspaces: Vec<Workspace>,
+    /// 上次退出时打开的会话
+    #[serde(default)]
+    pub last_session: Option<Workspace>,
+}
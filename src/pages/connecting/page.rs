@@ -376,10 +376,7 @@ pub fn render_connecting_page(
                             };
 
                             // 获取标签
-                            let label = match lang {
-                                Language::Chinese => stage.label_zh(),
-                                Language::English => stage.label_en(),
-                            };
+                            let label = i18n::t(&lang, stage.label_key());
 
                             // 简化标签，如果是长文本可以考虑截断或换行，这里暂时通过 CSS 处理
                             // 7 个节点，每个节点的宽度大约是 1/7，文字需要居中
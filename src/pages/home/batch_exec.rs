@@ -0,0 +1,802 @@
+// 批量执行页面 - 选择多台已保存的服务器（无论是否已连接），并行执行同一条命令或快捷命令，
+// 以表格展示每台主机的退出码与耗时，点击行展开标准输出/错误，并可导出为 CSV
+
+use std::collections::HashSet;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::{ActiveTheme, Sizable, StyledExt};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::ServerData;
+use crate::models::settings::Language;
+use crate::services::batch_exec::{
+    default_file_name, export_csv, run_on_host, BatchTarget, ExportRow, HostResult, MAX_PARALLEL,
+};
+use crate::services::storage;
+use crate::state::{SessionState, SessionStatus};
+
+/// 一台主机的执行状态
+pub struct HostRun {
+    pub server_id: String,
+    pub label: String,
+    pub address: String,
+    /// 执行结果（None 表示执行中或已取消）
+    pub result: Option<HostResult>,
+}
+
+/// 批量执行页面状态
+#[derive(Default)]
+pub struct BatchExecPageState {
+    /// 选中的服务器 ID
+    pub selected: HashSet<String>,
+    pub command_input: Option<Entity<InputState>>,
+    /// 最近一次执行的命令
+    pub last_command: String,
+    /// 最近一次执行的各主机状态
+    pub runs: Vec<HostRun>,
+    /// 展开输出的服务器 ID
+    pub expanded: HashSet<String>,
+    /// 执行中任务的取消令牌
+    cancel_token: Option<CancellationToken>,
+    /// 执行序号（忽略已取消的旧任务回传的结果）
+    run_id: u64,
+    /// 最近一次导出的结果（成功为文件路径）
+    pub export_result: Option<Result<String, String>>,
+}
+
+impl BatchExecPageState {
+    /// 确保命令输入框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.command_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "batch.command_placeholder");
+            self.command_input = Some(cx.new(|cx| {
+                InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .auto_grow(2, 6)
+            }));
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.cancel_token.is_some()
+    }
+
+    fn toggle(&mut self, server_id: String) {
+        if !self.selected.remove(&server_id) {
+            self.selected.insert(server_id);
+        }
+    }
+
+    fn toggle_expanded(&mut self, server_id: String) {
+        if !self.expanded.remove(&server_id) {
+            self.expanded.insert(server_id);
+        }
+    }
+
+    /// 在选中的服务器上并行执行命令
+    fn run(&mut self, session_state: &Entity<SessionState>, cx: &mut Context<Self>) {
+        if self.is_running() {
+            return;
+        }
+        let Some(input) = &self.command_input else {
+            return;
+        };
+        let command = input.read(cx).value().trim().to_string();
+        if command.is_empty() || self.selected.is_empty() {
+            return;
+        }
+
+        // 已连接的服务器复用对应标签页的会话
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let tabs = &session_state.read(cx).tabs;
+        let servers = storage::load_servers().unwrap_or_default().servers;
+        let targets: Vec<BatchTarget> = servers
+            .into_iter()
+            .filter(|server| self.selected.contains(&server.id))
+            .map(|server| {
+                let session = tabs
+                    .iter()
+                    .find(|tab| {
+                        tab.server_id == server.id && tab.status == SessionStatus::Connected
+                    })
+                    .and_then(|tab| ssh_manager.get_session(&tab.id));
+                BatchTarget { server, session }
+            })
+            .collect();
+        if targets.is_empty() {
+            return;
+        }
+        info!("[Batch] Running on {} servers: {}", targets.len(), command);
+
+        self.runs = targets
+            .iter()
+            .map(|target| HostRun {
+                server_id: target.server.id.clone(),
+                label: target.server.label.clone(),
+                address: server_address(&target.server),
+                result: None,
+            })
+            .collect();
+        self.expanded.clear();
+        self.export_result = None;
+        self.last_command = command.clone();
+        let token = CancellationToken::new();
+        self.cancel_token = Some(token.clone());
+        self.run_id += 1;
+        let run_id = self.run_id;
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<(usize, HostResult)>();
+        ssh_manager.runtime().spawn(async move {
+            let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(MAX_PARALLEL));
+            for (index, target) in targets.into_iter().enumerate() {
+                let tx = tx.clone();
+                let token = token.clone();
+                let semaphore = semaphore.clone();
+                let command = command.clone();
+                tokio::spawn(async move {
+                    tokio::select! {
+                        _ = token.cancelled() => {}
+                        result = async {
+                            let _permit = semaphore.acquire().await;
+                            run_on_host(target, &command).await
+                        } => {
+                            let _ = tx.send((index, result));
+                        }
+                    }
+                });
+            }
+        });
+
+        cx.spawn(async move |this, cx| {
+            while let Some((index, result)) = rx.recv().await {
+                let updated = this.update(cx, |this, cx| {
+                    if this.run_id != run_id {
+                        return;
+                    }
+                    if let Some(run) = this.runs.get_mut(index) {
+                        run.result = Some(result);
+                    }
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
+                }
+            }
+            // 全部主机完成（或已取消）
+            let _ = this.update(cx, |this, cx| {
+                if this.run_id == run_id {
+                    this.cancel_token = None;
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+        cx.notify();
+    }
+
+    /// 取消执行（未完成的主机保持无结果）
+    fn cancel(&mut self, cx: &mut Context<Self>) {
+        if let Some(token) = self.cancel_token.take() {
+            token.cancel();
+        }
+        cx.notify();
+    }
+
+    /// 生成 CSV 内容（仅包含已完成的主机）
+    fn csv(&self) -> String {
+        let rows: Vec<ExportRow> = self
+            .runs
+            .iter()
+            .filter_map(|run| {
+                run.result.as_ref().map(|result| ExportRow {
+                    label: &run.label,
+                    address: &run.address,
+                    result,
+                })
+            })
+            .collect();
+        export_csv(&self.last_command, &rows)
+    }
+}
+
+fn server_address(server: &ServerData) -> String {
+    format!("{}@{}:{}", server.username, server.host, server.port)
+}
+
+/// 耗时：不足 1 秒显示毫秒
+fn format_duration(duration: std::time::Duration) -> String {
+    if duration.as_secs() == 0 {
+        format!("{} ms", duration.as_millis())
+    } else {
+        format!("{:.1} s", duration.as_secs_f32())
+    }
+}
+
+/// 渲染批量执行内容区域
+pub fn render_batch_exec_content(
+    state: Entity<BatchExecPageState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let servers = storage::load_servers().unwrap_or_default().servers;
+    let connected: HashSet<String> = session_state
+        .read(cx)
+        .tabs
+        .iter()
+        .filter(|tab| tab.status == SessionStatus::Connected)
+        .map(|tab| tab.server_id.clone())
+        .collect();
+
+    let page = state.read(cx);
+    let total = page.runs.len();
+    let done = page.runs.iter().filter(|r| r.result.is_some()).count();
+    let failed = page
+        .runs
+        .iter()
+        .filter(|r| r.result.as_ref().is_some_and(|r| !r.is_success()))
+        .count();
+
+    div()
+        .size_full()
+        .p_6()
+        .flex()
+        .flex_col()
+        .gap_4()
+        // 标题
+        .child(
+            div()
+                .flex()
+                .items_baseline()
+                .gap_3()
+                .child(
+                    div()
+                        .text_lg()
+                        .font_semibold()
+                        .text_color(cx.theme().foreground)
+                        .child(i18n::t(&lang, "batch.title")),
+                )
+                .when(total > 0, |this| {
+                    this.child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(
+                                i18n::t(&lang, "batch.summary")
+                                    .replace("{done}", &done.to_string())
+                                    .replace("{total}", &total.to_string())
+                                    .replace("{failed}", &failed.to_string()),
+                            ),
+                    )
+                }),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_h(px(0.))
+                .flex()
+                .gap_4()
+                .child(render_server_picker(
+                    state.clone(),
+                    &servers,
+                    &connected,
+                    &lang,
+                    cx,
+                ))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .gap_3()
+                        .child(render_command_bar(state.clone(), session_state, &lang, cx))
+                        .children(page.export_result.clone().map(|result| {
+                            let (text, color) = match result {
+                                Ok(path) => (
+                                    i18n::t(&lang, "batch.export_done").replace("{}", &path),
+                                    cx.theme().muted_foreground,
+                                ),
+                                Err(e) => (
+                                    i18n::t(&lang, "batch.export_failed").replace("{}", &e),
+                                    cx.theme().danger,
+                                ),
+                            };
+                            div().text_xs().text_color(color).child(text)
+                        }))
+                        .child(render_results(state.clone(), &lang, cx)),
+                ),
+        )
+}
+
+/// 渲染左侧服务器选择列表
+fn render_server_picker(
+    state: Entity<BatchExecPageState>,
+    servers: &[ServerData],
+    connected: &HashSet<String>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let selected = state.read(cx).selected.clone();
+    let all_ids: Vec<String> = servers.iter().map(|s| s.id.clone()).collect();
+    let hover_bg = cx.theme().muted;
+    let state_for_all = state.clone();
+    let state_for_none = state.clone();
+
+    div()
+        .w(px(260.))
+        .flex_shrink_0()
+        .h_full()
+        .flex()
+        .flex_col()
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .bg(cx.theme().popover)
+        .child(
+            div()
+                .px_3()
+                .py_2()
+                .flex()
+                .items_center()
+                .gap_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    div()
+                        .flex_1()
+                        .text_sm()
+                        .font_medium()
+                        .text_color(cx.theme().foreground)
+                        .child(
+                            i18n::t(lang, "batch.servers")
+                                .replace("{}", &selected.len().to_string()),
+                        ),
+                )
+                .child(
+                    div()
+                        .id("batch-select-all")
+                        .text_xs()
+                        .text_color(cx.theme().primary)
+                        .cursor_pointer()
+                        .child(i18n::t(lang, "batch.select_all"))
+                        .on_click(move |_, _, cx| {
+                            state_for_all.update(cx, |s, cx| {
+                                s.selected.extend(all_ids.iter().cloned());
+                                cx.notify();
+                            });
+                        }),
+                )
+                .child(
+                    div()
+                        .id("batch-select-none")
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .cursor_pointer()
+                        .child(i18n::t(lang, "batch.select_none"))
+                        .on_click(move |_, _, cx| {
+                            state_for_none.update(cx, |s, cx| {
+                                s.selected.clear();
+                                cx.notify();
+                            });
+                        }),
+                ),
+        )
+        .child(
+            div()
+                .id("batch-server-list")
+                .flex_1()
+                .overflow_y_scroll()
+                .p_1()
+                .when(servers.is_empty(), |this| {
+                    this.child(
+                        div()
+                            .p_3()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(lang, "batch.empty_servers")),
+                    )
+                })
+                .children(servers.iter().map(|server| {
+                    let checked = selected.contains(&server.id);
+                    let online = connected.contains(&server.id);
+                    let server_id = server.id.clone();
+                    let state = state.clone();
+                    div()
+                        .id(SharedString::from(format!("batch-server-{}", server.id)))
+                        .px_2()
+                        .py_1p5()
+                        .rounded_md()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(hover_bg))
+                        .on_click(move |_, _, cx| {
+                            state.update(cx, |s, cx| {
+                                s.toggle(server_id.clone());
+                                cx.notify();
+                            });
+                        })
+                        .child(render_checkbox(checked, cx))
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .flex()
+                                .flex_col()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(server.label.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(format!("{}@{}", server.username, server.host)),
+                                ),
+                        )
+                        .when(online, |this| {
+                            this.child(
+                                div()
+                                    .size(px(6.))
+                                    .flex_shrink_0()
+                                    .rounded_full()
+                                    .bg(cx.theme().success),
+                            )
+                        })
+                })),
+        )
+}
+
+/// 渲染复选框
+fn render_checkbox(checked: bool, cx: &App) -> impl IntoElement {
+    div()
+        .size(px(16.))
+        .flex_shrink_0()
+        .flex()
+        .items_center()
+        .justify_center()
+        .rounded(px(3.))
+        .border_1()
+        .when(checked, |this| {
+            this.bg(cx.theme().primary)
+                .border_color(cx.theme().primary)
+                .child(
+                    svg()
+                        .path(icons::CHECK)
+                        .size(px(12.))
+                        .text_color(cx.theme().primary_foreground),
+                )
+        })
+        .when(!checked, |this| this.border_color(cx.theme().border))
+}
+
+/// 渲染命令输入与操作按钮
+fn render_command_bar(
+    state: Entity<BatchExecPageState>,
+    session_state: Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let page = state.read(cx);
+    let running = page.is_running();
+    let can_run = !page.selected.is_empty();
+    let can_export = page.runs.iter().any(|r| r.result.is_some()) && !running;
+    let input = page.command_input.clone();
+    let snippets = storage::load_snippets().unwrap_or_default().commands;
+    let no_snippets = i18n::t(lang, "batch.no_snippets");
+    let export_title = i18n::t(lang, "batch.export_csv");
+
+    let state_for_run = state.clone();
+    let state_for_export = state.clone();
+    let input_for_snippets = input.clone();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .children(input.map(|input| Input::new(&input)))
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(
+                    Button::new("batch-snippets")
+                        .small()
+                        .outline()
+                        .child(i18n::t(lang, "batch.snippet"))
+                        .dropdown_menu(move |menu, _, _| {
+                            if snippets.is_empty() {
+                                return menu.item(PopupMenuItem::new(no_snippets).disabled(true));
+                            }
+                            let mut menu = menu.min_w(px(240.));
+                            for snippet in &snippets {
+                                let command = snippet.command.clone();
+                                let input = input_for_snippets.clone();
+                                menu =
+                                    menu.item(PopupMenuItem::new(snippet.name.clone()).on_click(
+                                        move |_, window, cx| {
+                                            if let Some(input) = &input {
+                                                input.update(cx, |s, cx| {
+                                                    s.set_value(command.clone(), window, cx)
+                                                });
+                                            }
+                                        },
+                                    ));
+                            }
+                            menu
+                        }),
+                )
+                .child(div().flex_1())
+                .child(
+                    Button::new("batch-export")
+                        .small()
+                        .outline()
+                        .child(export_title)
+                        .disabled(!can_export)
+                        .on_click(move |_, _, cx| {
+                            let content = state_for_export.read(cx).csv();
+                            let state = state_for_export.clone();
+                            cx.spawn(async move |cx| {
+                                let picker = rfd::AsyncFileDialog::new()
+                                    .set_title(export_title)
+                                    .set_file_name(default_file_name())
+                                    .add_filter("CSV", &["csv"]);
+                                let Some(handle) = picker.save_file().await else {
+                                    return;
+                                };
+                                let path = handle.path().to_path_buf();
+                                let result = std::fs::write(&path, content)
+                                    .map(|_| path.display().to_string())
+                                    .map_err(|e| e.to_string());
+                                let _ = state.update(cx, |s, cx| {
+                                    s.export_result = Some(result);
+                                    cx.notify();
+                                });
+                            })
+                            .detach();
+                        }),
+                )
+                .child(if running {
+                    Button::new("batch-cancel")
+                        .small()
+                        .danger()
+                        .child(i18n::t(lang, "common.cancel"))
+                        .on_click(move |_, _, cx| {
+                            state_for_run.update(cx, |s, cx| s.cancel(cx));
+                        })
+                } else {
+                    Button::new("batch-run")
+                        .small()
+                        .primary()
+                        .child(i18n::t(lang, "batch.run"))
+                        .disabled(!can_run)
+                        .on_click(move |_, _, cx| {
+                            state_for_run.update(cx, |s, cx| s.run(&session_state, cx));
+                        })
+                }),
+        )
+}
+
+/// 渲染结果表格
+fn render_results(
+    state: Entity<BatchExecPageState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let page = state.read(cx);
+    let running = page.is_running();
+    let border = cx.theme().border;
+    let muted = cx.theme().muted_foreground;
+    let hover_bg = cx.theme().muted;
+
+    let header = div()
+        .px_3()
+        .py_1p5()
+        .flex()
+        .items_center()
+        .gap_3()
+        .border_b_1()
+        .border_color(border)
+        .text_xs()
+        .font_medium()
+        .text_color(muted)
+        .child(div().flex_1().child(i18n::t(lang, "batch.col.server")))
+        .child(div().w(px(140.)).child(i18n::t(lang, "batch.col.status")))
+        .child(
+            div()
+                .w(px(80.))
+                .text_right()
+                .child(i18n::t(lang, "batch.col.duration")),
+        );
+
+    let rows: Vec<AnyElement> = page
+        .runs
+        .iter()
+        .map(|run| {
+            let expanded = page.expanded.contains(&run.server_id);
+            let (status, color) = match &run.result {
+                Some(result) => match (&result.error, result.exit_code) {
+                    (Some(_), _) => (i18n::t(lang, "batch.failed").to_string(), cx.theme().danger),
+                    (None, Some(0)) => (
+                        i18n::t(lang, "batch.exit_code").replace("{}", "0"),
+                        cx.theme().success,
+                    ),
+                    (None, code) => (
+                        i18n::t(lang, "batch.exit_code")
+                            .replace("{}", &code.map(|c| c.to_string()).unwrap_or_default()),
+                        cx.theme().danger,
+                    ),
+                },
+                None if running => (i18n::t(lang, "batch.running").to_string(), muted),
+                None => (i18n::t(lang, "batch.cancelled").to_string(), muted),
+            };
+            let duration = run
+                .result
+                .as_ref()
+                .map(|r| format_duration(r.duration))
+                .unwrap_or_default();
+            let server_id = run.server_id.clone();
+            let state = state.clone();
+
+            div()
+                .flex()
+                .flex_col()
+                .border_b_1()
+                .border_color(border.opacity(0.5))
+                .child(
+                    div()
+                        .id(SharedString::from(format!("batch-row-{}", run.server_id)))
+                        .px_3()
+                        .py_1p5()
+                        .flex()
+                        .items_center()
+                        .gap_3()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(hover_bg))
+                        .on_click(move |_, _, cx| {
+                            state.update(cx, |s, cx| {
+                                s.toggle_expanded(server_id.clone());
+                                cx.notify();
+                            });
+                        })
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .child(
+                                    svg()
+                                        .path(if expanded {
+                                            icons::CHEVRON_DOWN
+                                        } else {
+                                            icons::CHEVRON_RIGHT
+                                        })
+                                        .size(px(12.))
+                                        .text_color(muted),
+                                )
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().foreground)
+                                        .child(run.label.clone()),
+                                )
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(muted)
+                                        .overflow_hidden()
+                                        .whitespace_nowrap()
+                                        .text_ellipsis()
+                                        .child(run.address.clone()),
+                                ),
+                        )
+                        .child(div().w(px(140.)).text_xs().text_color(color).child(status))
+                        .child(
+                            div()
+                                .w(px(80.))
+                                .text_right()
+                                .text_xs()
+                                .text_color(muted)
+                                .child(duration),
+                        ),
+                )
+                .when(expanded, |this| this.child(render_output(run, lang, cx)))
+                .into_any_element()
+        })
+        .collect();
+
+    div()
+        .id("batch-results")
+        .flex_1()
+        .min_h(px(0.))
+        .overflow_y_scroll()
+        .rounded_lg()
+        .border_1()
+        .border_color(border)
+        .bg(cx.theme().popover)
+        .child(header)
+        .when(rows.is_empty(), |this| {
+            this.child(
+                div()
+                    .p_4()
+                    .text_sm()
+                    .text_color(muted)
+                    .child(i18n::t(lang, "batch.no_results")),
+            )
+        })
+        .children(rows)
+}
+
+/// 渲染一台主机的输出（错误、标准输出与标准错误）
+fn render_output(run: &HostRun, lang: &Language, cx: &App) -> impl IntoElement {
+    let Some(result) = &run.result else {
+        return div().into_any_element();
+    };
+    let empty = result.error.is_none() && result.stdout.is_empty() && result.stderr.is_empty();
+
+    div()
+        .mx_3()
+        .mb_2()
+        .p_2()
+        .rounded_md()
+        .bg(cx.theme().muted.opacity(0.5))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .text_xs()
+        .font_family("monospace")
+        .children(
+            result
+                .error
+                .clone()
+                .map(|e| div().text_color(cx.theme().danger).child(e)),
+        )
+        .when(!result.stdout.is_empty(), |this| {
+            this.child(
+                div()
+                    .text_color(cx.theme().foreground)
+                    .whitespace_normal()
+                    .child(result.stdout.trim_end().to_string()),
+            )
+        })
+        .when(!result.stderr.is_empty(), |this| {
+            this.child(
+                div()
+                    .text_color(cx.theme().danger)
+                    .child(result.stderr.trim_end().to_string()),
+            )
+        })
+        .when(empty, |this| {
+            this.child(
+                div()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(i18n::t(lang, "batch.no_output")),
+            )
+        })
+        .into_any_element()
+}
@@ -0,0 +1,514 @@
+// 全局命令面板（Cmd+K）：模糊搜索各模块提供的命令并仅用键盘执行
+// 命令来源：全局 action、主页菜单、设置区域、会话工作区、服务器、快捷命令、SFTP 收藏目录与会话标签
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::ActiveTheme;
+
+use super::sidebar::MenuType;
+use crate::components::common::icon::render_icon;
+use crate::components::common::settings_dialog::SettingsSection;
+use crate::constants::icons;
+use crate::i18n;
+use crate::keybindings::{
+    palette_actions, DismissCommandPalette, SelectNextCommand, SelectPrevCommand,
+    COMMAND_PALETTE_CONTEXT,
+};
+use crate::models::settings::Language;
+use crate::services::server_search;
+use crate::services::sftp::SftpBookmarkStore;
+use crate::services::storage;
+use crate::state::{SessionState, SessionStatus};
+
+/// 最多显示的结果数量
+const MAX_RESULTS: usize = 50;
+
+/// 命令面板中可执行的命令
+pub enum PaletteCommand {
+    /// 分发已注册的全局 action
+    Action(Box<dyn Action>),
+    /// 连接已保存的服务器
+    ConnectServer { id: String, label: String },
+    /// 切换到主页菜单
+    OpenMenu(MenuType),
+    /// 打开设置并定位到指定区域
+    OpenSettings(SettingsSection),
+    /// 打开会话工作区弹窗
+    OpenWorkspaces,
+    /// 在当前终端执行快捷命令
+    RunSnippet(String),
+    /// 在标签的 SFTP 面板中打开收藏目录
+    OpenSftpPath { tab_id: String, path: String },
+    /// 用编辑器重新打开标签服务器上最近编辑的文件
+    OpenSftpFile { tab_id: String, path: String },
+    /// 切换到会话标签
+    SwitchTab(String),
+}
+
+impl Clone for PaletteCommand {
+    fn clone(&self) -> Self {
+        match self {
+            PaletteCommand::Action(action) => PaletteCommand::Action(action.boxed_clone()),
+            PaletteCommand::ConnectServer { id, label } => PaletteCommand::ConnectServer {
+                id: id.clone(),
+                label: label.clone(),
+            },
+            PaletteCommand::OpenMenu(menu) => PaletteCommand::OpenMenu(*menu),
+            PaletteCommand::OpenSettings(section) => PaletteCommand::OpenSettings(*section),
+            PaletteCommand::OpenWorkspaces => PaletteCommand::OpenWorkspaces,
+            PaletteCommand::RunSnippet(command) => PaletteCommand::RunSnippet(command.clone()),
+            PaletteCommand::OpenSftpPath { tab_id, path } => PaletteCommand::OpenSftpPath {
+                tab_id: tab_id.clone(),
+                path: path.clone(),
+            },
+            PaletteCommand::OpenSftpFile { tab_id, path } => PaletteCommand::OpenSftpFile {
+                tab_id: tab_id.clone(),
+                path: path.clone(),
+            },
+            PaletteCommand::SwitchTab(tab_id) => PaletteCommand::SwitchTab(tab_id.clone()),
+        }
+    }
+}
+
+/// 命令面板中的一项
+pub struct PaletteEntry {
+    pub icon: &'static str,
+    pub title: String,
+    /// 附加信息（主机、命令内容、路径等），同样参与搜索
+    pub subtitle: Option<String>,
+    /// 命令类别的 i18n 键
+    pub kind_key: &'static str,
+    pub command: PaletteCommand,
+}
+
+/// 命令面板状态
+#[derive(Default)]
+pub struct CommandPaletteState {
+    pub visible: bool,
+    pub query_input: Option<Entity<InputState>>,
+    /// 打开面板时收集的命令
+    entries: Vec<PaletteEntry>,
+    /// 当前选中项在过滤结果中的下标
+    selected: usize,
+    scroll_handle: ScrollHandle,
+    /// 下次渲染时清空并聚焦搜索框
+    focus_requested: bool,
+    /// 待执行的命令（由主页在渲染时取走并执行）
+    pub pending: Option<PaletteCommand>,
+}
+
+impl CommandPaletteState {
+    /// 打开面板并重新收集各模块的命令
+    pub fn open(&mut self, session: &SessionState) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        self.entries = collect_commands(session, &lang);
+        self.visible = true;
+        self.selected = 0;
+        self.focus_requested = true;
+    }
+
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.entries.clear();
+    }
+
+    /// 确保搜索框已创建，打开时清空并聚焦（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.query_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "palette.placeholder");
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            cx.subscribe_in(
+                &input,
+                window,
+                |this, _, event: &InputEvent, window, cx| match event {
+                    InputEvent::PressEnter { .. } => this.confirm(window, cx),
+                    InputEvent::Change => {
+                        this.selected = 0;
+                        this.scroll_handle.scroll_to_item(0);
+                        cx.notify();
+                    }
+                    _ => {}
+                },
+            )
+            .detach();
+            self.query_input = Some(input);
+        }
+        if std::mem::take(&mut self.focus_requested) {
+            if let Some(input) = &self.query_input {
+                input.update(cx, |input, cx| {
+                    input.set_value("", window, cx);
+                    input.focus(window, cx);
+                });
+            }
+        }
+    }
+
+    fn query(&self, cx: &App) -> String {
+        self.query_input
+            .as_ref()
+            .map(|input| input.read(cx).value().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 过滤后的命令下标（按匹配得分排序，空查询时保持收集顺序）
+    fn filtered(&self, cx: &App) -> Vec<usize> {
+        let terms = server_search::search_terms(&self.query(cx));
+        let mut matches: Vec<(i32, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let mut fields = vec![entry.title.as_str()];
+                fields.extend(entry.subtitle.as_deref());
+                server_search::match_score(&terms, &fields).map(|score| (score, index))
+            })
+            .collect();
+        matches.sort_by_key(|(score, _)| -score);
+        matches
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, index)| index)
+            .collect()
+    }
+
+    /// 上下移动选中项（循环）
+    fn move_selection(&mut self, delta: isize, cx: &App) {
+        let count = self.filtered(cx).len();
+        if count == 0 {
+            return;
+        }
+        self.selected = (self.selected as isize + delta).rem_euclid(count as isize) as usize;
+        self.scroll_handle.scroll_to_item(self.selected);
+    }
+
+    /// 执行选中的命令并关闭面板
+    fn confirm(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(index) = self.filtered(cx).get(self.selected).copied() else {
+            return;
+        };
+        let command = self.entries[index].command.clone();
+        self.close();
+        match command {
+            // 全局 action 从当前焦点分发，由主页或全局处理器响应
+            PaletteCommand::Action(action) => window.dispatch_action(action, cx),
+            command => self.pending = Some(command),
+        }
+        cx.notify();
+    }
+}
+
+/// 收集各模块提供的命令
+fn collect_commands(session: &SessionState, lang: &Language) -> Vec<PaletteEntry> {
+    let mut entries = Vec::new();
+
+    // 会话标签
+    for tab in &session.tabs {
+        entries.push(PaletteEntry {
+            icon: icons::TERMINAL,
+            title: tab.server_label.clone(),
+            subtitle: None,
+            kind_key: "palette.kind.tab",
+            command: PaletteCommand::SwitchTab(tab.id.clone()),
+        });
+    }
+
+    // 全局 action
+    for (label_key, action) in palette_actions() {
+        entries.push(PaletteEntry {
+            icon: icons::COMMAND,
+            title: i18n::t(lang, label_key).to_string(),
+            subtitle: None,
+            kind_key: "palette.kind.action",
+            command: PaletteCommand::Action(action),
+        });
+    }
+    entries.push(PaletteEntry {
+        icon: icons::ARCHIVE,
+        title: i18n::t(lang, "workspace.title").to_string(),
+        subtitle: None,
+        kind_key: "palette.kind.action",
+        command: PaletteCommand::OpenWorkspaces,
+    });
+
+    // 已保存的服务器
+    if let Ok(config) = storage::load_servers() {
+        for server in config.servers {
+            entries.push(PaletteEntry {
+                icon: icons::SERVER,
+                subtitle: Some(format!(
+                    "{}@{}:{}",
+                    server.username, server.host, server.port
+                )),
+                title: server.label.clone(),
+                kind_key: "palette.kind.server",
+                command: PaletteCommand::ConnectServer {
+                    id: server.id,
+                    label: server.label,
+                },
+            });
+        }
+    }
+
+    // 主页菜单
+    for menu in MenuType::ALL {
+        entries.push(PaletteEntry {
+            icon: menu.icon(),
+            title: i18n::t(lang, menu.label_key()).to_string(),
+            subtitle: None,
+            kind_key: "palette.kind.menu",
+            command: PaletteCommand::OpenMenu(menu),
+        });
+    }
+
+    // 设置区域
+    for section in SettingsSection::ALL {
+        entries.push(PaletteEntry {
+            icon: section.icon(),
+            title: i18n::t(lang, section.label_key()).to_string(),
+            subtitle: None,
+            kind_key: "palette.kind.settings",
+            command: PaletteCommand::OpenSettings(section),
+        });
+    }
+
+    // 快捷命令：只列出对当前标签的服务器可见的命令（与会话侧边栏一致）
+    if let Ok(config) = storage::load_snippets() {
+        let config = match session.active_tab().and_then(|t| t.server_data.as_ref()) {
+            Some(server) => {
+                let servers = storage::load_servers().unwrap_or_default();
+                config.visible_for(server, &servers)
+            }
+            None => config,
+        };
+        for snippet in config.commands {
+            entries.push(PaletteEntry {
+                icon: icons::CODE,
+                title: snippet.name,
+                subtitle: Some(snippet.command.clone()),
+                kind_key: "palette.kind.snippet",
+                command: PaletteCommand::RunSnippet(snippet.command),
+            });
+        }
+    }
+
+    // 已连接标签的 SFTP 收藏目录与最近编辑的文件
+    for tab in &session.tabs {
+        if tab.status != SessionStatus::Connected {
+            continue;
+        }
+        let bookmarks = SftpBookmarkStore::global().server(&tab.server_id);
+        for path in bookmarks.bookmarks {
+            entries.push(PaletteEntry {
+                icon: icons::BOOKMARK,
+                title: path.clone(),
+                subtitle: Some(tab.server_label.clone()),
+                kind_key: "palette.kind.bookmark",
+                command: PaletteCommand::OpenSftpPath {
+                    tab_id: tab.id.clone(),
+                    path,
+                },
+            });
+        }
+        for path in bookmarks.recent_files {
+            entries.push(PaletteEntry {
+                icon: icons::FILE_TEXT,
+                title: path.clone(),
+                subtitle: Some(tab.server_label.clone()),
+                kind_key: "palette.kind.recent_file",
+                command: PaletteCommand::OpenSftpFile {
+                    tab_id: tab.id.clone(),
+                    path,
+                },
+            });
+        }
+    }
+
+    entries
+}
+
+/// 渲染命令面板覆盖层
+pub fn render_command_palette(state: Entity<CommandPaletteState>, cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let results = state_read.filtered(cx);
+    let selected = state_read.selected.min(results.len().saturating_sub(1));
+
+    let foreground = cx.theme().foreground;
+    let muted = cx.theme().muted_foreground;
+    let selected_bg = cx.theme().list_active;
+
+    let rows: Vec<AnyElement> = results
+        .iter()
+        .enumerate()
+        .map(|(position, &index)| {
+            let entry = &state_read.entries[index];
+            let is_selected = position == selected;
+            let state = state.clone();
+            div()
+                .id(("palette-item", position))
+                .px_3()
+                .py_2()
+                .rounded_md()
+                .cursor_pointer()
+                .when(is_selected, |this| this.bg(selected_bg))
+                .hover(move |s| s.bg(selected_bg))
+                .flex()
+                .items_center()
+                .gap_3()
+                .on_click(move |_, window, cx| {
+                    state.update(cx, |s, cx| {
+                        s.selected = position;
+                        s.confirm(window, cx);
+                    });
+                })
+                .child(render_icon(entry.icon, muted))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_sm()
+                                .text_color(foreground)
+                                .child(entry.title.clone()),
+                        )
+                        .children(entry.subtitle.clone().map(|subtitle| {
+                            div()
+                                .min_w_0()
+                                .text_xs()
+                                .text_color(muted)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(subtitle)
+                        })),
+                )
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, entry.kind_key)),
+                )
+                .into_any_element()
+        })
+        .collect();
+    let is_empty = rows.is_empty();
+
+    let state_for_backdrop = state.clone();
+    let state_for_prev = state.clone();
+    let state_for_next = state.clone();
+    let state_for_dismiss = state.clone();
+
+    div()
+        .id("command-palette-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.3))
+        .flex()
+        .justify_center()
+        .pt(px(80.))
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            state_for_backdrop.update(cx, |s, cx| {
+                s.close();
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .id("command-palette")
+                .key_context(COMMAND_PALETTE_CONTEXT)
+                .w(px(560.))
+                .max_h(px(480.))
+                .bg(cx.theme().popover)
+                .rounded_lg()
+                .border_1()
+                .border_color(cx.theme().border)
+                .shadow_lg()
+                .flex()
+                .flex_col()
+                .overflow_hidden()
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    cx.stop_propagation();
+                })
+                .on_action(move |_: &SelectPrevCommand, _, cx| {
+                    state_for_prev.update(cx, |s, cx| {
+                        s.move_selection(-1, cx);
+                        cx.notify();
+                    });
+                })
+                .on_action(move |_: &SelectNextCommand, _, cx| {
+                    state_for_next.update(cx, |s, cx| {
+                        s.move_selection(1, cx);
+                        cx.notify();
+                    });
+                })
+                .on_action(move |_: &DismissCommandPalette, _, cx| {
+                    state_for_dismiss.update(cx, |s, cx| {
+                        s.close();
+                        cx.notify();
+                    });
+                })
+                // 搜索框
+                .child(
+                    div()
+                        .p_2()
+                        .border_b_1()
+                        .border_color(cx.theme().border)
+                        .children(
+                            state_read
+                                .query_input
+                                .as_ref()
+                                .map(|input| Input::new(input)),
+                        ),
+                )
+                // 结果列表
+                .child(if is_empty {
+                    div()
+                        .py_6()
+                        .flex()
+                        .justify_center()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "palette.empty"))
+                        .into_any_element()
+                } else {
+                    div()
+                        .id("command-palette-results")
+                        .flex_1()
+                        .min_h_0()
+                        .p_1()
+                        .overflow_y_scroll()
+                        .track_scroll(&state_read.scroll_handle)
+                        .flex()
+                        .flex_col()
+                        .children(rows)
+                        .into_any_element()
+                })
+                // 操作提示
+                .child(
+                    div()
+                        .px_3()
+                        .py_1()
+                        .border_t_1()
+                        .border_color(cx.theme().border)
+                        .text_xs()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "palette.hint")),
+                ),
+        )
+}
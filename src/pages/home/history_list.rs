@@ -0,0 +1,307 @@
+// 连接历史页面 - 按结果筛选、搜索，并可从历史重新连接
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::history::{ConnectionHistoryEntry, ConnectionHistoryFilter};
+use crate::models::settings::Language;
+use crate::services::connection_history::ConnectionHistoryStore;
+use crate::services::inventory::SharedInventory;
+use crate::services::server_search::AdHocTarget;
+use crate::services::storage;
+use crate::state::SessionState;
+
+/// 连接历史页面状态
+#[derive(Default)]
+pub struct HistoryPageState {
+    pub search_input: Option<Entity<InputState>>,
+    pub filter: ConnectionHistoryFilter,
+}
+
+impl HistoryPageState {
+    /// 确保搜索框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.search_input.is_none() {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            let placeholder = i18n::t(&lang, "connection_history.search_placeholder");
+            self.search_input =
+                Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+        }
+    }
+}
+
+/// 从历史记录重新连接：服务器仍在列表或共享清单中则按其配置连接，否则按记录的地址临时连接
+pub fn connect_from_history(
+    entry: &ConnectionHistoryEntry,
+    session_state: &Entity<SessionState>,
+    cx: &mut App,
+) {
+    let config = storage::load_servers().unwrap_or_default();
+    let saved = config.servers.iter().find(|s| s.id == entry.server_id);
+    session_state.update(cx, |state, cx| {
+        match saved {
+            Some(server) => {
+                state.add_tab(server.id.clone(), server.label.clone());
+            }
+            // 共享清单中的服务器按清单与本地身份连接
+            None => match SharedInventory::global().server(&entry.server_id) {
+                Some(server) => {
+                    state.add_adhoc_tab(server);
+                }
+                None => {
+                    let target = AdHocTarget {
+                        username: entry.username.clone(),
+                        host: entry.host.clone(),
+                        port: entry.port,
+                    };
+                    state.add_adhoc_tab(target.to_server_data(None));
+                }
+            },
+        }
+        // 确保 Monitor 详情弹窗状态已创建
+        state.ensure_monitor_detail_dialog(cx);
+    });
+}
+
+/// 格式化持续时长，如 `1h 05m`、`3m 20s`、`12s`
+fn format_duration(duration: chrono::Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// 渲染连接历史内容区域
+pub fn render_history_content(
+    state: Entity<HistoryPageState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let filter = state_read.filter;
+    let query = state_read
+        .search_input
+        .as_ref()
+        .map(|input| input.read(cx).value().to_string())
+        .unwrap_or_default();
+    let entries = ConnectionHistoryStore::global().entries(filter, &query);
+    let muted = cx.theme().muted_foreground;
+
+    div()
+        .flex_1()
+        .h_full()
+        .overflow_hidden()
+        .bg(crate::theme::background_color(cx))
+        .flex()
+        .flex_col()
+        // 头部：筛选 + 搜索 + 清空
+        .child(
+            div()
+                .flex_shrink_0()
+                .p_6()
+                .pb_4()
+                .flex()
+                .items_center()
+                .gap_3()
+                .child(div().flex().items_center().gap_1().children(
+                    ConnectionHistoryFilter::ALL.iter().map(|&f| {
+                        let selected = f == filter;
+                        let state = state.clone();
+                        div()
+                            .id(SharedString::from(format!(
+                                "connection-history-filter-{:?}",
+                                f
+                            )))
+                            .px_2()
+                            .py(px(2.))
+                            .rounded_full()
+                            .border_1()
+                            .cursor_pointer()
+                            .text_xs()
+                            .when(selected, |this| {
+                                this.border_color(cx.theme().primary)
+                                    .text_color(cx.theme().primary)
+                            })
+                            .when(!selected, |this| {
+                                this.border_color(cx.theme().border).text_color(muted)
+                            })
+                            .child(i18n::t(&lang, f.label_key()))
+                            .on_click(move |_, _, cx| {
+                                state.update(cx, |s, cx| {
+                                    s.filter = f;
+                                    cx.notify();
+                                });
+                            })
+                    }),
+                ))
+                .children(state_read.search_input.as_ref().map(|input| {
+                    div().flex_1().child(
+                        Input::new(input)
+                            .small()
+                            .cleanable(true)
+                            .prefix(svg().path(icons::SEARCH).size(px(14.)).text_color(muted)),
+                    )
+                }))
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(format!(
+                            "{} {}",
+                            entries.len(),
+                            i18n::t(&lang, "connection_history.items")
+                        )),
+                )
+                .child(
+                    Button::new("connection-history-clear")
+                        .small()
+                        .ghost()
+                        .child(i18n::t(&lang, "connection_history.clear"))
+                        .on_click({
+                            let state = state.clone();
+                            move |_, _, cx| {
+                                ConnectionHistoryStore::global().clear();
+                                state.update(cx, |_, cx| cx.notify());
+                            }
+                        }),
+                ),
+        )
+        .child(if entries.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_3()
+                .child(render_icon(icons::HISTORY, muted.into()))
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "connection_history.empty")),
+                )
+                .into_any_element()
+        } else {
+            div()
+                .id("connection-history-scroll")
+                .flex_1()
+                .overflow_y_scroll()
+                .px_6()
+                .pb_6()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(entries.into_iter().enumerate().map(|(index, entry)| {
+                    render_history_row(index, entry, session_state.clone(), &lang, cx)
+                }))
+                .into_any_element()
+        })
+}
+
+/// 渲染一条连接历史
+fn render_history_row(
+    index: usize,
+    entry: ConnectionHistoryEntry,
+    session_state: Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let status_color: Hsla = if entry.succeeded {
+        rgb(0x22c55e).into()
+    } else {
+        cx.theme().danger
+    };
+    let duration = match (&entry.ended_at, entry.succeeded) {
+        (None, true) => i18n::t(lang, "connection_history.active").to_string(),
+        _ => entry.duration().map(format_duration).unwrap_or_default(),
+    };
+    let hover_bg = cx.theme().list_hover;
+
+    div()
+        .id(("connection-history-row", index))
+        .px_3()
+        .py_2()
+        .rounded_md()
+        .flex()
+        .items_center()
+        .gap_3()
+        .hover(move |s| s.bg(hover_bg))
+        .child(
+            div()
+                .size_2()
+                .flex_shrink_0()
+                .rounded_full()
+                .bg(status_color),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(entry.server_label.clone()),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(match &entry.error {
+                            Some(error) => format!("{}  ·  {}", entry.address(), error),
+                            None => entry.address(),
+                        }),
+                ),
+        )
+        .child(
+            div()
+                .w(px(150.))
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(muted)
+                .child(entry.started_at.clone()),
+        )
+        .child(
+            div()
+                .w(px(80.))
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(muted)
+                .child(duration),
+        )
+        .child(
+            Button::new(("connection-history-reconnect", index))
+                .xsmall()
+                .ghost()
+                .child(i18n::t(lang, "connection_history.reconnect"))
+                .on_click(move |_, _, cx| {
+                    connect_from_history(&entry, &session_state, cx);
+                }),
+        )
+}
@@ -0,0 +1,310 @@
+// 身份库页面 - 管理命名的私钥 / Agent 身份，并显示引用每个身份的服务器
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::{error, info};
+
+use crate::components::common::icon::render_icon;
+use crate::components::common::identity_dialog::IdentityDialogState;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::{Identity, IdentityKind};
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染身份库内容区域
+pub fn render_identities_content(
+    dialog_state: Entity<IdentityDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let config = storage::load_servers().unwrap_or_default();
+    let muted = cx.theme().muted_foreground;
+    let identities: Vec<(Identity, Vec<String>)> = config
+        .identities
+        .iter()
+        .map(|identity| {
+            let users = config
+                .identity_users(&identity.id)
+                .map(|s| s.label.clone())
+                .collect();
+            (identity.clone(), users)
+        })
+        .collect();
+
+    div()
+        .flex_1()
+        .h_full()
+        .overflow_hidden()
+        .bg(crate::theme::background_color(cx))
+        .flex()
+        .flex_col()
+        // 头部：数量 + 新建
+        .child(
+            div()
+                .flex_shrink_0()
+                .p_6()
+                .pb_4()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(div().text_sm().text_color(muted).child(format!(
+                    "{} {}",
+                    identities.len(),
+                    i18n::t(&lang, "identity.items")
+                )))
+                .child(
+                    Button::new("identity-new")
+                        .small()
+                        .primary()
+                        .child(i18n::t(&lang, "identity.new"))
+                        .on_click({
+                            let dialog_state = dialog_state.clone();
+                            move |_, _, cx| {
+                                dialog_state.update(cx, |s, cx| {
+                                    s.open_new();
+                                    cx.notify();
+                                });
+                            }
+                        }),
+                ),
+        )
+        .child(if identities.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_3()
+                .child(render_icon(icons::KEY, muted.into()))
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .text_center()
+                        .max_w(px(320.))
+                        .child(i18n::t(&lang, "identity.empty")),
+                )
+                .into_any_element()
+        } else {
+            div()
+                .id("identities-scroll")
+                .flex_1()
+                .overflow_y_scroll()
+                .px_6()
+                .pb_6()
+                .flex()
+                .flex_wrap()
+                .gap_4()
+                .children(
+                    identities
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (identity, users))| {
+                            render_identity_card(
+                                index,
+                                identity,
+                                users,
+                                dialog_state.clone(),
+                                &lang,
+                                cx,
+                            )
+                        }),
+                )
+                .into_any_element()
+        })
+}
+
+/// 渲染单个身份卡片
+fn render_identity_card(
+    index: usize,
+    identity: Identity,
+    users: Vec<String>,
+    dialog_state: Entity<IdentityDialogState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let primary = cx.theme().primary;
+    let source = match identity.kind {
+        IdentityKind::KeyFile => identity.private_key_filename.clone().unwrap_or_default(),
+        IdentityKind::Agent => identity
+            .agent_key
+            .clone()
+            .unwrap_or_else(|| i18n::t(lang, "identity.agent_any").to_string()),
+    };
+    let usage = if users.is_empty() {
+        i18n::t(lang, "identity.unused").to_string()
+    } else {
+        format!(
+            "{} · {}",
+            i18n::t(lang, "identity.used_by").replace("{}", &users.len().to_string()),
+            users.join(", ")
+        )
+    };
+    let is_key_file = identity.kind == IdentityKind::KeyFile;
+    let identity_id = identity.id.clone();
+
+    div()
+        .id(("identity-card", index))
+        .w(px(300.))
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .p_4()
+        .hover(move |s| s.border_color(primary.opacity(0.5)).shadow_md())
+        .flex()
+        .flex_col()
+        .gap_3()
+        // 顶部：图标、名称与来源
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .child(
+                    div()
+                        .w_10()
+                        .h_10()
+                        .flex_shrink_0()
+                        .rounded_lg()
+                        .bg(primary.opacity(0.1))
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .child(render_icon(icons::KEY, primary.into())),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .gap(px(2.0))
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(FontWeight::MEDIUM)
+                                .text_color(cx.theme().foreground)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(identity.name.clone()),
+                        )
+                        .child(div().text_xs().text_color(muted).child(
+                            match identity.username.as_str() {
+                                "" => i18n::t(lang, identity.kind.label_key()).to_string(),
+                                username => format!(
+                                    "{} · {}",
+                                    username,
+                                    i18n::t(lang, identity.kind.label_key())
+                                ),
+                            },
+                        )),
+                ),
+        )
+        // 私钥文件或 Agent 密钥
+        .child(
+            div()
+                .px_2()
+                .py_1()
+                .bg(muted.opacity(0.05))
+                .rounded_md()
+                .text_xs()
+                .font_family("monospace")
+                .text_color(muted)
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(source),
+        )
+        // 引用该身份的服务器
+        .child(
+            div()
+                .text_xs()
+                .text_color(muted)
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(usage),
+        )
+        // 操作
+        .child(
+            div()
+                .flex()
+                .justify_end()
+                .gap_1()
+                .child(
+                    Button::new(("identity-edit", index))
+                        .xsmall()
+                        .ghost()
+                        .child(i18n::t(lang, "identity.edit"))
+                        .on_click({
+                            let identity = identity.clone();
+                            move |_, _, cx| {
+                                dialog_state.update(cx, |s, cx| {
+                                    s.open_edit(identity.clone());
+                                    cx.notify();
+                                });
+                            }
+                        }),
+                )
+                .when(is_key_file, |this| {
+                    let identity_id = identity_id.clone();
+                    this.child(
+                        Button::new(("identity-rotate", index))
+                            .xsmall()
+                            .ghost()
+                            .child(i18n::t(lang, "identity.rotate"))
+                            .on_click(move |_, _, cx| {
+                                rotate_key(identity_id.clone(), cx);
+                            }),
+                    )
+                })
+                .child(
+                    Button::new(("identity-delete", index))
+                        .xsmall()
+                        .ghost()
+                        .child(i18n::t(lang, "identity.delete"))
+                        .on_click(
+                            move |_, _, _| match storage::delete_identity(&identity_id) {
+                                Ok(count) => {
+                                    info!("[Identity] Deleted identity, detached {} servers", count)
+                                }
+                                Err(e) => error!("[Identity] Failed to delete identity: {:#}", e),
+                            },
+                        ),
+                ),
+        )
+}
+
+/// 选择新私钥并更换身份的私钥；新私钥的密码需在编辑身份时填写
+fn rotate_key(identity_id: String, cx: &mut App) {
+    let receiver = cx.prompt_for_paths(PathPromptOptions {
+        files: true,
+        directories: false,
+        multiple: false,
+        prompt: Some("Select Private Key File".into()),
+    });
+    cx.spawn(async move |_| {
+        let Ok(Ok(Some(paths))) = receiver.await else {
+            return;
+        };
+        let Some(path) = paths.first() else {
+            return;
+        };
+        let result = storage::store_private_key(path)
+            .and_then(|filename| storage::rotate_identity_key(&identity_id, filename, None));
+        match result {
+            Ok(count) => info!("[Identity] Rotated key, {} servers use the new key", count),
+            Err(e) => error!("[Identity] Failed to rotate key: {:#}", e),
+        }
+    })
+    .detach();
+}
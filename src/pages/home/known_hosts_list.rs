@@ -111,7 +111,7 @@ pub fn render_known_hosts_content(
                 .overflow_y_scroll()
                 .px_6()
                 .pb_6()
-                .child(render_card_grid(state, hosts, colors))
+                .child(render_card_grid(state, hosts, &lang, colors))
                 .into_any_element()
         } else {
             render_empty_state(&lang, colors).into_any_element()
@@ -139,6 +139,7 @@ fn render_header(lang: &Language, colors: CardColors, count: usize) -> impl Into
 fn render_card_grid(
     state: Entity<KnownHostsPageState>,
     hosts: Vec<KnownHost>,
+    lang: &Language,
     colors: CardColors,
 ) -> impl IntoElement {
     div()
@@ -147,7 +148,7 @@ fn render_card_grid(
         .gap_4()
         .children(hosts.into_iter().map(|host| {
             let state_clone = state.clone();
-            render_host_card(state_clone, host, colors)
+            render_host_card(state_clone, host, lang, colors)
         }))
 }
 
@@ -155,6 +156,7 @@ fn render_card_grid(
 fn render_host_card(
     state: Entity<KnownHostsPageState>,
     host: KnownHost,
+    lang: &Language,
     colors: CardColors,
 ) -> impl IntoElement {
     let host_key = host.host.clone();
@@ -256,8 +258,14 @@ fn render_host_card(
                 .justify_between()
                 .text_xs()
                 .text_color(colors.muted_foreground)
-                .child(format!("首次: {}", format_date(&host.first_seen)))
-                .child(format!("最近: {}", format_date(&host.last_used))),
+                .child(
+                    i18n::t(lang, "known_hosts.first_seen")
+                        .replace("{}", &format_date(&host.first_seen)),
+                )
+                .child(
+                    i18n::t(lang, "known_hosts.last_used")
+                        .replace("{}", &format_date(&host.last_used)),
+                ),
         )
 }
 
@@ -0,0 +1,168 @@
+// 会话延迟指示器：标签上显示最近的往返延迟与无响应状态，悬停时显示最近的延迟曲线
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::state::SessionLatency;
+
+/// 低于该延迟（毫秒）显示为良好
+const GOOD_LATENCY_MS: u32 = 150;
+/// 低于该延迟（毫秒）显示为较慢，否则显示为很慢
+const SLOW_LATENCY_MS: u32 = 500;
+/// 延迟曲线的高度
+const GRAPH_HEIGHT: f32 = 40.;
+
+/// 延迟对应的颜色；未应答或无响应显示为红色
+fn latency_color(latency_ms: Option<u32>, cx: &App) -> Hsla {
+    match latency_ms {
+        Some(ms) if ms < GOOD_LATENCY_MS => cx.theme().success,
+        Some(ms) if ms < SLOW_LATENCY_MS => cx.theme().warning,
+        _ => cx.theme().danger,
+    }
+}
+
+/// 悬停提示：最近的延迟曲线与统计
+struct LatencyTooltip {
+    latency: SessionLatency,
+    lang: Language,
+}
+
+impl Render for LatencyTooltip {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let lang = &self.lang;
+        let muted = cx.theme().muted_foreground;
+        let max_ms = self
+            .latency
+            .samples
+            .iter()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(1)
+            .max(1);
+
+        let bars = self
+            .latency
+            .samples
+            .iter()
+            .map(|sample| {
+                // 未应答的测量显示为满高的红色细条
+                let height = match sample {
+                    Some(ms) => (*ms as f32 / max_ms as f32 * GRAPH_HEIGHT).max(2.),
+                    None => GRAPH_HEIGHT,
+                };
+                let opacity = if sample.is_some() { 0.8 } else { 0.4 };
+                div()
+                    .w(px(3.))
+                    .h(px(height))
+                    .rounded_t(px(1.))
+                    .bg(latency_color(*sample, cx).opacity(opacity))
+            })
+            .collect::<Vec<_>>();
+
+        let mut summary = Vec::new();
+        if let Some(ms) = self.latency.latest_ms() {
+            summary.push(i18n::t(lang, "latency.current").replace("{}", &ms.to_string()));
+        }
+        if let Some(ms) = self.latency.average_ms() {
+            summary.push(i18n::t(lang, "latency.average").replace("{}", &ms.to_string()));
+        }
+        let stale_hint = match self.latency.seconds_since_reply() {
+            _ if !self.latency.is_stale() => None,
+            Some(secs) => {
+                Some(i18n::t(lang, "latency.no_reply_for").replace("{}", &secs.to_string()))
+            }
+            None => Some(i18n::t(lang, "latency.never_replied").to_string()),
+        };
+
+        div()
+            .p_2()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .shadow_md()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .text_xs()
+            .text_color(cx.theme().foreground)
+            .child(i18n::t(lang, "latency.title"))
+            .child(
+                div()
+                    .h(px(GRAPH_HEIGHT))
+                    .min_w(px(120.))
+                    .flex()
+                    .items_end()
+                    .gap(px(1.))
+                    .children(bars),
+            )
+            .when(!summary.is_empty(), |this| {
+                this.child(div().text_color(muted).child(summary.join(" · ")))
+            })
+            .when_some(stale_hint, |this, hint| {
+                this.child(div().text_color(cx.theme().danger).child(hint))
+            })
+    }
+}
+
+/// 渲染标签上的延迟指示器（尚未测量时不显示）；`compact` 为 true 时只显示状态点
+pub fn render_latency_indicator(
+    tab_id: &str,
+    latency: &SessionLatency,
+    lang: &Language,
+    compact: bool,
+    cx: &App,
+) -> Option<AnyElement> {
+    if latency.samples.is_empty() {
+        return None;
+    }
+    let stale = latency.is_stale();
+    // 最近一次未应答但还未超过无响应时长时显示上一次应答的延迟
+    let shown_ms = if stale {
+        None
+    } else {
+        latency.samples.iter().rev().flatten().next().copied()
+    };
+    let color = latency_color(shown_ms, cx);
+    let text = match shown_ms {
+        _ if stale => i18n::t(lang, "latency.stale").to_string(),
+        Some(ms) => format!("{}ms", ms),
+        None => String::new(),
+    };
+
+    let latency = latency.clone();
+    let lang = lang.clone();
+    Some(
+        div()
+            .id(SharedString::from(format!("tab-latency-{}", tab_id)))
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(div().size(px(6.)).rounded_full().bg(color))
+            .when(!compact && !text.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(if stale {
+                            color
+                        } else {
+                            cx.theme().muted_foreground
+                        })
+                        .child(text),
+                )
+            })
+            .tooltip(move |_window, cx| {
+                let tooltip = LatencyTooltip {
+                    latency: latency.clone(),
+                    lang: lang.clone(),
+                };
+                cx.new(|_| tooltip).into()
+            })
+            .into_any_element(),
+    )
+}
@@ -1,10 +1,21 @@
 // 主页模块
 
+pub mod batch_exec;
+pub mod command_palette;
+pub mod history_list;
+pub mod identities_list;
 pub mod known_hosts_list;
+pub mod latency_indicator;
+pub mod monitor_dashboard;
+pub mod notification_drawer;
 pub mod page;
+pub mod plugins_list;
+pub mod schedules_list;
+pub mod server_filter;
 pub mod server_list;
 pub mod sidebar;
 pub mod snippets_list;
 pub mod titlebar;
+pub mod trash_list;
 
 pub use page::HomePage;
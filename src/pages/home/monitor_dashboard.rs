@@ -0,0 +1,418 @@
+// 主机监控总览 - 以紧凑卡片展示所有已连接（可选包括未连接）服务器的健康状态
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::switch::Switch;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::i18n;
+use crate::models::settings::{Language, MonitorSettings};
+use crate::services::storage;
+use crate::state::{SessionState, SessionStatus, SessionTab};
+
+/// 卡片宽度
+const TILE_WIDTH: f32 = 240.0;
+
+/// 监控总览页面状态
+pub struct MonitorDashboardState {
+    /// 是否同时显示已保存但未连接的服务器
+    pub show_disconnected: bool,
+}
+
+/// 卡片上的连接状态
+#[derive(Clone, Copy, PartialEq)]
+enum TileStatus {
+    Online,
+    Connecting,
+    Offline,
+}
+
+/// 单个服务器卡片的数据
+struct DashboardTile {
+    /// 已打开的会话标签 ID（未连接的服务器为 None，点击后发起连接）
+    tab_id: Option<String>,
+    server_id: String,
+    label: String,
+    address: String,
+    status: TileStatus,
+    cpu: Option<f32>,
+    memory: Option<f32>,
+    disk: Option<f32>,
+    alerts: usize,
+}
+
+impl DashboardTile {
+    /// 从会话标签生成卡片（指标来自该标签的监控状态）
+    fn from_tab(tab: &SessionTab, alerts: usize) -> Self {
+        let status = match &tab.status {
+            SessionStatus::Connected => TileStatus::Online,
+            SessionStatus::Connecting | SessionStatus::Reconnecting { .. } => {
+                TileStatus::Connecting
+            }
+            SessionStatus::Error(_) | SessionStatus::Disconnected => TileStatus::Offline,
+        };
+        let monitor = &tab.monitor_state;
+        // 占位的空负载信息时间戳为 0，不显示
+        let load = monitor.current_load().filter(|load| load.timestamp > 0);
+        let memory_total = monitor
+            .system_info
+            .as_ref()
+            .map(|info| info.memory.total_bytes)
+            .unwrap_or(0);
+        let online = status == TileStatus::Online;
+
+        Self {
+            tab_id: Some(tab.id.clone()),
+            server_id: tab.server_id.clone(),
+            label: tab.server_label.clone(),
+            address: tab
+                .server_data
+                .as_ref()
+                .map(|s| format!("{}@{}", s.username, s.host))
+                .unwrap_or_default(),
+            status,
+            cpu: load.filter(|_| online).map(|load| load.cpu.usage_percent),
+            memory: load
+                .filter(|_| online && memory_total > 0)
+                .map(|load| load.memory.used_bytes as f32 / memory_total as f32 * 100.0),
+            disk: monitor
+                .disk_info
+                .as_ref()
+                .filter(|_| online)
+                .and_then(|info| {
+                    info.disks
+                        .iter()
+                        .map(|disk| disk.usage_percent)
+                        .max_by(|a, b| a.total_cmp(b))
+                }),
+            alerts,
+        }
+    }
+}
+
+/// 渲染主机监控总览
+pub fn render_monitor_dashboard(
+    dashboard_state: Entity<MonitorDashboardState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let settings = storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language.clone();
+    let show_disconnected = dashboard_state.read(cx).show_disconnected;
+
+    // 已打开的会话（告警数按标签统计通知中心中的记录）
+    let state = session_state.read(cx);
+    let mut tiles: Vec<DashboardTile> = state
+        .tabs
+        .iter()
+        .map(|tab| {
+            let alerts = state
+                .alert_history
+                .iter()
+                .filter(|record| record.tab_id == tab.id)
+                .count();
+            DashboardTile::from_tab(tab, alerts)
+        })
+        .collect();
+
+    // 已保存但没有打开会话的服务器
+    if show_disconnected {
+        let config = storage::load_servers().unwrap_or_default();
+        let saved: Vec<DashboardTile> = config
+            .servers
+            .iter()
+            .filter(|server| !state.tabs.iter().any(|tab| tab.server_id == server.id))
+            .map(|server| DashboardTile {
+                tab_id: None,
+                server_id: server.id.clone(),
+                label: server.label.clone(),
+                address: format!("{}@{}", server.username, server.host),
+                status: TileStatus::Offline,
+                cpu: None,
+                memory: None,
+                disk: None,
+                alerts: 0,
+            })
+            .collect();
+        tiles.extend(saved);
+    }
+
+    let online_count = tiles
+        .iter()
+        .filter(|tile| tile.status == TileStatus::Online)
+        .count();
+
+    div()
+        .size_full()
+        .p_6()
+        .flex()
+        .flex_col()
+        .gap_4()
+        // 标题与筛选
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .flex()
+                        .items_baseline()
+                        .gap_3()
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_semibold()
+                                .text_color(cx.theme().foreground)
+                                .child(i18n::t(&lang, "dashboard.title")),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(
+                                    i18n::t(&lang, "dashboard.summary")
+                                        .replace("{online}", &online_count.to_string())
+                                        .replace("{total}", &tiles.len().to_string()),
+                                ),
+                        ),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(i18n::t(&lang, "dashboard.show_disconnected")),
+                        )
+                        .child(
+                            Switch::new("dashboard-show-disconnected")
+                                .checked(show_disconnected)
+                                .on_click(move |checked, _, cx| {
+                                    dashboard_state.update(cx, |state, cx| {
+                                        state.show_disconnected = *checked;
+                                        cx.notify();
+                                    });
+                                }),
+                        ),
+                ),
+        )
+        .child(if tiles.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(i18n::t(&lang, "dashboard.empty"))
+                .into_any_element()
+        } else {
+            div()
+                .id("dashboard-grid")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .flex()
+                .flex_wrap()
+                .content_start()
+                .gap_4()
+                .children(tiles.into_iter().map(|tile| {
+                    render_tile(tile, &settings.monitor, &lang, session_state.clone(), cx)
+                }))
+                .into_any_element()
+        })
+}
+
+/// 渲染单个服务器卡片：已连接的点击切换到会话标签，未连接的点击发起连接
+fn render_tile(
+    tile: DashboardTile,
+    monitor: &MonitorSettings,
+    lang: &Language,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let (status_color, status_key) = match tile.status {
+        TileStatus::Online => (Hsla::from(rgb(0x22c55e)), "dashboard.status.online"),
+        TileStatus::Connecting => (Hsla::from(rgb(0xf59e0b)), "dashboard.status.connecting"),
+        TileStatus::Offline => (Hsla::from(rgb(0xef4444)), "dashboard.status.offline"),
+    };
+    let is_saved_only = tile.tab_id.is_none();
+    let element_id = SharedString::from(format!(
+        "dashboard-tile-{}",
+        tile.tab_id.as_deref().unwrap_or(&tile.server_id)
+    ));
+    let tab_id = tile.tab_id.clone();
+    let server_id = tile.server_id.clone();
+    let label = tile.label.clone();
+
+    div()
+        .id(element_id)
+        .w(px(TILE_WIDTH))
+        .p_3()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .rounded_lg()
+        .border_1()
+        .border_color(if tile.alerts > 0 {
+            cx.theme().danger.opacity(0.6)
+        } else {
+            cx.theme().border
+        })
+        .bg(cx.theme().secondary)
+        .cursor_pointer()
+        .hover(|s| s.border_color(cx.theme().primary))
+        .when(is_saved_only, |this| this.opacity(0.7))
+        .on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                match &tab_id {
+                    Some(tab_id) => state.show_session_tab(tab_id),
+                    None => {
+                        state.add_tab(server_id.clone(), label.clone());
+                        // 确保 Monitor 详情弹窗状态已创建
+                        state.ensure_monitor_detail_dialog(cx);
+                    }
+                }
+                cx.notify();
+            });
+        })
+        // 名称、状态与告警数
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(div().size(px(8.)).rounded_full().bg(status_color))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_sm()
+                        .font_medium()
+                        .text_color(cx.theme().foreground)
+                        .child(tile.label.clone()),
+                )
+                .when(tile.alerts > 0, |this| {
+                    this.child(
+                        div()
+                            .px_1p5()
+                            .rounded(px(4.))
+                            .bg(cx.theme().danger)
+                            .text_xs()
+                            .text_color(cx.theme().danger_foreground)
+                            .child(
+                                i18n::t(lang, "dashboard.alerts")
+                                    .replace("{}", &tile.alerts.to_string()),
+                            ),
+                    )
+                }),
+        )
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(tile.address.clone()),
+                )
+                .child(div().flex_shrink_0().child(i18n::t(lang, status_key))),
+        )
+        // 指标
+        .child(render_metric_row(
+            i18n::t(lang, "dashboard.cpu"),
+            tile.cpu,
+            monitor.cpu_alert_threshold,
+            cx,
+        ))
+        .child(render_metric_row(
+            i18n::t(lang, "dashboard.memory"),
+            tile.memory,
+            monitor.memory_alert_threshold,
+            cx,
+        ))
+        .child(render_metric_row(
+            i18n::t(lang, "dashboard.disk"),
+            tile.disk,
+            monitor.disk_alert_threshold,
+            cx,
+        ))
+        .when(is_saved_only, |this| {
+            this.child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(i18n::t(lang, "dashboard.click_to_connect")),
+            )
+        })
+}
+
+/// 渲染一行指标：名称、进度条和百分比（没有数据时显示 --，超过告警阈值时标红）
+fn render_metric_row(
+    label: &'static str,
+    percent: Option<f32>,
+    threshold: u32,
+    cx: &App,
+) -> impl IntoElement {
+    let over_threshold = percent.is_some_and(|p| p >= threshold as f32);
+    let bar_color = if over_threshold {
+        cx.theme().danger
+    } else {
+        hsla(145.0 / 360.0, 0.63, 0.42, 1.0)
+    };
+    let ratio = percent.unwrap_or(0.0).clamp(0.0, 100.0) / 100.0;
+
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .text_xs()
+        .child(
+            div()
+                .w(px(36.))
+                .flex_shrink_0()
+                .text_color(cx.theme().muted_foreground)
+                .child(label),
+        )
+        .child(
+            div()
+                .flex_1()
+                .h(px(6.))
+                .rounded(px(3.))
+                .bg(cx.theme().border)
+                .overflow_hidden()
+                .child(div().h_full().w(relative(ratio)).bg(bar_color)),
+        )
+        .child(
+            div()
+                .w(px(44.))
+                .flex_shrink_0()
+                .flex()
+                .justify_end()
+                .text_color(if over_threshold {
+                    cx.theme().danger
+                } else {
+                    cx.theme().foreground
+                })
+                .child(
+                    percent
+                        .map(|p| format!("{:.1}%", p))
+                        .unwrap_or_else(|| "--".to_string()),
+                ),
+        )
+}
@@ -0,0 +1,306 @@
+// 通知中心抽屉：右侧滑出的通知历史，可按类别筛选，打开后未读通知标记为已读
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::notification::NotificationType;
+use gpui_component::ActiveTheme;
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::notifications::{NotificationCategory, NotificationCenter};
+use crate::services::storage;
+
+/// 抽屉宽度
+const DRAWER_WIDTH: f32 = 360.;
+
+/// 通知中心抽屉状态
+#[derive(Default)]
+pub struct NotificationDrawerState {
+    pub visible: bool,
+    /// 当前筛选的类别，None 表示全部
+    pub filter: Option<NotificationCategory>,
+}
+
+impl NotificationDrawerState {
+    pub fn toggle(&mut self) {
+        if self.visible {
+            self.close();
+        } else {
+            self.visible = true;
+        }
+    }
+
+    /// 关闭抽屉，已看过的通知标记为已读
+    pub fn close(&mut self) {
+        self.visible = false;
+        NotificationCenter::global().mark_all_read();
+    }
+}
+
+fn kind_color(kind: &NotificationType, cx: &App) -> Hsla {
+    match kind {
+        NotificationType::Success => cx.theme().success,
+        NotificationType::Warning => cx.theme().warning,
+        NotificationType::Error => cx.theme().danger,
+        _ => cx.theme().info,
+    }
+}
+
+/// 渲染筛选按钮
+fn render_filter_chip(
+    state: Entity<NotificationDrawerState>,
+    category: Option<NotificationCategory>,
+    selected: bool,
+    label: String,
+    cx: &App,
+) -> impl IntoElement {
+    let id = category.map(|c| c.label_key()).unwrap_or("all");
+    let primary = cx.theme().primary;
+    let secondary_hover = cx.theme().secondary_hover;
+
+    div()
+        .id(SharedString::from(format!("notification-filter-{}", id)))
+        .px_2()
+        .py_0p5()
+        .rounded_md()
+        .text_xs()
+        .cursor_pointer()
+        .border_1()
+        .when(selected, |this| {
+            this.border_color(primary)
+                .bg(primary.opacity(0.12))
+                .text_color(primary)
+        })
+        .when(!selected, |this| {
+            this.border_color(cx.theme().border)
+                .text_color(cx.theme().muted_foreground)
+                .hover(move |s| s.bg(secondary_hover))
+        })
+        .on_click(move |_, _, cx| {
+            state.update(cx, |state, cx| {
+                state.filter = category;
+                cx.notify();
+            });
+        })
+        .child(label)
+}
+
+/// 渲染通知中心抽屉覆盖层
+pub fn render_notification_drawer(
+    state: Entity<NotificationDrawerState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let filter = state.read(cx).filter;
+    let records = NotificationCenter::global().records(filter);
+    let unread = NotificationCenter::global().unread_count();
+
+    let foreground = cx.theme().foreground;
+    let muted = cx.theme().muted_foreground;
+    let border = cx.theme().border;
+    let secondary_hover = cx.theme().secondary_hover;
+
+    let state_for_backdrop = state.clone();
+    let state_for_close = state.clone();
+    let state_for_read = state.clone();
+    let state_for_clear = state.clone();
+
+    let filters = std::iter::once((None, i18n::t(&lang, "notifications.filter_all")))
+        .chain(
+            NotificationCategory::ALL
+                .into_iter()
+                .map(|c| (Some(c), i18n::t(&lang, c.label_key()))),
+        )
+        .map(|(category, label)| {
+            render_filter_chip(
+                state.clone(),
+                category,
+                filter == category,
+                label.to_string(),
+                cx,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let rows = records
+        .iter()
+        .map(|record| {
+            div()
+                .id(("notification-record", record.id as usize))
+                .px_3()
+                .py_2()
+                .border_b_1()
+                .border_color(border)
+                .when(!record.read, |this| this.bg(cx.theme().list_active))
+                .flex()
+                .gap_2()
+                .child(
+                    div()
+                        .mt_1()
+                        .flex_shrink_0()
+                        .size_2()
+                        .rounded_full()
+                        .bg(kind_color(&record.kind, cx)),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(foreground)
+                                .child(record.message.clone()),
+                        )
+                        .child(div().text_xs().text_color(muted).child(format!(
+                            "{} · {}",
+                            record.time,
+                            i18n::t(&lang, record.category.label_key())
+                        ))),
+                )
+        })
+        .collect::<Vec<_>>();
+    let is_empty = rows.is_empty();
+
+    div()
+        .id("notification-drawer-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .flex()
+        .justify_end()
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            state_for_backdrop.update(cx, |state, cx| {
+                state.close();
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .id("notification-drawer")
+                .w(px(DRAWER_WIDTH))
+                .h_full()
+                .bg(cx.theme().popover)
+                .border_l_1()
+                .border_color(border)
+                .shadow_lg()
+                .flex()
+                .flex_col()
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    cx.stop_propagation();
+                })
+                // 标题与操作按钮
+                .child(
+                    div()
+                        .h(px(44.))
+                        .px_3()
+                        .flex_shrink_0()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .border_b_1()
+                        .border_color(border)
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_sm()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(foreground)
+                                .child(i18n::t(&lang, "notifications.title")),
+                        )
+                        .when(unread > 0, move |this| {
+                            this.child(
+                                div()
+                                    .id("notification-mark-read")
+                                    .px_2()
+                                    .py_1()
+                                    .rounded_md()
+                                    .text_xs()
+                                    .text_color(muted)
+                                    .cursor_pointer()
+                                    .hover(move |s| s.bg(secondary_hover))
+                                    .on_click(move |_, _, cx| {
+                                        NotificationCenter::global().mark_all_read();
+                                        state_for_read.update(cx, |_, cx| cx.notify());
+                                    })
+                                    .child(i18n::t(&lang, "notifications.mark_all_read")),
+                            )
+                        })
+                        .child(
+                            div()
+                                .id("notification-clear")
+                                .px_2()
+                                .py_1()
+                                .rounded_md()
+                                .text_xs()
+                                .text_color(muted)
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(secondary_hover))
+                                .on_click(move |_, _, cx| {
+                                    NotificationCenter::global().clear();
+                                    state_for_clear.update(cx, |_, cx| cx.notify());
+                                })
+                                .child(i18n::t(&lang, "notifications.clear")),
+                        )
+                        .child(
+                            div()
+                                .id("notification-drawer-close")
+                                .size_6()
+                                .rounded_md()
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(secondary_hover))
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .on_click(move |_, _, cx| {
+                                    state_for_close.update(cx, |state, cx| {
+                                        state.close();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(render_icon(icons::X, muted)),
+                        ),
+                )
+                // 类别筛选
+                .child(
+                    div()
+                        .px_3()
+                        .py_2()
+                        .flex_shrink_0()
+                        .flex()
+                        .flex_wrap()
+                        .gap_1()
+                        .border_b_1()
+                        .border_color(border)
+                        .children(filters),
+                )
+                // 通知列表
+                .child(if is_empty {
+                    div()
+                        .flex_1()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "notifications.empty"))
+                        .into_any_element()
+                } else {
+                    div()
+                        .id("notification-list")
+                        .flex_1()
+                        .min_h(px(0.))
+                        .overflow_y_scroll()
+                        .children(rows)
+                        .into_any_element()
+                }),
+        )
+}
@@ -1,27 +1,78 @@
 // HomePage 主页组件
 
+use std::path::PathBuf;
+
+use futures::channel::mpsc::UnboundedReceiver;
+use futures::StreamExt;
 use gpui::*;
-use std::collections::HashMap;
-use tracing::{debug, info};
+use gpui_component::ActiveTheme;
 
+use super::batch_exec::{render_batch_exec_content, BatchExecPageState};
+use super::command_palette::{render_command_palette, CommandPaletteState, PaletteCommand};
+use super::history_list::{render_history_content, HistoryPageState};
+use super::identities_list::render_identities_content;
 use super::known_hosts_list::{render_known_hosts_content, KnownHostsPageState};
-use super::server_list::{render_hosts_content, render_placeholder, ViewMode, ViewModeState};
+use super::monitor_dashboard::{render_monitor_dashboard, MonitorDashboardState};
+use super::notification_drawer::{render_notification_drawer, NotificationDrawerState};
+use super::plugins_list::{render_plugins_content, PluginsPageState};
+use super::schedules_list::{render_schedules_content, SchedulesPageState};
+use super::server_filter::{QuickConnectTarget, ServerFilterState};
+use super::server_list::{render_hosts_content, ViewMode, ViewModeState};
 use super::sidebar::{render_sidebar, MenuType, SidebarState};
 use super::snippets_list::{render_snippets_content, SnippetsPageState};
-use super::titlebar::{render_home_button, render_session_titlebar, render_titlebar};
+use super::titlebar::{render_home_button, render_session_titlebar, render_titlebar, DraggedTab};
+use super::trash_list::render_trash_content;
+use crate::components::common::batch_edit_dialog::{
+    render_batch_edit_dialog_overlay, BatchEditState,
+};
+use crate::components::common::close_tabs_dialog::render_close_tabs_dialog_overlay;
+use crate::components::common::group_dialog::{render_group_dialog_overlay, GroupDialogState};
+use crate::components::common::host_discovery_dialog::{
+    render_host_discovery_dialog_overlay, HostDiscoveryState,
+};
+use crate::components::common::identity_dialog::{
+    render_identity_dialog_overlay, IdentityDialogState,
+};
+use crate::components::common::plugin_dialog::render_plugin_dialog_overlay;
+use crate::components::common::recovery_dialog::{render_recovery_dialog_overlay, RecoveryState};
+use crate::components::common::schedule_dialog::render_schedule_dialog_overlay;
 use crate::components::common::server_dialog::{render_server_dialog_overlay, ServerDialogState};
+use crate::components::common::server_import_dialog::{
+    render_server_import_dialog_overlay, ServerImportState,
+};
 use crate::components::common::settings_dialog::{
     render_settings_dialog_overlay, SettingsDialogState,
 };
+use crate::components::common::ssh_snippet_dialog::render_ssh_snippet_dialog_overlay;
+use crate::components::common::sync_conflict_dialog::{
+    render_sync_conflict_dialog_overlay, SyncState,
+};
+use crate::components::common::workspace_dialog::{
+    render_workspace_dialog_overlay, WorkspaceDialogState,
+};
 use crate::constants::icons;
 use crate::i18n;
+use crate::keybindings::{
+    NavigateBack, NavigateForward, QuickConnect, ToggleCommandPalette, ToggleNotificationCenter,
+    ToggleTabOverview,
+};
+use crate::models::server::AuthType;
 use crate::models::settings::Language;
+use crate::models::window_state::WindowState;
 use crate::models::{HistoryItem, Server, ServerGroup};
-use crate::pages::connecting::{render_connecting_page, ConnectingProgress};
-use crate::pages::session::render_session_layout;
+use crate::pages::session::{
+    bind_navigation_actions, open_detached_window, render_tab_content, render_tab_overview,
+};
+use crate::router::{DeepLink, Route, Router};
+use crate::services::connection_history::ConnectionHistoryStore;
+use crate::services::database::{StorageKey, StorageService};
+use crate::services::inventory::{InventoryService, SharedInventory};
+use crate::services::notifications::{self, NotificationCategory};
+use crate::services::server_search::AdHocTarget;
 use crate::services::storage;
-use crate::ssh::start_ssh_connection;
-use crate::state::{SessionState, SessionStatus};
+use crate::services::tray::{TrayCommand, TrayModel, TrayService};
+use crate::services::window_state;
+use crate::state::SessionState;
 
 /// 主页状态
 pub struct HomePage {
@@ -29,15 +80,54 @@ pub struct HomePage {
     pub history: Vec<HistoryItem>,
     pub sidebar_state: Entity<SidebarState>,
     pub view_mode_state: Entity<ViewModeState>,
+    /// 主机列表的搜索与标签过滤状态
+    pub filter_state: Entity<ServerFilterState>,
     pub dialog_state: Entity<ServerDialogState>,
+    /// 从其他客户端导入服务器的对话框状态
+    pub import_state: Entity<ServerImportState>,
+    /// 扫描网段发现 SSH 主机的对话框状态
+    pub discovery_state: Entity<HostDiscoveryState>,
+    /// 服务器分组新建/重命名/删除弹窗状态
+    pub group_state: Entity<GroupDialogState>,
+    /// 服务器多选与批量编辑弹窗状态
+    pub batch_state: Entity<BatchEditState>,
+    /// 身份新建/编辑弹窗状态
+    pub identity_dialog_state: Entity<IdentityDialogState>,
     pub settings_dialog_state: Entity<SettingsDialogState>,
     pub session_state: Entity<SessionState>,
     pub snippets_state: Entity<SnippetsPageState>,
     pub known_hosts_state: Entity<KnownHostsPageState>,
-    // 连接进度状态（按 tab_id 索引）
-    pub connecting_progress: HashMap<String, Entity<ConnectingProgress>>,
+    pub monitor_dashboard_state: Entity<MonitorDashboardState>,
+    pub batch_exec_state: Entity<BatchExecPageState>,
+    /// 连接历史页面状态
+    pub history_state: Entity<HistoryPageState>,
+    /// 插件页面状态
+    pub plugins_state: Entity<PluginsPageState>,
+    /// 定时任务页面状态
+    pub schedules_state: Entity<SchedulesPageState>,
+    /// 数据同步状态（自动同步与冲突处理）
+    pub sync_state: Entity<SyncState>,
+    /// 配置损坏时的恢复对话框状态
+    pub recovery_state: Entity<RecoveryState>,
+    /// 会话工作区弹窗状态
+    pub workspace_state: Entity<WorkspaceDialogState>,
+    /// 命令面板状态
+    pub command_palette: Entity<CommandPaletteState>,
+    /// 通知中心抽屉状态
+    pub notification_drawer: Entity<NotificationDrawerState>,
+    /// 视图切换的导航历史（后退/前进）
+    router: Router,
+    /// 窗口移动或调整大小后的位置，退出时保存
+    window_placement: Option<WindowState>,
     /// 上一次的 show_home 状态，用于检测视图切换
     last_show_home: bool,
+    /// 已加载的服务器列表与连接历史的修订号，变化时重新加载
+    servers_revision: u64,
+    history_revision: u64,
+    /// 已合并的共享清单修订号
+    inventory_revision: u64,
+    /// 已应用到窗口的背景外观（随当前标签的终端透明度变化）
+    window_background: WindowBackgroundAppearance,
 }
 
 impl HomePage {
@@ -50,11 +140,45 @@ impl HomePage {
             mode: ViewMode::List,
         });
 
+        let filter_state = cx.new(|_| ServerFilterState::default());
         let dialog_state = cx.new(|_| ServerDialogState::default());
+        let import_state = cx.new(|_| ServerImportState::default());
+        let discovery_state = cx.new(|_| HostDiscoveryState::default());
+        let group_state = cx.new(|_| GroupDialogState::default());
+        let batch_state = cx.new(|_| BatchEditState::default());
+        let identity_dialog_state = cx.new(|_| IdentityDialogState::default());
         let settings_dialog_state = cx.new(|_| SettingsDialogState::default());
-        let session_state = cx.new(|_| SessionState::default());
+        let session_state = cx.new(|_| SessionState {
+            sidebar_collapsed: storage::load_window_state()
+                .unwrap_or_default()
+                .layout
+                .sidebar_collapsed,
+            ..Default::default()
+        });
+        session_state.update(cx, |state, cx| {
+            state.start_hibernation_timer(cx);
+            state.start_latency_timer(cx);
+            state.start_plugins(cx);
+            state.start_scheduler(cx);
+        });
         let snippets_state = cx.new(|cx| SnippetsPageState::new(cx));
         let known_hosts_state = cx.new(|_| KnownHostsPageState::new());
+        let monitor_dashboard_state = cx.new(|_| MonitorDashboardState {
+            show_disconnected: false,
+        });
+        let batch_exec_state = cx.new(|_| BatchExecPageState::default());
+        let history_state = cx.new(|_| HistoryPageState::default());
+        let plugins_state = cx.new(|_| PluginsPageState::default());
+        let schedules_state = cx.new(|cx| SchedulesPageState::new(cx));
+        let sync_state = cx.new(|_| SyncState::default());
+        sync_state.update(cx, |state, cx| state.start_auto_sync_timer(cx));
+        settings_dialog_state.update(cx, |state, _| {
+            state.sync_state = Some(sync_state.clone());
+        });
+        let recovery_state = cx.new(|_| RecoveryState::new());
+        let workspace_state = cx.new(|_| WorkspaceDialogState::new(session_state.clone()));
+        let command_palette = cx.new(|_| CommandPaletteState::default());
+        let notification_drawer = cx.new(|_| NotificationDrawerState::default());
 
         // 从存储加载服务器数据
         let server_groups = Self::load_server_groups();
@@ -64,16 +188,411 @@ impl HomePage {
             history: Self::load_history(),
             sidebar_state,
             view_mode_state,
+            filter_state,
             dialog_state,
+            import_state,
+            discovery_state,
+            group_state,
+            batch_state,
+            identity_dialog_state,
             settings_dialog_state,
             session_state,
             snippets_state,
             known_hosts_state,
-            connecting_progress: HashMap::new(),
+            monitor_dashboard_state,
+            batch_exec_state,
+            history_state,
+            plugins_state,
+            schedules_state,
+            sync_state,
+            recovery_state,
+            workspace_state,
+            command_palette,
+            notification_drawer,
+            router: Router::default(),
+            window_placement: None,
             last_show_home: true,
+            servers_revision: 0,
+            history_revision: 0,
+            inventory_revision: 0,
+            // 与 main 中打开窗口时使用的外观一致
+            window_background: crate::theme::window_background_appearance(
+                &crate::services::storage::load_settings()
+                    .unwrap_or_default()
+                    .terminal,
+                None,
+            ),
+        }
+    }
+
+    /// 订阅存储变更：服务器列表或连接历史被写入（包括同步、导入等后台写入）或共享清单刷新后自动重新加载
+    pub fn observe_storage(&mut self, cx: &mut Context<Self>) {
+        let service = cx.global::<StorageService>();
+        self.servers_revision = service.revision(StorageKey::Servers);
+        self.history_revision = service.revision(StorageKey::ConnectionHistory);
+        cx.observe_global::<StorageService>(|this, cx| {
+            let service = cx.global::<StorageService>();
+            let servers_revision = service.revision(StorageKey::Servers);
+            let history_revision = service.revision(StorageKey::ConnectionHistory);
+            if servers_revision != this.servers_revision
+                || history_revision != this.history_revision
+            {
+                this.servers_revision = servers_revision;
+                this.history_revision = history_revision;
+                this.reload_servers();
+                cx.notify();
+            }
+        })
+        .detach();
+        // 团队共享清单刷新后合并到服务器列表
+        cx.observe_global::<InventoryService>(|this, cx| {
+            let revision = cx.global::<InventoryService>().revision();
+            if revision != this.inventory_revision {
+                this.inventory_revision = revision;
+                this.reload_servers();
+                cx.notify();
+            }
+        })
+        .detach();
+    }
+
+    /// 退出时记录打开的会话与窗口状态，下次启动时可恢复
+    pub fn save_session_on_quit(&mut self, cx: &mut Context<Self>) {
+        cx.on_app_quit(|this, cx| {
+            let snapshot = this
+                .session_state
+                .read(cx)
+                .workspace_snapshot(String::new());
+            if let Err(e) = storage::save_last_session(snapshot) {
+                tracing::error!("[Workspace] Failed to save last session: {:#}", e);
+            }
+            let remember_window = storage::load_settings()
+                .map(|s| s.system.save_window_position)
+                .unwrap_or(true);
+            if let Some(placement) = this.window_placement.take().filter(|_| remember_window) {
+                if let Err(e) = storage::save_window_placement(placement) {
+                    tracing::error!("[Window] Failed to save window state: {:#}", e);
+                }
+            }
+            let sidebar_collapsed = this.session_state.read(cx).sidebar_collapsed;
+            if let Err(e) =
+                storage::update_panel_layout(|layout| layout.sidebar_collapsed = sidebar_collapsed)
+            {
+                tracing::error!("[Window] Failed to save panel layout: {:#}", e);
+            }
+            async {}
+        })
+        .detach();
+    }
+
+    /// 记录窗口移动、调整大小、最大化与全屏后的位置（退出时保存）
+    pub fn remember_window_state(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        cx.observe_window_bounds(window, |this, window, cx| {
+            this.window_placement = Some(window_state::capture(window, cx));
+        })
+        .detach();
+    }
+
+    /// 启动托盘图标：菜单中可连接收藏的服务器、查看进行中的传输、显示/隐藏主窗口与退出
+    pub fn install_tray(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let session_for_model = self.session_state.clone();
+        let session_state = self.session_state.clone();
+        let window_handle = window.window_handle();
+        let show_window = move |cx: &mut App| {
+            cx.activate(true);
+            let _ = window_handle.update(cx, |_, window, _| window.activate_window());
+        };
+
+        TrayService::init(
+            cx,
+            move |cx| {
+                let transfers = session_for_model
+                    .read(cx)
+                    .tabs
+                    .iter()
+                    .flat_map(|tab| {
+                        tab.active_transfers
+                            .iter()
+                            .filter(|t| t.status.is_active())
+                            .map(|t| {
+                                // 进度按 10% 取整，避免频繁重建菜单
+                                let percent = (t.progress.percentage() as u32) / 10 * 10;
+                                let arrow = if t.is_upload { "↑" } else { "↓" };
+                                let text = format!(
+                                    "{} {} · {} {}%",
+                                    arrow,
+                                    t.file_name(),
+                                    tab.server_label,
+                                    percent
+                                );
+                                (tab.id.clone(), text)
+                            })
+                    })
+                    .collect();
+                TrayModel::new(transfers)
+            },
+            move |command, cx| match command {
+                TrayCommand::Connect { server_id, label } => {
+                    session_state.update(cx, |state, cx| {
+                        state.add_tab(server_id, label);
+                        // 确保 Monitor 详情弹窗状态已创建
+                        state.ensure_monitor_detail_dialog(cx);
+                    });
+                    show_window(cx);
+                }
+                TrayCommand::ShowTab(tab_id) => {
+                    session_state.update(cx, |state, _| state.show_session_tab(&tab_id));
+                    show_window(cx);
+                }
+                TrayCommand::ToggleWindow => {
+                    let active = window_handle
+                        .update(cx, |_, window, _| window.is_window_active())
+                        .unwrap_or(false);
+                    if active {
+                        cx.hide();
+                    } else {
+                        show_window(cx);
+                    }
+                }
+                TrayCommand::Quit => cx.quit(),
+            },
+        );
+    }
+
+    /// 处理系统打开的 shellmaster:// 深链接（应用启动前收到的链接也会在窗口创建后处理）
+    pub fn handle_deep_links(
+        &mut self,
+        mut links: UnboundedReceiver<String>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let window_handle = window.window_handle();
+        cx.spawn(async move |this, cx| {
+            while let Some(link) = links.next().await {
+                let Some(link) = DeepLink::parse(&link) else {
+                    tracing::warn!("[Router] Ignored invalid deep link: {}", link);
+                    continue;
+                };
+                let handled = window_handle.update(cx, |_, window, cx| {
+                    cx.activate(true);
+                    window.activate_window();
+                    this.update(cx, |this, cx| this.open_deep_link(link, window, cx))
+                });
+                if !matches!(handled, Ok(Ok(()))) {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// 打开深链接：路由链接切换到对应视图；连接链接匹配已保存的服务器时直接连接，
+    /// 否则通过通知确认后建立不保存的临时连接
+    fn open_deep_link(&mut self, link: DeepLink, window: &mut Window, cx: &mut Context<Self>) {
+        use gpui_component::notification::{Notification, NotificationType};
+        use gpui_component::WindowExt;
+
+        let target = match link {
+            DeepLink::Open(route) => {
+                if self.route_valid(&route, cx) {
+                    self.navigate(route, cx);
+                }
+                return;
+            }
+            DeepLink::Connect(target) => target,
+        };
+
+        let saved = storage::load_servers().ok().and_then(|config| {
+            target
+                .find_saved(&config)
+                .map(|s| (s.id.clone(), s.label.clone()))
+        });
+        if let Some((id, label)) = saved {
+            self.session_state.update(cx, |state, cx| {
+                state.add_tab(id, label);
+                // 确保 Monitor 详情弹窗状态已创建
+                state.ensure_monitor_detail_dialog(cx);
+            });
+            cx.notify();
+            return;
+        }
+
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        let session_state = self.session_state.clone();
+        let notification = Notification::new()
+            .title(i18n::t(&lang, "deep_link.connect_prompt").replace("{}", &target.display()))
+            .message(i18n::t(&lang, "deep_link.connect_hint"))
+            .with_type(NotificationType::Info)
+            .on_click(move |_, _, cx| {
+                session_state.update(cx, |state, cx| {
+                    state.add_adhoc_tab(target.to_server_data(None));
+                    // 确保 Monitor 详情弹窗状态已创建
+                    state.ensure_monitor_detail_dialog(cx);
+                    cx.notify();
+                });
+            });
+        window.push_notification(notification, cx);
+    }
+
+    /// 打开命令行参数指定的连接：未指定私钥且匹配已保存的服务器时使用其配置，
+    /// 否则建立不保存的临时连接（用户主动发起，无需确认）
+    pub fn open_launch_target(
+        &mut self,
+        target: AdHocTarget,
+        identity_file: Option<PathBuf>,
+        cx: &mut Context<Self>,
+    ) {
+        let saved = storage::load_servers()
+            .ok()
+            .filter(|_| identity_file.is_none())
+            .and_then(|config| {
+                target
+                    .find_saved(&config)
+                    .map(|s| (s.id.clone(), s.label.clone()))
+            });
+        self.session_state.update(cx, |state, cx| {
+            match saved {
+                Some((id, label)) => {
+                    state.add_tab(id, label);
+                }
+                None => {
+                    let mut server = target.to_server_data(None);
+                    if let Some(path) = identity_file {
+                        server.auth_type = AuthType::PublicKey;
+                        server.private_key_path = Some(path.to_string_lossy().to_string());
+                    }
+                    state.add_adhoc_tab(server);
+                }
+            }
+            // 确保 Monitor 详情弹窗状态已创建
+            state.ensure_monitor_detail_dialog(cx);
+        });
+        cx.notify();
+    }
+
+    /// 当前显示的视图
+    fn current_route(&self, cx: &App) -> Route {
+        let settings = self.settings_dialog_state.read(cx);
+        if settings.visible {
+            return Route::Settings(settings.current_section);
+        }
+        let session = self.session_state.read(cx);
+        match session.window_active_tab(None) {
+            Some(tab) if !session.show_home => Route::Session(tab.id.clone()),
+            _ => Route::Home(self.sidebar_state.read(cx).selected_menu),
+        }
+    }
+
+    /// 路由是否仍可打开（已关闭或已移到独立窗口的标签不可打开）
+    fn route_valid(&self, route: &Route, cx: &App) -> bool {
+        match route {
+            Route::Session(tab_id) => self
+                .session_state
+                .read(cx)
+                .tabs
+                .iter()
+                .any(|t| &t.id == tab_id && t.window_id.is_none()),
+            Route::Home(_) | Route::Settings(_) => true,
         }
     }
 
+    /// 切换到路由对应的视图
+    fn navigate(&mut self, route: Route, cx: &mut Context<Self>) {
+        tracing::debug!("[Router] Navigate to {}", route.path());
+        match route {
+            Route::Home(menu) => {
+                self.settings_dialog_state
+                    .update(cx, |state, _| state.close());
+                self.session_state
+                    .update(cx, |state, _| state.show_home = true);
+                self.sidebar_state
+                    .update(cx, |state, _| state.selected_menu = menu);
+            }
+            Route::Session(tab_id) => {
+                self.settings_dialog_state
+                    .update(cx, |state, _| state.close());
+                self.session_state
+                    .update(cx, |state, _| state.show_session_tab(&tab_id));
+            }
+            Route::Settings(section) => {
+                // 设置弹窗在主页视图中渲染
+                self.session_state
+                    .update(cx, |state, _| state.show_home = true);
+                self.settings_dialog_state.update(cx, |state, _| {
+                    if state.visible {
+                        state.current_section = section;
+                    } else {
+                        state.open_section(section);
+                    }
+                });
+            }
+        }
+        cx.notify();
+    }
+
+    /// 后退到上一个视图
+    fn navigate_back(&mut self, cx: &mut Context<Self>) {
+        let mut router = std::mem::take(&mut self.router);
+        let route = router.back(|route| self.route_valid(route, cx));
+        self.router = router;
+        if let Some(route) = route {
+            self.navigate(route, cx);
+        }
+    }
+
+    /// 前进到下一个视图
+    fn navigate_forward(&mut self, cx: &mut Context<Self>) {
+        let mut router = std::mem::take(&mut self.router);
+        let route = router.forward(|route| self.route_valid(route, cx));
+        self.router = router;
+        if let Some(route) = route {
+            self.navigate(route, cx);
+        }
+    }
+
+    /// 启动时恢复上次的会话：开启自动恢复时直接重新连接，否则通过通知询问
+    pub fn restore_last_session(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        use gpui_component::notification::{Notification, NotificationType};
+        use gpui_component::WindowExt;
+
+        let Some(last_session) = storage::load_workspaces()
+            .ok()
+            .and_then(|config| config.last_session)
+            .filter(|w| !w.tabs.is_empty())
+        else {
+            return;
+        };
+        let settings = storage::load_settings().unwrap_or_default();
+        if settings.system.restore_sessions {
+            self.session_state.update(cx, |state, cx| {
+                state.restore_workspace(&last_session, cx);
+            });
+            return;
+        }
+
+        let lang = settings.theme.language;
+        // 通知列表由 Root 管理，等窗口的根视图创建后再显示
+        cx.defer_in(window, move |this, window, cx| {
+            let session_state = this.session_state.clone();
+            let notification = Notification::new()
+                .title(i18n::t(&lang, "workspace.restore_prompt"))
+                .message(
+                    i18n::t(&lang, "workspace.restore_hint")
+                        .replace("{}", &last_session.tabs.len().to_string()),
+                )
+                .with_type(NotificationType::Info)
+                .on_click(move |_, _, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.restore_workspace(&last_session, cx);
+                        cx.notify();
+                    });
+                });
+            window.push_notification(notification, cx);
+        });
+    }
+
     /// 从存储加载服务器分组数据
     fn load_server_groups() -> Vec<ServerGroup> {
         // 加载当前语言
@@ -84,62 +603,91 @@ impl HomePage {
         let config = crate::services::storage::load_servers().unwrap_or_default();
 
         // 将 ServerData 转换为视图用的 Server 结构
+        let to_view = |s: &crate::models::ServerData| Server {
+            id: s.id.clone(),
+            name: s.label.clone(),
+            host: s.host.clone(),
+            port: s.port,
+            description: s.description.clone().unwrap_or_else(|| "-".into()),
+            account: s.username.clone(),
+            last_connected: s
+                .last_connected_at
+                .clone()
+                .unwrap_or_else(|| i18n::t(&lang, "server_list.never_connected").to_string()),
+            tags: s.tags.clone(),
+            shared: false,
+        };
+
+        // 按分组树的先序排列，子分组紧跟在父分组之后
         let mut server_groups: Vec<ServerGroup> = config
-            .groups
-            .iter()
-            .map(|group| {
-                let group_servers: Vec<Server> = config
+            .ordered_groups()
+            .into_iter()
+            .map(|(depth, group)| ServerGroup {
+                id: Some(group.id.clone()),
+                depth,
+                name: group.name.clone(),
+                icon_path: icons::SERVER,
+                servers: config
                     .servers
                     .iter()
                     .filter(|s| s.group_id.as_deref() == Some(&group.id))
-                    .map(|s| Server {
-                        id: s.id.clone(),
-                        name: s.label.clone(),
-                        host: s.host.clone(),
-                        port: s.port,
-                        description: s.description.clone().unwrap_or_else(|| "-".into()),
-                        account: s.username.clone(),
-                        last_connected: s.last_connected_at.clone().unwrap_or_else(|| {
-                            i18n::t(&lang, "server_list.never_connected").to_string()
-                        }),
-                    })
-                    .collect();
-
-                ServerGroup {
-                    name: group.name.clone(),
-                    icon_path: icons::SERVER,
-                    servers: group_servers,
-                }
+                    .map(to_view)
+                    .collect(),
+                shared: false,
             })
             .collect();
 
-        // 未分组的服务器放入 "未分组" 分组
+        // 未分组（或所属分组已不存在）的服务器放入 "未分组" 分组
         let ungrouped_servers: Vec<Server> = config
             .servers
             .iter()
-            .filter(|s| s.group_id.is_none())
-            .map(|s| Server {
-                id: s.id.clone(),
-                name: s.label.clone(),
-                host: s.host.clone(),
-                port: s.port,
-                description: s.description.clone().unwrap_or_else(|| "-".into()),
-                account: s.username.clone(),
-                last_connected: s
-                    .last_connected_at
-                    .clone()
-                    .unwrap_or_else(|| i18n::t(&lang, "server_list.never_connected").to_string()),
+            .filter(|s| {
+                s.group_id
+                    .as_deref()
+                    .is_none_or(|id| !config.groups.iter().any(|g| g.id == id))
             })
+            .map(to_view)
             .collect();
 
         if !ungrouped_servers.is_empty() {
             server_groups.push(ServerGroup {
+                id: None,
+                depth: 0,
                 name: i18n::t(&lang, "server_list.ungrouped").to_string(),
                 icon_path: icons::SERVER,
                 servers: ungrouped_servers,
+                shared: false,
             });
         }
 
+        // 团队共享清单按清单中的分组排在最后，未指定分组的归入“共享”
+        let shared_name = i18n::t(&lang, "server_list.shared");
+        for shared in SharedInventory::global().servers() {
+            let name = match &shared.group {
+                Some(group) => format!("{} / {}", shared_name, group),
+                None => shared_name.to_string(),
+            };
+            let server = Server {
+                shared: true,
+                last_connected: "-".to_string(),
+                ..to_view(&shared.server)
+            };
+            match server_groups
+                .iter_mut()
+                .find(|g| g.shared && g.name == name)
+            {
+                Some(group) => group.servers.push(server),
+                None => server_groups.push(ServerGroup {
+                    id: None,
+                    depth: 0,
+                    name,
+                    icon_path: icons::SERVER,
+                    servers: vec![server],
+                    shared: true,
+                }),
+            }
+        }
+
         server_groups
     }
 
@@ -149,34 +697,18 @@ impl HomePage {
         self.history = Self::load_history();
     }
 
-    /// 从存储加载历史记录
+    /// 从连接历史加载最近连接过的服务器
     fn load_history() -> Vec<HistoryItem> {
         let lang = storage::load_settings()
             .map(|s| s.theme.language)
             .unwrap_or(Language::Chinese);
 
-        let config = storage::load_servers().unwrap_or_default();
-        let mut servers_with_time: Vec<(String, chrono::NaiveDateTime)> = config
-            .servers
-            .iter()
-            .filter_map(|s| {
-                s.last_connected_at.as_ref().and_then(|time_str| {
-                    match chrono::NaiveDateTime::parse_from_str(time_str, "%Y-%m-%d %H:%M") {
-                        Ok(dt) => Some((s.label.clone(), dt)),
-                        Err(_) => None,
-                    }
-                })
-            })
-            .collect();
-
-        // 按时间倒序排序
-        servers_with_time.sort_by(|a, b| b.1.cmp(&a.1));
-
-        // 取前 10 条
-        servers_with_time
+        // 取最近 10 台
+        ConnectionHistoryStore::global()
+            .recent(10)
             .into_iter()
-            .take(10)
-            .map(|(name, time)| {
+            .filter_map(|entry| {
+                let time = entry.started()?;
                 let now = chrono::Local::now().naive_local();
                 let duration = now.signed_duration_since(time);
                 let time_str = if duration.num_seconds() < 60 {
@@ -201,20 +733,16 @@ impl HomePage {
                     )
                 };
 
-                HistoryItem {
-                    name,
+                Some(HistoryItem {
+                    entry_id: entry.id,
+                    name: entry.server_label,
                     time: time_str,
-                }
+                })
             })
             .collect()
     }
 
     fn render_content(&self, selected_menu: MenuType, cx: &Context<Self>) -> AnyElement {
-        // 加载当前语言
-        let lang = storage::load_settings()
-            .map(|s| s.theme.language)
-            .unwrap_or(Language::Chinese);
-
         let view_mode = self.view_mode_state.read(cx).mode;
         match selected_menu {
             MenuType::Hosts => render_hosts_content(
@@ -222,20 +750,51 @@ impl HomePage {
                 view_mode,
                 self.view_mode_state.clone(),
                 self.dialog_state.clone(),
+                self.import_state.clone(),
+                self.discovery_state.clone(),
+                self.group_state.clone(),
+                self.filter_state.clone(),
+                self.batch_state.clone(),
+                self.session_state.clone(),
+                cx,
+            )
+            .into_any_element(),
+            MenuType::Monitor => render_monitor_dashboard(
+                self.monitor_dashboard_state.clone(),
                 self.session_state.clone(),
                 cx,
             )
             .into_any_element(),
-            MenuType::Monitor => {
-                render_placeholder("Monitor", i18n::t(&lang, "session.monitor.title"), cx)
-                    .into_any_element()
-            }
             MenuType::Snippets => {
                 render_snippets_content(self.snippets_state.clone(), cx).into_any_element()
             }
+            MenuType::BatchExec => render_batch_exec_content(
+                self.batch_exec_state.clone(),
+                self.session_state.clone(),
+                cx,
+            )
+            .into_any_element(),
+            MenuType::Schedules => render_schedules_content(
+                self.schedules_state.clone(),
+                self.session_state.clone(),
+                cx,
+            )
+            .into_any_element(),
+            MenuType::Identities => {
+                render_identities_content(self.identity_dialog_state.clone(), cx).into_any_element()
+            }
             MenuType::KnownHosts => {
                 render_known_hosts_content(self.known_hosts_state.clone(), cx).into_any_element()
             }
+            MenuType::History => {
+                render_history_content(self.history_state.clone(), self.session_state.clone(), cx)
+                    .into_any_element()
+            }
+            MenuType::Plugins => {
+                render_plugins_content(self.plugins_state.clone(), self.session_state.clone(), cx)
+                    .into_any_element()
+            }
+            MenuType::Trash => render_trash_content(cx).into_any_element(),
         }
     }
 
@@ -254,9 +813,36 @@ impl HomePage {
         let settings_dialog_state = self.settings_dialog_state.clone();
 
         // 检查是否有会话，决定使用哪个标题栏
-        let has_sessions = self.session_state.read(cx).has_sessions();
+        let has_sessions = self.session_state.read(cx).has_main_tabs();
         let session_state = self.session_state.clone();
 
+        // 主机页的搜索框需要在 Window 上下文中创建
+        if selected_menu == MenuType::Hosts {
+            self.filter_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+                // 快速连接快捷键：聚焦搜索框
+                if std::mem::take(&mut state.focus_requested) {
+                    if let Some(input) = &state.search_input {
+                        input.update(cx, |input, cx| input.focus(window, cx));
+                    }
+                }
+            });
+        }
+
+        // 连接历史页的搜索框需要在 Window 上下文中创建
+        if selected_menu == MenuType::History {
+            self.history_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+        }
+
+        // 批量执行页的命令输入框需要在 Window 上下文中创建
+        if selected_menu == MenuType::BatchExec {
+            self.batch_exec_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+        }
+
         div()
             .size_full()
             .bg(crate::theme::background_color(cx))
@@ -272,9 +858,15 @@ impl HomePage {
                     .child(render_home_button(session_state.clone(), cx))
                     // Titlebar（有会话时显示标签页）
                     .child(if has_sessions {
-                        render_session_titlebar(session_state, cx).into_any_element()
+                        render_session_titlebar(
+                            session_state,
+                            None,
+                            Some(self.workspace_state.clone()),
+                            cx,
+                        )
+                        .into_any_element()
                     } else {
-                        render_titlebar(cx).into_any_element()
+                        render_titlebar(self.workspace_state.clone(), cx).into_any_element()
                     }),
             )
             // 第二行：Sidebar + Content
@@ -290,6 +882,7 @@ impl HomePage {
                         selected_menu,
                         &history,
                         self.settings_dialog_state.clone(),
+                        self.session_state.clone(),
                         cx,
                     ))
                     // Content
@@ -310,15 +903,94 @@ impl HomePage {
             } else {
                 None
             })
+            // 导入服务器弹窗
+            .children(if self.import_state.read(cx).visible {
+                Some(render_server_import_dialog_overlay(
+                    self.import_state.clone(),
+                    cx,
+                ))
+            } else {
+                None
+            })
+            // 主机发现弹窗
+            .children(if self.discovery_state.read(cx).visible {
+                self.discovery_state.update(cx, |state, cx| {
+                    state.ensure_inputs_created(window, cx);
+                });
+                Some(render_host_discovery_dialog_overlay(
+                    self.discovery_state.clone(),
+                    self.dialog_state.clone(),
+                    cx,
+                ))
+            } else {
+                None
+            })
+            // 服务器分组弹窗
+            .children(if self.group_state.read(cx).is_open() {
+                self.group_state.update(cx, |state, cx| {
+                    state.ensure_inputs_created(window, cx);
+                });
+                Some(render_group_dialog_overlay(self.group_state.clone(), cx))
+            } else {
+                None
+            })
+            // 批量编辑弹窗
+            .children(if self.batch_state.read(cx).visible {
+                self.batch_state.update(cx, |state, cx| {
+                    state.ensure_inputs_created(window, cx);
+                });
+                Some(render_batch_edit_dialog_overlay(
+                    self.batch_state.clone(),
+                    cx,
+                ))
+            } else {
+                None
+            })
+            // 身份编辑弹窗
+            .children(if self.identity_dialog_state.read(cx).visible {
+                self.identity_dialog_state.update(cx, |state, cx| {
+                    state.ensure_inputs_created(window, cx);
+                });
+                Some(render_identity_dialog_overlay(
+                    self.identity_dialog_state.clone(),
+                    cx,
+                ))
+            } else {
+                None
+            })
             // 设置弹窗
             .children(if settings_dialog_visible {
                 self.settings_dialog_state.update(cx, |state, cx| {
                     state.ensure_inputs_created(window, cx);
+                    state.refresh_changes(cx);
                 });
                 Some(render_settings_dialog_overlay(settings_dialog_state, cx))
             } else {
                 None
             })
+            // SSH 配置片段弹窗（从服务器列表打开）
+            .children(
+                self.session_state
+                    .read(cx)
+                    .get_ssh_snippet_dialog()
+                    .filter(|dialog| {
+                        let dialog = dialog.read(cx);
+                        dialog.is_open && dialog.tab_id.is_none()
+                    })
+                    .map(|dialog| render_ssh_snippet_dialog_overlay(dialog, cx)),
+            )
+            // 定时任务弹窗
+            .children({
+                let dialog_state = self.schedules_state.read(cx).dialog_state.clone();
+                if dialog_state.read(cx).visible {
+                    dialog_state.update(cx, |state, cx| {
+                        state.ensure_inputs_created(window, cx);
+                    });
+                    Some(render_schedule_dialog_overlay(dialog_state, cx))
+                } else {
+                    None
+                }
+            })
             // Snippets 弹窗
             .children({
                 let snippets_dialog_open =
@@ -340,6 +1012,81 @@ impl HomePage {
             })
     }
 
+    /// 执行命令面板中选中的命令
+    fn run_palette_command(
+        &mut self,
+        command: PaletteCommand,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        use gpui_component::notification::NotificationType;
+
+        match command {
+            PaletteCommand::Action(action) => window.dispatch_action(action, cx),
+            PaletteCommand::ConnectServer { id, label } => {
+                self.session_state.update(cx, |state, cx| {
+                    state.add_tab(id, label);
+                    // 确保 Monitor 详情弹窗状态已创建
+                    state.ensure_monitor_detail_dialog(cx);
+                });
+            }
+            PaletteCommand::OpenMenu(menu) => {
+                self.session_state
+                    .update(cx, |state, _| state.show_home = true);
+                self.sidebar_state
+                    .update(cx, |state, _| state.selected_menu = menu);
+            }
+            PaletteCommand::OpenSettings(section) => {
+                // 设置弹窗在主页视图中渲染
+                self.session_state
+                    .update(cx, |state, _| state.show_home = true);
+                self.settings_dialog_state
+                    .update(cx, |state, _| state.open_section(section));
+            }
+            PaletteCommand::OpenWorkspaces => {
+                self.workspace_state.update(cx, |state, _| state.open());
+            }
+            PaletteCommand::RunSnippet(command) => {
+                // 含变量的命令会先在会话视图中弹出变量表单
+                let sent = self
+                    .session_state
+                    .update(cx, |state, cx| state.run_snippet(&command, cx));
+                if sent {
+                    self.session_state
+                        .update(cx, |state, _| state.show_home = false);
+                } else {
+                    let lang = storage::load_settings()
+                        .map(|s| s.theme.language)
+                        .unwrap_or(Language::Chinese);
+                    notifications::notify_in(
+                        window,
+                        cx,
+                        NotificationCategory::Operation,
+                        NotificationType::Warning,
+                        i18n::t(&lang, "palette.no_terminal"),
+                    );
+                }
+            }
+            PaletteCommand::OpenSftpPath { tab_id, path } => {
+                self.session_state.update(cx, |state, cx| {
+                    state.show_session_tab(&tab_id);
+                    state.sftp_navigate_to(&tab_id, path, cx);
+                });
+            }
+            PaletteCommand::OpenSftpFile { tab_id, path } => {
+                self.session_state.update(cx, |state, cx| {
+                    state.show_session_tab(&tab_id);
+                    state.sftp_edit_file(&tab_id, path, cx);
+                });
+            }
+            PaletteCommand::SwitchTab(tab_id) => {
+                self.session_state
+                    .update(cx, |state, _| state.show_session_tab(&tab_id));
+            }
+        }
+        cx.notify();
+    }
+
     /// 渲染会话视图（标签页 + 内容）
     fn render_session_view(
         &mut self,
@@ -347,222 +1094,40 @@ impl HomePage {
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let session_state = self.session_state.clone();
-        let state = session_state.read(cx);
-
-        // 获取当前活动标签
-        let active_tab = state.active_tab().cloned();
-
-        // 渲染内容区域
-        let content: AnyElement = if let Some(tab) = active_tab {
-            match &tab.status {
-                SessionStatus::Connecting => {
-                    // 获取或创建连接进度状态
-                    let progress_state = self
-                        .connecting_progress
-                        .entry(tab.id.clone())
-                        .or_insert_with(|| cx.new(|_| ConnectingProgress::new(tab.id.clone())))
-                        .clone();
-
-                    // 检查是否需要启动连接（首次进入此 tab 时启动）
-                    let should_start = !progress_state.read(cx).connection_started;
-
-                    if should_start {
-                        let server_label = tab.server_label.clone();
-                        info!("[SSH] 开始连接到服务器: {}", server_label);
-
-                        // 标记连接已启动
-                        progress_state.update(cx, |p, _| {
-                            p.mark_started();
-                        });
-
-                        // 启动 SSH 连接（使用 SSH 模块）
-                        let progress_for_timer = progress_state.clone();
-                        let session_for_timer = session_state.clone();
-                        let tab_id = tab.id.clone();
-                        let server_id_for_log = tab.server_id.clone();
-
-                        // 根据 server_id 获取完整的 ServerData
-                        if let Ok(config) = crate::services::storage::load_servers() {
-                            if let Some(server_data) = config
-                                .servers
-                                .iter()
-                                .find(|s| s.id == server_id_for_log)
-                                .cloned()
-                            {
-                                start_ssh_connection(
-                                    server_data,
-                                    tab_id,
-                                    progress_for_timer,
-                                    session_for_timer,
-                                    cx,
-                                );
-                            }
-                        }
-                    }
 
-                    render_connecting_page(&tab, progress_state, session_state.clone(), cx)
-                        .into_any_element()
-                }
-                SessionStatus::Connected => {
-                    // 确保命令输入框已创建
-                    session_state.update(cx, |state, cx| {
-                        state.ensure_command_input_created(window, cx);
-                    });
-
-                    // 确保 SFTP 文件列表视图已创建并同步数据
-                    let tab_id_for_sftp = tab.id.clone();
-                    session_state.update(cx, |state, cx| {
-                        let view = state.ensure_sftp_file_list_view(&tab_id_for_sftp, window, cx);
-                        // 同步 SFTP 状态到 FileListView
-                        let sftp_state = state
-                            .tabs
-                            .iter()
-                            .find(|t| t.id == tab_id_for_sftp)
-                            .and_then(|t| t.sftp_state.as_ref());
-                        view.update(cx, |v, cx| {
-                            v.sync_from_sftp_state(sftp_state, cx);
-                        });
-
-                        // 提取当前路径（避免借用冲突）
-                        let current_path = sftp_state.map(|s| s.current_path.clone());
-
-                        // 确保 PathBarState 创建并同步路径
-                        let entity = cx.entity().clone();
-                        let path_bar =
-                            state.ensure_sftp_path_bar_state(&tab_id_for_sftp, entity, window, cx);
-                        // 同步当前路径到 PathBarState
-                        if let Some(path) = current_path {
-                            path_bar.update(cx, |pb, cx| {
-                                pb.set_path(&path, window, cx);
-                            });
-                        }
-                    });
-
-                    // 检查当前激活的终端是否已初始化
-                    let needs_init = tab
-                        .active_terminal_id
-                        .as_ref()
-                        .and_then(|id| tab.terminals.iter().find(|t| &t.id == id))
-                        .map(|inst| !inst.pty_initialized)
-                        .unwrap_or(false);
-
-                    // 自动初始化 PTY（在 UI 挂载成功后触发）
-                    if needs_init {
-                        let tab_id = tab.id.clone();
-                        let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
-                        session_state.update(cx, |state, cx| {
-                            // 根据窗口尺寸计算终端区域
-                            // 布局常量（与 session_layout.rs 保持一致）
-                            const TITLEBAR_HEIGHT: f32 = 44.0;
-                            const MONITOR_PANEL_WIDTH: f32 = 230.0;
-                            const SFTP_PANEL_HEIGHT: f32 = 300.0;
-                            const SIDEBAR_WIDTH: f32 = 230.0;
-                            const MINI_SIDEBAR_WIDTH: f32 = 28.0;
-                            const COMMAND_INPUT_HEIGHT: f32 = 40.0; // 24px 按钮 + 2*8px padding
-
-                            // 获取窗口内容区域尺寸
-                            let window_size = window.viewport_size();
-                            let window_width = f32::from(window_size.width);
-                            let window_height = f32::from(window_size.height);
-
-                            // 计算终端区域尺寸
-                            let sidebar_width = if sidebar_collapsed {
-                                0.0
-                            } else {
-                                SIDEBAR_WIDTH
-                            };
-                            let terminal_width = window_width
-                                - MONITOR_PANEL_WIDTH
-                                - sidebar_width
-                                - MINI_SIDEBAR_WIDTH;
-                            let terminal_height = window_height
-                                - TITLEBAR_HEIGHT
-                                - SFTP_PANEL_HEIGHT
-                                - COMMAND_INPUT_HEIGHT;
-
-                            debug!(
-                                "[Terminal] Window: {}x{}, Calculated terminal area: {}x{}",
-                                window_width, window_height, terminal_width, terminal_height
-                            );
-
-                            state.initialize_terminal(
-                                &tab_id,
-                                terminal_width.max(100.0),
-                                terminal_height.max(100.0),
-                                window,
-                                cx,
-                            );
-                        });
-                    }
+        // 获取主窗口的当前标签（移到独立窗口的标签不在此显示）
+        let active_tab_id = session_state
+            .read(cx)
+            .window_active_tab(None)
+            .map(|t| t.id.clone());
 
-                    // 确保 SFTP 新建文件夹对话框输入框已创建
-                    let new_folder_dialog = session_state.read(cx).sftp_new_folder_dialog.clone();
-                    if let Some(dialog) = new_folder_dialog {
-                        let is_open = dialog.read(cx).is_open;
-                        if is_open {
-                            dialog.update(cx, |ds, cx| {
-                                ds.ensure_input_created(window, cx);
-                            });
-                        }
-                    }
+        // 活动标签若已休眠，先按快照恢复终端
+        if let Some(tab_id) = &active_tab_id {
+            session_state.update(cx, |state, cx| state.wake_tab(tab_id, cx));
+        }
+        let active_tab = active_tab_id.and_then(|id| {
+            session_state
+                .read(cx)
+                .tabs
+                .iter()
+                .find(|t| t.id == id)
+                .cloned()
+        });
 
-                    // 确保 SFTP 新建文件对话框输入框已创建
-                    let new_file_dialog = session_state.read(cx).sftp_new_file_dialog.clone();
-                    if let Some(dialog) = new_file_dialog {
-                        let is_open = dialog.read(cx).is_open;
-                        if is_open {
-                            dialog.update(cx, |ds, cx| {
-                                ds.ensure_input_created(window, cx);
-                            });
-                        }
-                    }
+        // 窗口背景跟随当前标签的终端透明度（含服务器级覆盖）
+        let opacity_override = active_tab
+            .as_ref()
+            .and_then(|t| t.server_data.as_ref())
+            .and_then(|s| s.terminal_opacity);
+        let appearance = crate::theme::sync_window_background(
+            window,
+            &mut self.window_background,
+            opacity_override,
+        );
 
-                    let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
-                    render_session_layout(
-                        &tab,
-                        sidebar_collapsed,
-                        session_state.clone(),
-                        window,
-                        cx,
-                    )
-                    .into_any_element()
-                }
-                SessionStatus::Disconnected => {
-                    // 断开状态继续显示终端布局，终端区域会显示断开提示
-                    let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
-                    render_session_layout(
-                        &tab,
-                        sidebar_collapsed,
-                        session_state.clone(),
-                        window,
-                        cx,
-                    )
-                    .into_any_element()
-                }
-                SessionStatus::Reconnecting { .. } => {
-                    // 重连状态继续显示终端布局，终端区域会显示重连进度
-                    let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
-                    render_session_layout(
-                        &tab,
-                        sidebar_collapsed,
-                        session_state.clone(),
-                        window,
-                        cx,
-                    )
-                    .into_any_element()
-                }
-                SessionStatus::Error(_) => {
-                    // 错误状态使用连接页面显示
-                    let progress_state = self
-                        .connecting_progress
-                        .entry(tab.id.clone())
-                        .or_insert_with(|| cx.new(|_| ConnectingProgress::new(tab.id.clone())))
-                        .clone();
-
-                    render_connecting_page(&tab, progress_state, session_state.clone(), cx)
-                        .into_any_element()
-                }
-            }
+        // 渲染内容区域
+        let content: AnyElement = if let Some(tab) = active_tab {
+            render_tab_content(tab, session_state.clone(), window, cx)
         } else {
             // 没有活动标签，显示空白
             div().into_any_element()
@@ -570,7 +1135,7 @@ impl HomePage {
 
         div()
             .size_full()
-            .bg(crate::theme::background_color(cx))
+            .bg(crate::theme::session_background_color(appearance, cx))
             .flex()
             .flex_col()
             // 第一行：Home 按钮区域 + 会话标题栏
@@ -581,10 +1146,30 @@ impl HomePage {
                     // Home 按钮区域
                     .child(render_home_button(session_state.clone(), cx))
                     // 会话标题栏（带标签页）
-                    .child(render_session_titlebar(session_state, cx)),
+                    .child(render_session_titlebar(
+                        session_state.clone(),
+                        None,
+                        Some(self.workspace_state.clone()),
+                        cx,
+                    )),
+            )
+            // 第二行：会话内容区域（把标签拖出标签栏放到这里时在新窗口打开）
+            .child(
+                div()
+                    .id("session-content")
+                    .flex_1()
+                    .w_full()
+                    .min_h(px(0.))
+                    .drag_over::<DraggedTab>(|this, _, _, cx| {
+                        this.bg(cx.theme().primary.opacity(0.08))
+                    })
+                    .on_drop(move |dragged: &DraggedTab, _, cx| {
+                        let tab_id = dragged.id.clone();
+                        let session_state = session_state.clone();
+                        cx.defer(move |cx| open_detached_window(tab_id, session_state, cx));
+                    })
+                    .child(content),
             )
-            // 第二行：会话内容区域
-            .child(div().flex_1().w_full().min_h(px(0.)).child(content))
     }
 }
 
@@ -596,18 +1181,68 @@ impl Render for HomePage {
         // 统一的服务器列表刷新逻辑
         let show_home = self.session_state.read(cx).show_home;
         let needs_refresh_from_dialog = self.dialog_state.read(cx).needs_refresh;
+        let needs_refresh_from_import = self.import_state.read(cx).needs_page_refresh;
+        let needs_refresh_from_group = self.group_state.read(cx).needs_page_refresh;
+        let needs_refresh_from_batch = self.batch_state.read(cx).needs_page_refresh;
 
-        // 刷新条件：1) 从会话视图切换到主页视图  2) 对话框保存后需要刷新
-        if (show_home && !self.last_show_home) || needs_refresh_from_dialog {
+        // 刷新条件：1) 从会话视图切换到主页视图  2) 对话框保存、导入服务器、调整分组或批量编辑后需要刷新
+        if (show_home && !self.last_show_home)
+            || needs_refresh_from_dialog
+            || needs_refresh_from_import
+            || needs_refresh_from_group
+            || needs_refresh_from_batch
+        {
             self.reload_servers();
             if needs_refresh_from_dialog {
                 self.dialog_state.update(cx, |state, _| {
                     state.needs_refresh = false;
                 });
             }
+            if needs_refresh_from_import {
+                self.import_state.update(cx, |state, _| {
+                    state.needs_page_refresh = false;
+                });
+            }
+            if needs_refresh_from_group {
+                self.group_state.update(cx, |state, _| {
+                    state.needs_page_refresh = false;
+                });
+            }
+            if needs_refresh_from_batch {
+                self.batch_state.update(cx, |state, _| {
+                    state.needs_page_refresh = false;
+                });
+            }
         }
         self.last_show_home = show_home;
 
+        // 命令面板中选中的命令
+        let palette_command = self
+            .command_palette
+            .update(cx, |state, _| state.pending.take());
+        if let Some(command) = palette_command {
+            self.run_palette_command(command, window, cx);
+        }
+
+        // 搜索框回车发起的快速连接
+        let pending_connect = self
+            .filter_state
+            .update(cx, |state, _| state.pending_connect.take());
+        if let Some(target) = pending_connect {
+            self.session_state.update(cx, |state, cx| {
+                match target {
+                    QuickConnectTarget::Saved { id, label } => {
+                        state.add_tab(id, label);
+                    }
+                    QuickConnectTarget::AdHoc(server) => {
+                        state.add_adhoc_tab(server);
+                    }
+                }
+                // 确保 Monitor 详情弹窗状态已创建
+                state.ensure_monitor_detail_dialog(cx);
+            });
+        }
+
         // Snippets 弹窗保存后刷新
         let snippets_needs_refresh = self
             .snippets_state
@@ -629,19 +1264,47 @@ impl Render for HomePage {
             });
         }
 
-        // 清理已关闭标签的进度状态
-        let active_tabs: Vec<String> = self
-            .session_state
-            .read(cx)
-            .tabs
-            .iter()
-            .map(|t| t.id.clone())
-            .collect();
-        self.connecting_progress
-            .retain(|id, _| active_tabs.contains(id));
+        // 设置中导入配置或同步拉取远程配置后刷新服务器列表与快捷命令
+        let sync_needs_refresh = self.sync_state.read(cx).needs_page_refresh;
+        if self.settings_dialog_state.read(cx).needs_page_refresh || sync_needs_refresh {
+            self.reload_servers();
+            self.snippets_state.update(cx, |state, _| state.refresh());
+            self.session_state.update(cx, |state, _| {
+                state.refresh_snippets_config();
+            });
+            self.settings_dialog_state.update(cx, |state, _| {
+                state.needs_page_refresh = false;
+            });
+            if sync_needs_refresh {
+                // 远程设置可能已写入本地，重新加载设置弹窗中的值
+                self.settings_dialog_state.update(cx, |state, _| {
+                    state.reload_after_import();
+                });
+            }
+            self.settings_dialog_state.update(cx, |state, _| {
+                state.needs_page_refresh = false;
+            });
+            self.sync_state.update(cx, |state, _| {
+                state.needs_page_refresh = false;
+            });
+        }
+
+        // 设置保存后立即应用到已打开的终端
+        if self.settings_dialog_state.read(cx).needs_session_refresh {
+            self.settings_dialog_state.update(cx, |state, _| {
+                state.needs_session_refresh = false;
+            });
+            self.session_state.update(cx, |state, cx| {
+                state.apply_terminal_settings(window, cx);
+            });
+        }
+
+        // 记录视图切换，用于后退/前进
+        let route = self.current_route(cx);
+        self.router.visit(route);
 
         // 根据 show_home 状态决定渲染哪个视图
-        let has_sessions = self.session_state.read(cx).has_sessions();
+        let has_sessions = self.session_state.read(cx).has_main_tabs();
 
         // 如果 show_home=true 或没有会话，显示主页
         // 如果 show_home=false 且有会话，显示会话视图
@@ -651,14 +1314,158 @@ impl Render for HomePage {
             self.render_session_view(window, cx).into_any_element()
         };
 
+        // 标签页总览（仅在有会话时显示）
+        let tab_overview = if has_sessions && self.session_state.read(cx).tab_overview_open {
+            Some(render_tab_overview(self.session_state.clone(), cx))
+        } else {
+            None
+        };
+
+        // 同步冲突对话框（自动同步可能在任意视图下产生冲突）
+        let sync_conflict_dialog = if self.sync_state.read(cx).conflict.is_some() {
+            Some(render_sync_conflict_dialog_overlay(
+                self.sync_state.clone(),
+                cx,
+            ))
+        } else {
+            None
+        };
+
+        // 配置恢复对话框（启动时发现无法解析的配置）
+        let recovery_dialog = if self.recovery_state.read(cx).visible() {
+            Some(render_recovery_dialog_overlay(
+                self.recovery_state.clone(),
+                cx,
+            ))
+        } else {
+            None
+        };
+
+        // 会话工作区弹窗（主页与会话视图的标题栏都可打开）
+        let workspace_dialog = if self.workspace_state.read(cx).visible {
+            self.workspace_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+            Some(render_workspace_dialog_overlay(
+                self.workspace_state.clone(),
+                cx,
+            ))
+        } else {
+            None
+        };
+
+        // 关闭标签确认对话框（只在发起关闭的主窗口显示）
+        let close_tabs_dialog = if self
+            .session_state
+            .read(cx)
+            .pending_tab_close
+            .as_ref()
+            .is_some_and(|pending| pending.window_id.is_none())
+        {
+            Some(render_close_tabs_dialog_overlay(
+                self.session_state.clone(),
+                cx,
+            ))
+        } else {
+            None
+        };
+
+        // 插件请求显示的提示框或确认框
+        let plugin_dialog = if self.session_state.read(cx).plugins.dialogs.is_empty() {
+            None
+        } else {
+            Some(render_plugin_dialog_overlay(self.session_state.clone(), cx))
+        };
+
+        // 通知中心抽屉
+        let notification_drawer = if self.notification_drawer.read(cx).visible {
+            Some(render_notification_drawer(
+                self.notification_drawer.clone(),
+                cx,
+            ))
+        } else {
+            None
+        };
+
+        // 命令面板（覆盖在所有弹窗之上）
+        let command_palette = if self.command_palette.read(cx).visible {
+            self.command_palette.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+            Some(render_command_palette(self.command_palette.clone(), cx))
+        } else {
+            None
+        };
+
         // 获取通知列表
         let notifications = window.notifications(cx);
 
         // 包装主内容和通知列表
-        div()
+        bind_navigation_actions(div(), self.session_state.clone(), None)
             .size_full()
             .relative()
+            .on_action(cx.listener(|this, _: &ToggleTabOverview, _window, cx| {
+                this.session_state.update(cx, |state, cx| {
+                    state.toggle_tab_overview();
+                    cx.notify();
+                });
+            }))
+            .on_action(cx.listener(|this, _: &QuickConnect, _window, cx| {
+                // 回到主页的主机列表并聚焦搜索框
+                this.session_state.update(cx, |state, _| {
+                    state.show_home = true;
+                });
+                this.sidebar_state.update(cx, |state, _| {
+                    state.selected_menu = MenuType::Hosts;
+                });
+                this.filter_state.update(cx, |state, _| {
+                    state.focus_requested = true;
+                });
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &ToggleCommandPalette, _window, cx| {
+                let visible = this.command_palette.read(cx).visible;
+                let session_state = this.session_state.clone();
+                this.command_palette.update(cx, |state, cx| {
+                    if visible {
+                        state.close();
+                    } else {
+                        state.open(session_state.read(cx));
+                    }
+                });
+                cx.notify();
+            }))
+            .on_action(
+                cx.listener(|this, _: &ToggleNotificationCenter, _window, cx| {
+                    this.notification_drawer
+                        .update(cx, |state, _| state.toggle());
+                    cx.notify();
+                }),
+            )
+            .on_action(cx.listener(|this, _: &NavigateBack, _window, cx| {
+                this.navigate_back(cx);
+            }))
+            .on_action(cx.listener(|this, _: &NavigateForward, _window, cx| {
+                this.navigate_forward(cx);
+            }))
+            // 鼠标侧键后退/前进
+            .on_mouse_down(
+                MouseButton::Navigate(NavigationDirection::Back),
+                cx.listener(|this, _, _window, cx| this.navigate_back(cx)),
+            )
+            .on_mouse_down(
+                MouseButton::Navigate(NavigationDirection::Forward),
+                cx.listener(|this, _, _window, cx| this.navigate_forward(cx)),
+            )
             .child(main_content)
+            .children(tab_overview)
+            .children(sync_conflict_dialog)
+            .children(recovery_dialog)
+            .children(workspace_dialog)
+            .children(close_tabs_dialog)
+            .children(plugin_dialog)
+            .children(notification_drawer)
+            .children(command_palette)
             // 通知列表覆盖层（显示在顶部中间）
             .child(
                 div()
@@ -0,0 +1,373 @@
+// 插件页面 - 列出插件目录中的 Rhai 脚本，启用/停用、手动运行，并查看所选插件的日志
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::switch::Switch;
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::error;
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::plugins::{PluginInfo, PluginStatus};
+use crate::services::storage;
+use crate::state::{PluginLogEntry, SessionState};
+
+/// 插件页面状态
+#[derive(Default)]
+pub struct PluginsPageState {
+    /// 查看日志的插件 ID
+    pub selected: Option<String>,
+}
+
+/// 状态标签的 i18n 键
+fn status_key(status: PluginStatus) -> &'static str {
+    match status {
+        PluginStatus::Stopped => "plugins.status.stopped",
+        PluginStatus::Running => "plugins.status.running",
+        PluginStatus::Idle => "plugins.status.idle",
+        PluginStatus::Failed => "plugins.status.failed",
+    }
+}
+
+/// 渲染插件内容区域
+pub fn render_plugins_content(
+    state: Entity<PluginsPageState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let muted = cx.theme().muted_foreground;
+    let runtime = &session_state.read(cx).plugins;
+    let plugins = runtime.plugins.clone();
+    // 默认查看第一个插件的日志
+    let selected = state
+        .read(cx)
+        .selected
+        .clone()
+        .filter(|id| plugins.iter().any(|p| &p.id == id))
+        .or_else(|| plugins.first().map(|p| p.id.clone()));
+    let log = selected
+        .as_ref()
+        .and_then(|id| runtime.logs.get(id))
+        .cloned()
+        .unwrap_or_default();
+
+    div()
+        .flex_1()
+        .h_full()
+        .overflow_hidden()
+        .bg(crate::theme::background_color(cx))
+        .flex()
+        .flex_col()
+        // 头部：说明 + 打开目录、重新加载
+        .child(
+            div()
+                .flex_shrink_0()
+                .p_6()
+                .pb_4()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_4()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "plugins.hint")),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .gap_2()
+                        .child(
+                            Button::new("plugins-open-dir")
+                                .small()
+                                .outline()
+                                .child(i18n::t(&lang, "plugins.open_dir"))
+                                .on_click(|_, _, cx| match storage::get_plugins_dir() {
+                                    Ok(dir) => cx.open_with_system(&dir),
+                                    Err(e) => {
+                                        error!("[Plugins] No plugins directory: {:#}", e)
+                                    }
+                                }),
+                        )
+                        .child(
+                            Button::new("plugins-reload")
+                                .small()
+                                .outline()
+                                .child(i18n::t(&lang, "plugins.reload"))
+                                .on_click({
+                                    let session_state = session_state.clone();
+                                    move |_, _, cx| {
+                                        session_state.update(cx, |state, cx| {
+                                            state.reload_plugins(cx);
+                                        });
+                                    }
+                                }),
+                        ),
+                ),
+        )
+        .child(if plugins.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_3()
+                .child(render_icon(icons::PUZZLE, muted.into()))
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "plugins.empty")),
+                )
+                .into_any_element()
+        } else {
+            div()
+                .flex_1()
+                .min_h(px(0.))
+                .px_6()
+                .pb_6()
+                .flex()
+                .gap_4()
+                // 插件列表
+                .child(
+                    div()
+                        .id("plugins-scroll")
+                        .w(px(380.))
+                        .flex_shrink_0()
+                        .overflow_y_scroll()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .children(plugins.iter().enumerate().map(|(index, plugin)| {
+                            let status = runtime.status(&plugin.id);
+                            let enabled = runtime.is_enabled(&plugin.id);
+                            let is_selected = selected.as_deref() == Some(plugin.id.as_str());
+                            render_plugin_row(
+                                index,
+                                plugin,
+                                status,
+                                enabled,
+                                is_selected,
+                                &lang,
+                                state.clone(),
+                                session_state.clone(),
+                                cx,
+                            )
+                        })),
+                )
+                // 日志
+                .child(render_log(selected, log, &lang, session_state.clone(), cx))
+                .into_any_element()
+        })
+}
+
+/// 渲染插件列表中的一行：名称、版本、说明、状态、运行按钮与启用开关
+#[allow(clippy::too_many_arguments)]
+fn render_plugin_row(
+    index: usize,
+    plugin: &PluginInfo,
+    status: PluginStatus,
+    enabled: bool,
+    is_selected: bool,
+    lang: &Language,
+    state: Entity<PluginsPageState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let status_color = match status {
+        PluginStatus::Failed => cx.theme().danger,
+        PluginStatus::Running => cx.theme().primary,
+        PluginStatus::Idle | PluginStatus::Stopped => muted,
+    };
+    let title = match &plugin.version {
+        Some(version) => format!("{} {}", plugin.name, version),
+        None => plugin.name.clone(),
+    };
+    let select_id = plugin.id.clone();
+    let run_id = plugin.id.clone();
+    let toggle_id = plugin.id.clone();
+    let run_state = session_state.clone();
+
+    div()
+        .id(("plugin-row", index))
+        .px_4()
+        .py_3()
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(if is_selected {
+            cx.theme().primary
+        } else {
+            cx.theme().border
+        })
+        .cursor_pointer()
+        .flex()
+        .items_center()
+        .gap_3()
+        .on_click(move |_, _, cx| {
+            state.update(cx, |state, cx| {
+                state.selected = Some(select_id.clone());
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(title),
+                )
+                .children(plugin.description.clone().map(|description| {
+                    div()
+                        .text_xs()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(description)
+                }))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(status_color)
+                        .child(i18n::t(lang, status_key(status))),
+                ),
+        )
+        .when(enabled, |this| {
+            this.child(
+                Button::new(("plugin-run", index))
+                    .xsmall()
+                    .ghost()
+                    .disabled(status == PluginStatus::Running)
+                    .child(i18n::t(lang, "plugins.run"))
+                    .on_click(move |_, _, cx| {
+                        cx.stop_propagation();
+                        run_state.update(cx, |state, _| state.run_plugin(&run_id));
+                    }),
+            )
+        })
+        .child(
+            Switch::new(("plugin-enabled", index))
+                .checked(enabled)
+                .on_click(move |checked, _, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.set_plugin_enabled(&toggle_id, *checked, cx);
+                    });
+                }),
+        )
+}
+
+/// 渲染所选插件的日志（最新的在前）
+fn render_log(
+    selected: Option<String>,
+    log: Vec<PluginLogEntry>,
+    lang: &Language,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let foreground = cx.theme().foreground;
+    let danger = cx.theme().danger;
+    let is_empty = log.is_empty();
+
+    div()
+        .flex_1()
+        .min_w_0()
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(
+            div()
+                .flex_shrink_0()
+                .px_4()
+                .py_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(muted)
+                        .child(i18n::t(lang, "plugins.log")),
+                )
+                .child(
+                    Button::new("plugins-clear-log")
+                        .xsmall()
+                        .ghost()
+                        .disabled(is_empty)
+                        .child(i18n::t(lang, "plugins.clear_log"))
+                        .on_click(move |_, _, cx| {
+                            if let Some(plugin_id) = &selected {
+                                session_state.update(cx, |state, cx| {
+                                    state.clear_plugin_log(plugin_id);
+                                    cx.notify();
+                                });
+                            }
+                        }),
+                ),
+        )
+        .child(if is_empty {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(muted)
+                .child(i18n::t(lang, "plugins.log_empty"))
+                .into_any_element()
+        } else {
+            div()
+                .id("plugins-log")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .px_4()
+                .py_2()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(log.into_iter().rev().map(|entry| {
+                    div()
+                        .flex()
+                        .gap_2()
+                        .text_xs()
+                        .child(div().flex_shrink_0().text_color(muted).child(entry.time))
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .font_family("monospace")
+                                .text_color(if entry.is_error { danger } else { foreground })
+                                .child(entry.message),
+                        )
+                }))
+                .into_any_element()
+        })
+}
@@ -0,0 +1,483 @@
+// 定时任务页面 - 管理按 cron 计划在服务器上运行快捷命令或 Runbook 的任务，
+// 手动运行、启用/停用，并查看每次运行的结果（点击记录展开输出）
+
+use std::collections::HashSet;
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::switch::Switch;
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::error;
+
+use crate::components::common::icon::render_icon;
+use crate::components::common::schedule_dialog::{task_label, ScheduleDialogState};
+use crate::components::cron::describe_schedule;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::schedule::{JobRun, ScheduledJob};
+use crate::models::settings::Language;
+use crate::models::SnippetsConfig;
+use crate::services::{scheduler, storage};
+use crate::state::SessionState;
+
+/// 定时任务页面状态
+pub struct SchedulesPageState {
+    /// 任务编辑弹窗状态
+    pub dialog_state: Entity<ScheduleDialogState>,
+    /// 展开输出的运行记录 ID
+    pub expanded: HashSet<String>,
+}
+
+impl SchedulesPageState {
+    pub fn new(cx: &mut App) -> Self {
+        Self {
+            dialog_state: cx.new(|_| ScheduleDialogState::default()),
+            expanded: HashSet::new(),
+        }
+    }
+}
+
+/// 渲染定时任务内容区域
+pub fn render_schedules_content(
+    state: Entity<SchedulesPageState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let muted = cx.theme().muted_foreground;
+    let jobs = storage::load_schedules().unwrap_or_default().jobs;
+    let runs = storage::load_job_history().unwrap_or_default().runs;
+    let servers = storage::load_servers().unwrap_or_default().servers;
+    let snippets = storage::load_snippets().unwrap_or_default();
+    let running = session_state.read(cx).running_jobs.clone();
+    let expanded = state.read(cx).expanded.clone();
+    let dialog_state = state.read(cx).dialog_state.clone();
+
+    div()
+        .flex_1()
+        .h_full()
+        .overflow_hidden()
+        .bg(crate::theme::background_color(cx))
+        .flex()
+        .flex_col()
+        // 头部：说明 + 新建任务
+        .child(
+            div()
+                .flex_shrink_0()
+                .p_6()
+                .pb_4()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_4()
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "schedules.hint")),
+                )
+                .child(
+                    Button::new("schedules-add")
+                        .small()
+                        .primary()
+                        .child(i18n::t(&lang, "schedules.add"))
+                        .on_click({
+                            let dialog_state = dialog_state.clone();
+                            move |_, _, cx| {
+                                dialog_state.update(cx, |s, cx| {
+                                    s.open_new();
+                                    cx.notify();
+                                });
+                            }
+                        }),
+                ),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_h(px(0.))
+                .px_6()
+                .pb_6()
+                .flex()
+                .gap_4()
+                // 任务列表
+                .child(if jobs.is_empty() {
+                    div()
+                        .w(px(420.))
+                        .flex_shrink_0()
+                        .flex()
+                        .flex_col()
+                        .items_center()
+                        .justify_center()
+                        .gap_3()
+                        .child(render_icon(icons::CLOCK, muted.into()))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(muted)
+                                .child(i18n::t(&lang, "schedules.empty")),
+                        )
+                        .into_any_element()
+                } else {
+                    div()
+                        .id("schedules-scroll")
+                        .w(px(420.))
+                        .flex_shrink_0()
+                        .overflow_y_scroll()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .children(jobs.iter().enumerate().map(|(index, job)| {
+                            let server_label = servers
+                                .iter()
+                                .find(|s| s.id == job.server_id)
+                                .map(|s| s.label.clone());
+                            render_job_row(
+                                index,
+                                job,
+                                server_label,
+                                &snippets,
+                                running.contains(&job.id),
+                                &lang,
+                                dialog_state.clone(),
+                                session_state.clone(),
+                                cx,
+                            )
+                        }))
+                        .into_any_element()
+                })
+                // 运行记录
+                .child(render_history(runs, expanded, &lang, state, cx)),
+        )
+}
+
+/// 渲染任务列表中的一行：名称、服务器与运行内容、计划与下次运行时间、操作按钮与启用开关
+#[allow(clippy::too_many_arguments)]
+fn render_job_row(
+    index: usize,
+    job: &ScheduledJob,
+    server_label: Option<String>,
+    snippets: &SnippetsConfig,
+    is_running: bool,
+    lang: &Language,
+    dialog_state: Entity<ScheduleDialogState>,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let missing = i18n::t(lang, "schedules.missing");
+    let target = format!(
+        "{} · {}",
+        server_label.unwrap_or_else(|| missing.to_string()),
+        task_label(&job.task, snippets, lang).unwrap_or_else(|| missing.to_string())
+    );
+    let schedule = match scheduler::parse_schedule(&job.schedule) {
+        Ok(parsed) => {
+            let mut text = format!("{} · {}", job.schedule, describe_schedule(&parsed, lang));
+            if let Some(next) = job
+                .enabled
+                .then(|| scheduler::next_run(job, chrono::Local::now()))
+                .flatten()
+            {
+                text.push_str(&format!(
+                    " · {} {}",
+                    i18n::t(lang, "schedules.next_run"),
+                    next.format("%m-%d %H:%M")
+                ));
+            }
+            text
+        }
+        Err(_) => format!("{} · {}", job.schedule, i18n::t(lang, "cron.hint.invalid")),
+    };
+    let run_job = job.clone();
+    let edit_job = job.clone();
+    let delete_id = job.id.clone();
+    let toggle_id = job.id.clone();
+
+    div()
+        .id(("schedule-row", index))
+        .px_4()
+        .py_3()
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .gap_3()
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(if job.enabled {
+                            cx.theme().foreground
+                        } else {
+                            muted
+                        })
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(job.name.clone()),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(target),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(if is_running {
+                            cx.theme().primary
+                        } else {
+                            muted
+                        })
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(if is_running {
+                            i18n::t(lang, "schedules.running").to_string()
+                        } else {
+                            schedule
+                        }),
+                ),
+        )
+        .child(
+            Button::new(("schedule-run", index))
+                .xsmall()
+                .ghost()
+                .disabled(is_running)
+                .child(i18n::t(lang, "schedules.run_now"))
+                .on_click(move |_, _, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.run_scheduled_job(run_job.clone(), true, cx);
+                    });
+                }),
+        )
+        .child(
+            Button::new(("schedule-edit", index))
+                .xsmall()
+                .ghost()
+                .child(i18n::t(lang, "schedules.edit"))
+                .on_click(move |_, _, cx| {
+                    dialog_state.update(cx, |s, cx| {
+                        s.open_edit(&edit_job);
+                        cx.notify();
+                    });
+                }),
+        )
+        .child(
+            Button::new(("schedule-delete", index))
+                .xsmall()
+                .ghost()
+                .child(i18n::t(lang, "schedules.delete"))
+                .on_click(move |_, _, _| {
+                    if let Err(e) = storage::delete_scheduled_job(&delete_id) {
+                        error!("[Scheduler] Failed to delete job: {}", e);
+                    }
+                }),
+        )
+        .child(
+            Switch::new(("schedule-enabled", index))
+                .checked(job.enabled)
+                .on_click(move |checked, _, _| {
+                    if let Err(e) = storage::set_scheduled_job_enabled(&toggle_id, *checked) {
+                        error!("[Scheduler] Failed to update job: {}", e);
+                    }
+                }),
+        )
+}
+
+/// 渲染运行记录（最新的在前，点击展开输出）
+fn render_history(
+    runs: Vec<JobRun>,
+    expanded: HashSet<String>,
+    lang: &Language,
+    state: Entity<SchedulesPageState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let is_empty = runs.is_empty();
+
+    div()
+        .flex_1()
+        .min_w_0()
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(
+            div()
+                .flex_shrink_0()
+                .px_4()
+                .py_2()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .text_xs()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(muted)
+                        .child(i18n::t(lang, "schedules.history")),
+                )
+                .child(
+                    Button::new("schedules-clear-history")
+                        .xsmall()
+                        .ghost()
+                        .disabled(is_empty)
+                        .child(i18n::t(lang, "schedules.clear_history"))
+                        .on_click(|_, _, _| {
+                            if let Err(e) = storage::clear_job_history() {
+                                error!("[Scheduler] Failed to clear job history: {}", e);
+                            }
+                        }),
+                ),
+        )
+        .child(if is_empty {
+            div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(muted)
+                .child(i18n::t(lang, "schedules.history_empty"))
+                .into_any_element()
+        } else {
+            div()
+                .id("schedules-history")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .flex()
+                .flex_col()
+                .children(runs.into_iter().enumerate().map(|(index, run)| {
+                    let is_expanded = expanded.contains(&run.id);
+                    render_run_row(index, run, is_expanded, lang, state.clone(), cx)
+                }))
+                .into_any_element()
+        })
+}
+
+/// 渲染一条运行记录：状态、任务、服务器、开始时间与耗时，展开时显示输出与错误
+fn render_run_row(
+    index: usize,
+    run: JobRun,
+    is_expanded: bool,
+    lang: &Language,
+    state: Entity<SchedulesPageState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let success = run.is_success();
+    let status = match (run.exit_code, &run.error) {
+        (_, Some(_)) => i18n::t(lang, "schedules.status.error").to_string(),
+        (Some(0), None) => i18n::t(lang, "schedules.status.success").to_string(),
+        (Some(code), None) => format!("{} {}", i18n::t(lang, "schedules.status.exit"), code),
+        (None, None) => i18n::t(lang, "schedules.status.error").to_string(),
+    };
+    let mut details = format!(
+        "{} · {} · {:.1}s",
+        run.server_label,
+        run.started_at,
+        run.duration_ms as f64 / 1000.0
+    );
+    if run.manual {
+        details.push_str(&format!(" · {}", i18n::t(lang, "schedules.manual")));
+    }
+    let toggle_id = run.id.clone();
+
+    div()
+        .id(("schedule-run-row", index))
+        .px_4()
+        .py_2()
+        .border_b_1()
+        .border_color(cx.theme().border)
+        .cursor_pointer()
+        .hover(|s| s.bg(cx.theme().muted))
+        .flex()
+        .flex_col()
+        .gap_1()
+        .on_click(move |_, _, cx| {
+            state.update(cx, |state, cx| {
+                if !state.expanded.remove(&toggle_id) {
+                    state.expanded.insert(toggle_id.clone());
+                }
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_2()
+                .text_xs()
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .w(px(72.))
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(if success {
+                            cx.theme().success
+                        } else {
+                            cx.theme().danger
+                        })
+                        .child(status),
+                )
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_color(cx.theme().foreground)
+                        .child(run.job_name.clone()),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(details),
+                ),
+        )
+        .when(is_expanded, |this| {
+            this.when_some(run.error.clone(), |this, error| {
+                this.child(div().text_xs().text_color(cx.theme().danger).child(error))
+            })
+            .child(
+                div()
+                    .p_2()
+                    .rounded_md()
+                    .bg(cx.theme().muted)
+                    .text_xs()
+                    .font_family("monospace")
+                    .text_color(cx.theme().foreground)
+                    .child(if run.output.is_empty() {
+                        i18n::t(lang, "schedules.no_output").to_string()
+                    } else {
+                        run.output.clone()
+                    }),
+            )
+        })
+}
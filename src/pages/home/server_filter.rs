@@ -0,0 +1,393 @@
+// 主页服务器过滤栏 - 文本搜索与标签筛选组合过滤，兼作快速连接入口
+// 搜索词按空白拆分，每个词需模糊命中名称、主机、账号、描述或标签之一；选中的标签需全部具备
+// 回车连接排名最高的服务器；输入 `user@host:port` 时可不保存直接临时连接
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputEvent, InputState};
+use gpui_component::{ActiveTheme, Sizable};
+
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::ServerData;
+use crate::models::settings::Language;
+use crate::models::{Server, ServerGroup};
+use crate::services::server_search::{self, AdHocTarget};
+use crate::services::storage;
+
+/// 待发起的快速连接（由主页在渲染时取走并打开会话标签）
+pub enum QuickConnectTarget {
+    /// 已保存的服务器
+    Saved { id: String, label: String },
+    /// 未保存的临时服务器
+    AdHoc(ServerData),
+}
+
+/// 服务器过滤状态
+#[derive(Default)]
+pub struct ServerFilterState {
+    pub search_input: Option<Entity<InputState>>,
+    /// 临时连接的密码输入框
+    pub password_input: Option<Entity<InputState>>,
+    /// 选中的标签（需同时具备）
+    pub selected_tags: Vec<String>,
+    /// 待发起的快速连接
+    pub pending_connect: Option<QuickConnectTarget>,
+    /// 下次渲染时聚焦搜索框（快速连接快捷键）
+    pub focus_requested: bool,
+}
+
+impl ServerFilterState {
+    /// 确保搜索框已创建（在 Window 上下文中调用）
+    pub fn ensure_inputs_created(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        if self.search_input.is_none() {
+            let placeholder = i18n::t(&lang, "server_list.search_placeholder");
+            let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+            cx.subscribe_in(&input, window, |this, _, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.connect_top_hit(window, cx);
+                }
+            })
+            .detach();
+            self.search_input = Some(input);
+        }
+        if self.password_input.is_none() {
+            let placeholder = i18n::t(&lang, "server_list.adhoc_password");
+            let input = cx.new(|cx| {
+                InputState::new(window, cx)
+                    .placeholder(placeholder)
+                    .masked(true)
+            });
+            cx.subscribe_in(&input, window, |this, _, event: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = event {
+                    this.connect_top_hit(window, cx);
+                }
+            })
+            .detach();
+            self.password_input = Some(input);
+        }
+    }
+
+    /// 搜索框原始内容
+    fn query(&self, cx: &App) -> String {
+        self.search_input
+            .as_ref()
+            .map(|input| input.read(cx).value().to_string())
+            .unwrap_or_default()
+    }
+
+    /// 回车时要连接的目标：`user@host:port` 优先（已保存则复用），否则为排名最高的服务器
+    pub fn quick_target(&self, cx: &App) -> Option<QuickConnectTarget> {
+        let query = self.query(cx);
+        let config = storage::load_servers().unwrap_or_default();
+        if let Some(target) = AdHocTarget::parse(&query) {
+            return Some(match target.find_saved(&config) {
+                Some(server) => QuickConnectTarget::Saved {
+                    id: server.id.clone(),
+                    label: server.label.clone(),
+                },
+                None => {
+                    let password = self
+                        .password_input
+                        .as_ref()
+                        .map(|input| input.read(cx).value().to_string());
+                    QuickConnectTarget::AdHoc(target.to_server_data(password))
+                }
+            });
+        }
+        server_search::best_match(&config, &query, &self.selected_tags).map(|server| {
+            QuickConnectTarget::Saved {
+                id: server.id.clone(),
+                label: server.label.clone(),
+            }
+        })
+    }
+
+    /// 连接回车目标并清空输入
+    fn connect_top_hit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(target) = self.quick_target(cx) else {
+            return;
+        };
+        self.pending_connect = Some(target);
+        for input in [&self.search_input, &self.password_input]
+            .into_iter()
+            .flatten()
+        {
+            input.update(cx, |s, cx| s.set_value("", window, cx));
+        }
+        cx.notify();
+    }
+
+    /// 切换标签的选中状态
+    pub fn toggle_tag(&mut self, tag: String) {
+        if let Some(pos) = self.selected_tags.iter().position(|t| *t == tag) {
+            self.selected_tags.remove(pos);
+        } else {
+            self.selected_tags.push(tag);
+        }
+    }
+
+    /// 小写化的搜索词
+    fn search_terms(&self, cx: &App) -> Vec<String> {
+        server_search::search_terms(&self.query(cx))
+    }
+
+    /// 是否有生效的过滤条件
+    pub fn is_active(&self, cx: &App) -> bool {
+        !self.selected_tags.is_empty() || !self.search_terms(cx).is_empty()
+    }
+
+    /// 过滤服务器分组；过滤生效时隐藏没有匹配服务器的分组，组内按匹配度排序
+    pub fn apply(&self, groups: &[ServerGroup], cx: &App) -> Vec<ServerGroup> {
+        let terms = self.search_terms(cx);
+        if terms.is_empty() && self.selected_tags.is_empty() {
+            return groups.to_vec();
+        }
+        groups
+            .iter()
+            .filter_map(|group| {
+                let mut scored: Vec<(i32, &Server)> = group
+                    .servers
+                    .iter()
+                    .filter_map(|s| {
+                        server_score(s, &terms, &self.selected_tags).map(|score| (score, s))
+                    })
+                    .collect();
+                // 稳定排序：同分时保持原有顺序
+                scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+                let servers: Vec<Server> = scored.into_iter().map(|(_, s)| s.clone()).collect();
+                (!servers.is_empty()).then(|| ServerGroup {
+                    servers,
+                    ..group.clone()
+                })
+            })
+            .collect()
+    }
+}
+
+/// 服务器的匹配得分；不具备所有选中标签或有搜索词未命中时为 None
+fn server_score(server: &Server, terms: &[String], tags: &[String]) -> Option<i32> {
+    let has_tags = tags
+        .iter()
+        .all(|tag| server.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)));
+    if !has_tags {
+        return None;
+    }
+    let mut fields = vec![
+        server.name.as_str(),
+        server.host.as_str(),
+        server.account.as_str(),
+        server.description.as_str(),
+    ];
+    fields.extend(server.tags.iter().map(String::as_str));
+    server_search::match_score(terms, &fields)
+}
+
+/// 所有服务器上出现过的标签（去重并排序）
+pub fn collect_tags(groups: &[ServerGroup]) -> Vec<String> {
+    let mut tags: Vec<String> = groups
+        .iter()
+        .flat_map(|g| g.servers.iter())
+        .flat_map(|s| s.tags.iter().cloned())
+        .collect();
+    tags.sort_by_key(|t| t.to_lowercase());
+    tags.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    tags
+}
+
+/// 渲染过滤栏：搜索框 + 标签筛选 + 匹配数量
+pub fn render_filter_bar(
+    state: Entity<ServerFilterState>,
+    all_tags: Vec<String>,
+    matched: usize,
+    total: usize,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let state_read = state.read(cx);
+    let muted = cx.theme().muted_foreground;
+    let active = state_read.is_active(cx);
+    let selected_tags = state_read.selected_tags.clone();
+    let adhoc = AdHocTarget::parse(&state_read.query(cx)).filter(|target| {
+        target
+            .find_saved(&storage::load_servers().unwrap_or_default())
+            .is_none()
+    });
+    let top_hit = match adhoc {
+        Some(_) => None,
+        None => match state_read.quick_target(cx) {
+            Some(QuickConnectTarget::Saved { label, .. }) => Some(label),
+            _ => None,
+        },
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .children(state_read.search_input.as_ref().map(|input| {
+                    div().flex_1().child(
+                        Input::new(input)
+                            .small()
+                            .cleanable(true)
+                            .prefix(svg().path(icons::SEARCH).size(px(14.)).text_color(muted)),
+                    )
+                }))
+                .when(active, |this| {
+                    this.child(
+                        div().flex_shrink_0().text_xs().text_color(muted).child(
+                            i18n::t(&lang, "server_list.match_count")
+                                .replacen("{}", &matched.to_string(), 1)
+                                .replacen("{}", &total.to_string(), 1),
+                        ),
+                    )
+                }),
+        )
+        // 临时连接：user@host:port + 可选密码
+        .children(adhoc.map(|target| {
+            let state = state.clone();
+            div()
+                .flex()
+                .items_center()
+                .gap_3()
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_sm()
+                        .text_color(cx.theme().foreground)
+                        .child(
+                            i18n::t(&lang, "server_list.adhoc_connect")
+                                .replace("{}", &target.display()),
+                        ),
+                )
+                .children(
+                    state_read
+                        .password_input
+                        .as_ref()
+                        .map(|input| div().w(px(220.)).child(Input::new(input).small())),
+                )
+                .child(
+                    Button::new("server-filter-adhoc-connect")
+                        .primary()
+                        .small()
+                        .child(i18n::t(&lang, "server_list.connect"))
+                        .on_click(move |_, window, cx| {
+                            state.update(cx, |s, cx| s.connect_top_hit(window, cx));
+                        }),
+                )
+        }))
+        // 回车将连接的服务器
+        .children(top_hit.map(|label| {
+            div()
+                .text_xs()
+                .text_color(muted)
+                .child(i18n::t(&lang, "server_list.enter_to_connect").replace("{}", &label))
+        }))
+        .when(!all_tags.is_empty(), |this| {
+            this.child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .items_center()
+                    .gap_2()
+                    .child(svg().path(icons::TAG).size(px(14.)).text_color(muted))
+                    .children(all_tags.into_iter().enumerate().map(|(index, tag)| {
+                        let selected = selected_tags.contains(&tag);
+                        render_tag_toggle(index, tag, selected, state.clone(), cx)
+                    }))
+                    .when(!selected_tags.is_empty(), |this| {
+                        let state = state.clone();
+                        this.child(
+                            div()
+                                .id("server-filter-clear-tags")
+                                .px_2()
+                                .text_xs()
+                                .text_color(cx.theme().link)
+                                .cursor_pointer()
+                                .on_click(move |_, _, cx| {
+                                    state.update(cx, |s, cx| {
+                                        s.selected_tags.clear();
+                                        cx.notify();
+                                    });
+                                })
+                                .child(i18n::t(&lang, "server_list.clear_filter")),
+                        )
+                    }),
+            )
+        })
+}
+
+/// 渲染可点击的标签筛选项
+fn render_tag_toggle(
+    index: usize,
+    tag: String,
+    selected: bool,
+    state: Entity<ServerFilterState>,
+    cx: &App,
+) -> impl IntoElement {
+    let (bg, fg, border) = if selected {
+        (
+            cx.theme().primary,
+            cx.theme().primary_foreground,
+            cx.theme().primary,
+        )
+    } else {
+        (
+            cx.theme().secondary,
+            cx.theme().foreground,
+            cx.theme().border,
+        )
+    };
+    let hover_border = cx.theme().primary;
+
+    div()
+        .id(("server-filter-tag", index))
+        .px_2()
+        .py_0p5()
+        .rounded_full()
+        .border_1()
+        .border_color(border)
+        .bg(bg)
+        .text_xs()
+        .text_color(fg)
+        .cursor_pointer()
+        .hover(move |s| s.border_color(hover_border))
+        .on_click({
+            let tag = tag.clone();
+            move |_, _, cx| {
+                state.update(cx, |s, cx| {
+                    s.toggle_tag(tag.clone());
+                    cx.notify();
+                });
+            }
+        })
+        .child(tag)
+}
+
+/// 渲染服务器上的标签小块（只读）
+pub fn render_tag_chips(tags: &[String], color: Hsla) -> impl IntoElement {
+    div()
+        .flex()
+        .flex_wrap()
+        .gap_1()
+        .children(tags.iter().map(|tag| {
+            div()
+                .px_1p5()
+                .rounded(px(3.))
+                .bg(color.opacity(0.12))
+                .text_xs()
+                .text_color(color)
+                .child(tag.clone())
+        }))
+}
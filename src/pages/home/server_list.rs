@@ -1,16 +1,28 @@
 // 服务器列表组件
 
+use gpui::prelude::*;
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{ContextMenuExt, DropdownMenu, PopupMenu, PopupMenuItem};
+use gpui_component::notification::{Notification, NotificationType};
 use gpui_component::scroll::ScrollableElement;
-use gpui_component::{ActiveTheme, InteractiveElementExt};
+use gpui_component::tooltip::Tooltip;
+use gpui_component::{ActiveTheme, InteractiveElementExt, WindowExt};
 use tracing::error;
 
+use super::server_filter::{collect_tags, render_filter_bar, render_tag_chips, ServerFilterState};
+use crate::components::common::batch_edit_dialog::BatchEditState;
+use crate::components::common::group_dialog::GroupDialogState;
+use crate::components::common::host_discovery_dialog::HostDiscoveryState;
 use crate::components::common::icon::render_icon;
 use crate::components::common::server_dialog::ServerDialogState;
+use crate::components::common::server_import_dialog::ServerImportState;
 use crate::constants::icons;
 use crate::i18n;
 use crate::models::settings::Language;
 use crate::models::{Server, ServerGroup};
+use crate::services::inventory::{self, SharedInventory};
+use crate::services::reachability::{Reachability, ReachabilityService, ReachabilityStore};
 use crate::services::storage;
 use crate::state::SessionState;
 
@@ -27,23 +39,94 @@ pub struct ViewModeState {
     pub mode: ViewMode,
 }
 
+/// 拖拽中的服务器（放到分组标题上移入该分组，放到服务器上排在其前面）
+#[derive(Clone)]
+pub struct DraggedServer {
+    pub id: String,
+    pub name: String,
+}
+
+/// 拖拽中的分组（放到其他分组标题上成为其子分组，放到“未分组”上移到顶层）
+#[derive(Clone)]
+pub struct DraggedGroup {
+    pub id: String,
+    pub name: String,
+}
+
+impl Render for DraggedServer {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        render_drag_preview(icons::TERMINAL, self.name.clone(), cx)
+    }
+}
+
+impl Render for DraggedGroup {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        render_drag_preview(icons::FOLDER, self.name.clone(), cx)
+    }
+}
+
+/// 拖拽时跟随鼠标的预览
+pub fn render_drag_preview(icon: &'static str, name: String, cx: &App) -> impl IntoElement {
+    div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .px_2()
+        .py_1()
+        .bg(cx.theme().popover)
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(px(4.))
+        .shadow_md()
+        .child(render_icon(icon, cx.theme().primary))
+        .child(
+            div()
+                .text_xs()
+                .text_color(cx.theme().foreground)
+                .child(name),
+        )
+}
+
 /// 渲染主机内容区域（包含工具栏和服务器列表）
 pub fn render_hosts_content(
     server_groups: &[ServerGroup],
     view_mode: ViewMode,
     view_state: Entity<ViewModeState>,
     dialog_state: Entity<ServerDialogState>,
+    import_state: Entity<ServerImportState>,
+    discovery_state: Entity<HostDiscoveryState>,
+    group_state: Entity<GroupDialogState>,
+    filter_state: Entity<ServerFilterState>,
+    batch_state: Entity<BatchEditState>,
     session_state: Entity<SessionState>,
     cx: &App,
 ) -> impl IntoElement {
     let dialog_state_for_list = dialog_state.clone();
     let dialog_state_for_card = dialog_state.clone();
     let dialog_state_for_empty = dialog_state.clone();
+    let group_state_for_toolbar = group_state.clone();
     let session_state_for_list = session_state.clone();
     let session_state_for_card = session_state;
 
-    // 检查是否有任何服务器
-    let has_servers = server_groups.iter().any(|g| !g.servers.is_empty());
+    // 检查是否有任何服务器或分组（空分组也需要显示，便于拖入服务器）
+    let has_servers = !server_groups.is_empty();
+
+    // 按搜索词与标签过滤
+    let total = server_groups.iter().map(|g| g.servers.len()).sum::<usize>();
+    let all_tags = collect_tags(server_groups);
+    let filtering = filter_state.read(cx).is_active(cx);
+    let filtered_groups = filter_state.read(cx).apply(server_groups, cx);
+    let matched = filtered_groups
+        .iter()
+        .map(|g| g.servers.len())
+        .sum::<usize>();
+    let server_groups = filtered_groups.as_slice();
+    let visible_ids: Vec<String> = server_groups
+        .iter()
+        .filter(|g| !g.shared)
+        .flat_map(|g| g.servers.iter().map(|s| s.id.clone()))
+        .collect();
+    let has_selection = !batch_state.read(cx).selected_ids.is_empty();
 
     let bg_color = crate::theme::background_color(cx);
 
@@ -60,7 +143,28 @@ pub fn render_hosts_content(
                 .flex_shrink_0() // 不压缩
                 .p_6()
                 .pb_4()
-                .child(render_toolbar(view_mode, view_state, dialog_state, cx))
+                .flex()
+                .flex_col()
+                .gap_4()
+                .child(render_toolbar(
+                    view_mode,
+                    view_state,
+                    dialog_state,
+                    import_state,
+                    discovery_state,
+                    group_state_for_toolbar,
+                    cx,
+                ))
+                .child(render_filter_bar(
+                    filter_state,
+                    all_tags,
+                    matched,
+                    total,
+                    cx,
+                ))
+                .when(has_selection, |this| {
+                    this.child(render_selection_bar(batch_state.clone(), visible_ids, cx))
+                })
                 .into_any_element()
         } else {
             // 没有服务器时不显示工具栏
@@ -75,10 +179,26 @@ pub fn render_hosts_content(
                 .overflow_y_scrollbar()
                 .px_6()
                 .pb_6()
+                .when(filtering && server_groups.is_empty(), |this| {
+                    let lang = storage::load_settings()
+                        .map(|s| s.theme.language)
+                        .unwrap_or(Language::Chinese);
+                    this.child(
+                        div()
+                            .pt_8()
+                            .flex()
+                            .justify_center()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(i18n::t(&lang, "server_list.no_match")),
+                    )
+                })
                 .child(match view_mode {
                     ViewMode::List => render_list_view(
                         server_groups,
                         dialog_state_for_list,
+                        group_state.clone(),
+                        batch_state.clone(),
                         session_state_for_list,
                         cx,
                     )
@@ -86,6 +206,8 @@ pub fn render_hosts_content(
                     ViewMode::Card => render_card_view(
                         server_groups,
                         dialog_state_for_card,
+                        group_state,
+                        batch_state,
                         session_state_for_card,
                         cx,
                     )
@@ -94,7 +216,8 @@ pub fn render_hosts_content(
                 .into_any_element()
         } else {
             // 没有服务器时显示空状态
-            render_empty_state(dialog_state_for_empty, cx).into_any_element()
+            render_empty_state(dialog_state_for_empty, import_state, discovery_state, cx)
+                .into_any_element()
         })
 }
 
@@ -103,6 +226,9 @@ fn render_toolbar(
     view_mode: ViewMode,
     view_state: Entity<ViewModeState>,
     dialog_state: Entity<ServerDialogState>,
+    import_state: Entity<ServerImportState>,
+    discovery_state: Entity<HostDiscoveryState>,
+    group_state: Entity<GroupDialogState>,
     cx: &App,
 ) -> impl IntoElement {
     // 加载当前语言
@@ -112,6 +238,7 @@ fn render_toolbar(
 
     let state_for_card = view_state.clone();
     let state_for_list = view_state;
+    let dialog_state_for_templates = dialog_state.clone();
 
     div()
         .flex()
@@ -119,25 +246,75 @@ fn render_toolbar(
         .items_center()
         .child(
             div()
-                .id("add-server-btn")
-                .px_4()
-                .py_2()
-                .bg(cx.theme().primary)
-                .rounded_md()
-                .cursor_pointer()
-                .hover(move |s| s.bg(cx.theme().primary_hover))
                 .flex()
                 .items_center()
                 .gap_2()
-                .on_click(move |_, _, cx| {
-                    dialog_state.update(cx, |s, _| s.open_add());
-                })
-                .child(render_icon(icons::PLUS, rgb(0xffffff).into()))
                 .child(
                     div()
-                        .text_sm()
-                        .text_color(cx.theme().primary_foreground)
-                        .child(i18n::t(&lang, "server_list.add_server")),
+                        .id("add-server-btn")
+                        .px_4()
+                        .py_2()
+                        .bg(cx.theme().primary)
+                        .rounded_md()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(cx.theme().primary_hover))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .on_click(move |_, _, cx| {
+                            dialog_state.update(cx, |s, _| s.open_add());
+                        })
+                        .child(render_icon(icons::PLUS, rgb(0xffffff).into()))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().primary_foreground)
+                                .child(i18n::t(&lang, "server_list.add_server")),
+                        ),
+                )
+                .child(render_import_button(
+                    "import-servers-btn",
+                    import_state,
+                    &lang,
+                    cx,
+                ))
+                .child(render_discover_button(
+                    "discover-hosts-btn",
+                    discovery_state,
+                    &lang,
+                    cx,
+                ))
+                .child(render_template_button(
+                    dialog_state_for_templates,
+                    &lang,
+                    cx,
+                ))
+                .child(render_probe_button(&lang, cx))
+                .child(
+                    div()
+                        .id("new-group-btn")
+                        .px_4()
+                        .py_2()
+                        .bg(cx.theme().secondary)
+                        .rounded_md()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(cx.theme().secondary_hover))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .on_click(move |_, _, cx| {
+                            group_state.update(cx, |s, cx| {
+                                s.open_create(None);
+                                cx.notify();
+                            });
+                        })
+                        .child(render_icon(icons::FOLDER_PLUS, cx.theme().foreground))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().foreground)
+                                .child(i18n::t(&lang, "server_list.new_group")),
+                        ),
                 ),
         )
         .child(
@@ -227,10 +404,13 @@ fn render_toolbar(
 fn render_list_view(
     server_groups: &[ServerGroup],
     dialog_state: Entity<ServerDialogState>,
+    group_state: Entity<GroupDialogState>,
+    batch_state: Entity<BatchEditState>,
     session_state: Entity<SessionState>,
     cx: &App,
 ) -> impl IntoElement {
     let groups_owned: Vec<ServerGroup> = server_groups.to_vec();
+    let selected_ids = batch_state.read(cx).selected_ids.clone();
 
     let colors = CardColors {
         bg: cx.theme().popover,
@@ -250,8 +430,10 @@ fn render_list_view(
         .gap_6()
         .children(groups_owned.into_iter().map(move |group| {
             let state = dialog_state.clone();
+            let groups = group_state.clone();
+            let batch = batch_state.clone();
             let sess = session_state.clone();
-            render_server_group(group, state, sess, colors)
+            render_server_group(group, state, groups, batch, &selected_ids, sess, colors)
         }))
 }
 
@@ -271,10 +453,13 @@ struct CardColors {
 fn render_card_view(
     server_groups: &[ServerGroup],
     dialog_state: Entity<ServerDialogState>,
+    group_state: Entity<GroupDialogState>,
+    batch_state: Entity<BatchEditState>,
     session_state: Entity<SessionState>,
     cx: &App,
 ) -> impl IntoElement {
     let groups_owned: Vec<ServerGroup> = server_groups.to_vec();
+    let selected_ids = batch_state.read(cx).selected_ids.clone();
 
     let colors = CardColors {
         bg: cx.theme().popover,
@@ -294,8 +479,10 @@ fn render_card_view(
         .gap_6()
         .children(groups_owned.into_iter().map(move |group| {
             let state = dialog_state.clone();
+            let groups = group_state.clone();
+            let batch = batch_state.clone();
             let sess = session_state.clone();
-            render_card_group(group, state, sess, colors)
+            render_card_group(group, state, groups, batch, &selected_ids, sess, colors)
         }))
 }
 
@@ -303,59 +490,630 @@ fn render_card_view(
 fn render_card_group(
     group: ServerGroup,
     dialog_state: Entity<ServerDialogState>,
+    group_state: Entity<GroupDialogState>,
+    batch_state: Entity<BatchEditState>,
+    selected_ids: &[String],
     session_state: Entity<SessionState>,
     colors: CardColors,
 ) -> impl IntoElement {
-    let servers_owned = group.servers.clone();
+    let servers_owned: Vec<(Server, bool)> = group
+        .servers
+        .iter()
+        .map(|s| (s.clone(), selected_ids.contains(&s.id)))
+        .collect();
+    let group_id = group.id.clone();
     div()
+        .ml(px(group.depth as f32 * GROUP_INDENT))
         .flex()
         .flex_col()
         .gap_3()
+        // 组标题
+        .child(render_group_header(
+            &group,
+            dialog_state.clone(),
+            group_state,
+            colors,
+            false,
+        ))
+        .child(
+            // 卡片网格
+            div()
+                .flex()
+                .flex_wrap()
+                .gap_4()
+                .children(servers_owned.into_iter().map(move |(server, selected)| {
+                    let state = dialog_state.clone();
+                    let batch = batch_state.clone();
+                    let sess = session_state.clone();
+                    render_server_card(
+                        server,
+                        group_id.clone(),
+                        state,
+                        batch,
+                        selected,
+                        sess,
+                        colors,
+                    )
+                })),
+        )
+}
+
+/// 嵌套分组每一层的缩进
+const GROUP_INDENT: f32 = 24.;
+
+/// 渲染分组标题：可拖拽到其他分组下，接收服务器与分组的拖放，右键菜单管理分组
+fn render_group_header(
+    group: &ServerGroup,
+    dialog_state: Entity<ServerDialogState>,
+    group_state: Entity<GroupDialogState>,
+    colors: CardColors,
+    in_table: bool,
+) -> AnyElement {
+    let group_id = group.id.clone();
+    let dialog_for_server = dialog_state.clone();
+    let dialog_for_group = dialog_state.clone();
+    let target_for_server = group_id.clone();
+    let target_for_group = group_id.clone();
+
+    let header = div()
+        .id(SharedString::from(format!(
+            "group-header-{}",
+            group_id.as_deref().unwrap_or("ungrouped")
+        )))
+        .when(in_table, |this| this.px_4().py_3())
+        .when(!in_table, |this| this.px_1().py_1().rounded_md())
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(div().w_1().h_5().bg(colors.primary).rounded_sm())
+        .child(render_icon(group.icon_path, colors.muted_foreground.into()))
+        .child(
+            div()
+                .text_base()
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(colors.foreground)
+                .child(group.name.clone()),
+        );
+
+    // 共享清单的分组只读：不接收拖放，也没有管理菜单
+    if group.shared {
+        return header.child(render_shared_badge(colors)).into_any_element();
+    }
+
+    let header = header
+        // 服务器拖入分组：排在分组末尾
+        .drag_over::<DraggedServer>(move |this, _, _, _| this.bg(colors.primary.opacity(0.1)))
+        .on_drop(move |dragged: &DraggedServer, _, cx| {
+            if let Err(e) = storage::move_server(&dragged.id, target_for_server.clone(), None) {
+                error!("Failed to move server: {}", e);
+            }
+            mark_refresh(&dialog_for_server, cx);
+        })
+        // 分组拖入分组：成为其子分组（拖到“未分组”上则移到顶层）
+        .drag_over::<DraggedGroup>(move |this, _, _, _| this.bg(colors.primary.opacity(0.1)))
+        .on_drop(move |dragged: &DraggedGroup, _, cx| {
+            if target_for_group.as_deref() == Some(dragged.id.as_str()) {
+                return;
+            }
+            if let Err(e) = storage::move_group(&dragged.id, target_for_group.clone(), None) {
+                error!("Failed to move group: {}", e);
+            }
+            mark_refresh(&dialog_for_group, cx);
+        });
+
+    // “未分组”不是真实分组，不能拖动也没有管理菜单
+    let Some(group_id) = group_id else {
+        return header.into_any_element();
+    };
+    let dragged = DraggedGroup {
+        id: group_id.clone(),
+        name: group.name.clone(),
+    };
+    let depth = group.depth;
+    header
+        .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+        .context_menu(move |menu, _window, _cx| {
+            let lang = storage::load_settings()
+                .map(|s| s.theme.language)
+                .unwrap_or(Language::Chinese);
+            build_group_context_menu(
+                menu,
+                group_id.clone(),
+                depth,
+                &lang,
+                group_state.clone(),
+                dialog_state.clone(),
+            )
+        })
+        .into_any_element()
+}
+
+/// “共享”标记：来自团队共享清单的分组与服务器
+fn render_shared_badge(colors: CardColors) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    div()
+        .flex_shrink_0()
+        .px_1p5()
+        .rounded_sm()
+        .bg(colors.primary.opacity(0.1))
+        .text_xs()
+        .text_color(colors.primary)
+        .child(i18n::t(&lang, "server_list.shared_badge"))
+}
+
+/// 分组右键菜单：重命名、新建子分组、调整顺序与层级、删除
+fn build_group_context_menu(
+    menu: PopupMenu,
+    group_id: String,
+    depth: usize,
+    lang: &Language,
+    group_state: Entity<GroupDialogState>,
+    dialog_state: Entity<ServerDialogState>,
+) -> PopupMenu {
+    let dialog_item = |label: &'static str, open: fn(&mut GroupDialogState, String)| {
+        let group_state = group_state.clone();
+        let group_id = group_id.clone();
+        PopupMenuItem::new(label).on_click(move |_, _, cx| {
+            group_state.update(cx, |s, cx| {
+                open(s, group_id.clone());
+                cx.notify();
+            });
+        })
+    };
+    let shift_item = |label: &'static str, offset: isize| {
+        let dialog_state = dialog_state.clone();
+        let group_id = group_id.clone();
+        PopupMenuItem::new(label).on_click(move |_, _, cx| {
+            if let Err(e) = storage::shift_group(&group_id, offset) {
+                error!("Failed to reorder group: {}", e);
+            }
+            mark_refresh(&dialog_state, cx);
+        })
+    };
+
+    let menu = menu
+        .min_w(px(160.))
+        .item(dialog_item(
+            i18n::t(lang, "server_group.rename"),
+            GroupDialogState::open_rename,
+        ))
+        .item(dialog_item(
+            i18n::t(lang, "server_group.new_subgroup"),
+            |s, id| s.open_create(Some(id)),
+        ))
+        .separator()
+        .item(shift_item(i18n::t(lang, "server_group.move_up"), -1))
+        .item(shift_item(i18n::t(lang, "server_group.move_down"), 1));
+    let menu = if depth > 0 {
+        let group_id = group_id.clone();
+        let dialog_state = dialog_state.clone();
+        menu.item(
+            PopupMenuItem::new(i18n::t(lang, "server_group.move_to_top")).on_click(
+                move |_, _, cx| {
+                    if let Err(e) = storage::move_group(&group_id, None, None) {
+                        error!("Failed to move group: {}", e);
+                    }
+                    mark_refresh(&dialog_state, cx);
+                },
+            ),
+        )
+    } else {
+        menu
+    };
+    menu.separator().item(dialog_item(
+        i18n::t(lang, "server_group.delete"),
+        GroupDialogState::open_delete,
+    ))
+}
+
+/// 连接服务器；共享清单中的服务器按清单数据与本地身份建立会话
+fn connect_server(
+    session_state: &Entity<SessionState>,
+    server_id: &str,
+    label: &str,
+    cx: &mut App,
+) {
+    let shared = inventory::is_shared_id(server_id)
+        .then(|| SharedInventory::global().server(server_id))
+        .flatten();
+    session_state.update(cx, |state, cx| {
+        match shared {
+            Some(server) => {
+                state.add_adhoc_tab(server);
+            }
+            None => {
+                state.add_tab(server_id.to_string(), label.to_string());
+            }
+        }
+        // 确保 Monitor 详情弹窗状态已创建
+        state.ensure_monitor_detail_dialog(cx);
+    });
+}
+
+/// 标记服务器列表需要刷新（拖放或调整分组后）
+/// Ctrl/Cmd+点击切换选中；普通单击清空已有选择
+fn toggle_selection_on_click(
+    event: &ClickEvent,
+    batch_state: &Entity<BatchEditState>,
+    server_id: &str,
+    cx: &mut App,
+) {
+    let secondary = event.modifiers().secondary();
+    batch_state.update(cx, |s, cx| {
+        if secondary {
+            s.toggle_selection(server_id.to_string());
+        } else if !s.selected_ids.is_empty() {
+            s.clear_selection();
+        } else {
+            return;
+        }
+        cx.notify();
+    });
+}
+
+/// 渲染多选操作栏：已选数量、批量编辑、全选当前可见、清空选择
+fn render_selection_bar(
+    batch_state: Entity<BatchEditState>,
+    visible_ids: Vec<String>,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let count = batch_state.read(cx).selected_ids.len();
+    let secondary_hover = cx.theme().secondary_hover;
+    let action = |id: &'static str, label: &'static str| {
+        div()
+            .id(id)
+            .px_3()
+            .py_1()
+            .rounded_md()
+            .cursor_pointer()
+            .text_sm()
+            .text_color(cx.theme().foreground)
+            .hover(move |s| s.bg(secondary_hover))
+            .child(label)
+    };
+
+    div()
+        .px_3()
+        .py_2()
+        .rounded_md()
+        .border_1()
+        .border_color(cx.theme().primary.opacity(0.4))
+        .bg(cx.theme().primary.opacity(0.06))
+        .flex()
+        .items_center()
+        .gap_2()
+        .child(
+            div()
+                .flex_1()
+                .text_sm()
+                .text_color(cx.theme().foreground)
+                .child(
+                    i18n::t(&lang, "batch_edit.selected_count").replace("{}", &count.to_string()),
+                ),
+        )
+        .child(
+            div()
+                .id("batch-edit-open")
+                .px_3()
+                .py_1()
+                .rounded_md()
+                .cursor_pointer()
+                .bg(cx.theme().primary)
+                .hover(move |s| s.bg(cx.theme().primary_hover))
+                .flex()
+                .items_center()
+                .gap_1()
+                .text_sm()
+                .text_color(cx.theme().primary_foreground)
+                .child(render_icon(icons::EDIT, cx.theme().primary_foreground))
+                .child(i18n::t(&lang, "batch_edit.edit"))
+                .on_click({
+                    let batch_state = batch_state.clone();
+                    move |_, _, cx| {
+                        batch_state.update(cx, |s, cx| {
+                            s.open();
+                            cx.notify();
+                        });
+                    }
+                }),
+        )
+        .child(
+            action("batch-select-all", i18n::t(&lang, "batch_edit.select_all")).on_click({
+                let batch_state = batch_state.clone();
+                move |_, _, cx| {
+                    batch_state.update(cx, |s, cx| {
+                        for id in &visible_ids {
+                            if !s.is_selected(id) {
+                                s.selected_ids.push(id.clone());
+                            }
+                        }
+                        cx.notify();
+                    });
+                }
+            }),
+        )
+        .child(
+            action(
+                "batch-clear-selection",
+                i18n::t(&lang, "batch_edit.clear_selection"),
+            )
+            .on_click(move |_, _, cx| {
+                batch_state.update(cx, |s, cx| {
+                    s.clear_selection();
+                    cx.notify();
+                });
+            }),
+        )
+}
+
+/// 服务器右键菜单：编辑、克隆、另存为模板、查看 SSH 配置；共享服务器只能复制为本地服务器
+fn build_server_context_menu(
+    menu: PopupMenu,
+    (server_id, server_name): (String, String),
+    dialog_state: Entity<ServerDialogState>,
+    session_state: Entity<SessionState>,
+) -> PopupMenu {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    // 共享服务器只读，只能复制为本地服务器后再修改
+    if inventory::is_shared_id(&server_id) {
+        let copy_item = PopupMenuItem::new(i18n::t(&lang, "server_list.copy_to_local")).on_click(
+            move |_, _, cx| {
+                let Some(mut server) = SharedInventory::global().server(&server_id) else {
+                    return;
+                };
+                server.id = uuid::Uuid::new_v4().to_string();
+                server.created_at = chrono::Utc::now().to_rfc3339();
+                if let Err(e) = storage::add_server(server) {
+                    error!("Failed to copy shared server: {}", e);
+                }
+                mark_refresh(&dialog_state, cx);
+            },
+        );
+        return menu.min_w(px(160.)).item(copy_item);
+    }
+    let edit_item = {
+        let dialog_state = dialog_state.clone();
+        let server_id = server_id.clone();
+        PopupMenuItem::new(i18n::t(&lang, "server_list.edit")).on_click(move |_, _, cx| {
+            dialog_state.update(cx, |s, _| s.open_edit(server_id.clone()));
+        })
+    };
+    let clone_item = {
+        let dialog_state = dialog_state.clone();
+        let server_id = server_id.clone();
+        let label = i18n::t(&lang, "server_list.clone_label").replace("{}", &server_name);
+        PopupMenuItem::new(i18n::t(&lang, "server_list.clone")).on_click(move |_, _, cx| {
+            if let Err(e) = storage::clone_server(&server_id, label.clone()) {
+                error!("Failed to clone server: {}", e);
+            }
+            mark_refresh(&dialog_state, cx);
+        })
+    };
+    let favorite_item = {
+        let dialog_state = dialog_state.clone();
+        let server_id = server_id.clone();
+        let favorite = storage::load_servers()
+            .ok()
+            .and_then(|config| config.servers.into_iter().find(|s| s.id == server_id))
+            .is_some_and(|s| s.favorite);
+        let key = if favorite {
+            "server_list.unfavorite"
+        } else {
+            "server_list.favorite"
+        };
+        PopupMenuItem::new(i18n::t(&lang, key)).on_click(move |_, _, cx| {
+            if let Err(e) = storage::set_server_favorite(&server_id, !favorite) {
+                error!("Failed to update favorite: {}", e);
+            }
+            mark_refresh(&dialog_state, cx);
+        })
+    };
+    let ssh_snippet_item = {
+        let server_id = server_id.clone();
+        PopupMenuItem::new(i18n::t(&lang, "server_list.ssh_snippet")).on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                state.open_ssh_snippet(&server_id, None, cx);
+            });
+        })
+    };
+    let template_name = i18n::t(&lang, "server_list.template_name").replace("{}", &server_name);
+    let template_item = PopupMenuItem::new(i18n::t(&lang, "server_list.save_as_template"))
+        .on_click(move |_, _, cx| {
+            match storage::save_server_as_template(&server_id, template_name.clone()) {
+                // 保存后打开模板编辑，便于调整名称与设置
+                Ok(Some(template_id)) => {
+                    dialog_state.update(cx, |s, _| s.open_edit_template(&template_id));
+                }
+                Ok(None) => {}
+                Err(e) => error!("Failed to save template: {}", e),
+            }
+        });
+
+    menu.min_w(px(160.))
+        .item(edit_item)
+        .item(clone_item)
+        .item(favorite_item)
+        .separator()
+        .item(template_item)
+        .item(ssh_snippet_item)
+}
+
+/// 渲染模板按钮：下拉菜单中从模板新建服务器、编辑模板或新建模板
+/// 渲染"检测全部"按钮：立即探测全部服务器的可达性
+fn render_probe_button(lang: &Language, cx: &App) -> impl IntoElement {
+    let running = ReachabilityService::running(cx);
+    Button::new("probe-all-btn")
+        .outline()
+        .disabled(running)
         .child(
-            // 组标题
             div()
                 .flex()
                 .items_center()
                 .gap_2()
-                .child(div().w_1().h_5().bg(colors.primary).rounded_sm())
-                .child(render_icon(group.icon_path, colors.muted_foreground.into()))
-                .child(
+                .child(render_icon(icons::ACTIVITY, cx.theme().foreground))
+                .child(i18n::t(
+                    lang,
+                    if running {
+                        "server_list.probing"
+                    } else {
+                        "server_list.probe_all"
+                    },
+                )),
+        )
+        .on_click(|_, _, cx| ReachabilityService::probe_all(cx))
+}
+
+/// 渲染服务器的可达状态点（尚未探测时不显示），卡片视图同时显示连接耗时
+fn render_reachability_dot(
+    server_id: &str,
+    show_latency: bool,
+    colors: CardColors,
+) -> Option<AnyElement> {
+    let store = ReachabilityStore::global();
+    if !store.has_results() {
+        return None;
+    }
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let reachability = store.get(server_id);
+    let (color, label) = match reachability {
+        Reachability::Online { latency_ms } => (
+            Hsla::from(rgb(0x22c55e)),
+            i18n::t(&lang, "server_list.reachability.online")
+                .replace("{}", &latency_ms.to_string()),
+        ),
+        Reachability::Offline => (
+            Hsla::from(rgb(0xef4444)),
+            i18n::t(&lang, "server_list.reachability.offline").to_string(),
+        ),
+        Reachability::Unknown => (
+            colors.muted_foreground.opacity(0.5),
+            i18n::t(&lang, "server_list.reachability.unknown").to_string(),
+        ),
+    };
+
+    Some(
+        div()
+            .id(SharedString::from(format!("reachability-{}", server_id)))
+            .flex_shrink_0()
+            .flex()
+            .items_center()
+            .gap_1()
+            .child(div().w(px(8.)).h(px(8.)).rounded_full().bg(color))
+            .when(show_latency, |this| match reachability {
+                Reachability::Online { latency_ms } => this.child(
                     div()
-                        .text_base()
-                        .font_weight(FontWeight::MEDIUM)
-                        .text_color(colors.foreground)
-                        .child(group.name.clone()),
+                        .text_xs()
+                        .text_color(colors.muted_foreground)
+                        .child(format!("{} ms", latency_ms)),
                 ),
-        )
+                _ => this,
+            })
+            .tooltip(move |window, cx| Tooltip::new(label.clone()).build(window, cx))
+            .into_any_element(),
+    )
+}
+
+fn render_template_button(
+    dialog_state: Entity<ServerDialogState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let lang = lang.clone();
+    Button::new("templates-btn")
+        .outline()
         .child(
-            // 卡片网格
             div()
                 .flex()
-                .flex_wrap()
-                .gap_4()
-                .children(servers_owned.into_iter().map(move |server| {
-                    let state = dialog_state.clone();
-                    let sess = session_state.clone();
-                    render_server_card(server, state, sess, colors)
-                })),
+                .items_center()
+                .gap_2()
+                .child(render_icon(icons::COPY, cx.theme().foreground))
+                .child(i18n::t(&lang, "server_list.templates")),
         )
+        .dropdown_menu_with_anchor(Corner::TopLeft, move |menu, _, _| {
+            let templates = storage::load_servers()
+                .map(|c| c.templates)
+                .unwrap_or_default();
+            let mut menu = menu.min_w(px(200.));
+            for template in &templates {
+                let dialog_state = dialog_state.clone();
+                let template_id = template.id.clone();
+                let label =
+                    i18n::t(&lang, "server_list.new_from_template").replace("{}", &template.name);
+                menu = menu.item(PopupMenuItem::new(label).on_click(move |_, _, cx| {
+                    dialog_state.update(cx, |s, _| s.open_add_from_template(&template_id));
+                }));
+            }
+            if !templates.is_empty() {
+                menu = menu.separator();
+            }
+            for template in &templates {
+                let dialog_state = dialog_state.clone();
+                let template_id = template.id.clone();
+                let label =
+                    i18n::t(&lang, "server_list.edit_template").replace("{}", &template.name);
+                menu = menu.item(PopupMenuItem::new(label).on_click(move |_, _, cx| {
+                    dialog_state.update(cx, |s, _| s.open_edit_template(&template_id));
+                }));
+            }
+            if !templates.is_empty() {
+                menu = menu.separator();
+            }
+            let dialog_state = dialog_state.clone();
+            menu.item(
+                PopupMenuItem::new(i18n::t(&lang, "server_list.new_template")).on_click(
+                    move |_, _, cx| {
+                        dialog_state.update(cx, |s, _| s.open_new_template());
+                    },
+                ),
+            )
+        })
+}
+
+fn mark_refresh(dialog_state: &Entity<ServerDialogState>, cx: &mut App) {
+    dialog_state.update(cx, |s, _| {
+        s.needs_refresh = true;
+    });
 }
 
 /// 渲染服务器卡片
 fn render_server_card(
     server: Server,
+    group_id: Option<String>,
     dialog_state: Entity<ServerDialogState>,
+    batch_state: Entity<BatchEditState>,
+    selected: bool,
     session_state: Entity<SessionState>,
     colors: CardColors,
 ) -> impl IntoElement {
     let server_id = server.id.clone();
+    let shared = server.shared;
     let server_id_for_edit = server_id.clone();
     let server_id_for_delete = server_id.clone();
+    let server_name_for_delete = server.name.clone();
     let server_id_for_connect = server_id.clone();
+    let server_id_for_drop = server_id.clone();
     let server_label_for_connect = server.name.clone();
     let dialog_for_edit = dialog_state.clone();
-    let dialog_for_delete = dialog_state;
+    let dialog_for_drop = dialog_state.clone();
+    let dialog_for_delete = dialog_state.clone();
+    let session_for_menu = session_state.clone();
     let session_for_connect = session_state;
+    let server_id_for_select = server_id.clone();
+    let menu_target = (server_id.clone(), server.name.clone());
+    let dialog_for_menu = dialog_state.clone();
+    let dragged = DraggedServer {
+        id: server_id.clone(),
+        name: server.name.clone(),
+    };
 
     div()
         .id(SharedString::from(format!("card-{}", server_id)))
@@ -367,16 +1125,50 @@ fn render_server_card(
         .p_4()
         .cursor_pointer()
         .hover(move |s| s.border_color(colors.primary).shadow_md())
+        .when(selected, |this| {
+            this.border_color(colors.primary)
+                .bg(colors.primary.opacity(0.08))
+        })
+        // Ctrl/Cmd+点击多选
+        .on_click(move |event, _, cx| {
+            if !shared {
+                toggle_selection_on_click(event, &batch_state, &server_id_for_select, cx)
+            }
+        })
+        // 拖动排序：放到卡片上时排在该服务器之前（共享服务器不参与排序）
+        .when(!shared, |this| {
+            this.on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+                .drag_over::<DraggedServer>(move |this, _, _, _| {
+                    this.border_color(colors.primary)
+                        .bg(colors.primary.opacity(0.05))
+                })
+                .on_drop(move |dragged: &DraggedServer, _, cx| {
+                    if let Err(e) = storage::move_server(
+                        &dragged.id,
+                        group_id.clone(),
+                        Some(&server_id_for_drop),
+                    ) {
+                        error!("Failed to move server: {}", e);
+                    }
+                    mark_refresh(&dialog_for_drop, cx);
+                })
+        })
         // 双击连接服务器
         .on_double_click(move |_, _, cx| {
-            session_for_connect.update(cx, |state, cx| {
-                state.add_tab(
-                    server_id_for_connect.clone(),
-                    server_label_for_connect.clone(),
-                );
-                // 确保 Monitor 详情弹窗状态已创建
-                state.ensure_monitor_detail_dialog(cx);
-            });
+            connect_server(
+                &session_for_connect,
+                &server_id_for_connect,
+                &server_label_for_connect,
+                cx,
+            );
+        })
+        .context_menu(move |menu, _window, _cx| {
+            build_server_context_menu(
+                menu,
+                menu_target.clone(),
+                dialog_for_menu.clone(),
+                session_for_menu.clone(),
+            )
         })
         .flex()
         .flex_col()
@@ -399,14 +1191,22 @@ fn render_server_card(
                         .child(render_icon(icons::TERMINAL, colors.primary.into())),
                 )
                 .child(
-                    div().flex_1().overflow_hidden().child(
-                        div()
-                            .text_sm()
-                            .font_weight(FontWeight::MEDIUM)
-                            .text_color(colors.foreground)
-                            .overflow_hidden()
-                            .child(server.name.clone()),
-                    ),
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(FontWeight::MEDIUM)
+                                .text_color(colors.foreground)
+                                .overflow_hidden()
+                                .child(server.name.clone()),
+                        )
+                        .when(shared, |this| this.child(render_shared_badge(colors)))
+                        .children(render_reachability_dot(&server_id, true, colors)),
                 ),
         )
         .child({
@@ -478,6 +1278,10 @@ fn render_server_card(
                         ),
                 )
         })
+        // 标签
+        .when(!server.tags.is_empty(), |this| {
+            this.child(render_tag_chips(&server.tags, colors.muted_foreground))
+        })
         .child(
             // 底部：最近连接时间
             div()
@@ -497,6 +1301,8 @@ fn render_server_card(
                     div()
                         .flex()
                         .gap_2()
+                        // 共享服务器只读，不显示编辑与删除
+                        .when(shared, |this| this.invisible())
                         .child(
                             div()
                                 .id(SharedString::from(format!(
@@ -523,14 +1329,15 @@ fn render_server_card(
                                 .cursor_pointer()
                                 .hover(move |s| s.bg(colors.destructive.opacity(0.1)).rounded_md())
                                 .p_1()
-                                .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                                .on_mouse_down(MouseButton::Left, move |_, window, cx| {
                                     cx.stop_propagation();
-                                    if let Err(e) = storage::delete_server(&server_id_for_delete) {
-                                        error!("Failed to delete server: {}", e);
-                                    }
-                                    dialog_for_delete.update(cx, |s, _| {
-                                        s.needs_refresh = true;
-                                    });
+                                    delete_server_with_undo(
+                                        server_id_for_delete.clone(),
+                                        server_name_for_delete.clone(),
+                                        dialog_for_delete.clone(),
+                                        window,
+                                        cx,
+                                    );
                                 })
                                 .child(render_icon(icons::TRASH, colors.destructive.into())),
                         ),
@@ -538,10 +1345,47 @@ fn render_server_card(
         )
 }
 
+/// 删除服务器（移入回收站），并弹出可点击撤销的提示
+fn delete_server_with_undo(
+    server_id: String,
+    name: String,
+    dialog_state: Entity<ServerDialogState>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    if let Err(e) = storage::delete_server(&server_id) {
+        error!("Failed to delete server: {}", e);
+        return;
+    }
+    dialog_state.update(cx, |s, _| {
+        s.needs_refresh = true;
+    });
+
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let notification = Notification::new()
+        .title(i18n::t(&lang, "trash.deleted").replace("{}", &name))
+        .message(i18n::t(&lang, "trash.undo_hint"))
+        .with_type(NotificationType::Info)
+        .on_click(move |_, _, cx| {
+            if let Err(e) = storage::restore_server(&server_id) {
+                error!("Failed to restore server: {}", e);
+            }
+            dialog_state.update(cx, |s, _| {
+                s.needs_refresh = true;
+            });
+        });
+    window.push_notification(notification, cx);
+}
+
 /// 渲染服务器组（表格）
 fn render_server_group(
     group: ServerGroup,
     dialog_state: Entity<ServerDialogState>,
+    group_state: Entity<GroupDialogState>,
+    batch_state: Entity<BatchEditState>,
+    selected_ids: &[String],
     session_state: Entity<SessionState>,
     colors: CardColors,
 ) -> impl IntoElement {
@@ -550,31 +1394,27 @@ fn render_server_group(
         .map(|s| s.theme.language)
         .unwrap_or(Language::Chinese);
 
-    let servers_owned = group.servers.clone();
+    let servers_owned: Vec<(Server, bool)> = group
+        .servers
+        .iter()
+        .map(|s| (s.clone(), selected_ids.contains(&s.id)))
+        .collect();
+    let group_id = group.id.clone();
     div()
+        .ml(px(group.depth as f32 * GROUP_INDENT))
         .bg(colors.bg)
         .rounded_lg()
         .border_1()
         .border_color(colors.border)
         .overflow_hidden()
-        .child(
-            // 组标题
-            div()
-                .px_4()
-                .py_3()
-                .flex()
-                .items_center()
-                .gap_2()
-                .child(div().w_1().h_5().bg(colors.primary).rounded_sm())
-                .child(render_icon(group.icon_path, colors.muted_foreground.into()))
-                .child(
-                    div()
-                        .text_base()
-                        .font_weight(FontWeight::MEDIUM)
-                        .text_color(colors.foreground)
-                        .child(group.name.clone()),
-                ),
-        )
+        // 组标题
+        .child(render_group_header(
+            &group,
+            dialog_state.clone(),
+            group_state,
+            colors,
+            true,
+        ))
         .child(
             // 表格头
             div()
@@ -640,10 +1480,19 @@ fn render_server_group(
             div()
                 .flex()
                 .flex_col()
-                .children(servers_owned.into_iter().map(move |server| {
+                .children(servers_owned.into_iter().map(move |(server, selected)| {
                     let state = dialog_state.clone();
+                    let batch = batch_state.clone();
                     let sess = session_state.clone();
-                    render_server_row(server, state, sess, colors)
+                    render_server_row(
+                        server,
+                        group_id.clone(),
+                        state,
+                        batch,
+                        selected,
+                        sess,
+                        colors,
+                    )
                 })),
         )
 }
@@ -651,18 +1500,33 @@ fn render_server_group(
 /// 渲染服务器行
 fn render_server_row(
     server: Server,
+    group_id: Option<String>,
     dialog_state: Entity<ServerDialogState>,
+    batch_state: Entity<BatchEditState>,
+    selected: bool,
     session_state: Entity<SessionState>,
     colors: CardColors,
 ) -> impl IntoElement {
     let server_id = server.id.clone();
+    let shared = server.shared;
     let server_id_for_edit = server_id.clone();
     let server_id_for_delete = server_id.clone();
+    let server_name_for_delete = server.name.clone();
     let server_id_for_connect = server_id.clone();
+    let server_id_for_drop = server_id.clone();
     let server_label_for_connect = server.name.clone();
     let dialog_for_edit = dialog_state.clone();
-    let dialog_for_delete = dialog_state;
+    let dialog_for_drop = dialog_state.clone();
+    let dialog_for_delete = dialog_state.clone();
+    let session_for_menu = session_state.clone();
     let session_for_connect = session_state;
+    let server_id_for_select = server_id.clone();
+    let menu_target = (server_id.clone(), server.name.clone());
+    let dialog_for_menu = dialog_state.clone();
+    let dragged = DraggedServer {
+        id: server_id.clone(),
+        name: server.name.clone(),
+    };
 
     div()
         .id(SharedString::from(format!("row-{}", server_id)))
@@ -673,16 +1537,46 @@ fn render_server_row(
         .items_center()
         .cursor_pointer()
         .hover(move |s| s.bg(colors.header_bg))
+        .when(selected, |this| this.bg(colors.primary.opacity(0.08)))
+        // Ctrl/Cmd+点击多选
+        .on_click(move |event, _, cx| {
+            if !shared {
+                toggle_selection_on_click(event, &batch_state, &server_id_for_select, cx)
+            }
+        })
+        // 拖动排序：放到行上时排在该服务器之前（共享服务器不参与排序）
+        .when(!shared, |this| {
+            this.on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+                .drag_over::<DraggedServer>(move |this, _, _, _| {
+                    this.border_t_2().border_color(colors.primary)
+                })
+                .on_drop(move |dragged: &DraggedServer, _, cx| {
+                    if let Err(e) = storage::move_server(
+                        &dragged.id,
+                        group_id.clone(),
+                        Some(&server_id_for_drop),
+                    ) {
+                        error!("Failed to move server: {}", e);
+                    }
+                    mark_refresh(&dialog_for_drop, cx);
+                })
+        })
         // 双击连接服务器
         .on_double_click(move |_, _, cx| {
-            session_for_connect.update(cx, |state, cx| {
-                state.add_tab(
-                    server_id_for_connect.clone(),
-                    server_label_for_connect.clone(),
-                );
-                // 确保 Monitor 详情弹窗状态已创建
-                state.ensure_monitor_detail_dialog(cx);
-            });
+            connect_server(
+                &session_for_connect,
+                &server_id_for_connect,
+                &server_label_for_connect,
+                cx,
+            );
+        })
+        .context_menu(move |menu, _window, _cx| {
+            build_server_context_menu(
+                menu,
+                menu_target.clone(),
+                dialog_for_menu.clone(),
+                session_for_menu.clone(),
+            )
         })
         .child(
             div()
@@ -703,9 +1597,28 @@ fn render_server_row(
                 )
                 .child(
                     div()
-                        .text_sm()
-                        .text_color(colors.foreground)
-                        .child(server.name.clone()),
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .gap_0p5()
+                        .child(
+                            div()
+                                .flex()
+                                .items_center()
+                                .gap_2()
+                                .child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(colors.foreground)
+                                        .child(server.name.clone()),
+                                )
+                                .when(shared, |this| this.child(render_shared_badge(colors)))
+                                .children(render_reachability_dot(&server_id, false, colors)),
+                        )
+                        .when(!server.tags.is_empty(), |this| {
+                            this.child(render_tag_chips(&server.tags, colors.muted_foreground))
+                        }),
                 ),
         )
         .child({
@@ -775,6 +1688,8 @@ fn render_server_row(
                 .flex()
                 .items_center()
                 .gap_3()
+                // 共享服务器只读，不显示编辑与删除
+                .when(shared, |this| this.invisible())
                 .child(
                     div()
                         .id(SharedString::from(format!(
@@ -801,14 +1716,15 @@ fn render_server_row(
                         .cursor_pointer()
                         .hover(move |s| s.bg(colors.destructive.opacity(0.1)).rounded_md())
                         .p_1()
-                        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                        .on_mouse_down(MouseButton::Left, move |_, window, cx| {
                             cx.stop_propagation();
-                            if let Err(e) = storage::delete_server(&server_id_for_delete) {
-                                error!("Failed to delete server: {}", e);
-                            }
-                            dialog_for_delete.update(cx, |s, _| {
-                                s.needs_refresh = true;
-                            });
+                            delete_server_with_undo(
+                                server_id_for_delete.clone(),
+                                server_name_for_delete.clone(),
+                                dialog_for_delete.clone(),
+                                window,
+                                cx,
+                            );
                         })
                         .child(render_icon(icons::TRASH, colors.destructive.into())),
                 ),
@@ -816,7 +1732,12 @@ fn render_server_row(
 }
 
 /// 渲染空状态（没有服务器时显示）
-fn render_empty_state(dialog_state: Entity<ServerDialogState>, cx: &App) -> impl IntoElement {
+fn render_empty_state(
+    dialog_state: Entity<ServerDialogState>,
+    import_state: Entity<ServerImportState>,
+    discovery_state: Entity<HostDiscoveryState>,
+    cx: &App,
+) -> impl IntoElement {
     // 加载当前语言
     let lang = storage::load_settings()
         .map(|s| s.theme.language)
@@ -864,60 +1785,113 @@ fn render_empty_state(dialog_state: Entity<ServerDialogState>, cx: &App) -> impl
                 ),
         )
         .child(
-            // 添加服务器按钮
             div()
-                .id("empty-add-server-btn")
-                .px_6()
-                .py_3()
-                .bg(cx.theme().primary)
-                .rounded_lg()
-                .cursor_pointer()
-                .hover(move |s| s.bg(cx.theme().primary_hover))
                 .flex()
                 .items_center()
-                .gap_2()
-                .on_click(move |_, _, cx| {
-                    dialog_state.update(cx, |s, _| s.open_add());
-                })
-                .child(render_icon(icons::PLUS, rgb(0xffffff).into()))
+                .gap_3()
+                // 添加服务器按钮
                 .child(
                     div()
-                        .text_base()
-                        .text_color(cx.theme().primary_foreground)
-                        .child(i18n::t(&lang, "server_list.add_server")),
-                ),
+                        .id("empty-add-server-btn")
+                        .px_6()
+                        .py_3()
+                        .bg(cx.theme().primary)
+                        .rounded_lg()
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(cx.theme().primary_hover))
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .on_click(move |_, _, cx| {
+                            dialog_state.update(cx, |s, _| s.open_add());
+                        })
+                        .child(render_icon(icons::PLUS, rgb(0xffffff).into()))
+                        .child(
+                            div()
+                                .text_base()
+                                .text_color(cx.theme().primary_foreground)
+                                .child(i18n::t(&lang, "server_list.add_server")),
+                        ),
+                )
+                // 从其他客户端导入
+                .child(render_import_button(
+                    "empty-import-servers-btn",
+                    import_state,
+                    &lang,
+                    cx,
+                ))
+                // 扫描网段发现主机
+                .child(render_discover_button(
+                    "empty-discover-hosts-btn",
+                    discovery_state,
+                    &lang,
+                    cx,
+                )),
         )
 }
 
-/// 渲染占位内容
-pub fn render_placeholder(title: &str, description: &str, cx: &App) -> impl IntoElement {
+/// 渲染“导入”按钮，打开服务器导入对话框
+fn render_import_button(
+    id: &'static str,
+    import_state: Entity<ServerImportState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
     div()
-        .flex_1()
-        .h_full()
-        // .bg(rgb(0xffffff)) // Removed hardcoded white
+        .id(id)
+        .px_4()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(cx.theme().secondary_hover))
         .flex()
-        .flex_col()
-        .justify_center()
         .items_center()
-        .gap_4()
+        .gap_2()
+        .on_click(move |_, _, cx| {
+            import_state.update(cx, |s, cx| {
+                s.open();
+                cx.notify();
+            });
+        })
+        .child(render_icon(icons::DOWNLOAD, cx.theme().foreground))
         .child(
             div()
-                .text_2xl()
-                .font_weight(FontWeight::SEMIBOLD)
+                .text_sm()
                 .text_color(cx.theme().foreground)
-                .child(title.to_string()),
-        )
-        .child(
-            div()
-                .text_base()
-                .text_color(cx.theme().muted_foreground)
-                .child(description.to_string()),
+                .child(i18n::t(lang, "server_list.import")),
         )
+}
+
+/// 渲染“发现主机”按钮，打开主机发现对话框
+fn render_discover_button(
+    id: &'static str,
+    discovery_state: Entity<HostDiscoveryState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    div()
+        .id(id)
+        .px_4()
+        .py_2()
+        .bg(cx.theme().secondary)
+        .rounded_md()
+        .cursor_pointer()
+        .hover(move |s| s.bg(cx.theme().secondary_hover))
+        .flex()
+        .items_center()
+        .gap_2()
+        .on_click(move |_, _, cx| {
+            discovery_state.update(cx, |s, cx| {
+                s.open();
+                cx.notify();
+            });
+        })
+        .child(render_icon(icons::NETWORK, cx.theme().foreground))
         .child(
             div()
-                .mt_4()
                 .text_sm()
-                .text_color(cx.theme().muted_foreground)
-                .child("Coming soon..."),
+                .text_color(cx.theme().foreground)
+                .child(i18n::t(lang, "server_list.discover")),
         )
 }
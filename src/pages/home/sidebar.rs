@@ -9,23 +9,53 @@ use crate::constants::icons;
 use crate::i18n;
 use crate::models::settings::Language;
 use crate::models::HistoryItem;
+use crate::services::connection_history::ConnectionHistoryStore;
+use crate::state::SessionState;
+
+use super::history_list::connect_from_history;
 
 /// 菜单类型
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MenuType {
     Hosts,
     Monitor,
     Snippets,
+    BatchExec,
+    Schedules,
+    Identities,
     KnownHosts,
+    History,
+    Plugins,
+    Trash,
 }
 
 impl MenuType {
+    /// 侧边栏中的全部菜单（按显示顺序）
+    pub const ALL: [MenuType; 10] = [
+        MenuType::Hosts,
+        MenuType::Monitor,
+        MenuType::Snippets,
+        MenuType::BatchExec,
+        MenuType::Schedules,
+        MenuType::Identities,
+        MenuType::KnownHosts,
+        MenuType::History,
+        MenuType::Plugins,
+        MenuType::Trash,
+    ];
+
     pub fn id(&self) -> &'static str {
         match self {
             MenuType::Hosts => "hosts",
             MenuType::Monitor => "monitor",
             MenuType::Snippets => "snippets",
+            MenuType::BatchExec => "batch_exec",
+            MenuType::Schedules => "schedules",
+            MenuType::Identities => "identities",
             MenuType::KnownHosts => "known_hosts",
+            MenuType::History => "history",
+            MenuType::Plugins => "plugins",
+            MenuType::Trash => "trash",
         }
     }
 
@@ -34,7 +64,13 @@ impl MenuType {
             MenuType::Hosts => "sidebar.hosts",
             MenuType::Monitor => "sidebar.monitor",
             MenuType::Snippets => "sidebar.snippets",
+            MenuType::BatchExec => "sidebar.batch_exec",
+            MenuType::Schedules => "sidebar.schedules",
+            MenuType::Identities => "sidebar.identities",
             MenuType::KnownHosts => "sidebar.known_hosts",
+            MenuType::History => "sidebar.connection_history",
+            MenuType::Plugins => "sidebar.plugins",
+            MenuType::Trash => "sidebar.trash",
         }
     }
 
@@ -43,7 +79,13 @@ impl MenuType {
             MenuType::Hosts => icons::SERVER,
             MenuType::Monitor => icons::MONITOR,
             MenuType::Snippets => icons::CODE,
+            MenuType::BatchExec => icons::SEND,
+            MenuType::Schedules => icons::CLOCK,
+            MenuType::Identities => icons::KEY,
             MenuType::KnownHosts => icons::FINGERPRINT,
+            MenuType::History => icons::HISTORY,
+            MenuType::Plugins => icons::PUZZLE,
+            MenuType::Trash => icons::TRASH,
         }
     }
 }
@@ -59,14 +101,10 @@ pub fn render_sidebar(
     selected_menu: MenuType,
     history: &[HistoryItem],
     settings_dialog_state: Entity<SettingsDialogState>,
+    session_state: Entity<SessionState>,
     cx: &App,
 ) -> impl IntoElement {
-    let menus = [
-        MenuType::Hosts,
-        MenuType::Monitor,
-        MenuType::Snippets,
-        MenuType::KnownHosts,
-    ];
+    let menus = MenuType::ALL;
 
     let lang = &settings_dialog_state.read(cx).settings.theme.language;
 
@@ -116,12 +154,24 @@ pub fn render_sidebar(
                 .flex_col()
                 .gap_1()
                 .children(history.iter().map(|item| {
+                    let entry_id = item.entry_id.clone();
+                    let session_state = session_state.clone();
                     div()
+                        .id(SharedString::from(format!(
+                            "sidebar-recent-{}",
+                            item.entry_id
+                        )))
                         .px_3()
                         .py_2()
                         .rounded_md()
                         .hover(move |s| s.bg(hover_bg))
                         .cursor_pointer()
+                        // 点击重新连接
+                        .on_click(move |_, _, cx| {
+                            if let Some(entry) = ConnectionHistoryStore::global().get(&entry_id) {
+                                connect_from_history(&entry, &session_state, cx);
+                            }
+                        })
                         .child(
                             div()
                                 .text_sm()
@@ -1,17 +1,50 @@
 // Snippets (快捷命令) 列表页面组件
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::notification::NotificationType;
 use gpui_component::ActiveTheme;
 use tracing::error;
 
+use super::server_list::render_drag_preview;
 use crate::components::common::icon::render_icon;
 use crate::components::common::snippets_dialog::SnippetsDialogState;
 use crate::constants::icons;
 use crate::i18n;
 use crate::models::settings::Language;
 use crate::models::{SnippetCommand, SnippetGroup, SnippetsConfig};
+use crate::services::notifications::{notify, NotificationCategory};
+use crate::services::snippet_library::{self, LibraryFormat};
 use crate::services::storage;
 
+/// 拖拽中的命令（放到命令上排在其前面，放到命令组或面包屑上移入该组）
+#[derive(Clone)]
+pub struct DraggedSnippetCommand {
+    pub id: String,
+    pub name: String,
+}
+
+/// 拖拽中的命令组（放到命令组上排在其前面，放到面包屑上移入该层级）
+#[derive(Clone)]
+pub struct DraggedSnippetGroup {
+    pub id: String,
+    pub name: String,
+}
+
+impl Render for DraggedSnippetCommand {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        render_drag_preview(icons::CODE, self.name.clone(), cx)
+    }
+}
+
+impl Render for DraggedSnippetGroup {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        render_drag_preview(icons::FOLDER, self.name.clone(), cx)
+    }
+}
+
 /// Snippets 页面状态（仅包含页面导航状态）
 pub struct SnippetsPageState {
     /// 当前路径（命令组 ID 栈，空表示根目录）
@@ -99,6 +132,41 @@ impl SnippetsPageState {
         });
     }
 
+    /// 移动命令（拖拽排序），`group_id` 为目标组，`before_id` 为排在其前面的命令
+    pub fn move_command(
+        &mut self,
+        command_id: &str,
+        group_id: Option<String>,
+        before_id: Option<&str>,
+        cx: &mut App,
+    ) {
+        if self.config.move_command(command_id, group_id, before_id) {
+            self.save_config(cx);
+        }
+    }
+
+    /// 移动命令组（拖拽排序），`parent_id` 为目标父级，`before_id` 为排在其前面的组
+    pub fn move_group(
+        &mut self,
+        group_id: &str,
+        parent_id: Option<String>,
+        before_id: Option<&str>,
+        cx: &mut App,
+    ) {
+        if self.config.move_group(group_id, parent_id, before_id) {
+            self.save_config(cx);
+        }
+    }
+
+    /// 保存当前配置，并通知主页同步会话侧边栏中的快捷命令
+    fn save_config(&mut self, cx: &mut App) {
+        if let Err(e) = storage::save_snippets(&self.config) {
+            error!("Failed to save snippets: {}", e);
+        }
+        self.dialog_state
+            .update(cx, |s, _| s.needs_page_refresh = true);
+    }
+
     /// 检查弹窗是否打开
     #[allow(dead_code)]
     pub fn is_dialog_open(&self, cx: &App) -> bool {
@@ -106,6 +174,9 @@ impl SnippetsPageState {
     }
 }
 
+/// 命令卡片上最多显示的标签数量
+const MAX_CARD_TAGS: usize = 2;
+
 /// 卡片颜色配置
 #[derive(Clone, Copy)]
 struct CardColors {
@@ -183,6 +254,7 @@ pub fn render_snippets_content(state: Entity<SnippetsPageState>, cx: &App) -> im
                     groups,
                     commands,
                     &config,
+                    &lang,
                     colors,
                     cx,
                 ))
@@ -201,7 +273,10 @@ fn render_toolbar(
     cx: &App,
 ) -> impl IntoElement {
     let state_for_group = state.clone();
-    let state_for_command = state;
+    let state_for_command = state.clone();
+    let state_for_import = state;
+    let import_title = i18n::t(lang, "snippets.library.import");
+    let export_title = i18n::t(lang, "snippets.library.export");
 
     div()
         .flex_shrink_0()
@@ -257,6 +332,143 @@ fn render_toolbar(
                         .child(i18n::t(lang, "snippets.add_command")),
                 ),
         )
+        .child(div().flex_1())
+        .child(
+            // 导入命令库（JSON / YAML 或 shell alias 文件）
+            Button::new("snippets-import-btn")
+                .outline()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(render_icon(icons::UPLOAD, colors.muted_foreground.into()))
+                        .child(import_title),
+                )
+                .on_click(move |_, _, cx| import_library(state_for_import.clone(), cx)),
+        )
+        .child(
+            // 导出命令库：选择格式后弹出保存对话框
+            Button::new("snippets-export-btn")
+                .outline()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(render_icon(icons::DOWNLOAD, colors.muted_foreground.into()))
+                        .child(export_title),
+                )
+                .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                    let mut menu = menu;
+                    for (format, label) in
+                        [(LibraryFormat::Json, "JSON"), (LibraryFormat::Yaml, "YAML")]
+                    {
+                        menu = menu.item(
+                            PopupMenuItem::new(label)
+                                .on_click(move |_, _, cx| export_library(format, label, cx)),
+                        );
+                    }
+                    menu
+                }),
+        )
+}
+
+/// 选择文件并导入命令库，完成后刷新页面与会话侧边栏
+fn import_library(state: Entity<SnippetsPageState>, cx: &mut App) {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let title = i18n::t(&lang, "snippets.library.import");
+    let done = i18n::t(&lang, "snippets.library.imported");
+    let duplicates = i18n::t(&lang, "snippets.library.duplicates");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .add_filter("JSON / YAML", &["json", "yaml", "yml"])
+            .add_filter("Shell aliases", &["sh", "bash", "zsh", "aliases"])
+            .add_filter("*", &["*"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+        let result = snippet_library::import_snippets(file.path());
+        let _ = cx.update(|cx| match result {
+            Ok(summary) => {
+                let mut text = done.replace("{}", &summary.commands.to_string());
+                if summary.duplicates > 0 {
+                    text.push_str(" · ");
+                    text.push_str(&duplicates.replace("{}", &summary.duplicates.to_string()));
+                }
+                state.update(cx, |s, cx| {
+                    s.dialog_state
+                        .update(cx, |ds, _| ds.needs_page_refresh = true);
+                });
+                notify(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Success,
+                    text,
+                );
+            }
+            Err(e) => {
+                error!("Failed to import snippets: {}", e);
+                notify(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Error,
+                    e.to_string(),
+                );
+            }
+        });
+    })
+    .detach();
+}
+
+/// 选择保存位置并导出命令库
+fn export_library(format: LibraryFormat, label: &'static str, cx: &mut App) {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let title = i18n::t(&lang, "snippets.library.export");
+    let done = i18n::t(&lang, "snippets.library.exported");
+
+    cx.spawn(async move |cx| {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_title(title)
+            .set_file_name(format.default_file_name())
+            .add_filter(label, format.extensions())
+            .save_file()
+            .await
+        else {
+            return;
+        };
+        let result = snippet_library::export_snippets(file.path(), format);
+        let _ = cx.update(|cx| match result {
+            Ok(count) => notify(
+                cx,
+                NotificationCategory::Operation,
+                NotificationType::Success,
+                format!(
+                    "{} {}",
+                    done.replace("{}", &count.to_string()),
+                    file.path().display()
+                ),
+            ),
+            Err(e) => {
+                error!("Failed to export snippets: {}", e);
+                notify(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Error,
+                    e.to_string(),
+                );
+            }
+        });
+    })
+    .detach();
 }
 
 /// 渲染面包屑导航
@@ -294,8 +506,14 @@ fn render_breadcrumb(
                     CursorStyle::PointingHand
                 })
                 .hover(move |s| if !is_root { s.underline() } else { s })
-                .on_click(move |_, _, cx| {
-                    state_for_root.update(cx, |s, _| s.go_to_level(0));
+                .on_click({
+                    let state = state_for_root.clone();
+                    move |_, _, cx| {
+                        state.update(cx, |s, _| s.go_to_level(0));
+                    }
+                })
+                .when(!is_root, |this| {
+                    breadcrumb_drop_target(this, state_for_root, None, colors)
                 })
                 .child(i18n::t(lang, "snippets.breadcrumb.all")),
         )
@@ -332,25 +550,57 @@ fn render_breadcrumb(
                             CursorStyle::PointingHand
                         })
                         .hover(move |s| if is_last { s } else { s.underline() })
-                        .on_click(move |_, _, cx| {
-                            if !is_last {
-                                state_for_click.update(cx, |s, _| s.go_to_level(level));
+                        .on_click({
+                            let state = state_for_click.clone();
+                            move |_, _, cx| {
+                                if !is_last {
+                                    state.update(cx, |s, _| s.go_to_level(level));
+                                }
                             }
                         })
+                        .when(!is_last, |this| {
+                            breadcrumb_drop_target(this, state_for_click, Some(id_owned), colors)
+                        })
                         .child(name_owned),
                 )
         }))
 }
 
+/// 面包屑层级作为拖放目标：命令和命令组拖到上级层级时移入该层级（`group_id` 为 None 表示根目录）
+fn breadcrumb_drop_target(
+    el: Stateful<Div>,
+    state: Entity<SnippetsPageState>,
+    group_id: Option<String>,
+    colors: CardColors,
+) -> Stateful<Div> {
+    let state_for_group = state.clone();
+    let target_for_group = group_id.clone();
+    el.drag_over::<DraggedSnippetCommand>(move |this, _, _, _| this.bg(colors.primary.opacity(0.1)))
+        .on_drop(move |dragged: &DraggedSnippetCommand, _, cx| {
+            state.update(cx, |s, cx| {
+                s.move_command(&dragged.id, group_id.clone(), None, cx)
+            });
+        })
+        .drag_over::<DraggedSnippetGroup>(move |this, _, _, _| this.bg(colors.primary.opacity(0.1)))
+        .on_drop(move |dragged: &DraggedSnippetGroup, _, cx| {
+            state_for_group.update(cx, |s, cx| {
+                s.move_group(&dragged.id, target_for_group.clone(), None, cx)
+            });
+        })
+}
+
 /// 渲染卡片网格
 fn render_card_grid(
     state: Entity<SnippetsPageState>,
     groups: Vec<SnippetGroup>,
     commands: Vec<SnippetCommand>,
     config: &SnippetsConfig,
+    lang: &Language,
     colors: CardColors,
-    _cx: &App,
+    cx: &App,
 ) -> impl IntoElement {
+    let parent_id = state.read(cx).current_parent_id().map(|s| s.to_string());
+
     div()
         .flex()
         .flex_wrap()
@@ -359,7 +609,14 @@ fn render_card_grid(
         .children(groups.into_iter().map(|group| {
             let state_clone = state.clone();
             let child_count = config.count_children(&group.id);
-            render_group_card(state_clone, group, child_count, colors)
+            render_group_card(
+                state_clone,
+                group,
+                parent_id.clone(),
+                child_count,
+                lang,
+                colors,
+            )
         }))
         // 命令卡片
         .children(commands.into_iter().map(|command| {
@@ -372,16 +629,26 @@ fn render_card_grid(
 fn render_group_card(
     state: Entity<SnippetsPageState>,
     group: SnippetGroup,
+    parent_id: Option<String>,
     child_count: usize,
+    lang: &Language,
     colors: CardColors,
 ) -> impl IntoElement {
     let group_id = group.id.clone();
     let group_for_enter = group_id.clone();
     let group_for_edit = group.clone();
     let group_id_for_delete = group_id.clone();
+    let group_id_for_reorder = group_id.clone();
+    let group_id_for_move_in = group_id.clone();
     let state_for_enter = state.clone();
     let state_for_edit = state.clone();
+    let state_for_reorder = state.clone();
+    let state_for_move_in = state.clone();
     let state_for_delete = state;
+    let dragged = DraggedSnippetGroup {
+        id: group_id.clone(),
+        name: group.name.clone(),
+    };
 
     div()
         .id(SharedString::from(format!("group-card-{}", group_id)))
@@ -397,6 +664,32 @@ fn render_group_card(
         .on_click(move |_, _, cx| {
             state_for_enter.update(cx, |s, _| s.enter_group(group_for_enter.clone()));
         })
+        .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+        // 命令组拖到命令组上：排在其前面
+        .drag_over::<DraggedSnippetGroup>(move |this, _, _, _| {
+            this.border_color(colors.primary)
+                .bg(colors.primary.opacity(0.1))
+        })
+        .on_drop(move |dragged: &DraggedSnippetGroup, _, cx| {
+            state_for_reorder.update(cx, |s, cx| {
+                s.move_group(
+                    &dragged.id,
+                    parent_id.clone(),
+                    Some(group_id_for_reorder.as_str()),
+                    cx,
+                )
+            });
+        })
+        // 命令拖到命令组上：移入该组
+        .drag_over::<DraggedSnippetCommand>(move |this, _, _, _| {
+            this.border_color(colors.primary)
+                .bg(colors.primary.opacity(0.1))
+        })
+        .on_drop(move |dragged: &DraggedSnippetCommand, _, cx| {
+            state_for_move_in.update(cx, |s, cx| {
+                s.move_command(&dragged.id, Some(group_id_for_move_in.clone()), None, cx)
+            });
+        })
         .flex()
         .flex_col()
         .justify_between()
@@ -418,14 +711,26 @@ fn render_group_card(
                         .child(render_icon(icons::FOLDER, colors.primary.into())),
                 )
                 .child(
-                    div().flex_1().overflow_hidden().child(
-                        div()
-                            .text_sm()
-                            .font_weight(FontWeight::MEDIUM)
-                            .text_color(colors.foreground)
-                            .overflow_hidden()
-                            .child(group.name.clone()),
-                    ),
+                    div()
+                        .flex_1()
+                        .overflow_hidden()
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(FontWeight::MEDIUM)
+                                .text_color(colors.foreground)
+                                .overflow_hidden()
+                                .child(group.name.clone()),
+                        )
+                        .children(group.description.clone().map(|description| {
+                            div()
+                                .text_xs()
+                                .text_color(colors.muted_foreground)
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .child(description)
+                        })),
                 ),
         )
         .child(
@@ -434,12 +739,9 @@ fn render_group_card(
                 .flex()
                 .justify_between()
                 .items_center()
-                .child(
-                    div()
-                        .text_xs()
-                        .text_color(colors.muted_foreground)
-                        .child(format!("{} 项", child_count)),
-                )
+                .child(div().text_xs().text_color(colors.muted_foreground).child(
+                    i18n::t(lang, "snippets.group.items").replace("{}", &child_count.to_string()),
+                ))
                 .child(
                     div()
                         .flex()
@@ -496,8 +798,25 @@ fn render_command_card(
     let command_text = command.command.clone();
     let command_for_edit = command.clone();
     let command_id_for_delete = command_id.clone();
+    let command_id_for_reorder = command_id.clone();
+    let group_id_for_reorder = command.group_id.clone();
     let state_for_edit = state.clone();
+    let state_for_reorder = state.clone();
     let state_for_delete = state;
+    let dragged = DraggedSnippetCommand {
+        id: command_id.clone(),
+        name: command.name.clone(),
+    };
+    // 有颜色标记时图标使用该颜色
+    let accent: Hsla = command
+        .color
+        .map(|color| rgb(color).into())
+        .unwrap_or(colors.muted_foreground);
+    // 有描述时显示描述，否则显示命令预览
+    let preview = command
+        .description
+        .clone()
+        .unwrap_or_else(|| truncate_command(&command.command, 30));
 
     div()
         .id(SharedString::from(format!("command-card-{}", command_id)))
@@ -514,6 +833,22 @@ fn render_command_card(
         .on_click(move |_, _, cx| {
             cx.write_to_clipboard(ClipboardItem::new_string(command_text.clone()));
         })
+        .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+        // 命令拖到命令上：排在其前面
+        .drag_over::<DraggedSnippetCommand>(move |this, _, _, _| {
+            this.border_color(colors.primary)
+                .bg(colors.primary.opacity(0.1))
+        })
+        .on_drop(move |dragged: &DraggedSnippetCommand, _, cx| {
+            state_for_reorder.update(cx, |s, cx| {
+                s.move_command(
+                    &dragged.id,
+                    group_id_for_reorder.clone(),
+                    Some(command_id_for_reorder.as_str()),
+                    cx,
+                )
+            });
+        })
         .flex()
         .flex_col()
         .justify_between()
@@ -528,11 +863,11 @@ fn render_command_card(
                         .w_10()
                         .h_10()
                         .rounded_lg()
-                        .bg(colors.muted_foreground.opacity(0.1))
+                        .bg(accent.opacity(0.1))
                         .flex()
                         .items_center()
                         .justify_center()
-                        .child(render_icon(icons::CODE, colors.muted_foreground.into())),
+                        .child(render_icon(icons::CODE, accent.into())),
                 )
                 .child(
                     div().flex_1().overflow_hidden().child(
@@ -546,61 +881,100 @@ fn render_command_card(
                 ),
         )
         .child(
-            // 中部：命令预览
+            // 中部：描述或命令预览
             div()
                 .text_xs()
                 .text_color(colors.muted_foreground)
                 .overflow_hidden()
-                .child(truncate_command(&command.command, 30)),
+                .whitespace_nowrap()
+                .text_ellipsis()
+                .child(preview),
         )
         .child(
-            // 底部：操作按钮
-            div().flex().justify_end().items_center().child(
-                div()
-                    .flex()
-                    .gap_1()
-                    .child(
-                        div()
-                            .id(SharedString::from(format!(
-                                "command-edit-{}",
-                                command_id.clone()
-                            )))
-                            .cursor_pointer()
-                            .p_1()
-                            .rounded_sm()
-                            .hover(move |s| s.bg(colors.secondary_hover))
-                            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
-                                cx.stop_propagation();
-                                state_for_edit
-                                    .update(cx, |s, cx| s.open_edit_command(&command_for_edit, cx));
-                            })
-                            .child(render_icon(icons::EDIT, colors.muted_foreground.into())),
-                    )
-                    .child(
-                        div()
-                            .id(SharedString::from(format!("command-delete-{}", command_id)))
-                            .cursor_pointer()
-                            .p_1()
-                            .rounded_sm()
-                            .hover(move |s| s.bg(colors.destructive.opacity(0.1)))
-                            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
-                                cx.stop_propagation();
-                                if let Err(e) =
-                                    storage::delete_snippet_command(&command_id_for_delete)
-                                {
-                                    error!("Failed to delete snippet command: {}", e);
-                                }
-                                state_for_delete.update(cx, |s, _| {
-                                    s.needs_refresh = true;
-                                    s.refresh();
-                                });
-                            })
-                            .child(render_icon(icons::TRASH, colors.destructive.into())),
-                    ),
-            ),
+            // 底部：标签和操作按钮
+            div()
+                .flex()
+                .justify_between()
+                .items_center()
+                .gap_1()
+                .child(render_tags(&command.tags, colors))
+                .child(
+                    div()
+                        .flex()
+                        .gap_1()
+                        .child(
+                            div()
+                                .id(SharedString::from(format!(
+                                    "command-edit-{}",
+                                    command_id.clone()
+                                )))
+                                .cursor_pointer()
+                                .p_1()
+                                .rounded_sm()
+                                .hover(move |s| s.bg(colors.secondary_hover))
+                                .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                                    cx.stop_propagation();
+                                    state_for_edit.update(cx, |s, cx| {
+                                        s.open_edit_command(&command_for_edit, cx)
+                                    });
+                                })
+                                .child(render_icon(icons::EDIT, colors.muted_foreground.into())),
+                        )
+                        .child(
+                            div()
+                                .id(SharedString::from(format!("command-delete-{}", command_id)))
+                                .cursor_pointer()
+                                .p_1()
+                                .rounded_sm()
+                                .hover(move |s| s.bg(colors.destructive.opacity(0.1)))
+                                .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                                    cx.stop_propagation();
+                                    if let Err(e) =
+                                        storage::delete_snippet_command(&command_id_for_delete)
+                                    {
+                                        error!("Failed to delete snippet command: {}", e);
+                                    }
+                                    state_for_delete.update(cx, |s, _| {
+                                        s.needs_refresh = true;
+                                        s.refresh();
+                                    });
+                                })
+                                .child(render_icon(icons::TRASH, colors.destructive.into())),
+                        ),
+                ),
         )
 }
 
+/// 渲染命令标签（最多显示两个，其余显示数量）
+fn render_tags(tags: &[String], colors: CardColors) -> impl IntoElement {
+    let hidden = tags.len().saturating_sub(MAX_CARD_TAGS);
+    div()
+        .flex_1()
+        .min_w_0()
+        .flex()
+        .items_center()
+        .gap_1()
+        .overflow_hidden()
+        .children(tags.iter().take(MAX_CARD_TAGS).map(|tag| {
+            div()
+                .px_1()
+                .rounded_sm()
+                .bg(colors.primary.opacity(0.1))
+                .text_xs()
+                .text_color(colors.primary)
+                .whitespace_nowrap()
+                .child(tag.clone())
+        }))
+        .when(hidden > 0, |this| {
+            this.child(
+                div()
+                    .text_xs()
+                    .text_color(colors.muted_foreground)
+                    .child(format!("+{}", hidden)),
+            )
+        })
+}
+
 /// 渲染空状态
 fn render_empty_state(
     state: Entity<SnippetsPageState>,
@@ -609,7 +983,10 @@ fn render_empty_state(
     cx: &App,
 ) -> impl IntoElement {
     let state_for_group = state.clone();
-    let state_for_command = state;
+    let state_for_command = state.clone();
+    let state_for_import = state;
+    let import_title = i18n::t(lang, "snippets.library.import");
+    let export_title = i18n::t(lang, "snippets.library.export");
 
     div()
         .flex_1()
@@ -1,11 +1,24 @@
 // 标题栏组件
 
+use std::collections::HashMap;
+
+use gpui::prelude::FluentBuilder;
 use gpui::*;
-use gpui_component::ActiveTheme;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{ContextMenuExt, DropdownMenu, PopupMenu, PopupMenuItem};
+use gpui_component::{ActiveTheme, Sizable};
 
+use super::latency_indicator::render_latency_indicator;
 use crate::components::common::icon::render_icon;
+use crate::components::common::workspace_dialog::WorkspaceDialogState;
 use crate::constants::icons;
-use crate::state::{SessionState, SessionStatus};
+use crate::i18n;
+use crate::keybindings::ToggleNotificationCenter;
+use crate::models::settings::Language;
+use crate::pages::session::detached_window::{activate_window, open_detached_window};
+use crate::services::notifications::NotificationCenter;
+use crate::services::storage;
+use crate::state::{SessionState, SessionStatus, SessionTab, TabColor};
 
 /// 渲染 Home 按钮（紧凑版本，不占用 sidebar 宽度）
 pub fn render_home_button(session_state: Entity<SessionState>, cx: &App) -> impl IntoElement {
@@ -54,12 +67,90 @@ pub fn render_home_button(session_state: Entity<SessionState>, cx: &App) -> impl
         )
 }
 
+/// 渲染会话工作区按钮（打开工作区弹窗）
+fn render_workspace_button(
+    workspace_state: Entity<WorkspaceDialogState>,
+    cx: &App,
+) -> impl IntoElement {
+    let icon_color = cx.theme().muted_foreground;
+
+    div()
+        .id("workspace-btn")
+        .flex_shrink_0()
+        .mx_1()
+        .w_8()
+        .h_8()
+        .rounded_md()
+        .cursor_pointer()
+        .hover(|s| s.bg(rgba(0x80808040))) // 与 Home 按钮相同的悬浮效果
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_click(move |_, _, cx| {
+            workspace_state.update(cx, |state, cx| {
+                state.open();
+                cx.notify();
+            });
+        })
+        .child(render_icon(icons::ARCHIVE, icon_color.into()))
+}
+
+/// 渲染通知中心按钮（有未读通知时显示数量角标）
+fn render_notification_button(cx: &App) -> impl IntoElement {
+    let icon_color = cx.theme().muted_foreground;
+    let unread = NotificationCenter::global().unread_count();
+
+    div()
+        .id("notification-center-btn")
+        .relative()
+        .flex_shrink_0()
+        .mx_1()
+        .w_8()
+        .h_8()
+        .rounded_md()
+        .cursor_pointer()
+        .hover(|s| s.bg(rgba(0x80808040))) // 与 Home 按钮相同的悬浮效果
+        .flex()
+        .items_center()
+        .justify_center()
+        .on_click(|_, window, cx| {
+            window.dispatch_action(Box::new(ToggleNotificationCenter), cx);
+        })
+        .child(render_icon(icons::BELL, icon_color.into()))
+        .when(unread > 0, |this| {
+            this.child(
+                div()
+                    .absolute()
+                    .top_0p5()
+                    .right_0p5()
+                    .min_w(px(14.))
+                    .h(px(14.))
+                    .px_0p5()
+                    .rounded_full()
+                    .bg(cx.theme().danger)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_size(px(9.))
+                    .text_color(cx.theme().danger_foreground)
+                    .child(if unread > 99 {
+                        "99+".to_string()
+                    } else {
+                        unread.to_string()
+                    }),
+            )
+        })
+}
+
 /// 渲染主页标题栏（Home 页面，无内容）
 use crate::components::common::window_controls::render_windows_controls;
 
 // ...
 
-pub fn render_titlebar(cx: &App) -> impl IntoElement {
+pub fn render_titlebar(
+    workspace_state: Entity<WorkspaceDialogState>,
+    cx: &App,
+) -> impl IntoElement {
     let bg = crate::theme::titlebar_color(cx);
     let border = cx.theme().title_bar_border;
 
@@ -79,22 +170,107 @@ pub fn render_titlebar(cx: &App) -> impl IntoElement {
                 .h_full()
                 .window_control_area(WindowControlArea::Drag),
         ) // Spacer with drag functionality
+        .child(render_notification_button(cx))
+        .child(render_workspace_button(workspace_state, cx))
         .child(render_windows_controls(cx)) // Add window controls
 }
 
+/// 标签颜色标记可选的颜色：(0xRRGGBB, 名称的 i18n 键)
+pub const TAB_COLORS: [(u32, &str); 8] = [
+    (0xef4444, "tab.color.red"),
+    (0xf97316, "tab.color.orange"),
+    (0xeab308, "tab.color.yellow"),
+    (0x22c55e, "tab.color.green"),
+    (0x14b8a6, "tab.color.teal"),
+    (0x3b82f6, "tab.color.blue"),
+    (0x8b5cf6, "tab.color.purple"),
+    (0xec4899, "tab.color.pink"),
+];
+
+/// 标签数量达到该值时显示标签列表下拉按钮
+const TAB_LIST_THRESHOLD: usize = 5;
+
+/// 拖拽中的会话标签（放到其他标签上时排在其前面，放到会话内容区域时在新窗口打开）
+#[derive(Clone)]
+pub struct DraggedTab {
+    pub id: String,
+    pub label: String,
+}
+
+impl Render for DraggedTab {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_3()
+            .py_1()
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .shadow_md()
+            .text_sm()
+            .text_color(cx.theme().foreground)
+            .child(self.label.clone())
+    }
+}
+
+/// 标签显示的颜色：手动设置的颜色优先，否则按服务器所属分组分配
+fn tab_color(tab: &SessionTab, server_groups: &HashMap<String, String>) -> Option<Hsla> {
+    match tab.color {
+        TabColor::Rgb(color) => Some(rgb(color).into()),
+        TabColor::None => None,
+        TabColor::Auto => server_groups.get(&tab.server_id).map(|group_id| {
+            let hash = group_id
+                .bytes()
+                .fold(0usize, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+            rgb(TAB_COLORS[hash % TAB_COLORS.len()].0).into()
+        }),
+    }
+}
+
 /// 渲染会话标题栏（带标签页）
-pub fn render_session_titlebar(session_state: Entity<SessionState>, cx: &App) -> impl IntoElement {
+pub fn render_session_titlebar(
+    session_state: Entity<SessionState>,
+    window_id: Option<WindowId>,
+    workspace_state: Option<Entity<WorkspaceDialogState>>,
+    cx: &App,
+) -> impl IntoElement {
     let bg = crate::theme::titlebar_color(cx);
     let border = cx.theme().title_bar_border;
-    let primary = cx.theme().primary;
-    let foreground = cx.theme().foreground;
     let muted_foreground = cx.theme().muted_foreground;
-    let secondary = cx.theme().secondary;
-    let secondary_hover = cx.theme().secondary_hover;
 
+    // 只显示当前窗口中的标签
     let state = session_state.read(cx);
-    let tabs = state.tabs.clone();
-    let active_tab_id = state.active_tab_id.clone();
+    let tabs: Vec<SessionTab> = state.window_tabs(window_id).cloned().collect();
+    let active_tab_id = state.window_active_tab(window_id).map(|t| t.id.clone());
+    // 可移入的其他窗口（主窗口 + 其他独立窗口）
+    let other_windows: Vec<(Option<WindowId>, String)> = window_id
+        .map(|_| (None, String::new()))
+        .into_iter()
+        .chain(
+            state
+                .detached_windows()
+                .into_iter()
+                .filter(|(id, _)| Some(*id) != window_id)
+                .map(|(id, title)| (Some(id), title)),
+        )
+        .collect();
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    // 服务器 ID -> 分组 ID，用于按分组自动分配标签颜色
+    let server_groups: HashMap<String, String> = storage::load_servers()
+        .map(|config| {
+            config
+                .servers
+                .into_iter()
+                .filter_map(|s| Some((s.id, s.group_id?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let tab_list: Vec<(String, String)> = tabs
+        .iter()
+        .map(|t| (t.id.clone(), t.server_label.clone()))
+        .collect();
 
     div()
         .h(px(44.)) // 与 Home 按钮区域高度相同
@@ -105,111 +281,30 @@ pub fn render_session_titlebar(session_state: Entity<SessionState>, cx: &App) ->
         .flex()
         .items_center()
         .pl_4() // 只添加左侧 padding，右侧让 window controls 靠边
-        // 标签页列表
+        // 标签页列表（标签较多时横向滚动）
         .child(
             div()
+                .id("session-tabs")
+                .flex_shrink()
+                .min_w_0()
+                .h_full()
+                .overflow_x_scroll()
                 .flex()
                 .items_center()
                 .gap_1()
-                .children(tabs.into_iter().map({
-                    let active_id = active_tab_id.clone();
-                    let session_state = session_state.clone();
-                    move |tab| {
-                        let is_active = active_id.as_ref() == Some(&tab.id);
-                        let tab_id = tab.id.clone();
-                        let tab_id_for_click = tab_id.clone();
-                        let tab_id_for_close = tab_id.clone();
-                        let session_state_for_click = session_state.clone();
-                        let session_state_for_close = session_state.clone();
-
-                        // 标签状态图标
-                        let status_icon = match &tab.status {
-                            SessionStatus::Connecting => Some((icons::LOADER, primary)),
-                            SessionStatus::Connected => Some((icons::CHECK, primary)),
-                            SessionStatus::Error(_) => Some((icons::X, Hsla::from(rgb(0xef4444)))),
-                            SessionStatus::Disconnected => {
-                                Some((icons::LOADER, Hsla::from(rgb(0xf59e0b))))
-                                // 橙色加载图标
-                            }
-                            SessionStatus::Reconnecting { .. } => {
-                                Some((icons::LOADER, Hsla::from(rgb(0xf59e0b))))
-                                // 橙色加载图标
-                            }
-                        };
-
-                        div()
-                            .id(SharedString::from(format!("tab-{}", tab_id)))
-                            .h(px(32.))
-                            .px_3()
-                            .rounded_md()
-                            .cursor_pointer()
-                            .bg(if is_active { secondary } else { bg })
-                            .hover(move |s| {
-                                s.bg(if is_active {
-                                    secondary
-                                } else {
-                                    secondary_hover
-                                })
-                            })
-                            .flex()
-                            .items_center()
-                            .gap_2()
-                            .on_click(move |_, _, cx| {
-                                session_state_for_click.update(cx, |state, _| {
-                                    state.activate_tab(&tab_id_for_click);
-                                    state.show_home = false;
-                                });
-                            })
-                            // 状态图标
-                            .children(status_icon.map(|(icon, color)| {
-                                div()
-                                    .w_4()
-                                    .h_4()
-                                    .flex()
-                                    .items_center()
-                                    .justify_center()
-                                    .child(render_icon(icon, color.into()))
-                            }))
-                            // 标签名
-                            .child(
-                                div()
-                                    .text_sm()
-                                    .text_color(if is_active {
-                                        foreground
-                                    } else {
-                                        muted_foreground
-                                    })
-                                    .max_w(px(150.))
-                                    .overflow_hidden()
-                                    .child(tab.server_label.clone()),
-                            )
-                            // 关闭按钮
-                            .child(
-                                div()
-                                    .id(SharedString::from(format!(
-                                        "tab-close-{}",
-                                        tab_id_for_close.clone()
-                                    )))
-                                    .w_4()
-                                    .h_4()
-                                    .rounded_sm()
-                                    .cursor_pointer()
-                                    .hover(move |s| s.bg(secondary_hover))
-                                    .flex()
-                                    .items_center()
-                                    .justify_center()
-                                    .on_mouse_down(MouseButton::Left, move |_, _, cx| {
-                                        cx.stop_propagation();
-                                        session_state_for_close.update(cx, |state, _| {
-                                            state.close_tab(&tab_id_for_close);
-                                        });
-                                        // 主动关闭 SSH 连接
-                                        crate::ssh::SshManager::global()
-                                            .close_session(&tab_id_for_close);
-                                    })
-                                    .child(render_icon(icons::X, muted_foreground.into())),
-                            )
-                    }
+                .children(tabs.into_iter().map(|tab| {
+                    let is_active = active_tab_id.as_ref() == Some(&tab.id);
+                    let color = tab_color(&tab, &server_groups);
+                    render_session_tab(
+                        tab,
+                        is_active,
+                        color,
+                        &lang,
+                        window_id,
+                        &other_windows,
+                        session_state.clone(),
+                        cx,
+                    )
                 })),
         )
         // 占位，将折叠按钮推到右侧 (with drag support for Windows)
@@ -217,8 +312,359 @@ pub fn render_session_titlebar(session_state: Entity<SessionState>, cx: &App) ->
             div()
                 .id("session-titlebar-drag-area")
                 .flex_1()
+                .min_w(px(24.))
                 .h_full()
                 .window_control_area(WindowControlArea::Drag),
         )
+        // 标签列表下拉（标签较多、部分标签被滚动隐藏时快速切换）
+        .when(tab_list.len() >= TAB_LIST_THRESHOLD, |this| {
+            let session_state = session_state.clone();
+            this.child(
+                Button::new("session-tab-list")
+                    .ghost()
+                    .xsmall()
+                    .child(render_icon(icons::CHEVRON_DOWN, muted_foreground.into()))
+                    .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+                        let mut menu = menu.min_w(px(200.));
+                        for (tab_id, label) in &tab_list {
+                            let session_state = session_state.clone();
+                            let tab_id = tab_id.clone();
+                            menu = menu.item(
+                                PopupMenuItem::new(label.clone())
+                                    .checked(active_tab_id.as_ref() == Some(&tab_id))
+                                    .on_click(move |_, _, cx| {
+                                        session_state.update(cx, |state, _| {
+                                            state.show_session_tab(&tab_id);
+                                        });
+                                    }),
+                            );
+                        }
+                        menu
+                    }),
+            )
+        })
+        .when_some(workspace_state, |this, workspace_state| {
+            this.child(render_notification_button(cx))
+                .child(render_workspace_button(workspace_state, cx))
+        })
         .child(render_windows_controls(cx)) // Add window controls
 }
+
+/// 渲染一个会话标签：固定标签只显示状态与简短名称，不显示关闭按钮
+#[allow(clippy::too_many_arguments)]
+fn render_session_tab(
+    tab: SessionTab,
+    is_active: bool,
+    color: Option<Hsla>,
+    lang: &Language,
+    window_id: Option<WindowId>,
+    other_windows: &[(Option<WindowId>, String)],
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let bg = crate::theme::titlebar_color(cx);
+    let primary = cx.theme().primary;
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let secondary = cx.theme().secondary;
+    let secondary_hover = cx.theme().secondary_hover;
+
+    let tab_id = tab.id.clone();
+    let tab_id_for_click = tab_id.clone();
+    let tab_id_for_close = tab_id.clone();
+    let tab_id_for_drop = tab_id.clone();
+    let session_state_for_click = session_state.clone();
+    let session_state_for_close = session_state.clone();
+    let session_state_for_drop = session_state.clone();
+    let pinned = tab.pinned;
+    let current_color = tab.color;
+    let dragged = DraggedTab {
+        id: tab_id.clone(),
+        label: tab.server_label.clone(),
+    };
+    let lang = lang.clone();
+    let other_windows = other_windows.to_vec();
+
+    // 标签状态图标
+    let status_icon = match &tab.status {
+        SessionStatus::Connecting => Some((icons::LOADER, primary)),
+        SessionStatus::Connected => Some((icons::CHECK, primary)),
+        SessionStatus::Error(_) => Some((icons::X, Hsla::from(rgb(0xef4444)))),
+        SessionStatus::Disconnected => {
+            Some((icons::LOADER, Hsla::from(rgb(0xf59e0b))))
+            // 橙色加载图标
+        }
+        SessionStatus::Reconnecting { .. } => {
+            Some((icons::LOADER, Hsla::from(rgb(0xf59e0b))))
+            // 橙色加载图标
+        }
+    };
+
+    div()
+        .id(SharedString::from(format!("tab-{}", tab_id)))
+        .flex_shrink_0()
+        .h(px(32.))
+        .when(pinned, |this| this.px_2())
+        .when(!pinned, |this| this.px_3())
+        .rounded_md()
+        .cursor_pointer()
+        .bg(if is_active { secondary } else { bg })
+        .hover(move |s| {
+            s.bg(if is_active {
+                secondary
+            } else {
+                secondary_hover
+            })
+        })
+        // 颜色标记显示为底部色条
+        .when_some(color, |this, color| this.border_b_2().border_color(color))
+        .flex()
+        .items_center()
+        .gap_2()
+        .on_click(move |_, _, cx| {
+            session_state_for_click.update(cx, |state, _| {
+                state.activate_tab(&tab_id_for_click);
+                state.show_home = false;
+            });
+        })
+        // 拖动排序：放到标签上时排在该标签之前
+        .on_drag(dragged, |dragged, _, _, cx| cx.new(|_| dragged.clone()))
+        .drag_over::<DraggedTab>(move |this, _, _, _| this.bg(primary.opacity(0.15)))
+        .on_drop(move |dragged: &DraggedTab, _, cx| {
+            session_state_for_drop.update(cx, |state, cx| {
+                state.move_tab(&dragged.id, Some(&tab_id_for_drop));
+                cx.notify();
+            });
+        })
+        // 状态图标
+        .children(status_icon.map(|(icon, color)| {
+            div()
+                .w_4()
+                .h_4()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(render_icon(icon, color.into()))
+        }))
+        // 标签名
+        .child(
+            div()
+                .text_sm()
+                .text_color(if is_active {
+                    foreground
+                } else {
+                    muted_foreground
+                })
+                .max_w(if pinned { px(48.) } else { px(150.) })
+                .overflow_hidden()
+                .whitespace_nowrap()
+                .child(tab.server_label.clone()),
+        )
+        // 往返延迟与无响应状态
+        .when(tab.status == SessionStatus::Connected, |this| {
+            this.children(render_latency_indicator(
+                &tab_id,
+                &tab.latency,
+                &lang,
+                pinned,
+                cx,
+            ))
+        })
+        // 未读告警标记
+        .when(tab.unread_alerts > 0, |this| {
+            this.child(
+                div()
+                    .min_w(px(16.))
+                    .h(px(16.))
+                    .px_1()
+                    .rounded_full()
+                    .bg(Hsla::from(rgb(0xef4444)))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_xs()
+                    .text_color(gpui::white())
+                    .child(if tab.unread_alerts > 99 {
+                        "99+".to_string()
+                    } else {
+                        tab.unread_alerts.to_string()
+                    }),
+            )
+        })
+        // 关闭按钮（固定标签不显示）
+        .when(!pinned, |this| {
+            this.child(
+                div()
+                    .id(SharedString::from(format!(
+                        "tab-close-{}",
+                        tab_id_for_close.clone()
+                    )))
+                    .w_4()
+                    .h_4()
+                    .rounded_sm()
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(secondary_hover))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                        cx.stop_propagation();
+                        // 有进行中的工作时先确认
+                        session_state_for_close.update(cx, |state, cx| {
+                            state.request_close_tabs(vec![tab_id_for_close.clone()], window_id);
+                            cx.notify();
+                        });
+                    })
+                    .child(render_icon(icons::X, muted_foreground.into())),
+            )
+        })
+        // 右键菜单：关闭、固定、颜色标记与窗口
+        .context_menu({
+            let session_state = session_state.clone();
+            let tab_id = tab_id.clone();
+            move |menu, _window, _cx| {
+                build_tab_context_menu(
+                    menu,
+                    tab_id.clone(),
+                    pinned,
+                    current_color,
+                    &lang,
+                    window_id,
+                    &other_windows,
+                    session_state.clone(),
+                )
+            }
+        })
+}
+
+/// 构建标签的右键菜单：关闭标签、固定/取消固定、查看 SSH 配置、颜色标记、在新窗口打开与移到其他窗口
+#[allow(clippy::too_many_arguments)]
+fn build_tab_context_menu(
+    menu: PopupMenu,
+    tab_id: String,
+    pinned: bool,
+    current_color: TabColor,
+    lang: &Language,
+    window_id: Option<WindowId>,
+    other_windows: &[(Option<WindowId>, String)],
+    session_state: Entity<SessionState>,
+) -> PopupMenu {
+    let color_item = |label: &str, color: TabColor| {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.clone();
+        PopupMenuItem::new(label.to_string())
+            .checked(current_color == color)
+            .on_click(move |_, _, cx| {
+                session_state.update(cx, |state, cx| {
+                    state.set_tab_color(&tab_id, color);
+                    cx.notify();
+                });
+            })
+    };
+
+    // 关闭类操作统一走 request_close_tabs，有进行中的工作时合并为一次确认
+    let close_item =
+        |label: &str, collect: fn(&SessionState, &str, Option<WindowId>) -> Vec<String>| {
+            let session_state = session_state.clone();
+            let tab_id = tab_id.clone();
+            PopupMenuItem::new(label.to_string()).on_click(move |_, _, cx| {
+                session_state.update(cx, |state, cx| {
+                    let tab_ids = collect(state, &tab_id, window_id);
+                    if !tab_ids.is_empty() {
+                        state.request_close_tabs(tab_ids, window_id);
+                        cx.notify();
+                    }
+                });
+            })
+        };
+
+    let pin_item = {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.clone();
+        PopupMenuItem::new(i18n::t(lang, if pinned { "tab.unpin" } else { "tab.pin" })).on_click(
+            move |_, _, cx| {
+                session_state.update(cx, |state, cx| {
+                    state.toggle_tab_pinned(&tab_id);
+                    cx.notify();
+                });
+            },
+        )
+    };
+
+    let open_window_item = {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.clone();
+        PopupMenuItem::new(i18n::t(lang, "tab.open_in_new_window")).on_click(move |_, _, cx| {
+            let session_state = session_state.clone();
+            let tab_id = tab_id.clone();
+            cx.defer(move |cx| open_detached_window(tab_id, session_state, cx));
+        })
+    };
+
+    let ssh_snippet_item = {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.clone();
+        PopupMenuItem::new(i18n::t(lang, "tab.ssh_snippet")).on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                let Some(server_id) = state
+                    .tabs
+                    .iter()
+                    .find(|t| t.id == tab_id)
+                    .map(|t| t.server_id.clone())
+                else {
+                    return;
+                };
+                state.open_ssh_snippet(&server_id, Some(tab_id.clone()), cx);
+            });
+        })
+    };
+
+    let mut menu = menu.min_w(px(180.));
+    // 固定标签不能直接关闭
+    if !pinned {
+        menu = menu.item(close_item(i18n::t(lang, "tab.close"), |_, tab_id, _| {
+            vec![tab_id.to_string()]
+        }));
+    }
+    let mut menu = menu
+        .item(close_item(
+            i18n::t(lang, "tab.close_others"),
+            |state, tab_id, window_id| state.other_tab_ids(tab_id, window_id),
+        ))
+        .item(close_item(
+            i18n::t(lang, "tab.close_all"),
+            |state, _, window_id| state.closable_tab_ids(window_id),
+        ))
+        .separator()
+        .item(pin_item)
+        .item(ssh_snippet_item)
+        .item(open_window_item);
+    for (window_id, title) in other_windows {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.clone();
+        let window_id = *window_id;
+        let label = match window_id {
+            Some(_) => i18n::t(lang, "tab.move_to_window").replace("{}", title),
+            None => i18n::t(lang, "tab.move_to_main").to_string(),
+        };
+        menu = menu.item(PopupMenuItem::new(label).on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                state.move_tab_to_window(&tab_id, window_id);
+                cx.notify();
+            });
+            if let Some(window_id) = window_id {
+                activate_window(window_id, cx);
+            }
+        }));
+    }
+
+    let mut menu = menu
+        .separator()
+        .label(i18n::t(lang, "tab.color"))
+        .item(color_item(i18n::t(lang, "tab.color.auto"), TabColor::Auto))
+        .item(color_item(i18n::t(lang, "tab.color.none"), TabColor::None));
+    for (color, key) in TAB_COLORS {
+        menu = menu.item(color_item(i18n::t(lang, key), TabColor::Rgb(color)));
+    }
+    menu
+}
@@ -0,0 +1,195 @@
+// 回收站页面 - 列出已删除的服务器，可恢复或永久删除；超过保留期的由定时任务清理
+
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::{ActiveTheme, Sizable};
+use tracing::{error, info};
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::server::{TrashedServer, TRASH_RETENTION_DAYS};
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 渲染回收站内容区域
+pub fn render_trash_content(cx: &App) -> impl IntoElement {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+    let config = storage::load_servers().unwrap_or_default();
+    let muted = cx.theme().muted_foreground;
+    // 最近删除的在前
+    let trash: Vec<TrashedServer> = config.trash.iter().rev().cloned().collect();
+
+    div()
+        .flex_1()
+        .h_full()
+        .overflow_hidden()
+        .bg(crate::theme::background_color(cx))
+        .flex()
+        .flex_col()
+        // 头部：数量与保留期 + 清空
+        .child(
+            div()
+                .flex_shrink_0()
+                .p_6()
+                .pb_4()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(div().text_sm().text_color(muted).child(format!(
+                    "{} {} · {}",
+                    trash.len(),
+                    i18n::t(&lang, "trash.items"),
+                    i18n::t(&lang, "trash.retention")
+                        .replace("{}", &TRASH_RETENTION_DAYS.to_string())
+                )))
+                .child(
+                    Button::new("trash-empty")
+                        .small()
+                        .outline()
+                        .disabled(trash.is_empty())
+                        .child(i18n::t(&lang, "trash.empty_trash"))
+                        .on_click(|_, _, _| match storage::empty_trash() {
+                            Ok(count) => info!("[Trash] Emptied trash, {} servers removed", count),
+                            Err(e) => error!("[Trash] Failed to empty trash: {:#}", e),
+                        }),
+                ),
+        )
+        .child(if trash.is_empty() {
+            div()
+                .flex_1()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_3()
+                .child(render_icon(icons::TRASH, muted.into()))
+                .child(
+                    div()
+                        .text_sm()
+                        .text_color(muted)
+                        .child(i18n::t(&lang, "trash.empty")),
+                )
+                .into_any_element()
+        } else {
+            div()
+                .id("trash-scroll")
+                .flex_1()
+                .overflow_y_scroll()
+                .px_6()
+                .pb_6()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .children(
+                    trash
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, item)| render_trash_row(index, item, &lang, cx)),
+                )
+                .into_any_element()
+        })
+}
+
+/// 渲染回收站中的一行
+fn render_trash_row(
+    index: usize,
+    item: TrashedServer,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted = cx.theme().muted_foreground;
+    let server = &item.server;
+    let deleted_at = chrono::DateTime::parse_from_rfc3339(&item.deleted_at)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    let restore_id = server.id.clone();
+    let purge_id = server.id.clone();
+
+    div()
+        .id(("trash-row", index))
+        .px_4()
+        .py_3()
+        .bg(cx.theme().popover)
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .flex()
+        .items_center()
+        .gap_3()
+        .child(render_icon(icons::SERVER, muted.into()))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .gap(px(2.0))
+                .child(
+                    div()
+                        .text_sm()
+                        .font_weight(FontWeight::MEDIUM)
+                        .text_color(cx.theme().foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(server.label.clone()),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(muted)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(format!(
+                            "{}@{}:{}",
+                            server.username, server.host, server.port
+                        )),
+                ),
+        )
+        .child(
+            div()
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(muted)
+                .child(format!(
+                    "{} · {}",
+                    deleted_at,
+                    i18n::t(lang, "trash.days_left").replace("{}", &item.days_left().to_string())
+                )),
+        )
+        .child(
+            div()
+                .flex()
+                .gap_1()
+                .child(
+                    Button::new(("trash-restore", index))
+                        .xsmall()
+                        .ghost()
+                        .child(i18n::t(lang, "trash.restore"))
+                        .on_click(move |_, _, _| {
+                            if let Err(e) = storage::restore_server(&restore_id) {
+                                error!("[Trash] Failed to restore server: {:#}", e);
+                            }
+                        }),
+                )
+                .child(
+                    Button::new(("trash-purge", index))
+                        .xsmall()
+                        .ghost()
+                        .child(i18n::t(lang, "trash.purge"))
+                        .on_click(move |_, _, _| {
+                            if let Err(e) = storage::purge_server(&purge_id) {
+                                error!("[Trash] Failed to delete server: {:#}", e);
+                            }
+                        }),
+                ),
+        )
+}
@@ -0,0 +1,188 @@
+// 独立会话窗口：从主窗口移出的标签在自己的系统窗口中显示，与主窗口共享同一个 SessionState
+
+use gpui::*;
+use gpui_component::{ActiveTheme, Root};
+use tracing::{error, info};
+
+use super::navigation::bind_navigation_actions;
+use super::tab_content::render_tab_content;
+use crate::components::common::close_tabs_dialog::render_close_tabs_dialog_overlay;
+use crate::pages::home::titlebar::render_session_titlebar;
+use crate::state::SessionState;
+
+/// 独立窗口的根视图
+pub struct DetachedWindow {
+    session_state: Entity<SessionState>,
+    window_id: WindowId,
+    /// 已应用到窗口的背景外观（随当前标签的终端透明度变化）
+    window_background: WindowBackgroundAppearance,
+}
+
+/// 在新窗口中打开标签
+pub fn open_detached_window(tab_id: String, session_state: Entity<SessionState>, cx: &mut App) {
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    // 窗口背景按移入标签的终端透明度（含服务器级覆盖）设置
+    let opacity_override = session_state
+        .read(cx)
+        .tabs
+        .iter()
+        .find(|t| t.id == tab_id)
+        .and_then(|t| t.server_data.as_ref())
+        .and_then(|s| s.terminal_opacity);
+    let window_background =
+        crate::theme::window_background_appearance(&settings.terminal, opacity_override);
+    let bounds = Bounds::centered(None, size(px(1000.), px(700.)), cx);
+    let result = cx.open_window(
+        WindowOptions {
+            window_bounds: Some(WindowBounds::Windowed(bounds)),
+            titlebar: Some(TitlebarOptions {
+                appears_transparent: true,
+                traffic_light_position: Some(point(px(16.), px(16.))),
+                ..Default::default()
+            }),
+            // 终端背景透明/模糊需要窗口背景配合
+            window_background,
+            ..Default::default()
+        },
+        |window, cx| {
+            let window_id = window.window_handle().window_id();
+            session_state.update(cx, |state, cx| {
+                state.move_tab_to_window(&tab_id, Some(window_id));
+                cx.notify();
+            });
+
+            // 关闭独立窗口时把其中的标签移回主窗口
+            let session_for_close = session_state.clone();
+            window.on_window_should_close(cx, move |_window, cx| {
+                session_for_close.update(cx, |state, cx| {
+                    state.close_window(window_id);
+                    cx.notify();
+                });
+                true
+            });
+
+            let view = cx.new(|_| DetachedWindow {
+                session_state: session_state.clone(),
+                window_id,
+                window_background,
+            });
+            cx.new(|cx| Root::new(view, window, cx))
+        },
+    );
+    match result {
+        Ok(_) => info!("[Window] Opened detached window"),
+        Err(e) => error!("[Window] Failed to open detached window: {:?}", e),
+    }
+}
+
+/// 激活指定窗口（标签移到该窗口后）
+pub fn activate_window(window_id: WindowId, cx: &mut App) {
+    if let Some(handle) = cx
+        .windows()
+        .into_iter()
+        .find(|w| w.window_id() == window_id)
+    {
+        let _ = handle.update(cx, |_, window, _| window.activate_window());
+    }
+}
+
+impl Render for DetachedWindow {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        use gpui_component::WindowExt;
+
+        let window_id = self.window_id;
+        let Some(tab_id) = self
+            .session_state
+            .read(cx)
+            .window_active_tab(Some(window_id))
+            .map(|t| t.id.clone())
+        else {
+            // 标签已全部关闭或移走，关闭窗口
+            window.remove_window();
+            return div().into_any_element();
+        };
+
+        // 标签若已休眠，先按快照恢复终端
+        self.session_state
+            .update(cx, |state, cx| state.wake_tab(&tab_id, cx));
+        let Some(tab) = self
+            .session_state
+            .read(cx)
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .cloned()
+        else {
+            return div().into_any_element();
+        };
+        window.set_window_title(&tab.server_label);
+
+        // 窗口背景跟随当前标签的终端透明度
+        let opacity_override = tab.server_data.as_ref().and_then(|s| s.terminal_opacity);
+        let appearance = crate::theme::sync_window_background(
+            window,
+            &mut self.window_background,
+            opacity_override,
+        );
+
+        let content = render_tab_content(tab, self.session_state.clone(), window, cx);
+        // 关闭标签确认对话框（只在发起关闭的窗口显示）
+        let close_tabs_dialog = self
+            .session_state
+            .read(cx)
+            .pending_tab_close
+            .as_ref()
+            .is_some_and(|pending| pending.window_id == Some(window_id))
+            .then(|| render_close_tabs_dialog_overlay(self.session_state.clone(), cx));
+        let notifications = window.notifications(cx);
+
+        // macOS 红绿灯在左侧，标签页需要留出空间
+        let left_padding = if cfg!(target_os = "macos") {
+            px(64.)
+        } else {
+            px(0.)
+        };
+
+        bind_navigation_actions(div(), self.session_state.clone(), Some(window_id))
+            .size_full()
+            .relative()
+            .bg(crate::theme::session_background_color(appearance, cx))
+            .flex()
+            .flex_col()
+            .child(
+                div()
+                    .w_full()
+                    .flex()
+                    .child(
+                        div()
+                            .h(px(44.))
+                            .w(left_padding)
+                            .flex_shrink_0()
+                            .bg(crate::theme::titlebar_color(cx))
+                            .border_b_1()
+                            .border_color(cx.theme().title_bar_border),
+                    )
+                    .child(render_session_titlebar(
+                        self.session_state.clone(),
+                        Some(window_id),
+                        None,
+                        cx,
+                    )),
+            )
+            .child(div().flex_1().w_full().min_h(px(0.)).child(content))
+            .children(close_tabs_dialog)
+            // 通知列表覆盖层（显示在顶部中间）
+            .child(
+                div()
+                    .absolute()
+                    .top_2()
+                    .left_0()
+                    .right_0()
+                    .flex()
+                    .flex_col()
+                    .items_center()
+                    .children(notifications.iter().map(|n| n.clone())),
+            )
+            .into_any_element()
+    }
+}
@@ -0,0 +1,302 @@
+// 键盘宏面板 - 录制/停止录制，键盘宏列表（回放到当前或其他会话、编辑、删除）
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
+use gpui_component::notification::NotificationType;
+use gpui_component::{ActiveTheme, Sizable, StyledExt};
+
+use crate::constants::icons;
+use crate::models::settings::Language;
+use crate::models::terminal_macro::escape_keys;
+use crate::models::TerminalMacro;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::state::{SessionState, SessionStatus};
+
+/// 渲染键盘宏面板
+pub fn render_macro_panel(
+    tab_id: &str,
+    session_state: Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let state = session_state.read(cx);
+    let muted_foreground = cx.theme().muted_foreground;
+    let macros = state
+        .snippets_config
+        .as_ref()
+        .map(|config| config.macros.clone())
+        .unwrap_or_default();
+    // 可回放的其他会话（已连接的标签）
+    let targets: Vec<(String, String)> = state
+        .tabs
+        .iter()
+        .filter(|tab| tab.id != tab_id && tab.status == SessionStatus::Connected)
+        .map(|tab| (tab.id.clone(), tab.server_label.clone()))
+        .collect();
+    // 本标签正在录制时为已录制的字符数
+    let recorded = state
+        .macro_recording
+        .as_ref()
+        .filter(|r| r.tab_id == tab_id)
+        .map(|r| r.keys.chars().count());
+
+    // 工具栏：录制中显示已录制的字符数与停止按钮
+    let toolbar = div()
+        .flex()
+        .items_center()
+        .gap_2()
+        .px_2()
+        .pt_2()
+        .child(match recorded {
+            Some(count) => div()
+                .flex_1()
+                .flex()
+                .items_center()
+                .gap_1()
+                .text_xs()
+                .text_color(cx.theme().danger)
+                .child(div().size(px(6.)).rounded_full().bg(cx.theme().danger))
+                .child(crate::i18n::t(lang, "macro.recording").replace("{}", &count.to_string())),
+            None => div().flex_1(),
+        })
+        .child(
+            Button::new("macro-record")
+                .xsmall()
+                .when(recorded.is_some(), |b| b.primary())
+                .when(recorded.is_none(), |b| b.ghost())
+                .child(crate::i18n::t(
+                    lang,
+                    if recorded.is_some() {
+                        "macro.stop"
+                    } else {
+                        "macro.record"
+                    },
+                ))
+                .on_click({
+                    let session_state = session_state.clone();
+                    let tab_id = tab_id.to_string();
+                    move |_, _, cx| {
+                        session_state.update(cx, |state, cx| {
+                            if state.is_recording_macro(&tab_id) {
+                                state.stop_macro_recording(cx);
+                            } else {
+                                state.start_macro_recording(&tab_id, cx);
+                            }
+                        });
+                    }
+                }),
+        );
+
+    let body = if macros.is_empty() {
+        div()
+            .flex_1()
+            .flex()
+            .items_center()
+            .justify_center()
+            .px_4()
+            .text_xs()
+            .text_color(muted_foreground)
+            .child(crate::i18n::t(lang, "macro.empty"))
+            .into_any_element()
+    } else {
+        div()
+            .id("macro-list")
+            .flex_1()
+            .min_h(px(0.))
+            .overflow_y_scroll()
+            .px_2()
+            .py_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .children(
+                macros
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, terminal_macro)| {
+                        render_macro_item(
+                            index,
+                            tab_id,
+                            terminal_macro,
+                            targets.clone(),
+                            &session_state,
+                            lang,
+                            cx,
+                        )
+                    }),
+            )
+            .into_any_element()
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(toolbar)
+        .child(body)
+}
+
+/// 回放键盘宏，目标标签没有可用终端时提示
+fn play(
+    session_state: &Entity<SessionState>,
+    tab_id: &str,
+    terminal_macro: &TerminalMacro,
+    cx: &mut App,
+) {
+    let played = session_state.update(cx, |state, cx| state.play_macro(tab_id, terminal_macro, cx));
+    if !played {
+        notify_key(
+            cx,
+            NotificationCategory::Operation,
+            NotificationType::Error,
+            "macro.no_terminal",
+        );
+    }
+}
+
+/// 渲染一个键盘宏条目
+fn render_macro_item(
+    index: usize,
+    tab_id: &str,
+    terminal_macro: TerminalMacro,
+    targets: Vec<(String, String)>,
+    session_state: &Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let subtitle = escape_keys(&terminal_macro.keys);
+    let id = terminal_macro.id.clone();
+
+    let icon_button = |suffix: &str, icon: &'static str, on_click: Box<dyn Fn(&mut App)>| {
+        div()
+            .id(SharedString::from(format!("macro-{}-{}", suffix, id)))
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .on_click(move |_, _, cx| on_click(cx))
+            .child(svg().path(icon).size(px(14.)).text_color(muted_foreground))
+    };
+
+    let play_button = icon_button("play", icons::PLAY, {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.to_string();
+        let terminal_macro = terminal_macro.clone();
+        Box::new(move |cx: &mut App| play(&session_state, &tab_id, &terminal_macro, cx))
+    });
+    // 回放到其他会话
+    let play_to_button = Button::new(("macro-play-to", index))
+        .xsmall()
+        .ghost()
+        .disabled(targets.is_empty())
+        .tooltip(crate::i18n::t(lang, "macro.play_to"))
+        .child(
+            svg()
+                .path(icons::SEND)
+                .size(px(14.))
+                .text_color(muted_foreground),
+        )
+        .dropdown_menu_with_anchor(Corner::TopRight, {
+            let session_state = session_state.clone();
+            let terminal_macro = terminal_macro.clone();
+            move |menu, _, _| {
+                targets
+                    .iter()
+                    .fold(menu.min_w(px(140.)), |menu, (target_id, label)| {
+                        let session_state = session_state.clone();
+                        let terminal_macro = terminal_macro.clone();
+                        let target_id = target_id.clone();
+                        menu.item(PopupMenuItem::new(label.clone()).on_click(move |_, _, cx| {
+                            play(&session_state, &target_id, &terminal_macro, cx)
+                        }))
+                    })
+            }
+        });
+    let edit_button = icon_button("edit", icons::EDIT, {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.to_string();
+        let terminal_macro = terminal_macro.clone();
+        Box::new(move |cx: &mut App| {
+            session_state.update(cx, |state, cx| {
+                let dialog = state.ensure_macro_dialog(cx);
+                dialog.update(cx, |d, _| d.open_edit(tab_id.clone(), &terminal_macro));
+                cx.notify();
+            })
+        })
+    });
+    let delete_button = icon_button("delete", icons::TRASH, {
+        let session_state = session_state.clone();
+        let id = terminal_macro.id.clone();
+        Box::new(move |cx: &mut App| {
+            session_state.update(cx, |state, cx| state.delete_macro(&id, cx));
+        })
+    });
+
+    div()
+        .w_full()
+        .px_2()
+        .py_1()
+        .rounded(px(4.))
+        .flex()
+        .items_center()
+        .gap_2()
+        .hover(|s| s.bg(cx.theme().muted.opacity(0.3)))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_xs()
+                                .font_medium()
+                                .text_color(cx.theme().foreground)
+                                .child(terminal_macro.name.clone()),
+                        )
+                        .when_some(terminal_macro.keybinding.clone(), |this, keybinding| {
+                            this.child(
+                                div()
+                                    .flex_shrink_0()
+                                    .px_1()
+                                    .rounded(px(3.))
+                                    .bg(cx.theme().muted)
+                                    .text_xs()
+                                    .text_color(muted_foreground)
+                                    .child(keybinding),
+                            )
+                        }),
+                )
+                .child(
+                    div()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_xs()
+                        .font_family("monospace")
+                        .text_color(muted_foreground)
+                        .child(subtitle),
+                ),
+        )
+        .child(play_button)
+        .child(play_to_button)
+        .child(edit_button)
+        .child(delete_button)
+}
@@ -1,9 +1,20 @@
 // 会话页面模块
 
+pub mod detached_window;
+pub mod macro_panel;
 pub mod monitor_panel;
+pub mod navigation;
+pub mod notification_panel;
+pub mod runbook_panel;
 pub mod session_layout;
 pub mod session_sidebar;
 pub mod sftp_panel;
+pub mod tab_content;
+pub mod tab_overview;
 pub mod terminal_page;
 
+pub use detached_window::open_detached_window;
+pub use navigation::bind_navigation_actions;
 pub use session_layout::render_session_layout;
+pub use tab_content::render_tab_content;
+pub use tab_overview::render_tab_overview;
@@ -1,46 +1,216 @@
-// Monitor 面板组件
+// Monitor 面板组件（系统监控/进程/Docker/服务/网络/计划任务/软件包/防火墙八个页面）
 
+use gpui::prelude::FluentBuilder;
 use gpui::*;
 use gpui_component::ActiveTheme;
 
+use crate::components::connections::ConnectionListView;
+use crate::components::cron::CronView;
+use crate::components::docker::DockerView;
+use crate::components::firewall::FirewallView;
 use crate::components::monitor::{render_monitor_view, DetailDialogState};
+use crate::components::packages::PackageView;
+use crate::components::processes::ProcessListView;
+use crate::components::systemd::ServiceListView;
 use crate::models::monitor::MonitorState;
-use crate::state::SessionState;
+use crate::state::{MonitorPanelView, SessionState};
 
 /// 渲染 Monitor 面板
 pub fn render_monitor_panel(
     state: &MonitorState,
     detail_dialog_state: Option<Entity<DetailDialogState>>,
+    process_view: Option<Entity<ProcessListView>>,
+    docker_view: Option<Entity<DockerView>>,
+    service_view: Option<Entity<ServiceListView>>,
+    connection_view: Option<Entity<ConnectionListView>>,
+    cron_view: Option<Entity<CronView>>,
+    package_view: Option<Entity<PackageView>>,
+    firewall_view: Option<Entity<FirewallView>>,
     session_state: Entity<SessionState>,
     tab_id: String,
     cx: &App,
 ) -> impl IntoElement {
     let bg_color = crate::theme::sidebar_color(cx);
     let muted_color = cx.theme().muted_foreground;
+    let view = session_state.read(cx).monitor_panel_view;
+    let focus_handle = session_state.read(cx).monitor_focus_handle.clone();
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
 
-    // 如果没有 detail_dialog_state，只渲染基本视图
-    if let Some(dialog_state) = detail_dialog_state {
-        div()
-            .size_full()
+    // 页面切换
+    let tabs = div()
+        .flex()
+        .flex_wrap()
+        .flex_shrink_0()
+        .items_center()
+        .gap_1()
+        .px_2()
+        .pt_2()
+        .child(render_panel_view_tab(
+            "monitor-view-monitor",
+            crate::i18n::t(&lang, "monitor.tab.monitor"),
+            MonitorPanelView::Monitor,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-processes",
+            crate::i18n::t(&lang, "monitor.tab.processes"),
+            MonitorPanelView::Processes,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-docker",
+            crate::i18n::t(&lang, "monitor.tab.docker"),
+            MonitorPanelView::Docker,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-services",
+            crate::i18n::t(&lang, "monitor.tab.services"),
+            MonitorPanelView::Services,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-network",
+            crate::i18n::t(&lang, "monitor.tab.network"),
+            MonitorPanelView::Network,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-cron",
+            crate::i18n::t(&lang, "monitor.tab.cron"),
+            MonitorPanelView::Cron,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-packages",
+            crate::i18n::t(&lang, "monitor.tab.packages"),
+            MonitorPanelView::Packages,
+            view,
+            session_state.clone(),
+            cx,
+        ))
+        .child(render_panel_view_tab(
+            "monitor-view-firewall",
+            crate::i18n::t(&lang, "monitor.tab.firewall"),
+            MonitorPanelView::Firewall,
+            view,
+            session_state.clone(),
+            cx,
+        ));
+
+    let content = match (view, detail_dialog_state) {
+        (MonitorPanelView::Processes, _) if process_view.is_some() => div()
+            .flex_1()
             .min_h(px(0.))
-            .bg(bg_color)
-            .flex()
-            .flex_col()
-            .child(render_monitor_view(
-                state,
-                dialog_state,
-                session_state,
-                tab_id,
-                cx,
-            ))
-    } else {
-        // 没有 dialog state 时的降级渲染（不应该发生）
-        div()
-            .size_full()
-            .bg(bg_color)
+            .children(process_view)
+            .into_any_element(),
+        (MonitorPanelView::Docker, _) if docker_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(docker_view)
+            .into_any_element(),
+        (MonitorPanelView::Services, _) if service_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(service_view)
+            .into_any_element(),
+        (MonitorPanelView::Network, _) if connection_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(connection_view)
+            .into_any_element(),
+        (MonitorPanelView::Cron, _) if cron_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(cron_view)
+            .into_any_element(),
+        (MonitorPanelView::Packages, _) if package_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(package_view)
+            .into_any_element(),
+        (MonitorPanelView::Firewall, _) if firewall_view.is_some() => div()
+            .flex_1()
+            .min_h(px(0.))
+            .children(firewall_view)
+            .into_any_element(),
+        (MonitorPanelView::Monitor, Some(dialog_state)) => {
+            render_monitor_view(state, dialog_state, session_state, tab_id, cx).into_any_element()
+        }
+        // 视图尚未创建时的降级渲染
+        _ => div()
+            .flex_1()
             .flex()
             .items_center()
             .justify_center()
-            .child(div().text_sm().text_color(muted_color).child("Loading..."))
-    }
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(muted_color)
+                    .child(crate::i18n::t(&lang, "common.loading")),
+            )
+            .into_any_element(),
+    };
+
+    div()
+        .id("monitor-panel")
+        .size_full()
+        .min_h(px(0.))
+        .bg(bg_color)
+        .flex()
+        .flex_col()
+        // 快捷键聚焦 Monitor 面板
+        .when_some(focus_handle, |this, handle| this.track_focus(&handle))
+        .child(tabs)
+        .child(content)
+}
+
+/// 渲染 Monitor 面板的页面切换按钮
+fn render_panel_view_tab(
+    id: &'static str,
+    label: &'static str,
+    target: MonitorPanelView,
+    current: MonitorPanelView,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let selected = target == current;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id(id)
+        .px_2()
+        .py(px(2.))
+        .rounded(px(4.))
+        .cursor_pointer()
+        .text_xs()
+        .when(selected, |this| {
+            this.bg(cx.theme().muted)
+                .text_color(cx.theme().foreground)
+                .font_medium()
+        })
+        .when(!selected, |this| {
+            this.text_color(muted_foreground)
+                .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+        })
+        .child(label)
+        .on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                state.set_monitor_panel_view(target, cx);
+            });
+        })
 }
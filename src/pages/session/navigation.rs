@@ -0,0 +1,136 @@
+// 键盘导航 action：主窗口与独立窗口的根元素注册同一组处理器，作用于各自窗口中的标签
+
+use gpui::*;
+
+use crate::keybindings::{
+    ActivateLastTab, ActivateTab1, ActivateTab2, ActivateTab3, ActivateTab4, ActivateTab5,
+    ActivateTab6, ActivateTab7, ActivateTab8, FocusMonitor, FocusSftp, FocusTerminal, NextTab,
+    NextTerminal, PrevTab, PrevTerminal, ToggleSidebar,
+};
+use crate::state::{SessionPanel, SessionState};
+
+fn update(state: &Entity<SessionState>, cx: &mut App, f: impl FnOnce(&mut SessionState)) {
+    state.update(cx, |state, cx| {
+        f(state);
+        cx.notify();
+    });
+}
+
+fn focus(
+    state: &Entity<SessionState>,
+    window_id: Option<WindowId>,
+    panel: SessionPanel,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    state.update(cx, |state, cx| {
+        state.focus_panel(window_id, panel, window, cx);
+        cx.notify();
+    });
+}
+
+/// 为窗口的根元素注册键盘导航 action（`window_id` 为 None 表示主窗口）
+pub fn bind_navigation_actions(
+    element: Div,
+    session_state: Entity<SessionState>,
+    window_id: Option<WindowId>,
+) -> Div {
+    element
+        // 第 N 个标签 / 最后一个标签
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab1, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(0)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab2, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(1)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab3, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(2)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab4, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(3)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab5, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(4)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab6, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(5)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab7, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(6)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateTab8, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, Some(7)))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ActivateLastTab, _, cx| {
+                update(&state, cx, |s| s.activate_tab_at(window_id, None))
+            }
+        })
+        // 上一个/下一个标签
+        .on_action({
+            let state = session_state.clone();
+            move |_: &NextTab, _, cx| update(&state, cx, |s| s.activate_adjacent_tab(window_id, 1))
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &PrevTab, _, cx| update(&state, cx, |s| s.activate_adjacent_tab(window_id, -1))
+        })
+        // 上一个/下一个终端实例
+        .on_action({
+            let state = session_state.clone();
+            move |_: &NextTerminal, _, cx| {
+                update(&state, cx, |s| s.activate_adjacent_terminal(window_id, 1))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &PrevTerminal, _, cx| {
+                update(&state, cx, |s| s.activate_adjacent_terminal(window_id, -1))
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &ToggleSidebar, _, cx| update(&state, cx, |s| s.toggle_sidebar())
+        })
+        // 聚焦面板
+        .on_action({
+            let state = session_state.clone();
+            move |_: &FocusTerminal, window, cx| {
+                focus(&state, window_id, SessionPanel::Terminal, window, cx)
+            }
+        })
+        .on_action({
+            let state = session_state.clone();
+            move |_: &FocusSftp, window, cx| {
+                focus(&state, window_id, SessionPanel::Sftp, window, cx)
+            }
+        })
+        .on_action(move |_: &FocusMonitor, window, cx| {
+            focus(&session_state, window_id, SessionPanel::Monitor, window, cx)
+        })
+}
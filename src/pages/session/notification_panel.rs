@@ -0,0 +1,256 @@
+// 通知中心面板 - 监控告警历史与静音控制
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::models::settings::Language;
+use crate::services::monitor::AlertMetric;
+use crate::state::{AlertRecord, SessionState};
+
+/// 渲染通知中心面板
+pub fn render_notification_panel(
+    session_state: Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let state = session_state.read(cx);
+    let records: Vec<AlertRecord> = state.alert_history.iter().cloned().collect();
+    let alerts_muted = state.alerts_muted;
+    // 当前标签的服务器（用于按服务器静音）
+    let active_server = state.active_tab().map(|tab| {
+        (
+            tab.server_id.clone(),
+            tab.server_label.clone(),
+            state.is_server_alerts_muted(&tab.server_id),
+        )
+    });
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let toolbar = div()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1()
+        .px_2()
+        .pt_2()
+        .child(render_toggle(
+            "alerts-mute-all",
+            if alerts_muted {
+                crate::i18n::t(lang, "alerts.unmute_all").to_string()
+            } else {
+                crate::i18n::t(lang, "alerts.mute_all").to_string()
+            },
+            alerts_muted,
+            {
+                let session_state = session_state.clone();
+                move |cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.toggle_alerts_muted();
+                        cx.notify();
+                    })
+                }
+            },
+            cx,
+        ))
+        .children(active_server.map(|(server_id, server_label, muted)| {
+            let key = if muted {
+                "alerts.unmute_server"
+            } else {
+                "alerts.mute_server"
+            };
+            let session_state = session_state.clone();
+            render_toggle(
+                "alerts-mute-server",
+                crate::i18n::t(lang, key).replace("{}", &server_label),
+                muted,
+                move |cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.toggle_server_alerts_muted(&server_id);
+                        cx.notify();
+                    })
+                },
+                cx,
+            )
+        }))
+        .child(div().flex_1())
+        .when(!records.is_empty(), |this| {
+            let session_state = session_state.clone();
+            this.child(render_toggle(
+                "alerts-clear",
+                crate::i18n::t(lang, "alerts.clear").to_string(),
+                false,
+                move |cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.clear_alert_history();
+                        cx.notify();
+                    })
+                },
+                cx,
+            ))
+        });
+
+    let body = if records.is_empty() {
+        div()
+            .flex_1()
+            .flex()
+            .items_center()
+            .justify_center()
+            .text_xs()
+            .text_color(muted_foreground)
+            .child(crate::i18n::t(lang, "alerts.empty"))
+            .into_any_element()
+    } else {
+        div()
+            .id("alert-history-list")
+            .flex_1()
+            .min_h(px(0.))
+            .overflow_y_scroll()
+            .px_2()
+            .py_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .children(
+                records
+                    .iter()
+                    .enumerate()
+                    .map(|(ix, record)| render_alert_record(ix, record, lang, &session_state, cx)),
+            )
+            .into_any_element()
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(toolbar)
+        .child(body)
+}
+
+/// 渲染工具栏上的切换按钮
+fn render_toggle(
+    id: &'static str,
+    label: String,
+    active: bool,
+    on_click: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id(id)
+        .px_2()
+        .py(px(2.))
+        .rounded(px(4.))
+        .cursor_pointer()
+        .text_xs()
+        .when(active, |this| {
+            this.bg(cx.theme().muted)
+                .text_color(cx.theme().foreground)
+                .font_medium()
+        })
+        .when(!active, |this| {
+            this.text_color(muted_foreground)
+                .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+        })
+        .child(label)
+        .on_click(move |_, _, cx| on_click(cx))
+}
+
+/// 渲染一条告警记录，点击后切换到对应标签
+fn render_alert_record(
+    ix: usize,
+    record: &AlertRecord,
+    lang: &Language,
+    session_state: &Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let color = if record.muted {
+        muted_foreground
+    } else {
+        cx.theme().danger
+    };
+    let icon = match record.alert.metric {
+        AlertMetric::Cpu => icons::CPU,
+        AlertMetric::Memory => icons::MEMORY,
+        AlertMetric::Disk | AlertMetric::DriveHealth => icons::HARD_DRIVE,
+        AlertMetric::Temperature => icons::GAUGE,
+    };
+    let tab_id = record.tab_id.clone();
+    let session_state = session_state.clone();
+
+    div()
+        .id(("alert-record", ix))
+        .w_full()
+        .px_2()
+        .py_1()
+        .rounded(px(4.))
+        .flex()
+        .items_start()
+        .gap_2()
+        .cursor_pointer()
+        .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+        .on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                state.activate_tab(&tab_id);
+                state.show_home = false;
+                cx.notify();
+            });
+        })
+        .child(div().pt(px(2.)).child(render_icon(icon, color.into())))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_xs()
+                                .font_medium()
+                                .text_color(cx.theme().foreground)
+                                .child(record.server_label.clone()),
+                        )
+                        .when(record.muted, |this| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(muted_foreground)
+                                    .child(crate::i18n::t(lang, "alerts.muted")),
+                            )
+                        })
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_xs()
+                                .text_color(muted_foreground)
+                                .child(record.time.clone()),
+                        ),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(if record.muted {
+                            muted_foreground
+                        } else {
+                            cx.theme().foreground
+                        })
+                        .child(record.message(lang)),
+                ),
+        )
+}
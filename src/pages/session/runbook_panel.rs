@@ -0,0 +1,546 @@
+// Runbook 面板 - Runbook 列表，运行时显示步骤清单、暂停/中止控制与运行日志
+
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use gpui_component::notification::NotificationType;
+use gpui_component::{ActiveTheme, StyledExt};
+
+use crate::components::common::icon::render_icon;
+use crate::constants::icons;
+use crate::models::settings::Language;
+use crate::models::Runbook;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::state::{RunbookRun, RunbookRunStatus, RunbookStepStatus, SessionState};
+
+/// 运行控制按钮对应的操作（暂停、继续、中止等）
+type RunAction = fn(&mut SessionState, &str, &mut Context<SessionState>);
+
+/// 渲染 Runbook 面板
+pub fn render_runbook_panel(
+    tab_id: &str,
+    session_state: Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let state = session_state.read(cx);
+    let content = match state.runbook_runs.get(tab_id) {
+        Some(run) => render_run(tab_id, run, &session_state, lang, cx).into_any_element(),
+        None => {
+            let runbooks = state
+                .snippets_config
+                .as_ref()
+                .map(|config| config.runbooks.clone())
+                .unwrap_or_default();
+            render_runbook_list(tab_id, runbooks, &session_state, lang, cx).into_any_element()
+        }
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(content)
+}
+
+/// 渲染工具栏按钮
+fn render_action(
+    id: impl Into<ElementId>,
+    label: String,
+    primary: bool,
+    on_click: impl Fn(&mut App) + 'static,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id(id)
+        .px_2()
+        .py(px(2.))
+        .rounded(px(4.))
+        .cursor_pointer()
+        .text_xs()
+        .when(primary, |this| {
+            this.bg(cx.theme().primary)
+                .text_color(cx.theme().primary_foreground)
+                .font_medium()
+        })
+        .when(!primary, |this| {
+            this.text_color(muted_foreground)
+                .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+        })
+        .child(label)
+        .on_click(move |_, _, cx| on_click(cx))
+}
+
+/// 渲染 Runbook 列表（运行、编辑、删除）
+fn render_runbook_list(
+    tab_id: &str,
+    runbooks: Vec<Runbook>,
+    session_state: &Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+
+    let toolbar = div()
+        .flex()
+        .items_center()
+        .px_2()
+        .pt_2()
+        .child(div().flex_1())
+        .child(render_action(
+            "runbook-add",
+            crate::i18n::t(lang, "runbook.add").to_string(),
+            false,
+            {
+                let session_state = session_state.clone();
+                let tab_id = tab_id.to_string();
+                move |cx| {
+                    session_state.update(cx, |state, cx| {
+                        let dialog = state.ensure_runbook_dialog(cx);
+                        dialog.update(cx, |d, _| d.open_new(tab_id.clone()));
+                        cx.notify();
+                    })
+                }
+            },
+            cx,
+        ));
+
+    let body = if runbooks.is_empty() {
+        div()
+            .flex_1()
+            .flex()
+            .items_center()
+            .justify_center()
+            .px_4()
+            .text_xs()
+            .text_color(muted_foreground)
+            .child(crate::i18n::t(lang, "runbook.empty"))
+            .into_any_element()
+    } else {
+        div()
+            .id("runbook-list")
+            .flex_1()
+            .min_h(px(0.))
+            .overflow_y_scroll()
+            .px_2()
+            .py_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .children(
+                runbooks
+                    .into_iter()
+                    .map(|runbook| render_runbook_item(tab_id, runbook, session_state, lang, cx)),
+            )
+            .into_any_element()
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(toolbar)
+        .child(body)
+}
+
+/// 渲染一个 Runbook 条目
+fn render_runbook_item(
+    tab_id: &str,
+    runbook: Runbook,
+    session_state: &Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let subtitle = runbook.description.clone().unwrap_or_else(|| {
+        crate::i18n::t(lang, "runbook.step_count").replace("{}", &runbook.steps.len().to_string())
+    });
+    let id = runbook.id.clone();
+
+    let icon_button = |suffix: &str, icon: &'static str, on_click: Box<dyn Fn(&mut App)>| {
+        div()
+            .id(SharedString::from(format!("runbook-{}-{}", suffix, id)))
+            .size(px(22.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .rounded(px(4.))
+            .cursor_pointer()
+            .hover(|s| s.bg(cx.theme().muted.opacity(0.5)))
+            .on_click(move |_, _, cx| on_click(cx))
+            .child(svg().path(icon).size(px(14.)).text_color(muted_foreground))
+    };
+
+    let run_button = icon_button("run", icons::PLAY, {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.to_string();
+        let runbook = runbook.clone();
+        Box::new(move |cx: &mut App| {
+            let started = session_state.update(cx, |state, cx| {
+                state.start_runbook(&tab_id, runbook.clone(), cx)
+            });
+            if !started {
+                notify_key(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Error,
+                    "runbook.no_terminal",
+                );
+            }
+        })
+    });
+    let edit_button = icon_button("edit", icons::EDIT, {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.to_string();
+        let runbook = runbook.clone();
+        Box::new(move |cx: &mut App| {
+            session_state.update(cx, |state, cx| {
+                let dialog = state.ensure_runbook_dialog(cx);
+                dialog.update(cx, |d, _| d.open_edit(tab_id.clone(), &runbook));
+                cx.notify();
+            })
+        })
+    });
+    let delete_button = icon_button("delete", icons::TRASH, {
+        let session_state = session_state.clone();
+        let id = runbook.id.clone();
+        Box::new(move |cx: &mut App| {
+            session_state.update(cx, |state, cx| state.delete_runbook(&id, cx));
+        })
+    });
+
+    div()
+        .w_full()
+        .px_2()
+        .py_1()
+        .rounded(px(4.))
+        .flex()
+        .items_center()
+        .gap_2()
+        .hover(|s| s.bg(cx.theme().muted.opacity(0.3)))
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_xs()
+                        .font_medium()
+                        .text_color(cx.theme().foreground)
+                        .child(runbook.name.clone()),
+                )
+                .child(
+                    div()
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .text_xs()
+                        .text_color(muted_foreground)
+                        .child(subtitle),
+                ),
+        )
+        .child(run_button)
+        .child(edit_button)
+        .child(delete_button)
+}
+
+/// 渲染运行中（或已结束）的 Runbook：控制按钮、步骤清单与运行日志
+fn render_run(
+    tab_id: &str,
+    run: &RunbookRun,
+    session_state: &Entity<SessionState>,
+    lang: &Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let foreground = cx.theme().foreground;
+    let status_key = match run.status {
+        RunbookRunStatus::Running => "runbook.status.running",
+        RunbookRunStatus::AwaitingConfirm => "runbook.status.awaiting_confirm",
+        RunbookRunStatus::Paused => "runbook.status.paused",
+        RunbookRunStatus::Finished => "runbook.status.finished",
+        RunbookRunStatus::Failed => "runbook.status.failed",
+        RunbookRunStatus::Aborted => "runbook.status.aborted",
+    };
+    let status_color = match run.status {
+        RunbookRunStatus::Finished => cx.theme().success,
+        RunbookRunStatus::Failed | RunbookRunStatus::Aborted => cx.theme().danger,
+        RunbookRunStatus::AwaitingConfirm | RunbookRunStatus::Paused => cx.theme().warning,
+        RunbookRunStatus::Running => cx.theme().primary,
+    };
+    let done = run
+        .step_status
+        .iter()
+        .filter(|s| **s == RunbookStepStatus::Done)
+        .count();
+
+    // 控制按钮：运行中可暂停/继续与中止，结束后可关闭或重新运行
+    let action = |id: &'static str, key: &'static str, primary: bool, f: RunAction| {
+        let session_state = session_state.clone();
+        let tab_id = tab_id.to_string();
+        render_action(
+            id,
+            crate::i18n::t(lang, key).to_string(),
+            primary,
+            move |cx| session_state.update(cx, |state, cx| f(state, &tab_id, cx)),
+            cx,
+        )
+    };
+    let controls = div()
+        .flex()
+        .flex_wrap()
+        .items_center()
+        .gap_1()
+        .when(run.status == RunbookRunStatus::Paused, |this| {
+            this.child(action(
+                "runbook-resume",
+                "runbook.resume",
+                false,
+                SessionState::resume_runbook,
+            ))
+        })
+        .when(
+            matches!(
+                run.status,
+                RunbookRunStatus::Running | RunbookRunStatus::AwaitingConfirm
+            ),
+            |this| {
+                this.child(action(
+                    "runbook-pause",
+                    "runbook.pause",
+                    false,
+                    SessionState::pause_runbook,
+                ))
+            },
+        )
+        .when(run.status.is_active(), |this| {
+            this.child(action(
+                "runbook-abort",
+                "runbook.abort",
+                false,
+                SessionState::abort_runbook,
+            ))
+        })
+        .when(!run.status.is_active(), |this| {
+            let session_state = session_state.clone();
+            let tab_id = tab_id.to_string();
+            let runbook = run.runbook.clone();
+            this.child(render_action(
+                "runbook-rerun",
+                crate::i18n::t(lang, "runbook.run_again").to_string(),
+                false,
+                move |cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.dismiss_runbook_run(&tab_id, cx);
+                        state.start_runbook(&tab_id, runbook.clone(), cx);
+                    })
+                },
+                cx,
+            ))
+            .child(action(
+                "runbook-dismiss",
+                "common.close",
+                false,
+                SessionState::dismiss_runbook_run,
+            ))
+        });
+
+    // 等待手动确认
+    let confirm_prompt = match (run.status, run.current_step()) {
+        (
+            RunbookRunStatus::AwaitingConfirm,
+            Some(crate::models::RunbookStep::Confirm { message }),
+        ) => Some(
+            div()
+                .mx_2()
+                .p_2()
+                .rounded(px(4.))
+                .border_1()
+                .border_color(cx.theme().warning)
+                .flex()
+                .flex_col()
+                .gap_2()
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(foreground)
+                        .child(message.clone()),
+                )
+                .child(div().flex().justify_end().child(action(
+                    "runbook-confirm",
+                    "runbook.continue",
+                    true,
+                    SessionState::confirm_runbook_step,
+                ))),
+        ),
+        _ => None,
+    };
+
+    let steps = run
+        .runbook
+        .steps
+        .iter()
+        .zip(run.step_status.iter())
+        .enumerate()
+        .map(|(ix, (step, status))| {
+            let (icon, color) = match status {
+                RunbookStepStatus::Pending => (icons::CIRCLE, muted_foreground),
+                RunbookStepStatus::Running => (icons::LOADER, cx.theme().primary),
+                RunbookStepStatus::Done => (icons::CHECK, cx.theme().success),
+                RunbookStepStatus::Failed => (icons::X, cx.theme().danger),
+                RunbookStepStatus::Skipped => (icons::CIRCLE, muted_foreground.opacity(0.4)),
+            };
+            let is_current = ix == run.current && run.status.is_active();
+            div()
+                .w_full()
+                .px_2()
+                .py_1()
+                .rounded(px(4.))
+                .flex()
+                .items_start()
+                .gap_2()
+                .when(is_current, |s| s.bg(cx.theme().muted.opacity(0.5)))
+                .child(div().pt(px(2.)).child(render_icon(icon, color)))
+                .child(
+                    div()
+                        .flex_1()
+                        .min_w_0()
+                        .flex()
+                        .flex_col()
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(muted_foreground)
+                                .child(crate::i18n::t(lang, step.kind_key())),
+                        )
+                        .child(
+                            div()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_xs()
+                                .font_family("monospace")
+                                .text_color(foreground)
+                                .child(step.summary()),
+                        ),
+                )
+        });
+
+    // 运行日志（最新的在前）
+    let log = run.log.iter().rev().map(|entry| {
+        div()
+            .flex()
+            .gap_2()
+            .text_xs()
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .text_color(muted_foreground)
+                    .child(entry.time.clone()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .text_color(if entry.is_error {
+                        cx.theme().danger
+                    } else {
+                        foreground
+                    })
+                    .child(entry.message.clone()),
+            )
+    });
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .gap_2()
+        .overflow_hidden()
+        // 名称、状态与进度
+        .child(
+            div()
+                .px_2()
+                .pt_2()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .overflow_hidden()
+                                .whitespace_nowrap()
+                                .text_ellipsis()
+                                .text_sm()
+                                .font_medium()
+                                .text_color(foreground)
+                                .child(run.runbook.name.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_xs()
+                                .text_color(status_color)
+                                .child(crate::i18n::t(lang, status_key)),
+                        ),
+                )
+                .child(div().text_xs().text_color(muted_foreground).child(format!(
+                    "{}/{}",
+                    done,
+                    run.runbook.steps.len()
+                )))
+                .child(controls),
+        )
+        .children(confirm_prompt)
+        // 步骤清单
+        .child(
+            div()
+                .id("runbook-steps")
+                .max_h(px(280.))
+                .overflow_y_scroll()
+                .px_2()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(steps),
+        )
+        // 运行日志
+        .child(
+            div()
+                .px_4()
+                .pt_2()
+                .border_t_1()
+                .border_color(cx.theme().border)
+                .text_xs()
+                .font_medium()
+                .text_color(muted_foreground)
+                .child(crate::i18n::t(lang, "runbook.log")),
+        )
+        .child(
+            div()
+                .id("runbook-log")
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .px_4()
+                .pb_2()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .children(log),
+        )
+}
@@ -2,20 +2,53 @@
 
 use gpui::prelude::FluentBuilder;
 use gpui::*;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::menu::{DropdownMenu, PopupMenuItem};
 use gpui_component::resizable::{h_resizable, resizable_panel, v_resizable};
-use gpui_component::ActiveTheme;
+use gpui_component::{ActiveTheme, Sizable};
 
 use super::monitor_panel::render_monitor_panel;
 use super::session_sidebar::render_session_sidebar;
 use super::sftp_panel::render_sftp_panel;
 use super::terminal_page::render_terminal_panel;
+use crate::components::common::macro_dialog::render_macro_dialog_overlay;
+use crate::components::common::runbook_dialog::render_runbook_dialog_overlay;
+use crate::components::common::snippet_variables_dialog::render_snippet_variables_dialog_overlay;
+use crate::components::common::ssh_snippet_dialog::render_ssh_snippet_dialog_overlay;
 use crate::components::monitor::render_detail_dialog;
 use crate::components::sftp::{
-    render_new_file_dialog_overlay, render_new_folder_dialog_overlay,
-    render_properties_dialog_overlay,
+    render_compare_dialog_overlay, render_conflict_dialog_overlay,
+    render_copy_to_server_dialog_overlay, render_empty_trash_dialog_overlay,
+    render_hex_viewer_dialog_overlay, render_new_file_dialog_overlay,
+    render_new_folder_dialog_overlay, render_new_link_dialog_overlay,
+    render_overwrite_dialog_overlay, render_properties_dialog_overlay,
+    render_sudo_password_dialog_overlay, render_sync_dialog_overlay,
 };
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::models::window_state::PanelLayout;
+use crate::services::storage;
 use crate::state::{SessionState, SessionTab, SidebarPanel};
 
+/// 记录服务器的面板布局（拖动分隔条、调整侧边栏位置等），下次打开该服务器的会话时使用
+fn save_panel_layout(server_id: &str, f: impl Fn(&mut PanelLayout)) {
+    if let Err(e) = storage::update_server_layout(server_id, f) {
+        tracing::warn!("[Window] Failed to save panel layout: {:#}", e);
+    }
+}
+
+/// 恢复服务器的默认面板布局
+fn reset_panel_layout(server_id: &str) {
+    if let Err(e) = storage::reset_server_layout(server_id) {
+        tracing::warn!("[Window] Failed to reset panel layout: {:#}", e);
+    }
+}
+
+/// 可拖动面板组的 ID：按标签区分，切换标签时使用各自服务器记录的尺寸
+fn resizable_id(name: &str, tab_id: &str) -> SharedString {
+    SharedString::from(format!("{}-{}", name, tab_id))
+}
+
 /// 渲染 Session 主布局
 pub fn render_session_layout(
     tab: &SessionTab,
@@ -27,41 +60,114 @@ pub fn render_session_layout(
     // 获取命令输入状态和终端焦点句柄
     let command_input = session_state.read(cx).command_input.clone();
     let terminal_focus_handle = session_state.read(cx).get_terminal_focus_handle();
+    let terminal_focused = terminal_focus_handle
+        .as_ref()
+        .is_some_and(|handle| handle.is_focused(window));
 
     // 获取 Monitor 详情弹窗状态
     let monitor_detail_dialog = session_state.read(cx).monitor_detail_dialog.clone();
     let monitor_detail_dialog_for_panel = monitor_detail_dialog.clone();
+    // 获取进程管理视图（显示进程页时才会创建）
+    let process_view = session_state.read(cx).get_process_view(&tab.id);
+    // 获取 Docker 管理视图（显示 Docker 页时才会创建）
+    let docker_view = session_state.read(cx).get_docker_view(&tab.id);
+    // 获取服务管理视图（显示服务页时才会创建）
+    let service_view = session_state.read(cx).get_service_view(&tab.id);
+    // 获取网络连接视图（显示网络页时才会创建）
+    let connection_view = session_state.read(cx).get_connection_view(&tab.id);
+    // 获取计划任务视图（显示计划任务页时才会创建）
+    let cron_view = session_state.read(cx).get_cron_view(&tab.id);
+    // 获取软件包管理视图（显示软件包页时才会创建）
+    let package_view = session_state.read(cx).get_package_view(&tab.id);
+    // 获取防火墙视图（显示防火墙页时才会创建）
+    let firewall_view = session_state.read(cx).get_firewall_view(&tab.id);
 
     // 获取 SFTP 新建文件夹对话框状态
     let new_folder_dialog = session_state.read(cx).get_sftp_new_folder_dialog();
     // 获取 SFTP 新建文件对话框状态
     let new_file_dialog = session_state.read(cx).get_sftp_new_file_dialog();
+    // 获取 SFTP 新建链接对话框状态
+    let new_link_dialog = session_state.read(cx).get_sftp_new_link_dialog();
     // 获取 SFTP 属性对话框状态
     let properties_dialog = session_state.read(cx).get_sftp_properties_dialog();
+    // 获取 SFTP 覆盖确认对话框状态
+    let overwrite_dialog = session_state.read(cx).get_sftp_overwrite_dialog();
+    // 获取 SFTP 清空回收站确认对话框状态
+    let empty_trash_dialog = session_state.read(cx).get_sftp_empty_trash_dialog();
+    // 获取 SFTP 目录同步对话框状态
+    let sync_dialog = session_state.read(cx).get_sftp_sync_dialog();
+    // 获取 SFTP 复制到其他服务器对话框状态
+    let copy_to_server_dialog = session_state.read(cx).get_sftp_copy_to_server_dialog();
+    // 获取 SFTP 传输冲突对话框状态
+    let conflict_dialog = session_state.read(cx).get_sftp_conflict_dialog();
+    // 获取 SFTP 十六进制查看器对话框状态
+    let hex_viewer_dialog = session_state.read(cx).get_sftp_hex_viewer_dialog();
+    // 获取 SFTP 文件/目录对比对话框状态
+    let compare_dialog = session_state.read(cx).get_sftp_compare_dialog();
+    // 获取 SFTP sudo 密码对话框状态
+    let sudo_dialog = session_state.read(cx).get_sftp_sudo_dialog();
+    // 获取快捷命令变量表单状态
+    let snippet_variables_dialog = session_state.read(cx).get_snippet_variables_dialog();
+    // 获取 Runbook 编辑弹窗状态
+    let runbook_dialog = session_state.read(cx).get_runbook_dialog();
+    // 获取键盘宏编辑弹窗状态
+    let macro_dialog = session_state.read(cx).get_macro_dialog();
+    // 获取 SSH 配置片段弹窗状态
+    let ssh_snippet_dialog = session_state.read(cx).get_ssh_snippet_dialog();
 
     // 获取 tab_id 用于网络接口选择
     let tab_id = tab.id.clone();
 
-    // 上方区域：Monitor | Terminal （水平分隔）
-    let top_area = h_resizable("session-top-h")
-        .child(
-            resizable_panel()
-                .size(px(229.)) // Monitor 面板初始宽度 230px
-                .child(render_monitor_panel(
-                    &tab.monitor_state,
-                    monitor_detail_dialog_for_panel,
-                    session_state.clone(),
-                    tab_id,
-                    cx,
-                )),
-        )
-        .child(resizable_panel().child(render_terminal_panel(
-            tab,
-            command_input,
-            session_state.clone(),
-            terminal_focus_handle,
-            cx,
-        )));
+    // 该服务器上次调整后的面板布局
+    let server_id = tab.server_id.clone();
+    let layout = storage::load_server_layout(&server_id);
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or(Language::Chinese);
+
+    let terminal_panel = render_terminal_panel(
+        tab,
+        command_input,
+        session_state.clone(),
+        terminal_focus_handle,
+        terminal_focused,
+        cx,
+    );
+
+    // 上方区域：Monitor | Terminal （水平分隔），Monitor 折叠时终端占满
+    let top_area = if layout.monitor_collapsed {
+        div().size_full().child(terminal_panel).into_any_element()
+    } else {
+        let server_id_for_resize = server_id.clone();
+        h_resizable(resizable_id("session-top-h", &tab.id))
+            .on_resize(move |state, _, cx| {
+                if let Some(width) = state.read(cx).sizes().first() {
+                    save_panel_layout(&server_id_for_resize, |layout| {
+                        layout.monitor_panel_width = f32::from(*width)
+                    });
+                }
+            })
+            .child(
+                resizable_panel()
+                    .size(px(layout.monitor_panel_width))
+                    .child(render_monitor_panel(
+                        &tab.monitor_state,
+                        monitor_detail_dialog_for_panel,
+                        process_view,
+                        docker_view,
+                        service_view,
+                        connection_view,
+                        cron_view,
+                        package_view,
+                        firewall_view,
+                        session_state.clone(),
+                        tab_id,
+                        cx,
+                    )),
+            )
+            .child(resizable_panel().child(terminal_panel))
+            .into_any_element()
+    };
 
     // 左侧区域：上方区域 | SFTP （垂直分隔）
     let session_state_for_sftp = session_state.clone();
@@ -70,17 +176,35 @@ pub fn render_session_layout(
     let sftp_file_list_view = session_state.read(cx).get_sftp_file_list_view(&tab.id);
     // 获取 SFTP 路径栏状态（如果存在）
     let sftp_path_bar_state = session_state.read(cx).get_sftp_path_bar_state(&tab.id);
-    let left_area = v_resizable("session-left-v")
+    // 获取 SFTP 搜索状态（如果存在）
+    let sftp_search_state = session_state.read(cx).get_sftp_search_state(&tab.id);
+    // 获取 SFTP 本地面板（双面板模式）
+    let sftp_local_panel = session_state.read(cx).get_sftp_local_panel(&tab.id);
+    let server_id_for_sftp = server_id.clone();
+    let left_area = v_resizable(resizable_id("session-left-v", &tab.id))
+        .on_resize(move |state, _, cx| {
+            if let Some(height) = state.read(cx).sizes().get(1) {
+                save_panel_layout(&server_id_for_sftp, |layout| {
+                    layout.sftp_panel_height = f32::from(*height)
+                });
+            }
+        })
         .child(resizable_panel().child(top_area))
-        .child(resizable_panel().size(px(300.)).child(render_sftp_panel(
-            tab.sftp_state.as_ref(),
-            sftp_file_list_view,
-            sftp_path_bar_state,
-            session_state_for_sftp,
-            tab_id_for_sftp,
-            window,
-            cx,
-        ))); // SFTP ~40%
+        .child(
+            resizable_panel()
+                .size(px(layout.sftp_panel_height))
+                .child(render_sftp_panel(
+                    tab.sftp_state.as_ref(),
+                    sftp_file_list_view,
+                    sftp_path_bar_state,
+                    sftp_search_state,
+                    sftp_local_panel,
+                    session_state_for_sftp,
+                    tab_id_for_sftp,
+                    window,
+                    cx,
+                )),
+        ); // SFTP ~40%
 
     // 获取主题颜色
     let border_color = cx.theme().border;
@@ -175,52 +299,306 @@ pub fn render_session_layout(
                 }),
         );
 
-    // 小侧栏组件 - 始终存在，包含两个图标按钮
+    // 创建通知中心图标按钮（有未读告警时显示红点）
+    let is_notifications_active = active_panel == SidebarPanel::Notifications;
+    let has_unread_alerts = session_state
+        .read(cx)
+        .tabs
+        .iter()
+        .any(|tab| tab.unread_alerts > 0);
+    let notifications_session_state = session_state.clone();
+    let notifications_button = div()
+        .id("mini-sidebar-notifications")
+        .relative()
+        .size(px(24.))
+        .flex()
+        .items_center()
+        .justify_center()
+        .cursor_pointer()
+        .rounded(px(4.))
+        .when(is_notifications_active, |s| s.bg(hover_bg))
+        .hover(|s| s.bg(hover_bg))
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            notifications_session_state.update(cx, |state, _| {
+                // 如果已经是当前面板，则切换侧边栏折叠状态
+                if state.active_sidebar_panel == SidebarPanel::Notifications {
+                    state.toggle_sidebar();
+                } else {
+                    // 切换到该面板并确保侧边栏展开
+                    state.set_sidebar_panel(SidebarPanel::Notifications);
+                }
+            });
+        })
+        .child(
+            svg()
+                .path(icons::BELL)
+                .size(px(16.))
+                .text_color(if is_notifications_active {
+                    active_icon_color
+                } else {
+                    icon_color
+                }),
+        )
+        .when(has_unread_alerts, |s| {
+            s.child(
+                div()
+                    .absolute()
+                    .top(px(3.))
+                    .right(px(3.))
+                    .size(px(6.))
+                    .rounded_full()
+                    .bg(cx.theme().danger),
+            )
+        });
+
+    // 创建端口转发图标按钮
+    let is_forwards_active = active_panel == SidebarPanel::Forwards;
+    let forwards_session_state = session_state.clone();
+    let forwards_button = div()
+        .id("mini-sidebar-forwards")
+        .size(px(24.))
+        .flex()
+        .items_center()
+        .justify_center()
+        .cursor_pointer()
+        .rounded(px(4.))
+        .when(is_forwards_active, |s| s.bg(hover_bg))
+        .hover(|s| s.bg(hover_bg))
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            forwards_session_state.update(cx, |state, _| {
+                // 如果已经是当前面板，则切换侧边栏折叠状态
+                if state.active_sidebar_panel == SidebarPanel::Forwards {
+                    state.toggle_sidebar();
+                } else {
+                    // 切换到该面板并确保侧边栏展开
+                    state.set_sidebar_panel(SidebarPanel::Forwards);
+                    if state.sidebar_collapsed {
+                        state.sidebar_collapsed = false;
+                    }
+                }
+            });
+        })
+        .child(
+            svg()
+                .path(icons::GLOBE)
+                .size(px(16.))
+                .text_color(if is_forwards_active {
+                    active_icon_color
+                } else {
+                    icon_color
+                }),
+        );
+
+    // 创建 Runbook 图标按钮
+    let is_runbooks_active = active_panel == SidebarPanel::Runbooks;
+    let runbooks_session_state = session_state.clone();
+    let runbooks_button =
+        div()
+            .id("mini-sidebar-runbooks")
+            .size(px(24.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .cursor_pointer()
+            .rounded(px(4.))
+            .when(is_runbooks_active, |s| s.bg(hover_bg))
+            .hover(|s| s.bg(hover_bg))
+            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                runbooks_session_state.update(cx, |state, _| {
+                    // 如果已经是当前面板，则切换侧边栏折叠状态
+                    if state.active_sidebar_panel == SidebarPanel::Runbooks {
+                        state.toggle_sidebar();
+                    } else {
+                        // 切换到该面板并确保侧边栏展开（Runbook 与快捷命令保存在同一配置中）
+                        state.load_snippets_config();
+                        state.set_sidebar_panel(SidebarPanel::Runbooks);
+                        if state.sidebar_collapsed {
+                            state.sidebar_collapsed = false;
+                        }
+                    }
+                });
+            })
+            .child(svg().path(icons::LIST_CHECKS).size(px(16.)).text_color(
+                if is_runbooks_active {
+                    active_icon_color
+                } else {
+                    icon_color
+                },
+            ));
+
+    // 创建键盘宏图标按钮
+    let is_macros_active = active_panel == SidebarPanel::Macros;
+    let macros_session_state = session_state.clone();
+    let macros_button = div()
+        .id("mini-sidebar-macros")
+        .size(px(24.))
+        .flex()
+        .items_center()
+        .justify_center()
+        .cursor_pointer()
+        .rounded(px(4.))
+        .when(is_macros_active, |s| s.bg(hover_bg))
+        .hover(|s| s.bg(hover_bg))
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            macros_session_state.update(cx, |state, _| {
+                // 如果已经是当前面板，则切换侧边栏折叠状态
+                if state.active_sidebar_panel == SidebarPanel::Macros {
+                    state.toggle_sidebar();
+                } else {
+                    // 切换到该面板并确保侧边栏展开（键盘宏与快捷命令保存在同一配置中）
+                    state.load_snippets_config();
+                    state.set_sidebar_panel(SidebarPanel::Macros);
+                    if state.sidebar_collapsed {
+                        state.sidebar_collapsed = false;
+                    }
+                }
+            });
+        })
+        .child(
+            svg()
+                .path(icons::KEYBOARD)
+                .size(px(16.))
+                .text_color(if is_macros_active {
+                    active_icon_color
+                } else {
+                    icon_color
+                }),
+        );
+
+    // 创建布局菜单按钮：调整侧边栏位置、折叠 Monitor、恢复默认布局
+    let sidebar_left = layout.sidebar_left;
+    let monitor_collapsed = layout.monitor_collapsed;
+    let layout_session_state = session_state.clone();
+    let layout_server_id = server_id.clone();
+    let layout_menu_anchor = if sidebar_left {
+        Corner::BottomLeft
+    } else {
+        Corner::BottomRight
+    };
+    let layout_button = Button::new("mini-sidebar-layout")
+        .xsmall()
+        .ghost()
+        .child(
+            svg()
+                .path(icons::COLUMNS)
+                .size(px(16.))
+                .text_color(icon_color),
+        )
+        .dropdown_menu_with_anchor(layout_menu_anchor, move |menu, _, _| {
+            let state_for_side = layout_session_state.clone();
+            let state_for_monitor = layout_session_state.clone();
+            let state_for_reset = layout_session_state.clone();
+            let server_id_for_side = layout_server_id.clone();
+            let server_id_for_monitor = layout_server_id.clone();
+            let server_id_for_reset = layout_server_id.clone();
+            let side_key = if sidebar_left {
+                "session_layout.sidebar_right"
+            } else {
+                "session_layout.sidebar_left"
+            };
+            let monitor_key = if monitor_collapsed {
+                "session_layout.expand_monitor"
+            } else {
+                "session_layout.collapse_monitor"
+            };
+            menu.item(
+                PopupMenuItem::new(i18n::t(&lang, side_key)).on_click(move |_, _, cx| {
+                    save_panel_layout(&server_id_for_side, |layout| {
+                        layout.sidebar_left = !sidebar_left
+                    });
+                    state_for_side.update(cx, |_, cx| cx.notify());
+                }),
+            )
+            .item(
+                PopupMenuItem::new(i18n::t(&lang, monitor_key)).on_click(move |_, _, cx| {
+                    save_panel_layout(&server_id_for_monitor, |layout| {
+                        layout.monitor_collapsed = !monitor_collapsed
+                    });
+                    state_for_monitor.update(cx, |_, cx| cx.notify());
+                }),
+            )
+            .separator()
+            .item(
+                PopupMenuItem::new(i18n::t(&lang, "session_layout.reset")).on_click(
+                    move |_, _, cx| {
+                        reset_panel_layout(&server_id_for_reset);
+                        state_for_reset.update(cx, |_, cx| cx.notify());
+                    },
+                ),
+            )
+        });
+
+    // 小侧栏组件 - 始终存在，包含各面板的图标按钮，布局菜单固定在底部
     let mini_sidebar = div()
         .w(px(sidebar_width))
         .flex_shrink_0()
-        .border_l_1()
+        .when(sidebar_left, |s| s.border_r_1())
+        .when(!sidebar_left, |s| s.border_l_1())
         .border_color(border_color)
         .bg(sidebar_bg)
         .flex()
         .flex_col()
         .items_center()
         .pt_3()
+        .pb_2()
         .gap_2()
         .child(snippets_button)
-        .child(transfer_button);
+        .child(transfer_button)
+        .child(forwards_button)
+        .child(runbooks_button)
+        .child(macros_button)
+        .child(notifications_button)
+        .child(div().flex_1())
+        .child(layout_button);
 
-    // 主布局：使用简单的 flex 容器
+    // 主布局：使用简单的 flex 容器，侧边栏与小侧栏可位于左侧或右侧
     // 包装在 relative 容器中以支持 dialog overlay
-    let main_content = if sidebar_collapsed {
-        // 折叠时：直接使用 h_resizable 填满左侧 + 小侧栏在右侧
-        div()
-            .size_full()
-            .flex()
-            .flex_row()
-            .child(h_resizable("session-main-collapsed").child(resizable_panel().child(left_area)))
-            .child(mini_sidebar)
+    let side = if sidebar_left { "left" } else { "right" };
+    let body = if sidebar_collapsed {
+        // 折叠时：直接使用 h_resizable 填满剩余区域
+        h_resizable(resizable_id(
+            &format!("session-main-collapsed-{}", side),
+            &tab.id,
+        ))
+        .child(resizable_panel().child(left_area))
     } else {
-        // 展开时：h_resizable 包含 left_area + session_sidebar，小侧栏在最右侧
-        div()
-            .size_full()
-            .flex()
-            .flex_row()
-            .child(
-                h_resizable("session-main-expanded")
-                    .child(resizable_panel().child(left_area))
-                    .child(
-                        resizable_panel()
-                            .size(px(230.))
-                            .child(render_session_sidebar(
-                                tab,
-                                active_panel,
-                                session_state.clone(),
-                                cx,
-                            )),
-                    ),
-            )
-            .child(mini_sidebar)
+        // 展开时：h_resizable 包含 left_area + session_sidebar，侧边栏紧挨小侧栏
+        let sidebar_index = if sidebar_left { 0 } else { 1 };
+        let server_id_for_sidebar = server_id.clone();
+        let session_sidebar = resizable_panel()
+            .size(px(layout.session_sidebar_width))
+            .child(render_session_sidebar(
+                tab,
+                active_panel,
+                session_state.clone(),
+                cx,
+            ));
+        let group = h_resizable(resizable_id(
+            &format!("session-main-expanded-{}", side),
+            &tab.id,
+        ))
+        .on_resize(move |state, _, cx| {
+            if let Some(width) = state.read(cx).sizes().get(sidebar_index) {
+                save_panel_layout(&server_id_for_sidebar, |layout| {
+                    layout.session_sidebar_width = f32::from(*width)
+                });
+            }
+        });
+        if sidebar_left {
+            group
+                .child(session_sidebar)
+                .child(resizable_panel().child(left_area))
+        } else {
+            group
+                .child(resizable_panel().child(left_area))
+                .child(session_sidebar)
+        }
+    };
+    let main_content = div().size_full().flex().flex_row();
+    let main_content = if sidebar_left {
+        main_content.child(mini_sidebar).child(body)
+    } else {
+        main_content.child(body).child(mini_sidebar)
     };
 
     // 返回带有 dialog overlay 的容器
@@ -265,11 +643,241 @@ pub fn render_session_layout(
         }
     }
 
+    // 添加 SFTP 新建链接弹窗
+    if let Some(dialog_state) = new_link_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_browse = session_state.clone();
+            let session_state_for_create = session_state.clone();
+            result = result.child(render_new_link_dialog_overlay(
+                dialog_state,
+                move |dialog, dir, cx| {
+                    session_state_for_browse.update(cx, |state, cx| {
+                        state.sftp_link_browse(dialog, dir, cx);
+                    });
+                },
+                move |dialog, cx| {
+                    session_state_for_create.update(cx, |state, cx| {
+                        state.sftp_create_link(dialog, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
     // 添加 SFTP 属性弹窗
     if let Some(dialog_state) = properties_dialog {
         let is_open = dialog_state.read(cx).is_open;
         if is_open {
-            result = result.child(render_properties_dialog_overlay(dialog_state, cx));
+            let session_state_for_apply = session_state.clone();
+            let session_state_for_checksum = session_state.clone();
+            result = result.child(render_properties_dialog_overlay(
+                dialog_state,
+                move |dialog, cx| {
+                    session_state_for_apply.update(cx, |state, cx| {
+                        state.sftp_apply_properties(dialog, cx);
+                    });
+                },
+                move |dialog, algorithm, cx| {
+                    session_state_for_checksum.update(cx, |state, cx| {
+                        state.sftp_compute_checksum(dialog, algorithm, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 覆盖确认弹窗
+    if let Some(dialog_state) = overwrite_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_overwrite = session_state.clone();
+            result = result.child(render_overwrite_dialog_overlay(
+                dialog_state,
+                move |tab_id, source, target, cx| {
+                    session_state_for_overwrite.update(cx, |state, cx| {
+                        state.sftp_move(&tab_id, source, target, true, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 清空回收站确认弹窗
+    if let Some(dialog_state) = empty_trash_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_empty_trash = session_state.clone();
+            result = result.child(render_empty_trash_dialog_overlay(
+                dialog_state,
+                move |tab_id, cx| {
+                    session_state_for_empty_trash.update(cx, |state, cx| {
+                        state.sftp_empty_trash(&tab_id, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 目录同步弹窗
+    if let Some(dialog_state) = sync_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_compare = session_state.clone();
+            let session_state_for_execute = session_state.clone();
+            result = result.child(render_sync_dialog_overlay(
+                dialog_state,
+                move |dialog, cx| {
+                    session_state_for_compare.update(cx, |state, cx| {
+                        state.sftp_sync_compare(dialog, cx);
+                    });
+                },
+                move |dialog, cx| {
+                    session_state_for_execute.update(cx, |state, cx| {
+                        state.sftp_execute_sync(dialog, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 复制到其他服务器弹窗
+    if let Some(dialog_state) = copy_to_server_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_copy = session_state.clone();
+            result = result.child(render_copy_to_server_dialog_overlay(
+                dialog_state,
+                move |dialog, cx| {
+                    session_state_for_copy.update(cx, |state, cx| {
+                        state.sftp_copy_to_server(dialog, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 十六进制查看器弹窗
+    if let Some(dialog_state) = hex_viewer_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_load = session_state.clone();
+            let session_state_for_search = session_state.clone();
+            result = result.child(render_hex_viewer_dialog_overlay(
+                dialog_state,
+                move |dialog, offset, cx| {
+                    session_state_for_load.update(cx, |state, cx| {
+                        state.sftp_hex_load_page(dialog, offset, cx);
+                    });
+                },
+                move |dialog, cx| {
+                    session_state_for_search.update(cx, |state, cx| {
+                        state.sftp_hex_search(dialog, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP 文件/目录对比弹窗
+    if let Some(dialog_state) = compare_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_child = session_state.clone();
+            result = result.child(render_compare_dialog_overlay(
+                dialog_state,
+                move |dialog, relative_path, cx| {
+                    session_state_for_child.update(cx, |state, cx| {
+                        state.sftp_compare_open_child(dialog, relative_path, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加 SFTP sudo 密码弹窗
+    if let Some(dialog_state) = sudo_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            let session_state_for_confirm = session_state.clone();
+            result = result.child(render_sudo_password_dialog_overlay(
+                dialog_state,
+                move |tab_id, remote_path, password, cx| {
+                    session_state_for_confirm.update(cx, |state, cx| {
+                        state.sftp_confirm_sudo_password(tab_id, remote_path, password, cx);
+                    });
+                },
+                cx,
+            ));
+        }
+    }
+
+    // 添加快捷命令变量表单（只在运行命令的标签中显示）
+    if let Some(dialog_state) = snippet_variables_dialog {
+        let is_open = {
+            let dialog = dialog_state.read(cx);
+            dialog.is_open && dialog.tab_id == tab.id
+        };
+        if is_open {
+            dialog_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+            result = result.child(render_snippet_variables_dialog_overlay(dialog_state, cx));
+        }
+    }
+
+    // 添加 Runbook 编辑弹窗（只在打开弹窗的标签中显示）
+    if let Some(dialog_state) = runbook_dialog {
+        let is_open = {
+            let dialog = dialog_state.read(cx);
+            dialog.is_open && dialog.tab_id == tab.id
+        };
+        if is_open {
+            dialog_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+            result = result.child(render_runbook_dialog_overlay(dialog_state, cx));
+        }
+    }
+
+    // 添加键盘宏编辑弹窗（只在打开弹窗的标签中显示）
+    if let Some(dialog_state) = macro_dialog {
+        let is_open = {
+            let dialog = dialog_state.read(cx);
+            dialog.is_open && dialog.tab_id == tab.id
+        };
+        if is_open {
+            dialog_state.update(cx, |state, cx| {
+                state.ensure_inputs_created(window, cx);
+            });
+            result = result.child(render_macro_dialog_overlay(dialog_state, cx));
+        }
+    }
+
+    // 添加 SSH 配置片段弹窗（只在打开弹窗的标签中显示）
+    if let Some(dialog_state) = ssh_snippet_dialog {
+        let is_open = {
+            let dialog = dialog_state.read(cx);
+            dialog.is_open && dialog.tab_id.as_deref() == Some(tab.id.as_str())
+        };
+        if is_open {
+            result = result.child(render_ssh_snippet_dialog_overlay(dialog_state, cx));
+        }
+    }
+
+    // 添加 SFTP 传输冲突弹窗（放在最后，显示在其他弹窗之上）
+    if let Some(dialog_state) = conflict_dialog {
+        let is_open = dialog_state.read(cx).is_open;
+        if is_open {
+            result = result.child(render_conflict_dialog_overlay(dialog_state, cx));
         }
     }
 
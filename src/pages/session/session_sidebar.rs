@@ -2,20 +2,25 @@
 
 use gpui::prelude::FluentBuilder;
 use gpui::*;
-use gpui_component::menu::{ContextMenuExt, PopupMenuItem};
+use gpui_component::button::Button;
+use gpui_component::menu::{ContextMenuExt, DropdownMenu, PopupMenuItem};
 use gpui_component::tooltip::Tooltip;
 use gpui_component::{ActiveTheme, StyledExt};
-use std::sync::Arc;
+use std::rc::Rc;
 use tracing::debug;
 
 use crate::components::common::icon::render_icon;
 use crate::constants::icons;
+use crate::models::sftp::{
+    ArchiveOp, TransferHistoryEntry, TransferHistoryFilter, TransferItem, TransferStatus,
+};
 use crate::models::{SnippetCommand, SnippetGroup, SnippetsConfig};
-use crate::state::{SessionState, SessionTab, SidebarPanel};
+use crate::services::transfer::{self, TransferHistoryStore, TransferQueue};
+use crate::state::{SessionState, SessionTab, SidebarPanel, TransferPanelView};
 
 /// 渲染会话右侧边栏
 pub fn render_session_sidebar(
-    _tab: &SessionTab,
+    tab: &SessionTab,
     active_panel: SidebarPanel,
     session_state: Entity<SessionState>,
     cx: &App,
@@ -32,12 +37,34 @@ pub fn render_session_sidebar(
     let (title, content) = match active_panel {
         SidebarPanel::Snippets => (
             crate::i18n::t(&lang, "mini_sidebar.snippets"),
-            render_snippets_tree(session_state.clone(), cx).into_any_element(),
+            render_snippets_tree(tab, session_state.clone(), cx).into_any_element(),
         ),
         SidebarPanel::Transfer => (
             crate::i18n::t(&lang, "mini_sidebar.transfer"),
             render_transfer_panel(session_state.clone(), &lang, cx).into_any_element(),
         ),
+        SidebarPanel::Notifications => (
+            crate::i18n::t(&lang, "mini_sidebar.notifications"),
+            super::notification_panel::render_notification_panel(session_state.clone(), &lang, cx)
+                .into_any_element(),
+        ),
+        SidebarPanel::Forwards => (
+            crate::i18n::t(&lang, "mini_sidebar.forwards"),
+            match session_state.read(cx).get_port_forward_view(&tab.id) {
+                Some(view) => view.into_any_element(),
+                None => div().into_any_element(),
+            },
+        ),
+        SidebarPanel::Runbooks => (
+            crate::i18n::t(&lang, "mini_sidebar.runbooks"),
+            super::runbook_panel::render_runbook_panel(&tab.id, session_state.clone(), &lang, cx)
+                .into_any_element(),
+        ),
+        SidebarPanel::Macros => (
+            crate::i18n::t(&lang, "mini_sidebar.macros"),
+            super::macro_panel::render_macro_panel(&tab.id, session_state.clone(), &lang, cx)
+                .into_any_element(),
+        ),
     };
 
     div()
@@ -69,15 +96,123 @@ pub fn render_session_sidebar(
         )
 }
 
-/// 渲染传输管理面板
+/// 拖动中的等待传输（用于调整队列顺序）
+#[derive(Clone)]
+struct DraggedTransfer {
+    id: String,
+    name: String,
+}
+
+impl Render for DraggedTransfer {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .bg(cx.theme().popover)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(4.))
+            .shadow_md()
+            .text_xs()
+            .text_color(cx.theme().foreground)
+            .child(self.name.clone())
+    }
+}
+
+/// 渲染传输管理面板（进行中/历史两个页面）
 fn render_transfer_panel(
     session_state: Entity<SessionState>,
     lang: &crate::models::settings::Language,
     cx: &App,
+) -> impl IntoElement {
+    let view = session_state.read(cx).transfer_panel_view;
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        // 页面切换
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .px_2()
+                .pt_2()
+                .child(render_panel_view_tab(
+                    "transfer-view-active",
+                    crate::i18n::t(lang, "transfer.tab.active"),
+                    TransferPanelView::Active,
+                    view,
+                    session_state.clone(),
+                    cx,
+                ))
+                .child(render_panel_view_tab(
+                    "transfer-view-history",
+                    crate::i18n::t(lang, "transfer.tab.history"),
+                    TransferPanelView::History,
+                    view,
+                    session_state.clone(),
+                    cx,
+                )),
+        )
+        .child(match view {
+            TransferPanelView::Active => {
+                render_active_transfers(session_state, lang, cx).into_any_element()
+            }
+            TransferPanelView::History => {
+                render_transfer_history(session_state, lang, cx).into_any_element()
+            }
+        })
+}
+
+/// 渲染传输面板的页面切换按钮
+fn render_panel_view_tab(
+    id: &'static str,
+    label: &'static str,
+    target: TransferPanelView,
+    current: TransferPanelView,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    let selected = target == current;
+    let muted_foreground = cx.theme().muted_foreground;
+
+    div()
+        .id(id)
+        .px_2()
+        .py(px(2.))
+        .rounded(px(4.))
+        .cursor_pointer()
+        .text_xs()
+        .when(selected, |this| {
+            this.bg(cx.theme().muted)
+                .text_color(cx.theme().foreground)
+                .font_medium()
+        })
+        .when(!selected, |this| {
+            this.text_color(muted_foreground)
+                .hover(move |s| s.bg(muted_foreground.opacity(0.1)))
+        })
+        .child(label)
+        .on_click(move |_, _, cx| {
+            session_state.update(cx, |state, cx| {
+                state.set_transfer_panel_view(target, cx);
+            });
+        })
+}
+
+/// 渲染进行中的传输列表
+fn render_active_transfers(
+    session_state: Entity<SessionState>,
+    lang: &crate::models::settings::Language,
+    cx: &App,
 ) -> impl IntoElement {
     let state = session_state.read(cx);
 
     // 获取当前活动 tab 的传输列表
+    let tab_id = state.active_tab_id.clone().unwrap_or_default();
     let transfers: Vec<_> = state
         .active_tab_id
         .as_ref()
@@ -111,110 +246,304 @@ fn render_transfer_panel(
             )
             .into_any_element()
     } else {
-        // 传输列表
+        let has_waiting = transfers
+            .iter()
+            .any(|t| t.status == TransferStatus::Pending);
+        let queue_paused = TransferQueue::global().is_paused();
+        let session_state_for_toggle = session_state.clone();
+        let session_state_for_download_limit = session_state.clone();
+        let session_state_for_upload_limit = session_state.clone();
+
         div()
-            .id("transfer-list-scroll")
             .flex_1()
-            .overflow_y_scroll()
-            .p_2()
             .flex()
             .flex_col()
-            .gap_2()
-            .children(transfers.iter().enumerate().map(|(idx, transfer)| {
-                let progress_percent = transfer.progress.percentage();
-                let status_text = transfer.status.display_text();
-                let status_color = if transfer.status.is_error() {
-                    destructive
-                } else if transfer.status.is_complete() {
-                    success
-                } else {
-                    primary
-                };
-
+            .overflow_hidden()
+            // 工具栏：拖动提示、全局限速和全部暂停/继续
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .px_3()
+                    .pt_2()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_xs()
+                            .text_color(muted_foreground)
+                            .overflow_hidden()
+                            .when(has_waiting, |this| {
+                                this.child(crate::i18n::t(lang, "transfer.drag_hint"))
+                            }),
+                    )
+                    // 全局下载/上传限速
+                    .child(render_speed_limit_button(
+                        "transfer-download-limit",
+                        icons::DOWNLOAD,
+                        true,
+                        transfer::global_limit_kb(false),
+                        lang.clone(),
+                        Rc::new(move |limit_kb: u32, cx: &mut App| {
+                            session_state_for_download_limit.update(cx, |state, cx| {
+                                state.set_global_speed_limit(false, limit_kb, cx);
+                            });
+                        }),
+                        cx,
+                    ))
+                    .child(render_speed_limit_button(
+                        "transfer-upload-limit",
+                        icons::UPLOAD,
+                        true,
+                        transfer::global_limit_kb(true),
+                        lang.clone(),
+                        Rc::new(move |limit_kb: u32, cx: &mut App| {
+                            session_state_for_upload_limit.update(cx, |state, cx| {
+                                state.set_global_speed_limit(true, limit_kb, cx);
+                            });
+                        }),
+                        cx,
+                    ))
+                    .child(
+                        div()
+                            .id("transfer-toggle-all")
+                            .flex()
+                            .items_center()
+                            .gap_1()
+                            .px_2()
+                            .py(px(2.))
+                            .rounded(px(4.))
+                            .cursor_pointer()
+                            .hover(|s| s.bg(muted_foreground.opacity(0.2)))
+                            .child(render_icon(
+                                if queue_paused {
+                                    icons::PLAY
+                                } else {
+                                    icons::PAUSE
+                                },
+                                muted_foreground.into(),
+                            ))
+                            .child(div().text_xs().text_color(foreground).child(crate::i18n::t(
+                                lang,
+                                if queue_paused {
+                                    "transfer.resume_all"
+                                } else {
+                                    "transfer.pause_all"
+                                },
+                            )))
+                            .on_click(move |_, _, cx| {
+                                session_state_for_toggle.update(cx, |state, cx| {
+                                    if queue_paused {
+                                        state.resume_all_transfers(cx);
+                                    } else {
+                                        state.pause_all_transfers(cx);
+                                    }
+                                });
+                            }),
+                    ),
+            )
+            .child(
+                // 传输列表
                 div()
-                    .id(SharedString::from(format!("transfer-{}", idx)))
-                    .p_3()
-                    .bg(cx.theme().muted)
-                    .rounded(px(6.))
+                    .id("transfer-list-scroll")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .p_2()
                     .flex()
                     .flex_col()
                     .gap_2()
-                    // 文件名和状态
-                    .child({
-                        let transfer_id = transfer.id.clone();
-                        let session_state_for_cancel = session_state.clone();
-                        let is_active =
-                            !transfer.status.is_complete() && !transfer.status.is_error();
-                        let cancel_color = cx.theme().muted_foreground;
+                    .children(transfers.iter().enumerate().map(|(idx, transfer)| {
+                        let progress_percent = transfer.progress.percentage();
+                        // 归档操作进行中时显示"压缩中"/"解压中"，通过完整性校验时显示"已校验"
+                        let status_text = match transfer.archive_op {
+                            Some(ArchiveOp::Compress) if transfer.status.is_active() => {
+                                crate::i18n::t(lang, "sftp.archive.compressing")
+                            }
+                            Some(ArchiveOp::Extract) if transfer.status.is_active() => {
+                                crate::i18n::t(lang, "sftp.archive.extracting")
+                            }
+                            _ if transfer.verified => crate::i18n::t(lang, "transfer.verified"),
+                            _ => crate::i18n::t(lang, transfer.status.label_key()),
+                        };
+                        let status_color = if transfer.status.is_error() {
+                            destructive
+                        } else if transfer.status.is_complete() {
+                            success
+                        } else {
+                            primary
+                        };
+                        // 等待中的传输可以拖动调整在队列中的顺序（文件夹中的文件各自排队）
+                        let is_waiting =
+                            transfer.status == TransferStatus::Pending && !transfer.is_folder;
 
                         div()
+                            .id(SharedString::from(format!("transfer-{}", idx)))
+                            .p_3()
+                            .bg(cx.theme().muted)
+                            .rounded(px(6.))
                             .flex()
-                            .items_center()
-                            .justify_between()
-                            .child(
+                            .flex_col()
+                            .gap_2()
+                            .when(is_waiting, |this| {
+                                let dragged = DraggedTransfer {
+                                    id: transfer.id.clone(),
+                                    name: transfer.file_name(),
+                                };
+                                let session_state_for_drop = session_state.clone();
+                                let tab_id = tab_id.clone();
+                                let transfer_id = transfer.id.clone();
+                                this.cursor_grab()
+                                    .on_drag(dragged, |dragged, _, _, cx| {
+                                        cx.new(|_| dragged.clone())
+                                    })
+                                    .drag_over::<DraggedTransfer>(move |this, _, _, _| {
+                                        this.border_t_2().border_color(primary)
+                                    })
+                                    .on_drop(move |dragged: &DraggedTransfer, _, cx| {
+                                        session_state_for_drop.update(cx, |state, cx| {
+                                            state.reorder_transfer(
+                                                &tab_id,
+                                                &dragged.id,
+                                                &transfer_id,
+                                                cx,
+                                            );
+                                        });
+                                    })
+                            })
+                            // 文件名和状态
+                            .child({
+                                let transfer_id = transfer.id.clone();
+                                let session_state_for_cancel = session_state.clone();
+                                let is_active =
+                                    !transfer.status.is_complete() && !transfer.status.is_error();
+                                let cancel_color = cx.theme().muted_foreground;
+
                                 div()
                                     .flex()
                                     .items_center()
-                                    .gap_1()
-                                    // 上传/下载方向图标
-                                    .child(render_icon(
-                                        if transfer.is_upload {
-                                            icons::UPLOAD
-                                        } else {
-                                            icons::DOWNLOAD
-                                        },
-                                        if transfer.is_upload {
-                                            gpui::rgb(0x3b82f6).into() // 蓝色表示上传
-                                        } else {
-                                            gpui::rgb(0x22c55e).into() // 绿色表示下载
-                                        },
-                                    ))
+                                    .justify_between()
                                     .child(
                                         div()
-                                            .text_xs()
-                                            .font_medium()
-                                            .text_color(foreground)
-                                            .overflow_hidden()
-                                            .max_w(px(110.))
-                                            .child(transfer.file_name()),
-                                    ),
-                            )
-                            .child(
-                                div()
-                                    .flex()
-                                    .items_center()
-                                    .gap_2()
-                                    .child(
-                                        div().text_xs().text_color(status_color).child(status_text),
+                                            .flex()
+                                            .items_center()
+                                            .gap_1()
+                                            // 文件夹传输展开/收起各文件明细
+                                            .when(transfer.is_folder, |this| {
+                                                let session_state_for_expand =
+                                                    session_state.clone();
+                                                let transfer_id_expand = transfer_id.clone();
+                                                this.child(
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "expand-{}",
+                                                            transfer_id_expand
+                                                        )))
+                                                        .cursor_pointer()
+                                                        .rounded(px(2.))
+                                                        .hover(|s| s.bg(cancel_color.opacity(0.2)))
+                                                        .child(render_icon(
+                                                            if transfer.expanded {
+                                                                icons::CHEVRON_DOWN
+                                                            } else {
+                                                                icons::CHEVRON_RIGHT
+                                                            },
+                                                            cancel_color,
+                                                        ))
+                                                        .on_click(move |_, _, cx| {
+                                                            session_state_for_expand.update(
+                                                                cx,
+                                                                |state, cx| {
+                                                                    state.toggle_transfer_expanded(
+                                                                        &transfer_id_expand,
+                                                                        cx,
+                                                                    );
+                                                                },
+                                                            );
+                                                        }),
+                                                )
+                                            })
+                                            // 上传/下载/跨服务器复制/归档图标
+                                            .child(render_icon(
+                                                if transfer.archive_op.is_some() {
+                                                    icons::ARCHIVE
+                                                } else if transfer.remote_target.is_some() {
+                                                    icons::TRANSFER
+                                                } else if transfer.is_upload {
+                                                    icons::UPLOAD
+                                                } else {
+                                                    icons::DOWNLOAD
+                                                },
+                                                if transfer.archive_op.is_some() {
+                                                    gpui::rgb(0xf59e0b).into() // 橙色表示归档操作
+                                                } else if transfer.remote_target.is_some() {
+                                                    gpui::rgb(0xa855f7).into() // 紫色表示跨服务器复制
+                                                } else if transfer.is_upload {
+                                                    gpui::rgb(0x3b82f6).into() // 蓝色表示上传
+                                                } else {
+                                                    gpui::rgb(0x22c55e).into() // 绿色表示下载
+                                                },
+                                            ))
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .font_medium()
+                                                    .text_color(foreground)
+                                                    .overflow_hidden()
+                                                    .max_w(px(110.))
+                                                    .child(transfer.file_name()),
+                                            ),
                                     )
-                                    // 暂停/继续按钮（仅在下载中或已暂停时显示）
-                                    .when(is_active, |this| {
-                                        let is_paused = transfer.status
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .child(
+                                                div()
+                                                    .text_xs()
+                                                    .text_color(status_color)
+                                                    .child(status_text),
+                                            )
+                                            // 暂停/继续按钮（仅在下载中或已暂停时显示，归档操作不支持暂停）
+                                            .when(
+                                                is_active && transfer.archive_op.is_none(),
+                                                |this| {
+                                                    let is_paused = transfer.status
                                             == crate::models::sftp::TransferStatus::Paused;
-                                        let session_state_for_pause = session_state.clone();
-                                        let transfer_id_pause = transfer_id.clone();
-                                        let button_color = cx.theme().muted_foreground;
-
-                                        this.child(
-                                            div()
-                                                .id(SharedString::from(format!(
-                                                    "pause-{}",
-                                                    transfer_id_pause
-                                                )))
-                                                .cursor_pointer()
-                                                .rounded(px(2.))
-                                                .p(px(2.))
-                                                .hover(|s| s.bg(button_color.opacity(0.2)))
-                                                .child(if is_paused {
-                                                    // 显示播放图标（继续）
-                                                    render_icon(icons::PLAY, button_color)
-                                                } else {
-                                                    // 显示暂停图标
-                                                    render_icon(icons::PAUSE, button_color)
-                                                })
-                                                .on_click({
-                                                    move |_, _, cx| {
-                                                        session_state_for_pause.update(
+                                                    let session_state_for_pause =
+                                                        session_state.clone();
+                                                    let transfer_id_pause = transfer_id.clone();
+                                                    let button_color = cx.theme().muted_foreground;
+
+                                                    this.child(
+                                                        div()
+                                                            .id(SharedString::from(format!(
+                                                                "pause-{}",
+                                                                transfer_id_pause
+                                                            )))
+                                                            .cursor_pointer()
+                                                            .rounded(px(2.))
+                                                            .p(px(2.))
+                                                            .hover(|s| {
+                                                                s.bg(button_color.opacity(0.2))
+                                                            })
+                                                            .child(if is_paused {
+                                                                // 显示播放图标（继续）
+                                                                render_icon(
+                                                                    icons::PLAY,
+                                                                    button_color,
+                                                                )
+                                                            } else {
+                                                                // 显示暂停图标
+                                                                render_icon(
+                                                                    icons::PAUSE,
+                                                                    button_color,
+                                                                )
+                                                            })
+                                                            .on_click({
+                                                                move |_, _, cx| {
+                                                                    session_state_for_pause.update(
                                                             cx,
                                                             |state, cx| {
                                                                 if is_paused {
@@ -230,103 +559,645 @@ fn render_transfer_panel(
                                                                 }
                                                             },
                                                         );
-                                                    }
-                                                }),
-                                        )
-                                    })
-                                    // 取消按钮（仅在传输中显示）
-                                    .when(is_active, |this| {
-                                        this.child(
-                                            div()
-                                                .id(SharedString::from(format!(
-                                                    "cancel-{}",
-                                                    transfer_id
-                                                )))
-                                                .cursor_pointer()
-                                                .rounded(px(2.))
-                                                .p(px(2.))
-                                                .hover(|s| s.bg(cancel_color.opacity(0.2)))
-                                                .child(render_icon(icons::X, cancel_color))
-                                                .on_click({
-                                                    let transfer_id = transfer_id.clone();
-                                                    move |_, _, cx| {
-                                                        session_state_for_cancel.update(
-                                                            cx,
-                                                            |state, cx| {
-                                                                state.cancel_transfer(
-                                                                    &transfer_id,
+                                                                }
+                                                            }),
+                                                    )
+                                                },
+                                            )
+                                            // 单个传输限速（仅本地与远程之间的普通传输）
+                                            .when(
+                                                is_active
+                                                    && transfer.archive_op.is_none()
+                                                    && transfer.remote_target.is_none(),
+                                                |this| {
+                                                    let session_state_for_limit =
+                                                        session_state.clone();
+                                                    let transfer_id_limit = transfer_id.clone();
+                                                    this.child(render_speed_limit_button(
+                                                        SharedString::from(format!(
+                                                            "limit-{}",
+                                                            transfer_id
+                                                        )),
+                                                        icons::GAUGE,
+                                                        false,
+                                                        (transfer.speed_limit() / 1024) as u32,
+                                                        lang.clone(),
+                                                        Rc::new(
+                                                            move |limit_kb: u32, cx: &mut App| {
+                                                                session_state_for_limit.update(
+                                                                cx,
+                                                                |state, cx| {
+                                                                    state.set_transfer_speed_limit(
+                                                                        &transfer_id_limit,
+                                                                        limit_kb,
+                                                                        cx,
+                                                                    );
+                                                                },
+                                                            );
+                                                            },
+                                                        ),
+                                                        cx,
+                                                    ))
+                                                },
+                                            )
+                                            // 取消按钮（仅在传输中显示）
+                                            .when(is_active, |this| {
+                                                this.child(
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "cancel-{}",
+                                                            transfer_id
+                                                        )))
+                                                        .cursor_pointer()
+                                                        .rounded(px(2.))
+                                                        .p(px(2.))
+                                                        .hover(|s| s.bg(cancel_color.opacity(0.2)))
+                                                        .child(render_icon(icons::X, cancel_color))
+                                                        .on_click({
+                                                            let transfer_id = transfer_id.clone();
+                                                            move |_, _, cx| {
+                                                                session_state_for_cancel.update(
                                                                     cx,
+                                                                    |state, cx| {
+                                                                        state.cancel_transfer(
+                                                                            &transfer_id,
+                                                                            cx,
+                                                                        );
+                                                                    },
                                                                 );
-                                                            },
-                                                        );
-                                                    }
-                                                }),
-                                        )
-                                    }),
-                            )
-                    })
-                    // 进度条
-                    .child(
-                        div()
-                            .h(px(4.))
-                            .w_full()
-                            .bg(cx.theme().muted_foreground.opacity(0.2))
-                            .rounded_full()
+                                                            }
+                                                        }),
+                                                )
+                                            })
+                                            // 续传按钮（失败或取消后保留了部分文件时显示）
+                                            .when(transfer.can_resume(), |this| {
+                                                let session_state_for_retry = session_state.clone();
+                                                let transfer_id_retry = transfer_id.clone();
+
+                                                this.child(
+                                                    div()
+                                                        .id(SharedString::from(format!(
+                                                            "retry-{}",
+                                                            transfer_id_retry
+                                                        )))
+                                                        .cursor_pointer()
+                                                        .rounded(px(2.))
+                                                        .p(px(2.))
+                                                        .hover(|s| s.bg(cancel_color.opacity(0.2)))
+                                                        .child(render_icon(
+                                                            icons::REFRESH,
+                                                            cancel_color,
+                                                        ))
+                                                        .on_click(move |_, _, cx| {
+                                                            session_state_for_retry.update(
+                                                                cx,
+                                                                |state, cx| {
+                                                                    state.retry_transfer(
+                                                                        &transfer_id_retry,
+                                                                        cx,
+                                                                    );
+                                                                },
+                                                            );
+                                                        }),
+                                                )
+                                            }),
+                                    )
+                            })
+                            // 进度条
                             .child(
                                 div()
-                                    .h_full()
-                                    .w(relative(progress_percent as f32 / 100.0))
-                                    .bg(status_color)
-                                    .rounded_full(),
-                            ),
-                    )
-                    // 进度详情
-                    .child(
-                        div()
-                            .flex()
-                            .items_center()
-                            .justify_between()
+                                    .h(px(4.))
+                                    .w_full()
+                                    .bg(cx.theme().muted_foreground.opacity(0.2))
+                                    .rounded_full()
+                                    .child(
+                                        div()
+                                            .h_full()
+                                            .w(relative(progress_percent as f32 / 100.0))
+                                            .bg(status_color)
+                                            .rounded_full(),
+                                    ),
+                            )
+                            // 进度详情
                             .child(
                                 div()
                                     .flex()
                                     .items_center()
-                                    .gap_2()
-                                    .child(div().text_xs().text_color(muted_foreground).child(
-                                        format!(
-                                            "{} / {}",
-                                            format_bytes(transfer.progress.bytes_transferred),
-                                            format_bytes(transfer.progress.total_bytes)
-                                        ),
-                                    ))
-                                    // 下载速度（仅在下载中显示）
-                                    .when(
-                                        transfer.progress.speed_bytes_per_sec > 0
-                                            && !transfer.status.is_complete()
-                                            && !transfer.status.is_error(),
-                                        |this| {
-                                            this.child(div().text_xs().text_color(primary).child(
-                                                format!(
-                                                    "{}",
-                                                    format_speed(
-                                                        transfer.progress.speed_bytes_per_sec
-                                                    )
+                                    .justify_between()
+                                    .child(
+                                        div()
+                                            .flex()
+                                            .items_center()
+                                            .gap_2()
+                                            .child(
+                                                div().text_xs().text_color(muted_foreground).child(
+                                                    if transfer.archive_op.is_some() {
+                                                        // 归档操作的进度单位为条目数
+                                                        format!(
+                                                            "{} / {}",
+                                                            transfer.progress.bytes_transferred,
+                                                            transfer.progress.total_bytes
+                                                        )
+                                                    } else {
+                                                        format!(
+                                                            "{} / {}",
+                                                            format_bytes(
+                                                                transfer.progress.bytes_transferred
+                                                            ),
+                                                            format_bytes(
+                                                                transfer.progress.total_bytes
+                                                            )
+                                                        )
+                                                    },
                                                 ),
-                                            ))
-                                        },
+                                            )
+                                            // 文件夹传输的文件数
+                                            .when(transfer.is_folder, |this| {
+                                                let (completed, total) = transfer.file_counts();
+                                                this.child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(muted_foreground)
+                                                        .child(format!(
+                                                            "{}/{} {}",
+                                                            completed,
+                                                            total,
+                                                            crate::i18n::t(
+                                                                lang,
+                                                                "transfer.folder.files"
+                                                            )
+                                                        )),
+                                                )
+                                            })
+                                            // 断点续传的起点
+                                            .when_some(transfer.resumed_from(), |this, offset| {
+                                                this.child(
+                                                    div()
+                                                        .text_xs()
+                                                        .text_color(muted_foreground)
+                                                        .child(format!(
+                                                            "{} {}",
+                                                            crate::i18n::t(
+                                                                lang,
+                                                                "transfer.resumed_from"
+                                                            ),
+                                                            format_bytes(offset)
+                                                        )),
+                                                )
+                                            })
+                                            // 跨服务器复制的目标服务器
+                                            .when_some(
+                                                transfer.remote_target.as_ref(),
+                                                |this, (server, _)| {
+                                                    this.child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(muted_foreground)
+                                                            .child(format!("→ {}", server)),
+                                                    )
+                                                },
+                                            )
+                                            // 下载速度（仅在下载中显示）
+                                            .when(
+                                                transfer.progress.speed_bytes_per_sec > 0
+                                                    && !transfer.status.is_complete()
+                                                    && !transfer.status.is_error(),
+                                                |this| {
+                                                    this.child(
+                                                        div().text_xs().text_color(primary).child(
+                                                            format!(
+                                                                "{}",
+                                                                format_speed(
+                                                                    transfer
+                                                                        .progress
+                                                                        .speed_bytes_per_sec
+                                                                )
+                                                            ),
+                                                        ),
+                                                    )
+                                                },
+                                            )
+                                            // 单个传输的限速
+                                            .when(
+                                                transfer.speed_limit() > 0
+                                                    && !transfer.status.is_complete()
+                                                    && !transfer.status.is_error(),
+                                                |this| {
+                                                    this.child(
+                                                        div()
+                                                            .text_xs()
+                                                            .text_color(muted_foreground)
+                                                            .child(format!(
+                                                                "≤ {}",
+                                                                format_speed(
+                                                                    transfer.speed_limit()
+                                                                )
+                                                            )),
+                                                    )
+                                                },
+                                            ),
+                                    )
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(muted_foreground)
+                                            .child(format!("{:.0}%", progress_percent)),
                                     ),
                             )
-                            .child(
-                                div()
-                                    .text_xs()
-                                    .text_color(muted_foreground)
-                                    .child(format!("{:.0}%", progress_percent)),
-                            ),
-                    )
-            }))
+                            // 文件夹传输展开后的各文件明细
+                            .when(transfer.is_folder && transfer.expanded, |this| {
+                                this.child(render_folder_files(&transfer.children, lang, cx))
+                            })
+                    })),
+            )
             .into_any_element()
     }
 }
 
+/// 渲染文件夹传输中各文件的名称、状态和进度
+fn render_folder_files(
+    files: &[TransferItem],
+    lang: &crate::models::settings::Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let foreground = cx.theme().foreground;
+    let destructive: Hsla = gpui::rgb(0xef4444).into();
+    let success: Hsla = gpui::rgb(0x22c55e).into();
+
+    div()
+        .flex()
+        .flex_col()
+        .gap_1()
+        .pl_4()
+        .border_l_1()
+        .border_color(cx.theme().border)
+        .children(files.iter().map(|file| {
+            let status_color = if file.status.is_error() {
+                destructive
+            } else if file.status.is_complete() {
+                success
+            } else {
+                muted_foreground
+            };
+            let status_text = if file.status.is_active() && file.status != TransferStatus::Pending {
+                format!("{:.0}%", file.progress.percentage())
+            } else if file.verified {
+                crate::i18n::t(lang, "transfer.verified").to_string()
+            } else {
+                crate::i18n::t(lang, file.status.label_key()).to_string()
+            };
+
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .child(
+                    div()
+                        .flex_1()
+                        .text_xs()
+                        .text_color(foreground)
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_ellipsis()
+                        .child(file.file_name()),
+                )
+                .child(div().text_xs().text_color(status_color).child(status_text))
+        }))
+}
+
+/// 渲染传输历史页面：筛选条件和历史记录列表
+fn render_transfer_history(
+    session_state: Entity<SessionState>,
+    lang: &crate::models::settings::Language,
+    cx: &App,
+) -> impl IntoElement {
+    let state = session_state.read(cx);
+    let filter = state.transfer_history_filter;
+    let entries = TransferHistoryStore::global().entries(filter);
+    let muted_foreground = cx.theme().muted_foreground;
+    let session_state_for_clear = session_state.clone();
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        // 筛选条件和清空按钮
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_1()
+                .px_2()
+                .pt_2()
+                .child(div().flex().items_center().gap_1().children(
+                    TransferHistoryFilter::ALL.iter().map(|&f| {
+                        let selected = f == filter;
+                        let session_state = session_state.clone();
+                        div()
+                            .id(SharedString::from(format!(
+                                "transfer-history-filter-{:?}",
+                                f
+                            )))
+                            .px_2()
+                            .py(px(2.))
+                            .rounded_full()
+                            .border_1()
+                            .cursor_pointer()
+                            .text_xs()
+                            .when(selected, |this| {
+                                this.border_color(cx.theme().primary)
+                                    .text_color(cx.theme().primary)
+                            })
+                            .when(!selected, |this| {
+                                this.border_color(cx.theme().border)
+                                    .text_color(muted_foreground)
+                            })
+                            .child(crate::i18n::t(lang, f.label_key()))
+                            .on_click(move |_, _, cx| {
+                                session_state.update(cx, |state, cx| {
+                                    state.set_transfer_history_filter(f, cx);
+                                });
+                            })
+                    }),
+                ))
+                .child(
+                    div()
+                        .id("transfer-history-clear")
+                        .cursor_pointer()
+                        .rounded(px(2.))
+                        .p(px(2.))
+                        .hover(|s| s.bg(muted_foreground.opacity(0.2)))
+                        .child(render_icon(icons::TRASH, muted_foreground.into()))
+                        .tooltip({
+                            let label = crate::i18n::t(lang, "transfer.history.clear");
+                            move |window, cx| Tooltip::new(label).build(window, cx)
+                        })
+                        .on_click(move |_, _, cx| {
+                            session_state_for_clear.update(cx, |state, cx| {
+                                state.clear_transfer_history(cx);
+                            });
+                        }),
+                ),
+        )
+        .child(if entries.is_empty() {
+            div()
+                .id("transfer-history-empty")
+                .flex_1()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_2()
+                .pt_8()
+                .child(render_icon(icons::HISTORY, muted_foreground.into()))
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(muted_foreground)
+                        .child(crate::i18n::t(lang, "transfer.history.empty")),
+                )
+        } else {
+            div()
+                .id("transfer-history-scroll")
+                .flex_1()
+                .overflow_y_scroll()
+                .p_2()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .children(entries.into_iter().map(|entry| {
+                    let can_retransfer = state.history_tab_id(&entry.server_id).is_some();
+                    render_history_entry(entry, can_retransfer, session_state.clone(), lang, cx)
+                }))
+        })
+}
+
+/// 渲染一条传输历史记录
+fn render_history_entry(
+    entry: TransferHistoryEntry,
+    can_retransfer: bool,
+    session_state: Entity<SessionState>,
+    lang: &crate::models::settings::Language,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let foreground = cx.theme().foreground;
+    let status_color: Hsla = if entry.succeeded {
+        gpui::rgb(0x22c55e).into()
+    } else {
+        gpui::rgb(0xef4444).into()
+    };
+    let status_text = if entry.succeeded {
+        crate::i18n::t(lang, TransferStatus::Completed.label_key())
+    } else {
+        crate::i18n::t(lang, TransferStatus::Failed.label_key())
+    };
+    let local_path = entry.local_path.clone();
+
+    div()
+        .id(SharedString::from(format!("transfer-history-{}", entry.id)))
+        .p_3()
+        .bg(cx.theme().muted)
+        .rounded(px(6.))
+        .flex()
+        .flex_col()
+        .gap_1()
+        // 文件名、状态和操作按钮
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_1()
+                        .child(render_icon(
+                            if entry.is_upload {
+                                icons::UPLOAD
+                            } else {
+                                icons::DOWNLOAD
+                            },
+                            if entry.is_upload {
+                                gpui::rgb(0x3b82f6).into()
+                            } else {
+                                gpui::rgb(0x22c55e).into()
+                            },
+                        ))
+                        .child(
+                            div()
+                                .text_xs()
+                                .font_medium()
+                                .text_color(foreground)
+                                .overflow_hidden()
+                                .max_w(px(110.))
+                                .child(entry.file_name()),
+                        ),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap_2()
+                        .child(div().text_xs().text_color(status_color).child(status_text))
+                        // 重新传输（需要该服务器的会话仍然打开）
+                        .when(can_retransfer, |this| {
+                            let entry = entry.clone();
+                            this.child(
+                                div()
+                                    .id(SharedString::from(format!(
+                                        "transfer-history-retry-{}",
+                                        entry.id
+                                    )))
+                                    .cursor_pointer()
+                                    .rounded(px(2.))
+                                    .p(px(2.))
+                                    .hover(|s| s.bg(muted_foreground.opacity(0.2)))
+                                    .child(render_icon(icons::REFRESH, muted_foreground.into()))
+                                    .tooltip({
+                                        let label = crate::i18n::t(lang, "transfer.history.retry");
+                                        move |window, cx| Tooltip::new(label).build(window, cx)
+                                    })
+                                    .on_click(move |_, _, cx| {
+                                        session_state.update(cx, |state, cx| {
+                                            state.retransfer_history_entry(&entry, cx);
+                                        });
+                                    }),
+                            )
+                        })
+                        // 打开所在文件夹
+                        .child(
+                            div()
+                                .id(SharedString::from(format!(
+                                    "transfer-history-reveal-{}",
+                                    entry.id
+                                )))
+                                .cursor_pointer()
+                                .rounded(px(2.))
+                                .p(px(2.))
+                                .hover(|s| s.bg(muted_foreground.opacity(0.2)))
+                                .child(render_icon(icons::FOLDER_OPEN, muted_foreground.into()))
+                                .tooltip({
+                                    let label =
+                                        crate::i18n::t(lang, "transfer.history.open_folder");
+                                    move |window, cx| Tooltip::new(label).build(window, cx)
+                                })
+                                .on_click(move |_, _, cx| {
+                                    if local_path.exists() {
+                                        cx.reveal_path(&local_path);
+                                    } else if let Some(dir) =
+                                        local_path.parent().filter(|p| p.exists())
+                                    {
+                                        cx.open_with_system(dir);
+                                    }
+                                }),
+                        ),
+                ),
+        )
+        // 服务器、大小和时间
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_between()
+                .gap_2()
+                .text_xs()
+                .text_color(muted_foreground)
+                .child(div().overflow_hidden().child(format!(
+                    "{} · {}",
+                    entry.server_label,
+                    format_bytes(entry.size)
+                )))
+                .child(entry.finished_at.clone()),
+        )
+        // 失败原因
+        .when_some(
+            entry.error.clone().filter(|_| !entry.succeeded),
+            |this, error| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(status_color)
+                        .overflow_hidden()
+                        .child(error),
+                )
+            },
+        )
+}
+
+/// 限速预设（KB/s，0 表示不限速）
+const SPEED_LIMIT_PRESETS: [u32; 6] = [0, 128, 512, 1024, 5 * 1024, 10 * 1024];
+
+/// 限速显示文本
+fn format_speed_limit(lang: &crate::models::settings::Language, limit_kb: u32) -> String {
+    if limit_kb == 0 {
+        crate::i18n::t(lang, "transfer.limit.unlimited").to_string()
+    } else {
+        format_speed(transfer::kb_to_bytes(limit_kb))
+    }
+}
+
+/// 渲染限速下拉按钮，选择预设后调用 `on_select`
+///
+/// `show_label` 为 true 时在图标旁显示当前限速
+fn render_speed_limit_button(
+    id: impl Into<ElementId>,
+    icon: &'static str,
+    show_label: bool,
+    limit_kb: u32,
+    lang: crate::models::settings::Language,
+    on_select: Rc<dyn Fn(u32, &mut App)>,
+    cx: &App,
+) -> impl IntoElement {
+    let muted_foreground = cx.theme().muted_foreground;
+    let color = if limit_kb > 0 {
+        cx.theme().primary
+    } else {
+        muted_foreground
+    };
+    let tooltip = format!(
+        "{}: {}",
+        crate::i18n::t(&lang, "transfer.limit.title"),
+        format_speed_limit(&lang, limit_kb)
+    );
+
+    Button::new(id)
+        .h(px(20.))
+        .px_1()
+        .outline()
+        .tooltip(tooltip)
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(render_icon(icon, color.into()))
+                .when(show_label, |this| {
+                    this.child(div().text_xs().text_color(color).child(if limit_kb == 0 {
+                        "∞".to_string()
+                    } else {
+                        format_speed(transfer::kb_to_bytes(limit_kb))
+                    }))
+                }),
+        )
+        .dropdown_menu_with_anchor(Corner::TopRight, move |menu, _, _| {
+            SPEED_LIMIT_PRESETS
+                .iter()
+                .fold(menu.min_w(px(120.)), |menu, &preset| {
+                    let on_select = on_select.clone();
+                    menu.item(
+                        PopupMenuItem::new(format_speed_limit(&lang, preset))
+                            .on_click(move |_, _, cx| on_select(preset, cx)),
+                    )
+                })
+        })
+}
+
 /// 格式化字节数
 fn format_bytes(bytes: u64) -> String {
     if bytes < 1024 {
@@ -357,10 +1228,21 @@ fn format_speed(bytes_per_sec: u64) -> String {
 }
 
 /// 渲染快捷命令树
-fn render_snippets_tree(session_state: Entity<SessionState>, cx: &App) -> impl IntoElement {
-    // 获取配置
+fn render_snippets_tree(
+    tab: &SessionTab,
+    session_state: Entity<SessionState>,
+    cx: &App,
+) -> impl IntoElement {
+    // 获取配置，只显示对当前标签的服务器可见的命令
     let state = session_state.read(cx);
     let config = state.snippets_config.clone().unwrap_or_default();
+    let config = match &tab.server_data {
+        Some(server) => {
+            let servers = crate::services::storage::load_servers().unwrap_or_default();
+            config.visible_for(server, &servers)
+        }
+        None => config,
+    };
     let expanded = state.snippets_expanded.clone();
 
     // 获取根级组和命令
@@ -628,6 +1510,8 @@ fn render_command_node(
         .map(|s| s.theme.language)
         .unwrap_or_default();
 
+    let icon_color: Hsla = command.color.map(|c| rgb(c).into()).unwrap_or(muted);
+
     let command_id = command.id.clone();
     let command_text = command.command.clone();
     let command_text_for_tooltip = command.command.clone();
@@ -638,17 +1522,6 @@ fn render_command_node(
     let execute_label = crate::i18n::t(&lang, "snippets.context_menu.execute");
     let edit_label = crate::i18n::t(&lang, "snippets.context_menu.edit_in_box");
 
-    // 获取 PTY channel 用于执行命令
-    let pty_channel: Option<Arc<crate::ssh::session::TerminalChannel>> = session_state
-        .read(cx)
-        .active_tab()
-        .and_then(|tab| {
-            tab.active_terminal_id
-                .as_ref()
-                .and_then(|id| tab.terminals.iter().find(|t| &t.id == id))
-        })
-        .and_then(|inst| inst.pty_channel.clone());
-
     div()
         .id(SharedString::from(format!("cmd-{}", command_id)))
         .h(px(28.))
@@ -669,7 +1542,7 @@ fn render_command_node(
         .context_menu(move |menu, _window, _cx| {
             let cmd_for_execute = command_text_for_execute.clone();
             let cmd_for_edit = command_text_for_edit.clone();
-            let pty_for_menu = pty_channel.clone();
+            let session_for_execute = session_state.clone();
             let session_for_menu = session_state.clone();
 
             menu
@@ -683,22 +1556,11 @@ fn render_command_node(
                             .child(execute_label.clone())
                     })
                     .on_click(move |_, _window, cx| {
-                        if let Some(channel) = &pty_for_menu {
-                            let cmd = cmd_for_execute.clone();
-                            let channel = Arc::clone(channel);
-                            debug!("[ContextMenu] Executing command: {}", cmd);
-                            cx.spawn(async move |_| {
-                                let mut cmd_with_newline = cmd.into_bytes();
-                                cmd_with_newline.push(0x0d); // CR
-                                if let Err(e) = channel.write(&cmd_with_newline).await {
-                                    tracing::error!(
-                                        "[ContextMenu] Failed to send command: {:?}",
-                                        e
-                                    );
-                                }
-                            })
-                            .detach();
-                        }
+                        // 含变量的命令先弹出变量表单
+                        debug!("[ContextMenu] Executing command: {}", cmd_for_execute);
+                        session_for_execute.update(cx, |state, cx| {
+                            state.run_snippet(&cmd_for_execute, cx);
+                        });
                     })
                 })
                 // 在命令框编辑
@@ -719,8 +1581,8 @@ fn render_command_node(
                     })
                 })
         })
-        // 命令图标（紧贴左侧）
-        .child(svg().path(icons::CODE).size(px(14.)).text_color(muted))
+        // 命令图标（紧贴左侧，有颜色标记时使用该颜色）
+        .child(svg().path(icons::CODE).size(px(14.)).text_color(icon_color))
         // 命令名称
         .child(
             div()
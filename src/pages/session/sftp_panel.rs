@@ -7,8 +7,8 @@ use gpui_component::ActiveTheme;
 use crate::models::sftp::FileType;
 
 use crate::components::sftp::{
-    render_folder_tree, render_sftp_toolbar, FileListView, FolderTreeEvent, PathBarState,
-    SftpToolbarEvent,
+    render_folder_tree, render_preview_pane, render_sftp_toolbar, FileListView, FolderTreeEvent,
+    LocalPanelState, PathBarState, SftpSearchState, SftpToolbarEvent,
 };
 use crate::models::sftp::SftpState;
 use crate::state::SessionState;
@@ -16,16 +16,19 @@ use crate::state::SessionState;
 /// 渲染 SFTP 面板（使用 Table 组件）
 /// 布局结构：
 /// ┌─────────────────────────────────────────────────────────────────┐
-/// │ [←][→][↑][🏠] │     /home/user/path/to/folder      │ [🔄][📁][👁] │
+/// │ [←][→][↑][🏠] │  /home/user/path/to/folder  │ [☆][🔖] │ [🔍    ] │ [🔄][📁][👁] │
 /// ├─────────────────┬───────────────────────────────────────────────┤
 /// │                 │                                               │
 /// │    文件夹树      │              文件列表                         │
 /// │                 │                                               │
 /// └─────────────────┴───────────────────────────────────────────────┘
+/// 日志查看器、磁盘占用分析或搜索结果视图打开时替换右侧文件列表；双面板模式下最左侧增加本地面板
 pub fn render_sftp_panel(
     sftp_state: Option<&SftpState>,
     file_list_view: Option<Entity<FileListView>>,
     path_bar_state: Option<Entity<PathBarState>>,
+    search_state: Option<Entity<SftpSearchState>>,
+    local_panel: Option<Entity<LocalPanelState>>,
     session_state: Entity<SessionState>,
     tab_id: String,
     window: &mut Window,
@@ -44,6 +47,19 @@ pub fn render_sftp_panel(
             SftpToolbarEvent::GoHome => state.sftp_go_home(&tab_id_for_toolbar, cx),
             SftpToolbarEvent::Refresh => state.sftp_refresh(&tab_id_for_toolbar, cx),
             SftpToolbarEvent::ToggleHidden => state.sftp_toggle_hidden(&tab_id_for_toolbar, cx),
+            SftpToolbarEvent::ToggleDualPane => {
+                state.sftp_toggle_dual_pane(&tab_id_for_toolbar, cx);
+            }
+            SftpToolbarEvent::TogglePreview => {
+                state.sftp_toggle_preview(&tab_id_for_toolbar, cx);
+            }
+            SftpToolbarEvent::ToggleWatch => state.sftp_toggle_watch(&tab_id_for_toolbar, cx),
+            SftpToolbarEvent::DiskUsage => {
+                state.sftp_open_disk_usage(&tab_id_for_toolbar, cx);
+            }
+            SftpToolbarEvent::LogViewer => {
+                state.sftp_open_log_viewer(&tab_id_for_toolbar, None, cx);
+            }
             SftpToolbarEvent::NewFolder => {
                 state.sftp_open_new_folder_dialog(&tab_id_for_toolbar, cx);
             }
@@ -60,6 +76,15 @@ pub fn render_sftp_panel(
                     state.sftp_upload_folder_with_picker(&tab_id_for_toolbar, current_path, cx);
                 }
             }
+            SftpToolbarEvent::ToggleBookmark => {
+                state.sftp_toggle_bookmark(&tab_id_for_toolbar, cx);
+            }
+            SftpToolbarEvent::Navigate(path) => {
+                state.sftp_navigate_to(&tab_id_for_toolbar, path, cx);
+            }
+            SftpToolbarEvent::EditFile(path) => {
+                state.sftp_edit_file(&tab_id_for_toolbar, path, cx);
+            }
             SftpToolbarEvent::Download => {
                 // 获取选中的文件或文件夹
                 if let Some(ref file_list) = file_list_for_toolbar {
@@ -96,12 +121,24 @@ pub fn render_sftp_panel(
                 state.sftp_toggle_expand(&tab_id_for_tree, path, cx)
             }
             FolderTreeEvent::SelectDir(path) => state.sftp_navigate_to(&tab_id_for_tree, path, cx),
+            FolderTreeEvent::MoveInto { source, target_dir } => {
+                state.sftp_move_into(&tab_id_for_tree, source, target_dir, cx)
+            }
         });
     };
 
     // === 顶部工具栏 ===
     let toolbar: AnyElement = if let Some(path_bar) = path_bar_state {
-        render_sftp_toolbar(sftp_state, path_bar, on_toolbar_event, cx).into_any_element()
+        let bookmarks = session_state.read(cx).sftp_server_bookmarks(&tab_id);
+        render_sftp_toolbar(
+            sftp_state,
+            path_bar,
+            search_state.clone(),
+            bookmarks,
+            on_toolbar_event,
+            cx,
+        )
+        .into_any_element()
     } else {
         // 回退：显示空占位
         let bg_color = crate::theme::sidebar_color(cx);
@@ -120,13 +157,31 @@ pub fn render_sftp_panel(
     let folder_tree = render_folder_tree(&tab_id, sftp_state, on_folder_tree_event, window, cx);
 
     // === 右侧内容区：文件列表（使用 Table Entity） ===
-    let file_list: AnyElement = if let Some(view) = file_list_view {
+    let log_viewer = session_state
+        .read(cx)
+        .get_sftp_log_viewer(&tab_id)
+        .filter(|v| v.read(cx).is_open());
+    let disk_usage_view = session_state
+        .read(cx)
+        .get_sftp_disk_usage_view(&tab_id)
+        .filter(|v| v.read(cx).is_open());
+    let search_view = search_state.filter(|s| s.read(cx).is_open());
+    let file_list: AnyElement = if let Some(log_viewer) = log_viewer {
+        log_viewer.into_any_element()
+    } else if let Some(disk_usage_view) = disk_usage_view {
+        disk_usage_view.into_any_element()
+    } else if let Some(search_view) = search_view {
+        search_view.into_any_element()
+    } else if let Some(view) = file_list_view {
         // 直接使用已同步的 FileListView（数据同步在 page.rs 中完成）
         view.into_any_element()
     } else {
         // 回退：显示提示信息
         let bg_color = crate::theme::sidebar_color(cx);
         let muted_foreground = cx.theme().muted_foreground;
+        let lang = crate::services::storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or_default();
         div()
             .size_full()
             .bg(bg_color)
@@ -137,19 +192,40 @@ pub fn render_sftp_panel(
                 div()
                     .text_sm()
                     .text_color(muted_foreground)
-                    .child("Loading..."),
+                    .child(crate::i18n::t(&lang, "sftp.loading")),
             )
             .into_any_element()
     };
 
     // === 下方内容区：使用水平可调整大小布局分隔文件夹树和文件列表 ===
-    let content_area = h_resizable("sftp-panel-h")
-        .child(
-            resizable_panel()
-                .size(px(229.)) // 文件夹树初始宽度，与 Monitor 面板对齐
-                .child(folder_tree),
-        )
-        .child(resizable_panel().child(file_list));
+    let tree_panel = resizable_panel()
+        .size(px(229.)) // 文件夹树初始宽度，与 Monitor 面板对齐
+        .child(folder_tree);
+    let preview_open = sftp_state.is_some_and(|s| s.preview_open);
+    let remote_area: AnyElement = if preview_open {
+        // 预览面板打开时在文件列表右侧显示
+        let preview = render_preview_pane(session_state.read(cx).sftp_previews.get(&tab_id), cx);
+        h_resizable("sftp-panel-preview-h")
+            .child(tree_panel)
+            .child(resizable_panel().child(file_list))
+            .child(resizable_panel().size(px(280.)).child(preview))
+            .into_any_element()
+    } else {
+        h_resizable("sftp-panel-h")
+            .child(tree_panel)
+            .child(resizable_panel().child(file_list))
+            .into_any_element()
+    };
+
+    // === 双面板：本地面板 | 远程区域 ===
+    let dual_pane = sftp_state.is_some_and(|s| s.dual_pane);
+    let content_area: AnyElement = match local_panel.filter(|_| dual_pane) {
+        Some(local_panel) => h_resizable("sftp-panel-dual-h")
+            .child(resizable_panel().size(px(360.)).child(local_panel))
+            .child(resizable_panel().child(remote_area))
+            .into_any_element(),
+        None => remote_area.into_any_element(),
+    };
 
     // === 整体布局：工具栏 + 内容区 ===
     div()
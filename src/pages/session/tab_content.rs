@@ -0,0 +1,343 @@
+// 会话标签内容：按连接状态渲染连接页面或会话布局（主窗口与独立窗口共用）
+
+use gpui::*;
+use tracing::{debug, info};
+
+use super::render_session_layout;
+use crate::pages::connecting::{render_connecting_page, ConnectingProgress};
+use crate::ssh::start_ssh_connection;
+use crate::state::{SessionState, SessionStatus, SessionTab};
+
+/// 获取或创建标签的连接进度状态（保存在 SessionState 中，标签移到其他窗口后不会重复连接）
+fn ensure_connecting_progress(
+    tab: &SessionTab,
+    session_state: &Entity<SessionState>,
+    cx: &mut App,
+) -> Entity<ConnectingProgress> {
+    session_state.update(cx, |state, cx| {
+        state
+            .connecting_progress
+            .entry(tab.id.clone())
+            .or_insert_with(|| cx.new(|_| ConnectingProgress::new(tab.id.clone())))
+            .clone()
+    })
+}
+
+/// 渲染标签的内容区域
+pub fn render_tab_content(
+    tab: SessionTab,
+    session_state: Entity<SessionState>,
+    window: &mut Window,
+    cx: &mut App,
+) -> AnyElement {
+    match &tab.status {
+        SessionStatus::Connecting => {
+            // 获取或创建连接进度状态
+            let progress_state = ensure_connecting_progress(&tab, &session_state, cx);
+
+            // 检查是否需要启动连接（首次进入此 tab 时启动）
+            let should_start = !progress_state.read(cx).connection_started;
+
+            if should_start {
+                let server_label = tab.server_label.clone();
+                info!("[SSH] 开始连接到服务器: {}", server_label);
+
+                // 标记连接已启动
+                progress_state.update(cx, |p, _| {
+                    p.mark_started();
+                });
+
+                // 启动 SSH 连接（使用 SSH 模块）
+                let progress_for_timer = progress_state.clone();
+                let session_for_timer = session_state.clone();
+                let tab_id = tab.id.clone();
+                let server_id_for_log = tab.server_id.clone();
+
+                // 临时连接的 ServerData 保存在标签上，其余根据 server_id 从配置获取
+                let server_data = tab.server_data.clone().or_else(|| {
+                    crate::services::storage::load_servers()
+                        .ok()?
+                        .servers
+                        .into_iter()
+                        .find(|s| s.id == server_id_for_log)
+                });
+                if let Some(server_data) = server_data {
+                    start_ssh_connection(
+                        server_data,
+                        tab_id,
+                        progress_for_timer,
+                        session_for_timer,
+                        cx,
+                    );
+                }
+            }
+
+            render_connecting_page(&tab, progress_state, session_state.clone(), cx)
+                .into_any_element()
+        }
+        SessionStatus::Connected => {
+            // 确保命令输入框已创建
+            session_state.update(cx, |state, cx| {
+                state.ensure_command_input_created(window, cx);
+            });
+
+            // 确保 SFTP 文件列表视图已创建并同步数据
+            let tab_id_for_sftp = tab.id.clone();
+            session_state.update(cx, |state, cx| {
+                let view = state.ensure_sftp_file_list_view(&tab_id_for_sftp, window, cx);
+                // 同步 SFTP 状态到 FileListView
+                let sftp_state = state
+                    .tabs
+                    .iter()
+                    .find(|t| t.id == tab_id_for_sftp)
+                    .and_then(|t| t.sftp_state.as_ref());
+                view.update(cx, |v, cx| {
+                    v.sync_from_sftp_state(sftp_state, cx);
+                });
+
+                // 提取当前路径（避免借用冲突）
+                let current_path = sftp_state.map(|s| s.current_path.clone());
+
+                // 确保 PathBarState 创建并同步路径
+                let entity = cx.entity().clone();
+                let path_bar =
+                    state.ensure_sftp_path_bar_state(&tab_id_for_sftp, entity, window, cx);
+                // 同步当前路径到 PathBarState
+                if let Some(path) = current_path {
+                    path_bar.update(cx, |pb, cx| {
+                        pb.set_path(&path, window, cx);
+                    });
+
+                    // 确保搜索状态创建，并以当前目录作为搜索根目录
+                    let entity = cx.entity().clone();
+                    let search =
+                        state.ensure_sftp_search_state(&tab_id_for_sftp, entity, window, cx);
+                    search.update(cx, |s, _| s.set_root(&path));
+                }
+
+                // 确保日志查看器已创建（其中的输入框需要 window）
+                state.ensure_sftp_log_viewer(&tab_id_for_sftp, window, cx);
+
+                // 确保端口转发视图已创建（侧边栏"端口转发"页使用）
+                state.ensure_port_forward_view(&tab_id_for_sftp, window, cx);
+
+                // 显示进程页时确保进程管理视图已创建（并开始轮询）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Processes {
+                    state.ensure_process_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 显示 Docker 页时确保 Docker 管理视图已创建（并开始检测与轮询）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Docker {
+                    state.ensure_docker_view(&tab_id_for_sftp, cx);
+                }
+
+                // 显示服务页时确保服务管理视图已创建（并开始轮询）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Services {
+                    state.ensure_service_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 显示网络页时确保网络连接视图已创建（并开始轮询）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Network {
+                    state.ensure_connection_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 显示计划任务页时确保计划任务视图已创建（并读取 crontab）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Cron {
+                    state.ensure_cron_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 显示软件包页时确保软件包管理视图已创建（并检测包管理器）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Packages {
+                    state.ensure_package_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 显示防火墙页时确保防火墙视图已创建（并读取规则）
+                if state.monitor_panel_view == crate::state::MonitorPanelView::Firewall {
+                    state.ensure_firewall_view(&tab_id_for_sftp, window, cx);
+                }
+
+                // 双面板模式下确保本地面板已创建
+                let dual_pane = state
+                    .tabs
+                    .iter()
+                    .find(|t| t.id == tab_id_for_sftp)
+                    .and_then(|t| t.sftp_state.as_ref())
+                    .is_some_and(|s| s.dual_pane);
+                if dual_pane {
+                    let entity = cx.entity().clone();
+                    state.ensure_sftp_local_panel(&tab_id_for_sftp, entity, cx);
+                }
+            });
+
+            // 检查当前激活的终端是否已初始化
+            let needs_init = tab
+                .active_terminal_id
+                .as_ref()
+                .and_then(|id| tab.terminals.iter().find(|t| &t.id == id))
+                .map(|inst| !inst.pty_initialized)
+                .unwrap_or(false);
+
+            // 自动初始化 PTY（在 UI 挂载成功后触发）
+            if needs_init {
+                let tab_id = tab.id.clone();
+                let server_id = tab.server_id.clone();
+                let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
+                session_state.update(cx, |state, cx| {
+                    // 根据窗口尺寸计算终端区域
+                    // 布局常量（与 session_layout.rs 保持一致），可拖动的面板使用记录的尺寸
+                    const TITLEBAR_HEIGHT: f32 = 44.0;
+                    const MINI_SIDEBAR_WIDTH: f32 = 28.0;
+                    const COMMAND_INPUT_HEIGHT: f32 = 40.0; // 24px 按钮 + 2*8px padding
+
+                    // 获取窗口内容区域尺寸
+                    let window_size = window.viewport_size();
+                    let window_width = f32::from(window_size.width);
+                    let window_height = f32::from(window_size.height);
+
+                    // 计算终端区域尺寸
+                    let layout = crate::services::storage::load_server_layout(&server_id);
+                    let sidebar_width = if sidebar_collapsed {
+                        0.0
+                    } else {
+                        layout.session_sidebar_width
+                    };
+                    let monitor_width = if layout.monitor_collapsed {
+                        0.0
+                    } else {
+                        layout.monitor_panel_width
+                    };
+                    let terminal_width =
+                        window_width - monitor_width - sidebar_width - MINI_SIDEBAR_WIDTH;
+                    let terminal_height = window_height
+                        - TITLEBAR_HEIGHT
+                        - layout.sftp_panel_height
+                        - COMMAND_INPUT_HEIGHT;
+
+                    debug!(
+                        "[Terminal] Window: {}x{}, Calculated terminal area: {}x{}",
+                        window_width, window_height, terminal_width, terminal_height
+                    );
+
+                    state.initialize_terminal(
+                        &tab_id,
+                        terminal_width.max(100.0),
+                        terminal_height.max(100.0),
+                        window,
+                        cx,
+                    );
+                });
+            }
+
+            // 确保 SFTP 新建文件夹对话框输入框已创建
+            let new_folder_dialog = session_state.read(cx).sftp_new_folder_dialog.clone();
+            if let Some(dialog) = new_folder_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 新建文件对话框输入框已创建
+            let new_file_dialog = session_state.read(cx).sftp_new_file_dialog.clone();
+            if let Some(dialog) = new_file_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 新建链接对话框输入框已创建
+            let new_link_dialog = session_state.read(cx).sftp_new_link_dialog.clone();
+            if let Some(dialog) = new_link_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 属性对话框的八进制权限输入框已创建
+            let properties_dialog = session_state.read(cx).sftp_properties_dialog.clone();
+            if let Some(dialog) = properties_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 目录同步对话框的路径输入框已创建
+            let sync_dialog = session_state.read(cx).sftp_sync_dialog.clone();
+            if let Some(dialog) = sync_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 复制到其他服务器对话框的目录输入框已创建
+            let copy_dialog = session_state.read(cx).sftp_copy_to_server_dialog.clone();
+            if let Some(dialog) = copy_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP 十六进制查看器的偏移量与搜索输入框已创建
+            let hex_dialog = session_state.read(cx).sftp_hex_viewer_dialog.clone();
+            if let Some(dialog) = hex_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            // 确保 SFTP sudo 密码输入框已创建
+            let sudo_dialog = session_state.read(cx).sftp_sudo_dialog.clone();
+            if let Some(dialog) = sudo_dialog {
+                let is_open = dialog.read(cx).is_open;
+                if is_open {
+                    dialog.update(cx, |ds, cx| {
+                        ds.ensure_input_created(window, cx);
+                    });
+                }
+            }
+
+            let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
+            render_session_layout(&tab, sidebar_collapsed, session_state.clone(), window, cx)
+                .into_any_element()
+        }
+        SessionStatus::Disconnected => {
+            // 断开状态继续显示终端布局，终端区域会显示断开提示
+            let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
+            render_session_layout(&tab, sidebar_collapsed, session_state.clone(), window, cx)
+                .into_any_element()
+        }
+        SessionStatus::Reconnecting { .. } => {
+            // 重连状态继续显示终端布局，终端区域会显示重连进度
+            let sidebar_collapsed = session_state.read(cx).sidebar_collapsed;
+            render_session_layout(&tab, sidebar_collapsed, session_state.clone(), window, cx)
+                .into_any_element()
+        }
+        SessionStatus::Error(_) => {
+            // 错误状态使用连接页面显示
+            let progress_state = ensure_connecting_progress(&tab, &session_state, cx);
+
+            render_connecting_page(&tab, progress_state, session_state.clone(), cx)
+                .into_any_element()
+        }
+    }
+}
@@ -0,0 +1,205 @@
+// 标签页总览 - 以实时缩略图展示所有会话，便于在大量标签间快速切换
+
+use gpui::*;
+use gpui_component::ActiveTheme;
+
+use crate::constants::icons;
+use crate::i18n;
+use crate::models::settings::TerminalSettings;
+use crate::state::{SessionState, SessionStatus, SessionTab};
+use crate::terminal::{hex_to_hsla, render_terminal_thumbnail, TERMINAL_PADDING_LEFT};
+
+/// 缩略图卡片宽度
+const CARD_WIDTH: f32 = 280.0;
+/// 缩略图区域高度
+const THUMBNAIL_HEIGHT: f32 = 160.0;
+
+/// 渲染标签页总览覆盖层
+pub fn render_tab_overview(session_state: Entity<SessionState>, cx: &App) -> impl IntoElement {
+    let settings = crate::services::storage::load_settings().unwrap_or_default();
+    let lang = settings.theme.language.clone();
+    let terminal_settings = settings.terminal;
+
+    let state = session_state.read(cx);
+    // 只显示主窗口中的标签（独立窗口中的标签在各自窗口显示）
+    let tabs: Vec<_> = state.window_tabs(None).cloned().collect();
+    let active_tab_id = state.active_tab_id.clone();
+
+    let foreground = cx.theme().foreground;
+    let muted_foreground = cx.theme().muted_foreground;
+    let session_for_backdrop = session_state.clone();
+
+    div()
+        .id("tab-overview-overlay")
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full()
+        .bg(gpui::black().opacity(0.6))
+        .flex()
+        .flex_col()
+        .items_center()
+        .p_8()
+        .gap_6()
+        // 点击空白处关闭
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            session_for_backdrop.update(cx, |state, cx| {
+                state.tab_overview_open = false;
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .text_lg()
+                .font_weight(FontWeight::BOLD)
+                .text_color(gpui::white())
+                .child(i18n::t(&lang, "tab_overview.title")),
+        )
+        .child(
+            div()
+                .id("tab-overview-grid")
+                .w_full()
+                .flex_1()
+                .min_h(px(0.))
+                .overflow_y_scroll()
+                .flex()
+                .flex_wrap()
+                .justify_center()
+                .content_start()
+                .gap_4()
+                .children(tabs.iter().map(|tab| {
+                    let is_active = active_tab_id.as_deref() == Some(tab.id.as_str());
+                    // 缩略图使用服务器覆盖后的终端设置
+                    let terminal_settings = match &tab.server_data {
+                        Some(server) => server.resolve_terminal_settings(terminal_settings.clone()),
+                        None => terminal_settings.clone(),
+                    };
+                    render_tab_card(
+                        tab,
+                        is_active,
+                        &terminal_settings,
+                        session_state.clone(),
+                        foreground,
+                        muted_foreground,
+                        cx,
+                    )
+                })),
+        )
+}
+
+/// 渲染单个标签卡片
+fn render_tab_card(
+    tab: &SessionTab,
+    is_active: bool,
+    terminal_settings: &TerminalSettings,
+    session_state: Entity<SessionState>,
+    foreground: Hsla,
+    muted_foreground: Hsla,
+    cx: &App,
+) -> AnyElement {
+    let tab_id = tab.id.clone();
+    let border_color = if is_active {
+        cx.theme().primary
+    } else {
+        cx.theme().border
+    };
+
+    let status_color = match &tab.status {
+        SessionStatus::Connected => Hsla::from(rgb(0x22c55e)),
+        SessionStatus::Error(_) => Hsla::from(rgb(0xef4444)),
+        _ => Hsla::from(rgb(0xf59e0b)),
+    };
+
+    div()
+        .id(SharedString::from(format!("tab-overview-card-{}", tab.id)))
+        .w(px(CARD_WIDTH))
+        .flex()
+        .flex_col()
+        .rounded_lg()
+        .overflow_hidden()
+        .border_2()
+        .border_color(border_color)
+        .bg(cx.theme().popover)
+        .cursor_pointer()
+        .hover(|s| s.border_color(cx.theme().primary_hover))
+        .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+            cx.stop_propagation();
+            session_state.update(cx, |state, cx| {
+                state.select_tab_from_overview(&tab_id);
+                cx.notify();
+            });
+        })
+        .child(
+            div()
+                .w_full()
+                .h(px(THUMBNAIL_HEIGHT))
+                .child(render_tab_thumbnail(tab, terminal_settings, cx)),
+        )
+        .child(
+            div()
+                .px_3()
+                .py_2()
+                .flex()
+                .items_center()
+                .gap_2()
+                .child(div().size(px(8.)).rounded_full().bg(status_color))
+                .child(
+                    div()
+                        .flex_1()
+                        .text_sm()
+                        .text_color(if is_active {
+                            foreground
+                        } else {
+                            muted_foreground
+                        })
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(tab.server_label.clone()),
+                ),
+        )
+        .into_any_element()
+}
+
+/// 渲染标签的终端缩略图（使用当前激活的终端实例）
+fn render_tab_thumbnail(
+    tab: &SessionTab,
+    terminal_settings: &TerminalSettings,
+    cx: &App,
+) -> AnyElement {
+    let terminal = tab
+        .active_terminal_id
+        .as_ref()
+        .and_then(|id| tab.terminals.iter().find(|t| &t.id == id))
+        .or_else(|| tab.terminals.first())
+        .and_then(|instance| instance.terminal.clone());
+
+    let Some(terminal) = terminal else {
+        // 尚未创建终端（连接中/出错）
+        return div()
+            .size_full()
+            .bg(hex_to_hsla(&terminal_settings.background_color))
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+                svg()
+                    .path(icons::TERMINAL)
+                    .size(px(32.))
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .into_any_element();
+    };
+
+    let state = terminal.read(cx);
+    let size = state.size();
+
+    // 等比缩放，保证整屏内容都能放进卡片
+    let content_width = size.columns as f32 * size.cell_width + TERMINAL_PADDING_LEFT;
+    let content_height = size.lines as f32 * size.line_height;
+    let scale = (CARD_WIDTH / content_width)
+        .min(THUMBNAIL_HEIGHT / content_height)
+        .min(1.0);
+
+    render_terminal_thumbnail(&state.term().lock(), size, terminal_settings, scale)
+        .into_any_element()
+}
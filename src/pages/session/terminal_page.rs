@@ -1,5 +1,6 @@
 // Terminal 面板组件 - 包含终端区域和命令输入框
 
+use std::rc::Rc;
 use std::sync::Arc;
 
 use gpui::prelude::FluentBuilder;
@@ -16,8 +17,8 @@ use crate::ssh::session::TerminalChannel;
 use crate::state::{SessionState, SessionStatus, SessionTab};
 use crate::terminal::{
     hex_to_hsla, keystroke_to_escape, render_terminal_view, SendDown, SendEnter, SendEscape,
-    SendLeft, SendRight, SendTab, SendUp, TerminalCopy, TerminalPaste, TerminalState,
-    TERMINAL_PADDING_LEFT,
+    SendLeft, SendRight, SendTab, SendUp, TerminalAppearance, TerminalCopy, TerminalPaste,
+    TerminalState, TERMINAL_PADDING_LEFT,
 };
 
 /// 渲染终端面板
@@ -26,13 +27,24 @@ pub fn render_terminal_panel(
     command_input: Option<Entity<InputState>>,
     session_state: Entity<SessionState>,
     terminal_focus_handle: Option<FocusHandle>,
+    terminal_focused: bool,
     cx: &App,
 ) -> impl IntoElement {
     let border_color = cx.theme().border;
 
-    // 获取终端设置
+    // 获取终端设置（应用服务器的覆盖设置）
     let settings = crate::services::storage::load_settings().unwrap_or_default();
-    let terminal_settings = settings.terminal.clone();
+    let terminal_settings = match &tab.server_data {
+        Some(server) => server.resolve_terminal_settings(settings.terminal.clone()),
+        None => settings.terminal.clone(),
+    };
+
+    // 终端外观：服务器级透明度覆盖全局设置，未聚焦时按设置变暗
+    let appearance = TerminalAppearance::resolve(
+        &terminal_settings,
+        tab.server_data.as_ref().and_then(|s| s.terminal_opacity),
+        terminal_focused,
+    );
 
     // 获取当前激活的终端实例
     let active_terminal_id = tab.active_terminal_id.clone();
@@ -234,16 +246,29 @@ pub fn render_terminal_panel(
             });
         }
 
+        // 键盘宏录制：记录发送到 PTY 的按键与粘贴内容（仅在本标签录制时）
+        let macro_recorder = {
+            let session_state = session_state.clone();
+            let tab_id = tab.id.clone();
+            Rc::new(move |bytes: &[u8], cx: &mut App| {
+                if session_state.read(cx).is_recording_macro(&tab_id) {
+                    session_state.update(cx, |state, _| state.record_macro_input(&tab_id, bytes));
+                }
+            })
+        };
+
         // 处理 Terminal 专用 actions（通过 key binding 触发，覆盖默认焦点切换行为）
         // Tab 键
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendTab, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x09], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x09]).await; // Tab = 0x09
                     })
@@ -255,11 +280,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendEnter, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x0D], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x0D]).await; // Enter = 0x0D
                     })
@@ -271,11 +298,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendEscape, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x1B], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x1B]).await; // Escape = 0x1B
                     })
@@ -287,11 +316,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendUp, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x1B, b'[', b'A'], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x1B, b'[', b'A']).await; // Up arrow
                     })
@@ -303,11 +334,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendDown, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x1B, b'[', b'B'], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x1B, b'[', b'B']).await; // Down arrow
                     })
@@ -319,11 +352,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendLeft, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x1B, b'[', b'D'], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x1B, b'[', b'D']).await; // Left arrow
                     })
@@ -335,11 +370,13 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &SendRight, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     if let Some(terminal) = terminal.clone() {
                         terminal.update(cx, |t, _| t.show_cursor());
                     }
+                    record_macro(&[0x1B, b'[', b'C'], cx);
                     cx.spawn(async move |_| {
                         let _ = channel.write(&[0x1B, b'[', b'C']).await; // Right arrow
                     })
@@ -370,6 +407,7 @@ pub fn render_terminal_panel(
         {
             let channel = pty_channel.clone();
             let terminal = terminal_entity.clone();
+            let record_macro = macro_recorder.clone();
             terminal_display = terminal_display.on_action(move |_: &TerminalPaste, _window, cx| {
                 if let Some(channel) = channel.clone() {
                     // 从剪贴板读取文本
@@ -383,6 +421,8 @@ pub fn render_terminal_panel(
                                 terminal.update(cx, |t, _| t.show_cursor());
                             }
 
+                            record_macro(&bytes, cx);
+
                             // 发送到 PTY
                             cx.spawn(async move |_| {
                                 if let Err(e) = channel.write(&bytes).await {
@@ -401,6 +441,8 @@ pub fn render_terminal_panel(
         // 键盘：PageUp/Down 用于滚动历史（非 ALT_SCREEN），其余按键发送到 PTY
         let terminal_for_key = terminal_entity.clone();
         let pty_channel_for_key = pty_channel.clone();
+        let session_state_for_key = session_state.clone();
+        let tab_id_for_key = tab.id.clone();
         terminal_display = terminal_display.on_key_down(move |event, _window, cx| {
             let key = event.keystroke.key.as_str();
 
@@ -431,6 +473,21 @@ pub fn render_terminal_panel(
                 }
             }
 
+            // 绑定了键盘宏的快捷键：回放键盘宏，不发送按键本身
+            if event.keystroke.modifiers.modified() {
+                let keystroke = event.keystroke.unparse();
+                let played = session_state_for_key.update(cx, |state, cx| {
+                    state.load_snippets_config();
+                    state
+                        .macro_for_keystroke(&keystroke)
+                        .is_some_and(|m| state.play_macro(&tab_id_for_key, &m, cx))
+                });
+                if played {
+                    cx.stop_propagation();
+                    return;
+                }
+            }
+
             let Some(channel) = pty_channel_for_key.clone() else {
                 return;
             };
@@ -450,6 +507,8 @@ pub fn render_terminal_panel(
                     });
                 }
 
+                macro_recorder(&bytes, cx);
+
                 // 发送到 PTY (异步)
                 cx.spawn(async move |_async_cx| {
                     if let Err(e) = channel.write(&bytes).await {
@@ -481,7 +540,8 @@ pub fn render_terminal_panel(
         // 有终端实例 - 渲染真实终端内容
         // 如果处于重连或断开状态，在终端内容上叠加状态覆盖层
         let terminal_content =
-            render_terminal_content(terminal.clone(), &terminal_settings, cx).into_any_element();
+            render_terminal_content(terminal.clone(), &terminal_settings, appearance, cx)
+                .into_any_element();
 
         match &session_status {
             SessionStatus::Reconnecting { attempt, max_attempts } => {
@@ -677,6 +737,7 @@ pub fn render_terminal_panel(
 fn render_terminal_content(
     terminal: Entity<TerminalState>,
     settings: &crate::models::settings::TerminalSettings,
+    appearance: TerminalAppearance,
     cx: &App,
 ) -> impl IntoElement {
     let state = terminal.read(cx);
@@ -685,7 +746,7 @@ fn render_terminal_content(
     let cursor_visible = state.is_cursor_visible();
 
     // 使用 renderer 中的 render_terminal_view 函数
-    render_terminal_view(&term.lock(), size, settings, cursor_visible, cx)
+    render_terminal_view(&term.lock(), size, settings, appearance, cursor_visible, cx)
 }
 
 /// 渲染错误状态的终端
@@ -740,6 +801,9 @@ fn render_error_terminal(
 fn render_loading_terminal(settings: &crate::models::settings::TerminalSettings, _cx: &App) -> Div {
     let bg_color = hex_to_hsla(&settings.background_color);
     let fg_color = hex_to_hsla(&settings.foreground_color);
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
 
     div()
         .size_full()
@@ -758,7 +822,7 @@ fn render_loading_terminal(settings: &crate::models::settings::TerminalSettings,
                     div()
                         .text_color(fg_color.opacity(0.6))
                         .text_sm()
-                        .child("Initializing terminal..."),
+                        .child(crate::i18n::t(&lang, "session.terminal.placeholder")),
                 ),
         )
 }
@@ -1 +1,176 @@
-// 页面路由逻辑
+// 页面路由：主窗口中可导航的视图（主页菜单、会话标签、设置区域）与后退/前进历史，
+// 以及系统打开的 shellmaster:// 深链接与 ssh:// 链接解析
+
+use crate::components::common::settings_dialog::SettingsSection;
+use crate::pages::home::sidebar::MenuType;
+use crate::services::server_search::{self, AdHocTarget};
+
+/// 深链接的 URL Scheme
+pub const URL_SCHEME: &str = "shellmaster";
+/// 注册为默认处理程序后由系统转交的 SSH 链接 Scheme
+pub const SSH_URL_SCHEME: &str = "ssh";
+
+/// 最多保留的导航历史条数
+const MAX_HISTORY: usize = 100;
+
+/// 主窗口中可导航的视图
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Route {
+    /// 主页的侧边栏菜单
+    Home(MenuType),
+    /// 会话标签（标签 ID）
+    Session(String),
+    /// 设置弹窗中的区域
+    Settings(SettingsSection),
+}
+
+impl Route {
+    /// 路由路径，如 `home/hosts`、`session/<tab_id>`、`settings/terminal`
+    pub fn path(&self) -> String {
+        match self {
+            Route::Home(menu) => format!("home/{}", menu.id()),
+            Route::Session(tab_id) => format!("session/{}", tab_id),
+            Route::Settings(section) => format!("settings/{}", section.id()),
+        }
+    }
+
+    /// 解析路由路径；省略菜单或区域时使用第一项（`home` 即主机列表，`settings` 即主题设置）
+    pub fn parse(path: &str) -> Option<Self> {
+        let path = path.trim_matches('/');
+        let (view, param) = match path.split_once('/') {
+            Some((view, param)) => (view, Some(param)),
+            None => (path, None),
+        };
+        match (view, param) {
+            ("home", None) => Some(Route::Home(MenuType::Hosts)),
+            ("home", Some(id)) => MenuType::ALL
+                .into_iter()
+                .find(|m| m.id() == id)
+                .map(Route::Home),
+            ("session", Some(tab_id)) if !tab_id.is_empty() => {
+                Some(Route::Session(tab_id.to_string()))
+            }
+            ("settings", None) => Some(Route::Settings(SettingsSection::default())),
+            ("settings", Some(id)) => SettingsSection::ALL
+                .into_iter()
+                .find(|s| s.id() == id)
+                .map(Route::Settings),
+            _ => None,
+        }
+    }
+}
+
+/// 导航历史：视图切换时记录，支持后退与前进
+#[derive(Default)]
+pub struct Router {
+    history: Vec<Route>,
+    /// 当前路由在历史中的位置
+    index: usize,
+}
+
+impl Router {
+    pub fn current(&self) -> Option<&Route> {
+        self.history.get(self.index)
+    }
+
+    /// 记录切换到的视图：与当前路由相同时忽略，否则丢弃前进历史后追加
+    pub fn visit(&mut self, route: Route) {
+        if self.current() == Some(&route) {
+            return;
+        }
+        if !self.history.is_empty() {
+            self.history.truncate(self.index + 1);
+        }
+        self.history.push(route);
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.index = self.history.len() - 1;
+    }
+
+    /// 后退到上一个仍然有效的路由（`valid` 用于跳过已关闭的标签等）
+    pub fn back(&mut self, valid: impl Fn(&Route) -> bool) -> Option<Route> {
+        let index = (0..self.index).rev().find(|&i| valid(&self.history[i]))?;
+        self.index = index;
+        Some(self.history[index].clone())
+    }
+
+    /// 前进到下一个仍然有效的路由
+    pub fn forward(&mut self, valid: impl Fn(&Route) -> bool) -> Option<Route> {
+        let index = (self.index + 1..self.history.len()).find(|&i| valid(&self.history[i]))?;
+        self.index = index;
+        Some(self.history[index].clone())
+    }
+}
+
+/// 系统打开的深链接
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeepLink {
+    /// `shellmaster://connect?host=example.com&user=root&port=22` 或 `ssh://root@example.com:22`
+    Connect(AdHocTarget),
+    /// `shellmaster://home/hosts`、`shellmaster://settings/terminal` 等路由路径
+    Open(Route),
+}
+
+impl DeepLink {
+    pub fn parse(link: &str) -> Option<Self> {
+        let url = url::Url::parse(link.trim()).ok()?;
+        if url.scheme() == SSH_URL_SCHEME {
+            return parse_ssh_url(&url).map(DeepLink::Connect);
+        }
+        if url.scheme() != URL_SCHEME {
+            return None;
+        }
+        let view = url.host_str().unwrap_or_default();
+        if view != "connect" {
+            return Route::parse(&format!("{}{}", view, url.path())).map(DeepLink::Open);
+        }
+
+        let mut host = None;
+        let mut username = None;
+        let mut port = 22;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "host" => host = Some(value.into_owned()),
+                "user" | "username" => username = Some(value.into_owned()),
+                "port" => port = value.parse().ok()?,
+                _ => {}
+            }
+        }
+        let host = host.filter(|h| !h.is_empty())?;
+        let username = username.filter(|u| !u.is_empty())?;
+        if port == 0 {
+            return None;
+        }
+        Some(DeepLink::Connect(AdHocTarget {
+            username,
+            host,
+            port,
+        }))
+    }
+}
+
+/// 解析 `ssh://[user[;fingerprint=...]@]host[:port]`，省略用户名时使用本机用户名
+fn parse_ssh_url(url: &url::Url) -> Option<AdHocTarget> {
+    let host = url
+        .host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_string();
+    // 用户名后可能带有主机密钥指纹等参数（以分号分隔），连接时仍按已知主机校验
+    let username = url.username().split(';').next().unwrap_or_default();
+    let username = if username.is_empty() {
+        server_search::local_username()?
+    } else {
+        username.to_string()
+    };
+    let port = url.port().unwrap_or(22);
+    if host.is_empty() || port == 0 {
+        return None;
+    }
+    Some(AdHocTarget {
+        username,
+        host,
+        port,
+    })
+}
@@ -0,0 +1,174 @@
+// 批量执行服务
+// 在多台服务器上并行执行同一条命令：已连接的服务器复用会话，未连接的服务器临时建立连接、执行完毕后断开；
+// 结果包含每台主机的标准输出/错误、退出码与耗时，可导出为 CSV
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use tokio::sync::{mpsc, oneshot};
+use tracing::{info, warn};
+
+use crate::models::server::ServerData;
+use crate::ssh::event::{ConnectionEvent, HostKeyAction};
+use crate::ssh::session::SshSession;
+use crate::ssh::SshClient;
+
+/// 同时执行的最大主机数
+pub const MAX_PARALLEL: usize = 16;
+
+/// 一台待执行的主机
+#[derive(Clone)]
+pub struct BatchTarget {
+    pub server: ServerData,
+    /// 已打开标签页的会话（None 时临时连接）
+    pub session: Option<Arc<SshSession>>,
+}
+
+/// 单台主机的执行结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostResult {
+    /// 退出码（连接或执行失败时为 None）
+    pub exit_code: Option<u32>,
+    pub stdout: String,
+    pub stderr: String,
+    pub duration: Duration,
+    /// 连接或执行失败的原因
+    pub error: Option<String>,
+}
+
+impl HostResult {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none() && self.exit_code == Some(0)
+    }
+
+    fn failed(error: String, started: Instant) -> Self {
+        Self {
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            duration: started.elapsed(),
+            error: Some(error),
+        }
+    }
+}
+
+/// 在一台主机上执行命令
+pub async fn run_on_host(target: BatchTarget, command: &str) -> HostResult {
+    let started = Instant::now();
+    let label = target.server.label.clone();
+    let (session, temporary) = match target.session.filter(|s| s.is_alive()) {
+        Some(session) => (session, false),
+        None => match connect(&target.server).await {
+            Ok(session) => (Arc::new(session), true),
+            Err(e) => {
+                warn!("[Batch] Failed to connect to {}: {}", label, e);
+                return HostResult::failed(e, started);
+            }
+        },
+    };
+
+    let output = match session.open_exec().await {
+        Ok(exec) => exec.exec(command).await.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+    if temporary {
+        let _ = session.close().await;
+    }
+
+    match output {
+        Ok(output) => {
+            info!("[Batch] {} exited with {}", label, output.exit_code);
+            HostResult {
+                exit_code: Some(output.exit_code),
+                stdout: output.stdout_string(),
+                stderr: output.stderr_string(),
+                duration: started.elapsed(),
+                error: None,
+            }
+        }
+        Err(e) => HostResult::failed(e, started),
+    }
+}
+
+/// 临时连接服务器（不注册到 SshManager）
+///
+/// 批量执行无法交互确认主机密钥，未知或已变化的主机密钥一律拒绝
+async fn connect(server: &ServerData) -> Result<SshSession, String> {
+    let config = crate::ssh::connector::build_ssh_config(server);
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (host_key_tx, host_key_rx) = oneshot::channel();
+    let rejected = Arc::new(AtomicBool::new(false));
+
+    let rejected_for_events = rejected.clone();
+    tokio::spawn(async move {
+        let mut host_key_tx = Some(host_key_tx);
+        while let Some(event) = event_rx.recv().await {
+            if let ConnectionEvent::HostKeyVerification { .. }
+            | ConnectionEvent::HostKeyMismatch { .. } = event
+            {
+                rejected_for_events.store(true, Ordering::SeqCst);
+                if let Some(tx) = host_key_tx.take() {
+                    let _ = tx.send(HostKeyAction::Reject);
+                }
+            }
+        }
+    });
+
+    let mut client = SshClient::new(config, event_tx, host_key_rx);
+    let session_id = format!("batch-{}", uuid::Uuid::new_v4());
+    client.connect(session_id).await.map_err(|e| {
+        if rejected.load(Ordering::SeqCst) {
+            "Host key not trusted, connect to this server once to verify it".to_string()
+        } else {
+            e.to_string()
+        }
+    })
+}
+
+/// 导出的一行：服务器名称、地址与结果
+pub struct ExportRow<'a> {
+    pub label: &'a str,
+    pub address: &'a str,
+    pub result: &'a HostResult,
+}
+
+/// 默认导出文件名
+pub fn default_file_name() -> String {
+    format!("batch-exec-{}.csv", Local::now().format("%Y%m%d-%H%M%S"))
+}
+
+/// 把执行结果写成 CSV
+pub fn export_csv(command: &str, rows: &[ExportRow]) -> String {
+    let mut out =
+        String::from("server,address,command,exit_code,duration_ms,error,stdout,stderr\n");
+    for row in rows {
+        let fields = [
+            row.label.to_string(),
+            row.address.to_string(),
+            command.to_string(),
+            row.result
+                .exit_code
+                .map(|c| c.to_string())
+                .unwrap_or_default(),
+            row.result.duration.as_millis().to_string(),
+            row.result.error.clone().unwrap_or_default(),
+            row.result.stdout.clone(),
+            row.result.stderr.clone(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// 含逗号、引号或换行的字段加双引号
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
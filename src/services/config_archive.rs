@@ -0,0 +1,298 @@
+// 配置导入/导出服务
+// 把服务器、分组、快捷命令、快捷键与应用设置打包为一个加密归档（可选包含密码与私钥），
+// 使用 PBKDF2-HMAC-SHA256 从口令派生密钥，AES-256-GCM 加密
+
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::models::{AppSettings, ServerConfig, SnippetsConfig};
+use crate::services::storage;
+
+/// 归档文件头
+const MAGIC: &[u8; 8] = b"SM3CONF1";
+/// 归档格式版本
+const ARCHIVE_VERSION: u32 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const PBKDF2_ROUNDS: u32 = 100_000;
+/// 快捷键配置文件名（位于配置目录，存在时一并导出）
+const KEYBINDINGS_FILE: &str = "keybindings.json";
+
+/// 归档中的私钥文件
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ArchivedKey {
+    pub filename: String,
+    pub content: Vec<u8>,
+}
+
+/// 归档内容（加密前的明文）
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ConfigArchive {
+    pub version: u32,
+    pub created_at: String,
+    pub includes_secrets: bool,
+    pub servers: ServerConfig,
+    pub snippets: SnippetsConfig,
+    pub settings: AppSettings,
+    #[serde(default)]
+    pub keybindings: Option<serde_json::Value>,
+    #[serde(default)]
+    pub keys: Vec<ArchivedKey>,
+}
+
+/// 应用归档时写入哪些部分
+#[derive(Clone, Copy)]
+pub struct ArchiveScope {
+    /// 服务器与分组
+    pub servers: bool,
+    pub snippets: bool,
+    pub settings: bool,
+    pub keybindings: bool,
+}
+
+impl ArchiveScope {
+    pub const ALL: Self = Self {
+        servers: true,
+        snippets: true,
+        settings: true,
+        keybindings: true,
+    };
+}
+
+/// 导入结果摘要
+pub struct ImportSummary {
+    pub servers: usize,
+    pub groups: usize,
+    pub snippets: usize,
+    pub includes_secrets: bool,
+}
+
+impl ConfigArchive {
+    /// 读取本地当前配置；不含密码时清除服务器密码、私钥口令、代理密码与 WebDAV 密码；同步口令始终不包含
+    pub fn snapshot(include_secrets: bool) -> Result<Self> {
+        let mut servers = storage::load_servers()?;
+        let mut settings = storage::load_settings()?;
+        let snippets = storage::load_snippets()?;
+        let keybindings = load_keybindings()?;
+
+        let mut keys = Vec::new();
+        if include_secrets {
+            let keys_dir = storage::get_keys_dir()?;
+            for server in &servers.servers {
+                let Some(filename) = &server.private_key_filename else {
+                    continue;
+                };
+                if keys.iter().any(|k: &ArchivedKey| &k.filename == filename) {
+                    continue;
+                }
+                if let Ok(content) = fs::read(keys_dir.join(filename)) {
+                    keys.push(ArchivedKey {
+                        filename: filename.clone(),
+                        content,
+                    });
+                }
+            }
+        } else {
+            for server in &mut servers.servers {
+                server.password_encrypted = None;
+                server.key_passphrase_encrypted = None;
+                if let Some(proxy) = &mut server.proxy {
+                    proxy.password_encrypted = None;
+                }
+            }
+            settings.sync.webdav_password.clear();
+        }
+        // 同步口令只保存在本机，任何归档都不包含
+        settings.sync.sync_passphrase.clear();
+
+        Ok(Self {
+            version: ARCHIVE_VERSION,
+            created_at: Local::now().to_rfc3339(),
+            includes_secrets: include_secrets,
+            servers,
+            snippets,
+            settings,
+            keybindings,
+            keys,
+        })
+    }
+
+    /// 序列化并用口令加密
+    pub fn seal(&self, password: &str) -> Result<Vec<u8>> {
+        if password.is_empty() {
+            bail!("Password is required");
+        }
+        let plaintext = serde_json::to_vec(self).context("无法序列化配置归档")?;
+        encrypt(&plaintext, password)
+    }
+
+    /// 解密并解析归档
+    pub fn open(data: &[u8], password: &str) -> Result<Self> {
+        let plaintext = decrypt(data, password)?;
+        let archive: Self = serde_json::from_slice(&plaintext).context("无法解析配置归档")?;
+        if archive.version > ARCHIVE_VERSION {
+            bail!("Archive version {} is not supported", archive.version);
+        }
+        Ok(archive)
+    }
+
+    /// 把归档写入本地配置（写入前 storage 会自动备份原文件）
+    ///
+    /// 归档不含密码时保留本地同 ID 服务器已保存的密码
+    pub fn apply(mut self, scope: ArchiveScope) -> Result<ImportSummary> {
+        if self.includes_secrets {
+            let keys_dir = storage::get_keys_dir()?;
+            for key in &self.keys {
+                // 只接受普通文件名，避免写到密钥目录之外
+                if Path::new(&key.filename).file_name() != Some(OsStr::new(&key.filename)) {
+                    continue;
+                }
+                let key_path = keys_dir.join(&key.filename);
+                fs::write(&key_path, &key.content)
+                    .with_context(|| format!("无法写入密钥文件: {:?}", key_path))?;
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    let _ = fs::set_permissions(&key_path, fs::Permissions::from_mode(0o600));
+                }
+            }
+        } else {
+            let local_servers = storage::load_servers().unwrap_or_default();
+            for server in &mut self.servers.servers {
+                let Some(local) = local_servers.servers.iter().find(|s| s.id == server.id) else {
+                    continue;
+                };
+                server.password_encrypted = local.password_encrypted.clone();
+                server.key_passphrase_encrypted = local.key_passphrase_encrypted.clone();
+                if let (Some(proxy), Some(local_proxy)) = (&mut server.proxy, &local.proxy) {
+                    proxy.password_encrypted = local_proxy.password_encrypted.clone();
+                }
+            }
+            if let Ok(local_settings) = storage::load_settings() {
+                self.settings.sync.webdav_password = local_settings.sync.webdav_password;
+            }
+        }
+        if let Ok(local_settings) = storage::load_settings() {
+            self.settings.sync.sync_passphrase = local_settings.sync.sync_passphrase;
+        }
+
+        if scope.servers {
+            storage::save_servers(&self.servers)?;
+        }
+        if scope.snippets {
+            storage::save_snippets(&self.snippets)?;
+        }
+        if scope.settings {
+            storage::save_settings(&self.settings)?;
+        }
+        if let (true, Some(keybindings)) = (scope.keybindings, &self.keybindings) {
+            let path = storage::get_config_dir()?.join(KEYBINDINGS_FILE);
+            let content =
+                serde_json::to_string_pretty(keybindings).context("无法序列化快捷键配置")?;
+            fs::write(&path, content).context("无法写入快捷键配置文件")?;
+        }
+
+        Ok(ImportSummary {
+            servers: self.servers.servers.len(),
+            groups: self.servers.groups.len(),
+            snippets: self.snippets.commands.len(),
+            includes_secrets: self.includes_secrets,
+        })
+    }
+}
+
+/// 默认导出文件名
+pub fn default_file_name() -> String {
+    format!(
+        "shellmaster-{}.smconf",
+        Local::now().format("%Y%m%d-%H%M%S")
+    )
+}
+
+/// 导出当前配置到加密归档
+pub fn export_config(path: &Path, password: &str, include_secrets: bool) -> Result<()> {
+    let archive = ConfigArchive::snapshot(include_secrets)?;
+    fs::write(path, archive.seal(password)?)
+        .with_context(|| format!("无法写入配置归档: {:?}", path))?;
+    info!(
+        "[ConfigArchive] Exported {} servers to {:?} (secrets: {})",
+        archive.servers.servers.len(),
+        path,
+        include_secrets
+    );
+    Ok(())
+}
+
+/// 从加密归档导入配置，覆盖本地的服务器、快捷命令、快捷键与设置
+pub fn import_config(path: &Path, password: &str) -> Result<ImportSummary> {
+    let data = fs::read(path).with_context(|| format!("无法读取配置归档: {:?}", path))?;
+    let summary = ConfigArchive::open(&data, password)?.apply(ArchiveScope::ALL)?;
+    info!(
+        "[ConfigArchive] Imported {} servers from {:?}",
+        summary.servers, path
+    );
+    Ok(summary)
+}
+
+/// 读取快捷键配置（文件不存在时为 None）
+fn load_keybindings() -> Result<Option<serde_json::Value>> {
+    let path = storage::get_config_dir()?.join(KEYBINDINGS_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).context("无法读取快捷键配置文件")?;
+    Ok(Some(
+        serde_json::from_str(&content).context("无法解析快捷键配置文件")?,
+    ))
+}
+
+/// 从口令派生 256 位密钥
+fn derive_key(password: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+/// 加密：MAGIC | salt | nonce | 密文
+fn encrypt(plaintext: &[u8], password: &str) -> Result<Vec<u8>> {
+    // 盐与 nonce 均取自操作系统的安全随机数
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let key = derive_key(password, &salt);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| anyhow::anyhow!("Encryption failed"))?;
+
+    let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// 解密归档，口令错误或文件损坏时返回错误
+fn decrypt(data: &[u8], password: &str) -> Result<Vec<u8>> {
+    let header_len = MAGIC.len() + SALT_LEN + NONCE_LEN;
+    if data.len() < header_len || !data.starts_with(MAGIC) {
+        bail!("Not a ShellMaster config archive");
+    }
+    let salt = &data[MAGIC.len()..MAGIC.len() + SALT_LEN];
+    let nonce = &data[MAGIC.len() + SALT_LEN..header_len];
+    let key = derive_key(password, salt);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), &data[header_len..])
+        .map_err(|_| anyhow::anyhow!("Wrong password or corrupted archive"))
+}
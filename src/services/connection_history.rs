@@ -0,0 +1,121 @@
+// 连接历史：每次连接的结果写入磁盘，并在内存中缓存供主页与历史页读取
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tracing::error;
+
+use crate::models::history::{ConnectionHistory, ConnectionHistoryEntry, ConnectionHistoryFilter};
+use crate::services::storage;
+
+/// 最多保留的历史记录条数（按保留天数清理之外的上限）
+const MAX_ENTRIES: usize = 1000;
+
+/// 连接历史存储
+pub struct ConnectionHistoryStore {
+    /// 首次访问时从磁盘加载
+    cache: Mutex<Option<ConnectionHistory>>,
+}
+
+impl ConnectionHistoryStore {
+    /// 获取全局单例
+    pub fn global() -> &'static ConnectionHistoryStore {
+        static STORE: Lazy<ConnectionHistoryStore> = Lazy::new(|| ConnectionHistoryStore {
+            cache: Mutex::new(None),
+        });
+        &STORE
+    }
+
+    /// 在缓存上执行操作，`save` 为 true 时操作后写回磁盘
+    fn with_history<T>(&self, save: bool, f: impl FnOnce(&mut ConnectionHistory) -> T) -> T {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let history = cache.get_or_insert_with(|| {
+            let mut history = storage::load_connection_history().unwrap_or_default();
+            history.prune(retention_days());
+            history
+        });
+        let result = f(history);
+        if save {
+            if let Err(e) = storage::save_connection_history(history) {
+                error!("[History] Failed to save connection history: {}", e);
+            }
+        }
+        result
+    }
+
+    /// 记录一次连接，返回条目 ID
+    pub fn record(&self, entry: ConnectionHistoryEntry) -> String {
+        let id = entry.id.clone();
+        self.with_history(true, |history| {
+            history.entries.insert(0, entry);
+            history.entries.truncate(MAX_ENTRIES);
+            history.prune(retention_days());
+        });
+        id
+    }
+
+    /// 标记会话结束，记录持续时长
+    pub fn finish(&self, id: &str) {
+        self.with_history(true, |history| {
+            if let Some(entry) = history.entries.iter_mut().find(|e| e.id == id) {
+                entry.finish();
+            }
+        });
+    }
+
+    /// 按 ID 获取条目
+    pub fn get(&self, id: &str) -> Option<ConnectionHistoryEntry> {
+        self.with_history(false, |history| {
+            history.entries.iter().find(|e| e.id == id).cloned()
+        })
+    }
+
+    /// 按筛选条件与搜索词获取历史记录（最新的在前）
+    pub fn entries(
+        &self,
+        filter: ConnectionHistoryFilter,
+        query: &str,
+    ) -> Vec<ConnectionHistoryEntry> {
+        let query = query.trim().to_lowercase();
+        self.with_history(false, |history| {
+            history
+                .entries
+                .iter()
+                .filter(|e| filter.matches(e))
+                .filter(|e| {
+                    query.is_empty()
+                        || e.server_label.to_lowercase().contains(&query)
+                        || e.address().to_lowercase().contains(&query)
+                })
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// 最近连接过的服务器（每台只取最新一条）
+    pub fn recent(&self, limit: usize) -> Vec<ConnectionHistoryEntry> {
+        self.with_history(false, |history| {
+            let mut seen = HashSet::new();
+            history
+                .entries
+                .iter()
+                .filter(|e| seen.insert((e.host.clone(), e.port, e.username.clone())))
+                .take(limit)
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// 清空历史记录
+    pub fn clear(&self) {
+        self.with_history(true, |history| history.entries.clear());
+    }
+}
+
+/// 历史记录保留天数（沿用日志保留天数设置）
+fn retention_days() -> u32 {
+    storage::load_settings()
+        .map(|s| s.system.log_retention_days)
+        .unwrap_or(7)
+}
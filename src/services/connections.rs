@@ -0,0 +1,108 @@
+// 网络连接服务
+// 通过 SSH ExecChannel 执行 ss -tunap 获取监听端口和已建立的连接（非 root 用户只能看到自己进程的 PID）
+
+use tracing::debug;
+
+use crate::services::remote_exec::exec_command;
+use crate::ssh::session::SshSession;
+
+/// 列出 TCP/UDP 套接字及所属进程（-H 不输出表头；旧版本 ss 不支持 -H 时回退，解析时跳过表头）
+const CONNECTION_LIST_COMMAND: &str = "ss -tunapH 2>/dev/null || ss -tunap";
+
+/// 单个套接字的信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionEntry {
+    /// 协议（tcp / udp）
+    pub protocol: String,
+    /// 状态（LISTEN / UNCONN / ESTAB）
+    pub state: String,
+    /// 本地地址（含端口，如 0.0.0.0:22、[::]:80）
+    pub local: String,
+    /// 对端地址（含端口，监听套接字为 0.0.0.0:* 等）
+    pub peer: String,
+    /// 所属进程 PID（无权限查看时为 None）
+    pub pid: Option<u32>,
+    /// 所属进程名
+    pub process: String,
+}
+
+impl ConnectionEntry {
+    /// 是否为监听套接字（TCP LISTEN 或已绑定端口的 UDP）
+    pub fn is_listening(&self) -> bool {
+        matches!(self.state.as_str(), "LISTEN" | "UNCONN")
+    }
+
+    /// 本地端口
+    pub fn local_port(&self) -> &str {
+        self.local
+            .rsplit_once(':')
+            .map(|(_, port)| port)
+            .unwrap_or_default()
+    }
+}
+
+/// 获取监听端口和已建立的连接（监听在前，再按协议、本地端口排序）
+pub async fn list_connections(session: &SshSession) -> Result<Vec<ConnectionEntry>, String> {
+    let output = exec_command(session, CONNECTION_LIST_COMMAND).await?;
+    let mut connections = parse_socket_table(&output);
+    connections.sort_by(|a, b| {
+        let port = |c: &ConnectionEntry| c.local_port().parse::<u32>().unwrap_or(u32::MAX);
+        b.is_listening()
+            .cmp(&a.is_listening())
+            .then_with(|| a.protocol.cmp(&b.protocol))
+            .then_with(|| port(a).cmp(&port(b)))
+    });
+    debug!("[Connections] Fetched {} sockets", connections.len());
+    Ok(connections)
+}
+
+/// 在终端中查看本地端口相关套接字的命令
+pub fn inspect_command(port: &str) -> String {
+    format!("ss -tunap 'sport = :{}'\n", port.replace('\'', ""))
+}
+
+/// 解析 `ss -tunap` 的输出：Netid State Recv-Q Send-Q Local Peer Process
+///
+/// 只保留监听和已建立的套接字（TIME-WAIT 等过渡状态没有所属进程，意义不大）
+fn parse_socket_table(output: &str) -> Vec<ConnectionEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let protocol = fields.next()?;
+            if !matches!(protocol, "tcp" | "udp") {
+                // 表头（Netid ...）
+                return None;
+            }
+            let state = fields.next()?;
+            if !matches!(state, "LISTEN" | "UNCONN" | "ESTAB") {
+                return None;
+            }
+            let local = fields.nth(2)?.to_string();
+            let peer = fields.next()?.to_string();
+            let (process, pid) = parse_process_field(&fields.collect::<Vec<_>>().join(" "));
+            Some(ConnectionEntry {
+                protocol: protocol.to_string(),
+                state: state.to_string(),
+                local,
+                peer,
+                pid,
+                process,
+            })
+        })
+        .collect()
+}
+
+/// 解析进程列：`users:(("sshd",pid=812,fd=3),...)`，多个进程共享套接字时取第一个
+fn parse_process_field(field: &str) -> (String, Option<u32>) {
+    let Some(rest) = field.split_once("((\"").map(|(_, rest)| rest) else {
+        return (String::new(), None);
+    };
+    let name = rest.split('"').next().unwrap_or_default().to_string();
+    let pid = rest.split_once("pid=").and_then(|(_, rest)| {
+        rest.split(|c: char| !c.is_ascii_digit())
+            .next()
+            .and_then(|pid| pid.parse().ok())
+    });
+    (name, pid)
+}
@@ -0,0 +1,628 @@
+// cron 计划任务服务
+// 通过 SSH ExecChannel 读取当前用户的 crontab 与 /etc/cron.d（需要 sudo），解析计划表达式并计算下次运行时间，
+// 保存时先确认远端未被修改并备份原内容，再通过 `crontab -` 写回
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use tracing::{debug, info};
+
+use crate::services::remote_exec::{exec_command, exec_command_with_stdin};
+use crate::services::sftp::run_sudo;
+use crate::ssh::session::SshSession;
+
+/// 读取当前用户的 crontab
+const USER_CRONTAB_COMMAND: &str = "crontab -l";
+
+/// 读取 /etc/cron.d 下的全部文件（每个文件前输出一行 `### 路径` 作为分隔）
+const SYSTEM_CRONTAB_COMMAND: &str = "sh -c 'for f in /etc/cron.d/*; do [ -f \"$f\" ] && { echo \"### $f\"; cat \"$f\"; }; done; true'";
+
+/// 文件分隔行前缀
+const FILE_MARKER: &str = "### ";
+
+/// 计算下次运行时间时最多向后查找的天数（覆盖闰年的 2 月 29 日）
+const MAX_SEARCH_DAYS: u32 = 366 * 5;
+
+/// 单条计划任务
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronEntry {
+    /// 计划表达式（5 个字段或 @daily 等宏）
+    pub schedule: String,
+    pub command: String,
+    /// 是否启用（停用的任务以 # 注释保存）
+    pub enabled: bool,
+    /// 执行用户（仅 /etc/cron.d 中的任务有）
+    pub user: Option<String>,
+    /// 来源文件（仅 /etc/cron.d 中的任务有）
+    pub source: Option<String>,
+}
+
+impl CronEntry {
+    /// 写回 crontab 的文本行
+    fn to_line(&self) -> String {
+        format!(
+            "{}{} {}",
+            if self.enabled { "" } else { "#" },
+            self.schedule,
+            self.command
+        )
+    }
+}
+
+/// crontab 中的一行：计划任务，或原样保留的注释、空行、环境变量
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrontabLine {
+    Entry(CronEntry),
+    Raw(String),
+}
+
+/// 当前用户的 crontab
+#[derive(Debug, Clone, Default)]
+pub struct UserCrontab {
+    /// 读取到的原始文本（保存前用于确认远端未被修改）
+    pub original: String,
+    pub lines: Vec<CrontabLine>,
+}
+
+/// 读取 crontab 原始文本（用户还没有 crontab 时返回空文本）
+async fn read_user_crontab(session: &SshSession) -> Result<String, String> {
+    match exec_command(session, USER_CRONTAB_COMMAND).await {
+        Ok(text) => Ok(text),
+        Err(e) if e.to_lowercase().contains("no crontab") => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 获取当前用户的 crontab
+pub async fn list_user_crontab(session: &SshSession) -> Result<UserCrontab, String> {
+    let original = read_user_crontab(session).await?;
+    let lines = parse_crontab(&original);
+    debug!("[Cron] Fetched {} crontab lines", lines.len());
+    Ok(UserCrontab { original, lines })
+}
+
+/// 获取 /etc/cron.d 中的计划任务；`password` 为空字符串表示免密 sudo
+pub async fn list_system_crontabs(
+    session: &SshSession,
+    password: &str,
+) -> Result<Vec<CronEntry>, String> {
+    let output = run_sudo(session, SYSTEM_CRONTAB_COMMAND, password).await?;
+    let entries = parse_system_crontabs(&String::from_utf8_lossy(&output));
+    debug!("[Cron] Fetched {} system cron entries", entries.len());
+    Ok(entries)
+}
+
+/// 保存 crontab 失败的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrontabSaveError {
+    /// 远端内容与读取时不一致，需要重新读取
+    Modified,
+    /// 远程命令失败（错误信息来自远程）
+    Command(String),
+}
+
+impl From<String> for CrontabSaveError {
+    fn from(error: String) -> Self {
+        CrontabSaveError::Command(error)
+    }
+}
+
+/// 写回当前用户的 crontab，返回备份文件路径（原来没有 crontab 时为 None）
+///
+/// 远端内容与读取时不一致时拒绝保存，避免覆盖其他途径做的修改
+pub async fn save_user_crontab(
+    session: &SshSession,
+    original: &str,
+    content: &str,
+) -> Result<Option<String>, CrontabSaveError> {
+    let current = read_user_crontab(session).await?;
+    if current != original {
+        return Err(CrontabSaveError::Modified);
+    }
+
+    let backup = if current.is_empty() {
+        None
+    } else {
+        let path = format!(
+            "$HOME/.crontab.bak.{}",
+            Local::now().format("%Y%m%d-%H%M%S")
+        );
+        let output = exec_command(
+            session,
+            &format!("crontab -l > \"{0}\" && echo \"{0}\"", path),
+        )
+        .await?;
+        Some(output.trim().to_string())
+    };
+
+    exec_command_with_stdin(session, "crontab -", content.as_bytes()).await?;
+    info!("[Cron] Crontab saved (backup: {:?})", backup);
+    Ok(backup)
+}
+
+/// 把 crontab 行序列化为文本（cron 要求最后一行以换行结尾）
+pub fn serialize_crontab(lines: &[CrontabLine]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        match line {
+            CrontabLine::Entry(entry) => out.push_str(&entry.to_line()),
+            CrontabLine::Raw(text) => out.push_str(text),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// 解析用户 crontab：能识别为计划任务的行（包括以 # 注释掉的任务）解析为 Entry，其余原样保留
+pub fn parse_crontab(text: &str) -> Vec<CrontabLine> {
+    text.lines()
+        .map(|line| match parse_entry_line(line, false) {
+            Some(entry) => CrontabLine::Entry(entry),
+            None => CrontabLine::Raw(line.to_string()),
+        })
+        .collect()
+}
+
+/// 解析 /etc/cron.d 的输出（带执行用户字段，只保留启用的任务）
+fn parse_system_crontabs(output: &str) -> Vec<CronEntry> {
+    let mut source = None;
+    let mut entries = Vec::new();
+    for line in output.lines() {
+        if let Some(path) = line.strip_prefix(FILE_MARKER) {
+            source = Some(path.trim().to_string());
+            continue;
+        }
+        if let Some(mut entry) = parse_entry_line(line, true).filter(|e| e.enabled) {
+            entry.source = source.clone();
+            entries.push(entry);
+        }
+    }
+    entries
+}
+
+/// 解析一行计划任务；`with_user` 表示计划表达式后面带执行用户字段
+///
+/// 注释行只有在去掉 # 后是合法的计划任务时才视为停用的任务（避免把说明文字当成任务）
+fn parse_entry_line(line: &str, with_user: bool) -> Option<CronEntry> {
+    let trimmed = line.trim();
+    let (enabled, body) = match trimmed.strip_prefix('#') {
+        Some(rest) => (false, rest.trim_start()),
+        None => (true, trimmed),
+    };
+    if body.is_empty() {
+        return None;
+    }
+    // 环境变量赋值（如 MAILTO=root、PATH=...）
+    let first = body.split_whitespace().next()?;
+    if first.contains('=') {
+        return None;
+    }
+
+    let field_count = if first.starts_with('@') { 1 } else { 5 };
+    let mut rest = body;
+    let mut fields = Vec::with_capacity(field_count);
+    for _ in 0..field_count {
+        let (field, remaining) = split_field(rest)?;
+        fields.push(field);
+        rest = remaining;
+    }
+    let user = if with_user {
+        let (user, remaining) = split_field(rest)?;
+        rest = remaining;
+        Some(user.to_string())
+    } else {
+        None
+    };
+    let command = rest.trim();
+    if command.is_empty() {
+        return None;
+    }
+
+    let schedule = fields.join(" ");
+    if !enabled && CronSchedule::parse(&schedule).is_err() {
+        return None;
+    }
+    Some(CronEntry {
+        schedule,
+        command: command.to_string(),
+        enabled,
+        user,
+        source: None,
+    })
+}
+
+/// 取出第一个空白分隔的字段，返回字段和剩余文本
+fn split_field(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim_start();
+    if text.is_empty() {
+        return None;
+    }
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    Some((&text[..end], &text[end..]))
+}
+
+/// 解析后的计划表达式
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronSchedule {
+    /// 展开宏后的 5 个字段（@reboot 时为空）
+    pub fields: Vec<String>,
+    /// 是否为 @reboot（开机时运行一次）
+    pub reboot: bool,
+    minutes: u64,
+    hours: u32,
+    days: u32,
+    months: u16,
+    weekdays: u8,
+    /// 日期和星期字段是否都有限定（都限定时满足任一即可运行）
+    day_or_weekday: bool,
+}
+
+impl CronSchedule {
+    /// 解析计划表达式（5 个字段或 @hourly / @daily 等宏）
+    pub fn parse(schedule: &str) -> Result<Self, String> {
+        let schedule = schedule.trim();
+        let expanded = match schedule.to_lowercase().as_str() {
+            "@reboot" => {
+                return Ok(Self {
+                    fields: Vec::new(),
+                    reboot: true,
+                    minutes: 0,
+                    hours: 0,
+                    days: 0,
+                    months: 0,
+                    weekdays: 0,
+                    day_or_weekday: false,
+                })
+            }
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            s if s.starts_with('@') => return Err(format!("unknown macro {}", schedule)),
+            _ => schedule,
+        };
+
+        let fields: Vec<String> = expanded.split_whitespace().map(str::to_string).collect();
+        if fields.len() != 5 {
+            return Err(format!("expected 5 fields, got {}", fields.len()));
+        }
+        let minutes = parse_field(&fields[0], 0, 59, &[])?;
+        let hours = parse_field(&fields[1], 0, 23, &[])?;
+        let days = parse_field(&fields[2], 1, 31, &[])?;
+        let months = parse_field(&fields[3], 1, 12, &MONTH_NAMES)?;
+        // 星期允许 0-7（0 和 7 都是周日）
+        let mut weekdays = parse_field(&fields[4], 0, 7, &WEEKDAY_NAMES)?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & 0x7f;
+        }
+        let day_or_weekday = !fields[2].starts_with('*') && !fields[4].starts_with('*');
+
+        Ok(Self {
+            fields,
+            reboot: false,
+            minutes,
+            hours: hours as u32,
+            days: days as u32,
+            months: months as u16,
+            weekdays: weekdays as u8,
+            day_or_weekday,
+        })
+    }
+
+    /// 星期字段包含的日期（0 = 周日）
+    pub fn weekdays(&self) -> Vec<u32> {
+        (0..7).filter(|d| self.weekdays & (1 << d) != 0).collect()
+    }
+
+    /// 从 `from` 之后的接下来 `count` 次运行时间（@reboot 返回空）
+    pub fn next_runs(&self, from: DateTime<Local>, count: usize) -> Vec<DateTime<Local>> {
+        let mut runs = Vec::with_capacity(count);
+        if self.reboot {
+            return runs;
+        }
+        let mut cursor = from.naive_local();
+        while runs.len() < count {
+            let Some(next) = self.next_after(cursor) else {
+                break;
+            };
+            // 夏令时跳过的时间不存在，直接跳过
+            if let Some(time) = Local.from_local_datetime(&next).earliest() {
+                runs.push(time);
+            }
+            cursor = next;
+        }
+        runs
+    }
+
+    /// `after` 之后（不含当前分钟）第一次匹配的时间
+    fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let mut date = start.date();
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_date(date) {
+                let first_hour = if date == start.date() {
+                    start.hour()
+                } else {
+                    0
+                };
+                for hour in (first_hour..24).filter(|h| self.hours & (1 << h) != 0) {
+                    let first_minute = if date == start.date() && hour == start.hour() {
+                        start.minute()
+                    } else {
+                        0
+                    };
+                    if let Some(minute) =
+                        (first_minute..60).find(|m| self.minutes & (1u64 << m) != 0)
+                    {
+                        return date.and_hms_opt(hour, minute, 0);
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    /// 日期是否匹配月份、日期和星期字段
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if self.months & (1 << date.month()) == 0 {
+            return false;
+        }
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.day_or_weekday {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+}
+
+/// 月份名称（下标 + 1 为月份）
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// 星期名称（下标为星期，0 = 周日）
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// 解析单个字段为位图：支持 *、数字、名称、a-b 范围、/n 步长和逗号列表
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step in {}", field))?;
+                if step == 0 {
+                    return Err(format!("invalid step in {}", field));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (
+                parse_value(a, min, max, names)?,
+                parse_value(b, min, max, names)?,
+            )
+        } else {
+            let value = parse_value(range, min, max, names)?;
+            // 带步长的单个值表示从该值到最大值（如 5/15）
+            (value, if step > 1 { max } else { value })
+        };
+        if start > end {
+            return Err(format!("invalid range in {}", field));
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1u64 << value;
+        }
+    }
+    Ok(bits)
+}
+
+/// 解析字段中的单个值（数字或名称）
+fn parse_value(value: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, String> {
+    let lower = value.to_lowercase();
+    if let Some(index) = names.iter().position(|name| *name == lower) {
+        // 月份名称从 1 开始，星期名称从 0 开始
+        return Ok(index as u32 + min);
+    }
+    let number: u32 = value
+        .parse()
+        .map_err(|_| format!("invalid value {}", value))?;
+    if number < min || number > max {
+        return Err(format!("value {} out of range {}-{}", number, min, max));
+    }
+    Ok(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_parse_entry_line() {
+        let entry = parse_entry_line("*/5 * * * *   /usr/bin/backup.sh --full", false).unwrap();
+        assert_eq!(entry.schedule, "*/5 * * * *");
+        assert_eq!(entry.command, "/usr/bin/backup.sh --full");
+        assert!(entry.enabled);
+        assert_eq!(entry.user, None);
+
+        let entry = parse_entry_line("# 0 3 * * 1 echo hi", false).unwrap();
+        assert_eq!(entry.schedule, "0 3 * * 1");
+        assert_eq!(entry.command, "echo hi");
+        assert!(!entry.enabled);
+
+        let entry = parse_entry_line("@daily  run.sh", false).unwrap();
+        assert_eq!(entry.schedule, "@daily");
+        assert_eq!(entry.command, "run.sh");
+
+        let entry = parse_entry_line("30 2 * * * root /bin/cleanup", true).unwrap();
+        assert_eq!(entry.schedule, "30 2 * * *");
+        assert_eq!(entry.user.as_deref(), Some("root"));
+        assert_eq!(entry.command, "/bin/cleanup");
+    }
+
+    #[test]
+    fn test_parse_entry_line_rejects_non_entries() {
+        assert_eq!(parse_entry_line("", false), None);
+        assert_eq!(parse_entry_line("   #   ", false), None);
+        assert_eq!(parse_entry_line("MAILTO=root", false), None);
+        assert_eq!(parse_entry_line("PATH=/usr/bin:/bin", false), None);
+        // 字段不足或没有命令
+        assert_eq!(parse_entry_line("* * * *", false), None);
+        assert_eq!(parse_entry_line("0 3 * * *", false), None);
+        assert_eq!(parse_entry_line("0 3 * * * root", true), None);
+        // 说明文字不是停用的任务
+        assert_eq!(
+            parse_entry_line("# this job cleans up the tmp dir", false),
+            None
+        );
+        assert_eq!(parse_entry_line("# @sometimes run.sh", false), None);
+    }
+
+    #[test]
+    fn test_parse_crontab_round_trip() {
+        let text = "MAILTO=root\n# nightly jobs\n0 3 * * * backup\n#15 4 * * 0 weekly\n\n";
+        let lines = parse_crontab(text);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], CrontabLine::Raw("MAILTO=root".to_string()));
+        assert_eq!(lines[1], CrontabLine::Raw("# nightly jobs".to_string()));
+        assert!(matches!(&lines[2], CrontabLine::Entry(e) if e.enabled && e.command == "backup"));
+        assert!(matches!(&lines[3], CrontabLine::Entry(e) if !e.enabled && e.command == "weekly"));
+        assert_eq!(serialize_crontab(&lines), text);
+    }
+
+    #[test]
+    fn test_parse_system_crontabs() {
+        let output = "### /etc/cron.d/php\n\
+                      09,39 * * * * root [ -x /usr/lib/php/sessionclean ] && /usr/lib/php/sessionclean\n\
+                      #0 1 * * * root disabled\n\
+                      ### /etc/cron.d/empty\n\
+                      SHELL=/bin/sh\n";
+        let entries = parse_system_crontabs(output);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].schedule, "09,39 * * * *");
+        assert_eq!(entries[0].user.as_deref(), Some("root"));
+        assert_eq!(
+            entries[0].command,
+            "[ -x /usr/lib/php/sessionclean ] && /usr/lib/php/sessionclean"
+        );
+        assert_eq!(entries[0].source.as_deref(), Some("/etc/cron.d/php"));
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let schedule = CronSchedule::parse("@hourly").unwrap();
+        assert_eq!(schedule.fields, ["0", "*", "*", "*", "*"]);
+        assert!(!schedule.reboot);
+
+        let schedule = CronSchedule::parse("@reboot").unwrap();
+        assert!(schedule.reboot);
+        assert!(schedule.next_runs(Local::now(), 3).is_empty());
+
+        assert_eq!(CronSchedule::parse("0 0 * * 7").unwrap().weekdays(), [0]);
+        assert_eq!(
+            CronSchedule::parse("0 0 * * mon-fri").unwrap().weekdays(),
+            [1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            CronSchedule::parse("0 0 * jan,DEC 1,sat")
+                .unwrap()
+                .weekdays(),
+            [1, 6]
+        );
+    }
+
+    #[test]
+    fn test_parse_schedule_rejects_malformed() {
+        assert!(CronSchedule::parse("@often").is_err());
+        assert!(CronSchedule::parse("").is_err());
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("* * * * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* 24 * * *").is_err());
+        assert!(CronSchedule::parse("* * 0 * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("* * * foo *").is_err());
+        assert!(CronSchedule::parse("* * * * 8").is_err());
+    }
+
+    #[test]
+    fn test_next_after() {
+        let every_15 = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            every_15.next_after(at("2024-01-01 10:07:30")),
+            Some(at("2024-01-01 10:15:00"))
+        );
+        // 不包含当前分钟
+        assert_eq!(
+            every_15.next_after(at("2024-01-01 10:15:00")),
+            Some(at("2024-01-01 10:30:00"))
+        );
+
+        let daily = CronSchedule::parse("@daily").unwrap();
+        assert_eq!(
+            daily.next_after(at("2024-01-31 23:59:00")),
+            Some(at("2024-02-01 00:00:00"))
+        );
+
+        // 2024-09-06 为周五
+        let weekdays = CronSchedule::parse("30 9 * * 1-5").unwrap();
+        assert_eq!(
+            weekdays.next_after(at("2024-09-06 10:00:00")),
+            Some(at("2024-09-09 09:30:00"))
+        );
+
+        let leap_day = CronSchedule::parse("0 0 29 2 *").unwrap();
+        assert_eq!(
+            leap_day.next_after(at("2024-03-01 00:00:00")),
+            Some(at("2028-02-29 00:00:00"))
+        );
+
+        let never = CronSchedule::parse("0 0 30 2 *").unwrap();
+        assert_eq!(never.next_after(at("2024-01-01 00:00:00")), None);
+    }
+
+    #[test]
+    fn test_next_after_day_or_weekday() {
+        // 日期与星期都有限定时满足任一即可：每月 10 日或每周五
+        let schedule = CronSchedule::parse("0 12 10 * 5").unwrap();
+        let first = schedule.next_after(at("2024-09-01 00:00:00")).unwrap();
+        let second = schedule.next_after(first).unwrap();
+        let third = schedule.next_after(second).unwrap();
+        assert_eq!(first, at("2024-09-06 12:00:00"));
+        assert_eq!(second, at("2024-09-10 12:00:00"));
+        assert_eq!(third, at("2024-09-13 12:00:00"));
+    }
+
+    #[test]
+    fn test_next_runs() {
+        let schedule = CronSchedule::parse("0 */6 * * *").unwrap();
+        let from = Local
+            .from_local_datetime(&at("2024-06-01 05:00:00"))
+            .earliest()
+            .unwrap();
+        let runs: Vec<NaiveDateTime> = schedule
+            .next_runs(from, 3)
+            .iter()
+            .map(|run| run.naive_local())
+            .collect();
+        assert_eq!(
+            runs,
+            [
+                at("2024-06-01 06:00:00"),
+                at("2024-06-01 12:00:00"),
+                at("2024-06-01 18:00:00"),
+            ]
+        );
+    }
+}
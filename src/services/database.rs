@@ -0,0 +1,525 @@
+// SQLite 存储服务：各类配置以 JSON 文档的形式保存在 shellmaster.db 中
+//
+// - 读取走内存缓存，渲染路径上频繁调用 `storage::load_*` 不再反复读盘
+// - 写入在事务中完成，`update` 提供“读取-修改-写回”的原子操作
+// - 每次写入都会广播变更事件，`StorageService`（GPUI 全局状态）据此通知视图刷新
+// - 文档带模式版本（见 services/schema.rs），启动时执行迁移并检查能否解析，损坏的文档交给用户恢复
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{bail, Context, Result};
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::StreamExt;
+use gpui::{App, Global};
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{error, info, warn};
+
+use crate::services::{schema, storage};
+
+/// 数据库文件名（位于配置目录）
+const DATABASE_FILE: &str = "shellmaster.db";
+
+/// 存储的文档类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StorageKey {
+    Servers,
+    Settings,
+    Snippets,
+    KnownHosts,
+    TransferHistory,
+    ConnectionHistory,
+    SftpBookmarks,
+    Workspaces,
+    WindowState,
+    Schedules,
+    JobHistory,
+}
+
+impl StorageKey {
+    pub const ALL: [StorageKey; 11] = [
+        StorageKey::Servers,
+        StorageKey::Settings,
+        StorageKey::Snippets,
+        StorageKey::KnownHosts,
+        StorageKey::TransferHistory,
+        StorageKey::ConnectionHistory,
+        StorageKey::SftpBookmarks,
+        StorageKey::Workspaces,
+        StorageKey::WindowState,
+        StorageKey::Schedules,
+        StorageKey::JobHistory,
+    ];
+
+    /// 数据库中的文档键
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKey::Servers => "servers",
+            StorageKey::Settings => "settings",
+            StorageKey::Snippets => "snippets",
+            StorageKey::KnownHosts => "known_hosts",
+            StorageKey::TransferHistory => "transfer_history",
+            StorageKey::ConnectionHistory => "connection_history",
+            StorageKey::SftpBookmarks => "sftp_bookmarks",
+            StorageKey::Workspaces => "workspaces",
+            StorageKey::WindowState => "window_state",
+            StorageKey::Schedules => "schedules",
+            StorageKey::JobHistory => "job_history",
+        }
+    }
+
+    /// 迁移前使用的 JSON 文件名
+    pub fn legacy_file(&self) -> &'static str {
+        match self {
+            StorageKey::Servers => "servers.json",
+            StorageKey::Settings => "settings.json",
+            StorageKey::Snippets => "snippets.json",
+            StorageKey::KnownHosts => "known_hosts.json",
+            StorageKey::TransferHistory => "transfer_history.json",
+            StorageKey::ConnectionHistory => "connection_history.json",
+            StorageKey::SftpBookmarks => "sftp_bookmarks.json",
+            StorageKey::Workspaces => "workspaces.json",
+            StorageKey::WindowState => "window_state.json",
+            StorageKey::Schedules => "schedules.json",
+            StorageKey::JobHistory => "job_history.json",
+        }
+    }
+
+    /// 配置类文档在覆盖前备份旧版本；历史记录与窗口状态不备份
+    pub fn backup_on_write(&self) -> bool {
+        !matches!(
+            self,
+            StorageKey::TransferHistory
+                | StorageKey::ConnectionHistory
+                | StorageKey::WindowState
+                | StorageKey::JobHistory
+        )
+    }
+}
+
+/// 缓存的已解析文档
+type CachedDocument = Arc<dyn Any + Send + Sync>;
+
+/// SQLite 数据库
+pub struct Database {
+    conn: Mutex<Connection>,
+    cache: Mutex<HashMap<StorageKey, CachedDocument>>,
+    subscribers: Mutex<Vec<UnboundedSender<StorageKey>>>,
+    /// 启动时无法解析的文档及错误信息（等待用户恢复）
+    corrupted: Mutex<HashMap<StorageKey, String>>,
+    /// 数据库文件无法打开时的错误；此时使用只读的内存数据库，拒绝一切写入
+    open_error: Option<String>,
+}
+
+impl Database {
+    /// 获取全局数据库（首次访问时打开并迁移旧的 JSON 文件）
+    ///
+    /// 打开失败时退回到只读的内存数据库（导入旧的 JSON 文件供查看），保证应用仍可启动；
+    /// 错误通过恢复对话框告知用户，写入一律返回错误，不会让用户误以为修改已保存
+    pub fn global() -> &'static Database {
+        static DATABASE: Lazy<Database> = Lazy::new(|| {
+            Database::open().unwrap_or_else(|e| {
+                error!(
+                    "[Database] Failed to open database, running read-only: {:#}",
+                    e
+                );
+                let mut db = Database::with_connection(
+                    Connection::open_in_memory().expect("无法创建内存数据库"),
+                )
+                .expect("无法初始化内存数据库");
+                if let Err(e) = db.migrate_legacy_files() {
+                    warn!("[Database] Failed to load legacy files: {:#}", e);
+                }
+                db.open_error = Some(format!("{:#}", e));
+                db
+            })
+        });
+        &DATABASE
+    }
+
+    /// 数据库文件无法打开时的错误（本次运行为只读）
+    pub fn open_error(&self) -> Option<String> {
+        self.open_error.clone()
+    }
+
+    /// 数据库文件未能打开时拒绝写入
+    fn ensure_writable(&self) -> Result<()> {
+        match &self.open_error {
+            Some(e) => bail!("配置数据库未能打开，修改无法保存: {}", e),
+            None => Ok(()),
+        }
+    }
+
+    /// 打开配置目录下的数据库文件
+    fn open() -> Result<Self> {
+        let path = storage::get_config_dir()?.join(DATABASE_FILE);
+        let conn = Connection::open(&path).with_context(|| format!("无法打开数据库 {:?}", path))?;
+        let db = Self::with_connection(conn)?;
+        db.migrate_legacy_files()?;
+        db.check_documents();
+        Ok(db)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA synchronous = NORMAL;
+             CREATE TABLE IF NOT EXISTS documents (
+                 key TEXT PRIMARY KEY,
+                 value TEXT NOT NULL,
+                 revision INTEGER NOT NULL DEFAULT 1,
+                 updated_at TEXT NOT NULL
+             );",
+        )
+        .context("无法初始化数据库表")?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            cache: Mutex::new(HashMap::new()),
+            subscribers: Mutex::new(Vec::new()),
+            corrupted: Mutex::new(HashMap::new()),
+            open_error: None,
+        })
+    }
+
+    /// 把尚未导入的 JSON 配置文件导入数据库（原文件保留，便于回退旧版本）
+    fn migrate_legacy_files(&self) -> Result<()> {
+        let config_dir = storage::get_config_dir()?;
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction()?;
+        for key in StorageKey::ALL {
+            let exists: bool = tx
+                .query_row(
+                    "SELECT 1 FROM documents WHERE key = ?1",
+                    params![key.as_str()],
+                    |_| Ok(true),
+                )
+                .optional()?
+                .unwrap_or(false);
+            let path = config_dir.join(key.legacy_file());
+            if exists || !path.exists() {
+                continue;
+            }
+            // 无法解析的文件同样导入，随后的检查会把它交给恢复流程
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("无法读取旧配置文件 {:?}", path))?;
+            tx.execute(
+                "INSERT INTO documents (key, value, updated_at) VALUES (?1, ?2, ?3)",
+                params![key.as_str(), content, chrono::Local::now().to_rfc3339()],
+            )?;
+            info!("[Database] Imported {:?} into the database", path);
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 对每个文档执行未完成的迁移并检查能否解析
+    ///
+    /// 无法解析的文档先另存一份到备份目录，再记录下来等待用户从备份恢复或重置，
+    /// 不会被静默替换为默认值
+    fn check_documents(&self) {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        for key in StorageKey::ALL {
+            let result = (|| -> Result<()> {
+                let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+                let Some(content) = read_content(&tx, key)? else {
+                    return Ok(());
+                };
+                match schema::parse_document(key, &content) {
+                    Ok((value, migrated)) => {
+                        if migrated {
+                            write_value(&tx, key, value)?;
+                        }
+                    }
+                    Err(e) => {
+                        error!("[Database] {} is corrupted: {:#}", key.as_str(), e);
+                        match storage::backup_corrupted_document(key.as_str(), &content) {
+                            Ok(path) => info!("[Database] Saved corrupted copy to {:?}", path),
+                            Err(e) => warn!("[Database] Failed to save corrupted copy: {}", e),
+                        }
+                        self.corrupted
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .insert(key, format!("{:#}", e));
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            })();
+            if let Err(e) = result {
+                error!("[Database] Failed to check {}: {:#}", key.as_str(), e);
+            }
+        }
+    }
+
+    /// 启动时无法解析的文档
+    pub fn corrupted(&self) -> Vec<(StorageKey, String)> {
+        let corrupted = self.corrupted.lock().unwrap_or_else(|e| e.into_inner());
+        StorageKey::ALL
+            .iter()
+            .filter_map(|key| corrupted.get(key).map(|e| (*key, e.clone())))
+            .collect()
+    }
+
+    /// 用备份内容替换文档（备份会经过迁移与校验）
+    pub fn restore(&self, key: StorageKey, content: &str) -> Result<()> {
+        self.ensure_writable()?;
+        let (value, _) = schema::parse_document(key, content)?;
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        write_value(&tx, key, value)?;
+        tx.commit()?;
+        drop(conn);
+        self.invalidate(key);
+        self.notify(key);
+        Ok(())
+    }
+
+    /// 删除文档，之后读取得到默认值
+    pub fn reset(&self, key: StorageKey) -> Result<()> {
+        self.ensure_writable()?;
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.execute(
+            "DELETE FROM documents WHERE key = ?1",
+            params![key.as_str()],
+        )
+        .with_context(|| format!("无法重置 {}", key.as_str()))?;
+        drop(conn);
+        self.invalidate(key);
+        self.notify(key);
+        Ok(())
+    }
+
+    /// 读取文档，不存在时返回默认值
+    pub fn load<T>(&self, key: StorageKey) -> Result<T>
+    where
+        T: DeserializeOwned + Default + Clone + Send + Sync + 'static,
+    {
+        if let Some(value) = self.cached::<T>(key) {
+            return Ok(value);
+        }
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let value = read_document::<T>(&conn, key)?.unwrap_or_default();
+        drop(conn);
+        self.set_cache(key, value.clone());
+        Ok(value)
+    }
+
+    /// 写入文档
+    pub fn save<T>(&self, key: StorageKey, value: &T) -> Result<()>
+    where
+        T: Serialize + Clone + Send + Sync + 'static,
+    {
+        self.ensure_writable()?;
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        write_document(&tx, key, value)?;
+        tx.commit()?;
+        drop(conn);
+        self.set_cache(key, value.clone());
+        self.resolved(key);
+        self.notify(key);
+        Ok(())
+    }
+
+    /// 在写事务中读取、修改并写回文档；`f` 返回错误时不写入
+    pub fn update<T, R>(&self, key: StorageKey, f: impl FnOnce(&mut T) -> Result<R>) -> Result<R>
+    where
+        T: Serialize + DeserializeOwned + Default + Clone + Send + Sync + 'static,
+    {
+        self.ensure_writable()?;
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut value = read_document::<T>(&tx, key)?.unwrap_or_default();
+        let result = f(&mut value)?;
+        write_document(&tx, key, &value)?;
+        tx.commit()?;
+        drop(conn);
+        self.set_cache(key, value);
+        self.resolved(key);
+        self.notify(key);
+        Ok(result)
+    }
+
+    /// 文档的修订号（每次写入加一，不存在时为 0）
+    pub fn revision(&self, key: StorageKey) -> u64 {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        conn.query_row(
+            "SELECT revision FROM documents WHERE key = ?1",
+            params![key.as_str()],
+            |row| row.get::<_, i64>(0),
+        )
+        .optional()
+        .ok()
+        .flatten()
+        .map(|r| r as u64)
+        .unwrap_or(0)
+    }
+
+    /// 订阅变更事件
+    pub fn subscribe(&self) -> UnboundedReceiver<StorageKey> {
+        let (tx, rx) = unbounded();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(tx);
+        rx
+    }
+
+    fn notify(&self, key: StorageKey) {
+        let mut subscribers = self.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+        subscribers.retain(|tx| tx.unbounded_send(key).is_ok());
+    }
+
+    /// 文档被重新写入后不再视为损坏
+    fn resolved(&self, key: StorageKey) {
+        self.corrupted
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key);
+    }
+
+    fn invalidate(&self, key: StorageKey) {
+        self.resolved(key);
+        self.cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key);
+    }
+
+    fn cached<T: Clone + 'static>(&self, key: StorageKey) -> Option<T> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.get(&key)?.downcast_ref::<T>().cloned()
+    }
+
+    fn set_cache<T: Send + Sync + 'static>(&self, key: StorageKey, value: T) {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.insert(key, Arc::new(value));
+    }
+}
+
+/// 读取文档的原始内容
+fn read_content(conn: &Connection, key: StorageKey) -> Result<Option<String>> {
+    conn.query_row(
+        "SELECT value FROM documents WHERE key = ?1",
+        params![key.as_str()],
+        |row| row.get(0),
+    )
+    .optional()
+    .with_context(|| format!("无法读取 {}", key.as_str()))
+}
+
+/// 读取并解析文档（先执行未完成的迁移）
+fn read_document<T: DeserializeOwned>(conn: &Connection, key: StorageKey) -> Result<Option<T>> {
+    read_content(conn, key)?
+        .map(|content| {
+            let mut value: Value = serde_json::from_str(&content)
+                .with_context(|| format!("无法解析 {}", key.as_str()))?;
+            schema::migrate(key, &mut value)?;
+            serde_json::from_value(value).with_context(|| format!("无法解析 {}", key.as_str()))
+        })
+        .transpose()
+}
+
+/// 写入文档
+fn write_document<T: Serialize>(conn: &Connection, key: StorageKey, value: &T) -> Result<()> {
+    let value =
+        serde_json::to_value(value).with_context(|| format!("无法序列化 {}", key.as_str()))?;
+    write_value(conn, key, value)
+}
+
+/// 记录模式版本后写入文档；内容未变化时跳过，配置类文档先把旧版本备份到 backups 目录
+fn write_value(conn: &Connection, key: StorageKey, mut value: Value) -> Result<()> {
+    schema::stamp(key, &mut value);
+    let content = serde_json::to_string_pretty(&value)
+        .with_context(|| format!("无法序列化 {}", key.as_str()))?;
+    let previous = read_content(conn, key)?;
+    if previous.as_deref() == Some(content.as_str()) {
+        return Ok(());
+    }
+    let backup =
+        previous.filter(|p| key.backup_on_write() && !only_last_connected_changed(key, p, &value));
+    if let Some(previous) = backup {
+        if let Err(e) = storage::backup_document(key.as_str(), &previous) {
+            warn!("无法备份 {}: {}", key.as_str(), e);
+        }
+    }
+    conn.execute(
+        "INSERT INTO documents (key, value, updated_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT(key) DO UPDATE SET
+             value = excluded.value,
+             revision = revision + 1,
+             updated_at = excluded.updated_at",
+        params![key.as_str(), content, chrono::Local::now().to_rfc3339()],
+    )
+    .with_context(|| format!("无法写入 {}", key.as_str()))?;
+    Ok(())
+}
+
+/// 服务器配置只有最后连接时间变化时不备份，避免每次连接都挤掉一份有意义的备份
+fn only_last_connected_changed(key: StorageKey, previous: &str, current: &Value) -> bool {
+    if key != StorageKey::Servers {
+        return false;
+    }
+    let Ok(mut previous) = serde_json::from_str::<Value>(previous) else {
+        return false;
+    };
+    let mut current = current.clone();
+    for value in [&mut previous, &mut current] {
+        if let Some(servers) = value.get_mut("servers").and_then(Value::as_array_mut) {
+            for server in servers.iter_mut().filter_map(Value::as_object_mut) {
+                server.remove("last_connected_at");
+            }
+        }
+    }
+    previous == current
+}
+
+/// 存储服务（GPUI 全局状态）：记录各文档的修订号，写入后通知观察者
+///
+/// 视图通过 `cx.observe_global::<StorageService>` 订阅，比较 `revision` 判断需要刷新的数据
+#[derive(Default)]
+pub struct StorageService {
+    revisions: HashMap<StorageKey, u64>,
+}
+
+impl Global for StorageService {}
+
+impl StorageService {
+    /// 注册全局状态并开始转发数据库变更事件
+    pub fn init(cx: &mut App) {
+        let db = Database::global();
+        let revisions = StorageKey::ALL
+            .iter()
+            .map(|key| (*key, db.revision(*key)))
+            .collect();
+        cx.set_global(Self { revisions });
+
+        let mut events = db.subscribe();
+        cx.spawn(async move |cx| {
+            while let Some(key) = events.next().await {
+                let revision = Database::global().revision(key);
+                let result = cx.update(|cx| {
+                    cx.update_global::<StorageService, _>(|service, _| {
+                        service.revisions.insert(key, revision);
+                    });
+                    // 设置等配置在渲染时读取，直接重绘窗口即可生效
+                    cx.refresh_windows();
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// 文档的修订号
+    pub fn revision(&self, key: StorageKey) -> u64 {
+        self.revisions.get(&key).copied().unwrap_or(0)
+    }
+}
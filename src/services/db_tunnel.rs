@@ -0,0 +1,87 @@
+// 数据库隧道：常见数据库的默认端口、经本地转发后的连接串，以及按设置中的命令启动本地客户端
+
+use tracing::info;
+
+use crate::models::settings::DbClientCommands;
+
+/// 支持快速建立隧道的数据库
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatabaseKind {
+    MySql,
+    PostgreSql,
+    Redis,
+    MongoDb,
+}
+
+impl DatabaseKind {
+    pub const ALL: [DatabaseKind; 4] = [
+        DatabaseKind::MySql,
+        DatabaseKind::PostgreSql,
+        DatabaseKind::Redis,
+        DatabaseKind::MongoDb,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DatabaseKind::MySql => "MySQL",
+            DatabaseKind::PostgreSql => "PostgreSQL",
+            DatabaseKind::Redis => "Redis",
+            DatabaseKind::MongoDb => "MongoDB",
+        }
+    }
+
+    /// 服务器上的默认监听端口
+    pub fn default_port(&self) -> u16 {
+        match self {
+            DatabaseKind::MySql => 3306,
+            DatabaseKind::PostgreSql => 5432,
+            DatabaseKind::Redis => 6379,
+            DatabaseKind::MongoDb => 27017,
+        }
+    }
+
+    /// 通过本地端口连接的连接串（用户名取各数据库的默认管理员）
+    pub fn connection_string(&self, local_port: u16) -> String {
+        match self {
+            DatabaseKind::MySql => format!("mysql://root@127.0.0.1:{}/", local_port),
+            DatabaseKind::PostgreSql => {
+                format!("postgresql://postgres@127.0.0.1:{}/postgres", local_port)
+            }
+            DatabaseKind::Redis => format!("redis://127.0.0.1:{}", local_port),
+            DatabaseKind::MongoDb => format!("mongodb://127.0.0.1:{}", local_port),
+        }
+    }
+
+    /// 设置中为该数据库配置的客户端命令（为空表示未配置）
+    pub fn client_command<'a>(&self, commands: &'a DbClientCommands) -> &'a str {
+        match self {
+            DatabaseKind::MySql => &commands.mysql,
+            DatabaseKind::PostgreSql => &commands.postgresql,
+            DatabaseKind::Redis => &commands.redis,
+            DatabaseKind::MongoDb => &commands.mongodb,
+        }
+    }
+}
+
+/// 启动本地数据库客户端
+///
+/// 命令中的 `{host}`、`{port}`、`{url}` 分别替换为本地地址、本地端口和连接串，通过系统 shell 执行
+pub fn launch_db_client(command: &str, kind: DatabaseKind, local_port: u16) -> anyhow::Result<()> {
+    use std::process::Command;
+
+    let command = command
+        .replace("{host}", "127.0.0.1")
+        .replace("{port}", &local_port.to_string())
+        .replace("{url}", &kind.connection_string(local_port));
+    info!("[DbTunnel] Launching client: {}", command);
+
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("cmd").args(["/C", &command]).spawn()?;
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        Command::new("sh").arg("-c").arg(&command).spawn()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// Docker 容器管理服务
+// 通过 SSH ExecChannel 执行 docker CLI（--format '{{json .}}' 输出 JSON Lines）获取容器与镜像列表，并执行启停/删除操作
+
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::ssh::session::SshSession;
+
+/// 检测 docker CLI 是否可用且能连接到 daemon
+const DOCKER_DETECT_COMMAND: &str =
+    "command -v docker >/dev/null 2>&1 && docker version --format '{{.Server.Version}}'";
+
+/// 列出全部容器（包括已停止的）
+const CONTAINER_LIST_COMMAND: &str = "docker ps -a --no-trunc --format '{{json .}}'";
+
+/// 列出镜像
+const IMAGE_LIST_COMMAND: &str = "docker images --format '{{json .}}'";
+
+/// 单个容器的信息
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DockerContainer {
+    #[serde(rename = "ID")]
+    pub id: String,
+    /// 容器名（多个名称以逗号分隔）
+    #[serde(rename = "Names")]
+    pub names: String,
+    #[serde(rename = "Image")]
+    pub image: String,
+    /// 运行状态（running / exited / paused 等，旧版本 docker 可能没有该字段）
+    #[serde(rename = "State", default)]
+    pub state: String,
+    /// 状态描述（如 "Up 2 hours"）
+    #[serde(rename = "Status", default)]
+    pub status: String,
+    #[serde(rename = "Ports", default)]
+    pub ports: String,
+}
+
+impl DockerContainer {
+    /// 短 ID（前 12 位）
+    pub fn short_id(&self) -> &str {
+        &self.id[..self.id.len().min(12)]
+    }
+
+    /// 显示名称（第一个容器名）
+    pub fn name(&self) -> &str {
+        self.names.split(',').next().unwrap_or(&self.names)
+    }
+
+    /// 容器是否正在运行
+    pub fn is_running(&self) -> bool {
+        if self.state.is_empty() {
+            self.status.starts_with("Up")
+        } else {
+            self.state == "running"
+        }
+    }
+}
+
+/// 单个镜像的信息
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DockerImage {
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Repository")]
+    pub repository: String,
+    #[serde(rename = "Tag")]
+    pub tag: String,
+    #[serde(rename = "Size", default)]
+    pub size: String,
+    #[serde(rename = "CreatedSince", default)]
+    pub created_since: String,
+}
+
+impl DockerImage {
+    /// 镜像引用（repository:tag，悬空镜像使用 ID）
+    pub fn reference(&self) -> String {
+        if self.repository == "<none>" || self.tag == "<none>" {
+            self.id.clone()
+        } else {
+            format!("{}:{}", self.repository, self.tag)
+        }
+    }
+}
+
+/// 容器与镜像列表
+#[derive(Debug, Clone, Default)]
+pub struct DockerSnapshot {
+    pub containers: Vec<DockerContainer>,
+    pub images: Vec<DockerImage>,
+}
+
+/// 容器操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerAction {
+    Start,
+    Stop,
+    Restart,
+    Remove,
+}
+
+impl ContainerAction {
+    /// docker 子命令
+    fn command(self) -> &'static str {
+        match self {
+            ContainerAction::Start => "start",
+            ContainerAction::Stop => "stop",
+            ContainerAction::Restart => "restart",
+            ContainerAction::Remove => "rm",
+        }
+    }
+}
+
+/// 检测远程主机上的 docker，返回 daemon 版本；未安装或无权访问 daemon 时返回错误
+pub async fn detect_docker(session: &SshSession) -> Result<String, String> {
+    let version = exec_command(session, DOCKER_DETECT_COMMAND).await?;
+    let version = version.trim().to_string();
+    info!("[Docker] Detected docker {}", version);
+    Ok(version)
+}
+
+/// 获取容器与镜像列表
+pub async fn list_docker(session: &SshSession) -> Result<DockerSnapshot, String> {
+    let containers = parse_json_lines(&exec_command(session, CONTAINER_LIST_COMMAND).await?);
+    let images = parse_json_lines(&exec_command(session, IMAGE_LIST_COMMAND).await?);
+    debug!(
+        "[Docker] Fetched {} containers, {} images",
+        containers.len(),
+        images.len()
+    );
+    Ok(DockerSnapshot { containers, images })
+}
+
+/// 对容器执行启动/停止/重启/删除
+pub async fn container_action(
+    session: &SshSession,
+    id: &str,
+    action: ContainerAction,
+) -> Result<(), String> {
+    info!("[Docker] docker {} {}", action.command(), id);
+    exec_command(
+        session,
+        &format!("docker {} {}", action.command(), shell_quote(id)),
+    )
+    .await
+    .map(|_| ())
+}
+
+/// 删除镜像
+pub async fn remove_image(session: &SshSession, id: &str) -> Result<(), String> {
+    info!("[Docker] docker rmi {}", id);
+    exec_command(session, &format!("docker rmi {}", shell_quote(id)))
+        .await
+        .map(|_| ())
+}
+
+/// 在终端中持续输出容器日志的命令
+pub fn logs_command(id: &str) -> String {
+    format!("docker logs -f --tail 200 {}\n", shell_quote(id))
+}
+
+/// 在终端中进入容器的命令（优先 bash，没有时使用 sh）
+pub fn exec_shell_command(id: &str) -> String {
+    let id = shell_quote(id);
+    format!(
+        "docker exec -it {id} sh -c 'command -v bash >/dev/null 2>&1 && exec bash || exec sh'\n"
+    )
+}
+
+/// 解析 JSON Lines 输出，跳过无法解析的行
+fn parse_json_lines<T: for<'de> Deserialize<'de>>(output: &str) -> Vec<T> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                warn!("[Docker] Failed to parse line: {} ({})", line, e);
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,509 @@
+// 防火墙规则服务
+// 通过 sudo 检测远程使用的防火墙（依次尝试 ufw、firewalld、iptables）并读取规则：
+// `ufw status numbered` / `firewall-cmd --list-all` / `iptables -S`，解析为统一的规则表；
+// 另提供放行/拒绝单个端口的快捷操作
+
+use tracing::{debug, info};
+
+use crate::services::remote_exec::shell_quote;
+use crate::services::sftp::run_sudo;
+use crate::ssh::session::SshSession;
+
+/// 检测防火墙并输出规则（第一行为 `### 后端名`）
+const LIST_COMMAND: &str = "sh -c 'if command -v ufw >/dev/null 2>&1; then echo \"### ufw\"; ufw status numbered; elif command -v firewall-cmd >/dev/null 2>&1; then echo \"### firewalld\"; firewall-cmd --list-all; elif command -v iptables >/dev/null 2>&1; then echo \"### iptables\"; iptables -S; else echo \"### none\"; fi'";
+
+/// 后端标记前缀
+const BACKEND_MARKER: &str = "### ";
+
+/// 防火墙后端
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirewallBackend {
+    Ufw,
+    Firewalld,
+    Iptables,
+}
+
+impl FirewallBackend {
+    pub fn label(self) -> &'static str {
+        match self {
+            FirewallBackend::Ufw => "ufw",
+            FirewallBackend::Firewalld => "firewalld",
+            FirewallBackend::Iptables => "iptables",
+        }
+    }
+}
+
+/// 一条防火墙规则
+#[derive(Debug, Clone, PartialEq)]
+pub struct FirewallRule {
+    /// 规则序号（ufw 的编号，iptables 为链内序号；firewalld 没有序号）
+    pub index: Option<u32>,
+    /// 动作（ALLOW / DENY / REJECT / ACCEPT / DROP 等，统一为大写）
+    pub action: String,
+    /// 目标（端口、服务名或规则说明）
+    pub target: String,
+    /// 来源地址（为空表示任意来源）
+    pub source: String,
+    /// 方向（ufw 的 IN/OUT）或链名（iptables）或区域（firewalld）
+    pub direction: String,
+    /// 原始文本，用于搜索和提示
+    pub raw: String,
+}
+
+impl FirewallRule {
+    /// 是否为放行规则
+    pub fn is_allow(&self) -> bool {
+        matches!(self.action.as_str(), "ALLOW" | "ACCEPT")
+    }
+
+    /// 是否匹配搜索关键字（不区分大小写）
+    pub fn matches(&self, query: &str) -> bool {
+        query.is_empty() || self.raw.to_lowercase().contains(&query.to_lowercase())
+    }
+}
+
+/// 读取到的防火墙状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct FirewallStatus {
+    /// 使用的后端（None 表示未找到支持的防火墙）
+    pub backend: Option<FirewallBackend>,
+    /// 状态说明（如 ufw 的 `Status: active`、firewalld 的区域）
+    pub summary: String,
+    pub rules: Vec<FirewallRule>,
+}
+
+/// 端口规则的协议
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortProtocol {
+    Tcp,
+    Udp,
+    /// 不限协议（firewalld 和 iptables 需要协议时按 TCP 处理）
+    Any,
+}
+
+impl PortProtocol {
+    pub fn label(self) -> &'static str {
+        match self {
+            PortProtocol::Tcp => "TCP",
+            PortProtocol::Udp => "UDP",
+            PortProtocol::Any => "Any",
+        }
+    }
+
+    fn name(self) -> Option<&'static str> {
+        match self {
+            PortProtocol::Tcp => Some("tcp"),
+            PortProtocol::Udp => Some("udp"),
+            PortProtocol::Any => None,
+        }
+    }
+}
+
+/// 放行或拒绝端口的快捷规则
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortRule {
+    pub allow: bool,
+    pub port: u16,
+    pub protocol: PortProtocol,
+}
+
+impl PortRule {
+    /// 端口说明，如 `8080/tcp`
+    pub fn describe(&self) -> String {
+        match self.protocol.name() {
+            Some(protocol) => format!("{}/{}", self.port, protocol),
+            None => self.port.to_string(),
+        }
+    }
+
+    /// 在指定后端上执行的命令（不含 sudo）
+    pub fn command(&self, backend: FirewallBackend) -> String {
+        let protocol = self.protocol.name().unwrap_or("tcp");
+        match backend {
+            FirewallBackend::Ufw => format!(
+                "ufw {} {}",
+                if self.allow { "allow" } else { "deny" },
+                self.describe()
+            ),
+            FirewallBackend::Firewalld => {
+                let change = if self.allow {
+                    format!("--add-port={}/{}", self.port, protocol)
+                } else {
+                    format!(
+                        "--add-rich-rule='rule port port=\"{}\" protocol=\"{}\" reject'",
+                        self.port, protocol
+                    )
+                };
+                format!(
+                    "firewall-cmd --permanent {} && firewall-cmd --reload",
+                    change
+                )
+            }
+            FirewallBackend::Iptables => format!(
+                "iptables -I INPUT -p {} --dport {} -j {}",
+                protocol,
+                self.port,
+                if self.allow { "ACCEPT" } else { "DROP" }
+            ),
+        }
+    }
+}
+
+/// 读取防火墙规则；`password` 为空字符串表示免密 sudo
+pub async fn list_firewall_rules(
+    session: &SshSession,
+    password: &str,
+) -> Result<FirewallStatus, String> {
+    let output = run_sudo(session, LIST_COMMAND, password).await?;
+    let status = parse_firewall_output(&String::from_utf8_lossy(&output));
+    debug!(
+        "[Firewall] {:?}: {} rules",
+        status.backend,
+        status.rules.len()
+    );
+    Ok(status)
+}
+
+/// 放行或拒绝端口
+pub async fn apply_port_rule(
+    session: &SshSession,
+    backend: FirewallBackend,
+    rule: &PortRule,
+    password: &str,
+) -> Result<(), String> {
+    let command = rule.command(backend);
+    info!("[Firewall] {}", command);
+    run_sudo(
+        session,
+        &format!("sh -c {}", shell_quote(&command)),
+        password,
+    )
+    .await
+    .map(drop)
+}
+
+/// 按第一行的后端标记解析输出
+fn parse_firewall_output(output: &str) -> FirewallStatus {
+    let mut lines = output.lines();
+    let backend = match lines
+        .by_ref()
+        .find_map(|line| line.strip_prefix(BACKEND_MARKER))
+        .map(str::trim)
+    {
+        Some("ufw") => Some(FirewallBackend::Ufw),
+        Some("firewalld") => Some(FirewallBackend::Firewalld),
+        Some("iptables") => Some(FirewallBackend::Iptables),
+        _ => None,
+    };
+    let body: Vec<&str> = lines.collect();
+    let (summary, rules) = match backend {
+        Some(FirewallBackend::Ufw) => parse_ufw(&body),
+        Some(FirewallBackend::Firewalld) => parse_firewalld(&body),
+        Some(FirewallBackend::Iptables) => parse_iptables(&body),
+        None => (String::new(), Vec::new()),
+    };
+    FirewallStatus {
+        backend,
+        summary,
+        rules,
+    }
+}
+
+/// 解析 `ufw status numbered`：`[ 1] 22/tcp   ALLOW IN   Anywhere   # 注释`
+fn parse_ufw(lines: &[&str]) -> (String, Vec<FirewallRule>) {
+    let summary = lines
+        .iter()
+        .find(|line| line.starts_with("Status:"))
+        .map(|line| line.trim().to_string())
+        .unwrap_or_default();
+    let rules = lines
+        .iter()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix('[')?;
+            let (index, rest) = rest.split_once(']')?;
+            let index = index.trim().parse().ok()?;
+            let rule = rest.split('#').next().unwrap_or(rest);
+            // 各列之间至少有两个空格
+            let columns: Vec<&str> = rule
+                .split("  ")
+                .map(str::trim)
+                .filter(|c| !c.is_empty())
+                .collect();
+            let [target, action, source] = columns.as_slice() else {
+                return None;
+            };
+            let (action, direction) = action.split_once(' ').unwrap_or((*action, ""));
+            Some(FirewallRule {
+                index: Some(index),
+                action: action.to_uppercase(),
+                target: target.to_string(),
+                source: normalize_source(source),
+                direction: direction.to_string(),
+                raw: line.trim().to_string(),
+            })
+        })
+        .collect();
+    (summary, rules)
+}
+
+/// 解析 `firewall-cmd --list-all`：services/ports 各自展开为放行规则，rich rules 按原文保留
+fn parse_firewalld(lines: &[&str]) -> (String, Vec<FirewallRule>) {
+    let zone = lines
+        .iter()
+        .find(|line| !line.trim().is_empty() && !line.starts_with(' '))
+        .map(|line| line.trim().to_string())
+        .unwrap_or_default();
+    let zone_name = zone
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let mut rules = Vec::new();
+    let mut in_rich_rules = false;
+    for line in lines {
+        let trimmed = line.trim();
+        if let Some((key, value)) = trimmed
+            .split_once(':')
+            .filter(|(k, _)| !k.contains(' ') || *k == "rich rules")
+        {
+            in_rich_rules = key == "rich rules";
+            let value = value.trim();
+            match key {
+                "services" | "ports" => {
+                    for item in value.split_whitespace() {
+                        rules.push(FirewallRule {
+                            index: None,
+                            action: "ACCEPT".to_string(),
+                            target: item.to_string(),
+                            source: String::new(),
+                            direction: zone_name.clone(),
+                            raw: format!("{}: {}", key, item),
+                        });
+                    }
+                }
+                "rich rules" if !value.is_empty() => rules.push(rich_rule(value, &zone_name)),
+                _ => {}
+            }
+        } else if in_rich_rules && trimmed.starts_with("rule ") {
+            rules.push(rich_rule(trimmed, &zone_name));
+        }
+    }
+    (zone, rules)
+}
+
+/// rich rule：动作取最后的 accept/reject/drop，目标取 port/service，来源取 source address
+fn rich_rule(text: &str, zone: &str) -> FirewallRule {
+    let value_of = |key: &str| {
+        text.split_once(key).and_then(|(_, rest)| {
+            let rest = rest.trim_start_matches('"');
+            rest.split('"').next().map(str::to_string)
+        })
+    };
+    let action = ["accept", "reject", "drop"]
+        .into_iter()
+        .rfind(|a| text.split_whitespace().any(|w| w == *a))
+        .unwrap_or("rule")
+        .to_uppercase();
+    let target = match (value_of("port port="), value_of("protocol=")) {
+        (Some(port), Some(protocol)) => format!("{}/{}", port, protocol),
+        (Some(port), None) => port,
+        _ => value_of("service name=").unwrap_or_default(),
+    };
+    FirewallRule {
+        index: None,
+        action,
+        target,
+        source: value_of("source address=").unwrap_or_default(),
+        direction: zone.to_string(),
+        raw: text.to_string(),
+    }
+}
+
+/// 解析 `iptables -S`：链的默认策略（-P）与规则（-A），规则按链内顺序编号
+fn parse_iptables(lines: &[&str]) -> (String, Vec<FirewallRule>) {
+    let mut policies = Vec::new();
+    let mut rules = Vec::new();
+    let mut counters: Vec<(String, u32)> = Vec::new();
+    for line in lines {
+        let args: Vec<&str> = line.split_whitespace().collect();
+        match args.as_slice() {
+            ["-P", chain, policy] => policies.push(format!("{} {}", chain, policy)),
+            ["-A", chain, rest @ ..] => {
+                let value_of = |flag: &str| {
+                    rest.iter()
+                        .position(|a| *a == flag)
+                        .and_then(|i| rest.get(i + 1))
+                        .map(|v| v.to_string())
+                };
+                let index = match counters.iter_mut().find(|(c, _)| c == chain) {
+                    Some((_, n)) => {
+                        *n += 1;
+                        *n
+                    }
+                    None => {
+                        counters.push((chain.to_string(), 1));
+                        1
+                    }
+                };
+                let port = value_of("--dport").or_else(|| value_of("--dports"));
+                let target = match (port, value_of("-p")) {
+                    (Some(port), Some(protocol)) => format!("{}/{}", port, protocol),
+                    (Some(port), None) => port,
+                    (None, Some(protocol)) => protocol,
+                    (None, None) => value_of("-i").unwrap_or_else(|| "*".to_string()),
+                };
+                rules.push(FirewallRule {
+                    index: Some(index),
+                    action: value_of("-j").unwrap_or_default().to_uppercase(),
+                    target,
+                    source: normalize_source(&value_of("-s").unwrap_or_default()),
+                    direction: chain.to_string(),
+                    raw: line.trim().to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+    (policies.join(", "), rules)
+}
+
+/// 任意来源统一显示为空
+fn normalize_source(source: &str) -> String {
+    match source.trim() {
+        "Anywhere" | "Anywhere (v6)" | "0.0.0.0/0" | "::/0" => String::new(),
+        source => source.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 规则的关键字段（序号, 动作, 目标, 来源, 方向）
+    fn summarize(rules: &[FirewallRule]) -> Vec<(Option<u32>, &str, &str, &str, &str)> {
+        rules
+            .iter()
+            .map(|r| {
+                (
+                    r.index,
+                    r.action.as_str(),
+                    r.target.as_str(),
+                    r.source.as_str(),
+                    r.direction.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_ufw() {
+        let output = "### ufw
+Status: active
+
+     To                         Action      From
+     --                         ------      ----
+[ 1] 22/tcp                     ALLOW IN    Anywhere
+[ 2] 80,443/tcp                 ALLOW IN    192.168.1.0/24             # web
+[ 3] 3306                       DENY IN     10.0.0.5
+[ 4] 22/tcp (v6)                ALLOW IN    Anywhere (v6)
+";
+        let status = parse_firewall_output(output);
+        assert_eq!(status.backend, Some(FirewallBackend::Ufw));
+        assert_eq!(status.summary, "Status: active");
+        assert_eq!(
+            summarize(&status.rules),
+            vec![
+                (Some(1), "ALLOW", "22/tcp", "", "IN"),
+                (Some(2), "ALLOW", "80,443/tcp", "192.168.1.0/24", "IN"),
+                (Some(3), "DENY", "3306", "10.0.0.5", "IN"),
+                (Some(4), "ALLOW", "22/tcp (v6)", "", "IN"),
+            ]
+        );
+        assert!(status.rules[1].raw.ends_with("# web"));
+        assert!(!status.rules[2].is_allow());
+    }
+
+    #[test]
+    fn test_parse_ufw_inactive() {
+        let status = parse_firewall_output("### ufw\nStatus: inactive\n");
+        assert_eq!(status.backend, Some(FirewallBackend::Ufw));
+        assert_eq!(status.summary, "Status: inactive");
+        assert!(status.rules.is_empty());
+    }
+
+    #[test]
+    fn test_parse_firewalld() {
+        let output = "### firewalld
+public (active)
+  target: default
+  icmp-block-inversion: no
+  interfaces: eth0
+  sources:
+  services: cockpit dhcpv6-client ssh
+  ports: 8080/tcp 51820/udp
+  protocols:
+  forward: yes
+  masquerade: no
+  forward-ports:
+  source-ports:
+  icmp-blocks:
+  rich rules:
+\trule family=\"ipv4\" source address=\"192.168.1.0/24\" port port=\"5432\" protocol=\"tcp\" accept
+\trule family=\"ipv4\" source address=\"203.0.113.7\" service name=\"ssh\" drop
+";
+        let status = parse_firewall_output(output);
+        assert_eq!(status.backend, Some(FirewallBackend::Firewalld));
+        assert_eq!(status.summary, "public (active)");
+        assert_eq!(
+            summarize(&status.rules),
+            vec![
+                (None, "ACCEPT", "cockpit", "", "public"),
+                (None, "ACCEPT", "dhcpv6-client", "", "public"),
+                (None, "ACCEPT", "ssh", "", "public"),
+                (None, "ACCEPT", "8080/tcp", "", "public"),
+                (None, "ACCEPT", "51820/udp", "", "public"),
+                (None, "ACCEPT", "5432/tcp", "192.168.1.0/24", "public"),
+                (None, "DROP", "ssh", "203.0.113.7", "public"),
+            ]
+        );
+        assert_eq!(status.rules[3].raw, "ports: 8080/tcp");
+    }
+
+    #[test]
+    fn test_parse_iptables() {
+        let output = "### iptables
+-P INPUT DROP
+-P FORWARD DROP
+-P OUTPUT ACCEPT
+-N DOCKER
+-A INPUT -i lo -j ACCEPT
+-A INPUT -m conntrack --ctstate RELATED,ESTABLISHED -j ACCEPT
+-A INPUT -p tcp -m tcp --dport 22 -j ACCEPT
+-A INPUT -s 10.0.0.0/8 -p udp -m multiport --dports 60000:61000 -j ACCEPT
+-A INPUT -s 203.0.113.0/24 -j DROP
+-A DOCKER -d 172.17.0.2/32 ! -i docker0 -o docker0 -p tcp -m tcp --dport 80 -j ACCEPT
+";
+        let status = parse_firewall_output(output);
+        assert_eq!(status.backend, Some(FirewallBackend::Iptables));
+        assert_eq!(status.summary, "INPUT DROP, FORWARD DROP, OUTPUT ACCEPT");
+        assert_eq!(
+            summarize(&status.rules),
+            vec![
+                (Some(1), "ACCEPT", "lo", "", "INPUT"),
+                (Some(2), "ACCEPT", "*", "", "INPUT"),
+                (Some(3), "ACCEPT", "22/tcp", "", "INPUT"),
+                (Some(4), "ACCEPT", "60000:61000/udp", "10.0.0.0/8", "INPUT"),
+                (Some(5), "DROP", "*", "203.0.113.0/24", "INPUT"),
+                (Some(1), "ACCEPT", "80/tcp", "", "DOCKER"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_no_firewall() {
+        let status = parse_firewall_output("### none\n");
+        assert_eq!(status.backend, None);
+        assert!(status.summary.is_empty());
+        assert!(status.rules.is_empty());
+    }
+}
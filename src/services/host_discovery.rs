@@ -0,0 +1,276 @@
+// 主机发现：扫描网段或 IP 范围内开放的 SSH 端口，并读取服务端的版本标识（banner）。
+// 扫描目标支持 CIDR（192.168.1.0/24）、范围（192.168.1.10-50 或 192.168.1.10-192.168.1.50）与单个地址，
+// 多个目标用逗号或空格分隔；同时连接的主机数有上限，可随时取消。
+
+use std::collections::HashSet;
+use std::net::{IpAddr, Ipv4Addr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+
+/// 单次扫描的主机数量上限
+pub const MAX_HOSTS: usize = 4096;
+/// 同时探测的主机数量上限
+pub const MAX_CONCURRENT: usize = 64;
+/// 连接端口的超时
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(1500);
+/// 读取 banner 的超时
+const BANNER_TIMEOUT: Duration = Duration::from_secs(2);
+/// banner 最大长度（RFC 4253 规定不超过 255 字节）
+const MAX_BANNER_LEN: usize = 255;
+
+/// 扫描目标解析错误
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TargetError {
+    /// 无法识别的目标
+    Invalid(String),
+    /// 主机数量超过上限
+    TooMany,
+}
+
+impl TargetError {
+    /// 错误提示的 i18n 键
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            TargetError::Invalid(_) => "discovery.error.invalid",
+            TargetError::TooMany => "discovery.error.too_many",
+        }
+    }
+}
+
+/// 发现的 SSH 主机
+#[derive(Clone, Debug)]
+pub struct DiscoveredHost {
+    pub ip: IpAddr,
+    pub port: u16,
+    pub latency_ms: u32,
+    /// 服务端版本标识（如 `SSH-2.0-OpenSSH_9.6`），未读取到时为 None
+    pub banner: Option<String>,
+}
+
+/// 解析扫描目标，返回去重后的地址列表（保持输入顺序）
+pub fn parse_targets(input: &str) -> Result<Vec<IpAddr>, TargetError> {
+    let mut seen = HashSet::new();
+    let mut targets = Vec::new();
+    for token in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+    {
+        let invalid = || TargetError::Invalid(token.to_string());
+        let addresses: Vec<IpAddr> = if let Some((base, prefix)) = token.split_once('/') {
+            let base: Ipv4Addr = base.parse().map_err(|_| invalid())?;
+            let prefix: u32 = prefix
+                .parse()
+                .ok()
+                .filter(|p| *p <= 32)
+                .ok_or_else(invalid)?;
+            cidr_hosts(base, prefix)?
+        } else if let Some((start, end)) = token.split_once('-') {
+            let start: Ipv4Addr = start.parse().map_err(|_| invalid())?;
+            // 结束地址可以只写最后一段
+            let end: Ipv4Addr = match end.parse::<u8>() {
+                Ok(last) => {
+                    let [a, b, c, _] = start.octets();
+                    Ipv4Addr::new(a, b, c, last)
+                }
+                Err(_) => end.parse().map_err(|_| invalid())?,
+            };
+            let (start, end) = (u32::from(start), u32::from(end));
+            if end < start {
+                return Err(invalid());
+            }
+            if (end - start) as usize >= MAX_HOSTS {
+                return Err(TargetError::TooMany);
+            }
+            (start..=end)
+                .map(|ip| IpAddr::V4(Ipv4Addr::from(ip)))
+                .collect()
+        } else {
+            vec![token.parse().map_err(|_| invalid())?]
+        };
+
+        for address in addresses {
+            if seen.insert(address) {
+                targets.push(address);
+            }
+        }
+        if targets.len() > MAX_HOSTS {
+            return Err(TargetError::TooMany);
+        }
+    }
+    Ok(targets)
+}
+
+/// CIDR 网段内的主机地址（/31、/32 以外不含网络地址与广播地址）
+fn cidr_hosts(base: Ipv4Addr, prefix: u32) -> Result<Vec<IpAddr>, TargetError> {
+    let size = 1u64 << (32 - prefix);
+    if size > MAX_HOSTS as u64 + 2 {
+        return Err(TargetError::TooMany);
+    }
+    let network = u32::from(base) & (u32::MAX.checked_shl(32 - prefix).unwrap_or(0));
+    let range = if size > 2 { 1..size - 1 } else { 0..size };
+    Ok(range
+        .map(|offset| IpAddr::V4(Ipv4Addr::from(network + offset as u32)))
+        .collect())
+}
+
+/// 本机所在的 /24 网段，作为扫描目标的默认值（无法确定时为 None）
+pub fn local_subnet() -> Option<String> {
+    // UDP 的 connect 只选择路由，不发送数据
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    match socket.local_addr().ok()?.ip() {
+        IpAddr::V4(ip) if !ip.is_loopback() && !ip.is_unspecified() => {
+            let [a, b, c, _] = ip.octets();
+            Some(format!("{}.{}.{}.0/24", a, b, c))
+        }
+        _ => None,
+    }
+}
+
+/// 探测单个主机：端口可连接时读取 banner，端口未开放时返回 None
+pub async fn probe(ip: IpAddr, port: u16) -> Option<DiscoveredHost> {
+    let started = Instant::now();
+    let mut stream = tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect((ip, port)))
+        .await
+        .ok()?
+        .ok()?;
+    let latency_ms = started.elapsed().as_millis() as u32;
+    let banner = tokio::time::timeout(BANNER_TIMEOUT, read_banner(&mut stream))
+        .await
+        .ok()
+        .flatten();
+    Some(DiscoveredHost {
+        ip,
+        port,
+        latency_ms,
+        banner,
+    })
+}
+
+/// 读取服务端发送的第一行 `SSH-` 标识（之前可能有其他说明行）
+async fn read_banner(stream: &mut TcpStream) -> Option<String> {
+    let mut buf = Vec::with_capacity(MAX_BANNER_LEN);
+    let mut chunk = [0u8; 256];
+    let find_banner = |buf: &[u8], complete_only: bool| {
+        String::from_utf8_lossy(buf)
+            .split_inclusive('\n')
+            .find(|line| line.starts_with("SSH-") && (!complete_only || line.ends_with('\n')))
+            .map(|line| line.trim_end().to_string())
+    };
+    while buf.len() < MAX_BANNER_LEN * 4 {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(banner) = find_banner(&buf, true) {
+            return Some(banner);
+        }
+    }
+    // 连接已关闭或内容过长时，取已收到的部分
+    find_banner(&buf, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ips(addresses: &[&str]) -> Vec<IpAddr> {
+        addresses.iter().map(|a| a.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        assert_eq!(parse_targets(""), Ok(Vec::new()));
+        assert_eq!(parse_targets(" , "), Ok(Vec::new()));
+        assert_eq!(parse_targets("10.0.0.1 ::1"), Ok(ips(&["10.0.0.1", "::1"])));
+        // 范围的结束地址可以只写最后一段
+        assert_eq!(
+            parse_targets("192.168.1.10-12"),
+            Ok(ips(&["192.168.1.10", "192.168.1.11", "192.168.1.12"]))
+        );
+        assert_eq!(
+            parse_targets("192.168.1.254-192.168.2.1"),
+            Ok(ips(&[
+                "192.168.1.254",
+                "192.168.1.255",
+                "192.168.2.0",
+                "192.168.2.1"
+            ]))
+        );
+        // 去重并保持输入顺序
+        assert_eq!(
+            parse_targets("192.168.1.2,192.168.1.0/30 192.168.1.2"),
+            Ok(ips(&["192.168.1.2", "192.168.1.1"]))
+        );
+    }
+
+    #[test]
+    fn test_parse_targets_cidr() {
+        // 基地址不是网络地址时按网段计算，不含网络地址与广播地址
+        let hosts = parse_targets("192.168.1.77/24").unwrap();
+        assert_eq!(hosts.len(), 254);
+        assert_eq!(hosts.first(), Some(&"192.168.1.1".parse().unwrap()));
+        assert_eq!(hosts.last(), Some(&"192.168.1.254".parse().unwrap()));
+
+        assert_eq!(parse_targets("10.0.0.7/32"), Ok(ips(&["10.0.0.7"])));
+        assert_eq!(
+            parse_targets("10.0.0.7/31"),
+            Ok(ips(&["10.0.0.6", "10.0.0.7"]))
+        );
+        assert_eq!(parse_targets("10.0.0.0/20").unwrap().len(), 4094);
+    }
+
+    #[test]
+    fn test_parse_targets_rejects_malformed() {
+        for input in [
+            "abc",
+            "300.1.1.1",
+            "10.0.0.0/33",
+            "10.0.0.0/",
+            "10.0.0.0/x",
+            "::1/128",
+            "10.0.0.9-10.0.0.1",
+            "10.0.0.1-300",
+            "10.0.0.1-",
+            "fe80::1-fe80::2",
+        ] {
+            assert_eq!(
+                parse_targets(input),
+                Err(TargetError::Invalid(input.to_string())),
+                "{}",
+                input
+            );
+        }
+        // 只报告第一个无法识别的目标
+        assert_eq!(
+            parse_targets("10.0.0.1, bad, 10.0.0.2"),
+            Err(TargetError::Invalid("bad".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_targets_too_many() {
+        assert_eq!(parse_targets("10.0.0.0/19"), Err(TargetError::TooMany));
+        assert_eq!(parse_targets("0.0.0.0/0"), Err(TargetError::TooMany));
+        assert_eq!(
+            parse_targets("10.0.0.0-10.0.16.0"),
+            Err(TargetError::TooMany)
+        );
+        assert_eq!(
+            parse_targets("10.0.0.0-10.0.15.255").unwrap().len(),
+            MAX_HOSTS
+        );
+        // 多个目标合计超过上限
+        assert_eq!(
+            parse_targets("10.0.0.0/20 10.1.0.0/20"),
+            Err(TargetError::TooMany)
+        );
+        assert_eq!(
+            TargetError::TooMany.message_key(),
+            "discovery.error.too_many"
+        );
+    }
+}
@@ -0,0 +1,334 @@
+// 团队共享服务器清单：从 HTTP(S) 地址或本地/挂载路径加载只读的服务器列表（JSON 或 YAML），
+// 按设置的间隔刷新后合并显示在服务器列表中。清单只包含主机信息，连接时使用本地的身份认证，
+// 因此团队可以分发统一的主机列表，而各自的凭据仍保存在本机。
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use gpui::{App, Global};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tracing::{info, warn};
+
+use crate::models::server::{AuthType, ServerData};
+use crate::models::settings::SyncSettings;
+use crate::services::storage;
+
+/// 共享服务器 ID 的前缀，与本地服务器区分
+pub const SHARED_ID_PREFIX: &str = "shared:";
+/// 下载清单的超时时间
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// 检查是否需要刷新的间隔
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 清单文件：服务器数组，或带 `servers` 字段的对象
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InventoryDocument {
+    List(Vec<InventoryEntry>),
+    Object { servers: Vec<InventoryEntry> },
+}
+
+/// 清单中的一台服务器
+#[derive(Deserialize)]
+struct InventoryEntry {
+    /// 稳定的条目 ID（省略时使用 用户名@主机:端口）
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default, alias = "label")]
+    name: Option<String>,
+    host: String,
+    #[serde(default = "default_port")]
+    port: u16,
+    #[serde(default, alias = "user")]
+    username: String,
+    /// 分组名称
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// 连接使用的本地身份名称（省略时使用设置中的默认身份）
+    #[serde(default)]
+    identity: Option<String>,
+}
+
+fn default_port() -> u16 {
+    22
+}
+
+/// 共享清单中的服务器
+#[derive(Clone, Debug)]
+pub struct SharedServer {
+    pub server: ServerData,
+    /// 清单中的分组名称
+    pub group: Option<String>,
+    /// 清单指定的本地身份名称
+    pub identity: Option<String>,
+}
+
+impl InventoryEntry {
+    fn into_shared(self) -> SharedServer {
+        let key = self
+            .id
+            .unwrap_or_else(|| format!("{}@{}:{}", self.username, self.host, self.port));
+        let server = ServerData {
+            id: format!("{}{}", SHARED_ID_PREFIX, key),
+            label: self.name.unwrap_or_else(|| self.host.clone()),
+            host: self.host,
+            port: self.port,
+            username: self.username,
+            auth_type: AuthType::Identity,
+            description: self.description,
+            tags: self.tags,
+            ..Default::default()
+        };
+        SharedServer {
+            server,
+            group: self.group.filter(|g| !g.trim().is_empty()),
+            identity: self.identity,
+        }
+    }
+}
+
+/// 是否为共享服务器的 ID
+pub fn is_shared_id(server_id: &str) -> bool {
+    server_id.starts_with(SHARED_ID_PREFIX)
+}
+
+/// 读取并解析清单：`http://` / `https://` 开头的地址通过网络下载，其余按本地路径读取
+pub async fn fetch(source: &str) -> Result<Vec<SharedServer>> {
+    let content = if source.starts_with("http://") || source.starts_with("https://") {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("无法创建 HTTP 客户端")?;
+        client
+            .get(source)
+            .send()
+            .await
+            .with_context(|| format!("无法下载共享清单: {}", source))?
+            .error_for_status()
+            .with_context(|| format!("无法下载共享清单: {}", source))?
+            .text()
+            .await
+            .context("无法读取共享清单")?
+    } else {
+        tokio::fs::read_to_string(source)
+            .await
+            .with_context(|| format!("无法读取共享清单: {}", source))?
+    };
+    parse(source, &content)
+}
+
+/// 按扩展名解析清单：`.json` 按 JSON 解析，其余按 YAML 解析（YAML 兼容 JSON）
+fn parse(source: &str, content: &str) -> Result<Vec<SharedServer>> {
+    let document: InventoryDocument = if source.to_ascii_lowercase().ends_with(".json") {
+        serde_json::from_str(content).context("共享清单不是有效的 JSON")?
+    } else {
+        serde_yaml::from_str(content).context("共享清单不是有效的 YAML")?
+    };
+    let entries = match document {
+        InventoryDocument::List(entries) | InventoryDocument::Object { servers: entries } => {
+            entries
+        }
+    };
+    Ok(entries
+        .into_iter()
+        .filter(|e| !e.host.trim().is_empty())
+        .map(InventoryEntry::into_shared)
+        .collect())
+}
+
+/// 最近一次加载的清单
+#[derive(Default)]
+struct InventoryState {
+    servers: Vec<SharedServer>,
+    /// 加载清单使用的地址
+    source: String,
+    refreshed_at: Option<Instant>,
+    /// 最近一次刷新的结果（成功时为服务器数量）
+    status: Option<Result<usize, String>>,
+}
+
+/// 共享清单缓存
+pub struct SharedInventory {
+    state: Mutex<InventoryState>,
+}
+
+impl SharedInventory {
+    /// 获取全局单例
+    pub fn global() -> &'static SharedInventory {
+        static INVENTORY: Lazy<SharedInventory> = Lazy::new(|| SharedInventory {
+            state: Mutex::new(InventoryState::default()),
+        });
+        &INVENTORY
+    }
+
+    fn with_state<T>(&self, f: impl FnOnce(&mut InventoryState) -> T) -> T {
+        f(&mut self.state.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// 共享服务器列表（保持清单中的顺序）
+    pub fn servers(&self) -> Vec<SharedServer> {
+        self.with_state(|state| state.servers.clone())
+    }
+
+    /// 最近一次刷新的结果
+    pub fn status(&self) -> Option<Result<usize, String>> {
+        self.with_state(|state| state.status.clone())
+    }
+
+    /// 用于连接的服务器数据：按清单指定的身份名称或设置中的默认身份填入本地凭据
+    pub fn server(&self, server_id: &str) -> Option<ServerData> {
+        let shared = self.with_state(|state| {
+            state
+                .servers
+                .iter()
+                .find(|s| s.server.id == server_id)
+                .cloned()
+        })?;
+        let config = storage::load_servers().unwrap_or_default();
+        let settings = storage::load_settings().unwrap_or_default();
+        let identity_id = shared
+            .identity
+            .as_deref()
+            .and_then(|name| config.identities.iter().find(|i| i.name == name))
+            .map(|i| i.id.clone())
+            .or(settings.sync.inventory_identity_id);
+        let mut server = shared.server;
+        if identity_id.is_none() {
+            warn!(
+                "[Inventory] No local identity for shared server {}",
+                server.label
+            );
+        }
+        server.identity_id = identity_id;
+        Some(server)
+    }
+
+    /// 是否需要刷新：地址变化或距上次刷新已超过间隔
+    fn is_due(&self, source: &str, interval: Duration) -> bool {
+        self.with_state(|state| {
+            state.source != source || state.refreshed_at.is_none_or(|t| t.elapsed() >= interval)
+        })
+    }
+
+    fn set(&self, source: String, result: Result<Vec<SharedServer>, String>) {
+        self.with_state(|state| {
+            state.source = source;
+            state.refreshed_at = Some(Instant::now());
+            match result {
+                Ok(servers) => {
+                    state.status = Some(Ok(servers.len()));
+                    state.servers = servers;
+                }
+                // 刷新失败时保留上次的列表
+                Err(e) => state.status = Some(Err(e)),
+            }
+        });
+    }
+
+    /// 清空清单（未配置地址时）
+    fn clear(&self) -> bool {
+        self.with_state(|state| {
+            let changed = !state.servers.is_empty() || state.status.is_some();
+            *state = InventoryState::default();
+            changed
+        })
+    }
+}
+
+/// 共享清单的 GPUI 全局状态：定时刷新并在清单变化后通知界面
+pub struct InventoryService {
+    revision: u64,
+    running: bool,
+}
+
+impl Global for InventoryService {}
+
+impl InventoryService {
+    /// 注册全局状态并启动定时刷新
+    pub fn init(cx: &mut App) {
+        cx.set_global(Self {
+            revision: 0,
+            running: false,
+        });
+        cx.spawn(async move |cx| loop {
+            if cx.update(|cx| Self::tick(cx)).is_err() {
+                break;
+            }
+            cx.background_executor().timer(REFRESH_CHECK_INTERVAL).await;
+        })
+        .detach();
+    }
+
+    /// 清单的修订号，每次刷新或清空后递增
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// 是否正在刷新
+    pub fn running(&self) -> bool {
+        self.running
+    }
+
+    fn tick(cx: &mut App) {
+        let settings = storage::load_settings().unwrap_or_default().sync;
+        let source = settings.inventory_source.trim();
+        let interval = Duration::from_secs(settings.inventory_refresh_minutes.max(1) as u64 * 60);
+        if source.is_empty() || SharedInventory::global().is_due(source, interval) {
+            Self::refresh(&settings, cx);
+        }
+    }
+
+    /// 立即按给定的同步设置刷新清单（设置页可在保存前使用正在编辑的地址）
+    pub fn refresh(settings: &SyncSettings, cx: &mut App) {
+        let source = settings.inventory_source.trim().to_string();
+        if source.is_empty() {
+            if SharedInventory::global().clear() {
+                Self::bump(cx);
+            }
+            return;
+        }
+        if cx.global::<Self>().running {
+            return;
+        }
+        cx.global_mut::<Self>().running = true;
+        cx.refresh_windows();
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let source_for_task = source.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = fetch(&source_for_task)
+                    .await
+                    .map_err(|e| format!("{:#}", e));
+                let _ = tx.send(result);
+            });
+
+        cx.spawn(async move |cx| {
+            let Ok(result) = rx.await else {
+                return;
+            };
+            match &result {
+                Ok(servers) => info!("[Inventory] Loaded {} shared servers", servers.len()),
+                Err(e) => warn!("[Inventory] Failed to refresh shared inventory: {}", e),
+            }
+            SharedInventory::global().set(source, result);
+            let _ = cx.update(|cx| {
+                cx.global_mut::<Self>().running = false;
+                Self::bump(cx);
+            });
+        })
+        .detach();
+    }
+
+    fn bump(cx: &mut App) {
+        cx.update_global::<Self, _>(|service, _| service.revision += 1);
+        cx.refresh_windows();
+    }
+}
@@ -1,6 +1,37 @@
 // 业务逻辑/后台服务模块
 
+pub mod batch_exec;
+pub mod config_archive;
+pub mod connection_history;
+pub mod connections;
+pub mod cron;
+pub mod database;
+pub mod db_tunnel;
+pub mod docker;
+pub mod firewall;
+pub mod host_discovery;
+pub mod inventory;
 pub mod monitor;
+pub mod monitor_export;
+pub mod notifications;
+pub mod packages;
+pub mod plugins;
+pub mod processes;
+pub mod reachability;
+pub mod remote_exec;
+pub mod scheduler;
+pub mod schema;
+pub mod server_export;
+pub mod server_import;
+pub mod server_search;
 pub mod sftp;
+pub mod snippet_library;
 pub mod ssh;
 pub mod storage;
+pub mod sync;
+pub mod systemd;
+pub mod transfer;
+pub mod tray;
+pub mod ui_theme;
+pub mod url_handler;
+pub mod window_state;
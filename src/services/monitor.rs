@@ -1,6 +1,8 @@
 // Monitor 后端服务
 // 使用 SSH ExecChannel 轮询执行 Shell 脚本收集系统监控信息
 
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -9,8 +11,10 @@ use tokio::task::JoinHandle;
 use tracing::{debug, info, trace, warn};
 
 use crate::models::monitor::{
-    CpuInfo, CpuLoadInfo, DiskDeviceInfo, DiskInfo, HostInfo, LoadInfo, MemoryLoadInfo,
+    CpuInfo, CpuLoadInfo, DiskDeviceInfo, DiskInfo, DiskIoInfo, DriveHealth, GpuDeviceInfo,
+    GpuInfo, GpuProcessInfo, HostInfo, LoadInfo, LoginInfo, LoginRecord, MemoryLoadInfo,
     MemoryTotalInfo, NetworkGlobalInfo, NetworkInfo, NetworkInterfaceInfo, ProcessInfo, SystemInfo,
+    TemperatureSensor,
 };
 use crate::ssh::session::SshSession;
 
@@ -25,6 +29,16 @@ pub enum MonitorEvent {
     NetworkInfo(NetworkInfo),
     /// 磁盘状态信息（每 10 秒）
     DiskInfo(DiskInfo),
+    /// GPU 状态信息（仅在检测到 nvidia-smi 时，与负载信息同频）
+    GpuInfo(GpuInfo),
+    /// 温度传感器读数（每 10 秒，没有可用传感器时不发送）
+    Temperatures(Vec<TemperatureSensor>),
+    /// 硬盘 SMART 健康状态（每 5 分钟，远端没有 smartctl 时不发送）
+    DriveHealth(Vec<DriveHealth>),
+    /// 登录信息（每分钟）
+    LoginInfo(LoginInfo),
+    /// 告警（指标连续多次超过阈值）
+    Alert(MonitorAlert),
     /// 错误信息
     Error(String),
 }
@@ -36,8 +50,12 @@ pub struct MonitorSettings {
     pub load_interval_ms: u64,
     /// 网络信息刷新间隔（毫秒）
     pub network_interval_ms: u64,
-    /// 磁盘信息刷新间隔（毫秒）
+    /// 磁盘信息刷新间隔（毫秒，温度传感器同频）
     pub disk_interval_ms: u64,
+    /// SMART 健康状态刷新间隔（毫秒）
+    pub smart_interval_ms: u64,
+    /// 登录信息刷新间隔（毫秒）
+    pub login_interval_ms: u64,
 }
 
 impl Default for MonitorSettings {
@@ -46,10 +64,312 @@ impl Default for MonitorSettings {
             load_interval_ms: 2000,    // 2 秒
             network_interval_ms: 2000, // 2 秒
             disk_interval_ms: 10000,   // 10 秒
+            smart_interval_ms: 300000, // 5 分钟
+            login_interval_ms: 60000,  // 1 分钟
         }
     }
 }
 
+impl MonitorSettings {
+    /// 按刷新间隔（秒）生成配置：负载与网络按该间隔刷新，磁盘与温度为其 5 倍（至少 10 秒）
+    pub fn with_refresh_interval(secs: u32) -> Self {
+        let load_interval_ms = u64::from(secs.max(1)) * 1000;
+        Self {
+            load_interval_ms,
+            network_interval_ms: load_interval_ms,
+            disk_interval_ms: (load_interval_ms * 5).max(10000),
+            ..Self::default()
+        }
+    }
+}
+
+/// 各卡片的采集开关（每次轮询时读取，修改设置后立即生效；关闭的卡片不再在远端执行对应脚本）
+#[derive(Debug, Clone, Copy)]
+struct CollectorToggles {
+    load_info: bool,
+    network: bool,
+    disk: bool,
+    gpu: bool,
+    health: bool,
+    logins: bool,
+}
+
+impl CollectorToggles {
+    fn load() -> Self {
+        let monitor = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .monitor;
+        Self {
+            load_info: monitor.show_load(),
+            network: monitor.show_network,
+            disk: monitor.show_disk,
+            gpu: monitor.show_gpu,
+            health: monitor.show_health,
+            logins: monitor.show_logins,
+        }
+    }
+}
+
+/// 采集开关打开时才执行采集，关闭时返回 None
+async fn fetch_if<T>(
+    enabled: bool,
+    fetch: impl Future<Output = Result<T, String>>,
+) -> Option<Result<T, String>> {
+    if enabled {
+        Some(fetch.await)
+    } else {
+        None
+    }
+}
+
+/// 远端系统类型（连接后检测一次，决定使用哪一套采集脚本）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RemoteOs {
+    /// 带 procps / coreutils 的 Linux（/proc、free、ps --sort、df -T）
+    Linux,
+    /// 只有 BusyBox 工具的 Linux（OpenWrt、精简容器等）
+    BusyBox,
+    /// macOS（sysctl、vm_stat、ioreg / iostat、netstat -ib）
+    MacOs,
+    /// FreeBSD 及其他 BSD（sysctl、swapinfo、iostat、netstat -ib）
+    FreeBsd,
+}
+
+impl RemoteOs {
+    /// 根据检测脚本的输出识别系统
+    fn from_uname(name: &str) -> Option<Self> {
+        match name {
+            "Linux" => Some(Self::Linux),
+            "BusyBox" => Some(Self::BusyBox),
+            "Darwin" => Some(Self::MacOs),
+            "FreeBSD" | "OpenBSD" | "NetBSD" | "DragonFly" => Some(Self::FreeBsd),
+            _ => None,
+        }
+    }
+
+    fn system_info_script(self) -> &'static str {
+        match self {
+            Self::Linux => SYSTEM_INFO_SCRIPT,
+            Self::BusyBox => BUSYBOX_SYSTEM_INFO_SCRIPT,
+            Self::MacOs | Self::FreeBsd => BSD_SYSTEM_INFO_SCRIPT,
+        }
+    }
+
+    fn load_info_script(self) -> &'static str {
+        match self {
+            Self::Linux => LOAD_INFO_SCRIPT,
+            Self::BusyBox => BUSYBOX_LOAD_INFO_SCRIPT,
+            Self::MacOs | Self::FreeBsd => BSD_LOAD_INFO_SCRIPT,
+        }
+    }
+
+    fn network_info_script(self) -> &'static str {
+        match self {
+            // BusyBox 同样提供 /sys/class/net、netstat 和 ip -o
+            Self::Linux | Self::BusyBox => NETWORK_INFO_SCRIPT,
+            Self::MacOs | Self::FreeBsd => BSD_NETWORK_INFO_SCRIPT,
+        }
+    }
+
+    fn disk_info_script(self) -> &'static str {
+        match self {
+            Self::Linux => DISK_INFO_SCRIPT,
+            Self::BusyBox => BUSYBOX_DISK_INFO_SCRIPT,
+            Self::MacOs | Self::FreeBsd => BSD_DISK_INFO_SCRIPT,
+        }
+    }
+}
+
+/// 告警指标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertMetric {
+    Cpu,
+    Memory,
+    Disk,
+    /// 传感器温度
+    Temperature,
+    /// 硬盘 SMART 自检未通过
+    DriveHealth,
+}
+
+/// 监控告警
+#[derive(Debug, Clone)]
+pub struct MonitorAlert {
+    pub metric: AlertMetric,
+    /// 触发时的值（使用率百分比或摄氏温度）
+    pub value: f32,
+    /// 告警阈值（百分比或摄氏度）
+    pub threshold: u32,
+    /// 告警对象：磁盘挂载点、温度传感器或硬盘设备名，CPU/内存为空
+    pub subject: String,
+}
+
+/// 告警阈值（来自监控设置，阈值为 0 表示不检查该指标）
+#[derive(Debug, Clone, Copy)]
+struct AlertThresholds {
+    cpu: u32,
+    memory: u32,
+    disk: u32,
+    temperature: u32,
+    consecutive: u32,
+}
+
+impl AlertThresholds {
+    /// 读取当前设置（每次检查时读取，修改设置后立即生效）
+    fn load() -> Self {
+        let monitor = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .monitor;
+        Self {
+            cpu: monitor.cpu_alert_threshold,
+            memory: monitor.memory_alert_threshold,
+            disk: monitor.disk_alert_threshold,
+            temperature: monitor.temperature_alert_threshold,
+            consecutive: monitor.alert_consecutive_samples.max(1),
+        }
+    }
+}
+
+/// 告警引擎
+/// 指标连续 N 次采样超过阈值时触发一次告警；回落到阈值以下后重新计数，避免持续高负载时重复告警
+#[derive(Debug, Default)]
+struct AlertEngine {
+    /// 连续超过阈值的采样次数（磁盘、温度按告警对象分别计数）
+    counters: HashMap<(AlertMetric, String), u32>,
+    /// 已触发且尚未恢复的告警
+    firing: HashSet<(AlertMetric, String)>,
+}
+
+impl AlertEngine {
+    /// 检查单个指标，达到连续次数时返回告警
+    fn check(
+        &mut self,
+        metric: AlertMetric,
+        subject: &str,
+        value: f32,
+        threshold: u32,
+        consecutive: u32,
+    ) -> Option<MonitorAlert> {
+        let key = (metric, subject.to_string());
+        if threshold == 0 || value < threshold as f32 {
+            self.counters.remove(&key);
+            self.firing.remove(&key);
+            return None;
+        }
+
+        let count = self.counters.entry(key.clone()).or_insert(0);
+        *count += 1;
+        if *count < consecutive || !self.firing.insert(key) {
+            return None;
+        }
+        Some(MonitorAlert {
+            metric,
+            value,
+            threshold,
+            subject: subject.to_string(),
+        })
+    }
+
+    /// 检查 CPU 与内存使用率
+    fn check_load(&mut self, info: &LoadInfo, memory_total: u64) -> Vec<MonitorAlert> {
+        let thresholds = AlertThresholds::load();
+        let memory_percent = if memory_total > 0 {
+            (info.memory.used_bytes as f32 / memory_total as f32) * 100.0
+        } else {
+            0.0
+        };
+        [
+            self.check(
+                AlertMetric::Cpu,
+                "",
+                info.cpu.usage_percent,
+                thresholds.cpu,
+                thresholds.consecutive,
+            ),
+            self.check(
+                AlertMetric::Memory,
+                "",
+                memory_percent,
+                thresholds.memory,
+                thresholds.consecutive,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// 检查各挂载点的磁盘使用率
+    fn check_disk(&mut self, info: &DiskInfo) -> Vec<MonitorAlert> {
+        let thresholds = AlertThresholds::load();
+        // 已卸载的磁盘不再保留计数
+        self.counters.retain(|(metric, mount), _| {
+            *metric != AlertMetric::Disk || info.disks.iter().any(|d| &d.mount_point == mount)
+        });
+        info.disks
+            .iter()
+            .filter_map(|disk| {
+                self.check(
+                    AlertMetric::Disk,
+                    &disk.mount_point,
+                    disk.usage_percent,
+                    thresholds.disk,
+                    thresholds.consecutive,
+                )
+            })
+            .collect()
+    }
+
+    /// 检查各传感器温度
+    fn check_temperatures(&mut self, sensors: &[TemperatureSensor]) -> Vec<MonitorAlert> {
+        let thresholds = AlertThresholds::load();
+        sensors
+            .iter()
+            .filter_map(|sensor| {
+                self.check(
+                    AlertMetric::Temperature,
+                    &sensor_name(sensor),
+                    sensor.celsius,
+                    thresholds.temperature,
+                    thresholds.consecutive,
+                )
+            })
+            .collect()
+    }
+
+    /// 检查硬盘 SMART 状态：自检未通过是确定性的结果，无需连续多次，每块硬盘只告警一次
+    fn check_drive_health(&mut self, drives: &[DriveHealth]) -> Vec<MonitorAlert> {
+        drives
+            .iter()
+            .filter_map(|drive| {
+                let key = (AlertMetric::DriveHealth, drive.device.clone());
+                if drive.passed != Some(false) {
+                    self.firing.remove(&key);
+                    return None;
+                }
+                if !self.firing.insert(key) {
+                    return None;
+                }
+                Some(MonitorAlert {
+                    metric: AlertMetric::DriveHealth,
+                    value: 0.0,
+                    threshold: 0,
+                    subject: drive.device.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 传感器显示名称（芯片名 + 传感器名）
+pub fn sensor_name(sensor: &TemperatureSensor) -> String {
+    if sensor.chip.is_empty() {
+        sensor.label.clone()
+    } else {
+        format!("{} {}", sensor.chip, sensor.label)
+    }
+}
+
 /// Monitor 后端服务
 pub struct MonitorService {
     session_id: String,
@@ -120,20 +440,54 @@ impl MonitorService {
     ) {
         info!("[Monitor] Starting polling loop for session {}", session_id);
 
+        // 检测远端系统，选择对应的采集脚本；无法识别时仍按 Linux 尝试，并提示用户
+        let os = match Self::detect_os(&session).await {
+            Ok(os) => {
+                info!("[Monitor] Remote OS for session {}: {:?}", session_id, os);
+                os
+            }
+            Err(e) => {
+                warn!("[Monitor] {}, falling back to Linux collectors", e);
+                let _ = data_tx.send(MonitorEvent::Error(e));
+                RemoteOs::Linux
+            }
+        };
+
+        let mut alert_engine = AlertEngine::default();
+        // 内存总量来自系统信息，用于计算内存使用率
+        let mut memory_total = 0;
+        // GPU 是否可用（None 表示尚未检测，GPU 卡片关闭时不检测）
+        let mut gpu_available: Option<bool> = None;
+
         // ========================================================================
-        // 初次启动：并行获取所有数据
+        // 初次启动：并行获取所有数据（已关闭的卡片不采集）
         // ========================================================================
         {
-            let (system_result, load_result, network_result, disk_result) = tokio::join!(
-                Self::fetch_system_info(&session, &session_id),
-                Self::fetch_load_info(&session),
-                Self::fetch_network_info(&session),
-                Self::fetch_disk_info(&session)
+            let toggles = CollectorToggles::load();
+            let (
+                system_result,
+                load_result,
+                network_result,
+                disk_result,
+                gpu_result,
+                temperature_result,
+                smart_result,
+                login_result,
+            ) = tokio::join!(
+                Self::fetch_system_info(&session, &session_id, os),
+                fetch_if(toggles.load_info, Self::fetch_load_info(&session, os)),
+                fetch_if(toggles.network, Self::fetch_network_info(&session, os)),
+                fetch_if(toggles.disk, Self::fetch_disk_info(&session, os)),
+                fetch_if(toggles.gpu, Self::fetch_gpu_info(&session)),
+                fetch_if(toggles.health, Self::fetch_temperatures(&session)),
+                fetch_if(toggles.health, Self::fetch_drive_health(&session)),
+                fetch_if(toggles.logins, Self::fetch_login_info(&session))
             );
 
             // 发送系统信息
             match system_result {
                 Ok(info) => {
+                    memory_total = info.memory.total_bytes;
                     let _ = data_tx.send(MonitorEvent::SystemInfo(info));
                 }
                 Err(e) => {
@@ -147,32 +501,85 @@ impl MonitorService {
 
             // 发送负载信息
             match load_result {
-                Ok(info) => {
+                Some(Ok(info)) => {
+                    for alert in alert_engine.check_load(&info, memory_total) {
+                        let _ = data_tx.send(MonitorEvent::Alert(alert));
+                    }
                     let _ = data_tx.send(MonitorEvent::LoadInfo(info));
                 }
-                Err(e) => {
-                    debug!("[Monitor] Failed to fetch initial load info: {}", e);
+                Some(Err(e)) => {
+                    warn!("[Monitor] Failed to fetch initial load info: {}", e);
+                    let _ = data_tx.send(MonitorEvent::Error(format!(
+                        "Failed to fetch load info: {}",
+                        e
+                    )));
                 }
+                None => {}
             }
 
             // 发送网络信息
             match network_result {
-                Ok(info) => {
+                Some(Ok(info)) => {
                     let _ = data_tx.send(MonitorEvent::NetworkInfo(info));
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     debug!("[Monitor] Failed to fetch initial network info: {}", e);
                 }
+                None => {}
             }
 
             // 发送磁盘信息
             match disk_result {
-                Ok(info) => {
+                Some(Ok(info)) => {
+                    for alert in alert_engine.check_disk(&info) {
+                        let _ = data_tx.send(MonitorEvent::Alert(alert));
+                    }
                     let _ = data_tx.send(MonitorEvent::DiskInfo(info));
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     debug!("[Monitor] Failed to fetch initial disk info: {}", e);
                 }
+                None => {}
+            }
+
+            // 检测 GPU：没有 nvidia-smi 或没有 GPU 时后续不再查询，界面隐藏 GPU 卡片
+            Self::handle_gpu_result(gpu_result, &mut gpu_available, &session_id, &data_tx);
+
+            // 发送温度与 SMART 健康状态
+            match temperature_result {
+                Some(Ok(sensors)) => {
+                    for alert in alert_engine.check_temperatures(&sensors) {
+                        let _ = data_tx.send(MonitorEvent::Alert(alert));
+                    }
+                    let _ = data_tx.send(MonitorEvent::Temperatures(sensors));
+                }
+                Some(Err(e)) => {
+                    debug!("[Monitor] No temperature sensors: {}", e);
+                }
+                None => {}
+            }
+            match smart_result {
+                Some(Ok(drives)) => {
+                    for alert in alert_engine.check_drive_health(&drives) {
+                        let _ = data_tx.send(MonitorEvent::Alert(alert));
+                    }
+                    let _ = data_tx.send(MonitorEvent::DriveHealth(drives));
+                }
+                Some(Err(e)) => {
+                    debug!("[Monitor] No SMART data: {}", e);
+                }
+                None => {}
+            }
+
+            // 发送登录信息
+            match login_result {
+                Some(Ok(info)) => {
+                    let _ = data_tx.send(MonitorEvent::LoginInfo(info));
+                }
+                Some(Err(e)) => {
+                    debug!("[Monitor] Failed to fetch login info: {}", e);
+                }
+                None => {}
             }
 
             info!(
@@ -186,13 +593,19 @@ impl MonitorService {
         // ========================================================================
         let load_interval = Duration::from_millis(settings.load_interval_ms);
         let disk_interval = Duration::from_millis(settings.disk_interval_ms);
+        let smart_interval = Duration::from_millis(settings.smart_interval_ms);
+        let login_interval = Duration::from_millis(settings.login_interval_ms);
 
         let mut load_ticker = tokio::time::interval(load_interval);
         let mut disk_ticker = tokio::time::interval(disk_interval);
+        let mut smart_ticker = tokio::time::interval(smart_interval);
+        let mut login_ticker = tokio::time::interval(login_interval);
 
         // 跳过第一个即时触发（因为初始数据已经获取过了）
         load_ticker.tick().await;
         disk_ticker.tick().await;
+        smart_ticker.tick().await;
+        login_ticker.tick().await;
 
         loop {
             tokio::select! {
@@ -208,45 +621,122 @@ impl MonitorService {
                         break;
                     }
 
-                    // 并行获取负载和网络信息
-                    let (load_result, network_result) = tokio::join!(
-                        Self::fetch_load_info(&session),
-                        Self::fetch_network_info(&session)
+                    // 并行获取负载、网络和 GPU 信息
+                    let toggles = CollectorToggles::load();
+                    let (load_result, network_result, gpu_result) = tokio::join!(
+                        fetch_if(toggles.load_info, Self::fetch_load_info(&session, os)),
+                        fetch_if(toggles.network, Self::fetch_network_info(&session, os)),
+                        fetch_if(
+                            toggles.gpu && gpu_available != Some(false),
+                            Self::fetch_gpu_info(&session)
+                        )
                     );
 
                     // 发送负载信息
                     match load_result {
-                        Ok(info) => {
+                        Some(Ok(info)) => {
+                            for alert in alert_engine.check_load(&info, memory_total) {
+                                let _ = data_tx.send(MonitorEvent::Alert(alert));
+                            }
                             let _ = data_tx.send(MonitorEvent::LoadInfo(info));
                         }
-                        Err(e) => {
+                        Some(Err(e)) => {
                             debug!("[Monitor] Failed to fetch load info: {}", e);
                         }
+                        None => {}
                     }
 
                     // 发送网络信息
                     match network_result {
-                        Ok(info) => {
+                        Some(Ok(info)) => {
                             let _ = data_tx.send(MonitorEvent::NetworkInfo(info));
                         }
-                        Err(e) => {
+                        Some(Err(e)) => {
                             debug!("[Monitor] Failed to fetch network info: {}", e);
                         }
+                        None => {}
                     }
+
+                    // 发送 GPU 信息
+                    Self::handle_gpu_result(gpu_result, &mut gpu_available, &session_id, &data_tx);
                 }
                 _ = disk_ticker.tick() => {
                     if !session.is_alive() {
                         break;
                     }
 
-                    // 获取磁盘信息
-                    match Self::fetch_disk_info(&session).await {
-                        Ok(info) => {
+                    // 并行获取磁盘信息和温度
+                    let toggles = CollectorToggles::load();
+                    let (disk_result, temperature_result) = tokio::join!(
+                        fetch_if(toggles.disk, Self::fetch_disk_info(&session, os)),
+                        fetch_if(toggles.health, Self::fetch_temperatures(&session))
+                    );
+
+                    // 发送磁盘信息
+                    match disk_result {
+                        Some(Ok(info)) => {
+                            for alert in alert_engine.check_disk(&info) {
+                                let _ = data_tx.send(MonitorEvent::Alert(alert));
+                            }
                             let _ = data_tx.send(MonitorEvent::DiskInfo(info));
                         }
-                        Err(e) => {
+                        Some(Err(e)) => {
                             debug!("[Monitor] Failed to fetch disk info: {}", e);
                         }
+                        None => {}
+                    }
+
+                    // 发送温度
+                    match temperature_result {
+                        Some(Ok(sensors)) => {
+                            for alert in alert_engine.check_temperatures(&sensors) {
+                                let _ = data_tx.send(MonitorEvent::Alert(alert));
+                            }
+                            let _ = data_tx.send(MonitorEvent::Temperatures(sensors));
+                        }
+                        Some(Err(e)) => {
+                            trace!("[Monitor] Failed to fetch temperatures: {}", e);
+                        }
+                        None => {}
+                    }
+                }
+                _ = smart_ticker.tick() => {
+                    if !session.is_alive() {
+                        break;
+                    }
+                    if !CollectorToggles::load().health {
+                        continue;
+                    }
+
+                    // 获取 SMART 健康状态
+                    match Self::fetch_drive_health(&session).await {
+                        Ok(drives) => {
+                            for alert in alert_engine.check_drive_health(&drives) {
+                                let _ = data_tx.send(MonitorEvent::Alert(alert));
+                            }
+                            let _ = data_tx.send(MonitorEvent::DriveHealth(drives));
+                        }
+                        Err(e) => {
+                            trace!("[Monitor] Failed to fetch SMART data: {}", e);
+                        }
+                    }
+                }
+                _ = login_ticker.tick() => {
+                    if !session.is_alive() {
+                        break;
+                    }
+                    if !CollectorToggles::load().logins {
+                        continue;
+                    }
+
+                    // 获取登录信息
+                    match Self::fetch_login_info(&session).await {
+                        Ok(info) => {
+                            let _ = data_tx.send(MonitorEvent::LoginInfo(info));
+                        }
+                        Err(e) => {
+                            debug!("[Monitor] Failed to fetch login info: {}", e);
+                        }
                     }
                 }
             }
@@ -255,12 +745,62 @@ impl MonitorService {
         info!("[Monitor] Polling loop ended for session {}", session_id);
     }
 
+    /// 处理 GPU 查询结果：首次查询失败视为没有 GPU，之后不再查询
+    fn handle_gpu_result(
+        result: Option<Result<GpuInfo, String>>,
+        gpu_available: &mut Option<bool>,
+        session_id: &str,
+        data_tx: &mpsc::UnboundedSender<MonitorEvent>,
+    ) {
+        match result {
+            Some(Ok(info)) => {
+                if gpu_available.is_none() {
+                    info!(
+                        "[Monitor] Detected {} GPU(s) for session {}",
+                        info.gpus.len(),
+                        session_id
+                    );
+                }
+                *gpu_available = Some(true);
+                let _ = data_tx.send(MonitorEvent::GpuInfo(info));
+            }
+            Some(Err(e)) => {
+                if gpu_available.is_none() {
+                    debug!("[Monitor] No GPU detected: {}", e);
+                    *gpu_available = Some(false);
+                } else {
+                    debug!("[Monitor] Failed to fetch GPU info: {}", e);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// 检测远端系统类型（uname -s；Linux 上缺少 procps / coreutils 时视为 BusyBox）
+    async fn detect_os(session: &Arc<SshSession>) -> Result<RemoteOs, String> {
+        let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+        let output = exec
+            .exec(OS_DETECT_SCRIPT)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let name = output.stdout_string().trim().to_string();
+        RemoteOs::from_uname(&name).ok_or_else(|| {
+            if name.is_empty() {
+                "Failed to detect remote OS".to_string()
+            } else {
+                format!("Unsupported remote OS: {}", name)
+            }
+        })
+    }
+
     /// 获取系统信息
     async fn fetch_system_info(
         session: &Arc<SshSession>,
         session_id: &str,
+        os: RemoteOs,
     ) -> Result<SystemInfo, String> {
-        let script = SYSTEM_INFO_SCRIPT;
+        let script = os.system_info_script();
 
         let exec = session.open_exec().await.map_err(|e| e.to_string())?;
         let output = exec.exec(script).await.map_err(|e| e.to_string())?;
@@ -319,8 +859,8 @@ impl MonitorService {
     }
 
     /// 获取负载信息
-    async fn fetch_load_info(session: &Arc<SshSession>) -> Result<LoadInfo, String> {
-        let script = LOAD_INFO_SCRIPT;
+    async fn fetch_load_info(session: &Arc<SshSession>, os: RemoteOs) -> Result<LoadInfo, String> {
+        let script = os.load_info_script();
 
         let exec = session.open_exec().await.map_err(|e| e.to_string())?;
         let output = exec.exec(script).await.map_err(|e| e.to_string())?;
@@ -380,14 +920,119 @@ impl MonitorService {
                 cached_bytes: parsed["memory"]["cached_bytes"].as_u64().unwrap_or(0),
                 swap_used_bytes: parsed["memory"]["swap_used_bytes"].as_u64().unwrap_or(0),
             },
+            disk_io: DiskIoInfo {
+                read_bytes: parsed["disk_io"]["read_bytes"].as_u64().unwrap_or(0),
+                write_bytes: parsed["disk_io"]["write_bytes"].as_u64().unwrap_or(0),
+            },
             top_cpu_processes: parse_processes("top_cpu_processes"),
             top_memory_processes: parse_processes("top_memory_processes"),
         })
     }
 
+    /// 获取 GPU 信息（远端没有 nvidia-smi 或没有 GPU 时返回错误）
+    async fn fetch_gpu_info(session: &Arc<SshSession>) -> Result<GpuInfo, String> {
+        let script = GPU_INFO_SCRIPT;
+
+        let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+        let output = exec.exec(script).await.map_err(|e| e.to_string())?;
+
+        if !output.is_success() {
+            return Err(format!(
+                "Command failed with exit code {}: {}",
+                output.exit_code,
+                output.stderr_string()
+            ));
+        }
+
+        let text = output.stdout_string();
+        trace!("[Monitor] GPU info raw output: {}", text);
+
+        let info = parse_gpu_info(&text);
+        if info.gpus.is_empty() {
+            return Err("No GPU found".to_string());
+        }
+        Ok(info)
+    }
+
+    /// 获取温度传感器读数（优先 `sensors -j`，没有 lm-sensors 时读取 thermal_zone）
+    async fn fetch_temperatures(
+        session: &Arc<SshSession>,
+    ) -> Result<Vec<TemperatureSensor>, String> {
+        let script = TEMPERATURE_SCRIPT;
+
+        let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+        let output = exec.exec(script).await.map_err(|e| e.to_string())?;
+
+        if !output.is_success() {
+            return Err(format!(
+                "Command failed with exit code {}: {}",
+                output.exit_code,
+                output.stderr_string()
+            ));
+        }
+
+        let text = output.stdout_string();
+        trace!("[Monitor] Temperature raw output: {}", text);
+
+        let sensors = parse_temperatures(&text);
+        if sensors.is_empty() {
+            return Err("No temperature sensors found".to_string());
+        }
+        Ok(sensors)
+    }
+
+    /// 获取硬盘 SMART 健康状态（需要 smartctl；非 root 用户仅在免密 sudo 时能读取）
+    async fn fetch_drive_health(session: &Arc<SshSession>) -> Result<Vec<DriveHealth>, String> {
+        let script = SMART_HEALTH_SCRIPT;
+
+        let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+        let output = exec.exec(script).await.map_err(|e| e.to_string())?;
+
+        if !output.is_success() {
+            return Err(format!(
+                "Command failed with exit code {}: {}",
+                output.exit_code,
+                output.stderr_string()
+            ));
+        }
+
+        let text = output.stdout_string();
+        trace!("[Monitor] SMART raw output: {}", text);
+
+        let drives = parse_drive_health(&text);
+        if drives.is_empty() {
+            return Err("No SMART capable drives found".to_string());
+        }
+        Ok(drives)
+    }
+
+    /// 获取登录信息（当前会话、最近登录和失败登录）
+    async fn fetch_login_info(session: &Arc<SshSession>) -> Result<LoginInfo, String> {
+        let script = LOGIN_INFO_SCRIPT;
+
+        let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+        let output = exec.exec(script).await.map_err(|e| e.to_string())?;
+
+        if !output.is_success() {
+            return Err(format!(
+                "Command failed with exit code {}: {}",
+                output.exit_code,
+                output.stderr_string()
+            ));
+        }
+
+        let text = output.stdout_string();
+        trace!("[Monitor] Login info raw output: {}", text);
+
+        Ok(parse_login_info(&text))
+    }
+
     /// 获取网络信息
-    async fn fetch_network_info(session: &Arc<SshSession>) -> Result<NetworkInfo, String> {
-        let script = NETWORK_INFO_SCRIPT;
+    async fn fetch_network_info(
+        session: &Arc<SshSession>,
+        os: RemoteOs,
+    ) -> Result<NetworkInfo, String> {
+        let script = os.network_info_script();
 
         let exec = session.open_exec().await.map_err(|e| e.to_string())?;
         let output = exec.exec(script).await.map_err(|e| e.to_string())?;
@@ -446,8 +1091,8 @@ impl MonitorService {
     }
 
     /// 获取磁盘信息
-    async fn fetch_disk_info(session: &Arc<SshSession>) -> Result<DiskInfo, String> {
-        let script = DISK_INFO_SCRIPT;
+    async fn fetch_disk_info(session: &Arc<SshSession>, os: RemoteOs) -> Result<DiskInfo, String> {
+        let script = os.disk_info_script();
 
         let exec = session.open_exec().await.map_err(|e| e.to_string())?;
         let output = exec.exec(script).await.map_err(|e| e.to_string())?;
@@ -499,10 +1144,242 @@ impl Drop for MonitorService {
     }
 }
 
+/// 解析 nvidia-smi 输出（GPU 列表与计算进程列表以 `---` 分隔，均为无表头、无单位的 CSV）
+fn parse_gpu_info(output: &str) -> GpuInfo {
+    // 不支持的字段输出为 [N/A] 或 [Not Supported]
+    let number = |value: &str| value.trim().parse::<f64>().ok();
+    const MIB: f64 = 1024.0 * 1024.0;
+
+    let (gpu_part, process_part) = output.split_once("---").unwrap_or((output, ""));
+    let gpus = gpu_part
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() < 7 {
+                return None;
+            }
+            Some(GpuDeviceInfo {
+                index: fields[0].parse().ok()?,
+                name: fields[1].to_string(),
+                uuid: fields[2].to_string(),
+                utilization_percent: number(fields[3]).unwrap_or(0.0) as f32,
+                memory_used_bytes: (number(fields[4]).unwrap_or(0.0) * MIB) as u64,
+                memory_total_bytes: (number(fields[5]).unwrap_or(0.0) * MIB) as u64,
+                temperature_celsius: number(fields[6]).map(|t| t as f32),
+            })
+        })
+        .collect();
+
+    let processes = process_part
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() < 4 {
+                return None;
+            }
+            // 进程名中可能包含逗号，显存始终是最后一列
+            let last = fields.len() - 1;
+            let path = fields[2..last].join(",");
+            Some(GpuProcessInfo {
+                gpu_uuid: fields[0].to_string(),
+                pid: fields[1].parse().ok()?,
+                name: path.rsplit('/').next().unwrap_or(&path).to_string(),
+                memory_used_bytes: (number(fields[last]).unwrap_or(0.0) * MIB) as u64,
+            })
+        })
+        .collect();
+
+    GpuInfo { gpus, processes }
+}
+
+/// 解析温度输出：`sensors -j` 的 JSON，或以 `THERMAL` 开头的 thermal_zone 列表（类型|毫摄氏度）
+fn parse_temperatures(output: &str) -> Vec<TemperatureSensor> {
+    if let Some(zones) = output.trim_start().strip_prefix("THERMAL") {
+        return zones
+            .lines()
+            .filter_map(|line| {
+                let (label, value) = line.split_once('|')?;
+                let celsius = value.trim().parse::<f32>().ok()? / 1000.0;
+                (celsius > 0.0).then(|| TemperatureSensor {
+                    chip: String::new(),
+                    label: label.trim().to_string(),
+                    celsius,
+                    high: None,
+                    critical: None,
+                })
+            })
+            .collect();
+    }
+
+    let Ok(serde_json::Value::Object(chips)) = serde_json::from_str::<serde_json::Value>(output)
+    else {
+        return Vec::new();
+    };
+    let mut sensors = Vec::new();
+    for (chip, features) in &chips {
+        let Some(features) = features.as_object() else {
+            continue;
+        };
+        for (label, values) in features {
+            let Some(values) = values.as_object() else {
+                continue;
+            };
+            // 每个温度特征包含 tempN_input，以及可选的 tempN_max / tempN_crit
+            for (key, value) in values {
+                let Some(prefix) = key
+                    .strip_suffix("_input")
+                    .filter(|prefix| prefix.starts_with("temp"))
+                else {
+                    continue;
+                };
+                let Some(celsius) = value.as_f64().map(|v| v as f32).filter(|v| *v > 0.0) else {
+                    continue;
+                };
+                let limit = |suffix: &str| {
+                    values
+                        .get(&format!("{}_{}", prefix, suffix))
+                        .and_then(|v| v.as_f64())
+                        .map(|v| v as f32)
+                        .filter(|v| *v > 0.0)
+                };
+                sensors.push(TemperatureSensor {
+                    chip: chip.clone(),
+                    label: label.clone(),
+                    celsius,
+                    high: limit("max"),
+                    critical: limit("crit"),
+                });
+            }
+        }
+    }
+    sensors
+}
+
+/// 解析 SMART 输出：每行 `设备|型号|状态`，状态为空表示无法读取（无权限或不支持 SMART）
+fn parse_drive_health(output: &str) -> Vec<DriveHealth> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '|');
+            let device = fields.next()?.trim();
+            if device.is_empty() {
+                return None;
+            }
+            let model = fields.next().unwrap_or("").trim().to_string();
+            let status = fields.next().unwrap_or("").trim().to_string();
+            let passed = match status.as_str() {
+                "" => None,
+                "PASSED" | "OK" => Some(true),
+                _ => Some(false),
+            };
+            Some(DriveHealth {
+                device: device.to_string(),
+                model,
+                passed,
+                status,
+            })
+        })
+        .collect()
+}
+
+/// 解析登录信息输出：WHO / LAST / LASTB 三段，lastb 无权限时该段为 UNAVAILABLE
+fn parse_login_info(output: &str) -> LoginInfo {
+    let mut info = LoginInfo::default();
+    let mut section = "";
+    for line in output.lines() {
+        let line = line.trim_end();
+        match line {
+            "WHO" | "LAST" | "LASTB" => {
+                section = line;
+                if section == "LASTB" {
+                    info.failed_available = true;
+                }
+                continue;
+            }
+            "UNAVAILABLE" if section == "LASTB" => {
+                info.failed_available = false;
+                continue;
+            }
+            _ => {}
+        }
+        match section {
+            "WHO" => info.sessions.extend(parse_who_line(line)),
+            "LAST" => info.recent.extend(parse_last_line(line)),
+            "LASTB" => info.failed.extend(parse_last_line(line)),
+            _ => {}
+        }
+    }
+    info
+}
+
+/// 解析 who 的一行：USER TTY DATE TIME [(FROM)]
+fn parse_who_line(line: &str) -> Option<LoginRecord> {
+    let mut fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 3 {
+        return None;
+    }
+    let from = match fields.last() {
+        Some(last) if last.starts_with('(') => {
+            let from = last.trim_matches(|c| c == '(' || c == ')').to_string();
+            fields.pop();
+            from
+        }
+        _ => String::new(),
+    };
+    Some(LoginRecord {
+        user: fields[0].to_string(),
+        tty: fields[1].to_string(),
+        from,
+        time: fields[2..].join(" "),
+    })
+}
+
+/// 解析 last / lastb 的一行：USER TTY [FROM] TIME...，跳过 reboot 记录和末尾的 "wtmp begins" 提示
+fn parse_last_line(line: &str) -> Option<LoginRecord> {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 4 || matches!(fields[0], "reboot" | "shutdown" | "wtmp" | "btmp") {
+        return None;
+    }
+    // 本地登录可能没有来源地址列，此时第三列已经是星期
+    let (from, time_start) = if WEEKDAYS.contains(&fields[2]) {
+        (String::new(), 2)
+    } else {
+        (fields[2].to_string(), 3)
+    };
+    Some(LoginRecord {
+        user: fields[0].to_string(),
+        tty: fields[1].to_string(),
+        // last -i 对本地登录显示 0.0.0.0
+        from: if from == "0.0.0.0" {
+            String::new()
+        } else {
+            from
+        },
+        time: fields[time_start..].join(" "),
+    })
+}
+
 // ============================================================================
 // Shell 脚本
 // ============================================================================
 
+/// 系统检测脚本（连接后执行一次）
+const OS_DETECT_SCRIPT: &str = r#"
+os=$(uname -s 2>/dev/null)
+if [ "$os" = "Linux" ]; then
+    # Linux 脚本依赖 procps 的 ps --sort 和 coreutils 的 df -B1 -T，缺少时改用 BusyBox 脚本
+    if ps aux --sort=-%cpu >/dev/null 2>&1 && df -B1 -T / >/dev/null 2>&1; then
+        echo "Linux"
+    else
+        echo "BusyBox"
+    fi
+else
+    echo "$os"
+fi
+"#;
+
 /// 系统信息脚本（执行一次）
 const SYSTEM_INFO_SCRIPT: &str = r#"
 hostname=$(hostname 2>/dev/null || echo "unknown")
@@ -607,6 +1484,16 @@ else
     swap_used=0
 fi
 
+# 磁盘 IO 累计字节数（只统计整块磁盘，扇区按 512 字节计算）
+if [ -f /proc/diskstats ]; then
+    disk_io=$(awk '$3 ~ /^(sd[a-z]+|vd[a-z]+|xvd[a-z]+|hd[a-z]+|nvme[0-9]+n[0-9]+|mmcblk[0-9]+)$/ {r += $6; w += $10} END {printf "%.0f %.0f", r * 512, w * 512}' /proc/diskstats)
+    disk_read=$(echo $disk_io | awk '{print $1}')
+    disk_write=$(echo $disk_io | awk '{print $2}')
+else
+    disk_read=0
+    disk_write=0
+fi
+
 # Top 5 CPU进程
 top_cpu=$(ps aux --sort=-%cpu 2>/dev/null | head -6 | tail -5 | awk '{gsub(/"/, "\\\"", $11); printf "{\"pid\":%s,\"name\":\"%s\",\"cpu_percent\":%s,\"memory_percent\":%s,\"user\":\"%s\"},", $2, $11, $3, $4, $1}' | sed 's/,$//' || echo "")
 
@@ -627,6 +1514,10 @@ cat <<EOF
     "cached_bytes": $mem_cached,
     "swap_used_bytes": $swap_used
   },
+  "disk_io": {
+    "read_bytes": $disk_read,
+    "write_bytes": $disk_write
+  },
   "top_cpu_processes": [$top_cpu],
   "top_memory_processes": [$top_mem]
 }
@@ -748,3 +1639,414 @@ cat <<EOF
 }
 EOF
 "#;
+
+/// GPU 状态脚本（每 2 秒，仅在检测到 nvidia-smi 时）
+const GPU_INFO_SCRIPT: &str = r#"
+command -v nvidia-smi >/dev/null 2>&1 || exit 1
+nvidia-smi --query-gpu=index,name,uuid,utilization.gpu,memory.used,memory.total,temperature.gpu --format=csv,noheader,nounits || exit 1
+echo '---'
+nvidia-smi --query-compute-apps=gpu_uuid,pid,process_name,used_memory --format=csv,noheader,nounits 2>/dev/null
+"#;
+
+/// 温度脚本（每 10 秒）
+const TEMPERATURE_SCRIPT: &str = r#"
+if command -v sensors >/dev/null 2>&1; then
+    sensors -j 2>/dev/null && exit 0
+fi
+# 没有 lm-sensors 时回退到 thermal_zone（单位为毫摄氏度）
+echo "THERMAL"
+for zone in /sys/class/thermal/thermal_zone*; do
+    [ -f "$zone/temp" ] || continue
+    echo "$(cat "$zone/type" 2>/dev/null)|$(cat "$zone/temp" 2>/dev/null)"
+done
+"#;
+
+/// SMART 健康状态脚本（每 5 分钟）
+const SMART_HEALTH_SCRIPT: &str = r#"
+command -v smartctl >/dev/null 2>&1 || exit 0
+SMARTCTL="smartctl"
+# 非 root 用户只在免密 sudo 时读取（不会弹出密码提示）
+if [ "$(id -u)" != "0" ] && sudo -n true 2>/dev/null; then
+    SMARTCTL="sudo -n smartctl"
+fi
+for dev in $(lsblk -dno NAME,TYPE 2>/dev/null | awk '$2 == "disk" {print $1}'); do
+    out=$($SMARTCTL -H -i "/dev/$dev" 2>/dev/null)
+    model=$(echo "$out" | awk -F: '/^(Device Model|Model Number|Product):/ {sub(/^[ \t]+/, "", $2); print $2; exit}')
+    status=$(echo "$out" | awk -F: '/overall-health self-assessment test result|SMART Health Status/ {sub(/^[ \t]+/, "", $2); print $2; exit}')
+    echo "$dev|$model|$status"
+done
+"#;
+
+/// 登录信息脚本（每分钟）
+const LOGIN_INFO_SCRIPT: &str = r#"
+echo "WHO"
+who 2>/dev/null
+echo "LAST"
+last -n 20 -w -i 2>/dev/null || last -n 20 2>/dev/null
+echo "LASTB"
+# lastb 需要读取 /var/log/btmp，非 root 用户只在免密 sudo 时读取（不会弹出密码提示）
+if [ "$(id -u)" = "0" ]; then
+    lastb -n 20 -w -i 2>/dev/null || lastb -n 20 2>/dev/null
+elif sudo -n true 2>/dev/null; then
+    sudo -n lastb -n 20 -w -i 2>/dev/null || sudo -n lastb -n 20 2>/dev/null
+else
+    echo "UNAVAILABLE"
+fi
+true
+"#;
+
+// ============================================================================
+// 非 Linux 主机的采集脚本（输出与 Linux 脚本相同的 JSON）
+// ============================================================================
+
+/// BusyBox 系统信息脚本（执行一次，只依赖 /proc 和 BusyBox 自带的命令）
+const BUSYBOX_SYSTEM_INFO_SCRIPT: &str = r#"
+hostname=$(hostname 2>/dev/null || cat /proc/sys/kernel/hostname 2>/dev/null || echo "unknown")
+if [ -f /etc/os-release ]; then
+    os=$(. /etc/os-release && echo "${PRETTY_NAME:-${NAME} ${VERSION_ID}}" 2>/dev/null || echo "Linux")
+elif [ -f /etc/openwrt_release ]; then
+    os=$(. /etc/openwrt_release && echo "${DISTRIB_DESCRIPTION:-OpenWrt}" 2>/dev/null || echo "OpenWrt")
+else
+    os="Linux (BusyBox)"
+fi
+kernel=$(uname -r 2>/dev/null || echo "")
+uptime_seconds=$(awk '{print int($1)}' /proc/uptime 2>/dev/null || echo "0")
+
+# CPU 信息（ARM / MIPS 设备的 cpuinfo 没有 model name，改用 Hardware / cpu model / system type）
+cpu_model=$(awk -F: '/^(model name|Hardware|cpu model|system type)/ {sub(/^[ \t]+/, "", $2); print $2; exit}' /proc/cpuinfo 2>/dev/null)
+[ -z "$cpu_model" ] && cpu_model="Unknown"
+cores_logical=$(grep -c '^processor' /proc/cpuinfo 2>/dev/null)
+[ "${cores_logical:-0}" -gt 0 ] 2>/dev/null || cores_logical=1
+cores_physical=$(awk -F: '/^physical id/ {p = $2} /^core id/ {seen[p ":" $2] = 1} END {n = 0; for (k in seen) n++; print n}' /proc/cpuinfo 2>/dev/null)
+[ "${cores_physical:-0}" -gt 0 ] 2>/dev/null || cores_physical=$cores_logical
+architecture=$(uname -m 2>/dev/null || echo "unknown")
+
+mem_total=$(awk '/^MemTotal:/ {printf "%.0f", $2 * 1024}' /proc/meminfo 2>/dev/null)
+swap_total=$(awk '/^SwapTotal:/ {printf "%.0f", $2 * 1024}' /proc/meminfo 2>/dev/null)
+
+cat <<EOF
+{
+  "host": {
+    "hostname": "$hostname",
+    "os": "$os",
+    "kernel": "$kernel",
+    "uptime_seconds": ${uptime_seconds:-0}
+  },
+  "cpu": {
+    "model": "$cpu_model",
+    "cores_physical": $cores_physical,
+    "cores_logical": $cores_logical,
+    "architecture": "$architecture"
+  },
+  "memory": {
+    "total_bytes": ${mem_total:-0},
+    "swap_total_bytes": ${swap_total:-0}
+  }
+}
+EOF
+"#;
+
+/// BusyBox 系统负载脚本（每 2 秒，内存直接读取 /proc/meminfo，进程列表来自 BusyBox top）
+const BUSYBOX_LOAD_INFO_SCRIPT: &str = r#"
+timestamp=$(date +%s)
+
+# CPU使用率
+cpu_usage=$(head -1 /proc/stat 2>/dev/null | awk '{t = $2 + $3 + $4 + $5; if (t > 0) printf "%.1f", (t - $5) / t * 100; else print 0}')
+[ -z "$cpu_usage" ] && cpu_usage=0
+
+# 负载
+load_avg=$(awk '{print $1","$2","$3}' /proc/loadavg 2>/dev/null)
+[ -z "$load_avg" ] && load_avg="0,0,0"
+
+# 内存详情（旧内核没有 MemAvailable，按 MemFree + Buffers + Cached 估算）
+mem=$(awk '/^MemTotal:/ {t = $2} /^MemFree:/ {f = $2} /^MemAvailable:/ {a = $2} /^Buffers:/ {b = $2} /^Cached:/ {c = $2} /^SwapTotal:/ {st = $2} /^SwapFree:/ {sf = $2} END {if (a == "") a = f + b + c; printf "%.0f %.0f %.0f %.0f %.0f", (t - a) * 1024, a * 1024, b * 1024, c * 1024, (st - sf) * 1024}' /proc/meminfo 2>/dev/null)
+set -- $mem
+mem_used=${1:-0}
+mem_available=${2:-0}
+mem_buffers=${3:-0}
+mem_cached=${4:-0}
+swap_used=${5:-0}
+
+# 磁盘 IO 累计字节数（只统计整块磁盘，扇区按 512 字节计算）
+if [ -f /proc/diskstats ]; then
+    disk_io=$(awk '$3 ~ /^(sd[a-z]+|vd[a-z]+|xvd[a-z]+|hd[a-z]+|nvme[0-9]+n[0-9]+|mmcblk[0-9]+)$/ {r += $6; w += $10} END {printf "%.0f %.0f", r * 512, w * 512}' /proc/diskstats)
+    disk_read=$(echo $disk_io | awk '{print $1}')
+    disk_write=$(echo $disk_io | awk '{print $2}')
+else
+    disk_read=0
+    disk_write=0
+fi
+
+# 进程列表：按表头定位 BusyBox top 的列（没有 %MEM 时用 %VSZ 代替）
+procs=$(top -b -n 1 2>/dev/null | awk '
+    !h && $1 == "PID" {for (i = 1; i <= NF; i++) col[$i] = i; h = 1; next}
+    h && $1 ~ /^[0-9]+$/ {
+        c = col["%CPU"]; m = col["%MEM"] ? col["%MEM"] : col["%VSZ"]
+        cpu = c ? $c : 0; mem = m ? $m : 0
+        gsub(/%/, "", cpu); gsub(/%/, "", mem)
+        name = $(col["COMMAND"]); sub(/.*\//, "", name); gsub(/"/, "\\\"", name)
+        printf "%s %s %s %s %s\n", $(col["PID"]), cpu + 0, mem + 0, $(col["USER"]), name
+    }')
+top_json() {
+    echo "$procs" | sort -k"$1" -nr | head -5 | awk 'NF >= 5 {printf "{\"pid\":%s,\"name\":\"%s\",\"cpu_percent\":%s,\"memory_percent\":%s,\"user\":\"%s\"},", $1, $5, $2, $3, $4}' | sed 's/,$//'
+}
+top_cpu=$(top_json 2)
+top_mem=$(top_json 3)
+
+cat <<EOF
+{
+  "timestamp": $timestamp,
+  "cpu": {
+    "usage_percent": $cpu_usage,
+    "load_average": [$load_avg]
+  },
+  "memory": {
+    "used_bytes": $mem_used,
+    "available_bytes": $mem_available,
+    "buffers_bytes": $mem_buffers,
+    "cached_bytes": $mem_cached,
+    "swap_used_bytes": $swap_used
+  },
+  "disk_io": {
+    "read_bytes": $disk_read,
+    "write_bytes": $disk_write
+  },
+  "top_cpu_processes": [$top_cpu],
+  "top_memory_processes": [$top_mem]
+}
+EOF
+"#;
+
+/// BusyBox 磁盘状态脚本（每 10 秒）
+/// BusyBox df 不支持 -B1 / -T：用 df -P 防止长设备名换行，文件系统类型取自 /proc/mounts
+const BUSYBOX_DISK_INFO_SCRIPT: &str = r#"
+timestamp=$(date +%s)
+
+disks=$( { df -P -i 2>/dev/null | awk 'NR > 1 && NF >= 6 {print "I", $NF, $2, $3, $4}'; df -P -k 2>/dev/null | awk 'NR > 1 && NF >= 6 {print "D", $1, $2, $3, $4, $NF}'; } | awk '
+    FILENAME == "/proc/mounts" {type[$2] = $3; next}
+    $1 == "I" {it[$2] = $3; iu[$2] = $4; ia[$2] = $5; next}
+    $1 == "D" {
+        mp = $6; fs = type[mp]
+        if ($2 == "none" || fs ~ /^(tmpfs|devtmpfs|squashfs|overlay|ramfs|proc|sysfs|devpts|cgroup2?|debugfs|tracefs|securityfs|pstore|bpf|mqueue|hugetlbfs|configfs|fusectl)$/) next
+        total = $3 * 1024; if (total == 0) next
+        used = $4 * 1024; avail = $5 * 1024
+        printf "%s{\"device\":\"%s\",\"mount_point\":\"%s\",\"fs_type\":\"%s\",\"total_bytes\":%.0f,\"used_bytes\":%.0f,\"available_bytes\":%.0f,\"usage_percent\":%.1f,\"inodes_total\":%.0f,\"inodes_used\":%.0f,\"inodes_available\":%.0f}", (n++ ? "," : ""), $2, mp, fs, total, used, avail, used / total * 100, it[mp], iu[mp], ia[mp]
+    }' /proc/mounts - )
+
+cat <<EOF
+{
+  "timestamp": $timestamp,
+  "disks": [$disks]
+}
+EOF
+"#;
+
+/// macOS / FreeBSD 系统信息脚本（执行一次）
+const BSD_SYSTEM_INFO_SCRIPT: &str = r#"
+export LC_ALL=C
+hostname=$(hostname 2>/dev/null || echo "unknown")
+kernel=$(uname -r 2>/dev/null || echo "")
+if command -v sw_vers >/dev/null 2>&1; then
+    os="$(sw_vers -productName 2>/dev/null) $(sw_vers -productVersion 2>/dev/null)"
+    cpu_model=$(sysctl -n machdep.cpu.brand_string 2>/dev/null || echo "Unknown")
+    cores_physical=$(sysctl -n hw.physicalcpu 2>/dev/null || echo "1")
+    cores_logical=$(sysctl -n hw.logicalcpu 2>/dev/null || echo "1")
+    mem_total=$(sysctl -n hw.memsize 2>/dev/null || echo "0")
+    # vm.swapusage 形如 "total = 2048.00M  used = 1023.25M  free = 1024.75M"
+    swap_total=$(sysctl -n vm.swapusage 2>/dev/null | awk '{v = $3; u = substr(v, length(v)); m = (u == "G") ? 1073741824 : (u == "M") ? 1048576 : 1024; printf "%.0f", v * m}')
+else
+    os="$(uname -s) $(freebsd-version 2>/dev/null || uname -r)"
+    cpu_model=$(sysctl -n hw.model 2>/dev/null || echo "Unknown")
+    cores_logical=$(sysctl -n hw.ncpu 2>/dev/null || echo "1")
+    cores_physical=$(sysctl -n kern.smp.cores 2>/dev/null || echo "$cores_logical")
+    mem_total=$(sysctl -n hw.physmem 2>/dev/null || echo "0")
+    swap_total=$(swapinfo -k 2>/dev/null | awk 'NR > 1 && $1 != "Total" {t += $2} END {printf "%.0f", t * 1024}')
+fi
+architecture=$(uname -m 2>/dev/null || echo "unknown")
+
+# kern.boottime 形如 "{ sec = 1700000000, usec = 0 } ..."
+boot_time=$(sysctl -n kern.boottime 2>/dev/null | sed 's/^{ sec = \([0-9]*\).*/\1/')
+now=$(date +%s)
+uptime_seconds=$((now - ${boot_time:-$now}))
+
+cat <<EOF
+{
+  "host": {
+    "hostname": "$hostname",
+    "os": "$os",
+    "kernel": "$kernel",
+    "uptime_seconds": $uptime_seconds
+  },
+  "cpu": {
+    "model": "$cpu_model",
+    "cores_physical": $cores_physical,
+    "cores_logical": $cores_logical,
+    "architecture": "$architecture"
+  },
+  "memory": {
+    "total_bytes": $mem_total,
+    "swap_total_bytes": ${swap_total:-0}
+  }
+}
+EOF
+"#;
+
+/// macOS / FreeBSD 系统负载脚本（每 2 秒）
+const BSD_LOAD_INFO_SCRIPT: &str = r#"
+export LC_ALL=C
+timestamp=$(date +%s)
+
+# vm.loadavg 形如 "{ 1.23 1.10 1.00 }"
+load_avg=$(sysctl -n vm.loadavg 2>/dev/null | awk '{print $2","$3","$4}')
+[ -z "$load_avg" ] && load_avg="0,0,0"
+mem_buffers=0
+mem_cached=0
+
+if command -v vm_stat >/dev/null 2>&1; then
+    # macOS：CPU 使用率取 top 的 user + sys，内存按 vm_stat 的页数计算
+    cpu_usage=$(top -l 1 -n 0 2>/dev/null | awk '/CPU usage/ {gsub("%", ""); printf "%.1f", $3 + $5}')
+    vm=$(vm_stat 2>/dev/null)
+    page_size=$(echo "$vm" | awk '/page size of/ {for (i = 1; i <= NF; i++) if ($i == "of") print $(i + 1)}')
+    [ -z "$page_size" ] && page_size=4096
+    pages() { echo "$vm" | awk -F: -v key="$1" '$1 == key {gsub(/[ .]/, "", $2); n = $2} END {print n + 0}'; }
+    mem_used=$((($(pages "Pages active") + $(pages "Pages wired down") + $(pages "Pages occupied by compressor")) * page_size))
+    mem_available=$((($(pages "Pages free") + $(pages "Pages inactive") + $(pages "Pages speculative")) * page_size))
+    mem_cached=$(($(pages "File-backed pages") * page_size))
+    swap_used=$(sysctl -n vm.swapusage 2>/dev/null | awk '{v = $6; u = substr(v, length(v)); m = (u == "G") ? 1073741824 : (u == "M") ? 1048576 : 1024; printf "%.0f", v * m}')
+    # 磁盘 IO：优先读取 ioreg 中各块设备的累计读写字节数
+    disk_io=$(ioreg -c IOBlockStorageDriver -r -w 0 2>/dev/null | awk '/"Statistics"/ {
+        if (match($0, /"Bytes \(Read\)"=[0-9]+/)) r += substr($0, RSTART + 15, RLENGTH - 15)
+        if (match($0, /"Bytes \(Write\)"=[0-9]+/)) w += substr($0, RSTART + 16, RLENGTH - 16)
+        n++
+    } END {if (n) printf "%.0f %.0f", r, w}')
+    if [ -z "$disk_io" ]; then
+        # 没有 ioreg 时用 iostat 的累计传输量（MB，不区分读写，全部计入读取）
+        disk_io=$(iostat -Id 2>/dev/null | awk 'NR == 3 {for (i = 3; i <= NF; i += 3) t += $i} END {printf "%.0f 0", t * 1048576}')
+    fi
+else
+    # FreeBSD：CPU 使用率按 kern.cp_time（user nice sys intr idle）累计值计算，内存按页数计算
+    cpu_usage=$(sysctl -n kern.cp_time 2>/dev/null | awk '{t = $1 + $2 + $3 + $4 + $5; if (t > 0) printf "%.1f", (t - $5) / t * 100; else print 0}')
+    page_size=$(sysctl -n hw.pagesize 2>/dev/null || echo 4096)
+    vm_count() { sysctl -n "vm.stats.vm.$1" 2>/dev/null || echo 0; }
+    mem_used=$((($(vm_count v_active_count) + $(vm_count v_wire_count)) * page_size))
+    mem_available=$((($(vm_count v_free_count) + $(vm_count v_inactive_count) + $(vm_count v_cache_count)) * page_size))
+    mem_buffers=$(sysctl -n vfs.bufspace 2>/dev/null || echo 0)
+    swap_used=$(swapinfo -k 2>/dev/null | awk 'NR > 1 && $1 != "Total" {u += $3} END {printf "%.0f", u * 1024}')
+    # iostat -I 输出启动以来的累计值（kr/i、kw/i 单位为 KB）
+    disk_io=$(iostat -Ix -d 2>/dev/null | awk 'NF >= 5 && $1 != "device" && $0 !~ /extended/ {r += $4; w += $5} END {printf "%.0f %.0f", r * 1024, w * 1024}')
+fi
+[ -z "$cpu_usage" ] && cpu_usage=0
+[ -z "$swap_used" ] && swap_used=0
+disk_read=$(echo $disk_io | awk '{print $1 + 0}')
+disk_write=$(echo $disk_io | awk '{print $2 + 0}')
+
+# Top 5 进程（ucomm 为不含路径和空格的进程名）
+procs=$(ps -axo pid=,pcpu=,pmem=,user=,ucomm= 2>/dev/null)
+top_json() {
+    echo "$procs" | sort -k"$1" -nr | head -5 | awk 'NF >= 5 {gsub(/"/, "\\\"", $5); printf "{\"pid\":%s,\"name\":\"%s\",\"cpu_percent\":%s,\"memory_percent\":%s,\"user\":\"%s\"},", $1, $5, $2, $3, $4}' | sed 's/,$//'
+}
+top_cpu=$(top_json 2)
+top_mem=$(top_json 3)
+
+cat <<EOF
+{
+  "timestamp": $timestamp,
+  "cpu": {
+    "usage_percent": $cpu_usage,
+    "load_average": [$load_avg]
+  },
+  "memory": {
+    "used_bytes": $mem_used,
+    "available_bytes": $mem_available,
+    "buffers_bytes": $mem_buffers,
+    "cached_bytes": $mem_cached,
+    "swap_used_bytes": $swap_used
+  },
+  "disk_io": {
+    "read_bytes": $disk_read,
+    "write_bytes": $disk_write
+  },
+  "top_cpu_processes": [$top_cpu],
+  "top_memory_processes": [$top_mem]
+}
+EOF
+"#;
+
+/// macOS / FreeBSD 网络状态脚本（每 2 秒）
+const BSD_NETWORK_INFO_SCRIPT: &str = r#"
+export LC_ALL=C
+timestamp=$(date +%s)
+
+# TCP连接统计
+tcp_stats=$(netstat -an -p tcp 2>/dev/null)
+tcp_total=$(echo "$tcp_stats" | grep -c "^tcp" 2>/dev/null | tr -d '\n' || echo "0")
+tcp_established=$(echo "$tcp_stats" | grep -c "ESTABLISHED" 2>/dev/null | tr -d '\n' || echo "0")
+tcp_listen=$(echo "$tcp_stats" | grep -c "LISTEN" 2>/dev/null | tr -d '\n' || echo "0")
+tcp_time_wait=$(echo "$tcp_stats" | grep -c "TIME_WAIT" 2>/dev/null | tr -d '\n' || echo "0")
+
+# 网卡信息：netstat -ibn 中 Network 列为 <Link#N> 的行包含 MAC 和累计流量
+# 按表头定位各列（macOS 没有 Idrop 列）；没有 MAC 的接口（如 lo0）Address 列为空，后面的列整体左移一列
+interfaces=$(netstat -ibn 2>/dev/null | awk '
+    NR == 1 {for (i = 1; i <= NF; i++) col[$i] = i; next}
+    $3 ~ /^<Link#/ && !seen[$1]++ {
+        has_mac = ($4 ~ /^[0-9a-fA-F]+(:[0-9a-fA-F]+)+$/)
+        off = has_mac ? 0 : -1
+        print $1, (has_mac ? $4 : "00:00:00:00:00:00"), $(col["Ipkts"] + off), $(col["Ierrs"] + off), $(col["Ibytes"] + off), $(col["Opkts"] + off), $(col["Oerrs"] + off), $(col["Obytes"] + off)
+    }' | while read name mac rx_packets rx_errors rx_bytes tx_packets tx_errors tx_bytes; do
+    # 跳过隧道、AirDrop 等虚拟接口
+    case "$name" in
+        gif*|stf*|utun*|awdl*|llw*|anpi*|ap[0-9]*|bridge*|pflog*|pfsync*|enc*) continue ;;
+    esac
+    info=$(ifconfig "$name" 2>/dev/null)
+    ips=$(echo "$info" | awk '$1 == "inet" {print $2} $1 == "inet6" && $2 !~ /^fe80/ {sub(/%.*/, "", $2); print $2}' | awk '{printf "%s\"%s\"", (NR > 1 ? "," : ""), $1}')
+    echo "$info" | head -1 | grep -q "<UP" && is_up=true || is_up=false
+    printf '{"name":"%s","mac_address":"%s","ip_addresses":[%s],"rx_bytes":%s,"tx_bytes":%s,"rx_packets":%s,"tx_packets":%s,"rx_errors":%s,"tx_errors":%s,"is_up":%s},' \
+        "$name" "$mac" "$ips" "$rx_bytes" "$tx_bytes" "$rx_packets" "$tx_packets" "$rx_errors" "$tx_errors" "$is_up"
+done)
+
+# 移除末尾逗号
+interfaces=${interfaces%,}
+
+cat <<EOF
+{
+  "timestamp": $timestamp,
+  "global": {
+    "tcp_connections": $tcp_total,
+    "tcp_established": $tcp_established,
+    "tcp_listen": $tcp_listen,
+    "tcp_time_wait": $tcp_time_wait
+  },
+  "interfaces": [$interfaces]
+}
+EOF
+"#;
+
+/// macOS / FreeBSD 磁盘状态脚本（每 10 秒）
+/// mount 输出形如 "/dev/disk1s1 on / (apfs, local, journaled)"，用于查找文件系统类型；
+/// df -k -i 输出：Filesystem 1024-blocks Used Avail Capacity iused ifree %iused Mounted on
+const BSD_DISK_INFO_SCRIPT: &str = r#"
+export LC_ALL=C
+timestamp=$(date +%s)
+
+disks=$( {
+    mount 2>/dev/null | awk '{s = index($0, " on "); rest = substr($0, s + 4); p = index(rest, " ("); t = substr(rest, p + 2); sub(/[,)].*/, "", t); printf "M\t%s\t%s\n", substr(rest, 1, p - 1), t}'
+    df -k -i 2>/dev/null | awk 'NR > 1 && NF >= 9 {mp = $9; for (i = 10; i <= NF; i++) mp = mp " " $i; printf "D\t%s\t%s\t%s\t%s\t%s\t%s\t%s\n", $1, $2, $3, $4, $6, $7, mp}'
+} | awk -F'\t' '
+    $1 == "M" {type[$2] = $3; next}
+    $1 == "D" {
+        mp = $8; fs = type[mp]
+        if (fs ~ /^(devfs|autofs|fdescfs|procfs|linprocfs|linsysfs|tmpfs|nullfs)$/) next
+        # macOS 的系统卷（VM、Preboot 等）只保留数据卷
+        if (mp ~ /^\/System\/Volumes\// && mp != "/System/Volumes/Data") next
+        total = $3 * 1024; if (total == 0) next
+        used = $4 * 1024; avail = $5 * 1024
+        printf "%s{\"device\":\"%s\",\"mount_point\":\"%s\",\"fs_type\":\"%s\",\"total_bytes\":%.0f,\"used_bytes\":%.0f,\"available_bytes\":%.0f,\"usage_percent\":%.1f,\"inodes_total\":%.0f,\"inodes_used\":%.0f,\"inodes_available\":%.0f}", (n++ ? "," : ""), $2, mp, fs, total, used, avail, used / total * 100, $6 + $7, $6, $7
+    }' )
+
+cat <<EOF
+{
+  "timestamp": $timestamp,
+  "disks": [$disks]
+}
+EOF
+"#;
@@ -0,0 +1,119 @@
+// 监控数据导出
+// 把历史采样（CPU / 内存 / 网络 / 磁盘 IO）写成 CSV 或 JSON，便于分享或导入表格
+
+use chrono::{Local, TimeZone};
+
+use crate::models::monitor::MetricSample;
+
+/// 导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Json];
+
+    /// 文件扩展名
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    /// 菜单中显示的名称
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+        }
+    }
+}
+
+/// CSV 表头（速率单位为 bytes/s）
+const CSV_HEADER: &str = "time,timestamp,cpu_percent,memory_percent,rx_bytes_per_sec,tx_bytes_per_sec,disk_read_bytes_per_sec,disk_write_bytes_per_sec";
+
+/// 默认文件名：主机名-monitor-导出时间.扩展名
+pub fn default_file_name(host: &str, format: ExportFormat) -> String {
+    let host: String = host
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!(
+        "{}-monitor-{}.{}",
+        if host.is_empty() { "server" } else { &host },
+        Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    )
+}
+
+/// 把历史采样序列化为指定格式的文本
+pub fn export_samples(
+    samples: &[MetricSample],
+    host: &str,
+    format: ExportFormat,
+) -> Result<String, String> {
+    match format {
+        ExportFormat::Csv => Ok(export_csv(samples)),
+        ExportFormat::Json => export_json(samples, host),
+    }
+}
+
+/// 采样时间转为本地时间字符串（远端时间戳，按本地时区显示）
+fn format_time(timestamp: u64) -> String {
+    Local
+        .timestamp_opt(timestamp as i64, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+fn export_csv(samples: &[MetricSample]) -> String {
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for s in samples {
+        out.push_str(&format!(
+            "{},{},{:.1},{:.1},{:.0},{:.0},{:.0},{:.0}\n",
+            format_time(s.timestamp),
+            s.timestamp,
+            s.cpu_percent,
+            s.memory_percent,
+            s.rx_speed,
+            s.tx_speed,
+            s.disk_read_speed,
+            s.disk_write_speed
+        ));
+    }
+    out
+}
+
+fn export_json(samples: &[MetricSample], host: &str) -> Result<String, String> {
+    let value = serde_json::json!({
+        "host": host,
+        "exported_at": Local::now().to_rfc3339(),
+        "samples": samples
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "time": format_time(s.timestamp),
+                    "timestamp": s.timestamp,
+                    "cpu_percent": s.cpu_percent,
+                    "memory_percent": s.memory_percent,
+                    "rx_bytes_per_sec": s.rx_speed,
+                    "tx_bytes_per_sec": s.tx_speed,
+                    "disk_read_bytes_per_sec": s.disk_read_speed,
+                    "disk_write_bytes_per_sec": s.disk_write_speed,
+                })
+            })
+            .collect::<Vec<_>>(),
+    });
+    serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+}
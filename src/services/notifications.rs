@@ -0,0 +1,261 @@
+// 通知中心：应用内的通知统一经过这里推送——记录到历史（未读计数、按类别筛选），在当前窗口弹出提示，
+// 应用处于后台时按设置发送系统通知（传输完成、会话断开、监控告警）。开启免打扰时只记录，不弹出提示。
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use gpui::{App, AppContext as _, Styled, Window};
+use gpui_component::notification::{Notification, NotificationType};
+use gpui_component::WindowExt;
+use once_cell::sync::Lazy;
+use tracing::warn;
+
+use crate::models::settings::AppSettings;
+use crate::services::storage;
+
+/// 通知中心最多保留的通知条数
+const MAX_NOTIFICATIONS: usize = 300;
+
+/// 通知类别（通知中心按类别筛选）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationCategory {
+    /// 文件传输、同步与 SFTP 操作
+    Transfer,
+    /// 会话连接、断开与重连
+    Session,
+    /// 监控告警
+    Monitor,
+    /// 进程、服务、容器等管理操作及其他
+    Operation,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 4] = [
+        NotificationCategory::Transfer,
+        NotificationCategory::Session,
+        NotificationCategory::Monitor,
+        NotificationCategory::Operation,
+    ];
+
+    /// 类别名称的 i18n 键
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            NotificationCategory::Transfer => "notifications.category.transfer",
+            NotificationCategory::Session => "notifications.category.session",
+            NotificationCategory::Monitor => "notifications.category.monitor",
+            NotificationCategory::Operation => "notifications.category.operation",
+        }
+    }
+
+    /// 该类别在应用处于后台时是否发送系统通知
+    fn system_notification_enabled(&self, settings: &AppSettings) -> bool {
+        match self {
+            NotificationCategory::Transfer => settings.system.notify_on_transfer,
+            NotificationCategory::Session => settings.system.notify_on_disconnect,
+            NotificationCategory::Monitor => settings.monitor.alert_notification,
+            NotificationCategory::Operation => false,
+        }
+    }
+}
+
+/// 通知中心的一条记录
+#[derive(Clone)]
+pub struct NotificationRecord {
+    pub id: u64,
+    pub category: NotificationCategory,
+    pub kind: NotificationType,
+    pub message: String,
+    /// 推送时间（本地时间 HH:MM:SS）
+    pub time: String,
+    pub read: bool,
+}
+
+#[derive(Default)]
+struct CenterState {
+    records: VecDeque<NotificationRecord>,
+    next_id: u64,
+}
+
+/// 通知历史
+pub struct NotificationCenter {
+    state: Mutex<CenterState>,
+}
+
+impl NotificationCenter {
+    /// 获取全局单例
+    pub fn global() -> &'static NotificationCenter {
+        static CENTER: Lazy<NotificationCenter> = Lazy::new(|| NotificationCenter {
+            state: Mutex::new(CenterState::default()),
+        });
+        &CENTER
+    }
+
+    fn with_state<T>(&self, f: impl FnOnce(&mut CenterState) -> T) -> T {
+        f(&mut self.state.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// 通知记录（最新的在前），`category` 为 None 时返回全部
+    pub fn records(&self, category: Option<NotificationCategory>) -> Vec<NotificationRecord> {
+        self.with_state(|state| {
+            state
+                .records
+                .iter()
+                .filter(|r| category.is_none_or(|c| r.category == c))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// 未读通知数量
+    pub fn unread_count(&self) -> usize {
+        self.with_state(|state| state.records.iter().filter(|r| !r.read).count())
+    }
+
+    /// 全部标记为已读
+    pub fn mark_all_read(&self) {
+        self.with_state(|state| state.records.iter_mut().for_each(|r| r.read = true));
+    }
+
+    /// 清空通知历史
+    pub fn clear(&self) {
+        self.with_state(|state| state.records.clear());
+    }
+
+    fn push(&self, category: NotificationCategory, kind: NotificationType, message: String) {
+        self.with_state(|state| {
+            state.next_id += 1;
+            let record = NotificationRecord {
+                id: state.next_id,
+                category,
+                kind,
+                message,
+                time: chrono::Local::now().format("%H:%M:%S").to_string(),
+                read: false,
+            };
+            state.records.push_front(record);
+            state.records.truncate(MAX_NOTIFICATIONS);
+        });
+    }
+}
+
+/// 推送通知：记录到通知中心，并在当前窗口弹出提示（应用处于后台时按设置发送系统通知）
+pub fn notify(
+    cx: &mut App,
+    category: NotificationCategory,
+    kind: NotificationType,
+    message: impl Into<String>,
+) {
+    let message = message.into();
+    match cx.active_window() {
+        Some(window) => {
+            let shown = cx.update_window(window, |_, window, cx| {
+                notify_in(window, cx, category, kind.clone(), message.clone())
+            });
+            // 窗口正在更新时无法弹出提示，仍然记录到通知中心
+            if shown.is_err() {
+                record(cx, category, kind, message, false);
+            }
+        }
+        None => record(cx, category, kind, message, false),
+    }
+}
+
+/// 按 i18n 键推送通知
+pub fn notify_key(cx: &mut App, category: NotificationCategory, kind: NotificationType, key: &str) {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+    notify(cx, category, kind, crate::i18n::t(&lang, key));
+}
+
+/// 在指定窗口推送通知（已持有窗口时使用）
+pub fn notify_in(
+    window: &mut Window,
+    cx: &mut App,
+    category: NotificationCategory,
+    kind: NotificationType,
+    message: impl Into<String>,
+) {
+    let message = message.into();
+    let do_not_disturb = storage::load_settings()
+        .map(|s| s.system.do_not_disturb)
+        .unwrap_or(false);
+    if !do_not_disturb {
+        // 告警内容较长，使用更宽的提示框
+        let notification = Notification::new()
+            .message(message.clone())
+            .with_type(kind.clone());
+        let notification = if category == NotificationCategory::Monitor {
+            notification.w_72()
+        } else {
+            notification.w_48()
+        };
+        window.push_notification(notification.py_2(), cx);
+    }
+    record(cx, category, kind, message, window.is_window_active());
+}
+
+/// 写入历史；应用处于后台时按设置发送系统通知
+fn record(
+    cx: &mut App,
+    category: NotificationCategory,
+    kind: NotificationType,
+    message: String,
+    focused: bool,
+) {
+    let settings = storage::load_settings().unwrap_or_default();
+    if !focused
+        && !settings.system.do_not_disturb
+        && settings.system.system_notifications
+        && category.system_notification_enabled(&settings)
+    {
+        let title = crate::i18n::t(&settings.theme.language, category.label_key());
+        send_system_notification(title, &message);
+    }
+    NotificationCenter::global().push(category, kind, message);
+    cx.refresh_windows();
+}
+
+/// 发送系统通知（macOS 使用 osascript，Linux 使用 notify-send，Windows 使用 PowerShell 弹出 Toast）
+fn send_system_notification(title: &str, body: &str) {
+    use std::process::Command;
+
+    let title = format!("ShellMaster · {}", title);
+    #[cfg(target_os = "macos")]
+    let result = {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+        Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display notification {} with title {}",
+                quote(body),
+                quote(&title)
+            ))
+            .spawn()
+    };
+    #[cfg(target_os = "windows")]
+    let result = {
+        let quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+        let script = format!(
+            "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+             $xml = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+             $text = $xml.GetElementsByTagName('text'); \
+             $text.Item(0).AppendChild($xml.CreateTextNode({})) > $null; \
+             $text.Item(1).AppendChild($xml.CreateTextNode({})) > $null; \
+             [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('ShellMaster').Show([Windows.UI.Notifications.ToastNotification]::new($xml))",
+            quote(&title),
+            quote(body)
+        );
+        Command::new("powershell")
+            .args(["-NoProfile", "-WindowStyle", "Hidden", "-Command", &script])
+            .spawn()
+    };
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let result = Command::new("notify-send")
+        .args(["--app-name", "ShellMaster", &title, body])
+        .spawn();
+
+    if let Err(e) = result {
+        warn!("[Notifications] Failed to send system notification: {}", e);
+    }
+}
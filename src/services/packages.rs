@@ -0,0 +1,346 @@
+// 软件包管理服务
+// 通过 SSH ExecChannel 检测远程的包管理器（apt / dnf / yum / brew），列出可升级的软件包、搜索软件包，
+// 并以流式输出执行更新索引、升级、安装等操作（非 root 用户通过 sudo 执行，brew 不使用 sudo）
+
+use tracing::{debug, info};
+
+use crate::services::remote_exec::shell_quote;
+use crate::ssh::session::SshSession;
+
+/// 检测包管理器：第一行输出用户 ID，第二行输出找到的包管理器路径（brew 可能不在非登录 shell 的 PATH 中）
+const DETECT_COMMAND: &str = "id -u; for m in apt-get dnf yum brew /opt/homebrew/bin/brew /usr/local/bin/brew /home/linuxbrew/.linuxbrew/bin/brew; do p=$(command -v \"$m\" 2>/dev/null) && { echo \"$p\"; exit 0; }; done; true";
+
+/// 搜索结果的最大条数
+pub const MAX_SEARCH_RESULTS: usize = 200;
+
+/// 包管理器类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageManagerKind {
+    Apt,
+    Dnf,
+    Yum,
+    Brew,
+}
+
+impl PackageManagerKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PackageManagerKind::Apt => "apt",
+            PackageManagerKind::Dnf => "dnf",
+            PackageManagerKind::Yum => "yum",
+            PackageManagerKind::Brew => "brew",
+        }
+    }
+}
+
+/// 检测到的包管理器
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageManager {
+    pub kind: PackageManagerKind,
+    /// 可执行文件路径
+    pub program: String,
+    /// 当前用户是否为 root
+    pub is_root: bool,
+}
+
+impl PackageManager {
+    /// 修改软件包是否需要 sudo（brew 不允许以 root 运行）
+    pub fn needs_sudo(&self) -> bool {
+        self.kind != PackageManagerKind::Brew && !self.is_root
+    }
+}
+
+/// 可升级的软件包
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpgradablePackage {
+    pub name: String,
+    /// 当前版本（dnf/yum 不提供时为空）
+    pub current: String,
+    pub available: String,
+}
+
+/// 搜索到的软件包
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageSearchResult {
+    pub name: String,
+    /// 简介（brew 不提供时为空）
+    pub description: String,
+}
+
+/// 修改软件包的操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum PackageAction {
+    /// 更新软件包索引
+    Refresh,
+    /// 升级全部软件包
+    UpgradeAll,
+    Upgrade(String),
+    Install(String),
+}
+
+/// 执行命令并返回退出码与标准输出
+async fn exec_output(session: &SshSession, command: &str) -> Result<(u32, String), String> {
+    let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+    let output = exec.exec(command).await.map_err(|e| e.to_string())?;
+    if !output.is_success() && output.stdout.is_empty() {
+        let stderr = output.stderr_string().trim().to_string();
+        if !stderr.is_empty() {
+            return Err(stderr);
+        }
+    }
+    Ok((output.exit_code, output.stdout_string()))
+}
+
+/// 检测远程的包管理器（未找到时返回 None）
+pub async fn detect_package_manager(
+    session: &SshSession,
+) -> Result<Option<PackageManager>, String> {
+    let (_, output) = exec_output(session, DETECT_COMMAND).await?;
+    let mut lines = output.lines().map(str::trim);
+    let is_root = lines.next() == Some("0");
+    let Some(program) = lines.next().filter(|p| !p.is_empty()) else {
+        return Ok(None);
+    };
+    let kind = match program.rsplit('/').next().unwrap_or(program) {
+        "apt-get" => PackageManagerKind::Apt,
+        "dnf" => PackageManagerKind::Dnf,
+        "yum" => PackageManagerKind::Yum,
+        "brew" => PackageManagerKind::Brew,
+        _ => return Ok(None),
+    };
+    info!("[Packages] Detected {} at {}", kind.label(), program);
+    Ok(Some(PackageManager {
+        kind,
+        program: program.to_string(),
+        is_root,
+    }))
+}
+
+/// 列出可升级的软件包（基于本地索引，不会先更新索引）
+pub async fn list_upgradable(
+    session: &SshSession,
+    manager: &PackageManager,
+) -> Result<Vec<UpgradablePackage>, String> {
+    let program = shell_quote(&manager.program);
+    let packages = match manager.kind {
+        PackageManagerKind::Apt => {
+            let (_, output) = exec_output(session, "apt list --upgradable 2>/dev/null").await?;
+            parse_apt_upgradable(&output)
+        }
+        PackageManagerKind::Dnf | PackageManagerKind::Yum => {
+            // check-update 有可用更新时退出码为 100
+            let command = format!("{} -q check-update", program);
+            let (code, output) = exec_output(session, &command).await?;
+            if code != 0 && code != 100 {
+                return Err(format!("check-update failed with exit code {}", code));
+            }
+            parse_rpm_upgradable(&output)
+        }
+        PackageManagerKind::Brew => {
+            let command = format!("{} outdated --verbose", program);
+            let (_, output) = exec_output(session, &command).await?;
+            parse_brew_outdated(&output)
+        }
+    };
+    debug!("[Packages] {} upgradable packages", packages.len());
+    Ok(packages)
+}
+
+/// 搜索软件包（按名称）
+pub async fn search_packages(
+    session: &SshSession,
+    manager: &PackageManager,
+    query: &str,
+) -> Result<Vec<PackageSearchResult>, String> {
+    let program = shell_quote(&manager.program);
+    let query = shell_quote(query);
+    let command = match manager.kind {
+        PackageManagerKind::Apt => format!("apt-cache search --names-only -- {}", query),
+        PackageManagerKind::Dnf | PackageManagerKind::Yum => {
+            format!("{} -q search {}", program, query)
+        }
+        PackageManagerKind::Brew => format!("{} search {}", program, query),
+    };
+    let (_, output) = exec_output(session, &command).await?;
+    let mut results = match manager.kind {
+        PackageManagerKind::Apt => parse_search_lines(&output, " - "),
+        PackageManagerKind::Dnf | PackageManagerKind::Yum => parse_search_lines(&output, " : "),
+        PackageManagerKind::Brew => output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with("==>"))
+            .map(|name| PackageSearchResult {
+                name: name.to_string(),
+                description: String::new(),
+            })
+            .collect(),
+    };
+    results.truncate(MAX_SEARCH_RESULTS);
+    Ok(results)
+}
+
+/// 操作对应的命令（不含 sudo）
+fn action_command(manager: &PackageManager, action: &PackageAction) -> String {
+    let program = shell_quote(&manager.program);
+    match manager.kind {
+        PackageManagerKind::Apt => {
+            // 避免 debconf 等待交互输入
+            let apt = format!("DEBIAN_FRONTEND=noninteractive {} -y", program);
+            match action {
+                PackageAction::Refresh => format!("{} update", program),
+                PackageAction::UpgradeAll => format!("{} upgrade", apt),
+                PackageAction::Upgrade(name) => {
+                    format!("{} install --only-upgrade {}", apt, shell_quote(name))
+                }
+                PackageAction::Install(name) => format!("{} install {}", apt, shell_quote(name)),
+            }
+        }
+        PackageManagerKind::Dnf | PackageManagerKind::Yum => {
+            let upgrade = if manager.kind == PackageManagerKind::Dnf {
+                "upgrade"
+            } else {
+                "update"
+            };
+            match action {
+                PackageAction::Refresh => format!("{} -y makecache", program),
+                PackageAction::UpgradeAll => format!("{} -y {}", program, upgrade),
+                PackageAction::Upgrade(name) => {
+                    format!("{} -y {} {}", program, upgrade, shell_quote(name))
+                }
+                PackageAction::Install(name) => {
+                    format!("{} -y install {}", program, shell_quote(name))
+                }
+            }
+        }
+        PackageManagerKind::Brew => match action {
+            PackageAction::Refresh => format!("{} update", program),
+            PackageAction::UpgradeAll => format!("{} upgrade", program),
+            PackageAction::Upgrade(name) => format!("{} upgrade {}", program, shell_quote(name)),
+            PackageAction::Install(name) => format!("{} install {}", program, shell_quote(name)),
+        },
+    }
+}
+
+/// 执行操作并逐行回调输出
+///
+/// 需要 sudo 时 `password` 为已验证的密码（空字符串表示免密 sudo）；回调返回 `false` 时提前结束
+pub async fn run_package_action<F>(
+    session: &SshSession,
+    manager: &PackageManager,
+    action: &PackageAction,
+    password: Option<&str>,
+    on_line: F,
+) -> Result<(), String>
+where
+    F: FnMut(&str) -> bool,
+{
+    let command = action_command(manager, action);
+    info!(
+        "[Packages] {}{}",
+        command,
+        if manager.needs_sudo() { " (sudo)" } else { "" }
+    );
+    let (command, input) = match password.filter(|_| manager.needs_sudo()) {
+        Some("") => (format!("sudo -n sh -c {}", shell_quote(&command)), None),
+        Some(password) => (
+            format!("sudo -S -p '' sh -c {}", shell_quote(&command)),
+            Some(format!("{}\n", password)),
+        ),
+        None => (command, None),
+    };
+
+    let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+    let exit_code = exec
+        .exec_streaming(&command, input.as_deref().map(str::as_bytes), on_line)
+        .await
+        .map_err(|e| e.to_string())?;
+    match exit_code {
+        Some(0) => Ok(()),
+        Some(code) => Err(format!("Command failed with exit code {}", code)),
+        None => Err("Command terminated without exit status".to_string()),
+    }
+}
+
+/// 解析 `apt list --upgradable`：name/suite version arch [upgradable from: old]
+fn parse_apt_upgradable(output: &str) -> Vec<UpgradablePackage> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once('/')?;
+            let mut fields = rest.split_whitespace();
+            let _suite = fields.next()?;
+            let available = fields.next()?.to_string();
+            let current = line
+                .split_once("upgradable from: ")
+                .map(|(_, v)| v.trim_end_matches(']').to_string())
+                .unwrap_or_default();
+            Some(UpgradablePackage {
+                name: name.to_string(),
+                current,
+                available,
+            })
+        })
+        .collect()
+}
+
+/// 解析 `dnf/yum check-update`：name.arch version repo（遇到“Obsoleting Packages”段落即停止）
+fn parse_rpm_upgradable(output: &str) -> Vec<UpgradablePackage> {
+    let mut packages = Vec::new();
+    for line in output.lines() {
+        if line.starts_with("Obsoleting") {
+            break;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [name, version, _repo] = fields.as_slice() else {
+            continue;
+        };
+        // 去掉架构后缀
+        let name = name.rsplit_once('.').map_or(*name, |(name, _)| name);
+        packages.push(UpgradablePackage {
+            name: name.to_string(),
+            current: String::new(),
+            available: version.to_string(),
+        });
+    }
+    packages
+}
+
+/// 解析 `brew outdated --verbose`：name (old[, old2]) < new 或 name (old) != new
+fn parse_brew_outdated(output: &str) -> Vec<UpgradablePackage> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.trim().split_once(' ')?;
+            let (current, available) =
+                rest.split_once(" < ").or_else(|| rest.split_once(" != "))?;
+            Some(UpgradablePackage {
+                name: name.to_string(),
+                current: current
+                    .trim_start_matches('(')
+                    .trim_end_matches(')')
+                    .to_string(),
+                available: available.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 解析“名称 分隔符 简介”形式的搜索结果（dnf 的名称带架构后缀，分节标题以 = 开头）
+fn parse_search_lines(output: &str, separator: &str) -> Vec<PackageSearchResult> {
+    output
+        .lines()
+        .filter(|line| !line.starts_with('='))
+        .filter_map(|line| {
+            let (name, description) = line.split_once(separator)?;
+            let name = name.trim();
+            let name = match name.rsplit_once('.') {
+                Some((base, arch)) if separator == " : " && !arch.contains('-') => base,
+                _ => name,
+            };
+            Some(PackageSearchResult {
+                name: name.to_string(),
+                description: description.trim().to_string(),
+            })
+        })
+        .collect()
+}
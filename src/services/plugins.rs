@@ -0,0 +1,600 @@
+// 脚本插件：从配置目录的 plugins 子目录加载 Rhai 脚本（*.rhai），每个启用的插件在独立线程中运行。
+// 脚本通过稳定的 API（会话、终端输入输出、SFTP、对话框、通知与日志）调用应用功能，
+// 这些调用经通道交给界面线程处理，脚本线程阻塞等待结果；停用插件时正在执行的脚本会被中止。
+//
+// 脚本开头的注释可以声明元数据：`// @name 名称`、`// @version 1.0`、`// @description 说明`。
+// 脚本顶层代码在加载时执行一次，另外可以定义：
+//   fn run()                      在插件管理页点击“运行”时执行
+//   fn on_connect(tab_id, server) 会话连接成功（终端就绪）后执行
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Position, Scope, AST};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info};
+
+use crate::services::sftp::SftpService;
+use crate::services::storage;
+
+/// 插件脚本的扩展名
+pub const PLUGIN_EXTENSION: &str = "rhai";
+/// 插件 API 版本（脚本可通过 api_version() 读取），API 只增加不修改
+pub const API_VERSION: i64 = 1;
+/// 等待界面线程回复、等待输出时检查是否已停用的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 插件每次启动的编号，用于丢弃已停止的旧线程发来的消息
+static NEXT_RUN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// 插件目录中的一个脚本
+#[derive(Clone, Debug)]
+pub struct PluginInfo {
+    /// 文件名（不含扩展名），作为插件 ID
+    pub id: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub path: PathBuf,
+}
+
+/// 插件运行状态
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PluginStatus {
+    /// 未启用或已停止
+    #[default]
+    Stopped,
+    /// 正在执行脚本
+    Running,
+    /// 已加载，等待事件
+    Idle,
+    /// 脚本加载失败
+    Failed,
+}
+
+/// 发送给插件线程的事件
+pub enum PluginEvent {
+    /// 手动运行（调用脚本的 run 函数）
+    Run,
+    /// 会话连接成功
+    Connected { tab_id: String, server: String },
+}
+
+/// 插件的 SFTP 操作
+pub enum SftpOp {
+    List { path: String },
+    Read { path: String },
+    Write { path: String, content: String },
+    Mkdir { path: String },
+    Remove { path: String },
+    Rename { from: String, to: String },
+}
+
+/// 插件向界面线程发出的请求
+pub enum PluginRequest {
+    /// 已打开的会话列表
+    Sessions,
+    /// 按服务器 ID 或名称打开会话，返回标签 ID
+    OpenSession { server: String },
+    /// 向会话的激活终端写入文本（不追加回车）
+    Send { tab_id: String, text: String },
+    /// 读取会话终端自上次读取以来的输出（已去除控制序列）
+    ReadOutput { tab_id: String },
+    /// 在会话的 SFTP 连接上执行文件操作
+    Sftp { tab_id: String, op: SftpOp },
+    /// 显示提示框，用户关闭后返回
+    Alert { title: String, message: String },
+    /// 显示确认框，返回用户是否确认
+    Confirm { title: String, message: String },
+    /// 显示应用内通知
+    Notify { message: String },
+}
+
+/// 请求结果的回复通道
+pub struct PluginReply(mpsc::Sender<Result<Dynamic, String>>);
+
+impl PluginReply {
+    pub fn send(self, result: Result<Dynamic, String>) {
+        // 插件已停止时无人接收，忽略即可
+        let _ = self.0.send(result);
+    }
+}
+
+/// 插件线程发给界面线程的消息内容
+pub enum PluginMessageKind {
+    Call {
+        request: PluginRequest,
+        reply: PluginReply,
+    },
+    Log {
+        message: String,
+        is_error: bool,
+    },
+    Status(PluginStatus),
+}
+
+/// 插件线程发给界面线程的消息
+pub struct PluginMessage {
+    pub plugin_id: String,
+    pub run_id: u64,
+    pub kind: PluginMessageKind,
+}
+
+/// 运行中插件的句柄，丢弃时停止插件
+pub struct PluginHandle {
+    run_id: u64,
+    events: mpsc::Sender<PluginEvent>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl PluginHandle {
+    pub fn run_id(&self) -> u64 {
+        self.run_id
+    }
+
+    /// 发送事件（插件线程已退出时忽略）
+    pub fn send(&self, event: PluginEvent) {
+        let _ = self.events.send(event);
+    }
+}
+
+impl Drop for PluginHandle {
+    fn drop(&mut self) {
+        // 中止正在执行的脚本；事件通道随句柄关闭，线程随后退出
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// 扫描插件目录，按名称排序返回全部脚本
+pub fn discover_plugins() -> Vec<PluginInfo> {
+    let Ok(dir) = storage::get_plugins_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut plugins: Vec<PluginInfo> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file() && path.extension().is_some_and(|ext| ext == PLUGIN_EXTENSION)
+        })
+        .filter_map(|path| {
+            let id = path.file_stem()?.to_str()?.to_string();
+            let header = fs::read_to_string(&path).unwrap_or_default();
+            Some(parse_header(id, path, &header))
+        })
+        .collect();
+    plugins.sort_by_key(|p| p.name.to_lowercase());
+    plugins
+}
+
+/// 读取脚本开头注释中的元数据
+fn parse_header(id: String, path: PathBuf, source: &str) -> PluginInfo {
+    let mut info = PluginInfo {
+        name: id.clone(),
+        id,
+        version: None,
+        description: None,
+        path,
+    };
+    let comments = source
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with("//"));
+    for line in comments {
+        let Some((key, value)) = line
+            .trim_start_matches('/')
+            .trim()
+            .strip_prefix('@')
+            .and_then(|rest| rest.split_once(char::is_whitespace))
+        else {
+            continue;
+        };
+        let value = value.trim().to_string();
+        match key {
+            "name" => info.name = value,
+            "version" => info.version = Some(value),
+            "description" => info.description = Some(value),
+            _ => {}
+        }
+    }
+    info
+}
+
+/// 在新线程中加载并运行插件
+pub fn start_plugin(info: &PluginInfo, messages: UnboundedSender<PluginMessage>) -> PluginHandle {
+    let run_id = NEXT_RUN_ID.fetch_add(1, Ordering::Relaxed);
+    let (events, events_rx) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let api = PluginApi {
+        plugin_id: info.id.clone(),
+        run_id,
+        messages,
+        cancel: cancel.clone(),
+    };
+    let path = info.path.clone();
+    let spawned = std::thread::Builder::new()
+        .name(format!("plugin-{}", info.id))
+        .spawn(move || run_plugin(api, path, events_rx));
+    if let Err(e) = spawned {
+        error!("[Plugins] Failed to spawn plugin thread: {}", e);
+    }
+    PluginHandle {
+        run_id,
+        events,
+        cancel,
+    }
+}
+
+/// 插件线程：编译并执行脚本顶层代码，之后按事件调用脚本中的函数，直到插件停止
+fn run_plugin(api: PluginApi, path: PathBuf, events: mpsc::Receiver<PluginEvent>) {
+    let engine = build_engine(&api);
+    let ast = match fs::read_to_string(&path)
+        .map_err(|e| format!("无法读取脚本：{}", e))
+        .and_then(|source| engine.compile(source).map_err(|e| e.to_string()))
+    {
+        Ok(ast) => ast,
+        Err(e) => {
+            api.log(e, true);
+            api.status(PluginStatus::Failed);
+            return;
+        }
+    };
+    info!("[Plugins] Loaded plugin {}", api.plugin_id);
+
+    let mut scope = Scope::new();
+    api.status(PluginStatus::Running);
+    if let Err(e) = engine.run_ast_with_scope(&mut scope, &ast) {
+        if !api.is_cancelled() {
+            api.log(e.to_string(), true);
+            api.status(PluginStatus::Failed);
+        }
+        return;
+    }
+    api.status(PluginStatus::Idle);
+
+    while let Ok(event) = events.recv() {
+        if api.is_cancelled() {
+            break;
+        }
+        let result = match event {
+            PluginEvent::Run if has_function(&ast, "run", 0) => {
+                api.status(PluginStatus::Running);
+                engine.call_fn::<Dynamic>(&mut scope, &ast, "run", ())
+            }
+            PluginEvent::Run => {
+                api.log("脚本没有定义 run() 函数".to_string(), true);
+                continue;
+            }
+            PluginEvent::Connected { tab_id, server } if has_function(&ast, "on_connect", 2) => {
+                api.status(PluginStatus::Running);
+                engine.call_fn::<Dynamic>(&mut scope, &ast, "on_connect", (tab_id, server))
+            }
+            PluginEvent::Connected { .. } => continue,
+        };
+        if api.is_cancelled() {
+            break;
+        }
+        if let Err(e) = result {
+            api.log(e.to_string(), true);
+        }
+        api.status(PluginStatus::Idle);
+    }
+    info!("[Plugins] Stopped plugin {}", api.plugin_id);
+}
+
+/// 脚本是否定义了指定名称与参数个数的函数
+fn has_function(ast: &AST, name: &str, params: usize) -> bool {
+    ast.iter_functions()
+        .any(|f| f.name == name && f.params.len() == params)
+}
+
+/// 插件线程与界面线程之间的桥接，提供给脚本 API 使用
+#[derive(Clone)]
+struct PluginApi {
+    plugin_id: String,
+    run_id: u64,
+    messages: UnboundedSender<PluginMessage>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl PluginApi {
+    fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    fn send(&self, kind: PluginMessageKind) -> bool {
+        self.messages
+            .send(PluginMessage {
+                plugin_id: self.plugin_id.clone(),
+                run_id: self.run_id,
+                kind,
+            })
+            .is_ok()
+    }
+
+    fn log(&self, message: String, is_error: bool) {
+        self.send(PluginMessageKind::Log { message, is_error });
+    }
+
+    fn status(&self, status: PluginStatus) {
+        self.send(PluginMessageKind::Status(status));
+    }
+
+    /// 中止脚本的错误
+    fn terminated() -> Box<EvalAltResult> {
+        Box::new(EvalAltResult::ErrorTerminated(
+            Dynamic::UNIT,
+            Position::NONE,
+        ))
+    }
+
+    /// 把请求交给界面线程处理并等待结果，插件停用时中止脚本
+    fn call(&self, request: PluginRequest) -> Result<Dynamic, Box<EvalAltResult>> {
+        let (reply, rx) = mpsc::channel();
+        let reply = PluginReply(reply);
+        if !self.send(PluginMessageKind::Call { request, reply }) {
+            return Err(Self::terminated());
+        }
+        loop {
+            if self.is_cancelled() {
+                return Err(Self::terminated());
+            }
+            match rx.recv_timeout(POLL_INTERVAL) {
+                Ok(result) => return result.map_err(Into::into),
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err("请求未被处理".into());
+                }
+            }
+        }
+    }
+
+    /// 可中止的等待
+    fn sleep(&self, duration: Duration) -> Result<(), Box<EvalAltResult>> {
+        let deadline = Instant::now() + duration;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            if self.is_cancelled() {
+                return Err(Self::terminated());
+            }
+            std::thread::sleep(remaining.min(POLL_INTERVAL));
+        }
+        Ok(())
+    }
+
+    /// 读取会话输出直到匹配正则表达式，返回期间收到的全部输出；超时抛出错误
+    fn wait_for(
+        &self,
+        tab_id: &str,
+        pattern: &str,
+        timeout_ms: i64,
+    ) -> Result<String, Box<EvalAltResult>> {
+        let regex = Regex::new(pattern).map_err(|e| format!("无效的正则表达式：{}", e))?;
+        let deadline = Instant::now() + Duration::from_millis(timeout_ms.max(0) as u64);
+        let mut output = String::new();
+        loop {
+            let chunk = self.call(PluginRequest::ReadOutput {
+                tab_id: tab_id.to_string(),
+            })?;
+            output.push_str(&chunk.into_string().unwrap_or_default());
+            if regex.is_match(&output) {
+                return Ok(output);
+            }
+            if Instant::now() >= deadline {
+                return Err(format!("等待输出超时：{}", pattern).into());
+            }
+            self.sleep(POLL_INTERVAL)?;
+        }
+    }
+}
+
+/// 创建脚本引擎并注册插件 API
+fn build_engine(api: &PluginApi) -> Engine {
+    let mut engine = Engine::new();
+
+    // 停用插件时中止正在执行的脚本（包括死循环）
+    let cancel = api.cancel.clone();
+    engine.on_progress(move |_| cancel.load(Ordering::Relaxed).then_some(Dynamic::UNIT));
+
+    // 日志
+    let a = api.clone();
+    engine.on_print(move |text| a.log(text.to_string(), false));
+    let a = api.clone();
+    engine.on_debug(move |text, _, _| a.log(text.to_string(), false));
+    let a = api.clone();
+    engine.register_fn("log", move |message: &str| {
+        a.log(message.to_string(), false)
+    });
+    engine.register_fn("api_version", || API_VERSION);
+    let a = api.clone();
+    engine.register_fn("sleep", move |ms: i64| {
+        a.sleep(Duration::from_millis(ms.max(0) as u64))
+    });
+
+    // 会话与终端
+    let a = api.clone();
+    engine.register_fn("sessions", move || -> Result<Array, Box<EvalAltResult>> {
+        Ok(a.call(PluginRequest::Sessions)?
+            .try_cast::<Array>()
+            .unwrap_or_default())
+    });
+    let a = api.clone();
+    engine.register_fn("open_session", move |server: &str| {
+        a.call(PluginRequest::OpenSession {
+            server: server.to_string(),
+        })
+    });
+    let a = api.clone();
+    engine.register_fn("send", move |tab_id: &str, text: &str| {
+        a.call(PluginRequest::Send {
+            tab_id: tab_id.to_string(),
+            text: text.to_string(),
+        })
+        .map(|_| ())
+    });
+    let a = api.clone();
+    engine.register_fn("send_line", move |tab_id: &str, command: &str| {
+        a.call(PluginRequest::Send {
+            tab_id: tab_id.to_string(),
+            text: format!("{}\r", command),
+        })
+        .map(|_| ())
+    });
+    let a = api.clone();
+    engine.register_fn("read_output", move |tab_id: &str| {
+        a.call(PluginRequest::ReadOutput {
+            tab_id: tab_id.to_string(),
+        })
+    });
+    let a = api.clone();
+    engine.register_fn(
+        "wait_for",
+        move |tab_id: &str, pattern: &str, timeout_ms: i64| a.wait_for(tab_id, pattern, timeout_ms),
+    );
+
+    // SFTP
+    let sftp = |api: &PluginApi| {
+        let a = api.clone();
+        move |tab_id: &str, op: SftpOp| {
+            a.call(PluginRequest::Sftp {
+                tab_id: tab_id.to_string(),
+                op,
+            })
+        }
+    };
+    let call = sftp(api);
+    engine.register_fn("sftp_list", move |tab_id: &str, path: &str| {
+        call(
+            tab_id,
+            SftpOp::List {
+                path: path.to_string(),
+            },
+        )
+    });
+    let call = sftp(api);
+    engine.register_fn("sftp_read", move |tab_id: &str, path: &str| {
+        call(
+            tab_id,
+            SftpOp::Read {
+                path: path.to_string(),
+            },
+        )
+    });
+    let call = sftp(api);
+    engine.register_fn(
+        "sftp_write",
+        move |tab_id: &str, path: &str, content: &str| {
+            call(
+                tab_id,
+                SftpOp::Write {
+                    path: path.to_string(),
+                    content: content.to_string(),
+                },
+            )
+            .map(|_| ())
+        },
+    );
+    let call = sftp(api);
+    engine.register_fn("sftp_mkdir", move |tab_id: &str, path: &str| {
+        call(
+            tab_id,
+            SftpOp::Mkdir {
+                path: path.to_string(),
+            },
+        )
+        .map(|_| ())
+    });
+    let call = sftp(api);
+    engine.register_fn("sftp_remove", move |tab_id: &str, path: &str| {
+        call(
+            tab_id,
+            SftpOp::Remove {
+                path: path.to_string(),
+            },
+        )
+        .map(|_| ())
+    });
+    let call = sftp(api);
+    engine.register_fn("sftp_rename", move |tab_id: &str, from: &str, to: &str| {
+        call(
+            tab_id,
+            SftpOp::Rename {
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        )
+        .map(|_| ())
+    });
+
+    // 对话框与通知
+    let a = api.clone();
+    engine.register_fn("alert", move |title: &str, message: &str| {
+        a.call(PluginRequest::Alert {
+            title: title.to_string(),
+            message: message.to_string(),
+        })
+        .map(|_| ())
+    });
+    let a = api.clone();
+    engine.register_fn("confirm", move |title: &str, message: &str| {
+        a.call(PluginRequest::Confirm {
+            title: title.to_string(),
+            message: message.to_string(),
+        })
+        .map(|confirmed| confirmed.as_bool().unwrap_or(false))
+    });
+    let a = api.clone();
+    engine.register_fn("notify", move |message: &str| {
+        a.call(PluginRequest::Notify {
+            message: message.to_string(),
+        })
+        .map(|_| ())
+    });
+
+    engine
+}
+
+impl SftpOp {
+    /// 在 SFTP 服务上执行操作（在 SSH 运行时中调用）
+    pub async fn execute(self, service: &SftpService) -> Result<Dynamic, String> {
+        match self {
+            SftpOp::List { path } => {
+                let entries = service.read_dir(&path).await?;
+                Ok(Dynamic::from(
+                    entries
+                        .into_iter()
+                        .map(|entry| {
+                            let mut map = Map::new();
+                            map.insert("is_dir".into(), Dynamic::from(entry.is_dir()));
+                            map.insert("size".into(), Dynamic::from(entry.size as i64));
+                            map.insert("name".into(), Dynamic::from(entry.name));
+                            map.insert("path".into(), Dynamic::from(entry.path));
+                            Dynamic::from(map)
+                        })
+                        .collect::<Array>(),
+                ))
+            }
+            SftpOp::Read { path } => service.read_file(&path).await.map(Dynamic::from),
+            SftpOp::Write { path, content } => service
+                .write_file(&path, content.as_bytes())
+                .await
+                .map(|_| Dynamic::UNIT),
+            SftpOp::Mkdir { path } => service.mkdir_recursive(&path).await.map(|_| Dynamic::UNIT),
+            SftpOp::Remove { path } => {
+                // 删除文件或空目录
+                if service.stat(&path).await?.is_dir() {
+                    service.remove_dir(&path).await?;
+                } else {
+                    service.remove_file(&path).await?;
+                }
+                Ok(Dynamic::UNIT)
+            }
+            SftpOp::Rename { from, to } => service.rename(&from, &to).await.map(|_| Dynamic::UNIT),
+        }
+    }
+}
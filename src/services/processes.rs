@@ -0,0 +1,142 @@
+// 远程进程管理服务
+// 通过 SSH ExecChannel 执行 ps（不可用时回退到 top -b）获取进程列表，并支持发送信号与调整优先级
+
+use tracing::{debug, info};
+
+use crate::services::remote_exec::exec_command;
+use crate::ssh::session::SshSession;
+
+/// 获取进程列表的命令：优先使用 procps 的 ps（加宽用户名列），其次是 BSD ps，最后回退到 top 批处理模式
+const PROCESS_LIST_COMMAND: &str = "ps -eo pid,user:32,pcpu,pmem,args 2>/dev/null \
+     || ps -eo pid,user,pcpu,pmem,args 2>/dev/null \
+     || top -b -n 1";
+
+/// 单个进程的信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessEntry {
+    pub pid: u32,
+    pub user: String,
+    pub cpu_percent: f32,
+    pub memory_percent: f32,
+    /// 完整命令行
+    pub command: String,
+}
+
+impl ProcessEntry {
+    /// 程序名（命令行第一段的文件名部分）
+    pub fn name(&self) -> &str {
+        let program = self.command.split_whitespace().next().unwrap_or_default();
+        program.rsplit('/').next().unwrap_or(program)
+    }
+}
+
+/// 可发送给进程的信号
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    /// 请求进程正常退出
+    Term,
+    /// 强制结束进程
+    Kill,
+}
+
+impl ProcessSignal {
+    /// kill 命令使用的信号名
+    pub fn name(self) -> &'static str {
+        match self {
+            ProcessSignal::Term => "TERM",
+            ProcessSignal::Kill => "KILL",
+        }
+    }
+}
+
+/// 获取远程进程列表
+pub async fn list_processes(session: &SshSession) -> Result<Vec<ProcessEntry>, String> {
+    let output = exec_command(session, PROCESS_LIST_COMMAND).await?;
+    let processes = parse_process_table(&output);
+    if processes.is_empty() {
+        return Err("Unrecognized process list output".to_string());
+    }
+    debug!("[Processes] Fetched {} processes", processes.len());
+    Ok(processes)
+}
+
+/// 向进程发送信号
+pub async fn send_signal(
+    session: &SshSession,
+    pid: u32,
+    signal: ProcessSignal,
+) -> Result<(), String> {
+    info!("[Processes] Sending SIG{} to {}", signal.name(), pid);
+    exec_command(session, &format!("kill -{} {}", signal.name(), pid))
+        .await
+        .map(|_| ())
+}
+
+/// 调整进程的 nice 值（-20 ~ 19，降低 nice 值通常需要 root 权限）
+pub async fn renice(session: &SshSession, pid: u32, niceness: i32) -> Result<(), String> {
+    info!("[Processes] Renice {} to {}", pid, niceness);
+    exec_command(session, &format!("renice -n {} -p {}", niceness, pid))
+        .await
+        .map(|_| ())
+}
+
+/// 解析 ps / top 的表格输出
+///
+/// 以首列为 `PID` 的行作为表头，按列名定位各字段；命令必须是最后一列，可以包含空格。
+/// top 输出表头前的汇总信息会被跳过
+pub fn parse_process_table(output: &str) -> Vec<ProcessEntry> {
+    let mut lines = output.lines();
+    let Some(header) = lines
+        .by_ref()
+        .find(|line| line.split_whitespace().next() == Some("PID"))
+    else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.split_whitespace().collect();
+    let find = |names: &[&str]| columns.iter().position(|c| names.contains(c));
+    let (Some(pid_ix), Some(user_ix), Some(command_ix)) = (
+        find(&["PID"]),
+        find(&["USER"]),
+        find(&["COMMAND", "ARGS", "CMD"]),
+    ) else {
+        return Vec::new();
+    };
+    if command_ix != columns.len() - 1 {
+        return Vec::new();
+    }
+    let cpu_ix = find(&["%CPU"]);
+    let mem_ix = find(&["%MEM"]).or_else(|| find(&["%VSZ"]));
+
+    lines
+        .filter_map(|line| {
+            let (fields, command) = split_fields(line, command_ix)?;
+            let percent = |ix: Option<usize>| {
+                ix.and_then(|ix| fields[ix].trim_end_matches('%').parse::<f32>().ok())
+                    .unwrap_or(0.0)
+            };
+            Some(ProcessEntry {
+                pid: fields[pid_ix].parse().ok()?,
+                user: fields[user_ix].to_string(),
+                cpu_percent: percent(cpu_ix),
+                memory_percent: percent(mem_ix),
+                command: command.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 把一行拆成前 `count` 个空白分隔的字段和剩余部分
+fn split_fields(line: &str, count: usize) -> Option<(Vec<&str>, &str)> {
+    let mut fields = Vec::with_capacity(count);
+    let mut rest = line.trim_start();
+    for _ in 0..count {
+        let end = rest.find(char::is_whitespace)?;
+        fields.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    let rest = rest.trim_end();
+    if rest.is_empty() {
+        return None;
+    }
+    Some((fields, rest))
+}
@@ -0,0 +1,200 @@
+// 服务器可达性探测：对已保存与共享清单中的服务器的 SSH 端口发起 TCP 连接，记录在线状态与连接耗时。
+// 后台探测需在连接设置中开启，并按设置的间隔刷新；主页的"检测全部"按钮可随时手动探测。
+// 经跳板机、代理或全局代理连接的服务器无法直接探测，显示为未知。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use gpui::{App, Global};
+use once_cell::sync::Lazy;
+use tracing::info;
+
+use crate::models::settings::GlobalProxyType;
+use crate::services::inventory::SharedInventory;
+use crate::services::storage;
+
+/// 单个服务器的连接超时
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// 同时探测的服务器数量上限
+const MAX_CONCURRENT_PROBES: usize = 32;
+/// 检查是否需要后台探测的间隔
+const PROBE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 服务器的可达状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reachability {
+    /// 端口可连接，附带连接耗时
+    Online { latency_ms: u32 },
+    /// 连接失败或超时
+    Offline,
+    /// 尚未探测或无法直接探测
+    Unknown,
+}
+
+#[derive(Default)]
+struct ProbeState {
+    results: HashMap<String, Reachability>,
+    checked_at: Option<Instant>,
+}
+
+/// 探测结果缓存
+pub struct ReachabilityStore {
+    state: Mutex<ProbeState>,
+}
+
+impl ReachabilityStore {
+    /// 获取全局单例
+    pub fn global() -> &'static ReachabilityStore {
+        static STORE: Lazy<ReachabilityStore> = Lazy::new(|| ReachabilityStore {
+            state: Mutex::new(ProbeState::default()),
+        });
+        &STORE
+    }
+
+    fn with_state<T>(&self, f: impl FnOnce(&mut ProbeState) -> T) -> T {
+        f(&mut self.state.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// 服务器的可达状态（未探测过为未知）
+    pub fn get(&self, server_id: &str) -> Reachability {
+        self.with_state(|state| {
+            state
+                .results
+                .get(server_id)
+                .copied()
+                .unwrap_or(Reachability::Unknown)
+        })
+    }
+
+    /// 是否已经探测过（未探测时列表中不显示状态点）
+    pub fn has_results(&self) -> bool {
+        self.with_state(|state| state.checked_at.is_some())
+    }
+
+    fn is_due(&self, interval: Duration) -> bool {
+        self.with_state(|state| state.checked_at.is_none_or(|t| t.elapsed() >= interval))
+    }
+
+    fn set(&self, results: HashMap<String, Reachability>) {
+        self.with_state(|state| {
+            state.results = results;
+            state.checked_at = Some(Instant::now());
+        });
+    }
+}
+
+/// 可达性探测的 GPUI 全局状态：定时探测并在结果更新后刷新界面
+pub struct ReachabilityService {
+    running: bool,
+}
+
+impl Global for ReachabilityService {}
+
+impl ReachabilityService {
+    /// 注册全局状态并启动后台探测
+    pub fn init(cx: &mut App) {
+        cx.set_global(Self { running: false });
+        cx.spawn(async move |cx| loop {
+            if cx.update(|cx| Self::tick(cx)).is_err() {
+                break;
+            }
+            cx.background_executor().timer(PROBE_CHECK_INTERVAL).await;
+        })
+        .detach();
+    }
+
+    /// 是否正在探测
+    pub fn running(cx: &App) -> bool {
+        cx.global::<Self>().running
+    }
+
+    fn tick(cx: &mut App) {
+        let settings = storage::load_settings().unwrap_or_default().connection;
+        if !settings.reachability_probe {
+            return;
+        }
+        let interval =
+            Duration::from_secs(settings.reachability_interval_minutes.max(1) as u64 * 60);
+        if ReachabilityStore::global().is_due(interval) {
+            Self::probe_all(cx);
+        }
+    }
+
+    /// 立即探测全部服务器
+    pub fn probe_all(cx: &mut App) {
+        if cx.global::<Self>().running {
+            return;
+        }
+        let settings = storage::load_settings().unwrap_or_default();
+        let global_proxy = settings.connection.global_proxy_type != GlobalProxyType::None;
+        let mut servers = storage::load_servers().unwrap_or_default().servers;
+        servers.extend(
+            SharedInventory::global()
+                .servers()
+                .into_iter()
+                .map(|s| s.server),
+        );
+
+        // 需要经过跳板机或代理的服务器不直接探测
+        let mut results = HashMap::new();
+        let mut targets = Vec::new();
+        for server in servers {
+            if global_proxy
+                || server.jump_host_id.is_some()
+                || server.proxy.as_ref().is_some_and(|p| p.enabled)
+            {
+                results.insert(server.id, Reachability::Unknown);
+            } else {
+                targets.push((server.id, server.host, server.port));
+            }
+        }
+
+        cx.global_mut::<Self>().running = true;
+        cx.refresh_windows();
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let probed: Vec<(String, Reachability)> = futures::stream::iter(targets)
+                    .map(|(id, host, port)| async move { (id, probe(&host, port).await) })
+                    .buffer_unordered(MAX_CONCURRENT_PROBES)
+                    .collect()
+                    .await;
+                let _ = tx.send(probed);
+            });
+
+        cx.spawn(async move |cx| {
+            let probed = rx.await.unwrap_or_default();
+            let online = probed
+                .iter()
+                .filter(|(_, r)| matches!(r, Reachability::Online { .. }))
+                .count();
+            info!(
+                "[Reachability] Probed {} servers, {} online",
+                probed.len(),
+                online
+            );
+            results.extend(probed);
+            ReachabilityStore::global().set(results);
+            let _ = cx.update(|cx| {
+                cx.global_mut::<Self>().running = false;
+                cx.refresh_windows();
+            });
+        })
+        .detach();
+    }
+}
+
+/// 连接服务器的 SSH 端口并计时
+async fn probe(host: &str, port: u16) -> Reachability {
+    let started = Instant::now();
+    match tokio::time::timeout(PROBE_TIMEOUT, tokio::net::TcpStream::connect((host, port))).await {
+        Ok(Ok(_)) => Reachability::Online {
+            latency_ms: started.elapsed().as_millis() as u32,
+        },
+        _ => Reachability::Offline,
+    }
+}
@@ -0,0 +1,59 @@
+// 远程命令执行的公共工具：在 SSH exec 通道上执行命令，并为拼接进命令行的参数加引号
+
+use crate::ssh::session::{CommandOutput, SshSession};
+
+/// 用单引号包裹参数，转义其中的单引号
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// 执行命令，退出码非 0 时返回标准错误
+pub async fn exec_command(session: &SshSession, command: &str) -> Result<String, String> {
+    let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+    let output = exec.exec(command).await.map_err(|e| e.to_string())?;
+    if !output.is_success() {
+        return Err(command_error(&output));
+    }
+    Ok(output.stdout_string())
+}
+
+/// 执行命令并把 `input` 写入其标准输入（写完后关闭），返回标准输出的原始字节；退出码非 0 时返回标准错误
+pub async fn exec_command_with_stdin(
+    session: &SshSession,
+    command: &str,
+    input: &[u8],
+) -> Result<Vec<u8>, String> {
+    let exec = session.open_exec().await.map_err(|e| e.to_string())?;
+    let output = exec
+        .exec_with_input(command, input)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !output.is_success() {
+        return Err(command_error(&output));
+    }
+    Ok(output.stdout)
+}
+
+/// 命令失败时的错误信息：标准错误为空时使用退出码
+fn command_error(output: &CommandOutput) -> String {
+    let stderr = output.stderr_string().trim().to_string();
+    if stderr.is_empty() {
+        format!("Command failed with exit code {}", output.exit_code)
+    } else {
+        stderr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("plain"), "'plain'");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("a b;rm -rf /"), "'a b;rm -rf /'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
+        assert_eq!(shell_quote("$(id)"), "'$(id)'");
+    }
+}
@@ -0,0 +1,137 @@
+// 定时任务服务
+// 按 cron 表达式判断到期的任务，把快捷命令或 Runbook 转换为要执行的命令，并把执行结果整理为运行记录；
+// 执行本身复用批量执行（已连接的服务器复用会话，未连接时临时连接）
+
+use chrono::{DateTime, Local};
+
+use crate::models::schedule::{JobRun, ScheduledJob, ScheduledTask};
+use crate::models::snippets::fill_variables;
+use crate::models::{RunbookStep, SnippetsConfig};
+use crate::services::batch_exec::HostResult;
+use crate::services::cron::CronSchedule;
+
+/// 运行记录中最多保留的输出（字节）
+const MAX_OUTPUT_LEN: usize = 16 * 1024;
+
+/// 计划表达式不可用于定时任务的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleError {
+    /// 定时任务不支持 @reboot
+    Reboot,
+    /// 表达式无效（解析器给出的说明）
+    Invalid(String),
+}
+
+/// 解析计划表达式（定时任务不支持 @reboot）
+pub fn parse_schedule(schedule: &str) -> Result<CronSchedule, ScheduleError> {
+    let parsed = CronSchedule::parse(schedule).map_err(ScheduleError::Invalid)?;
+    if parsed.reboot {
+        return Err(ScheduleError::Reboot);
+    }
+    Ok(parsed)
+}
+
+/// 任务在 `from` 之后的下一次运行时间（表达式无效时为 None）
+pub fn next_run(job: &ScheduledJob, from: DateTime<Local>) -> Option<DateTime<Local>> {
+    parse_schedule(&job.schedule)
+        .ok()?
+        .next_runs(from, 1)
+        .into_iter()
+        .next()
+}
+
+/// 在 (`since`, `now`] 之间到了运行时间的已启用任务（错过的多次运行只算一次）
+pub fn due_jobs(
+    jobs: &[ScheduledJob],
+    since: DateTime<Local>,
+    now: DateTime<Local>,
+) -> Vec<ScheduledJob> {
+    jobs.iter()
+        .filter(|job| job.enabled)
+        .filter(|job| next_run(job, since).is_some_and(|time| time <= now))
+        .cloned()
+        .collect()
+}
+
+/// 把任务内容转换为在服务器上执行的命令
+///
+/// 快捷命令的变量使用该服务器上次填写的值，没有时使用默认值；
+/// Runbook 的发送命令步骤依次执行（任一命令失败即停止），等待时长步骤转换为 sleep，
+/// 等待输出步骤在非交互执行中无需等待而跳过，手动确认步骤无法自动完成因此不支持
+///
+/// 失败时返回错误信息的 i18n 键
+pub fn build_command(
+    task: &ScheduledTask,
+    server_id: &str,
+    snippets: &SnippetsConfig,
+) -> Result<String, &'static str> {
+    match task {
+        ScheduledTask::Snippet { snippet_id } => {
+            let snippet = snippets
+                .commands
+                .iter()
+                .find(|c| &c.id == snippet_id)
+                .ok_or("schedules.error.snippet_missing")?;
+            let values = snippets
+                .variable_values
+                .get(server_id)
+                .cloned()
+                .unwrap_or_default();
+            Ok(fill_variables(&snippet.command, &values))
+        }
+        ScheduledTask::Runbook { runbook_id } => {
+            let runbook = snippets
+                .runbooks
+                .iter()
+                .find(|r| &r.id == runbook_id)
+                .ok_or("schedules.error.runbook_missing")?;
+            let mut lines = vec!["set -e".to_string()];
+            for step in &runbook.steps {
+                match step {
+                    RunbookStep::Send { command } => lines.push(command.clone()),
+                    RunbookStep::Sleep { secs } => lines.push(format!("sleep {}", secs)),
+                    RunbookStep::WaitFor { .. } => {}
+                    RunbookStep::Confirm { .. } => return Err("schedules.error.runbook_confirm"),
+                }
+            }
+            Ok(lines.join("\n"))
+        }
+    }
+}
+
+/// 根据执行结果生成运行记录
+pub fn job_run(
+    job: &ScheduledJob,
+    server_label: &str,
+    started_at: DateTime<Local>,
+    result: HostResult,
+    manual: bool,
+) -> JobRun {
+    let mut output = result.stdout;
+    if !result.stderr.is_empty() {
+        if !output.is_empty() && !output.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push_str(&result.stderr);
+    }
+    if output.len() > MAX_OUTPUT_LEN {
+        let cut = (0..=MAX_OUTPUT_LEN)
+            .rev()
+            .find(|&i| output.is_char_boundary(i))
+            .unwrap_or(0);
+        output.truncate(cut);
+        output.push_str("\n…");
+    }
+    JobRun {
+        id: uuid::Uuid::new_v4().to_string(),
+        job_id: job.id.clone(),
+        job_name: job.name.clone(),
+        server_label: server_label.to_string(),
+        started_at: started_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        duration_ms: result.duration.as_millis() as u64,
+        exit_code: result.exit_code,
+        output,
+        error: result.error,
+        manual,
+    }
+}
@@ -0,0 +1,160 @@
+// 存储文档的模式版本与迁移
+//
+// 每个文档在 JSON 顶层记录 `schema_version`（旧的 JSON 文件没有该字段，视为版本 0）。
+// 读取时按顺序执行尚未执行的迁移，写入时记录当前版本；备份文件与数据库内容格式一致，
+// 因此从旧备份恢复时也会经过同样的迁移。
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use tracing::{info, warn};
+
+use crate::models::history::ConnectionHistory;
+use crate::models::schedule::{JobHistory, SchedulesConfig};
+use crate::models::sftp::{SftpBookmarks, TransferHistory};
+use crate::models::window_state::WindowState;
+use crate::models::workspace::WorkspacesConfig;
+use crate::models::{AppSettings, KnownHostsConfig, ServerConfig, SnippetsConfig};
+use crate::services::database::StorageKey;
+use crate::services::storage;
+
+/// 文档中记录模式版本的字段
+const VERSION_FIELD: &str = "schema_version";
+
+/// 一次迁移：把文档从版本 N 升级到 N+1
+type Migration = fn(&mut Value) -> Result<()>;
+
+/// 各文档的迁移列表，下标 N 的迁移把版本 N 升级到 N+1；只能在末尾追加
+fn migrations(key: StorageKey) -> &'static [Migration] {
+    match key {
+        StorageKey::Servers => &[servers_v1_store_private_keys],
+        StorageKey::Settings => &[settings_v1_rename_speed_limit],
+        StorageKey::Snippets
+        | StorageKey::KnownHosts
+        | StorageKey::TransferHistory
+        | StorageKey::ConnectionHistory
+        | StorageKey::SftpBookmarks
+        | StorageKey::Workspaces
+        | StorageKey::WindowState
+        | StorageKey::Schedules
+        | StorageKey::JobHistory => &[],
+    }
+}
+
+/// 文档的当前模式版本
+pub fn current_version(key: StorageKey) -> u32 {
+    migrations(key).len() as u32
+}
+
+/// 取出文档记录的版本并执行尚未执行的迁移，返回是否有迁移被执行
+///
+/// 较新版本的应用写入的文档不做处理，按当前模型尽量解析
+pub fn migrate(key: StorageKey, value: &mut Value) -> Result<bool> {
+    let object = value
+        .as_object_mut()
+        .with_context(|| format!("{} 不是 JSON 对象", key.as_str()))?;
+    let version = object
+        .remove(VERSION_FIELD)
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    let current = current_version(key);
+    if version > current {
+        warn!(
+            "[Schema] {} was written by a newer version (v{} > v{})",
+            key.as_str(),
+            version,
+            current
+        );
+        return Ok(false);
+    }
+    for (index, migration) in migrations(key).iter().enumerate().skip(version as usize) {
+        migration(value).with_context(|| {
+            format!("{} 从 v{} 迁移到 v{} 失败", key.as_str(), index, index + 1)
+        })?;
+        info!("[Schema] Migrated {} to v{}", key.as_str(), index + 1);
+    }
+    Ok(version < current)
+}
+
+/// 写入前记录当前模式版本
+pub fn stamp(key: StorageKey, value: &mut Value) {
+    if let Some(object) = value.as_object_mut() {
+        object.insert(VERSION_FIELD.to_string(), current_version(key).into());
+    }
+}
+
+/// 解析文档内容：执行迁移并按对应的模型校验，返回迁移后的内容与是否执行了迁移
+pub fn parse_document(key: StorageKey, content: &str) -> Result<(Value, bool)> {
+    let mut value: Value =
+        serde_json::from_str(content).with_context(|| format!("无法解析 {}", key.as_str()))?;
+    let migrated = migrate(key, &mut value)?;
+    validate(key, &value)?;
+    Ok((value, migrated))
+}
+
+/// 按文档对应的模型校验内容
+fn validate(key: StorageKey, value: &Value) -> Result<()> {
+    fn check<T: serde::de::DeserializeOwned>(value: &Value) -> Result<()> {
+        T::deserialize(value).map(drop).map_err(Into::into)
+    }
+    match key {
+        StorageKey::Servers => check::<ServerConfig>(value),
+        StorageKey::Settings => check::<AppSettings>(value),
+        StorageKey::Snippets => check::<SnippetsConfig>(value),
+        StorageKey::KnownHosts => check::<KnownHostsConfig>(value),
+        StorageKey::TransferHistory => check::<TransferHistory>(value),
+        StorageKey::ConnectionHistory => check::<ConnectionHistory>(value),
+        StorageKey::SftpBookmarks => check::<SftpBookmarks>(value),
+        StorageKey::Workspaces => check::<WorkspacesConfig>(value),
+        StorageKey::WindowState => check::<WindowState>(value),
+        StorageKey::Schedules => check::<SchedulesConfig>(value),
+        StorageKey::JobHistory => check::<JobHistory>(value),
+    }
+    .with_context(|| format!("{} 的内容与当前版本不兼容", key.as_str()))
+}
+
+// ======================== 迁移 ========================
+
+/// servers v1：使用完整路径的私钥复制到应用的 keys 目录，只记录文件名
+fn servers_v1_store_private_keys(value: &mut Value) -> Result<()> {
+    let Some(servers) = value.get_mut("servers").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+    for server in servers {
+        if server
+            .get("private_key_filename")
+            .is_some_and(|v| !v.is_null())
+        {
+            continue;
+        }
+        let Some(old_path) = server
+            .get("private_key_path")
+            .and_then(Value::as_str)
+            .map(std::path::PathBuf::from)
+        else {
+            continue;
+        };
+        // 只迁移存在的绝对路径，其余保持原样
+        if !old_path.is_absolute() || !old_path.exists() {
+            continue;
+        }
+        match storage::store_private_key(&old_path) {
+            Ok(filename) => {
+                server["private_key_filename"] = filename.into();
+                server["private_key_path"] = Value::Null;
+                info!("[Schema] Stored private key {:?}", old_path);
+            }
+            Err(e) => warn!("[Schema] Failed to store private key {:?}: {}", old_path, e),
+        }
+    }
+    Ok(())
+}
+
+/// settings v1：`sftp.speed_limit_kb` 改名为 `sftp.download_limit_kb`
+fn settings_v1_rename_speed_limit(value: &mut Value) -> Result<()> {
+    if let Some(sftp) = value.get_mut("sftp").and_then(Value::as_object_mut) {
+        if let Some(limit) = sftp.remove("speed_limit_kb") {
+            sftp.entry("download_limit_kb").or_insert(limit);
+        }
+    }
+    Ok(())
+}
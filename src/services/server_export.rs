@@ -0,0 +1,531 @@
+// 服务器清单导出：CSV 表格与 OpenSSH config（不含密码），以及单台服务器的配置片段与 ssh 命令
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tracing::info;
+
+use crate::models::server::{
+    AuthType, Identity, IdentityKind, ProxyType, ServerConfig, ServerData,
+};
+use crate::services::remote_exec::shell_quote;
+use crate::services::storage;
+use crate::ssh::forward::{ForwardKind, ForwardSpec};
+
+/// 导出格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    SshConfig,
+}
+
+impl ExportFormat {
+    /// 保存对话框的默认文件名
+    pub fn default_file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "shellmaster-servers.csv",
+            ExportFormat::SshConfig => "config",
+        }
+    }
+}
+
+/// 导出已保存的服务器，返回导出数量
+pub fn export_servers(path: &Path, format: ExportFormat) -> Result<usize> {
+    let config = storage::load_servers()?;
+    let content = match format {
+        ExportFormat::Csv => to_csv(&config),
+        ExportFormat::SshConfig => to_ssh_config(&config),
+    };
+    fs::write(path, content).with_context(|| format!("无法写入 {:?}", path))?;
+    info!(
+        "[Export] Exported {} servers as {:?} to {:?}",
+        config.servers.len(),
+        format,
+        path
+    );
+    Ok(config.servers.len())
+}
+
+/// CSV：每台服务器一行，分组以完整路径、跳板机以名称表示
+fn to_csv(config: &ServerConfig) -> String {
+    let groups: HashMap<&str, String> = config
+        .groups
+        .iter()
+        .map(|g| (g.id.as_str(), config.group_path(&g.id)))
+        .collect();
+    let labels: HashMap<&str, &str> = config
+        .servers
+        .iter()
+        .map(|s| (s.id.as_str(), s.label.as_str()))
+        .collect();
+
+    let mut out =
+        String::from("Label,Group,Host,Port,Username,Auth,JumpHost,Proxy,Description,Tags\n");
+    for server in &config.servers {
+        let group = server
+            .group_id
+            .as_deref()
+            .and_then(|id| groups.get(id))
+            .map(String::as_str)
+            .unwrap_or_default();
+        let jump = server
+            .jump_host_id
+            .as_deref()
+            .and_then(|id| labels.get(id).copied())
+            .unwrap_or_default();
+        let auth = match server.auth_type {
+            AuthType::Password => "password",
+            AuthType::PublicKey => "publickey",
+            AuthType::Identity => "identity",
+        };
+        let proxy = active_proxy_url(server).unwrap_or_default();
+        let port = server.port.to_string();
+        let tags = server.tags.join(";");
+        let fields = [
+            server.label.as_str(),
+            group,
+            server.host.as_str(),
+            port.as_str(),
+            server.username.as_str(),
+            auth,
+            jump,
+            proxy.as_str(),
+            server.description.as_deref().unwrap_or_default(),
+            tags.as_str(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// 含逗号、引号或换行的字段用双引号包裹
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 启用的代理，形如 socks5://user@host:port
+fn active_proxy_url(server: &ServerData) -> Option<String> {
+    let proxy = server.proxy.as_ref().filter(|p| p.enabled)?;
+    let scheme = match proxy.proxy_type {
+        ProxyType::Http => "http",
+        ProxyType::Socks5 => "socks5",
+    };
+    let user = proxy
+        .username
+        .as_deref()
+        .filter(|u| !u.is_empty())
+        .map(|u| format!("{}@", u))
+        .unwrap_or_default();
+    Some(format!(
+        "{}://{}{}:{}",
+        scheme, user, proxy.host, proxy.port
+    ))
+}
+
+/// OpenSSH config：每台服务器一个 Host 块，别名由名称生成且保证唯一
+fn to_ssh_config(config: &ServerConfig) -> String {
+    let mut used = HashSet::new();
+    let aliases: HashMap<&str, String> = config
+        .servers
+        .iter()
+        .map(|s| (s.id.as_str(), unique_alias(&s.label, &s.host, &mut used)))
+        .collect();
+    let groups: HashMap<&str, String> = config
+        .groups
+        .iter()
+        .map(|g| (g.id.as_str(), config.group_path(&g.id)))
+        .collect();
+
+    let mut out = format!(
+        "# Exported from ShellMaster on {}\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M")
+    );
+    for server in &config.servers {
+        out.push('\n');
+        if let Some(group) = server.group_id.as_deref().and_then(|id| groups.get(id)) {
+            out.push_str(&format!("# {}\n", group));
+        }
+        out.push_str(&host_block(server, config, &aliases));
+    }
+    out
+}
+
+/// 一台服务器的 Host 块（跳板机以 `aliases` 中的别名引用）
+fn host_block(
+    server: &ServerData,
+    config: &ServerConfig,
+    aliases: &HashMap<&str, String>,
+) -> String {
+    let mut out = format!("Host {}\n", aliases[server.id.as_str()]);
+    out.push_str(&format!("    HostName {}\n", server.host));
+    let username = effective_username(server, config);
+    if !username.is_empty() {
+        out.push_str(&format!("    User {}\n", username));
+    }
+    if server.port != 22 {
+        out.push_str(&format!("    Port {}\n", server.port));
+    }
+    if let Some(key) = identity_file(server, config) {
+        out.push_str(&format!("    IdentityFile \"{}\"\n", key));
+    }
+    if let Some(jump) = server
+        .jump_host_id
+        .as_deref()
+        .and_then(|id| aliases.get(id))
+    {
+        out.push_str(&format!("    ProxyJump {}\n", jump));
+    } else if let Some(command) = proxy_command(server) {
+        out.push_str(&format!("    ProxyCommand {}\n", command));
+    }
+    out
+}
+
+/// 用户名：引用身份且未填写用户名时使用身份的默认用户名
+fn effective_username<'a>(server: &'a ServerData, config: &'a ServerConfig) -> &'a str {
+    match server_identity(server, config) {
+        Some(identity) if server.username.is_empty() => identity.username.as_str(),
+        _ => server.username.as_str(),
+    }
+}
+
+fn server_identity<'a>(server: &ServerData, config: &'a ServerConfig) -> Option<&'a Identity> {
+    server
+        .identity_id
+        .as_deref()
+        .filter(|_| server.auth_type == AuthType::Identity)
+        .and_then(|id| config.identity(id))
+}
+
+/// 私钥文件的完整路径（Agent 身份由 ssh 自动使用 Agent，没有私钥文件）
+fn identity_file(server: &ServerData, config: &ServerConfig) -> Option<String> {
+    let in_keys_dir = |filename: &String| {
+        storage::get_keys_dir()
+            .ok()
+            .map(|dir| dir.join(filename).display().to_string())
+    };
+    match (&server.auth_type, server_identity(server, config)) {
+        (AuthType::PublicKey, _) => server
+            .private_key_filename
+            .as_ref()
+            .and_then(in_keys_dir)
+            .or_else(|| server.private_key_path.clone()),
+        (AuthType::Identity, Some(identity)) => identity
+            .private_key_filename
+            .as_ref()
+            .filter(|_| identity.kind == IdentityKind::KeyFile)
+            .and_then(in_keys_dir),
+        _ => None,
+    }
+}
+
+/// HTTP/SOCKS5 代理通过 nc 转发（OpenBSD netcat 语法）
+fn proxy_command(server: &ServerData) -> Option<String> {
+    let proxy = server.proxy.as_ref().filter(|p| p.enabled)?;
+    let kind = match proxy.proxy_type {
+        ProxyType::Http => "connect",
+        ProxyType::Socks5 => "5",
+    };
+    Some(format!(
+        "nc -X {} -x {}:{} %h %p",
+        kind, proxy.host, proxy.port
+    ))
+}
+
+/// 单台服务器的 OpenSSH 配置片段与等效的 ssh 命令
+pub struct SshSnippet {
+    /// Host 块（跳板机的 Host 块在前）
+    pub config: String,
+    pub command: String,
+}
+
+/// 生成服务器的 OpenSSH 配置片段与 ssh 命令，包含跳板机链与给定的端口转发
+///
+/// 服务器可以是未保存的临时连接，跳板机与身份从已保存的配置中查找
+pub fn server_snippet(server: &ServerData, forwards: &[ForwardSpec]) -> Result<SshSnippet> {
+    let config = storage::load_servers()?;
+
+    // 跳板机链：从目标服务器开始依次向外（跳过循环引用）
+    let mut chain = vec![server];
+    while let Some(jump) = chain
+        .last()
+        .and_then(|s| s.jump_host_id.as_deref())
+        .and_then(|id| config.servers.iter().find(|s| s.id == id))
+    {
+        if chain.iter().any(|s| s.id == jump.id) {
+            break;
+        }
+        chain.push(jump);
+    }
+    let mut used = HashSet::new();
+    let aliases: HashMap<&str, String> = chain
+        .iter()
+        .map(|s| (s.id.as_str(), unique_alias(&s.label, &s.host, &mut used)))
+        .collect();
+
+    // 配置：先写跳板机（最外层在前），目标服务器的 Host 块附加端口转发
+    let mut blocks: Vec<String> = chain
+        .iter()
+        .rev()
+        .map(|s| host_block(s, &config, &aliases))
+        .collect();
+    if let Some(last) = blocks.last_mut() {
+        for spec in forwards {
+            last.push_str(&match spec.kind {
+                ForwardKind::Local => format!(
+                    "    LocalForward {} {}\n",
+                    forward_address(&spec.bind_host, spec.bind_port),
+                    forward_address(&spec.target_host, spec.target_port)
+                ),
+                ForwardKind::Remote => format!(
+                    "    RemoteForward {} {}\n",
+                    forward_address(&spec.bind_host, spec.bind_port),
+                    forward_address(&spec.target_host, spec.target_port)
+                ),
+                ForwardKind::Dynamic => format!(
+                    "    DynamicForward {}\n",
+                    forward_address(&spec.bind_host, spec.bind_port)
+                ),
+            });
+        }
+    }
+
+    // 命令：跳板机以 -J 按连接顺序列出（跳板机自身的私钥与代理无法在 -J 中表达）
+    let mut args = vec!["ssh".to_string()];
+    if server.port != 22 {
+        args.push(format!("-p {}", server.port));
+    }
+    if let Some(key) = identity_file(server, &config) {
+        args.push(format!("-i {}", shell_quote(&key)));
+    }
+    if chain.len() > 1 {
+        let jumps: Vec<String> = chain[1..]
+            .iter()
+            .rev()
+            .map(|s| destination(s, &config, true))
+            .collect();
+        args.push(format!("-J {}", jumps.join(",")));
+    } else if let Some(command) = proxy_command(server) {
+        args.push(format!(
+            "-o {}",
+            shell_quote(&format!("ProxyCommand={}", command))
+        ));
+    }
+    for spec in forwards {
+        let bind = forward_address(&spec.bind_host, spec.bind_port);
+        args.push(match spec.kind {
+            ForwardKind::Dynamic => format!("-D {}", bind),
+            kind => format!(
+                "-{} {}:{}",
+                kind.short_label(),
+                bind,
+                forward_address(&spec.target_host, spec.target_port)
+            ),
+        });
+    }
+    args.push(destination(server, &config, false));
+
+    Ok(SshSnippet {
+        config: blocks.join("\n"),
+        command: args.join(" "),
+    })
+}
+
+/// ssh 目标：`[user@]host`，`with_port` 时非默认端口写作 `host:port`（用于 -J）
+fn destination(server: &ServerData, config: &ServerConfig, with_port: bool) -> String {
+    let username = effective_username(server, config);
+    let mut out = if username.is_empty() {
+        server.host.clone()
+    } else {
+        format!("{}@{}", username, server.host)
+    };
+    if with_port && server.port != 22 {
+        out = format!("{}:{}", out, server.port);
+    }
+    out
+}
+
+/// 转发地址 `host:port`（IPv6 地址加方括号）
+fn forward_address(host: &str, port: u16) -> String {
+    if host.contains(':') {
+        format!("[{}]:{}", host, port)
+    } else {
+        format!("{}:{}", host, port)
+    }
+}
+
+/// 由名称生成 Host 别名：空白替换为 -，重名时追加序号
+fn unique_alias(label: &str, host: &str, used: &mut HashSet<String>) -> String {
+    let base: String = label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .filter(|c| !matches!(c, '*' | '?' | '!' | '#' | '"'))
+        .collect();
+    let base = if base.is_empty() {
+        host.to_string()
+    } else {
+        base
+    };
+    let mut alias = base.clone();
+    let mut n = 2;
+    while !used.insert(alias.clone()) {
+        alias = format!("{}-{}", base, n);
+        n += 1;
+    }
+    alias
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::server::{ProxyConfig, ServerGroupData};
+    use crate::services::server_import::{self, ImportSource};
+
+    /// 不应出现在导出内容中的密码 / 口令
+    const SECRET: &str = "s3cr3t-v4lue";
+
+    fn sample_config() -> ServerConfig {
+        let group = |id: &str, name: &str, parent_id: Option<&str>| ServerGroupData {
+            id: id.to_string(),
+            name: name.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            ..Default::default()
+        };
+        let server = |id: &str, label: &str, host: &str, username: &str| ServerData {
+            id: id.to_string(),
+            label: label.to_string(),
+            host: host.to_string(),
+            username: username.to_string(),
+            ..Default::default()
+        };
+        ServerConfig {
+            groups: vec![
+                group("g-prod", "Prod", None),
+                group("g-web", "Web", Some("g-prod")),
+            ],
+            servers: vec![
+                ServerData {
+                    group_id: Some("g-web".to_string()),
+                    password_encrypted: Some(SECRET.to_string()),
+                    description: Some("frontend, primary".to_string()),
+                    tags: vec!["env:prod".to_string(), "role:web".to_string()],
+                    ..server("s-web", "Web 1", "10.0.0.1", "deploy")
+                },
+                ServerData {
+                    group_id: Some("g-prod".to_string()),
+                    port: 2222,
+                    auth_type: AuthType::PublicKey,
+                    private_key_path: Some("/home/me/.ssh/id_ed25519".to_string()),
+                    key_passphrase_encrypted: Some(SECRET.to_string()),
+                    jump_host_id: Some("s-web".to_string()),
+                    ..server("s-db", "DB", "db.internal", "postgres")
+                },
+                ServerData {
+                    proxy: Some(ProxyConfig {
+                        enabled: true,
+                        proxy_type: ProxyType::Socks5,
+                        host: "proxy.local".to_string(),
+                        port: 1080,
+                        username: Some("proxy-user".to_string()),
+                        password_encrypted: Some(SECRET.to_string()),
+                    }),
+                    ..server("s-lab", "Lab", "lab.example.com", "root")
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    /// 解析导出的 OpenSSH config：(Host 别名, 紧挨着的分组注释, 选项)
+    fn parse_ssh_config(text: &str) -> Vec<(String, Option<String>, HashMap<String, String>)> {
+        let mut blocks: Vec<(String, Option<String>, HashMap<String, String>)> = Vec::new();
+        let mut comment = None;
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                comment = None;
+            } else if let Some(group) = line.strip_prefix("# ") {
+                comment = Some(group.to_string());
+            } else if let Some(alias) = line.strip_prefix("Host ") {
+                blocks.push((alias.to_string(), comment.take(), HashMap::new()));
+            } else if let Some((key, value)) = line.trim().split_once(' ') {
+                if let Some((_, _, options)) = blocks.last_mut() {
+                    options.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+        blocks
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let config = sample_config();
+        let csv = to_csv(&config);
+        assert!(!csv.contains(SECRET));
+        assert!(csv.contains("socks5://proxy-user@proxy.local:1080"));
+
+        let path =
+            std::env::temp_dir().join(format!("shellmaster-export-{}.csv", uuid::Uuid::new_v4()));
+        fs::write(&path, &csv).unwrap();
+        let imported = server_import::parse(ImportSource::Termius, Some(&path));
+        let _ = fs::remove_file(&path);
+        let imported = imported.unwrap();
+
+        let summary: Vec<(&str, Option<&str>, &str, u16, &str)> = imported
+            .iter()
+            .map(|s| {
+                (
+                    s.label.as_str(),
+                    s.group.as_deref(),
+                    s.host.as_str(),
+                    s.port,
+                    s.username.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Web 1", Some("Prod/Web"), "10.0.0.1", 22, "deploy"),
+                ("DB", Some("Prod"), "db.internal", 2222, "postgres"),
+                ("Lab", None, "lab.example.com", 22, "root"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ssh_config_round_trip() {
+        let text = to_ssh_config(&sample_config());
+        assert!(!text.contains(SECRET));
+
+        let blocks = parse_ssh_config(&text);
+        let aliases: Vec<&str> = blocks.iter().map(|(alias, _, _)| alias.as_str()).collect();
+        assert_eq!(aliases, vec!["Web-1", "DB", "Lab"]);
+
+        let (_, group, web) = &blocks[0];
+        assert_eq!(group.as_deref(), Some("Prod/Web"));
+        assert_eq!(web["HostName"], "10.0.0.1");
+        assert_eq!(web["User"], "deploy");
+        assert!(!web.contains_key("Port"));
+
+        let (_, group, db) = &blocks[1];
+        assert_eq!(group.as_deref(), Some("Prod"));
+        assert_eq!(db["HostName"], "db.internal");
+        assert_eq!(db["Port"], "2222");
+        assert_eq!(db["IdentityFile"], "\"/home/me/.ssh/id_ed25519\"");
+        assert_eq!(db["ProxyJump"], "Web-1");
+
+        let (_, group, lab) = &blocks[2];
+        assert_eq!(*group, None);
+        assert_eq!(lab["User"], "root");
+        assert_eq!(lab["ProxyCommand"], "nc -X 5 -x proxy.local:1080 %h %p");
+    }
+}
@@ -0,0 +1,194 @@
+// 从其他 SSH 客户端导入服务器
+// 支持 PuTTY（.reg 导出或 Windows 注册表）、Termius 导出文件与 SecureCRT XML 导出；
+// 解析结果先标记重复项供用户预览勾选，确认后再写入服务器配置
+
+mod putty;
+mod securecrt;
+mod termius;
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tracing::{info, warn};
+
+use crate::models::server::{AuthType, ProxyConfig, ServerConfig, ServerData};
+use crate::services::storage;
+
+/// 导入来源
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImportSource {
+    /// PuTTY 会话的 .reg 导出文件
+    PuttyReg,
+    /// 直接读取本机注册表中的 PuTTY 会话（仅 Windows）
+    PuttyRegistry,
+    /// Termius 导出的 JSON / CSV
+    Termius,
+    /// SecureCRT 导出的 XML
+    SecureCrt,
+}
+
+impl ImportSource {
+    /// 当前平台可用的导入来源
+    pub fn available() -> Vec<ImportSource> {
+        let mut sources = vec![ImportSource::PuttyReg];
+        if cfg!(windows) {
+            sources.push(ImportSource::PuttyRegistry);
+        }
+        sources.push(ImportSource::Termius);
+        sources.push(ImportSource::SecureCrt);
+        sources
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ImportSource::PuttyReg => "server_import.source.putty_reg",
+            ImportSource::PuttyRegistry => "server_import.source.putty_registry",
+            ImportSource::Termius => "server_import.source.termius",
+            ImportSource::SecureCrt => "server_import.source.securecrt",
+        }
+    }
+
+    /// 文件选择器的扩展名过滤（不需要选择文件时为 None）
+    pub fn file_extensions(&self) -> Option<&'static [&'static str]> {
+        match self {
+            ImportSource::PuttyReg => Some(&["reg"]),
+            ImportSource::PuttyRegistry => None,
+            ImportSource::Termius => Some(&["json", "csv"]),
+            ImportSource::SecureCrt => Some(&["xml"]),
+        }
+    }
+}
+
+/// 解析出的一台服务器
+#[derive(Clone, Debug, Default)]
+pub struct ImportedServer {
+    pub label: String,
+    /// 来源中的分组/文件夹名称
+    pub group: Option<String>,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    /// 来源中引用的私钥文件
+    pub private_key_path: Option<PathBuf>,
+    pub proxy: Option<ProxyConfig>,
+    /// 与已有服务器或前面的导入项重复（主机、端口、用户名相同）
+    pub duplicate: bool,
+}
+
+/// 解析导入文件；`PuttyRegistry` 忽略 `path` 直接读取注册表
+pub fn parse(source: ImportSource, path: Option<&Path>) -> Result<Vec<ImportedServer>> {
+    let mut servers = match source {
+        ImportSource::PuttyRegistry => putty::read_registry()?,
+        _ => {
+            let path = path.context("No file selected")?;
+            let content = read_text(path)?;
+            match source {
+                ImportSource::PuttyReg => putty::parse_reg(&content),
+                ImportSource::Termius => termius::parse(&content)?,
+                ImportSource::SecureCrt => securecrt::parse(&content)?,
+                ImportSource::PuttyRegistry => unreachable!(),
+            }
+        }
+    };
+    servers.retain(|s| !s.host.is_empty());
+    for server in &mut servers {
+        if server.label.is_empty() {
+            server.label = server.host.clone();
+        }
+    }
+    mark_duplicates(&mut servers, &storage::load_servers().unwrap_or_default());
+    info!(
+        "[Import] Parsed {} servers from {:?}",
+        servers.len(),
+        source
+    );
+    Ok(servers)
+}
+
+/// 读取文本文件，兼容 regedit 默认的 UTF-16 编码与 UTF-8 BOM
+fn read_text(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("无法读取 {:?}", path))?;
+    let (text, _, _) = match bytes.as_slice() {
+        [0xFF, 0xFE, ..] => encoding_rs::UTF_16LE.decode(&bytes),
+        [0xFE, 0xFF, ..] => encoding_rs::UTF_16BE.decode(&bytes),
+        _ => encoding_rs::UTF_8.decode(&bytes),
+    };
+    Ok(text.into_owned())
+}
+
+/// 重复判断的键：主机（忽略大小写）、端口、用户名
+fn dedupe_key(host: &str, port: u16, username: &str) -> (String, u16, String) {
+    (host.to_lowercase(), port, username.to_string())
+}
+
+/// 标记与已有服务器或列表中靠前项重复的条目
+fn mark_duplicates(servers: &mut [ImportedServer], existing: &ServerConfig) {
+    let mut seen: HashSet<_> = existing
+        .servers
+        .iter()
+        .map(|s| dedupe_key(&s.host, s.port, &s.username))
+        .collect();
+    for server in servers {
+        server.duplicate = !seen.insert(dedupe_key(&server.host, server.port, &server.username));
+    }
+}
+
+/// 把选中的服务器写入配置，按路径复用或新建分组，返回导入数量
+pub fn import(servers: &[ImportedServer]) -> Result<usize> {
+    let mut config = storage::load_servers()?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    for imported in servers {
+        // 来源中的文件夹路径（如 “生产/数据库”）映射为嵌套分组
+        let group_id = imported
+            .group
+            .as_deref()
+            .and_then(|path| config.ensure_group_path(path));
+
+        // 私钥复制到应用密钥目录；PuTTY 的 .ppk 格式无法直接使用，保留认证方式为密码
+        let private_key_filename = imported
+            .private_key_path
+            .as_ref()
+            .filter(|path| path.exists())
+            .filter(|path| path.extension().is_none_or(|ext| ext != "ppk"))
+            .and_then(|path| match storage::store_private_key(path) {
+                Ok(filename) => Some(filename),
+                Err(e) => {
+                    warn!("[Import] Failed to copy private key {:?}: {}", path, e);
+                    None
+                }
+            });
+
+        config.servers.push(ServerData {
+            id: uuid::Uuid::new_v4().to_string(),
+            group_id,
+            label: imported.label.clone(),
+            host: imported.host.clone(),
+            port: imported.port,
+            username: imported.username.clone(),
+            auth_type: if private_key_filename.is_some() {
+                AuthType::PublicKey
+            } else {
+                AuthType::Password
+            },
+            private_key_filename,
+            proxy: imported.proxy.clone(),
+            created_at: now.clone(),
+            ..Default::default()
+        });
+    }
+
+    storage::save_servers(&config)?;
+    info!("[Import] Imported {} servers", servers.len());
+    Ok(servers.len())
+}
+
+/// 拆分 `user@host` 形式的主机名
+fn split_user_host(value: &str) -> (Option<String>, String) {
+    match value.rsplit_once('@') {
+        Some((user, host)) => (Some(user.to_string()), host.to_string()),
+        None => (None, value.to_string()),
+    }
+}
@@ -0,0 +1,268 @@
+// PuTTY 会话导入：解析 regedit 导出的 .reg 文件（Windows 上也可直接导出注册表后解析）
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use super::{split_user_host, ImportedServer};
+use crate::models::server::{ProxyConfig, ProxyType};
+
+/// 会话所在的注册表路径
+const SESSIONS_KEY: &str = r"HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\";
+
+/// 注册表中的值
+enum RegValue {
+    String(String),
+    Dword(u32),
+}
+
+/// 解析 .reg 文件中的所有 SSH 会话
+pub fn parse_reg(content: &str) -> Vec<ImportedServer> {
+    let mut sessions: Vec<(String, HashMap<String, RegValue>)> = Vec::new();
+    // 当前 key 是否为 PuTTY 会话（其他 key 下的值不能归入上一个会话）
+    let mut in_session = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(key) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_session = match key.strip_prefix(SESSIONS_KEY) {
+                Some(name) => {
+                    sessions.push((decode_session_name(name), HashMap::new()));
+                    true
+                }
+                None => false,
+            };
+            continue;
+        }
+        let Some((_, values)) = sessions.last_mut().filter(|_| in_session) else {
+            continue;
+        };
+        if let Some((name, value)) = parse_value_line(line) {
+            values.insert(name, value);
+        }
+    }
+
+    sessions
+        .into_iter()
+        .filter_map(|(name, values)| to_server(name, &values))
+        .collect()
+}
+
+/// 读取本机注册表中的 PuTTY 会话（通过 `reg export` 导出到临时文件后解析）
+#[cfg(windows)]
+pub fn read_registry() -> Result<Vec<ImportedServer>> {
+    use anyhow::{bail, Context};
+    use std::os::windows::process::CommandExt;
+
+    // 不弹出控制台窗口
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let path = std::env::temp_dir().join(format!("putty-{}.reg", uuid::Uuid::new_v4()));
+    let output = std::process::Command::new("reg")
+        .args(["export", SESSIONS_KEY.trim_end_matches('\\')])
+        .arg(&path)
+        .arg("/y")
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .context("无法运行 reg 命令")?;
+    if !output.status.success() {
+        bail!("No PuTTY sessions found in the registry");
+    }
+    let content = super::read_text(&path);
+    let _ = std::fs::remove_file(&path);
+    Ok(parse_reg(&content?))
+}
+
+#[cfg(not(windows))]
+pub fn read_registry() -> Result<Vec<ImportedServer>> {
+    anyhow::bail!("Reading the PuTTY registry is only supported on Windows")
+}
+
+/// 会话名在注册表中经过 URL 编码（如空格为 %20）
+fn decode_session_name(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// 解析 `"Name"="value"` 或 `"Name"=dword:00000016`
+fn parse_value_line(line: &str) -> Option<(String, RegValue)> {
+    let (name, value) = line.strip_prefix('"')?.split_once("\"=")?;
+    let value = if let Some(hex) = value.strip_prefix("dword:") {
+        RegValue::Dword(u32::from_str_radix(hex, 16).ok()?)
+    } else {
+        let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+        RegValue::String(inner.replace("\\\"", "\"").replace("\\\\", "\\"))
+    };
+    Some((name.to_string(), value))
+}
+
+fn to_server(name: String, values: &HashMap<String, RegValue>) -> Option<ImportedServer> {
+    let string = |key: &str| match values.get(key) {
+        Some(RegValue::String(s)) if !s.is_empty() => Some(s.clone()),
+        _ => None,
+    };
+    let dword = |key: &str| match values.get(key) {
+        Some(RegValue::Dword(v)) => Some(*v),
+        _ => None,
+    };
+
+    // 只导入 SSH 会话（未设置协议时 PuTTY 默认使用 SSH）
+    if string("Protocol").is_some_and(|p| p != "ssh") {
+        return None;
+    }
+    let (host_user, host) = split_user_host(&string("HostName")?);
+    let username = string("UserName").or(host_user).unwrap_or_default();
+
+    // ProxyMethod: 0 无，1 SOCKS4，2 SOCKS5，3 HTTP，4 Telnet，5 本地命令
+    let proxy_type = match dword("ProxyMethod") {
+        Some(1 | 2) => Some(ProxyType::Socks5),
+        Some(3) => Some(ProxyType::Http),
+        _ => None,
+    };
+    let proxy = proxy_type.and_then(|proxy_type| {
+        Some(ProxyConfig {
+            enabled: true,
+            proxy_type,
+            host: string("ProxyHost")?,
+            port: dword("ProxyPort").and_then(|p| u16::try_from(p).ok())?,
+            username: string("ProxyUsername"),
+            password_encrypted: None,
+        })
+    });
+
+    Some(ImportedServer {
+        label: name,
+        group: None,
+        host,
+        port: dword("PortNumber")
+            .and_then(|p| u16::try_from(p).ok())
+            .unwrap_or(22),
+        username,
+        private_key_path: string("PublicKeyFile").map(Into::into),
+        proxy,
+        duplicate: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const SESSIONS: &str = r#"Windows Registry Editor Version 5.00
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\Web%20Server]
+"HostName"="admin@web.example.com"
+"PortNumber"=dword:00000016
+"Protocol"="ssh"
+"PublicKeyFile"="C:\\keys\\web.ppk"
+"ProxyMethod"=dword:00000002
+"ProxyHost"="proxy.local"
+"ProxyPort"=dword:00000438
+"ProxyUsername"=""
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\db]
+"HostName"="10.0.0.5"
+"UserName"="postgres"
+"PortNumber"=dword:00000922
+"ProxyMethod"=dword:00000003
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\serial]
+"Protocol"="serial"
+"HostName"="COM1"
+
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\Default%20Settings]
+"HostName"=""
+"#;
+
+    #[test]
+    fn test_parse_reg() {
+        let servers = parse_reg(SESSIONS);
+        assert_eq!(servers.len(), 2);
+
+        let web = &servers[0];
+        assert_eq!(web.label, "Web Server");
+        assert_eq!(web.host, "web.example.com");
+        assert_eq!(web.username, "admin");
+        assert_eq!(web.port, 22);
+        assert_eq!(
+            web.private_key_path,
+            Some(PathBuf::from(r"C:\keys\web.ppk"))
+        );
+        let proxy = web.proxy.as_ref().unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Socks5);
+        assert_eq!(proxy.host, "proxy.local");
+        assert_eq!(proxy.port, 1080);
+        assert_eq!(proxy.username, None);
+
+        // HTTP 代理缺少地址时不导入代理
+        let db = &servers[1];
+        assert_eq!(db.label, "db");
+        assert_eq!(db.host, "10.0.0.5");
+        assert_eq!(db.username, "postgres");
+        assert_eq!(db.port, 2338);
+        assert!(db.proxy.is_none());
+    }
+
+    #[test]
+    fn test_parse_reg_malformed() {
+        assert!(parse_reg("").is_empty());
+        assert!(parse_reg("not a registry file").is_empty());
+
+        // 会话之外的值（包括其他 key 下的值）、无法解析的值与未闭合的 key 行都被忽略
+        let content = r#""HostName"="orphan.example.com"
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\broken]
+"HostName"="broken.example.com"
+"PortNumber"=dword:zzzz
+"UserName"=unquoted
+[HKEY_CURRENT_USER\Software\SimonTatham\PuTTY\Sessions\unclosed
+"UserName"="root"
+[HKEY_CURRENT_USER\Software\Other\Sessions\skipped]
+"HostName"="other.example.com"
+"#;
+        let servers = parse_reg(content);
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].label, "broken");
+        assert_eq!(servers[0].host, "broken.example.com");
+        assert_eq!(servers[0].port, 22);
+        assert_eq!(servers[0].username, "root");
+    }
+
+    #[test]
+    fn test_decode_session_name() {
+        assert_eq!(decode_session_name("plain"), "plain");
+        assert_eq!(decode_session_name("My%20Server"), "My Server");
+        assert_eq!(decode_session_name("%E4%BD%A0%E5%A5%BD"), "你好");
+        // 不完整或非法的转义原样保留
+        assert_eq!(decode_session_name("50%zz"), "50%zz");
+        assert_eq!(decode_session_name("tail%2"), "tail%2");
+    }
+
+    #[test]
+    fn test_parse_value_line() {
+        assert!(matches!(
+            parse_value_line(r#""PortNumber"=dword:00000016"#),
+            Some((name, RegValue::Dword(22))) if name == "PortNumber"
+        ));
+        assert!(matches!(
+            parse_value_line(r#""HostName"="say \"hi\" C:\\dir""#),
+            Some((_, RegValue::String(value))) if value == r#"say "hi" C:\dir"#
+        ));
+        assert!(parse_value_line("HostName=value").is_none());
+        assert!(parse_value_line(r#""HostName"="unterminated"#).is_none());
+        assert!(parse_value_line(r#""PortNumber"=dword:xyz"#).is_none());
+    }
+}
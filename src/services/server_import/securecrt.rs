@@ -0,0 +1,225 @@
+// SecureCRT 导入：解析 “Export Settings” 生成的 XML
+// 结构为 <VanDyke><key name="Sessions"><key name="文件夹"><key name="会话">…，
+// 会话的各项设置为 <string name="Hostname">…</string>、<dword name="[SSH2] Port">22</dword> 等
+
+use anyhow::{Context, Result};
+use roxmltree::{Document, Node};
+
+use super::{split_user_host, ImportedServer};
+use crate::models::server::{ProxyConfig, ProxyType};
+
+pub fn parse(content: &str) -> Result<Vec<ImportedServer>> {
+    let doc = Document::parse(content).context("无法解析 SecureCRT XML 文件")?;
+    let root = doc.root_element();
+    let Some(sessions) = child_key(root, "Sessions") else {
+        return Ok(Vec::new());
+    };
+    let firewalls = child_key(root, "Firewalls");
+
+    let mut servers = Vec::new();
+    collect(sessions, &mut Vec::new(), firewalls, &mut servers);
+    Ok(servers)
+}
+
+/// 名为 `name` 的直接子 key
+fn child_key<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.has_tag_name("key") && n.attribute("name") == Some(name))
+}
+
+/// 指定类型（string / dword）、名称的设置值
+fn setting<'a>(node: Node<'a, '_>, tag: &str, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|n| n.has_tag_name(tag) && n.attribute("name") == Some(name))
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// 递归遍历文件夹；含 Hostname 的 key 视为会话，文件夹路径作为分组名
+fn collect(
+    node: Node,
+    path: &mut Vec<String>,
+    firewalls: Option<Node>,
+    servers: &mut Vec<ImportedServer>,
+) {
+    for key in node.children().filter(|n| n.has_tag_name("key")) {
+        let name = key.attribute("name").unwrap_or_default().to_string();
+        if setting(key, "string", "Hostname").is_some() {
+            servers.extend(to_server(key, name, path, firewalls));
+        } else {
+            path.push(name);
+            collect(key, path, firewalls, servers);
+            path.pop();
+        }
+    }
+}
+
+fn to_server(
+    key: Node,
+    name: String,
+    path: &[String],
+    firewalls: Option<Node>,
+) -> Option<ImportedServer> {
+    // 只导入 SSH 会话
+    let protocol = setting(key, "string", "Protocol Name").unwrap_or("SSH2");
+    if !protocol.starts_with("SSH") {
+        return None;
+    }
+    let (host_user, host) = split_user_host(setting(key, "string", "Hostname")?);
+    let port_name = if protocol == "SSH1" {
+        "[SSH1] Port"
+    } else {
+        "[SSH2] Port"
+    };
+    // 身份文件格式为 “路径::rawkey” 等，只取路径部分
+    let private_key_path = setting(key, "string", "Identity Filename V2")
+        .and_then(|value| value.split("::").next())
+        .filter(|path| !path.is_empty())
+        .map(Into::into);
+
+    Some(ImportedServer {
+        label: name,
+        group: (!path.is_empty()).then(|| path.join("/")),
+        host,
+        port: setting(key, "dword", port_name)
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(22),
+        username: setting(key, "string", "Username")
+            .map(str::to_string)
+            .or(host_user)
+            .unwrap_or_default(),
+        private_key_path,
+        proxy: firewall(key, firewalls),
+        duplicate: false,
+    })
+}
+
+/// 会话引用的全局防火墙（代理）配置；“None” 表示不使用
+fn firewall(key: Node, firewalls: Option<Node>) -> Option<ProxyConfig> {
+    let name = setting(key, "string", "Firewall Name").filter(|n| *n != "None")?;
+    let firewall = child_key(firewalls?, name)?;
+    let proxy_type = match setting(firewall, "string", "Type") {
+        Some(t) if t.to_uppercase().contains("SOCKS") => ProxyType::Socks5,
+        _ => ProxyType::Http,
+    };
+    Some(ProxyConfig {
+        enabled: true,
+        proxy_type,
+        host: setting(firewall, "string", "Address")?.to_string(),
+        port: setting(firewall, "dword", "Port")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(1080),
+        username: setting(firewall, "string", "Username").map(str::to_string),
+        password_encrypted: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const EXPORT: &str = r#"<VanDyke version="3.0">
+  <key name="Firewalls">
+    <key name="corp">
+      <string name="Type">SOCKS version 5</string>
+      <string name="Address">proxy.corp</string>
+      <dword name="Port">1081</dword>
+      <string name="Username">alice</string>
+    </key>
+    <key name="web-proxy">
+      <string name="Type">HTTP</string>
+      <string name="Address">http.corp</string>
+    </key>
+  </key>
+  <key name="Sessions">
+    <key name="Prod">
+      <key name="Web">
+        <key name="web01">
+          <string name="Hostname">web01.example.com</string>
+          <string name="Protocol Name">SSH2</string>
+          <dword name="[SSH2] Port">2222</dword>
+          <string name="Username">deploy</string>
+          <string name="Identity Filename V2">C:\keys\id_rsa::rawkey</string>
+          <string name="Firewall Name">corp</string>
+        </key>
+      </key>
+    </key>
+    <key name="legacy">
+      <string name="Hostname">root@old.example.com</string>
+      <string name="Protocol Name">SSH1</string>
+      <dword name="[SSH1] Port">2022</dword>
+      <dword name="[SSH2] Port">22</dword>
+      <string name="Firewall Name">None</string>
+    </key>
+    <key name="behind-http">
+      <string name="Hostname">  intranet.local  </string>
+      <dword name="[SSH2] Port">not-a-port</dword>
+      <string name="Firewall Name">web-proxy</string>
+    </key>
+    <key name="telnet">
+      <string name="Hostname">switch.local</string>
+      <string name="Protocol Name">Telnet</string>
+    </key>
+    <key name="Empty Folder"/>
+    <key name="blank">
+      <string name="Hostname"></string>
+    </key>
+  </key>
+</VanDyke>"#;
+
+    #[test]
+    fn test_parse() {
+        let servers = parse(EXPORT).unwrap();
+        assert_eq!(servers.len(), 3);
+
+        let web = &servers[0];
+        assert_eq!(web.label, "web01");
+        assert_eq!(web.group.as_deref(), Some("Prod/Web"));
+        assert_eq!(web.host, "web01.example.com");
+        assert_eq!(web.port, 2222);
+        assert_eq!(web.username, "deploy");
+        assert_eq!(web.private_key_path, Some(PathBuf::from(r"C:\keys\id_rsa")));
+        let proxy = web.proxy.as_ref().unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Socks5);
+        assert_eq!(proxy.host, "proxy.corp");
+        assert_eq!(proxy.port, 1081);
+        assert_eq!(proxy.username.as_deref(), Some("alice"));
+
+        // SSH1 会话使用 [SSH1] Port；“None” 表示不使用代理
+        let legacy = &servers[1];
+        assert_eq!(legacy.group, None);
+        assert_eq!(legacy.host, "old.example.com");
+        assert_eq!(legacy.username, "root");
+        assert_eq!(legacy.port, 2022);
+        assert!(legacy.proxy.is_none());
+
+        // 未设置协议视为 SSH2；无法解析的端口使用默认值
+        let intranet = &servers[2];
+        assert_eq!(intranet.host, "intranet.local");
+        assert_eq!(intranet.port, 22);
+        let proxy = intranet.proxy.as_ref().unwrap();
+        assert_eq!(proxy.proxy_type, ProxyType::Http);
+        assert_eq!(proxy.port, 1080);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert!(parse("").is_err());
+        assert!(parse("<VanDyke><key name=\"Sessions\">").is_err());
+        assert!(parse("<VanDyke/>").unwrap().is_empty());
+        // 引用不存在的防火墙时不导入代理
+        let content = r#"<VanDyke>
+  <key name="Sessions">
+    <key name="s">
+      <string name="Hostname">h.example.com</string>
+      <string name="Firewall Name">missing</string>
+    </key>
+  </key>
+</VanDyke>"#;
+        let servers = parse(content).unwrap();
+        assert_eq!(servers.len(), 1);
+        assert!(servers[0].proxy.is_none());
+    }
+}
@@ -0,0 +1,278 @@
+// Termius 导入：支持 JSON 导出（主机数组或嵌套结构）与 CSV 导出
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+use super::{split_user_host, ImportedServer};
+
+/// 按内容判断格式：以 `[` 或 `{` 开头视为 JSON，否则按 CSV 解析
+pub fn parse(content: &str) -> Result<Vec<ImportedServer>> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('[') || trimmed.starts_with('{') {
+        let value: Value = serde_json::from_str(trimmed).context("无法解析 Termius JSON 文件")?;
+        let mut servers = Vec::new();
+        collect_json(&value, None, &mut servers);
+        Ok(servers)
+    } else {
+        parse_csv(content)
+    }
+}
+
+/// 递归查找含地址字段的对象；分组对象的名称向下传递
+fn collect_json(value: &Value, group: Option<&str>, servers: &mut Vec<ImportedServer>) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                collect_json(item, group, servers);
+            }
+        }
+        Value::Object(map) => {
+            if let Some(address) = ["address", "hostname", "host"]
+                .iter()
+                .find_map(|key| map.get(*key).and_then(Value::as_str))
+            {
+                servers.push(json_host(value, address, group));
+                return;
+            }
+            // 分组对象：{ "label": "...", "hosts": [...] }
+            let group_name = map
+                .get("label")
+                .or_else(|| map.get("name"))
+                .and_then(Value::as_str)
+                .or(group);
+            for child in map.values().filter(|v| v.is_array() || v.is_object()) {
+                collect_json(child, group_name, servers);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_host(value: &Value, address: &str, group: Option<&str>) -> ImportedServer {
+    let text = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    // SSH 参数可能在顶层，也可能在 ssh_config / ssh_config.identity 中
+    let ssh = value.get("ssh_config").unwrap_or(&Value::Null);
+    let identity = ssh.get("identity").unwrap_or(&Value::Null);
+
+    let (host_user, host) = split_user_host(address);
+    let username = text("username")
+        .or_else(|| {
+            ssh.get("username")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .or_else(|| {
+            identity
+                .get("username")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        })
+        .or(host_user)
+        .unwrap_or_default();
+    let port = [value.get("port"), ssh.get("port")]
+        .into_iter()
+        .flatten()
+        .find_map(json_port)
+        .unwrap_or(22);
+    let group = match value.get("group") {
+        Some(Value::String(name)) => Some(name.clone()),
+        Some(group) => group
+            .get("label")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        None => group.map(str::to_string),
+    };
+
+    ImportedServer {
+        label: text("label").or_else(|| text("name")).unwrap_or_default(),
+        group,
+        host,
+        port,
+        username,
+        ..Default::default()
+    }
+}
+
+/// 端口可能是数字或字符串
+fn json_port(value: &Value) -> Option<u16> {
+    match value {
+        Value::Number(n) => n.as_u64().and_then(|p| u16::try_from(p).ok()),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// 解析带表头的 CSV，列名不区分大小写
+fn parse_csv(content: &str) -> Result<Vec<ImportedServer>> {
+    let mut lines = content.lines().filter(|l| !l.trim().is_empty());
+    let Some(header) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: Vec<String> = split_csv_line(header)
+        .into_iter()
+        .map(|c| c.trim().to_lowercase())
+        .collect();
+    let find = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+    let Some(host_col) = find(&["hostname", "address", "host"]) else {
+        bail!("CSV file has no Hostname/Address column");
+    };
+    let label_col = find(&["label", "name", "alias"]);
+    let group_col = find(&["group", "groups", "folder"]);
+    let port_col = find(&["port"]);
+    let user_col = find(&["username", "user"]);
+
+    Ok(lines
+        .map(|line| {
+            let fields = split_csv_line(line);
+            let field = |col: Option<usize>| {
+                col.and_then(|i| fields.get(i))
+                    .map(|f| f.trim().to_string())
+                    .filter(|f| !f.is_empty())
+            };
+            let (host_user, host) = split_user_host(&field(Some(host_col)).unwrap_or_default());
+            ImportedServer {
+                label: field(label_col).unwrap_or_default(),
+                group: field(group_col),
+                host,
+                port: field(port_col).and_then(|p| p.parse().ok()).unwrap_or(22),
+                username: field(user_col).or(host_user).unwrap_or_default(),
+                ..Default::default()
+            }
+        })
+        .collect())
+}
+
+/// 按逗号拆分一行，支持双引号包裹与 `""` 转义
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    fields.push(current);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find<'a>(servers: &'a [ImportedServer], label: &str) -> &'a ImportedServer {
+        servers.iter().find(|s| s.label == label).unwrap()
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let content = r#"
+        {
+            "groups": [
+                {
+                    "label": "Prod",
+                    "hosts": [
+                        { "label": "api", "address": "deploy@api.example.com", "port": "2222" },
+                        {
+                            "label": "db",
+                            "hostname": "10.0.0.9",
+                            "ssh_config": { "port": 5432, "identity": { "username": "pg" } }
+                        }
+                    ]
+                }
+            ],
+            "hosts": [
+                { "name": "solo", "host": "solo.example.com", "group": { "label": "Misc" } },
+                { "label": "bad-port", "address": "b.example.com", "port": 70000 }
+            ]
+        }"#;
+        let servers = parse(content).unwrap();
+        assert_eq!(servers.len(), 4);
+
+        let api = find(&servers, "api");
+        assert_eq!(api.group.as_deref(), Some("Prod"));
+        assert_eq!(api.host, "api.example.com");
+        assert_eq!(api.username, "deploy");
+        assert_eq!(api.port, 2222);
+
+        let db = find(&servers, "db");
+        assert_eq!(db.group.as_deref(), Some("Prod"));
+        assert_eq!(db.host, "10.0.0.9");
+        assert_eq!(db.username, "pg");
+        assert_eq!(db.port, 5432);
+
+        let solo = find(&servers, "solo");
+        assert_eq!(solo.group.as_deref(), Some("Misc"));
+        assert_eq!(solo.username, "");
+        assert_eq!(solo.port, 22);
+
+        // 超出范围的端口使用默认值
+        assert_eq!(find(&servers, "bad-port").port, 22);
+    }
+
+    #[test]
+    fn test_parse_json_malformed() {
+        assert!(parse("{ \"hosts\": [").is_err());
+        assert!(parse("[1, \"text\", null, {\"label\": \"no address\"}]")
+            .unwrap()
+            .is_empty());
+        let servers = parse("[{\"address\": \"a.example.com\", \"port\": \"ssh\"}]").unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].label, "");
+        assert_eq!(servers[0].port, 22);
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let content = "Label,Group,Hostname,Port,Username\n\
+                       \"Web, primary\",Prod,web.example.com,2200,root\n\
+                       \n\
+                       db,,admin@db.example.com,,\n\
+                       \"Quote \"\"test\"\"\",Dev,q.example.com,notaport,\n";
+        let servers = parse(content).unwrap();
+        assert_eq!(servers.len(), 3);
+
+        assert_eq!(servers[0].label, "Web, primary");
+        assert_eq!(servers[0].group.as_deref(), Some("Prod"));
+        assert_eq!(servers[0].host, "web.example.com");
+        assert_eq!(servers[0].port, 2200);
+        assert_eq!(servers[0].username, "root");
+
+        assert_eq!(servers[1].group, None);
+        assert_eq!(servers[1].host, "db.example.com");
+        assert_eq!(servers[1].username, "admin");
+        assert_eq!(servers[1].port, 22);
+
+        assert_eq!(servers[2].label, "Quote \"test\"");
+        assert_eq!(servers[2].port, 22);
+        assert_eq!(servers[2].username, "");
+    }
+
+    #[test]
+    fn test_parse_csv_malformed() {
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("Hostname,Port\n").unwrap().is_empty());
+        assert!(parse("Name,Port\nweb,22\n").is_err());
+        // 列数不足的行缺少的字段为空
+        let servers = parse("name,port,host\nshort\n").unwrap();
+        assert_eq!(servers.len(), 1);
+        assert_eq!(servers[0].label, "short");
+        assert_eq!(servers[0].host, "");
+    }
+
+    #[test]
+    fn test_split_csv_line() {
+        assert_eq!(split_csv_line("a,b,c"), ["a", "b", "c"]);
+        assert_eq!(split_csv_line("a,\"b,c\",\"\"\"\""), ["a", "b,c", "\""]);
+        assert_eq!(split_csv_line(",,"), ["", "", ""]);
+        // 未闭合的引号一直延续到行尾
+        assert_eq!(split_csv_line("\"abc,def"), ["abc,def"]);
+    }
+}
@@ -0,0 +1,283 @@
+// 服务器快速搜索：模糊匹配名称、主机、用户名与标签，
+// 并解析 `user@host:port` 形式的输入用于未保存服务器的临时连接
+
+use std::path::PathBuf;
+
+use crate::models::server::{AuthType, ServerConfig, ServerData};
+
+/// 临时连接的服务器 ID 前缀（不会写入配置）
+pub const ADHOC_ID_PREFIX: &str = "adhoc-";
+
+/// 单个词在文本中的模糊匹配得分（`term` 需已小写），不匹配时为 None
+///
+/// 连续子串得分最高且越靠前越高；否则按子序列匹配，相邻字符与单词起始位置加分
+pub fn fuzzy_score(term: &str, text: &str) -> Option<i32> {
+    let text = text.to_lowercase();
+    if let Some(pos) = text.find(term) {
+        let prefix_bonus = if pos == 0 { 50 } else { 0 };
+        return Some(100 + prefix_bonus - pos.min(50) as i32);
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut score = 0;
+    let mut start = 0;
+    let mut prev: Option<usize> = None;
+    for qc in term.chars() {
+        let found = (start..chars.len()).find(|&i| chars[i] == qc)?;
+        score += match prev {
+            Some(p) if p + 1 == found => 5,
+            _ => 1,
+        };
+        if found == 0 || !chars[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        prev = Some(found);
+        start = found + 1;
+    }
+    Some(score)
+}
+
+/// 多个词（需已小写）在多个字段中的综合得分：每个词都需命中某个字段，取各词最高分之和
+pub fn match_score(terms: &[String], fields: &[&str]) -> Option<i32> {
+    terms.iter().try_fold(0, |total, term| {
+        fields
+            .iter()
+            .filter_map(|field| fuzzy_score(term, field))
+            .max()
+            .map(|score| total + score)
+    })
+}
+
+/// 把搜索框内容拆成小写的搜索词
+pub fn search_terms(query: &str) -> Vec<String> {
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+/// 已保存服务器的匹配得分（名称、主机、用户名、标签）
+pub fn server_score(server: &ServerData, terms: &[String]) -> Option<i32> {
+    let mut fields = vec![
+        server.label.as_str(),
+        server.host.as_str(),
+        server.username.as_str(),
+    ];
+    fields.extend(server.tags.iter().map(String::as_str));
+    match_score(terms, &fields)
+}
+
+/// 得分最高的已保存服务器；`tags` 非空时只在同时具备这些标签的服务器中查找
+pub fn best_match<'a>(
+    config: &'a ServerConfig,
+    query: &str,
+    tags: &[String],
+) -> Option<&'a ServerData> {
+    let terms = search_terms(query);
+    if terms.is_empty() {
+        return None;
+    }
+    config
+        .servers
+        .iter()
+        .filter(|s| {
+            tags.iter()
+                .all(|tag| s.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        })
+        .filter_map(|s| server_score(s, &terms).map(|score| (score, s)))
+        // 同分时保持列表中靠前的服务器
+        .fold(
+            None,
+            |best: Option<(i32, &ServerData)>, (score, s)| match best {
+                Some((best_score, _)) if best_score >= score => best,
+                _ => Some((score, s)),
+            },
+        )
+        .map(|(_, s)| s)
+}
+
+/// `user@host[:port]` 形式的临时连接目标
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdHocTarget {
+    pub username: String,
+    pub host: String,
+    pub port: u16,
+}
+
+impl AdHocTarget {
+    /// 解析 `user@host`、`user@host:port`、`user@[ipv6]:port`，可带 `ssh ` 前缀
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let input = input.strip_prefix("ssh ").unwrap_or(input).trim();
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return None;
+        }
+        let (username, address) = input.rsplit_once('@')?;
+        if username.is_empty() || address.is_empty() {
+            return None;
+        }
+
+        let (host, port) = if let Some(rest) = address.strip_prefix('[') {
+            // [ipv6]:port
+            let (host, rest) = rest.split_once(']')?;
+            let port = match rest.strip_prefix(':') {
+                Some(port) => port.parse().ok()?,
+                None if rest.is_empty() => 22,
+                None => return None,
+            };
+            (host.to_string(), port)
+        } else if address.matches(':').count() == 1 {
+            let (host, port) = address.split_once(':')?;
+            (host.to_string(), port.parse().ok()?)
+        } else {
+            // 无端口，或未加方括号的 IPv6 地址
+            (address.to_string(), 22)
+        };
+        if host.is_empty() || port == 0 {
+            return None;
+        }
+
+        Some(Self {
+            username: username.to_string(),
+            host,
+            port,
+        })
+    }
+
+    /// 显示文本，如 `root@example.com:2222`
+    pub fn display(&self) -> String {
+        if self.port == 22 {
+            format!("{}@{}", self.username, self.host)
+        } else {
+            format!("{}@{}:{}", self.username, self.host, self.port)
+        }
+    }
+
+    /// 与目标主机、端口、用户名相同的已保存服务器
+    pub fn find_saved<'a>(&self, config: &'a ServerConfig) -> Option<&'a ServerData> {
+        config.servers.iter().find(|s| {
+            s.host.eq_ignore_ascii_case(&self.host)
+                && s.port == self.port
+                && s.username == self.username
+        })
+    }
+
+    /// 构造不保存的临时服务器配置；未填写密码时尝试使用 ~/.ssh 下的默认私钥
+    pub fn to_server_data(&self, password: Option<String>) -> ServerData {
+        let mut server = ServerData {
+            id: format!("{}{}", ADHOC_ID_PREFIX, uuid::Uuid::new_v4()),
+            label: self.display(),
+            host: self.host.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            ..Default::default()
+        };
+        match (password.filter(|p| !p.is_empty()), default_private_key()) {
+            (Some(password), _) => server.password_encrypted = Some(password),
+            (None, Some(key)) => {
+                server.auth_type = AuthType::PublicKey;
+                server.private_key_path = Some(key.to_string_lossy().to_string());
+            }
+            (None, None) => {}
+        }
+        server
+    }
+}
+
+/// 本机当前用户名（省略用户名的 ssh:// 链接与命令行参数使用，与 ssh 命令一致）
+pub fn local_username() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|u| !u.is_empty())
+}
+
+/// ~/.ssh 下第一个存在的默认私钥
+fn default_private_key() -> Option<PathBuf> {
+    let ssh_dir = dirs::home_dir()?.join(".ssh");
+    ["id_ed25519", "id_ecdsa", "id_rsa"]
+        .iter()
+        .map(|name| ssh_dir.join(name))
+        .find(|path| path.exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(username: &str, host: &str, port: u16) -> Option<AdHocTarget> {
+        Some(AdHocTarget {
+            username: username.to_string(),
+            host: host.to_string(),
+            port,
+        })
+    }
+
+    #[test]
+    fn test_adhoc_target_parse() {
+        assert_eq!(
+            AdHocTarget::parse("root@example.com"),
+            target("root", "example.com", 22)
+        );
+        assert_eq!(
+            AdHocTarget::parse("  ssh root@example.com:2222 "),
+            target("root", "example.com", 2222)
+        );
+        assert_eq!(
+            AdHocTarget::parse("admin@[2001:db8::1]:2200"),
+            target("admin", "2001:db8::1", 2200)
+        );
+        assert_eq!(
+            AdHocTarget::parse("admin@[::1]"),
+            target("admin", "::1", 22)
+        );
+        // 未加方括号的 IPv6 地址不能带端口
+        assert_eq!(
+            AdHocTarget::parse("admin@2001:db8::1"),
+            target("admin", "2001:db8::1", 22)
+        );
+        // 用户名中可以包含 @
+        assert_eq!(
+            AdHocTarget::parse("me@corp@jump.example.com"),
+            target("me@corp", "jump.example.com", 22)
+        );
+    }
+
+    #[test]
+    fn test_adhoc_target_parse_rejects_malformed() {
+        for input in [
+            "",
+            "ssh",
+            "example.com",
+            "@example.com",
+            "root@",
+            "root@:22",
+            "root@host:",
+            "root@host:0",
+            "root@host:65536",
+            "root@host:ssh",
+            "root @host",
+            "root@[::1",
+            "root@[::1]22",
+            "root@[]:22",
+        ] {
+            assert_eq!(AdHocTarget::parse(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_adhoc_target_display() {
+        assert_eq!(
+            AdHocTarget::parse("root@example.com:22").unwrap().display(),
+            "root@example.com"
+        );
+        assert_eq!(
+            AdHocTarget::parse("root@example.com:2222")
+                .unwrap()
+                .display(),
+            "root@example.com:2222"
+        );
+    }
+}
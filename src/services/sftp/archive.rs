@@ -0,0 +1,576 @@
+// SFTP 远程归档：通过远程 `tar` / `zip` / `unzip` 命令压缩和解压
+// 远程没有 zip/unzip 时，zip 归档经由 SFTP 读写，在本地临时文件中按块处理
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::mpsc;
+use tracing::info;
+
+use super::scp::CHUNK_SIZE;
+use super::SftpService;
+use crate::models::sftp::state::{get_parent_path, join_path};
+use crate::services::remote_exec::shell_quote;
+
+/// 远程命令输出中的条目总数标记
+const TOTAL_MARKER: &str = "__SM_ARCHIVE_TOTAL__";
+/// 远程命令输出中的退出码标记
+const EXIT_MARKER: &str = "__SM_ARCHIVE_EXIT__";
+/// shell 找不到命令时的退出码
+pub const COMMAND_NOT_FOUND: u32 = 127;
+/// SFTP 回退时单个条目解压后的大小上限
+const MAX_ZIP_ENTRY_SIZE: u64 = 2 * 1024 * 1024 * 1024;
+/// SFTP 回退时所有条目解压后的总大小上限
+const MAX_ZIP_TOTAL_SIZE: u64 = 8 * 1024 * 1024 * 1024;
+/// zip 读写线程与 SFTP 之间的通道容量（按块计）
+const ZIP_CHANNEL_CAPACITY: usize = 8;
+
+/// 归档格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    TarGz,
+    TarBz2,
+    TarXz,
+    Tar,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// 根据文件名识别归档格式
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else if name.ends_with(".tar.bz2") || name.ends_with(".tbz2") {
+            Some(Self::TarBz2)
+        } else if name.ends_with(".tar.xz") || name.ends_with(".txz") {
+            Some(Self::TarXz)
+        } else if name.ends_with(".tar") {
+            Some(Self::Tar)
+        } else if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else {
+            None
+        }
+    }
+
+    /// 文件扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::TarGz => ".tar.gz",
+            Self::TarBz2 => ".tar.bz2",
+            Self::TarXz => ".tar.xz",
+            Self::Tar => ".tar",
+            Self::Zip => ".zip",
+        }
+    }
+
+    /// tar 的压缩参数
+    pub(super) fn tar_flag(&self) -> &'static str {
+        match self {
+            Self::TarGz => "z",
+            Self::TarBz2 => "j",
+            Self::TarXz => "J",
+            Self::Tar | Self::Zip => "",
+        }
+    }
+}
+
+/// 归档命令输出的一行
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArchiveLine {
+    /// 条目总数
+    Total(u64),
+    /// 处理了一个条目
+    Entry,
+    /// 命令结束及退出码
+    Exit(u32),
+}
+
+/// 解析归档命令输出的一行
+pub fn parse_archive_line(line: &str) -> Option<ArchiveLine> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    if let Some(rest) = line.strip_prefix(TOTAL_MARKER) {
+        return Some(ArchiveLine::Total(rest.trim().parse().unwrap_or(0)));
+    }
+    if let Some(rest) = line.strip_prefix(EXIT_MARKER) {
+        return Some(ArchiveLine::Exit(rest.trim().parse().unwrap_or(1)));
+    }
+    Some(ArchiveLine::Entry)
+}
+
+/// 以 `-` 开头的文件名加上 `./`，避免被当作命令参数
+fn quote_name(name: &str) -> String {
+    if name.starts_with('-') {
+        shell_quote(&format!("./{}", name))
+    } else {
+        shell_quote(name)
+    }
+}
+
+/// 构建压缩命令：在 `dir` 中把 `names` 打包为 `archive_name`
+pub fn compress_command(
+    dir: &str,
+    names: &[String],
+    archive_name: &str,
+    format: ArchiveFormat,
+) -> String {
+    let names = names
+        .iter()
+        .map(|n| quote_name(n))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let archive = quote_name(archive_name);
+    let body = match format {
+        ArchiveFormat::Zip => format!("zip -r {} {}", archive, names),
+        _ => format!("tar -c{}vf {} {}", format.tar_flag(), archive, names),
+    };
+    format!(
+        "cd {} && echo {} $(find {} 2>/dev/null | wc -l) && {}; echo {} $?",
+        shell_quote(dir),
+        TOTAL_MARKER,
+        names,
+        body,
+        EXIT_MARKER
+    )
+}
+
+/// 构建解压命令：把 `archive_path` 解压到 `dest_dir`
+pub fn extract_command(archive_path: &str, dest_dir: &str, format: ArchiveFormat) -> String {
+    let archive = shell_quote(archive_path);
+    let dest = shell_quote(dest_dir);
+    let (count, body) = match format {
+        ArchiveFormat::Zip => (
+            format!("unzip -Z1 {}", archive),
+            format!("unzip -o {} -d {}", archive, dest),
+        ),
+        _ => (
+            format!("tar -t{}f {}", format.tar_flag(), archive),
+            format!("tar -x{}vf {} -C {}", format.tar_flag(), archive, dest),
+        ),
+    };
+    format!(
+        "echo {} $({} 2>/dev/null | wc -l) && {}; echo {} $?",
+        TOTAL_MARKER, count, body, EXIT_MARKER
+    )
+}
+
+impl SftpService {
+    /// 在目录中生成不与已有文件重名的归档名称（如 `name (1).zip`）
+    pub async fn unique_archive_name(
+        &self,
+        dir: &str,
+        base: &str,
+        format: ArchiveFormat,
+    ) -> String {
+        let mut candidate = format!("{}{}", base, format.extension());
+        let mut n = 1;
+        while self.stat(&join_path(dir, &candidate)).await.is_ok() {
+            candidate = format!("{} ({}){}", base, n, format.extension());
+            n += 1;
+        }
+        candidate
+    }
+
+    /// 经由 SFTP 把远程文件打包为 zip（远程没有 zip 命令时使用）
+    ///
+    /// 文件按块读取后交给后台线程写入本地临时归档，完成后再上传到远程目录
+    pub async fn compress_zip_via_sftp<F>(
+        &self,
+        dir: &str,
+        names: &[String],
+        archive_name: &str,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        info!("[SFTP] Compressing via SFTP: {} in {}", archive_name, dir);
+        let spool = spool_path();
+        let result = self
+            .compress_zip_into(&spool, dir, names, archive_name, progress_callback)
+            .await;
+        let _ = std::fs::remove_file(&spool);
+        result
+    }
+
+    async fn compress_zip_into<F>(
+        &self,
+        spool: &Path,
+        dir: &str,
+        names: &[String],
+        archive_name: &str,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        // 收集所有条目（归档内路径, 远程路径, 是否目录, 权限）
+        let mut entries = Vec::new();
+        for name in names {
+            let root = self.stat(&join_path(dir, name)).await?;
+            entries.push((
+                name.clone(),
+                root.path.clone(),
+                root.is_dir(),
+                root.permissions,
+            ));
+            if root.is_dir() {
+                for entry in self.read_dir_recursive(&root.path).await? {
+                    let relative = entry
+                        .path
+                        .strip_prefix(dir)
+                        .unwrap_or(&entry.path)
+                        .trim_start_matches('/')
+                        .to_string();
+                    if entry.is_dir() || entry.is_file() {
+                        entries.push((relative, entry.path, entry.is_dir(), entry.permissions));
+                    }
+                }
+            }
+        }
+
+        let (tx, rx) = mpsc::channel(ZIP_CHANNEL_CAPACITY);
+        let writer_path = spool.to_path_buf();
+        let writer = tokio::task::spawn_blocking(move || write_zip(&writer_path, rx));
+        let sent = self
+            .send_zip_entries(entries, &tx, &progress_callback)
+            .await;
+        drop(tx);
+        writer
+            .await
+            .map_err(|e| format!("Zip writer failed: {}", e))?
+            .and(sent)?;
+
+        // 上传本地临时归档
+        let path = join_path(dir, archive_name);
+        let mut local = tokio::fs::File::open(spool)
+            .await
+            .map_err(|e| format!("Failed to open temporary archive: {}", e))?;
+        let mut remote = self
+            .sftp()?
+            .create(&path)
+            .await
+            .map_err(|e| format!("Failed to create file {}: {}", path, e))?;
+        tokio::io::copy(&mut local, &mut remote)
+            .await
+            .map_err(|e| format!("Failed to write to file {}: {}", path, e))?;
+        remote
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to flush file {}: {}", path, e))
+    }
+
+    /// 按块读取远程文件并发送给 zip 写入线程；写入线程已退出时直接返回
+    async fn send_zip_entries<F>(
+        &self,
+        entries: Vec<(String, String, bool, u32)>,
+        tx: &mpsc::Sender<ZipChunk>,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        let total = entries.len() as u64;
+        let mut total_size = 0u64;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        for (ix, (name, path, is_dir, mode)) in entries.into_iter().enumerate() {
+            if tx
+                .send(ZipChunk::Start(name.clone(), is_dir, Some(mode)))
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
+            if !is_dir {
+                let mut file = self.open(&path).await?;
+                let mut entry_size = 0u64;
+                loop {
+                    let bytes_read = file
+                        .read(&mut buffer)
+                        .await
+                        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+                    if bytes_read == 0 {
+                        break;
+                    }
+                    entry_size += bytes_read as u64;
+                    total_size += bytes_read as u64;
+                    check_zip_size(&name, entry_size, total_size)?;
+                    if tx
+                        .send(ZipChunk::Data(buffer[..bytes_read].to_vec()))
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                }
+            }
+            progress_callback(ix as u64 + 1, total);
+        }
+        Ok(())
+    }
+
+    /// 经由 SFTP 解压 zip 归档（远程没有 unzip 命令时使用）
+    ///
+    /// 归档先下载到本地临时文件，由后台线程逐个条目按块解压，再写入远程目录
+    pub async fn extract_zip_via_sftp<F>(
+        &self,
+        archive_path: &str,
+        dest_dir: &str,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        info!(
+            "[SFTP] Extracting via SFTP: {} -> {}",
+            archive_path, dest_dir
+        );
+        let spool = spool_path();
+        let result = self
+            .extract_zip_from(&spool, archive_path, dest_dir, progress_callback)
+            .await;
+        let _ = std::fs::remove_file(&spool);
+        result
+    }
+
+    async fn extract_zip_from<F>(
+        &self,
+        spool: &Path,
+        archive_path: &str,
+        dest_dir: &str,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        // 下载归档到本地临时文件
+        let mut remote = self.open(archive_path).await?;
+        let mut local = tokio::fs::File::create(spool)
+            .await
+            .map_err(|e| format!("Failed to create temporary archive: {}", e))?;
+        tokio::io::copy(&mut remote, &mut local)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", archive_path, e))?;
+        local
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to flush temporary archive: {}", e))?;
+        drop(local);
+
+        let file = std::fs::File::open(spool)
+            .map_err(|e| format!("Failed to open temporary archive: {}", e))?;
+        let archive =
+            zip::ZipArchive::new(file).map_err(|e| format!("Invalid zip archive: {}", e))?;
+        let total = archive.len() as u64;
+
+        let (tx, mut rx) = mpsc::channel(ZIP_CHANNEL_CAPACITY);
+        let reader = tokio::task::spawn_blocking(move || read_zip(archive, &tx));
+        let written = self
+            .write_zip_entries(&mut rx, dest_dir, total, &progress_callback)
+            .await;
+        drop(rx);
+        reader
+            .await
+            .map_err(|e| format!("Zip reader failed: {}", e))?
+            .and(written)
+    }
+
+    /// 把 zip 读取线程发来的条目写入远程目录
+    async fn write_zip_entries<F>(
+        &self,
+        rx: &mut mpsc::Receiver<ZipChunk>,
+        dest_dir: &str,
+        total: u64,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64),
+    {
+        let sftp = self.sftp()?;
+        let mut done = 0u64;
+        // 正在写入的文件（远程文件, 路径, 权限）
+        let mut current = None;
+        while let Some(chunk) = rx.recv().await {
+            match chunk {
+                ZipChunk::Start(name, is_dir, mode) => {
+                    if let Some(entry) = current.take() {
+                        self.finish_zip_entry(entry).await?;
+                        done += 1;
+                        progress_callback(done.min(total), total);
+                    }
+                    let path = join_path(dest_dir, &name);
+                    if is_dir {
+                        self.mkdir_recursive(&path).await?;
+                        done += 1;
+                        progress_callback(done.min(total), total);
+                    } else {
+                        self.mkdir_recursive(&get_parent_path(&path)).await?;
+                        let file = sftp
+                            .create(&path)
+                            .await
+                            .map_err(|e| format!("Failed to create file {}: {}", path, e))?;
+                        current = Some((file, path, mode));
+                    }
+                }
+                ZipChunk::Data(data) => {
+                    if let Some((file, path, _)) = current.as_mut() {
+                        file.write_all(&data)
+                            .await
+                            .map_err(|e| format!("Failed to write to file {}: {}", path, e))?;
+                    }
+                }
+            }
+        }
+        if let Some(entry) = current.take() {
+            self.finish_zip_entry(entry).await?;
+            done += 1;
+            progress_callback(done.min(total), total);
+        }
+        Ok(())
+    }
+
+    /// 写完一个解压出的文件并恢复权限（不保留 setuid/setgid/sticky 位）
+    async fn finish_zip_entry(
+        &self,
+        (mut file, path, mode): (russh_sftp::client::fs::File, String, Option<u32>),
+    ) -> Result<(), String> {
+        file.flush()
+            .await
+            .map_err(|e| format!("Failed to flush file {}: {}", path, e))?;
+        drop(file);
+        if let Some(mode) = mode {
+            let _ = self.chmod(&path, mode & 0o777).await;
+        }
+        Ok(())
+    }
+}
+
+/// zip 读写线程与 SFTP 之间传递的数据
+enum ZipChunk {
+    /// 开始一个条目（归档内路径, 是否目录, 权限）
+    Start(String, bool, Option<u32>),
+    /// 当前条目的一段内容
+    Data(Vec<u8>),
+}
+
+/// 本地临时归档路径
+fn spool_path() -> PathBuf {
+    std::env::temp_dir().join(format!("shellmaster-{}.zip", uuid::Uuid::new_v4()))
+}
+
+/// 检查条目大小与累计大小是否超过上限
+fn check_zip_size(name: &str, entry_size: u64, total_size: u64) -> Result<(), String> {
+    if entry_size > MAX_ZIP_ENTRY_SIZE {
+        return Err(format!(
+            "{} is larger than the {} GiB limit for zip entries",
+            name,
+            MAX_ZIP_ENTRY_SIZE >> 30
+        ));
+    }
+    if total_size > MAX_ZIP_TOTAL_SIZE {
+        return Err(format!(
+            "Zip contents are larger than the {} GiB limit",
+            MAX_ZIP_TOTAL_SIZE >> 30
+        ));
+    }
+    Ok(())
+}
+
+/// 在后台线程中把收到的条目写入本地 zip 归档
+fn write_zip(path: &Path, mut rx: mpsc::Receiver<ZipChunk>) -> Result<(), String> {
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Failed to create temporary archive: {}", e))?;
+    let mut writer = zip::ZipWriter::new(file);
+    while let Some(chunk) = rx.blocking_recv() {
+        match chunk {
+            ZipChunk::Start(name, is_dir, mode) => {
+                let options = zip::write::SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Deflated)
+                    .unix_permissions(mode.unwrap_or(0o644) & 0o777);
+                if is_dir {
+                    writer
+                        .add_directory(name, options)
+                        .map_err(|e| format!("Failed to add directory to zip: {}", e))?;
+                } else {
+                    writer
+                        .start_file(name, options)
+                        .map_err(|e| format!("Failed to add file to zip: {}", e))?;
+                }
+            }
+            ZipChunk::Data(data) => writer
+                .write_all(&data)
+                .map_err(|e| format!("Failed to write zip: {}", e))?,
+        }
+    }
+    writer
+        .finish()
+        .map(drop)
+        .map_err(|e| format!("Failed to finish zip: {}", e))
+}
+
+/// 在后台线程中逐个条目按块解压并发送，跳过路径不安全的条目；接收端已退出时直接返回
+fn read_zip(
+    mut archive: zip::ZipArchive<std::fs::File>,
+    tx: &mpsc::Sender<ZipChunk>,
+) -> Result<(), String> {
+    let mut total_size = 0u64;
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    for ix in 0..archive.len() {
+        let mut file = archive
+            .by_index(ix)
+            .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+        let Some(name) = file.enclosed_name() else {
+            continue;
+        };
+        let name = name.to_string_lossy().replace('\\', "/");
+        // 先按声明的大小检查，解压时再按实际读出的字节数检查
+        check_zip_size(&name, file.size(), total_size + file.size())?;
+        let start = ZipChunk::Start(name.clone(), file.is_dir(), file.unix_mode());
+        if tx.blocking_send(start).is_err() {
+            return Ok(());
+        }
+        if file.is_dir() {
+            continue;
+        }
+        let mut entry_size = 0u64;
+        loop {
+            let bytes_read = file
+                .read(&mut buffer)
+                .map_err(|e| format!("Failed to extract {}: {}", name, e))?;
+            if bytes_read == 0 {
+                break;
+            }
+            entry_size += bytes_read as u64;
+            total_size += bytes_read as u64;
+            check_zip_size(&name, entry_size, total_size)?;
+            if tx
+                .blocking_send(ZipChunk::Data(buffer[..bytes_read].to_vec()))
+                .is_err()
+            {
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_zip_size() {
+        assert!(check_zip_size("a.txt", 1024, 1024).is_ok());
+        assert!(check_zip_size("a.txt", MAX_ZIP_ENTRY_SIZE, MAX_ZIP_TOTAL_SIZE).is_ok());
+
+        let err =
+            check_zip_size("big.bin", MAX_ZIP_ENTRY_SIZE + 1, MAX_ZIP_ENTRY_SIZE + 1).unwrap_err();
+        assert!(err.contains("big.bin"));
+        assert!(check_zip_size("a.txt", 1, MAX_ZIP_TOTAL_SIZE + 1).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+// 属性对话框使用的远程查询：按需计算校验和、多个路径的总大小、扩展属性与 ACL
+// 均通过 exec 通道执行 shell 命令，远程缺少对应工具时视为不可用
+
+use tracing::debug;
+
+use super::verify::remote_file_checksum_command;
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::ssh::session::SshSession;
+
+/// 校验和算法
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Md5,
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// 显示名称
+    pub fn label(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "MD5",
+            ChecksumAlgorithm::Sha256 => "SHA-256",
+        }
+    }
+
+    /// 计算单个远程文件校验和的命令（GNU 工具缺失时改用 BSD/macOS 的等价命令）
+    fn command(self, path: &str) -> String {
+        match self {
+            ChecksumAlgorithm::Md5 => {
+                format!("md5sum {0} 2>/dev/null || md5 -r {0}", shell_quote(path))
+            }
+            ChecksumAlgorithm::Sha256 => remote_file_checksum_command(path),
+        }
+    }
+
+    /// 十六进制摘要长度
+    fn hex_len(self) -> usize {
+        match self {
+            ChecksumAlgorithm::Md5 => 32,
+            ChecksumAlgorithm::Sha256 => 64,
+        }
+    }
+}
+
+/// 计算远程文件的校验和，返回小写十六进制摘要
+pub async fn remote_checksum(
+    ssh_session: &SshSession,
+    path: &str,
+    algorithm: ChecksumAlgorithm,
+) -> Result<String, String> {
+    debug!("[SFTP] Computing {} of {}", algorithm.label(), path);
+    let stdout = exec_command(ssh_session, &algorithm.command(path)).await?;
+    let hash = stdout.split_whitespace().next().unwrap_or_default();
+    if hash.len() != algorithm.hex_len() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Unexpected checksum output: {}", stdout.trim()));
+    }
+    Ok(hash.to_ascii_lowercase())
+}
+
+/// 计算多个远程路径的总大小（字节），使用 `du -sbc` 的合计行
+///
+/// 无权限读取的子项会被忽略：du 仍输出合计并以非 0 退出，管道的退出码取自 `tail`
+pub async fn remote_total_size(ssh_session: &SshSession, paths: &[String]) -> Result<u64, String> {
+    let quoted: Vec<String> = paths.iter().map(|p| shell_quote(p)).collect();
+    let command = format!("du -sbc -- {} 2>/dev/null | tail -n 1", quoted.join(" "));
+    let stdout = exec_command(ssh_session, &command).await?;
+    stdout
+        .split_whitespace()
+        .next()
+        .and_then(|size| size.parse::<u64>().ok())
+        .ok_or_else(|| format!("Failed to parse du output: {}", stdout.trim()))
+}
+
+/// 读取扩展属性（`getfattr`）与访问控制列表（`getfacl`），返回 (扩展属性, ACL 条目)
+///
+/// 只保留超出基本权限位的 ACL 条目（具名用户/组、mask、默认 ACL），工具不存在时返回空列表
+pub async fn remote_extended_attributes(
+    ssh_session: &SshSession,
+    path: &str,
+) -> (Vec<String>, Vec<String>) {
+    let quoted = shell_quote(path);
+    let xattrs = exec_command(
+        ssh_session,
+        &format!(
+            "getfattr -d -m - --absolute-names -- {} 2>/dev/null",
+            quoted
+        ),
+    )
+    .await
+    .map(|out| parse_xattr_output(&out))
+    .unwrap_or_default();
+    let acl = exec_command(
+        ssh_session,
+        &format!("getfacl -cp -- {} 2>/dev/null", quoted),
+    )
+    .await
+    .map(|out| parse_acl_output(&out))
+    .unwrap_or_default();
+    (xattrs, acl)
+}
+
+/// 解析 getfattr 输出，跳过注释行（`# file: ...`）与空行
+fn parse_xattr_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// 解析 getfacl 输出，去掉与权限位重复的 `user::`/`group::`/`other::` 条目
+fn parse_acl_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| {
+            let mut fields = line.split(':');
+            let tag = fields.next().unwrap_or_default();
+            !(matches!(tag, "user" | "group" | "other") && fields.next() == Some(""))
+        })
+        .map(str::to_string)
+        .collect()
+}
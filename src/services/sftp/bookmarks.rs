@@ -0,0 +1,63 @@
+// SFTP 收藏夹存储：收藏目录、最近目录和最近文件写入磁盘，并在内存中缓存供工具栏读取
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tracing::error;
+
+use crate::models::sftp::{ServerBookmarks, SftpBookmarks};
+use crate::services::storage;
+
+/// SFTP 收藏夹存储
+pub struct SftpBookmarkStore {
+    /// 首次访问时从磁盘加载
+    cache: Mutex<Option<SftpBookmarks>>,
+}
+
+impl SftpBookmarkStore {
+    /// 获取全局单例
+    pub fn global() -> &'static SftpBookmarkStore {
+        static STORE: Lazy<SftpBookmarkStore> = Lazy::new(|| SftpBookmarkStore {
+            cache: Mutex::new(None),
+        });
+        &STORE
+    }
+
+    /// 在指定服务器的收藏夹上执行操作，`f` 返回的标志为 true 时写回磁盘
+    fn with_server<T>(
+        &self,
+        server_id: &str,
+        f: impl FnOnce(&mut ServerBookmarks) -> (T, bool),
+    ) -> T {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let bookmarks =
+            cache.get_or_insert_with(|| storage::load_sftp_bookmarks().unwrap_or_default());
+        let (result, changed) = f(bookmarks.servers.entry(server_id.to_string()).or_default());
+        if changed {
+            if let Err(e) = storage::save_sftp_bookmarks(bookmarks) {
+                error!("[SFTP] Failed to save bookmarks: {}", e);
+            }
+        }
+        result
+    }
+
+    /// 获取服务器的收藏目录、最近目录和最近文件
+    pub fn server(&self, server_id: &str) -> ServerBookmarks {
+        self.with_server(server_id, |server| (server.clone(), false))
+    }
+
+    /// 收藏或取消收藏目录，返回操作后是否处于收藏状态
+    pub fn toggle_bookmark(&self, server_id: &str, path: &str) -> bool {
+        self.with_server(server_id, |server| (server.toggle_bookmark(path), true))
+    }
+
+    /// 记录最近访问的目录
+    pub fn record_recent(&self, server_id: &str, path: &str) {
+        self.with_server(server_id, |server| ((), server.record_recent(path)));
+    }
+
+    /// 记录最近编辑的文件
+    pub fn record_recent_file(&self, server_id: &str, path: &str) {
+        self.with_server(server_id, |server| ((), server.record_recent_file(path)));
+    }
+}
@@ -0,0 +1,194 @@
+// SFTP 元数据通道池
+// 目录读取与 stat 请求在主通道和若干额外通道间轮转，使文件夹树展开、大小统计等可以并行加载；
+// 额外通道在后台打开，服务器限制会话数（如 MaxSessions）时只使用已打开的通道，最少退化为仅主通道；
+// 另有一个底层列表通道用于分批读取巨型目录（见 dir_stream）
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+use futures::StreamExt;
+use russh_sftp::client::{RawSftpSession, SftpSession};
+use tracing::{info, warn};
+
+use super::recovery::is_channel_closed;
+use super::service::SftpService;
+use crate::models::sftp::FileEntry;
+use crate::ssh::session::SshSession;
+
+/// 除主通道外额外打开的元数据通道数
+const EXTRA_METADATA_CHANNELS: usize = 3;
+/// 并行读取目录时同时进行的请求数
+const PARALLEL_READ_DIRS: usize = 8;
+
+/// 额外的元数据通道
+#[derive(Default)]
+pub(super) struct ChannelPool {
+    channels: RwLock<Vec<Arc<SftpSession>>>,
+    next: AtomicUsize,
+    /// 分批读取目录使用的底层通道
+    listing: RwLock<Option<Arc<RawSftpSession>>>,
+}
+
+impl ChannelPool {
+    /// 轮转选择下一个通道（包含主通道）
+    fn pick(&self, main: &Arc<SftpSession>) -> Arc<SftpSession> {
+        let Ok(channels) = self.channels.read() else {
+            return main.clone();
+        };
+        match self.next.fetch_add(1, Ordering::Relaxed) % (channels.len() + 1) {
+            0 => main.clone(),
+            ix => channels[ix - 1].clone(),
+        }
+    }
+
+    /// 加入新打开的通道
+    fn push(&self, sftp: Arc<SftpSession>) {
+        if let Ok(mut channels) = self.channels.write() {
+            channels.push(sftp);
+        }
+    }
+
+    /// 移除已失效的通道
+    fn discard(&self, sftp: &Arc<SftpSession>) {
+        if let Ok(mut channels) = self.channels.write() {
+            channels.retain(|c| !Arc::ptr_eq(c, sftp));
+        }
+    }
+
+    /// 当前额外通道数
+    fn len(&self) -> usize {
+        self.channels.read().map(|c| c.len()).unwrap_or(0)
+    }
+
+    /// 获取列表通道（尚未打开或已失效时为 None）
+    pub(super) fn listing_channel(&self) -> Option<Arc<RawSftpSession>> {
+        self.listing.read().ok().and_then(|l| l.clone())
+    }
+
+    /// 设置或移除列表通道
+    pub(super) fn set_listing_channel(&self, raw: Option<Arc<RawSftpSession>>) {
+        if let Ok(mut listing) = self.listing.write() {
+            *listing = raw;
+        }
+    }
+}
+
+/// 打开一个 SFTP 子系统通道
+pub(super) async fn open_sftp_channel(ssh_session: &SshSession) -> Result<SftpSession, String> {
+    let channel = open_subsystem_channel(ssh_session).await?;
+    SftpSession::new(channel.into_stream())
+        .await
+        .map_err(|e| format!("Failed to create SFTP session: {}", e))
+}
+
+/// 打开一个底层 SFTP 通道（可逐个发送 READDIR 请求）
+async fn open_raw_sftp_channel(ssh_session: &SshSession) -> Result<RawSftpSession, String> {
+    let channel = open_subsystem_channel(ssh_session).await?;
+    let raw = RawSftpSession::new(channel.into_stream());
+    raw.init()
+        .await
+        .map_err(|e| format!("Failed to init SFTP session: {}", e))?;
+    Ok(raw)
+}
+
+/// 打开会话通道并请求 sftp 子系统
+async fn open_subsystem_channel(
+    ssh_session: &SshSession,
+) -> Result<russh::Channel<russh::client::Msg>, String> {
+    let channel = ssh_session
+        .handle()
+        .channel_open_session()
+        .await
+        .map_err(|e| format!("Failed to open channel: {}", e))?;
+
+    channel
+        .request_subsystem(true, "sftp")
+        .await
+        .map_err(|e| format!("Failed to request sftp subsystem: {}", e))?;
+    Ok(channel)
+}
+
+impl SftpService {
+    /// 在后台打开列表通道与额外的元数据通道（仅用于标签页的主 SFTP 服务）
+    ///
+    /// 打开失败时停止继续尝试，已打开的通道照常使用
+    pub fn start_metadata_pool(&self, ssh_session: Arc<SshSession>) {
+        if self.is_scp_fallback() {
+            return;
+        }
+        let pool = self.pool();
+        let session_id = self.session_id().to_string();
+        tokio::spawn(async move {
+            match open_raw_sftp_channel(&ssh_session).await {
+                Ok(raw) => pool.set_listing_channel(Some(Arc::new(raw))),
+                Err(e) => {
+                    warn!(
+                        "[SFTP] Listing channel unavailable for {}, using main channel: {}",
+                        session_id, e
+                    );
+                    return;
+                }
+            }
+            for _ in 0..EXTRA_METADATA_CHANNELS {
+                match open_sftp_channel(&ssh_session).await {
+                    Ok(sftp) => pool.push(Arc::new(sftp)),
+                    Err(e) => {
+                        warn!(
+                            "[SFTP] Metadata channel pool for {} limited to {} extra channel(s): {}",
+                            session_id,
+                            pool.len(),
+                            e
+                        );
+                        return;
+                    }
+                }
+            }
+            info!(
+                "[SFTP] Metadata channel pool ready for {} ({} extra channels)",
+                session_id,
+                pool.len()
+            );
+        });
+    }
+
+    /// 在元数据通道上执行请求；额外通道失效时将其移出通道池并改用主通道 `main`
+    pub(super) async fn with_metadata_channel<T, E, F, Fut>(
+        &self,
+        main: Arc<SftpSession>,
+        op: F,
+    ) -> Result<T, E>
+    where
+        F: Fn(Arc<SftpSession>) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let sftp = self.pool().pick(&main);
+        if Arc::ptr_eq(&sftp, &main) {
+            return op(sftp).await;
+        }
+        match op(sftp.clone()).await {
+            Err(e) if is_channel_closed(&e.to_string()) => {
+                warn!("[SFTP] Metadata channel closed ({}), falling back", e);
+                self.pool().discard(&sftp);
+                op(main).await
+            }
+            result => result,
+        }
+    }
+
+    /// 并行读取多个目录，结果顺序与输入不保证一致
+    pub async fn read_dirs(
+        &self,
+        paths: Vec<String>,
+    ) -> Vec<(String, Result<Vec<FileEntry>, String>)> {
+        futures::stream::iter(paths)
+            .map(|path| async move {
+                let result = self.read_dir(&path).await;
+                (path, result)
+            })
+            .buffer_unordered(PARALLEL_READ_DIRS)
+            .collect()
+            .await
+    }
+}
@@ -0,0 +1,328 @@
+// 文件/目录对比
+// 文本差异基于 similar 按行计算；目录差异按相对路径比较两侧的条目类型与大小
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use similar::{ChangeTag, TextDiff};
+use tokio_util::sync::CancellationToken;
+
+use super::preview::{decode_text, looks_binary};
+use super::SftpService;
+use crate::models::sftp::state::join_path;
+
+/// 参与文本对比的文件最大字节数
+pub const MAX_COMPARE_FILE_BYTES: usize = 2 * 1024 * 1024;
+/// 文本差异中每处修改前后保留的上下文行数
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// 对比对象所在位置
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompareLocation {
+    /// 本机文件系统
+    Local,
+    /// 某个会话标签页的远程服务器
+    Remote {
+        tab_id: String,
+        server_label: String,
+    },
+}
+
+/// 参与对比的文件或目录
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompareTarget {
+    pub location: CompareLocation,
+    pub path: String,
+    pub is_dir: bool,
+}
+
+impl CompareTarget {
+    /// 目录下相对路径对应的对比对象
+    pub fn child(&self, relative_path: &str, is_dir: bool) -> CompareTarget {
+        let path = match self.location {
+            CompareLocation::Local => relative_path
+                .split('/')
+                .fold(Path::new(&self.path).to_path_buf(), |path, part| {
+                    path.join(part)
+                })
+                .to_string_lossy()
+                .to_string(),
+            CompareLocation::Remote { .. } => join_path(&self.path, relative_path),
+        };
+        CompareTarget {
+            location: self.location.clone(),
+            path,
+            is_dir,
+        }
+    }
+}
+
+/// 通过"选择用于比较"选中的对象，在所有标签页与本地面板间共享
+static COMPARE_BASE: Lazy<Mutex<Option<CompareTarget>>> = Lazy::new(|| Mutex::new(None));
+
+/// 设置选中用于比较的对象
+pub fn set_compare_base(target: Option<CompareTarget>) {
+    if let Ok(mut base) = COMPARE_BASE.lock() {
+        *base = target;
+    }
+}
+
+/// 当前选中用于比较的对象
+pub fn compare_base() -> Option<CompareTarget> {
+    COMPARE_BASE.lock().ok().and_then(|base| base.clone())
+}
+
+/// 差异行的类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Equal,
+    Added,
+    Removed,
+    /// 两处修改之间省略的未变更内容
+    Gap,
+}
+
+/// 文本差异中的一行
+#[derive(Clone, Debug)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// 左侧行号（从 1 开始）
+    pub old_line: Option<usize>,
+    /// 右侧行号（从 1 开始）
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+/// 两个文件的对比结果
+#[derive(Clone, Debug)]
+pub enum FileDiff {
+    /// 文本差异；内容相同时 `lines` 为空
+    Text {
+        lines: Vec<DiffLine>,
+        added: usize,
+        removed: usize,
+    },
+    /// 二进制文件，只比较是否相同
+    Binary { identical: bool },
+    /// 文件过大，未做对比
+    TooLarge,
+}
+
+/// 对比两个文件的内容（传入的字节最多读取 `MAX_COMPARE_FILE_BYTES + 1`）
+pub fn diff_files(old: &[u8], new: &[u8]) -> FileDiff {
+    if old.len() > MAX_COMPARE_FILE_BYTES || new.len() > MAX_COMPARE_FILE_BYTES {
+        return FileDiff::TooLarge;
+    }
+    if looks_binary(old) || looks_binary(new) {
+        return FileDiff::Binary {
+            identical: old == new,
+        };
+    }
+
+    let (old, _) = decode_text(old);
+    let (new, _) = decode_text(new);
+    let mut lines = Vec::new();
+    let (mut added, mut removed) = (0, 0);
+    if old != new {
+        let diff = TextDiff::from_lines(&old, &new);
+        for (ix, group) in diff.grouped_ops(DIFF_CONTEXT_LINES).iter().enumerate() {
+            if ix > 0 {
+                lines.push(DiffLine {
+                    kind: DiffLineKind::Gap,
+                    old_line: None,
+                    new_line: None,
+                    text: String::new(),
+                });
+            }
+            for op in group {
+                for change in diff.iter_changes(op) {
+                    let kind = match change.tag() {
+                        ChangeTag::Equal => DiffLineKind::Equal,
+                        ChangeTag::Insert => {
+                            added += 1;
+                            DiffLineKind::Added
+                        }
+                        ChangeTag::Delete => {
+                            removed += 1;
+                            DiffLineKind::Removed
+                        }
+                    };
+                    lines.push(DiffLine {
+                        kind,
+                        old_line: change.old_index().map(|i| i + 1),
+                        new_line: change.new_index().map(|i| i + 1),
+                        text: change
+                            .value()
+                            .trim_end_matches(|c| c == '\n' || c == '\r')
+                            .to_string(),
+                    });
+                }
+            }
+        }
+    }
+    FileDiff::Text {
+        lines,
+        added,
+        removed,
+    }
+}
+
+/// 目录树中的一项（以相对路径为键）
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeItem {
+    pub is_dir: bool,
+    pub size: u64,
+}
+
+/// 目录树：相对路径（以 `/` 分隔） -> 条目
+pub type TreeMap = BTreeMap<String, TreeItem>;
+
+/// 目录对比中一项的状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirDiffStatus {
+    /// 两侧相同
+    Same,
+    /// 仅右侧存在
+    Added,
+    /// 仅左侧存在
+    Removed,
+    /// 两侧都存在但类型或大小不同（目录：其下有差异）
+    Modified,
+}
+
+/// 目录对比中的一项
+#[derive(Clone, Debug)]
+pub struct DirDiffEntry {
+    pub relative_path: String,
+    pub name: String,
+    /// 层级深度（根目录下的直接子项为 0）
+    pub depth: usize,
+    pub is_dir: bool,
+    pub status: DirDiffStatus,
+    /// 两侧都是普通文件，可以进一步对比内容
+    pub comparable: bool,
+}
+
+/// 按相对路径对比两棵目录树，结果按树形顺序排列
+pub fn diff_trees(left: &TreeMap, right: &TreeMap) -> Vec<DirDiffEntry> {
+    let mut paths: Vec<&String> = left.keys().chain(right.keys()).collect();
+    paths.sort_by(|a, b| a.split('/').cmp(b.split('/')));
+    paths.dedup();
+
+    let mut entries: Vec<DirDiffEntry> = paths
+        .into_iter()
+        .map(|path| {
+            let (l, r) = (left.get(path), right.get(path));
+            let status = match (l, r) {
+                (Some(_), None) => DirDiffStatus::Removed,
+                (None, Some(_)) => DirDiffStatus::Added,
+                (Some(l), Some(r)) if l.is_dir != r.is_dir => DirDiffStatus::Modified,
+                (Some(l), Some(r)) if !l.is_dir && l.size != r.size => DirDiffStatus::Modified,
+                _ => DirDiffStatus::Same,
+            };
+            let is_dir = l.or(r).is_some_and(|item| item.is_dir);
+            DirDiffEntry {
+                name: path.rsplit('/').next().unwrap_or(path).to_string(),
+                depth: path.matches('/').count(),
+                relative_path: path.clone(),
+                is_dir,
+                status,
+                comparable: matches!((l, r), (Some(l), Some(r)) if !l.is_dir && !r.is_dir),
+            }
+        })
+        .collect();
+
+    // 子项有差异的目录标记为已修改
+    let changed: Vec<String> = entries
+        .iter()
+        .filter(|e| e.status != DirDiffStatus::Same)
+        .map(|e| e.relative_path.clone())
+        .collect();
+    for entry in entries
+        .iter_mut()
+        .filter(|e| e.is_dir && e.status == DirDiffStatus::Same)
+    {
+        let prefix = format!("{}/", entry.relative_path);
+        if changed.iter().any(|path| path.starts_with(&prefix)) {
+            entry.status = DirDiffStatus::Modified;
+        }
+    }
+    entries
+}
+
+/// 递归收集本地目录树
+pub fn collect_local_tree(root: &Path) -> std::io::Result<TreeMap> {
+    let mut tree = TreeMap::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = dirs.pop() {
+        for item in std::fs::read_dir(&dir)? {
+            let item = item?;
+            let relative = format!("{}{}", prefix, item.file_name().to_string_lossy());
+            let Ok(metadata) = item.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                dirs.push((item.path(), format!("{}/", relative)));
+            }
+            tree.insert(
+                relative,
+                TreeItem {
+                    is_dir: metadata.is_dir(),
+                    size: metadata.len(),
+                },
+            );
+        }
+    }
+    Ok(tree)
+}
+
+/// 读取本地文件用于对比（最多 `MAX_COMPARE_FILE_BYTES + 1` 字节）
+pub fn read_local_for_compare(path: &Path) -> std::io::Result<Vec<u8>> {
+    use std::io::Read;
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)?
+        .take(MAX_COMPARE_FILE_BYTES as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+impl SftpService {
+    /// 递归收集远程目录树（不跟随符号链接）
+    pub async fn collect_compare_tree(
+        &self,
+        root: &str,
+        cancel_token: &CancellationToken,
+    ) -> Result<TreeMap, String> {
+        let prefix = format!("{}/", root.trim_end_matches('/'));
+        let mut tree = TreeMap::new();
+        let mut dirs = vec![root.to_string()];
+        while let Some(dir) = dirs.pop() {
+            if cancel_token.is_cancelled() {
+                return Err("Compare cancelled".to_string());
+            }
+            for entry in self.read_dir(&dir).await? {
+                let Some(relative) = entry.path.strip_prefix(&prefix) else {
+                    continue;
+                };
+                if entry.is_dir() {
+                    dirs.push(entry.path.clone());
+                }
+                tree.insert(
+                    relative.to_string(),
+                    TreeItem {
+                        is_dir: entry.is_dir(),
+                        size: entry.size,
+                    },
+                );
+            }
+        }
+        Ok(tree)
+    }
+
+    /// 读取远程文件用于对比（最多 `MAX_COMPARE_FILE_BYTES + 1` 字节）
+    pub async fn read_for_compare(&self, path: &str) -> Result<Vec<u8>, String> {
+        self.read_head(path, MAX_COMPARE_FILE_BYTES + 1).await
+    }
+}
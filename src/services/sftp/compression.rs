@@ -0,0 +1,309 @@
+// 传输压缩与下载后自动解压
+// 压缩传输类似 HTTP 的 content-encoding：通过 exec 通道在远程运行 gzip，数据以 gzip 流的形式经过网络，
+// 本地边收边解压（下载）或边读边压缩（上传），落盘的文件与原文件一致。不支持断点续传
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use flate2::write::{GzDecoder, GzEncoder};
+use flate2::Compression;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+use super::archive::ArchiveFormat;
+use super::conflict::unique_local_path;
+use super::resume::{finish_local_part, local_part_path, remote_part_path};
+use super::scp::{SpeedMeter, CHUNK_SIZE};
+use super::service::{cancelled_error, SftpService};
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::services::transfer::Throttle;
+use crate::ssh::session::SshSession;
+
+/// 远程是否可以使用 gzip
+pub async fn gzip_available(ssh_session: &SshSession) -> bool {
+    exec_command(ssh_session, "command -v gzip >/dev/null 2>&1")
+        .await
+        .is_ok()
+}
+
+/// 打开执行命令的通道，返回读写数据流（标准输出 / 标准输入）
+async fn open_command_stream(
+    ssh_session: &SshSession,
+    command: &str,
+) -> Result<impl AsyncRead + AsyncWrite + Unpin + Send, String> {
+    let channel = ssh_session
+        .handle()
+        .channel_open_session()
+        .await
+        .map_err(|e| format!("Failed to open channel: {}", e))?;
+    channel
+        .exec(true, command)
+        .await
+        .map_err(|e| format!("Failed to start gzip: {}", e))?;
+    Ok(channel.into_stream())
+}
+
+/// 压缩下载：远程 `gzip -c` 输出到通道，本地解压后写入部分文件，完成后替换目标文件
+///
+/// 解压后的大小与 `total_size` 不一致时视为失败（远程 gzip 出错时输出为空）
+pub async fn download_file_gzip<F>(
+    ssh_session: &SshSession,
+    remote_path: &str,
+    local_path: &Path,
+    total_size: u64,
+    cancel_token: &CancellationToken,
+    throttle: &Throttle,
+    progress_callback: F,
+) -> Result<(), String>
+where
+    F: Fn(u64, u64, u64),
+{
+    info!(
+        "[SFTP] Downloading with gzip: {} -> {:?}",
+        remote_path, local_path
+    );
+    let mut stream = open_command_stream(
+        ssh_session,
+        &format!("gzip -c -- {}", shell_quote(remote_path)),
+    )
+    .await?;
+
+    let part_path = local_part_path(local_path);
+    let mut local_file = tokio::fs::File::create(&part_path)
+        .await
+        .map_err(|e| format!("Failed to create local file: {}", e))?;
+    progress_callback(0, total_size, 0);
+
+    let result = async {
+        let mut decoder = GzDecoder::new(Vec::new());
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut received = 0u64;
+        let mut written = 0u64;
+        let mut meter = SpeedMeter::new();
+        loop {
+            if cancel_token.is_cancelled() {
+                return Err(cancelled_error(false));
+            }
+            let bytes_read = stream
+                .read(&mut buffer)
+                .await
+                .map_err(|e| format!("Failed to read from remote file: {}", e))?;
+            if bytes_read == 0 {
+                break;
+            }
+            received += bytes_read as u64;
+            throttle.consume(bytes_read as u64).await;
+            decoder
+                .write_all(&buffer[..bytes_read])
+                .map_err(|e| format!("Invalid gzip stream: {}", e))?;
+            let output = std::mem::take(decoder.get_mut());
+            local_file
+                .write_all(&output)
+                .await
+                .map_err(|e| format!("Failed to write to local file: {}", e))?;
+            written += output.len() as u64;
+            progress_callback(written, total_size, meter.update(written));
+        }
+
+        let output = decoder
+            .finish()
+            .map_err(|e| format!("Invalid gzip stream: {}", e))?;
+        local_file
+            .write_all(&output)
+            .await
+            .map_err(|e| format!("Failed to write to local file: {}", e))?;
+        written += output.len() as u64;
+        local_file
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to flush local file: {}", e))?;
+
+        if written != total_size {
+            return Err(format!(
+                "Size mismatch after decompression: expected {}, got {}",
+                total_size, written
+            ));
+        }
+        debug!(
+            "[SFTP] gzip download completed: {} ({} bytes over the wire, {} bytes on disk)",
+            remote_path, received, written
+        );
+        Ok(())
+    }
+    .await;
+
+    drop(local_file);
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(e);
+    }
+    finish_local_part(&part_path, local_path).await
+}
+
+impl SftpService {
+    /// 压缩上传：本地边读边压缩写入通道，远程 `gzip -dc` 解压到部分文件，完成后替换目标文件
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upload_file_gzip<F>(
+        &self,
+        ssh_session: &SshSession,
+        local_path: &Path,
+        remote_path: &str,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64),
+    {
+        info!(
+            "[SFTP] Uploading with gzip: {:?} -> {}",
+            local_path, remote_path
+        );
+        let mut local_file = tokio::fs::File::open(local_path)
+            .await
+            .map_err(|e| format!("Failed to open local file: {}", e))?;
+        let total_size = local_file
+            .metadata()
+            .await
+            .map_err(|e| format!("Failed to get local file metadata: {}", e))?
+            .len();
+
+        let part_path = remote_part_path(remote_path);
+        let mut stream = open_command_stream(
+            ssh_session,
+            &format!("gzip -dc > {}", shell_quote(&part_path)),
+        )
+        .await?;
+        progress_callback(0, total_size, 0);
+
+        let result = async {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+            let mut buffer = vec![0u8; CHUNK_SIZE];
+            let mut transferred = 0u64;
+            let mut meter = SpeedMeter::new();
+            while transferred < total_size {
+                if cancel_token.is_cancelled() {
+                    return Err(cancelled_error(true));
+                }
+                let bytes_read = local_file
+                    .read(&mut buffer)
+                    .await
+                    .map_err(|e| format!("Failed to read from local file: {}", e))?;
+                if bytes_read == 0 {
+                    return Err("Local file changed during upload".to_string());
+                }
+                encoder
+                    .write_all(&buffer[..bytes_read])
+                    .map_err(|e| format!("Failed to compress: {}", e))?;
+                let output = std::mem::take(encoder.get_mut());
+                throttle.consume(output.len() as u64).await;
+                stream
+                    .write_all(&output)
+                    .await
+                    .map_err(|e| format!("Failed to write to remote file: {}", e))?;
+                transferred += bytes_read as u64;
+                progress_callback(transferred, total_size, meter.update(transferred));
+            }
+
+            let output = encoder
+                .finish()
+                .map_err(|e| format!("Failed to compress: {}", e))?;
+            stream
+                .write_all(&output)
+                .await
+                .map_err(|e| format!("Failed to write to remote file: {}", e))?;
+            // 发送 EOF 后等待远程 gzip 退出（通道关闭）
+            stream
+                .shutdown()
+                .await
+                .map_err(|e| format!("Failed to finish upload: {}", e))?;
+            let mut rest = Vec::new();
+            let _ = stream.read_to_end(&mut rest).await;
+
+            let written = self.stat(&part_path).await?.size;
+            if written != total_size {
+                return Err(format!(
+                    "Size mismatch after decompression: expected {}, got {}",
+                    total_size, written
+                ));
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            let _ = self.remove_file(&part_path).await;
+            return Err(e);
+        }
+        self.finish_remote_part(&part_path, remote_path).await?;
+        debug!("[SFTP] gzip upload completed: {:?}", local_path);
+        Ok(())
+    }
+}
+
+/// 去掉归档扩展名后的名称（如 `site.tar.gz` -> `site`）
+fn archive_stem(name: &str) -> &str {
+    const SUFFIXES: [&str; 8] = [
+        ".tar.gz", ".tgz", ".tar.bz2", ".tbz2", ".tar.xz", ".txz", ".tar", ".zip",
+    ];
+    let lower = name.to_lowercase();
+    SUFFIXES
+        .iter()
+        .find(|suffix| lower.ends_with(*suffix))
+        .map(|suffix| &name[..name.len() - suffix.len()])
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or(name)
+}
+
+/// 把下载的归档解压到同目录下的同名文件夹（已存在时加序号），返回解压目录
+///
+/// zip 在本地解压，tar 系列调用本地 `tar` 命令
+pub async fn extract_local_archive(archive_path: &Path) -> Result<PathBuf, String> {
+    let name = archive_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let format =
+        ArchiveFormat::from_name(&name).ok_or_else(|| format!("Not an archive: {}", name))?;
+    let dest = archive_path.with_file_name(archive_stem(&name));
+    let dest = if dest.exists() {
+        unique_local_path(&dest)
+    } else {
+        dest
+    };
+    info!("[SFTP] Extracting {:?} to {:?}", archive_path, dest);
+
+    let archive = archive_path.to_path_buf();
+    let dest_for_task = dest.clone();
+    tokio::task::spawn_blocking(move || -> Result<(), String> {
+        std::fs::create_dir_all(&dest_for_task)
+            .map_err(|e| format!("Failed to create {:?}: {}", dest_for_task, e))?;
+        match format {
+            ArchiveFormat::Zip => {
+                let file = std::fs::File::open(&archive)
+                    .map_err(|e| format!("Failed to open {:?}: {}", archive, e))?;
+                zip::ZipArchive::new(file)
+                    .and_then(|mut zip| zip.extract(&dest_for_task))
+                    .map_err(|e| format!("Failed to extract zip: {}", e))
+            }
+            _ => {
+                let output = std::process::Command::new("tar")
+                    .arg(format!("-x{}f", format.tar_flag()))
+                    .arg(&archive)
+                    .arg("-C")
+                    .arg(&dest_for_task)
+                    .output()
+                    .map_err(|e| format!("Failed to run tar: {}", e))?;
+                if output.status.success() {
+                    Ok(())
+                } else {
+                    Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    Ok(dest)
+}
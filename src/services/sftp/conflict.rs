@@ -0,0 +1,54 @@
+// SFTP 传输冲突处理：目标已存在时的改名与新旧比较
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::SftpService;
+use crate::models::sftp::state::{get_parent_path, join_path};
+
+/// 在文件名的扩展名前插入序号，如 `a.txt` -> `a (1).txt`
+pub fn numbered_name(name: &str, n: u32) -> String {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => format!("{} ({}){}", &name[..dot], n, &name[dot..]),
+        _ => format!("{} ({})", name, n),
+    }
+}
+
+/// 源文件是否比目标文件新（任一时间未知时视为不新）
+pub fn is_source_newer(source: Option<SystemTime>, target: Option<SystemTime>) -> bool {
+    match (source, target) {
+        (Some(source), Some(target)) => source > target,
+        _ => false,
+    }
+}
+
+/// 生成不与本地已有文件重名的路径
+pub fn unique_local_path(path: &Path) -> PathBuf {
+    let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+        return path.to_path_buf();
+    };
+    let mut n = 1;
+    loop {
+        let candidate = path.with_file_name(numbered_name(&name, n));
+        if !candidate.exists() {
+            return candidate;
+        }
+        n += 1;
+    }
+}
+
+impl SftpService {
+    /// 生成不与远程已有文件重名的路径
+    pub async fn unique_remote_path(&self, path: &str) -> String {
+        let dir = get_parent_path(path);
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let mut n = 1;
+        loop {
+            let candidate = join_path(&dir, &numbered_name(name, n));
+            if self.stat(&candidate).await.is_err() {
+                return candidate;
+            }
+            n += 1;
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// SFTP 目录分批读取
+// 巨型目录按 READDIR 响应分批交给调用方，UI 可以边读取边显示，而不是等全部条目收集完毕
+
+use std::time::{Duration, Instant};
+
+use russh_sftp::client::error::Error as SftpError;
+use russh_sftp::client::RawSftpSession;
+use russh_sftp::protocol::StatusCode;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, warn};
+
+use super::recovery::is_channel_closed;
+use super::service::{file_entry_from_attrs, SftpService};
+use crate::models::sftp::{FileEntry, FileType};
+
+/// 单批最多条目数
+const BATCH_MAX_ENTRIES: usize = 2000;
+/// 未满一批时最长的交出间隔
+const BATCH_INTERVAL: Duration = Duration::from_millis(200);
+
+impl SftpService {
+    /// 分批读取目录，每批条目通过 `on_batch` 交出，返回完整的条目列表
+    ///
+    /// 列表通道不可用时退化为一次性读取（整个目录作为一批）；取消后返回错误
+    pub async fn read_dir_streaming<F>(
+        &self,
+        path: &str,
+        cancel_token: &CancellationToken,
+        on_batch: &mut F,
+    ) -> Result<Vec<FileEntry>, String>
+    where
+        F: FnMut(Vec<FileEntry>),
+    {
+        // SCP 回退模式下没有列表通道，同样一次性读取
+        let Some(raw) = self.pool().listing_channel() else {
+            return self.read_dir_single_batch(path, on_batch).await;
+        };
+
+        let handle = match raw.opendir(path).await {
+            Ok(handle) => handle.handle,
+            Err(e) if is_channel_closed(&e.to_string()) => {
+                warn!("[SFTP] Listing channel closed ({}), using main channel", e);
+                self.pool().set_listing_channel(None);
+                return self.read_dir_single_batch(path, on_batch).await;
+            }
+            Err(e) => return Err(format!("Failed to read directory {}: {}", path, e)),
+        };
+
+        let result = self
+            .read_dir_handle(&raw, path, &handle, cancel_token, on_batch)
+            .await;
+        let _ = raw.close(handle).await;
+        result
+    }
+
+    /// 一次性读取目录并作为一批交出
+    async fn read_dir_single_batch<F>(
+        &self,
+        path: &str,
+        on_batch: &mut F,
+    ) -> Result<Vec<FileEntry>, String>
+    where
+        F: FnMut(Vec<FileEntry>),
+    {
+        let entries = self.read_dir(path).await?;
+        on_batch(entries.clone());
+        Ok(entries)
+    }
+
+    /// 在已打开的目录句柄上循环读取，直到 EOF
+    async fn read_dir_handle<F>(
+        &self,
+        raw: &RawSftpSession,
+        path: &str,
+        handle: &str,
+        cancel_token: &CancellationToken,
+        on_batch: &mut F,
+    ) -> Result<Vec<FileEntry>, String>
+    where
+        F: FnMut(Vec<FileEntry>),
+    {
+        let main = self.sftp()?;
+        let mut entries = Vec::new();
+        let mut pending = Vec::new();
+        let mut last_batch = Instant::now();
+
+        loop {
+            if cancel_token.is_cancelled() {
+                return Err("Listing cancelled".to_string());
+            }
+            let name = match raw.readdir(handle).await {
+                Ok(name) => name,
+                Err(SftpError::Status(status)) if matches!(status.status_code, StatusCode::Eof) => {
+                    break
+                }
+                Err(e) => return Err(format!("Failed to read directory {}: {}", path, e)),
+            };
+
+            for file in name.files {
+                // 跳过 . 和 ..
+                if file.filename == "." || file.filename == ".." {
+                    continue;
+                }
+                let mut entry = file_entry_from_attrs(path, &file.filename, &file.attrs);
+                if entry.file_type == FileType::Symlink {
+                    entry.link_target = main.read_link(entry.path.as_str()).await.ok();
+                }
+                pending.push(entry);
+            }
+
+            if pending.len() >= BATCH_MAX_ENTRIES
+                || (!pending.is_empty() && last_batch.elapsed() >= BATCH_INTERVAL)
+            {
+                entries.extend(pending.iter().cloned());
+                on_batch(std::mem::take(&mut pending));
+                last_batch = Instant::now();
+            }
+        }
+
+        if !pending.is_empty() {
+            entries.extend(pending.iter().cloned());
+            on_batch(pending);
+        }
+        debug!("[SFTP] Streamed {} entries from {}", entries.len(), path);
+        Ok(entries)
+    }
+}
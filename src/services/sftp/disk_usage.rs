@@ -0,0 +1,138 @@
+// SFTP 磁盘占用分析
+// 优先通过远程 `du` 统计目录下各项的大小；`du` 不可用时回退为 SFTP 递归遍历
+
+use std::collections::HashMap;
+
+use tokio_util::sync::CancellationToken;
+
+use super::SftpService;
+use crate::models::sftp::FileEntry;
+use crate::services::remote_exec::shell_quote;
+
+/// 统计方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiskUsageSource {
+    /// 远程 `du` 命令
+    Du,
+    /// SFTP 递归遍历
+    SftpWalk,
+}
+
+/// 目录下的一项
+#[derive(Clone, Debug)]
+pub struct DiskUsageItem {
+    pub name: String,
+    pub path: String,
+    /// 占用大小（目录为所有子项之和）
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+/// 一个目录的占用统计
+#[derive(Clone, Debug)]
+pub struct DiskUsageLevel {
+    /// 目录路径
+    pub path: String,
+    /// 目录总大小
+    pub total: u64,
+    /// 直接子项，按大小从大到小排列
+    pub items: Vec<DiskUsageItem>,
+    /// 统计方式
+    pub source: DiskUsageSource,
+}
+
+/// 统计目录下各直接子项大小的 `du` 命令（字节，包含文件）
+pub fn du_command(dir: &str) -> String {
+    format!("du -ab --max-depth=1 -- {} 2>/dev/null", shell_quote(dir))
+}
+
+/// 解析 `du` 输出，返回子项名称到大小的映射，以及目录自身的总大小
+///
+/// 权限不足的子目录会让 `du` 以非零状态退出，但其余行仍然有效，因此只看输出内容
+pub fn parse_du_output(dir: &str, stdout: &str) -> (HashMap<String, u64>, Option<u64>) {
+    let dir = dir.trim_end_matches('/');
+    let mut sizes = HashMap::new();
+    let mut total = None;
+    for line in stdout.lines() {
+        let Some((size, path)) = line.split_once('\t') else {
+            continue;
+        };
+        let Ok(size) = size.trim().parse::<u64>() else {
+            continue;
+        };
+        let path = path.trim_end_matches('/');
+        if path == dir {
+            total = Some(size);
+        } else if let Some(name) = path.rsplit('/').next().filter(|n| !n.is_empty()) {
+            sizes.insert(name.to_string(), size);
+        }
+    }
+    (sizes, total)
+}
+
+/// 由目录条目和大小映射生成统计结果
+pub fn build_level(
+    dir: &str,
+    entries: Vec<FileEntry>,
+    sizes: &HashMap<String, u64>,
+    total: Option<u64>,
+    source: DiskUsageSource,
+) -> DiskUsageLevel {
+    let mut items: Vec<DiskUsageItem> = entries
+        .into_iter()
+        .map(|entry| DiskUsageItem {
+            size: sizes.get(&entry.name).copied().unwrap_or(entry.size),
+            is_dir: entry.is_dir(),
+            name: entry.name,
+            path: entry.path,
+        })
+        .collect();
+    items.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    let total = total.unwrap_or_else(|| items.iter().map(|i| i.size).sum());
+    DiskUsageLevel {
+        path: dir.to_string(),
+        total,
+        items,
+        source,
+    }
+}
+
+/// 通过 SFTP 递归遍历统计目录下各直接子项的大小（不跟随符号链接）
+pub async fn walk_sizes(
+    service: &SftpService,
+    entries: &[FileEntry],
+    cancel_token: &CancellationToken,
+) -> Result<HashMap<String, u64>, String> {
+    let mut sizes = HashMap::new();
+    for entry in entries {
+        let size = if entry.is_dir() {
+            let mut size = 0;
+            let mut level = vec![entry.path.clone()];
+            // 同一层级的子目录通过元数据通道池并行读取
+            while !level.is_empty() {
+                if cancel_token.is_cancelled() {
+                    return Err("Scan cancelled".to_string());
+                }
+                let dirs = std::mem::take(&mut level);
+                for (_, result) in service.read_dirs(dirs).await {
+                    // 无权限读取的子目录跳过，与 du 的行为一致
+                    let Ok(children) = result else {
+                        continue;
+                    };
+                    for child in children {
+                        if child.is_dir() {
+                            level.push(child.path);
+                        } else {
+                            size += child.size;
+                        }
+                    }
+                }
+            }
+            size
+        } else {
+            entry.size
+        };
+        sizes.insert(entry.name.clone(), size);
+    }
+    Ok(sizes)
+}
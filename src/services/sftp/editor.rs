@@ -26,6 +26,16 @@ pub fn get_temp_edit_dir() -> PathBuf {
 /// 格式: {session_id}_{remote_path_hash}_{filename}
 /// 返回规范化的绝对路径（解析符号链接）
 pub fn temp_file_path(session_id: &str, remote_path: &str) -> PathBuf {
+    temp_file_path_with_marker(session_id, remote_path, "")
+}
+
+/// 以管理员身份编辑时的临时文件路径
+/// 文件名带 `[root]` 标记，外置编辑器的标题栏中可以直接看出
+pub fn root_temp_file_path(session_id: &str, remote_path: &str) -> PathBuf {
+    temp_file_path_with_marker(session_id, remote_path, "[root]_")
+}
+
+fn temp_file_path_with_marker(session_id: &str, remote_path: &str, marker: &str) -> PathBuf {
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
@@ -38,7 +48,7 @@ pub fn temp_file_path(session_id: &str, remote_path: &str) -> PathBuf {
         .map(|s| s.to_string_lossy().to_string())
         .unwrap_or_else(|| "unknown".to_string());
 
-    get_temp_edit_dir().join(format!("{}_{}_{}", session_id, hash, filename))
+    get_temp_edit_dir().join(format!("{}_{}_{}{}", session_id, hash, marker, filename))
 }
 
 /// 确保临时目录存在
@@ -110,6 +120,8 @@ pub struct WatchedFile {
     pub remote_path: String,
     pub session_id: String,
     pub last_modified: SystemTime,
+    /// 以管理员身份编辑时的 sudo 密码（空字符串表示免密 sudo）
+    pub sudo_password: Option<String>,
 }
 
 /// 文件监控事件
@@ -120,6 +132,7 @@ pub enum FileWatchEvent {
         session_id: String,
         local_path: PathBuf,
         remote_path: String,
+        sudo_password: Option<String>,
     },
 }
 
@@ -174,6 +187,9 @@ impl FileWatcher {
                                                         session_id: watched.session_id.clone(),
                                                         local_path: watched.local_path.clone(),
                                                         remote_path: watched.remote_path.clone(),
+                                                        sudo_password: watched
+                                                            .sudo_password
+                                                            .clone(),
                                                     });
                                             }
                                         }
@@ -0,0 +1,68 @@
+// SFTP 十六进制查看：偏移量与搜索内容的解析、分页与 ASCII 显示
+
+/// 每行显示的字节数
+pub const HEX_BYTES_PER_ROW: usize = 16;
+/// 每页读取的字节数
+pub const HEX_PAGE_BYTES: usize = 4096;
+
+/// 搜索内容的格式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HexSearchMode {
+    /// 十六进制字节（如 `7F 45 4C 46`）
+    #[default]
+    Hex,
+    /// UTF-8 字符串
+    Text,
+}
+
+/// 解析偏移量，支持 `0x` 前缀的十六进制和十进制
+pub fn parse_offset(input: &str) -> Option<u64> {
+    let input = input.trim().replace('_', "");
+    match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => input.parse().ok(),
+    }
+}
+
+/// 解析搜索内容，十六进制模式下忽略空白，字节数必须完整
+pub fn parse_search_pattern(mode: HexSearchMode, input: &str) -> Option<Vec<u8>> {
+    match mode {
+        HexSearchMode::Text => (!input.is_empty()).then(|| input.as_bytes().to_vec()),
+        HexSearchMode::Hex => {
+            let digits: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            if digits.is_empty() || digits.len() % 2 != 0 {
+                return None;
+            }
+            (0..digits.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok())
+                .collect()
+        }
+    }
+}
+
+/// 将偏移量对齐到所在页的起始位置
+pub fn page_start(offset: u64) -> u64 {
+    offset - offset % HEX_PAGE_BYTES as u64
+}
+
+/// 偏移量列的宽度（文件超过 4 GB 时使用更多位数）
+pub fn offset_width(file_size: u64) -> usize {
+    if file_size > u32::MAX as u64 {
+        16
+    } else {
+        8
+    }
+}
+
+/// 单个字节的 ASCII 显示，不可打印字符显示为 `.`
+pub fn ascii_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
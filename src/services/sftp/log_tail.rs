@@ -0,0 +1,96 @@
+// 远程日志跟踪：生成 tail -F 命令，识别日志行的级别，查找关键词位置
+
+use std::ops::Range;
+
+use crate::services::remote_exec::shell_quote;
+
+/// 开始跟踪时先显示的历史行数
+pub const LOG_TAIL_INITIAL_LINES: usize = 200;
+
+/// 常见的日志文件路径（不同发行版位置不同，不存在的文件 tail 会提示并等待创建）
+pub const COMMON_LOG_PATHS: [&str; 11] = [
+    "/var/log/syslog",
+    "/var/log/messages",
+    "/var/log/auth.log",
+    "/var/log/secure",
+    "/var/log/kern.log",
+    "/var/log/nginx/access.log",
+    "/var/log/nginx/error.log",
+    "/var/log/apache2/error.log",
+    "/var/log/httpd/error_log",
+    "/var/log/mysql/error.log",
+    "/var/log/dpkg.log",
+];
+
+/// 日志行的级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSeverity {
+    Error,
+    Warning,
+    Info,
+    Debug,
+    /// 没有识别出级别
+    Plain,
+}
+
+/// 跟踪日志文件的命令（文件被轮转或重建后继续跟踪，错误信息也输出到标准输出）
+pub fn tail_command(path: &str) -> String {
+    format!(
+        "tail -n {} -F -- {} 2>&1",
+        LOG_TAIL_INITIAL_LINES,
+        shell_quote(path)
+    )
+}
+
+/// 根据行中出现的级别关键词判断日志级别（按单词匹配，不区分大小写，取最严重的级别）
+pub fn detect_severity(line: &str) -> LogSeverity {
+    let mut severity = LogSeverity::Plain;
+    for word in line
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        let level = match word.to_ascii_lowercase().as_str() {
+            "error" | "err" | "fatal" | "crit" | "critical" | "emerg" | "alert" | "panic"
+            | "failed" => LogSeverity::Error,
+            "warn" | "warning" => LogSeverity::Warning,
+            "info" | "notice" => LogSeverity::Info,
+            "debug" | "trace" => LogSeverity::Debug,
+            _ => continue,
+        };
+        if level == LogSeverity::Error {
+            return level;
+        }
+        if severity_rank(level) > severity_rank(severity) {
+            severity = level;
+        }
+    }
+    severity
+}
+
+fn severity_rank(severity: LogSeverity) -> u8 {
+    match severity {
+        LogSeverity::Plain => 0,
+        LogSeverity::Debug => 1,
+        LogSeverity::Info => 2,
+        LogSeverity::Warning => 3,
+        LogSeverity::Error => 4,
+    }
+}
+
+/// 查找关键词在行中出现的全部位置（ASCII 不区分大小写，`needle` 需已用 to_ascii_lowercase 转为小写）
+pub fn find_matches(line: &str, needle: &str) -> Vec<Range<usize>> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    // 只转换 ASCII 字母，保证字节位置与原文一致
+    let haystack = line.to_ascii_lowercase();
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while let Some(pos) = haystack[start..].find(needle) {
+        let begin = start + pos;
+        let end = begin + needle.len();
+        matches.push(begin..end);
+        start = end;
+    }
+    matches
+}
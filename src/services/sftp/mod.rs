@@ -1,11 +1,72 @@
 // SFTP 后端服务
 
+mod archive;
+mod attributes;
+mod bookmarks;
+mod channel_pool;
+mod compare;
+mod compression;
+mod conflict;
+mod dir_stream;
+mod disk_usage;
 mod editor;
+mod hex;
+mod log_tail;
 mod multi_channel;
 mod operations;
+mod preview;
+mod recovery;
+mod remote_copy;
+mod resume;
+mod scp;
+mod search;
 mod service;
+mod sudo_edit;
+mod sync;
+mod verify;
+mod watch;
 
+pub use archive::{
+    compress_command, extract_command, parse_archive_line, ArchiveFormat, ArchiveLine,
+    COMMAND_NOT_FOUND,
+};
+pub use attributes::{
+    remote_checksum, remote_extended_attributes, remote_total_size, ChecksumAlgorithm,
+};
+pub use bookmarks::SftpBookmarkStore;
+pub use compare::{
+    collect_local_tree, compare_base, diff_files, diff_trees, read_local_for_compare,
+    set_compare_base, CompareLocation, CompareTarget, DiffLine, DiffLineKind, DirDiffEntry,
+    DirDiffStatus, FileDiff, TreeMap,
+};
+pub use compression::{download_file_gzip, extract_local_archive, gzip_available};
+pub use conflict::{is_source_newer, unique_local_path};
+pub use disk_usage::{
+    build_level, du_command, parse_du_output, walk_sizes, DiskUsageItem, DiskUsageLevel,
+    DiskUsageSource,
+};
 pub use editor::*;
+pub use hex::{
+    ascii_char, offset_width, page_start, parse_offset, parse_search_pattern, HexSearchMode,
+    HEX_BYTES_PER_ROW, HEX_PAGE_BYTES,
+};
+pub use log_tail::{detect_severity, find_matches, tail_command, LogSeverity, COMMON_LOG_PATHS};
 pub use multi_channel::MultiChannelDownloader;
 pub use multi_channel::MultiChannelUploader;
+pub use operations::{
+    expand_remote_home, is_safe_trash_root, is_same_or_descendant, LinkKind, MoveOutcome,
+};
+pub use preview::{build_preview, preview_read_len, PreviewData, PreviewImageFormat};
+pub use recovery::{is_channel_closed, is_permission_denied, sudo_list_dir};
+pub use search::{SearchHit, SearchMatchMode, SearchQuery, MAX_SEARCH_RESULTS};
 pub use service::SftpService;
+pub use sudo_edit::{run_sudo, sudo_check};
+pub use sync::{
+    build_sync_plan, collect_local_files, local_checksum, local_sync_path, parse_checksum_line,
+    remote_checksum_command, SyncAction, SyncCompareMode, SyncFileInfo, SyncItem, SyncMode,
+};
+pub use verify::verify_checksum;
+pub use watch::{
+    dir_signature, inotify_changed, inotify_watch_command, WATCH_CYCLE_SECS, WATCH_DEBOUNCE,
+    WATCH_POLL_INTERVAL,
+};
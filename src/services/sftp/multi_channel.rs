@@ -6,7 +6,11 @@ use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
-use super::service::SftpService;
+use super::resume::{
+    finish_local_part, keep_local_part, local_part_path, local_resume_offset, remote_part_path,
+};
+use super::service::{cancelled_error, SftpService};
+use crate::services::transfer::Throttle;
 use crate::ssh::session::SshSession;
 
 /// 多通道下载器
@@ -18,6 +22,10 @@ pub struct MultiChannelDownloader {
     session_id: String,
     /// 并行通道数
     channel_count: usize,
+    /// 是否断点续传
+    resume: bool,
+    /// 传输限速器（未设置时不单独限速，只受全局限速约束）
+    throttle: Option<Throttle>,
 }
 
 /// 分片下载任务
@@ -40,6 +48,18 @@ struct ChunkProgress {
     speed: u64,
 }
 
+/// 计算从文件开头起连续写入完成的字节数（失败或取消后可安全保留的前缀）
+fn confirmed_prefix(tasks: &[ChunkTask], progress: &[ChunkProgress], start: u64) -> u64 {
+    let mut confirmed = start;
+    for (task, chunk) in tasks.iter().zip(progress) {
+        confirmed = task.offset + chunk.bytes_transferred;
+        if chunk.bytes_transferred < task.length {
+            break;
+        }
+    }
+    confirmed
+}
+
 impl MultiChannelDownloader {
     /// 创建多通道下载器
     ///
@@ -54,11 +74,27 @@ impl MultiChannelDownloader {
             ssh_session,
             session_id,
             channel_count,
+            resume: false,
+            throttle: None,
         }
     }
 
+    /// 设置是否从已有的 `.part` 部分文件续传，并在失败或取消后保留部分文件
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// 设置传输限速器，所有分片共享同一个限速
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
     /// 并行下载文件
     ///
+    /// 数据先写入 `.part` 部分文件，完成后再改名为目标文件
+    ///
     /// # Arguments
     /// * `remote_path` - 远程文件路径
     /// * `local_path` - 本地保存路径
@@ -87,24 +123,32 @@ impl MultiChannelDownloader {
             remote_path, file_size, self.channel_count
         );
 
+        // 续传时跳过已确认的前缀
+        let part_path = local_part_path(local_path);
+        let start = if self.resume {
+            local_resume_offset(&part_path, file_size).await
+        } else {
+            0
+        };
+
         // 创建分片任务
-        let tasks = self.create_chunk_tasks(file_size);
+        let tasks = create_chunk_tasks(start, file_size, self.channel_count);
         let task_count = tasks.len();
 
         info!("[SFTP] Created {} chunk tasks", task_count);
 
-        // 创建本地文件（预分配空间）
+        // 创建本地部分文件，保留已有前缀
         let local_file = tokio::fs::OpenOptions::new()
             .write(true)
             .create(true)
-            .truncate(true)
-            .open(local_path)
+            .truncate(start == 0)
+            .open(&part_path)
             .await
             .map_err(|e| format!("Failed to create local file: {}", e))?;
 
-        // 预分配文件大小
+        // 截断到已确认的前缀，丢弃之后可能写入的残缺数据
         local_file
-            .set_len(file_size)
+            .set_len(start)
             .await
             .map_err(|e| format!("Failed to set file size: {}", e))?;
 
@@ -113,13 +157,21 @@ impl MultiChannelDownloader {
         // 创建进度追踪
         let progress_tracker = Arc::new(Mutex::new(vec![ChunkProgress::default(); task_count]));
 
+        // 先回报一次续传起点
+        progress_callback(start, file_size, 0);
+
         // 包装回调函数
         let progress_callback = Arc::new(progress_callback);
 
+        let throttle = self
+            .throttle
+            .clone()
+            .unwrap_or_else(|| Throttle::new(false, Default::default(), cancel_token.clone()));
+
         // 启动并行下载任务
         let mut handles = Vec::with_capacity(task_count);
 
-        for task in tasks {
+        for task in tasks.clone() {
             let ssh_session = self.ssh_session.clone();
             let session_id = self.session_id.clone();
             let remote_path = remote_path.to_string();
@@ -129,6 +181,7 @@ impl MultiChannelDownloader {
             let file_size = file_size;
             let pause_flag_clone = pause_flag.clone();
             let cancel_token_clone = cancel_token.clone();
+            let throttle = throttle.clone();
 
             let handle = tokio::spawn(async move {
                 // 为每个分片创建独立的 SFTP 服务
@@ -159,6 +212,7 @@ impl MultiChannelDownloader {
                         local_file,
                         task.offset,
                         task.length,
+                        &throttle,
                         move |bytes_transferred, speed| {
                             // 检查暂停状态并等待
                             use std::sync::atomic::Ordering;
@@ -182,9 +236,9 @@ impl MultiChannelDownloader {
                                     tracker[chunk_idx].bytes_transferred = bytes_transferred;
                                     tracker[chunk_idx].speed = speed;
 
-                                    // 计算总进度
-                                    let total_transferred: u64 =
-                                        tracker.iter().map(|p| p.bytes_transferred).sum();
+                                    // 计算总进度（包含续传前已完成的部分）
+                                    let total_transferred: u64 = start
+                                        + tracker.iter().map(|p| p.bytes_transferred).sum::<u64>();
                                     let total_speed: u64 = tracker.iter().map(|p| p.speed).sum();
 
                                     // 调用总进度回调
@@ -210,22 +264,26 @@ impl MultiChannelDownloader {
             handles.push(handle);
         }
 
-        // 等待所有任务完成，同时监听取消信号
-        let local_path_for_cleanup = local_path.to_path_buf();
-
         // 使用 futures::future::join_all 来并行等待所有任务
-        let all_tasks = futures::future::join_all(handles);
+        let abort_handles: Vec<_> = handles.iter().map(|h| h.abort_handle()).collect();
+        let mut all_tasks = futures::future::join_all(handles);
 
         let results = tokio::select! {
             // 监听取消信号
-            _ = cancel_token.cancelled() => {
-                warn!("[SFTP] Multi-channel download cancelled by user");
-                // 删除不完整的文件
-                let _ = tokio::fs::remove_file(&local_path_for_cleanup).await;
-                return Err("下载已取消".to_string());
-            }
+            _ = cancel_token.cancelled() => None,
             // 等待所有下载任务完成
-            results = all_tasks => results,
+            results = &mut all_tasks => Some(results),
+        };
+        let Some(results) = results else {
+            warn!("[SFTP] Multi-channel download cancelled by user");
+            // 停止所有分片任务并等待其退出，确保之后不会再写入
+            abort_handles.iter().for_each(|h| h.abort());
+            let _ = all_tasks.await;
+            // 只保留已确认的前缀（或删除不完整的文件）
+            let confirmed = confirmed_prefix(&tasks, &progress_tracker.lock().await, start);
+            drop(local_file);
+            keep_local_part(&part_path, confirmed, self.resume).await;
+            return Err(cancelled_error(false));
         };
 
         // 处理结果
@@ -248,8 +306,10 @@ impl MultiChannelDownloader {
 
         // 检查是否有错误
         if !errors.is_empty() {
-            // 删除不完整的文件
-            let _ = tokio::fs::remove_file(local_path).await;
+            // 只保留已确认的前缀（或删除不完整的文件）
+            let confirmed = confirmed_prefix(&tasks, &progress_tracker.lock().await, start);
+            drop(local_file);
+            keep_local_part(&part_path, confirmed, self.resume).await;
             return Err(format!(
                 "Multi-channel download failed:\n{}",
                 errors.join("\n")
@@ -263,6 +323,8 @@ impl MultiChannelDownloader {
                 .await
                 .map_err(|e| format!("Failed to sync file: {}", e))?;
         }
+        drop(local_file);
+        finish_local_part(&part_path, local_path).await?;
 
         info!(
             "[SFTP] Multi-channel download completed: {} ({} bytes)",
@@ -271,35 +333,6 @@ impl MultiChannelDownloader {
 
         Ok(())
     }
-
-    /// 创建分片任务
-    fn create_chunk_tasks(&self, file_size: u64) -> Vec<ChunkTask> {
-        // 最小分片大小为 1MB
-        const MIN_CHUNK_SIZE: u64 = 1024 * 1024;
-
-        // 计算每个分片的大小
-        let chunk_size = std::cmp::max(
-            MIN_CHUNK_SIZE,
-            (file_size + self.channel_count as u64 - 1) / self.channel_count as u64,
-        );
-
-        let mut tasks = Vec::new();
-        let mut offset = 0u64;
-        let mut index = 0;
-
-        while offset < file_size {
-            let length = std::cmp::min(chunk_size, file_size - offset);
-            tasks.push(ChunkTask {
-                index,
-                offset,
-                length,
-            });
-            offset += length;
-            index += 1;
-        }
-
-        tasks
-    }
 }
 
 use tokio::io::AsyncWriteExt;
@@ -313,6 +346,10 @@ pub struct MultiChannelUploader {
     session_id: String,
     /// 并行通道数
     channel_count: usize,
+    /// 是否断点续传
+    resume: bool,
+    /// 传输限速器（未设置时不单独限速，只受全局限速约束）
+    throttle: Option<Throttle>,
 }
 
 impl MultiChannelUploader {
@@ -329,11 +366,27 @@ impl MultiChannelUploader {
             ssh_session,
             session_id,
             channel_count,
+            resume: false,
+            throttle: None,
         }
     }
 
+    /// 设置是否从已有的 `.part` 部分文件续传，并在失败或取消后保留部分文件
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
+    /// 设置传输限速器，所有分片共享同一个限速
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
     /// 并行上传文件
     ///
+    /// 数据先写入远程的 `.part` 部分文件，完成后再改名为目标文件
+    ///
     /// # Arguments
     /// * `local_path` - 本地文件路径
     /// * `remote_path` - 远程保存路径
@@ -362,11 +415,7 @@ impl MultiChannelUploader {
             local_path, remote_path, file_size, self.channel_count
         );
 
-        // 创建分片任务
-        let tasks = self.create_chunk_tasks(file_size);
-        let task_count = tasks.len();
-
-        info!("[SFTP] Created {} upload chunk tasks", task_count);
+        let part_path = remote_part_path(remote_path);
 
         // 打开本地文件
         let local_file = tokio::fs::File::open(local_path)
@@ -374,7 +423,6 @@ impl MultiChannelUploader {
             .map_err(|e| format!("Failed to open local file: {}", e))?;
         let local_file = Arc::new(Mutex::new(local_file));
 
-        // 在远程创建空文件（预分配空间）
         // 使用主 SFTP 服务创建文件
         let main_sftp = SftpService::new(
             format!("{}-upload-main", self.session_id),
@@ -383,36 +431,62 @@ impl MultiChannelUploader {
         .await
         .map_err(|e| format!("Failed to create main SFTP channel: {}", e))?;
 
-        // 创建远程文件
-        let remote_file = main_sftp
-            .sftp()
-            .create(remote_path)
-            .await
-            .map_err(|e| format!("Failed to create remote file: {}", e))?;
+        // 续传时跳过已确认的前缀
+        let start = if self.resume {
+            main_sftp.remote_resume_offset(&part_path, file_size).await
+        } else {
+            0
+        };
 
-        // 预分配文件大小（写入空字节到最后位置）
-        // 关闭文件，让分片任务可以写入
-        drop(remote_file);
+        // 创建分片任务
+        let tasks = create_chunk_tasks(start, file_size, self.channel_count);
+        let task_count = tasks.len();
+
+        info!("[SFTP] Created {} upload chunk tasks", task_count);
+
+        if start > 0 {
+            // 截断到已确认的前缀，丢弃之后可能写入的残缺数据
+            main_sftp.truncate(&part_path, start).await?;
+        } else {
+            // 创建远程部分文件
+            let remote_file = main_sftp
+                .sftp()?
+                .create(&part_path)
+                .await
+                .map_err(|e| format!("Failed to create remote file: {}", e))?;
+
+            // 关闭文件，让分片任务可以写入
+            drop(remote_file);
+        }
 
         // 创建进度追踪
         let progress_tracker = Arc::new(Mutex::new(vec![ChunkProgress::default(); task_count]));
 
+        // 先回报一次续传起点
+        progress_callback(start, file_size, 0);
+
         // 包装回调函数
         let progress_callback = Arc::new(progress_callback);
 
+        let throttle = self
+            .throttle
+            .clone()
+            .unwrap_or_else(|| Throttle::new(true, Default::default(), cancel_token.clone()));
+
         // 启动并行上传任务
         let mut handles = Vec::with_capacity(task_count);
 
-        for task in tasks {
+        for task in tasks.clone() {
             let ssh_session = self.ssh_session.clone();
             let session_id = self.session_id.clone();
-            let remote_path = remote_path.to_string();
+            let remote_path = part_path.clone();
             let local_file = local_file.clone();
             let progress_tracker = progress_tracker.clone();
             let progress_callback = progress_callback.clone();
             let file_size = file_size;
             let pause_flag_clone = pause_flag.clone();
             let cancel_token_clone = cancel_token.clone();
+            let throttle = throttle.clone();
 
             let handle = tokio::spawn(async move {
                 // 为每个分片创建独立的 SFTP 服务
@@ -443,6 +517,7 @@ impl MultiChannelUploader {
                         &remote_path,
                         task.offset,
                         task.length,
+                        &throttle,
                         move |bytes_transferred, speed| {
                             // 检查暂停状态并等待
                             use std::sync::atomic::Ordering;
@@ -466,9 +541,9 @@ impl MultiChannelUploader {
                                     tracker[chunk_idx].bytes_transferred = bytes_transferred;
                                     tracker[chunk_idx].speed = speed;
 
-                                    // 计算总进度
-                                    let total_transferred: u64 =
-                                        tracker.iter().map(|p| p.bytes_transferred).sum();
+                                    // 计算总进度（包含续传前已完成的部分）
+                                    let total_transferred: u64 = start
+                                        + tracker.iter().map(|p| p.bytes_transferred).sum::<u64>();
                                     let total_speed: u64 = tracker.iter().map(|p| p.speed).sum();
 
                                     // 调用总进度回调
@@ -497,22 +572,27 @@ impl MultiChannelUploader {
             handles.push(handle);
         }
 
-        // 等待所有任务完成，同时监听取消信号
-        let remote_path_for_cleanup = remote_path.to_string();
-
         // 使用 futures::future::join_all 来并行等待所有任务
-        let all_tasks = futures::future::join_all(handles);
+        let abort_handles: Vec<_> = handles.iter().map(|h| h.abort_handle()).collect();
+        let mut all_tasks = futures::future::join_all(handles);
 
         let results = tokio::select! {
             // 监听取消信号
-            _ = cancel_token.cancelled() => {
-                warn!("[SFTP] Multi-channel upload cancelled by user");
-                // 删除不完整的远程文件
-                let _ = main_sftp.remove_file(&remote_path_for_cleanup).await;
-                return Err("上传已取消".to_string());
-            }
+            _ = cancel_token.cancelled() => None,
             // 等待所有上传任务完成
-            results = all_tasks => results,
+            results = &mut all_tasks => Some(results),
+        };
+        let Some(results) = results else {
+            warn!("[SFTP] Multi-channel upload cancelled by user");
+            // 停止所有分片任务并等待其退出，确保之后不会再写入
+            abort_handles.iter().for_each(|h| h.abort());
+            let _ = all_tasks.await;
+            // 只保留已确认的前缀（或删除不完整的远程文件）
+            let confirmed = confirmed_prefix(&tasks, &progress_tracker.lock().await, start);
+            main_sftp
+                .keep_remote_part(&part_path, confirmed, self.resume)
+                .await;
+            return Err(cancelled_error(true));
         };
 
         // 处理结果
@@ -535,13 +615,19 @@ impl MultiChannelUploader {
 
         // 检查是否有错误
         if !errors.is_empty() {
-            // 删除不完整的远程文件
-            let _ = main_sftp.remove_file(&remote_path_for_cleanup).await;
+            // 只保留已确认的前缀（或删除不完整的远程文件）
+            let confirmed = confirmed_prefix(&tasks, &progress_tracker.lock().await, start);
+            main_sftp
+                .keep_remote_part(&part_path, confirmed, self.resume)
+                .await;
             return Err(format!(
                 "Multi-channel upload failed:\n{}",
                 errors.join("\n")
             ));
         }
+        main_sftp
+            .finish_remote_part(&part_path, remote_path)
+            .await?;
 
         info!(
             "[SFTP] Multi-channel upload completed: {} ({} bytes)",
@@ -550,33 +636,34 @@ impl MultiChannelUploader {
 
         Ok(())
     }
+}
 
-    /// 创建分片任务
-    fn create_chunk_tasks(&self, file_size: u64) -> Vec<ChunkTask> {
-        // 最小分片大小为 1MB
-        const MIN_CHUNK_SIZE: u64 = 1024 * 1024;
-
-        // 计算每个分片的大小
-        let chunk_size = std::cmp::max(
-            MIN_CHUNK_SIZE,
-            (file_size + self.channel_count as u64 - 1) / self.channel_count as u64,
-        );
-
-        let mut tasks = Vec::new();
-        let mut offset = 0u64;
-        let mut index = 0;
-
-        while offset < file_size {
-            let length = std::cmp::min(chunk_size, file_size - offset);
-            tasks.push(ChunkTask {
-                index,
-                offset,
-                length,
-            });
-            offset += length;
-            index += 1;
-        }
-
-        tasks
+/// 把 `[start, file_size)` 划分为分片任务
+fn create_chunk_tasks(start: u64, file_size: u64, channel_count: usize) -> Vec<ChunkTask> {
+    // 最小分片大小为 1MB
+    const MIN_CHUNK_SIZE: u64 = 1024 * 1024;
+
+    // 计算每个分片的大小
+    let remaining = file_size.saturating_sub(start);
+    let chunk_size = std::cmp::max(
+        MIN_CHUNK_SIZE,
+        (remaining + channel_count as u64 - 1) / channel_count as u64,
+    );
+
+    let mut tasks = Vec::new();
+    let mut offset = start;
+    let mut index = 0;
+
+    while offset < file_size {
+        let length = std::cmp::min(chunk_size, file_size - offset);
+        tasks.push(ChunkTask {
+            index,
+            offset,
+            length,
+        });
+        offset += length;
+        index += 1;
     }
+
+    tasks
 }
@@ -1,4 +1,300 @@
 // SFTP 文件操作扩展
-// 包含上传、下载等高级操作（后续实现）
+// 基于 SftpService 基础接口组合出的高级操作（移动、覆盖、回收站、权限修改、链接等）
 
-// 上传下载功能将在后续 Phase 4 实现
+use tracing::info;
+
+use super::SftpService;
+use crate::models::sftp::state::{get_parent_path, join_path};
+use crate::models::sftp::{FileEntry, FileType};
+
+/// 移动/重命名操作结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MoveOutcome {
+    /// 已完成移动
+    Moved,
+    /// 目标已存在，需要用户确认是否覆盖
+    TargetExists,
+}
+
+/// 链接类型
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinkKind {
+    /// 符号链接
+    #[default]
+    Symbolic,
+    /// 硬链接
+    Hard,
+}
+
+/// 判断 `path` 是否为 `ancestor` 自身或其子路径
+pub fn is_same_or_descendant(path: &str, ancestor: &str) -> bool {
+    let ancestor = ancestor.trim_end_matches('/');
+    if ancestor.is_empty() {
+        return true;
+    }
+    path == ancestor
+        || path
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// 将以 `~` 开头的远程路径展开为主目录下的绝对路径
+pub fn expand_remote_home(path: &str, home_dir: &str) -> String {
+    let path = path.trim();
+    if path == "~" {
+        return home_dir.to_string();
+    }
+    match path.strip_prefix("~/") {
+        Some(rest) => join_path(home_dir, rest.trim_end_matches('/')),
+        None => path.trim_end_matches('/').to_string(),
+    }
+}
+
+/// 回收站目录是否可以安全使用（清空回收站会递归删除其中的全部内容）
+///
+/// 目录必须是 `~/子目录` 或绝对路径，不能为空、根目录或主目录，不能包含 `.`、`..` 或空路径段；
+/// 已知主目录时还要求展开后的目录不是主目录本身或其上级目录
+pub fn is_safe_trash_root(trash_dir: &str, home_dir: Option<&str>) -> bool {
+    let dir = trash_dir.trim();
+    let rest = match dir.strip_prefix("~/") {
+        Some(rest) => rest,
+        None if dir.starts_with('/') => &dir[1..],
+        None => return false,
+    };
+    let rest = rest.trim_end_matches('/');
+    if rest.is_empty()
+        || rest
+            .split('/')
+            .any(|part| part.is_empty() || part == "." || part == "..")
+    {
+        return false;
+    }
+    match home_dir {
+        Some(home_dir) => !is_same_or_descendant(home_dir, &expand_remote_home(dir, home_dir)),
+        None => true,
+    }
+}
+
+/// 将链接目标解析为绝对路径
+///
+/// 相对目标以链接所在目录为基准，并折叠其中的 `.` 与 `..`
+pub fn resolve_link_target(link_path: &str, target: &str) -> String {
+    let joined = if target.starts_with('/') {
+        target.to_string()
+    } else {
+        join_path(&get_parent_path(link_path), target)
+    };
+
+    let mut parts: Vec<&str> = Vec::new();
+    for part in joined.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+impl SftpService {
+    /// 检查远程路径是否存在
+    pub async fn exists(&self, path: &str) -> bool {
+        self.stat(path).await.is_ok()
+    }
+
+    /// 递归删除文件或目录
+    pub async fn remove_recursive(&self, path: &str) -> Result<(), String> {
+        let entry = self.stat(path).await?;
+        if !entry.is_dir() {
+            return self.remove_file(path).await;
+        }
+
+        info!("[SFTP] Removing directory recursively: {}", path);
+        let mut entries = self.read_dir_recursive(path).await?;
+        // 按路径深度倒序删除，保证子项先于父目录被删除
+        entries.sort_by_key(|e| std::cmp::Reverse(e.path.matches('/').count()));
+        for entry in entries {
+            if entry.is_dir() {
+                self.remove_dir(&entry.path).await?;
+            } else {
+                self.remove_file(&entry.path).await?;
+            }
+        }
+        self.remove_dir(path).await
+    }
+
+    /// 移动（或重命名）文件/目录
+    ///
+    /// # Arguments
+    /// * `from` - 源路径
+    /// * `to` - 目标完整路径
+    /// * `overwrite` - 目标已存在时是否覆盖
+    ///
+    /// # Returns
+    /// * `Ok(MoveOutcome::Moved)` - 移动成功
+    /// * `Ok(MoveOutcome::TargetExists)` - 目标已存在且未允许覆盖
+    /// * `Err(String)` - 移动失败
+    pub async fn move_entry(
+        &self,
+        from: &str,
+        to: &str,
+        overwrite: bool,
+    ) -> Result<MoveOutcome, String> {
+        if from == to {
+            return Ok(MoveOutcome::Moved);
+        }
+        if is_same_or_descendant(&get_parent_path(to), from) {
+            return Err(format!("Cannot move {} into itself", from));
+        }
+
+        if self.exists(to).await {
+            if !overwrite {
+                return Ok(MoveOutcome::TargetExists);
+            }
+            info!("[SFTP] Overwriting existing target: {}", to);
+            self.remove_recursive(to).await?;
+        }
+
+        self.rename(from, to).await?;
+        Ok(MoveOutcome::Moved)
+    }
+
+    /// 将文件或目录移动到远程回收站
+    /// 每次删除操作放入 `<trash_root>/<时间戳>/` 下，返回移动后的路径
+    pub async fn move_to_trash(&self, path: &str, trash_root: &str) -> Result<String, String> {
+        if is_same_or_descendant(path, trash_root) {
+            return Err(format!("{} is already in the trash", path));
+        }
+
+        let batch_dir = join_path(
+            trash_root,
+            &chrono::Local::now().format("%Y%m%d-%H%M%S").to_string(),
+        );
+        self.mkdir_recursive(&batch_dir).await?;
+
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let mut target = join_path(&batch_dir, name);
+        // 同一批次内同名时追加序号
+        let mut index = 1;
+        while self.exists(&target).await {
+            target = join_path(&batch_dir, &format!("{} ({})", name, index));
+            index += 1;
+        }
+
+        info!("[SFTP] Moving to trash: {} -> {}", path, target);
+        self.rename(path, &target).await?;
+        Ok(target)
+    }
+
+    /// 清空远程回收站（保留回收站目录本身）
+    pub async fn empty_trash(&self, trash_root: &str) -> Result<(), String> {
+        if !self.exists(trash_root).await {
+            return Ok(());
+        }
+
+        info!("[SFTP] Emptying trash: {}", trash_root);
+        for entry in self.read_dir(trash_root).await? {
+            self.remove_recursive(&entry.path).await?;
+        }
+        Ok(())
+    }
+
+    /// 修改权限与所有者
+    ///
+    /// # Arguments
+    /// * `path` - 文件/目录路径
+    /// * `mode` - 新的权限位，`None` 表示不修改
+    /// * `owner` - 新的 (uid, gid)，`None` 表示不修改
+    /// * `recursive` - 是否递归应用到目录下的所有条目（跳过符号链接）
+    pub async fn set_attributes(
+        &self,
+        path: &str,
+        mode: Option<u32>,
+        owner: Option<(u32, u32)>,
+        recursive: bool,
+    ) -> Result<(), String> {
+        let mut targets = vec![path.to_string()];
+        if recursive {
+            let entries = self.read_dir_recursive(path).await?;
+            targets.extend(
+                entries
+                    .into_iter()
+                    .filter(|e| e.file_type != FileType::Symlink)
+                    .map(|e| e.path),
+            );
+        }
+
+        for target in &targets {
+            if let Some(mode) = mode {
+                self.chmod(target, mode).await?;
+            }
+            if let Some((uid, gid)) = owner {
+                self.chown(target, uid, gid).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 在 `path` 处创建指向 `target` 的链接
+    ///
+    /// 符号链接的目标按原样写入（允许相对路径或尚不存在的目标）；
+    /// 硬链接要求目标存在且不是目录
+    pub async fn create_link(
+        &self,
+        path: &str,
+        target: &str,
+        kind: LinkKind,
+    ) -> Result<(), String> {
+        if self.exists(path).await {
+            return Err(format!("{} already exists", path));
+        }
+
+        match kind {
+            LinkKind::Symbolic => self.symlink(path, target).await,
+            LinkKind::Hard => {
+                let resolved = resolve_link_target(path, target);
+                if self.stat(&resolved).await?.is_dir() {
+                    return Err(format!("Cannot create hard link to directory {}", resolved));
+                }
+                self.hard_link(path, &resolved).await
+            }
+        }
+    }
+
+    /// 解析符号链接指向的条目（跟随链接），返回的条目路径为目标的绝对路径
+    pub async fn follow_link(&self, path: &str) -> Result<FileEntry, String> {
+        let target = self.read_link(path).await?;
+        let resolved = resolve_link_target(path, &target);
+        self.stat(&resolved).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_safe_trash_root() {
+        assert!(is_safe_trash_root("~/.shellmaster-trash", None));
+        assert!(is_safe_trash_root("~/.trash/", Some("/home/user")));
+        assert!(is_safe_trash_root("/tmp/trash", Some("/home/user")));
+
+        // 空路径、根目录、主目录
+        assert!(!is_safe_trash_root("", None));
+        assert!(!is_safe_trash_root("/", None));
+        assert!(!is_safe_trash_root("~", None));
+        assert!(!is_safe_trash_root("~/", None));
+        assert!(!is_safe_trash_root("~//", None));
+        // 相对路径与 `.`、`..`
+        assert!(!is_safe_trash_root("trash", None));
+        assert!(!is_safe_trash_root("~/.", None));
+        assert!(!is_safe_trash_root("~/../user", None));
+        assert!(!is_safe_trash_root("/home//user", None));
+        // 主目录本身或其上级目录
+        assert!(!is_safe_trash_root("/home/user", Some("/home/user")));
+        assert!(!is_safe_trash_root("/home/user/", Some("/home/user")));
+        assert!(!is_safe_trash_root("/home", Some("/home/user")));
+    }
+}
@@ -0,0 +1,160 @@
+// SFTP 文件预览：按文件类型决定读取的字节数，并把读取到的开头内容解析为预览数据
+// 只读取文件开头，不下载整个文件
+
+use encoding_rs::{Encoding, GBK, UTF_8, WINDOWS_1252};
+
+/// 文本文件预览读取的字节数
+pub const TEXT_PREVIEW_BYTES: usize = 64 * 1024;
+/// 可预览缩略图的最大图片大小
+pub const IMAGE_PREVIEW_MAX_BYTES: u64 = 8 * 1024 * 1024;
+/// PDF 及二进制文件读取的头部字节数
+const HEADER_BYTES: usize = 1024;
+/// 二进制文件显示的头部字节数（十六进制）
+const HEX_HEADER_BYTES: usize = 32;
+
+/// 预览的图片格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreviewImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Bmp,
+    Svg,
+}
+
+/// 预览数据
+#[derive(Clone, Debug)]
+pub enum PreviewData {
+    /// 图片（完整内容，用于缩略图）
+    Image {
+        format: PreviewImageFormat,
+        bytes: Vec<u8>,
+    },
+    /// 文本开头部分
+    Text {
+        text: String,
+        /// 检测到的编码名称
+        encoding: &'static str,
+        /// 文件是否超出读取范围
+        truncated: bool,
+    },
+    /// PDF 头部信息
+    Pdf { version: Option<String> },
+    /// 其他二进制文件，仅显示头部字节
+    Binary { header: String },
+    /// 图片过大，不生成缩略图
+    TooLarge,
+}
+
+/// 根据文件扩展名判断图片格式
+fn image_format(name: &str) -> Option<PreviewImageFormat> {
+    let ext = name.rsplit_once('.')?.1.to_lowercase();
+    match ext.as_str() {
+        "png" => Some(PreviewImageFormat::Png),
+        "jpg" | "jpeg" => Some(PreviewImageFormat::Jpeg),
+        "gif" => Some(PreviewImageFormat::Gif),
+        "webp" => Some(PreviewImageFormat::Webp),
+        "bmp" => Some(PreviewImageFormat::Bmp),
+        "svg" => Some(PreviewImageFormat::Svg),
+        _ => None,
+    }
+}
+
+/// 预览需要读取的字节数，返回 None 表示不需要读取文件内容
+pub fn preview_read_len(name: &str, size: u64) -> Option<usize> {
+    if image_format(name).is_some() {
+        return (size <= IMAGE_PREVIEW_MAX_BYTES).then_some(size as usize);
+    }
+    if is_pdf(name) {
+        return Some(HEADER_BYTES);
+    }
+    // 其他文件读取文本预览的长度，根据内容判断是文本还是二进制
+    Some(TEXT_PREVIEW_BYTES)
+}
+
+/// 根据读取到的开头内容生成预览数据
+pub fn build_preview(name: &str, size: u64, bytes: Vec<u8>) -> PreviewData {
+    if let Some(format) = image_format(name) {
+        if size > IMAGE_PREVIEW_MAX_BYTES {
+            return PreviewData::TooLarge;
+        }
+        return PreviewData::Image { format, bytes };
+    }
+    if is_pdf(name) || bytes.starts_with(b"%PDF-") {
+        return PreviewData::Pdf {
+            version: pdf_version(&bytes),
+        };
+    }
+    if looks_binary(&bytes) {
+        return PreviewData::Binary {
+            header: hex_header(&bytes),
+        };
+    }
+
+    let (text, encoding) = decode_text(&bytes);
+    PreviewData::Text {
+        text,
+        encoding,
+        truncated: size > bytes.len() as u64,
+    }
+}
+
+fn is_pdf(name: &str) -> bool {
+    name.to_lowercase().ends_with(".pdf")
+}
+
+/// 解析 PDF 头部的版本号（如 `%PDF-1.7`）
+fn pdf_version(bytes: &[u8]) -> Option<String> {
+    let rest = bytes.strip_prefix(b"%PDF-")?;
+    let version: String = rest
+        .iter()
+        .take_while(|b| b.is_ascii_digit() || **b == b'.')
+        .map(|&b| b as char)
+        .collect();
+    (!version.is_empty()).then_some(version)
+}
+
+/// 包含 NUL 字节（且不是 UTF-16 BOM 开头）时视为二进制文件
+pub(super) fn looks_binary(bytes: &[u8]) -> bool {
+    Encoding::for_bom(bytes).is_none() && bytes.iter().take(HEADER_BYTES * 8).any(|&b| b == 0)
+}
+
+/// 头部字节的十六进制表示
+fn hex_header(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take(HEX_HEADER_BYTES)
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 检测文本编码并解码
+///
+/// 依次尝试 BOM、UTF-8、GBK，都不符合时按 Windows-1252 解码；
+/// 读取范围末尾被截断的多字节字符不算作编码错误
+pub(super) fn decode_text(bytes: &[u8]) -> (String, &'static str) {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        let (text, _) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+        return (text.into_owned(), encoding.name());
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) => return (text.to_string(), UTF_8.name()),
+        Err(e) if e.error_len().is_none() => {
+            let text = String::from_utf8_lossy(&bytes[..e.valid_up_to()]);
+            return (text.into_owned(), UTF_8.name());
+        }
+        Err(_) => {}
+    }
+
+    let (text, _, had_errors) = GBK.decode(bytes);
+    // 末尾可能截断了一个双字节字符，只要错误不超过一处就认为是 GBK
+    if !had_errors || text.matches('\u{FFFD}').count() <= 1 {
+        return (text.trim_end_matches('\u{FFFD}').to_string(), GBK.name());
+    }
+
+    let (text, _, _) = WINDOWS_1252.decode(bytes);
+    (text.into_owned(), WINDOWS_1252.name())
+}
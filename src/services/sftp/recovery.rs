@@ -0,0 +1,157 @@
+// SFTP 目录读取失败的恢复
+// 根据错误信息区分权限不足与通道失效；权限不足时可通过远程 `sudo ls` 列出目录
+
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::sudo_edit::run_sudo;
+use crate::models::sftp::{FileEntry, FileType};
+use crate::services::remote_exec::shell_quote;
+use crate::ssh::session::SshSession;
+
+/// 错误信息是否表示权限不足
+pub fn is_permission_denied(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("permission denied") || message.contains("access denied")
+}
+
+/// 错误信息是否表示 SFTP 通道已失效（需要重新打开通道）
+pub fn is_channel_closed(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "session closed",
+        "channel closed",
+        "connection closed",
+        "connection lost",
+        "connection reset",
+        "no connection",
+        "not connected",
+        "broken pipe",
+        "unexpected eof",
+        "timeout",
+        "timed out",
+    ]
+    .iter()
+    .any(|keyword| message.contains(keyword))
+}
+
+/// 以 sudo 列出目录时执行的命令
+///
+/// 数字 uid/gid 便于解析；不使用 GNU 专有的 `--time-style`，以兼容 BSD / busybox 的 `ls`
+fn list_dir_command(dir: &str) -> String {
+    format!("env LC_ALL=C ls -lan -- {}", shell_quote(dir))
+}
+
+/// 以 sudo 列出目录（密码为空表示免密 sudo）
+pub async fn sudo_list_dir(
+    ssh_session: &SshSession,
+    dir: &str,
+    password: &str,
+) -> Result<Vec<FileEntry>, String> {
+    let stdout = run_sudo(ssh_session, &list_dir_command(dir), password).await?;
+    Ok(parse_ls_output(dir, &String::from_utf8_lossy(&stdout)))
+}
+
+/// 解析 `ls -lan` 输出为目录条目（跳过 `.` 与 `..`）
+///
+/// 时间列为数字时间戳（`--time-style=+%s`）时设置修改时间；三段式日期（如 `Jan  5 12:34`）不设置修改时间
+pub fn parse_ls_output(dir: &str, stdout: &str) -> Vec<FileEntry> {
+    stdout
+        .lines()
+        .filter_map(|line| parse_ls_line(dir, line))
+        .collect()
+}
+
+/// 解析 `ls -lan` 的一行
+fn parse_ls_line(dir: &str, line: &str) -> Option<FileEntry> {
+    let mut rest = line;
+    let perms = next_field(&mut rest)?;
+    let (file_type, permissions) = parse_mode(perms)?;
+    let _links = next_field(&mut rest)?;
+    let uid = next_field(&mut rest)?.parse::<u32>().ok();
+    let gid = next_field(&mut rest)?.parse::<u32>().ok();
+    // 设备文件显示为 "主设备号, 次设备号"
+    let size_field = next_field(&mut rest)?;
+    let size = if size_field.ends_with(',') {
+        next_field(&mut rest)?;
+        0
+    } else {
+        size_field.parse::<u64>().unwrap_or(0)
+    };
+    let time_field = next_field(&mut rest)?;
+    let modified = match time_field.parse::<u64>() {
+        Ok(secs) => Some(UNIX_EPOCH + Duration::from_secs(secs)),
+        Err(_) => {
+            next_field(&mut rest)?;
+            next_field(&mut rest)?;
+            None
+        }
+    };
+
+    let name_field = rest.strip_prefix(' ').unwrap_or(rest.trim_start());
+    let (name, link_target) = match name_field.split_once(" -> ") {
+        Some((name, target)) if file_type == FileType::Symlink => (name, Some(target.to_string())),
+        _ => (name_field, None),
+    };
+    if name.is_empty() || name == "." || name == ".." {
+        return None;
+    }
+
+    let path = if dir == "/" {
+        format!("/{}", name)
+    } else {
+        format!("{}/{}", dir.trim_end_matches('/'), name)
+    };
+    let mut entry = FileEntry::new(name.to_string(), path, file_type);
+    entry.size = size;
+    entry.modified = modified;
+    entry.permissions = permissions;
+    entry.uid = uid;
+    entry.gid = gid;
+    entry.link_target = link_target;
+    Some(entry)
+}
+
+/// 取出下一个以空白分隔的字段
+fn next_field<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    let trimmed = rest.trim_start();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+    *rest = &trimmed[end..];
+    Some(&trimmed[..end])
+}
+
+/// 解析权限字符串（如 `drwxr-sr-x`）为文件类型与权限位
+fn parse_mode(perms: &str) -> Option<(FileType, u32)> {
+    let bytes = perms.as_bytes();
+    if bytes.len() < 10 {
+        return None;
+    }
+    let file_type = match bytes[0] {
+        b'd' => FileType::Directory,
+        b'l' => FileType::Symlink,
+        b'-' => FileType::File,
+        b'b' | b'c' | b'p' | b's' => FileType::Other,
+        _ => return None,
+    };
+
+    let mut mode = 0;
+    for (ix, &c) in bytes[1..10].iter().enumerate() {
+        let bit = 0o400 >> ix;
+        // setuid / setgid / sticky 位显示在各组的执行位上
+        let special = match ix {
+            2 => 0o4000,
+            5 => 0o2000,
+            8 => 0o1000,
+            _ => 0,
+        };
+        match c {
+            b'r' | b'w' | b'x' => mode |= bit,
+            b's' | b't' => mode |= bit | special,
+            b'S' | b'T' => mode |= special,
+            _ => {}
+        }
+    }
+    Some((file_type, mode))
+}
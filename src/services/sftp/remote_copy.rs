@@ -0,0 +1,106 @@
+// 跨服务器复制：经由客户端把一台服务器上的文件流式写入另一台服务器
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::info;
+
+use super::scp::{SpeedMeter, CHUNK_SIZE};
+use super::SftpService;
+
+/// 暂停时的检查间隔
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+impl SftpService {
+    /// 将本服务器上的文件流式复制到目标服务器
+    ///
+    /// # Arguments
+    /// * `target` - 目标服务器的 SFTP 服务
+    /// * `source_path` - 本服务器上的源文件路径
+    /// * `target_path` - 目标服务器上的完整路径（已存在则覆盖）
+    /// * `pause_flag` - 暂停标志，置位期间停止读写
+    /// * `progress_callback` - 进度回调函数，参数为 (已传输字节数, 总字节数, 速度bytes/s)
+    pub async fn copy_file_to<F>(
+        &self,
+        target: &SftpService,
+        source_path: &str,
+        target_path: &str,
+        pause_flag: Arc<AtomicBool>,
+        progress_callback: F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64) + Send + 'static,
+    {
+        info!(
+            "[SFTP] Copying {}:{} -> {}:{}",
+            self.session_id(),
+            source_path,
+            target.session_id(),
+            target_path
+        );
+
+        let attrs = self
+            .sftp()?
+            .metadata(source_path)
+            .await
+            .map_err(|e| format!("Failed to get file metadata: {}", e))?;
+        if attrs.is_dir() {
+            return Err("Cannot copy a directory as a file".to_string());
+        }
+        let total_size = attrs.size.unwrap_or(0);
+
+        let mut source_file = self
+            .sftp()?
+            .open(source_path)
+            .await
+            .map_err(|e| format!("Failed to open source file: {}", e))?;
+        let mut target_file = target
+            .sftp()?
+            .create(target_path)
+            .await
+            .map_err(|e| format!("Failed to create target file: {}", e))?;
+
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut bytes_transferred: u64 = 0;
+        let mut meter = SpeedMeter::new();
+
+        loop {
+            while pause_flag.load(Ordering::Relaxed) {
+                tokio::time::sleep(PAUSE_POLL_INTERVAL).await;
+                meter.reset(bytes_transferred);
+            }
+
+            let bytes_read = source_file
+                .read(&mut buffer)
+                .await
+                .map_err(|e| format!("Failed to read from source file: {}", e))?;
+            if bytes_read == 0 {
+                break;
+            }
+
+            target_file
+                .write_all(&buffer[..bytes_read])
+                .await
+                .map_err(|e| format!("Failed to write to target file: {}", e))?;
+
+            bytes_transferred += bytes_read as u64;
+
+            let speed = meter.update(bytes_transferred);
+            progress_callback(bytes_transferred, total_size, speed);
+        }
+
+        // 确保所有数据都写入目标服务器
+        target_file
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to flush target file: {}", e))?;
+
+        info!(
+            "[SFTP] Copy completed: {} ({} bytes)",
+            source_path, bytes_transferred
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// SFTP 断点续传：未完成的传输先写入带 `.part` 后缀的部分文件，完成后再改名为目标文件
+// 失败或取消时部分文件只保留已确认写入的前缀，下次传输从该位置继续
+
+use std::path::{Path, PathBuf};
+
+use russh_sftp::protocol::FileAttributes;
+use tracing::{debug, info};
+
+use super::SftpService;
+use crate::services::remote_exec::{exec_command, shell_quote};
+
+/// 部分文件的后缀
+pub const PART_SUFFIX: &str = ".part";
+
+/// 本地目标文件对应的部分文件路径
+pub fn local_part_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(PART_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// 远程目标文件对应的部分文件路径
+pub fn remote_part_path(path: &str) -> String {
+    format!("{}{}", path, PART_SUFFIX)
+}
+
+/// 根据已有部分文件的大小确定续传起点
+///
+/// 部分文件比源文件还大时说明源文件已变化，只能从头开始
+pub fn resume_offset(existing: Option<u64>, total: u64) -> u64 {
+    match existing {
+        Some(size) if size <= total => size,
+        _ => 0,
+    }
+}
+
+/// 本地部分文件的续传起点
+pub async fn local_resume_offset(part_path: &Path, total: u64) -> u64 {
+    let existing = tokio::fs::metadata(part_path)
+        .await
+        .ok()
+        .filter(|m| m.is_file())
+        .map(|m| m.len());
+    let offset = resume_offset(existing, total);
+    if offset > 0 {
+        info!("[SFTP] Resuming {:?} from {} bytes", part_path, offset);
+    }
+    offset
+}
+
+/// 把本地部分文件截断到已确认的长度，或在不续传时删除
+pub async fn keep_local_part(part_path: &Path, confirmed: u64, resume: bool) {
+    if !resume {
+        let _ = tokio::fs::remove_file(part_path).await;
+        return;
+    }
+    debug!("[SFTP] Keeping {:?} at {} bytes", part_path, confirmed);
+    if let Ok(file) = tokio::fs::OpenOptions::new()
+        .write(true)
+        .open(part_path)
+        .await
+    {
+        let _ = file.set_len(confirmed).await;
+    }
+}
+
+/// 用部分文件替换本地目标文件
+pub async fn finish_local_part(part_path: &Path, local_path: &Path) -> Result<(), String> {
+    tokio::fs::rename(part_path, local_path)
+        .await
+        .map_err(|e| format!("Failed to rename {:?}: {}", part_path, e))
+}
+
+impl SftpService {
+    /// 远程部分文件的续传起点
+    pub async fn remote_resume_offset(&self, part_path: &str, total: u64) -> u64 {
+        let existing = self
+            .stat(part_path)
+            .await
+            .ok()
+            .filter(|e| !e.is_dir())
+            .map(|e| e.size);
+        let offset = resume_offset(existing, total);
+        if offset > 0 {
+            info!("[SFTP] Resuming {} from {} bytes", part_path, offset);
+        }
+        offset
+    }
+
+    /// 把远程文件截断到指定长度
+    pub async fn truncate(&self, path: &str, size: u64) -> Result<(), String> {
+        debug!("[SFTP] Truncating {} to {} bytes", path, size);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("truncate -s {} -- {}", size, shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to truncate {}: {}", path, e));
+        }
+        let mut attrs = FileAttributes::empty();
+        attrs.size = Some(size);
+        self.sftp()?
+            .set_metadata(path, attrs)
+            .await
+            .map_err(|e| format!("Failed to truncate {}: {}", path, e))
+    }
+
+    /// 把远程部分文件截断到已确认的长度，或在不续传时删除
+    pub async fn keep_remote_part(&self, part_path: &str, confirmed: u64, resume: bool) {
+        if resume {
+            let _ = self.truncate(part_path, confirmed).await;
+        } else {
+            let _ = self.remove_file(part_path).await;
+        }
+    }
+
+    /// 用部分文件替换远程目标文件
+    ///
+    /// SFTP 的重命名不会覆盖已有文件，需要先删除目标
+    pub async fn finish_remote_part(
+        &self,
+        part_path: &str,
+        remote_path: &str,
+    ) -> Result<(), String> {
+        let _ = self.remove_file(remote_path).await;
+        self.rename(part_path, remote_path).await
+    }
+}
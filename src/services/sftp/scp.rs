@@ -0,0 +1,427 @@
+// SCP 回退模式
+// 服务器禁用 SFTP 子系统时，通过 exec 通道执行 `scp -f` / `scp -t` 传输文件，
+// 目录列表使用 `ls`，其余文件操作使用对应的 shell 命令
+
+use std::path::Path;
+use std::time::Instant;
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+use super::recovery::parse_ls_output;
+use super::resume::{finish_local_part, keep_local_part, local_part_path, remote_part_path};
+use super::service::{cancelled_error, SftpService};
+use crate::models::sftp::FileEntry;
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::services::transfer::Throttle;
+use crate::ssh::session::SshSession;
+
+/// 传输块大小 (256KB)，压缩传输与跨服务器复制共用
+pub(super) const CHUNK_SIZE: usize = 256 * 1024;
+/// 上传时使用的文件权限
+const UPLOAD_MODE: u32 = 0o644;
+
+/// 远程是否可以使用 SCP 回退模式
+pub(super) async fn scp_available(ssh_session: &SshSession) -> bool {
+    exec_command(ssh_session, "command -v scp >/dev/null 2>&1")
+        .await
+        .is_ok()
+}
+
+/// 列出目录的命令（目录为符号链接时列出其指向的目录）
+fn ls_dir_command(dir: &str) -> String {
+    format!(
+        "LC_ALL=C ls -lan --time-style=+%s -- {}",
+        shell_quote(&format!("{}/", dir.trim_end_matches('/')))
+    )
+}
+
+/// 获取单个条目属性的命令：在父目录中列出该条目，输出的名称不含路径
+fn ls_entry_command(parent: &str, name: &str) -> String {
+    format!(
+        "cd -- {} && LC_ALL=C ls -land --time-style=+%s -- {}",
+        shell_quote(parent),
+        shell_quote(name)
+    )
+}
+
+/// 打开执行 scp 命令的通道，返回读写数据流（标准输出 / 标准输入）
+async fn open_scp_stream(
+    ssh_session: &SshSession,
+    command: &str,
+) -> Result<impl AsyncRead + AsyncWrite + Unpin + Send, String> {
+    let channel = ssh_session
+        .handle()
+        .channel_open_session()
+        .await
+        .map_err(|e| format!("Failed to open channel: {}", e))?;
+    channel
+        .exec(true, command)
+        .await
+        .map_err(|e| format!("Failed to start scp: {}", e))?;
+    Ok(channel.into_stream())
+}
+
+/// 读取一行协议消息（不含换行符）
+async fn read_line<S: AsyncRead + Unpin>(stream: &mut S) -> Result<String, String> {
+    let mut line = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        stream
+            .read_exact(&mut byte)
+            .await
+            .map_err(|e| format!("SCP protocol error: {}", e))?;
+        if byte[0] == b'\n' {
+            return Ok(String::from_utf8_lossy(&line).into_owned());
+        }
+        line.push(byte[0]);
+    }
+}
+
+/// 读取对端确认：0 为成功，1/2 后跟错误信息
+async fn read_ack<S: AsyncRead + Unpin>(stream: &mut S) -> Result<(), String> {
+    let mut byte = [0u8; 1];
+    stream
+        .read_exact(&mut byte)
+        .await
+        .map_err(|e| format!("SCP protocol error: {}", e))?;
+    match byte[0] {
+        0 => Ok(()),
+        _ => Err(read_line(stream).await?),
+    }
+}
+
+/// 发送确认
+async fn send_ack<S: AsyncWrite + Unpin>(stream: &mut S) -> Result<(), String> {
+    stream
+        .write_all(&[0])
+        .await
+        .map_err(|e| format!("SCP protocol error: {}", e))
+}
+
+/// 传输速度统计（每 100ms 更新一次），压缩传输与跨服务器复制共用
+pub(super) struct SpeedMeter {
+    last_update: Instant,
+    last_bytes: u64,
+    speed: u64,
+}
+
+impl SpeedMeter {
+    pub(super) fn new() -> Self {
+        Self {
+            last_update: Instant::now(),
+            last_bytes: 0,
+            speed: 0,
+        }
+    }
+
+    /// 根据已传输字节数更新并返回当前速度 (bytes/s)
+    pub(super) fn update(&mut self, transferred: u64) -> u64 {
+        let elapsed = self.last_update.elapsed();
+        if elapsed.as_millis() >= 100 {
+            self.speed = ((transferred - self.last_bytes) as f64 / elapsed.as_secs_f64()) as u64;
+            self.last_update = Instant::now();
+            self.last_bytes = transferred;
+        }
+        self.speed
+    }
+
+    /// 暂停恢复后重新开始计时，避免暂停时间拉低速度
+    pub(super) fn reset(&mut self, transferred: u64) {
+        self.last_update = Instant::now();
+        self.last_bytes = transferred;
+    }
+}
+
+/// 通过 `scp -f` 把远程文件写入本地文件，`transferred` 记录已写入的字节数
+async fn scp_receive<F>(
+    ssh_session: &SshSession,
+    remote_path: &str,
+    local_path: &Path,
+    transferred: &mut u64,
+    cancel_token: &CancellationToken,
+    throttle: &Throttle,
+    progress_callback: &F,
+) -> Result<(), String>
+where
+    F: Fn(u64, u64, u64),
+{
+    let mut stream = open_scp_stream(
+        ssh_session,
+        &format!("scp -f -- {}", shell_quote(remote_path)),
+    )
+    .await?;
+    send_ack(&mut stream).await?;
+
+    // 文件头：C<权限> <大小> <文件名>；T 开头的时间戳行跳过
+    let header = loop {
+        let mut kind = [0u8; 1];
+        stream
+            .read_exact(&mut kind)
+            .await
+            .map_err(|e| format!("SCP protocol error: {}", e))?;
+        match kind[0] {
+            b'C' => break read_line(&mut stream).await?,
+            b'T' => {
+                read_line(&mut stream).await?;
+                send_ack(&mut stream).await?;
+            }
+            b'D' => return Err("Cannot download a directory".to_string()),
+            1 | 2 => return Err(read_line(&mut stream).await?),
+            other => return Err(format!("Unexpected SCP message: {:#04x}", other)),
+        }
+    };
+    let total_size = header
+        .split(' ')
+        .nth(1)
+        .and_then(|size| size.parse::<u64>().ok())
+        .ok_or_else(|| format!("Invalid SCP header: {}", header))?;
+    send_ack(&mut stream).await?;
+
+    let mut local_file = tokio::fs::File::create(local_path)
+        .await
+        .map_err(|e| format!("Failed to create local file: {}", e))?;
+    progress_callback(0, total_size, 0);
+
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut meter = SpeedMeter::new();
+    while *transferred < total_size {
+        if cancel_token.is_cancelled() {
+            return Err(cancelled_error(false));
+        }
+        let want = (total_size - *transferred).min(CHUNK_SIZE as u64) as usize;
+        let bytes_read = stream
+            .read(&mut buffer[..want])
+            .await
+            .map_err(|e| format!("Failed to read from remote file: {}", e))?;
+        if bytes_read == 0 {
+            return Err("SCP stream ended unexpectedly".to_string());
+        }
+        throttle.consume(bytes_read as u64).await;
+        local_file
+            .write_all(&buffer[..bytes_read])
+            .await
+            .map_err(|e| format!("Failed to write to local file: {}", e))?;
+        *transferred += bytes_read as u64;
+        progress_callback(*transferred, total_size, meter.update(*transferred));
+    }
+
+    // 数据之后是发送端的状态字节
+    read_ack(&mut stream).await?;
+    send_ack(&mut stream).await?;
+    let _ = stream.shutdown().await;
+
+    local_file
+        .flush()
+        .await
+        .map_err(|e| format!("Failed to flush local file: {}", e))
+}
+
+/// 通过 `scp -t` 把数据写入远程文件
+#[allow(clippy::too_many_arguments)]
+async fn scp_send<R, F>(
+    ssh_session: &SshSession,
+    remote_path: &str,
+    mut reader: R,
+    total_size: u64,
+    transferred: &mut u64,
+    cancel_token: &CancellationToken,
+    throttle: Option<&Throttle>,
+    progress_callback: &F,
+) -> Result<(), String>
+where
+    R: AsyncRead + Unpin,
+    F: Fn(u64, u64, u64),
+{
+    let mut stream = open_scp_stream(
+        ssh_session,
+        &format!("scp -t -- {}", shell_quote(remote_path)),
+    )
+    .await?;
+    read_ack(&mut stream).await?;
+
+    let name = remote_path.rsplit('/').next().unwrap_or(remote_path);
+    stream
+        .write_all(format!("C{:04o} {} {}\n", UPLOAD_MODE, total_size, name).as_bytes())
+        .await
+        .map_err(|e| format!("SCP protocol error: {}", e))?;
+    read_ack(&mut stream).await?;
+    progress_callback(0, total_size, 0);
+
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut meter = SpeedMeter::new();
+    while *transferred < total_size {
+        if cancel_token.is_cancelled() {
+            return Err(cancelled_error(true));
+        }
+        let want = (total_size - *transferred).min(CHUNK_SIZE as u64) as usize;
+        let bytes_read = reader
+            .read(&mut buffer[..want])
+            .await
+            .map_err(|e| format!("Failed to read from local file: {}", e))?;
+        if bytes_read == 0 {
+            return Err("Local file changed during upload".to_string());
+        }
+        if let Some(throttle) = throttle {
+            throttle.consume(bytes_read as u64).await;
+        }
+        stream
+            .write_all(&buffer[..bytes_read])
+            .await
+            .map_err(|e| format!("Failed to write to remote file: {}", e))?;
+        *transferred += bytes_read as u64;
+        progress_callback(*transferred, total_size, meter.update(*transferred));
+    }
+
+    send_ack(&mut stream).await?;
+    read_ack(&mut stream).await?;
+    let _ = stream.shutdown().await;
+    Ok(())
+}
+
+impl SftpService {
+    /// SCP 模式：通过 `ls` 读取目录
+    pub(super) async fn scp_read_dir(
+        ssh_session: &SshSession,
+        path: &str,
+    ) -> Result<Vec<FileEntry>, String> {
+        let stdout = exec_command(ssh_session, &ls_dir_command(path)).await?;
+        Ok(parse_ls_output(path, &stdout))
+    }
+
+    /// SCP 模式：通过 `ls -d` 获取单个条目的属性
+    pub(super) async fn scp_stat(
+        ssh_session: &SshSession,
+        path: &str,
+    ) -> Result<FileEntry, String> {
+        let (parent, name) = match path.trim_end_matches('/').rsplit_once('/') {
+            Some(("", name)) => ("/", name),
+            Some((parent, name)) => (parent, name),
+            None => (".", path),
+        };
+        if name.is_empty() {
+            // 根目录
+            let mut entry = FileEntry::new(
+                "/".to_string(),
+                "/".to_string(),
+                crate::models::sftp::FileType::Directory,
+            );
+            entry.permissions = 0o755;
+            return Ok(entry);
+        }
+        let stdout = exec_command(ssh_session, &ls_entry_command(parent, name)).await?;
+        parse_ls_output(parent, &stdout)
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No such file: {}", path))
+    }
+
+    /// SCP 模式：把内容写入远程文件
+    pub(super) async fn scp_write(
+        ssh_session: &SshSession,
+        path: &str,
+        content: &[u8],
+    ) -> Result<(), String> {
+        let mut transferred = 0;
+        scp_send(
+            ssh_session,
+            path,
+            content,
+            content.len() as u64,
+            &mut transferred,
+            &CancellationToken::new(),
+            None,
+            &|_, _, _| {},
+        )
+        .await
+    }
+
+    /// SCP 模式下载：写入本地部分文件，完成后改名为目标文件（不支持续传）
+    pub(super) async fn scp_download_file<F>(
+        ssh_session: &SshSession,
+        remote_path: &str,
+        local_path: &Path,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64),
+    {
+        info!(
+            "[SCP] Downloading file: {} -> {:?}",
+            remote_path, local_path
+        );
+        let part_path = local_part_path(local_path);
+        let mut transferred = 0;
+        let result = scp_receive(
+            ssh_session,
+            remote_path,
+            &part_path,
+            &mut transferred,
+            cancel_token,
+            throttle,
+            progress_callback,
+        )
+        .await;
+        if let Err(e) = result {
+            keep_local_part(&part_path, transferred, false).await;
+            return Err(e);
+        }
+        finish_local_part(&part_path, local_path).await?;
+        debug!(
+            "[SCP] Download completed: {} ({} bytes)",
+            remote_path, transferred
+        );
+        Ok(())
+    }
+
+    /// SCP 模式上传：写入远程部分文件，完成后改名为目标文件（不支持续传）
+    pub(super) async fn scp_upload_file<F>(
+        &self,
+        ssh_session: &SshSession,
+        local_path: &Path,
+        remote_path: &str,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64),
+    {
+        info!("[SCP] Uploading file: {:?} -> {}", local_path, remote_path);
+        let local_file = tokio::fs::File::open(local_path)
+            .await
+            .map_err(|e| format!("Failed to open local file: {}", e))?;
+        let total_size = local_file
+            .metadata()
+            .await
+            .map_err(|e| format!("Failed to get local file metadata: {}", e))?
+            .len();
+
+        let part_path = remote_part_path(remote_path);
+        let mut transferred = 0;
+        let result = scp_send(
+            ssh_session,
+            &part_path,
+            local_file,
+            total_size,
+            &mut transferred,
+            cancel_token,
+            Some(throttle),
+            progress_callback,
+        )
+        .await;
+        if let Err(e) = result {
+            let _ = self.remove_file(&part_path).await;
+            return Err(e);
+        }
+        self.finish_remote_part(&part_path, remote_path).await?;
+        debug!(
+            "[SCP] Upload completed: {:?} ({} bytes)",
+            local_path, transferred
+        );
+        Ok(())
+    }
+}
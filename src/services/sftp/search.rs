@@ -0,0 +1,109 @@
+// SFTP 远程文件搜索
+// 通过远程 `find` / `grep` 命令按名称或内容查找文件，结果按行流式返回
+
+use crate::services::remote_exec::shell_quote;
+
+/// 单次搜索最多返回的结果数量
+pub const MAX_SEARCH_RESULTS: usize = 1000;
+
+/// 名称/内容匹配模式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchMatchMode {
+    /// 通配符（如 `*.log`），内容搜索时按纯文本匹配
+    #[default]
+    Glob,
+    /// 扩展正则表达式
+    Regex,
+}
+
+/// 搜索条件
+#[derive(Clone, Debug)]
+pub struct SearchQuery {
+    /// 搜索根目录
+    pub root: String,
+    /// 文件名模式（为空时匹配所有文件）
+    pub name_pattern: String,
+    /// 文件内容模式（为空时只按名称搜索）
+    pub content_pattern: String,
+    /// 匹配模式
+    pub mode: SearchMatchMode,
+}
+
+/// 搜索结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchHit {
+    /// 文件完整路径
+    pub path: String,
+    /// 匹配行号（仅内容搜索）
+    pub line: Option<u32>,
+    /// 匹配行文本（仅内容搜索）
+    pub text: Option<String>,
+}
+
+impl SearchQuery {
+    /// 是否为内容搜索
+    pub fn is_content_search(&self) -> bool {
+        !self.content_pattern.is_empty()
+    }
+
+    /// 构建远程执行的 shell 命令
+    pub fn to_command(&self) -> String {
+        let root = shell_quote(&self.root);
+        let name = self.name_pattern.trim();
+
+        // 通配符名称交给 find 匹配；正则名称用 awk 匹配最后一级路径
+        let mut find = format!("find {} -mindepth 1", root);
+        if self.is_content_search() {
+            find.push_str(" -type f");
+        }
+        if !name.is_empty() && self.mode == SearchMatchMode::Glob {
+            find.push_str(&format!(" -name {}", shell_quote(name)));
+        }
+        find.push_str(" 2>/dev/null");
+        if !name.is_empty() && self.mode == SearchMatchMode::Regex {
+            find.push_str(&format!(
+                " | RE={} awk -F/ '$NF ~ ENVIRON[\"RE\"]'",
+                shell_quote(name)
+            ));
+        }
+
+        if !self.is_content_search() {
+            return find;
+        }
+
+        // -Z 使文件名后以 NUL 分隔，避免文件名中的冒号干扰解析
+        let grep_flag = match self.mode {
+            SearchMatchMode::Glob => "-F",
+            SearchMatchMode::Regex => "-E",
+        };
+        format!(
+            "{} | xargs -r -d '\\n' grep -nIHZ {} -e {} 2>/dev/null",
+            find,
+            grep_flag,
+            shell_quote(&self.content_pattern)
+        )
+    }
+
+    /// 解析命令输出的一行
+    pub fn parse_line(&self, line: &str) -> Option<SearchHit> {
+        if line.is_empty() {
+            return None;
+        }
+        if !self.is_content_search() {
+            return Some(SearchHit {
+                path: line.to_string(),
+                line: None,
+                text: None,
+            });
+        }
+
+        // 格式：路径\0行号:内容
+        let (path, rest) = line.split_once('\0')?;
+        let (line_no, text) = rest.split_once(':')?;
+        Some(SearchHit {
+            path: path.to_string(),
+            line: line_no.parse().ok(),
+            text: Some(text.trim().to_string()),
+        })
+    }
+}
@@ -3,9 +3,18 @@
 use std::sync::Arc;
 
 use russh_sftp::client::SftpSession;
-use tracing::{debug, error, info};
-
+use russh_sftp::protocol::FileAttributes;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+
+use super::channel_pool::{open_sftp_channel, ChannelPool};
+use super::resume::{
+    finish_local_part, keep_local_part, local_part_path, local_resume_offset, remote_part_path,
+};
+use super::scp::scp_available;
 use crate::models::sftp::{FileEntry, FileType};
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::services::transfer::Throttle;
 use crate::ssh::session::SshSession;
 
 /// SFTP 服务
@@ -14,38 +23,47 @@ use crate::ssh::session::SshSession;
 pub struct SftpService {
     /// 会话 ID
     session_id: String,
+    /// 文件操作后端
+    backend: Backend,
+    /// 额外的元数据通道（目录读取与 stat 并行使用）
+    pool: Arc<ChannelPool>,
+}
+
+/// 文件操作后端
+#[derive(Clone)]
+enum Backend {
     /// russh-sftp 客户端会话（线程安全）
-    sftp: Arc<SftpSession>,
+    Sftp(Arc<SftpSession>),
+    /// SFTP 子系统不可用时的回退模式：传输使用 scp，其余操作使用 shell 命令
+    Scp(Arc<SshSession>),
 }
 
 impl SftpService {
     /// 创建 SFTP 服务
+    ///
+    /// 服务器禁用 SFTP 子系统但提供 scp 时，回退到 SCP 模式
     pub async fn new(session_id: String, ssh_session: &Arc<SshSession>) -> Result<Self, String> {
         info!("[SFTP] Creating SFTP service for session {}", session_id);
 
-        // 打开 SFTP 子系统通道
-        let channel = ssh_session
-            .handle()
-            .channel_open_session()
-            .await
-            .map_err(|e| format!("Failed to open channel: {}", e))?;
-
-        // 请求 SFTP 子系统
-        channel
-            .request_subsystem(true, "sftp")
-            .await
-            .map_err(|e| format!("Failed to request sftp subsystem: {}", e))?;
-
-        // 使用 russh-sftp 包装通道
-        let sftp = SftpSession::new(channel.into_stream())
-            .await
-            .map_err(|e| format!("Failed to create SFTP session: {}", e))?;
+        // 打开 SFTP 子系统通道并使用 russh-sftp 包装
+        let backend = match open_sftp_channel(ssh_session).await {
+            Ok(sftp) => Backend::Sftp(Arc::new(sftp)),
+            Err(e) if scp_available(ssh_session).await => {
+                warn!(
+                    "[SFTP] SFTP subsystem unavailable for session {} ({}), falling back to SCP",
+                    session_id, e
+                );
+                Backend::Scp(ssh_session.clone())
+            }
+            Err(e) => return Err(e),
+        };
 
         info!("[SFTP] SFTP service created for session {}", session_id);
 
         Ok(Self {
             session_id,
-            sftp: Arc::new(sftp),
+            backend,
+            pool: Arc::new(ChannelPool::default()),
         })
     }
 
@@ -54,15 +72,42 @@ impl SftpService {
         &self.session_id
     }
 
-    /// 获取 SFTP 会话引用（用于并发操作）
-    pub fn sftp(&self) -> Arc<SftpSession> {
-        self.sftp.clone()
+    /// 获取 SFTP 会话引用（用于并发操作；SCP 回退模式下不可用）
+    pub fn sftp(&self) -> Result<Arc<SftpSession>, String> {
+        match &self.backend {
+            Backend::Sftp(sftp) => Ok(sftp.clone()),
+            Backend::Scp(_) => Err("Not supported in SCP fallback mode".to_string()),
+        }
+    }
+
+    /// SCP 回退模式下使用的 SSH 会话
+    pub(super) fn scp_session(&self) -> Option<&Arc<SshSession>> {
+        match &self.backend {
+            Backend::Sftp(_) => None,
+            Backend::Scp(ssh_session) => Some(ssh_session),
+        }
+    }
+
+    /// 是否处于 SCP 回退模式
+    pub fn is_scp_fallback(&self) -> bool {
+        self.scp_session().is_some()
+    }
+
+    /// 获取元数据通道池
+    pub(super) fn pool(&self) -> Arc<ChannelPool> {
+        self.pool.clone()
     }
 
     /// 获取用户主目录
     pub async fn get_home_dir(&self) -> Result<String, String> {
         // 尝试通过 realpath 获取 ~ 的真实路径
-        match self.sftp.canonicalize(".").await {
+        let home = match &self.backend {
+            Backend::Sftp(sftp) => sftp.canonicalize(".").await.map_err(|e| e.to_string()),
+            Backend::Scp(ssh_session) => exec_command(ssh_session, "pwd")
+                .await
+                .map(|stdout| stdout.trim().to_string()),
+        };
+        match home {
             Ok(path) => {
                 // russh-sftp canonicalize 返回 String
                 debug!("[SFTP] Home directory: {}", path);
@@ -79,10 +124,17 @@ impl SftpService {
     /// 读取目录内容
     pub async fn read_dir(&self, path: &str) -> Result<Vec<FileEntry>, String> {
         debug!("[SFTP] Reading directory: {}", path);
+        if let Some(ssh_session) = self.scp_session() {
+            return Self::scp_read_dir(ssh_session, path)
+                .await
+                .map_err(|e| format!("Failed to read directory {}: {}", path, e));
+        }
 
-        let dir = self
-            .sftp
-            .read_dir(path)
+        // 符号链接目标在同一通道上读取
+        let (dir, sftp) = self
+            .with_metadata_channel(self.sftp()?, |sftp| async move {
+                sftp.read_dir(path).await.map(|dir| (dir, sftp))
+            })
             .await
             .map_err(|e| format!("Failed to read directory {}: {}", path, e))?;
 
@@ -95,34 +147,11 @@ impl SftpService {
                 continue;
             }
 
-            let full_path = if path == "/" {
-                format!("/{}", name)
-            } else {
-                format!("{}/{}", path.trim_end_matches('/'), name)
-            };
-
-            let attrs = entry.metadata();
-
-            // 确定文件类型
-            let file_type = if attrs.is_dir() {
-                FileType::Directory
-            } else if attrs.is_symlink() {
-                FileType::Symlink
-            } else {
-                // russh-sftp 没有 is_file()，默认为普通文件
-                FileType::File
-            };
+            let mut file_entry = file_entry_from_attrs(path, &name, &entry.metadata());
 
-            let mut file_entry = FileEntry::new(name.to_string(), full_path, file_type);
-            file_entry.size = attrs.size.unwrap_or(0);
-            file_entry.permissions = attrs.permissions.map(|p| p as u32).unwrap_or(0);
-            file_entry.uid = attrs.uid;
-            file_entry.gid = attrs.gid;
-
-            // 修改时间
-            if let Some(mtime) = attrs.mtime {
-                file_entry.modified =
-                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(mtime as u64));
+            // 符号链接目标
+            if file_entry.file_type == FileType::Symlink {
+                file_entry.link_target = sftp.read_link(file_entry.path.as_str()).await.ok();
             }
 
             entries.push(file_entry);
@@ -135,9 +164,14 @@ impl SftpService {
     /// 读取文件内容（用于读取 /etc/passwd 和 /etc/group）
     pub async fn read_file(&self, path: &str) -> Result<String, String> {
         debug!("[SFTP] Reading file: {}", path);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("cat -- {}", shell_quote(path)))
+                .await
+                .map_err(|e| format!("Failed to read file {}: {}", path, e));
+        }
 
         let mut file = self
-            .sftp
+            .sftp()?
             .open(path)
             .await
             .map_err(|e| format!("Failed to open file {}: {}", path, e))?;
@@ -152,10 +186,116 @@ impl SftpService {
         Ok(content)
     }
 
+    /// 读取文件开头最多 `max_bytes` 字节（用于预览，不下载整个文件）
+    pub async fn read_head(&self, path: &str, max_bytes: usize) -> Result<Vec<u8>, String> {
+        debug!("[SFTP] Reading first {} bytes of {}", max_bytes, path);
+
+        let file = self
+            .sftp()?
+            .open(path)
+            .await
+            .map_err(|e| format!("Failed to open file {}: {}", path, e))?;
+
+        use tokio::io::AsyncReadExt;
+        let mut content = Vec::with_capacity(max_bytes.min(64 * 1024));
+        file.take(max_bytes as u64)
+            .read_to_end(&mut content)
+            .await
+            .map_err(|e| format!("Failed to read file {}: {}", path, e))?;
+
+        debug!("[SFTP] Read {} bytes from {}", content.len(), path);
+        Ok(content)
+    }
+
+    /// 读取文件中从 `offset` 开始的最多 `len` 字节（十六进制查看器按需读取）
+    pub async fn read_range(&self, path: &str, offset: u64, len: usize) -> Result<Vec<u8>, String> {
+        debug!("[SFTP] Reading {} bytes at {} of {}", len, offset, path);
+
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+        let mut file = self
+            .sftp()?
+            .open(path)
+            .await
+            .map_err(|e| format!("Failed to open file {}: {}", path, e))?;
+        file.seek(std::io::SeekFrom::Start(offset))
+            .await
+            .map_err(|e| format!("Failed to seek file {}: {}", path, e))?;
+
+        let mut content = Vec::with_capacity(len);
+        file.take(len as u64)
+            .read_to_end(&mut content)
+            .await
+            .map_err(|e| format!("Failed to read file {}: {}", path, e))?;
+        Ok(content)
+    }
+
+    /// 从 `start` 开始向后查找字节序列，返回首次出现的偏移量
+    ///
+    /// 按块流式读取，相邻块之间保留 `pattern.len() - 1` 字节的重叠，跨块的匹配也能找到
+    pub async fn find_bytes(
+        &self,
+        path: &str,
+        start: u64,
+        pattern: &[u8],
+        cancel_token: CancellationToken,
+    ) -> Result<Option<u64>, String> {
+        const CHUNK_SIZE: usize = 256 * 1024;
+        if pattern.is_empty() {
+            return Ok(None);
+        }
+        info!(
+            "[SFTP] Searching {} bytes in {} from {}",
+            pattern.len(),
+            path,
+            start
+        );
+
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+        let mut file = self
+            .sftp()?
+            .open(path)
+            .await
+            .map_err(|e| format!("Failed to open file {}: {}", path, e))?;
+        file.seek(std::io::SeekFrom::Start(start))
+            .await
+            .map_err(|e| format!("Failed to seek file {}: {}", path, e))?;
+
+        // window 的第一个字节对应文件中的 window_offset
+        let mut window: Vec<u8> = Vec::with_capacity(CHUNK_SIZE + pattern.len());
+        let mut window_offset = start;
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        loop {
+            if cancel_token.is_cancelled() {
+                return Err("Search cancelled".to_string());
+            }
+            let n = file
+                .read(&mut chunk)
+                .await
+                .map_err(|e| format!("Failed to read file {}: {}", path, e))?;
+            if n == 0 {
+                return Ok(None);
+            }
+            window.extend_from_slice(&chunk[..n]);
+            if let Some(pos) = window.windows(pattern.len()).position(|w| w == pattern) {
+                return Ok(Some(window_offset + pos as u64));
+            }
+            let keep = (pattern.len() - 1).min(window.len());
+            let drop = window.len() - keep;
+            window.drain(..drop);
+            window_offset += drop as u64;
+        }
+    }
+
     /// 创建目录
     pub async fn mkdir(&self, path: &str) -> Result<(), String> {
         info!("[SFTP] Creating directory: {}", path);
-        self.sftp
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("mkdir -- {}", shell_quote(path)))
+                .await
+                .map(drop)
+                .map_err(|e| format!("Failed to create directory {}: {}", path, e));
+        }
+        self.sftp()?
             .create_dir(path)
             .await
             .map_err(|e| format!("Failed to create directory {}: {}", path, e))
@@ -164,9 +304,15 @@ impl SftpService {
     /// 创建空文件
     pub async fn create_file(&self, path: &str) -> Result<(), String> {
         info!("[SFTP] Creating file: {}", path);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("touch -- {}", shell_quote(path)))
+                .await
+                .map(drop)
+                .map_err(|e| format!("Failed to create file {}: {}", path, e));
+        }
         use tokio::io::AsyncWriteExt;
         let mut file = self
-            .sftp
+            .sftp()?
             .create(path)
             .await
             .map_err(|e| format!("Failed to create file {}: {}", path, e))?;
@@ -179,7 +325,7 @@ impl SftpService {
     /// 打开远程文件（用于读取）
     pub async fn open(&self, path: &str) -> Result<russh_sftp::client::fs::File, String> {
         debug!("[SFTP] Opening file: {}", path);
-        self.sftp
+        self.sftp()?
             .open(path)
             .await
             .map_err(|e| format!("Failed to open file {}: {}", path, e))
@@ -188,10 +334,15 @@ impl SftpService {
     /// 写入文件内容
     pub async fn write_file(&self, path: &str, content: &[u8]) -> Result<(), String> {
         info!("[SFTP] Writing file: {} ({} bytes)", path, content.len());
+        if let Some(ssh_session) = self.scp_session() {
+            return Self::scp_write(ssh_session, path, content)
+                .await
+                .map_err(|e| format!("Failed to write to file {}: {}", path, e));
+        }
         use tokio::io::AsyncWriteExt;
 
         let mut file = self
-            .sftp
+            .sftp()?
             .create(path)
             .await
             .map_err(|e| format!("Failed to create file {}: {}", path, e))?;
@@ -210,16 +361,64 @@ impl SftpService {
     /// 读取符号链接目标
     pub async fn read_link(&self, path: &str) -> Result<String, String> {
         info!("[SFTP] Reading symlink target: {}", path);
-        self.sftp
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("readlink -- {}", shell_quote(path)))
+                .await
+                .map(|stdout| stdout.trim_end_matches('\n').to_string())
+                .map_err(|e| format!("Failed to read link {}: {}", path, e));
+        }
+        self.sftp()?
             .read_link(path)
             .await
             .map_err(|e| format!("Failed to read link {}: {}", path, e))
     }
 
+    /// 创建符号链接 `path` -> `target`
+    pub async fn symlink(&self, path: &str, target: &str) -> Result<(), String> {
+        info!("[SFTP] Creating symlink: {} -> {}", path, target);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("ln -s -- {} {}", shell_quote(target), shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to create symlink {}: {}", path, e));
+        }
+        self.sftp()?
+            .symlink(path, target)
+            .await
+            .map_err(|e| format!("Failed to create symlink {}: {}", path, e))
+    }
+
+    /// 创建硬链接 `path`，与 `target` 指向同一文件（需服务器支持 hardlink@openssh.com）
+    pub async fn hard_link(&self, path: &str, target: &str) -> Result<(), String> {
+        info!("[SFTP] Creating hard link: {} -> {}", path, target);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("ln -- {} {}", shell_quote(target), shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to create hard link {}: {}", path, e));
+        }
+        self.sftp()?
+            .hardlink(target, path)
+            .await
+            .map_err(|e| format!("Failed to create hard link {}: {}", path, e))
+    }
+
     /// 删除文件
     pub async fn remove_file(&self, path: &str) -> Result<(), String> {
         info!("[SFTP] Removing file: {}", path);
-        self.sftp
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("rm -f -- {}", shell_quote(path)))
+                .await
+                .map(drop)
+                .map_err(|e| format!("Failed to remove file {}: {}", path, e));
+        }
+        self.sftp()?
             .remove_file(path)
             .await
             .map_err(|e| format!("Failed to remove file {}: {}", path, e))
@@ -228,7 +427,13 @@ impl SftpService {
     /// 删除目录
     pub async fn remove_dir(&self, path: &str) -> Result<(), String> {
         info!("[SFTP] Removing directory: {}", path);
-        self.sftp
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("rmdir -- {}", shell_quote(path)))
+                .await
+                .map(drop)
+                .map_err(|e| format!("Failed to remove directory {}: {}", path, e));
+        }
+        self.sftp()?
             .remove_dir(path)
             .await
             .map_err(|e| format!("Failed to remove directory {}: {}", path, e))
@@ -237,19 +442,98 @@ impl SftpService {
     /// 重命名文件或目录
     pub async fn rename(&self, from: &str, to: &str) -> Result<(), String> {
         info!("[SFTP] Renaming {} -> {}", from, to);
-        self.sftp
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("mv -- {} {}", shell_quote(from), shell_quote(to)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to rename {} to {}: {}", from, to, e));
+        }
+        self.sftp()?
             .rename(from, to)
             .await
             .map_err(|e| format!("Failed to rename {} to {}: {}", from, to, e))
     }
 
+    /// 修改文件/目录权限
+    pub async fn chmod(&self, path: &str, mode: u32) -> Result<(), String> {
+        info!("[SFTP] Changing mode of {} to {:o}", path, mode);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("chmod {:o} -- {}", mode & 0o7777, shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to chmod {}: {}", path, e));
+        }
+        let mut attrs = FileAttributes::empty();
+        attrs.permissions = Some(mode);
+        self.sftp()?
+            .set_metadata(path, attrs)
+            .await
+            .map_err(|e| format!("Failed to chmod {}: {}", path, e))
+    }
+
+    /// 修改文件/目录所有者
+    pub async fn chown(&self, path: &str, uid: u32, gid: u32) -> Result<(), String> {
+        info!("[SFTP] Changing owner of {} to {}:{}", path, uid, gid);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("chown {}:{} -- {}", uid, gid, shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to chown {}: {}", path, e));
+        }
+        let mut attrs = FileAttributes::empty();
+        attrs.uid = Some(uid);
+        attrs.gid = Some(gid);
+        self.sftp()?
+            .set_metadata(path, attrs)
+            .await
+            .map_err(|e| format!("Failed to chown {}: {}", path, e))
+    }
+
+    /// 修改文件的访问/修改时间（同步后保持两侧时间一致）
+    pub async fn set_mtime(&self, path: &str, mtime: u32) -> Result<(), String> {
+        debug!("[SFTP] Setting mtime of {} to {}", path, mtime);
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(
+                ssh_session,
+                &format!("touch -d @{} -- {}", mtime, shell_quote(path)),
+            )
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to set mtime of {}: {}", path, e));
+        }
+        let mut attrs = FileAttributes::empty();
+        attrs.atime = Some(mtime);
+        attrs.mtime = Some(mtime);
+        self.sftp()?
+            .set_metadata(path, attrs)
+            .await
+            .map_err(|e| format!("Failed to set mtime of {}: {}", path, e))
+    }
+
     /// 获取文件/目录属性
     pub async fn stat(&self, path: &str) -> Result<FileEntry, String> {
         debug!("[SFTP] Getting stat for: {}", path);
 
+        if let Some(ssh_session) = self.scp_session() {
+            return Self::scp_stat(ssh_session, path)
+                .await
+                .map_err(|e| format!("Failed to stat {}: {}", path, e));
+        }
+
         let attrs = self
-            .sftp
-            .metadata(path)
+            .with_metadata_channel(
+                self.sftp()?,
+                |sftp| async move { sftp.metadata(path).await },
+            )
             .await
             .map_err(|e| format!("Failed to stat {}: {}", path, e))?;
 
@@ -280,11 +564,13 @@ impl SftpService {
 
     /// 递归读取目录，返回所有文件条目（包含完整路径）
     ///
+    /// 同一层级的子目录通过元数据通道池并行读取
+    ///
     /// # Arguments
     /// * `path` - 要遍历的目录路径
     ///
     /// # Returns
-    /// * `Ok(Vec<FileEntry>)` - 所有文件和目录的列表（深度优先）
+    /// * `Ok(Vec<FileEntry>)` - 所有文件和目录的列表（按层级，父目录总在其子项之前）
     /// * `Err(String)` - 读取失败
     pub async fn read_dir_recursive(&self, path: &str) -> Result<Vec<FileEntry>, String> {
         info!("[SFTP] Reading directory recursively: {}", path);
@@ -292,15 +578,16 @@ impl SftpService {
         let mut all_entries = Vec::new();
         let mut dirs_to_process = vec![path.to_string()];
 
-        while let Some(current_dir) = dirs_to_process.pop() {
-            let entries = self.read_dir(&current_dir).await?;
-
-            for entry in entries {
-                if entry.is_dir() {
-                    // 将子目录加入待处理队列
-                    dirs_to_process.push(entry.path.clone());
+        while !dirs_to_process.is_empty() {
+            let level = std::mem::take(&mut dirs_to_process);
+            for (_, result) in self.read_dirs(level).await {
+                for entry in result? {
+                    if entry.is_dir() {
+                        // 将子目录加入下一层待处理队列
+                        dirs_to_process.push(entry.path.clone());
+                    }
+                    all_entries.push(entry);
                 }
-                all_entries.push(entry);
             }
         }
 
@@ -323,6 +610,13 @@ impl SftpService {
     pub async fn mkdir_recursive(&self, path: &str) -> Result<(), String> {
         info!("[SFTP] Creating directory recursively: {}", path);
 
+        if let Some(ssh_session) = self.scp_session() {
+            return exec_command(ssh_session, &format!("mkdir -p -- {}", shell_quote(path)))
+                .await
+                .map(drop)
+                .map_err(|e| format!("Failed to create directory {}: {}", path, e));
+        }
+
         // 收集需要创建的所有路径段
         let mut paths_to_create = Vec::new();
         let mut current = path.to_string();
@@ -330,7 +624,7 @@ impl SftpService {
         // 从目标路径向上遍历，找出所有不存在的目录
         while !current.is_empty() && current != "/" {
             // 检查目录是否存在
-            match self.sftp.metadata(&current).await {
+            match self.sftp()?.metadata(&current).await {
                 Ok(attrs) => {
                     if attrs.is_dir() {
                         // 目录已存在，停止向上遍历
@@ -369,9 +663,14 @@ impl SftpService {
 
     /// 下载文件到本地
     ///
+    /// 数据先写入 `.part` 部分文件，完成后再改名为目标文件
+    ///
     /// # Arguments
     /// * `remote_path` - 远程文件路径
     /// * `local_path` - 本地保存路径
+    /// * `resume` - 是否从已有部分文件续传，并在失败或取消后保留部分文件
+    /// * `cancel_token` - 取消令牌
+    /// * `throttle` - 传输限速器
     /// * `progress_callback` - 进度回调函数，参数为 (已传输字节数, 总字节数, 速度bytes/s)
     ///
     /// # Returns
@@ -381,6 +680,9 @@ impl SftpService {
         &self,
         remote_path: &str,
         local_path: &std::path::Path,
+        resume: bool,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
         progress_callback: F,
     ) -> Result<(), String>
     where
@@ -390,10 +692,21 @@ impl SftpService {
             "[SFTP] Downloading file: {} -> {:?}",
             remote_path, local_path
         );
+        if let Some(ssh_session) = self.scp_session() {
+            return Self::scp_download_file(
+                ssh_session,
+                remote_path,
+                local_path,
+                cancel_token,
+                throttle,
+                &progress_callback,
+            )
+            .await;
+        }
 
         // 获取文件大小
         let attrs = self
-            .sftp
+            .sftp()?
             .metadata(remote_path)
             .await
             .map_err(|e| format!("Failed to get file metadata: {}", e))?;
@@ -403,33 +716,107 @@ impl SftpService {
             return Err("Cannot download a directory".to_string());
         }
 
-        // 打开远程文件
+        let part_path = local_part_path(local_path);
+        let offset = if resume {
+            local_resume_offset(&part_path, total_size).await
+        } else {
+            0
+        };
+
+        let mut bytes_transferred = offset;
+        let result = self
+            .download_to_part(
+                remote_path,
+                &part_path,
+                total_size,
+                &mut bytes_transferred,
+                cancel_token,
+                throttle,
+                &progress_callback,
+            )
+            .await;
+
+        if let Err(e) = result {
+            // 顺序写入，已写入的字节数即为已确认的前缀
+            keep_local_part(&part_path, bytes_transferred, resume).await;
+            return Err(e);
+        }
+        finish_local_part(&part_path, local_path).await?;
+
+        info!(
+            "[SFTP] Download completed: {} ({} bytes)",
+            remote_path,
+            bytes_transferred - offset
+        );
+
+        Ok(())
+    }
+
+    /// 从 `bytes_transferred` 处开始把远程文件写入本地部分文件
+    #[allow(clippy::too_many_arguments)]
+    async fn download_to_part<F>(
+        &self,
+        remote_path: &str,
+        part_path: &std::path::Path,
+        total_size: u64,
+        bytes_transferred: &mut u64,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64),
+    {
+        use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+        let offset = *bytes_transferred;
+
+        // 打开远程文件并定位到续传起点
         let mut remote_file = self
-            .sftp
+            .sftp()?
             .open(remote_path)
             .await
             .map_err(|e| format!("Failed to open remote file: {}", e))?;
+        if offset > 0 {
+            remote_file
+                .seek(std::io::SeekFrom::Start(offset))
+                .await
+                .map_err(|e| format!("Failed to seek remote file: {}", e))?;
+        }
 
-        // 创建本地文件
-        let mut local_file = tokio::fs::File::create(local_path)
+        // 打开本地部分文件，续传时保留已有的前缀
+        let mut local_file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(offset == 0)
+            .open(part_path)
             .await
             .map_err(|e| format!("Failed to create local file: {}", e))?;
+        if offset > 0 {
+            local_file
+                .seek(std::io::SeekFrom::Start(offset))
+                .await
+                .map_err(|e| format!("Failed to seek local file: {}", e))?;
+        }
 
-        // 读取并写入
-        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        // 先回报一次续传起点
+        progress_callback(offset, total_size, 0);
 
         // 使用较大的 chunk size 以提高性能 (256KB)
         const CHUNK_SIZE: usize = 256 * 1024;
         let mut buffer = vec![0u8; CHUNK_SIZE];
-        let mut bytes_transferred: u64 = 0;
 
         // 速度计算变量
         let start_time = std::time::Instant::now();
         let mut last_update_time = start_time;
-        let mut last_bytes = 0u64;
+        let mut last_bytes = offset;
         let mut current_speed: u64 = 0;
 
         loop {
+            if cancel_token.is_cancelled() {
+                return Err(cancelled_error(false));
+            }
+
             let bytes_read = remote_file
                 .read(&mut buffer)
                 .await
@@ -438,40 +825,34 @@ impl SftpService {
             if bytes_read == 0 {
                 break;
             }
+            throttle.consume(bytes_read as u64).await;
 
             local_file
                 .write_all(&buffer[..bytes_read])
                 .await
                 .map_err(|e| format!("Failed to write to local file: {}", e))?;
 
-            bytes_transferred += bytes_read as u64;
+            *bytes_transferred += bytes_read as u64;
 
             // 计算速度（每100ms更新一次或更少）
             let now = std::time::Instant::now();
             let elapsed = now.duration_since(last_update_time);
             if elapsed.as_millis() >= 100 {
-                let bytes_delta = bytes_transferred - last_bytes;
+                let bytes_delta = *bytes_transferred - last_bytes;
                 current_speed = (bytes_delta as f64 / elapsed.as_secs_f64()) as u64;
                 last_update_time = now;
-                last_bytes = bytes_transferred;
+                last_bytes = *bytes_transferred;
             }
 
             // 调用进度回调（包含速度）
-            progress_callback(bytes_transferred, total_size, current_speed);
+            progress_callback(*bytes_transferred, total_size, current_speed);
         }
 
         // 确保所有数据都写入磁盘
         local_file
             .flush()
             .await
-            .map_err(|e| format!("Failed to flush local file: {}", e))?;
-
-        info!(
-            "[SFTP] Download completed: {} ({} bytes)",
-            remote_path, bytes_transferred
-        );
-
-        Ok(())
+            .map_err(|e| format!("Failed to flush local file: {}", e))
     }
 
     /// 下载文件的指定分片（用于多通道并行下载）
@@ -481,6 +862,7 @@ impl SftpService {
     /// * `local_file` - 本地文件句柄（线程安全，使用 seek 写入指定位置）
     /// * `offset` - 起始偏移量
     /// * `length` - 要下载的字节数
+    /// * `throttle` - 传输限速器（各分片共享）
     /// * `progress_callback` - 进度回调函数，参数为 (本分片已传输字节数, 速度bytes/s)
     ///
     /// # Returns
@@ -492,6 +874,7 @@ impl SftpService {
         local_file: std::sync::Arc<tokio::sync::Mutex<tokio::fs::File>>,
         offset: u64,
         length: u64,
+        throttle: &Throttle,
         progress_callback: F,
     ) -> Result<u64, String>
     where
@@ -506,7 +889,7 @@ impl SftpService {
 
         // 打开远程文件
         let mut remote_file = self
-            .sftp
+            .sftp()?
             .open(remote_path)
             .await
             .map_err(|e| format!("Failed to open remote file: {}", e))?;
@@ -540,6 +923,7 @@ impl SftpService {
                 // 文件可能比预期短
                 break;
             }
+            throttle.consume(bytes_read as u64).await;
 
             // 写入本地文件（需要 seek 到正确位置）
             {
@@ -579,9 +963,14 @@ impl SftpService {
 
     /// 上传本地文件到远程服务器
     ///
+    /// 数据先写入远程的 `.part` 部分文件，完成后再改名为目标文件
+    ///
     /// # Arguments
     /// * `local_path` - 本地文件路径
     /// * `remote_path` - 远程保存路径
+    /// * `resume` - 是否从已有部分文件续传，并在失败或取消后保留部分文件
+    /// * `cancel_token` - 取消令牌
+    /// * `throttle` - 传输限速器
     /// * `progress_callback` - 进度回调函数，参数为 (已传输字节数, 总字节数, 速度bytes/s)
     ///
     /// # Returns
@@ -591,6 +980,9 @@ impl SftpService {
         &self,
         local_path: &std::path::Path,
         remote_path: &str,
+        resume: bool,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
         progress_callback: F,
     ) -> Result<(), String>
     where
@@ -607,34 +999,123 @@ impl SftpService {
         if metadata.is_dir() {
             return Err("Cannot upload a directory".to_string());
         }
+        if let Some(ssh_session) = self.scp_session() {
+            return self
+                .scp_upload_file(
+                    ssh_session,
+                    local_path,
+                    remote_path,
+                    cancel_token,
+                    throttle,
+                    &progress_callback,
+                )
+                .await;
+        }
+
+        let part_path = remote_part_path(remote_path);
+        let offset = if resume {
+            self.remote_resume_offset(&part_path, total_size).await
+        } else {
+            0
+        };
+
+        let mut bytes_transferred = offset;
+        let result = self
+            .upload_to_part(
+                local_path,
+                &part_path,
+                total_size,
+                &mut bytes_transferred,
+                cancel_token,
+                throttle,
+                &progress_callback,
+            )
+            .await;
+
+        if let Err(e) = result {
+            // 顺序写入，已写入的字节数即为已确认的前缀
+            self.keep_remote_part(&part_path, bytes_transferred, resume)
+                .await;
+            return Err(e);
+        }
+        self.finish_remote_part(&part_path, remote_path).await?;
+
+        info!(
+            "[SFTP] Upload completed: {:?} ({} bytes)",
+            local_path,
+            bytes_transferred - offset
+        );
 
-        // 打开本地文件
+        Ok(())
+    }
+
+    /// 从 `bytes_transferred` 处开始把本地文件写入远程部分文件
+    #[allow(clippy::too_many_arguments)]
+    async fn upload_to_part<F>(
+        &self,
+        local_path: &std::path::Path,
+        part_path: &str,
+        total_size: u64,
+        bytes_transferred: &mut u64,
+        cancel_token: &CancellationToken,
+        throttle: &Throttle,
+        progress_callback: &F,
+    ) -> Result<(), String>
+    where
+        F: Fn(u64, u64, u64),
+    {
+        use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+        let offset = *bytes_transferred;
+
+        // 打开本地文件并定位到续传起点
         let mut local_file = tokio::fs::File::open(local_path)
             .await
             .map_err(|e| format!("Failed to open local file: {}", e))?;
+        if offset > 0 {
+            local_file
+                .seek(std::io::SeekFrom::Start(offset))
+                .await
+                .map_err(|e| format!("Failed to seek local file: {}", e))?;
+        }
 
-        // 创建远程文件
-        let mut remote_file = self
-            .sftp
-            .create(remote_path)
-            .await
-            .map_err(|e| format!("Failed to create remote file: {}", e))?;
+        // 打开远程部分文件，续传时先截断到已确认的前缀
+        let mut remote_file = if offset > 0 {
+            self.truncate(part_path, offset).await?;
+            let mut file = self
+                .sftp
+                .open_with_flags(part_path, russh_sftp::protocol::OpenFlags::WRITE)
+                .await
+                .map_err(|e| format!("Failed to open remote file for writing: {}", e))?;
+            file.seek(std::io::SeekFrom::Start(offset))
+                .await
+                .map_err(|e| format!("Failed to seek remote file: {}", e))?;
+            file
+        } else {
+            self.sftp()?
+                .create(part_path)
+                .await
+                .map_err(|e| format!("Failed to create remote file: {}", e))?
+        };
 
-        // 读取并写入
-        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        // 先回报一次续传起点
+        progress_callback(offset, total_size, 0);
 
         // 使用较大的 chunk size 以提高性能 (256KB)
         const CHUNK_SIZE: usize = 256 * 1024;
         let mut buffer = vec![0u8; CHUNK_SIZE];
-        let mut bytes_transferred: u64 = 0;
 
         // 速度计算变量
         let start_time = std::time::Instant::now();
         let mut last_update_time = start_time;
-        let mut last_bytes = 0u64;
+        let mut last_bytes = offset;
         let mut current_speed: u64 = 0;
 
         loop {
+            if cancel_token.is_cancelled() {
+                return Err(cancelled_error(true));
+            }
+
             let bytes_read = local_file
                 .read(&mut buffer)
                 .await
@@ -643,40 +1124,34 @@ impl SftpService {
             if bytes_read == 0 {
                 break;
             }
+            throttle.consume(bytes_read as u64).await;
 
             remote_file
                 .write_all(&buffer[..bytes_read])
                 .await
                 .map_err(|e| format!("Failed to write to remote file: {}", e))?;
 
-            bytes_transferred += bytes_read as u64;
+            *bytes_transferred += bytes_read as u64;
 
             // 计算速度（每100ms更新一次或更少）
             let now = std::time::Instant::now();
             let elapsed = now.duration_since(last_update_time);
             if elapsed.as_millis() >= 100 {
-                let bytes_delta = bytes_transferred - last_bytes;
+                let bytes_delta = *bytes_transferred - last_bytes;
                 current_speed = (bytes_delta as f64 / elapsed.as_secs_f64()) as u64;
                 last_update_time = now;
-                last_bytes = bytes_transferred;
+                last_bytes = *bytes_transferred;
             }
 
             // 调用进度回调（包含速度）
-            progress_callback(bytes_transferred, total_size, current_speed);
+            progress_callback(*bytes_transferred, total_size, current_speed);
         }
 
         // 确保所有数据都写入远程
         remote_file
             .flush()
             .await
-            .map_err(|e| format!("Failed to flush remote file: {}", e))?;
-
-        info!(
-            "[SFTP] Upload completed: {:?} ({} bytes)",
-            local_path, bytes_transferred
-        );
-
-        Ok(())
+            .map_err(|e| format!("Failed to flush remote file: {}", e))
     }
 
     /// 上传文件的指定分片（用于多通道并行上传）
@@ -686,6 +1161,7 @@ impl SftpService {
     /// * `remote_path` - 远程文件路径
     /// * `offset` - 起始偏移量
     /// * `length` - 要上传的字节数
+    /// * `throttle` - 传输限速器（各分片共享）
     /// * `progress_callback` - 进度回调函数，参数为 (本分片已传输字节数, 速度bytes/s)
     ///
     /// # Returns
@@ -697,6 +1173,7 @@ impl SftpService {
         remote_path: &str,
         offset: u64,
         length: u64,
+        throttle: &Throttle,
         progress_callback: F,
     ) -> Result<u64, String>
     where
@@ -711,7 +1188,7 @@ impl SftpService {
 
         // 打开远程文件用于写入（需要使用特殊标志来支持 seek 写入）
         let mut remote_file = self
-            .sftp
+            .sftp()?
             .open_with_flags(remote_path, russh_sftp::protocol::OpenFlags::WRITE)
             .await
             .map_err(|e| format!("Failed to open remote file for writing: {}", e))?;
@@ -752,6 +1229,7 @@ impl SftpService {
                 // 文件可能比预期短
                 break;
             }
+            throttle.consume(bytes_read as u64).await;
 
             // 写入远程文件
             remote_file
@@ -791,6 +1269,51 @@ impl SftpService {
     }
 }
 
+/// 传输被取消时返回的错误信息（按当前界面语言）
+pub(super) fn cancelled_error(is_upload: bool) -> String {
+    let lang = crate::services::storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+    let key = if is_upload {
+        "transfer.upload_cancelled"
+    } else {
+        "transfer.download_cancelled"
+    };
+    crate::i18n::t(&lang, key).to_string()
+}
+
+/// 由目录项属性构建文件条目（不含符号链接目标）
+pub(super) fn file_entry_from_attrs(dir: &str, name: &str, attrs: &FileAttributes) -> FileEntry {
+    let full_path = if dir == "/" {
+        format!("/{}", name)
+    } else {
+        format!("{}/{}", dir.trim_end_matches('/'), name)
+    };
+
+    // 确定文件类型
+    let file_type = if attrs.is_dir() {
+        FileType::Directory
+    } else if attrs.is_symlink() {
+        FileType::Symlink
+    } else {
+        // russh-sftp 没有 is_file()，默认为普通文件
+        FileType::File
+    };
+
+    let mut file_entry = FileEntry::new(name.to_string(), full_path, file_type);
+    file_entry.size = attrs.size.unwrap_or(0);
+    file_entry.permissions = attrs.permissions.map(|p| p as u32).unwrap_or(0);
+    file_entry.uid = attrs.uid;
+    file_entry.gid = attrs.gid;
+
+    // 修改时间
+    if let Some(mtime) = attrs.mtime {
+        file_entry.modified =
+            Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(mtime as u64));
+    }
+    file_entry
+}
+
 impl Drop for SftpService {
     fn drop(&mut self) {
         info!(
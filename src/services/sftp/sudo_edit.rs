@@ -0,0 +1,88 @@
+// 以管理员身份编辑远程文件
+// 读取通过 `sudo cat`；保存时把新内容经标准输入直接交给 `sudo tee` 写回目标文件（保留目标文件的所有者与权限），
+// 不经过其他用户可读写的临时文件
+// 密码为空表示免密 sudo，此时使用 `sudo -n`，标准输入只包含命令自身的输入
+
+use tracing::{debug, info};
+
+use super::service::SftpService;
+use crate::services::remote_exec::{exec_command_with_stdin, shell_quote};
+use crate::ssh::session::SshSession;
+
+/// 以 sudo 执行命令，返回标准输出
+pub async fn run_sudo(
+    ssh_session: &SshSession,
+    command: &str,
+    password: &str,
+) -> Result<Vec<u8>, String> {
+    run_sudo_with_input(ssh_session, command, password, &[]).await
+}
+
+/// 以 sudo 执行命令，`input` 写入命令的标准输入，返回标准输出
+///
+/// 有密码时标准输入的第一行是密码；使用 `-k` 忽略已缓存的凭据，保证 sudo 总会读走密码行，
+/// 不会把密码当作命令的输入
+pub async fn run_sudo_with_input(
+    ssh_session: &SshSession,
+    command: &str,
+    password: &str,
+    input: &[u8],
+) -> Result<Vec<u8>, String> {
+    if password.is_empty() {
+        return exec_command_with_stdin(ssh_session, &format!("sudo -n {}", command), input).await;
+    }
+    let mut stdin = format!("{}\n", password).into_bytes();
+    stdin.extend_from_slice(input);
+    exec_command_with_stdin(
+        ssh_session,
+        &format!("sudo -k -S -p '' {}", command),
+        &stdin,
+    )
+    .await
+}
+
+/// 验证 sudo 权限：`None` 检查是否免密，`Some` 检查密码是否正确
+pub async fn sudo_check(ssh_session: &SshSession, password: Option<&str>) -> Result<(), String> {
+    run_sudo(ssh_session, "true", password.unwrap_or_default())
+        .await
+        .map(drop)
+}
+
+impl SftpService {
+    /// 以管理员身份读取文件内容
+    pub async fn sudo_read_file(
+        &self,
+        ssh_session: &SshSession,
+        path: &str,
+        password: &str,
+    ) -> Result<Vec<u8>, String> {
+        debug!("[SFTP] Reading {} with sudo", path);
+        run_sudo(
+            ssh_session,
+            &format!("cat -- {}", shell_quote(path)),
+            password,
+        )
+        .await
+        .map_err(|e| format!("Failed to read file {}: {}", path, e))
+    }
+
+    /// 以管理员身份写入文件：内容经标准输入交给 `sudo tee` 写入目标
+    pub async fn sudo_write_file(
+        &self,
+        ssh_session: &SshSession,
+        path: &str,
+        content: &[u8],
+        password: &str,
+    ) -> Result<(), String> {
+        info!(
+            "[SFTP] Writing {} with sudo ({} bytes)",
+            path,
+            content.len()
+        );
+        let command = format!("tee -- {} > /dev/null", shell_quote(path));
+        run_sudo_with_input(ssh_session, &command, password, content)
+            .await
+            .map(drop)
+            .map_err(|e| format!("Failed to write file {}: {}", path, e))
+    }
+}
@@ -0,0 +1,278 @@
+// 目录同步：比较本地与远程目录，生成逐文件的同步计划
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use sha2::{Digest, Sha256};
+
+use super::SftpService;
+use crate::models::sftp::FileType;
+use crate::services::remote_exec::shell_quote;
+
+/// 修改时间比较容差（SFTP 时间戳为秒级，部分文件系统精度为 2 秒）
+const MTIME_TOLERANCE: Duration = Duration::from_secs(2);
+
+/// 同步模式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SyncMode {
+    /// 以本地为准镜像到远程（删除远程多余文件）
+    #[default]
+    MirrorToRemote,
+    /// 以远程为准镜像到本地（删除本地多余文件）
+    MirrorToLocal,
+    /// 双向同步：缺失的文件互相补齐，两侧都有时以较新的为准
+    Bidirectional,
+}
+
+/// 文件比较方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SyncCompareMode {
+    /// 比较大小和修改时间
+    #[default]
+    SizeMtime,
+    /// 比较 SHA-256 校验和
+    Checksum,
+}
+
+/// 单个文件的同步操作
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncAction {
+    Upload,
+    Download,
+    DeleteLocal,
+    DeleteRemote,
+    Skip,
+}
+
+/// 参与比较的文件信息
+#[derive(Clone, Debug)]
+pub struct SyncFileInfo {
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+/// 相对路径（以 `/` 分隔）到文件信息的映射
+pub type SyncFileMap = BTreeMap<String, SyncFileInfo>;
+
+/// 同步计划中的一项
+#[derive(Clone, Debug)]
+pub struct SyncItem {
+    /// 相对于同步根目录的路径（以 `/` 分隔）
+    pub relative_path: String,
+    pub local: Option<SyncFileInfo>,
+    pub remote: Option<SyncFileInfo>,
+    pub action: SyncAction,
+}
+
+impl SyncItem {
+    /// 该条目可选的操作（按界面切换顺序）
+    pub fn available_actions(&self) -> Vec<SyncAction> {
+        let mut actions = Vec::new();
+        if self.local.is_some() {
+            actions.push(SyncAction::Upload);
+        }
+        if self.remote.is_some() {
+            actions.push(SyncAction::Download);
+        }
+        if self.local.is_some() {
+            actions.push(SyncAction::DeleteLocal);
+        }
+        if self.remote.is_some() {
+            actions.push(SyncAction::DeleteRemote);
+        }
+        actions.push(SyncAction::Skip);
+        actions
+    }
+
+    /// 切换到下一个可选操作
+    pub fn cycle_action(&mut self) {
+        let actions = self.available_actions();
+        let current = actions.iter().position(|a| *a == self.action).unwrap_or(0);
+        self.action = actions[(current + 1) % actions.len()];
+    }
+}
+
+/// 将相对路径拼接到本地根目录
+pub fn local_sync_path(root: &Path, relative_path: &str) -> PathBuf {
+    relative_path
+        .split('/')
+        .fold(root.to_path_buf(), |path, part| path.join(part))
+}
+
+/// 收集本地目录下所有普通文件（跳过符号链接）
+pub fn collect_local_files(root: &Path) -> std::io::Result<SyncFileMap> {
+    let mut files = SyncFileMap::new();
+    let mut dirs = vec![(root.to_path_buf(), String::new())];
+    while let Some((dir, prefix)) = dirs.pop() {
+        for item in std::fs::read_dir(&dir)? {
+            let item = item?;
+            let name = item.file_name().to_string_lossy().to_string();
+            let relative = if prefix.is_empty() {
+                name
+            } else {
+                format!("{}/{}", prefix, name)
+            };
+            let file_type = item.file_type()?;
+            if file_type.is_dir() {
+                dirs.push((item.path(), relative));
+            } else if file_type.is_file() {
+                let metadata = item.metadata()?;
+                files.insert(
+                    relative,
+                    SyncFileInfo {
+                        size: metadata.len(),
+                        modified: metadata.modified().ok(),
+                    },
+                );
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// 计算本地文件的 SHA-256
+pub fn local_checksum(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// 生成在远程目录下计算 SHA-256 的命令，输出形如 `hash  ./relative/path`
+pub fn remote_checksum_command(root: &str) -> String {
+    format!(
+        "cd {} && find . -type f -exec sha256sum {{}} + 2>/dev/null",
+        shell_quote(root)
+    )
+}
+
+/// 解析 sha256sum 的输出行，返回 (相对路径, 校验和)
+pub fn parse_checksum_line(line: &str) -> Option<(String, String)> {
+    let (hash, path) = line.split_once("  ")?;
+    let path = path.strip_prefix("./")?;
+    if hash.len() != 64 || path.is_empty() {
+        return None;
+    }
+    Some((path.to_string(), hash.to_string()))
+}
+
+impl SftpService {
+    /// 收集远程目录下所有普通文件（跳过符号链接）
+    pub async fn collect_sync_files(&self, root: &str) -> Result<SyncFileMap, String> {
+        let prefix = format!("{}/", root.trim_end_matches('/'));
+        let entries = self.read_dir_recursive(root).await?;
+        Ok(entries
+            .into_iter()
+            .filter(|e| e.file_type == FileType::File)
+            .filter_map(|e| {
+                let relative = e.path.strip_prefix(&prefix)?.to_string();
+                Some((
+                    relative,
+                    SyncFileInfo {
+                        size: e.size,
+                        modified: e.modified,
+                    },
+                ))
+            })
+            .collect())
+    }
+}
+
+/// 比较两侧的同名文件是否相同
+///
+/// `checksums` 中缺少该文件时退回到大小和修改时间比较
+fn is_same(
+    relative_path: &str,
+    local: &SyncFileInfo,
+    remote: &SyncFileInfo,
+    compare: SyncCompareMode,
+    checksums: &BTreeMap<String, (String, String)>,
+) -> bool {
+    if local.size != remote.size {
+        return false;
+    }
+    if compare == SyncCompareMode::Checksum {
+        if let Some((local_hash, remote_hash)) = checksums.get(relative_path) {
+            return local_hash == remote_hash;
+        }
+    }
+    match (local.modified, remote.modified) {
+        (Some(l), Some(r)) => {
+            let diff = l.duration_since(r).or_else(|_| r.duration_since(l));
+            diff.map(|d| d <= MTIME_TOLERANCE).unwrap_or(true)
+        }
+        _ => true,
+    }
+}
+
+/// 双向同步时选择较新的一侧，无法判断时跳过
+fn newer_side(local: &SyncFileInfo, remote: &SyncFileInfo) -> SyncAction {
+    match (local.modified, remote.modified) {
+        (Some(l), Some(r)) if l > r + MTIME_TOLERANCE => SyncAction::Upload,
+        (Some(l), Some(r)) if r > l + MTIME_TOLERANCE => SyncAction::Download,
+        _ => SyncAction::Skip,
+    }
+}
+
+/// 比较两侧文件并生成同步计划，内容相同的文件不会出现在计划中
+///
+/// `checksums` 为校验和模式下两侧都存在且大小相同的文件的 (本地, 远程) 校验和
+pub fn build_sync_plan(
+    local: &SyncFileMap,
+    remote: &SyncFileMap,
+    mode: SyncMode,
+    compare: SyncCompareMode,
+    checksums: &BTreeMap<String, (String, String)>,
+) -> Vec<SyncItem> {
+    let mut paths: Vec<&String> = local.keys().chain(remote.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let local_info = local.get(path);
+            let remote_info = remote.get(path);
+            let action = match (local_info, remote_info) {
+                (Some(l), Some(r)) => {
+                    if is_same(path, l, r, compare, checksums) {
+                        return None;
+                    }
+                    match mode {
+                        SyncMode::MirrorToRemote => SyncAction::Upload,
+                        SyncMode::MirrorToLocal => SyncAction::Download,
+                        SyncMode::Bidirectional => newer_side(l, r),
+                    }
+                }
+                (Some(_), None) => match mode {
+                    SyncMode::MirrorToLocal => SyncAction::DeleteLocal,
+                    _ => SyncAction::Upload,
+                },
+                (None, Some(_)) => match mode {
+                    SyncMode::MirrorToRemote => SyncAction::DeleteRemote,
+                    _ => SyncAction::Download,
+                },
+                (None, None) => return None,
+            };
+            Some(SyncItem {
+                relative_path: path.clone(),
+                local: local_info.cloned(),
+                remote: remote_info.cloned(),
+                action,
+            })
+        })
+        .collect()
+}
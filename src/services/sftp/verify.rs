@@ -0,0 +1,68 @@
+// SFTP 传输完整性校验：传输完成后比较远程 `sha256sum` 与本地计算的 SHA-256
+
+use std::path::Path;
+
+use tracing::{info, warn};
+
+use super::sync::local_checksum;
+use crate::services::remote_exec::shell_quote;
+use crate::ssh::session::SshSession;
+
+/// 生成计算单个远程文件 SHA-256 的命令（远程没有 sha256sum 时改用 shasum）
+pub fn remote_file_checksum_command(path: &str) -> String {
+    format!(
+        "sha256sum {0} 2>/dev/null || shasum -a 256 {0}",
+        shell_quote(path)
+    )
+}
+
+/// 解析单个文件的校验和命令输出，取第一列的哈希值
+pub fn parse_file_checksum(output: &str) -> Option<String> {
+    let hash = output.split_whitespace().next()?;
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(hash.to_ascii_lowercase())
+}
+
+/// 校验远程文件与本地文件的 SHA-256 是否一致，不一致时返回错误
+pub async fn verify_checksum(
+    session: &SshSession,
+    remote_path: &str,
+    local_path: &Path,
+) -> Result<(), String> {
+    info!(
+        "[SFTP] Verifying checksum: {} <-> {:?}",
+        remote_path, local_path
+    );
+
+    let exec = session.open_exec().await.map_err(|e| format!("{:?}", e))?;
+    let output = exec
+        .exec(&remote_file_checksum_command(remote_path))
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    let remote_hash = parse_file_checksum(&output.stdout_string()).ok_or_else(|| {
+        format!(
+            "Failed to compute remote checksum: {}",
+            output.stderr_string().trim()
+        )
+    })?;
+
+    let local = local_path.to_path_buf();
+    let local_hash = tokio::task::spawn_blocking(move || local_checksum(&local))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to compute local checksum: {}", e))?;
+
+    if remote_hash != local_hash {
+        warn!(
+            "[SFTP] Checksum mismatch for {}: remote {}, local {}",
+            remote_path, remote_hash, local_hash
+        );
+        return Err(format!(
+            "Checksum mismatch: remote {}, local {}",
+            remote_hash, local_hash
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// 远程目录监视
+// 优先通过远程 `inotifywait` 等待目录变化；不可用时定期读取目录并比较条目签名（名称、大小、修改时间）
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+use crate::models::sftp::FileEntry;
+use crate::services::remote_exec::shell_quote;
+
+/// 轮询模式下读取目录的间隔
+pub const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// 单轮等待的最长时间（秒），到期后重新确认要监视的目录
+pub const WATCH_CYCLE_SECS: u64 = 30;
+/// 检测到变化后等待后续变化平息的时间
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// 等待目录变化的 `inotifywait` 命令
+///
+/// 退出码：0 表示发生变化，2 表示超时；其他（如 127 命令不存在）表示不可用
+pub fn inotify_watch_command(dir: &str) -> String {
+    format!(
+        "inotifywait -qq -e create,delete,move,modify,attrib --timeout {} -- {}",
+        WATCH_CYCLE_SECS,
+        shell_quote(dir)
+    )
+}
+
+/// `inotifywait` 退出码对应的结果：Some(是否变化)，None 表示不可用
+pub fn inotify_changed(exit_code: u32) -> Option<bool> {
+    match exit_code {
+        0 => Some(true),
+        2 => Some(false),
+        _ => None,
+    }
+}
+
+/// 目录条目签名（与条目顺序无关）
+pub fn dir_signature(entries: &[FileEntry]) -> u64 {
+    let mut items: Vec<_> = entries
+        .iter()
+        .map(|e| (e.name.as_str(), e.size, e.modified, e.permissions))
+        .collect();
+    items.sort_unstable();
+    let mut hasher = DefaultHasher::new();
+    items.hash(&mut hasher);
+    hasher.finish()
+}
@@ -0,0 +1,303 @@
+// 快捷命令库导入/导出
+// 以 JSON / YAML 文件在团队间共享命令库，并兼容导入 shell 的 alias 定义文件（如 .bash_aliases）；
+// 导入时合并到本地命令库：同名命令组复用，完全相同的命令跳过
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::models::{SnippetCommand, SnippetGroup, SnippetsConfig};
+use crate::services::storage;
+
+/// 命令库文件格式版本
+const LIBRARY_VERSION: u32 = 1;
+
+/// 导出格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LibraryFormat {
+    Json,
+    Yaml,
+}
+
+impl LibraryFormat {
+    /// 保存对话框的默认文件名
+    pub fn default_file_name(&self) -> &'static str {
+        match self {
+            LibraryFormat::Json => "shellmaster-snippets.json",
+            LibraryFormat::Yaml => "shellmaster-snippets.yaml",
+        }
+    }
+
+    /// 文件选择器的扩展名过滤
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            LibraryFormat::Json => &["json"],
+            LibraryFormat::Yaml => &["yaml", "yml"],
+        }
+    }
+}
+
+/// 命令库文件内容（不含变量记忆值与 Runbook）
+#[derive(Serialize, Deserialize)]
+struct SnippetLibrary {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    groups: Vec<SnippetGroup>,
+    #[serde(default)]
+    commands: Vec<SnippetCommand>,
+}
+
+/// 导入结果统计
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LibraryImportSummary {
+    /// 新增的命令数量
+    pub commands: usize,
+    /// 与本地完全相同而跳过的命令数量
+    pub duplicates: usize,
+}
+
+/// 导出本地命令库，返回导出的命令数量
+pub fn export_snippets(path: &Path, format: LibraryFormat) -> Result<usize> {
+    let config = storage::load_snippets()?;
+    let library = SnippetLibrary {
+        version: LIBRARY_VERSION,
+        groups: config.groups,
+        commands: config
+            .commands
+            .into_iter()
+            .map(|mut command| {
+                // 服务器与分组 ID 只在本机有效，可见范围只保留服务器标签
+                command.scope.server_ids.clear();
+                command.scope.group_ids.clear();
+                command
+            })
+            .collect(),
+    };
+    let content = match format {
+        LibraryFormat::Json => serde_json::to_string_pretty(&library)?,
+        LibraryFormat::Yaml => serde_yaml::to_string(&library)?,
+    };
+    fs::write(path, content).with_context(|| format!("无法写入 {:?}", path))?;
+    info!(
+        "[Snippets] Exported {} snippets as {:?} to {:?}",
+        library.commands.len(),
+        format,
+        path
+    );
+    Ok(library.commands.len())
+}
+
+/// 导入命令库文件（JSON / YAML 命令库或 shell alias 文件）并合并到本地
+pub fn import_snippets(path: &Path) -> Result<LibraryImportSummary> {
+    let content = fs::read_to_string(path).with_context(|| format!("无法读取 {:?}", path))?;
+    let library = parse_library(path, &content)?;
+    if library.commands.is_empty() {
+        bail!("文件中没有可导入的命令");
+    }
+
+    let summary = storage::update_snippets(|config| Ok(merge_library(config, library)))?;
+    info!(
+        "[Snippets] Imported {} snippets from {:?} ({} duplicates skipped)",
+        summary.commands, path, summary.duplicates
+    );
+    Ok(summary)
+}
+
+/// 按扩展名识别格式，无法识别时依次尝试 JSON、YAML 与 alias 文件
+fn parse_library(path: &Path, content: &str) -> Result<SnippetLibrary> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("json") => serde_json::from_str(content).context("无法解析 JSON 命令库"),
+        Some("yaml" | "yml") => serde_yaml::from_str(content).context("无法解析 YAML 命令库"),
+        _ => {
+            if let Ok(library) = serde_json::from_str(content) {
+                return Ok(library);
+            }
+            if let Ok(library) = serde_yaml::from_str(content) {
+                return Ok(library);
+            }
+            Ok(parse_alias_file(path, content))
+        }
+    }
+}
+
+/// 解析 shell alias 文件：每个 `alias name='command'` 成为一条命令，放入以文件名命名的命令组
+fn parse_alias_file(path: &Path, content: &str) -> SnippetLibrary {
+    let group_name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.trim_start_matches('.'))
+        .filter(|s| !s.is_empty())
+        .unwrap_or("aliases");
+    let group = SnippetGroup {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: group_name.to_string(),
+        ..Default::default()
+    };
+
+    let commands = content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("alias "))
+        .flat_map(parse_alias_definitions)
+        .map(|(name, command)| SnippetCommand {
+            name,
+            command,
+            group_id: Some(group.id.clone()),
+            ..Default::default()
+        })
+        .collect();
+
+    SnippetLibrary {
+        version: LIBRARY_VERSION,
+        groups: vec![group],
+        commands,
+    }
+}
+
+/// 解析一行 alias 中的 `name=value` 定义（支持单引号、双引号与未加引号的值，忽略选项与行尾注释）
+fn parse_alias_definitions(rest: &str) -> Vec<(String, String)> {
+    let mut definitions = Vec::new();
+    let mut chars = rest.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(&first) = chars.peek() else {
+            break;
+        };
+        if first == '#' {
+            break;
+        }
+
+        let mut name = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            name.push(c);
+        }
+        // 选项（如 zsh 的 `alias -g`）或只查询别名而不定义的参数
+        if chars.next_if_eq(&'=').is_none() || name.starts_with('-') {
+            continue;
+        }
+
+        let mut value = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            match c {
+                '\'' => {
+                    for c in chars.by_ref() {
+                        if c == '\'' {
+                            break;
+                        }
+                        value.push(c);
+                    }
+                }
+                '"' => {
+                    while let Some(c) = chars.next() {
+                        match c {
+                            '"' => break,
+                            '\\' => value.extend(chars.next()),
+                            _ => value.push(c),
+                        }
+                    }
+                }
+                '\\' => value.extend(chars.next()),
+                _ => value.push(c),
+            }
+        }
+
+        if !name.is_empty() && !value.trim().is_empty() {
+            definitions.push((name, value.trim().to_string()));
+        }
+    }
+
+    definitions
+}
+
+/// 合并命令库：命令组按（父级, 名称）复用已有组，命令重新生成 ID，同组内名称与内容都相同的命令跳过
+fn merge_library(config: &mut SnippetsConfig, library: SnippetLibrary) -> LibraryImportSummary {
+    let now = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+    let mut group_ids: HashMap<String, String> = HashMap::new();
+
+    // 父级组可能排在子组之后，按层级逐轮处理，直到没有可处理的组
+    let mut pending = library.groups;
+    loop {
+        let before = pending.len();
+        let mut remaining = Vec::new();
+        for group in pending {
+            let parent_id = match group.parent_id.as_deref() {
+                None => None,
+                Some(parent) => match group_ids.get(parent) {
+                    Some(id) => Some(id.clone()),
+                    None => {
+                        remaining.push(group);
+                        continue;
+                    }
+                },
+            };
+            let existing = config
+                .groups
+                .iter()
+                .find(|g| g.parent_id == parent_id && g.name == group.name)
+                .map(|g| g.id.clone());
+            let id = existing.unwrap_or_else(|| {
+                let id = uuid::Uuid::new_v4().to_string();
+                config.groups.push(SnippetGroup {
+                    id: id.clone(),
+                    name: group.name.clone(),
+                    parent_id,
+                    description: group.description.clone(),
+                    created_at: now.clone(),
+                });
+                id
+            });
+            group_ids.insert(group.id, id);
+        }
+        if remaining.len() == before {
+            // 父级不存在（或循环引用）的组放到顶级
+            for group in &mut remaining {
+                group.parent_id = None;
+            }
+        }
+        if remaining.is_empty() {
+            break;
+        }
+        pending = remaining;
+    }
+
+    let mut summary = LibraryImportSummary::default();
+    for mut command in library.commands {
+        command.group_id = command
+            .group_id
+            .as_deref()
+            .and_then(|id| group_ids.get(id))
+            .cloned();
+        let duplicate = config.commands.iter().any(|c| {
+            c.group_id == command.group_id && c.name == command.name && c.command == command.command
+        });
+        if duplicate {
+            summary.duplicates += 1;
+            continue;
+        }
+
+        command.id = uuid::Uuid::new_v4().to_string();
+        if command.created_at.is_empty() {
+            command.created_at = now.clone();
+        }
+        // 导入的命令不自动执行，需要在本机确认可见范围后手动开启
+        command.auto_run = false;
+        command.scope.server_ids.clear();
+        command.scope.group_ids.clear();
+        config.commands.push(command);
+        summary.commands += 1;
+    }
+    summary
+}
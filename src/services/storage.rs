@@ -1,10 +1,14 @@
-// 本地数据持久化服务
+// 本地数据持久化服务：配置与记录保存在 SQLite 数据库中（见 services/database.rs），私钥与备份仍为文件
 
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::models::server::{
+    AuthType, Identity, IdentityKind, ServerPatch, ServerTemplate, TrashedServer,
+};
 use crate::models::{ServerConfig, ServerData, ServerGroupData};
+use crate::services::database::{Database, StorageKey};
 
 /// 获取配置目录路径
 /// macOS: ~/Library/Application Support/shellmaster
@@ -45,6 +49,67 @@ pub fn get_keys_dir() -> Result<PathBuf> {
     Ok(keys_dir)
 }
 
+/// 每个配置文件保留的自动备份数量
+const MAX_BACKUPS_PER_FILE: usize = 10;
+
+/// 获取自动备份目录路径（配置目录下的 backups）
+pub fn get_backups_dir() -> Result<PathBuf> {
+    let backups_dir = get_config_dir()?.join("backups");
+    if !backups_dir.exists() {
+        fs::create_dir_all(&backups_dir).context("无法创建备份目录")?;
+    }
+    Ok(backups_dir)
+}
+
+/// 获取插件脚本目录
+pub fn get_plugins_dir() -> Result<PathBuf> {
+    let plugins_dir = get_config_dir()?.join("plugins");
+    if !plugins_dir.exists() {
+        fs::create_dir_all(&plugins_dir).context("无法创建插件目录")?;
+    }
+    Ok(plugins_dir)
+}
+
+/// 把文档被覆盖前的内容保存为 backups/<名称>-<时间戳>.json，并清理超出数量的旧备份
+pub fn backup_document(name: &str, content: &str) -> Result<()> {
+    let backups_dir = get_backups_dir()?;
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f");
+    let backup_path = backups_dir.join(format!("{}-{}.json", name, timestamp));
+    fs::write(&backup_path, content).with_context(|| format!("无法写入备份: {:?}", backup_path))?;
+
+    let backups = list_backups(name)?;
+    for old in backups.iter().skip(MAX_BACKUPS_PER_FILE) {
+        let _ = fs::remove_file(old);
+    }
+    Ok(())
+}
+
+/// 文档的自动备份，最新的在前
+pub fn list_backups(name: &str) -> Result<Vec<PathBuf>> {
+    // 时间戳定长，按文件名排序即按时间排序
+    let prefix = format!("{}-", name);
+    let mut backups: Vec<PathBuf> = fs::read_dir(get_backups_dir()?)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix(&prefix))
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .collect();
+    backups.sort();
+    backups.reverse();
+    Ok(backups)
+}
+
+/// 把无法解析的文档另存为 backups/<名称>-corrupted-<时间戳>.json（不参与自动清理）
+pub fn backup_corrupted_document(name: &str, content: &str) -> Result<PathBuf> {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f");
+    let path = get_backups_dir()?.join(format!("{}-corrupted-{}.json", name, timestamp));
+    fs::write(&path, content).with_context(|| format!("无法写入备份: {:?}", path))?;
+    Ok(path)
+}
+
 /// 存储私钥文件到应用密钥目录
 /// 返回存储后的文件名（非完整路径）
 pub fn store_private_key(source_path: &std::path::Path) -> Result<String> {
@@ -86,306 +151,613 @@ pub fn store_private_key(source_path: &std::path::Path) -> Result<String> {
     Ok(stored_filename)
 }
 
-/// 迁移旧的私钥路径到新的密钥目录
-/// 应用启动时调用，将所有使用完整路径的私钥迁移到keys目录
-pub fn migrate_legacy_private_keys() -> Result<()> {
-    let mut config = load_servers()?;
-    let mut updated = false;
+/// 加载服务器配置（读取内存缓存，不存在时为空配置）
+pub fn load_servers() -> Result<ServerConfig> {
+    Database::global().load(StorageKey::Servers)
+}
+
+/// 保存服务器配置
+pub fn save_servers(config: &ServerConfig) -> Result<()> {
+    Database::global().save(StorageKey::Servers, config)
+}
+
+/// 在一个写事务中读取、修改并保存服务器配置
+pub fn update_server_config<R>(f: impl FnOnce(&mut ServerConfig) -> Result<R>) -> Result<R> {
+    Database::global().update(StorageKey::Servers, f)
+}
+
+/// 添加服务器
+pub fn add_server(mut server: ServerData) -> Result<()> {
+    update_server_config(|config| {
+        resolve_server_group(config, &mut server);
+        config.servers.push(server);
+        Ok(())
+    })
+}
+
+/// 服务器的 group_id 不是已有分组时视为分组路径（如 “生产/数据库”），按层级查找或创建分组
+fn resolve_server_group(config: &mut ServerConfig, server: &mut ServerData) {
+    if let Some(group_id) = &server.group_id {
+        if !config.groups.iter().any(|g| g.id == *group_id) {
+            server.group_id = config.ensure_group_path(group_id);
+        }
+    }
+}
+
+/// 更新服务器
+pub fn update_server(mut server: ServerData) -> Result<()> {
+    update_server_config(|config| {
+        resolve_server_group(config, &mut server);
+        if let Some(pos) = config.servers.iter().position(|s| s.id == server.id) {
+            // 编辑弹窗不修改收藏状态
+            server.favorite = config.servers[pos].favorite;
+            config.servers[pos] = server;
+        }
+        Ok(())
+    })
+}
 
-    for server in &mut config.servers {
-        // 如果已有新字段，跳过迁移
-        if server.private_key_filename.is_some() {
-            continue;
+/// 收藏或取消收藏服务器
+pub fn set_server_favorite(server_id: &str, favorite: bool) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(server) = config.servers.iter_mut().find(|s| s.id == server_id) {
+            server.favorite = favorite;
         }
+        Ok(())
+    })
+}
 
-        // 如果有旧的完整路径，尝试迁移
-        if let Some(old_path_str) = server.private_key_path.clone() {
-            let old_path = std::path::Path::new(&old_path_str);
-
-            // 判断是否是完整路径（绝对路径）且文件存在
-            if old_path.is_absolute() && old_path.exists() {
-                match store_private_key(old_path) {
-                    Ok(filename) => {
-                        server.private_key_filename = Some(filename);
-                        server.private_key_path = None; // 清除旧字段
-                        updated = true;
-                        tracing::info!("已迁移服务器 {} 的私钥: {}", server.label, old_path_str);
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            "无法迁移服务器 {} 的私钥: {} - 错误: {}",
-                            server.label,
-                            old_path_str,
-                            e
-                        );
-                    }
-                }
+/// 批量修改服务器，所有修改在同一事务中写入，返回修改的服务器数量
+pub fn update_servers(server_ids: &[String], patch: &ServerPatch) -> Result<usize> {
+    update_server_config(|config| {
+        let group_id = patch
+            .group_path
+            .as_ref()
+            .map(|path| path.as_deref().and_then(|p| config.ensure_group_path(p)));
+
+        let mut count = 0;
+        for server in config
+            .servers
+            .iter_mut()
+            .filter(|s| server_ids.contains(&s.id))
+        {
+            patch.apply(server);
+            if let Some(group_id) = &group_id {
+                server.group_id = group_id.clone();
             }
+            count += 1;
         }
-    }
+        Ok(count)
+    })
+}
 
-    if updated {
-        save_servers(&config)?;
-        tracing::info!("私钥迁移完成");
-    }
+/// 更新服务器的最后连接时间
+pub fn update_server_last_connected(server_id: &str) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(server) = config.servers.iter_mut().find(|s| s.id == server_id) {
+            server.last_connected_at =
+                Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string());
+        }
+        Ok(())
+    })
+}
 
-    Ok(())
+/// 删除服务器：移入回收站，保留期内可恢复（分组由用户管理，不再随最后一台服务器自动删除）
+pub fn delete_server(server_id: &str) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(pos) = config.servers.iter().position(|s| s.id == server_id) {
+            let server = config.servers.remove(pos);
+            config.trash.push(TrashedServer::new(server));
+        }
+        Ok(())
+    })
 }
 
-/// 获取服务器配置文件路径
-pub fn get_servers_file() -> Result<PathBuf> {
-    Ok(get_config_dir()?.join("servers.json"))
+/// 从回收站恢复服务器（排在列表末尾），原分组已删除时恢复到未分组
+pub fn restore_server(server_id: &str) -> Result<bool> {
+    update_server_config(|config| {
+        let Some(pos) = config.trash.iter().position(|t| t.server.id == server_id) else {
+            return Ok(false);
+        };
+        let mut server = config.trash.remove(pos).server;
+        if let Some(group_id) = &server.group_id {
+            if !config.groups.iter().any(|g| g.id == *group_id) {
+                server.group_id = None;
+            }
+        }
+        config.servers.push(server);
+        Ok(true)
+    })
 }
 
-/// 加载服务器配置
-pub fn load_servers() -> Result<ServerConfig> {
-    let path = get_servers_file()?;
-    if !path.exists() {
-        // 返回空配置，不创建默认分组
-        return Ok(ServerConfig {
-            groups: vec![],
-            servers: vec![],
-        });
+/// 从回收站永久删除服务器
+pub fn purge_server(server_id: &str) -> Result<()> {
+    purge_trash(|t| t.server.id == server_id).map(drop)
+}
+
+/// 清空回收站，返回删除的服务器数量
+pub fn empty_trash() -> Result<usize> {
+    purge_trash(|_| true)
+}
+
+/// 永久删除回收站中超过保留期的服务器，返回删除的数量
+pub fn purge_expired_trash() -> Result<usize> {
+    purge_trash(TrashedServer::is_expired)
+}
+
+/// 永久删除回收站中符合条件的服务器
+///
+/// 被删除的条目先写入 backups/trash-<时间戳>.json，与其他自动备份一起轮换保留
+fn purge_trash(filter: impl Fn(&TrashedServer) -> bool) -> Result<usize> {
+    let purged = update_server_config(|config| {
+        let (purged, kept) = std::mem::take(&mut config.trash)
+            .into_iter()
+            .partition::<Vec<_>, _>(|t| filter(t));
+        config.trash = kept;
+        Ok(purged)
+    })?;
+    if !purged.is_empty() {
+        let content = serde_json::to_string_pretty(&purged)?;
+        backup_document("trash", &content)?;
     }
-    let content = fs::read_to_string(&path).context("无法读取服务器配置文件")?;
-    let config: ServerConfig = serde_json::from_str(&content).context("无法解析服务器配置文件")?;
-    Ok(config)
+    Ok(purged.len())
 }
 
-/// 保存服务器配置
-pub fn save_servers(config: &ServerConfig) -> Result<()> {
-    let path = get_servers_file()?;
-    let content = serde_json::to_string_pretty(config).context("无法序列化服务器配置")?;
-    fs::write(&path, content).context("无法写入服务器配置文件")?;
-    Ok(())
+/// 克隆服务器（排在原服务器之后，使用新 ID 与名称），返回新服务器 ID
+pub fn clone_server(server_id: &str, label: String) -> Result<Option<String>> {
+    update_server_config(|config| {
+        let Some(pos) = config.servers.iter().position(|s| s.id == server_id) else {
+            return Ok(None);
+        };
+        let mut server = config.servers[pos].clone();
+        server.id = uuid::Uuid::new_v4().to_string();
+        server.label = label;
+        server.created_at = chrono::Utc::now().to_rfc3339();
+        server.last_connected_at = None;
+        let id = server.id.clone();
+        config.servers.insert(pos + 1, server);
+        Ok(Some(id))
+    })
 }
 
-/// 添加服务器
-pub fn add_server(server: ServerData) -> Result<()> {
-    let mut config = load_servers()?;
+/// 保存服务器模板（已存在则覆盖）；分组路径的处理与服务器一致
+pub fn save_template(mut template: ServerTemplate) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(group_id) = &template.group_id {
+            if !config.groups.iter().any(|g| g.id == *group_id) {
+                template.group_id = config.ensure_group_path(group_id);
+            }
+        }
+        match config.templates.iter().position(|t| t.id == template.id) {
+            Some(pos) => config.templates[pos] = template,
+            None => config.templates.push(template),
+        }
+        Ok(())
+    })
+}
 
-    // 如果服务器指定了分组名但分组不存在，创建新分组
-    if let Some(group_id) = &server.group_id {
-        let group_exists = config.groups.iter().any(|g| g.id == *group_id);
-        if !group_exists {
-            // 使用 group_id 作为名称创建新分组
-            config.groups.push(ServerGroupData {
-                id: group_id.clone(),
-                name: group_id.clone(),
-                icon_path: "icons/server.svg".to_string(),
-            });
+/// 以服务器的设置创建模板，返回模板 ID
+pub fn save_server_as_template(server_id: &str, name: String) -> Result<Option<String>> {
+    update_server_config(|config| {
+        let Some(server) = config.servers.iter().find(|s| s.id == server_id) else {
+            return Ok(None);
+        };
+        let id = uuid::Uuid::new_v4().to_string();
+        let template = ServerTemplate::from_server(id.clone(), name, server);
+        config.templates.push(template);
+        Ok(Some(id))
+    })
+}
+
+/// 删除服务器模板
+pub fn delete_template(template_id: &str) -> Result<()> {
+    update_server_config(|config| {
+        config.templates.retain(|t| t.id != template_id);
+        Ok(())
+    })
+}
+
+/// 保存身份（已存在则覆盖）；引用它的服务器无需修改
+pub fn save_identity(identity: Identity) -> Result<()> {
+    update_server_config(|config| {
+        match config.identities.iter().position(|i| i.id == identity.id) {
+            Some(pos) => config.identities[pos] = identity,
+            None => config.identities.push(identity),
         }
-    }
+        Ok(())
+    })
+}
 
-    config.servers.push(server);
-    save_servers(&config)?;
-    Ok(())
+/// 删除身份，返回受影响的服务器数量
+///
+/// 引用该身份的服务器改为直接使用它的私钥文件（Agent 身份则改为密码认证，需要重新设置）
+pub fn delete_identity(identity_id: &str) -> Result<usize> {
+    update_server_config(|config| {
+        let Some(pos) = config.identities.iter().position(|i| i.id == identity_id) else {
+            return Ok(0);
+        };
+        let identity = config.identities.remove(pos);
+        let mut count = 0;
+        // 回收站中的服务器也一并处理，恢复后仍可连接
+        let trashed = config.trash.iter_mut().map(|t| &mut t.server);
+        for server in config.servers.iter_mut().chain(trashed).filter(|s| {
+            s.auth_type == AuthType::Identity && s.identity_id.as_deref() == Some(identity_id)
+        }) {
+            detach_identity(&identity, &mut server.auth_type, &mut server.username);
+            server.identity_id = None;
+            server.private_key_filename = identity.private_key_filename.clone();
+            server.key_passphrase_encrypted = identity.key_passphrase_encrypted.clone();
+            count += 1;
+        }
+        for template in config
+            .templates
+            .iter_mut()
+            .filter(|t| t.identity_id.as_deref() == Some(identity_id))
+        {
+            detach_identity(&identity, &mut template.auth_type, &mut template.username);
+            template.identity_id = None;
+            template.private_key_filename = identity.private_key_filename.clone();
+            template.key_passphrase_encrypted = identity.key_passphrase_encrypted.clone();
+        }
+        Ok(count)
+    })
 }
 
-/// 更新服务器
-pub fn update_server(server: ServerData) -> Result<()> {
-    let mut config = load_servers()?;
-    if let Some(pos) = config.servers.iter().position(|s| s.id == server.id) {
-        config.servers[pos] = server;
-        save_servers(&config)?;
+/// 身份被删除后，引用方改用的认证方式；未填写的用户名取身份的默认用户名
+fn detach_identity(identity: &Identity, auth_type: &mut AuthType, username: &mut String) {
+    *auth_type = match identity.kind {
+        IdentityKind::KeyFile if identity.private_key_filename.is_some() => AuthType::PublicKey,
+        _ => AuthType::Password,
+    };
+    if username.is_empty() {
+        *username = identity.username.clone();
     }
-    Ok(())
 }
 
-/// 更新服务器的最后连接时间
-pub fn update_server_last_connected(server_id: &str) -> Result<()> {
-    let mut config = load_servers()?;
-    if let Some(server) = config.servers.iter_mut().find(|s| s.id == server_id) {
-        server.last_connected_at = Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string());
-        save_servers(&config)?;
+/// 更换身份的私钥，返回引用该身份的服务器数量
+///
+/// 服务器通过 ID 引用身份，下次连接即使用新私钥；旧私钥文件不再被引用时从 keys 目录删除
+pub fn rotate_identity_key(
+    identity_id: &str,
+    private_key_filename: String,
+    key_passphrase_encrypted: Option<String>,
+) -> Result<usize> {
+    let (old_filename, count) = update_server_config(|config| {
+        let Some(identity) = config.identities.iter_mut().find(|i| i.id == identity_id) else {
+            return Ok((None, 0));
+        };
+        identity.kind = IdentityKind::KeyFile;
+        let old_filename = identity
+            .private_key_filename
+            .replace(private_key_filename.clone());
+        identity.key_passphrase_encrypted = key_passphrase_encrypted;
+        let count = config.identity_users(identity_id).count();
+        Ok((old_filename, count))
+    })?;
+
+    if let Some(old_filename) = old_filename.filter(|old| *old != private_key_filename) {
+        let config = load_servers()?;
+        let referenced = config
+            .servers
+            .iter()
+            .chain(config.trash.iter().map(|t| &t.server))
+            .map(|s| &s.private_key_filename)
+            .chain(config.templates.iter().map(|t| &t.private_key_filename))
+            .chain(config.identities.iter().map(|i| &i.private_key_filename))
+            .any(|f| f.as_deref() == Some(old_filename.as_str()));
+        if !referenced {
+            let path = get_keys_dir()?.join(&old_filename);
+            if let Err(e) = fs::remove_file(&path) {
+                tracing::warn!("无法删除旧私钥 {:?}: {}", path, e);
+            }
+        }
     }
-    Ok(())
+    Ok(count)
 }
 
-/// 删除服务器
-pub fn delete_server(server_id: &str) -> Result<()> {
-    let mut config = load_servers()?;
-    config.servers.retain(|s| s.id != server_id);
-
-    // 自动删除空分组（没有服务器的分组）
-    let groups_with_servers: std::collections::HashSet<String> = config
-        .servers
-        .iter()
-        .filter_map(|s| s.group_id.clone())
-        .collect();
-    config
-        .groups
-        .retain(|g| groups_with_servers.contains(&g.id));
+/// 新建分组，返回分组 ID
+pub fn add_group(name: &str, parent_id: Option<String>) -> Result<String> {
+    update_server_config(|config| {
+        let id = uuid::Uuid::new_v4().to_string();
+        config.groups.push(ServerGroupData {
+            id: id.clone(),
+            name: name.to_string(),
+            parent_id,
+            ..Default::default()
+        });
+        Ok(id)
+    })
+}
 
-    save_servers(&config)?;
-    Ok(())
+/// 重命名分组
+pub fn rename_group(group_id: &str, name: &str) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(group) = config.groups.iter_mut().find(|g| g.id == group_id) {
+            group.name = name.to_string();
+        }
+        Ok(())
+    })
+}
+
+/// 删除分组：其服务器移到 `reassign_to`（None 为未分组），子分组上移到被删分组的父分组
+pub fn delete_group(group_id: &str, reassign_to: Option<String>) -> Result<()> {
+    update_server_config(|config| {
+        let Some(pos) = config.groups.iter().position(|g| g.id == group_id) else {
+            return Ok(());
+        };
+        let removed = config.groups.remove(pos);
+        // 目标分组不能是被删分组本身
+        let reassign_to = reassign_to.filter(|id| id != group_id);
+
+        for group in &mut config.groups {
+            if group.parent_id.as_deref() == Some(group_id) {
+                group.parent_id = removed.parent_id.clone();
+            }
+        }
+        for server in &mut config.servers {
+            if server.group_id.as_deref() == Some(group_id) {
+                server.group_id = reassign_to.clone();
+            }
+        }
+        Ok(())
+    })
 }
 
-/// 获取所有分组
-pub fn get_groups() -> Result<Vec<ServerGroupData>> {
+/// 把分组移到 `parent_id` 下；`before_group_id` 指定时排在该分组之前，否则排在同级末尾
+///
+/// 不允许移动到自身或子孙分组下
+pub fn move_group(
+    group_id: &str,
+    parent_id: Option<String>,
+    before_group_id: Option<&str>,
+) -> Result<()> {
+    update_server_config(|config| {
+        if let Some(parent) = &parent_id {
+            if config.is_within(parent, group_id) {
+                anyhow::bail!("Cannot move a group into itself");
+            }
+        }
+        let Some(pos) = config.groups.iter().position(|g| g.id == group_id) else {
+            return Ok(());
+        };
+        let mut group = config.groups.remove(pos);
+        group.parent_id = parent_id;
+
+        let index = before_group_id
+            .and_then(|before| config.groups.iter().position(|g| g.id == before))
+            .unwrap_or(config.groups.len());
+        config.groups.insert(index, group);
+        Ok(())
+    })
+}
+
+/// 同级分组中上移（`offset` 为 -1）或下移（1）一位
+pub fn shift_group(group_id: &str, offset: isize) -> Result<()> {
     let config = load_servers()?;
-    Ok(config.groups)
+    let Some(group) = config.groups.iter().find(|g| g.id == group_id) else {
+        return Ok(());
+    };
+    let parent_id = group.parent_id.clone();
+    let siblings: Vec<String> = config
+        .child_groups(parent_id.as_deref())
+        .map(|g| g.id.clone())
+        .collect();
+    let Some(index) = siblings.iter().position(|id| id == group_id) else {
+        return Ok(());
+    };
+    let target = index as isize + offset;
+    if target < 0 || target as usize >= siblings.len() {
+        return Ok(());
+    }
+    // 下移时插到下下个兄弟之前（或末尾）
+    let before = if offset < 0 {
+        siblings.get(target as usize)
+    } else {
+        siblings.get(target as usize + 1)
+    };
+    move_group(group_id, parent_id, before.map(String::as_str))
+}
+
+/// 把服务器移到 `group_id`（None 为未分组）；`before_server_id` 指定时排在该服务器之前，否则排在末尾
+pub fn move_server(
+    server_id: &str,
+    group_id: Option<String>,
+    before_server_id: Option<&str>,
+) -> Result<()> {
+    if before_server_id == Some(server_id) {
+        return Ok(());
+    }
+    update_server_config(|config| {
+        let Some(pos) = config.servers.iter().position(|s| s.id == server_id) else {
+            return Ok(());
+        };
+        let mut server = config.servers.remove(pos);
+        server.group_id = group_id;
+
+        let index = before_server_id
+            .and_then(|before| config.servers.iter().position(|s| s.id == before))
+            .unwrap_or(config.servers.len());
+        config.servers.insert(index, server);
+        Ok(())
+    })
 }
 
 // ======================== Settings 配置持久化 ========================
 
 use crate::models::AppSettings;
 
-/// 获取设置配置文件路径
-pub fn get_settings_file() -> Result<PathBuf> {
-    Ok(get_config_dir()?.join("settings.json"))
-}
-
-/// 加载应用设置
+/// 加载应用设置（读取内存缓存，渲染时可放心调用）
 pub fn load_settings() -> Result<AppSettings> {
-    let path = get_settings_file()?;
-    if !path.exists() {
-        return Ok(AppSettings::default());
-    }
-    let content = fs::read_to_string(&path).context("无法读取设置配置文件")?;
-    let settings: AppSettings = serde_json::from_str(&content).context("无法解析设置配置文件")?;
-    Ok(settings)
+    Database::global().load(StorageKey::Settings)
 }
 
 /// 保存应用设置
 pub fn save_settings(settings: &AppSettings) -> Result<()> {
-    let path = get_settings_file()?;
-    let content = serde_json::to_string_pretty(settings).context("无法序列化设置配置")?;
-    fs::write(&path, content).context("无法写入设置配置文件")?;
-    Ok(())
+    Database::global().save(StorageKey::Settings, settings)
 }
 
 // ======================== Snippets (快捷命令) 持久化 ========================
 
-use crate::models::{SnippetCommand, SnippetGroup, SnippetsConfig};
-
-/// 获取 Snippets 配置文件路径
-pub fn get_snippets_file() -> Result<PathBuf> {
-    Ok(get_config_dir()?.join("snippets.json"))
-}
+use crate::models::{Runbook, SnippetCommand, SnippetGroup, SnippetsConfig, TerminalMacro};
 
 /// 加载 Snippets 配置
 pub fn load_snippets() -> Result<SnippetsConfig> {
-    let path = get_snippets_file()?;
-    if !path.exists() {
-        return Ok(SnippetsConfig::default());
-    }
-    let content = fs::read_to_string(&path).context("无法读取 Snippets 配置文件")?;
-    let config: SnippetsConfig =
-        serde_json::from_str(&content).context("无法解析 Snippets 配置文件")?;
-    Ok(config)
+    Database::global().load(StorageKey::Snippets)
 }
 
 /// 保存 Snippets 配置
 pub fn save_snippets(config: &SnippetsConfig) -> Result<()> {
-    let path = get_snippets_file()?;
-    let content = serde_json::to_string_pretty(config).context("无法序列化 Snippets 配置")?;
-    fs::write(&path, content).context("无法写入 Snippets 配置文件")?;
-    Ok(())
+    Database::global().save(StorageKey::Snippets, config)
+}
+
+/// 在一个写事务中读取、修改并保存 Snippets 配置
+pub fn update_snippets<R>(f: impl FnOnce(&mut SnippetsConfig) -> Result<R>) -> Result<R> {
+    Database::global().update(StorageKey::Snippets, f)
 }
 
 /// 添加命令组
 pub fn add_snippet_group(group: SnippetGroup) -> Result<()> {
-    let mut config = load_snippets()?;
-    config.groups.push(group);
-    save_snippets(&config)?;
-    Ok(())
+    update_snippets(|config| {
+        config.groups.push(group);
+        Ok(())
+    })
 }
 
 /// 更新命令组
 pub fn update_snippet_group(group: SnippetGroup) -> Result<()> {
-    let mut config = load_snippets()?;
-    if let Some(pos) = config.groups.iter().position(|g| g.id == group.id) {
-        config.groups[pos] = group;
-        save_snippets(&config)?;
-    }
-    Ok(())
+    update_snippets(|config| {
+        if let Some(pos) = config.groups.iter().position(|g| g.id == group.id) {
+            config.groups[pos] = group;
+        }
+        Ok(())
+    })
 }
 
 /// 删除命令组（级联删除子组和命令）
 pub fn delete_snippet_group(group_id: &str) -> Result<()> {
-    let mut config = load_snippets()?;
-
-    // 收集所有要删除的组 ID（包括子组）
-    let mut to_delete = vec![group_id.to_string()];
-    let mut i = 0;
-    while i < to_delete.len() {
-        let parent_id = &to_delete[i];
-        let children: Vec<String> = config
-            .groups
-            .iter()
-            .filter(|g| g.parent_id.as_deref() == Some(parent_id))
-            .map(|g| g.id.clone())
-            .collect();
-        to_delete.extend(children);
-        i += 1;
-    }
-
-    // 删除组和相关命令
-    config.groups.retain(|g| !to_delete.contains(&g.id));
-    config.commands.retain(|c| {
-        c.group_id
-            .as_ref()
-            .map_or(true, |gid| !to_delete.contains(gid))
-    });
+    update_snippets(|config| {
+        // 收集所有要删除的组 ID（包括子组）
+        let mut to_delete = vec![group_id.to_string()];
+        let mut i = 0;
+        while i < to_delete.len() {
+            let parent_id = &to_delete[i];
+            let children: Vec<String> = config
+                .groups
+                .iter()
+                .filter(|g| g.parent_id.as_deref() == Some(parent_id))
+                .map(|g| g.id.clone())
+                .collect();
+            to_delete.extend(children);
+            i += 1;
+        }
 
-    save_snippets(&config)?;
-    Ok(())
+        // 删除组和相关命令
+        config.groups.retain(|g| !to_delete.contains(&g.id));
+        config.commands.retain(|c| {
+            c.group_id
+                .as_ref()
+                .map_or(true, |gid| !to_delete.contains(gid))
+        });
+        Ok(())
+    })
 }
 
 /// 添加命令
 pub fn add_snippet_command(command: SnippetCommand) -> Result<()> {
-    let mut config = load_snippets()?;
-    config.commands.push(command);
-    save_snippets(&config)?;
-    Ok(())
+    update_snippets(|config| {
+        config.commands.push(command);
+        Ok(())
+    })
 }
 
 /// 更新命令
 pub fn update_snippet_command(command: SnippetCommand) -> Result<()> {
-    let mut config = load_snippets()?;
-    if let Some(pos) = config.commands.iter().position(|c| c.id == command.id) {
-        config.commands[pos] = command;
-        save_snippets(&config)?;
-    }
-    Ok(())
+    update_snippets(|config| {
+        if let Some(pos) = config.commands.iter().position(|c| c.id == command.id) {
+            config.commands[pos] = command;
+        }
+        Ok(())
+    })
 }
 
 /// 删除命令
 pub fn delete_snippet_command(command_id: &str) -> Result<()> {
-    let mut config = load_snippets()?;
-    config.commands.retain(|c| c.id != command_id);
-    save_snippets(&config)?;
-    Ok(())
+    update_snippets(|config| {
+        config.commands.retain(|c| c.id != command_id);
+        Ok(())
+    })
 }
 
-// ======================== Known Hosts 持久化 ========================
+/// 记住在服务器上运行快捷命令时填写的变量值，下次运行时作为初始值
+pub fn remember_snippet_variables(
+    server_id: &str,
+    values: &std::collections::HashMap<String, String>,
+) -> Result<()> {
+    update_snippets(|config| {
+        config
+            .variable_values
+            .entry(server_id.to_string())
+            .or_default()
+            .extend(values.iter().map(|(k, v)| (k.clone(), v.clone())));
+        Ok(())
+    })
+}
 
-use crate::models::{KnownHost, KnownHostsConfig};
+/// 保存 Runbook（已存在则替换，否则追加）
+pub fn save_runbook(runbook: Runbook) -> Result<()> {
+    update_snippets(|config| {
+        match config.runbooks.iter().position(|r| r.id == runbook.id) {
+            Some(pos) => config.runbooks[pos] = runbook,
+            None => config.runbooks.push(runbook),
+        }
+        Ok(())
+    })
+}
+
+/// 删除 Runbook
+pub fn delete_runbook(runbook_id: &str) -> Result<()> {
+    update_snippets(|config| {
+        config.runbooks.retain(|r| r.id != runbook_id);
+        Ok(())
+    })
+}
+
+/// 保存键盘宏（已存在则替换，否则追加）
+pub fn save_macro(terminal_macro: TerminalMacro) -> Result<()> {
+    update_snippets(|config| {
+        match config.macros.iter().position(|m| m.id == terminal_macro.id) {
+            Some(pos) => config.macros[pos] = terminal_macro,
+            None => config.macros.push(terminal_macro),
+        }
+        Ok(())
+    })
+}
 
-/// 获取 Known Hosts 配置文件路径
-pub fn get_known_hosts_file() -> Result<PathBuf> {
-    Ok(get_config_dir()?.join("known_hosts.json"))
+/// 删除键盘宏
+pub fn delete_macro(macro_id: &str) -> Result<()> {
+    update_snippets(|config| {
+        config.macros.retain(|m| m.id != macro_id);
+        Ok(())
+    })
 }
 
+// ======================== Known Hosts 持久化 ========================
+
+use crate::models::{KnownHost, KnownHostsConfig};
+
 /// 加载 Known Hosts 配置
 pub fn load_known_hosts() -> Result<KnownHostsConfig> {
-    let path = get_known_hosts_file()?;
-    if !path.exists() {
-        return Ok(KnownHostsConfig::default());
-    }
-    let content = fs::read_to_string(&path).context("无法读取 Known Hosts 配置文件")?;
-    let config: KnownHostsConfig =
-        serde_json::from_str(&content).context("无法解析 Known Hosts 配置文件")?;
-    Ok(config)
+    Database::global().load(StorageKey::KnownHosts)
 }
 
 /// 保存 Known Hosts 配置
 pub fn save_known_hosts(config: &KnownHostsConfig) -> Result<()> {
-    let path = get_known_hosts_file()?;
-    let content = serde_json::to_string_pretty(config).context("无法序列化 Known Hosts 配置")?;
-    fs::write(&path, content).context("无法写入 Known Hosts 配置文件")?;
-    Ok(())
+    Database::global().save(StorageKey::KnownHosts, config)
+}
+
+/// 在一个写事务中读取、修改并保存 Known Hosts 配置
+fn update_known_hosts<R>(f: impl FnOnce(&mut KnownHostsConfig) -> Result<R>) -> Result<R> {
+    Database::global().update(StorageKey::KnownHosts, f)
 }
 
 /// 查找已知主机（通过 host:port）
@@ -397,46 +769,239 @@ pub fn find_known_host(host: &str, port: u16) -> Result<Option<KnownHost>> {
 
 /// 添加已知主机
 pub fn add_known_host(host: &str, port: u16, key_type: &str, fingerprint: &str) -> Result<()> {
-    let mut config = load_known_hosts()?;
     let key = format!("{}:{}", host, port);
     let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-
-    // 如果已存在，更新 last_used
-    if let Some(existing) = config.hosts.iter_mut().find(|h| h.host == key) {
-        existing.last_used = now;
-        existing.fingerprint = fingerprint.to_string();
-        existing.key_type = key_type.to_string();
-    } else {
-        // 添加新条目
-        config.hosts.push(KnownHost {
-            host: key,
-            key_type: key_type.to_string(),
-            fingerprint: fingerprint.to_string(),
-            first_seen: now.clone(),
-            last_used: now,
-        });
-    }
-
-    save_known_hosts(&config)?;
-    Ok(())
+    update_known_hosts(|config| {
+        // 如果已存在，更新 last_used
+        if let Some(existing) = config.hosts.iter_mut().find(|h| h.host == key) {
+            existing.last_used = now;
+            existing.fingerprint = fingerprint.to_string();
+            existing.key_type = key_type.to_string();
+        } else {
+            // 添加新条目
+            config.hosts.push(KnownHost {
+                host: key,
+                key_type: key_type.to_string(),
+                fingerprint: fingerprint.to_string(),
+                first_seen: now.clone(),
+                last_used: now,
+            });
+        }
+        Ok(())
+    })
 }
 
 /// 删除已知主机
 pub fn remove_known_host(host: &str, port: u16) -> Result<()> {
-    let mut config = load_known_hosts()?;
     let key = format!("{}:{}", host, port);
-    config.hosts.retain(|h| h.host != key);
-    save_known_hosts(&config)?;
-    Ok(())
+    update_known_hosts(|config| {
+        config.hosts.retain(|h| h.host != key);
+        Ok(())
+    })
 }
 
 /// 更新已知主机的最后使用时间
 pub fn update_known_host_last_used(host: &str, port: u16) -> Result<()> {
-    let mut config = load_known_hosts()?;
     let key = format!("{}:{}", host, port);
-    if let Some(h) = config.hosts.iter_mut().find(|h| h.host == key) {
-        h.last_used = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-        save_known_hosts(&config)?;
-    }
-    Ok(())
+    update_known_hosts(|config| {
+        if let Some(h) = config.hosts.iter_mut().find(|h| h.host == key) {
+            h.last_used = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        }
+        Ok(())
+    })
+}
+
+// ======================== 传输历史持久化 ========================
+
+use crate::models::sftp::TransferHistory;
+
+/// 加载传输历史
+pub fn load_transfer_history() -> Result<TransferHistory> {
+    Database::global().load(StorageKey::TransferHistory)
+}
+
+/// 保存传输历史（属于记录而非配置，不做自动备份）
+pub fn save_transfer_history(history: &TransferHistory) -> Result<()> {
+    Database::global().save(StorageKey::TransferHistory, history)
+}
+
+// ======================== 连接历史持久化 ========================
+
+use crate::models::history::ConnectionHistory;
+
+/// 加载连接历史
+pub fn load_connection_history() -> Result<ConnectionHistory> {
+    Database::global().load(StorageKey::ConnectionHistory)
+}
+
+/// 保存连接历史（属于记录而非配置，不做自动备份）
+pub fn save_connection_history(history: &ConnectionHistory) -> Result<()> {
+    Database::global().save(StorageKey::ConnectionHistory, history)
+}
+
+// ======================== SFTP 收藏夹持久化 ========================
+
+use crate::models::sftp::SftpBookmarks;
+
+/// 加载 SFTP 收藏夹
+pub fn load_sftp_bookmarks() -> Result<SftpBookmarks> {
+    Database::global().load(StorageKey::SftpBookmarks)
+}
+
+/// 保存 SFTP 收藏夹
+pub fn save_sftp_bookmarks(bookmarks: &SftpBookmarks) -> Result<()> {
+    Database::global().save(StorageKey::SftpBookmarks, bookmarks)
+}
+
+// ======================== 会话工作区持久化 ========================
+
+use crate::models::workspace::{Workspace, WorkspacesConfig};
+
+/// 加载会话工作区
+pub fn load_workspaces() -> Result<WorkspacesConfig> {
+    Database::global().load(StorageKey::Workspaces)
+}
+
+/// 保存工作区（同名的工作区被覆盖）
+pub fn save_workspace(workspace: Workspace) -> Result<()> {
+    Database::global().update(StorageKey::Workspaces, |config: &mut WorkspacesConfig| {
+        config.workspaces.retain(|w| w.name != workspace.name);
+        config.workspaces.push(workspace);
+        Ok(())
+    })
+}
+
+/// 删除工作区
+pub fn delete_workspace(workspace_id: &str) -> Result<()> {
+    Database::global().update(StorageKey::Workspaces, |config: &mut WorkspacesConfig| {
+        config.workspaces.retain(|w| w.id != workspace_id);
+        Ok(())
+    })
+}
+
+/// 记录退出时打开的会话（没有打开的会话时清除）
+pub fn save_last_session(workspace: Option<Workspace>) -> Result<()> {
+    Database::global().update(StorageKey::Workspaces, |config: &mut WorkspacesConfig| {
+        config.last_session = workspace;
+        Ok(())
+    })
+}
+
+// ======================== 定时任务持久化 ========================
+
+use crate::models::schedule::{JobHistory, JobRun, ScheduledJob, SchedulesConfig, MAX_JOB_HISTORY};
+
+/// 加载定时任务
+pub fn load_schedules() -> Result<SchedulesConfig> {
+    Database::global().load(StorageKey::Schedules)
+}
+
+/// 保存定时任务（已存在则替换，否则追加）
+pub fn save_scheduled_job(job: ScheduledJob) -> Result<()> {
+    Database::global().update(StorageKey::Schedules, |config: &mut SchedulesConfig| {
+        match config.jobs.iter().position(|j| j.id == job.id) {
+            Some(pos) => config.jobs[pos] = job,
+            None => config.jobs.push(job),
+        }
+        Ok(())
+    })
+}
+
+/// 启用或停用定时任务
+pub fn set_scheduled_job_enabled(job_id: &str, enabled: bool) -> Result<()> {
+    Database::global().update(StorageKey::Schedules, |config: &mut SchedulesConfig| {
+        if let Some(job) = config.jobs.iter_mut().find(|j| j.id == job_id) {
+            job.enabled = enabled;
+        }
+        Ok(())
+    })
+}
+
+/// 删除定时任务（运行记录保留）
+pub fn delete_scheduled_job(job_id: &str) -> Result<()> {
+    Database::global().update(StorageKey::Schedules, |config: &mut SchedulesConfig| {
+        config.jobs.retain(|j| j.id != job_id);
+        Ok(())
+    })
+}
+
+/// 加载定时任务运行记录
+pub fn load_job_history() -> Result<JobHistory> {
+    Database::global().load(StorageKey::JobHistory)
+}
+
+/// 记录一次运行（超出上限时丢弃最旧的记录）
+pub fn add_job_run(run: JobRun) -> Result<()> {
+    Database::global().update(StorageKey::JobHistory, |history: &mut JobHistory| {
+        history.runs.insert(0, run);
+        history.runs.truncate(MAX_JOB_HISTORY);
+        Ok(())
+    })
+}
+
+/// 清空运行记录
+pub fn clear_job_history() -> Result<()> {
+    Database::global().save(StorageKey::JobHistory, &JobHistory::default())
+}
+
+// ======================== 窗口状态持久化 ========================
+
+use crate::models::window_state::{PanelLayout, WindowState};
+
+/// 加载窗口状态（读取内存缓存，渲染时可放心调用）
+pub fn load_window_state() -> Result<WindowState> {
+    Database::global().load(StorageKey::WindowState)
+}
+
+/// 保存主窗口的位置、大小与所在显示器（面板布局保持不变）
+pub fn save_window_placement(placement: WindowState) -> Result<()> {
+    Database::global().update(StorageKey::WindowState, |state: &mut WindowState| {
+        *state = WindowState {
+            layout: std::mem::take(&mut state.layout),
+            server_layouts: std::mem::take(&mut state.server_layouts),
+            ..placement
+        };
+        Ok(())
+    })
+}
+
+/// 修改会话面板布局
+pub fn update_panel_layout(f: impl FnOnce(&mut PanelLayout)) -> Result<()> {
+    Database::global().update(StorageKey::WindowState, |state: &mut WindowState| {
+        f(&mut state.layout);
+        Ok(())
+    })
+}
+
+/// 服务器的会话面板布局（没有单独记录时使用最近一次调整的布局）
+pub fn load_server_layout(server_id: &str) -> PanelLayout {
+    let state = load_window_state().unwrap_or_default();
+    state
+        .server_layouts
+        .get(server_id)
+        .cloned()
+        .unwrap_or(state.layout)
+}
+
+/// 修改服务器的会话面板布局，修改后的布局同时作为其他服务器的默认布局
+pub fn update_server_layout(server_id: &str, f: impl Fn(&mut PanelLayout)) -> Result<()> {
+    Database::global().update(StorageKey::WindowState, |state: &mut WindowState| {
+        let layout = state
+            .server_layouts
+            .entry(server_id.to_string())
+            .or_insert_with(|| state.layout.clone());
+        f(layout);
+        f(&mut state.layout);
+        Ok(())
+    })
+}
+
+/// 恢复服务器的默认面板布局
+pub fn reset_server_layout(server_id: &str) -> Result<()> {
+    Database::global().update(StorageKey::WindowState, |state: &mut WindowState| {
+        state
+            .server_layouts
+            .insert(server_id.to_string(), PanelLayout::default());
+        Ok(())
+    })
 }
@@ -0,0 +1,299 @@
+// Git 同步后端
+// 把配置（不含密码与私钥）写入本地仓库并提交，再通过应用自身的 SSH 连接（见 git_ssh）与远程仓库拉取/推送；
+// 仓库历史可用于恢复某一版本的服务器列表
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::info;
+
+use super::git_ssh::{GIT_KEY_ENV, GIT_SSH_ARG};
+use super::SyncOutcome;
+use crate::models::settings::SyncSettings;
+use crate::models::ServerConfig;
+use crate::services::config_archive::{ArchiveScope, ConfigArchive};
+use crate::services::storage;
+
+/// 本地仓库目录（位于配置目录）
+const REPO_DIR: &str = "git-sync";
+const SERVERS_FILE: &str = "servers.json";
+const SNIPPETS_FILE: &str = "snippets.json";
+const SETTINGS_FILE: &str = "settings.json";
+const KEYBINDINGS_FILE: &str = "keybindings.json";
+/// 历史列表显示的最大提交数
+const HISTORY_LIMIT: usize = 50;
+
+/// 服务器列表的一个历史版本
+#[derive(Clone, Debug)]
+pub struct GitCommit {
+    pub id: String,
+    pub time: String,
+    pub message: String,
+}
+
+fn repo_dir() -> Result<PathBuf> {
+    let dir = storage::get_config_dir()?.join(REPO_DIR);
+    if !dir.exists() {
+        fs::create_dir_all(&dir).context("无法创建 Git 同步目录")?;
+    }
+    Ok(dir)
+}
+
+/// 执行 git 命令，SSH 传输交给应用自身（GIT_SSH_COMMAND 指向本程序的转发模式）
+fn git(repo: &Path, settings: &SyncSettings, args: &[&str]) -> Result<String> {
+    let exe = std::env::current_exe().context("无法获取程序路径")?;
+    let output = Command::new("git")
+        .current_dir(repo)
+        .args([
+            "-c",
+            "user.name=ShellMaster",
+            "-c",
+            "user.email=shellmaster@localhost",
+        ])
+        .args(args)
+        .env(
+            "GIT_SSH_COMMAND",
+            format!("\"{}\" {}", exe.display(), GIT_SSH_ARG),
+        )
+        .env("GIT_SSH_VARIANT", "ssh")
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .env(GIT_KEY_ENV, &settings.git_private_key)
+        .output()
+        .context("无法运行 git，请确认已安装 Git")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args[0], stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 仓库是否存在某个引用
+fn has_ref(repo: &Path, settings: &SyncSettings, name: &str) -> bool {
+    git(repo, settings, &["rev-parse", "--verify", "--quiet", name]).is_ok()
+}
+
+fn head(repo: &Path, settings: &SyncSettings) -> Option<String> {
+    git(repo, settings, &["rev-parse", "HEAD"])
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+/// 打开本地仓库，必要时初始化并设置远程地址
+fn open_repo(settings: &SyncSettings) -> Result<PathBuf> {
+    if settings.git_remote.trim().is_empty() {
+        bail!("Git remote is not configured");
+    }
+    let repo = repo_dir()?;
+    if !repo.join(".git").exists() {
+        git(&repo, settings, &["init"])?;
+    }
+    let remote = settings.git_remote.trim();
+    if git(&repo, settings, &["remote", "get-url", "origin"]).is_ok() {
+        git(&repo, settings, &["remote", "set-url", "origin", remote])?;
+    } else {
+        git(&repo, settings, &["remote", "add", "origin", remote])?;
+    }
+    Ok(repo)
+}
+
+/// 测试能否访问远程仓库
+pub fn test_connection(settings: &SyncSettings) -> Result<()> {
+    let repo = open_repo(settings)?;
+    git(&repo, settings, &["ls-remote", "--heads", "origin"])?;
+    Ok(())
+}
+
+/// 执行一次同步：提交本地改动，变基到远程最新提交，应用远程改动，再推送
+///
+/// 本机首次同步且远程已有内容时以远程为准（被覆盖的本地配置在 storage 的自动备份中）
+pub fn sync(settings: &SyncSettings) -> Result<SyncOutcome> {
+    let repo = open_repo(settings)?;
+    let branch = settings.git_branch.trim();
+    let remote_ref = format!("origin/{}", branch);
+    let scope = super::scope(settings);
+
+    git(&repo, settings, &["fetch", "origin"])?;
+    let remote_exists = has_ref(&repo, settings, &remote_ref);
+
+    let mut pulled = false;
+    if head(&repo, settings).is_none() {
+        if remote_exists {
+            git(&repo, settings, &["checkout", "-B", branch, &remote_ref])?;
+            apply_tree(&repo, settings, scope)?;
+            pulled = true;
+        } else {
+            let head_ref = format!("refs/heads/{}", branch);
+            git(&repo, settings, &["symbolic-ref", "HEAD", &head_ref])?;
+        }
+    }
+
+    write_tree(&repo, scope)?;
+    git(&repo, settings, &["add", "-A"])?;
+    let local_changed = !git(&repo, settings, &["status", "--porcelain"])?
+        .trim()
+        .is_empty();
+    if local_changed {
+        let message = format!(
+            "Update config ({})",
+            Local::now().format("%Y-%m-%d %H:%M:%S")
+        );
+        git(&repo, settings, &["commit", "-m", &message])?;
+    }
+
+    if remote_exists && !pulled {
+        let before = head(&repo, settings);
+        if let Err(e) = git(&repo, settings, &["rebase", &remote_ref]) {
+            let _ = git(&repo, settings, &["rebase", "--abort"]);
+            bail!(
+                "Local and remote changes conflict, resolve them in {:?}: {}",
+                repo,
+                e
+            );
+        }
+        if head(&repo, settings) != before {
+            apply_tree(&repo, settings, scope)?;
+            pulled = true;
+        }
+    }
+
+    let ahead = if remote_exists {
+        let range = format!("{}..HEAD", remote_ref);
+        git(&repo, settings, &["rev-list", "--count", &range])?.trim() != "0"
+    } else {
+        head(&repo, settings).is_some()
+    };
+    if ahead {
+        let refspec = format!("HEAD:refs/heads/{}", branch);
+        git(&repo, settings, &["push", "origin", &refspec])?;
+    }
+
+    super::save_record(&head(&repo, settings).unwrap_or_default());
+    info!(
+        "[Sync] Git sync finished (pushed: {}, pulled: {})",
+        ahead, pulled
+    );
+    Ok(match (ahead && local_changed, pulled) {
+        (true, true) => SyncOutcome::Merged,
+        (true, false) => SyncOutcome::Pushed,
+        (false, true) => SyncOutcome::Pulled,
+        (false, false) => SyncOutcome::UpToDate,
+    })
+}
+
+/// 服务器列表的历史版本（新的在前）
+pub fn history(settings: &SyncSettings) -> Result<Vec<GitCommit>> {
+    let repo = open_repo(settings)?;
+    if head(&repo, settings).is_none() {
+        return Ok(Vec::new());
+    }
+    let limit = format!("-n{}", HISTORY_LIMIT);
+    let log = git(
+        &repo,
+        settings,
+        &[
+            "log",
+            &limit,
+            "--date=format:%Y-%m-%d %H:%M",
+            "--format=%H%x1f%ad%x1f%s",
+            "--",
+            SERVERS_FILE,
+        ],
+    )?;
+    Ok(log
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\x1f');
+            Some(GitCommit {
+                id: parts.next()?.to_string(),
+                time: parts.next()?.to_string(),
+                message: parts.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect())
+}
+
+/// 把服务器列表恢复到某个历史版本（保留本地已保存的密码），返回恢复后的服务器数量
+///
+/// 恢复结果在下次同步时作为新的提交推送
+pub fn restore_servers(settings: &SyncSettings, commit: &str) -> Result<usize> {
+    let repo = open_repo(settings)?;
+    let spec = format!("{}:{}", commit, SERVERS_FILE);
+    let content = git(&repo, settings, &["show", &spec])?;
+    let servers: ServerConfig =
+        serde_json::from_str(&content).context("无法解析历史版本的服务器配置")?;
+
+    let mut archive = ConfigArchive::snapshot(false)?;
+    archive.servers = servers;
+    let summary = archive.apply(ArchiveScope {
+        servers: true,
+        snippets: false,
+        settings: false,
+        keybindings: false,
+    })?;
+    info!("[Sync] Restored server list from commit {}", commit);
+    Ok(summary.servers)
+}
+
+/// 把本地配置写入仓库工作区（不含密码、私钥与同步设置）
+fn write_tree(repo: &Path, scope: ArchiveScope) -> Result<()> {
+    let mut archive = ConfigArchive::snapshot(false)?;
+    for server in &mut archive.servers.servers {
+        // 最近连接时间每次连接都会变化，不纳入版本
+        server.last_connected_at = None;
+    }
+    archive.settings.sync = SyncSettings::default();
+
+    if scope.servers {
+        write_json(&repo.join(SERVERS_FILE), &archive.servers)?;
+    }
+    if scope.snippets {
+        write_json(&repo.join(SNIPPETS_FILE), &archive.snippets)?;
+    }
+    if scope.settings {
+        write_json(&repo.join(SETTINGS_FILE), &archive.settings)?;
+    }
+    if let (true, Some(keybindings)) = (scope.keybindings, &archive.keybindings) {
+        write_json(&repo.join(KEYBINDINGS_FILE), keybindings)?;
+    }
+    Ok(())
+}
+
+/// 把仓库工作区的配置应用到本地（保留本地密码与同步设置）
+fn apply_tree(repo: &Path, settings: &SyncSettings, scope: ArchiveScope) -> Result<()> {
+    let mut archive = ConfigArchive::snapshot(false)?;
+    if let Some(servers) = read_json(&repo.join(SERVERS_FILE))? {
+        archive.servers = servers;
+    }
+    if let Some(snippets) = read_json(&repo.join(SNIPPETS_FILE))? {
+        archive.snippets = snippets;
+    }
+    if let Some(app_settings) = read_json(&repo.join(SETTINGS_FILE))? {
+        archive.settings = app_settings;
+    }
+    if let Some(keybindings) = read_json(&repo.join(KEYBINDINGS_FILE))? {
+        archive.keybindings = Some(keybindings);
+    }
+    archive.settings.sync = settings.clone();
+    archive.apply(scope)?;
+    Ok(())
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let content = serde_json::to_string_pretty(value).context("无法序列化配置")?;
+    fs::write(path, content).with_context(|| format!("无法写入 {:?}", path))
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path).with_context(|| format!("无法读取 {:?}", path))?;
+    Ok(Some(
+        serde_json::from_str(&content).with_context(|| format!("无法解析 {:?}", path))?,
+    ))
+}
@@ -0,0 +1,196 @@
+// Git 的 SSH 传输：应用以 `--git-ssh` 参数被 git 作为 GIT_SSH_COMMAND 调用，
+// 用应用自身的 SSH 连接执行 git-upload-pack / git-receive-pack，并把标准输入输出与通道对接
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use russh::ChannelMsg;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::ssh::config::{AuthMethod, SshConfig};
+use crate::ssh::event::{ConnectionEvent, HostKeyAction};
+use crate::ssh::SshClient;
+
+/// 以 SSH 转发模式启动时的命令行参数
+pub const GIT_SSH_ARG: &str = "--git-ssh";
+/// 传递私钥路径的环境变量
+pub const GIT_KEY_ENV: &str = "SHELLMASTER_GIT_KEY";
+
+/// git 传入的连接目标
+struct Target {
+    username: String,
+    host: String,
+    port: u16,
+    command: String,
+}
+
+/// 转发模式入口，返回进程退出码
+pub fn run_git_ssh(args: &[String]) -> i32 {
+    let Some(target) = parse_args(args) else {
+        eprintln!(
+            "shellmaster: usage: {} [-p port] [user@]host command",
+            GIT_SSH_ARG
+        );
+        return 255;
+    };
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("shellmaster: {}", e);
+            return 255;
+        }
+    };
+    match runtime.block_on(pipe(target)) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("shellmaster: {:#}", e);
+            255
+        }
+    }
+}
+
+/// 解析 git 传入的 ssh 参数（GIT_SSH_VARIANT=ssh）：`[-o opt] [-p port] [-4|-6] [user@]host command`
+fn parse_args(args: &[String]) -> Option<Target> {
+    let mut port = 22;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-p" => port = iter.next()?.parse().ok()?,
+            "-o" => {
+                iter.next();
+            }
+            flag if flag.starts_with('-') && rest.is_empty() => {}
+            _ => rest.push(arg.clone()),
+        }
+    }
+    let (destination, command) = rest.split_first()?;
+    let (username, host) = destination
+        .split_once('@')
+        .unwrap_or(("git", destination.as_str()));
+    Some(Target {
+        username: username.to_string(),
+        host: host.to_string(),
+        port,
+        command: command.join(" "),
+    })
+}
+
+/// 私钥路径：优先使用同步设置中指定的，否则使用 ~/.ssh 下的默认私钥
+fn key_path() -> Result<PathBuf> {
+    if let Some(path) = std::env::var_os(GIT_KEY_ENV).filter(|p| !p.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+    let ssh_dir = dirs::home_dir().context("无法获取用户目录")?.join(".ssh");
+    ["id_ed25519", "id_ecdsa", "id_rsa"]
+        .iter()
+        .map(|name| ssh_dir.join(name))
+        .find(|path| path.exists())
+        .context("No private key configured for Git sync")
+}
+
+/// 转发模式无法交互输入私钥密码：私钥有密码保护时直接给出说明，而不是笼统的认证失败
+fn ensure_unencrypted_key(path: &Path) -> Result<()> {
+    let key_data = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read private key {}", path.display()))?;
+    match russh::keys::decode_secret_key(&key_data, None) {
+        Err(russh::keys::Error::KeyIsEncrypted) => bail!(
+            "Private key {} is protected by a passphrase, which Git sync cannot prompt for; \
+             choose a key without a passphrase in the sync settings",
+            path.display()
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// 连接并执行 git 的远程命令，返回远程命令的退出码
+async fn pipe(target: Target) -> Result<i32> {
+    let key_path = key_path()?;
+    ensure_unencrypted_key(&key_path)?;
+    let config = SshConfig {
+        host: target.host,
+        port: target.port,
+        username: target.username,
+        auth: AuthMethod::PublicKey {
+            key_path,
+            passphrase: None,
+        },
+        ..Default::default()
+    };
+
+    // 无法交互确认主机密钥：首次连接时信任并保存，密钥变化时拒绝
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let (host_key_tx, host_key_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let mut host_key_tx = Some(host_key_tx);
+        while let Some(event) = event_rx.recv().await {
+            let action = match event {
+                ConnectionEvent::HostKeyVerification { .. } => HostKeyAction::AcceptAndSave,
+                ConnectionEvent::HostKeyMismatch { .. } => HostKeyAction::Reject,
+                _ => continue,
+            };
+            if let Some(tx) = host_key_tx.take() {
+                let _ = tx.send(action);
+            }
+        }
+    });
+
+    let mut client = SshClient::new(config, event_tx, host_key_rx);
+    let session = client
+        .connect(format!("git-{}", uuid::Uuid::new_v4()))
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let mut channel = session
+        .handle()
+        .channel_open_session()
+        .await
+        .context("无法打开 SSH 通道")?;
+    channel
+        .exec(true, target.command.as_str())
+        .await
+        .context("无法执行远程命令")?;
+
+    // 标准输入转发到通道；通道的标准输出、标准错误（扩展数据）分别写到本进程的 stdout / stderr
+    let mut stdin = tokio::io::stdin();
+    let mut stdout = tokio::io::stdout();
+    let mut stderr = tokio::io::stderr();
+    let mut buffer = vec![0u8; 32 * 1024];
+    let mut stdin_open = true;
+    let mut exit_status = None;
+    loop {
+        tokio::select! {
+            read = stdin.read(&mut buffer), if stdin_open => match read {
+                Ok(0) | Err(_) => {
+                    stdin_open = false;
+                    channel.eof().await.context("SSH channel closed")?;
+                }
+                Ok(n) => channel
+                    .data(&buffer[..n])
+                    .await
+                    .context("SSH channel closed")?,
+            },
+            msg = channel.wait() => match msg {
+                Some(ChannelMsg::Data { data }) => {
+                    stdout.write_all(&data).await?;
+                    stdout.flush().await?;
+                }
+                Some(ChannelMsg::ExtendedData { data, .. }) => {
+                    stderr.write_all(&data).await?;
+                    stderr.flush().await?;
+                }
+                Some(ChannelMsg::ExitStatus { exit_status: status }) => {
+                    exit_status = Some(status);
+                }
+                Some(ChannelMsg::Close) | None => break,
+                _ => {}
+            },
+        }
+    }
+    let _ = session.close().await;
+    // 未收到退出码（如远程进程被信号终止）时与 ssh 一致返回 255
+    Ok(exit_status.map_or(255, |status| status as i32))
+}
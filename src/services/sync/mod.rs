@@ -0,0 +1,397 @@
+// 数据同步服务
+// 把加密配置归档（见 config_archive）推送到 WebDAV 或从 WebDAV 拉取；
+// 以上次同步时的内容指纹判断哪一侧发生了变化，两侧都变化时按冲突策略处理或交由用户选择。
+// 选择 Git 后端时改为提交到本地仓库并与远程仓库拉取/推送（见 git）
+
+mod git;
+mod git_ssh;
+mod webdav;
+
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::models::settings::{ConflictStrategy, SyncMethod, SyncSettings};
+use crate::services::config_archive::{ArchiveScope, ConfigArchive};
+use crate::services::storage;
+use webdav::WebDavClient;
+
+pub use git::GitCommit;
+pub use git_ssh::{run_git_ssh, GIT_SSH_ARG};
+
+/// 同步记录文件名（位于配置目录）
+const SYNC_RECORD_FILE: &str = "sync_state.json";
+
+/// 上次成功同步的记录
+#[derive(Default, Serialize, Deserialize)]
+struct SyncRecord {
+    /// 同步完成时双方内容的指纹
+    last_fingerprint: Option<String>,
+    last_synced_at: Option<String>,
+}
+
+/// 同步结果
+pub enum SyncOutcome {
+    /// 两侧一致，无需同步
+    UpToDate,
+    /// 本地改动已上传
+    Pushed,
+    /// 已拉取远程改动
+    Pulled,
+    /// 两侧改动已合并并上传
+    Merged,
+    /// 两侧都有改动，等待用户选择
+    Conflict(SyncConflict),
+}
+
+/// 两侧都有改动时的冲突信息
+pub struct SyncConflict {
+    /// 远程归档
+    pub remote: ConfigArchive,
+    pub local_servers: usize,
+    pub local_snippets: usize,
+}
+
+impl SyncConflict {
+    pub fn remote_servers(&self) -> usize {
+        self.remote.servers.servers.len()
+    }
+
+    pub fn remote_snippets(&self) -> usize {
+        self.remote.snippets.commands.len()
+    }
+}
+
+/// 冲突的处理方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConflictResolution {
+    /// 保留本地，覆盖远程
+    KeepLocal,
+    /// 使用远程，覆盖本地
+    KeepRemote,
+    /// 合并两侧（同 ID 条目以本地为准）
+    Merge,
+}
+
+/// 同步是否已启用且配置了所选后端的地址
+pub fn is_configured(settings: &SyncSettings) -> bool {
+    settings.enabled
+        && match settings.method {
+            SyncMethod::ICloud => false,
+            SyncMethod::Git => !settings.git_remote.trim().is_empty(),
+            SyncMethod::None | SyncMethod::WebDAV => !settings.webdav_url.is_empty(),
+        }
+}
+
+/// 测试 WebDAV 连接或 Git 远程仓库
+pub async fn test_connection(settings: &SyncSettings) -> Result<()> {
+    if settings.method == SyncMethod::Git {
+        return blocking(settings, git::test_connection).await;
+    }
+    WebDavClient::new(settings)?.check().await
+}
+
+/// 执行一次同步
+pub async fn sync(settings: &SyncSettings) -> Result<SyncOutcome> {
+    match settings.method {
+        SyncMethod::ICloud => bail!("iCloud sync is not supported yet"),
+        SyncMethod::Git => return blocking(settings, git::sync).await,
+        SyncMethod::None | SyncMethod::WebDAV => {}
+    }
+    let client = WebDavClient::new(settings)?;
+    let password = archive_password(settings)?;
+    let scope = scope(settings);
+    let local = ConfigArchive::snapshot(settings.sync_keys)?;
+
+    let Some(remote_data) = client.download().await? else {
+        info!("[Sync] Remote is empty, uploading local config");
+        push(&client, settings, &local, None).await?;
+        return Ok(SyncOutcome::Pushed);
+    };
+    let (remote, legacy) = open_remote(settings, &remote_data, password)?;
+
+    let base = load_record().last_fingerprint;
+    let local_fp = fingerprint(&local, scope);
+    let remote_fp = fingerprint(&remote, scope);
+    if local_fp == remote_fp && legacy {
+        // 内容一致但仍是旧口令加密的，重新用同步口令加密上传
+        push(&client, settings, &local, Some(&remote_data)).await?;
+        return Ok(SyncOutcome::Pushed);
+    }
+    if local_fp == remote_fp {
+        save_record(&local_fp);
+        return Ok(SyncOutcome::UpToDate);
+    }
+    if base.as_deref() == Some(remote_fp.as_str()) {
+        push(&client, settings, &local, Some(&remote_data)).await?;
+        return Ok(SyncOutcome::Pushed);
+    }
+    if base.as_deref() == Some(local_fp.as_str()) {
+        pull(settings, remote)?;
+        return Ok(SyncOutcome::Pulled);
+    }
+
+    info!("[Sync] Both local and remote changed since last sync");
+    let resolution = match settings.conflict_strategy {
+        ConflictStrategy::Ask => {
+            return Ok(SyncOutcome::Conflict(SyncConflict {
+                local_servers: local.servers.servers.len(),
+                local_snippets: local.snippets.commands.len(),
+                remote,
+            }))
+        }
+        ConflictStrategy::LocalFirst => ConflictResolution::KeepLocal,
+        ConflictStrategy::RemoteFirst => ConflictResolution::KeepRemote,
+        ConflictStrategy::Merge => ConflictResolution::Merge,
+    };
+    resolve_with(
+        &client,
+        settings,
+        local,
+        remote,
+        Some(&remote_data),
+        resolution,
+    )
+    .await
+}
+
+/// 按用户选择处理冲突
+pub async fn resolve(
+    settings: &SyncSettings,
+    conflict: SyncConflict,
+    resolution: ConflictResolution,
+) -> Result<SyncOutcome> {
+    let client = WebDavClient::new(settings)?;
+    let local = ConfigArchive::snapshot(settings.sync_keys)?;
+    // 冲突期间远程可能再次变化，覆盖前重新下载一份用于备份
+    let remote_data = client.download().await?;
+    resolve_with(
+        &client,
+        settings,
+        local,
+        conflict.remote,
+        remote_data.as_deref(),
+        resolution,
+    )
+    .await
+}
+
+async fn resolve_with(
+    client: &WebDavClient,
+    settings: &SyncSettings,
+    local: ConfigArchive,
+    remote: ConfigArchive,
+    remote_data: Option<&[u8]>,
+    resolution: ConflictResolution,
+) -> Result<SyncOutcome> {
+    match resolution {
+        ConflictResolution::KeepLocal => {
+            push(client, settings, &local, remote_data).await?;
+            Ok(SyncOutcome::Pushed)
+        }
+        ConflictResolution::KeepRemote => {
+            pull(settings, remote)?;
+            Ok(SyncOutcome::Pulled)
+        }
+        ConflictResolution::Merge => {
+            let merged = merge(local, remote);
+            merged.clone().apply(scope(settings))?;
+            push(client, settings, &merged, remote_data).await?;
+            Ok(SyncOutcome::Merged)
+        }
+    }
+}
+
+/// Git 仓库中服务器列表的历史版本（新的在前）
+pub async fn git_history(settings: &SyncSettings) -> Result<Vec<GitCommit>> {
+    blocking(settings, git::history).await
+}
+
+/// 把服务器列表恢复到 Git 仓库中的某个历史版本，返回恢复后的服务器数量
+pub async fn restore_git_version(settings: &SyncSettings, commit: String) -> Result<usize> {
+    blocking(settings, move |settings| {
+        git::restore_servers(settings, &commit)
+    })
+    .await
+}
+
+/// 在阻塞线程中执行 Git 操作（git 子进程与其 SSH 转发可能耗时较长）
+async fn blocking<T, F>(settings: &SyncSettings, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&SyncSettings) -> Result<T> + Send + 'static,
+{
+    let settings = settings.clone();
+    tokio::task::spawn_blocking(move || f(&settings))
+        .await
+        .context("Git 同步任务异常退出")?
+}
+
+/// 上传本地归档；开启“同步前备份”时先把被覆盖的远程文件保存到本地备份目录
+async fn push(
+    client: &WebDavClient,
+    settings: &SyncSettings,
+    local: &ConfigArchive,
+    remote_data: Option<&[u8]>,
+) -> Result<()> {
+    if let (true, Some(data)) = (settings.backup_before_sync, remote_data) {
+        let name = format!(
+            "sync-remote-{}.smconf",
+            Local::now().format("%Y%m%d-%H%M%S")
+        );
+        fs::write(storage::get_backups_dir()?.join(name), data).context("无法备份远程同步文件")?;
+    }
+    client
+        .upload(local.seal(archive_password(settings)?)?)
+        .await?;
+    save_record(&fingerprint(local, scope(settings)));
+    info!("[Sync] Uploaded local config");
+    Ok(())
+}
+
+/// 应用远程归档；同步设置本身（地址、账号等）始终保留本机的
+fn pull(settings: &SyncSettings, mut remote: ConfigArchive) -> Result<()> {
+    remote.settings.sync = settings.clone();
+    let scope = scope(settings);
+    remote.apply(scope)?;
+    let local = ConfigArchive::snapshot(settings.sync_keys)?;
+    save_record(&fingerprint(&local, scope));
+    info!("[Sync] Applied remote config");
+    Ok(())
+}
+
+/// 合并两侧：以本地为基础，补充仅存在于远程的服务器、分组、命令组与命令
+fn merge(mut local: ConfigArchive, remote: ConfigArchive) -> ConfigArchive {
+    for group in remote.servers.groups {
+        if !local.servers.groups.iter().any(|g| g.id == group.id) {
+            local.servers.groups.push(group);
+        }
+    }
+    for server in remote.servers.servers {
+        if !local.servers.servers.iter().any(|s| s.id == server.id) {
+            local.servers.servers.push(server);
+        }
+    }
+    for group in remote.snippets.groups {
+        if !local.snippets.groups.iter().any(|g| g.id == group.id) {
+            local.snippets.groups.push(group);
+        }
+    }
+    for command in remote.snippets.commands {
+        if !local.snippets.commands.iter().any(|c| c.id == command.id) {
+            local.snippets.commands.push(command);
+        }
+    }
+    if local.includes_secrets && remote.includes_secrets {
+        for key in remote.keys {
+            if !local.keys.iter().any(|k| k.filename == key.filename) {
+                local.keys.push(key);
+            }
+        }
+    }
+    if local.keybindings.is_none() {
+        local.keybindings = remote.keybindings;
+    }
+    local
+}
+
+/// 同步内容范围（服务器与分组同属服务器配置，任一开启即同步）
+fn scope(settings: &SyncSettings) -> ArchiveScope {
+    ArchiveScope {
+        servers: settings.sync_servers || settings.sync_groups,
+        snippets: true,
+        settings: settings.sync_settings,
+        keybindings: settings.sync_keybindings,
+    }
+}
+
+/// 归档口令：独立的同步口令，只保存在本机、不会发送到服务器，各设备需一致
+fn archive_password(settings: &SyncSettings) -> Result<&str> {
+    if settings.sync_passphrase.is_empty() {
+        bail!("Sync passphrase is required to encrypt synced data");
+    }
+    Ok(&settings.sync_passphrase)
+}
+
+/// 解密远程归档；旧版本用 WebDAV 密码加密，同步口令解密失败时回退尝试
+///
+/// 返回值的第二项表示远程归档仍是旧口令加密的
+fn open_remote(
+    settings: &SyncSettings,
+    data: &[u8],
+    password: &str,
+) -> Result<(ConfigArchive, bool)> {
+    let error = match ConfigArchive::open(data, password) {
+        Ok(archive) => return Ok((archive, false)),
+        Err(e) => e,
+    };
+    if !settings.webdav_password.is_empty() {
+        if let Ok(archive) = ConfigArchive::open(data, &settings.webdav_password) {
+            info!("[Sync] Remote archive uses the legacy WebDAV password, re-encrypting");
+            return Ok((archive, true));
+        }
+    }
+    Err(error).context("无法解密远程同步文件，请确认各设备使用相同的同步口令")
+}
+
+/// 同步范围内容的指纹
+///
+/// 忽略密码、私钥、最近连接时间与同步设置本身，避免这些字段引起误判
+fn fingerprint(archive: &ConfigArchive, scope: ArchiveScope) -> String {
+    let mut servers = archive.servers.clone();
+    for server in &mut servers.servers {
+        server.password_encrypted = None;
+        server.key_passphrase_encrypted = None;
+        server.last_connected_at = None;
+        if let Some(proxy) = &mut server.proxy {
+            proxy.password_encrypted = None;
+        }
+    }
+    let mut settings = archive.settings.clone();
+    settings.sync = SyncSettings::default();
+
+    // 先转为 Value，对象键按名称排序，保证序列化结果稳定
+    let content = serde_json::json!({
+        "servers": scope.servers.then_some(servers),
+        "snippets": scope.snippets.then_some(&archive.snippets),
+        "settings": scope.settings.then_some(settings),
+        "keybindings": if scope.keybindings { archive.keybindings.clone() } else { None },
+    });
+    let bytes = serde_json::to_vec(&content).unwrap_or_default();
+    Sha256::digest(&bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn load_record() -> SyncRecord {
+    storage::get_config_dir()
+        .ok()
+        .and_then(|dir| fs::read_to_string(dir.join(SYNC_RECORD_FILE)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_record(fingerprint: &str) {
+    let record = SyncRecord {
+        last_fingerprint: Some(fingerprint.to_string()),
+        last_synced_at: Some(Local::now().format("%Y-%m-%d %H:%M:%S").to_string()),
+    };
+    let result = storage::get_config_dir().and_then(|dir| {
+        let content = serde_json::to_string_pretty(&record)?;
+        fs::write(dir.join(SYNC_RECORD_FILE), content)?;
+        Ok(())
+    });
+    if let Err(e) = result {
+        tracing::warn!("[Sync] Failed to save sync record: {}", e);
+    }
+}
+
+/// 上次同步完成时间
+pub fn last_synced_at() -> Option<String> {
+    load_record().last_synced_at
+}
@@ -0,0 +1,137 @@
+// WebDAV 客户端：读取/上传同步文件，必要时逐级创建远程目录
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use reqwest::{Method, StatusCode};
+
+use crate::models::settings::SyncSettings;
+
+/// 远程同步文件名
+const REMOTE_FILE_NAME: &str = "shellmaster.smconf";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct WebDavClient {
+    client: reqwest::Client,
+    /// 服务器根地址（不含末尾 /）
+    base_url: String,
+    /// 远程目录的各级名称
+    segments: Vec<String>,
+    username: String,
+    password: String,
+}
+
+impl WebDavClient {
+    pub fn new(settings: &SyncSettings) -> Result<Self> {
+        let base_url = settings.webdav_url.trim().trim_end_matches('/').to_string();
+        if base_url.is_empty() {
+            bail!("WebDAV URL is not configured");
+        }
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("无法创建 HTTP 客户端")?;
+        Ok(Self {
+            client,
+            base_url,
+            segments: settings
+                .webdav_path
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+            username: settings.webdav_username.clone(),
+            password: settings.webdav_password.clone(),
+        })
+    }
+
+    /// 前 `depth` 级目录的地址
+    fn dir_url(&self, depth: usize) -> String {
+        let mut url = self.base_url.clone();
+        for segment in &self.segments[..depth] {
+            url.push('/');
+            url.push_str(segment);
+        }
+        url
+    }
+
+    fn file_url(&self) -> String {
+        format!("{}/{}", self.dir_url(self.segments.len()), REMOTE_FILE_NAME)
+    }
+
+    fn request(&self, method: Method, url: &str) -> reqwest::RequestBuilder {
+        let request = self.client.request(method, url);
+        if self.username.is_empty() {
+            request
+        } else {
+            request.basic_auth(&self.username, Some(&self.password))
+        }
+    }
+
+    /// 检查服务器可访问且认证通过
+    pub async fn check(&self) -> Result<()> {
+        let propfind = Method::from_bytes(b"PROPFIND").expect("valid method");
+        let response = self
+            .request(propfind, &format!("{}/", self.base_url))
+            .header("Depth", "0")
+            .send()
+            .await
+            .context("无法连接 WebDAV 服务器")?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                bail!("Authentication failed ({})", response.status())
+            }
+            status => bail!("WebDAV server returned {}", status),
+        }
+    }
+
+    /// 下载同步文件（远程尚不存在时返回 None）
+    pub async fn download(&self) -> Result<Option<Vec<u8>>> {
+        let response = self
+            .request(Method::GET, &self.file_url())
+            .send()
+            .await
+            .context("无法连接 WebDAV 服务器")?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => {
+                let bytes = response.bytes().await.context("无法读取远程同步文件")?;
+                Ok(Some(bytes.to_vec()))
+            }
+            status => bail!("Download failed: {}", status),
+        }
+    }
+
+    /// 上传同步文件（覆盖远程）
+    pub async fn upload(&self, data: Vec<u8>) -> Result<()> {
+        self.ensure_dirs().await?;
+        let response = self
+            .request(Method::PUT, &self.file_url())
+            .body(data)
+            .send()
+            .await
+            .context("无法连接 WebDAV 服务器")?;
+        if !response.status().is_success() {
+            bail!("Upload failed: {}", response.status());
+        }
+        Ok(())
+    }
+
+    /// 逐级创建远程目录（已存在时服务器返回 405，视为成功）
+    async fn ensure_dirs(&self) -> Result<()> {
+        let mkcol = Method::from_bytes(b"MKCOL").expect("valid method");
+        for depth in 1..=self.segments.len() {
+            let response = self
+                .request(mkcol.clone(), &format!("{}/", self.dir_url(depth)))
+                .send()
+                .await
+                .context("无法连接 WebDAV 服务器")?;
+            let status = response.status();
+            if !status.is_success() && status != StatusCode::METHOD_NOT_ALLOWED {
+                bail!("Failed to create remote folder: {}", status);
+            }
+        }
+        Ok(())
+    }
+}
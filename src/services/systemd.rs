@@ -0,0 +1,177 @@
+// systemd 服务管理服务
+// 通过 SSH ExecChannel 执行 systemctl（--output=json，旧版本 systemd 回退到纯文本表格）获取服务列表，并执行启停/开机启动设置
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tracing::{debug, info};
+
+use crate::services::remote_exec::{exec_command, shell_quote};
+use crate::services::sftp::run_sudo;
+use crate::ssh::session::SshSession;
+
+/// 列出全部服务单元（包括未运行的）
+const UNIT_LIST_COMMAND: &str =
+    "systemctl list-units --type=service --all --no-pager --output=json";
+
+/// 列出全部服务单元（纯文本，适用于不支持 JSON 输出的 systemd）
+const UNIT_LIST_PLAIN_COMMAND: &str =
+    "systemctl list-units --type=service --all --no-pager --plain --no-legend";
+
+/// 列出服务的开机启动状态
+const UNIT_FILE_LIST_COMMAND: &str =
+    "systemctl list-unit-files --type=service --no-pager --plain --no-legend";
+
+/// 单个服务单元的信息
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ServiceUnit {
+    /// 单元名（如 nginx.service）
+    pub unit: String,
+    /// 加载状态（loaded / not-found / masked 等）
+    pub load: String,
+    /// 活动状态（active / inactive / failed / activating 等）
+    pub active: String,
+    /// 子状态（running / exited / dead 等）
+    pub sub: String,
+    #[serde(default)]
+    pub description: String,
+    /// 开机启动状态（enabled / disabled / static 等，来自 list-unit-files）
+    #[serde(skip)]
+    pub enabled: String,
+}
+
+impl ServiceUnit {
+    /// 去掉 .service 后缀的服务名
+    pub fn name(&self) -> &str {
+        self.unit.strip_suffix(".service").unwrap_or(&self.unit)
+    }
+
+    /// 服务是否正在运行
+    pub fn is_active(&self) -> bool {
+        self.active == "active"
+    }
+
+    /// 是否可以设置开机启动（static / generated 等单元不能 enable/disable）
+    pub fn can_toggle_enabled(&self) -> bool {
+        matches!(self.enabled.as_str(), "enabled" | "disabled")
+    }
+}
+
+/// 服务操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceAction {
+    Start,
+    Stop,
+    Restart,
+    Enable,
+    Disable,
+}
+
+impl ServiceAction {
+    /// systemctl 子命令
+    pub fn command(self) -> &'static str {
+        match self {
+            ServiceAction::Start => "start",
+            ServiceAction::Stop => "stop",
+            ServiceAction::Restart => "restart",
+            ServiceAction::Enable => "enable",
+            ServiceAction::Disable => "disable",
+        }
+    }
+}
+
+/// 获取服务列表（按单元名排序）
+pub async fn list_services(session: &SshSession) -> Result<Vec<ServiceUnit>, String> {
+    // 旧版本 systemd 不支持 JSON 输出（可能报错，也可能忽略参数输出表格），此时回退到纯文本
+    let json = exec_command(session, UNIT_LIST_COMMAND)
+        .await
+        .ok()
+        .and_then(|output| serde_json::from_str::<Vec<ServiceUnit>>(&output).ok());
+    let mut units = match json {
+        Some(units) => units,
+        None => parse_unit_table(&exec_command(session, UNIT_LIST_PLAIN_COMMAND).await?),
+    };
+    if let Ok(output) = exec_command(session, UNIT_FILE_LIST_COMMAND).await {
+        let states = parse_unit_file_states(&output);
+        for unit in &mut units {
+            if let Some(state) = states.get(unit.unit.as_str()) {
+                unit.enabled = state.to_string();
+            }
+        }
+    }
+    units.sort_by(|a, b| a.unit.cmp(&b.unit));
+    debug!("[Systemd] Fetched {} services", units.len());
+    Ok(units)
+}
+
+/// 执行服务操作；`password` 为 `Some` 时通过 sudo 执行（空字符串表示免密 sudo）
+pub async fn service_action(
+    session: &SshSession,
+    unit: &str,
+    action: ServiceAction,
+    password: Option<&str>,
+) -> Result<(), String> {
+    info!(
+        "[Systemd] systemctl {} {}{}",
+        action.command(),
+        unit,
+        if password.is_some() { " (sudo)" } else { "" }
+    );
+    let command = format!("systemctl {} {}", action.command(), shell_quote(unit));
+    match password {
+        Some(password) => run_sudo(session, &command, password).await.map(drop),
+        None => exec_command(session, &command).await.map(drop),
+    }
+}
+
+/// 错误是否由权限不足引起（需要改用 sudo 重试）
+pub fn is_permission_error(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("access denied")
+        || error.contains("authentication is required")
+        || error.contains("authentication required")
+        || error.contains("permission denied")
+}
+
+/// 在终端中查看服务日志的命令
+pub fn journal_command(unit: &str) -> String {
+    format!("journalctl -u {} -n 200 -f\n", shell_quote(unit))
+}
+
+/// 解析 `list-units --plain --no-legend` 的输出：UNIT LOAD ACTIVE SUB DESCRIPTION
+fn parse_unit_table(output: &str) -> Vec<ServiceUnit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            // 失败的单元前面可能带有 ● 标记
+            let line = line.trim_start().trim_start_matches('●');
+            let mut fields = line.split_whitespace();
+            let unit = fields.next()?;
+            if !unit.ends_with(".service") {
+                return None;
+            }
+            let load = fields.next()?.to_string();
+            let active = fields.next()?.to_string();
+            let sub = fields.next()?.to_string();
+            Some(ServiceUnit {
+                unit: unit.to_string(),
+                load,
+                active,
+                sub,
+                description: fields.collect::<Vec<_>>().join(" "),
+                enabled: String::new(),
+            })
+        })
+        .collect()
+}
+
+/// 解析 `list-unit-files --plain --no-legend` 的输出：UNIT STATE [PRESET]
+fn parse_unit_file_states(output: &str) -> HashMap<&str, &str> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?, fields.next()?))
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+// 传输历史：已结束的传输写入磁盘，并在内存中缓存供传输面板读取
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tracing::error;
+
+use crate::models::sftp::{TransferHistory, TransferHistoryEntry, TransferHistoryFilter};
+use crate::services::storage;
+
+/// 最多保留的历史记录条数（按保留天数清理之外的上限）
+const MAX_ENTRIES: usize = 1000;
+
+/// 传输历史存储
+pub struct TransferHistoryStore {
+    /// 首次访问时从磁盘加载
+    cache: Mutex<Option<TransferHistory>>,
+}
+
+impl TransferHistoryStore {
+    /// 获取全局单例
+    pub fn global() -> &'static TransferHistoryStore {
+        static STORE: Lazy<TransferHistoryStore> = Lazy::new(|| TransferHistoryStore {
+            cache: Mutex::new(None),
+        });
+        &STORE
+    }
+
+    /// 在缓存上执行操作，`save` 为 true 时操作后写回磁盘
+    fn with_history<T>(&self, save: bool, f: impl FnOnce(&mut TransferHistory) -> T) -> T {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let history = cache.get_or_insert_with(|| {
+            let mut history = storage::load_transfer_history().unwrap_or_default();
+            history.prune(retention_days());
+            history
+        });
+        let result = f(history);
+        if save {
+            if let Err(e) = storage::save_transfer_history(history) {
+                error!("[Transfer] Failed to save transfer history: {}", e);
+            }
+        }
+        result
+    }
+
+    /// 记录一条已结束的传输，同时按保留天数清理过期记录
+    pub fn record(&self, entry: TransferHistoryEntry) {
+        self.with_history(true, |history| {
+            history.entries.retain(|e| e.id != entry.id);
+            history.entries.insert(0, entry);
+            history.entries.truncate(MAX_ENTRIES);
+            history.prune(retention_days());
+        });
+    }
+
+    /// 按筛选条件获取历史记录（最新的在前）
+    pub fn entries(&self, filter: TransferHistoryFilter) -> Vec<TransferHistoryEntry> {
+        self.with_history(false, |history| {
+            history
+                .entries
+                .iter()
+                .filter(|e| filter.matches(e))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// 清空历史记录
+    pub fn clear(&self) {
+        self.with_history(true, |history| history.entries.clear());
+    }
+}
+
+/// 历史记录保留天数（沿用日志保留天数设置）
+fn retention_days() -> u32 {
+    storage::load_settings()
+        .map(|s| s.system.log_retention_days)
+        .unwrap_or(7)
+}
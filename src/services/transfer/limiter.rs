@@ -0,0 +1,260 @@
+// 传输限速：令牌桶限速器，SFTP 读写循环每写入一块数据都先在这里等待
+// 全局上传/下载限速与单个传输的限速同时生效，限速值可在传输过程中随时调整
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::services::storage;
+
+/// 单次最长等待时间，等待结束后重新读取限速值，使调整尽快生效
+const MAX_WAIT: Duration = Duration::from_millis(200);
+
+/// 令牌桶状态
+struct Bucket {
+    /// 可用字节数，为负数时表示需要等待偿还的欠额
+    tokens: f64,
+    /// 上次补充令牌的时间
+    last: Instant,
+}
+
+impl Bucket {
+    /// 按经过的时间补充令牌，桶容量为 1 秒的流量
+    fn refill(&mut self, limit: u64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * limit as f64).min(limit as f64);
+    }
+}
+
+/// 令牌桶限速器
+struct RateLimiter {
+    /// 限速（字节/秒，0 表示不限速）
+    limit: Arc<AtomicU64>,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    fn new(limit: Arc<AtomicU64>) -> Self {
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: 0.0,
+                last: Instant::now(),
+            }),
+        }
+    }
+
+    /// 消耗 `bytes` 个令牌，令牌不足时等待到欠额还清
+    async fn consume(&self, bytes: u64) {
+        let mut take = bytes as f64;
+        loop {
+            let wait = {
+                let limit = self.limit.load(Ordering::Relaxed);
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+                if limit == 0 {
+                    // 不限速时清空欠额，重新限速后从空桶开始
+                    bucket.tokens = 0.0;
+                    bucket.last = Instant::now();
+                    return;
+                }
+                bucket.refill(limit);
+                bucket.tokens -= take;
+                take = 0.0;
+                if bucket.tokens >= 0.0 {
+                    return;
+                }
+                Duration::from_secs_f64(-bucket.tokens / limit as f64).min(MAX_WAIT)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 全局下载限速器
+static DOWNLOAD_LIMITER: Lazy<RateLimiter> = Lazy::new(|| {
+    let kb = storage::load_settings()
+        .map(|s| s.sftp.download_limit_kb)
+        .unwrap_or(0);
+    RateLimiter::new(Arc::new(AtomicU64::new(kb_to_bytes(kb))))
+});
+
+/// 全局上传限速器
+static UPLOAD_LIMITER: Lazy<RateLimiter> = Lazy::new(|| {
+    let kb = storage::load_settings()
+        .map(|s| s.sftp.upload_limit_kb)
+        .unwrap_or(0);
+    RateLimiter::new(Arc::new(AtomicU64::new(kb_to_bytes(kb))))
+});
+
+fn global_limiter(is_upload: bool) -> &'static RateLimiter {
+    if is_upload {
+        &UPLOAD_LIMITER
+    } else {
+        &DOWNLOAD_LIMITER
+    }
+}
+
+/// KB/s 转换为字节/秒
+pub fn kb_to_bytes(kb: u32) -> u64 {
+    kb as u64 * 1024
+}
+
+/// 设置全局上传或下载限速（KB/s，0 表示不限速），立即对进行中的传输生效
+pub fn set_global_limit_kb(is_upload: bool, kb: u32) {
+    global_limiter(is_upload)
+        .limit
+        .store(kb_to_bytes(kb), Ordering::Relaxed);
+    info!(
+        "[Transfer] Global {} limit set to {} KB/s",
+        if is_upload { "upload" } else { "download" },
+        kb
+    );
+}
+
+/// 当前的全局上传或下载限速（KB/s，0 表示不限速）
+pub fn global_limit_kb(is_upload: bool) -> u32 {
+    (global_limiter(is_upload).limit.load(Ordering::Relaxed) / 1024) as u32
+}
+
+/// 单个传输的限速器，同时受对应方向的全局限速约束
+///
+/// 多通道传输的各个分片共享同一个限速器
+#[derive(Clone)]
+pub struct Throttle {
+    global: &'static RateLimiter,
+    transfer: Arc<RateLimiter>,
+    /// 传输取消时立即结束等待
+    cancel_token: CancellationToken,
+}
+
+impl Throttle {
+    /// 创建传输的限速器
+    ///
+    /// # Arguments
+    /// * `is_upload` - 是否是上传，决定受哪个全局限速约束
+    /// * `speed_limit` - 单个传输的限速（字节/秒，0 表示不限速），与传输项共享
+    /// * `cancel_token` - 传输的取消令牌
+    pub fn new(
+        is_upload: bool,
+        speed_limit: Arc<AtomicU64>,
+        cancel_token: CancellationToken,
+    ) -> Self {
+        Self {
+            global: global_limiter(is_upload),
+            transfer: Arc::new(RateLimiter::new(speed_limit)),
+            cancel_token,
+        }
+    }
+
+    /// 传输 `bytes` 字节前调用，超出限速时等待
+    pub async fn consume(&self, bytes: u64) {
+        tokio::select! {
+            _ = self.cancel_token.cancelled() => {}
+            _ = async {
+                self.transfer.consume(bytes).await;
+                self.global.consume(bytes).await;
+            } => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn limiter(limit: u64) -> RateLimiter {
+        RateLimiter::new(Arc::new(AtomicU64::new(limit)))
+    }
+
+    fn tokens(limiter: &RateLimiter) -> f64 {
+        limiter.bucket.lock().unwrap().tokens
+    }
+
+    #[test]
+    fn test_bucket_refill() {
+        // 闲置再久，桶内最多只有 1 秒的流量
+        let mut bucket = Bucket {
+            tokens: 0.0,
+            last: Instant::now() - Duration::from_secs(5),
+        };
+        bucket.refill(1000);
+        assert_eq!(bucket.tokens, 1000.0);
+
+        // 欠额按经过的时间偿还
+        let mut bucket = Bucket {
+            tokens: -500.0,
+            last: Instant::now() - Duration::from_millis(250),
+        };
+        bucket.refill(1000);
+        assert!(
+            (-250.0..-200.0).contains(&bucket.tokens),
+            "{}",
+            bucket.tokens
+        );
+    }
+
+    #[test]
+    fn test_unlimited_does_not_wait() {
+        let limiter = limiter(0);
+        let started = Instant::now();
+        block_on(limiter.consume(100 * 1024 * 1024));
+        assert!(started.elapsed() < Duration::from_millis(50));
+        assert_eq!(tokens(&limiter), 0.0);
+    }
+
+    #[test]
+    fn test_consume_waits_for_debt() {
+        // 1 MB/s 时 200 KB 约需等待 200ms
+        let limiter = limiter(1_000_000);
+        let started = Instant::now();
+        block_on(limiter.consume(200_000));
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(180), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_secs(1), "{:?}", elapsed);
+        assert!(tokens(&limiter) >= 0.0);
+
+        // 桶内令牌足够时不等待
+        limiter.bucket.lock().unwrap().tokens = 1_000_000.0;
+        let started = Instant::now();
+        block_on(limiter.consume(1000));
+        assert!(started.elapsed() < Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_limit_change_takes_effect_while_waiting() {
+        // 1 KB/s 下 1 MB 需等待约 1000 秒；等待中取消限速后应在一个等待周期内返回
+        let limit = Arc::new(AtomicU64::new(1000));
+        let limiter = RateLimiter::new(limit.clone());
+        let started = Instant::now();
+        block_on(async {
+            tokio::join!(limiter.consume(1_000_000), async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                limit.store(0, Ordering::Relaxed);
+            });
+        });
+        assert!(started.elapsed() < Duration::from_secs(1));
+        // 欠额已清空，重新限速后从空桶开始
+        assert_eq!(tokens(&limiter), 0.0);
+    }
+
+    #[test]
+    fn test_kb_to_bytes() {
+        assert_eq!(kb_to_bytes(0), 0);
+        assert_eq!(kb_to_bytes(512), 512 * 1024);
+        assert_eq!(kb_to_bytes(u32::MAX), u32::MAX as u64 * 1024);
+    }
+}
@@ -0,0 +1,9 @@
+// 传输服务：调度队列、限速与历史记录
+
+mod history;
+mod limiter;
+mod queue;
+
+pub use history::TransferHistoryStore;
+pub use limiter::{global_limit_kb, kb_to_bytes, set_global_limit_kb, Throttle};
+pub use queue::{TransferPermit, TransferQueue};
@@ -0,0 +1,214 @@
+// 传输队列：所有传输先排队，按优先级顺序在全局并发数与单服务器并发数的限制内依次启动
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+/// 排队中的传输
+struct QueuedTransfer {
+    /// 传输项 ID
+    id: String,
+    /// 所属服务器
+    server: String,
+}
+
+/// 队列内部状态
+struct QueueInner {
+    /// 全局最大并发传输数
+    max_active: usize,
+    /// 单个服务器最大并发传输数
+    max_per_server: usize,
+    /// 是否暂停启动新的传输
+    paused: bool,
+    /// 等待启动的传输，越靠前优先级越高
+    waiting: Vec<QueuedTransfer>,
+    /// 每个服务器正在进行的传输数
+    running: HashMap<String, usize>,
+}
+
+impl QueueInner {
+    /// 正在进行的传输总数
+    fn active_count(&self) -> usize {
+        self.running.values().sum()
+    }
+
+    /// 按优先级找出下一个可以启动的传输
+    fn next_startable(&self) -> Option<&QueuedTransfer> {
+        if self.paused || self.active_count() >= self.max_active {
+            return None;
+        }
+        self.waiting
+            .iter()
+            .find(|t| self.running.get(&t.server).copied().unwrap_or(0) < self.max_per_server)
+    }
+}
+
+/// 全局传输队列
+pub struct TransferQueue {
+    inner: Mutex<QueueInner>,
+    /// 队列状态变化（有传输结束、限制变化、顺序变化等）时唤醒等待者
+    changed: Notify,
+}
+
+/// 传输许可，持有期间占用一个并发名额，释放时唤醒下一个传输
+pub struct TransferPermit {
+    queue: &'static TransferQueue,
+    server: String,
+}
+
+impl Drop for TransferPermit {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.queue.inner.lock() {
+            if let Some(count) = inner.running.get_mut(&self.server) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    inner.running.remove(&self.server);
+                }
+            }
+        }
+        self.queue.changed.notify_waiters();
+    }
+}
+
+/// 等待期间的守卫：等待被取消或 future 被丢弃时把传输移出队列
+struct WaitingGuard<'a> {
+    queue: &'a TransferQueue,
+    id: &'a str,
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.queue.inner.lock() {
+            inner.waiting.retain(|t| t.id != self.id);
+        }
+        self.queue.changed.notify_waiters();
+    }
+}
+
+impl TransferQueue {
+    fn new() -> Self {
+        Self {
+            inner: Mutex::new(QueueInner {
+                max_active: 3,
+                max_per_server: 2,
+                paused: false,
+                waiting: Vec::new(),
+                running: HashMap::new(),
+            }),
+            changed: Notify::new(),
+        }
+    }
+
+    /// 获取全局单例
+    pub fn global() -> &'static TransferQueue {
+        static QUEUE: Lazy<TransferQueue> = Lazy::new(TransferQueue::new);
+        &QUEUE
+    }
+
+    /// 设置并发限制（至少为 1）
+    pub fn set_limits(&self, max_active: usize, max_per_server: usize) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.max_active = max_active.max(1);
+            inner.max_per_server = max_per_server.max(1);
+        }
+        self.changed.notify_waiters();
+    }
+
+    /// 排队等待启动传输
+    ///
+    /// 轮到该传输且并发名额充足时返回许可；在此之前被取消则返回 `None`
+    pub async fn acquire(
+        &'static self,
+        id: &str,
+        server: &str,
+        cancel_token: &CancellationToken,
+    ) -> Option<TransferPermit> {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.waiting.push(QueuedTransfer {
+                id: id.to_string(),
+                server: server.to_string(),
+            });
+        }
+        let guard = WaitingGuard { queue: self, id };
+        debug!("[Transfer] Queued {} on {}", id, server);
+
+        loop {
+            // 先登记唤醒再检查状态，避免错过检查与等待之间的通知
+            let mut changed = std::pin::pin!(self.changed.notified());
+            changed.as_mut().enable();
+
+            if self.try_start(id, server) {
+                std::mem::forget(guard);
+                info!("[Transfer] Started {} on {}", id, server);
+                // 名额可能还有剩余，让下一个传输也检查一次
+                self.changed.notify_waiters();
+                return Some(TransferPermit {
+                    queue: self,
+                    server: server.to_string(),
+                });
+            }
+
+            tokio::select! {
+                _ = cancel_token.cancelled() => {
+                    debug!("[Transfer] Cancelled while queued: {}", id);
+                    return None;
+                }
+                _ = changed => {}
+            }
+        }
+    }
+
+    /// 轮到 `id` 时把它从等待队列移到运行中
+    fn try_start(&self, id: &str, server: &str) -> bool {
+        let Ok(mut inner) = self.inner.lock() else {
+            return false;
+        };
+        if inner.next_startable().map(|t| t.id.as_str()) != Some(id) {
+            return false;
+        }
+        inner.waiting.retain(|t| t.id != id);
+        *inner.running.entry(server.to_string()).or_insert(0) += 1;
+        true
+    }
+
+    /// 把等待中的传输 `id` 移到 `before` 之前（`before` 为 `None` 时移到队尾）
+    pub fn move_before(&self, id: &str, before: Option<&str>) {
+        if let Ok(mut inner) = self.inner.lock() {
+            let Some(from) = inner.waiting.iter().position(|t| t.id == id) else {
+                return;
+            };
+            let item = inner.waiting.remove(from);
+            let to = before
+                .and_then(|b| inner.waiting.iter().position(|t| t.id == b))
+                .unwrap_or(inner.waiting.len());
+            inner.waiting.insert(to, item);
+        }
+        self.changed.notify_waiters();
+    }
+
+    /// 暂停启动新的传输（已开始的传输不受影响）
+    pub fn pause(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.paused = true;
+        }
+        info!("[Transfer] Queue paused");
+    }
+
+    /// 恢复启动排队中的传输
+    pub fn resume(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.paused = false;
+        }
+        info!("[Transfer] Queue resumed");
+        self.changed.notify_waiters();
+    }
+
+    /// 队列是否已暂停
+    pub fn is_paused(&self) -> bool {
+        self.inner.lock().map(|inner| inner.paused).unwrap_or(false)
+    }
+}
@@ -0,0 +1,269 @@
+// 系统托盘/菜单栏图标：常驻的快速操作——连接收藏的服务器、查看进行中的传输、显示/隐藏主窗口、退出。
+// 菜单内容在主线程定时生成，变化时重建菜单；菜单点击事件同样在主线程轮询后交给调用方处理。
+// Linux 下托盘依赖 GTK 事件循环，托盘图标与菜单在独立的 GTK 线程中创建。
+
+use std::time::Duration;
+
+use gpui::App;
+use tracing::warn;
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+use crate::i18n;
+use crate::models::settings::Language;
+use crate::services::storage;
+
+/// 检查菜单内容与点击事件的间隔
+const TRAY_POLL_INTERVAL: Duration = Duration::from_millis(300);
+/// 托盘图标
+const TRAY_ICON_PNG: &[u8] = include_bytes!("../../assets/app-icon.png");
+
+const CONNECT_PREFIX: &str = "tray:connect:";
+const TAB_PREFIX: &str = "tray:tab:";
+const TOGGLE_WINDOW_ID: &str = "tray:toggle";
+const QUIT_ID: &str = "tray:quit";
+
+/// 托盘菜单中的操作
+#[derive(Clone, Debug)]
+pub enum TrayCommand {
+    /// 连接收藏的服务器
+    Connect {
+        server_id: String,
+        label: String,
+    },
+    /// 切换到有进行中传输的标签
+    ShowTab(String),
+    /// 显示或隐藏主窗口
+    ToggleWindow,
+    Quit,
+}
+
+impl TrayCommand {
+    fn parse(id: &str, favorites: &[(String, String)]) -> Option<Self> {
+        if let Some(server_id) = id.strip_prefix(CONNECT_PREFIX) {
+            let label = favorites
+                .iter()
+                .find(|(id, _)| id == server_id)
+                .map(|(_, label)| label.clone())?;
+            return Some(TrayCommand::Connect {
+                server_id: server_id.to_string(),
+                label,
+            });
+        }
+        if let Some(tab_id) = id.strip_prefix(TAB_PREFIX) {
+            return Some(TrayCommand::ShowTab(tab_id.to_string()));
+        }
+        match id {
+            TOGGLE_WINDOW_ID => Some(TrayCommand::ToggleWindow),
+            QUIT_ID => Some(TrayCommand::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// 托盘菜单内容（在主线程生成，在托盘所在线程构建菜单）
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrayModel {
+    /// 收藏的服务器 (服务器 ID, 名称)
+    favorites: Vec<(String, String)>,
+    /// 进行中的传输 (标签 ID, 描述)
+    transfers: Vec<(String, String)>,
+    lang: Language,
+}
+
+impl TrayModel {
+    /// 收藏的服务器从存储读取，进行中的传输由调用方提供
+    pub fn new(transfers: Vec<(String, String)>) -> Self {
+        let favorites = storage::load_servers()
+            .map(|config| {
+                config
+                    .servers
+                    .into_iter()
+                    .filter(|s| s.favorite)
+                    .map(|s| (s.id, s.label))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let lang = storage::load_settings()
+            .map(|s| s.theme.language)
+            .unwrap_or(Language::Chinese);
+        Self {
+            favorites,
+            transfers,
+            lang,
+        }
+    }
+
+    fn build_menu(&self) -> Menu {
+        let lang = &self.lang;
+        let menu = Menu::new();
+
+        let favorites = Submenu::new(i18n::t(lang, "tray.favorites"), true);
+        if self.favorites.is_empty() {
+            let _ = favorites.append(&MenuItem::new(
+                i18n::t(lang, "tray.no_favorites"),
+                false,
+                None,
+            ));
+        }
+        for (server_id, label) in &self.favorites {
+            let id = format!("{}{}", CONNECT_PREFIX, server_id);
+            let _ = favorites.append(&MenuItem::with_id(id, label, true, None));
+        }
+
+        let transfers_title =
+            i18n::t(lang, "tray.transfers").replace("{}", &self.transfers.len().to_string());
+        let transfers = Submenu::new(transfers_title, !self.transfers.is_empty());
+        for (tab_id, text) in &self.transfers {
+            let id = format!("{}{}", TAB_PREFIX, tab_id);
+            let _ = transfers.append(&MenuItem::with_id(id, text, true, None));
+        }
+
+        let toggle = MenuItem::with_id(
+            TOGGLE_WINDOW_ID,
+            i18n::t(lang, "tray.toggle_window"),
+            true,
+            None,
+        );
+        let quit = MenuItem::with_id(QUIT_ID, i18n::t(lang, "tray.quit"), true, None);
+
+        let separator = PredefinedMenuItem::separator();
+        if let Err(e) = menu.append_items(&[&favorites, &transfers, &separator, &toggle, &quit]) {
+            warn!("[Tray] Failed to build tray menu: {}", e);
+        }
+        menu
+    }
+}
+
+/// 托盘图标：模型为 None 时移除图标，否则创建图标或重建菜单
+#[derive(Default)]
+struct TraySlot {
+    tray: Option<TrayIcon>,
+}
+
+impl TraySlot {
+    fn apply(&mut self, model: Option<TrayModel>) {
+        let Some(model) = model else {
+            self.tray = None;
+            return;
+        };
+        let menu = Box::new(model.build_menu());
+        if let Some(tray) = &self.tray {
+            tray.set_menu(Some(menu));
+            return;
+        }
+        let mut builder = TrayIconBuilder::new()
+            .with_menu(menu)
+            .with_tooltip("ShellMaster");
+        match load_icon() {
+            Ok(icon) => builder = builder.with_icon(icon),
+            Err(e) => warn!("[Tray] Failed to load tray icon: {}", e),
+        }
+        match builder.build() {
+            Ok(tray) => self.tray = Some(tray),
+            Err(e) => warn!("[Tray] Failed to create tray icon: {}", e),
+        }
+    }
+}
+
+fn load_icon() -> anyhow::Result<Icon> {
+    let image = image::load_from_memory(TRAY_ICON_PNG)?
+        .resize(32, 32, image::imageops::FilterType::Lanczos3)
+        .into_rgba8();
+    let (width, height) = image.dimensions();
+    Ok(Icon::from_rgba(image.into_raw(), width, height)?)
+}
+
+/// 托盘所在线程：macOS、Windows 为主线程，Linux 为独立的 GTK 线程
+#[cfg(not(target_os = "linux"))]
+#[derive(Default)]
+struct TrayHost {
+    slot: TraySlot,
+}
+
+#[cfg(not(target_os = "linux"))]
+impl TrayHost {
+    fn apply(&mut self, model: Option<TrayModel>) {
+        self.slot.apply(model);
+    }
+}
+
+#[cfg(target_os = "linux")]
+struct TrayHost {
+    sender: std::sync::mpsc::Sender<Option<TrayModel>>,
+}
+
+#[cfg(target_os = "linux")]
+impl Default for TrayHost {
+    fn default() -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel::<Option<TrayModel>>();
+        let spawned = std::thread::Builder::new()
+            .name("tray".to_string())
+            .spawn(move || {
+                if let Err(e) = gtk::init() {
+                    warn!("[Tray] Failed to initialize GTK: {}", e);
+                    return;
+                }
+                let mut slot = TraySlot::default();
+                gtk::glib::timeout_add_local(TRAY_POLL_INTERVAL, move || {
+                    while let Ok(model) = receiver.try_recv() {
+                        slot.apply(model);
+                    }
+                    gtk::glib::ControlFlow::Continue
+                });
+                gtk::main();
+            });
+        if let Err(e) = spawned {
+            warn!("[Tray] Failed to start tray thread: {}", e);
+        }
+        Self { sender }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl TrayHost {
+    fn apply(&mut self, model: Option<TrayModel>) {
+        let _ = self.sender.send(model);
+    }
+}
+
+pub struct TrayService;
+
+impl TrayService {
+    /// 启动托盘：`model` 生成当前的菜单内容，`handler` 处理菜单点击。
+    /// 设置中关闭"显示托盘图标"后移除图标，重新开启后再次创建。
+    pub fn init(
+        cx: &mut App,
+        model: impl Fn(&App) -> TrayModel + 'static,
+        handler: impl Fn(TrayCommand, &mut App) + 'static,
+    ) {
+        let mut host = TrayHost::default();
+        let mut current: Option<TrayModel> = None;
+        cx.spawn(async move |cx| loop {
+            let alive = cx.update(|cx| {
+                let show = storage::load_settings()
+                    .map(|s| s.system.show_tray_icon)
+                    .unwrap_or(true);
+                let next = show.then(|| model(cx));
+                if next != current {
+                    host.apply(next.clone());
+                    current = next;
+                }
+                let favorites = current
+                    .as_ref()
+                    .map(|m| m.favorites.as_slice())
+                    .unwrap_or_default();
+                while let Ok(event) = MenuEvent::receiver().try_recv() {
+                    if let Some(command) = TrayCommand::parse(&event.id.0, favorites) {
+                        handler(command, cx);
+                    }
+                }
+            });
+            if alive.is_err() {
+                break;
+            }
+            cx.background_executor().timer(TRAY_POLL_INTERVAL).await;
+        })
+        .detach();
+    }
+}
@@ -0,0 +1,114 @@
+// 界面主题预设与导入/导出
+// 主题以 JSON 文件分享，包含深色与浅色两套配色；导入时校验颜色格式
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use tracing::info;
+
+use crate::models::settings::{UiColorSlot, UiPalette, UiTheme};
+use crate::theme::parse_hex_color;
+
+/// 由 (强调色, 背景, 表面, 侧边栏, 边框) 构建配色
+fn palette(colors: [&str; 5]) -> UiPalette {
+    let [accent, background, surface, sidebar, border] = colors.map(|c| Some(c.to_string()));
+    UiPalette {
+        accent,
+        background,
+        surface,
+        sidebar,
+        border,
+    }
+}
+
+fn preset(name: &str, dark: [&str; 5], light: [&str; 5]) -> UiTheme {
+    UiTheme {
+        name: name.to_string(),
+        dark: palette(dark),
+        light: palette(light),
+    }
+}
+
+/// 内置主题预设（第一个为默认配色）
+pub fn presets() -> Vec<UiTheme> {
+    vec![
+        UiTheme::default(),
+        preset(
+            "Nord",
+            ["#88c0d0", "#2e3440", "#3b4252", "#272c36", "#4c566a"],
+            ["#5e81ac", "#eceff4", "#e5e9f0", "#d8dee9", "#c5cdd9"],
+        ),
+        preset(
+            "Dracula",
+            ["#bd93f9", "#282a36", "#343746", "#21222c", "#44475a"],
+            ["#644ac9", "#fffbeb", "#f5f1dc", "#efeddc", "#cfcfde"],
+        ),
+        preset(
+            "Solarized",
+            ["#268bd2", "#002b36", "#073642", "#00212b", "#2b4f5a"],
+            ["#268bd2", "#fdf6e3", "#eee8d5", "#eee8d5", "#d3cbb7"],
+        ),
+        preset(
+            "Gruvbox",
+            ["#fe8019", "#282828", "#3c3836", "#1d2021", "#504945"],
+            ["#af3a03", "#fbf1c7", "#f2e5bc", "#ebdbb2", "#d5c4a1"],
+        ),
+        preset(
+            "Catppuccin",
+            ["#cba6f7", "#1e1e2e", "#313244", "#181825", "#45475a"],
+            ["#8839ef", "#eff1f5", "#e6e9ef", "#dce0e8", "#bcc0cc"],
+        ),
+    ]
+}
+
+/// 导出主题的默认文件名
+pub fn default_file_name(theme: &UiTheme) -> String {
+    let name: String = theme
+        .name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if name.trim_matches('-').is_empty() {
+        "shellmaster-theme.json".to_string()
+    } else {
+        format!("{}-theme.json", name.trim_matches('-'))
+    }
+}
+
+/// 导出主题为 JSON 文件
+pub fn export_theme(path: &Path, theme: &UiTheme) -> Result<()> {
+    let content = serde_json::to_string_pretty(theme)?;
+    fs::write(path, content).with_context(|| format!("无法写入 {:?}", path))?;
+    info!("[Theme] Exported UI theme '{}' to {:?}", theme.name, path);
+    Ok(())
+}
+
+/// 从 JSON 文件导入主题
+pub fn import_theme(path: &Path) -> Result<UiTheme> {
+    let content = fs::read_to_string(path).with_context(|| format!("无法读取 {:?}", path))?;
+    let mut theme: UiTheme = serde_json::from_str(&content).context("无法解析主题文件")?;
+    for palette in [&theme.dark, &theme.light] {
+        for slot in UiColorSlot::ALL {
+            if let Some(color) = palette.get(slot) {
+                if parse_hex_color(color).is_none() {
+                    bail!("无效的颜色: {}", color);
+                }
+            }
+        }
+    }
+    if theme.name.trim().is_empty() {
+        theme.name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+    }
+    info!("[Theme] Imported UI theme '{}' from {:?}", theme.name, path);
+    Ok(theme)
+}
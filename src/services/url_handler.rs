@@ -0,0 +1,162 @@
+// 注册为系统默认的 ssh:// 链接处理程序
+// macOS 通过 Launch Services 设置（应用包需声明 ssh Scheme），Windows 写入当前用户的注册表，
+// Linux 生成 .desktop 文件并通过 xdg-mime 设为默认；之后系统会把 ssh:// 链接作为启动参数或打开事件转交给应用
+
+use anyhow::Result;
+use tracing::info;
+
+use crate::router::SSH_URL_SCHEME;
+
+/// 把当前应用设为 ssh:// 链接的默认处理程序
+pub fn register_ssh_handler() -> Result<()> {
+    platform::register(SSH_URL_SCHEME)?;
+    info!(
+        "[UrlHandler] Registered as the default {}:// handler",
+        SSH_URL_SCHEME
+    );
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::{c_char, c_void, CString};
+
+    use anyhow::{bail, Result};
+
+    /// 与 Cargo.toml 中应用包的 identifier 一致
+    const BUNDLE_ID: &str = "com.shellmaster.app";
+    const K_CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringCreateWithCString(
+            alloc: *const c_void,
+            c_str: *const c_char,
+            encoding: u32,
+        ) -> *const c_void;
+        fn CFRelease(cf: *const c_void);
+    }
+
+    #[link(name = "CoreServices", kind = "framework")]
+    extern "C" {
+        fn LSSetDefaultHandlerForURLScheme(scheme: *const c_void, bundle_id: *const c_void) -> i32;
+    }
+
+    pub fn register(scheme: &str) -> Result<()> {
+        let scheme = CString::new(scheme)?;
+        let bundle_id = CString::new(BUNDLE_ID)?;
+        // SAFETY: 创建的 CFString 在调用后立即释放，指针不会逃逸
+        let status = unsafe {
+            let scheme = CFStringCreateWithCString(
+                std::ptr::null(),
+                scheme.as_ptr(),
+                K_CF_STRING_ENCODING_UTF8,
+            );
+            let bundle_id = CFStringCreateWithCString(
+                std::ptr::null(),
+                bundle_id.as_ptr(),
+                K_CF_STRING_ENCODING_UTF8,
+            );
+            let status = LSSetDefaultHandlerForURLScheme(scheme, bundle_id);
+            CFRelease(scheme);
+            CFRelease(bundle_id);
+            status
+        };
+        if status != 0 {
+            bail!("Launch Services 返回错误 {}", status);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    use anyhow::{bail, Context, Result};
+
+    /// 不弹出控制台窗口
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    /// 执行 `reg add` 写入一个注册表值（`value` 为 None 时写入默认值）
+    fn reg_add(key: &str, value: Option<&str>, data: &str) -> Result<()> {
+        let mut command = Command::new("reg");
+        command.args(["add", key]);
+        match value {
+            Some(value) => command.args(["/v", value]),
+            None => command.arg("/ve"),
+        };
+        let output = command
+            .args(["/d", data, "/f"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .context("无法运行 reg 命令")?;
+        if !output.status.success() {
+            bail!(
+                "写入注册表失败：{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+
+    pub fn register(scheme: &str) -> Result<()> {
+        let exe = std::env::current_exe().context("无法获取程序路径")?;
+        let key = format!(r"HKCU\Software\Classes\{}", scheme);
+        reg_add(
+            &key,
+            None,
+            &format!("URL:{} Protocol", scheme.to_uppercase()),
+        )?;
+        reg_add(&key, Some("URL Protocol"), "")?;
+        reg_add(
+            &format!(r"{}\shell\open\command", key),
+            None,
+            &format!("\"{}\" \"%1\"", exe.display()),
+        )
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use std::fs;
+    use std::process::Command;
+
+    use anyhow::{bail, Context, Result};
+
+    pub fn register(scheme: &str) -> Result<()> {
+        let exe = std::env::current_exe().context("无法获取程序路径")?;
+        let dir = dirs::data_dir()
+            .context("无法获取数据目录")?
+            .join("applications");
+        fs::create_dir_all(&dir).with_context(|| format!("无法创建 {:?}", dir))?;
+
+        let file_name = format!("shellmaster-{}.desktop", scheme);
+        let path = dir.join(&file_name);
+        let entry = format!(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=ShellMaster\n\
+             Exec=\"{}\" %u\n\
+             Terminal=false\n\
+             NoDisplay=true\n\
+             MimeType=x-scheme-handler/{};\n",
+            exe.display(),
+            scheme
+        );
+        fs::write(&path, entry).with_context(|| format!("无法写入 {:?}", path))?;
+
+        let mime = format!("x-scheme-handler/{}", scheme);
+        let status = Command::new("xdg-mime")
+            .args(["default", &file_name, &mime])
+            .status()
+            .context("无法运行 xdg-mime")?;
+        if !status.success() {
+            bail!("xdg-mime 设置默认程序失败");
+        }
+        // 刷新桌面程序缓存（部分桌面环境需要），失败不影响结果
+        let _ = Command::new("update-desktop-database").arg(&dir).status();
+        Ok(())
+    }
+}
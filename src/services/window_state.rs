@@ -0,0 +1,89 @@
+// 主窗口状态的记录与恢复：退出时记录位置、大小、最大化/全屏状态与所在显示器，启动时恢复到原来的显示器。
+// 记录的显示器已断开、或记录的位置已不在任何显示器内时，居中显示在主显示器上。
+
+use gpui::{point, px, size, App, Bounds, DisplayId, Pixels, Window, WindowBounds};
+
+use crate::models::window_state::{SavedWindowBounds, WindowState};
+use crate::services::storage;
+
+/// 首次启动时的窗口大小
+const DEFAULT_WINDOW_WIDTH: f32 = 1200.;
+const DEFAULT_WINDOW_HEIGHT: f32 = 800.;
+/// 小于该尺寸的记录视为无效
+const MIN_WINDOW_SIZE: f32 = 320.;
+
+/// 启动时主窗口的位置与所在显示器（关闭"记住窗口位置"时居中显示在主显示器上）
+pub fn initial_window_bounds(cx: &App) -> (WindowBounds, Option<DisplayId>) {
+    let remember = storage::load_settings()
+        .map(|s| s.system.save_window_position)
+        .unwrap_or(true);
+    let state = if remember {
+        storage::load_window_state().unwrap_or_default()
+    } else {
+        WindowState::default()
+    };
+    let displays = cx.displays();
+    let display_id = state.display_uuid.as_ref().and_then(|uuid| {
+        displays
+            .iter()
+            .find(|d| d.uuid().ok().map(|u| u.to_string()).as_ref() == Some(uuid))
+            .map(|d| d.id())
+    });
+
+    let saved = state
+        .bounds
+        .filter(|b| b.width >= MIN_WINDOW_SIZE && b.height >= MIN_WINDOW_SIZE)
+        .map(to_bounds)
+        .filter(|bounds| displays.iter().any(|d| d.bounds().intersects(bounds)));
+    let bounds = saved.unwrap_or_else(|| {
+        Bounds::centered(
+            display_id,
+            size(px(DEFAULT_WINDOW_WIDTH), px(DEFAULT_WINDOW_HEIGHT)),
+            cx,
+        )
+    });
+
+    let window_bounds = if state.fullscreen {
+        WindowBounds::Fullscreen(bounds)
+    } else if state.maximized {
+        WindowBounds::Maximized(bounds)
+    } else {
+        WindowBounds::Windowed(bounds)
+    };
+    (window_bounds, display_id)
+}
+
+/// 窗口当前的位置、大小与所在显示器（面板布局取默认值，保存时不会覆盖）
+pub fn capture(window: &Window, cx: &App) -> WindowState {
+    let (bounds, maximized, fullscreen) = match window.window_bounds() {
+        WindowBounds::Windowed(bounds) => (bounds, false, false),
+        WindowBounds::Maximized(bounds) => (bounds, true, false),
+        WindowBounds::Fullscreen(bounds) => (bounds, false, true),
+    };
+    WindowState {
+        bounds: Some(from_bounds(bounds)),
+        maximized,
+        fullscreen,
+        display_uuid: window
+            .display(cx)
+            .and_then(|d| d.uuid().ok())
+            .map(|u| u.to_string()),
+        ..Default::default()
+    }
+}
+
+fn to_bounds(saved: SavedWindowBounds) -> Bounds<Pixels> {
+    Bounds::new(
+        point(px(saved.x), px(saved.y)),
+        size(px(saved.width), px(saved.height)),
+    )
+}
+
+fn from_bounds(bounds: Bounds<Pixels>) -> SavedWindowBounds {
+    SavedWindowBounds {
+        x: f32::from(bounds.origin.x),
+        y: f32::from(bounds.origin.y),
+        width: f32::from(bounds.size.width),
+        height: f32::from(bounds.size.height),
+    }
+}
@@ -13,6 +13,7 @@ use tokio::time::timeout;
 use super::config::{AuthMethod, SshConfig};
 use super::error::SshError;
 use super::event::{ConnectionEvent, ConnectionStage, HostKeyAction, LogEntry};
+use super::forward::RemoteForwards;
 use super::handler::SshClientHandler;
 use super::proxy::connect_via_proxy;
 use super::session::SshSession;
@@ -78,13 +79,9 @@ impl SshClient {
                 proxy_type, proxy.host, proxy.port
             )));
 
-            let stream = connect_via_proxy(
-                proxy,
-                &self.config.host,
-                self.config.port,
-                connect_timeout,
-            )
-            .await?;
+            let stream =
+                connect_via_proxy(proxy, &self.config.host, self.config.port, connect_timeout)
+                    .await?;
 
             self.log(LogEntry::info("Proxy tunnel established"));
 
@@ -129,11 +126,13 @@ impl SshClient {
             .take()
             .expect("host_key_response_rx should be set");
 
+        let remote_forwards = RemoteForwards::default();
         let handler = SshClientHandler::new(
             self.event_sender.clone(),
             self.config.host.clone(),
             self.config.port,
             host_key_rx,
+            remote_forwards.clone(),
         );
 
         let mut handle = timeout(
@@ -180,6 +179,7 @@ impl SshClient {
             Arc::new(handle),
             self.config.host.clone(),
             self.config.username.clone(),
+            remote_forwards,
         );
 
         Ok(session)
@@ -258,6 +258,10 @@ impl SshClient {
                     }
                 }
             }
+            AuthMethod::Agent { key } => {
+                self.log(LogEntry::debug("Using SSH agent authentication"));
+                self.authenticate_with_agent(handle, key.as_deref()).await?;
+            }
             AuthMethod::KeyboardInteractive => {
                 // 预留：交互式键盘认证
                 return Err(SshError::Auth(
@@ -269,6 +273,69 @@ impl SshClient {
         Ok(())
     }
 
+    /// 使用 SSH Agent 中的密钥认证；`key` 为密钥注释或 SHA256 指纹，None 时依次尝试全部密钥
+    #[cfg(unix)]
+    async fn authenticate_with_agent(
+        &self,
+        handle: &mut Handle<SshClientHandler>,
+        key: Option<&str>,
+    ) -> Result<(), SshError> {
+        use russh::client::AuthResult;
+        use russh::keys::agent::client::AgentClient;
+        use russh::keys::HashAlg;
+
+        let mut agent = AgentClient::connect_env()
+            .await
+            .map_err(|e| SshError::Auth(format!("Failed to connect to SSH agent: {}", e)))?;
+        let identities = agent
+            .request_identities()
+            .await
+            .map_err(|e| SshError::Auth(format!("Failed to list SSH agent keys: {}", e)))?;
+        let candidates: Vec<_> = identities
+            .into_iter()
+            .filter(|public_key| {
+                key.map_or(true, |key| {
+                    public_key.comment() == key
+                        || public_key.fingerprint(HashAlg::Sha256).to_string() == key
+                })
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Err(SshError::Auth(
+                "No matching key found in SSH agent".to_string(),
+            ));
+        }
+
+        for public_key in candidates {
+            self.log(LogEntry::debug(format!(
+                "Trying SSH agent key: {}",
+                public_key.fingerprint(HashAlg::Sha256)
+            )));
+            let auth_result = handle
+                .authenticate_publickey_with(&self.config.username, public_key, None, &mut agent)
+                .await
+                .map_err(|e| SshError::Auth(format!("SSH agent signing failed: {:?}", e)))?;
+            if let AuthResult::Success = auth_result {
+                return Ok(());
+            }
+        }
+        Err(SshError::Auth(
+            "SSH agent authentication failed: no key was accepted".to_string(),
+        ))
+    }
+
+    /// SSH Agent 目前只支持 Unix 套接字（SSH_AUTH_SOCK）
+    #[cfg(not(unix))]
+    async fn authenticate_with_agent(
+        &self,
+        _handle: &mut Handle<SshClientHandler>,
+        _key: Option<&str>,
+    ) -> Result<(), SshError> {
+        Err(SshError::Auth(
+            "SSH agent authentication is only supported on Unix-like systems".to_string(),
+        ))
+    }
+
     /// 加载私钥文件
     async fn load_private_key(
         &self,
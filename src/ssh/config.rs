@@ -50,6 +50,11 @@ pub enum AuthMethod {
         /// 私钥密码（如果有）
         passphrase: Option<String>,
     },
+    /// SSH Agent 中的密钥
+    Agent {
+        /// 密钥的注释或 SHA256 指纹，None 时依次尝试 Agent 中的全部密钥
+        key: Option<String>,
+    },
     /// 交互式键盘认证（预留）
     KeyboardInteractive,
 }
@@ -6,52 +6,90 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
-use crate::models::server::ProxyType as ModelProxyType;
+use crate::models::history::ConnectionHistoryEntry;
+use crate::models::server::{AuthType, IdentityKind, ProxyType as ModelProxyType};
 use crate::models::ServerData;
 use crate::pages::connecting::page::ConnectionDetails;
 use crate::pages::connecting::ConnectingProgress;
+use crate::services::connection_history::ConnectionHistoryStore;
 use crate::state::{SessionState, SessionStatus};
 
 use super::config::{AuthMethod, KeepaliveConfig, ProxyConfig, ProxyType, SshConfig};
 use super::event::{ConnectionEvent, ConnectionStage, LogEntry};
 
-/// 从 ServerData 构建 SshConfig
-fn build_ssh_config(server: &ServerData) -> SshConfig {
-    let auth = match &server.auth_type {
-        crate::models::server::AuthType::Password => {
-            // 密码需要解密（暂时直接使用加密的值，后续实现解密）
-            AuthMethod::Password(server.password_encrypted.clone().unwrap_or_default())
+/// 解析服务器的认证方式与登录用户名
+///
+/// 引用身份时使用身份的私钥或 Agent 密钥，服务器未填写用户名时使用身份的默认用户名
+pub fn resolve_auth(server: &ServerData) -> (String, AuthMethod) {
+    let key_file = |filename: &Option<String>, legacy_path: &Option<String>| {
+        // 优先使用新字段 private_key_filename，从keys目录构建完整路径
+        // 如果不存在则回退到旧字段 private_key_path（向后兼容）
+        if let Some(filename) = filename {
+            crate::services::storage::get_keys_dir()
+                .map(|dir| dir.join(filename))
+                .unwrap_or_else(|_| filename.into())
+        } else if let Some(old_path) = legacy_path {
+            old_path.into()
+        } else {
+            "".into()
         }
-        crate::models::server::AuthType::PublicKey => {
-            // 优先使用新字段 private_key_filename，从keys目录构建完整路径
-            // 如果不存在则回退到旧字段 private_key_path（向后兼容）
-            let key_path = if let Some(filename) = &server.private_key_filename {
-                // 新格式：从文件名构建完整路径
-                crate::services::storage::get_keys_dir()
-                    .map(|dir| dir.join(filename))
-                    .unwrap_or_else(|_| filename.into())
-            } else if let Some(old_path) = &server.private_key_path {
-                // 旧格式：直接使用完整路径
-                old_path.into()
-            } else {
-                "".into()
-            };
+    };
 
+    match &server.auth_type {
+        AuthType::Password => (
+            server.username.clone(),
+            // 密码需要解密（暂时直接使用加密的值，后续实现解密）
+            AuthMethod::Password(server.password_encrypted.clone().unwrap_or_default()),
+        ),
+        AuthType::PublicKey => (
+            server.username.clone(),
             AuthMethod::PublicKey {
-                key_path,
+                key_path: key_file(&server.private_key_filename, &server.private_key_path),
                 passphrase: server.key_passphrase_encrypted.clone(),
-            }
+            },
+        ),
+        AuthType::Identity => {
+            let config = crate::services::storage::load_servers().unwrap_or_default();
+            let Some(identity) = server
+                .identity_id
+                .as_deref()
+                .and_then(|id| config.identity(id))
+            else {
+                warn!("[SSH] Identity of {} not found", server.label);
+                return (server.username.clone(), AuthMethod::Password(String::new()));
+            };
+            let username = if server.username.is_empty() {
+                identity.username.clone()
+            } else {
+                server.username.clone()
+            };
+            let auth = match identity.kind {
+                IdentityKind::KeyFile => AuthMethod::PublicKey {
+                    key_path: key_file(&identity.private_key_filename, &None),
+                    passphrase: identity.key_passphrase_encrypted.clone(),
+                },
+                IdentityKind::Agent => AuthMethod::Agent {
+                    key: identity.agent_key.clone(),
+                },
+            };
+            (username, auth)
         }
-    };
+    }
+}
+
+/// 从 ServerData 构建 SshConfig
+pub fn build_ssh_config(server: &ServerData) -> SshConfig {
+    let (username, auth) = resolve_auth(server);
 
     // 从用户设置中读取连接配置
     let settings = crate::services::storage::load_settings().unwrap_or_default();
     let connection_settings = &settings.connection;
 
-    // 构建心跳配置
+    // 构建心跳配置（服务器可单独覆盖间隔）
+    let keepalive_secs = server.resolve_keepalive_secs(connection_settings);
     let keepalive = KeepaliveConfig {
-        enabled: connection_settings.keepalive_interval_secs > 0,
-        interval: connection_settings.keepalive_interval_secs as u64,
+        enabled: keepalive_secs > 0,
+        interval: keepalive_secs as u64,
         max_retries: 3,
     };
 
@@ -78,7 +116,7 @@ fn build_ssh_config(server: &ServerData) -> SshConfig {
     SshConfig {
         host: server.host.clone(),
         port: server.port,
-        username: server.username.clone(),
+        username,
         auth,
         connect_timeout: connection_settings.connection_timeout_secs as u64,
         jump_host: None, // TODO: 从 server.jump_host_id 加载
@@ -184,6 +222,7 @@ pub fn start_ssh_connection(
 
         // 阶段2: 初始动画完成后，启动实际的后端SSH连接
         let start_time = std::time::Instant::now();
+        let started_at = chrono::Local::now();
 
         // 创建 UI 更新通道（使用 tokio unbounded channel 实现事件驱动）
         let (ui_sender, mut ui_receiver) = tokio::sync::mpsc::unbounded_channel::<UiUpdate>();
@@ -304,6 +343,9 @@ pub fn start_ssh_connection(
                     {
                         error!("[SSH] Failed to update last connected time: {}", e);
                     }
+                    let history_id = ConnectionHistoryStore::global().record(
+                        ConnectionHistoryEntry::new(&server_for_reconnect, started_at, None),
+                    );
 
                     // 阶段4: 300ms 成功动画延迟，让用户看到"连接成功"状态
                     debug!("[SSH] 开始连接成功动画（300ms）...");
@@ -324,6 +366,7 @@ pub fn start_ssh_connection(
                             if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_clone)
                             {
                                 tab.server_data = Some(server_data_clone);
+                                tab.history_id = Some(history_id);
                             }
 
                             // Monitor 和 SFTP 服务将在终端 PTY 创建成功后启动
@@ -340,6 +383,11 @@ pub fn start_ssh_connection(
                         "[SSH] [{}] Connection failed: {}",
                         server_label_for_log, error
                     );
+                    ConnectionHistoryStore::global().record(ConnectionHistoryEntry::new(
+                        &server_for_reconnect,
+                        started_at,
+                        Some(error.clone()),
+                    ));
 
                     let _ = async_cx.update(|cx| {
                         progress_for_result.update(cx, |p, cx| {
@@ -66,33 +66,18 @@ pub enum ConnectionStage {
 }
 
 impl ConnectionStage {
-    /// 获取阶段的本地化名称（简体中文）
-    pub fn label_zh(&self) -> &'static str {
+    /// 阶段名称的 i18n 键
+    pub fn label_key(&self) -> &'static str {
         match self {
-            Self::Initializing => "初始化连接",
-            Self::ConnectingProxy => "连接代理服务器",
-            Self::ConnectingJumpHost => "连接跳板机",
-            Self::ConnectingHost => "连接目标主机",
-            Self::Handshaking => "SSH 握手",
-            Self::Authenticating => "验证身份",
-            Self::EstablishingChannel => "建立安全通道",
-            Self::StartingSession => "启动会话",
-            Self::Connected => "连接成功",
-        }
-    }
-
-    /// 获取阶段的本地化名称（英文）
-    pub fn label_en(&self) -> &'static str {
-        match self {
-            Self::Initializing => "Initializing",
-            Self::ConnectingProxy => "Connecting to proxy",
-            Self::ConnectingJumpHost => "Connecting to jump host",
-            Self::ConnectingHost => "Connecting to host",
-            Self::Handshaking => "SSH handshake",
-            Self::Authenticating => "Authenticating",
-            Self::EstablishingChannel => "Establishing channel",
-            Self::StartingSession => "Starting session",
-            Self::Connected => "Connected",
+            Self::Initializing => "connecting.stage.initializing",
+            Self::ConnectingProxy => "connecting.stage.proxy",
+            Self::ConnectingJumpHost => "connecting.stage.jump_host",
+            Self::ConnectingHost => "connecting.stage.host",
+            Self::Handshaking => "connecting.stage.handshaking",
+            Self::Authenticating => "connecting.stage.authenticating",
+            Self::EstablishingChannel => "connecting.stage.channel",
+            Self::StartingSession => "connecting.stage.session",
+            Self::Connected => "connecting.stage.connected",
         }
     }
 
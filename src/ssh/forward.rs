@@ -0,0 +1,459 @@
+// SSH 端口转发
+// 支持本地转发（L）、远程转发（R）和动态转发（D，本地 SOCKS5 代理），每条转发记录连接数、流量和最近的错误
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use russh::client::Msg;
+use russh::Channel;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use super::error::SshError;
+use super::manager::SshManager;
+
+/// 转发类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ForwardKind {
+    /// 本地端口 → 远程目标（ssh -L）
+    Local,
+    /// 远程端口 → 本地目标（ssh -R）
+    Remote,
+    /// 本地 SOCKS5 代理，目标由客户端指定（ssh -D）
+    Dynamic,
+}
+
+impl ForwardKind {
+    /// 与 ssh 命令行参数对应的简写
+    pub fn short_label(&self) -> &'static str {
+        match self {
+            ForwardKind::Local => "L",
+            ForwardKind::Remote => "R",
+            ForwardKind::Dynamic => "D",
+        }
+    }
+}
+
+/// 转发配置
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForwardSpec {
+    pub kind: ForwardKind,
+    /// 监听地址（本地转发/动态转发为本机地址，远程转发为服务器上的地址）
+    pub bind_host: String,
+    /// 监听端口（远程转发为 0 时由服务器分配）
+    pub bind_port: u16,
+    /// 目标地址（动态转发不使用）
+    pub target_host: String,
+    pub target_port: u16,
+}
+
+impl ForwardSpec {
+    /// 简短描述，如 `L 127.0.0.1:8080 → db:5432`
+    pub fn describe(&self) -> String {
+        let bind = format!("{}:{}", self.bind_host, self.bind_port);
+        match self.kind {
+            ForwardKind::Dynamic => format!("D {}", bind),
+            kind => format!(
+                "{} {} → {}:{}",
+                kind.short_label(),
+                bind,
+                self.target_host,
+                self.target_port
+            ),
+        }
+    }
+}
+
+/// 解析 `[host:]port`，省略主机时使用 `default_host`
+pub fn parse_host_port(input: &str, default_host: &str) -> Option<(String, u16)> {
+    let input = input.trim();
+    if input.is_empty() {
+        return None;
+    }
+    match input.rsplit_once(':') {
+        Some((host, port)) => {
+            let host = host.trim_start_matches('[').trim_end_matches(']');
+            let host = if host.is_empty() { default_host } else { host };
+            Some((host.to_string(), port.parse().ok()?))
+        }
+        None => Some((default_host.to_string(), input.parse().ok()?)),
+    }
+}
+
+/// 转发的运行状态与流量计数（转发任务写入，界面读取）
+#[derive(Default)]
+pub struct ForwardStats {
+    running: AtomicBool,
+    /// 实际监听的端口（0 表示未监听）
+    bound_port: AtomicU32,
+    active_connections: AtomicU64,
+    total_connections: AtomicU64,
+    /// 从远程收到的字节数
+    bytes_in: AtomicU64,
+    /// 发送到远程的字节数
+    bytes_out: AtomicU64,
+    last_error: Mutex<Option<String>>,
+}
+
+impl ForwardStats {
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Relaxed)
+    }
+
+    pub fn bound_port(&self) -> Option<u16> {
+        match self.bound_port.load(Ordering::Relaxed) {
+            0 => None,
+            port => Some(port as u16),
+        }
+    }
+
+    pub fn active_connections(&self) -> u64 {
+        self.active_connections.load(Ordering::Relaxed)
+    }
+
+    pub fn total_connections(&self) -> u64 {
+        self.total_connections.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes_in(&self) -> u64 {
+        self.bytes_in.load(Ordering::Relaxed)
+    }
+
+    pub fn bytes_out(&self) -> u64 {
+        self.bytes_out.load(Ordering::Relaxed)
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().ok().and_then(|e| e.clone())
+    }
+
+    fn set_error(&self, error: impl Into<String>) {
+        let error = error.into();
+        warn!("[Forward] {}", error);
+        if let Ok(mut last) = self.last_error.lock() {
+            *last = Some(error);
+        }
+    }
+
+    fn set_running(&self, running: bool, bound_port: u16) {
+        self.running.store(running, Ordering::Relaxed);
+        self.bound_port.store(bound_port as u32, Ordering::Relaxed);
+    }
+}
+
+/// 远程转发的本地目标
+struct RemoteTarget {
+    host: String,
+    port: u16,
+    stats: Arc<ForwardStats>,
+    token: CancellationToken,
+}
+
+/// 已建立的远程转发（按服务器上的监听端口索引），供 Handler 分发服务器打开的 forwarded-tcpip 通道
+#[derive(Clone, Default)]
+pub struct RemoteForwards(Arc<Mutex<HashMap<u32, RemoteTarget>>>);
+
+impl RemoteForwards {
+    /// 处理服务器转发过来的连接：连接本地目标并转发数据
+    pub(crate) fn accept(&self, channel: Channel<Msg>, connected_port: u32) {
+        let target = self.0.lock().ok().and_then(|map| {
+            map.get(&connected_port)
+                .map(|t| (t.host.clone(), t.port, t.stats.clone(), t.token.clone()))
+        });
+        tokio::spawn(async move {
+            let Some((host, port, stats, token)) = target else {
+                warn!(
+                    "[Forward] No remote forward registered for port {}",
+                    connected_port
+                );
+                let _ = channel.close().await;
+                return;
+            };
+            match TcpStream::connect((host.as_str(), port)).await {
+                Ok(tcp) => pump(channel, tcp, stats, token).await,
+                Err(e) => {
+                    stats.set_error(format!("{}:{}: {}", host, port, e));
+                    let _ = channel.close().await;
+                }
+            }
+        });
+    }
+
+    fn insert(&self, port: u32, target: RemoteTarget) {
+        if let Ok(mut map) = self.0.lock() {
+            map.insert(port, target);
+        }
+    }
+
+    fn remove(&self, port: u32) {
+        if let Ok(mut map) = self.0.lock() {
+            map.remove(&port);
+        }
+    }
+}
+
+/// 启动转发：监听成功（或服务器接受远程转发）后返回，之后在后台运行直到 `token` 被取消；启动失败的原因同时记录到统计中
+///
+/// 本地转发和动态转发每个新连接都会按 `tab_id` 取当前的 SSH 会话，重连后无需重新启动
+pub async fn start_forward(
+    tab_id: String,
+    spec: ForwardSpec,
+    stats: Arc<ForwardStats>,
+    token: CancellationToken,
+) -> Result<(), SshError> {
+    let result = start(tab_id, spec, stats.clone(), token).await;
+    if let Err(e) = &result {
+        stats.set_error(e.to_string());
+    }
+    result
+}
+
+async fn start(
+    tab_id: String,
+    spec: ForwardSpec,
+    stats: Arc<ForwardStats>,
+    token: CancellationToken,
+) -> Result<(), SshError> {
+    match spec.kind {
+        ForwardKind::Local | ForwardKind::Dynamic => {
+            let listener = TcpListener::bind((spec.bind_host.as_str(), spec.bind_port)).await?;
+            let bound_port = listener.local_addr()?.port();
+            stats.set_running(true, bound_port);
+            info!(
+                "[Forward] {} listening on port {}",
+                spec.describe(),
+                bound_port
+            );
+            tokio::spawn(accept_loop(tab_id, spec, listener, stats, token));
+            Ok(())
+        }
+        ForwardKind::Remote => {
+            let session = SshManager::global()
+                .get_session(&tab_id)
+                .ok_or_else(|| SshError::Disconnected("Session not found".to_string()))?;
+            let handle = session.handle();
+            let requested = spec.bind_port as u32;
+            let allocated = handle
+                .tcpip_forward(spec.bind_host.clone(), requested)
+                .await
+                .map_err(SshError::from)?;
+            // 请求端口为 0 时服务器返回实际分配的端口
+            let bound_port = if requested == 0 { allocated } else { requested };
+            let remote_forwards = session.remote_forwards();
+            remote_forwards.insert(
+                bound_port,
+                RemoteTarget {
+                    host: spec.target_host.clone(),
+                    port: spec.target_port,
+                    stats: stats.clone(),
+                    token: token.clone(),
+                },
+            );
+            stats.set_running(true, bound_port as u16);
+            info!(
+                "[Forward] {} established on port {}",
+                spec.describe(),
+                bound_port
+            );
+
+            tokio::spawn(async move {
+                token.cancelled().await;
+                remote_forwards.remove(bound_port);
+                let _ = handle
+                    .cancel_tcpip_forward(spec.bind_host.clone(), bound_port)
+                    .await;
+                stats.set_running(false, 0);
+                info!("[Forward] {} stopped", spec.describe());
+            });
+            Ok(())
+        }
+    }
+}
+
+/// 本地监听循环：为每个连接打开 direct-tcpip 通道
+async fn accept_loop(
+    tab_id: String,
+    spec: ForwardSpec,
+    listener: TcpListener,
+    stats: Arc<ForwardStats>,
+    token: CancellationToken,
+) {
+    loop {
+        let accepted = tokio::select! {
+            _ = token.cancelled() => break,
+            accepted = listener.accept() => accepted,
+        };
+        let (tcp, peer) = match accepted {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                stats.set_error(format!("accept: {}", e));
+                continue;
+            }
+        };
+        let tab_id = tab_id.clone();
+        let spec = spec.clone();
+        let stats = stats.clone();
+        let token = token.clone();
+        tokio::spawn(async move {
+            let result = match spec.kind {
+                ForwardKind::Dynamic => handle_socks5(&tab_id, tcp, peer, &stats, &token).await,
+                _ => {
+                    let target = (spec.target_host.clone(), spec.target_port);
+                    match open_channel(&tab_id, &target.0, target.1, peer).await {
+                        Ok(channel) => {
+                            pump(channel, tcp, stats.clone(), token).await;
+                            Ok(())
+                        }
+                        Err(e) => Err(format!("{}:{}: {}", target.0, target.1, e)),
+                    }
+                }
+            };
+            if let Err(e) = result {
+                stats.set_error(e);
+            }
+        });
+    }
+    stats.set_running(false, 0);
+    info!("[Forward] {} stopped", spec.describe());
+}
+
+/// 通过当前会话打开到目标的 direct-tcpip 通道
+async fn open_channel(
+    tab_id: &str,
+    host: &str,
+    port: u16,
+    peer: SocketAddr,
+) -> Result<Channel<Msg>, SshError> {
+    let session = SshManager::global()
+        .get_session(tab_id)
+        .ok_or_else(|| SshError::Disconnected("Session not found".to_string()))?;
+    session
+        .open_direct_tcpip(host, port, &peer.ip().to_string(), peer.port())
+        .await
+}
+
+/// 处理一个 SOCKS5 客户端（仅支持无认证的 CONNECT）
+async fn handle_socks5(
+    tab_id: &str,
+    mut tcp: TcpStream,
+    peer: SocketAddr,
+    stats: &Arc<ForwardStats>,
+    token: &CancellationToken,
+) -> Result<(), String> {
+    let io_err = |e: std::io::Error| format!("SOCKS5: {}", e);
+
+    // 协商认证方式：回复“无需认证”
+    let mut header = [0u8; 2];
+    tcp.read_exact(&mut header).await.map_err(io_err)?;
+    if header[0] != 5 {
+        return Err(format!("SOCKS5: unsupported version {}", header[0]));
+    }
+    let mut methods = vec![0u8; header[1] as usize];
+    tcp.read_exact(&mut methods).await.map_err(io_err)?;
+    tcp.write_all(&[5, 0]).await.map_err(io_err)?;
+
+    // 请求：VER CMD RSV ATYP DST.ADDR DST.PORT
+    let mut request = [0u8; 4];
+    tcp.read_exact(&mut request).await.map_err(io_err)?;
+    if request[1] != 1 {
+        // 只支持 CONNECT
+        let _ = tcp.write_all(&[5, 7, 0, 1, 0, 0, 0, 0, 0, 0]).await;
+        return Err(format!("SOCKS5: unsupported command {}", request[1]));
+    }
+    let host = match request[3] {
+        1 => {
+            let mut addr = [0u8; 4];
+            tcp.read_exact(&mut addr).await.map_err(io_err)?;
+            std::net::Ipv4Addr::from(addr).to_string()
+        }
+        3 => {
+            let mut len = [0u8; 1];
+            tcp.read_exact(&mut len).await.map_err(io_err)?;
+            let mut name = vec![0u8; len[0] as usize];
+            tcp.read_exact(&mut name).await.map_err(io_err)?;
+            String::from_utf8_lossy(&name).to_string()
+        }
+        4 => {
+            let mut addr = [0u8; 16];
+            tcp.read_exact(&mut addr).await.map_err(io_err)?;
+            std::net::Ipv6Addr::from(addr).to_string()
+        }
+        atyp => {
+            let _ = tcp.write_all(&[5, 8, 0, 1, 0, 0, 0, 0, 0, 0]).await;
+            return Err(format!("SOCKS5: unsupported address type {}", atyp));
+        }
+    };
+    let mut port = [0u8; 2];
+    tcp.read_exact(&mut port).await.map_err(io_err)?;
+    let port = u16::from_be_bytes(port);
+
+    match open_channel(tab_id, &host, port, peer).await {
+        Ok(channel) => {
+            tcp.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0])
+                .await
+                .map_err(io_err)?;
+            pump(channel, tcp, stats.clone(), token.clone()).await;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = tcp.write_all(&[5, 5, 0, 1, 0, 0, 0, 0, 0, 0]).await;
+            Err(format!("{}:{}: {}", host, port, e))
+        }
+    }
+}
+
+/// 在 TCP 连接与 SSH 通道之间双向转发数据，直到两侧都关闭或转发被停止
+async fn pump(
+    channel: Channel<Msg>,
+    tcp: TcpStream,
+    stats: Arc<ForwardStats>,
+    token: CancellationToken,
+) {
+    stats.active_connections.fetch_add(1, Ordering::Relaxed);
+    stats.total_connections.fetch_add(1, Ordering::Relaxed);
+
+    let (mut tcp_read, mut tcp_write) = tcp.into_split();
+    let (mut channel_read, mut channel_write) = tokio::io::split(channel.into_stream());
+    let transfer = async {
+        tokio::join!(
+            copy_counted(&mut tcp_read, &mut channel_write, &stats.bytes_out),
+            copy_counted(&mut channel_read, &mut tcp_write, &stats.bytes_in),
+        )
+    };
+    tokio::select! {
+        _ = token.cancelled() => {}
+        (sent, received) = transfer => {
+            if let Err(e) = sent.and(received) {
+                stats.set_error(e.to_string());
+            }
+        }
+    }
+
+    stats.active_connections.fetch_sub(1, Ordering::Relaxed);
+}
+
+/// 复制数据并累计字节数，读到结尾时关闭写端
+async fn copy_counted<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    counter: &AtomicU64,
+) -> std::io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut buf = vec![0u8; 32 * 1024];
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            let _ = writer.shutdown().await;
+            return Ok(());
+        }
+        writer.write_all(&buf[..n]).await?;
+        counter.fetch_add(n as u64, Ordering::Relaxed);
+    }
+}
@@ -1,13 +1,16 @@
 // SSH 客户端 Handler 实现
 // 实现 russh::client::Handler trait
 
+use russh::client::{Msg, Session};
 use russh::keys::PublicKey;
+use russh::Channel;
 use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot, Mutex};
 use tracing::{error, info, warn};
 
 use super::event::{ConnectionEvent, HostKeyAction, LogEntry};
+use super::forward::RemoteForwards;
 
 /// SSH 客户端 Handler
 /// 处理 SSH 连接过程中的各种回调
@@ -20,6 +23,8 @@ pub struct SshClientHandler {
     port: u16,
     /// Host key 响应接收器（用于等待用户确认）
     host_key_response_rx: Arc<Mutex<Option<oneshot::Receiver<HostKeyAction>>>>,
+    /// 远程端口转发表（服务器打开 forwarded-tcpip 通道时查找本地目标）
+    remote_forwards: RemoteForwards,
 }
 
 impl SshClientHandler {
@@ -29,12 +34,14 @@ impl SshClientHandler {
         host: String,
         port: u16,
         host_key_response_rx: oneshot::Receiver<HostKeyAction>,
+        remote_forwards: RemoteForwards,
     ) -> Self {
         Self {
             event_sender,
             host,
             port,
             host_key_response_rx: Arc::new(Mutex::new(Some(host_key_response_rx))),
+            remote_forwards,
         }
     }
 
@@ -198,4 +205,22 @@ impl russh::client::Handler for SshClientHandler {
             }
         }
     }
+
+    /// 服务器为远程转发打开的通道
+    fn server_channel_open_forwarded_tcpip(
+        &mut self,
+        channel: Channel<Msg>,
+        connected_address: &str,
+        connected_port: u32,
+        originator_address: &str,
+        originator_port: u32,
+        _session: &mut Session,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        info!(
+            "[SSH] Forwarded connection to {}:{} from {}:{}",
+            connected_address, connected_port, originator_address, originator_port
+        );
+        self.remote_forwards.accept(channel, connected_port);
+        async { Ok(()) }
+    }
 }
@@ -4,6 +4,7 @@
 // - config: 连接配置 (SshConfig, AuthMethod)
 // - error: 错误类型 (SshError)
 // - event: 连接事件 (ConnectionEvent, ConnectionStage, LogEntry)
+// - forward: 端口转发 (ForwardSpec, ForwardStats)
 // - handler: russh Handler 实现
 // - client: SSH 客户端核心
 // - session: SSH 会话管理 (SshSession, TerminalChannel, ExecChannel, SftpChannel)
@@ -14,6 +15,7 @@ pub mod config;
 pub mod connector;
 pub mod error;
 pub mod event;
+pub mod forward;
 pub mod handler;
 pub mod manager;
 pub mod proxy;
@@ -26,6 +28,7 @@ pub use config::{AuthMethod, KeepaliveConfig, SshConfig};
 pub use connector::start_ssh_connection;
 pub use error::SshError;
 pub use event::{ConnectionEvent, ConnectionStage, LogEntry, LogLevel};
+pub use forward::{parse_host_port, start_forward, ForwardKind, ForwardSpec, ForwardStats};
 pub use manager::SshManager;
 pub use reconnect::{start_manual_reconnection, start_reconnection};
 pub use session::{
@@ -3,53 +3,34 @@
 // 当 SSH 连接断开时，自动尝试重新连接
 
 use gpui::{App, Entity};
+use gpui_component::notification::NotificationType;
 use tracing::{debug, error, info, warn};
 
 use crate::models::server::ServerData;
+use crate::services::notifications::{notify_key, NotificationCategory};
 use crate::state::{SessionState, SessionStatus};
 
-use super::config::{AuthMethod, KeepaliveConfig, SshConfig};
+use super::config::{KeepaliveConfig, SshConfig};
 use super::event::{ConnectionEvent, HostKeyAction};
 
 /// 从 ServerData 构建 SshConfig（复用 connector 中的逻辑）
 fn build_ssh_config(server: &ServerData) -> SshConfig {
-    let auth = match &server.auth_type {
-        crate::models::server::AuthType::Password => {
-            AuthMethod::Password(server.password_encrypted.clone().unwrap_or_default())
-        }
-        crate::models::server::AuthType::PublicKey => {
-            // Keep key-path resolution aligned with initial connection logic:
-            // prefer filename under managed keys dir, fallback to legacy absolute path.
-            let key_path = if let Some(filename) = &server.private_key_filename {
-                crate::services::storage::get_keys_dir()
-                    .map(|dir| dir.join(filename))
-                    .unwrap_or_else(|_| filename.into())
-            } else if let Some(old_path) = &server.private_key_path {
-                old_path.into()
-            } else {
-                "".into()
-            };
-
-            AuthMethod::PublicKey {
-                key_path,
-                passphrase: server.key_passphrase_encrypted.clone(),
-            }
-        }
-    };
+    let (username, auth) = super::connector::resolve_auth(server);
 
     let settings = crate::services::storage::load_settings().unwrap_or_default();
     let connection_settings = &settings.connection;
 
+    let keepalive_secs = server.resolve_keepalive_secs(connection_settings);
     let keepalive = KeepaliveConfig {
-        enabled: connection_settings.keepalive_interval_secs > 0,
-        interval: connection_settings.keepalive_interval_secs as u64,
+        enabled: keepalive_secs > 0,
+        interval: keepalive_secs as u64,
         max_retries: 3,
     };
 
     SshConfig {
         host: server.host.clone(),
         port: server.port,
-        username: server.username.clone(),
+        username,
         auth,
         connect_timeout: connection_settings.connection_timeout_secs as u64,
         jump_host: None,
@@ -180,25 +161,12 @@ pub fn start_reconnection(
                     });
 
                     // 推送重连成功通知
-                    if let Some(window) = cx.active_window() {
-                        use gpui::AppContext as _;
-                        let _ = cx.update_window(window, |_, window, cx| {
-                            use gpui::Styled;
-                            use gpui_component::notification::{Notification, NotificationType};
-                            use gpui_component::WindowExt;
-
-                            let lang = crate::services::storage::load_settings()
-                                .map(|s| s.theme.language)
-                                .unwrap_or_default();
-
-                            let notification = Notification::new()
-                                .message(crate::i18n::t(&lang, "terminal.reconnected"))
-                                .with_type(NotificationType::Success)
-                                .w_48()
-                                .py_2();
-                            window.push_notification(notification, cx);
-                        });
-                    }
+                    notify_key(
+                        cx,
+                        NotificationCategory::Session,
+                        NotificationType::Success,
+                        "terminal.reconnected",
+                    );
                 });
 
                 return; // 成功，退出重连循环
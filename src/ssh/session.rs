@@ -3,6 +3,7 @@
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
 use russh::client::Handle;
 use russh::client::Msg;
@@ -10,6 +11,7 @@ use russh::{ChannelMsg, ChannelReadHalf, ChannelWriteHalf};
 use tokio::sync::Mutex;
 
 use super::error::SshError;
+use super::forward::RemoteForwards;
 use super::handler::SshClientHandler;
 
 /// PTY 请求参数
@@ -55,6 +57,8 @@ pub struct SshSession {
     username: String,
     /// 连接状态
     is_connected: AtomicBool,
+    /// 远程端口转发（与 Handler 共享）
+    remote_forwards: RemoteForwards,
 }
 
 impl SshSession {
@@ -64,6 +68,7 @@ impl SshSession {
         handle: Arc<Handle<SshClientHandler>>,
         host: String,
         username: String,
+        remote_forwards: RemoteForwards,
     ) -> Self {
         Self {
             id,
@@ -71,6 +76,7 @@ impl SshSession {
             host,
             username,
             is_connected: AtomicBool::new(true),
+            remote_forwards,
         }
     }
 
@@ -104,6 +110,11 @@ impl SshSession {
         self.handle.clone()
     }
 
+    /// 获取远程端口转发表
+    pub fn remote_forwards(&self) -> RemoteForwards {
+        self.remote_forwards.clone()
+    }
+
     /// 打开终端 Shell 通道
     pub async fn open_terminal(&self, pty: PtyRequest) -> Result<TerminalChannel, SshError> {
         if !self.is_alive() {
@@ -179,6 +190,51 @@ impl SshSession {
         Ok(SftpChannel::new(channel))
     }
 
+    /// 打开 direct-tcpip 通道（本地转发/动态转发时连接远程目标）
+    pub async fn open_direct_tcpip(
+        &self,
+        host: &str,
+        port: u16,
+        originator_address: &str,
+        originator_port: u16,
+    ) -> Result<RusshChannel, SshError> {
+        if !self.is_alive() {
+            return Err(SshError::Disconnected(
+                "Session is disconnected".to_string(),
+            ));
+        }
+
+        self.handle
+            .channel_open_direct_tcpip(
+                host,
+                port as u32,
+                originator_address,
+                originator_port as u32,
+            )
+            .await
+            .map_err(SshError::from)
+    }
+
+    /// 测量往返延迟：打开一个会话通道并等待服务器确认后立即关闭
+    /// （与 keepalive 一样需要服务器应答，不会在远端执行任何命令）
+    pub async fn measure_latency(&self) -> Result<std::time::Duration, SshError> {
+        if !self.is_alive() {
+            return Err(SshError::Disconnected(
+                "Session is disconnected".to_string(),
+            ));
+        }
+
+        let started = std::time::Instant::now();
+        let channel = self
+            .handle
+            .channel_open_session()
+            .await
+            .map_err(SshError::from)?;
+        let elapsed = started.elapsed();
+        let _ = channel.close().await;
+        Ok(elapsed)
+    }
+
     /// 关闭会话
     pub async fn close(&self) -> Result<(), SshError> {
         self.mark_disconnected();
@@ -200,6 +256,8 @@ pub struct TerminalChannel {
     handle: Arc<Handle<SshClientHandler>>,
     read_half: Mutex<ChannelReadHalf>,
     write_half: Mutex<ChannelWriteHalf<Msg>>,
+    /// 最近一次收到输出或写入输入的时间（用于判断标签是否闲置）
+    last_activity: std::sync::Mutex<Instant>,
 }
 
 impl TerminalChannel {
@@ -211,12 +269,23 @@ impl TerminalChannel {
             read_half: Mutex::new(read_half),
             write_half: Mutex::new(write_half),
             handle,
+            last_activity: std::sync::Mutex::new(Instant::now()),
         }
     }
 
+    /// 最近一次收到输出或写入输入的时间
+    pub fn last_activity(&self) -> Instant {
+        *self.last_activity.lock().unwrap()
+    }
+
+    fn touch(&self) {
+        *self.last_activity.lock().unwrap() = Instant::now();
+    }
+
     /// 写入数据到终端
     /// 直接通过 handle 发送，不阻塞读取循环
     pub async fn write(&self, data: &[u8]) -> Result<(), SshError> {
+        self.touch();
         self.handle
             .data(self.id, data.to_vec().into())
             .await
@@ -231,8 +300,10 @@ impl TerminalChannel {
         // wait() on Channel<Msg> returns Option<ChannelMsg> directly
         match channel.wait().await {
             Some(channel_msg) => match channel_msg {
-                ChannelMsg::Data { data } => Ok(Some(data.to_vec())),
-                ChannelMsg::ExtendedData { data, .. } => Ok(Some(data.to_vec())),
+                ChannelMsg::Data { data } | ChannelMsg::ExtendedData { data, .. } => {
+                    self.touch();
+                    Ok(Some(data.to_vec()))
+                }
                 ChannelMsg::Eof | ChannelMsg::Close => Ok(None),
                 _ => Ok(Some(vec![])),
             },
@@ -281,40 +352,168 @@ impl ExecChannel {
             .await
             .map_err(|e| SshError::Channel(e.to_string()))?;
 
-        let mut stdout = Vec::new();
-        let mut stderr = Vec::new();
-        let mut exit_code = None;
+        collect_output(&mut channel).await
+    }
+
+    /// 执行命令，先把 `input` 写入标准输入并关闭，再获取输出（如 `sudo -S` 读取密码）
+    pub async fn exec_with_input(
+        &self,
+        command: &str,
+        input: &[u8],
+    ) -> Result<CommandOutput, SshError> {
+        let mut channel = self.channel.lock().await;
 
+        channel
+            .exec(true, command)
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+        channel
+            .data(input)
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+        channel
+            .eof()
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+
+        collect_output(&mut channel).await
+    }
+
+    /// 执行命令并按行回调标准输出（用于需要增量展示结果的耗时命令）
+    /// 回调返回 `false` 时关闭通道并提前结束
+    pub async fn exec_lines<F>(&self, command: &str, mut on_line: F) -> Result<(), SshError>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut channel = self.channel.lock().await;
+
+        channel
+            .exec(true, command)
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+
+        let mut buffer = Vec::new();
         loop {
             match channel.wait().await {
-                Some(channel_msg) => match channel_msg {
-                    ChannelMsg::Data { data } => {
-                        stdout.extend_from_slice(&data);
-                    }
-                    ChannelMsg::ExtendedData { data, ext } => {
-                        if ext == 1 {
-                            // stderr
-                            stderr.extend_from_slice(&data);
+                Some(ChannelMsg::Data { data }) => {
+                    buffer.extend_from_slice(&data);
+                    while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                        let line: Vec<u8> = buffer.drain(..=pos).collect();
+                        if !on_line(&String::from_utf8_lossy(&line[..line.len() - 1])) {
+                            let _ = channel.close().await;
+                            return Ok(());
                         }
                     }
-                    ChannelMsg::ExitStatus { exit_status } => {
-                        exit_code = Some(exit_status);
-                    }
-                    ChannelMsg::Eof | ChannelMsg::Close => {
-                        break;
-                    }
-                    _ => {}
-                },
-                None => break,
+                }
+                Some(ChannelMsg::Eof | ChannelMsg::Close) | None => break,
+                Some(_) => {}
             }
         }
 
-        Ok(CommandOutput {
-            stdout,
-            stderr,
-            exit_code: exit_code.unwrap_or(0),
-        })
+        // 最后一行可能没有换行符
+        if !buffer.is_empty() {
+            on_line(&String::from_utf8_lossy(&buffer));
+        }
+        Ok(())
     }
+
+    /// 执行命令并按行回调标准输出与标准错误，返回退出码（用于需要实时展示输出的安装、升级等命令）
+    /// `input` 会先写入标准输入后关闭（如 `sudo -S` 读取密码）；回调返回 `false` 时关闭通道并提前结束
+    pub async fn exec_streaming<F>(
+        &self,
+        command: &str,
+        input: Option<&[u8]>,
+        mut on_line: F,
+    ) -> Result<Option<u32>, SshError>
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut channel = self.channel.lock().await;
+
+        channel
+            .exec(true, command)
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+        if let Some(input) = input {
+            channel
+                .data(input)
+                .await
+                .map_err(|e| SshError::Channel(e.to_string()))?;
+        }
+        channel
+            .eof()
+            .await
+            .map_err(|e| SshError::Channel(e.to_string()))?;
+
+        // 标准输出与标准错误分别缓冲，避免两路输出拼进同一行
+        let mut buffers = [Vec::new(), Vec::new()];
+        let mut exit_code = None;
+        loop {
+            let (index, data) = match channel.wait().await {
+                Some(ChannelMsg::Data { data }) => (0, data),
+                Some(ChannelMsg::ExtendedData { data, .. }) => (1, data),
+                Some(ChannelMsg::ExitStatus { exit_status }) => {
+                    exit_code = Some(exit_status);
+                    continue;
+                }
+                // 退出码在 EOF 之后才到达，等到通道关闭为止
+                Some(ChannelMsg::Close) | None => break,
+                Some(_) => continue,
+            };
+            let buffer = &mut buffers[index];
+            buffer.extend_from_slice(&data);
+            while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=pos).collect();
+                let line = String::from_utf8_lossy(&line[..line.len() - 1]);
+                if !on_line(line.trim_end_matches('\r')) {
+                    let _ = channel.close().await;
+                    return Ok(exit_code);
+                }
+            }
+        }
+
+        for buffer in buffers.iter().filter(|b| !b.is_empty()) {
+            on_line(&String::from_utf8_lossy(buffer));
+        }
+        Ok(exit_code)
+    }
+}
+
+/// 读取通道消息直到结束，收集标准输出、标准错误与退出码
+async fn collect_output(channel: &mut RusshChannel) -> Result<CommandOutput, SshError> {
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let mut exit_code = None;
+
+    loop {
+        match channel.wait().await {
+            Some(channel_msg) => match channel_msg {
+                ChannelMsg::Data { data } => {
+                    stdout.extend_from_slice(&data);
+                }
+                ChannelMsg::ExtendedData { data, ext } => {
+                    if ext == 1 {
+                        // stderr
+                        stderr.extend_from_slice(&data);
+                    }
+                }
+                ChannelMsg::ExitStatus { exit_status } => {
+                    exit_code = Some(exit_status);
+                }
+                ChannelMsg::Eof | ChannelMsg::Close => {
+                    break;
+                }
+                _ => {}
+            },
+            None => break,
+        }
+    }
+
+    Ok(CommandOutput {
+        stdout,
+        stderr,
+        exit_code: exit_code.unwrap_or(0),
+    })
 }
 
 /// 命令输出
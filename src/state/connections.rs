@@ -0,0 +1,183 @@
+// 网络连接方法：轮询监听端口与连接列表、结束占用端口的进程，并在新终端中查看端口详情
+
+use std::time::Duration;
+
+use super::SessionState;
+use crate::components::connections::{ConnectionEvent, ConnectionListView};
+use crate::services::connections::{inspect_command, list_connections};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::processes::send_signal;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 连接列表刷新间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+impl SessionState {
+    /// 确保网络连接视图已创建，并只让该标签页轮询连接列表
+    pub fn ensure_connection_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<ConnectionListView> {
+        if !self.connection_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                ConnectionListView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.connection_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.connection_views.insert(tab_id.to_string(), view);
+        }
+        let view = self.connection_views.get(tab_id).unwrap().clone();
+
+        // 切换标签页后停止其他标签页的轮询
+        self.connection_poll_tokens.retain(|id, token| {
+            let keep = id == tab_id;
+            if !keep {
+                token.cancel();
+            }
+            keep
+        });
+        if !self.connection_poll_tokens.contains_key(tab_id) {
+            self.start_connection_polling(tab_id, view.clone(), cx);
+        }
+        view
+    }
+
+    /// 获取网络连接视图（如果存在）
+    pub fn get_connection_view(&self, tab_id: &str) -> Option<Entity<ConnectionListView>> {
+        self.connection_views.get(tab_id).cloned()
+    }
+
+    /// 停止所有标签页的连接列表轮询
+    pub(super) fn stop_connection_polling(&mut self) {
+        for (_, token) in self.connection_poll_tokens.drain() {
+            token.cancel();
+        }
+    }
+
+    /// 开始定时获取连接列表并写入视图
+    fn start_connection_polling(
+        &mut self,
+        tab_id: &str,
+        view: Entity<ConnectionListView>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Connections] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Connections] Start polling for tab {}", tab_id);
+
+        let token = CancellationToken::new();
+        self.connection_poll_tokens
+            .insert(tab_id.to_string(), token.clone());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            loop {
+                let result = tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    result = list_connections(&session) => result,
+                };
+                if tx.send(result).is_err() || !session.is_alive() {
+                    break;
+                }
+                tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                }
+            }
+        });
+
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(result) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    let updated = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| v.set_connections(result, cx));
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+                info!("[Connections] Polling ended for tab {}", tab_id);
+            })
+            .detach();
+    }
+
+    /// 执行网络连接操作：在终端查看直接打开新终端，结束进程（已在视图中确认）失败时在视图中显示原因
+    fn connection_action(
+        &mut self,
+        tab_id: &str,
+        event: ConnectionEvent,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (pid, signal) = match event {
+            ConnectionEvent::Terminal(port) => {
+                self.add_terminal_instance_with_command(tab_id, inspect_command(&port));
+                cx.notify();
+                return;
+            }
+            ConnectionEvent::Kill(pid, signal) => (pid, signal),
+        };
+
+        let Some(view) = self.connection_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Connections] No SSH session found for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let result = send_signal(&session, pid, signal).await;
+            // 成功后立即刷新列表，不等下一次轮询
+            let connections = match result {
+                Ok(()) => list_connections(&session).await.ok(),
+                Err(_) => None,
+            };
+            let _ = tx.send((result, connections));
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some((result, connections)) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match result {
+                    Ok(()) => {
+                        if let Some(connections) = connections {
+                            view.update(cx, |v, cx| v.set_connections(Ok(connections), cx));
+                        }
+                        notify_key(
+                            cx,
+                            NotificationCategory::Operation,
+                            NotificationType::Success,
+                            "processes.action_done",
+                        )
+                    }
+                    Err(e) => {
+                        error!("[Connections] Kill failed: {}", e);
+                        view.update(cx, |v, cx| v.set_action_error(Some(e), cx));
+                    }
+                });
+            })
+            .detach();
+    }
+}
@@ -1,12 +1,13 @@
 // 核心方法：标签页、侧边栏、快捷命令管理
 
 use super::{
-    MonitorState, SessionState, SessionStatus, SessionTab, SidebarPanel, TerminalInstance,
+    MonitorState, SessionState, SessionStatus, SessionTab, SidebarPanel, TabColor, TerminalInstance,
 };
+use crate::services::connection_history::ConnectionHistoryStore;
 use tracing::info;
 
 impl SessionState {
-    /// 添加新的会话标签（插入到最前面）
+    /// 添加新的会话标签（插入到固定标签之后的最前面）
     pub fn add_tab(&mut self, server_id: String, server_label: String) -> String {
         let tab_id = uuid::Uuid::new_v4().to_string();
 
@@ -19,25 +20,43 @@ impl SessionState {
             pty_initialized: false,
             last_sent_pty_size: None,
             pty_error: None,
+            snapshot: None,
+            startup_command: None,
         };
         let first_terminal_id = first_terminal.id.clone();
 
+        // 历史采样保留时长来自监控设置
+        let mut monitor_state = MonitorState::empty();
+        if let Ok(settings) = crate::services::storage::load_settings() {
+            monitor_state.set_history_retention_minutes(settings.monitor.history_retention_minutes);
+        }
+
         let tab = SessionTab {
             id: tab_id.clone(),
             server_id,
             server_label,
             status: SessionStatus::Connecting,
             server_data: None,
+            history_id: None,
             terminals: vec![first_terminal],
             active_terminal_id: Some(first_terminal_id),
             terminal_counter: 1,
-            monitor_state: MonitorState::empty(),
+            monitor_state,
             sftp_state: None,
             active_transfers: Vec::new(),
             services_started: false,
+            hibernated: false,
+            last_active_at: std::time::Instant::now(),
+            unread_alerts: 0,
+            sftp_start_path: None,
+            pinned: false,
+            color: TabColor::Auto,
+            window_id: None,
+            latency: Default::default(),
         };
-        // 新标签插入到最前面
-        self.tabs.insert(0, tab);
+        // 新标签插入到固定标签之后的最前面
+        let index = self.tabs.iter().filter(|t| t.pinned).count();
+        self.tabs.insert(index, tab);
         self.active_tab_id = Some(tab_id.clone());
         // 切换到会话视图
         self.show_home = false;
@@ -46,13 +65,51 @@ impl SessionState {
         tab_id
     }
 
+    /// 添加未保存服务器的临时会话标签（连接信息直接保存在标签上）
+    pub fn add_adhoc_tab(&mut self, server: crate::models::server::ServerData) -> String {
+        let tab_id = self.add_tab(server.id.clone(), server.label.clone());
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+            tab.server_data = Some(server);
+        }
+        tab_id
+    }
+
     /// 关闭标签
     pub fn close_tab(&mut self, tab_id: &str) {
         if let Some(pos) = self.tabs.iter().position(|t| t.id == tab_id) {
-            self.tabs.remove(pos);
-            // 如果关闭的是当前活动标签，切换到下一个
-            if self.active_tab_id.as_deref() == Some(tab_id) {
-                self.active_tab_id = self.tabs.first().map(|t| t.id.clone());
+            let tab = self.tabs.remove(pos);
+            if let Some(history_id) = &tab.history_id {
+                ConnectionHistoryStore::global().finish(history_id);
+            }
+            // 如果关闭的是所在窗口的当前标签，切换到同一窗口的下一个
+            let next_id = self
+                .tabs
+                .iter()
+                .find(|t| t.window_id == tab.window_id)
+                .map(|t| t.id.clone());
+            match tab.window_id {
+                None if self.active_tab_id.as_deref() == Some(tab_id) => {
+                    self.active_tab_id = next_id;
+                }
+                Some(window_id)
+                    if self.window_active_tabs.get(&window_id).map(String::as_str)
+                        == Some(tab_id) =>
+                {
+                    match next_id {
+                        Some(next_id) => self.window_active_tabs.insert(window_id, next_id),
+                        None => self.window_active_tabs.remove(&window_id),
+                    };
+                }
+                _ => {}
+            }
+            self.connecting_progress.remove(tab_id);
+            // 结束该标签的 Runbook 运行（过期的定时器找不到运行后直接忽略）
+            self.runbook_runs.remove(tab_id);
+            // 停止收集该标签的输出（插件之后读取时会报错）
+            self.plugins.output.remove(tab_id);
+            // 放弃该标签未结束的键盘宏录制
+            if self.is_recording_macro(tab_id) {
+                self.macro_recording = None;
             }
 
             // 停止并移除 MonitorService（Drop 会自动调用 stop）
@@ -62,10 +119,57 @@ impl SessionState {
                 }
             }
 
+            // 停止进程列表轮询并移除进程管理视图
+            if let Some(token) = self.process_poll_tokens.remove(tab_id) {
+                token.cancel();
+            }
+            self.process_views.remove(tab_id);
+
+            // 停止 Docker 列表轮询并移除 Docker 管理视图
+            if let Some(token) = self.docker_poll_tokens.remove(tab_id) {
+                token.cancel();
+            }
+            self.docker_views.remove(tab_id);
+
+            // 停止服务列表轮询并移除服务管理视图
+            if let Some(token) = self.service_poll_tokens.remove(tab_id) {
+                token.cancel();
+            }
+            self.service_views.remove(tab_id);
+
+            // 停止连接列表轮询并移除网络连接视图
+            if let Some(token) = self.connection_poll_tokens.remove(tab_id) {
+                token.cancel();
+            }
+            self.connection_views.remove(tab_id);
+
+            // 移除计划任务视图
+            self.cron_views.remove(tab_id);
+            self.package_views.remove(tab_id);
+            self.firewall_views.remove(tab_id);
+
+            // 停止全部端口转发并移除端口转发视图
+            self.port_forward_stop_all(tab_id);
+            self.port_forward_views.remove(tab_id);
+
             // 移除 SFTP 文件列表视图
             if self.sftp_file_list_views.remove(tab_id).is_some() {
                 info!("[SFTP] FileListView removed for closed tab {}", tab_id);
             }
+            self.sftp_search_states.remove(tab_id);
+            self.sftp_previews.remove(tab_id);
+            self.sftp_disk_usage_views.remove(tab_id);
+            self.sftp_log_viewers.remove(tab_id);
+            if let Some(token) = self.sftp_log_tail_tokens.remove(tab_id) {
+                token.cancel();
+            }
+            self.sftp_local_panels.remove(tab_id);
+            self.sftp_sudo_passwords.remove(tab_id);
+            self.sudo_pending_service_actions.remove(tab_id);
+            self.sudo_pending_cron_loads.remove(tab_id);
+            self.sudo_pending_package_actions.remove(tab_id);
+            self.sudo_pending_firewall_actions.remove(tab_id);
+            self.sudo_pending_sftp_lists.remove(tab_id);
 
             // 清理该 session 的文件监控和临时文件
             if let Some(watcher) = &self.file_watcher {
@@ -98,10 +202,53 @@ impl SessionState {
         }
     }
 
-    /// 激活指定标签
+    /// 拖动排序：把标签移到 `before_id` 之前（为 None 时移到末尾），固定标签始终排在最前
+    pub fn move_tab(&mut self, tab_id: &str, before_id: Option<&str>) {
+        if before_id == Some(tab_id) {
+            return;
+        }
+        let Some(pos) = self.tabs.iter().position(|t| t.id == tab_id) else {
+            return;
+        };
+        let tab = self.tabs.remove(pos);
+        let index = before_id
+            .and_then(|id| self.tabs.iter().position(|t| t.id == id))
+            .unwrap_or(self.tabs.len());
+        self.tabs.insert(index, tab);
+        self.sort_pinned_tabs();
+    }
+
+    /// 固定或取消固定标签
+    pub fn toggle_tab_pinned(&mut self, tab_id: &str) {
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+            tab.pinned = !tab.pinned;
+        }
+        self.sort_pinned_tabs();
+    }
+
+    /// 设置标签颜色
+    pub fn set_tab_color(&mut self, tab_id: &str, color: TabColor) {
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+            tab.color = color;
+        }
+    }
+
+    /// 固定标签排在最前（保持各自的相对顺序）
+    pub fn sort_pinned_tabs(&mut self) {
+        self.tabs.sort_by_key(|t| !t.pinned);
+    }
+
+    /// 激活指定标签（在标签所在的窗口中）
     pub fn activate_tab(&mut self, tab_id: &str) {
-        if self.tabs.iter().any(|t| t.id == tab_id) {
-            self.active_tab_id = Some(tab_id.to_string());
+        if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+            tab.unread_alerts = 0;
+            match tab.window_id {
+                Some(window_id) => {
+                    self.window_active_tabs
+                        .insert(window_id, tab_id.to_string());
+                }
+                None => self.active_tab_id = Some(tab_id.to_string()),
+            }
         }
     }
 
@@ -129,9 +276,32 @@ impl SessionState {
         self.sidebar_collapsed = !self.sidebar_collapsed;
     }
 
+    /// 切换标签页总览（仅在主窗口有会话时打开）
+    pub fn toggle_tab_overview(&mut self) {
+        self.tab_overview_open = !self.tab_overview_open && self.has_main_tabs();
+    }
+
+    /// 从主页（如主机监控总览）切换到指定标签的会话视图（独立窗口中的标签移回主窗口）
+    pub fn show_session_tab(&mut self, tab_id: &str) {
+        self.move_tab_to_window(tab_id, None);
+        self.activate_tab(tab_id);
+        self.show_home = false;
+    }
+
+    /// 从标签页总览中选择标签并切换到会话视图
+    pub fn select_tab_from_overview(&mut self, tab_id: &str) {
+        self.activate_tab(tab_id);
+        self.show_home = false;
+        self.tab_overview_open = false;
+    }
+
     /// 设置当前激活的侧边栏面板
     pub fn set_sidebar_panel(&mut self, panel: SidebarPanel) {
         self.active_sidebar_panel = panel;
+        // 打开通知中心即视为已读
+        if panel == SidebarPanel::Notifications {
+            self.clear_unread_alerts();
+        }
         // 如果 sidebar 折叠了，自动展开
         if self.sidebar_collapsed {
             self.sidebar_collapsed = false;
@@ -0,0 +1,233 @@
+// cron 计划任务方法：读取当前用户的 crontab、通过 sudo 读取 /etc/cron.d（必要时弹窗输入密码），并备份后写回 crontab
+
+use super::SessionState;
+use crate::components::cron::{CronEvent, CronView};
+use crate::services::cron::{
+    list_system_crontabs, list_user_crontab, save_user_crontab, CronEntry, CrontabSaveError,
+};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::sudo_check;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 读取 /etc/cron.d 的结果
+enum SystemLoadOutcome {
+    /// 读取完成；`passwordless_sudo` 表示使用了免密 sudo
+    Done {
+        passwordless_sudo: bool,
+        result: Result<Vec<CronEntry>, String>,
+    },
+    /// sudo 需要密码
+    NeedsPassword,
+}
+
+impl SessionState {
+    /// 确保计划任务视图已创建（首次创建时读取 crontab）
+    pub fn ensure_cron_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<CronView> {
+        if !self.cron_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                CronView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.cron_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.cron_views.insert(tab_id.to_string(), view);
+            self.cron_reload(tab_id, cx);
+        }
+        self.cron_views.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取计划任务视图（如果存在）
+    pub fn get_cron_view(&self, tab_id: &str) -> Option<Entity<CronView>> {
+        self.cron_views.get(tab_id).cloned()
+    }
+
+    /// 处理计划任务视图的事件
+    fn cron_action(&mut self, tab_id: &str, event: CronEvent, cx: &mut gpui::Context<Self>) {
+        match event {
+            CronEvent::Reload => self.cron_reload(tab_id, cx),
+            CronEvent::LoadSystem => self.cron_load_system(tab_id, cx),
+            CronEvent::Save { original, content } => self.cron_save(tab_id, original, content, cx),
+        }
+    }
+
+    /// 读取当前用户的 crontab 并写入视图
+    fn cron_reload(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(view) = self.cron_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Cron] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Cron] Loading crontab for tab {}", tab_id);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let _ = tx.send(list_user_crontab(&session).await);
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    view.update(cx, |v, cx| v.set_crontab(result, cx));
+                });
+            })
+            .detach();
+    }
+
+    /// 通过 sudo 读取 /etc/cron.d
+    ///
+    /// 本标签页已验证过 sudo 时直接使用；否则先尝试免密 sudo，需要密码时弹出 sudo 密码对话框，验证通过后重新读取
+    pub(super) fn cron_load_system(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(view) = self.cron_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Cron] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        let password = self.sftp_sudo_passwords.get(tab_id).cloned();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let outcome = match password {
+                Some(password) => SystemLoadOutcome::Done {
+                    passwordless_sudo: false,
+                    result: list_system_crontabs(&session, &password).await,
+                },
+                None if sudo_check(&session, None).await.is_ok() => SystemLoadOutcome::Done {
+                    passwordless_sudo: true,
+                    result: list_system_crontabs(&session, "").await,
+                },
+                None => SystemLoadOutcome::NeedsPassword,
+            };
+            let _ = tx.send(outcome);
+        });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(outcome) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match outcome {
+                    SystemLoadOutcome::Done {
+                        passwordless_sudo,
+                        result,
+                    } => {
+                        if passwordless_sudo {
+                            // 免密 sudo 以空密码记录
+                            session_state.update(cx, |state, _| {
+                                state.sftp_sudo_passwords.insert(tab_id, String::new());
+                            });
+                        }
+                        view.update(cx, |v, cx| v.set_system_entries(result, cx));
+                    }
+                    SystemLoadOutcome::NeedsPassword => {
+                        view.update(cx, |v, cx| v.cancel_system_loading(cx));
+                        session_state.update(cx, |state, cx| {
+                            let dialog = state.ensure_sftp_sudo_dialog(cx);
+                            dialog.update(cx, |d, _| {
+                                d.open_with(
+                                    "/etc/cron.d".to_string(),
+                                    tab_id.clone(),
+                                    "cron.sudo.title",
+                                    "cron.sudo.prompt",
+                                )
+                            });
+                            state.sudo_pending_service_actions.remove(&tab_id);
+                            state.sudo_pending_package_actions.remove(&tab_id);
+                            state.sudo_pending_firewall_actions.remove(&tab_id);
+                            state.sudo_pending_sftp_lists.remove(&tab_id);
+                            state.sudo_pending_cron_loads.insert(tab_id);
+                            cx.notify();
+                        });
+                    }
+                });
+            })
+            .detach();
+    }
+
+    /// 备份并写回 crontab，成功后重新读取
+    fn cron_save(
+        &mut self,
+        tab_id: &str,
+        original: String,
+        content: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.cron_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Cron] No SSH session found for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let result = save_user_crontab(&session, &original, &content).await;
+            // 保存成功后立即重新读取，以服务器上的实际内容为准
+            let crontab = match result {
+                Ok(_) => Some(list_user_crontab(&session).await),
+                Err(_) => None,
+            };
+            let _ = tx.send((result, crontab));
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some((result, crontab)) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match result {
+                    Ok(backup) => {
+                        view.update(cx, |v, cx| {
+                            if let Some(crontab) = crontab {
+                                v.set_crontab(crontab, cx);
+                            }
+                            v.set_saved(backup, cx);
+                        });
+                        notify_key(
+                            cx,
+                            NotificationCategory::Operation,
+                            NotificationType::Success,
+                            "cron.saved",
+                        )
+                    }
+                    Err(e) => {
+                        error!("[Cron] Save failed: {:?}", e);
+                        let message = match e {
+                            CrontabSaveError::Modified => {
+                                let lang = crate::services::storage::load_settings()
+                                    .map(|s| s.theme.language)
+                                    .unwrap_or_default();
+                                crate::i18n::t(&lang, "cron.error.modified").to_string()
+                            }
+                            CrontabSaveError::Command(e) => e,
+                        };
+                        view.update(cx, |v, cx| v.set_action_error(Some(message), cx));
+                    }
+                });
+            })
+            .detach();
+    }
+}
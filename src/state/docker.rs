@@ -0,0 +1,202 @@
+// Docker 管理方法：检测 docker、轮询容器/镜像列表、执行容器操作，并在新终端中查看日志或进入容器
+
+use std::time::Duration;
+
+use super::SessionState;
+use crate::components::docker::{DockerEvent, DockerView};
+use crate::services::docker::{
+    container_action, detect_docker, exec_shell_command, list_docker, logs_command, remove_image,
+    DockerSnapshot,
+};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 容器列表刷新间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 后台任务发送给视图的消息
+enum DockerUpdate {
+    Detected(Result<String, String>),
+    Snapshot(Result<DockerSnapshot, String>),
+}
+
+impl SessionState {
+    /// 确保 Docker 管理视图已创建，并只让该标签页轮询容器列表
+    pub fn ensure_docker_view(
+        &mut self,
+        tab_id: &str,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<DockerView> {
+        if !self.docker_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|_| {
+                DockerView::new(move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.docker_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.docker_views.insert(tab_id.to_string(), view);
+        }
+        let view = self.docker_views.get(tab_id).unwrap().clone();
+
+        // 切换标签页后停止其他标签页的轮询
+        self.docker_poll_tokens.retain(|id, token| {
+            let keep = id == tab_id;
+            if !keep {
+                token.cancel();
+            }
+            keep
+        });
+        if !self.docker_poll_tokens.contains_key(tab_id) {
+            self.start_docker_polling(tab_id, view.clone(), cx);
+        }
+        view
+    }
+
+    /// 获取 Docker 管理视图（如果存在）
+    pub fn get_docker_view(&self, tab_id: &str) -> Option<Entity<DockerView>> {
+        self.docker_views.get(tab_id).cloned()
+    }
+
+    /// 停止所有标签页的 Docker 列表轮询
+    pub(super) fn stop_docker_polling(&mut self) {
+        for (_, token) in self.docker_poll_tokens.drain() {
+            token.cancel();
+        }
+    }
+
+    /// 检测 docker，可用时定时获取容器与镜像列表并写入视图
+    fn start_docker_polling(
+        &mut self,
+        tab_id: &str,
+        view: Entity<DockerView>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Docker] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Docker] Start polling for tab {}", tab_id);
+
+        let token = CancellationToken::new();
+        self.docker_poll_tokens
+            .insert(tab_id.to_string(), token.clone());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            let detected = tokio::select! {
+                _ = token_for_task.cancelled() => return,
+                result = detect_docker(&session) => result,
+            };
+            let available = detected.is_ok();
+            if tx.send(DockerUpdate::Detected(detected)).is_err() || !available {
+                return;
+            }
+            loop {
+                let result = tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    result = list_docker(&session) => result,
+                };
+                if tx.send(DockerUpdate::Snapshot(result)).is_err() || !session.is_alive() {
+                    break;
+                }
+                tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                }
+            }
+        });
+
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(update) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    let updated = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| match update {
+                            DockerUpdate::Detected(result) => v.set_detected(result, cx),
+                            DockerUpdate::Snapshot(result) => v.set_snapshot(result, cx),
+                        });
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+                info!("[Docker] Polling ended for tab {}", tab_id);
+            })
+            .detach();
+    }
+
+    /// 执行 Docker 操作：日志与进入容器在新终端实例中运行，其余通过 ExecChannel 执行
+    fn docker_action(&mut self, tab_id: &str, event: DockerEvent, cx: &mut gpui::Context<Self>) {
+        let command = match &event {
+            DockerEvent::Logs(id) => Some(logs_command(id)),
+            DockerEvent::Exec(id) => Some(exec_shell_command(id)),
+            DockerEvent::Container(..) | DockerEvent::RemoveImage(_) => None,
+        };
+        if let Some(command) = command {
+            self.add_terminal_instance_with_command(tab_id, command);
+            cx.notify();
+            return;
+        }
+
+        let Some(view) = self.docker_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Docker] No SSH session found for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) =
+            tokio::sync::mpsc::unbounded_channel::<Result<Option<DockerSnapshot>, String>>();
+        ssh_manager.runtime().spawn(async move {
+            let result = match event {
+                DockerEvent::Container(id, action) => container_action(&session, &id, action).await,
+                DockerEvent::RemoveImage(id) => remove_image(&session, &id).await,
+                DockerEvent::Logs(_) | DockerEvent::Exec(_) => Ok(()),
+            };
+            // 操作完成后立即刷新列表，不等下一次轮询
+            let _ = tx.send(match result {
+                Ok(()) => Ok(list_docker(&session).await.ok()),
+                Err(e) => Err(e),
+            });
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match result {
+                    Ok(snapshot) => {
+                        if let Some(snapshot) = snapshot {
+                            view.update(cx, |v, cx| v.set_snapshot(Ok(snapshot), cx));
+                        }
+                        notify_key(
+                            cx,
+                            NotificationCategory::Operation,
+                            NotificationType::Success,
+                            "docker.action_done",
+                        )
+                    }
+                    Err(e) => {
+                        error!("[Docker] Action failed: {}", e);
+                        view.update(cx, |v, cx| v.set_action_error(Some(e), cx));
+                    }
+                });
+            })
+            .detach();
+    }
+}
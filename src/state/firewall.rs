@@ -0,0 +1,199 @@
+// 防火墙方法：通过 sudo 读取 ufw / firewalld / iptables 规则，以及放行/拒绝端口（必要时弹窗输入 sudo 密码）
+
+use super::SessionState;
+use crate::components::firewall::{FirewallEvent, FirewallView};
+use crate::services::firewall::{apply_port_rule, list_firewall_rules, FirewallStatus};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::sudo_check;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 后台任务回传的结果
+enum FirewallOutcome {
+    /// 执行结束；`passwordless_sudo` 表示使用了免密 sudo
+    Done {
+        passwordless_sudo: bool,
+        /// 快捷操作的结果（仅 Apply 时有值）
+        applied: Option<Result<(), String>>,
+        /// 读取到的规则（快捷操作失败时不重新读取）
+        status: Option<Result<FirewallStatus, String>>,
+    },
+    /// sudo 需要密码
+    NeedsPassword,
+}
+
+impl SessionState {
+    /// 确保防火墙视图已创建（首次创建时读取规则）
+    pub fn ensure_firewall_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<FirewallView> {
+        if !self.firewall_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                FirewallView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.firewall_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.firewall_views.insert(tab_id.to_string(), view);
+            self.firewall_action(tab_id, FirewallEvent::Reload, cx);
+        }
+        self.firewall_views.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取防火墙视图（如果存在）
+    pub fn get_firewall_view(&self, tab_id: &str) -> Option<Entity<FirewallView>> {
+        self.firewall_views.get(tab_id).cloned()
+    }
+
+    /// sudo 密码验证通过后重新执行等待中的操作
+    pub(super) fn firewall_resume(
+        &mut self,
+        tab_id: &str,
+        event: FirewallEvent,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if let Some(view) = self.firewall_views.get(tab_id) {
+            view.update(cx, |v, cx| v.start(&event, cx));
+        }
+        self.firewall_action(tab_id, event, cx);
+    }
+
+    /// 读取规则或执行快捷操作，快捷操作成功后重新读取规则
+    ///
+    /// 本标签页已验证过 sudo 则直接使用；否则先尝试免密 sudo，需要密码时弹出 sudo 密码对话框，验证通过后重新执行
+    fn firewall_action(
+        &mut self,
+        tab_id: &str,
+        event: FirewallEvent,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.firewall_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Firewall] No SSH session found for tab {}", tab_id);
+            // 可能由视图自身的事件触发，延后更新以避免重复借用
+            cx.defer(move |cx| {
+                view.update(cx, |v, cx| {
+                    v.set_status(Err("SSH session not found".to_string()), cx);
+                });
+            });
+            return;
+        };
+        let password = self.sftp_sudo_passwords.get(tab_id).cloned();
+        let pending = event.clone();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<FirewallOutcome>();
+        ssh_manager.runtime().spawn(async move {
+            let mut passwordless_sudo = false;
+            let password = match password {
+                Some(password) => password,
+                None if sudo_check(&session, None).await.is_ok() => {
+                    passwordless_sudo = true;
+                    String::new()
+                }
+                None => {
+                    let _ = tx.send(FirewallOutcome::NeedsPassword);
+                    return;
+                }
+            };
+
+            let applied = match &event {
+                FirewallEvent::Reload => None,
+                FirewallEvent::Apply { backend, rule } => {
+                    Some(apply_port_rule(&session, *backend, rule, &password).await)
+                }
+            };
+            let status = match &applied {
+                Some(Err(_)) => None,
+                _ => Some(list_firewall_rules(&session, &password).await),
+            };
+            let _ = tx.send(FirewallOutcome::Done {
+                passwordless_sudo,
+                applied,
+                status,
+            });
+        });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(outcome) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match outcome {
+                    FirewallOutcome::Done {
+                        passwordless_sudo,
+                        applied,
+                        status,
+                    } => {
+                        if passwordless_sudo {
+                            // 免密 sudo 以空密码记录
+                            session_state.update(cx, |state, _| {
+                                state
+                                    .sftp_sudo_passwords
+                                    .insert(tab_id.clone(), String::new());
+                            });
+                        }
+                        let succeeded = matches!(applied, Some(Ok(())));
+                        view.update(cx, |v, cx| {
+                            if let (FirewallEvent::Apply { rule, .. }, Some(result)) =
+                                (&pending, applied)
+                            {
+                                if let Err(e) = &result {
+                                    error!("[Firewall] Apply failed: {}", e);
+                                }
+                                v.set_applied(rule, result, cx);
+                            }
+                            match status {
+                                Some(status) => v.set_status(status, cx),
+                                None => v.cancel_loading(cx),
+                            }
+                        });
+                        if succeeded {
+                            info!("[Firewall] Port rule applied for tab {}", tab_id);
+                            notify_key(
+                                cx,
+                                NotificationCategory::Operation,
+                                NotificationType::Success,
+                                "firewall.applied",
+                            );
+                        }
+                    }
+                    FirewallOutcome::NeedsPassword => {
+                        view.update(cx, |v, cx| v.cancel_loading(cx));
+                        session_state.update(cx, |state, cx| {
+                            let dialog = state.ensure_sftp_sudo_dialog(cx);
+                            dialog.update(cx, |d, _| {
+                                d.open_with(
+                                    "firewall".to_string(),
+                                    tab_id.clone(),
+                                    "firewall.sudo.title",
+                                    "firewall.sudo.prompt",
+                                )
+                            });
+                            state.sudo_pending_service_actions.remove(&tab_id);
+                            state.sudo_pending_cron_loads.remove(&tab_id);
+                            state.sudo_pending_package_actions.remove(&tab_id);
+                            state.sudo_pending_sftp_lists.remove(&tab_id);
+                            state
+                                .sudo_pending_firewall_actions
+                                .insert(tab_id.clone(), pending.clone());
+                            cx.notify();
+                        });
+                    }
+                });
+            })
+            .detach();
+    }
+}
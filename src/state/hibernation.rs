@@ -0,0 +1,157 @@
+// 标签休眠：长时间闲置或已断开的标签释放终端状态与 PTY，激活时再按快照恢复
+
+use super::{SessionState, SessionStatus, SessionTab};
+use gpui::prelude::*;
+use std::time::{Duration, Instant};
+use tracing::{error, info};
+
+/// 休眠检查间隔
+const HIBERNATION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+impl SessionState {
+    /// 启动休眠检查定时器（应用启动时调用一次）
+    pub fn start_hibernation_timer(&self, cx: &mut gpui::Context<Self>) {
+        let session_state = cx.entity().downgrade();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                loop {
+                    async_cx
+                        .background_executor()
+                        .timer(HIBERNATION_CHECK_INTERVAL)
+                        .await;
+
+                    let result = async_cx.update(|cx| {
+                        session_state.update(cx, |state, cx| {
+                            state.hibernate_idle_tabs(cx);
+                        })
+                    });
+
+                    // SessionState 已释放，退出循环
+                    if !matches!(result, Ok(Ok(()))) {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// 休眠所有超过闲置时长的非活动标签
+    pub fn hibernate_idle_tabs(&mut self, cx: &mut gpui::Context<Self>) {
+        let settings = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .connection;
+        if settings.hibernate_after_minutes == 0 {
+            return;
+        }
+        let idle_limit = Duration::from_secs(settings.hibernate_after_minutes as u64 * 60);
+
+        // 各窗口正在显示的标签始终视为正在使用
+        let now = Instant::now();
+        let visible_tab_ids = self.visible_tab_ids();
+        for tab in self
+            .tabs
+            .iter_mut()
+            .filter(|t| visible_tab_ids.contains(&t.id))
+        {
+            tab.last_active_at = now;
+        }
+
+        // 闲置时长从最近一次显示或终端输入/输出算起；有进行中传输的标签不休眠
+        let idle_tab_ids: Vec<String> = self
+            .tabs
+            .iter()
+            .filter(|tab| !tab.hibernated)
+            .filter(|tab| now.duration_since(last_activity(tab)) >= idle_limit)
+            .filter(|tab| !tab.active_transfers.iter().any(|t| t.status.is_active()))
+            .filter(|tab| match tab.status {
+                SessionStatus::Disconnected => true,
+                SessionStatus::Connected => settings.hibernate_connected_tabs,
+                _ => false,
+            })
+            .map(|tab| tab.id.clone())
+            .collect();
+
+        for tab_id in idle_tab_ids {
+            self.hibernate_tab(&tab_id, cx);
+        }
+    }
+
+    /// 休眠指定标签：保存终端快照，释放终端状态并关闭 PTY 通道
+    pub fn hibernate_tab(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
+            return;
+        };
+        if tab.hibernated {
+            return;
+        }
+
+        for instance in tab.terminals.iter_mut() {
+            if let Some(terminal) = instance.terminal.take() {
+                instance.snapshot = Some(terminal.read(cx).snapshot());
+            }
+            instance.pty_initialized = false;
+            instance.last_sent_pty_size = None;
+
+            if let Some(channel) = instance.pty_channel.take() {
+                cx.to_async()
+                    .spawn(async move |_async_cx| {
+                        if let Err(e) = channel.close().await {
+                            error!("[Hibernation] Failed to close PTY channel: {:?}", e);
+                        }
+                    })
+                    .detach();
+            }
+        }
+
+        tab.hibernated = true;
+        info!("[Hibernation] Tab {} hibernated", tab_id);
+        cx.notify();
+    }
+
+    /// 唤醒当前活动标签（如已休眠）
+    /// 按快照重建终端状态；已连接的标签会在渲染时重新初始化 PTY
+    pub fn wake_active_tab(&mut self, cx: &mut gpui::Context<Self>) {
+        if let Some(tab_id) = self.active_tab_id.clone() {
+            self.wake_tab(&tab_id, cx);
+        }
+    }
+
+    /// 唤醒指定标签（如已休眠），独立窗口显示标签时使用
+    pub fn wake_tab(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
+            return;
+        };
+        tab.last_active_at = Instant::now();
+        if !tab.hibernated {
+            return;
+        }
+
+        let settings = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .terminal;
+        let settings = match &tab.server_data {
+            Some(server) => server.resolve_terminal_settings(settings),
+            None => settings,
+        };
+        for instance in tab.terminals.iter_mut() {
+            if let Some(snapshot) = instance.snapshot.take() {
+                let settings = settings.clone();
+                let terminal =
+                    cx.new(|_| crate::terminal::TerminalState::from_snapshot(settings, &snapshot));
+                instance.terminal = Some(terminal);
+            }
+        }
+
+        tab.hibernated = false;
+        info!("[Hibernation] Tab {} restored from snapshot", tab_id);
+    }
+}
+
+/// 标签最近一次活动的时间：显示时间与各终端 PTY 最近一次输入/输出时间中的较晚者
+fn last_activity(tab: &SessionTab) -> Instant {
+    tab.terminals
+        .iter()
+        .filter_map(|instance| instance.pty_channel.as_ref())
+        .map(|channel| channel.last_activity())
+        .fold(tab.last_active_at, Instant::max)
+}
@@ -0,0 +1,132 @@
+// 会话延迟：定期测量各已连接标签的 SSH 往返时间，长时间没有应答的会话标记为无响应
+
+use super::{SessionLatency, SessionState, SessionStatus};
+use gpui::prelude::*;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// 延迟测量间隔
+const LATENCY_INTERVAL: Duration = Duration::from_secs(5);
+/// 单次测量的超时时间（超时记为未应答）
+const LATENCY_TIMEOUT: Duration = Duration::from_secs(4);
+/// 超过该时长没有收到应答时视为无响应
+const STALE_AFTER: Duration = Duration::from_secs(15);
+/// 保留的测量结果数量（约 5 分钟）
+const MAX_SAMPLES: usize = 60;
+
+impl SessionLatency {
+    /// 记录一次测量结果
+    fn record(&mut self, sample: Option<u32>) {
+        if sample.is_some() {
+            self.last_reply_at = Some(Instant::now());
+        }
+        self.samples.push_back(sample);
+        while self.samples.len() > MAX_SAMPLES {
+            self.samples.pop_front();
+        }
+    }
+
+    /// 最近一次测量的延迟（毫秒），最近一次未应答时为 None
+    pub fn latest_ms(&self) -> Option<u32> {
+        self.samples.back().copied().flatten()
+    }
+
+    /// 应答测量结果的平均延迟（毫秒）
+    pub fn average_ms(&self) -> Option<u32> {
+        let replies: Vec<u32> = self.samples.iter().flatten().copied().collect();
+        if replies.is_empty() {
+            return None;
+        }
+        Some(replies.iter().sum::<u32>() / replies.len() as u32)
+    }
+
+    /// 连接是否已失去响应（已有测量但超过 STALE_AFTER 没有收到应答）
+    pub fn is_stale(&self) -> bool {
+        if self.latest_ms().is_some() || self.samples.is_empty() {
+            return false;
+        }
+        self.last_reply_at
+            .is_none_or(|at| at.elapsed() >= STALE_AFTER)
+    }
+
+    /// 最近一次应答距今的秒数
+    pub fn seconds_since_reply(&self) -> Option<u64> {
+        self.last_reply_at.map(|at| at.elapsed().as_secs())
+    }
+}
+
+impl SessionState {
+    /// 启动延迟测量定时器（应用启动时调用一次）
+    pub fn start_latency_timer(&self, cx: &mut gpui::Context<Self>) {
+        let session_state = cx.entity().downgrade();
+        cx.to_async()
+            .spawn(async move |async_cx| loop {
+                async_cx.background_executor().timer(LATENCY_INTERVAL).await;
+
+                let result = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.measure_latency(cx);
+                    })
+                });
+
+                // SessionState 已释放，退出循环
+                if !matches!(result, Ok(Ok(()))) {
+                    break;
+                }
+            })
+            .detach();
+    }
+
+    /// 并发测量所有已连接（未休眠）标签的往返延迟，结果写回标签
+    fn measure_latency(&mut self, cx: &mut gpui::Context<Self>) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let sessions: Vec<_> = self
+            .tabs
+            .iter()
+            .filter(|tab| tab.status == SessionStatus::Connected && !tab.hibernated)
+            .filter_map(|tab| {
+                ssh_manager
+                    .get_session(&tab.id)
+                    .map(|session| (tab.id.clone(), session))
+            })
+            .collect();
+        if sessions.is_empty() {
+            return;
+        }
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        ssh_manager.runtime().spawn(async move {
+            let measured = futures::future::join_all(
+                sessions
+                    .into_iter()
+                    .map(|(tab_id, session)| async move { (tab_id, measure(&session).await) }),
+            )
+            .await;
+            let _ = tx.send(measured);
+        });
+
+        cx.spawn(async move |this, cx| {
+            let Ok(measured) = rx.await else {
+                return;
+            };
+            let _ = this.update(cx, |state, cx| {
+                for (tab_id, sample) in measured {
+                    if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
+                        debug!("[Latency] Tab {}: {:?} ms", tab_id, sample);
+                        tab.latency.record(sample);
+                    }
+                }
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+}
+
+/// 测量一次往返延迟（毫秒），超时或失败时为 None
+async fn measure(session: &crate::ssh::session::SshSession) -> Option<u32> {
+    match tokio::time::timeout(LATENCY_TIMEOUT, session.measure_latency()).await {
+        Ok(Ok(elapsed)) => Some(elapsed.as_millis() as u32),
+        _ => None,
+    }
+}
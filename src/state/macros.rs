@@ -0,0 +1,140 @@
+// 键盘宏：录制终端中的按键，保存后可回放到当前或任意会话，并可通过绑定的快捷键触发
+
+use super::{MacroRecording, SessionState};
+use crate::components::common::macro_dialog::{MacroDialogEvent, MacroDialogState};
+use crate::models::TerminalMacro;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::storage;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{debug, error, info};
+
+impl SessionState {
+    /// 确保键盘宏编辑弹窗状态已创建
+    pub fn ensure_macro_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<MacroDialogState> {
+        if self.macro_dialog.is_none() {
+            let dialog = cx.new(|_| MacroDialogState::default());
+            cx.subscribe(
+                &dialog,
+                |this, _, event: &MacroDialogEvent, cx| match event {
+                    MacroDialogEvent::Saved => {
+                        this.refresh_snippets_config();
+                        cx.notify();
+                    }
+                },
+            )
+            .detach();
+            self.macro_dialog = Some(dialog);
+        }
+        self.macro_dialog.clone().unwrap()
+    }
+
+    /// 获取键盘宏编辑弹窗状态（如果存在）
+    pub fn get_macro_dialog(&self) -> Option<Entity<MacroDialogState>> {
+        self.macro_dialog.clone()
+    }
+
+    /// 删除键盘宏
+    pub fn delete_macro(&mut self, macro_id: &str, cx: &mut gpui::Context<Self>) {
+        if let Err(e) = storage::delete_macro(macro_id) {
+            error!("[Macro] Failed to delete macro: {}", e);
+        }
+        self.refresh_snippets_config();
+        cx.notify();
+    }
+
+    /// 标签是否正在录制键盘宏
+    pub fn is_recording_macro(&self, tab_id: &str) -> bool {
+        self.macro_recording
+            .as_ref()
+            .is_some_and(|recording| recording.tab_id == tab_id)
+    }
+
+    /// 开始在标签中录制键盘宏（替换其他标签未结束的录制）
+    pub fn start_macro_recording(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        info!("[Macro] Recording started in tab {}", tab_id);
+        self.macro_recording = Some(MacroRecording {
+            tab_id: tab_id.to_string(),
+            keys: String::new(),
+        });
+        cx.notify();
+    }
+
+    /// 结束录制并打开保存弹窗（没有录到按键时只提示）
+    pub fn stop_macro_recording(&mut self, cx: &mut gpui::Context<Self>) {
+        let Some(recording) = self.macro_recording.take() else {
+            return;
+        };
+        info!(
+            "[Macro] Recording stopped in tab {}: {} bytes",
+            recording.tab_id,
+            recording.keys.len()
+        );
+        if recording.keys.is_empty() {
+            notify_key(
+                cx,
+                NotificationCategory::Operation,
+                NotificationType::Warning,
+                "macro.notify.empty",
+            );
+        } else {
+            let dialog = self.ensure_macro_dialog(cx);
+            dialog.update(cx, |d, _| d.open_new(recording.tab_id, recording.keys));
+        }
+        cx.notify();
+    }
+
+    /// 记录发送到终端的按键或粘贴内容（仅在该标签录制时）
+    pub fn record_macro_input(&mut self, tab_id: &str, data: &[u8]) {
+        if let Some(recording) = self
+            .macro_recording
+            .as_mut()
+            .filter(|recording| recording.tab_id == tab_id)
+        {
+            recording.keys.push_str(&String::from_utf8_lossy(data));
+        }
+    }
+
+    /// 查找绑定到按键（GPUI 按键格式）的键盘宏
+    pub fn macro_for_keystroke(&self, keystroke: &str) -> Option<TerminalMacro> {
+        self.snippets_config
+            .as_ref()?
+            .macros
+            .iter()
+            .find(|m| m.keybinding.as_deref() == Some(keystroke))
+            .cloned()
+    }
+
+    /// 把键盘宏的按键序列写入标签的激活终端，没有可用终端时返回 false
+    pub fn play_macro(
+        &mut self,
+        tab_id: &str,
+        terminal_macro: &TerminalMacro,
+        cx: &mut gpui::Context<Self>,
+    ) -> bool {
+        let Some(channel) = self
+            .active_terminal_instance(tab_id)
+            .and_then(|inst| inst.pty_channel.clone())
+        else {
+            return false;
+        };
+        debug!(
+            "[Macro] Playing '{}' in tab {}",
+            terminal_macro.name, tab_id
+        );
+        // 录制中回放的宏也计入录制
+        self.record_macro_input(tab_id, terminal_macro.keys.as_bytes());
+        let data = terminal_macro.keys.as_bytes().to_vec();
+        cx.spawn(async move |_, _| {
+            if let Err(e) = channel.write(&data).await {
+                error!("[Macro] Failed to write to terminal: {:?}", e);
+            }
+        })
+        .detach();
+        true
+    }
+}
@@ -1,27 +1,89 @@
 // 全局 AppState 模块
 // 按功能拆分为多个子模块
 
+mod connections;
 mod core;
+mod cron;
+mod docker;
+mod firewall;
+mod hibernation;
+mod latency;
+mod macros;
+mod monitor_alerts;
+mod navigation;
+mod packages;
+mod plugins;
+mod port_forward;
+mod processes;
+mod runbook;
+mod scheduler;
+mod sftp_archive;
+mod sftp_bookmarks;
+mod sftp_compare;
+mod sftp_conflict;
+mod sftp_disk_usage;
+mod sftp_dual_pane;
+mod sftp_folder;
+mod sftp_hex;
+mod sftp_history;
+mod sftp_link;
+mod sftp_log_viewer;
 mod sftp_navigation;
+mod sftp_preview;
+mod sftp_queue;
+mod sftp_recovery;
+mod sftp_remote_copy;
+mod sftp_search;
+mod sftp_sudo_edit;
+mod sftp_sync;
 mod sftp_transfer;
+mod sftp_watch;
+mod snippets;
+mod ssh_snippet;
+mod systemd;
+mod tab_close;
 mod terminal;
 mod ui_state;
+mod windows;
+mod workspace;
 
+use crate::components::common::macro_dialog::MacroDialogState;
+use crate::components::common::runbook_dialog::RunbookDialogState;
+use crate::components::common::snippet_variables_dialog::SnippetVariablesDialogState;
+use crate::components::common::ssh_snippet_dialog::SshSnippetDialogState;
+use crate::components::connections::ConnectionListView;
+use crate::components::cron::CronView;
+use crate::components::docker::DockerView;
+use crate::components::firewall::{FirewallEvent, FirewallView};
 use crate::components::monitor::DetailDialogState;
+use crate::components::packages::PackageView;
+use crate::components::port_forward::PortForwardView;
+use crate::components::processes::ProcessListView;
 use crate::components::sftp::{
-    FileListView, NewFileDialogState, NewFolderDialogState, PathBarState, PropertiesDialogState,
+    CompareDialogState, ConflictDialogState, CopyToServerDialogState, DiskUsageView,
+    EmptyTrashDialogState, FileListView, HexViewerDialogState, LocalPanelState, LogViewerView,
+    NewFileDialogState, NewFolderDialogState, NewLinkDialogState, OverwriteDialogState,
+    PathBarState, PropertiesDialogState, SftpPreview, SftpSearchState, SudoPasswordDialogState,
+    SyncDialogState,
 };
+use crate::components::systemd::{ServiceEvent, ServiceListView};
 use crate::models::monitor::MonitorState;
 use crate::models::server::ServerData;
-use crate::models::sftp::SftpState;
-use crate::models::SnippetsConfig;
-use crate::services::monitor::MonitorService;
+use crate::models::sftp::{SftpState, TransferHistoryFilter};
+use crate::models::{Runbook, SnippetsConfig};
+use crate::pages::connecting::ConnectingProgress;
+use crate::services::monitor::{MonitorAlert, MonitorService};
+use crate::services::packages::{PackageAction, PackageManager};
+use crate::services::plugins::{
+    PluginHandle, PluginInfo, PluginMessage, PluginReply, PluginStatus,
+};
 use crate::services::sftp::{FileWatchEvent, FileWatcher, SftpService};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::{Arc, Mutex};
 
-use gpui::{Entity, FocusHandle};
+use gpui::{Entity, FocusHandle, WindowId};
 use gpui_component::input::InputState;
+use tokio_util::sync::CancellationToken;
 
 /// 会话连接状态
 #[derive(Clone, Debug, PartialEq)]
@@ -54,6 +116,10 @@ pub struct TerminalInstance {
     pub last_sent_pty_size: Option<(u32, u32)>,
     /// PTY 错误信息
     pub pty_error: Option<String>,
+    /// 休眠快照（休眠时释放终端状态，唤醒时据此恢复）
+    pub snapshot: Option<crate::terminal::TerminalSnapshot>,
+    /// PTY 创建后自动发送的命令（如进入容器、跟踪日志）
+    pub startup_command: Option<String>,
 }
 
 /// 会话标签
@@ -65,6 +131,8 @@ pub struct SessionTab {
     pub status: SessionStatus,
     /// 服务器连接数据（用于重连）
     pub server_data: Option<ServerData>,
+    /// 本次连接的历史记录 ID（会话结束时记录时长）
+    pub history_id: Option<String>,
     /// 多终端实例列表
     pub terminals: Vec<TerminalInstance>,
     /// 当前激活的终端 ID
@@ -79,54 +147,392 @@ pub struct SessionTab {
     pub active_transfers: Vec<crate::models::sftp::TransferItem>,
     /// 服务是否已启动（Monitor/SFTP，只在首次 PTY 创建时启动）
     pub services_started: bool,
+    /// 是否已休眠（终端状态与 PTY 已释放）
+    pub hibernated: bool,
+    /// 最近一次处于激活状态的时间（用于判断闲置时长）
+    pub last_active_at: std::time::Instant,
+    /// 未读的监控告警数（显示在标签上，切换到该标签或打开通知中心后清零）
+    pub unread_alerts: u32,
+    /// SFTP 启动后打开的目录（恢复工作区时使用，默认为主目录）
+    pub sftp_start_path: Option<String>,
+    /// 是否固定（固定标签以紧凑样式显示在最左侧）
+    pub pinned: bool,
+    /// 标签颜色标记
+    pub color: TabColor,
+    /// 所在的独立窗口（None 表示主窗口）
+    pub window_id: Option<WindowId>,
+    /// 最近的往返延迟测量结果
+    pub latency: SessionLatency,
+}
+
+/// 会话延迟：定期测量的 SSH 往返时间，用于区分响应慢的服务器与已失去响应的连接
+#[derive(Clone, Debug, Default)]
+pub struct SessionLatency {
+    /// 最近的测量结果（毫秒，最新的在后），None 表示超时未应答
+    pub samples: VecDeque<Option<u32>>,
+    /// 最近一次收到应答的时间
+    pub last_reply_at: Option<std::time::Instant>,
+}
+
+/// 标签颜色标记
+#[derive(Clone, Copy, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum TabColor {
+    /// 按服务器分组自动分配
+    #[default]
+    Auto,
+    /// 不显示颜色
+    None,
+    /// 手动选择的颜色（0xRRGGBB）
+    Rgb(u32),
+}
+
+/// 通知中心中的一条告警记录
+#[derive(Clone, Debug)]
+pub struct AlertRecord {
+    pub tab_id: String,
+    pub server_id: String,
+    pub server_label: String,
+    pub alert: MonitorAlert,
+    /// 触发时间（本地时间 HH:MM:SS）
+    pub time: String,
+    /// 触发时是否处于静音状态（静音时只记录，不弹通知也不标记标签）
+    pub muted: bool,
+}
+
+/// 标签上进行中的工作
+#[derive(Clone, Debug)]
+pub struct TabActiveWork {
+    pub tab_id: String,
+    pub label: String,
+    /// 已打开 Shell 的终端数量
+    pub terminals: usize,
+    /// 未完成的传输数量（等待、进行中或已暂停）
+    pub transfers: usize,
+    /// 运行中的端口转发数量
+    pub port_forwards: usize,
+}
+
+impl TabActiveWork {
+    pub fn is_empty(&self) -> bool {
+        self.terminals == 0 && self.transfers == 0 && self.port_forwards == 0
+    }
+}
+
+/// 等待确认的关闭请求
+#[derive(Clone, Debug)]
+pub struct PendingTabClose {
+    /// 发起关闭的窗口（None 表示主窗口），确认弹窗只在该窗口显示
+    pub window_id: Option<WindowId>,
+    pub tab_ids: Vec<String>,
+    /// 有进行中工作的标签
+    pub work: Vec<TabActiveWork>,
+}
+
+/// Runbook 步骤的执行状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunbookStepStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+    Skipped,
+}
+
+/// Runbook 运行状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunbookRunStatus {
+    Running,
+    /// 等待手动确认
+    AwaitingConfirm,
+    Paused,
+    Finished,
+    Failed,
+    Aborted,
+}
+
+impl RunbookRunStatus {
+    /// 是否尚未结束（运行中、等待确认或已暂停）
+    pub fn is_active(self) -> bool {
+        matches!(
+            self,
+            RunbookRunStatus::Running
+                | RunbookRunStatus::AwaitingConfirm
+                | RunbookRunStatus::Paused
+        )
+    }
+}
+
+/// Runbook 运行日志
+#[derive(Clone, Debug)]
+pub struct RunbookLogEntry {
+    /// 记录时间（HH:MM:SS）
+    pub time: String,
+    pub message: String,
+    pub is_error: bool,
+}
+
+/// 标签上运行中（或已结束但未关闭）的 Runbook
+pub struct RunbookRun {
+    pub runbook: Runbook,
+    /// 发送命令并读取输出的终端实例
+    pub terminal_id: String,
+    /// 当前步骤序号
+    pub current: usize,
+    pub step_status: Vec<RunbookStepStatus>,
+    pub status: RunbookRunStatus,
+    pub log: Vec<RunbookLogEntry>,
+    /// 上一条命令发出后收到的终端输出（已去除控制序列），供等待步骤匹配
+    output: String,
+    /// 等待步骤正在匹配的正则表达式
+    waiting: Option<regex::Regex>,
+    /// 开始步骤、暂停或中止时递增，使过期的定时器失效
+    generation: u64,
+}
+
+/// 正在录制的键盘宏
+pub struct MacroRecording {
+    /// 录制按键的标签
+    pub tab_id: String,
+    /// 已录制的按键序列
+    pub keys: String,
+}
+
+/// 插件日志
+#[derive(Clone, Debug)]
+pub struct PluginLogEntry {
+    /// 记录时间（HH:MM:SS）
+    pub time: String,
+    pub message: String,
+    pub is_error: bool,
+}
+
+/// 插件请求显示的提示框或确认框
+pub struct PluginDialog {
+    pub plugin_name: String,
+    pub title: String,
+    pub message: String,
+    /// 确认框（显示取消按钮，回复用户是否确认）
+    pub confirm: bool,
+    plugin_id: String,
+    reply: PluginReply,
+}
+
+/// 插件读取中的终端输出
+struct PluginOutput {
+    /// 开始读取时的激活终端
+    terminal_id: String,
+    /// 尚未被读取的输出（已去除控制序列）
+    text: String,
+}
+
+/// 脚本插件的运行状态
+#[derive(Default)]
+pub struct PluginRuntime {
+    /// 插件目录中的全部脚本
+    pub plugins: Vec<PluginInfo>,
+    /// 各插件的运行状态（按插件 ID 存储）
+    pub status: HashMap<String, PluginStatus>,
+    /// 各插件的日志（按插件 ID 存储）
+    pub logs: HashMap<String, Vec<PluginLogEntry>>,
+    /// 等待用户响应的对话框（按请求顺序逐个显示）
+    pub dialogs: VecDeque<PluginDialog>,
+    /// 运行中插件的句柄
+    handles: HashMap<String, PluginHandle>,
+    /// 插件线程发送消息的通道
+    messages: Option<tokio::sync::mpsc::UnboundedSender<PluginMessage>>,
+    /// 插件读取输出的会话（按 tab_id 存储）
+    output: HashMap<String, PluginOutput>,
 }
 
 /// 侧边栏面板类型
-#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
 pub enum SidebarPanel {
     #[default]
     Snippets, // 快捷命令
-    Transfer, // 传输管理
+    Transfer,      // 传输管理
+    Notifications, // 通知中心
+    Forwards,      // 端口转发
+    Runbooks,      // Runbook
+    Macros,        // 键盘宏
+}
+
+/// 传输面板的页面
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TransferPanelView {
+    #[default]
+    Active, // 进行中
+    History, // 历史
+}
+
+/// Monitor 区域的页面
+#[derive(Clone, Copy, Debug, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum MonitorPanelView {
+    #[default]
+    Monitor, // 系统监控
+    Processes, // 进程管理
+    Docker,    // Docker 容器管理
+    Services,  // systemd 服务管理
+    Network,   // 网络连接与端口监听
+    Cron,      // cron 计划任务
+    Packages,  // 软件包管理
+    Firewall,  // 防火墙规则
+}
+
+/// 可通过快捷键聚焦的会话面板
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionPanel {
+    Terminal,
+    Sftp,
+    Monitor,
 }
 
 /// 全局会话状态
 pub struct SessionState {
     pub tabs: Vec<SessionTab>,
+    /// 主窗口的当前标签
     pub active_tab_id: Option<String>,
+    /// 独立窗口的当前标签（按窗口记录）
+    pub window_active_tabs: HashMap<WindowId, String>,
+    /// 各标签的连接进度（按 tab_id 索引，标签移到其他窗口后继续使用）
+    pub connecting_progress: HashMap<String, Entity<ConnectingProgress>>,
     /// 是否显示主页视图（即使有会话也可以切换到主页）
     pub show_home: bool,
     /// 右侧 Sidebar 是否折叠
     pub sidebar_collapsed: bool,
+    /// 标签页总览是否打开
+    pub tab_overview_open: bool,
     /// 当前激活的侧边栏面板
     pub active_sidebar_panel: SidebarPanel,
+    /// 传输面板当前显示的页面
+    pub transfer_panel_view: TransferPanelView,
+    /// 传输历史的筛选条件
+    pub transfer_history_filter: TransferHistoryFilter,
     /// 快捷命令树展开的组 ID 集合
     pub snippets_expanded: HashSet<String>,
     /// 快捷命令配置缓存
     pub snippets_config: Option<SnippetsConfig>,
+    /// 快捷命令变量表单状态
+    pub snippet_variables_dialog: Option<Entity<SnippetVariablesDialogState>>,
+    /// Runbook 编辑弹窗状态
+    pub runbook_dialog: Option<Entity<RunbookDialogState>>,
+    /// 各标签的 Runbook 运行（按 tab_id 存储）
+    pub runbook_runs: HashMap<String, RunbookRun>,
+    /// 正在录制的键盘宏（同一时间只录制一个）
+    pub macro_recording: Option<MacroRecording>,
+    /// 键盘宏编辑弹窗状态
+    pub macro_dialog: Option<Entity<MacroDialogState>>,
+    /// SSH 配置片段弹窗状态
+    pub ssh_snippet_dialog: Option<Entity<SshSnippetDialogState>>,
+    /// 正在运行的定时任务 ID
+    pub running_jobs: HashSet<String>,
+    /// 上次检查到期定时任务的时间（定时任务未启动时为 None）
+    scheduler_checked_at: Option<chrono::DateTime<chrono::Local>>,
+    /// 脚本插件
+    pub plugins: PluginRuntime,
     /// 终端命令输入状态
     pub command_input: Option<Entity<InputState>>,
     /// 终端焦点句柄（用于键盘事件处理）
     pub terminal_focus_handle: Option<FocusHandle>,
+    /// Monitor 面板焦点句柄（快捷键聚焦 Monitor 面板时创建）
+    pub monitor_focus_handle: Option<FocusHandle>,
     /// Monitor 详情弹窗状态
     pub monitor_detail_dialog: Option<Entity<DetailDialogState>>,
     /// Monitor 服务实例（按 tab_id 存储）
     pub monitor_services: Arc<Mutex<HashMap<String, MonitorService>>>,
+    /// Monitor 区域当前显示的页面
+    pub monitor_panel_view: MonitorPanelView,
+    /// 进程管理视图（按 tab_id 存储）
+    pub process_views: HashMap<String, Entity<ProcessListView>>,
+    /// 进程列表轮询任务的取消令牌（按 tab_id 存储，只有显示进程页的标签在轮询）
+    pub process_poll_tokens: HashMap<String, CancellationToken>,
+    /// Docker 管理视图（按 tab_id 存储）
+    pub docker_views: HashMap<String, Entity<DockerView>>,
+    /// Docker 列表轮询任务的取消令牌（按 tab_id 存储，只有显示 Docker 页的标签在轮询）
+    pub docker_poll_tokens: HashMap<String, CancellationToken>,
+    /// systemd 服务管理视图（按 tab_id 存储）
+    pub service_views: HashMap<String, Entity<ServiceListView>>,
+    /// 服务列表轮询任务的取消令牌（按 tab_id 存储，只有显示服务页的标签在轮询）
+    pub service_poll_tokens: HashMap<String, CancellationToken>,
+    /// 网络连接视图（按 tab_id 存储）
+    pub connection_views: HashMap<String, Entity<ConnectionListView>>,
+    /// 连接列表轮询任务的取消令牌（按 tab_id 存储，只有显示网络页的标签在轮询）
+    pub connection_poll_tokens: HashMap<String, CancellationToken>,
+    /// cron 计划任务视图（按 tab_id 存储）
+    pub cron_views: HashMap<String, Entity<CronView>>,
+    /// 软件包管理视图（按 tab_id 存储）
+    pub package_views: HashMap<String, Entity<PackageView>>,
+    /// 防火墙视图（按 tab_id 存储）
+    pub firewall_views: HashMap<String, Entity<FirewallView>>,
+    /// 端口转发视图（按 tab_id 存储）
+    pub port_forward_views: HashMap<String, Entity<PortForwardView>>,
+    /// 运行中转发的取消令牌（tab_id -> 转发 ID -> 令牌）
+    pub port_forward_tokens: HashMap<String, HashMap<String, CancellationToken>>,
     /// SFTP 服务实例（按 tab_id 存储）
     pub sftp_services: Arc<Mutex<HashMap<String, SftpService>>>,
     /// SFTP 文件列表视图（按 tab_id 存储）
     pub sftp_file_list_views: HashMap<String, Entity<FileListView>>,
     /// SFTP 路径栏状态（按 tab_id 存储）
     pub sftp_path_bar_states: HashMap<String, Entity<PathBarState>>,
+    /// SFTP 搜索状态（按 tab_id 存储）
+    pub sftp_search_states: HashMap<String, Entity<SftpSearchState>>,
+    /// SFTP 双面板的本地面板（按 tab_id 存储）
+    pub sftp_local_panels: HashMap<String, Entity<LocalPanelState>>,
+    /// SFTP 文件预览（按 tab_id 存储）
+    pub sftp_previews: HashMap<String, SftpPreview>,
+    /// SFTP 磁盘占用分析视图（按 tab_id 存储）
+    pub sftp_disk_usage_views: HashMap<String, Entity<DiskUsageView>>,
+    /// SFTP 日志查看器（按 tab_id 存储）
+    pub sftp_log_viewers: HashMap<String, Entity<LogViewerView>>,
+    /// 日志跟踪任务的取消令牌（按 tab_id 存储）
+    pub sftp_log_tail_tokens: HashMap<String, CancellationToken>,
     /// SFTP 新建文件夹对话框状态
     pub sftp_new_folder_dialog: Option<Entity<NewFolderDialogState>>,
     /// SFTP 新建文件对话框状态
     pub sftp_new_file_dialog: Option<Entity<NewFileDialogState>>,
+    /// SFTP 新建链接对话框状态
+    pub sftp_new_link_dialog: Option<Entity<NewLinkDialogState>>,
+    /// SFTP 文件/目录对比对话框状态
+    pub sftp_compare_dialog: Option<Entity<CompareDialogState>>,
     /// SFTP 属性对话框状态
     pub sftp_properties_dialog: Option<Entity<PropertiesDialogState>>,
+    /// SFTP 覆盖确认对话框状态
+    pub sftp_overwrite_dialog: Option<Entity<OverwriteDialogState>>,
+    /// SFTP 清空回收站确认对话框状态
+    pub sftp_empty_trash_dialog: Option<Entity<EmptyTrashDialogState>>,
+    /// SFTP 目录同步对话框状态
+    pub sftp_sync_dialog: Option<Entity<SyncDialogState>>,
+    /// SFTP 复制到其他服务器对话框状态
+    pub sftp_copy_to_server_dialog: Option<Entity<CopyToServerDialogState>>,
+    /// SFTP 传输冲突对话框状态
+    pub sftp_conflict_dialog: Option<Entity<ConflictDialogState>>,
+    /// SFTP 十六进制查看器对话框状态
+    pub sftp_hex_viewer_dialog: Option<Entity<HexViewerDialogState>>,
+    /// SFTP sudo 密码对话框状态
+    pub sftp_sudo_dialog: Option<Entity<SudoPasswordDialogState>>,
+    /// 已验证的 sudo 密码（按 tab_id 存储，仅保存在内存中；空字符串表示免密 sudo）
+    pub sftp_sudo_passwords: HashMap<String, String>,
+    /// 等待 sudo 密码验证后执行的服务操作（按 tab_id 存储）
+    pub sudo_pending_service_actions: HashMap<String, ServiceEvent>,
+    /// 等待 sudo 密码验证后读取 /etc/cron.d 的标签页
+    pub sudo_pending_cron_loads: HashSet<String>,
+    /// 等待 sudo 密码验证后执行的软件包操作（按 tab_id 存储）
+    pub sudo_pending_package_actions: HashMap<String, (PackageManager, PackageAction)>,
+    /// 等待 sudo 密码验证后执行的防火墙操作（按 tab_id 存储）
+    pub sudo_pending_firewall_actions: HashMap<String, FirewallEvent>,
+    /// 等待 sudo 密码验证后以 sudo 列出当前目录的标签页
+    pub sudo_pending_sftp_lists: HashSet<String>,
+    /// 监控告警历史（最新的在前）
+    pub alert_history: VecDeque<AlertRecord>,
+    /// 是否静音全部告警
+    pub alerts_muted: bool,
+    /// 已静音告警的服务器 ID
+    pub muted_alert_servers: HashSet<String>,
     /// 外置编辑器文件监控器
     pub file_watcher: Option<Arc<Mutex<FileWatcher>>>,
     /// 文件监控事件接收器
     pub file_watch_receiver: Option<std::sync::mpsc::Receiver<FileWatchEvent>>,
+    /// 等待确认的关闭标签请求
+    pub pending_tab_close: Option<PendingTabClose>,
 }
 
 impl Default for SessionState {
@@ -134,23 +540,77 @@ impl Default for SessionState {
         Self {
             tabs: Vec::new(),
             active_tab_id: None,
+            window_active_tabs: HashMap::new(),
+            connecting_progress: HashMap::new(),
             show_home: true,
             sidebar_collapsed: false,
+            tab_overview_open: false,
             active_sidebar_panel: SidebarPanel::Snippets,
+            transfer_panel_view: TransferPanelView::Active,
+            transfer_history_filter: TransferHistoryFilter::All,
             snippets_expanded: HashSet::new(),
             snippets_config: None,
+            snippet_variables_dialog: None,
+            runbook_dialog: None,
+            runbook_runs: HashMap::new(),
+            macro_recording: None,
+            macro_dialog: None,
+            ssh_snippet_dialog: None,
+            running_jobs: HashSet::new(),
+            scheduler_checked_at: None,
+            plugins: PluginRuntime::default(),
             command_input: None,
             terminal_focus_handle: None,
+            monitor_focus_handle: None,
             monitor_detail_dialog: None,
             monitor_services: Arc::new(Mutex::new(HashMap::new())),
+            monitor_panel_view: MonitorPanelView::Monitor,
+            process_views: HashMap::new(),
+            process_poll_tokens: HashMap::new(),
+            docker_views: HashMap::new(),
+            docker_poll_tokens: HashMap::new(),
+            service_views: HashMap::new(),
+            service_poll_tokens: HashMap::new(),
+            connection_views: HashMap::new(),
+            connection_poll_tokens: HashMap::new(),
+            cron_views: HashMap::new(),
+            package_views: HashMap::new(),
+            firewall_views: HashMap::new(),
+            port_forward_views: HashMap::new(),
+            port_forward_tokens: HashMap::new(),
             sftp_services: Arc::new(Mutex::new(HashMap::new())),
             sftp_file_list_views: HashMap::new(),
             sftp_path_bar_states: HashMap::new(),
+            sftp_search_states: HashMap::new(),
+            sftp_local_panels: HashMap::new(),
+            sftp_previews: HashMap::new(),
+            sftp_disk_usage_views: HashMap::new(),
+            sftp_log_viewers: HashMap::new(),
+            sftp_log_tail_tokens: HashMap::new(),
             sftp_new_folder_dialog: None,
             sftp_new_file_dialog: None,
+            sftp_new_link_dialog: None,
+            sftp_compare_dialog: None,
             sftp_properties_dialog: None,
+            sftp_overwrite_dialog: None,
+            sftp_empty_trash_dialog: None,
+            sftp_sync_dialog: None,
+            sftp_copy_to_server_dialog: None,
+            sftp_conflict_dialog: None,
+            sftp_hex_viewer_dialog: None,
+            sftp_sudo_dialog: None,
+            sftp_sudo_passwords: HashMap::new(),
+            sudo_pending_service_actions: HashMap::new(),
+            sudo_pending_cron_loads: HashSet::new(),
+            sudo_pending_package_actions: HashMap::new(),
+            sudo_pending_firewall_actions: HashMap::new(),
+            sudo_pending_sftp_lists: HashSet::new(),
+            alert_history: VecDeque::new(),
+            alerts_muted: false,
+            muted_alert_servers: HashSet::new(),
             file_watcher: None,
             file_watch_receiver: None,
+            pending_tab_close: None,
         }
     }
 }
@@ -160,12 +620,19 @@ pub(crate) enum SftpInitResult {
     /// 1. 主目录路径就绪（工具栏可渲染）
     HomeReady {
         home_dir: String,
+        /// 初始打开的目录（默认为主目录）
+        start_dir: String,
     },
     /// 2. 当前目录内容就绪（文件列表可渲染）
     CurrentDirReady {
         path: String,
         entries: Vec<crate::models::sftp::FileEntry>,
     },
+    /// 2'. 当前目录读取失败（文件列表显示错误与恢复操作）
+    CurrentDirFailed {
+        path: String,
+        error: String,
+    },
     /// 3. 父级目录内容就绪（文件夹树可渲染）
     ParentDirsReady {
         dir_caches: Vec<(String, Vec<crate::models::sftp::FileEntry>)>,
@@ -175,6 +642,8 @@ pub(crate) enum SftpInitResult {
         passwd_content: Option<String>,
         group_content: Option<String>,
     },
+    /// 服务器禁用了 SFTP 子系统，已回退到 SCP 模式
+    ScpFallback,
     Error(String),
 }
 
@@ -197,43 +666,3 @@ pub(crate) fn get_path_hierarchy(path: &str) -> Vec<String> {
 
     hierarchy
 }
-
-/// 将 russh-sftp 的目录条目转换为 FileEntry
-pub(crate) fn convert_sftp_entries(
-    base_path: &str,
-    entries: Vec<russh_sftp::client::fs::DirEntry>,
-) -> Vec<crate::models::sftp::FileEntry> {
-    entries
-        .into_iter()
-        .filter_map(|entry| {
-            let name = entry.file_name();
-            if name == "." || name == ".." {
-                return None;
-            }
-            let full_path = if base_path == "/" {
-                format!("/{}", name)
-            } else {
-                format!("{}/{}", base_path.trim_end_matches('/'), name)
-            };
-            let attrs = entry.metadata();
-            let file_type = if attrs.is_dir() {
-                crate::models::sftp::FileType::Directory
-            } else if attrs.is_symlink() {
-                crate::models::sftp::FileType::Symlink
-            } else {
-                crate::models::sftp::FileType::File
-            };
-            let mut file_entry =
-                crate::models::sftp::FileEntry::new(name.to_string(), full_path, file_type);
-            file_entry.size = attrs.size.unwrap_or(0);
-            file_entry.permissions = attrs.permissions.map(|p| p as u32).unwrap_or(0);
-            file_entry.uid = attrs.uid;
-            file_entry.gid = attrs.gid;
-            if let Some(mtime) = attrs.mtime {
-                file_entry.modified =
-                    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(mtime as u64));
-            }
-            Some(file_entry)
-        })
-        .collect()
-}
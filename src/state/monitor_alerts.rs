@@ -0,0 +1,123 @@
+// 监控告警方法：把告警记录到通知中心、标记标签、弹出通知，以及全局/按服务器静音
+
+use super::{AlertRecord, SessionState};
+use crate::i18n::t;
+use crate::models::settings::{Language, TemperatureUnit};
+use crate::services::monitor::{AlertMetric, MonitorAlert};
+use crate::services::notifications::{notify, NotificationCategory};
+use gpui_component::notification::NotificationType;
+use tracing::info;
+
+/// 通知中心最多保留的告警条数
+const MAX_ALERT_HISTORY: usize = 200;
+
+impl AlertRecord {
+    /// 告警描述（如 "CPU 使用率 95.2% 已超过阈值 90%"）
+    pub fn message(&self, lang: &Language) -> String {
+        let metric = match self.alert.metric {
+            AlertMetric::Cpu => t(lang, "alerts.metric.cpu").to_string(),
+            AlertMetric::Memory => t(lang, "alerts.metric.memory").to_string(),
+            AlertMetric::Disk => t(lang, "alerts.metric.disk").replace("{}", &self.alert.subject),
+            AlertMetric::Temperature => {
+                // 温度按设置的单位显示
+                let unit = crate::services::storage::load_settings()
+                    .unwrap_or_default()
+                    .monitor
+                    .temperature_unit;
+                let format = |celsius: f32| match unit {
+                    TemperatureUnit::Celsius => format!("{:.0}°C", celsius),
+                    TemperatureUnit::Fahrenheit => format!("{:.0}°F", celsius * 9.0 / 5.0 + 32.0),
+                };
+                return t(lang, "alerts.message_temperature")
+                    .replace("{sensor}", &self.alert.subject)
+                    .replace("{value}", &format(self.alert.value))
+                    .replace("{threshold}", &format(self.alert.threshold as f32));
+            }
+            AlertMetric::DriveHealth => {
+                return t(lang, "alerts.message_drive").replace("{}", &self.alert.subject);
+            }
+        };
+        t(lang, "alerts.message")
+            .replace("{metric}", &metric)
+            .replace("{value}", &format!("{:.1}", self.alert.value))
+            .replace("{threshold}", &self.alert.threshold.to_string())
+    }
+}
+
+impl SessionState {
+    /// 记录监控告警：写入通知中心；未静音时标记标签并按设置弹出通知
+    pub(super) fn record_monitor_alert(
+        &mut self,
+        tab_id: &str,
+        alert: MonitorAlert,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
+            return;
+        };
+        let muted = self.alerts_muted || self.muted_alert_servers.contains(&tab.server_id);
+        if !muted {
+            tab.unread_alerts += 1;
+        }
+
+        let record = AlertRecord {
+            tab_id: tab.id.clone(),
+            server_id: tab.server_id.clone(),
+            server_label: tab.server_label.clone(),
+            alert,
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            muted,
+        };
+        let settings = crate::services::storage::load_settings().unwrap_or_default();
+        let message = record.message(&settings.theme.language);
+        info!(
+            "[Monitor] Alert on {}: {}{}",
+            record.server_label,
+            message,
+            if muted { " (muted)" } else { "" }
+        );
+
+        if !muted && settings.monitor.alert_notification {
+            notify(
+                cx,
+                NotificationCategory::Monitor,
+                NotificationType::Warning,
+                format!("{}: {}", record.server_label, message),
+            );
+        }
+
+        self.alert_history.push_front(record);
+        self.alert_history.truncate(MAX_ALERT_HISTORY);
+        cx.notify();
+    }
+
+    /// 清除所有标签上的未读告警标记
+    pub fn clear_unread_alerts(&mut self) {
+        for tab in &mut self.tabs {
+            tab.unread_alerts = 0;
+        }
+    }
+
+    /// 切换全局告警静音
+    pub fn toggle_alerts_muted(&mut self) {
+        self.alerts_muted = !self.alerts_muted;
+    }
+
+    /// 指定服务器的告警是否已静音
+    pub fn is_server_alerts_muted(&self, server_id: &str) -> bool {
+        self.muted_alert_servers.contains(server_id)
+    }
+
+    /// 切换指定服务器的告警静音
+    pub fn toggle_server_alerts_muted(&mut self, server_id: &str) {
+        if !self.muted_alert_servers.remove(server_id) {
+            self.muted_alert_servers.insert(server_id.to_string());
+        }
+    }
+
+    /// 清空告警历史
+    pub fn clear_alert_history(&mut self) {
+        self.alert_history.clear();
+        self.clear_unread_alerts();
+    }
+}
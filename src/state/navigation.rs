@@ -0,0 +1,88 @@
+// 键盘导航：在窗口的标签之间、当前标签的终端实例之间切换，聚焦终端 / SFTP / Monitor 面板
+
+use super::{SessionPanel, SessionState};
+use gpui::{Window, WindowId};
+
+impl SessionState {
+    /// 在窗口的标签之间循环切换（`delta` 为 1 切换到下一个，-1 切换到上一个）
+    pub fn activate_adjacent_tab(&mut self, window_id: Option<WindowId>, delta: isize) {
+        let ids: Vec<String> = self.window_tabs(window_id).map(|t| t.id.clone()).collect();
+        if ids.is_empty() {
+            return;
+        }
+        let current = self
+            .window_active_tab(window_id)
+            .and_then(|tab| ids.iter().position(|id| *id == tab.id))
+            .unwrap_or(0);
+        let next = (current as isize + delta).rem_euclid(ids.len() as isize) as usize;
+        self.activate_tab(&ids[next]);
+        if window_id.is_none() {
+            self.show_home = false;
+        }
+    }
+
+    /// 切换到窗口中的第 `index` 个标签（从 0 开始），`None` 表示最后一个标签
+    pub fn activate_tab_at(&mut self, window_id: Option<WindowId>, index: Option<usize>) {
+        let tab_id = match index {
+            Some(index) => self.window_tabs(window_id).nth(index),
+            None => self.window_tabs(window_id).last(),
+        }
+        .map(|t| t.id.clone());
+        if let Some(tab_id) = tab_id {
+            self.activate_tab(&tab_id);
+            if window_id.is_none() {
+                self.show_home = false;
+            }
+        }
+    }
+
+    /// 在当前标签的终端实例之间循环切换
+    pub fn activate_adjacent_terminal(&mut self, window_id: Option<WindowId>, delta: isize) {
+        let Some(tab) = self.window_active_tab(window_id) else {
+            return;
+        };
+        if tab.terminals.len() < 2 {
+            return;
+        }
+        let current = tab
+            .active_terminal_id
+            .as_ref()
+            .and_then(|id| tab.terminals.iter().position(|t| &t.id == id))
+            .unwrap_or(0);
+        let next = (current as isize + delta).rem_euclid(tab.terminals.len() as isize) as usize;
+        let tab_id = tab.id.clone();
+        let terminal_id = tab.terminals[next].id.clone();
+        self.activate_terminal_instance(&tab_id, &terminal_id);
+    }
+
+    /// 聚焦当前标签的终端、SFTP 文件列表或 Monitor 面板
+    pub fn focus_panel(
+        &mut self,
+        window_id: Option<WindowId>,
+        panel: SessionPanel,
+        window: &mut Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(tab_id) = self.window_active_tab(window_id).map(|t| t.id.clone()) else {
+            return;
+        };
+        if window_id.is_none() {
+            self.show_home = false;
+        }
+        match panel {
+            SessionPanel::Terminal => {
+                let handle = self.ensure_terminal_focus_handle_created(cx);
+                window.focus(&handle);
+            }
+            SessionPanel::Sftp => {
+                if let Some(view) = self.get_sftp_file_list_view(&tab_id) {
+                    view.read(cx).focus(window, cx);
+                }
+            }
+            SessionPanel::Monitor => {
+                let handle = self.ensure_monitor_focus_handle_created(cx);
+                window.focus(&handle);
+            }
+        }
+    }
+}
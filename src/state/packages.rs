@@ -0,0 +1,315 @@
+// 软件包管理方法：检测包管理器并读取可升级列表、搜索软件包，以及流式执行更新/升级/安装（非 root 时通过 sudo，必要时弹窗输入密码）
+
+use super::SessionState;
+use crate::components::packages::{PackageEvent, PackageView};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::packages::{
+    detect_package_manager, list_upgradable, run_package_action, search_packages, PackageAction,
+    PackageManager, UpgradablePackage,
+};
+use crate::services::sftp::sudo_check;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 读取包管理器信息的任务回传的消息
+enum LoadMessage {
+    Manager(Result<Option<PackageManager>, String>),
+    Upgradable(Result<Vec<UpgradablePackage>, String>),
+}
+
+/// 执行操作的任务回传的消息
+enum RunMessage {
+    Line(String),
+    /// 执行结束；`passwordless_sudo` 表示使用了免密 sudo，`upgradable` 为执行后的可升级列表
+    Done {
+        passwordless_sudo: bool,
+        result: Result<(), String>,
+        upgradable: Result<Vec<UpgradablePackage>, String>,
+    },
+    /// sudo 需要密码
+    NeedsPassword,
+}
+
+impl SessionState {
+    /// 确保软件包管理视图已创建（首次创建时检测包管理器）
+    pub fn ensure_package_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<PackageView> {
+        if !self.package_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                PackageView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.package_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.package_views.insert(tab_id.to_string(), view);
+            self.packages_load(tab_id, cx);
+        }
+        self.package_views.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取软件包管理视图（如果存在）
+    pub fn get_package_view(&self, tab_id: &str) -> Option<Entity<PackageView>> {
+        self.package_views.get(tab_id).cloned()
+    }
+
+    /// 处理软件包管理视图的事件
+    fn package_action(&mut self, tab_id: &str, event: PackageEvent, cx: &mut gpui::Context<Self>) {
+        match event {
+            PackageEvent::Reload => self.packages_load(tab_id, cx),
+            PackageEvent::Search { manager, query } => {
+                self.packages_search(tab_id, manager, query, cx)
+            }
+            PackageEvent::Run { manager, action } => self.packages_run(tab_id, manager, action, cx),
+        }
+    }
+
+    /// 检测包管理器并读取可升级列表
+    fn packages_load(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(view) = self.package_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Packages] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Packages] Detecting package manager for tab {}", tab_id);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let manager = detect_package_manager(&session).await;
+            let found = manager.clone().ok().flatten();
+            let _ = tx.send(LoadMessage::Manager(manager));
+            if let Some(manager) = found {
+                let _ = tx.send(LoadMessage::Upgradable(
+                    list_upgradable(&session, &manager).await,
+                ));
+            }
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(message) = rx.recv().await {
+                    let updated = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| match message {
+                            LoadMessage::Manager(result) => v.set_manager(result, cx),
+                            LoadMessage::Upgradable(result) => v.set_upgradable(result, cx),
+                        });
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// 搜索软件包
+    fn packages_search(
+        &mut self,
+        tab_id: &str,
+        manager: PackageManager,
+        query: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.package_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Packages] No SSH session found for tab {}", tab_id);
+            // 由视图自身的事件触发，延后更新以避免重复借用
+            cx.defer(move |cx| {
+                view.update(cx, |v, cx| {
+                    v.set_search_results(Err("SSH session not found".to_string()), cx);
+                });
+            });
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let _ = tx.send(search_packages(&session, &manager, &query).await);
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    view.update(cx, |v, cx| v.set_search_results(result, cx));
+                });
+            })
+            .detach();
+    }
+
+    /// sudo 密码验证通过后重新执行等待中的操作
+    pub(super) fn packages_resume(
+        &mut self,
+        tab_id: &str,
+        manager: PackageManager,
+        action: PackageAction,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if let Some(view) = self.package_views.get(tab_id) {
+            view.update(cx, |v, cx| v.start_output(&action, cx));
+        }
+        self.packages_run(tab_id, manager, action, cx);
+    }
+
+    /// 执行更新/升级/安装并把输出按批次写入视图，完成后刷新可升级列表
+    ///
+    /// 需要 sudo 时：本标签页已验证过 sudo 则直接使用；否则先尝试免密 sudo，需要密码时弹出 sudo 密码对话框，验证通过后重新执行
+    fn packages_run(
+        &mut self,
+        tab_id: &str,
+        manager: PackageManager,
+        action: PackageAction,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.package_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Packages] No SSH session found for tab {}", tab_id);
+            cx.defer(move |cx| {
+                view.update(cx, |v, cx| {
+                    v.finish_output(Err("SSH session not found".to_string()), cx);
+                });
+            });
+            return;
+        };
+        let password = self.sftp_sudo_passwords.get(tab_id).cloned();
+        let pending = (manager.clone(), action.clone());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<RunMessage>();
+        ssh_manager.runtime().spawn(async move {
+            let mut passwordless_sudo = false;
+            let password = match password {
+                Some(password) => Some(password),
+                None if !manager.needs_sudo() => None,
+                None if sudo_check(&session, None).await.is_ok() => {
+                    passwordless_sudo = true;
+                    Some(String::new())
+                }
+                None => {
+                    let _ = tx.send(RunMessage::NeedsPassword);
+                    return;
+                }
+            };
+
+            let result =
+                run_package_action(&session, &manager, &action, password.as_deref(), |line| {
+                    tx.send(RunMessage::Line(line.to_string())).is_ok()
+                })
+                .await;
+            // 执行完成后立即刷新可升级列表
+            let upgradable = list_upgradable(&session, &manager).await;
+            let _ = tx.send(RunMessage::Done {
+                passwordless_sudo,
+                result,
+                upgradable,
+            });
+        });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(message) = rx.recv().await {
+                    // 合并已到达的行，减少 UI 刷新次数
+                    let mut lines = Vec::new();
+                    let mut last = None;
+                    let mut next = Some(message);
+                    while let Some(message) = next.take() {
+                        match message {
+                            RunMessage::Line(line) => lines.push(line),
+                            message => {
+                                last = Some(message);
+                                break;
+                            }
+                        }
+                        next = rx.try_recv().ok();
+                    }
+
+                    let finished = last.is_some();
+                    let _ = async_cx.update(|cx| {
+                        if !lines.is_empty() {
+                            view.update(cx, |v, cx| v.append_output(lines, cx));
+                        }
+                        match last {
+                            Some(RunMessage::Done {
+                                passwordless_sudo,
+                                result,
+                                upgradable,
+                            }) => {
+                                if passwordless_sudo {
+                                    // 免密 sudo 以空密码记录
+                                    session_state.update(cx, |state, _| {
+                                        state
+                                            .sftp_sudo_passwords
+                                            .insert(tab_id.clone(), String::new());
+                                    });
+                                }
+                                let succeeded = result.is_ok();
+                                if let Err(e) = &result {
+                                    error!("[Packages] Action failed: {}", e);
+                                }
+                                view.update(cx, |v, cx| {
+                                    v.finish_output(result, cx);
+                                    v.set_upgradable(upgradable, cx);
+                                });
+                                if succeeded {
+                                    notify_key(
+                                        cx,
+                                        NotificationCategory::Operation,
+                                        NotificationType::Success,
+                                        "packages.action_done",
+                                    );
+                                }
+                            }
+                            Some(RunMessage::NeedsPassword) => {
+                                view.update(cx, |v, cx| v.cancel_output(cx));
+                                let (manager, action) = pending.clone();
+                                session_state.update(cx, |state, cx| {
+                                    let dialog = state.ensure_sftp_sudo_dialog(cx);
+                                    dialog.update(cx, |d, _| {
+                                        d.open_with(
+                                            manager.program.clone(),
+                                            tab_id.clone(),
+                                            "packages.sudo.title",
+                                            "packages.sudo.prompt",
+                                        )
+                                    });
+                                    state.sudo_pending_service_actions.remove(&tab_id);
+                                    state.sudo_pending_cron_loads.remove(&tab_id);
+                                    state.sudo_pending_firewall_actions.remove(&tab_id);
+                                    state.sudo_pending_sftp_lists.remove(&tab_id);
+                                    state
+                                        .sudo_pending_package_actions
+                                        .insert(tab_id.clone(), (manager, action));
+                                    cx.notify();
+                                });
+                            }
+                            _ => {}
+                        }
+                    });
+                    if finished {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+}
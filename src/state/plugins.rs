@@ -0,0 +1,386 @@
+// 脚本插件：加载插件目录中已启用的脚本，处理脚本发来的 API 请求（会话、终端、SFTP、对话框与通知），
+// 收集插件日志，并在会话终端就绪后通知插件
+
+use super::runbook::{t, ANSI_ESCAPE};
+use super::{
+    PluginDialog, PluginLogEntry, PluginOutput, PluginRuntime, SessionState, SessionStatus,
+};
+use crate::services::notifications::{notify, NotificationCategory};
+use crate::services::plugins::{
+    self, PluginEvent, PluginInfo, PluginMessage, PluginMessageKind, PluginReply, PluginRequest,
+    PluginStatus,
+};
+use crate::services::storage;
+use gpui::prelude::*;
+use gpui_component::notification::NotificationType;
+use rhai::{Array, Dynamic, Map};
+use tracing::{error, info};
+
+/// 每个插件最多保留的日志条数
+const MAX_LOG_ENTRIES: usize = 500;
+/// 每个会话最多缓存的未读取输出（字节）
+const MAX_OUTPUT_LEN: usize = 64 * 1024;
+
+impl PluginRuntime {
+    /// 插件的运行状态（未启用时为已停止）
+    pub fn status(&self, plugin_id: &str) -> PluginStatus {
+        self.status.get(plugin_id).copied().unwrap_or_default()
+    }
+
+    /// 插件是否已启用（正在运行或等待事件）
+    pub fn is_enabled(&self, plugin_id: &str) -> bool {
+        self.handles.contains_key(plugin_id)
+    }
+
+    /// 是否有插件正在读取该标签的终端输出
+    pub fn is_reading_output(&self, tab_id: &str) -> bool {
+        self.output.contains_key(tab_id)
+    }
+}
+
+impl SessionState {
+    /// 启动插件系统：接收插件线程发来的消息，并加载已启用的插件
+    pub fn start_plugins(&mut self, cx: &mut gpui::Context<Self>) {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<PluginMessage>();
+        self.plugins.messages = Some(tx);
+        cx.spawn(async move |this, cx| {
+            while let Some(message) = rx.recv().await {
+                let handled = this.update(cx, |state, cx| {
+                    state.handle_plugin_message(message, cx);
+                });
+                if handled.is_err() {
+                    return;
+                }
+            }
+        })
+        .detach();
+        self.reload_plugins(cx);
+    }
+
+    /// 重新扫描插件目录并重启已启用的插件（脚本修改后生效）
+    pub fn reload_plugins(&mut self, cx: &mut gpui::Context<Self>) {
+        let running: Vec<String> = self.plugins.handles.keys().cloned().collect();
+        for plugin_id in running {
+            self.stop_plugin(&plugin_id);
+        }
+        self.plugins.plugins = plugins::discover_plugins();
+        let enabled = storage::load_settings()
+            .map(|s| s.system.enabled_plugins)
+            .unwrap_or_default();
+        let to_start: Vec<PluginInfo> = self
+            .plugins
+            .plugins
+            .iter()
+            .filter(|p| enabled.contains(&p.id))
+            .cloned()
+            .collect();
+        info!(
+            "[Plugins] Found {} plugins, {} enabled",
+            self.plugins.plugins.len(),
+            to_start.len()
+        );
+        for info in &to_start {
+            self.start_plugin(info);
+        }
+        cx.notify();
+    }
+
+    fn start_plugin(&mut self, info: &PluginInfo) {
+        let Some(messages) = self.plugins.messages.clone() else {
+            return;
+        };
+        self.push_plugin_log(&info.id, t("plugins.log.started"), false);
+        let handle = plugins::start_plugin(info, messages);
+        self.plugins
+            .status
+            .insert(info.id.clone(), PluginStatus::Running);
+        self.plugins.handles.insert(info.id.clone(), handle);
+    }
+
+    /// 停止插件（丢弃句柄时中止正在执行的脚本），并关闭它等待中的对话框
+    fn stop_plugin(&mut self, plugin_id: &str) {
+        if self.plugins.handles.remove(plugin_id).is_some() {
+            self.push_plugin_log(plugin_id, t("plugins.log.stopped"), false);
+        }
+        self.plugins.status.remove(plugin_id);
+        self.plugins.dialogs.retain(|d| d.plugin_id != plugin_id);
+    }
+
+    /// 启用或停用插件（保存到设置中，下次启动时自动加载）
+    pub fn set_plugin_enabled(
+        &mut self,
+        plugin_id: &str,
+        enabled: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let mut settings = storage::load_settings().unwrap_or_default();
+        settings.system.enabled_plugins.retain(|id| id != plugin_id);
+        if enabled {
+            settings.system.enabled_plugins.push(plugin_id.to_string());
+        }
+        if let Err(e) = storage::save_settings(&settings) {
+            error!("[Plugins] Failed to save enabled plugins: {}", e);
+        }
+
+        self.stop_plugin(plugin_id);
+        if enabled {
+            let info = self
+                .plugins
+                .plugins
+                .iter()
+                .find(|p| p.id == plugin_id)
+                .cloned();
+            if let Some(info) = info {
+                self.start_plugin(&info);
+            }
+        }
+        cx.notify();
+    }
+
+    /// 手动运行插件（调用脚本的 run 函数）
+    pub fn run_plugin(&mut self, plugin_id: &str) {
+        if let Some(handle) = self.plugins.handles.get(plugin_id) {
+            handle.send(PluginEvent::Run);
+        }
+    }
+
+    /// 清空插件日志
+    pub fn clear_plugin_log(&mut self, plugin_id: &str) {
+        self.plugins.logs.remove(plugin_id);
+    }
+
+    /// 会话终端就绪后通知全部插件
+    pub fn notify_plugins_connected(&self, tab_id: &str) {
+        let Some(tab) = self.tabs.iter().find(|t| t.id == tab_id) else {
+            return;
+        };
+        for handle in self.plugins.handles.values() {
+            handle.send(PluginEvent::Connected {
+                tab_id: tab_id.to_string(),
+                server: tab.server_label.clone(),
+            });
+        }
+    }
+
+    /// 关闭当前显示的插件对话框，把结果回复给插件
+    pub fn respond_plugin_dialog(&mut self, confirmed: bool, cx: &mut gpui::Context<Self>) {
+        if let Some(dialog) = self.plugins.dialogs.pop_front() {
+            let result = if dialog.confirm {
+                Dynamic::from(confirmed)
+            } else {
+                Dynamic::UNIT
+            };
+            dialog.reply.send(Ok(result));
+            cx.notify();
+        }
+    }
+
+    /// 终端输出：收集插件正在读取的终端的输出
+    pub fn feed_plugin_output(&mut self, tab_id: &str, terminal_id: &str, data: &[u8]) {
+        let Some(output) = self.plugins.output.get_mut(tab_id) else {
+            return;
+        };
+        if output.terminal_id != terminal_id {
+            return;
+        }
+        let text = String::from_utf8_lossy(data);
+        output.text.push_str(&ANSI_ESCAPE.replace_all(&text, ""));
+        if output.text.len() > MAX_OUTPUT_LEN {
+            let cut = (output.text.len() - MAX_OUTPUT_LEN..)
+                .find(|&i| output.text.is_char_boundary(i))
+                .unwrap_or(0);
+            output.text.drain(..cut);
+        }
+    }
+
+    fn push_plugin_log(&mut self, plugin_id: &str, message: String, is_error: bool) {
+        let log = self.plugins.logs.entry(plugin_id.to_string()).or_default();
+        log.push(PluginLogEntry {
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            message,
+            is_error,
+        });
+        if log.len() > MAX_LOG_ENTRIES {
+            log.drain(..log.len() - MAX_LOG_ENTRIES);
+        }
+    }
+
+    fn handle_plugin_message(&mut self, message: PluginMessage, cx: &mut gpui::Context<Self>) {
+        // 已停止的旧线程发来的状态与请求直接忽略（丢弃回复通道，脚本随即中止）
+        let current = self
+            .plugins
+            .handles
+            .get(&message.plugin_id)
+            .is_some_and(|handle| handle.run_id() == message.run_id);
+        match message.kind {
+            PluginMessageKind::Log {
+                message: text,
+                is_error,
+            } => {
+                self.push_plugin_log(&message.plugin_id, text, is_error);
+            }
+            PluginMessageKind::Status(status) if current => {
+                self.plugins.status.insert(message.plugin_id, status);
+            }
+            PluginMessageKind::Call { request, reply } if current => {
+                self.handle_plugin_request(&message.plugin_id, request, reply, cx);
+            }
+            PluginMessageKind::Status(_) | PluginMessageKind::Call { .. } => {}
+        }
+        cx.notify();
+    }
+
+    fn handle_plugin_request(
+        &mut self,
+        plugin_id: &str,
+        request: PluginRequest,
+        reply: PluginReply,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let result = match request {
+            PluginRequest::Sessions => Ok(Dynamic::from(
+                self.tabs
+                    .iter()
+                    .map(|tab| {
+                        let mut map = Map::new();
+                        map.insert("tab_id".into(), Dynamic::from(tab.id.clone()));
+                        map.insert("server".into(), Dynamic::from(tab.server_label.clone()));
+                        map.insert(
+                            "connected".into(),
+                            Dynamic::from(tab.status == SessionStatus::Connected),
+                        );
+                        Dynamic::from(map)
+                    })
+                    .collect::<Array>(),
+            )),
+            PluginRequest::OpenSession { server } => {
+                let servers = storage::load_servers().unwrap_or_default().servers;
+                match servers
+                    .into_iter()
+                    .find(|s| s.id == server || s.label == server)
+                {
+                    Some(s) => Ok(Dynamic::from(self.add_tab(s.id, s.label))),
+                    None => Err(t("plugins.error.server_not_found").replace("{}", &server)),
+                }
+            }
+            PluginRequest::Send { tab_id, text } => {
+                self.plugin_send(&tab_id, text).map(|_| Dynamic::UNIT)
+            }
+            PluginRequest::ReadOutput { tab_id } => {
+                self.plugin_read_output(&tab_id).map(Dynamic::from)
+            }
+            PluginRequest::Sftp { tab_id, op } => {
+                let service = self
+                    .sftp_services
+                    .lock()
+                    .ok()
+                    .and_then(|s| s.get(&tab_id).cloned());
+                let Some(service) = service else {
+                    reply.send(Err(t("plugins.error.no_sftp").replace("{}", &tab_id)));
+                    return;
+                };
+                crate::ssh::manager::SshManager::global()
+                    .runtime()
+                    .spawn(async move {
+                        reply.send(op.execute(&service).await);
+                    });
+                return;
+            }
+            PluginRequest::Alert { title, message } => {
+                self.push_plugin_dialog(plugin_id, title, message, false, reply);
+                return;
+            }
+            PluginRequest::Confirm { title, message } => {
+                self.push_plugin_dialog(plugin_id, title, message, true, reply);
+                return;
+            }
+            PluginRequest::Notify { message } => {
+                notify(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Info,
+                    message,
+                );
+                Ok(Dynamic::UNIT)
+            }
+        };
+        reply.send(result);
+    }
+
+    fn push_plugin_dialog(
+        &mut self,
+        plugin_id: &str,
+        title: String,
+        message: String,
+        confirm: bool,
+        reply: PluginReply,
+    ) {
+        let plugin_name = self
+            .plugins
+            .plugins
+            .iter()
+            .find(|p| p.id == plugin_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| plugin_id.to_string());
+        self.plugins.dialogs.push_back(PluginDialog {
+            plugin_name,
+            title,
+            message,
+            confirm,
+            plugin_id: plugin_id.to_string(),
+            reply,
+        });
+    }
+
+    /// 向标签的激活终端写入文本，并开始收集该终端的输出供之后读取
+    fn plugin_send(&mut self, tab_id: &str, text: String) -> Result<(), String> {
+        let Some((terminal_id, channel)) = self
+            .active_terminal_instance(tab_id)
+            .and_then(|inst| Some((inst.id.clone(), inst.pty_channel.clone()?)))
+        else {
+            return Err(t("plugins.error.no_terminal").replace("{}", tab_id));
+        };
+        let output = self
+            .plugins
+            .output
+            .entry(tab_id.to_string())
+            .or_insert_with(|| PluginOutput {
+                terminal_id: terminal_id.clone(),
+                text: String::new(),
+            });
+        // 激活终端已切换时从新终端重新开始收集
+        if output.terminal_id != terminal_id {
+            output.terminal_id = terminal_id;
+            output.text.clear();
+        }
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                if let Err(e) = channel.write(text.as_bytes()).await {
+                    error!("[Plugins] Failed to send text: {:?}", e);
+                }
+            });
+        Ok(())
+    }
+
+    /// 读取并清空已收集的输出；首次读取时开始收集（返回空字符串）
+    fn plugin_read_output(&mut self, tab_id: &str) -> Result<String, String> {
+        if let Some(output) = self.plugins.output.get_mut(tab_id) {
+            return Ok(std::mem::take(&mut output.text));
+        }
+        let Some(terminal_id) = self
+            .active_terminal_instance(tab_id)
+            .map(|inst| inst.id.clone())
+        else {
+            return Err(t("plugins.error.no_terminal").replace("{}", tab_id));
+        };
+        self.plugins.output.insert(
+            tab_id.to_string(),
+            PluginOutput {
+                terminal_id,
+                text: String::new(),
+            },
+        );
+        Ok(String::new())
+    }
+}
@@ -0,0 +1,90 @@
+// 端口转发方法：在 SSH 运行时中启动/停止转发，关闭标签页时停止该标签的全部转发
+
+use super::SessionState;
+use crate::components::port_forward::{ForwardEntry, PortForwardEvent, PortForwardView};
+use crate::ssh::start_forward;
+use gpui::prelude::*;
+use gpui::Entity;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+impl SessionState {
+    /// 确保端口转发视图已创建
+    pub fn ensure_port_forward_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<PortForwardView> {
+        if !self.port_forward_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                PortForwardView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, _| match event {
+                        PortForwardEvent::Start(entry) => {
+                            state.port_forward_start(&tab_id_for_event, entry);
+                        }
+                        PortForwardEvent::Stop(id) => {
+                            state.port_forward_stop(&tab_id_for_event, &id);
+                        }
+                    });
+                })
+            });
+            self.port_forward_views.insert(tab_id.to_string(), view);
+        }
+        self.port_forward_views.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取端口转发视图（如果存在）
+    pub fn get_port_forward_view(&self, tab_id: &str) -> Option<Entity<PortForwardView>> {
+        self.port_forward_views.get(tab_id).cloned()
+    }
+
+    /// 启动转发（已在运行时忽略）
+    pub fn port_forward_start(&mut self, tab_id: &str, entry: ForwardEntry) {
+        let tokens = self
+            .port_forward_tokens
+            .entry(tab_id.to_string())
+            .or_default();
+        if tokens.get(&entry.id).is_some_and(|t| !t.is_cancelled()) {
+            return;
+        }
+        let token = CancellationToken::new();
+        tokens.insert(entry.id.clone(), token.clone());
+
+        info!(
+            "[Forward] Starting {} in tab {}",
+            entry.spec.describe(),
+            tab_id
+        );
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let tab_id = tab_id.to_string();
+        ssh_manager.runtime().spawn(async move {
+            if let Err(e) = start_forward(tab_id, entry.spec.clone(), entry.stats, token).await {
+                error!("[Forward] Failed to start {}: {}", entry.spec.describe(), e);
+            }
+        });
+    }
+
+    /// 停止转发
+    fn port_forward_stop(&mut self, tab_id: &str, id: &str) {
+        if let Some(token) = self
+            .port_forward_tokens
+            .get_mut(tab_id)
+            .and_then(|tokens| tokens.remove(id))
+        {
+            info!("[Forward] Stopping forward {} in tab {}", id, tab_id);
+            token.cancel();
+        }
+    }
+
+    /// 停止标签页的全部转发
+    pub(super) fn port_forward_stop_all(&mut self, tab_id: &str) {
+        if let Some(tokens) = self.port_forward_tokens.remove(tab_id) {
+            for token in tokens.into_values() {
+                token.cancel();
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+// 进程管理方法：切换 Monitor 区域页面、轮询远程进程列表、结束进程与调整优先级
+
+use std::time::Duration;
+
+use super::{MonitorPanelView, SessionState};
+use crate::components::processes::{ProcessEvent, ProcessListView};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::processes::{list_processes, renice, send_signal};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 进程列表刷新间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+impl SessionState {
+    /// 切换 Monitor 区域显示的页面（系统监控/进程/Docker/服务/网络/计划任务），离开列表页时停止对应的轮询
+    pub fn set_monitor_panel_view(&mut self, view: MonitorPanelView, cx: &mut gpui::Context<Self>) {
+        self.monitor_panel_view = view;
+        if view != MonitorPanelView::Processes {
+            for (_, token) in self.process_poll_tokens.drain() {
+                token.cancel();
+            }
+        }
+        if view != MonitorPanelView::Docker {
+            self.stop_docker_polling();
+        }
+        if view != MonitorPanelView::Services {
+            self.stop_service_polling();
+        }
+        if view != MonitorPanelView::Network {
+            self.stop_connection_polling();
+        }
+        cx.notify();
+    }
+
+    /// 确保进程管理视图已创建，并只让该标签页轮询进程列表
+    pub fn ensure_process_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<ProcessListView> {
+        if !self.process_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                ProcessListView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.process_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.process_views.insert(tab_id.to_string(), view);
+        }
+        let view = self.process_views.get(tab_id).unwrap().clone();
+
+        // 切换标签页后停止其他标签页的轮询
+        self.process_poll_tokens.retain(|id, token| {
+            let keep = id == tab_id;
+            if !keep {
+                token.cancel();
+            }
+            keep
+        });
+        if !self.process_poll_tokens.contains_key(tab_id) {
+            self.start_process_polling(tab_id, view.clone(), cx);
+        }
+        view
+    }
+
+    /// 获取进程管理视图（如果存在）
+    pub fn get_process_view(&self, tab_id: &str) -> Option<Entity<ProcessListView>> {
+        self.process_views.get(tab_id).cloned()
+    }
+
+    /// 开始定时获取进程列表并写入视图
+    fn start_process_polling(
+        &mut self,
+        tab_id: &str,
+        view: Entity<ProcessListView>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Processes] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Processes] Start polling for tab {}", tab_id);
+
+        let token = CancellationToken::new();
+        self.process_poll_tokens
+            .insert(tab_id.to_string(), token.clone());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            loop {
+                let result = tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    result = list_processes(&session) => result,
+                };
+                if tx.send(result).is_err() || !session.is_alive() {
+                    break;
+                }
+                tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                }
+            }
+        });
+
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(result) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    let updated = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| v.set_processes(result, cx));
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+                info!("[Processes] Polling ended for tab {}", tab_id);
+            })
+            .detach();
+    }
+
+    /// 执行进程操作（已在视图中确认），失败时在视图中显示原因
+    fn process_action(&mut self, tab_id: &str, event: ProcessEvent, cx: &mut gpui::Context<Self>) {
+        let Some(view) = self.process_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Processes] No SSH session found for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        ssh_manager.runtime().spawn(async move {
+            let result = match event {
+                ProcessEvent::Signal(pid, signal) => send_signal(&session, pid, signal).await,
+                ProcessEvent::Renice(pid, nice) => renice(&session, pid, nice).await,
+            };
+            let _ = tx.send(result);
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match result {
+                    Ok(()) => notify_key(
+                        cx,
+                        NotificationCategory::Operation,
+                        NotificationType::Success,
+                        "processes.action_done",
+                    ),
+                    Err(e) => {
+                        error!("[Processes] Action failed: {}", e);
+                        view.update(cx, |v, cx| v.set_action_error(Some(e), cx));
+                    }
+                });
+            })
+            .detach();
+    }
+}
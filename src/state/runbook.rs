@@ -0,0 +1,410 @@
+// Runbook 运行：在标签的终端中按顺序执行步骤（发送命令、等待输出匹配、手动确认、等待时长），
+// 支持暂停/继续、中止，并记录运行日志
+
+use super::{RunbookLogEntry, RunbookRun, RunbookRunStatus, RunbookStepStatus, SessionState};
+use crate::components::common::runbook_dialog::{RunbookDialogEvent, RunbookDialogState};
+use crate::models::{Runbook, RunbookStep};
+use crate::services::notifications::{notify, NotificationCategory};
+use crate::services::storage;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::time::Duration;
+use tracing::{error, info};
+
+/// 等待步骤最多保留的终端输出（字节）
+const MAX_OUTPUT_LEN: usize = 64 * 1024;
+
+/// 终端控制序列（CSI、OSC 等）与回车符，匹配输出前去除
+pub(super) static ANSI_ESCAPE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)|\x1b[@-Z\\-_]|\r")
+        .unwrap()
+});
+
+/// 按当前界面语言翻译
+pub(super) fn t(key: &'static str) -> String {
+    let lang = storage::load_settings()
+        .map(|s| s.theme.language)
+        .unwrap_or_default();
+    crate::i18n::t(&lang, key).to_string()
+}
+
+impl RunbookRun {
+    fn new(runbook: Runbook, terminal_id: String) -> Self {
+        let step_count = runbook.steps.len();
+        Self {
+            runbook,
+            terminal_id,
+            current: 0,
+            step_status: vec![RunbookStepStatus::Pending; step_count],
+            status: RunbookRunStatus::Running,
+            log: Vec::new(),
+            output: String::new(),
+            waiting: None,
+            generation: 0,
+        }
+    }
+
+    /// 追加一条运行日志
+    fn log(&mut self, message: String, is_error: bool) {
+        self.log.push(RunbookLogEntry {
+            time: chrono::Local::now().format("%H:%M:%S").to_string(),
+            message,
+            is_error,
+        });
+    }
+
+    /// 当前步骤（全部完成后为 None）
+    pub fn current_step(&self) -> Option<&RunbookStep> {
+        self.runbook.steps.get(self.current)
+    }
+
+    /// 完成当前步骤并移到下一步
+    fn complete_step(&mut self) {
+        if let Some(status) = self.step_status.get_mut(self.current) {
+            *status = RunbookStepStatus::Done;
+        }
+        self.current += 1;
+        self.waiting = None;
+        self.generation += 1;
+    }
+
+    /// 停止当前步骤的等待（暂停、中止或失败时调用）
+    fn stop_step(&mut self, status: RunbookStepStatus) {
+        if let Some(step_status) = self.step_status.get_mut(self.current) {
+            *step_status = status;
+        }
+        self.waiting = None;
+        self.generation += 1;
+    }
+}
+
+impl SessionState {
+    /// 确保 Runbook 编辑弹窗状态已创建
+    pub fn ensure_runbook_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<RunbookDialogState> {
+        if self.runbook_dialog.is_none() {
+            let dialog = cx.new(|_| RunbookDialogState::default());
+            cx.subscribe(
+                &dialog,
+                |this, _, event: &RunbookDialogEvent, cx| match event {
+                    RunbookDialogEvent::Saved => {
+                        this.refresh_snippets_config();
+                        cx.notify();
+                    }
+                },
+            )
+            .detach();
+            self.runbook_dialog = Some(dialog);
+        }
+        self.runbook_dialog.clone().unwrap()
+    }
+
+    /// 获取 Runbook 编辑弹窗状态（如果存在）
+    pub fn get_runbook_dialog(&self) -> Option<Entity<RunbookDialogState>> {
+        self.runbook_dialog.clone()
+    }
+
+    /// 删除 Runbook
+    pub fn delete_runbook(&mut self, runbook_id: &str, cx: &mut gpui::Context<Self>) {
+        if let Err(e) = storage::delete_runbook(runbook_id) {
+            error!("[Runbook] Failed to delete runbook: {}", e);
+        }
+        self.refresh_snippets_config();
+        cx.notify();
+    }
+
+    /// 在标签的激活终端中开始运行 Runbook（替换该标签已结束的运行）
+    ///
+    /// 该标签已有未结束的运行或没有可用终端时返回 false
+    pub fn start_runbook(
+        &mut self,
+        tab_id: &str,
+        runbook: Runbook,
+        cx: &mut gpui::Context<Self>,
+    ) -> bool {
+        if self
+            .runbook_runs
+            .get(tab_id)
+            .is_some_and(|run| run.status.is_active())
+        {
+            return false;
+        }
+        let Some(terminal_id) = self
+            .active_terminal_instance(tab_id)
+            .filter(|inst| inst.pty_channel.is_some())
+            .map(|inst| inst.id.clone())
+        else {
+            return false;
+        };
+
+        info!("[Runbook] Starting '{}' in tab {}", runbook.name, tab_id);
+        let mut run = RunbookRun::new(runbook, terminal_id);
+        let message = t("runbook.log.started").replace("{}", &run.runbook.name);
+        run.log(message, false);
+        self.runbook_runs.insert(tab_id.to_string(), run);
+        self.advance_runbook(tab_id, cx);
+        cx.notify();
+        true
+    }
+
+    /// 执行当前步骤：发送命令后立即继续下一步，其余步骤等待输出、确认或定时器
+    fn advance_runbook(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        loop {
+            let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+                return;
+            };
+            if run.status != RunbookRunStatus::Running {
+                return;
+            }
+            let Some(step) = run.current_step().cloned() else {
+                run.status = RunbookRunStatus::Finished;
+                run.log(t("runbook.log.finished"), false);
+                let message = t("runbook.notify.finished").replace("{}", &run.runbook.name);
+                notify(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Success,
+                    message,
+                );
+                return;
+            };
+            run.step_status[run.current] = RunbookStepStatus::Running;
+            run.generation += 1;
+            let generation = run.generation;
+
+            match step {
+                RunbookStep::Send { command } => {
+                    run.output.clear();
+                    run.log(t("runbook.log.sent").replace("{}", &command), false);
+                    let terminal_id = run.terminal_id.clone();
+                    if !self.run_in_terminal_instance(tab_id, &terminal_id, &command, cx) {
+                        self.fail_runbook(tab_id, t("runbook.log.no_terminal"), cx);
+                        return;
+                    }
+                    if let Some(run) = self.runbook_runs.get_mut(tab_id) {
+                        run.complete_step();
+                    }
+                }
+                RunbookStep::WaitFor {
+                    pattern,
+                    timeout_secs,
+                } => {
+                    let regex = match Regex::new(&pattern) {
+                        Ok(regex) => regex,
+                        Err(_) => {
+                            let message = t("runbook.log.invalid_pattern").replace("{}", &pattern);
+                            self.fail_runbook(tab_id, message, cx);
+                            return;
+                        }
+                    };
+                    run.log(t("runbook.log.waiting").replace("{}", &pattern), false);
+                    // 上一条命令的输出可能已经到达
+                    if regex.is_match(&run.output) {
+                        run.log(t("runbook.log.matched"), false);
+                        run.complete_step();
+                        continue;
+                    }
+                    run.waiting = Some(regex);
+                    self.schedule_runbook_timer(
+                        tab_id,
+                        generation,
+                        Duration::from_secs(timeout_secs),
+                        cx,
+                    );
+                    return;
+                }
+                RunbookStep::Confirm { message } => {
+                    run.status = RunbookRunStatus::AwaitingConfirm;
+                    run.log(t("runbook.log.confirm").replace("{}", &message), false);
+                    return;
+                }
+                RunbookStep::Sleep { secs } => {
+                    run.log(
+                        t("runbook.log.sleep").replace("{}", &secs.to_string()),
+                        false,
+                    );
+                    self.schedule_runbook_timer(tab_id, generation, Duration::from_secs(secs), cx);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// 启动步骤定时器：到期时如果仍在同一步骤，等待时长步骤完成，等待输出步骤判定超时
+    fn schedule_runbook_timer(
+        &self,
+        tab_id: &str,
+        generation: u64,
+        duration: Duration,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let tab_id = tab_id.to_string();
+        cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(duration).await;
+            let _ = this.update(cx, |state, cx| {
+                state.on_runbook_timer(&tab_id, generation, cx);
+            });
+        })
+        .detach();
+    }
+
+    fn on_runbook_timer(&mut self, tab_id: &str, generation: u64, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if run.generation != generation || run.status != RunbookRunStatus::Running {
+            return;
+        }
+        match run.current_step() {
+            Some(RunbookStep::WaitFor { timeout_secs, .. }) => {
+                let message = t("runbook.log.timeout").replace("{}", &timeout_secs.to_string());
+                self.fail_runbook(tab_id, message, cx);
+            }
+            Some(RunbookStep::Sleep { .. }) => {
+                run.complete_step();
+                self.advance_runbook(tab_id, cx);
+            }
+            _ => {}
+        }
+        cx.notify();
+    }
+
+    /// 终端输出：收集运行中 Runbook 所用终端的输出，正在等待时检查是否匹配
+    pub fn feed_runbook_output(
+        &mut self,
+        tab_id: &str,
+        terminal_id: &str,
+        data: &[u8],
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if !run.status.is_active() || run.terminal_id != terminal_id {
+            return;
+        }
+        let text = String::from_utf8_lossy(data);
+        run.output.push_str(&ANSI_ESCAPE.replace_all(&text, ""));
+        if run.output.len() > MAX_OUTPUT_LEN {
+            let cut = (run.output.len() - MAX_OUTPUT_LEN..)
+                .find(|&i| run.output.is_char_boundary(i))
+                .unwrap_or(0);
+            run.output.drain(..cut);
+        }
+
+        let matched = run.status == RunbookRunStatus::Running
+            && run
+                .waiting
+                .as_ref()
+                .is_some_and(|regex| regex.is_match(&run.output));
+        if matched {
+            run.log(t("runbook.log.matched"), false);
+            run.complete_step();
+            self.advance_runbook(tab_id, cx);
+            cx.notify();
+        }
+    }
+
+    /// 确认当前的手动确认步骤并继续
+    pub fn confirm_runbook_step(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if run.status != RunbookRunStatus::AwaitingConfirm {
+            return;
+        }
+        run.status = RunbookRunStatus::Running;
+        run.log(t("runbook.log.confirmed"), false);
+        run.complete_step();
+        self.advance_runbook(tab_id, cx);
+        cx.notify();
+    }
+
+    /// 暂停运行；继续时从头执行当前步骤（等待时长重新计时，等待输出会先检查已收到的输出）
+    pub fn pause_runbook(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if !matches!(
+            run.status,
+            RunbookRunStatus::Running | RunbookRunStatus::AwaitingConfirm
+        ) {
+            return;
+        }
+        run.status = RunbookRunStatus::Paused;
+        run.stop_step(RunbookStepStatus::Pending);
+        run.log(t("runbook.log.paused"), false);
+        cx.notify();
+    }
+
+    /// 继续已暂停的运行
+    pub fn resume_runbook(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if run.status != RunbookRunStatus::Paused {
+            return;
+        }
+        run.status = RunbookRunStatus::Running;
+        run.log(t("runbook.log.resumed"), false);
+        self.advance_runbook(tab_id, cx);
+        cx.notify();
+    }
+
+    /// 中止运行，剩余步骤标记为跳过
+    pub fn abort_runbook(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        if !run.status.is_active() {
+            return;
+        }
+        info!("[Runbook] Aborted '{}' in tab {}", run.runbook.name, tab_id);
+        run.status = RunbookRunStatus::Aborted;
+        run.stop_step(RunbookStepStatus::Skipped);
+        for status in run.step_status.iter_mut().skip(run.current) {
+            *status = RunbookStepStatus::Skipped;
+        }
+        run.log(t("runbook.log.aborted"), true);
+        cx.notify();
+    }
+
+    /// 当前步骤失败，结束运行
+    fn fail_runbook(&mut self, tab_id: &str, reason: String, cx: &mut gpui::Context<Self>) {
+        let Some(run) = self.runbook_runs.get_mut(tab_id) else {
+            return;
+        };
+        info!(
+            "[Runbook] '{}' failed in tab {}: {}",
+            run.runbook.name, tab_id, reason
+        );
+        run.status = RunbookRunStatus::Failed;
+        run.stop_step(RunbookStepStatus::Failed);
+        run.log(reason, true);
+        let message = t("runbook.notify.failed").replace("{}", &run.runbook.name);
+        notify(
+            cx,
+            NotificationCategory::Operation,
+            NotificationType::Error,
+            message,
+        );
+        cx.notify();
+    }
+
+    /// 关闭已结束的运行，回到 Runbook 列表
+    pub fn dismiss_runbook_run(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        if self
+            .runbook_runs
+            .get(tab_id)
+            .is_some_and(|run| !run.status.is_active())
+        {
+            self.runbook_runs.remove(tab_id);
+            cx.notify();
+        }
+    }
+}
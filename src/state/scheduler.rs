@@ -0,0 +1,149 @@
+// 定时任务：定期检查到期的任务，在目标服务器上运行快捷命令或 Runbook（未连接时临时连接），
+// 记录运行结果，失败时发送通知
+
+use super::runbook::t;
+use super::{SessionState, SessionStatus};
+use crate::models::schedule::{JobRun, ScheduledJob};
+use crate::services::batch_exec::{run_on_host, BatchTarget, HostResult};
+use crate::services::notifications::{notify, NotificationCategory};
+use crate::services::{scheduler, storage};
+use gpui::prelude::*;
+use gpui_component::notification::NotificationType;
+use std::time::Duration;
+use tracing::{error, info};
+
+/// 检查到期任务的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+impl SessionState {
+    /// 启动定时任务检查（只运行启动之后到期的任务，不补运行应用关闭期间错过的任务）
+    pub fn start_scheduler(&mut self, cx: &mut gpui::Context<Self>) {
+        self.scheduler_checked_at = Some(chrono::Local::now());
+        let session_state = cx.entity().downgrade();
+        cx.to_async()
+            .spawn(async move |async_cx| loop {
+                async_cx.background_executor().timer(CHECK_INTERVAL).await;
+
+                let result = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.run_due_jobs(cx);
+                    })
+                });
+
+                // SessionState 已释放，退出循环
+                if !matches!(result, Ok(Ok(()))) {
+                    break;
+                }
+            })
+            .detach();
+    }
+
+    /// 运行上次检查之后到期的任务（仍在运行的任务跳过本次）
+    fn run_due_jobs(&mut self, cx: &mut gpui::Context<Self>) {
+        let now = chrono::Local::now();
+        let Some(since) = self.scheduler_checked_at.replace(now) else {
+            return;
+        };
+        let jobs = storage::load_schedules().unwrap_or_default().jobs;
+        for job in scheduler::due_jobs(&jobs, since, now) {
+            if !self.running_jobs.contains(&job.id) {
+                self.run_scheduled_job(job, false, cx);
+            }
+        }
+    }
+
+    /// 在任务的服务器上运行一次（`manual` 表示手动运行），完成后记录结果，失败时通知
+    pub fn run_scheduled_job(
+        &mut self,
+        job: ScheduledJob,
+        manual: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let started_at = chrono::Local::now();
+        let Some(server) = storage::load_servers()
+            .unwrap_or_default()
+            .servers
+            .into_iter()
+            .find(|s| s.id == job.server_id)
+        else {
+            let result = failed_result(t("schedules.error.server_missing"));
+            let run = scheduler::job_run(&job, "-", started_at, result, manual);
+            self.finish_scheduled_job(run, cx);
+            return;
+        };
+        let snippets = storage::load_snippets().unwrap_or_default();
+        let command = match scheduler::build_command(&job.task, &server.id, &snippets) {
+            Ok(command) => command,
+            Err(e) => {
+                let result = failed_result(t(e));
+                let run = scheduler::job_run(&job, &server.label, started_at, result, manual);
+                self.finish_scheduled_job(run, cx);
+                return;
+            }
+        };
+
+        // 已连接的服务器复用对应标签页的会话
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let session = self
+            .tabs
+            .iter()
+            .find(|tab| tab.server_id == server.id && tab.status == SessionStatus::Connected)
+            .and_then(|tab| ssh_manager.get_session(&tab.id));
+        info!("[Scheduler] Running '{}' on {}", job.name, server.label);
+        self.running_jobs.insert(job.id.clone());
+        cx.notify();
+
+        let label = server.label.clone();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        ssh_manager.runtime().spawn(async move {
+            let result = run_on_host(BatchTarget { server, session }, &command).await;
+            let _ = tx.send(result);
+        });
+
+        cx.spawn(async move |this, cx| {
+            let Ok(result) = rx.await else {
+                return;
+            };
+            let _ = this.update(cx, |state, cx| {
+                state.running_jobs.remove(&job.id);
+                let run = scheduler::job_run(&job, &label, started_at, result, manual);
+                state.finish_scheduled_job(run, cx);
+            });
+        })
+        .detach();
+    }
+
+    /// 保存运行记录，失败时发送通知
+    fn finish_scheduled_job(&mut self, run: JobRun, cx: &mut gpui::Context<Self>) {
+        if !run.is_success() {
+            info!(
+                "[Scheduler] '{}' failed on {}: {:?} {:?}",
+                run.job_name, run.server_label, run.exit_code, run.error
+            );
+            let message = t("schedules.notify.failed")
+                .replacen("{}", &run.job_name, 1)
+                .replacen("{}", &run.server_label, 1);
+            notify(
+                cx,
+                NotificationCategory::Operation,
+                NotificationType::Error,
+                message,
+            );
+        }
+        if let Err(e) = storage::add_job_run(run) {
+            error!("[Scheduler] Failed to save job run: {}", e);
+        }
+        cx.notify();
+    }
+}
+
+/// 未能开始执行时的结果
+fn failed_result(error: String) -> HostResult {
+    HostResult {
+        exit_code: None,
+        stdout: String::new(),
+        stderr: String::new(),
+        duration: Duration::ZERO,
+        error: Some(error),
+    }
+}
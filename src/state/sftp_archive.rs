@@ -0,0 +1,353 @@
+// SFTP 归档方法：在远程压缩/解压文件，进度以传输项的形式展示
+
+use std::sync::Arc;
+
+use super::SessionState;
+use crate::models::sftp::state::{get_parent_path, join_path};
+use crate::models::sftp::{ArchiveOp, TransferItem};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::{
+    compress_command, extract_command, parse_archive_line, ArchiveFormat, ArchiveLine, SftpService,
+    COMMAND_NOT_FOUND,
+};
+use crate::ssh::session::SshSession;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 归档任务
+enum ArchiveTask {
+    /// 在 `dir` 中把 `names` 打包为 `archive_name`
+    Compress {
+        dir: String,
+        names: Vec<String>,
+        archive_name: String,
+        format: ArchiveFormat,
+    },
+    /// 把 `archive_path` 解压到 `dest_dir`
+    Extract {
+        archive_path: String,
+        dest_dir: String,
+        format: ArchiveFormat,
+    },
+}
+
+/// 归档任务回传的事件
+enum ArchiveEvent {
+    /// 已处理条目数, 条目总数
+    Progress(u64, u64),
+    Complete(Result<(), String>),
+}
+
+impl SessionState {
+    /// 把远程文件/文件夹压缩为同目录下的归档文件
+    pub fn sftp_compress(
+        &mut self,
+        tab_id: &str,
+        path: String,
+        format: ArchiveFormat,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some((session, service)) = self.archive_backend(tab_id) else {
+            return;
+        };
+
+        let dir = get_parent_path(&path);
+        let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+        info!("[SFTP] Compressing {} as {:?}", path, format);
+
+        let runtime = crate::ssh::manager::SshManager::global().runtime();
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                // 先确定不与已有文件重名的归档名称
+                let (tx_name, mut rx_name) = tokio::sync::mpsc::unbounded_channel();
+                let service_for_name = service.clone();
+                let (dir_for_name, base) = (dir.clone(), name.clone());
+                runtime.spawn(async move {
+                    let archive_name = service_for_name
+                        .unique_archive_name(&dir_for_name, &base, format)
+                        .await;
+                    let _ = tx_name.send(archive_name);
+                });
+                let Some(archive_name) = rx_name.recv().await else {
+                    return;
+                };
+
+                let item =
+                    TransferItem::new_archive(ArchiveOp::Compress, join_path(&dir, &archive_name));
+                let task = ArchiveTask::Compress {
+                    dir: dir.clone(),
+                    names: vec![name],
+                    archive_name,
+                    format,
+                };
+                run_archive_task(
+                    async_cx,
+                    session_state,
+                    tab_id,
+                    item,
+                    task,
+                    session,
+                    service,
+                )
+                .await;
+            })
+            .detach();
+    }
+
+    /// 把远程归档解压到其所在目录
+    pub fn sftp_extract(&mut self, tab_id: &str, path: String, cx: &mut gpui::Context<Self>) {
+        let name = path.rsplit('/').next().unwrap_or(&path);
+        let Some(format) = ArchiveFormat::from_name(name) else {
+            return;
+        };
+        let Some((session, service)) = self.archive_backend(tab_id) else {
+            return;
+        };
+        info!("[SFTP] Extracting {}", path);
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        let item = TransferItem::new_archive(ArchiveOp::Extract, path.clone());
+        let task = ArchiveTask::Extract {
+            dest_dir: get_parent_path(&path),
+            archive_path: path,
+            format,
+        };
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                run_archive_task(
+                    async_cx,
+                    session_state,
+                    tab_id,
+                    item,
+                    task,
+                    session,
+                    service,
+                )
+                .await;
+            })
+            .detach();
+    }
+
+    /// 获取执行归档命令的 SSH 会话与 SFTP 服务
+    fn archive_backend(&self, tab_id: &str) -> Option<(Arc<SshSession>, SftpService)> {
+        let Some(session) = crate::ssh::manager::SshManager::global().get_session(tab_id) else {
+            error!("[SFTP] No SSH session for tab {}", tab_id);
+            return None;
+        };
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned());
+        let Some(service) = service else {
+            error!("[SFTP] No SFTP service for tab {}", tab_id);
+            return None;
+        };
+        Some((session, service))
+    }
+}
+
+/// 添加传输项并执行归档任务，结束后刷新目录并提示结果
+async fn run_archive_task(
+    async_cx: &mut gpui::AsyncApp,
+    session_state: Entity<SessionState>,
+    tab_id: String,
+    item: TransferItem,
+    task: ArchiveTask,
+    session: Arc<SshSession>,
+    service: SftpService,
+) {
+    let transfer_id = item.id.clone();
+    let cancel_token = item.cancel_token.clone();
+    let op = item.archive_op;
+    let refresh_dir = match &task {
+        ArchiveTask::Compress { dir, .. } => dir.clone(),
+        ArchiveTask::Extract { dest_dir, .. } => dest_dir.clone(),
+    };
+
+    let tab_id_for_add = tab_id.clone();
+    let _ = async_cx.update(|cx| {
+        session_state.update(cx, |state, cx| {
+            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_for_add) {
+                tab.active_transfers.push(item);
+            }
+            state.set_sidebar_panel(super::SidebarPanel::Transfer);
+            cx.notify();
+        });
+    });
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ArchiveEvent>();
+    let cancel_for_task = cancel_token.clone();
+    crate::ssh::manager::SshManager::global()
+        .runtime()
+        .spawn(async move {
+            tokio::select! {
+                _ = cancel_for_task.cancelled() => {
+                    // 丢弃执行通道，远程命令随通道关闭结束
+                }
+                result = execute_archive_task(&session, &service, &task, &tx) => {
+                    let _ = tx.send(ArchiveEvent::Complete(result));
+                }
+            }
+        });
+
+    let mut result = None;
+    loop {
+        tokio::select! {
+            _ = cancel_token.cancelled() => {
+                info!("[SFTP] Archive task cancelled: {}", transfer_id);
+                break;
+            }
+            event = rx.recv() => {
+                let Some(event) = event else {
+                    break;
+                };
+                let done = matches!(event, ArchiveEvent::Complete(_));
+                let tab_id = tab_id.clone();
+                let transfer_id = transfer_id.clone();
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        let Some(transfer) = state
+                            .tabs
+                            .iter_mut()
+                            .find(|t| t.id == tab_id)
+                            .and_then(|tab| {
+                                tab.active_transfers.iter_mut().find(|t| t.id == transfer_id)
+                            })
+                        else {
+                            return;
+                        };
+                        match &event {
+                            ArchiveEvent::Progress(done, total) => {
+                                transfer.update_progress(*done, *total, 0);
+                            }
+                            ArchiveEvent::Complete(Ok(())) => transfer.set_completed(),
+                            ArchiveEvent::Complete(Err(e)) => {
+                                error!("[SFTP] Archive task failed: {}", e);
+                                transfer.set_failed(e.clone());
+                            }
+                        }
+                        cx.notify();
+                    });
+                });
+                if let ArchiveEvent::Complete(r) = event {
+                    result = Some(r);
+                }
+                if done {
+                    break;
+                }
+            }
+        }
+    }
+
+    let _ = async_cx.update(|cx| {
+        session_state.update(cx, |state, cx| {
+            if let Some(sftp_state) = state
+                .tabs
+                .iter_mut()
+                .find(|t| t.id == tab_id)
+                .and_then(|t| t.sftp_state.as_mut())
+            {
+                sftp_state.invalidate_cache(&refresh_dir);
+            }
+            state.sftp_refresh(&tab_id, cx);
+        });
+
+        let Some(result) = result else {
+            return;
+        };
+        let key = match (op, result.is_ok()) {
+            (Some(ArchiveOp::Compress), true) => "sftp.archive.compress_done",
+            (Some(ArchiveOp::Compress), false) => "sftp.archive.compress_failed",
+            (_, true) => "sftp.archive.extract_done",
+            (_, false) => "sftp.archive.extract_failed",
+        };
+        let kind = if result.is_ok() {
+            NotificationType::Success
+        } else {
+            NotificationType::Error
+        };
+        notify_key(cx, NotificationCategory::Transfer, kind, key);
+    });
+}
+
+/// 在远程执行归档命令；zip 格式且远程缺少 zip/unzip 时改为经由 SFTP 处理
+async fn execute_archive_task(
+    session: &SshSession,
+    service: &SftpService,
+    task: &ArchiveTask,
+    tx: &tokio::sync::mpsc::UnboundedSender<ArchiveEvent>,
+) -> Result<(), String> {
+    let (command, format) = match task {
+        ArchiveTask::Compress {
+            dir,
+            names,
+            archive_name,
+            format,
+        } => (compress_command(dir, names, archive_name, *format), *format),
+        ArchiveTask::Extract {
+            archive_path,
+            dest_dir,
+            format,
+        } => (extract_command(archive_path, dest_dir, *format), *format),
+    };
+
+    let exec = session.open_exec().await.map_err(|e| format!("{:?}", e))?;
+    let mut total = 0u64;
+    let mut done = 0u64;
+    let mut exit_code = None;
+    exec.exec_lines(&command, |line| {
+        match parse_archive_line(line) {
+            Some(ArchiveLine::Total(n)) => total = n,
+            Some(ArchiveLine::Entry) => {
+                done += 1;
+                let _ = tx.send(ArchiveEvent::Progress(done.min(total), total));
+            }
+            Some(ArchiveLine::Exit(code)) => exit_code = Some(code),
+            None => {}
+        }
+        true
+    })
+    .await
+    .map_err(|e| format!("{:?}", e))?;
+
+    let progress = |done: u64, total: u64| {
+        let _ = tx.send(ArchiveEvent::Progress(done, total));
+    };
+    match exit_code {
+        Some(0) => Ok(()),
+        Some(COMMAND_NOT_FOUND) if format == ArchiveFormat::Zip => {
+            info!("[SFTP] Remote zip/unzip not found, falling back to SFTP");
+            match task {
+                ArchiveTask::Compress {
+                    dir,
+                    names,
+                    archive_name,
+                    ..
+                } => {
+                    service
+                        .compress_zip_via_sftp(dir, names, archive_name, progress)
+                        .await
+                }
+                ArchiveTask::Extract {
+                    archive_path,
+                    dest_dir,
+                    ..
+                } => {
+                    service
+                        .extract_zip_via_sftp(archive_path, dest_dir, progress)
+                        .await
+                }
+            }
+        }
+        Some(code) => Err(format!("Archive command exited with status {}", code)),
+        None => Err("Archive command did not report an exit status".to_string()),
+    }
+}
@@ -0,0 +1,57 @@
+// SFTP 收藏夹方法：收藏当前目录、记录最近目录与最近文件、打开"前往路径"输入
+
+use super::SessionState;
+use crate::models::sftp::ServerBookmarks;
+use crate::services::sftp::SftpBookmarkStore;
+use tracing::info;
+
+impl SessionState {
+    /// 标签页所属服务器的收藏目录、最近目录和最近文件
+    pub fn sftp_server_bookmarks(&self, tab_id: &str) -> ServerBookmarks {
+        self.tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .map(|t| SftpBookmarkStore::global().server(&t.server_id))
+            .unwrap_or_default()
+    }
+
+    /// 收藏或取消收藏当前目录
+    pub fn sftp_toggle_bookmark(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some((server_id, path)) = self.tabs.iter().find(|t| t.id == tab_id).and_then(|t| {
+            t.sftp_state
+                .as_ref()
+                .map(|s| (t.server_id.clone(), s.current_path.clone()))
+        }) else {
+            return;
+        };
+        let bookmarked = SftpBookmarkStore::global().toggle_bookmark(&server_id, &path);
+        info!("[SFTP] Bookmark {} (bookmarked: {})", path, bookmarked);
+        cx.notify();
+    }
+
+    /// 记录最近访问的目录
+    pub(super) fn sftp_record_recent(&self, tab_id: &str, path: &str) {
+        if let Some(tab) = self.tabs.iter().find(|t| t.id == tab_id) {
+            SftpBookmarkStore::global().record_recent(&tab.server_id, path);
+        }
+    }
+
+    /// 记录最近编辑的文件
+    pub(super) fn sftp_record_recent_file(&self, tab_id: &str, path: &str) {
+        if let Some(tab) = self.tabs.iter().find(|t| t.id == tab_id) {
+            SftpBookmarkStore::global().record_recent_file(&tab.server_id, path);
+        }
+    }
+
+    /// 打开"前往路径…"输入（地址栏进入编辑模式）
+    pub fn sftp_start_go_to_path(
+        &self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if let Some(path_bar) = self.sftp_path_bar_states.get(tab_id) {
+            path_bar.update(cx, |path_bar, cx| path_bar.start_edit(window, cx));
+        }
+    }
+}
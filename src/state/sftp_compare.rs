@@ -0,0 +1,261 @@
+// SFTP 对比方法：选择两个文件或目录（本地/远程、可跨服务器）进行内容或目录树对比
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use super::SessionState;
+use crate::components::sftp::{CompareContent, CompareDialogState};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::{
+    collect_local_tree, compare_base, diff_files, diff_trees, read_local_for_compare,
+    set_compare_base, CompareLocation, CompareTarget, DirDiffEntry, FileDiff, SftpService, TreeMap,
+};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 对比一侧的数据来源
+enum CompareSource {
+    Local(PathBuf),
+    Remote(SftpService, String),
+}
+
+impl CompareSource {
+    /// 读取文件内容
+    async fn read(&self) -> Result<Vec<u8>, String> {
+        match self {
+            CompareSource::Local(path) => {
+                let path = path.clone();
+                tokio::task::spawn_blocking(move || read_local_for_compare(&path))
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| e.to_string())
+            }
+            CompareSource::Remote(service, path) => service.read_for_compare(path).await,
+        }
+    }
+
+    /// 收集目录树
+    async fn tree(&self, cancel_token: &CancellationToken) -> Result<TreeMap, String> {
+        match self {
+            CompareSource::Local(path) => {
+                let path = path.clone();
+                tokio::task::spawn_blocking(move || collect_local_tree(&path))
+                    .await
+                    .map_err(|e| e.to_string())?
+                    .map_err(|e| e.to_string())
+            }
+            CompareSource::Remote(service, path) => {
+                service.collect_compare_tree(path, cancel_token).await
+            }
+        }
+    }
+}
+
+/// 后台对比的结果
+enum CompareOutput {
+    File(FileDiff),
+    Dir(Vec<DirDiffEntry>),
+}
+
+/// 本地面板条目对应的对比对象
+pub(super) fn local_compare_target(path: PathBuf, is_dir: bool) -> CompareTarget {
+    CompareTarget {
+        location: CompareLocation::Local,
+        path: path.to_string_lossy().to_string(),
+        is_dir,
+    }
+}
+
+impl SessionState {
+    /// 确保对比对话框已创建
+    pub fn ensure_sftp_compare_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<CompareDialogState> {
+        if self.sftp_compare_dialog.is_none() {
+            self.sftp_compare_dialog = Some(cx.new(|_| CompareDialogState::default()));
+        }
+        self.sftp_compare_dialog.clone().unwrap()
+    }
+
+    /// 获取对比对话框状态（如果存在）
+    pub fn get_sftp_compare_dialog(&self) -> Option<Entity<CompareDialogState>> {
+        self.sftp_compare_dialog.clone()
+    }
+
+    /// 远程文件列表条目对应的对比对象
+    pub fn sftp_compare_target(
+        &self,
+        tab_id: &str,
+        path: &str,
+        is_dir: bool,
+    ) -> Option<CompareTarget> {
+        let tab = self.tabs.iter().find(|t| t.id == tab_id)?;
+        Some(CompareTarget {
+            location: CompareLocation::Remote {
+                tab_id: tab_id.to_string(),
+                server_label: tab.server_label.clone(),
+            },
+            path: path.to_string(),
+            is_dir,
+        })
+    }
+
+    /// 选择用于比较的对象
+    pub fn sftp_compare_select(&mut self, target: CompareTarget, cx: &mut gpui::Context<Self>) {
+        info!("[SFTP] Selected for compare: {:?}", target);
+        set_compare_base(Some(target));
+        notify_key(
+            cx,
+            NotificationCategory::Transfer,
+            NotificationType::Info,
+            "sftp.compare.selected",
+        );
+    }
+
+    /// 将对象与已选对象比较
+    pub fn sftp_compare_with(&mut self, target: CompareTarget, cx: &mut gpui::Context<Self>) {
+        let Some(base) = compare_base() else {
+            notify_key(
+                cx,
+                NotificationCategory::Transfer,
+                NotificationType::Warning,
+                "sftp.compare.no_selection",
+            );
+            return;
+        };
+        if base.is_dir != target.is_dir {
+            notify_key(
+                cx,
+                NotificationCategory::Transfer,
+                NotificationType::Warning,
+                "sftp.compare.kind_mismatch",
+            );
+            return;
+        }
+        if base == target {
+            notify_key(
+                cx,
+                NotificationCategory::Transfer,
+                NotificationType::Warning,
+                "sftp.compare.same_target",
+            );
+            return;
+        }
+
+        let dialog = self.ensure_sftp_compare_dialog(cx);
+        let token = dialog.update(cx, |d, cx| {
+            let token = d.open(base.clone(), target.clone());
+            cx.notify();
+            token
+        });
+        self.sftp_run_compare(dialog, base, target, token, cx);
+        cx.notify();
+    }
+
+    /// 在目录对比中打开两侧都存在的文件
+    pub fn sftp_compare_open_child(
+        &mut self,
+        dialog: Entity<CompareDialogState>,
+        relative_path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some((left, right, token)) = dialog.update(cx, |d, cx| {
+            let opened = d.open_child(&relative_path);
+            cx.notify();
+            opened
+        }) else {
+            return;
+        };
+        self.sftp_run_compare(dialog, left, right, token, cx);
+    }
+
+    /// 在后台读取两侧内容并计算差异
+    fn sftp_run_compare(
+        &mut self,
+        dialog: Entity<CompareDialogState>,
+        left: CompareTarget,
+        right: CompareTarget,
+        token: CancellationToken,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (Some(left_source), Some(right_source)) =
+            (self.compare_source(&left), self.compare_source(&right))
+        else {
+            dialog.update(cx, |d, cx| {
+                d.set_content(CompareContent::Error(
+                    "SFTP service not available".to_string(),
+                ));
+                cx.notify();
+            });
+            return;
+        };
+        info!("[SFTP] Comparing {} with {}", left.path, right.path);
+
+        let is_dir = left.is_dir;
+        let task_token = token.clone();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<CompareOutput, String>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = if is_dir {
+                    let left = left_source.tree(&task_token).await;
+                    let right = right_source.tree(&task_token).await;
+                    left.and_then(|left| {
+                        right.map(|right| CompareOutput::Dir(diff_trees(&left, &right)))
+                    })
+                } else {
+                    let left = left_source.read().await;
+                    let right = right_source.read().await;
+                    left.and_then(|left| {
+                        right.map(|right| CompareOutput::File(diff_files(&left, &right)))
+                    })
+                };
+                let _ = tx.send(result);
+            });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                // 对话框已关闭或开始了新的对比
+                if token.is_cancelled() {
+                    return;
+                }
+                let content = match result {
+                    Ok(CompareOutput::File(diff)) => CompareContent::from_file_diff(diff),
+                    Ok(CompareOutput::Dir(entries)) => CompareContent::Dir {
+                        entries: Rc::new(entries),
+                    },
+                    Err(e) => {
+                        error!("[SFTP] Compare failed: {}", e);
+                        CompareContent::Error(e)
+                    }
+                };
+                let _ = async_cx.update(|cx| {
+                    dialog.update(cx, |d, cx| {
+                        d.set_content(content);
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+
+    /// 获取对比对象的数据来源
+    fn compare_source(&self, target: &CompareTarget) -> Option<CompareSource> {
+        match &target.location {
+            CompareLocation::Local => Some(CompareSource::Local(PathBuf::from(&target.path))),
+            CompareLocation::Remote { tab_id, .. } => self
+                .sftp_services
+                .lock()
+                .ok()
+                .and_then(|s| s.get(tab_id).cloned())
+                .map(|service| CompareSource::Remote(service, target.path.clone())),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// SFTP 传输冲突处理：传输前检查目标是否已存在，按设置或用户选择覆盖、改名或跳过
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use super::SessionState;
+use crate::components::sftp::{ConflictDialogState, ConflictFileInfo, ConflictRequest};
+use crate::models::settings::ConflictAction;
+use crate::models::sftp::TransferOptions;
+use crate::services::sftp::{is_source_newer, unique_local_path, SftpService};
+use gpui::prelude::*;
+use gpui::Entity;
+use tokio::sync::oneshot;
+use tracing::info;
+
+impl SessionState {
+    /// 确保传输冲突对话框已创建
+    pub fn ensure_sftp_conflict_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<ConflictDialogState> {
+        if self.sftp_conflict_dialog.is_none() {
+            self.sftp_conflict_dialog = Some(cx.new(|_| ConflictDialogState::default()));
+        }
+        self.sftp_conflict_dialog.clone().unwrap()
+    }
+
+    /// 获取传输冲突对话框状态（如果存在）
+    pub fn get_sftp_conflict_dialog(&self) -> Option<Entity<ConflictDialogState>> {
+        self.sftp_conflict_dialog.clone()
+    }
+}
+
+/// 上传前检查远程目标是否已存在
+///
+/// 返回实际使用的远程路径，`None` 表示跳过该文件。
+/// `remembered` 保存用户勾选“应用到全部”后的选择，供同一批传输的后续文件使用；
+/// `options` 为该传输的可选处理，弹出冲突对话框时可由用户修改
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn resolve_upload_target(
+    async_cx: &mut gpui::AsyncApp,
+    session_state: &Entity<SessionState>,
+    service: &SftpService,
+    tab_id: &str,
+    local_path: &Path,
+    remote_path: String,
+    remembered: &mut Option<ConflictAction>,
+    options: Option<&mut TransferOptions>,
+) -> Option<String> {
+    let policy = remembered.clone().unwrap_or_else(conflict_policy);
+    if policy == ConflictAction::Overwrite {
+        return Some(remote_path);
+    }
+
+    let existing = {
+        let service = service.clone();
+        let path = remote_path.clone();
+        run_in_runtime(async move { service.stat(&path).await.ok() })
+            .await
+            .flatten()
+    };
+    // 目标不存在，或目标是目录（交给传输本身报错）
+    let Some(existing) = existing.filter(|e| !e.is_dir()) else {
+        return Some(remote_path);
+    };
+
+    let source = std::fs::metadata(local_path)
+        .map(|m| ConflictFileInfo {
+            size: m.len(),
+            modified: m.modified().ok(),
+        })
+        .unwrap_or_default();
+    let target = ConflictFileInfo {
+        size: existing.size,
+        modified: existing.modified,
+    };
+
+    let action = match policy {
+        ConflictAction::Ask => {
+            ask_conflict(
+                async_cx,
+                session_state,
+                tab_id,
+                remote_path.clone(),
+                true,
+                source.clone(),
+                target.clone(),
+                remembered,
+                options,
+            )
+            .await
+        }
+        other => other,
+    };
+    info!("[SFTP] Upload conflict on {}: {:?}", remote_path, action);
+
+    match action {
+        ConflictAction::Overwrite => Some(remote_path),
+        ConflictAction::OverwriteIfNewer => {
+            is_source_newer(source.modified, target.modified).then_some(remote_path)
+        }
+        ConflictAction::Rename => {
+            let service = service.clone();
+            run_in_runtime(async move { service.unique_remote_path(&remote_path).await }).await
+        }
+        ConflictAction::Skip | ConflictAction::Ask => None,
+    }
+}
+
+/// 下载前检查本地目标是否已存在
+///
+/// 返回实际使用的本地路径，`None` 表示跳过该文件。
+/// `remembered` 与 `options` 的含义同 [`resolve_upload_target`]
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn resolve_download_target(
+    async_cx: &mut gpui::AsyncApp,
+    session_state: &Entity<SessionState>,
+    service: &SftpService,
+    tab_id: &str,
+    remote_path: &str,
+    local_path: PathBuf,
+    remembered: &mut Option<ConflictAction>,
+    options: Option<&mut TransferOptions>,
+) -> Option<PathBuf> {
+    let policy = remembered.clone().unwrap_or_else(conflict_policy);
+    if policy == ConflictAction::Overwrite {
+        return Some(local_path);
+    }
+
+    // 目标不存在，或目标是目录（交给传输本身报错）
+    let Some(existing) = std::fs::metadata(&local_path).ok().filter(|m| !m.is_dir()) else {
+        return Some(local_path);
+    };
+
+    let source = {
+        let service = service.clone();
+        let path = remote_path.to_string();
+        run_in_runtime(async move { service.stat(&path).await.ok() })
+            .await
+            .flatten()
+            .map(|e| ConflictFileInfo {
+                size: e.size,
+                modified: e.modified,
+            })
+            .unwrap_or_default()
+    };
+    let target = ConflictFileInfo {
+        size: existing.len(),
+        modified: existing.modified().ok(),
+    };
+
+    let action = match policy {
+        ConflictAction::Ask => {
+            ask_conflict(
+                async_cx,
+                session_state,
+                tab_id,
+                local_path.to_string_lossy().to_string(),
+                false,
+                source.clone(),
+                target.clone(),
+                remembered,
+                options,
+            )
+            .await
+        }
+        other => other,
+    };
+    info!("[SFTP] Download conflict on {:?}: {:?}", local_path, action);
+
+    match action {
+        ConflictAction::Overwrite => Some(local_path),
+        ConflictAction::OverwriteIfNewer => {
+            is_source_newer(source.modified, target.modified).then_some(local_path)
+        }
+        ConflictAction::Rename => Some(unique_local_path(&local_path)),
+        ConflictAction::Skip | ConflictAction::Ask => None,
+    }
+}
+
+/// 读取全局的冲突处理策略
+fn conflict_policy() -> ConflictAction {
+    crate::services::storage::load_settings()
+        .map(|s| s.sftp.conflict_action)
+        .unwrap_or_default()
+}
+
+/// 将冲突加入对话框队列并等待用户选择（对话框被丢弃时视为跳过）
+///
+/// 用户勾选“应用到全部”时把选择写入 `remembered`，在对话框中修改的传输选项写回 `options`
+#[allow(clippy::too_many_arguments)]
+async fn ask_conflict(
+    async_cx: &mut gpui::AsyncApp,
+    session_state: &Entity<SessionState>,
+    tab_id: &str,
+    target_path: String,
+    is_upload: bool,
+    source: ConflictFileInfo,
+    target: ConflictFileInfo,
+    remembered: &mut Option<ConflictAction>,
+    options: Option<&mut TransferOptions>,
+) -> ConflictAction {
+    let (tx, rx) = oneshot::channel();
+    let request = ConflictRequest {
+        tab_id: tab_id.to_string(),
+        target_path,
+        is_upload,
+        source,
+        target,
+        options: options.as_deref().copied(),
+        responder: tx,
+    };
+    let _ = async_cx.update(|cx| {
+        session_state.update(cx, |state, cx| {
+            let dialog = state.ensure_sftp_conflict_dialog(cx);
+            dialog.update(cx, |d, cx| {
+                d.push(request);
+                cx.notify();
+            });
+            cx.notify();
+        });
+    });
+    let (action, apply_to_all, chosen) = rx.await.unwrap_or((ConflictAction::Skip, false, None));
+    if apply_to_all {
+        *remembered = Some(action.clone());
+    }
+    if let (Some(options), Some(chosen)) = (options, chosen) {
+        *options = chosen;
+    }
+    action
+}
+
+/// 在 SSH 运行时中执行异步操作并等待结果
+async fn run_in_runtime<T, F>(future: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    crate::ssh::manager::SshManager::global()
+        .runtime()
+        .spawn(async move {
+            let _ = tx.send(future.await);
+        });
+    rx.await.ok()
+}
@@ -0,0 +1,158 @@
+// SFTP 磁盘占用分析方法：通过 du（或 SFTP 遍历）统计目录下各项大小
+
+use std::sync::Arc;
+
+use super::SessionState;
+use crate::components::sftp::{DiskUsageEvent, DiskUsageView};
+use crate::services::sftp::{
+    build_level, du_command, parse_du_output, walk_sizes, DiskUsageLevel, DiskUsageSource,
+    SftpService,
+};
+use crate::ssh::session::SshSession;
+use gpui::prelude::*;
+use gpui::Entity;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+impl SessionState {
+    /// 确保磁盘占用分析视图已创建
+    fn ensure_sftp_disk_usage_view(
+        &mut self,
+        tab_id: &str,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<DiskUsageView> {
+        if !self.sftp_disk_usage_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|_| {
+                DiskUsageView::new(move |event, cx| {
+                    session_state.update(cx, |state, cx| match event {
+                        DiskUsageEvent::Scan(path, token) => {
+                            state.sftp_disk_usage_scan(&tab_id_for_event, path, token, cx);
+                        }
+                        DiskUsageEvent::Reveal(path) => {
+                            state.sftp_reveal_path(&tab_id_for_event, path, cx);
+                        }
+                    });
+                })
+            });
+            self.sftp_disk_usage_views.insert(tab_id.to_string(), view);
+        }
+        self.sftp_disk_usage_views.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取磁盘占用分析视图（如果存在）
+    pub fn get_sftp_disk_usage_view(&self, tab_id: &str) -> Option<Entity<DiskUsageView>> {
+        self.sftp_disk_usage_views.get(tab_id).cloned()
+    }
+
+    /// 打开磁盘占用分析，统计当前目录
+    pub fn sftp_open_disk_usage(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(current_path) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|s| s.current_path.clone())
+        else {
+            return;
+        };
+        info!(
+            "[SFTP] Open disk usage for {} in tab {}",
+            current_path, tab_id
+        );
+
+        let view = self.ensure_sftp_disk_usage_view(tab_id, cx);
+        let token = view.update(cx, |v, cx| v.open(current_path.clone(), cx));
+        if let Some(token) = token {
+            self.sftp_disk_usage_scan(tab_id, current_path, token, cx);
+        }
+        cx.notify();
+    }
+
+    /// 统计目录下各项的大小，完成后写入视图
+    pub fn sftp_disk_usage_scan(
+        &mut self,
+        tab_id: &str,
+        path: String,
+        token: CancellationToken,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.sftp_disk_usage_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let session = ssh_manager.get_session(tab_id);
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<DiskUsageLevel, String>>();
+        let path_for_task = path.clone();
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            let result = scan_disk_usage(session, service, &path_for_task, &token_for_task).await;
+            let _ = tx.send(result);
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                if token.is_cancelled() {
+                    return;
+                }
+                let _ = async_cx.update(|cx| {
+                    view.update(cx, |v, cx| v.finish_scan(&path, result, cx));
+                });
+            })
+            .detach();
+    }
+}
+
+/// 统计目录：先读取目录条目，再用 du 统计大小，du 不可用时通过 SFTP 遍历
+async fn scan_disk_usage(
+    session: Option<Arc<SshSession>>,
+    service: Option<SftpService>,
+    path: &str,
+    token: &CancellationToken,
+) -> Result<DiskUsageLevel, String> {
+    let service = service.ok_or_else(|| "SFTP service not found".to_string())?;
+    let entries = service.read_dir(path).await?;
+
+    if let Some(session) = session {
+        match session.open_exec().await {
+            Ok(exec_channel) => match exec_channel.exec(&du_command(path)).await {
+                Ok(output) => {
+                    let stdout = String::from_utf8_lossy(&output.stdout);
+                    let (sizes, total) = parse_du_output(path, &stdout);
+                    if total.is_some() {
+                        return Ok(build_level(
+                            path,
+                            entries,
+                            &sizes,
+                            total,
+                            DiskUsageSource::Du,
+                        ));
+                    }
+                    warn!("[SFTP] du produced no usable output for {}", path);
+                }
+                Err(e) => warn!("[SFTP] Failed to execute du: {:?}", e),
+            },
+            Err(e) => warn!("[SFTP] Failed to open exec channel: {:?}", e),
+        }
+    }
+
+    info!("[SFTP] Falling back to SFTP walk for {}", path);
+    let sizes = walk_sizes(&service, &entries, token).await?;
+    Ok(build_level(
+        path,
+        entries,
+        &sizes,
+        None,
+        DiskUsageSource::SftpWalk,
+    ))
+}
@@ -0,0 +1,388 @@
+// SFTP 双面板方法：本地面板与远程文件列表之间的复制/移动
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::SessionState;
+use crate::models::sftp::state::get_parent_path;
+use crate::models::sftp::FileEntry;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::{is_same_or_descendant, SftpService};
+use gpui::prelude::*;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 传输状态检查间隔
+const PANE_TRANSFER_POLL_INTERVAL: Duration = Duration::from_millis(500);
+/// 没有新传输项且没有进行中的传输时，放弃等待的时长
+const PANE_TRANSFER_STALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 双面板传输的源
+#[derive(Clone, Debug)]
+enum PaneSource {
+    Local(PathBuf),
+    Remote(String),
+}
+
+impl SessionState {
+    /// 切换双面板模式
+    pub fn sftp_toggle_dual_pane(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        if let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        {
+            sftp_state.dual_pane = !sftp_state.dual_pane;
+            info!(
+                "[SFTP] Dual pane {} for tab {}",
+                if sftp_state.dual_pane { "on" } else { "off" },
+                tab_id
+            );
+        }
+        cx.notify();
+    }
+
+    /// 复制/移动远程条目到本地面板当前目录
+    pub fn sftp_transfer_to_local(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        remove_source: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(panel) = self.sftp_local_panels.get(tab_id) else {
+            return;
+        };
+        if !self.is_dual_pane(tab_id) {
+            return;
+        }
+        let target_dir = panel.read(cx).current_path().to_path_buf();
+        self.sftp_download_into(tab_id, remote_path, target_dir, remove_source, cx);
+    }
+
+    /// 复制/移动本地条目到远程当前目录
+    pub fn sftp_transfer_to_remote(
+        &mut self,
+        tab_id: &str,
+        paths: Vec<PathBuf>,
+        remove_source: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(remote_dir) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|s| s.current_path.clone())
+        else {
+            return;
+        };
+
+        for path in paths {
+            info!(
+                "[SFTP] {} {:?} -> {} for tab {}",
+                if remove_source { "Move" } else { "Copy" },
+                path,
+                remote_dir,
+                tab_id
+            );
+            let known = self.transfer_ids(tab_id);
+            if path.is_dir() {
+                self.sftp_upload_folder(tab_id, path.clone(), remote_dir.clone(), cx);
+            } else if path.is_file() {
+                self.sftp_upload_file_direct(tab_id, path.clone(), remote_dir.clone(), true, cx);
+            } else {
+                continue;
+            }
+            self.sftp_track_pane_transfer(
+                tab_id,
+                PaneSource::Local(path),
+                known,
+                remove_source,
+                cx,
+            );
+        }
+    }
+
+    /// 下载远程条目到指定本地目录
+    pub fn sftp_download_into(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        target_dir: PathBuf,
+        remove_source: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(entry) = self.find_remote_entry(tab_id, &remote_path) else {
+            error!("[SFTP] Entry not found for pane transfer: {}", remote_path);
+            return;
+        };
+        info!(
+            "[SFTP] {} {} -> {:?} for tab {}",
+            if remove_source { "Move" } else { "Copy" },
+            remote_path,
+            target_dir,
+            tab_id
+        );
+
+        let known = self.transfer_ids(tab_id);
+        if entry.is_dir() {
+            self.sftp_download_folder(tab_id, entry.path.clone(), target_dir, cx);
+        } else {
+            self.sftp_download_file_to(
+                tab_id,
+                entry.path.clone(),
+                entry.name.clone(),
+                entry.size,
+                Some(target_dir),
+                true,
+                cx,
+            );
+        }
+        self.sftp_track_pane_transfer(
+            tab_id,
+            PaneSource::Remote(entry.path),
+            known,
+            remove_source,
+            cx,
+        );
+    }
+
+    /// 当前标签是否处于双面板模式
+    fn is_dual_pane(&self, tab_id: &str) -> bool {
+        self.tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .is_some_and(|s| s.dual_pane)
+    }
+
+    /// 当前标签已有的传输项 ID（文件夹传输按其中的各文件计）
+    pub(super) fn transfer_ids(&self, tab_id: &str) -> HashSet<String> {
+        self.tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .map(|t| {
+                t.active_transfers
+                    .iter()
+                    .flat_map(|tr| tr.files())
+                    .map(|tr| tr.id.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 在当前列表或目录缓存中查找远程条目
+    fn find_remote_entry(&self, tab_id: &str, path: &str) -> Option<FileEntry> {
+        let sftp_state = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())?;
+        sftp_state
+            .file_list
+            .iter()
+            .find(|e| e.path == path)
+            .or_else(|| {
+                sftp_state
+                    .dir_cache
+                    .get(&get_parent_path(path))
+                    .and_then(|cached| cached.entries.iter().find(|e| e.path == path))
+            })
+            .cloned()
+    }
+
+    /// 跟踪双面板传输：全部完成后刷新两侧面板，移动模式下删除源
+    fn sftp_track_pane_transfer(
+        &mut self,
+        tab_id: &str,
+        source: PaneSource,
+        known: HashSet<String>,
+        remove_source: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|services| services.get(&tab_id).cloned());
+        let Some(service) = service else {
+            return;
+        };
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                // 预期的传输项数量（与文件夹传输的收集规则一致：只计文件）
+                let expected = match &source {
+                    PaneSource::Local(path) => count_local_files(path),
+                    PaneSource::Remote(path) => {
+                        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+                        let service = service.clone();
+                        let path = path.clone();
+                        crate::ssh::manager::SshManager::global()
+                            .runtime()
+                            .spawn(async move {
+                                let _ = tx.send(count_remote_files(&service, &path).await);
+                            });
+                        rx.recv().await.unwrap_or(0)
+                    }
+                };
+                if expected == 0 {
+                    return;
+                }
+
+                let mut last_count = 0;
+                let mut last_change = Instant::now();
+                let succeeded = loop {
+                    async_cx
+                        .background_executor()
+                        .timer(PANE_TRANSFER_POLL_INTERVAL)
+                        .await;
+
+                    let progress = async_cx.update(|cx| {
+                        let state = session_state.read(cx);
+                        let tab = state.tabs.iter().find(|t| t.id == tab_id)?;
+                        let matched: Vec<_> = tab
+                            .active_transfers
+                            .iter()
+                            .flat_map(|tr| tr.files())
+                            .filter(|tr| !known.contains(&tr.id))
+                            .filter(|tr| match &source {
+                                PaneSource::Local(path) => {
+                                    tr.is_upload && tr.local_path.starts_with(path)
+                                }
+                                PaneSource::Remote(path) => {
+                                    !tr.is_upload && is_same_or_descendant(&tr.remote_path, path)
+                                }
+                            })
+                            .collect();
+                        let finished = matched
+                            .iter()
+                            .filter(|tr| tr.status.is_complete() || tr.status.is_error())
+                            .count();
+                        let completed = matched.iter().filter(|tr| tr.status.is_complete()).count();
+                        Some((matched.len(), finished, completed))
+                    });
+
+                    // 标签已关闭
+                    let Ok(Some((count, finished, completed))) = progress else {
+                        return;
+                    };
+
+                    if count >= expected && finished == count {
+                        break completed == count;
+                    }
+                    if count != last_count {
+                        last_count = count;
+                        last_change = Instant::now();
+                    } else if finished == count
+                        && last_change.elapsed() >= PANE_TRANSFER_STALL_TIMEOUT
+                    {
+                        break false;
+                    }
+                };
+
+                // 移动模式：全部成功后删除源
+                let mut move_result = None;
+                if remove_source {
+                    move_result = Some(if succeeded {
+                        remove_pane_source(&service, &source).await
+                    } else {
+                        Err("incomplete".to_string())
+                    });
+                }
+
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        if let Some(panel) = state.sftp_local_panels.get(&tab_id).cloned() {
+                            panel.update(cx, |panel, cx| panel.refresh(cx));
+                        }
+                        if let PaneSource::Remote(path) = &source {
+                            if let Some(sftp_state) = state
+                                .tabs
+                                .iter_mut()
+                                .find(|t| t.id == tab_id)
+                                .and_then(|t| t.sftp_state.as_mut())
+                            {
+                                sftp_state.invalidate_cache(&get_parent_path(path));
+                            }
+                        }
+                        state.sftp_refresh(&tab_id, cx);
+                    });
+
+                    let Some(result) = move_result else {
+                        return;
+                    };
+                    if let Err(e) = &result {
+                        error!("[SFTP] Pane move did not remove source {:?}: {}", source, e);
+                    }
+                    let (key, kind) = if result.is_ok() {
+                        ("sftp.pane.moved", NotificationType::Success)
+                    } else {
+                        ("sftp.pane.move_incomplete", NotificationType::Warning)
+                    };
+                    notify_key(cx, NotificationCategory::Transfer, kind, key);
+                });
+            })
+            .detach();
+    }
+}
+
+/// 统计本地路径下的文件数量
+fn count_local_files(path: &Path) -> usize {
+    if path.is_file() {
+        return 1;
+    }
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| count_local_files(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// 统计远程路径下的文件数量
+async fn count_remote_files(service: &SftpService, path: &str) -> usize {
+    match service.stat(path).await {
+        Ok(entry) if !entry.is_dir() => 1,
+        Ok(_) => service
+            .read_dir_recursive(path)
+            .await
+            .map(|entries| entries.iter().filter(|e| !e.is_dir()).count())
+            .unwrap_or(0),
+        Err(_) => 0,
+    }
+}
+
+/// 删除移动操作的源
+async fn remove_pane_source(service: &SftpService, source: &PaneSource) -> Result<(), String> {
+    match source {
+        PaneSource::Local(path) => {
+            let result = if path.is_dir() {
+                std::fs::remove_dir_all(path)
+            } else {
+                std::fs::remove_file(path)
+            };
+            result.map_err(|e| e.to_string())
+        }
+        PaneSource::Remote(path) => {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let service = service.clone();
+            let path = path.clone();
+            crate::ssh::manager::SshManager::global()
+                .runtime()
+                .spawn(async move {
+                    let _ = tx.send(service.remove_recursive(&path).await);
+                });
+            rx.recv()
+                .await
+                .unwrap_or_else(|| Err("channel closed".to_string()))
+        }
+    }
+}
@@ -0,0 +1,259 @@
+// SFTP 文件夹传输：整个文件夹作为一个传输项，各文件并行传输并汇总进度
+
+use std::sync::atomic::Ordering;
+
+use super::runbook::t;
+use super::sftp_queue::queue_transfer;
+use super::sftp_transfer::{
+    record_history, resume_transfers_enabled, verify_checksum_enabled, verify_transfer,
+};
+use super::SessionState;
+use crate::models::sftp::{TransferItem, TransferStatus};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::SftpService;
+use crate::services::transfer::Throttle;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 文件夹中单个文件的传输事件
+enum FolderEvent {
+    /// 文件 ID, 已传输, 总字节数, 速度
+    Progress(String, u64, u64, u64),
+    Complete(String, Result<(), String>),
+}
+
+impl SessionState {
+    /// 文件夹传输展开或收起各文件明细
+    pub fn toggle_transfer_expanded(&mut self, transfer_id: &str, cx: &mut gpui::Context<Self>) {
+        if let Some(transfer) = self
+            .tabs
+            .iter_mut()
+            .flat_map(|t| t.active_transfers.iter_mut())
+            .find(|t| t.id == transfer_id)
+        {
+            transfer.expanded = !transfer.expanded;
+            cx.notify();
+        }
+    }
+}
+
+/// 在文件夹传输项上执行操作，随后重新汇总文件夹的进度和状态
+fn update_folder(
+    state: &mut SessionState,
+    tab_id: &str,
+    folder_id: &str,
+    f: impl FnOnce(&mut TransferItem, &str, &str),
+) {
+    let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) else {
+        return;
+    };
+    if let Some(folder) = tab.active_transfers.iter_mut().find(|t| t.id == folder_id) {
+        f(folder, &tab.server_id, &tab.server_label);
+        folder.refresh_folder();
+    }
+}
+
+/// 执行文件夹传输
+///
+/// 文件夹传输项加入传输列表后，每个文件作为独立任务在传输队列中排队并行传输，
+/// 所有文件结束后推送一次通知，返回是否全部成功
+pub(super) async fn run_folder_transfer(
+    async_cx: &mut gpui::AsyncApp,
+    session_state: &Entity<SessionState>,
+    tab_id: &str,
+    server_key: &str,
+    service: &SftpService,
+    folder: TransferItem,
+) -> bool {
+    let folder_id = folder.id.clone();
+    let is_upload = folder.is_upload;
+    let cancel_token = folder.cancel_token.clone();
+    let pause_flag = folder.pause_flag.clone();
+    let throttle = Throttle::new(is_upload, folder.speed_limit.clone(), cancel_token.clone());
+    let files: Vec<_> = folder
+        .children
+        .iter()
+        .map(|c| {
+            (
+                c.id.clone(),
+                c.remote_path.clone(),
+                c.local_path.clone(),
+                c.cancel_token.clone(),
+            )
+        })
+        .collect();
+    let mut remaining = files.len();
+    info!(
+        "[SFTP] Folder transfer {} with {} files",
+        folder.file_name(),
+        remaining
+    );
+
+    // 添加文件夹传输项到列表
+    let _ = async_cx.update(|cx| {
+        session_state.update(cx, |state, cx| {
+            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
+                tab.active_transfers.push(folder);
+            }
+            cx.notify();
+        });
+    });
+
+    // 每个文件一个任务，由传输队列控制同时进行的数量
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<FolderEvent>();
+    let resume = resume_transfers_enabled();
+    let verify = verify_checksum_enabled();
+    let runtime = crate::ssh::manager::SshManager::global().runtime();
+
+    for (file_id, remote_path, local_path, file_cancel) in files {
+        let tx = tx.clone();
+        let service = service.clone();
+        let throttle = throttle.clone();
+        let pause_flag = pause_flag.clone();
+        let server_key = server_key.to_string();
+        let tab_id = tab_id.to_string();
+
+        runtime.spawn(async move {
+            // 文件夹暂停期间不启动新的文件
+            while pause_flag.load(Ordering::Relaxed) && !file_cancel.is_cancelled() {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+            let Some(_permit) = queue_transfer(&file_id, &server_key, &file_cancel).await else {
+                return;
+            };
+
+            let progress_tx = tx.clone();
+            let progress_id = file_id.clone();
+            let on_progress = move |transferred, total, speed| {
+                let _ = progress_tx.send(FolderEvent::Progress(
+                    progress_id.clone(),
+                    transferred,
+                    total,
+                    speed,
+                ));
+            };
+            let result = if is_upload {
+                service
+                    .upload_file(
+                        &local_path,
+                        &remote_path,
+                        resume,
+                        &file_cancel,
+                        &throttle,
+                        on_progress,
+                    )
+                    .await
+            } else {
+                service
+                    .download_file(
+                        &remote_path,
+                        &local_path,
+                        resume,
+                        &file_cancel,
+                        &throttle,
+                        on_progress,
+                    )
+                    .await
+            };
+            let result = verify_transfer(&tab_id, &remote_path, &local_path, result, verify).await;
+            let _ = tx.send(FolderEvent::Complete(file_id, result));
+        });
+    }
+    drop(tx);
+
+    // 处理各文件的进度和结果，直到所有文件结束或文件夹被取消
+    while remaining > 0 {
+        tokio::select! {
+            _ = cancel_token.cancelled() => {
+                info!("[SFTP] Folder transfer cancelled: {}", folder_id);
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        update_folder(state, tab_id, &folder_id, |folder, _, _| {
+                            for file in folder.children.iter_mut() {
+                                if !file.status.is_terminal() {
+                                    file.status = TransferStatus::Cancelled;
+                                    file.error = Some(t("transfer.cancelled_by_user"));
+                                }
+                            }
+                        });
+                        cx.notify();
+                    });
+                });
+                break;
+            }
+            event = rx.recv() => {
+                let Some(event) = event else {
+                    break;
+                };
+                if matches!(event, FolderEvent::Complete(..)) {
+                    remaining -= 1;
+                }
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        update_folder(state, tab_id, &folder_id, |folder, server_id, server_label| {
+                            match event {
+                                FolderEvent::Progress(file_id, transferred, total, speed) => {
+                                    if let Some(file) = folder.child_mut(&file_id) {
+                                        file.update_progress(transferred, total, speed);
+                                    }
+                                }
+                                FolderEvent::Complete(file_id, result) => {
+                                    let Some(file) = folder.child_mut(&file_id) else {
+                                        return;
+                                    };
+                                    match result {
+                                        Ok(()) => {
+                                            file.set_completed();
+                                            file.mark_verified(verify);
+                                        }
+                                        Err(e) => {
+                                            error!("[SFTP] Folder file failed: {}: {}", file_id, e);
+                                            file.set_failed(e);
+                                        }
+                                    }
+                                    record_history(server_id, server_label, file);
+                                }
+                            }
+                        });
+                        cx.notify();
+                    });
+                });
+            }
+        }
+    }
+
+    let succeeded = async_cx
+        .update(|cx| {
+            session_state
+                .read(cx)
+                .tabs
+                .iter()
+                .flat_map(|t| t.active_transfers.iter())
+                .find(|t| t.id == folder_id)
+                .is_some_and(|t| t.status.is_complete())
+        })
+        .unwrap_or(false);
+    info!(
+        "[SFTP] Folder transfer finished: {} (succeeded: {})",
+        folder_id, succeeded
+    );
+
+    // 推送通知
+    let _ = async_cx.update(|cx| {
+        let key = match (is_upload, succeeded) {
+            (true, true) => "sftp.upload.success",
+            (true, false) => "sftp.upload.failed",
+            (false, true) => "sftp.download.success",
+            (false, false) => "sftp.download.failed",
+        };
+        let kind = if succeeded {
+            NotificationType::Success
+        } else {
+            NotificationType::Error
+        };
+        notify_key(cx, NotificationCategory::Transfer, kind, key);
+    });
+
+    succeeded
+}
@@ -0,0 +1,177 @@
+// SFTP 十六进制查看方法：按页读取远程文件的指定范围，按字节序列搜索
+
+use super::SessionState;
+use crate::components::sftp::HexViewerDialogState;
+use crate::services::sftp::{SftpService, HEX_PAGE_BYTES};
+use gpui::prelude::*;
+use gpui::Entity;
+use tracing::{error, info};
+
+impl SessionState {
+    /// 确保十六进制查看器对话框已创建
+    pub fn ensure_sftp_hex_viewer_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<HexViewerDialogState> {
+        if self.sftp_hex_viewer_dialog.is_none() {
+            self.sftp_hex_viewer_dialog = Some(cx.new(|_| HexViewerDialogState::default()));
+        }
+        self.sftp_hex_viewer_dialog.clone().unwrap()
+    }
+
+    /// 获取十六进制查看器对话框状态（如果存在）
+    pub fn get_sftp_hex_viewer_dialog(&self) -> Option<Entity<HexViewerDialogState>> {
+        self.sftp_hex_viewer_dialog.clone()
+    }
+
+    /// 打开十六进制查看器并读取第一页
+    pub fn sftp_open_hex_viewer(
+        &mut self,
+        tab_id: &str,
+        path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(entry) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .and_then(|s| s.file_list.iter().find(|e| e.path == path))
+            .cloned()
+        else {
+            error!("[SFTP] File entry not found for path: {}", path);
+            return;
+        };
+        info!("[SFTP] Open hex viewer for: {} in tab {}", path, tab_id);
+
+        let dialog = self.ensure_sftp_hex_viewer_dialog(cx);
+        dialog.update(cx, |d, _| {
+            d.open(tab_id.to_string(), entry.path, entry.name, entry.size);
+        });
+        self.sftp_hex_load_page(dialog, 0, cx);
+        cx.notify();
+    }
+
+    /// 读取从 `offset` 开始的一页数据
+    pub fn sftp_hex_load_page(
+        &mut self,
+        dialog: Entity<HexViewerDialogState>,
+        offset: u64,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, path, file_size) = {
+            let d = dialog.read(cx);
+            (d.tab_id.clone(), d.path.clone(), d.file_size)
+        };
+        if file_size == 0 {
+            return;
+        }
+        let Some(service) = self.hex_viewer_service(&tab_id) else {
+            return;
+        };
+        let seq = dialog.update(cx, |d, cx| {
+            let seq = d.start_loading();
+            cx.notify();
+            seq
+        });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<Vec<u8>, String>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = service.read_range(&path, offset, HEX_PAGE_BYTES).await;
+                let _ = tx.send(result);
+            });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    dialog.update(cx, |d, cx| {
+                        d.set_page(seq, offset, result);
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+
+    /// 从上一次匹配之后查找下一个匹配，找到后跳转到所在页
+    pub fn sftp_hex_search(
+        &mut self,
+        dialog: Entity<HexViewerDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(pattern) = dialog.update(cx, |d, cx| {
+            let pattern = d.search_pattern(cx);
+            cx.notify();
+            pattern
+        }) else {
+            return;
+        };
+        let (tab_id, path) = {
+            let d = dialog.read(cx);
+            (d.tab_id.clone(), d.path.clone())
+        };
+        let Some(service) = self.hex_viewer_service(&tab_id) else {
+            return;
+        };
+        let (start, token) = dialog.update(cx, |d, cx| {
+            let started = d.start_search();
+            cx.notify();
+            started
+        });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<Option<u64>, String>>();
+        let pattern_len = pattern.len();
+        let token_for_task = token.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = service
+                    .find_bytes(&path, start, &pattern, token_for_task)
+                    .await;
+                let _ = tx.send(result);
+            });
+
+        let session_state = cx.entity().clone();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                // 搜索已被取消（对话框关闭或开始了新的搜索）时丢弃结果
+                if token.is_cancelled() {
+                    return;
+                }
+                let _ = async_cx.update(|cx| {
+                    let page = dialog.update(cx, |d, cx| {
+                        let page = d.finish_search(result, pattern_len);
+                        cx.notify();
+                        page
+                    });
+                    if let Some(page) = page {
+                        session_state.update(cx, |state, cx| {
+                            state.sftp_hex_load_page(dialog.clone(), page, cx);
+                        });
+                    }
+                });
+            })
+            .detach();
+    }
+
+    /// 十六进制查看器所属标签页的 SFTP 服务
+    fn hex_viewer_service(&self, tab_id: &str) -> Option<SftpService> {
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned());
+        if service.is_none() {
+            error!("[SFTP] No SFTP service for tab {}", tab_id);
+        }
+        service
+    }
+}
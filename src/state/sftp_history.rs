@@ -0,0 +1,100 @@
+// SFTP 传输历史方法：切换传输面板页面、筛选和重新传输历史记录
+
+use super::{SessionState, TransferPanelView};
+use crate::models::sftp::{TransferHistoryEntry, TransferHistoryFilter};
+use crate::services::transfer::TransferHistoryStore;
+use tracing::{info, warn};
+
+impl SessionState {
+    /// 切换传输面板显示的页面（进行中/历史）
+    pub fn set_transfer_panel_view(
+        &mut self,
+        view: TransferPanelView,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.transfer_panel_view = view;
+        cx.notify();
+    }
+
+    /// 设置传输历史的筛选条件
+    pub fn set_transfer_history_filter(
+        &mut self,
+        filter: TransferHistoryFilter,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.transfer_history_filter = filter;
+        cx.notify();
+    }
+
+    /// 清空传输历史
+    pub fn clear_transfer_history(&mut self, cx: &mut gpui::Context<Self>) {
+        TransferHistoryStore::global().clear();
+        cx.notify();
+    }
+
+    /// 按历史记录重新传输文件
+    ///
+    /// 使用同一服务器已打开的会话（优先当前标签页），传输前照常检查目标冲突
+    pub fn retransfer_history_entry(
+        &mut self,
+        entry: &TransferHistoryEntry,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(tab_id) = self.history_tab_id(&entry.server_id) else {
+            warn!(
+                "[SFTP] No open session for server {}, cannot retransfer",
+                entry.server_label
+            );
+            return;
+        };
+        info!("[SFTP] Retransferring from history: {}", entry.remote_path);
+
+        let Some(local_name) = entry
+            .local_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+        else {
+            return;
+        };
+        if entry.is_upload {
+            let remote_dir = crate::models::sftp::state::get_parent_path(&entry.remote_path);
+            self.sftp_upload_file_as(
+                &tab_id,
+                entry.local_path.clone(),
+                remote_dir,
+                entry.file_name(),
+                true,
+                cx,
+            );
+        } else {
+            self.sftp_download_file_to(
+                &tab_id,
+                entry.remote_path.clone(),
+                local_name,
+                entry.size,
+                entry.local_path.parent().map(|p| p.to_path_buf()),
+                true,
+                cx,
+            );
+        }
+        self.transfer_panel_view = TransferPanelView::Active;
+        cx.notify();
+    }
+
+    /// 历史记录所属服务器的已连接标签页（优先当前标签页）
+    pub fn history_tab_id(&self, server_id: &str) -> Option<String> {
+        let connected = |id: &str| {
+            self.sftp_services
+                .lock()
+                .map(|s| s.contains_key(id))
+                .unwrap_or(false)
+        };
+        self.active_tab_id
+            .as_ref()
+            .and_then(|id| self.tabs.iter().find(|t| &t.id == id))
+            .into_iter()
+            .chain(self.tabs.iter())
+            .find(|t| t.server_id == server_id && connected(&t.id))
+            .map(|t| t.id.clone())
+    }
+}
@@ -0,0 +1,195 @@
+// SFTP 链接方法：创建符号链接/硬链接，跳转到链接目标
+
+use super::SessionState;
+use crate::components::sftp::NewLinkDialogState;
+use crate::models::sftp::FileEntry;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::SftpService;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+impl SessionState {
+    /// 确保新建链接对话框已创建
+    pub fn ensure_sftp_new_link_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<NewLinkDialogState> {
+        if self.sftp_new_link_dialog.is_none() {
+            self.sftp_new_link_dialog = Some(cx.new(|_| NewLinkDialogState::default()));
+        }
+        self.sftp_new_link_dialog.clone().unwrap()
+    }
+
+    /// 获取新建链接对话框状态（如果存在）
+    pub fn get_sftp_new_link_dialog(&self) -> Option<Entity<NewLinkDialogState>> {
+        self.sftp_new_link_dialog.clone()
+    }
+
+    /// 打开新建链接对话框，链接创建在当前目录下
+    ///
+    /// `target` 为从文件右键菜单打开时预填的目标路径
+    pub fn sftp_open_new_link_dialog(
+        &mut self,
+        tab_id: &str,
+        target: Option<String>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let current_path = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|s| s.current_path.clone())
+            .unwrap_or_else(|| "/".to_string());
+
+        let dialog = self.ensure_sftp_new_link_dialog(cx);
+        dialog.update(cx, |s, _| {
+            s.open(tab_id.to_string(), current_path.clone(), target);
+        });
+        self.sftp_link_browse(dialog, current_path, cx);
+        cx.notify();
+    }
+
+    /// 读取目标选择器中的目录
+    pub fn sftp_link_browse(
+        &mut self,
+        dialog: Entity<NewLinkDialogState>,
+        dir: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let tab_id = dialog.read(cx).tab_id.clone();
+        let Some(service) = self.link_service(&tab_id) else {
+            return;
+        };
+        let seq = dialog.update(cx, |d, cx| {
+            let seq = d.start_browsing(dir.clone());
+            cx.notify();
+            seq
+        });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<Vec<FileEntry>, String>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(service.read_dir(&dir).await);
+            });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                if let Some(result) = rx.recv().await {
+                    let _ = async_cx.update(|cx| {
+                        dialog.update(cx, |d, cx| {
+                            d.set_browse_entries(seq, result);
+                            cx.notify();
+                        });
+                    });
+                }
+            })
+            .detach();
+    }
+
+    /// 按对话框中的输入创建链接
+    pub fn sftp_create_link(
+        &mut self,
+        dialog: Entity<NewLinkDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, link_path, target, kind) = {
+            let d = dialog.read(cx);
+            (d.tab_id.clone(), d.link_path(cx), d.target(cx), d.kind)
+        };
+        let Some(service) = self.link_service(&tab_id) else {
+            dialog.update(cx, |d, cx| {
+                d.set_error("SFTP service not available".to_string());
+                cx.notify();
+            });
+            return;
+        };
+        info!(
+            "[SFTP] Creating {:?} link {} -> {}",
+            kind, link_path, target
+        );
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(service.create_link(&link_path, &target, kind).await);
+            });
+
+        let session_state = cx.entity().clone();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                if let Some(result) = rx.recv().await {
+                    let _ = async_cx.update(|cx| {
+                        dialog.update(cx, |d, cx| {
+                            match &result {
+                                Ok(()) => d.close(),
+                                Err(e) => d.set_error(e.clone()),
+                            }
+                            cx.notify();
+                        });
+                        if result.is_ok() {
+                            session_state.update(cx, |state, cx| {
+                                state.sftp_refresh(&tab_id, cx);
+                            });
+                        }
+                    });
+                }
+            })
+            .detach();
+    }
+
+    /// 跳转到符号链接的目标：目标为目录时进入该目录，否则在其所在目录中定位
+    pub fn sftp_follow_link(&mut self, tab_id: &str, path: String, cx: &mut gpui::Context<Self>) {
+        let Some(service) = self.link_service(tab_id) else {
+            return;
+        };
+        info!("[SFTP] Following link: {}", path);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<FileEntry, String>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(service.follow_link(&path).await);
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match result {
+                    Ok(target) => session_state.update(cx, |state, cx| {
+                        if target.is_dir() {
+                            state.sftp_navigate_to(&tab_id, target.path, cx);
+                        } else {
+                            state.sftp_reveal_path(&tab_id, target.path, cx);
+                        }
+                    }),
+                    Err(e) => {
+                        error!("[SFTP] Failed to follow link: {}", e);
+                        notify_key(
+                            cx,
+                            NotificationCategory::Transfer,
+                            NotificationType::Error,
+                            "sftp.link.target_missing",
+                        );
+                    }
+                });
+            })
+            .detach();
+    }
+
+    /// 获取链接操作使用的 SFTP 服务
+    fn link_service(&self, tab_id: &str) -> Option<SftpService> {
+        self.sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned())
+    }
+}
@@ -0,0 +1,163 @@
+// SFTP 日志查看器方法：通过 tail -F 持续读取远程日志并按批次写入查看器
+
+use super::SessionState;
+use crate::components::sftp::{LogViewerEvent, LogViewerView};
+use crate::services::sftp::tail_command;
+use gpui::prelude::*;
+use gpui::Entity;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 跟踪任务回传的消息
+enum TailMessage {
+    Line(String),
+    /// 跟踪结束（文件被删除且不再出现、连接断开等）
+    Done(Result<(), String>),
+}
+
+impl SessionState {
+    /// 确保日志查看器已创建
+    pub fn ensure_sftp_log_viewer(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<LogViewerView> {
+        if !self.sftp_log_viewers.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                LogViewerView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| match event {
+                        LogViewerEvent::Tail(path) => {
+                            state.sftp_log_tail(&tab_id_for_event, path, cx);
+                        }
+                        LogViewerEvent::Stop => state.sftp_log_stop(&tab_id_for_event),
+                    });
+                })
+            });
+            self.sftp_log_viewers.insert(tab_id.to_string(), view);
+        }
+        self.sftp_log_viewers.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取日志查看器（如果存在）
+    pub fn get_sftp_log_viewer(&self, tab_id: &str) -> Option<Entity<LogViewerView>> {
+        self.sftp_log_viewers.get(tab_id).cloned()
+    }
+
+    /// 打开日志查看器；指定路径时立即开始跟踪
+    pub fn sftp_open_log_viewer(
+        &mut self,
+        tab_id: &str,
+        path: Option<String>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(view) = self.sftp_log_viewers.get(tab_id).cloned() else {
+            return;
+        };
+        info!("[LogViewer] Open log viewer in tab {}: {:?}", tab_id, path);
+        if let Some(path) = view.update(cx, |v, cx| v.open(path, cx)) {
+            self.sftp_log_tail(tab_id, path, cx);
+        }
+        cx.notify();
+    }
+
+    /// 停止当前的日志跟踪
+    fn sftp_log_stop(&mut self, tab_id: &str) {
+        if let Some(token) = self.sftp_log_tail_tokens.remove(tab_id) {
+            info!("[LogViewer] Stop tailing in tab {}", tab_id);
+            token.cancel();
+        }
+    }
+
+    /// 跟踪日志文件（停止之前的跟踪），新内容按批次写入查看器
+    fn sftp_log_tail(&mut self, tab_id: &str, path: String, cx: &mut gpui::Context<Self>) {
+        self.sftp_log_stop(tab_id);
+        let Some(view) = self.sftp_log_viewers.get(tab_id).cloned() else {
+            return;
+        };
+
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[LogViewer] No SSH session for tab {}", tab_id);
+            // 可能由查看器自身的事件触发，延后更新以避免重复借用
+            cx.defer(move |cx| {
+                view.update(cx, |v, cx| {
+                    v.finish(Err("SSH session not found".to_string()), cx);
+                });
+            });
+            return;
+        };
+
+        let token = CancellationToken::new();
+        self.sftp_log_tail_tokens
+            .insert(tab_id.to_string(), token.clone());
+
+        let command = tail_command(&path);
+        info!("[LogViewer] Tailing in tab {}: {}", tab_id, command);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<TailMessage>();
+
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            let exec_channel = match session.open_exec().await {
+                Ok(ch) => ch,
+                Err(e) => {
+                    let _ = tx.send(TailMessage::Done(Err(format!("{:?}", e))));
+                    return;
+                }
+            };
+
+            // 取消时丢弃 exec 通道，远程的 tail 随通道关闭而退出
+            let result = tokio::select! {
+                _ = token_for_task.cancelled() => return,
+                result = exec_channel.exec_lines(&command, |line| {
+                    tx.send(TailMessage::Line(line.to_string())).is_ok()
+                }) => result,
+            };
+
+            let _ = tx.send(TailMessage::Done(result.map_err(|e| format!("{:?}", e))));
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(message) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+
+                    // 合并已到达的行，减少 UI 刷新次数
+                    let mut lines = Vec::new();
+                    let mut done = None;
+                    let mut next = Some(message);
+                    while let Some(message) = next.take() {
+                        match message {
+                            TailMessage::Line(line) => lines.push(line),
+                            TailMessage::Done(result) => {
+                                done = Some(result);
+                                break;
+                            }
+                        }
+                        next = rx.try_recv().ok();
+                    }
+
+                    let finished = done.is_some();
+                    let _ = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| {
+                            if !lines.is_empty() {
+                                v.append_lines(lines, cx);
+                            }
+                            if let Some(result) = done {
+                                v.finish(result, cx);
+                            }
+                        });
+                    });
+                    if finished {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+}
@@ -1,9 +1,29 @@
 // SFTP 导航方法：服务启动、目录导航、刷新等
 
-use super::{convert_sftp_entries, get_path_hierarchy, SessionState, SftpInitResult};
-use crate::models::sftp::SftpState;
-use crate::services::sftp::SftpService;
-use tracing::{error, info};
+use super::runbook::t;
+use super::sftp_recovery::{dir_load_error, read_dir_with_reopen};
+use super::{
+    get_path_hierarchy, EmptyTrashDialogState, OverwriteDialogState, SessionState, SftpInitResult,
+};
+use crate::models::sftp::state::{get_parent_path, join_path};
+use crate::models::sftp::{FileEntry, SftpState};
+use crate::services::notifications::{notify, notify_key, NotificationCategory};
+use crate::services::sftp::{
+    expand_remote_home, is_safe_trash_root, is_same_or_descendant, MoveOutcome, SftpService,
+};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info};
+
+/// 后台目录读取发回 UI 的事件
+enum DirLoadEvent {
+    /// 一批新读取到的条目
+    Batch(Vec<FileEntry>),
+    /// 读取结束（成功时为完整列表）
+    Finished(Result<Vec<FileEntry>, String>),
+}
 
 impl SessionState {
     /// 启动 SFTP 服务
@@ -21,9 +41,14 @@ impl SessionState {
         info!("[SFTP] Starting SFTP service for tab {}", tab_id);
 
         // 直接初始化空的 SftpState
+        let mut start_path = None;
         if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+            start_path = tab.sftp_start_path.take();
             let mut sftp_state = SftpState::default();
             sftp_state.show_hidden = true;
+            sftp_state.dual_pane = crate::services::storage::load_settings()
+                .map(|s| s.sftp.dual_pane)
+                .unwrap_or(false);
             tab.sftp_state = Some(sftp_state);
         }
 
@@ -42,81 +67,66 @@ impl SessionState {
                         "[SFTP] SFTP service initialized for tab {}",
                         tab_id_for_tokio
                     );
-                    let sftp = service.sftp();
+                    service.start_metadata_pool(session);
+                    if service.is_scp_fallback() {
+                        let _ = tx.send(SftpInitResult::ScpFallback);
+                    }
 
                     let tx_dir = tx.clone();
-                    let sftp_for_dir = sftp.clone();
+                    let service_for_dir = service.clone();
                     let tab_id_for_dir = tab_id_for_tokio.clone();
                     let sftp_services_clone = sftp_services.clone();
 
                     let dir_task = async move {
                         // 阶段1：获取主目录
-                        let home_dir = match sftp_for_dir.canonicalize(".").await {
-                            Ok(home) => {
-                                info!("[SFTP] Home directory: {}", home);
-                                home
-                            }
-                            Err(e) => {
-                                error!("[SFTP] Failed to get home directory: {:?}", e);
-                                "/".to_string()
-                            }
-                        };
+                        let home_dir = service_for_dir
+                            .get_home_dir()
+                            .await
+                            .unwrap_or_else(|_| "/".to_string());
+                        info!("[SFTP] Home directory: {}", home_dir);
+                        // 恢复工作区时打开上次的目录
+                        let start_dir = start_path.unwrap_or_else(|| home_dir.clone());
 
                         let _ = tx_dir.send(SftpInitResult::HomeReady {
                             home_dir: home_dir.clone(),
+                            start_dir: start_dir.clone(),
                         });
                         info!("[SFTP] HomeReady sent");
 
                         // 阶段2：读取当前目录
-                        let home_entries = match sftp_for_dir.read_dir(&home_dir).await {
-                            Ok(entries) => {
-                                let entries: Vec<_> = entries.collect();
-                                let file_entries = convert_sftp_entries(&home_dir, entries);
+                        match service_for_dir.read_dir(&start_dir).await {
+                            Ok(file_entries) => {
                                 info!(
-                                    "[SFTP] Loaded {} entries from home: {}",
+                                    "[SFTP] Loaded {} entries from start directory: {}",
                                     file_entries.len(),
-                                    home_dir
+                                    start_dir
                                 );
-                                file_entries
+                                let _ = tx_dir.send(SftpInitResult::CurrentDirReady {
+                                    path: start_dir.clone(),
+                                    entries: file_entries,
+                                });
+                                info!("[SFTP] CurrentDirReady sent");
                             }
                             Err(e) => {
-                                error!("[SFTP] Failed to read home directory: {:?}", e);
-                                Vec::new()
+                                error!("[SFTP] Failed to read start directory: {:?}", e);
+                                let _ = tx_dir.send(SftpInitResult::CurrentDirFailed {
+                                    path: start_dir.clone(),
+                                    error: e,
+                                });
                             }
-                        };
-
-                        let _ = tx_dir.send(SftpInitResult::CurrentDirReady {
-                            path: home_dir.clone(),
-                            entries: home_entries,
-                        });
-                        info!("[SFTP] CurrentDirReady sent");
+                        }
 
                         // 阶段3：并行读取所有父级目录
-                        let path_hierarchy = get_path_hierarchy(&home_dir);
+                        let path_hierarchy = get_path_hierarchy(&start_dir);
                         let parent_paths: Vec<_> = path_hierarchy
                             .into_iter()
-                            .filter(|p| *p != home_dir)
+                            .filter(|p| *p != start_dir)
                             .collect();
 
                         if !parent_paths.is_empty() {
-                            let read_futures: Vec<_> = parent_paths
-                                .iter()
-                                .map(|path| {
-                                    let path = path.clone();
-                                    let sftp = sftp_for_dir.clone();
-                                    async move {
-                                        let result = sftp.read_dir(&path).await;
-                                        (path, result)
-                                    }
-                                })
-                                .collect();
-
-                            let dir_results = futures::future::join_all(read_futures).await;
                             let mut dir_caches = Vec::new();
-                            for (path, result) in dir_results {
-                                if let Ok(entries) = result {
-                                    let entries: Vec<_> = entries.collect();
-                                    let file_entries = convert_sftp_entries(&path, entries);
+                            for (path, result) in service_for_dir.read_dirs(parent_paths).await {
+                                if let Ok(file_entries) = result {
                                     info!(
                                         "[SFTP] Loaded {} entries from parent: {}",
                                         file_entries.len(),
@@ -131,45 +141,23 @@ impl SessionState {
                         }
 
                         if let Ok(mut services) = sftp_services_clone.lock() {
-                            services.insert(tab_id_for_dir, service);
+                            services.insert(tab_id_for_dir, service_for_dir);
                         }
                     };
 
                     let tx_ug = tx.clone();
-                    let sftp_for_ug = sftp.clone();
 
                     let user_group_task = async move {
-                        use tokio::io::AsyncReadExt;
-
                         let passwd_future = async {
-                            match sftp_for_ug.open("/etc/passwd").await {
-                                Ok(mut file) => {
-                                    let mut content = String::new();
-                                    if file.read_to_string(&mut content).await.is_ok() {
-                                        info!(
-                                            "[SFTP] Loaded /etc/passwd ({} bytes)",
-                                            content.len()
-                                        );
-                                        return Some(content);
-                                    }
-                                }
-                                Err(_) => {}
-                            }
-                            None
+                            let content = service.read_file("/etc/passwd").await.ok()?;
+                            info!("[SFTP] Loaded /etc/passwd ({} bytes)", content.len());
+                            Some(content)
                         };
 
                         let group_future = async {
-                            match sftp_for_ug.open("/etc/group").await {
-                                Ok(mut file) => {
-                                    let mut content = String::new();
-                                    if file.read_to_string(&mut content).await.is_ok() {
-                                        info!("[SFTP] Loaded /etc/group ({} bytes)", content.len());
-                                        return Some(content);
-                                    }
-                                }
-                                Err(_) => {}
-                            }
-                            None
+                            let content = service.read_file("/etc/group").await.ok()?;
+                            info!("[SFTP] Loaded /etc/group ({} bytes)", content.len());
+                            Some(content)
                         };
 
                         let (passwd_content, group_content) =
@@ -184,7 +172,11 @@ impl SessionState {
                 }
                 Err(e) => {
                     error!("[SFTP] Failed to initialize SFTP service: {:?}", e);
-                    let _ = tx.send(SftpInitResult::Error(format!("SFTP 初始化失败: {}", e)));
+                    let _ = tx.send(SftpInitResult::Error(format!(
+                        "{}: {}",
+                        t("sftp.init_failed"),
+                        e
+                    )));
                 }
             }
         });
@@ -200,10 +192,10 @@ impl SessionState {
                             if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_clone) {
                                 if let Some(sftp_state) = &mut tab.sftp_state {
                                     match result {
-                                        SftpInitResult::HomeReady { home_dir } => {
-                                            sftp_state.set_home_dir(home_dir.clone());
-                                            sftp_state.navigate_to(home_dir.clone());
-                                            sftp_state.expand_to_path(&home_dir);
+                                        SftpInitResult::HomeReady { home_dir, start_dir } => {
+                                            sftp_state.set_home_dir(home_dir);
+                                            sftp_state.navigate_to(start_dir.clone());
+                                            sftp_state.expand_to_path(&start_dir);
                                             info!("[SFTP] HomeReady processed: toolbar can render");
                                         }
                                         SftpInitResult::CurrentDirReady { path, entries } => {
@@ -212,6 +204,9 @@ impl SessionState {
                                             sftp_state.set_loading(false);
                                             info!("[SFTP] CurrentDirReady processed: file list can render");
                                         }
+                                        SftpInitResult::CurrentDirFailed { path, error } => {
+                                            sftp_state.set_load_error(dir_load_error(path, error));
+                                        }
                                         SftpInitResult::ParentDirsReady { dir_caches } => {
                                             for (path, entries) in dir_caches {
                                                 sftp_state.update_cache(path, entries);
@@ -227,6 +222,9 @@ impl SessionState {
                                             }
                                             info!("[SFTP] UserGroupReady processed: user/group names available");
                                         }
+                                        SftpInitResult::ScpFallback => {
+                                            sftp_state.scp_fallback = true;
+                                        }
                                         SftpInitResult::Error(msg) => {
                                             sftp_state.set_error(msg);
                                             sftp_state.set_loading(false);
@@ -283,6 +281,7 @@ impl SessionState {
             }
         };
 
+        self.sftp_record_recent(tab_id, &path);
         if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
             if let Some(ref mut sftp_state) = tab.sftp_state {
                 sftp_state.navigate_to(path.clone());
@@ -295,6 +294,7 @@ impl SessionState {
         }
         cx.notify();
 
+        self.sftp_load_tree_ancestors(tab_id, &path, cx);
         if needs_load {
             self.sftp_load_directory(tab_id, path, cx);
         }
@@ -425,6 +425,15 @@ impl SessionState {
                 .map(|s| s.current_path.clone())
         };
 
+        // 启用远程回收站时，删除改为移动到回收站（回收站内的项目仍直接删除）
+        let trash_root = match self.sftp_trash_root(tab_id) {
+            Ok(root) => root.filter(|root| !is_same_or_descendant(&path, root)),
+            Err(e) => {
+                self.sftp_set_trash_error(tab_id, e, cx);
+                return;
+            }
+        };
+
         // 判断是文件还是目录，并执行乐观更新（立即从列表移除）
         let (is_dir, removed_entry) = {
             let tab = self.tabs.iter_mut().find(|t| t.id == tab_id);
@@ -492,7 +501,9 @@ impl SessionState {
 
         // 在 tokio 运行时中执行删除操作
         ssh_manager.runtime().spawn(async move {
-            let result = if is_dir {
+            let result = if let Some(root) = trash_root {
+                service.move_to_trash(&path_clone, &root).await.map(|_| ())
+            } else if is_dir {
                 service.remove_dir(&path_clone).await
             } else {
                 service.remove_file(&path_clone).await
@@ -533,7 +544,11 @@ impl SessionState {
                                         {
                                             if let Some(ref mut sftp_state) = tab.sftp_state {
                                                 sftp_state.restore_file_to_list(index, entry);
-                                                sftp_state.set_error(format!("删除失败: {}", e));
+                                                sftp_state.set_error(format!(
+                                                    "{}: {}",
+                                                    t("sftp.delete.failed"),
+                                                    e
+                                                ));
                                             }
                                         }
                                     }
@@ -544,28 +559,12 @@ impl SessionState {
 
                         // 推送失败通知
                         if result.is_err() {
-                            if let Some(window) = cx.active_window() {
-                                use gpui::AppContext as _;
-                                let _ = cx.update_window(window, |_, window, cx| {
-                                    use gpui::Styled;
-                                    use gpui_component::notification::{
-                                        Notification, NotificationType,
-                                    };
-                                    use gpui_component::WindowExt;
-
-                                    // 加载语言设置
-                                    let lang = crate::services::storage::load_settings()
-                                        .map(|s| s.theme.language)
-                                        .unwrap_or_default();
-
-                                    let notification = Notification::new()
-                                        .message(crate::i18n::t(&lang, "sftp.delete.failed"))
-                                        .with_type(NotificationType::Error)
-                                        .w_48()
-                                        .py_2();
-                                    window.push_notification(notification, cx);
-                                });
-                            }
+                            notify_key(
+                                cx,
+                                NotificationCategory::Transfer,
+                                NotificationType::Error,
+                                "sftp.delete.failed",
+                            );
                         }
                     });
                 }
@@ -573,6 +572,137 @@ impl SessionState {
             .detach();
     }
 
+    /// 获取远程回收站的绝对路径（未启用回收站时返回 Ok(None)）
+    /// 回收站目录为根目录、主目录或其上级目录时返回错误，避免清空时误删
+    fn sftp_trash_root(&self, tab_id: &str) -> Result<Option<String>, String> {
+        let settings = crate::services::storage::load_settings().unwrap_or_default();
+        if !settings.sftp.use_remote_trash {
+            return Ok(None);
+        }
+        let Some(home_dir) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|s| s.home_dir.clone())
+        else {
+            return Ok(None);
+        };
+        let trash_dir = &settings.sftp.remote_trash_dir;
+        if !is_safe_trash_root(trash_dir, Some(&home_dir)) {
+            error!("[SFTP] Refusing unsafe trash directory: {}", trash_dir);
+            return Err(format!("{}: {}", t("sftp.trash.unsafe_root"), trash_dir));
+        }
+        Ok(Some(expand_remote_home(trash_dir, &home_dir)))
+    }
+
+    /// 回收站目录不安全时在文件列表中显示错误
+    fn sftp_set_trash_error(&mut self, tab_id: &str, error: String, cx: &mut gpui::Context<Self>) {
+        if let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        {
+            sftp_state.set_error(error);
+        }
+        cx.notify();
+    }
+
+    /// 请求清空远程回收站：先弹出确认对话框
+    pub fn sftp_request_empty_trash(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let trash_root = match self.sftp_trash_root(tab_id) {
+            Ok(Some(root)) => root,
+            Ok(None) => return,
+            Err(e) => {
+                self.sftp_set_trash_error(tab_id, e, cx);
+                return;
+            }
+        };
+        let dialog = self.ensure_sftp_empty_trash_dialog(cx);
+        dialog.update(cx, |d, cx| {
+            d.open(tab_id.to_string(), trash_root);
+            cx.notify();
+        });
+    }
+
+    /// 清空远程回收站（确认后调用）
+    pub fn sftp_empty_trash(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let trash_root = match self.sftp_trash_root(tab_id) {
+            Ok(Some(root)) => root,
+            Ok(None) => return,
+            Err(e) => {
+                self.sftp_set_trash_error(tab_id, e, cx);
+                return;
+            }
+        };
+        info!("[SFTP] Empty trash: {} for tab {}", trash_root, tab_id);
+
+        let service = {
+            let guard = match self.sftp_services.lock() {
+                Ok(g) => g,
+                Err(e) => {
+                    error!("[SFTP] Failed to lock sftp_services: {}", e);
+                    return;
+                }
+            };
+            match guard.get(tab_id) {
+                Some(s) => s.clone(),
+                None => {
+                    error!("[SFTP] No SFTP service for tab {}", tab_id);
+                    return;
+                }
+            }
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        let trash_root_for_task = trash_root.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = service.empty_trash(&trash_root_for_task).await;
+                let _ = tx.send(result);
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id_for_ui = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                if let Some(result) = rx.recv().await {
+                    let _ = async_cx.update(|cx| {
+                        session_state.update(cx, |state, cx| {
+                            if let Err(e) = &result {
+                                error!("[SFTP] Failed to empty trash {}: {}", trash_root, e);
+                            }
+                            // 正在浏览回收站时刷新
+                            let in_trash = state
+                                .tabs
+                                .iter_mut()
+                                .find(|t| t.id == tab_id_for_ui)
+                                .and_then(|t| t.sftp_state.as_mut())
+                                .map(|sftp_state| {
+                                    sftp_state.invalidate_cache(&trash_root);
+                                    is_same_or_descendant(&sftp_state.current_path, &trash_root)
+                                })
+                                .unwrap_or(false);
+                            if in_trash {
+                                state.sftp_navigate_to(&tab_id_for_ui, trash_root.clone(), cx);
+                            }
+                            cx.notify();
+                        });
+
+                        let (key, kind) = if result.is_ok() {
+                            ("sftp.trash.emptied", NotificationType::Success)
+                        } else {
+                            ("sftp.trash.empty_failed", NotificationType::Error)
+                        };
+                        notify_key(cx, NotificationCategory::Transfer, kind, key);
+                    });
+                }
+            })
+            .detach();
+    }
+
     /// SFTP 重命名文件或目录
     pub fn sftp_rename(
         &mut self,
@@ -587,15 +717,43 @@ impl SessionState {
         );
 
         // 计算新路径
-        let new_path = if let Some(parent) = old_path.rsplit_once('/').map(|(p, _)| p) {
-            if parent.is_empty() {
-                format!("/{}", new_name)
-            } else {
-                format!("{}/{}", parent, new_name)
-            }
-        } else {
-            new_name.clone()
-        };
+        let new_path = join_path(&get_parent_path(&old_path), &new_name);
+        self.sftp_move(tab_id, old_path, new_path, false, cx);
+    }
+
+    /// SFTP 将文件或目录移动到指定目录下（保持原名）
+    pub fn sftp_move_into(
+        &mut self,
+        tab_id: &str,
+        source: String,
+        target_dir: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        info!(
+            "[SFTP] Move: {} -> {} for tab {}",
+            source, target_dir, tab_id
+        );
+
+        let name = source.rsplit('/').next().unwrap_or(&source).to_string();
+        let target_path = join_path(&target_dir, &name);
+        if target_path == source {
+            return;
+        }
+        self.sftp_move(tab_id, source, target_path, false, cx);
+    }
+
+    /// SFTP 移动（重命名）文件或目录
+    /// 目标已存在且未允许覆盖时，弹出覆盖确认对话框
+    pub fn sftp_move(
+        &mut self,
+        tab_id: &str,
+        from: String,
+        to: String,
+        overwrite: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        // 同目录内为重命名，否则为移动（用于失败提示）
+        let is_rename = get_parent_path(&from) == get_parent_path(&to);
 
         // 获取当前目录路径用于刷新
         let current_path = {
@@ -607,10 +765,10 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
-        let old_path_clone = old_path.clone();
-        let new_path_clone = new_path.clone();
+        let from_clone = from.clone();
+        let to_clone = to.clone();
 
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<MoveOutcome, String>>();
 
         let ssh_manager = crate::ssh::manager::SshManager::global();
 
@@ -631,9 +789,9 @@ impl SessionState {
             }
         };
 
-        // 在 tokio 运行时中执行重命名操作
+        // 在 tokio 运行时中执行移动操作
         ssh_manager.runtime().spawn(async move {
-            let result = service.rename(&old_path_clone, &new_path_clone).await;
+            let result = service.move_entry(&from_clone, &to_clone, overwrite).await;
             let _ = tx.send(result);
         });
 
@@ -648,26 +806,42 @@ impl SessionState {
                         // 先更新 state
                         session_state.update(cx, |state, cx| {
                             match &result_clone {
-                                Ok(()) => {
-                                    info!(
-                                        "[SFTP] Successfully renamed: {} -> {}",
-                                        old_path, new_path
-                                    );
+                                Ok(MoveOutcome::Moved) => {
+                                    info!("[SFTP] Successfully moved: {} -> {}", from, to);
+                                    // 源目录与目标目录的缓存均已失效（文件夹树需要重新加载）
+                                    if let Some(tab) =
+                                        state.tabs.iter_mut().find(|t| t.id == tab_id_clone)
+                                    {
+                                        if let Some(ref mut sftp_state) = tab.sftp_state {
+                                            sftp_state.invalidate_cache(&get_parent_path(&from));
+                                            sftp_state.invalidate_cache(&get_parent_path(&to));
+                                            sftp_state.invalidate_cache(&from);
+                                        }
+                                    }
                                     // 刷新当前目录
                                     if let Some(current) = current_path.clone() {
                                         state.sftp_load_directory(&tab_id_clone, current, cx);
                                     }
                                 }
+                                Ok(MoveOutcome::TargetExists) => {
+                                    info!("[SFTP] Move target already exists: {}", to);
+                                    let dialog = state.ensure_sftp_overwrite_dialog(cx);
+                                    dialog.update(cx, |dialog, _| {
+                                        dialog.open(tab_id_clone.clone(), from.clone(), to.clone());
+                                    });
+                                }
                                 Err(e) => {
-                                    error!(
-                                        "[SFTP] Failed to rename {} to {}: {}",
-                                        old_path, new_path, e
-                                    );
+                                    error!("[SFTP] Failed to move {} to {}: {}", from, to, e);
                                     if let Some(tab) =
                                         state.tabs.iter_mut().find(|t| t.id == tab_id_clone)
                                     {
                                         if let Some(ref mut sftp_state) = tab.sftp_state {
-                                            sftp_state.set_error(format!("重命名失败: {}", e));
+                                            let prefix = if is_rename {
+                                                t("sftp.rename.failed")
+                                            } else {
+                                                t("sftp.move.failed")
+                                            };
+                                            sftp_state.set_error(format!("{}: {}", prefix, e));
                                         }
                                     }
                                 }
@@ -677,27 +851,17 @@ impl SessionState {
 
                         // 推送失败通知（成功时不通知，用户可通过文件列表刷新看到）
                         if result_clone.is_err() {
-                            if let Some(window) = cx.active_window() {
-                                use gpui::AppContext as _;
-                                let _ = cx.update_window(window, |_, window, cx| {
-                                    use gpui::Styled;
-                                    use gpui_component::notification::{
-                                        Notification, NotificationType,
-                                    };
-                                    use gpui_component::WindowExt;
-
-                                    let lang = crate::services::storage::load_settings()
-                                        .map(|s| s.theme.language)
-                                        .unwrap_or_default();
-
-                                    let notification = Notification::new()
-                                        .message(crate::i18n::t(&lang, "sftp.rename.failed"))
-                                        .with_type(NotificationType::Error)
-                                        .w_48()
-                                        .py_2();
-                                    window.push_notification(notification, cx);
-                                });
-                            }
+                            let key = if is_rename {
+                                "sftp.rename.failed"
+                            } else {
+                                "sftp.move.failed"
+                            };
+                            notify_key(
+                                cx,
+                                NotificationCategory::Transfer,
+                                NotificationType::Error,
+                                key,
+                            );
                         }
                     });
                 }
@@ -705,6 +869,38 @@ impl SessionState {
             .detach();
     }
 
+    /// 确保 SFTP 覆盖确认对话框状态已创建
+    pub fn ensure_sftp_overwrite_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<OverwriteDialogState> {
+        if self.sftp_overwrite_dialog.is_none() {
+            self.sftp_overwrite_dialog = Some(cx.new(|_| OverwriteDialogState::default()));
+        }
+        self.sftp_overwrite_dialog.clone().unwrap()
+    }
+
+    /// 获取 SFTP 覆盖确认对话框状态（如果存在）
+    pub fn get_sftp_overwrite_dialog(&self) -> Option<Entity<OverwriteDialogState>> {
+        self.sftp_overwrite_dialog.clone()
+    }
+
+    /// 确保 SFTP 清空回收站确认对话框状态已创建
+    pub fn ensure_sftp_empty_trash_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<EmptyTrashDialogState> {
+        if self.sftp_empty_trash_dialog.is_none() {
+            self.sftp_empty_trash_dialog = Some(cx.new(|_| EmptyTrashDialogState::default()));
+        }
+        self.sftp_empty_trash_dialog.clone().unwrap()
+    }
+
+    /// 获取 SFTP 清空回收站确认对话框状态（如果存在）
+    pub fn get_sftp_empty_trash_dialog(&self) -> Option<Entity<EmptyTrashDialogState>> {
+        self.sftp_empty_trash_dialog.clone()
+    }
+
     /// 切换显示/隐藏隐藏文件
     pub fn sftp_toggle_hidden(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
         info!("[SFTP] Toggle hidden for tab {}", tab_id);
@@ -743,6 +939,8 @@ impl SessionState {
     }
 
     /// 从 SftpService 加载目录内容
+    ///
+    /// 当前目录分批加载，每批到达后立即显示；其他目录（如文件夹树展开）只在完成后写入缓存
     pub(super) fn sftp_load_directory(
         &mut self,
         tab_id: &str,
@@ -753,60 +951,112 @@ impl SessionState {
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
         let path_clone = path.clone();
+        let cancel_token = CancellationToken::new();
 
         if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
             if let Some(ref mut sftp_state) = tab.sftp_state {
                 sftp_state.set_loading(true);
+                if sftp_state.current_path == path {
+                    sftp_state.begin_listing(cancel_token.clone());
+                }
             }
         }
         cx.notify();
 
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<
-            Result<Vec<crate::models::sftp::FileEntry>, String>,
-        >();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<DirLoadEvent>();
 
         let ssh_manager = crate::ssh::manager::SshManager::global();
 
-        let service = {
-            let guard = match sftp_services.lock() {
-                Ok(g) => g,
-                Err(e) => {
-                    error!("[SFTP] Failed to lock sftp_services: {}", e);
-                    return;
-                }
-            };
-            match guard.get(&tab_id_owned) {
-                Some(s) => s.clone(),
-                None => {
-                    error!("[SFTP] No SFTP service for tab {}", tab_id_owned);
-                    return;
-                }
+        // 服务不存在时在后台重新打开 SFTP 通道
+        let service = match sftp_services.lock() {
+            Ok(guard) => guard.get(&tab_id_owned).cloned(),
+            Err(e) => {
+                error!("[SFTP] Failed to lock sftp_services: {}", e);
+                None
             }
         };
 
+        let task_token = cancel_token.clone();
         ssh_manager.runtime().spawn(async move {
-            let result = match service.read_dir(&path_clone).await {
-                Ok(entries) => Ok(entries),
-                Err(e) => Err(e),
-            };
-            let _ = tx.send(result);
+            let tx_batch = tx.clone();
+            let result = read_dir_with_reopen(
+                &tab_id_owned,
+                service,
+                &sftp_services,
+                &path_clone,
+                &task_token,
+                &mut |batch| {
+                    let _ = tx_batch.send(DirLoadEvent::Batch(batch));
+                },
+            )
+            .await;
+            let _ = tx.send(DirLoadEvent::Finished(result));
         });
 
         let tab_id_for_ui = tab_id.to_string();
         cx.to_async()
             .spawn(async move |async_cx| {
-                if let Some(result) = rx.recv().await {
+                while let Some(event) = rx.recv().await {
+                    // 合并已到达的批次，减少列表重建次数
+                    let mut batch = Vec::new();
+                    let mut finished = None;
+                    let mut next = Some(event);
+                    while let Some(event) = next.take() {
+                        match event {
+                            DirLoadEvent::Batch(entries) => batch.extend(entries),
+                            DirLoadEvent::Finished(result) => {
+                                finished = Some(result);
+                                break;
+                            }
+                        }
+                        next = rx.try_recv().ok();
+                    }
+                    let done = finished.is_some();
+
                     let tab_id_clone = tab_id_for_ui.clone();
                     let path_for_update = path.clone();
+                    let cancel_token = cancel_token.clone();
                     let _ = async_cx.update(|cx| {
                         session_state.update(cx, |state, cx| {
                             if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_clone)
                             {
                                 if let Some(ref mut sftp_state) = tab.sftp_state {
-                                    sftp_state.set_loading(false);
+                                    let is_current = sftp_state.current_path == path_for_update;
+                                    if is_current
+                                        && !batch.is_empty()
+                                        && !cancel_token.is_cancelled()
+                                    {
+                                        sftp_state.append_listing(batch);
+                                    }
 
-                                    match result {
-                                        Ok(entries) => {
+                                    match finished {
+                                        None => {}
+                                        Some(_) if cancel_token.is_cancelled() => {
+                                            // 被同一目录的新加载取代时，由新的加载结束加载状态
+                                            let stopped_by_user = is_current
+                                                && sftp_state
+                                                    .listing_cancel
+                                                    .as_ref()
+                                                    .is_some_and(|t| t.is_cancelled());
+                                            if stopped_by_user {
+                                                info!(
+                                                    "[SFTP] Listing of {} stopped",
+                                                    path_for_update
+                                                );
+                                                sftp_state.set_loading(false);
+                                                sftp_state.finish_listing();
+                                                notify_key(
+                                                    cx,
+                                                    NotificationCategory::Transfer,
+                                                    NotificationType::Info,
+                                                    "sftp.loading_cancelled",
+                                                );
+                                            } else if !is_current {
+                                                sftp_state.set_loading(false);
+                                            }
+                                        }
+                                        Some(Ok(entries)) => {
+                                            sftp_state.set_loading(false);
                                             info!(
                                                 "[SFTP] Loaded {} entries from {}",
                                                 entries.len(),
@@ -817,16 +1067,22 @@ impl SessionState {
                                                 entries.clone(),
                                             );
 
-                                            if sftp_state.current_path == path_for_update {
-                                                sftp_state.update_file_list(entries);
+                                            if is_current {
+                                                sftp_state.set_listing(entries, false);
                                             }
                                         }
-                                        Err(e) => {
+                                        Some(Err(e)) => {
+                                            sftp_state.set_loading(false);
                                             error!(
                                                 "[SFTP] Failed to load directory {}: {}",
                                                 path_for_update, e
                                             );
-                                            sftp_state.set_error(e);
+                                            if is_current {
+                                                sftp_state.set_load_error(dir_load_error(
+                                                    path_for_update.clone(),
+                                                    e,
+                                                ));
+                                            }
                                         }
                                     }
                                 }
@@ -834,11 +1090,83 @@ impl SessionState {
                             cx.notify();
                         });
                     });
+                    if done {
+                        break;
+                    }
                 }
             })
             .detach();
     }
 
+    /// 并行加载文件夹树中尚未缓存的上级目录（直接跳转到深层路径时使用）
+    fn sftp_load_tree_ancestors(&mut self, tab_id: &str, path: &str, cx: &mut gpui::Context<Self>) {
+        let Some(sftp_state) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+        else {
+            return;
+        };
+        let mut ancestors = vec!["/".to_string()];
+        let mut current = String::new();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            current.push('/');
+            current.push_str(segment);
+            ancestors.push(current.clone());
+        }
+        ancestors.pop();
+        ancestors.retain(|dir| !sftp_state.is_cache_valid(dir));
+        if ancestors.is_empty() {
+            return;
+        }
+        let Some(service) = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned())
+        else {
+            return;
+        };
+
+        let (tx, mut rx) =
+            tokio::sync::mpsc::unbounded_channel::<Vec<(String, Result<Vec<FileEntry>, String>)>>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(service.read_dirs(ancestors).await);
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(results) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        let Some(sftp_state) = state
+                            .tabs
+                            .iter_mut()
+                            .find(|t| t.id == tab_id)
+                            .and_then(|t| t.sftp_state.as_mut())
+                        else {
+                            return;
+                        };
+                        for (dir, result) in results {
+                            match result {
+                                Ok(entries) => sftp_state.update_cache(dir, entries),
+                                Err(e) => debug!("[SFTP] Failed to load tree dir {}: {}", dir, e),
+                            }
+                        }
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+
     /// 在终端中打开目录 (cd 到指定路径)
     pub fn sftp_open_in_terminal(
         &mut self,
@@ -906,18 +1234,29 @@ impl SessionState {
     }
 
     /// 编辑远程文件（外置编辑器）
+    pub fn sftp_edit_file(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.sftp_edit_file_with(tab_id, remote_path, None, cx);
+    }
+
+    /// 编辑远程文件（外置编辑器），`sudo_password` 为 Some 时以管理员身份读写
     /// 1. 下载文件到本地临时目录
     /// 2. 注册文件监控
     /// 3. 启动外置编辑器
-    pub fn sftp_edit_file(
+    pub(super) fn sftp_edit_file_with(
         &mut self,
         tab_id: &str,
         remote_path: String,
+        sudo_password: Option<String>,
         cx: &mut gpui::Context<Self>,
     ) {
         use crate::services::sftp::{
-            ensure_temp_dir, open_in_external_editor, temp_file_path, FileWatchEvent, FileWatcher,
-            WatchedFile,
+            ensure_temp_dir, open_in_external_editor, root_temp_file_path, temp_file_path,
+            FileWatchEvent, FileWatcher, WatchedFile,
         };
         use std::sync::{Arc, Mutex};
 
@@ -958,41 +1297,38 @@ impl SessionState {
                     size, max_size_bytes
                 );
                 // 显示通知
-                if let Some(window) = cx.active_window() {
-                    use gpui::AppContext as _;
-                    let _ = cx.update_window(window, |_, window, cx| {
-                        use gpui::Styled;
-                        use gpui_component::notification::{Notification, NotificationType};
-                        use gpui_component::WindowExt;
-
-                        let lang = crate::services::storage::load_settings()
-                            .map(|s| s.theme.language)
-                            .unwrap_or_default();
-
-                        let notification = Notification::new()
-                            .message(format!(
-                                "{}: {}",
-                                crate::i18n::t(&lang, "sftp.edit.file_too_large"),
-                                format_file_size(size)
-                            ))
-                            .with_type(NotificationType::Warning)
-                            .w_64()
-                            .py_2();
-                        window.push_notification(notification, cx);
-                    });
-                }
+                let lang = crate::services::storage::load_settings()
+                    .map(|s| s.theme.language)
+                    .unwrap_or_default();
+                notify(
+                    cx,
+                    NotificationCategory::Transfer,
+                    NotificationType::Warning,
+                    format!(
+                        "{}: {}",
+                        crate::i18n::t(&lang, "sftp.edit.file_too_large"),
+                        format_file_size(size)
+                    ),
+                );
                 return;
             }
         }
 
+        // 记录到最近文件，供收藏夹菜单重新打开
+        self.sftp_record_recent_file(tab_id, &remote_path);
+
         // 确保临时目录存在
         if let Err(e) = ensure_temp_dir() {
             error!("[Editor] Failed to create temp dir: {}", e);
             return;
         }
 
-        // 生成临时文件路径
-        let local_path = temp_file_path(tab_id, &remote_path);
+        // 生成临时文件路径（以管理员身份编辑时文件名带 [root] 标记）
+        let local_path = if sudo_password.is_some() {
+            root_temp_file_path(tab_id, &remote_path)
+        } else {
+            temp_file_path(tab_id, &remote_path)
+        };
         info!("[Editor] Temp file path: {:?}", local_path);
 
         // 初始化文件监控器（如果尚未初始化）
@@ -1045,26 +1381,51 @@ impl SessionState {
 
         // 在 tokio 运行时中下载文件
         let tab_id_for_download = tab_id_owned.clone();
+        let ssh_session = ssh_manager.get_session(tab_id);
         ssh_manager.runtime().spawn(async move {
             use tokio::io::AsyncReadExt;
 
             info!("[Editor] Downloading file: {}", remote_path_clone);
 
-            // 打开远程文件
-            let mut remote_file = match service.open(&remote_path_clone).await {
-                Ok(f) => f,
-                Err(e) => {
-                    let _ = tx.send(Err(format!("Failed to open remote file: {}", e)));
+            // 读取文件内容
+            let content = match (&sudo_password, &ssh_session) {
+                (Some(password), Some(ssh_session)) => {
+                    match service
+                        .sudo_read_file(ssh_session, &remote_path_clone, password)
+                        .await
+                    {
+                        Ok(content) => content,
+                        Err(e) => {
+                            let _ = tx.send(Err(e));
+                            return;
+                        }
+                    }
+                }
+                (Some(_), None) => {
+                    let _ = tx.send(Err(format!(
+                        "SSH session not found: {}",
+                        tab_id_for_download
+                    )));
                     return;
                 }
-            };
+                (None, _) => {
+                    // 打开远程文件
+                    let mut remote_file = match service.open(&remote_path_clone).await {
+                        Ok(f) => f,
+                        Err(e) => {
+                            let _ = tx.send(Err(format!("Failed to open remote file: {}", e)));
+                            return;
+                        }
+                    };
 
-            // 读取文件内容
-            let mut content = Vec::new();
-            if let Err(e) = remote_file.read_to_end(&mut content).await {
-                let _ = tx.send(Err(format!("Failed to read remote file: {}", e)));
-                return;
-            }
+                    let mut content = Vec::new();
+                    if let Err(e) = remote_file.read_to_end(&mut content).await {
+                        let _ = tx.send(Err(format!("Failed to read remote file: {}", e)));
+                        return;
+                    }
+                    content
+                }
+            };
 
             // 写入本地临时文件
             if let Err(e) = std::fs::write(&local_path_clone, &content) {
@@ -1086,6 +1447,7 @@ impl SessionState {
                         remote_path: remote_path_clone.clone(),
                         session_id: tab_id_for_download.clone(),
                         last_modified: std::time::SystemTime::now(),
+                        sudo_password,
                     };
                     if let Err(e) = watcher.watch(watched_file) {
                         error!("[Editor] Failed to watch file: {}", e);
@@ -1116,23 +1478,12 @@ impl SessionState {
                         if let Err(e) = result {
                             error!("[Editor] Edit file failed: {}", e);
                             // 显示错误通知
-                            if let Some(window) = cx.active_window() {
-                                use gpui::AppContext as _;
-                                let _ = cx.update_window(window, |_, window, cx| {
-                                    use gpui::Styled;
-                                    use gpui_component::notification::{
-                                        Notification, NotificationType,
-                                    };
-                                    use gpui_component::WindowExt;
-
-                                    let notification = Notification::new()
-                                        .message(e)
-                                        .with_type(NotificationType::Error)
-                                        .w_64()
-                                        .py_2();
-                                    window.push_notification(notification, cx);
-                                });
-                            }
+                            notify(
+                                cx,
+                                NotificationCategory::Transfer,
+                                NotificationType::Error,
+                                e,
+                            );
                         }
                     });
                 }
@@ -1161,6 +1512,7 @@ impl SessionState {
                         session_id,
                         local_path,
                         remote_path,
+                        sudo_password,
                     } => {
                         info!(
                             "[FileWatcher] File modified: {:?} -> {}",
@@ -1203,11 +1555,30 @@ impl SessionState {
                         let local_path_clone = local_path.clone();
                         let file_watcher_clone = file_watcher.clone();
 
+                        let ssh_session = ssh_manager.get_session(&session_id);
+
                         // 在 tokio 运行时中上传文件
                         ssh_manager.runtime().spawn(async move {
                             info!("[FileWatcher] Uploading file to {}", remote_path_clone);
 
-                            match service.write_file(&remote_path_clone, &content).await {
+                            // 以管理员身份编辑的文件通过 sudo 写回
+                            let result = match (&sudo_password, &ssh_session) {
+                                (Some(password), Some(ssh_session)) => {
+                                    service
+                                        .sudo_write_file(
+                                            ssh_session,
+                                            &remote_path_clone,
+                                            &content,
+                                            password,
+                                        )
+                                        .await
+                                }
+                                (Some(_), None) => {
+                                    Err(format!("SSH session not found: {}", session_id))
+                                }
+                                (None, _) => service.write_file(&remote_path_clone, &content).await,
+                            };
+                            match result {
                                 Ok(()) => {
                                     info!(
                                         "[FileWatcher] Successfully uploaded {} bytes to {}",
@@ -0,0 +1,142 @@
+// SFTP 文件预览方法：切换预览面板、选中文件时只读取所需的开头字节生成预览
+
+use super::SessionState;
+use crate::components::sftp::SftpPreview;
+use crate::services::sftp::{build_preview, preview_read_len, PreviewData};
+use tracing::{error, info};
+
+impl SessionState {
+    /// 切换文件预览面板，打开时预览当前选中的文件
+    pub fn sftp_toggle_preview(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        else {
+            return;
+        };
+        sftp_state.preview_open = !sftp_state.preview_open;
+        let preview_open = sftp_state.preview_open;
+        info!(
+            "[SFTP] Preview pane {} for tab {}",
+            if preview_open { "on" } else { "off" },
+            tab_id
+        );
+
+        if preview_open {
+            let selected = self
+                .sftp_file_list_views
+                .get(tab_id)
+                .and_then(|view| view.read(cx).get_selected_file(cx));
+            if let Some(entry) = selected {
+                self.sftp_preview_path(tab_id, entry.path, cx);
+            }
+        } else {
+            self.sftp_previews.remove(tab_id);
+        }
+        cx.notify();
+    }
+
+    /// 预览面板是否打开
+    pub fn is_preview_open(&self, tab_id: &str) -> bool {
+        self.tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .is_some_and(|s| s.preview_open)
+    }
+
+    /// 预览当前目录中的文件
+    pub fn sftp_preview_path(&mut self, tab_id: &str, path: String, cx: &mut gpui::Context<Self>) {
+        if !self.is_preview_open(tab_id) {
+            return;
+        }
+        let Some(entry) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .and_then(|s| s.file_list.iter().find(|e| e.path == path))
+            .cloned()
+        else {
+            return;
+        };
+        // 同一文件已在预览中（或正在读取）时不重复读取
+        if self
+            .sftp_previews
+            .get(tab_id)
+            .is_some_and(|p| p.entry.path == entry.path && p.entry.modified == entry.modified)
+        {
+            return;
+        }
+
+        // 目录和符号链接只显示基本信息
+        let readable = entry.is_file();
+        let read_len = preview_read_len(&entry.name, entry.size);
+        let name = entry.name.clone();
+        let size = entry.size;
+        self.sftp_previews
+            .insert(tab_id.to_string(), SftpPreview::new(entry, readable));
+        cx.notify();
+
+        if !readable {
+            return;
+        }
+        let Some(read_len) = read_len else {
+            // 图片过大，不读取内容
+            if let Some(preview) = self.sftp_previews.get_mut(tab_id) {
+                preview.set_result(Ok(PreviewData::TooLarge));
+            }
+            return;
+        };
+
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|s| s.get(tab_id).cloned());
+        let Some(service) = service else {
+            error!("[SFTP] No SFTP service for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<PreviewData, String>>();
+        let path_for_task = path.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let result = service
+                    .read_head(&path_for_task, read_len)
+                    .await
+                    .map(|bytes| build_preview(&name, size, bytes));
+                let _ = tx.send(result);
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                if let Err(e) = &result {
+                    error!("[SFTP] Failed to preview {}: {}", path, e);
+                }
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        // 读取期间选中了其他文件时丢弃结果
+                        if let Some(preview) = state
+                            .sftp_previews
+                            .get_mut(&tab_id)
+                            .filter(|p| p.entry.path == path)
+                        {
+                            preview.set_result(result);
+                            cx.notify();
+                        }
+                    });
+                });
+            })
+            .detach();
+    }
+}
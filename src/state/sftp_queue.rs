@@ -0,0 +1,148 @@
+// SFTP 传输队列方法：排队、调整优先级、全部暂停/继续、限速
+
+use super::SessionState;
+use crate::models::sftp::TransferStatus;
+use crate::services::storage;
+use crate::services::transfer::{self, TransferPermit, TransferQueue};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+impl SessionState {
+    /// 传输队列中用于单服务器并发限制的服务器标识
+    pub(super) fn transfer_server_key(&self, tab_id: &str) -> String {
+        self.tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .map(|t| t.server_id.clone())
+            .unwrap_or_else(|| tab_id.to_string())
+    }
+
+    /// 把等待中的传输移到 `before` 之前，提高或降低其优先级
+    pub fn reorder_transfer(
+        &mut self,
+        tab_id: &str,
+        transfer_id: &str,
+        before: &str,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if transfer_id == before {
+            return;
+        }
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
+            return;
+        };
+        let transfers = &mut tab.active_transfers;
+        let Some(from) = transfers
+            .iter()
+            .position(|t| t.id == transfer_id && t.status == TransferStatus::Pending)
+        else {
+            return;
+        };
+        let item = transfers.remove(from);
+        let to = transfers
+            .iter()
+            .position(|t| t.id == before)
+            .unwrap_or(transfers.len());
+        transfers.insert(to, item);
+
+        // 队列中排在它后面的第一个等待中的传输
+        let next_waiting = transfers[to + 1..]
+            .iter()
+            .find(|t| t.status == TransferStatus::Pending)
+            .map(|t| t.id.clone());
+        TransferQueue::global().move_before(transfer_id, next_waiting.as_deref());
+        info!("[SFTP] Transfer {} moved before {}", transfer_id, before);
+        cx.notify();
+    }
+
+    /// 暂停所有传输：队列不再启动新的传输，进行中的传输暂停
+    pub fn pause_all_transfers(&mut self, cx: &mut gpui::Context<Self>) {
+        TransferQueue::global().pause();
+        for transfer in self
+            .tabs
+            .iter_mut()
+            .flat_map(|t| t.active_transfers.iter_mut())
+        {
+            transfer.pause();
+        }
+        cx.notify();
+    }
+
+    /// 继续所有传输：恢复已暂停的传输并继续启动排队中的传输
+    pub fn resume_all_transfers(&mut self, cx: &mut gpui::Context<Self>) {
+        for transfer in self
+            .tabs
+            .iter_mut()
+            .flat_map(|t| t.active_transfers.iter_mut())
+        {
+            if transfer.status == TransferStatus::Paused {
+                transfer.resume();
+            }
+        }
+        TransferQueue::global().resume();
+        cx.notify();
+    }
+
+    /// 设置单个传输的限速（KB/s，0 表示不限速），立即对进行中的传输生效
+    pub fn set_transfer_speed_limit(
+        &mut self,
+        transfer_id: &str,
+        limit_kb: u32,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        if let Some(transfer) = self
+            .tabs
+            .iter_mut()
+            .flat_map(|t| t.active_transfers.iter_mut())
+            .find(|t| t.id == transfer_id)
+        {
+            transfer.set_speed_limit(transfer::kb_to_bytes(limit_kb));
+            info!(
+                "[SFTP] Transfer {} limited to {} KB/s",
+                transfer_id, limit_kb
+            );
+        }
+        cx.notify();
+    }
+
+    /// 设置全局上传或下载限速（KB/s，0 表示不限速）并保存到设置
+    pub fn set_global_speed_limit(
+        &mut self,
+        is_upload: bool,
+        limit_kb: u32,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        transfer::set_global_limit_kb(is_upload, limit_kb);
+        let mut settings = storage::load_settings().unwrap_or_default();
+        if is_upload {
+            settings.sftp.upload_limit_kb = limit_kb;
+        } else {
+            settings.sftp.download_limit_kb = limit_kb;
+        }
+        if let Err(e) = storage::save_settings(&settings) {
+            error!("[SFTP] Failed to save speed limit: {}", e);
+        }
+        cx.notify();
+    }
+}
+
+/// 在传输队列中排队，轮到该传输时返回许可（排队期间被取消则返回 `None`）
+///
+/// 每次排队前按当前设置刷新并发限制
+pub(super) async fn queue_transfer(
+    transfer_id: &str,
+    server: &str,
+    cancel_token: &CancellationToken,
+) -> Option<TransferPermit> {
+    let (max_active, max_per_server) = storage::load_settings()
+        .map(|s| {
+            (
+                s.sftp.max_active_transfers as usize,
+                s.sftp.max_transfers_per_server as usize,
+            )
+        })
+        .unwrap_or((3, 2));
+    let queue = TransferQueue::global();
+    queue.set_limits(max_active, max_per_server);
+    queue.acquire(transfer_id, server, cancel_token).await
+}
@@ -0,0 +1,236 @@
+// SFTP 目录读取失败的恢复方法：通道失效时自动重新打开，权限不足时以 sudo 列出
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use super::SessionState;
+use crate::models::sftp::{DirLoadError, FileEntry};
+use crate::services::sftp::{
+    is_channel_closed, is_permission_denied, sudo_check, sudo_list_dir, SftpService,
+};
+use gpui::prelude::*;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+/// 以 sudo 列出目录的结果
+enum SudoListOutcome {
+    /// 列出完成；`passwordless_sudo` 表示使用了免密 sudo
+    Done {
+        passwordless_sudo: bool,
+        result: Result<Vec<FileEntry>, String>,
+    },
+    /// sudo 需要密码
+    NeedsPassword,
+}
+
+/// 分批读取目录；SFTP 通道已失效（或服务不存在）时重新打开通道并重试一次
+///
+/// 已交出部分条目后出错不再重试，避免重复的条目
+pub(super) async fn read_dir_with_reopen<F>(
+    tab_id: &str,
+    service: Option<SftpService>,
+    sftp_services: &Arc<Mutex<HashMap<String, SftpService>>>,
+    path: &str,
+    cancel_token: &CancellationToken,
+    on_batch: &mut F,
+) -> Result<Vec<FileEntry>, String>
+where
+    F: FnMut(Vec<FileEntry>),
+{
+    if let Some(service) = service {
+        let mut delivered = false;
+        let result = service
+            .read_dir_streaming(path, cancel_token, &mut |batch| {
+                delivered = true;
+                on_batch(batch);
+            })
+            .await;
+        match result {
+            Err(e) if !delivered && is_channel_closed(&e) => {
+                warn!("[SFTP] Channel seems closed ({}), reopening", e);
+            }
+            result => return result,
+        }
+    }
+
+    let service = reopen_sftp_service(tab_id, sftp_services).await?;
+    service
+        .read_dir_streaming(path, cancel_token, on_batch)
+        .await
+}
+
+/// 重新打开 SFTP 通道并替换标签页的 SFTP 服务
+async fn reopen_sftp_service(
+    tab_id: &str,
+    sftp_services: &Arc<Mutex<HashMap<String, SftpService>>>,
+) -> Result<SftpService, String> {
+    let session = crate::ssh::manager::SshManager::global()
+        .get_session(tab_id)
+        .ok_or_else(|| "SSH session not found".to_string())?;
+    let service = SftpService::new(tab_id.to_string(), &session).await?;
+    service.start_metadata_pool(session);
+    info!("[SFTP] SFTP channel reopened for tab {}", tab_id);
+    if let Ok(mut services) = sftp_services.lock() {
+        services.insert(tab_id.to_string(), service.clone());
+    }
+    Ok(service)
+}
+
+/// 由读取错误生成文件列表显示的错误信息
+pub(super) fn dir_load_error(path: String, message: String) -> DirLoadError {
+    DirLoadError {
+        permission_denied: is_permission_denied(&message),
+        path,
+        message,
+    }
+}
+
+impl SessionState {
+    /// 停止当前目录的分批加载，保留已加载的条目
+    pub fn sftp_cancel_listing(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        if let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        {
+            if let Some(token) = &sftp_state.listing_cancel {
+                info!("[SFTP] Cancel listing {}", sftp_state.current_path);
+                token.cancel();
+            }
+        }
+        cx.notify();
+    }
+
+    /// 通过远程 `sudo ls` 列出当前目录（SFTP 权限不足时使用）
+    ///
+    /// 本标签页已验证过 sudo 时直接使用；否则先尝试免密 sudo，需要密码时弹出 sudo 密码对话框，验证通过后重新列出
+    pub fn sftp_sudo_list(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(path) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|s| s.current_path.clone())
+        else {
+            return;
+        };
+        let Some(session) = crate::ssh::manager::SshManager::global().get_session(tab_id) else {
+            error!("[SFTP] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[SFTP] Listing {} with sudo", path);
+        let password = self.sftp_sudo_passwords.get(tab_id).cloned();
+
+        if let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        {
+            sftp_state.set_loading(true);
+        }
+        cx.notify();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let path_for_task = path.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let outcome = match password {
+                    Some(password) => SudoListOutcome::Done {
+                        passwordless_sudo: false,
+                        result: sudo_list_dir(&session, &path_for_task, &password).await,
+                    },
+                    None if sudo_check(&session, None).await.is_ok() => SudoListOutcome::Done {
+                        passwordless_sudo: true,
+                        result: sudo_list_dir(&session, &path_for_task, "").await,
+                    },
+                    None => SudoListOutcome::NeedsPassword,
+                };
+                let _ = tx.send(outcome);
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(outcome) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        let result = match outcome {
+                            SudoListOutcome::Done {
+                                passwordless_sudo,
+                                result,
+                            } => {
+                                if passwordless_sudo {
+                                    // 免密 sudo 以空密码记录
+                                    state
+                                        .sftp_sudo_passwords
+                                        .insert(tab_id.clone(), String::new());
+                                }
+                                result
+                            }
+                            SudoListOutcome::NeedsPassword => {
+                                if let Some(sftp_state) = state
+                                    .tabs
+                                    .iter_mut()
+                                    .find(|t| t.id == tab_id)
+                                    .and_then(|t| t.sftp_state.as_mut())
+                                {
+                                    sftp_state.set_loading(false);
+                                }
+                                let dialog = state.ensure_sftp_sudo_dialog(cx);
+                                dialog.update(cx, |d, _| {
+                                    d.open_with(
+                                        path.clone(),
+                                        tab_id.clone(),
+                                        "sftp.sudo.list_title",
+                                        "sftp.sudo.list_prompt",
+                                    )
+                                });
+                                state.sudo_pending_service_actions.remove(&tab_id);
+                                state.sudo_pending_cron_loads.remove(&tab_id);
+                                state.sudo_pending_package_actions.remove(&tab_id);
+                                state.sudo_pending_firewall_actions.remove(&tab_id);
+                                state.sudo_pending_sftp_lists.insert(tab_id.clone());
+                                cx.notify();
+                                return;
+                            }
+                        };
+                        let Some(sftp_state) = state
+                            .tabs
+                            .iter_mut()
+                            .find(|t| t.id == tab_id)
+                            .and_then(|t| t.sftp_state.as_mut())
+                        else {
+                            return;
+                        };
+                        sftp_state.set_loading(false);
+                        if sftp_state.current_path != path {
+                            return;
+                        }
+                        match result {
+                            Ok(entries) => {
+                                info!("[SFTP] Listed {} entries with sudo", entries.len());
+                                sftp_state.set_listing(entries, true);
+                            }
+                            Err(e) => {
+                                error!("[SFTP] sudo ls failed for {}: {}", path, e);
+                                // 保留"以 sudo 列出"入口，便于重试
+                                sftp_state.set_load_error(DirLoadError {
+                                    path: path.clone(),
+                                    message: e,
+                                    permission_denied: true,
+                                });
+                            }
+                        }
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+}
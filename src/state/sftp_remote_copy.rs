@@ -0,0 +1,329 @@
+// SFTP 跨服务器复制方法：经由客户端把文件从当前服务器流式复制到另一台已连接的服务器
+
+use super::{SessionState, SessionStatus};
+use crate::components::sftp::{CopyTarget, CopyToServerDialogState};
+use crate::models::sftp::state::join_path;
+use crate::models::sftp::TransferItem;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::SftpService;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+/// 需要复制的单个文件（源路径, 目标路径, 大小）
+type CopyFile = (String, String, u64);
+
+impl SessionState {
+    /// 确保复制到其他服务器对话框已创建
+    pub fn ensure_sftp_copy_to_server_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<CopyToServerDialogState> {
+        if self.sftp_copy_to_server_dialog.is_none() {
+            self.sftp_copy_to_server_dialog = Some(cx.new(|_| CopyToServerDialogState::default()));
+        }
+        self.sftp_copy_to_server_dialog.clone().unwrap()
+    }
+
+    /// 获取复制到其他服务器对话框状态（如果存在）
+    pub fn get_sftp_copy_to_server_dialog(&self) -> Option<Entity<CopyToServerDialogState>> {
+        self.sftp_copy_to_server_dialog.clone()
+    }
+
+    /// 打开复制到其他服务器对话框
+    ///
+    /// 可选目标为其他已连接且 SFTP 可用的标签页
+    pub fn sftp_open_copy_to_server_dialog(
+        &mut self,
+        tab_id: &str,
+        path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let targets: Vec<CopyTarget> = {
+            let Ok(services) = self.sftp_services.lock() else {
+                error!("[SFTP] Failed to lock sftp_services");
+                return;
+            };
+            self.tabs
+                .iter()
+                .filter(|t| t.id != tab_id)
+                .filter(|t| matches!(t.status, SessionStatus::Connected))
+                .filter(|t| services.contains_key(&t.id))
+                .map(|t| CopyTarget {
+                    tab_id: t.id.clone(),
+                    label: t.server_label.clone(),
+                    current_path: t
+                        .sftp_state
+                        .as_ref()
+                        .map(|s| s.current_path.clone())
+                        .unwrap_or_else(|| "/".to_string()),
+                })
+                .collect()
+        };
+
+        if targets.is_empty() {
+            notify_key(
+                cx,
+                NotificationCategory::Transfer,
+                NotificationType::Warning,
+                "sftp.remote_copy.no_targets",
+            );
+            return;
+        }
+
+        let dialog = self.ensure_sftp_copy_to_server_dialog(cx);
+        dialog.update(cx, |d, cx| {
+            d.open(tab_id.to_string(), path, targets);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// 执行跨服务器复制
+    ///
+    /// 文件夹会在目标服务器上重建目录结构，每个文件作为一个传输项显示在源标签页的传输列表中
+    pub fn sftp_copy_to_server(
+        &mut self,
+        dialog: Entity<CopyToServerDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, source_path, target, target_dir) = {
+            let d = dialog.read(cx);
+            let Some(target) = d.selected_target().cloned() else {
+                return;
+            };
+            (
+                d.tab_id.clone(),
+                d.source_path.clone(),
+                target,
+                d.target_dir(cx),
+            )
+        };
+        dialog.update(cx, |d, cx| {
+            d.close();
+            cx.notify();
+        });
+
+        let (source, dest) = {
+            let Ok(services) = self.sftp_services.lock() else {
+                error!("[SFTP] Failed to lock sftp_services");
+                return;
+            };
+            match (services.get(&tab_id), services.get(&target.tab_id)) {
+                (Some(source), Some(dest)) => (source.clone(), dest.clone()),
+                _ => {
+                    error!(
+                        "[SFTP] SFTP service missing for copy {} -> {}",
+                        tab_id, target.tab_id
+                    );
+                    return;
+                }
+            }
+        };
+
+        info!(
+            "[SFTP] Copying {} to {}:{}",
+            source_path, target.label, target_dir
+        );
+
+        let runtime = crate::ssh::manager::SshManager::global().runtime();
+        let session_state = cx.entity().clone();
+
+        self.set_sidebar_panel(super::SidebarPanel::Transfer);
+        cx.notify();
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                // 1. 收集要复制的文件，并在目标服务器上建好目录
+                let (tx_files, mut rx_files) = tokio::sync::mpsc::unbounded_channel();
+                let source_for_list = source.clone();
+                let dest_for_list = dest.clone();
+                let source_path_for_list = source_path.clone();
+                let target_dir_for_list = target_dir.clone();
+                runtime.spawn(async move {
+                    let result = prepare_copy(
+                        &source_for_list,
+                        &dest_for_list,
+                        &source_path_for_list,
+                        &target_dir_for_list,
+                    )
+                    .await;
+                    let _ = tx_files.send(result);
+                });
+
+                let files = match rx_files.recv().await {
+                    Some(Ok(files)) => files,
+                    Some(Err(e)) => {
+                        error!("[SFTP] Failed to prepare copy: {}", e);
+                        let _ = async_cx.update(|cx| {
+                            notify_key(cx, NotificationCategory::Transfer, NotificationType::Error, "sftp.remote_copy.failed");
+                        });
+                        return;
+                    }
+                    None => return,
+                };
+
+                // 2. 逐个复制文件
+                let mut failed = 0usize;
+                for (from, to, size) in files {
+                    let transfer_item =
+                        TransferItem::new_remote_copy(from.clone(), target.label.clone(), to.clone(), size);
+                    let transfer_id = transfer_item.id.clone();
+                    let cancel_token = transfer_item.cancel_token.clone();
+                    let pause_flag = transfer_item.pause_flag.clone();
+
+                    let tab_id_for_add = tab_id.clone();
+                    let _ = async_cx.update(|cx| {
+                        session_state.update(cx, |state, cx| {
+                            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_for_add) {
+                                tab.active_transfers.push(transfer_item);
+                            }
+                            cx.notify();
+                        });
+                    });
+
+                    enum CopyEvent {
+                        Progress(u64, u64, u64),
+                        Complete(Result<(), String>),
+                    }
+                    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<CopyEvent>();
+                    let tx_progress = tx.clone();
+                    let source_for_copy = source.clone();
+                    let dest_for_copy = dest.clone();
+                    let cancel_for_copy = cancel_token.clone();
+                    let (from_for_copy, to_for_copy) = (from.clone(), to.clone());
+                    runtime.spawn(async move {
+                        let copy = source_for_copy.copy_file_to(
+                            &dest_for_copy,
+                            &from_for_copy,
+                            &to_for_copy,
+                            pause_flag,
+                            move |transferred, total, speed| {
+                                let _ = tx_progress.send(CopyEvent::Progress(transferred, total, speed));
+                            },
+                        );
+                        tokio::select! {
+                            _ = cancel_for_copy.cancelled() => {
+                                // 删除未复制完的目标文件
+                                let _ = dest_for_copy.remove_file(&to_for_copy).await;
+                            }
+                            result = copy => {
+                                let _ = tx.send(CopyEvent::Complete(result));
+                            }
+                        }
+                    });
+
+                    loop {
+                        tokio::select! {
+                            _ = cancel_token.cancelled() => {
+                                info!("[SFTP] Remote copy cancelled: {}", transfer_id);
+                                failed += 1;
+                                break;
+                            }
+                            event = rx.recv() => {
+                                let Some(event) = event else {
+                                    break;
+                                };
+                                let tab_id = tab_id.clone();
+                                let transfer_id = transfer_id.clone();
+                                let done = matches!(event, CopyEvent::Complete(_));
+                                let _ = async_cx.update(|cx| {
+                                    session_state.update(cx, |state, cx| {
+                                        let Some(transfer) = state
+                                            .tabs
+                                            .iter_mut()
+                                            .find(|t| t.id == tab_id)
+                                            .and_then(|tab| {
+                                                tab.active_transfers
+                                                    .iter_mut()
+                                                    .find(|t| t.id == transfer_id)
+                                            })
+                                        else {
+                                            return;
+                                        };
+                                        match &event {
+                                            CopyEvent::Progress(transferred, total, speed) => {
+                                                transfer.update_progress(*transferred, *total, *speed);
+                                            }
+                                            CopyEvent::Complete(Ok(())) => transfer.set_completed(),
+                                            CopyEvent::Complete(Err(e)) => {
+                                                error!("[SFTP] Remote copy failed: {}", e);
+                                                transfer.set_failed(e.clone());
+                                            }
+                                        }
+                                        cx.notify();
+                                    });
+                                });
+                                if let CopyEvent::Complete(Err(_)) = event {
+                                    failed += 1;
+                                }
+                                if done {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // 3. 刷新目标标签页并提示结果
+                info!(
+                    "[SFTP] Remote copy to {} finished, {} failed",
+                    target.label, failed
+                );
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        if let Some(sftp_state) = state
+                            .tabs
+                            .iter_mut()
+                            .find(|t| t.id == target.tab_id)
+                            .and_then(|t| t.sftp_state.as_mut())
+                        {
+                            sftp_state.invalidate_cache(&target_dir);
+                        }
+                        state.sftp_refresh(&target.tab_id, cx);
+                    });
+
+                    let (key, kind) = if failed == 0 {
+                        ("sftp.remote_copy.done", NotificationType::Success)
+                    } else {
+                        ("sftp.remote_copy.failed", NotificationType::Error)
+                    };
+                    notify_key(cx, NotificationCategory::Transfer, kind, key);
+                });
+            })
+            .detach();
+    }
+}
+
+/// 列出需要复制的文件；源为文件夹时在目标服务器上创建对应的目录结构
+async fn prepare_copy(
+    source: &SftpService,
+    dest: &SftpService,
+    source_path: &str,
+    target_dir: &str,
+) -> Result<Vec<CopyFile>, String> {
+    let entry = source.stat(source_path).await?;
+    let target_root = join_path(target_dir, &entry.name);
+
+    if !entry.is_dir() {
+        return Ok(vec![(entry.path, target_root, entry.size)]);
+    }
+
+    dest.mkdir_recursive(&target_root).await?;
+    let mut files = Vec::new();
+    for entry in source.read_dir_recursive(source_path).await? {
+        let relative = entry
+            .path
+            .strip_prefix(source_path)
+            .unwrap_or(&entry.path)
+            .trim_start_matches('/');
+        let target_path = join_path(&target_root, relative);
+        if entry.is_dir() {
+            dest.mkdir_recursive(&target_path).await?;
+        } else if entry.is_file() {
+            files.push((entry.path, target_path, entry.size));
+        }
+    }
+    Ok(files)
+}
@@ -0,0 +1,148 @@
+// SFTP 搜索方法：执行远程搜索并流式回传结果，跳转到结果位置
+
+use super::SessionState;
+use crate::models::sftp::state::get_parent_path;
+use crate::services::sftp::{SearchHit, SearchQuery, MAX_SEARCH_RESULTS};
+use gpui::prelude::*;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 搜索任务回传的消息
+enum SearchMessage {
+    Hit(SearchHit),
+    /// 搜索结束（Ok 中为结果是否被截断）
+    Done(Result<bool, String>),
+}
+
+impl SessionState {
+    /// 在远程执行搜索，结果按批次写入搜索状态
+    pub fn sftp_search(
+        &mut self,
+        tab_id: &str,
+        query: SearchQuery,
+        token: CancellationToken,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(search_state) = self.sftp_search_states.get(tab_id).cloned() else {
+            return;
+        };
+
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[SFTP] No SSH session for tab {}", tab_id);
+            search_state.update(cx, |s, cx| {
+                s.finish(Err("SSH session not found".to_string()), cx);
+            });
+            return;
+        };
+
+        let command = query.to_command();
+        info!("[SFTP] Searching in tab {}: {}", tab_id, command);
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<SearchMessage>();
+
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            let exec_channel = match session.open_exec().await {
+                Ok(ch) => ch,
+                Err(e) => {
+                    let _ = tx.send(SearchMessage::Done(Err(format!("{:?}", e))));
+                    return;
+                }
+            };
+
+            let mut count = 0;
+            let mut truncated = false;
+            let result = tokio::select! {
+                _ = token_for_task.cancelled() => {
+                    info!("[SFTP] Search cancelled");
+                    return;
+                }
+                result = exec_channel.exec_lines(&command, |line| {
+                    let Some(hit) = query.parse_line(line) else {
+                        return true;
+                    };
+                    count += 1;
+                    let _ = tx.send(SearchMessage::Hit(hit));
+                    // 达到上限后停止读取
+                    if count >= MAX_SEARCH_RESULTS {
+                        truncated = true;
+                        return false;
+                    }
+                    true
+                }) => result,
+            };
+
+            let _ = tx.send(SearchMessage::Done(
+                result.map(|_| truncated).map_err(|e| format!("{:?}", e)),
+            ));
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(message) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+
+                    // 合并已到达的结果，减少 UI 刷新次数
+                    let mut hits = Vec::new();
+                    let mut done = None;
+                    let mut next = Some(message);
+                    while let Some(message) = next.take() {
+                        match message {
+                            SearchMessage::Hit(hit) => hits.push(hit),
+                            SearchMessage::Done(result) => {
+                                done = Some(result);
+                                break;
+                            }
+                        }
+                        next = rx.try_recv().ok();
+                    }
+
+                    let finished = done.is_some();
+                    let _ = async_cx.update(|cx| {
+                        search_state.update(cx, |s, cx| {
+                            if !hits.is_empty() {
+                                s.append_results(hits, cx);
+                            }
+                            if let Some(result) = done {
+                                s.finish(result, cx);
+                            }
+                        });
+                    });
+                    if finished {
+                        break;
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// 跳转到搜索结果：打开所在目录并选中该文件
+    pub fn sftp_reveal_search_hit(
+        &mut self,
+        tab_id: &str,
+        hit: SearchHit,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        self.sftp_reveal_path(tab_id, hit.path, cx);
+    }
+
+    /// 在文件列表中显示：打开所在目录并选中该项
+    pub fn sftp_reveal_path(&mut self, tab_id: &str, path: String, cx: &mut gpui::Context<Self>) {
+        info!("[SFTP] Reveal path: {}", path);
+        let parent = get_parent_path(&path);
+        self.sftp_navigate_to(tab_id, parent, cx);
+
+        if let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        {
+            sftp_state.request_reveal(path);
+        }
+        cx.notify();
+    }
+}
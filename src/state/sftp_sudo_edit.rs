@@ -0,0 +1,185 @@
+// SFTP 以管理员身份编辑：验证 sudo 权限（必要时弹窗输入密码），再以 root 身份打开外置编辑器
+
+use super::SessionState;
+use crate::components::sftp::SudoPasswordDialogState;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::sudo_check;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info};
+
+impl SessionState {
+    /// 确保 sudo 密码对话框已创建
+    pub fn ensure_sftp_sudo_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<SudoPasswordDialogState> {
+        if self.sftp_sudo_dialog.is_none() {
+            self.sftp_sudo_dialog = Some(cx.new(|_| SudoPasswordDialogState::default()));
+        }
+        self.sftp_sudo_dialog.clone().unwrap()
+    }
+
+    /// 获取 sudo 密码对话框状态（如果存在）
+    pub fn get_sftp_sudo_dialog(&self) -> Option<Entity<SudoPasswordDialogState>> {
+        self.sftp_sudo_dialog.clone()
+    }
+
+    /// 以管理员身份编辑文件
+    ///
+    /// 本标签页已验证过 sudo 时直接打开；否则先检查是否免密，需要密码时弹出密码对话框
+    pub fn sftp_edit_file_as_root(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        info!(
+            "[Editor] Edit file as root: {} for tab {}",
+            remote_path, tab_id
+        );
+
+        if let Some(password) = self.sftp_sudo_passwords.get(tab_id).cloned() {
+            self.sftp_edit_file_with(tab_id, remote_path, Some(password), cx);
+            return;
+        }
+
+        let Some(session) = crate::ssh::manager::SshManager::global().get_session(tab_id) else {
+            error!("[Editor] No SSH session found for tab {}", tab_id);
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<bool>();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(sudo_check(&session, None).await.is_ok());
+            });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(passwordless) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        if passwordless {
+                            // 免密 sudo 以空密码记录
+                            state
+                                .sftp_sudo_passwords
+                                .insert(tab_id.clone(), String::new());
+                            state.sftp_edit_file_with(
+                                &tab_id,
+                                remote_path,
+                                Some(String::new()),
+                                cx,
+                            );
+                        } else {
+                            let dialog = state.ensure_sftp_sudo_dialog(cx);
+                            state.sudo_pending_service_actions.remove(&tab_id);
+                            state.sudo_pending_cron_loads.remove(&tab_id);
+                            state.sudo_pending_package_actions.remove(&tab_id);
+                            state.sudo_pending_firewall_actions.remove(&tab_id);
+                            state.sudo_pending_sftp_lists.remove(&tab_id);
+                            dialog.update(cx, |d, _| d.open(remote_path, tab_id));
+                            cx.notify();
+                        }
+                    });
+                });
+            })
+            .detach();
+    }
+
+    /// 验证对话框中输入的 sudo 密码，通过后记住密码，并继续等待中的服务操作、读取 /etc/cron.d、软件包操作、防火墙操作、以 sudo 列出目录或以管理员身份打开文件
+    pub fn sftp_confirm_sudo_password(
+        &mut self,
+        tab_id: String,
+        remote_path: String,
+        password: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(dialog) = self.sftp_sudo_dialog.clone() else {
+            return;
+        };
+        let Some(session) = crate::ssh::manager::SshManager::global().get_session(&tab_id) else {
+            notify_key(
+                cx,
+                NotificationCategory::Transfer,
+                NotificationType::Error,
+                "sftp.sudo.auth_failed",
+            );
+            dialog.update(cx, |d, _| d.close());
+            return;
+        };
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        let password_for_check = password.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                let _ = tx.send(sudo_check(&session, Some(&password_for_check)).await);
+            });
+
+        let session_state = cx.entity().clone();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        match result {
+                            Ok(()) => {
+                                info!("[Editor] sudo password verified for tab {}", tab_id);
+                                dialog.update(cx, |d, _| d.close());
+                                state
+                                    .sftp_sudo_passwords
+                                    .insert(tab_id.clone(), password.clone());
+                                if let Some(event) =
+                                    state.sudo_pending_service_actions.remove(&tab_id)
+                                {
+                                    state.service_action(&tab_id, event, cx);
+                                } else if state.sudo_pending_cron_loads.remove(&tab_id) {
+                                    state.cron_load_system(&tab_id, cx);
+                                } else if let Some((manager, action)) =
+                                    state.sudo_pending_package_actions.remove(&tab_id)
+                                {
+                                    state.packages_resume(&tab_id, manager, action, cx);
+                                } else if let Some(event) =
+                                    state.sudo_pending_firewall_actions.remove(&tab_id)
+                                {
+                                    state.firewall_resume(&tab_id, event, cx);
+                                } else if state.sudo_pending_sftp_lists.remove(&tab_id) {
+                                    state.sftp_sudo_list(&tab_id, cx);
+                                } else {
+                                    state.sftp_edit_file_with(
+                                        &tab_id,
+                                        remote_path,
+                                        Some(password),
+                                        cx,
+                                    );
+                                }
+                            }
+                            Err(e) => {
+                                error!("[Editor] sudo verification failed: {}", e);
+                                let lang = crate::services::storage::load_settings()
+                                    .map(|s| s.theme.language)
+                                    .unwrap_or_default();
+                                let message = format!(
+                                    "{}: {}",
+                                    crate::i18n::t(&lang, "sftp.sudo.auth_failed"),
+                                    e
+                                );
+                                dialog.update(cx, |d, _| d.set_error(message));
+                            }
+                        }
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+}
@@ -0,0 +1,531 @@
+// SFTP 目录同步方法：比较本地与远程目录，按同步计划通过传输队列执行
+
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use super::SessionState;
+use crate::components::sftp::SyncDialogState;
+use crate::models::sftp::state::{get_parent_path, join_path};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::{
+    build_sync_plan, collect_local_files, local_checksum, local_sync_path, parse_checksum_line,
+    remote_checksum_command, SftpService, SyncAction, SyncCompareMode, SyncItem, SyncMode,
+};
+use crate::ssh::session::SshSession;
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::{error, info, warn};
+
+/// 传输状态检查间隔
+const SYNC_POLL_INTERVAL: Duration = Duration::from_millis(300);
+/// 提交后迟迟没有出现在传输列表中的传输视为失败
+const SYNC_START_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 同步计划中需要传输的文件
+struct SyncTransfer {
+    is_upload: bool,
+    remote_path: String,
+    local_path: PathBuf,
+    size: u64,
+    /// 源文件的修改时间，传输完成后写回目标文件
+    modified: Option<SystemTime>,
+}
+
+/// 已提交到传输队列的文件
+struct RunningTransfer {
+    transfer: SyncTransfer,
+    /// 对应的传输项 ID（出现在传输列表后才确定）
+    transfer_id: Option<String>,
+    submitted_at: Instant,
+}
+
+impl SessionState {
+    /// 确保目录同步对话框已创建
+    pub fn ensure_sftp_sync_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<SyncDialogState> {
+        if self.sftp_sync_dialog.is_none() {
+            self.sftp_sync_dialog = Some(cx.new(|_| SyncDialogState::default()));
+        }
+        self.sftp_sync_dialog.clone().unwrap()
+    }
+
+    /// 获取目录同步对话框状态（如果存在）
+    pub fn get_sftp_sync_dialog(&self) -> Option<Entity<SyncDialogState>> {
+        self.sftp_sync_dialog.clone()
+    }
+
+    /// 打开目录同步对话框
+    ///
+    /// 本地目录优先使用双面板的当前目录，其次为默认下载路径和用户主目录
+    pub fn sftp_open_sync_dialog(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let local_path = self
+            .sftp_local_panels
+            .get(tab_id)
+            .map(|panel| panel.read(cx).current_path().to_path_buf())
+            .or_else(|| {
+                crate::services::storage::load_settings()
+                    .ok()
+                    .map(|s| PathBuf::from(s.sftp.local_default_path))
+                    .filter(|p| !p.as_os_str().is_empty() && p.is_dir())
+            })
+            .or_else(dirs::home_dir)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let dialog = self.ensure_sftp_sync_dialog(cx);
+        dialog.update(cx, |s, _| {
+            s.open(tab_id.to_string(), local_path, remote_path);
+        });
+        cx.notify();
+    }
+
+    /// 比较本地与远程目录，生成同步计划并进入预览
+    pub fn sftp_sync_compare(
+        &mut self,
+        dialog: Entity<SyncDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, local_root, remote_root, mode, compare, token) = dialog.update(cx, |d, cx| {
+            let token = d.start_comparing();
+            cx.notify();
+            (
+                d.tab_id.clone(),
+                PathBuf::from(d.local_path(cx)),
+                d.remote_path(cx),
+                d.mode,
+                d.compare,
+                token,
+            )
+        });
+
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|services| services.get(&tab_id).cloned());
+        let Some(service) = service else {
+            error!("[SFTP] No SFTP service for tab {}", tab_id);
+            dialog.update(cx, |d, cx| {
+                d.set_error("SFTP service not found".to_string());
+                cx.notify();
+            });
+            return;
+        };
+
+        info!(
+            "[SFTP] Comparing {:?} with {} ({:?}, {:?}) for tab {}",
+            local_root, remote_root, mode, compare, tab_id
+        );
+
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let session = ssh_manager.get_session(&tab_id);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            let result = tokio::select! {
+                _ = token_for_task.cancelled() => return,
+                result = compare_dirs(
+                    &service, session, &local_root, &remote_root, mode, compare,
+                ) => result,
+            };
+            let _ = tx.send(result);
+        });
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(result) = rx.recv().await else {
+                    return;
+                };
+                if token.is_cancelled() {
+                    return;
+                }
+                let _ = async_cx.update(|cx| {
+                    dialog.update(cx, |d, cx| {
+                        match result {
+                            Ok((items, identical_count)) => {
+                                info!(
+                                    "[SFTP] Sync plan: {} differences, {} identical",
+                                    items.len(),
+                                    identical_count
+                                );
+                                d.set_plan(items, identical_count);
+                            }
+                            Err(e) => {
+                                error!("[SFTP] Sync compare failed: {}", e);
+                                d.set_error(e);
+                            }
+                        }
+                        cx.notify();
+                    });
+                });
+            })
+            .detach();
+    }
+
+    /// 执行同步计划：先完成删除和目录创建，再按并发数把文件提交到传输队列
+    pub fn sftp_execute_sync(
+        &mut self,
+        dialog: Entity<SyncDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, local_root, remote_root, items) = dialog.update(cx, |d, cx| {
+            let plan = (
+                d.tab_id.clone(),
+                PathBuf::from(d.local_path(cx)),
+                d.remote_path(cx),
+                std::mem::take(&mut d.items),
+            );
+            d.close();
+            cx.notify();
+            plan
+        });
+
+        let service = self
+            .sftp_services
+            .lock()
+            .ok()
+            .and_then(|services| services.get(&tab_id).cloned());
+        let Some(service) = service else {
+            error!("[SFTP] No SFTP service for tab {}", tab_id);
+            return;
+        };
+
+        let mut transfers = VecDeque::new();
+        let mut remote_deletes = Vec::new();
+        let mut local_deletes = Vec::new();
+        for item in items {
+            let remote_path = join_path(&remote_root, &item.relative_path);
+            let local_path = local_sync_path(&local_root, &item.relative_path);
+            match (item.action, item.local, item.remote) {
+                (SyncAction::Upload, Some(local), _) => transfers.push_back(SyncTransfer {
+                    is_upload: true,
+                    remote_path,
+                    local_path,
+                    size: local.size,
+                    modified: local.modified,
+                }),
+                (SyncAction::Download, _, Some(remote)) => transfers.push_back(SyncTransfer {
+                    is_upload: false,
+                    remote_path,
+                    local_path,
+                    size: remote.size,
+                    modified: remote.modified,
+                }),
+                (SyncAction::DeleteLocal, Some(_), _) => local_deletes.push(local_path),
+                (SyncAction::DeleteRemote, _, Some(_)) => remote_deletes.push(remote_path),
+                _ => {}
+            }
+        }
+
+        info!(
+            "[SFTP] Executing sync for tab {}: {} transfers, {} local deletes, {} remote deletes",
+            tab_id,
+            transfers.len(),
+            local_deletes.len(),
+            remote_deletes.len()
+        );
+
+        let known = self.transfer_ids(&tab_id);
+        let session_state = cx.entity().clone();
+        let limit = crate::services::storage::load_settings()
+            .map(|s| s.sftp.concurrent_transfers as usize)
+            .unwrap_or(3)
+            .max(1);
+
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                // 本地：删除多余文件，创建下载目标目录
+                let mut failed = 0;
+                for path in &local_deletes {
+                    if let Err(e) = std::fs::remove_file(path) {
+                        error!("[SFTP] Sync failed to delete {:?}: {}", path, e);
+                        failed += 1;
+                    }
+                }
+                let local_dirs: BTreeSet<PathBuf> = transfers
+                    .iter()
+                    .filter(|t| !t.is_upload)
+                    .filter_map(|t| t.local_path.parent().map(Path::to_path_buf))
+                    .collect();
+                for dir in &local_dirs {
+                    if let Err(e) = std::fs::create_dir_all(dir) {
+                        error!("[SFTP] Sync failed to create {:?}: {}", dir, e);
+                    }
+                }
+
+                // 远程：删除多余文件，创建上传目标目录
+                let remote_dirs: BTreeSet<String> = transfers
+                    .iter()
+                    .filter(|t| t.is_upload)
+                    .map(|t| get_parent_path(&t.remote_path))
+                    .collect();
+                let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+                let service_for_prepare = service.clone();
+                crate::ssh::manager::SshManager::global()
+                    .runtime()
+                    .spawn(async move {
+                        let mut failed = 0;
+                        for path in &remote_deletes {
+                            if let Err(e) = service_for_prepare.remove_file(path).await {
+                                error!("[SFTP] Sync failed to delete {}: {}", path, e);
+                                failed += 1;
+                            }
+                        }
+                        for dir in &remote_dirs {
+                            if let Err(e) = service_for_prepare.mkdir_recursive(dir).await {
+                                error!("[SFTP] Sync failed to create {}: {}", dir, e);
+                            }
+                        }
+                        let _ = tx.send(failed);
+                    });
+                failed += rx.recv().await.unwrap_or(0);
+
+                // 按并发数提交传输，完成后写回修改时间
+                let mut pending = transfers;
+                let mut running: Vec<RunningTransfer> = Vec::new();
+                let mut claimed = known;
+                while !pending.is_empty() || !running.is_empty() {
+                    while running.len() < limit {
+                        let Some(transfer) = pending.pop_front() else {
+                            break;
+                        };
+                        let submitted = async_cx.update(|cx| {
+                            session_state.update(cx, |state, cx| {
+                                submit_sync_transfer(state, &tab_id, &transfer, cx);
+                            });
+                        });
+                        if submitted.is_err() {
+                            return;
+                        }
+                        running.push(RunningTransfer {
+                            transfer,
+                            transfer_id: None,
+                            submitted_at: Instant::now(),
+                        });
+                    }
+
+                    async_cx
+                        .background_executor()
+                        .timer(SYNC_POLL_INTERVAL)
+                        .await;
+
+                    // 查询每个已提交文件的传输状态：None 为进行中，Some 为是否成功
+                    let statuses = async_cx.update(|cx| {
+                        let state = session_state.read(cx);
+                        let tab = state.tabs.iter().find(|t| t.id == tab_id)?;
+                        Some(
+                            running
+                                .iter_mut()
+                                .map(|r| {
+                                    if r.transfer_id.is_none() {
+                                        r.transfer_id = tab
+                                            .active_transfers
+                                            .iter()
+                                            .find(|tr| {
+                                                !claimed.contains(&tr.id)
+                                                    && tr.is_upload == r.transfer.is_upload
+                                                    && tr.remote_path == r.transfer.remote_path
+                                            })
+                                            .map(|tr| tr.id.clone());
+                                        if let Some(id) = &r.transfer_id {
+                                            claimed.insert(id.clone());
+                                        }
+                                    }
+                                    match &r.transfer_id {
+                                        Some(id) => tab
+                                            .active_transfers
+                                            .iter()
+                                            .find(|tr| &tr.id == id)
+                                            .map_or(Some(false), |tr| {
+                                                if tr.status.is_complete() {
+                                                    Some(true)
+                                                } else if tr.status.is_error() {
+                                                    Some(false)
+                                                } else {
+                                                    None
+                                                }
+                                            }),
+                                        None if r.submitted_at.elapsed() >= SYNC_START_TIMEOUT => {
+                                            Some(false)
+                                        }
+                                        None => None,
+                                    }
+                                })
+                                .collect::<Vec<_>>(),
+                        )
+                    });
+                    // 标签已关闭
+                    let Ok(Some(statuses)) = statuses else {
+                        return;
+                    };
+
+                    let mut still_running = Vec::new();
+                    for (r, status) in running.into_iter().zip(statuses) {
+                        match status {
+                            None => still_running.push(r),
+                            Some(true) => preserve_mtime(&service, &r.transfer),
+                            Some(false) => failed += 1,
+                        }
+                    }
+                    running = still_running;
+                }
+
+                info!("[SFTP] Sync finished for tab {}, {} failed", tab_id, failed);
+                let _ = async_cx.update(|cx| {
+                    session_state.update(cx, |state, cx| {
+                        if let Some(panel) = state.sftp_local_panels.get(&tab_id).cloned() {
+                            panel.update(cx, |panel, cx| panel.refresh(cx));
+                        }
+                        if let Some(sftp_state) = state
+                            .tabs
+                            .iter_mut()
+                            .find(|t| t.id == tab_id)
+                            .and_then(|t| t.sftp_state.as_mut())
+                        {
+                            sftp_state.invalidate_cache(&remote_root);
+                        }
+                        state.sftp_refresh(&tab_id, cx);
+                    });
+
+                    let (key, kind) = if failed == 0 {
+                        ("sftp.sync.done", NotificationType::Success)
+                    } else {
+                        ("sftp.sync.partial", NotificationType::Warning)
+                    };
+                    notify_key(cx, NotificationCategory::Transfer, kind, key);
+                });
+            })
+            .detach();
+    }
+}
+
+/// 比较本地与远程目录，返回同步计划和内容相同的文件数
+async fn compare_dirs(
+    service: &SftpService,
+    session: Option<Arc<SshSession>>,
+    local_root: &Path,
+    remote_root: &str,
+    mode: SyncMode,
+    compare: SyncCompareMode,
+) -> Result<(Vec<SyncItem>, usize), String> {
+    let local = collect_local_files(local_root).map_err(|e| e.to_string())?;
+    let remote = service.collect_sync_files(remote_root).await?;
+
+    // 校验和模式：只需比较两侧都存在且大小相同的文件
+    let mut checksums = BTreeMap::new();
+    if compare == SyncCompareMode::Checksum {
+        let candidates: Vec<&String> = local
+            .iter()
+            .filter(|(path, l)| remote.get(*path).is_some_and(|r| r.size == l.size))
+            .map(|(path, _)| path)
+            .collect();
+        if !candidates.is_empty() {
+            let session = session.ok_or_else(|| "SSH session not found".to_string())?;
+            let exec = session.open_exec().await.map_err(|e| format!("{:?}", e))?;
+            let mut remote_hashes = BTreeMap::new();
+            exec.exec_lines(&remote_checksum_command(remote_root), |line| {
+                if let Some((path, hash)) = parse_checksum_line(line) {
+                    remote_hashes.insert(path, hash);
+                }
+                true
+            })
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+
+            for path in candidates {
+                let Some(remote_hash) = remote_hashes.remove(path) else {
+                    continue;
+                };
+                let local_hash = local_checksum(&local_sync_path(local_root, path))
+                    .map_err(|e| e.to_string())?;
+                checksums.insert(path.clone(), (local_hash, remote_hash));
+            }
+        }
+    }
+
+    let total = local
+        .keys()
+        .chain(remote.keys())
+        .collect::<HashSet<_>>()
+        .len();
+    let items = build_sync_plan(&local, &remote, mode, compare, &checksums);
+    let identical_count = total - items.len();
+    Ok((items, identical_count))
+}
+
+/// 将单个文件提交到传输队列
+fn submit_sync_transfer(
+    state: &mut SessionState,
+    tab_id: &str,
+    transfer: &SyncTransfer,
+    cx: &mut gpui::Context<SessionState>,
+) {
+    if transfer.is_upload {
+        state.sftp_upload_file_direct(
+            tab_id,
+            transfer.local_path.clone(),
+            get_parent_path(&transfer.remote_path),
+            false,
+            cx,
+        );
+    } else {
+        let file_name = transfer
+            .remote_path
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        state.sftp_download_file_to(
+            tab_id,
+            transfer.remote_path.clone(),
+            file_name,
+            transfer.size,
+            transfer.local_path.parent().map(Path::to_path_buf),
+            false,
+            cx,
+        );
+    }
+}
+
+/// 将源文件的修改时间写回目标文件，使下次按大小和时间比较时视为相同
+fn preserve_mtime(service: &SftpService, transfer: &SyncTransfer) {
+    let Some(modified) = transfer.modified else {
+        return;
+    };
+    if transfer.is_upload {
+        let Ok(secs) = modified.duration_since(UNIX_EPOCH) else {
+            return;
+        };
+        let service = service.clone();
+        let remote_path = transfer.remote_path.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                if let Err(e) = service.set_mtime(&remote_path, secs.as_secs() as u32).await {
+                    warn!("[SFTP] Sync could not preserve mtime: {}", e);
+                }
+            });
+    } else {
+        let result = std::fs::File::options()
+            .write(true)
+            .open(&transfer.local_path)
+            .and_then(|file| file.set_modified(modified));
+        if let Err(e) = result {
+            warn!(
+                "[SFTP] Sync could not preserve mtime of {:?}: {}",
+                transfer.local_path, e
+            );
+        }
+    }
+}
@@ -2,10 +2,21 @@
 //!
 //! This module contains methods for downloading, uploading files, and managing transfer state.
 
+use super::runbook::t;
+use super::sftp_folder::run_folder_transfer;
+use super::sftp_queue::queue_transfer;
 use super::{NewFileDialogState, NewFolderDialogState, PropertiesDialogState, SessionState};
+use crate::models::sftp::state::get_parent_path;
+use crate::models::sftp::{TransferHistoryEntry, TransferItem, TransferOptions};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::ArchiveFormat;
+use crate::services::transfer::{Throttle, TransferHistoryStore};
+use crate::ssh::session::SshSession;
 use gpui::prelude::*;
 use gpui::Entity;
-use tracing::{error, info};
+use gpui_component::notification::NotificationType;
+use std::sync::Arc;
+use tracing::{error, info, warn};
 
 impl SessionState {
     /// 下载文件到本地
@@ -18,6 +29,24 @@ impl SessionState {
         file_name: String,
         file_size: u64,
         cx: &mut gpui::Context<Self>,
+    ) {
+        self.sftp_download_file_to(tab_id, remote_path, file_name, file_size, None, true, cx);
+    }
+
+    /// 下载文件到指定本地目录
+    ///
+    /// `local_dir` 为空时使用默认下载路径或系统文件选择器；
+    /// `check_conflict` 为 true 时，本地已有同名文件会按冲突策略处理
+    #[allow(clippy::too_many_arguments)]
+    pub fn sftp_download_file_to(
+        &mut self,
+        tab_id: &str,
+        remote_path: String,
+        file_name: String,
+        file_size: u64,
+        local_dir: Option<std::path::PathBuf>,
+        check_conflict: bool,
+        cx: &mut gpui::Context<Self>,
     ) {
         info!(
             "[SFTP] Download file: {} ({} bytes) for tab {}",
@@ -27,6 +56,7 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
+        let server_key = self.transfer_server_key(tab_id);
 
         // 尝试获取 SFTP 服务
         let service = {
@@ -56,12 +86,16 @@ impl SessionState {
             .unwrap_or_default();
 
         let file_name_clone = file_name.clone();
+        // 通过系统保存对话框选择路径时，覆盖确认已由系统完成
+        let from_picker = local_dir.is_none() && default_path.is_empty();
 
         // 使用 GPUI 异步上下文执行文件选择和下载
         cx.to_async()
             .spawn(async move |async_cx| {
-                // 确定保存路径：优先使用默认路径，否则打开文件选择器
-                let local_path = if !default_path.is_empty() {
+                // 确定保存路径：优先使用指定目录和默认路径，否则打开文件选择器
+                let local_path = if let Some(dir) = local_dir {
+                    dir.join(&file_name_clone)
+                } else if !default_path.is_empty() {
                     // 使用默认下载路径 + 文件名
                     let path = std::path::PathBuf::from(&default_path).join(&file_name_clone);
                     info!("[SFTP] Using default download path: {:?}", path);
@@ -69,7 +103,7 @@ impl SessionState {
                 } else {
                     // 打开系统文件保存对话框
                     let file_picker = rfd::AsyncFileDialog::new()
-                        .set_title("保存文件")
+                        .set_title(t("sftp.pick.save_file"))
                         .set_file_name(&file_name_clone);
 
                     let save_handle = file_picker.save_file().await;
@@ -82,6 +116,31 @@ impl SessionState {
                     file_handle.path().to_path_buf()
                 };
 
+                // 检查本地是否已有同名文件（冲突对话框中可调整传输选项）
+                let mut options = transfer_options();
+                let local_path = if check_conflict && !from_picker {
+                    match super::sftp_conflict::resolve_download_target(
+                        async_cx,
+                        &session_state,
+                        &service,
+                        &tab_id_owned,
+                        &remote_path,
+                        local_path,
+                        &mut None,
+                        Some(&mut options),
+                    )
+                    .await
+                    {
+                        Some(path) => path,
+                        None => {
+                            info!("[SFTP] Download skipped: {}", remote_path);
+                            return;
+                        }
+                    }
+                } else {
+                    local_path
+                };
+
                 info!("[SFTP] Downloading to: {:?}", local_path);
 
                 // 创建传输项并添加到列表
@@ -96,6 +155,11 @@ impl SessionState {
                 let cancel_token = transfer_item.cancel_token.clone();
                 // 克隆暂停标志以便在下载任务中使用
                 let pause_flag = transfer_item.pause_flag.clone();
+                let throttle = Throttle::new(
+                    false,
+                    transfer_item.speed_limit.clone(),
+                    cancel_token.clone(),
+                );
 
                 // 添加传输项到列表，同时自动切换到传输面板
                 let tab_id_for_transfer = tab_id_owned.clone();
@@ -131,12 +195,47 @@ impl SessionState {
                     .map(|s| s.sftp.concurrent_transfers as usize)
                     .unwrap_or(3);
 
+                // 是否断点续传、是否校验完整性
+                let resume = resume_transfers_enabled();
+                let verify = verify_checksum_enabled();
+
                 // 克隆取消令牌用于下载任务内部
                 let cancel_token_for_download = cancel_token.clone();
+                let transfer_id_for_queue = transfer_id_clone.clone();
 
                 runtime.spawn(async move {
+                    // 在传输队列中排队，轮到该传输时才开始
+                    let Some(_permit) = queue_transfer(
+                        &transfer_id_for_queue,
+                        &server_key,
+                        &cancel_token_for_download,
+                    )
+                    .await
+                    else {
+                        return;
+                    };
+
                     let result =
-                        if file_size >= MULTI_CHANNEL_THRESHOLD && concurrent_transfers > 1 {
+                        if let Some(ssh_session) = gzip_session(&tab_id_for_download, options).await {
+                            // 压缩传输（不支持断点续传与多通道）
+                            let tx_progress_clone = tx_progress.clone();
+                            crate::services::sftp::download_file_gzip(
+                                &ssh_session,
+                                &remote_path_clone,
+                                &local_path_clone,
+                                file_size,
+                                &cancel_token_for_download,
+                                &throttle,
+                                move |transferred, total, speed| {
+                                    let _ = tx_progress_clone
+                                        .send(DownloadEvent::Progress(transferred, total, speed));
+                                },
+                            )
+                            .await
+                        } else if file_size >= MULTI_CHANNEL_THRESHOLD
+                            && concurrent_transfers > 1
+                            && !service.is_scp_fallback()
+                        {
                             // 使用多通道下载
                             info!(
                             "[SFTP] Using multi-channel download ({} channels) for {} ({} bytes)",
@@ -151,7 +250,9 @@ impl SessionState {
                                     ssh_session,
                                     tab_id_for_download.clone(),
                                     concurrent_transfers,
-                                );
+                                )
+                                .with_resume(resume)
+                                .with_throttle(throttle);
 
                                 let tx_progress_clone = tx_progress.clone();
                                 downloader
@@ -177,6 +278,9 @@ impl SessionState {
                                 .download_file(
                                     &remote_path_clone,
                                     &local_path_clone,
+                                    resume,
+                                    &cancel_token_for_download,
+                                    &throttle,
                                     move |transferred, total, speed| {
                                         let _ = tx_progress.send(DownloadEvent::Progress(
                                             transferred,
@@ -187,6 +291,15 @@ impl SessionState {
                                 )
                                 .await
                         };
+                    let result = verify_transfer(
+                        &tab_id_for_download,
+                        &remote_path_clone,
+                        &local_path_clone,
+                        result,
+                        verify,
+                    )
+                    .await;
+                    let result = extract_download(&local_path_clone, result, options.extract).await;
 
                     let _ = tx.send(DownloadEvent::Complete(result));
                 });
@@ -197,8 +310,6 @@ impl SessionState {
                         // 监听取消信号
                         _ = cancel_token.cancelled() => {
                             info!("[SFTP] Download cancelled by user: {}", transfer_id_clone);
-                            // 删除未完成的文件（使用 std::fs 因为不在 tokio 运行时中）
-                            let _ = std::fs::remove_file(&local_path);
                             // 更新状态
                             let transfer_id = transfer_id_clone.clone();
                             let tab_id = tab_id_owned.clone();
@@ -211,7 +322,7 @@ impl SessionState {
                                             .find(|t| t.id == transfer_id)
                                         {
                                             transfer.status = crate::models::sftp::TransferStatus::Cancelled;
-                                            transfer.error = Some("用户取消".to_string());
+                                            transfer.error = Some(t("transfer.cancelled_by_user"));
                                         }
                                     }
                                     cx.notify();
@@ -257,6 +368,7 @@ impl SessionState {
                                                     match &result {
                                                         Ok(()) => {
                                                             transfer.set_completed();
+                                                            transfer.mark_verified(verify);
                                                             info!(
                                                                 "[SFTP] Download completed: {:?}",
                                                                 local_path
@@ -264,43 +376,26 @@ impl SessionState {
                                                         }
                                                         Err(e) => {
                                                             transfer.set_failed(e.clone());
+                                                            transfer.mark_resumable(resume);
                                                             error!("[SFTP] Download failed: {}", e);
                                                         }
                                                     }
+                                                    record_history(
+                                                        &tab.server_id,
+                                                        &tab.server_label,
+                                                        transfer,
+                                                    );
                                                 }
                                             }
                                             cx.notify();
                                         });
                                         
                                         // 推送通知
-                                        if let Some(window) = cx.active_window() {
-                                            use gpui::AppContext as _;
-                                            let _ = cx.update_window(window, |_, window, cx| {
-                                                use gpui::Styled;
-                                                use gpui_component::notification::{
-                                                    Notification, NotificationType,
-                                                };
-                                                use gpui_component::WindowExt;
-
-                                                let lang = crate::services::storage::load_settings()
-                                                    .map(|s| s.theme.language)
-                                                    .unwrap_or_default();
-
-                                                let notification = match result_clone {
-                                                    Ok(()) => Notification::new()
-                                                        .message(crate::i18n::t(&lang, "sftp.download.success"))
-                                                        .with_type(NotificationType::Success)
-                                                        .w_48()
-                                                        .py_2(),
-                                                    Err(_) => Notification::new()
-                                                        .message(crate::i18n::t(&lang, "sftp.download.failed"))
-                                                        .with_type(NotificationType::Error)
-                                                        .w_48()
-                                                        .py_2(),
-                                                };
-                                                window.push_notification(notification, cx);
-                                            });
-                                        }
+                                        let (key, kind) = match result_clone {
+                                            Ok(()) => ("sftp.download.success", NotificationType::Success),
+                                            Err(_) => ("sftp.download.failed", NotificationType::Error),
+                                        };
+                                        notify_key(cx, NotificationCategory::Transfer, kind, key);
                                     });
                                     break;
                                 }
@@ -333,6 +428,7 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
+        let server_key = self.transfer_server_key(tab_id);
 
         // 尝试获取 SFTP 服务
         let service = {
@@ -361,7 +457,7 @@ impl SessionState {
             .spawn(async move |async_cx| {
                 // 打开系统文件选择对话框
                 let file_picker = rfd::AsyncFileDialog::new()
-                    .set_title("选择要上传的文件");
+                    .set_title(t("sftp.pick.upload_files"));
 
                 let file_handle = file_picker.pick_file().await;
 
@@ -392,6 +488,24 @@ impl SessionState {
                     format!("{}/{}", remote_dir.trim_end_matches('/'), file_name)
                 };
 
+                // 检查远程是否已有同名文件（冲突对话框中可调整传输选项）
+                let mut options = transfer_options();
+                let Some(remote_path) = super::sftp_conflict::resolve_upload_target(
+                    async_cx,
+                    &session_state,
+                    &service,
+                    &tab_id_owned,
+                    &local_path,
+                    remote_path,
+                    &mut None,
+                    Some(&mut options),
+                )
+                .await
+                else {
+                    info!("[SFTP] Upload skipped: {:?}", local_path);
+                    return;
+                };
+
                 info!("[SFTP] Uploading {:?} to {}", local_path, remote_path);
 
                 // 创建传输项并添加到列表
@@ -404,6 +518,11 @@ impl SessionState {
                 let cancel_token = transfer_item.cancel_token.clone();
                 // 克隆暂停标志以便在上传任务中使用（必须在 push 之前克隆）
                 let pause_flag = transfer_item.pause_flag.clone();
+                let throttle = Throttle::new(
+                    true,
+                    transfer_item.speed_limit.clone(),
+                    cancel_token.clone(),
+                );
 
                 // 添加传输项到列表，同时自动切换到传输面板
                 let tab_id_for_transfer = tab_id_owned.clone();
@@ -439,12 +558,47 @@ impl SessionState {
                     .map(|s| s.sftp.concurrent_transfers as usize)
                     .unwrap_or(3);
 
+                // 是否断点续传、是否校验完整性
+                let resume = resume_transfers_enabled();
+                let verify = verify_checksum_enabled();
+
                 // 克隆取消令牌用于上传任务内部
                 let cancel_token_for_upload = cancel_token.clone();
+                let transfer_id_for_queue = transfer_id_clone.clone();
 
                 runtime.spawn(async move {
+                    // 在传输队列中排队，轮到该传输时才开始
+                    let Some(_permit) = queue_transfer(
+                        &transfer_id_for_queue,
+                        &server_key,
+                        &cancel_token_for_upload,
+                    )
+                    .await
+                    else {
+                        return;
+                    };
+
                     let result =
-                        if file_size >= MULTI_CHANNEL_THRESHOLD && concurrent_transfers > 1 {
+                        if let Some(ssh_session) = gzip_session(&tab_id_for_upload, options).await {
+                            // 压缩传输（不支持断点续传与多通道）
+                            let tx_progress_clone = tx_progress.clone();
+                            service
+                                .upload_file_gzip(
+                                    &ssh_session,
+                                    &local_path_clone,
+                                    &remote_path_clone,
+                                    &cancel_token_for_upload,
+                                    &throttle,
+                                    move |transferred, total, speed| {
+                                        let _ = tx_progress_clone
+                                            .send(UploadEvent::Progress(transferred, total, speed));
+                                    },
+                                )
+                                .await
+                        } else if file_size >= MULTI_CHANNEL_THRESHOLD
+                            && concurrent_transfers > 1
+                            && !service.is_scp_fallback()
+                        {
                             // 使用多通道上传
                             info!(
                                 "[SFTP] Using multi-channel upload ({} channels) for {:?} ({} bytes)",
@@ -459,7 +613,9 @@ impl SessionState {
                                     ssh_session,
                                     tab_id_for_upload.clone(),
                                     concurrent_transfers,
-                                );
+                                )
+                                .with_resume(resume)
+                                .with_throttle(throttle);
 
                                 let tx_progress_clone = tx_progress.clone();
                                 uploader
@@ -485,6 +641,9 @@ impl SessionState {
                                 .upload_file(
                                     &local_path_clone,
                                     &remote_path_clone,
+                                    resume,
+                                    &cancel_token_for_upload,
+                                    &throttle,
                                     move |transferred, total, speed| {
                                         let _ = tx_progress.send(UploadEvent::Progress(
                                             transferred,
@@ -495,6 +654,14 @@ impl SessionState {
                                 )
                                 .await
                         };
+                    let result = verify_transfer(
+                        &tab_id_for_upload,
+                        &remote_path_clone,
+                        &local_path_clone,
+                        result,
+                        verify,
+                    )
+                    .await;
 
                     let _ = tx.send(UploadEvent::Complete(result));
                 });
@@ -517,7 +684,7 @@ impl SessionState {
                                             .find(|t| t.id == transfer_id)
                                         {
                                             transfer.status = crate::models::sftp::TransferStatus::Cancelled;
-                                            transfer.error = Some("用户取消".to_string());
+                                            transfer.error = Some(t("transfer.cancelled_by_user"));
                                         }
                                     }
                                     cx.notify();
@@ -569,6 +736,7 @@ impl SessionState {
                                                     match &result {
                                                         Ok(()) => {
                                                             transfer.set_completed();
+                                                            transfer.mark_verified(verify);
                                                             info!(
                                                                 "[SFTP] Upload completed: {}",
                                                                 remote_path
@@ -576,43 +744,28 @@ impl SessionState {
                                                         }
                                                         Err(e) => {
                                                             transfer.set_failed(e.clone());
+                                                            transfer.mark_resumable(resume);
                                                             error!("[SFTP] Upload failed: {}", e);
                                                         }
                                                     }
+                                                    record_history(
+                                                        &tab.server_id,
+                                                        &tab.server_label,
+                                                        transfer,
+                                                    );
                                                 }
                                             }
                                             cx.notify();
                                         });
                                         
                                         // 推送通知
-                                        if let Some(window) = cx.active_window() {
-                                            use gpui::AppContext as _;
-                                            let _ = cx.update_window(window, |_, window, cx| {
-                                                use gpui::Styled;
-                                                use gpui_component::notification::{
-                                                    Notification, NotificationType,
-                                                };
-                                                use gpui_component::WindowExt;
-
-                                                let lang = crate::services::storage::load_settings()
-                                                    .map(|s| s.theme.language)
-                                                    .unwrap_or_default();
-
-                                                let notification = match result_clone {
-                                                    Ok(()) => Notification::new()
-                                                        .message(crate::i18n::t(&lang, "sftp.upload.success"))
-                                                        .with_type(NotificationType::Success)
-                                                        .w_48()
-                                                        .py_2(),
-                                                    Err(_) => Notification::new()
-                                                        .message(crate::i18n::t(&lang, "sftp.upload.failed"))
-                                                        .with_type(NotificationType::Error)
-                                                        .w_48()
-                                                        .py_2(),
-                                                };
-                                                window.push_notification(notification, cx);
-                                            });
+                                        let (key, kind) = match result_clone {
+                                            Ok(()) => {
+                                            ("sftp.upload.success", NotificationType::Success)
                                         }
+                                            Err(_) => ("sftp.upload.failed", NotificationType::Error),
+                                        };
+                                        notify_key(cx, NotificationCategory::Transfer, kind, key);
                                     });
                                     break;
                                 }
@@ -638,11 +791,14 @@ impl SessionState {
     }
 
     /// 直接上传单个文件到远程目录（无文件选择器，用于拖放上传）
+    ///
+    /// `check_conflict` 为 true 时，远程已有同名文件会按冲突策略处理
     pub fn sftp_upload_file_direct(
         &mut self,
         tab_id: &str,
         local_path: std::path::PathBuf,
         remote_dir: String,
+        check_conflict: bool,
         cx: &mut gpui::Context<Self>,
     ) {
         let file_name = local_path
@@ -657,6 +813,26 @@ impl SessionState {
             }
         };
 
+        self.sftp_upload_file_as(
+            tab_id,
+            local_path,
+            remote_dir,
+            file_name,
+            check_conflict,
+            cx,
+        );
+    }
+
+    /// 直接上传单个文件，并在远程目录中保存为 `file_name`
+    pub fn sftp_upload_file_as(
+        &mut self,
+        tab_id: &str,
+        local_path: std::path::PathBuf,
+        remote_dir: String,
+        file_name: String,
+        check_conflict: bool,
+        cx: &mut gpui::Context<Self>,
+    ) {
         let file_size = match std::fs::metadata(&local_path) {
             Ok(m) => m.len(),
             Err(e) => {
@@ -673,6 +849,7 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
+        let server_key = self.transfer_server_key(tab_id);
 
         // 尝试获取 SFTP 服务
         let service = {
@@ -706,6 +883,31 @@ impl SessionState {
                     format!("{}/{}", remote_dir.trim_end_matches('/'), file_name)
                 };
 
+                // 检查远程是否已有同名文件（冲突对话框中可调整传输选项）
+                let mut options = transfer_options();
+                let remote_path = if check_conflict {
+                    match super::sftp_conflict::resolve_upload_target(
+                        async_cx,
+                        &session_state,
+                        &service,
+                        &tab_id_owned,
+                        &local_path,
+                        remote_path,
+                        &mut None,
+                        Some(&mut options),
+                    )
+                    .await
+                    {
+                        Some(path) => path,
+                        None => {
+                            info!("[SFTP] Upload skipped: {:?}", local_path);
+                            return;
+                        }
+                    }
+                } else {
+                    remote_path
+                };
+
                 info!("[SFTP] Uploading {:?} to {}", local_path, remote_path);
 
                 // 创建传输项
@@ -715,6 +917,12 @@ impl SessionState {
                     file_size,
                 );
                 let transfer_id = transfer_item.id.clone();
+                let cancel_token = transfer_item.cancel_token.clone();
+                let throttle = Throttle::new(
+                    true,
+                    transfer_item.speed_limit.clone(),
+                    cancel_token.clone(),
+                );
 
                 // 添加传输项到列表
                 let tab_id_for_transfer = tab_id_owned.clone();
@@ -741,23 +949,67 @@ impl SessionState {
                 let local_path_clone = local_path.clone();
                 let remote_path_clone = remote_path.clone();
                 let tx_progress = tx.clone();
+                let resume = resume_transfers_enabled();
+                let verify = verify_checksum_enabled();
+                let tab_id_for_verify = tab_id_owned.clone();
+                let transfer_id_for_queue = transfer_id.clone();
 
                 runtime.spawn(async move {
-                    let result = service
-                        .upload_file(
-                            &local_path_clone,
-                            &remote_path_clone,
-                            move |transferred, total, speed| {
-                                let _ = tx_progress.send(UploadEvent::Progress(
-                                    transferred,
-                                    total,
-                                    speed,
-                                ));
-                            },
-                        )
-                        .await;
+                    // 在传输队列中排队，轮到该传输时才开始
+                    let Some(_permit) =
+                        queue_transfer(&transfer_id_for_queue, &server_key, &cancel_token).await
+                    else {
+                        return;
+                    };
+
+                    let result = if let Some(ssh_session) =
+                        gzip_session(&tab_id_for_verify, options).await
+                    {
+                        let tx_progress_clone = tx_progress.clone();
+                        service
+                            .upload_file_gzip(
+                                &ssh_session,
+                                &local_path_clone,
+                                &remote_path_clone,
+                                &cancel_token,
+                                &throttle,
+                                move |transferred, total, speed| {
+                                    let _ = tx_progress_clone.send(UploadEvent::Progress(
+                                        transferred,
+                                        total,
+                                        speed,
+                                    ));
+                                },
+                            )
+                            .await
+                    } else {
+                        service
+                            .upload_file(
+                                &local_path_clone,
+                                &remote_path_clone,
+                                resume,
+                                &cancel_token,
+                                &throttle,
+                                move |transferred, total, speed| {
+                                    let _ = tx_progress.send(UploadEvent::Progress(
+                                        transferred,
+                                        total,
+                                        speed,
+                                    ));
+                                },
+                            )
+                            .await
+                    };
+                    let result = verify_transfer(
+                        &tab_id_for_verify,
+                        &remote_path_clone,
+                        &local_path_clone,
+                        result,
+                        verify,
+                    )
+                    .await;
 
-                    let _ = tx.send(UploadEvent::Complete(result.map_err(|e| e.to_string())));
+                    let _ = tx.send(UploadEvent::Complete(result));
                 });
 
                 // 处理上传事件
@@ -803,6 +1055,7 @@ impl SessionState {
                                                 match &result {
                                                     Ok(()) => {
                                                         transfer.set_completed();
+                                                        transfer.mark_verified(verify);
                                                         info!(
                                                             "[SFTP] Upload completed: {}",
                                                             remote_path
@@ -810,43 +1063,28 @@ impl SessionState {
                                                     }
                                                     Err(e) => {
                                                         transfer.set_failed(e.clone());
+                                                        transfer.mark_resumable(resume);
                                                         error!("[SFTP] Upload failed: {}", e);
                                                     }
                                                 }
+                                                record_history(
+                                                    &tab.server_id,
+                                                    &tab.server_label,
+                                                    transfer,
+                                                );
                                             }
                                         }
                                         cx.notify();
                                     });
                                     
                                     // 推送通知
-                                    if let Some(window) = cx.active_window() {
-                                        use gpui::AppContext as _;
-                                        let _ = cx.update_window(window, |_, window, cx| {
-                                            use gpui::Styled;
-                                            use gpui_component::notification::{
-                                                Notification, NotificationType,
-                                            };
-                                            use gpui_component::WindowExt;
-
-                                            let lang = crate::services::storage::load_settings()
-                                                .map(|s| s.theme.language)
-                                                .unwrap_or_default();
-
-                                            let notification = match result_clone {
-                                                Ok(()) => Notification::new()
-                                                    .message(crate::i18n::t(&lang, "sftp.upload.success"))
-                                                    .with_type(NotificationType::Success)
-                                                    .w_48()
-                                                    .py_2(),
-                                                Err(_) => Notification::new()
-                                                    .message(crate::i18n::t(&lang, "sftp.upload.failed"))
-                                                    .with_type(NotificationType::Error)
-                                                    .w_48()
-                                                    .py_2(),
-                                            };
-                                            window.push_notification(notification, cx);
-                                        });
-                                    }
+                                    let (key, kind) = match result_clone {
+                                        Ok(()) => {
+                                            ("sftp.upload.success", NotificationType::Success)
+                                        }
+                                        Err(_) => ("sftp.upload.failed", NotificationType::Error),
+                                    };
+                                    notify_key(cx, NotificationCategory::Transfer, kind, key);
                                 });
                                 break;
                             }
@@ -901,7 +1139,7 @@ impl SessionState {
                     path
                 } else {
                     // 打开文件夹选择对话框
-                    let folder_picker = rfd::AsyncFileDialog::new().set_title("选择下载保存位置");
+                    let folder_picker = rfd::AsyncFileDialog::new().set_title(t("sftp.pick.download_dir"));
 
                     if let Some(folder_handle) = folder_picker.pick_folder().await {
                         folder_handle.path().to_path_buf()
@@ -944,6 +1182,7 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
+        let server_key = self.transfer_server_key(tab_id);
 
         // 尝试获取 SFTP 服务
         let service = {
@@ -975,7 +1214,10 @@ impl SessionState {
         cx.to_async()
             .spawn(async move |async_cx| {
                 // 1. 递归读取远程目录，获取所有文件
-                info!("[SFTP] Collecting files from remote folder: {}", remote_folder);
+                info!(
+                    "[SFTP] Collecting files from remote folder: {}",
+                    remote_folder
+                );
 
                 // 在 tokio 运行时中执行递归读取
                 let (tx_files, mut rx_files) = tokio::sync::mpsc::unbounded_channel();
@@ -983,7 +1225,9 @@ impl SessionState {
                 let remote_folder_clone = remote_folder.clone();
 
                 runtime.spawn(async move {
-                    let result = service_for_list.read_dir_recursive(&remote_folder_clone).await;
+                    let result = service_for_list
+                        .read_dir_recursive(&remote_folder_clone)
+                        .await;
                     let _ = tx_files.send(result);
                 });
 
@@ -1001,10 +1245,7 @@ impl SessionState {
                 };
 
                 // 2. 过滤出文件（跳过目录条目）
-                let files: Vec<_> = entries
-                    .into_iter()
-                    .filter(|e| !e.is_dir())
-                    .collect();
+                let files: Vec<_> = entries.into_iter().filter(|e| !e.is_dir()).collect();
 
                 if files.is_empty() {
                     info!("[SFTP] No files to download in folder: {}", remote_folder);
@@ -1021,10 +1262,13 @@ impl SessionState {
                     .unwrap_or("download");
                 let local_root = local_dir.join(folder_name);
 
-                // 4. 为每个文件创建独立的下载任务
+                // 4. 确定每个文件的本地路径，整个文件夹作为一个传输项并行下载
+                let mut conflict_choice = None;
+                let mut children = Vec::new();
                 for file_entry in files {
                     // 计算相对路径
-                    let relative_path = file_entry.path
+                    let relative_path = file_entry
+                        .path
                         .strip_prefix(&remote_folder)
                         .unwrap_or(&file_entry.path)
                         .trim_start_matches('/');
@@ -1034,162 +1278,51 @@ impl SessionState {
                     // 创建本地父目录
                     if let Some(parent) = local_file_path.parent() {
                         if let Err(e) = std::fs::create_dir_all(parent) {
-                            error!("[SFTP] Failed to create local directory {:?}: {}", parent, e);
+                            error!(
+                                "[SFTP] Failed to create local directory {:?}: {}",
+                                parent, e
+                            );
                             continue;
                         }
                     }
 
-                    // 创建传输项
-                    let transfer_item = crate::models::sftp::TransferItem::new_download(
+                    // 检查本地是否已有同名文件
+                    let Some(local_file_path) = super::sftp_conflict::resolve_download_target(
+                        async_cx,
+                        &session_state,
+                        &service,
+                        &tab_id_owned,
+                        &file_entry.path,
+                        local_file_path,
+                        &mut conflict_choice,
+                        None,
+                    )
+                    .await
+                    else {
+                        info!("[SFTP] Download skipped: {}", file_entry.path);
+                        continue;
+                    };
+
+                    children.push(TransferItem::new_download(
                         file_entry.path.clone(),
-                        local_file_path.clone(),
+                        local_file_path,
                         file_entry.size,
-                    );
-                    let transfer_id = transfer_item.id.clone();
-                    let cancel_token = transfer_item.cancel_token.clone();
-
-                    // 添加传输项到列表
-                    let tab_id_for_add = tab_id_owned.clone();
-                    let _ = async_cx.update(|cx| {
-                        session_state.update(cx, |state, cx| {
-                            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_for_add) {
-                                tab.active_transfers.push(transfer_item);
-                            }
-                            cx.notify();
-                        });
-                    });
-
-                    // 创建事件通道
-                    enum DownloadEvent {
-                        Progress(u64, u64, u64),
-                        Complete(Result<(), String>),
-                    }
-                    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<DownloadEvent>();
-
-                    // 在 tokio 运行时中启动下载任务
-                    let service_for_download = service.clone();
-                    let remote_path = file_entry.path.clone();
-                    let local_path = local_file_path.clone();
-                    let tx_progress = tx.clone();
-
-                    runtime.spawn(async move {
-                        let result = service_for_download
-                            .download_file(&remote_path, &local_path, move |transferred, total, speed| {
-                                let _ = tx_progress.send(DownloadEvent::Progress(transferred, total, speed));
-                            })
-                            .await;
-                        let _ = tx.send(DownloadEvent::Complete(result));
-                    });
+                    ));
+                }
 
-                    // 处理进度和结果事件 - 这个循环在 GPUI 异步上下文中运行
-                    let session_state_for_events = session_state.clone();
-                    let tab_id_for_events = tab_id_owned.clone();
-                    let transfer_id_for_events = transfer_id.clone();
-                    let local_path_for_events = local_file_path.clone();
-
-                    // 由于我们需要并行处理多个文件，我们使用 spawn 来处理每个文件的事件
-                    // 注意：这里不能使用 async_cx.clone()，因为它不能跨任务共享
-                    // 所以我们在主循环中处理事件
-                    loop {
-                        tokio::select! {
-                            _ = cancel_token.cancelled() => {
-                                info!("[SFTP] Download cancelled: {}", transfer_id_for_events);
-                                let _ = std::fs::remove_file(&local_path_for_events);
-                                let tab_id = tab_id_for_events.clone();
-                                let transfer_id = transfer_id_for_events.clone();
-                                let _ = async_cx.update(|cx| {
-                                    session_state_for_events.update(cx, |state, cx| {
-                                        if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                            if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                transfer.status = crate::models::sftp::TransferStatus::Cancelled;
-                                                transfer.error = Some("用户取消".to_string());
-                                            }
-                                        }
-                                        cx.notify();
-                                    });
-                                });
-                                break;
-                            }
-                            event = rx.recv() => {
-                                match event {
-                                    Some(DownloadEvent::Progress(transferred, total, speed)) => {
-                                        let tab_id = tab_id_for_events.clone();
-                                        let transfer_id = transfer_id_for_events.clone();
-                                        let _ = async_cx.update(|cx| {
-                                            session_state_for_events.update(cx, |state, cx| {
-                                                if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                                    if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                        transfer.update_progress(transferred, total, speed);
-                                                    }
-                                                }
-                                                cx.notify();
-                                            });
-                                        });
-                                    }
-                                    Some(DownloadEvent::Complete(result)) => {
-                                        let tab_id = tab_id_for_events.clone();
-                                        let transfer_id = transfer_id_for_events.clone();
-                                        let local_path = local_path_for_events.clone();
-                                        let _ = async_cx.update(|cx| {
-                                            let result_clone = result.clone();
-                                            session_state_for_events.update(cx, |state, cx| {
-                                                if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                                    if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                        match &result {
-                                                            Ok(()) => {
-                                                                transfer.set_completed();
-                                                                info!("[SFTP] Download completed: {:?}", local_path);
-                                                            }
-                                                            Err(e) => {
-                                                                transfer.set_failed(e.clone());
-                                                                error!("[SFTP] Download failed: {}", e);
-                                                            }
-                                                        }
-                                                    }
-                                                }
-                                                cx.notify();
-                                            });
-                                            
-                                            // 推送通知
-                                            if let Some(window) = cx.active_window() {
-                                                use gpui::AppContext as _;
-                                                let _ = cx.update_window(window, |_, window, cx| {
-                                                    use gpui::Styled;
-                                                    use gpui_component::notification::{
-                                                        Notification, NotificationType,
-                                                    };
-                                                    use gpui_component::WindowExt;
-
-                                                    let lang = crate::services::storage::load_settings()
-                                                        .map(|s| s.theme.language)
-                                                        .unwrap_or_default();
-
-                                                    let notification = match result_clone {
-                                                        Ok(()) => Notification::new()
-                                                            .message(crate::i18n::t(&lang, "sftp.download.success"))
-                                                            .with_type(NotificationType::Success)
-                                                            .w_48()
-                                                            .py_2(),
-                                                        Err(_) => Notification::new()
-                                                            .message(crate::i18n::t(&lang, "sftp.download.failed"))
-                                                            .with_type(NotificationType::Error)
-                                                            .w_48()
-                                                            .py_2(),
-                                                    };
-                                                    window.push_notification(notification, cx);
-                                                });
-                                            }
-                                        });
-                                        break;
-                                    }
-                                    None => {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
+                if children.is_empty() {
+                    return;
                 }
+                let folder = TransferItem::new_folder(false, remote_folder, local_root, children);
+                run_folder_transfer(
+                    async_cx,
+                    &session_state,
+                    &tab_id_owned,
+                    &server_key,
+                    &service,
+                    folder,
+                )
+                .await;
             })
             .detach();
     }
@@ -1216,7 +1349,7 @@ impl SessionState {
         cx.to_async()
             .spawn(async move |async_cx| {
                 // 打开文件夹选择对话框
-                let folder_picker = rfd::AsyncFileDialog::new().set_title("选择要上传的文件夹");
+                let folder_picker = rfd::AsyncFileDialog::new().set_title(t("sftp.pick.upload_folder"));
 
                 if let Some(folder_handle) = folder_picker.pick_folder().await {
                     let local_folder = folder_handle.path().to_path_buf();
@@ -1257,6 +1390,7 @@ impl SessionState {
         let sftp_services = self.sftp_services.clone();
         let session_state = cx.entity().clone();
         let tab_id_owned = tab_id.to_string();
+        let server_key = self.transfer_server_key(tab_id);
 
         // 尝试获取 SFTP 服务
         let service = {
@@ -1288,7 +1422,10 @@ impl SessionState {
         cx.to_async()
             .spawn(async move |async_cx| {
                 // 1. 递归遍历本地文件夹，收集所有文件
-                info!("[SFTP] Collecting files from local folder: {:?}", local_folder);
+                info!(
+                    "[SFTP] Collecting files from local folder: {:?}",
+                    local_folder
+                );
 
                 fn collect_local_files(
                     dir: &std::path::Path,
@@ -1379,7 +1516,9 @@ impl SessionState {
                     }
                 }
 
-                // 5. 为每个文件创建独立的上传任务
+                // 5. 确定每个文件的远程路径，整个文件夹作为一个传输项并行上传
+                let mut conflict_choice = None;
+                let mut children = Vec::new();
                 for local_file_path in files {
                     // 计算相对路径和远程路径
                     let relative_path = match local_file_path.strip_prefix(&local_folder) {
@@ -1389,160 +1528,55 @@ impl SessionState {
 
                     let remote_path = format!("{}/{}", remote_root, relative_path);
 
+                    // 检查远程是否已有同名文件
+                    let Some(remote_path) = super::sftp_conflict::resolve_upload_target(
+                        async_cx,
+                        &session_state,
+                        &service,
+                        &tab_id_owned,
+                        &local_file_path,
+                        remote_path,
+                        &mut conflict_choice,
+                        None,
+                    )
+                    .await
+                    else {
+                        info!("[SFTP] Upload skipped: {:?}", local_file_path);
+                        continue;
+                    };
+
                     // 获取文件大小
                     let file_size = match std::fs::metadata(&local_file_path) {
                         Ok(m) => m.len(),
                         Err(e) => {
-                            error!("[SFTP] Failed to get file size for {:?}: {}", local_file_path, e);
+                            error!(
+                                "[SFTP] Failed to get file size for {:?}: {}",
+                                local_file_path, e
+                            );
                             continue;
                         }
                     };
 
-                    // 创建传输项
-                    let transfer_item = crate::models::sftp::TransferItem::new_upload(
-                        local_file_path.clone(),
-                        remote_path.clone(),
+                    children.push(TransferItem::new_upload(
+                        local_file_path,
+                        remote_path,
                         file_size,
-                    );
-                    let transfer_id = transfer_item.id.clone();
-                    let cancel_token = transfer_item.cancel_token.clone();
-
-                    // 添加传输项到列表
-                    let tab_id_for_add = tab_id_owned.clone();
-                    let _ = async_cx.update(|cx| {
-                        session_state.update(cx, |state, cx| {
-                            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_for_add) {
-                                tab.active_transfers.push(transfer_item);
-                            }
-                            cx.notify();
-                        });
-                    });
-
-                    // 创建事件通道
-                    enum UploadEvent {
-                        Progress(u64, u64, u64),
-                        Complete(Result<(), String>),
-                    }
-                    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<UploadEvent>();
-
-                    // 在 tokio 运行时中启动上传任务
-                    let service_for_upload = service.clone();
-                    let local_path = local_file_path.clone();
-                    let remote = remote_path.clone();
-                    let tx_progress = tx.clone();
-
-                    runtime.spawn(async move {
-                        let result = service_for_upload
-                            .upload_file(&local_path, &remote, move |transferred, total, speed| {
-                                let _ = tx_progress.send(UploadEvent::Progress(transferred, total, speed));
-                            })
-                            .await;
-                        let _ = tx.send(UploadEvent::Complete(result));
-                    });
+                    ));
+                }
 
-                    // 处理进度和结果事件 - 这个循环在 GPUI 异步上下文中运行
-                    let session_state_for_events = session_state.clone();
-                    let tab_id_for_events = tab_id_owned.clone();
-                    let transfer_id_for_events = transfer_id.clone();
-
-                    loop {
-                        tokio::select! {
-                            _ = cancel_token.cancelled() => {
-                                info!("[SFTP] Upload cancelled: {}", transfer_id_for_events);
-                                let tab_id = tab_id_for_events.clone();
-                                let transfer_id = transfer_id_for_events.clone();
-                                let _ = async_cx.update(|cx| {
-                                    session_state_for_events.update(cx, |state, cx| {
-                                        if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                            if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                transfer.status = crate::models::sftp::TransferStatus::Cancelled;
-                                                transfer.error = Some("用户取消".to_string());
-                                            }
-                                        }
-                                        cx.notify();
-                                    });
-                                });
-                                break;
-                            }
-                            event = rx.recv() => {
-                                match event {
-                                    Some(UploadEvent::Progress(transferred, total, speed)) => {
-                                        let tab_id = tab_id_for_events.clone();
-                                        let transfer_id = transfer_id_for_events.clone();
-                                        let _ = async_cx.update(|cx| {
-                                            session_state_for_events.update(cx, |state, cx| {
-                                                if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                                    if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                        transfer.update_progress(transferred, total, speed);
-                                                    }
-                                                }
-                                                cx.notify();
-                                            });
-                                        });
-                                    }
-                                    Some(UploadEvent::Complete(result)) => {
-                                        let tab_id = tab_id_for_events.clone();
-                                        let transfer_id = transfer_id_for_events.clone();
-                                        let _ = async_cx.update(|cx| {
-                                            let result_clone = result.clone();
-                                            session_state_for_events.update(cx, |state, cx| {
-                                                if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
-                                                    if let Some(transfer) = tab.active_transfers.iter_mut().find(|t| t.id == transfer_id) {
-                                                        match &result {
-                                                            Ok(()) => {
-                                                                transfer.set_completed();
-                                                                info!("[SFTP] Upload completed: {}", remote_path);
-                                                            }
-                                                            Err(e) => {
-                                                                transfer.set_failed(e.clone());
-                                                                error!("[SFTP] Upload failed: {}", e);
-                                                            }
-                                                        }
-                                                    }
-                                                }
-                                                cx.notify();
-                                            });
-                                            
-                                            // 推送通知
-                                            if let Some(window) = cx.active_window() {
-                                                use gpui::AppContext as _;
-                                                let _ = cx.update_window(window, |_, window, cx| {
-                                                    use gpui::Styled;
-                                                    use gpui_component::notification::{
-                                                        Notification, NotificationType,
-                                                    };
-                                                    use gpui_component::WindowExt;
-
-                                                    let lang = crate::services::storage::load_settings()
-                                                        .map(|s| s.theme.language)
-                                                        .unwrap_or_default();
-
-                                                    let notification = match result_clone {
-                                                        Ok(()) => Notification::new()
-                                                            .message(crate::i18n::t(&lang, "sftp.upload.success"))
-                                                            .with_type(NotificationType::Success)
-                                                            .w_48()
-                                                            .py_2(),
-                                                        Err(_) => Notification::new()
-                                                            .message(crate::i18n::t(&lang, "sftp.upload.failed"))
-                                                            .with_type(NotificationType::Error)
-                                                            .w_48()
-                                                            .py_2(),
-                                                    };
-                                                    window.push_notification(notification, cx);
-                                                });
-                                            }
-                                        });
-                                        break;
-                                    }
-                                    None => {
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
+                if children.is_empty() {
+                    return;
                 }
+                let folder = TransferItem::new_folder(true, remote_root, local_folder, children);
+                run_folder_transfer(
+                    async_cx,
+                    &session_state,
+                    &tab_id_owned,
+                    &server_key,
+                    &service,
+                    folder,
+                )
+                .await;
 
                 // 所有文件上传完成后自动刷新目录
                 info!("[SFTP] All folder uploads completed, refreshing directory");
@@ -1573,7 +1607,8 @@ impl SessionState {
                 transfer.cancel_token.cancel();
                 // 更新状态
                 transfer.status = crate::models::sftp::TransferStatus::Cancelled;
-                transfer.error = Some("用户取消".to_string());
+                transfer.error = Some(t("transfer.cancelled_by_user"));
+                transfer.mark_resumable(resume_transfers_enabled());
 
                 info!("[SFTP] Transfer cancelled: {}", transfer_id);
                 cx.notify();
@@ -1632,6 +1667,62 @@ impl SessionState {
         }
     }
 
+    /// 从保留的 `.part` 部分文件继续失败或已取消的传输
+    ///
+    /// 原传输项会被移除，续传作为新的传输项重新加入列表
+    pub fn retry_transfer(&mut self, transfer_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some((tab_id, transfer)) = self.tabs.iter_mut().find_map(|tab| {
+            let ix = tab
+                .active_transfers
+                .iter()
+                .position(|t| t.id == transfer_id && t.can_resume())?;
+            Some((tab.id.clone(), tab.active_transfers.remove(ix)))
+        }) else {
+            return;
+        };
+        info!(
+            "[SFTP] Resuming transfer from partial file: {}",
+            transfer_id
+        );
+
+        if transfer.is_upload {
+            // 保持原来的远程文件名（可能因冲突处理而与本地文件名不同）
+            let remote_dir = get_parent_path(&transfer.remote_path);
+            let file_name = transfer
+                .remote_path
+                .rsplit('/')
+                .next()
+                .unwrap_or(&transfer.remote_path)
+                .to_string();
+            self.sftp_upload_file_as(
+                &tab_id,
+                transfer.local_path,
+                remote_dir,
+                file_name,
+                false,
+                cx,
+            );
+        } else {
+            let Some(file_name) = transfer
+                .local_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+            else {
+                return;
+            };
+            let local_dir = transfer.local_path.parent().map(|p| p.to_path_buf());
+            self.sftp_download_file_to(
+                &tab_id,
+                transfer.remote_path,
+                file_name,
+                transfer.progress.total_bytes,
+                local_dir,
+                false,
+                cx,
+            );
+        }
+    }
+
     /// 确保新建文件夹对话框已创建
     pub fn ensure_sftp_new_folder_dialog(
         &mut self,
@@ -1755,27 +1846,12 @@ impl SessionState {
 
                         // 推送失败通知（成功时不通知，用户可通过文件列表刷新看到）
                         if result_clone.is_err() {
-                            if let Some(window) = cx.active_window() {
-                                use gpui::AppContext as _;
-                                let _ = cx.update_window(window, |_, window, cx| {
-                                    use gpui::Styled;
-                                    use gpui_component::notification::{
-                                        Notification, NotificationType,
-                                    };
-                                    use gpui_component::WindowExt;
-
-                                    let lang = crate::services::storage::load_settings()
-                                        .map(|s| s.theme.language)
-                                        .unwrap_or_default();
-
-                                    let notification = Notification::new()
-                                        .message(crate::i18n::t(&lang, "sftp.new_folder.failed"))
-                                        .with_type(NotificationType::Error)
-                                        .w_48()
-                                        .py_2();
-                                    window.push_notification(notification, cx);
-                                });
-                            }
+                            notify_key(
+                                cx,
+                                NotificationCategory::Transfer,
+                                NotificationType::Error,
+                                "sftp.new_folder.failed",
+                            );
                         }
                     });
                 }
@@ -1895,27 +1971,12 @@ impl SessionState {
 
                         // 推送失败通知（成功时不通知，用户可通过文件列表刷新看到）
                         if result.is_err() {
-                            if let Some(window) = cx.active_window() {
-                                use gpui::AppContext as _;
-                                let _ = cx.update_window(window, |_, window, cx| {
-                                    use gpui::Styled;
-                                    use gpui_component::notification::{
-                                        Notification, NotificationType,
-                                    };
-                                    use gpui_component::WindowExt;
-
-                                    let lang = crate::services::storage::load_settings()
-                                        .map(|s| s.theme.language)
-                                        .unwrap_or_default();
-
-                                    let notification = Notification::new()
-                                        .message(crate::i18n::t(&lang, "sftp.new_file.failed"))
-                                        .with_type(NotificationType::Error)
-                                        .w_48()
-                                        .py_2();
-                                    window.push_notification(notification, cx);
-                                });
-                            }
+                            notify_key(
+                                cx,
+                                NotificationCategory::Transfer,
+                                NotificationType::Error,
+                                "sftp.new_file.failed",
+                            );
                         }
                     });
                 }
@@ -1942,49 +2003,198 @@ impl SessionState {
     }
 
     /// 打开属性对话框
+    ///
+    /// `paths` 为选中的条目路径，多个时显示汇总信息并将修改应用到全部条目
     pub fn sftp_open_properties_dialog(
         &mut self,
         tab_id: &str,
-        path: String,
+        paths: Vec<String>,
         cx: &mut gpui::Context<Self>,
     ) {
-        info!("[SFTP] Open properties dialog for: {} in tab {}", path, tab_id);
+        info!(
+            "[SFTP] Open properties dialog for {} item(s) in tab {}",
+            paths.len(),
+            tab_id
+        );
 
         // 从 file_list 中查找对应的 FileEntry
-        let entry = {
-            let tab = self.tabs.iter().find(|t| t.id == tab_id);
-            if let Some(tab) = tab {
-                if let Some(sftp_state) = &tab.sftp_state {
-                    sftp_state.file_list.iter().find(|e| e.path == path).cloned()
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+        let entries: Vec<crate::models::sftp::FileEntry> = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref())
+            .map(|sftp_state| {
+                paths
+                    .iter()
+                    .filter_map(|path| sftp_state.file_list.iter().find(|e| &e.path == path))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let Some(entry) = entries.first().cloned() else {
+            error!("[SFTP] File entry not found for paths: {:?}", paths);
+            return;
         };
+        let is_multi = entries.len() > 1;
+        let size_paths: Vec<String> = entries
+            .iter()
+            .filter(|e| is_multi || e.is_dir())
+            .map(|e| e.path.clone())
+            .collect();
 
-        if let Some(entry) = entry {
-            let dialog = self.ensure_sftp_properties_dialog(cx);
-            let entry_clone = entry.clone();
-            dialog.update(cx, |d, _| {
-                d.open(entry_clone, tab_id.to_string());
-            });
+        let dialog = self.ensure_sftp_properties_dialog(cx);
+        let sftp_state = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_ref());
+        dialog.update(cx, |d, _| {
+            d.open(entries, tab_id.to_string(), sftp_state);
+        });
 
+        if !is_multi {
             // 如果是符号链接，异步获取链接目标
             if entry.file_type == crate::models::sftp::FileType::Symlink {
-                self.sftp_fetch_symlink_target(tab_id, &path, dialog.clone(), cx);
+                self.sftp_fetch_symlink_target(tab_id, &entry.path, dialog.clone(), cx);
+            } else {
+                // 扩展属性与 ACL（远程缺少 getfattr/getfacl 时不显示）
+                self.sftp_fetch_extended_attributes(tab_id, &entry.path, dialog.clone(), cx);
             }
+        }
 
-            // 如果是文件夹，异步计算总大小（通过 SSH du 命令）
-            if entry.is_dir() {
-                self.sftp_calculate_folder_size(tab_id, &path, dialog.clone(), cx);
+        // 文件夹或多选时，异步计算总大小（通过 SSH du 命令）
+        if !size_paths.is_empty() {
+            self.sftp_calculate_folder_size(tab_id, size_paths, dialog.clone(), cx);
+        }
+
+        cx.notify();
+    }
+
+    /// 应用属性对话框中的权限/所有者修改（chmod/chown），多选时逐个应用到每个条目
+    pub fn sftp_apply_properties(
+        &mut self,
+        dialog: Entity<PropertiesDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, changes) = {
+            let d = dialog.read(cx);
+            // 符号链接的权限由目标决定，跳过
+            let changes: Vec<(String, Option<u32>, Option<(u32, u32)>, bool)> = d
+                .targets()
+                .into_iter()
+                .filter(|entry| entry.file_type != crate::models::sftp::FileType::Symlink)
+                .filter_map(|entry| {
+                    let (mode, owner) = d.pending_changes(entry);
+                    (mode.is_some() || owner.is_some()).then(|| {
+                        (
+                            entry.path.clone(),
+                            mode,
+                            owner,
+                            d.recursive && entry.is_dir(),
+                        )
+                    })
+                })
+                .collect();
+            (d.tab_id.clone(), changes)
+        };
+
+        if changes.is_empty() {
+            dialog.update(cx, |d, _| d.close());
+            return;
+        }
+
+        let service = {
+            let guard = match self.sftp_services.lock() {
+                Ok(g) => g,
+                Err(e) => {
+                    error!("[SFTP] Failed to lock sftp_services: {}", e);
+                    return;
+                }
+            };
+            match guard.get(&tab_id) {
+                Some(s) => s.clone(),
+                None => {
+                    error!("[SFTP] No SFTP service for tab {}", tab_id);
+                    return;
+                }
             }
+        };
 
+        dialog.update(cx, |d, cx| {
+            d.start_applying();
             cx.notify();
-        } else {
-            error!("[SFTP] File entry not found for path: {}", path);
-        }
+        });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<(), String>>();
+        let changes_for_task = changes.clone();
+        crate::ssh::manager::SshManager::global()
+            .runtime()
+            .spawn(async move {
+                for (path, mode, owner, recursive) in &changes_for_task {
+                    info!(
+                        "[SFTP] Applying attributes to {} (mode: {:?}, owner: {:?}, recursive: {})",
+                        path, mode, owner, recursive
+                    );
+                    if let Err(e) = service
+                        .set_attributes(path, *mode, *owner, *recursive)
+                        .await
+                    {
+                        let _ = tx.send(Err(format!("{}: {}", path, e)));
+                        return;
+                    }
+                }
+                let _ = tx.send(Ok(()));
+            });
+
+        let paths: Vec<String> = changes.into_iter().map(|(path, ..)| path).collect();
+        let session_state = cx.entity().clone();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                if let Some(result) = rx.recv().await {
+                    let _ = async_cx.update(|cx| {
+                        // 无论成功与否都刷新，部分条目可能已经修改
+                        session_state.update(cx, |state, cx| {
+                            let mut current_path = None;
+                            if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id) {
+                                if let Some(ref mut sftp_state) = tab.sftp_state {
+                                    for path in &paths {
+                                        sftp_state.invalidate_cache(&get_parent_path(path));
+                                        sftp_state.invalidate_cache(path);
+                                    }
+                                    current_path = Some(sftp_state.current_path.clone());
+                                }
+                            }
+                            if let Some(current) = current_path {
+                                state.sftp_load_directory(&tab_id, current, cx);
+                            }
+                        });
+
+                        match &result {
+                            Ok(()) => {
+                                info!("[SFTP] Attributes applied to {} item(s)", paths.len());
+                                dialog.update(cx, |d, _| d.close());
+                            }
+                            Err(e) => {
+                                error!("[SFTP] Failed to apply attributes: {}", e);
+                                dialog.update(cx, |d, cx| {
+                                    d.set_apply_error(e.clone());
+                                    cx.notify();
+                                });
+                            }
+                        }
+
+                        // 推送结果通知
+                        let (key, kind) = if result.is_ok() {
+                            ("sftp.properties.applied", NotificationType::Success)
+                        } else {
+                            ("sftp.properties.apply_failed", NotificationType::Error)
+                        };
+                        notify_key(cx, NotificationCategory::Transfer, kind, key);
+                    });
+                }
+            })
+            .detach();
     }
 
     /// 获取符号链接目标
@@ -2041,127 +2251,256 @@ impl SessionState {
             .detach();
     }
 
-    /// 计算文件夹大小（通过 SSH du 命令）
+    /// 计算文件夹（或多选条目）的总大小（通过 SSH du 命令）
+    ///
+    /// 取消时丢弃正在执行的 exec 通道，远程 du 随通道关闭而终止
     pub fn sftp_calculate_folder_size(
         &mut self,
         tab_id: &str,
-        path: &str,
+        paths: Vec<String>,
         dialog: Entity<PropertiesDialogState>,
         cx: &mut gpui::Context<Self>,
     ) {
-        info!("[SFTP] Calculating folder size for: {} in tab {}", path, tab_id);
-
-        // 标记正在计算，并获取取消令牌
-        let cancellation_token = dialog.update(cx, |d, _| {
-            d.start_calculating_size()
-        });
-
-        let tab_id_owned = tab_id.to_string();
-        let path_owned = path.to_string();
-
-        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<u64, String>>();
+        info!(
+            "[SFTP] Calculating total size of {} path(s) in tab {}",
+            paths.len(),
+            tab_id
+        );
 
         let ssh_manager = crate::ssh::manager::SshManager::global();
 
         // 获取 SSH session 来执行命令
-        let session = match ssh_manager.get_session(&tab_id_owned) {
-            Some(s) => s,
-            None => {
-                error!("[SFTP] No SSH session for tab {}", tab_id_owned);
-                return;
-            }
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[SFTP] No SSH session for tab {}", tab_id);
+            return;
         };
 
-        let path_clone = path_owned.clone();
+        // 标记正在计算，并获取取消令牌
+        let cancellation_token = dialog.update(cx, |d, _| d.start_calculating_size());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<u64, String>>();
         let token_for_task = cancellation_token.clone();
         ssh_manager.runtime().spawn(async move {
-            // 检查是否已取消
-            if token_for_task.is_cancelled() {
-                info!("[SFTP] Folder size calculation cancelled before start");
-                return;
+            tokio::select! {
+                _ = token_for_task.cancelled() => {
+                    info!("[SFTP] Folder size calculation cancelled");
+                }
+                result = crate::services::sftp::remote_total_size(&session, &paths) => {
+                    let _ = tx.send(result);
+                }
             }
+        });
 
-            // 打开 exec 通道
-            let exec_channel = match session.open_exec().await {
-                Ok(ch) => ch,
-                Err(e) => {
-                    let _ = tx.send(Err(format!("Failed to open exec channel: {:?}", e)));
-                    return;
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                // 取消后发送端被丢弃，recv 返回 None
+                if let Some(result) = rx.recv().await {
+                    if cancellation_token.is_cancelled() {
+                        return;
+                    }
+                    let _ = async_cx.update(|cx| {
+                        dialog.update(cx, |d, cx| {
+                            match result {
+                                Ok(size) => {
+                                    info!("[SFTP] Folder size calculated: {} bytes", size);
+                                    d.set_folder_size(size);
+                                }
+                                Err(e) => {
+                                    error!("[SFTP] Folder size calculation failed: {}", e);
+                                    d.set_folder_size_failed();
+                                }
+                            }
+                            cx.notify();
+                        });
+                    });
                 }
-            };
+            })
+            .detach();
+    }
 
-            // 再次检查是否已取消
-            if token_for_task.is_cancelled() {
-                info!("[SFTP] Folder size calculation cancelled");
+    /// 按需计算属性对话框中文件的校验和
+    pub fn sftp_compute_checksum(
+        &mut self,
+        dialog: Entity<PropertiesDialogState>,
+        algorithm: crate::services::sftp::ChecksumAlgorithm,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, path) = {
+            let d = dialog.read(cx);
+            let Some(entry) = &d.entry else {
                 return;
+            };
+            (d.tab_id.clone(), entry.path.clone())
+        };
+
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(&tab_id) else {
+            error!("[SFTP] No SSH session for tab {}", tab_id);
+            return;
+        };
+
+        info!("[SFTP] Computing {} of {}", algorithm.label(), path);
+        let cancellation_token = dialog.update(cx, |d, cx| {
+            cx.notify();
+            d.start_checksum(algorithm)
+        });
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<Result<String, String>>();
+        let token_for_task = cancellation_token.clone();
+        ssh_manager.runtime().spawn(async move {
+            tokio::select! {
+                _ = token_for_task.cancelled() => {}
+                result = crate::services::sftp::remote_checksum(&session, &path, algorithm) => {
+                    let _ = tx.send(result);
+                }
             }
+        });
 
-            // 执行 du 命令获取文件夹大小（字节）
-            // 使用 du -sb 获取总字节数，2>/dev/null 忽略权限错误
-            let command = format!("du -sb '{}' 2>/dev/null | cut -f1", path_clone.replace("'", "'\\''"));
-            
-            match exec_channel.exec(&command).await {
-                Ok(output) => {
-                    // 检查是否已取消
-                    if token_for_task.is_cancelled() {
-                        info!("[SFTP] Folder size calculation cancelled after exec");
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                if let Some(result) = rx.recv().await {
+                    if cancellation_token.is_cancelled() {
                         return;
                     }
-
-                    if output.exit_code == 0 {
-                        let stdout = String::from_utf8_lossy(&output.stdout);
-                        let size_str = stdout.trim();
-                        match size_str.parse::<u64>() {
-                            Ok(size) => {
-                                info!("[SFTP] Folder size calculated: {} bytes", size);
-                                let _ = tx.send(Ok(size));
-                            }
-                            Err(_) => {
-                                let _ = tx.send(Err(format!("Failed to parse size: {}", size_str)));
-                            }
-                        }
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        let _ = tx.send(Err(format!("du command failed: {}", stderr)));
+                    if let Err(e) = &result {
+                        error!("[SFTP] Failed to compute {}: {}", algorithm.label(), e);
                     }
+                    let _ = async_cx.update(|cx| {
+                        dialog.update(cx, |d, cx| {
+                            d.set_checksum(algorithm, result);
+                            cx.notify();
+                        });
+                    });
                 }
-                Err(e) => {
-                    let _ = tx.send(Err(format!("Failed to execute du: {:?}", e)));
+            })
+            .detach();
+    }
+
+    /// 获取扩展属性与 ACL
+    fn sftp_fetch_extended_attributes(
+        &self,
+        tab_id: &str,
+        path: &str,
+        dialog: Entity<PropertiesDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            return;
+        };
+
+        let cancellation_token = dialog.update(cx, |d, _| d.extended_attributes_token());
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<(Vec<String>, Vec<String>)>();
+        let path = path.to_string();
+        let token_for_task = cancellation_token.clone();
+        ssh_manager.runtime().spawn(async move {
+            tokio::select! {
+                _ = token_for_task.cancelled() => {}
+                attributes = crate::services::sftp::remote_extended_attributes(&session, &path) => {
+                    let _ = tx.send(attributes);
                 }
             }
         });
 
-        let token_for_ui = cancellation_token.clone();
         cx.to_async()
             .spawn(async move |async_cx| {
-                // 使用 tokio::select! 来同时监听结果和取消信号
-                tokio::select! {
-                    _ = token_for_ui.cancelled() => {
-                        info!("[SFTP] Folder size UI update cancelled");
-                    }
-                    result = rx.recv() => {
-                        if let Some(result) = result {
-                            let _ = async_cx.update(|cx| {
-                                match result {
-                                    Ok(size) => {
-                                        dialog.update(cx, |d, _| {
-                                            d.set_folder_size(size);
-                                        });
-                                    }
-                                    Err(e) => {
-                                        error!("[SFTP] Folder size calculation failed: {}", e);
-                                        // 设置为 0 表示计算失败
-                                        dialog.update(cx, |d, _| {
-                                            d.set_folder_size(0);
-                                        });
-                                    }
-                                }
-                            });
-                        }
+                if let Some((xattrs, acl)) = rx.recv().await {
+                    if cancellation_token.is_cancelled() {
+                        return;
                     }
+                    let _ = async_cx.update(|cx| {
+                        dialog.update(cx, |d, cx| {
+                            d.set_extended_attributes(xattrs, acl);
+                            cx.notify();
+                        });
+                    });
                 }
             })
             .detach();
     }
 }
 
+/// 是否启用断点续传（失败或取消后保留部分文件，下次从断点继续）
+pub(super) fn resume_transfers_enabled() -> bool {
+    crate::services::storage::load_settings()
+        .map(|s| s.sftp.resume_transfers)
+        .unwrap_or(true)
+}
+
+/// 是否在传输完成后校验完整性（比较远程与本地的 SHA-256）
+pub(super) fn verify_checksum_enabled() -> bool {
+    crate::services::storage::load_settings()
+        .map(|s| s.sftp.verify_checksum)
+        .unwrap_or(false)
+}
+
+/// 全局设置中的单文件传输选项（压缩传输、下载后自动解压）
+pub(super) fn transfer_options() -> TransferOptions {
+    crate::services::storage::load_settings()
+        .map(|s| TransferOptions {
+            compress: s.sftp.transfer_compression,
+            extract: s.sftp.auto_extract_downloads,
+        })
+        .unwrap_or_default()
+}
+
+/// 启用了压缩传输且远程可用 gzip 时返回用于压缩传输的 SSH 会话
+async fn gzip_session(tab_id: &str, options: TransferOptions) -> Option<Arc<SshSession>> {
+    if !options.compress {
+        return None;
+    }
+    let session = crate::ssh::manager::SshManager::global().get_session(tab_id)?;
+    if crate::services::sftp::gzip_available(&session).await {
+        Some(session)
+    } else {
+        warn!("[SFTP] gzip not available on remote, transferring uncompressed");
+        None
+    }
+}
+
+/// 下载成功且需要自动解压时把归档解压到同名文件夹，解压失败时把结果改为失败
+async fn extract_download(
+    local_path: &std::path::Path,
+    result: Result<(), String>,
+    extract: bool,
+) -> Result<(), String> {
+    if !extract || result.is_err() {
+        return result;
+    }
+    let name = local_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if ArchiveFormat::from_name(&name).is_none() {
+        return result;
+    }
+    crate::services::sftp::extract_local_archive(local_path)
+        .await
+        .map(|dest| info!("[SFTP] Extracted {:?} to {:?}", local_path, dest))
+}
+
+/// 传输成功且启用了完整性校验时比较两端的校验和，不一致时把结果改为失败
+pub(super) async fn verify_transfer(
+    tab_id: &str,
+    remote_path: &str,
+    local_path: &std::path::Path,
+    result: Result<(), String>,
+    verify: bool,
+) -> Result<(), String> {
+    if !verify || result.is_err() {
+        return result;
+    }
+    let Some(session) = crate::ssh::manager::SshManager::global().get_session(tab_id) else {
+        return Err(format!("SSH session not found: {}", tab_id));
+    };
+    crate::services::sftp::verify_checksum(&session, remote_path, local_path).await
+}
+
+/// 把已结束的传输写入传输历史
+pub(super) fn record_history(server_id: &str, server_label: &str, transfer: &TransferItem) {
+    TransferHistoryStore::global().record(TransferHistoryEntry::from_transfer(
+        transfer,
+        server_id,
+        server_label,
+    ));
+}
@@ -0,0 +1,242 @@
+// SFTP 目录监视方法：开启后当前目录内容变化时自动刷新文件列表
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::SessionState;
+use crate::models::sftp::FileEntry;
+use crate::services::sftp::{
+    dir_signature, inotify_changed, inotify_watch_command, SftpService, WATCH_CYCLE_SECS,
+    WATCH_DEBOUNCE, WATCH_POLL_INTERVAL,
+};
+use crate::ssh::session::SshSession;
+use gpui::prelude::*;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+/// 监视方式
+#[derive(Clone, Copy, PartialEq)]
+enum WatchMode {
+    /// 尚未确认远程是否有 inotifywait
+    Unknown,
+    Inotify,
+    Poll,
+}
+
+/// 一轮监视的结果
+enum WatchEvent {
+    /// 目录内容已变化（新的条目列表）
+    Changed(Vec<FileEntry>),
+    /// 本轮未发现变化
+    Idle,
+}
+
+/// 一轮监视的上下文，在界面循环与后台任务之间传递
+struct WatchCycle {
+    path: String,
+    mode: WatchMode,
+    /// 轮询模式下上一次读取的目录签名
+    baseline: Option<u64>,
+}
+
+/// 等待目录变化（最长一轮），返回结果并更新监视方式与签名
+async fn wait_for_change(
+    session: Arc<SshSession>,
+    service: SftpService,
+    cycle: &mut WatchCycle,
+    cancel_token: &CancellationToken,
+) -> Result<WatchEvent, String> {
+    if cycle.mode != WatchMode::Poll {
+        let channel = session.open_exec().await.map_err(|e| format!("{:?}", e))?;
+        let output = tokio::select! {
+            _ = cancel_token.cancelled() => return Ok(WatchEvent::Idle),
+            output = channel.exec(&inotify_watch_command(&cycle.path)) => {
+                output.map_err(|e| format!("{:?}", e))?
+            }
+        };
+        match inotify_changed(output.exit_code) {
+            Some(true) => {
+                cycle.mode = WatchMode::Inotify;
+                // 等待成批的变化结束后再读取
+                tokio::time::sleep(WATCH_DEBOUNCE).await;
+                return service.read_dir(&cycle.path).await.map(WatchEvent::Changed);
+            }
+            Some(false) => {
+                cycle.mode = WatchMode::Inotify;
+                return Ok(WatchEvent::Idle);
+            }
+            None => {
+                info!(
+                    "[SFTP] inotifywait unavailable (exit {}), polling {}",
+                    output.exit_code, cycle.path
+                );
+                cycle.mode = WatchMode::Poll;
+            }
+        }
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(WATCH_CYCLE_SECS);
+    loop {
+        let entries = service.read_dir(&cycle.path).await?;
+        let signature = dir_signature(&entries);
+        match cycle.baseline.replace(signature) {
+            Some(baseline) if baseline != signature => return Ok(WatchEvent::Changed(entries)),
+            _ => {}
+        }
+        if Instant::now() >= deadline {
+            return Ok(WatchEvent::Idle);
+        }
+        tokio::select! {
+            _ = cancel_token.cancelled() => return Ok(WatchEvent::Idle),
+            _ = tokio::time::sleep(WATCH_POLL_INTERVAL) => {}
+        }
+    }
+}
+
+impl SessionState {
+    /// 开启或关闭当前目录的监视
+    pub fn sftp_toggle_watch(&mut self, tab_id: &str, cx: &mut gpui::Context<Self>) {
+        let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        else {
+            return;
+        };
+
+        if let Some(token) = sftp_state.watch_cancel.take() {
+            info!("[SFTP] Directory watch off for tab {}", tab_id);
+            token.cancel();
+            sftp_state.watch_cycle = None;
+            cx.notify();
+            return;
+        }
+
+        info!("[SFTP] Directory watch on for tab {}", tab_id);
+        let token = CancellationToken::new();
+        sftp_state.watch_cancel = Some(token.clone());
+        cx.notify();
+        self.sftp_run_watch(tab_id.to_string(), token, cx);
+    }
+
+    /// 监视循环：每轮读取当前目录并在后台等待变化，变化后静默更新文件列表
+    fn sftp_run_watch(
+        &mut self,
+        tab_id: String,
+        token: CancellationToken,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let session_state = cx.entity().clone();
+        let sftp_services = self.sftp_services.clone();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let mut mode = WatchMode::Unknown;
+                let mut baseline: Option<(String, u64)> = None;
+                while !token.is_cancelled() {
+                    // 每轮开始时确认当前目录，并登记本轮的取消令牌
+                    let cycle_token = token.child_token();
+                    let path = async_cx
+                        .update(|cx| {
+                            session_state.update(cx, |state, _| {
+                                let sftp_state = state
+                                    .tabs
+                                    .iter_mut()
+                                    .find(|t| t.id == tab_id)
+                                    .and_then(|t| t.sftp_state.as_mut())?;
+                                sftp_state.watch_cycle = Some(cycle_token.clone());
+                                Some(sftp_state.current_path.clone())
+                            })
+                        })
+                        .ok()
+                        .flatten();
+                    let Some(path) = path else {
+                        break;
+                    };
+                    let service = sftp_services
+                        .lock()
+                        .ok()
+                        .and_then(|s| s.get(&tab_id).cloned());
+                    let session = crate::ssh::manager::SshManager::global().get_session(&tab_id);
+                    let (Some(service), Some(session)) = (service, session) else {
+                        break;
+                    };
+
+                    let mut cycle = WatchCycle {
+                        baseline: baseline
+                            .take()
+                            .filter(|(p, _)| *p == path)
+                            .map(|(_, signature)| signature),
+                        path,
+                        mode,
+                    };
+                    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+                    let task_token = cycle_token.clone();
+                    crate::ssh::manager::SshManager::global()
+                        .runtime()
+                        .spawn(async move {
+                            let result =
+                                wait_for_change(session, service, &mut cycle, &task_token).await;
+                            let _ = tx.send((result, cycle));
+                        });
+                    let Some((result, cycle)) = rx.recv().await else {
+                        break;
+                    };
+                    mode = cycle.mode;
+                    baseline = cycle
+                        .baseline
+                        .map(|signature| (cycle.path.clone(), signature));
+
+                    match result {
+                        Ok(WatchEvent::Changed(entries)) if !cycle_token.is_cancelled() => {
+                            debug!("[SFTP] Watched directory changed: {}", cycle.path);
+                            let _ = async_cx.update(|cx| {
+                                session_state.update(cx, |state, cx| {
+                                    state.sftp_apply_watched(&tab_id, cycle.path, entries, cx);
+                                });
+                            });
+                        }
+                        Ok(_) => {}
+                        Err(e) => {
+                            // 读取失败（如无权限或连接中断）时放慢节奏，避免频繁重试
+                            warn!("[SFTP] Directory watch failed for {}: {}", cycle.path, e);
+                            async_cx
+                                .background_executor()
+                                .timer(WATCH_POLL_INTERVAL)
+                                .await;
+                        }
+                    }
+                }
+                debug!("[SFTP] Directory watch loop ended for tab {}", tab_id);
+            })
+            .detach();
+    }
+
+    /// 将监视读取到的新内容写入缓存与文件列表（正在加载时跳过，由加载结果为准）
+    fn sftp_apply_watched(
+        &mut self,
+        tab_id: &str,
+        path: String,
+        entries: Vec<FileEntry>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let Some(sftp_state) = self
+            .tabs
+            .iter_mut()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.sftp_state.as_mut())
+        else {
+            return;
+        };
+        if !sftp_state.is_watching()
+            || sftp_state.current_path != path
+            || sftp_state.loading
+            || sftp_state.sudo_listing
+        {
+            return;
+        }
+        sftp_state.update_cache(path, entries.clone());
+        sftp_state.set_listing(entries, false);
+        cx.notify();
+    }
+}
@@ -0,0 +1,99 @@
+// 运行快捷命令：含 {{变量}} 占位符时先弹出变量表单，填写后替换变量再发送到终端
+
+use super::SessionState;
+use crate::components::common::snippet_variables_dialog::{
+    SnippetVariablesDialogState, SnippetVariablesEvent,
+};
+use crate::models::snippets::{fill_variables, parse_variables};
+use crate::services::storage;
+use gpui::prelude::*;
+use gpui::Entity;
+use tracing::error;
+
+impl SessionState {
+    /// 确保快捷命令变量表单状态已创建
+    pub fn ensure_snippet_variables_dialog(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<SnippetVariablesDialogState> {
+        if self.snippet_variables_dialog.is_none() {
+            let dialog = cx.new(|_| SnippetVariablesDialogState::default());
+            cx.subscribe(
+                &dialog,
+                |this, dialog, event: &SnippetVariablesEvent, cx| match event {
+                    SnippetVariablesEvent::Submit => this.confirm_snippet_variables(dialog, cx),
+                },
+            )
+            .detach();
+            self.snippet_variables_dialog = Some(dialog);
+        }
+        self.snippet_variables_dialog.clone().unwrap()
+    }
+
+    /// 获取快捷命令变量表单状态（如果存在）
+    pub fn get_snippet_variables_dialog(&self) -> Option<Entity<SnippetVariablesDialogState>> {
+        self.snippet_variables_dialog.clone()
+    }
+
+    /// 在当前标签的激活终端中运行快捷命令，没有可用终端时返回 false
+    ///
+    /// 命令含变量占位符时打开变量表单（初始值为上次在该服务器上填写的值），确认后再发送
+    pub fn run_snippet(&mut self, command: &str, cx: &mut gpui::Context<Self>) -> bool {
+        let Some((tab_id, server_id)) = self
+            .active_tab()
+            .map(|tab| (tab.id.clone(), tab.server_id.clone()))
+        else {
+            return false;
+        };
+        let has_terminal = self
+            .active_terminal_instance(&tab_id)
+            .is_some_and(|inst| inst.pty_channel.is_some());
+        if !has_terminal {
+            return false;
+        }
+
+        let variables = parse_variables(command);
+        if variables.is_empty() {
+            return self.run_in_terminal(&tab_id, command, cx);
+        }
+
+        let remembered = storage::load_snippets()
+            .ok()
+            .and_then(|mut config| config.variable_values.remove(&server_id));
+        let dialog = self.ensure_snippet_variables_dialog(cx);
+        dialog.update(cx, |d, _| {
+            d.open(
+                command.to_string(),
+                variables,
+                tab_id,
+                server_id,
+                remembered.as_ref(),
+            )
+        });
+        cx.notify();
+        true
+    }
+
+    /// 变量表单确认：记住填写的值，替换变量后发送到终端
+    fn confirm_snippet_variables(
+        &mut self,
+        dialog: Entity<SnippetVariablesDialogState>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (tab_id, server_id, template, values) = {
+            let d = dialog.read(cx);
+            (
+                d.tab_id.clone(),
+                d.server_id.clone(),
+                d.template.clone(),
+                d.values(cx),
+            )
+        };
+        if let Err(e) = storage::remember_snippet_variables(&server_id, &values) {
+            error!("[Snippets] Failed to remember variable values: {}", e);
+        }
+        self.run_in_terminal(&tab_id, &fill_variables(&template, &values), cx);
+        dialog.update(cx, |d, _| d.close());
+        cx.notify();
+    }
+}
@@ -0,0 +1,73 @@
+// SSH 配置片段：为服务器生成等效的 OpenSSH Host 块与 ssh 命令，便于在应用外复现连接
+
+use super::SessionState;
+use crate::components::common::ssh_snippet_dialog::SshSnippetDialogState;
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::{server_export, storage};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tracing::error;
+
+impl SessionState {
+    /// 获取 SSH 配置片段弹窗状态（如果存在）
+    pub fn get_ssh_snippet_dialog(&self) -> Option<Entity<SshSnippetDialogState>> {
+        self.ssh_snippet_dialog.clone()
+    }
+
+    /// 打开服务器的 SSH 配置片段弹窗
+    ///
+    /// 从标签打开（`tab_id` 为 Some）时使用该标签的连接信息与端口转发，
+    /// 从主页打开时包含该服务器已打开会话中的端口转发
+    pub fn open_ssh_snippet(
+        &mut self,
+        server_id: &str,
+        tab_id: Option<String>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let tab = match &tab_id {
+            Some(tab_id) => self.tabs.iter().find(|t| &t.id == tab_id),
+            None => self
+                .tabs
+                .iter()
+                .find(|t| t.server_id == server_id && self.port_forward_views.contains_key(&t.id)),
+        };
+        // 临时连接的服务器信息保存在标签上
+        let server = tab.and_then(|t| t.server_data.clone()).or_else(|| {
+            storage::load_servers()
+                .ok()?
+                .servers
+                .into_iter()
+                .find(|s| s.id == server_id)
+        });
+        let Some(server) = server else {
+            return;
+        };
+        let forwards = tab
+            .and_then(|t| self.port_forward_views.get(&t.id))
+            .map(|view| view.read(cx).specs())
+            .unwrap_or_default();
+
+        let snippet = match server_export::server_snippet(&server, &forwards) {
+            Ok(snippet) => snippet,
+            Err(e) => {
+                error!("[SshSnippet] Failed to generate snippet: {:#}", e);
+                notify_key(
+                    cx,
+                    NotificationCategory::Operation,
+                    NotificationType::Error,
+                    "ssh_snippet.failed",
+                );
+                return;
+            }
+        };
+        let dialog = self
+            .ssh_snippet_dialog
+            .get_or_insert_with(|| cx.new(|_| SshSnippetDialogState::default()))
+            .clone();
+        dialog.update(cx, |d, _| {
+            d.open(tab_id, server.label.clone(), snippet, forwards.len())
+        });
+        cx.notify();
+    }
+}
@@ -0,0 +1,243 @@
+// systemd 服务管理方法：轮询服务列表、执行 systemctl 操作（权限不足时改用 sudo，必要时弹窗输入密码），并在新终端中查看日志
+
+use std::time::Duration;
+
+use super::SessionState;
+use crate::components::systemd::{ServiceEvent, ServiceListView};
+use crate::services::notifications::{notify_key, NotificationCategory};
+use crate::services::sftp::sudo_check;
+use crate::services::systemd::{
+    is_permission_error, journal_command, list_services, service_action, ServiceUnit,
+};
+use gpui::prelude::*;
+use gpui::Entity;
+use gpui_component::notification::NotificationType;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+/// 服务列表刷新间隔
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 服务操作的执行结果
+enum ServiceActionOutcome {
+    /// 操作成功；`passwordless_sudo` 表示改用了免密 sudo，`services` 为操作后的服务列表
+    Done {
+        passwordless_sudo: bool,
+        services: Option<Vec<ServiceUnit>>,
+    },
+    /// 权限不足且 sudo 需要密码
+    NeedsPassword,
+    Failed(String),
+}
+
+impl SessionState {
+    /// 确保服务管理视图已创建，并只让该标签页轮询服务列表
+    pub fn ensure_service_view(
+        &mut self,
+        tab_id: &str,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<ServiceListView> {
+        if !self.service_views.contains_key(tab_id) {
+            let session_state = cx.entity().clone();
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                ServiceListView::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| {
+                        state.service_action(&tab_id_for_event, event, cx);
+                    });
+                })
+            });
+            self.service_views.insert(tab_id.to_string(), view);
+        }
+        let view = self.service_views.get(tab_id).unwrap().clone();
+
+        // 切换标签页后停止其他标签页的轮询
+        self.service_poll_tokens.retain(|id, token| {
+            let keep = id == tab_id;
+            if !keep {
+                token.cancel();
+            }
+            keep
+        });
+        if !self.service_poll_tokens.contains_key(tab_id) {
+            self.start_service_polling(tab_id, view.clone(), cx);
+        }
+        view
+    }
+
+    /// 获取服务管理视图（如果存在）
+    pub fn get_service_view(&self, tab_id: &str) -> Option<Entity<ServiceListView>> {
+        self.service_views.get(tab_id).cloned()
+    }
+
+    /// 停止所有标签页的服务列表轮询
+    pub(super) fn stop_service_polling(&mut self) {
+        for (_, token) in self.service_poll_tokens.drain() {
+            token.cancel();
+        }
+    }
+
+    /// 开始定时获取服务列表并写入视图
+    fn start_service_polling(
+        &mut self,
+        tab_id: &str,
+        view: Entity<ServiceListView>,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Systemd] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        info!("[Systemd] Start polling for tab {}", tab_id);
+
+        let token = CancellationToken::new();
+        self.service_poll_tokens
+            .insert(tab_id.to_string(), token.clone());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let token_for_task = token.clone();
+        ssh_manager.runtime().spawn(async move {
+            loop {
+                let result = tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    result = list_services(&session) => result,
+                };
+                if tx.send(result).is_err() || !session.is_alive() {
+                    break;
+                }
+                tokio::select! {
+                    _ = token_for_task.cancelled() => break,
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                }
+            }
+        });
+
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                while let Some(result) = rx.recv().await {
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    let updated = async_cx.update(|cx| {
+                        view.update(cx, |v, cx| v.set_services(result, cx));
+                    });
+                    if updated.is_err() {
+                        break;
+                    }
+                }
+                info!("[Systemd] Polling ended for tab {}", tab_id);
+            })
+            .detach();
+    }
+
+    /// 执行服务操作
+    ///
+    /// 本标签页已验证过 sudo 时直接通过 sudo 执行；否则先以当前用户执行，权限不足时尝试免密 sudo，
+    /// 需要密码时弹出 sudo 密码对话框，验证通过后重新执行
+    pub(super) fn service_action(
+        &mut self,
+        tab_id: &str,
+        event: ServiceEvent,
+        cx: &mut gpui::Context<Self>,
+    ) {
+        let (unit, action) = match event.clone() {
+            ServiceEvent::Journal(unit) => {
+                self.add_terminal_instance_with_command(tab_id, journal_command(&unit));
+                cx.notify();
+                return;
+            }
+            ServiceEvent::Action(unit, action) => (unit, action),
+        };
+
+        let Some(view) = self.service_views.get(tab_id).cloned() else {
+            return;
+        };
+        let ssh_manager = crate::ssh::manager::SshManager::global();
+        let Some(session) = ssh_manager.get_session(tab_id) else {
+            error!("[Systemd] No SSH session found for tab {}", tab_id);
+            return;
+        };
+        let password = self.sftp_sudo_passwords.get(tab_id).cloned();
+        let unit_for_dialog = unit.clone();
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        ssh_manager.runtime().spawn(async move {
+            let mut passwordless_sudo = false;
+            let mut result = service_action(&session, &unit, action, password.as_deref()).await;
+            if password.is_none() && result.as_ref().is_err_and(|e| is_permission_error(e)) {
+                if sudo_check(&session, None).await.is_err() {
+                    let _ = tx.send(ServiceActionOutcome::NeedsPassword);
+                    return;
+                }
+                passwordless_sudo = true;
+                result = service_action(&session, &unit, action, Some("")).await;
+            }
+            // 操作完成后立即刷新列表，不等下一次轮询
+            let _ = tx.send(match result {
+                Ok(()) => ServiceActionOutcome::Done {
+                    passwordless_sudo,
+                    services: list_services(&session).await.ok(),
+                },
+                Err(e) => ServiceActionOutcome::Failed(e),
+            });
+        });
+
+        let session_state = cx.entity().clone();
+        let tab_id = tab_id.to_string();
+        cx.to_async()
+            .spawn(async move |async_cx| {
+                let Some(outcome) = rx.recv().await else {
+                    return;
+                };
+                let _ = async_cx.update(|cx| match outcome {
+                    ServiceActionOutcome::Done {
+                        passwordless_sudo,
+                        services,
+                    } => {
+                        if passwordless_sudo {
+                            // 免密 sudo 以空密码记录
+                            session_state.update(cx, |state, _| {
+                                state.sftp_sudo_passwords.insert(tab_id, String::new());
+                            });
+                        }
+                        if let Some(services) = services {
+                            view.update(cx, |v, cx| v.set_services(Ok(services), cx));
+                        }
+                        notify_key(
+                            cx,
+                            NotificationCategory::Operation,
+                            NotificationType::Success,
+                            "services.action_done",
+                        )
+                    }
+                    ServiceActionOutcome::NeedsPassword => {
+                        session_state.update(cx, |state, cx| {
+                            let dialog = state.ensure_sftp_sudo_dialog(cx);
+                            dialog.update(cx, |d, _| {
+                                d.open_with(
+                                    unit_for_dialog,
+                                    tab_id.clone(),
+                                    "services.sudo.title",
+                                    "services.sudo.prompt",
+                                )
+                            });
+                            state.sudo_pending_cron_loads.remove(&tab_id);
+                            state.sudo_pending_package_actions.remove(&tab_id);
+                            state.sudo_pending_firewall_actions.remove(&tab_id);
+                            state.sudo_pending_sftp_lists.remove(&tab_id);
+                            state.sudo_pending_service_actions.insert(tab_id, event);
+                            cx.notify();
+                        });
+                    }
+                    ServiceActionOutcome::Failed(e) => {
+                        error!("[Systemd] Action failed: {}", e);
+                        view.update(cx, |v, cx| v.set_action_error(Some(e), cx));
+                    }
+                });
+            })
+            .detach();
+    }
+}
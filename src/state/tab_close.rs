@@ -0,0 +1,80 @@
+// 关闭标签：有进行中的工作（已打开的终端、未完成的传输、运行中的端口转发）时先确认，
+// "关闭其他标签页"与"关闭全部"合并为一次确认
+
+use super::{PendingTabClose, SessionState, TabActiveWork};
+use gpui::WindowId;
+
+impl SessionState {
+    /// 标签上进行中的工作
+    pub fn tab_active_work(&self, tab_id: &str) -> Option<TabActiveWork> {
+        let tab = self.tabs.iter().find(|t| t.id == tab_id)?;
+        Some(TabActiveWork {
+            tab_id: tab.id.clone(),
+            label: tab.server_label.clone(),
+            terminals: tab
+                .terminals
+                .iter()
+                .filter(|t| t.pty_channel.is_some())
+                .count(),
+            transfers: tab
+                .active_transfers
+                .iter()
+                .filter(|t| t.status.is_active())
+                .count(),
+            port_forwards: self
+                .port_forward_tokens
+                .get(tab_id)
+                .map(|tokens| tokens.len())
+                .unwrap_or(0),
+        })
+    }
+
+    /// 同一窗口中除指定标签与固定标签以外的标签
+    pub fn other_tab_ids(&self, tab_id: &str, window_id: Option<WindowId>) -> Vec<String> {
+        self.window_tabs(window_id)
+            .filter(|t| t.id != tab_id && !t.pinned)
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
+    /// 窗口中所有未固定的标签
+    pub fn closable_tab_ids(&self, window_id: Option<WindowId>) -> Vec<String> {
+        self.window_tabs(window_id)
+            .filter(|t| !t.pinned)
+            .map(|t| t.id.clone())
+            .collect()
+    }
+
+    /// 请求关闭标签：没有进行中的工作时直接关闭，否则等待确认
+    pub fn request_close_tabs(&mut self, tab_ids: Vec<String>, window_id: Option<WindowId>) {
+        let work: Vec<TabActiveWork> = tab_ids
+            .iter()
+            .filter_map(|id| self.tab_active_work(id))
+            .filter(|work| !work.is_empty())
+            .collect();
+        if work.is_empty() {
+            for tab_id in &tab_ids {
+                self.close_tab(tab_id);
+            }
+        } else {
+            self.pending_tab_close = Some(PendingTabClose {
+                window_id,
+                tab_ids,
+                work,
+            });
+        }
+    }
+
+    /// 确认关闭等待中的标签
+    pub fn confirm_pending_close(&mut self) {
+        if let Some(pending) = self.pending_tab_close.take() {
+            for tab_id in &pending.tab_ids {
+                self.close_tab(tab_id);
+            }
+        }
+    }
+
+    pub fn cancel_pending_close(&mut self) {
+        self.pending_tab_close = None;
+    }
+}
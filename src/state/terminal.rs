@@ -1,6 +1,8 @@
 // 终端管理方法
 
 use super::{SessionState, SessionStatus, TerminalInstance};
+use crate::models::server::ServerData;
+use crate::services::storage;
 use gpui::prelude::*;
 use tracing::{debug, error, info};
 
@@ -21,8 +23,8 @@ impl SessionState {
         // 查找 tab 并检查状态
         let tab_id_owned = tab_id.to_string();
 
-        // 获取需要初始化的终端实例 ID 和现有终端状态（如果有）
-        let (terminal_instance_id, existing_terminal) = {
+        // 获取需要初始化的终端实例 ID、现有终端状态（如果有）和服务器配置
+        let (terminal_instance_id, existing_terminal, server_data) = {
             let Some(tab) = self.tabs.iter().find(|t| t.id == tab_id) else {
                 return;
             };
@@ -39,7 +41,11 @@ impl SessionState {
                 return;
             }
             // 获取现有终端状态（用于重连时保留历史）
-            (active_id.clone(), instance.terminal.clone())
+            (
+                active_id.clone(),
+                instance.terminal.clone(),
+                tab.server_data.clone(),
+            )
         };
 
         info!(
@@ -57,10 +63,14 @@ impl SessionState {
             area_width, area_height
         );
 
-        // 创建终端设置
+        // 创建终端设置（应用服务器的覆盖设置）
         let settings = crate::services::storage::load_settings()
             .unwrap_or_default()
             .terminal;
+        let settings = match &server_data {
+            Some(server) => server.resolve_terminal_settings(settings),
+            None => settings,
+        };
 
         // 重用现有 TerminalState（保留历史）或创建新的
         let terminal_state = if let Some(existing) = existing_terminal {
@@ -103,7 +113,8 @@ impl SessionState {
         }
 
         // 启动光标闪烁定时器 (500ms 间隔)
-        let terminal_for_blink = terminal_state.clone();
+        // 使用弱引用，避免定时器阻止终端状态在关闭/休眠后被释放
+        let terminal_for_blink = terminal_state.downgrade();
         cx.to_async()
             .spawn(async move |async_cx| {
                 loop {
@@ -121,8 +132,8 @@ impl SessionState {
                         });
                     });
 
-                    // 如果更新失败（例如终端已关闭），退出循环
-                    if result.is_err() {
+                    // 如果更新失败（例如终端已关闭或已休眠），退出循环
+                    if !matches!(result, Ok(Ok(()))) {
                         break;
                     }
                 }
@@ -167,12 +178,39 @@ impl SessionState {
                                 if let Some(tab) =
                                     state.tabs.iter_mut().find(|t| t.id == session_id_for_state)
                                 {
+                                    // 首次创建 PTY 时执行服务器配置的连接后命令与自动执行的快捷命令
+                                    let post_connect = if tab.services_started {
+                                        None
+                                    } else {
+                                        tab.server_data.as_ref().and_then(post_connect_script)
+                                    };
                                     if let Some(instance) = tab
                                         .terminals
                                         .iter_mut()
                                         .find(|t| t.id == terminal_id_for_state)
                                     {
-                                        instance.pty_channel = Some(channel_for_state);
+                                        instance.pty_channel = Some(channel_for_state.clone());
+                                        // 连接后命令在启动命令之前发送
+                                        let startup = match (post_connect, instance.startup_command.take()) {
+                                            (Some(script), Some(command)) => Some(script + &command),
+                                            (script, command) => script.or(command),
+                                        };
+                                        // 发送创建实例时指定的启动命令
+                                        if let Some(command) = startup {
+                                            crate::ssh::manager::SshManager::global()
+                                                .runtime()
+                                                .spawn(async move {
+                                                    if let Err(e) = channel_for_state
+                                                        .write(command.as_bytes())
+                                                        .await
+                                                    {
+                                                        error!(
+                                                            "[Terminal] Failed to send startup command: {:?}",
+                                                            e
+                                                        );
+                                                    }
+                                                });
+                                        }
                                     }
 
                                     // 只有首次 PTY 创建时才启动 Monitor 和 SFTP 服务
@@ -183,6 +221,8 @@ impl SessionState {
                                             cx,
                                         );
                                         state.start_sftp_service(session_id_for_state.clone(), cx);
+                                        // 通知插件会话已就绪
+                                        state.notify_plugins_connected(&session_id_for_state);
                                     }
                                 }
                             });
@@ -320,6 +360,28 @@ impl SessionState {
             .detach();
     }
 
+    /// 设置保存后应用到所有已打开的终端（字体度量、回滚行数）
+    /// 远端 PTY 尺寸在终端下次同步尺寸时更新
+    pub fn apply_terminal_settings(&mut self, window: &gpui::Window, cx: &mut gpui::Context<Self>) {
+        let global = storage::load_settings().unwrap_or_default().terminal;
+        for tab in &self.tabs {
+            let settings = match &tab.server_data {
+                Some(server) => server.resolve_terminal_settings(global.clone()),
+                None => global.clone(),
+            };
+            let (cell_width, line_height) =
+                crate::terminal::measure_terminal_cell(&settings, window);
+            for terminal in tab.terminals.iter().filter_map(|t| t.terminal.clone()) {
+                terminal.update(cx, |t, cx| {
+                    t.apply_settings(settings.clone(), cell_width, line_height);
+                    cx.notify();
+                });
+            }
+        }
+        info!("[Terminal] Applied updated terminal settings to open sessions");
+        cx.notify();
+    }
+
     /// 添加新的终端实例到指定会话标签
     /// 返回新终端实例的 ID
     pub fn add_terminal_instance(&mut self, tab_id: &str) -> Option<String> {
@@ -336,6 +398,8 @@ impl SessionState {
             pty_initialized: false,
             last_sent_pty_size: None,
             pty_error: None,
+            snapshot: None,
+            startup_command: None,
         };
         let new_id = new_instance.id.clone();
         tab.terminals.push(new_instance);
@@ -348,6 +412,20 @@ impl SessionState {
         Some(new_id)
     }
 
+    /// 添加新的终端实例，并在 PTY 创建后自动执行指定命令
+    /// 返回新终端实例的 ID
+    pub fn add_terminal_instance_with_command(
+        &mut self,
+        tab_id: &str,
+        command: String,
+    ) -> Option<String> {
+        let new_id = self.add_terminal_instance(tab_id)?;
+        let tab = self.tabs.iter_mut().find(|t| t.id == tab_id)?;
+        let instance = tab.terminals.iter_mut().find(|t| t.id == new_id)?;
+        instance.startup_command = Some(command);
+        Some(new_id)
+    }
+
     /// 关闭指定的终端实例
     pub fn close_terminal_instance(&mut self, tab_id: &str, terminal_id: &str) {
         let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
@@ -412,4 +490,56 @@ impl SessionState {
             .find(|t| t.id == tab_id)
             .and_then(|tab| tab.active_terminal_id.clone())
     }
+
+    /// 在指定标签的激活终端中执行命令（追加回车），没有可用终端时返回 false
+    pub fn run_in_terminal(&self, tab_id: &str, command: &str, cx: &mut gpui::App) -> bool {
+        let Some(terminal_id) = self
+            .active_terminal_instance(tab_id)
+            .map(|inst| inst.id.clone())
+        else {
+            return false;
+        };
+        self.run_in_terminal_instance(tab_id, &terminal_id, command, cx)
+    }
+
+    /// 在指定终端实例中执行命令（追加回车），终端不存在或未连接时返回 false
+    pub fn run_in_terminal_instance(
+        &self,
+        tab_id: &str,
+        terminal_id: &str,
+        command: &str,
+        cx: &mut gpui::App,
+    ) -> bool {
+        let Some(channel) = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.terminals.iter().find(|inst| inst.id == terminal_id))
+            .and_then(|inst| inst.pty_channel.clone())
+        else {
+            return false;
+        };
+        let mut data = command.as_bytes().to_vec();
+        data.push(0x0d); // CR
+        debug!("[Terminal] Running command in tab {}: {}", tab_id, command);
+        cx.spawn(async move |_| {
+            if let Err(e) = channel.write(&data).await {
+                error!("[Terminal] Failed to send command: {:?}", e);
+            }
+        })
+        .detach();
+        true
+    }
+}
+
+/// 连接后要写入终端的命令：服务器配置的连接后命令在前，范围内自动执行的快捷命令在后
+fn post_connect_script(server: &ServerData) -> Option<String> {
+    let servers = storage::load_servers().unwrap_or_default();
+    let snippets = storage::load_snippets()
+        .ok()
+        .and_then(|config| config.auto_run_script(server, &servers));
+    match (server.post_connect_script(), snippets) {
+        (Some(script), Some(snippets)) => Some(script + &snippets),
+        (script, snippets) => script.or(snippets),
+    }
 }
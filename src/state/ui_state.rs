@@ -1,8 +1,12 @@
 // UI 状态管理方法：对话框、输入框、焦点句柄等
 
+use super::sftp_compare::local_compare_target;
 use super::SessionState;
 use crate::components::monitor::DetailDialogState;
-use crate::components::sftp::{FileListView, PathBarEvent, PathBarState};
+use crate::components::sftp::{
+    FileListView, LocalPanelEvent, LocalPanelState, PathBarEvent, PathBarState, SftpSearchEvent,
+    SftpSearchState,
+};
 use crate::services::monitor::{MonitorEvent, MonitorService, MonitorSettings};
 use gpui::prelude::*;
 use gpui::{Entity, FocusHandle};
@@ -48,8 +52,11 @@ impl SessionState {
                                 this.sftp_open(&tab_id, path, cx);
                             }
                         }
-                        TableEvent::SelectRow(_row_ix) => {
-                            // TODO: 处理选择事件
+                        TableEvent::SelectRow(row_ix) => {
+                            // 预览面板打开时预览选中的文件
+                            if let Some(path) = view_for_table.read(cx).get_file_path(*row_ix, cx) {
+                                this.sftp_preview_path(&tab_id_for_table, path, cx);
+                            }
                         }
                         _ => {}
                     }
@@ -89,6 +96,16 @@ impl SessionState {
                         FileListContextMenuEvent::RenameConfirmed { old_path, new_name } => {
                             this.sftp_rename(&tab_id, old_path.clone(), new_name.clone(), cx);
                         }
+                        FileListContextMenuEvent::MoveEntry { source, target_dir } => {
+                            // 拖动移动到文件夹
+                            this.sftp_move_into(&tab_id, source.clone(), target_dir.clone(), cx);
+                        }
+                        FileListContextMenuEvent::DragOut(path) => {
+                            // 拖出窗口：下载到系统下载目录
+                            if let Some(dir) = dirs::download_dir().or_else(dirs::home_dir) {
+                                this.sftp_download_into(&tab_id, path.clone(), dir, false, cx);
+                            }
+                        }
                         FileListContextMenuEvent::Download(path) => {
                             // 下载单个文件 - 需要获取文件信息
                             if let Some(tab) = this.tabs.iter().find(|t| t.id == tab_id) {
@@ -152,11 +169,16 @@ impl SessionState {
                                         &tab_id,
                                         path.clone(),
                                         target_dir.clone(),
+                                        true,
                                         cx,
                                     );
                                 }
                             }
                         }
+                        FileListContextMenuEvent::EmptyTrash => {
+                            // 清空远程回收站
+                            this.sftp_request_empty_trash(&tab_id, cx);
+                        }
                         FileListContextMenuEvent::NewFolder => {
                             // 新建文件夹
                             this.sftp_open_new_folder_dialog(&tab_id, cx);
@@ -165,18 +187,78 @@ impl SessionState {
                             // 新建文件
                             this.sftp_open_new_file_dialog(&tab_id, cx);
                         }
+                        FileListContextMenuEvent::NewLink(target) => {
+                            // 新建符号链接/硬链接
+                            this.sftp_open_new_link_dialog(&tab_id, target.clone(), cx);
+                        }
+                        FileListContextMenuEvent::FollowLink(path) => {
+                            // 跳转到符号链接的目标
+                            this.sftp_follow_link(&tab_id, path.clone(), cx);
+                        }
+                        FileListContextMenuEvent::RetryLoad => {
+                            // 重新读取当前目录（通道失效时会自动重新打开）
+                            this.sftp_refresh(&tab_id, cx);
+                        }
+                        FileListContextMenuEvent::SudoList => {
+                            // 权限不足时以 sudo 列出当前目录
+                            this.sftp_sudo_list(&tab_id, cx);
+                        }
+                        FileListContextMenuEvent::CancelLoad => {
+                            // 停止当前目录的分批加载
+                            this.sftp_cancel_listing(&tab_id, cx);
+                        }
+                        FileListContextMenuEvent::CompareSelect(path, is_dir) => {
+                            // 选择用于比较
+                            if let Some(target) = this.sftp_compare_target(&tab_id, path, *is_dir) {
+                                this.sftp_compare_select(target, cx);
+                            }
+                        }
+                        FileListContextMenuEvent::CompareWith(path, is_dir) => {
+                            // 与已选对象比较
+                            if let Some(target) = this.sftp_compare_target(&tab_id, path, *is_dir) {
+                                this.sftp_compare_with(target, cx);
+                            }
+                        }
                         FileListContextMenuEvent::OpenInTerminal(path) => {
                             // 在终端中打开目录
                             this.sftp_open_in_terminal(&tab_id, path.clone(), cx);
                         }
-                        FileListContextMenuEvent::Properties(path) => {
-                            // 显示属性对话框
-                            this.sftp_open_properties_dialog(&tab_id, path.clone(), cx);
+                        FileListContextMenuEvent::SyncDirectory(path) => {
+                            // 打开目录同步对话框
+                            this.sftp_open_sync_dialog(&tab_id, path.clone(), cx);
+                        }
+                        FileListContextMenuEvent::CopyToServer(path) => {
+                            // 打开复制到其他服务器对话框
+                            this.sftp_open_copy_to_server_dialog(&tab_id, path.clone(), cx);
+                        }
+                        FileListContextMenuEvent::Compress(path, format) => {
+                            // 压缩为归档文件
+                            this.sftp_compress(&tab_id, path.clone(), *format, cx);
+                        }
+                        FileListContextMenuEvent::Extract(path) => {
+                            // 解压到所在目录
+                            this.sftp_extract(&tab_id, path.clone(), cx);
+                        }
+                        FileListContextMenuEvent::Properties(paths) => {
+                            // 显示属性对话框（多选时显示汇总信息）
+                            this.sftp_open_properties_dialog(&tab_id, paths.clone(), cx);
+                        }
+                        FileListContextMenuEvent::HexView(path) => {
+                            // 十六进制查看
+                            this.sftp_open_hex_viewer(&tab_id, path.clone(), cx);
+                        }
+                        FileListContextMenuEvent::TailLog(path) => {
+                            // 在日志查看器中持续跟踪
+                            this.sftp_open_log_viewer(&tab_id, Some(path.clone()), cx);
                         }
                         FileListContextMenuEvent::EditFile(path) => {
                             // 编辑文件（外置编辑器）
                             this.sftp_edit_file(&tab_id, path.clone(), cx);
                         }
+                        FileListContextMenuEvent::EditAsRoot(path) => {
+                            // 以管理员身份编辑（sudo 写回）
+                            this.sftp_edit_file_as_root(&tab_id, path.clone(), cx);
+                        }
                         FileListContextMenuEvent::CopyName(name) => {
                             // 复制文件名到剪贴板
                             cx.write_to_clipboard(gpui::ClipboardItem::new_string(name.clone()));
@@ -185,6 +267,16 @@ impl SessionState {
                             // 复制完整路径到剪贴板
                             cx.write_to_clipboard(gpui::ClipboardItem::new_string(path.clone()));
                         }
+                        FileListContextMenuEvent::TransferToLocal {
+                            path,
+                            remove_source,
+                        } => {
+                            // 双面板：复制/移动到本地面板当前目录
+                            this.sftp_transfer_to_local(&tab_id, path.clone(), *remove_source, cx);
+                        }
+                        FileListContextMenuEvent::GoToPath => {
+                            this.sftp_start_go_to_path(&tab_id, window, cx);
+                        }
                         _ => {
                             // 其他事件
                         }
@@ -232,6 +324,95 @@ impl SessionState {
         self.sftp_path_bar_states.get(tab_id).cloned()
     }
 
+    /// 确保 SFTP 搜索状态已创建
+    pub fn ensure_sftp_search_state(
+        &mut self,
+        tab_id: &str,
+        session_state: Entity<SessionState>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<SftpSearchState> {
+        if !self.sftp_search_states.contains_key(tab_id) {
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                SftpSearchState::new(window, cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| match event {
+                        SftpSearchEvent::Start(query, token) => {
+                            state.sftp_search(&tab_id_for_event, query, token, cx);
+                        }
+                        SftpSearchEvent::Reveal(hit) => {
+                            state.sftp_reveal_search_hit(&tab_id_for_event, hit, cx);
+                        }
+                    });
+                })
+            });
+            self.sftp_search_states.insert(tab_id.to_string(), view);
+        }
+        let view = self.sftp_search_states.get(tab_id).unwrap().clone();
+        // 语言可能已变化，同步输入框占位符
+        view.update(cx, |state, cx| state.sync_language(window, cx));
+        view
+    }
+
+    /// 获取 SFTP 搜索状态（如果存在）
+    pub fn get_sftp_search_state(&self, tab_id: &str) -> Option<Entity<SftpSearchState>> {
+        self.sftp_search_states.get(tab_id).cloned()
+    }
+
+    /// 确保 SFTP 本地面板已创建
+    pub fn ensure_sftp_local_panel(
+        &mut self,
+        tab_id: &str,
+        session_state: Entity<SessionState>,
+        cx: &mut gpui::Context<Self>,
+    ) -> Entity<LocalPanelState> {
+        if !self.sftp_local_panels.contains_key(tab_id) {
+            let tab_id_for_event = tab_id.to_string();
+            let view = cx.new(|cx| {
+                LocalPanelState::new(cx, move |event, cx| {
+                    session_state.update(cx, |state, cx| match event {
+                        LocalPanelEvent::Upload {
+                            paths,
+                            remove_source,
+                        } => {
+                            state.sftp_transfer_to_remote(
+                                &tab_id_for_event,
+                                paths,
+                                remove_source,
+                                cx,
+                            );
+                        }
+                        LocalPanelEvent::Download {
+                            remote_path,
+                            target_dir,
+                        } => {
+                            state.sftp_download_into(
+                                &tab_id_for_event,
+                                remote_path,
+                                target_dir,
+                                false,
+                                cx,
+                            );
+                        }
+                        LocalPanelEvent::CompareSelect { path, is_dir } => {
+                            state.sftp_compare_select(local_compare_target(path, is_dir), cx);
+                        }
+                        LocalPanelEvent::CompareWith { path, is_dir } => {
+                            state.sftp_compare_with(local_compare_target(path, is_dir), cx);
+                        }
+                    });
+                })
+            });
+            self.sftp_local_panels.insert(tab_id.to_string(), view);
+        }
+        self.sftp_local_panels.get(tab_id).unwrap().clone()
+    }
+
+    /// 获取 SFTP 本地面板（如果存在）
+    pub fn get_sftp_local_panel(&self, tab_id: &str) -> Option<Entity<LocalPanelState>> {
+        self.sftp_local_panels.get(tab_id).cloned()
+    }
+
     /// 确保命令输入框已创建，并更新占位符为当前语言
     pub fn ensure_command_input_created(
         &mut self,
@@ -290,6 +471,16 @@ impl SessionState {
         self.terminal_focus_handle.clone()
     }
 
+    /// 确保 Monitor 面板焦点句柄已创建
+    pub fn ensure_monitor_focus_handle_created(
+        &mut self,
+        cx: &mut gpui::Context<Self>,
+    ) -> FocusHandle {
+        self.monitor_focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone()
+    }
+
     /// 启动 Monitor 服务
     /// 在终端 PTY 创建成功后调用，开始收集服务器监控数据
     pub fn start_monitor_service(&self, tab_id: String, cx: &mut gpui::Context<Self>) {
@@ -302,8 +493,18 @@ impl SessionState {
             return;
         };
 
-        // 使用默认监控设置
-        let settings = MonitorSettings::default();
+        // 刷新间隔：服务器单独设置优先，否则使用全局设置
+        let monitor_settings = crate::services::storage::load_settings()
+            .unwrap_or_default()
+            .monitor;
+        let interval_secs = self
+            .tabs
+            .iter()
+            .find(|t| t.id == tab_id)
+            .and_then(|t| t.server_data.as_ref())
+            .map(|s| s.resolve_monitor_interval_secs(&monitor_settings))
+            .unwrap_or_else(|| monitor_settings.refresh_interval.secs());
+        let settings = MonitorSettings::with_refresh_interval(interval_secs);
 
         // 创建 MonitorService（需要使用 SSH manager 的 runtime）
         let (service, mut receiver) =
@@ -328,6 +529,11 @@ impl SessionState {
                             let tab_id_clone = tab_id_for_task.clone();
                             let result = async_cx.update(|cx| {
                                 session_state.update(cx, |state, cx| {
+                                    // 告警写入通知中心（需要访问整个状态，不在标签内处理）
+                                    if let MonitorEvent::Alert(alert) = event {
+                                        state.record_monitor_alert(&tab_id_clone, alert, cx);
+                                        return;
+                                    }
                                     if let Some(tab) =
                                         state.tabs.iter_mut().find(|t| t.id == tab_id_clone)
                                     {
@@ -344,9 +550,23 @@ impl SessionState {
                                             MonitorEvent::DiskInfo(info) => {
                                                 tab.monitor_state.update_disk_info(info);
                                             }
+                                            MonitorEvent::GpuInfo(info) => {
+                                                tab.monitor_state.update_gpu_info(info);
+                                            }
+                                            MonitorEvent::Temperatures(sensors) => {
+                                                tab.monitor_state.update_temperatures(sensors);
+                                            }
+                                            MonitorEvent::DriveHealth(drives) => {
+                                                tab.monitor_state.update_drive_health(drives);
+                                            }
+                                            MonitorEvent::LoginInfo(info) => {
+                                                tab.monitor_state.update_login_info(info);
+                                            }
                                             MonitorEvent::Error(e) => {
                                                 tracing::error!("[Monitor] Error: {}", e);
+                                                tab.monitor_state.set_collector_error(e);
                                             }
+                                            MonitorEvent::Alert(_) => {}
                                         }
                                         cx.notify();
                                     }
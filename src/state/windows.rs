@@ -0,0 +1,101 @@
+// 多窗口：标签可以移到独立窗口，各窗口分别记录当前标签
+
+use super::{SessionState, SessionTab};
+use gpui::WindowId;
+use tracing::info;
+
+impl SessionState {
+    /// 指定窗口中的标签（None 表示主窗口）
+    pub fn window_tabs(&self, window_id: Option<WindowId>) -> impl Iterator<Item = &SessionTab> {
+        self.tabs.iter().filter(move |t| t.window_id == window_id)
+    }
+
+    /// 主窗口中是否有会话标签
+    pub fn has_main_tabs(&self) -> bool {
+        self.window_tabs(None).next().is_some()
+    }
+
+    /// 指定窗口的当前标签（记录的标签已移走时使用该窗口的第一个标签）
+    pub fn window_active_tab(&self, window_id: Option<WindowId>) -> Option<&SessionTab> {
+        let active_id = match window_id {
+            Some(window_id) => self.window_active_tabs.get(&window_id),
+            None => self.active_tab_id.as_ref(),
+        };
+        active_id
+            .and_then(|id| self.window_tabs(window_id).find(|t| &t.id == id))
+            .or_else(|| self.window_tabs(window_id).next())
+    }
+
+    /// 所有窗口中处于显示状态的标签
+    pub fn visible_tab_ids(&self) -> Vec<String> {
+        self.active_tab_id
+            .iter()
+            .chain(self.window_active_tabs.values())
+            .cloned()
+            .collect()
+    }
+
+    /// 已打开的独立窗口及其标题（当前标签的名称）
+    pub fn detached_windows(&self) -> Vec<(WindowId, String)> {
+        let mut windows: Vec<(WindowId, String)> = Vec::new();
+        for tab in &self.tabs {
+            if let Some(window_id) = tab.window_id {
+                if !windows.iter().any(|(id, _)| *id == window_id) {
+                    let title = self
+                        .window_active_tab(Some(window_id))
+                        .map(|t| t.server_label.clone())
+                        .unwrap_or_default();
+                    windows.push((window_id, title));
+                }
+            }
+        }
+        windows
+    }
+
+    /// 把标签移到指定窗口（None 表示主窗口）并设为该窗口的当前标签
+    pub fn move_tab_to_window(&mut self, tab_id: &str, window_id: Option<WindowId>) {
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) else {
+            return;
+        };
+        let previous = tab.window_id;
+        if previous == window_id {
+            return;
+        }
+        tab.window_id = window_id;
+
+        // 原窗口的当前标签切换到剩余的第一个
+        let next_id = self.window_tabs(previous).next().map(|t| t.id.clone());
+        match previous {
+            Some(previous) => match next_id {
+                Some(next_id) => {
+                    self.window_active_tabs.insert(previous, next_id);
+                }
+                None => {
+                    self.window_active_tabs.remove(&previous);
+                }
+            },
+            None if self.active_tab_id.as_deref() == Some(tab_id) => {
+                self.active_tab_id = next_id;
+            }
+            None => {}
+        }
+
+        self.activate_tab(tab_id);
+        if window_id.is_none() {
+            self.show_home = false;
+        }
+        info!("[Window] Moved tab {} to window {:?}", tab_id, window_id);
+    }
+
+    /// 独立窗口关闭时，把其中的标签移回主窗口（会话保持连接）
+    pub fn close_window(&mut self, window_id: WindowId) {
+        let tab_ids: Vec<String> = self
+            .window_tabs(Some(window_id))
+            .map(|t| t.id.clone())
+            .collect();
+        for tab_id in &tab_ids {
+            self.move_tab_to_window(tab_id, None);
+        }
+        self.window_active_tabs.remove(&window_id);
+    }
+}
@@ -0,0 +1,136 @@
+// 会话工作区：保存当前打开的标签与面板布局，之后按相同的顺序重新连接
+
+use super::SessionState;
+use crate::models::workspace::{Workspace, WorkspaceTab};
+use crate::services::inventory::{self, SharedInventory};
+use crate::services::storage;
+use tracing::{info, warn};
+
+impl SessionState {
+    /// 当前会话的快照（没有打开的标签时返回 None）
+    pub fn workspace_snapshot(&self, name: String) -> Option<Workspace> {
+        if self.tabs.is_empty() {
+            return None;
+        }
+        let saved_ids: Vec<String> = storage::load_servers()
+            .map(|config| config.servers.into_iter().map(|s| s.id).collect())
+            .unwrap_or_default();
+
+        let tabs = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                // 已保存的服务器在恢复时重新读取，其余连接（临时连接、共享清单）保存连接数据
+                let server_data = if saved_ids.contains(&tab.server_id) {
+                    None
+                } else {
+                    tab.server_data.clone()
+                };
+                WorkspaceTab {
+                    server_id: tab.server_id.clone(),
+                    server_label: tab.server_label.clone(),
+                    server_data,
+                    terminal_count: tab.terminals.len().max(1) as u32,
+                    sftp_path: tab
+                        .sftp_state
+                        .as_ref()
+                        .map(|s| s.current_path.clone())
+                        .filter(|p| !p.is_empty())
+                        .or_else(|| tab.sftp_start_path.clone()),
+                    pinned: tab.pinned,
+                    color: tab.color,
+                }
+            })
+            .collect();
+        let active_index = self
+            .active_tab_id
+            .as_ref()
+            .and_then(|id| self.tabs.iter().position(|t| &t.id == id));
+
+        Some(Workspace {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            tabs,
+            active_index,
+            sidebar_panel: self.active_sidebar_panel,
+            sidebar_collapsed: self.sidebar_collapsed,
+            monitor_panel_view: self.monitor_panel_view,
+            saved_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+
+    /// 恢复工作区：按保存的顺序打开标签并重建终端数量与 SFTP 目录，返回打开的标签数
+    pub fn restore_workspace(
+        &mut self,
+        workspace: &Workspace,
+        cx: &mut gpui::Context<Self>,
+    ) -> usize {
+        let saved_ids: Vec<String> = storage::load_servers()
+            .map(|config| config.servers.into_iter().map(|s| s.id).collect())
+            .unwrap_or_default();
+
+        // 新标签插入到最前面，因此倒序添加以保持原来的顺序
+        let mut opened: Vec<(usize, String)> = Vec::new();
+        for (index, saved) in workspace.tabs.iter().enumerate().rev() {
+            let tab_id = if saved_ids.contains(&saved.server_id) {
+                self.add_tab(saved.server_id.clone(), saved.server_label.clone())
+            } else if let Some(server) = inventory::is_shared_id(&saved.server_id)
+                .then(|| SharedInventory::global().server(&saved.server_id))
+                .flatten()
+            {
+                // 共享清单已加载时使用最新的清单条目
+                self.add_adhoc_tab(server)
+            } else if let Some(server) = saved.server_data.clone() {
+                self.add_adhoc_tab(server)
+            } else {
+                warn!(
+                    "[Workspace] Server {} is no longer available, skipped",
+                    saved.server_label
+                );
+                continue;
+            };
+
+            for _ in 1..saved.terminal_count {
+                self.add_terminal_instance(&tab_id);
+            }
+            if let Some(tab) = self.tabs.iter_mut().find(|t| t.id == tab_id) {
+                // 恢复后先显示第一个终端
+                tab.active_terminal_id = tab.terminals.first().map(|t| t.id.clone());
+                tab.sftp_start_path = saved.sftp_path.clone();
+                tab.color = saved.color;
+            }
+            opened.push((index, tab_id));
+        }
+        if opened.is_empty() {
+            return 0;
+        }
+        // 全部添加后再固定，避免影响新标签的插入位置
+        for (index, tab_id) in &opened {
+            if let Some(tab) = self.tabs.iter_mut().find(|t| &t.id == tab_id) {
+                tab.pinned = workspace.tabs[*index].pinned;
+            }
+        }
+        self.sort_pinned_tabs();
+
+        let active_tab_id = workspace
+            .active_index
+            .and_then(|active| opened.iter().find(|(index, _)| *index == active))
+            .or_else(|| opened.last())
+            .map(|(_, tab_id)| tab_id.clone());
+        if let Some(tab_id) = active_tab_id {
+            self.activate_tab(&tab_id);
+        }
+        self.active_sidebar_panel = workspace.sidebar_panel;
+        self.sidebar_collapsed = workspace.sidebar_collapsed;
+        self.set_monitor_panel_view(workspace.monitor_panel_view, cx);
+        // 确保 Monitor 详情弹窗状态已创建
+        self.ensure_monitor_detail_dialog(cx);
+
+        info!(
+            "[Workspace] Restored {} tabs from workspace {}",
+            opened.len(),
+            workspace.name
+        );
+        opened.len()
+    }
+}
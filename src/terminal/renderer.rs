@@ -12,11 +12,48 @@ use crate::terminal::colors::hex_to_hsla;
 use crate::terminal::state::{EventProxy, TerminalSize};
 use crate::terminal::TERMINAL_PADDING_LEFT;
 
+/// 非活动面板变暗时叠加的背景色不透明度
+const INACTIVE_DIM_ALPHA: f32 = 0.45;
+
+/// 终端外观参数（背景透明度与非活动变暗）
+#[derive(Clone, Copy, Debug)]
+pub struct TerminalAppearance {
+    /// 默认背景的不透明度 (0.0 - 1.0)
+    pub background_alpha: f32,
+    /// 是否以变暗方式绘制（终端未聚焦）
+    pub dimmed: bool,
+}
+
+impl TerminalAppearance {
+    /// 根据全局设置、服务器覆盖值和焦点状态计算外观
+    pub fn resolve(
+        settings: &TerminalSettings,
+        opacity_override: Option<u32>,
+        focused: bool,
+    ) -> Self {
+        let opacity = crate::theme::terminal_opacity(settings, opacity_override);
+        Self {
+            background_alpha: opacity as f32 / 100.0,
+            dimmed: settings.dim_inactive_panes && !focused,
+        }
+    }
+}
+
+impl Default for TerminalAppearance {
+    fn default() -> Self {
+        Self {
+            background_alpha: 1.0,
+            dimmed: false,
+        }
+    }
+}
+
 /// 渲染终端内容（Canvas 方式）
 pub fn render_terminal_view(
     term: &Term<EventProxy>,
     size: &TerminalSize,
     settings: &TerminalSettings,
+    appearance: TerminalAppearance,
     cursor_visible: bool,
     _cx: &App,
 ) -> impl IntoElement {
@@ -47,7 +84,7 @@ pub fn render_terminal_view(
 
     div()
         .size_full()
-        .bg(bg_color)
+        .bg(bg_color.opacity(appearance.background_alpha))
         .relative()
         .overflow_hidden()
         .child(
@@ -100,12 +137,67 @@ pub fn render_terminal_view(
                             );
                         }
                     }
+
+                    // 5. 非活动面板变暗：在最上层叠加半透明背景色
+                    if appearance.dimmed {
+                        window.paint_quad(fill(bounds, bg_color.opacity(INACTIVE_DIM_ALPHA)));
+                    }
                 },
             )
             .size_full(),
         )
 }
 
+/// 渲染终端缩略图（用于标签页总览）
+/// 按 `scale` 等比缩小网格，文本以同色块代替字形绘制，避免小字号排版开销
+pub fn render_terminal_thumbnail(
+    term: &Term<EventProxy>,
+    size: &TerminalSize,
+    settings: &TerminalSettings,
+    scale: f32,
+) -> impl IntoElement {
+    let layout = layout_grid(term, settings);
+    let bg_color = hex_to_hsla(&settings.background_color);
+
+    let cell_width = size.cell_width * scale;
+    let line_height = size.line_height * scale;
+    // 色块高度约为行高的 60%，保留行间距以便辨认文本结构
+    let glyph_height = (line_height * 0.6).max(1.0);
+
+    div().size_full().bg(bg_color).overflow_hidden().child(
+        canvas(
+            move |_bounds, _window, _cx| layout.clone(),
+            move |bounds, layout, window, _cx| {
+                let origin = Point::new(
+                    bounds.origin.x + px(TERMINAL_PADDING_LEFT * scale),
+                    bounds.origin.y,
+                );
+
+                for rect in &layout.background_rects {
+                    rect.paint(origin, cell_width, line_height, window);
+                }
+
+                for run in &layout.text_runs {
+                    let pos = Point::new(
+                        origin.x + px(run.start_col as f32 * cell_width),
+                        origin.y
+                            + px(run.line as f32 * line_height + (line_height - glyph_height) / 2.0),
+                    );
+                    let run_size = Size::new(
+                        px(run.cell_count as f32 * cell_width),
+                        px(glyph_height),
+                    );
+                    window.paint_quad(fill(
+                        Bounds::new(pos, run_size),
+                        run.fg_color.opacity(0.75),
+                    ));
+                }
+            },
+        )
+        .size_full(),
+    )
+}
+
 /// 绘制光标
 fn paint_cursor(
     alac_point: AlacPoint,
@@ -102,6 +102,15 @@ impl EventListener for EventProxy {
     }
 }
 
+/// 终端快照（休眠时保留的滚动历史，用于唤醒后恢复显示）
+#[derive(Clone, Debug)]
+pub struct TerminalSnapshot {
+    /// 滚动历史与屏幕内容的纯文本
+    pub text: String,
+    /// 休眠前的终端尺寸
+    pub size: TerminalSize,
+}
+
 /// 终端状态
 pub struct TerminalState {
     /// alacritty 终端实例
@@ -121,6 +130,8 @@ pub struct TerminalState {
     cursor_visible: bool,
     /// 终端显示区域在窗口中的偏移原点
     bounds_origin: (f32, f32),
+    /// 最近一次调整尺寸时的显示区域像素大小（用于字体变化后重新计算行列数）
+    area: (f32, f32),
 }
 
 impl TerminalState {
@@ -131,13 +142,12 @@ impl TerminalState {
         // 默认尺寸
         let size = TerminalSize::default();
 
-        // 创建终端配置
-        let mut config = TermConfig::default();
-        config.scrolling_history =
-            (settings.scrollback_lines as usize).min(Self::MAX_SCROLLBACK_LINES);
-
         // 创建终端实例
-        let term = Arc::new(FairMutex::new(Term::new(config, &size, EventProxy)));
+        let term = Arc::new(FairMutex::new(Term::new(
+            Self::term_config(&settings),
+            &size,
+            EventProxy,
+        )));
         let scroll_handle = TerminalScrollHandle::new(
             term.clone(),
             px(size.line_height),
@@ -156,6 +166,67 @@ impl TerminalState {
             scroll_px: px(0.),
             cursor_visible: true,
             bounds_origin: (0.0, 0.0),
+            area: (0.0, 0.0),
+        }
+    }
+
+    /// 根据终端设置创建 alacritty 配置
+    fn term_config(settings: &TerminalSettings) -> TermConfig {
+        let mut config = TermConfig::default();
+        config.scrolling_history =
+            (settings.scrollback_lines as usize).min(Self::MAX_SCROLLBACK_LINES);
+        config
+    }
+
+    /// 应用新的终端设置：更新回滚行数，并按新的字体度量重新计算行列数
+    pub fn apply_settings(
+        &mut self,
+        settings: TerminalSettings,
+        cell_width: f32,
+        line_height: f32,
+    ) {
+        self.term.lock().set_options(Self::term_config(&settings));
+        self.settings = settings;
+
+        let (width, height) = self.area;
+        if width > 0.0 && height > 0.0 {
+            self.resize(width, height, cell_width, line_height);
+        }
+    }
+
+    /// 从快照恢复终端状态（历史内容以纯文本形式重新写入）
+    pub fn from_snapshot(settings: TerminalSettings, snapshot: &TerminalSnapshot) -> Self {
+        let mut state = Self::new(settings);
+        state.size = snapshot.size.clone();
+        state
+            .scroll_handle
+            .set_line_height(px(state.size.line_height));
+        state
+            .scroll_handle
+            .set_viewport_height(px(state.size.line_height * state.size.lines as f32));
+        state.term.lock().resize(state.size.clone());
+
+        if !snapshot.text.is_empty() {
+            state.input_str(&snapshot.text.replace('\n', "\r\n"));
+            state.input_str("\r\n");
+        }
+        state
+    }
+
+    /// 生成终端快照（包含全部滚动历史）
+    pub fn snapshot(&self) -> TerminalSnapshot {
+        let term = self.term.lock();
+        let grid = term.grid();
+        let start = AlacPoint::new(Line(-(grid.history_size() as i32)), Column(0));
+        let end = AlacPoint::new(
+            Line(grid.screen_lines() as i32 - 1),
+            Column(grid.columns().saturating_sub(1)),
+        );
+        let text = term.bounds_to_string(start, end).trim_end().to_string();
+
+        TerminalSnapshot {
+            text,
+            size: self.size.clone(),
         }
     }
 
@@ -186,6 +257,7 @@ impl TerminalState {
 
     /// 调整终端尺寸
     pub fn resize(&mut self, width: f32, height: f32, cell_width: f32, line_height: f32) {
+        self.area = (width, height);
         let new_size = TerminalSize::from_pixels(width, height, cell_width, line_height);
 
         let dimensions_changed =
@@ -4,23 +4,20 @@
 use std::sync::Arc;
 
 use gpui::*;
+use gpui_component::notification::NotificationType;
 use tracing::{debug, error, info, trace, warn};
 
 use crate::models::settings::TerminalSettings;
+use crate::services::connection_history::ConnectionHistoryStore;
+use crate::services::notifications::{notify_key, NotificationCategory};
 use crate::ssh::session::{PtyRequest, TerminalChannel};
 use crate::state::{SessionState, SessionStatus};
 use crate::terminal::{TerminalState, TERMINAL_PADDING_LEFT};
 
-/// 使用 GPUI text_system 精确计算终端尺寸
+/// 测量终端单元格的宽度与行高
 ///
-/// 通过测量字体中 'm' 字符的实际 advance width 来精确计算终端的列数和行数
-pub fn calculate_terminal_size(
-    area_width: f32,
-    area_height: f32,
-    settings: &TerminalSettings,
-    window: &Window,
-    cx: &App,
-) -> (u32, u32, f32, f32) {
+/// 通过测量字体中 'm' 字符的实际 advance width 来精确计算单元格宽度
+pub fn measure_terminal_cell(settings: &TerminalSettings, window: &Window) -> (f32, f32) {
     let text_system = window.text_system();
 
     // 构建字体
@@ -49,6 +46,19 @@ pub fn calculate_terminal_size(
     // 行高计算
     let line_height = settings.font_size as f32 * settings.line_height;
 
+    (cell_width, line_height)
+}
+
+/// 使用 GPUI text_system 精确计算终端尺寸
+pub fn calculate_terminal_size(
+    area_width: f32,
+    area_height: f32,
+    settings: &TerminalSettings,
+    window: &Window,
+    cx: &App,
+) -> (u32, u32, f32, f32) {
+    let (cell_width, line_height) = measure_terminal_cell(settings, window);
+
     // 计算列数时减去左侧 padding 宽度
     let effective_width = area_width - TERMINAL_PADDING_LEFT;
     let cols = (effective_width / cell_width).floor() as u32;
@@ -103,6 +113,18 @@ pub fn start_pty_reader(
                             t.input(&data);
                             cx.notify();
                         });
+                        // 运行中的 Runbook 需要读取输出以匹配等待步骤
+                        if session_state.read(cx).runbook_runs.contains_key(&tab_id) {
+                            session_state.update(cx, |state, cx| {
+                                state.feed_runbook_output(&tab_id, &terminal_id, &data, cx);
+                            });
+                        }
+                        // 插件正在读取该标签的终端输出
+                        if session_state.read(cx).plugins.is_reading_output(&tab_id) {
+                            session_state.update(cx, |state, _| {
+                                state.feed_plugin_output(&tab_id, &terminal_id, &data);
+                            });
+                        }
                     });
                 }
                 Ok(Some(_)) => {
@@ -125,8 +147,30 @@ pub fn start_pty_reader(
             }
         }
 
+        // 通道已不再属于该终端实例（如标签休眠或关闭时主动释放），无需断开处理
+        let channel_released = async_cx
+            .update(|cx| {
+                session_state
+                    .read(cx)
+                    .tabs
+                    .iter()
+                    .find(|t| t.id == tab_id)
+                    .and_then(|t| t.terminals.iter().find(|i| i.id == terminal_id))
+                    .map(|instance| {
+                        !instance
+                            .pty_channel
+                            .as_ref()
+                            .is_some_and(|c| Arc::ptr_eq(c, &channel))
+                    })
+                    .unwrap_or(true)
+            })
+            .unwrap_or(true);
+        if channel_released {
+            debug!("[PTY Reader] Channel released by session, skipping disconnect handling");
+        }
+
         // 断开连接后处理
-        if disconnect_reason.is_some() {
+        if disconnect_reason.is_some() && !channel_released {
             // 读取设置和 server_data
             let (auto_reconnect, server_data) = async_cx
                 .update(|cx| {
@@ -187,30 +231,20 @@ fn set_disconnected_status(
         session_state_clone.update(cx, |state, cx| {
             if let Some(tab) = state.tabs.iter_mut().find(|t| t.id == tab_id_clone) {
                 tab.status = SessionStatus::Disconnected;
+                if let Some(history_id) = tab.history_id.take() {
+                    ConnectionHistoryStore::global().finish(&history_id);
+                }
             }
             cx.notify();
         });
 
         // 推送断开通知
-        if let Some(window) = cx.active_window() {
-            use gpui::AppContext as _;
-            let _ = cx.update_window(window, |_, window, cx| {
-                use gpui::Styled;
-                use gpui_component::notification::{Notification, NotificationType};
-                use gpui_component::WindowExt;
-
-                let lang = crate::services::storage::load_settings()
-                    .map(|s| s.theme.language)
-                    .unwrap_or_default();
-
-                let notification = Notification::new()
-                    .message(crate::i18n::t(&lang, "terminal.disconnected"))
-                    .with_type(NotificationType::Warning)
-                    .w_48()
-                    .py_2();
-                window.push_notification(notification, cx);
-            });
-        }
+        notify_key(
+            cx,
+            NotificationCategory::Session,
+            NotificationType::Warning,
+            "terminal.disconnected",
+        );
     });
 }
 
@@ -1,8 +1,19 @@
 use gpui::*;
-use gpui_component::theme::Theme;
+use gpui_component::theme::{Theme, ThemeConfig, ThemeConfigColors};
 use gpui_component::ActiveTheme;
 use std::rc::Rc;
 
+use crate::models::settings::{TerminalSettings, UiColorSlot, UiPalette, UiTheme};
+use crate::services::storage;
+
+/// 内置配色（应用用户界面配色之前的主题配置），切换界面配色时以此为基础
+struct BaseThemeConfigs {
+    dark: ThemeConfig,
+    light: ThemeConfig,
+}
+
+impl Global for BaseThemeConfigs {}
+
 /// 初始化全局主题配置
 /// 覆盖默认的深色模式主题，使用统一的深蓝色风格
 pub fn init(cx: &mut App) {
@@ -66,6 +77,25 @@ pub fn init(cx: &mut App) {
     light_config.colors.secondary_hover = Some("#e2e8f0".into()); // Slate 200
     light_config.colors.secondary_foreground = Some("#0f172a".into()); // Slate 900 (Dark text)
 
+    // 保存内置配色，再叠加用户的界面配色
+    cx.set_global(BaseThemeConfigs {
+        dark: dark_config,
+        light: light_config,
+    });
+    let settings = storage::load_settings().unwrap_or_default();
+    apply_ui_theme(&settings.theme.ui_theme, cx);
+}
+
+/// 在内置配色上叠加界面配色并立即应用到所有窗口
+pub fn apply_ui_theme(ui_theme: &UiTheme, cx: &mut App) {
+    let Some(base) = cx.try_global::<BaseThemeConfigs>() else {
+        return;
+    };
+    let mut dark_config = base.dark.clone();
+    let mut light_config = base.light.clone();
+    apply_palette(&mut dark_config.colors, &ui_theme.dark);
+    apply_palette(&mut light_config.colors, &ui_theme.light);
+
     // 更新全局主题
     let theme = Theme::global_mut(cx);
     theme.dark_theme = Rc::new(dark_config);
@@ -77,6 +107,85 @@ pub fn init(cx: &mut App) {
     } else {
         theme.apply_config(&theme.light_theme.clone());
     }
+    cx.refresh_windows();
+}
+
+/// 把配色写入主题颜色配置（无效的颜色忽略）
+fn apply_palette(colors: &mut ThemeConfigColors, palette: &UiPalette) {
+    let color = |slot| {
+        palette
+            .get(slot)
+            .filter(|c| parse_hex_color(c).is_some())
+            .map(|c| SharedString::from(c.to_string()))
+    };
+    if let Some(accent) = color(UiColorSlot::Accent) {
+        // 悬停色比强调色略暗
+        colors.primary_hover = shade_hex(&accent, 0.85).map(Into::into);
+        colors.primary = Some(accent);
+    }
+    if let Some(background) = color(UiColorSlot::Background) {
+        colors.background = Some(background);
+    }
+    if let Some(surface) = color(UiColorSlot::Surface) {
+        colors.popover = Some(surface);
+    }
+    if let Some(sidebar) = color(UiColorSlot::Sidebar) {
+        colors.sidebar = Some(sidebar.clone());
+        colors.title_bar = Some(sidebar);
+    }
+    if let Some(border) = color(UiColorSlot::Border) {
+        colors.border = Some(border.clone());
+        colors.title_bar_border = Some(border);
+    }
+}
+
+/// 解析 `#RRGGBB` 格式的颜色
+pub fn parse_hex_color(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// 按比例调暗颜色
+fn shade_hex(color: &str, factor: f32) -> Option<String> {
+    let [r, g, b] = parse_hex_color(color)?;
+    let scale = |c: u8| (c as f32 * factor).round() as u8;
+    Some(format!("#{:02x}{:02x}{:02x}", scale(r), scale(g), scale(b)))
+}
+
+/// 界面配色中某个颜色的实际显示值（未设置时取内置配色）
+pub fn palette_color(palette: &UiPalette, slot: UiColorSlot, dark: bool, cx: &App) -> Hsla {
+    if let Some([r, g, b]) = palette.get(slot).and_then(parse_hex_color) {
+        return crate::terminal::rgb_to_hsla(r, g, b);
+    }
+    let base = cx.try_global::<BaseThemeConfigs>().map(|base| {
+        if dark {
+            &base.dark.colors
+        } else {
+            &base.light.colors
+        }
+    });
+    let builtin = base.and_then(|colors| match slot {
+        UiColorSlot::Accent => colors.primary.clone(),
+        UiColorSlot::Background => colors.background.clone(),
+        UiColorSlot::Surface => colors.popover.clone(),
+        UiColorSlot::Sidebar => colors.sidebar.clone(),
+        UiColorSlot::Border => colors.border.clone(),
+    });
+    match builtin.as_deref().and_then(parse_hex_color) {
+        Some([r, g, b]) => crate::terminal::rgb_to_hsla(r, g, b),
+        // 内置配置未指定时使用当前主题颜色
+        None => match slot {
+            UiColorSlot::Accent => cx.theme().primary,
+            UiColorSlot::Background => cx.theme().background,
+            UiColorSlot::Surface => cx.theme().popover,
+            UiColorSlot::Sidebar => cx.theme().sidebar,
+            UiColorSlot::Border => cx.theme().border,
+        },
+    }
 }
 
 // 兼容性帮助函数 - 现在的实现直接返回全局主题颜色，
@@ -101,3 +210,50 @@ pub fn sidebar_color(cx: &App) -> Hsla {
 pub fn titlebar_color(cx: &App) -> Hsla {
     cx.theme().title_bar
 }
+
+/// 终端背景实际使用的不透明度（0-100），服务器级覆盖值优先于全局设置
+pub fn terminal_opacity(settings: &TerminalSettings, opacity_override: Option<u32>) -> u32 {
+    opacity_override
+        .unwrap_or(settings.background_opacity)
+        .min(100)
+}
+
+/// 根据终端透明度设置（含当前标签服务器的覆盖值）计算窗口背景外观
+/// 终端背景不透明时保持窗口不透明，避免无谓的合成开销
+pub fn window_background_appearance(
+    settings: &TerminalSettings,
+    opacity_override: Option<u32>,
+) -> WindowBackgroundAppearance {
+    if terminal_opacity(settings, opacity_override) >= 100 {
+        WindowBackgroundAppearance::Opaque
+    } else if settings.background_blur {
+        WindowBackgroundAppearance::Blurred
+    } else {
+        WindowBackgroundAppearance::Transparent
+    }
+}
+
+/// 会话视图根节点的背景色
+/// 窗口透明时不再绘制不透明底色，否则终端的半透明背景会被完全遮住
+pub fn session_background_color(appearance: WindowBackgroundAppearance, cx: &App) -> Hsla {
+    if appearance == WindowBackgroundAppearance::Opaque {
+        background_color(cx)
+    } else {
+        transparent_black()
+    }
+}
+
+/// 按当前标签计算窗口背景外观，与上次应用的不同时才更新窗口
+pub fn sync_window_background(
+    window: &mut Window,
+    applied: &mut WindowBackgroundAppearance,
+    opacity_override: Option<u32>,
+) -> WindowBackgroundAppearance {
+    let settings = storage::load_settings().unwrap_or_default();
+    let appearance = window_background_appearance(&settings.terminal, opacity_override);
+    if appearance != *applied {
+        window.set_background_appearance(appearance);
+        *applied = appearance;
+    }
+    appearance
+}